@@ -0,0 +1,70 @@
+//! Reads the arguments and output width of each transpiled circuit in `src`
+//! into `$OUT_DIR/widths.rs`, see [`widths`](src/widths.rs).
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut circuits = Vec::new();
+    for entry in fs::read_dir("src").unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        let Some(name) = file_name
+            .strip_prefix("frogzone_")
+            .and_then(|name| name.strip_suffix("_rs_fhe_lib.rs"))
+        else {
+            continue;
+        };
+        circuits.push((name.to_string(), fs::read_to_string(&path).unwrap()));
+    }
+    circuits.sort();
+
+    let mut out = String::from("pub static CIRCUITS: &[CircuitWidths] = &[\n");
+    for (name, src) in &circuits {
+        let args = between(src, "let args: &[&Vec<FheBool<E>>] = &[", "];")
+            .unwrap_or_else(|| panic!("no `let args` in circuit {name}"))
+            .split(',')
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        let output_bits: usize = between(src, "out.resize(", ",")
+            .unwrap_or_else(|| panic!("no `out.resize` in circuit {name}"))
+            .trim()
+            .parse()
+            .unwrap();
+
+        // Highest bit read from each argument, `Arg(position, bit)`.
+        let mut max_bits = vec![None; args.len()];
+        for rest in src.split("Arg(").skip(1) {
+            let (position, rest) = rest.split_once(',').unwrap();
+            let (bit, _) = rest.split_once(')').unwrap();
+            let Ok(position) = position.trim().parse::<usize>() else {
+                continue;
+            };
+            let bit: usize = bit.trim().parse().unwrap();
+            let max_bit = &mut max_bits[position];
+            *max_bit = Some(max_bit.map_or(bit, |max_bit: usize| max_bit.max(bit)));
+        }
+
+        writeln!(out, "    CircuitWidths {{").unwrap();
+        writeln!(out, "        name: {name:?},").unwrap();
+        writeln!(out, "        args: &[").unwrap();
+        for (arg, max_bit) in args.iter().zip(max_bits) {
+            writeln!(out, "            ({arg:?}, {max_bit:?}),").unwrap();
+        }
+        writeln!(out, "        ],").unwrap();
+        writeln!(out, "        output_bits: {output_bits},").unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    out += "];\n";
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("widths.rs"), out).unwrap();
+}
+
+/// Returns the text of `src` between the first `start` and the next `end`.
+fn between<'a>(src: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = src.split_once(start)?;
+    let (between, _) = rest.split_once(end)?;
+    Some(between)
+}
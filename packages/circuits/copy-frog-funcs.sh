@@ -3,8 +3,12 @@
 set -ex
 
 FUNCS="apply_move
+apply_move_pvp
 apply_move_flying
 apply_move_monster
+respawn
+maintain_monsters
+maintain_items
 get_cell
 get_cross_cells
 get_five_cells
@@ -14,3 +18,4 @@ get_vertical_cells"
 for func in $FUNCS; do
     cp ~/git/fully-homomorphic-encryption/projects/frogzone/out/${func}_rs_fhe_lib.rs src/frogzone_${func}_rs_fhe_lib.rs
 done
+
@@ -0,0 +1,5 @@
+//! Prints `circuits_cpp/src/capacity.h`, see [`phantom_benchs::capacity`].
+
+fn main() {
+    print!("{}", phantom_benchs::capacity::header());
+}
@@ -0,0 +1,51 @@
+//! Capacities of the zone, the single source of truth for the Rust structs and
+//! for `circuits_cpp/src/capacity.h`, which is generated from them with
+//! `cargo run --bin capacity_header > ../circuits_cpp/src/capacity.h`.
+
+/// Number of players, each holding a key share. Only 4 is supported: the named
+/// FHE parameters are vetted for 4 users, and the server checks its parameter
+/// has this many shares, see `server::zone::check_circuits`. Changing it needs
+/// a parameter vetted for the new number and the circuits to be regenerated.
+pub const NUM_PLAYERS: usize = 4;
+pub const NUM_ITEMS: usize = 12;
+pub const NUM_MONSTERS: usize = 23;
+pub const NUM_OBSTACLES: usize = 193;
+pub const NUM_SPAWN_POINTS: usize = 8;
+
+// Player `i` starts at spawn point `i`.
+const _: () = assert!(NUM_PLAYERS <= NUM_SPAWN_POINTS);
+
+/// Number of entity ids, players, items and monsters being numbered apart.
+pub const NUM_ENTITY_IDS: usize = max(NUM_PLAYERS, max(NUM_ITEMS, NUM_MONSTERS));
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Contents of `capacity.h`.
+pub fn header() -> String {
+    let defines = [
+        ("NUM_PLAYERS", NUM_PLAYERS),
+        ("NUM_ITEMS", NUM_ITEMS),
+        ("NUM_MONSTERS", NUM_MONSTERS),
+        ("NUM_OBSTACLES", NUM_OBSTACLES),
+        ("NUM_SPAWN_POINTS", NUM_SPAWN_POINTS),
+    ];
+    let mut header = String::from(
+        "// Generated by `cargo run --bin capacity_header` from `circuits/src/capacity.rs`,\n\
+         // do not edit by hand.\n\
+         \n\
+         #ifndef CAPACITY_H\n\
+         #define CAPACITY_H\n\
+         \n",
+    );
+    for (name, value) in defines {
+        header += &format!("#define {name} {value}\n");
+    }
+    header += "\n#endif\n";
+    header
+}
@@ -0,0 +1 @@
+../../circuits_cpp/src/out/maintain_monsters_rs_fhe_lib.rs
\ No newline at end of file
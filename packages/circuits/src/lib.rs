@@ -1,8 +1,14 @@
+pub mod capacity;
 pub mod frogzone_apply_move_flying_rs_fhe_lib;
 pub mod frogzone_apply_move_monster_rs_fhe_lib;
+pub mod frogzone_apply_move_pvp_rs_fhe_lib;
 pub mod frogzone_apply_move_rs_fhe_lib;
 pub mod frogzone_get_cell_rs_fhe_lib;
 pub mod frogzone_get_cross_cells_rs_fhe_lib;
 pub mod frogzone_get_five_cells_rs_fhe_lib;
 pub mod frogzone_get_horizontal_cells_rs_fhe_lib;
 pub mod frogzone_get_vertical_cells_rs_fhe_lib;
+pub mod frogzone_maintain_items_rs_fhe_lib;
+pub mod frogzone_maintain_monsters_rs_fhe_lib;
+pub mod frogzone_respawn_rs_fhe_lib;
+pub mod widths;
@@ -5,6 +5,7 @@ use rand::{rngs::StdRng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::env;
 
+use phantom_benchs::capacity::{NUM_ITEMS, NUM_OBSTACLES, NUM_PLAYERS};
 use phantom_benchs::*;
 
 // type Evaluator = FhewBoolEvaluator<NoisyNativeRing, NonNativePowerOfTwoRing>;
@@ -283,10 +284,6 @@ enum Bench {
     FZGetVerticalCells,
 }
 
-const NUM_OBSTACLES: usize = 100;
-const NUM_PLAYERS: usize = 4;
-const NUM_ITEMS: usize = 16;
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub struct Coord {
     pub x: u8,
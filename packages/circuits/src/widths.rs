@@ -0,0 +1,80 @@
+//! Arguments and output width of each transpiled circuit, read from its source
+//! by `build.rs`, to check a circuit against the layout it's called with before
+//! a stale one silently reads or writes the wrong bits.
+
+/// Widths of a transpiled circuit.
+#[derive(Debug)]
+pub struct CircuitWidths {
+    pub name: &'static str,
+    /// Arguments in the order of the C++ function, each with the highest bit
+    /// the circuit reads from it, if any.
+    pub args: &'static [(&'static str, Option<usize>)],
+    pub output_bits: usize,
+}
+
+include!(concat!(env!("OUT_DIR"), "/widths.rs"));
+
+/// Layout an argument is passed to a circuit with: `len` elements of `bits`
+/// bits each.
+#[derive(Clone, Copy, Debug)]
+pub struct ArgLayout {
+    pub name: &'static str,
+    pub len: usize,
+    pub bits: usize,
+}
+
+/// Checks the circuit `name` takes exactly the arguments `args`, reads each of
+/// them within its layout and up to its last element, and outputs
+/// `output_bits` bits.
+pub fn check(name: &str, args: &[ArgLayout], output_bits: usize) -> Result<(), String> {
+    let circuit = CIRCUITS
+        .iter()
+        .find(|circuit| circuit.name == name)
+        .ok_or_else(|| format!("circuit {name} is missing"))?;
+    let stale = |what: String| {
+        Err(format!(
+            "circuit {name} {what}, regenerate it with the current capacities and layouts"
+        ))
+    };
+
+    if circuit.args.len() != args.len() {
+        return stale(format!(
+            "takes {} arguments instead of {}",
+            circuit.args.len(),
+            args.len()
+        ));
+    }
+    for layout in args {
+        let Some((_, max_bit)) = circuit.args.iter().find(|(arg, _)| *arg == layout.name) else {
+            return stale(format!("doesn't take argument {}", layout.name));
+        };
+        let bits = layout.len * layout.bits;
+        match *max_bit {
+            Some(max_bit) if max_bit >= bits => {
+                return stale(format!(
+                    "reads bit {max_bit} of {} which has {bits} bits",
+                    layout.name
+                ));
+            }
+            // Every element of an array is read, at least its location, so a
+            // circuit not reaching the last one was made for a shorter array.
+            Some(max_bit) if layout.len > 1 && max_bit < bits - layout.bits => {
+                return stale(format!(
+                    "reads up to bit {max_bit} of {} which has {bits} bits",
+                    layout.name
+                ));
+            }
+            None if layout.len > 1 => {
+                return stale(format!("doesn't read {}", layout.name));
+            }
+            _ => {}
+        }
+    }
+    if circuit.output_bits != output_bits {
+        return stale(format!(
+            "outputs {} bits instead of {output_bits}",
+            circuit.output_bits
+        ));
+    }
+    Ok(())
+}
@@ -6,8 +6,12 @@ project="frogzone"
 
 if [ -z "${func}" ]; then
 	funcs="apply_move
+	apply_move_pvp
 	apply_move_monster
 	apply_move_flying
+	respawn
+	maintain_monsters
+	maintain_items
 	get_cell
 	get_five_cells
 	get_cross_cells
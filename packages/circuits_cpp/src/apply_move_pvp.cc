@@ -0,0 +1,96 @@
+#include "frogzone.h"
+
+// Same as apply_move, except that moving into another frog attacks it: the
+// attacker's atk is subtracted from the defender's hp, and on a kill the
+// defender's points go to the attacker.  Either way the attacker stays in
+// place.
+#pragma hls_top
+ApplyMovePvpOut apply_move_pvp(
+                               PlayerData player_data,
+                               Direction direction,
+                               OtherPlayers others,
+                               Monsters monsters,
+                               Items items) {
+  // A dead player can't move, pick up items or attack.
+  if (player_data.hp == 0) {
+    return ApplyMovePvpOut{
+      .player_data = player_data,
+      .others = others,
+      .items = items,
+      .monsters = monsters,
+    };
+  }
+
+  Coord old_coords = player_data.loc;
+  Coord new_coords = apply_move_check_obstacles(player_data.loc, direction);
+
+  PlayerData new_player_data = player_data;
+  OtherPlayers new_others = others;
+  Items new_items = items;
+  Monsters new_monsters = monsters;
+
+  bool hit_player = false;
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_PLAYERS - 1; i++) {
+    PlayerData other = others.values[i];
+    if (new_coords == other.loc) {
+      hit_player = true;
+      // Dead frogs still block the way, but can't be attacked.
+      if (other.hp != 0) {
+        if (player_data.atk >= other.hp) {
+          new_others.values[i].hp = 0;
+          new_others.values[i].points = 0;
+          new_player_data.points = saturating_add(new_player_data.points, other.points);
+        } else {
+          new_others.values[i].hp -= player_data.atk;
+        }
+      }
+    }
+  }
+  if (hit_player) {
+    new_coords = old_coords;
+  }
+
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_ITEMS; i++) {
+    ItemData item = new_items.values[i];
+    if ((new_coords == item.loc) && (!item.is_consumed)) {
+      new_items.values[i].is_consumed = true;
+      new_items.values[i].respawn_ticks = ITEM_RESPAWN_TICKS;
+      new_player_data.atk = saturating_add(new_player_data.atk, item.atk);
+      new_player_data.hp = saturating_add(new_player_data.hp, item.hp);
+      new_player_data.points = saturating_add(new_player_data.points, item.points);
+    }
+  }
+
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_MONSTERS; i++) {
+    MonsterData monster = new_monsters.values[i];
+    if ((new_coords == monster.loc) && (monster.hp != 0)) {
+        if (player_data.hp <= monster.atk) {
+            new_player_data.hp = 0;
+        } else {
+            new_player_data.hp -= monster.atk;
+        }
+
+        if (player_data.atk >= monster.hp) {
+            new_monsters.values[i].hp = 0;
+            new_player_data.atk = saturating_add(new_player_data.atk, monster.atk);
+            new_player_data.points = saturating_add(new_player_data.points, monster.points);
+        } else {
+            new_monsters.values[i].hp -= player_data.atk;
+        }
+
+        new_coords = old_coords;
+    }
+  }
+
+  new_player_data.loc = new_coords;
+
+  return ApplyMovePvpOut{
+    .player_data = new_player_data,
+    .others = new_others,
+    .items = new_items,
+    .monsters = new_monsters,
+  };
+}
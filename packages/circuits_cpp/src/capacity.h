@@ -0,0 +1,13 @@
+// Generated by `cargo run --bin capacity_header` from `circuits/src/capacity.rs`,
+// do not edit by hand.
+
+#ifndef CAPACITY_H
+#define CAPACITY_H
+
+#define NUM_PLAYERS 4
+#define NUM_ITEMS 12
+#define NUM_MONSTERS 23
+#define NUM_OBSTACLES 193
+#define NUM_SPAWN_POINTS 8
+
+#endif
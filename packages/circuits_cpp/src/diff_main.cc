@@ -0,0 +1,319 @@
+// Driver for the differential tests against the Rust mock zone (see
+// `packages/server/tests`).  It reads one case per line from stdin as
+// whitespace separated integers and writes the circuit output the same way.
+//
+// Usage:
+//   diff_main obstacles       prints the obstacles compiled into the circuits
+//   diff_main apply_move      runs apply_move on each case
+//   diff_main apply_move_pvp  runs apply_move_pvp on each case
+//   diff_main respawn         runs respawn on each case
+//   diff_main maintain_monsters
+//                             runs maintain_monsters on each case
+//   diff_main maintain_items  runs maintain_items on each case
+//
+// apply_move case:
+//   player(x y hp atk points) direction
+//   players_coords[NUM_PLAYERS](x y)
+//   monsters[NUM_MONSTERS](x y hp atk points)
+//   items[NUM_ITEMS](x y hp atk is_consumed points respawn_ticks)
+// apply_move output:
+//   player(x y hp atk points)
+//   monsters[NUM_MONSTERS](x y hp atk points)
+//   items[NUM_ITEMS](x y hp atk is_consumed points respawn_ticks)
+//
+// apply_move_pvp case:
+//   player(x y hp atk points) direction
+//   others[NUM_PLAYERS - 1](x y hp atk points)
+//   monsters[NUM_MONSTERS](x y hp atk points)
+//   items[NUM_ITEMS](x y hp atk is_consumed points respawn_ticks)
+// apply_move_pvp output:
+//   player(x y hp atk points)
+//   others[NUM_PLAYERS - 1](x y hp atk points)
+//   monsters[NUM_MONSTERS](x y hp atk points)
+//   items[NUM_ITEMS](x y hp atk is_consumed points respawn_ticks)
+//
+// respawn case:
+//   player(x y hp atk points) random_state points_shift
+//   players_coords[NUM_PLAYERS](x y)
+// respawn output:
+//   player(x y hp atk points)
+//
+// maintain_monsters case:
+//   monsters[NUM_MONSTERS](x y hp atk points) dead_ticks[NUM_MONSTERS]
+//   players_coords[NUM_PLAYERS](x y)
+// maintain_monsters output:
+//   monsters[NUM_MONSTERS](x y hp atk points) dead_ticks[NUM_MONSTERS]
+//
+// maintain_items case:
+//   items[NUM_ITEMS](...) players_coords[NUM_PLAYERS](x y)
+// maintain_items output:
+//   items[NUM_ITEMS](...)
+
+#include <cstdio>
+#include <cstring>
+#include <iostream>
+
+#include "frogzone.h"
+#include "map.h"
+
+// Shared by every circuit, see compile-frogzone.sh.
+#include "get_cell_no_check.cc"
+
+// Keep the helpers of each circuit apart.
+namespace apply_move_cc {
+#include "apply_move.cc"
+}
+namespace apply_move_pvp_cc {
+#include "apply_move_pvp.cc"
+}
+namespace respawn_cc {
+#include "respawn.cc"
+}
+namespace maintain_monsters_cc {
+#include "maintain_monsters.cc"
+}
+namespace maintain_items_cc {
+#include "maintain_items.cc"
+}
+
+static unsigned char read_u8() {
+  int value;
+  std::cin >> value;
+  return (unsigned char)value;
+}
+
+static void write_u8(unsigned char value) { std::cout << (int)value << " "; }
+
+static Coord read_coord() {
+  Coord coord;
+  coord.x = read_u8();
+  coord.y = read_u8();
+  return coord;
+}
+
+static void write_coord(Coord coord) {
+  write_u8(coord.x);
+  write_u8(coord.y);
+}
+
+static PlayerData read_player() {
+  PlayerData player;
+  player.loc = read_coord();
+  player.hp = read_u8();
+  player.atk = read_u8();
+  player.points = read_u8();
+  return player;
+}
+
+static void write_player(PlayerData player) {
+  write_coord(player.loc);
+  write_u8(player.hp);
+  write_u8(player.atk);
+  write_u8(player.points);
+}
+
+static MonsterData read_monster() {
+  MonsterData monster;
+  monster.loc = read_coord();
+  monster.hp = read_u8();
+  monster.atk = read_u8();
+  monster.points = read_u8();
+  return monster;
+}
+
+static void write_monster(MonsterData monster) {
+  write_coord(monster.loc);
+  write_u8(monster.hp);
+  write_u8(monster.atk);
+  write_u8(monster.points);
+}
+
+static ItemData read_item() {
+  ItemData item;
+  item.loc = read_coord();
+  item.hp = read_u8();
+  item.atk = read_u8();
+  item.is_consumed = read_u8() != 0;
+  item.points = read_u8();
+  item.respawn_ticks = read_u8();
+  return item;
+}
+
+static void write_item(ItemData item) {
+  write_coord(item.loc);
+  write_u8(item.hp);
+  write_u8(item.atk);
+  write_u8(item.is_consumed);
+  write_u8(item.points);
+  write_u8(item.respawn_ticks);
+}
+
+static void run_apply_move() {
+  while (true) {
+    PlayerData player = read_player();
+    Direction direction = (Direction)read_u8();
+    if (!std::cin) {
+      return;
+    }
+    Obstacles4 players_coords;
+    for (int i = 0; i < NUM_PLAYERS; i++) {
+      players_coords.values[i] = read_coord();
+    }
+    Monsters monsters;
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      monsters.values[i] = read_monster();
+    }
+    Items items;
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      items.values[i] = read_item();
+    }
+
+    ApplyMoveOut out =
+        apply_move_cc::apply_move(player, direction, players_coords, monsters, items);
+
+    write_player(out.player_data);
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      write_monster(out.monsters.values[i]);
+    }
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      write_item(out.items.values[i]);
+    }
+    std::cout << std::endl;
+  }
+}
+
+static void run_apply_move_pvp() {
+  while (true) {
+    PlayerData player = read_player();
+    Direction direction = (Direction)read_u8();
+    if (!std::cin) {
+      return;
+    }
+    OtherPlayers others;
+    for (int i = 0; i < NUM_PLAYERS - 1; i++) {
+      others.values[i] = read_player();
+    }
+    Monsters monsters;
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      monsters.values[i] = read_monster();
+    }
+    Items items;
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      items.values[i] = read_item();
+    }
+
+    ApplyMovePvpOut out = apply_move_pvp_cc::apply_move_pvp(
+        player, direction, others, monsters, items);
+
+    write_player(out.player_data);
+    for (int i = 0; i < NUM_PLAYERS - 1; i++) {
+      write_player(out.others.values[i]);
+    }
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      write_monster(out.monsters.values[i]);
+    }
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      write_item(out.items.values[i]);
+    }
+    std::cout << std::endl;
+  }
+}
+
+static void run_respawn() {
+  while (true) {
+    PlayerData player = read_player();
+    unsigned char random_state = read_u8();
+    unsigned char points_shift = read_u8();
+    if (!std::cin) {
+      return;
+    }
+    Obstacles4 players_coords;
+    for (int i = 0; i < NUM_PLAYERS; i++) {
+      players_coords.values[i] = read_coord();
+    }
+
+    write_player(
+        respawn_cc::respawn(player, random_state, points_shift, players_coords));
+    std::cout << std::endl;
+  }
+}
+
+static void run_maintain_monsters() {
+  while (true) {
+    Monsters monsters;
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      monsters.values[i] = read_monster();
+    }
+    MonsterTicks dead_ticks;
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      dead_ticks.values[i] = read_u8();
+    }
+    if (!std::cin) {
+      return;
+    }
+    Obstacles4 players_coords;
+    for (int i = 0; i < NUM_PLAYERS; i++) {
+      players_coords.values[i] = read_coord();
+    }
+
+    MaintainMonstersOut out =
+        maintain_monsters_cc::maintain_monsters(monsters, dead_ticks, players_coords);
+
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      write_monster(out.monsters.values[i]);
+    }
+    for (int i = 0; i < NUM_MONSTERS; i++) {
+      write_u8(out.dead_ticks.values[i]);
+    }
+    std::cout << std::endl;
+  }
+}
+
+static void run_maintain_items() {
+  while (true) {
+    Items items;
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      items.values[i] = read_item();
+    }
+    if (!std::cin) {
+      return;
+    }
+    Obstacles4 players_coords;
+    for (int i = 0; i < NUM_PLAYERS; i++) {
+      players_coords.values[i] = read_coord();
+    }
+
+    Items out = maintain_items_cc::maintain_items(items, players_coords);
+
+    for (int i = 0; i < NUM_ITEMS; i++) {
+      write_item(out.values[i]);
+    }
+    std::cout << std::endl;
+  }
+}
+
+int main(int argc, char **argv) {
+  if (argc == 2 && strcmp(argv[1], "obstacles") == 0) {
+    for (int i = 0; i < obstacles_len; i++) {
+      write_coord(obstacles[i]);
+    }
+    std::cout << std::endl;
+  } else if (argc == 2 && strcmp(argv[1], "apply_move") == 0) {
+    run_apply_move();
+  } else if (argc == 2 && strcmp(argv[1], "apply_move_pvp") == 0) {
+    run_apply_move_pvp();
+  } else if (argc == 2 && strcmp(argv[1], "respawn") == 0) {
+    run_respawn();
+  } else if (argc == 2 && strcmp(argv[1], "maintain_monsters") == 0) {
+    run_maintain_monsters();
+  } else if (argc == 2 && strcmp(argv[1], "maintain_items") == 0) {
+    run_maintain_items();
+  } else {
+    fprintf(stderr,
+            "usage: %s obstacles|apply_move|apply_move_pvp|respawn|"
+            "maintain_monsters|maintain_items\n",
+            argv[0]);
+    return 1;
+  }
+  return 0;
+}
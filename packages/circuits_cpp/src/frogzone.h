@@ -1,13 +1,11 @@
 #ifndef FROGZONE_H
 #define FROGZONE_H
 
-#define NUM_OBSTACLES 193
-#define NUM_PLAYERS 4
-#define NUM_ITEMS 12
-#define NUM_MONSTERS 23
+#include "capacity.h"
 
-#define HEIGHT 32
-#define WIDTH 32
+#define RESPAWN_HP 5
+#define MONSTER_RESPAWN_TICKS 8
+#define ITEM_RESPAWN_TICKS 12
 
 struct Coord {
     unsigned char x;
@@ -18,6 +16,12 @@ struct Coord {
     }
 };
 
+// Adds two stats, clamping at 255 instead of wrapping around.
+inline unsigned char saturating_add(unsigned char a, unsigned char b) {
+    unsigned char sum = a + b;
+    return sum < a ? 255 : sum;
+}
+
 struct Coords5 {
     Coord values[5];
 };
@@ -63,6 +67,8 @@ struct ItemData {
     unsigned char atk;
     bool is_consumed;
     unsigned char points;
+    // Ticks left before a consumed item respawns
+    unsigned char respawn_ticks;
 };
 
 struct ItemWithId {
@@ -98,12 +104,34 @@ struct MonstersWithId {
     MonsterWithId values[NUM_MONSTERS];
 };
 
+// Ticks each monster has been dead for
+struct MonsterTicks {
+    unsigned char values[NUM_MONSTERS];
+};
+
+struct MaintainMonstersOut {
+    Monsters monsters;
+    MonsterTicks dead_ticks;
+};
+
 struct ApplyMoveOut {
     PlayerData player_data;
     Items items;
     Monsters monsters;
 };
 
+// The 3 frogs other than the one moving
+struct OtherPlayers {
+    PlayerData values[NUM_PLAYERS - 1];
+};
+
+struct ApplyMovePvpOut {
+    PlayerData player_data;
+    OtherPlayers others;
+    Items items;
+    Monsters monsters;
+};
+
 struct PlayersWithId {
     PlayerWithId values[NUM_PLAYERS];
 };
@@ -136,6 +164,12 @@ struct CellDatas5 {
     CellData values[5];
 };
 
+Coord apply_move_raw(
+                     Coord old_coords,
+                     Direction direction);
+Coord apply_move_check_obstacles(
+                                 Coord old_coords,
+                                 Direction direction);
 CellData get_cell_no_check(
                            Coord coord,
                            MonstersWithId monsters,
@@ -175,6 +209,28 @@ ApplyMoveOut apply_move(
                         Monsters monsters,
                         Items items);
 
+ApplyMovePvpOut apply_move_pvp(
+                               PlayerData player_data,
+                               Direction direction,
+                               OtherPlayers others,
+                               Monsters monsters,
+                               Items items);
+
+PlayerData respawn(
+                   PlayerData player_data,
+                   unsigned char random_state,
+                   unsigned char points_shift,
+                   Obstacles4 players_coords);
+
+MaintainMonstersOut maintain_monsters(
+                                      Monsters monsters,
+                                      MonsterTicks dead_ticks,
+                                      Obstacles4 players_coords);
+
+Items maintain_items(
+                     Items items,
+                     Obstacles4 players_coords);
+
 CellData get_cell(
                   Coord player_coord,
                   Coord query_coord,
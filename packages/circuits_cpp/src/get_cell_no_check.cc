@@ -1,4 +1,50 @@
 #include "frogzone.h"
+#include "map.h"
+
+Coord apply_move_raw(
+                     Coord old_coords,
+                     Direction direction) {
+  Coord new_coords = old_coords;
+  switch (direction) {
+    case Up:
+      if (new_coords.y > 0) {
+        new_coords.y -= 1;
+      }
+      break;
+    case Down:
+      if (new_coords.y < HEIGHT-1) {
+        new_coords.y += 1;
+      }
+      break;
+    case Left:
+      if (new_coords.x > 0) {
+        new_coords.x -= 1;
+      }
+      break;
+    case Right:
+      if (new_coords.x < WIDTH-1) {
+        new_coords.x += 1;
+      }
+      break;
+  }
+  return new_coords;
+}
+
+// Moves, unless the map has an obstacle in the way.
+Coord apply_move_check_obstacles(
+                                 Coord old_coords,
+                                 Direction direction) {
+  Coord new_coords = apply_move_raw(old_coords, direction);
+
+  #pragma hls_unroll yes
+  for (int i = 0; i < obstacles_len; i++) {
+    Coord obstacle = obstacles[i];
+    if (new_coords == obstacle) {
+      return old_coords;
+    }
+  }
+  return new_coords;
+}
 
 CellData get_cell_no_check(
                            Coord coord,
@@ -9,6 +9,15 @@ void test_apply_move() {
   apply_move(player_data, direction, obstacles, monsters, items);
 }
 
+void test_apply_move_pvp() {
+  PlayerData player_data;
+  Direction direction;
+  OtherPlayers others;
+  Monsters monsters;
+  Items items;
+  apply_move_pvp(player_data, direction, others, monsters, items);
+}
+
 void test_apply_move_monster() {
   Coord old_coords;
   Direction direction;
@@ -27,6 +36,27 @@ void test_apply_move_flying() {
   apply_move_flying(old_coords, direction, player_coords, monster_coords, item_coords);
 }
 
+void test_respawn() {
+  PlayerData player_data;
+  unsigned char random_state;
+  unsigned char points_shift;
+  Obstacles4 players_coords;
+  respawn(player_data, random_state, points_shift, players_coords);
+}
+
+void test_maintain_monsters() {
+  Monsters monsters;
+  MonsterTicks dead_ticks;
+  Obstacles4 players_coords;
+  maintain_monsters(monsters, dead_ticks, players_coords);
+}
+
+void test_maintain_items() {
+  Items items;
+  Obstacles4 players_coords;
+  maintain_items(items, players_coords);
+}
+
 void test_get_cell() {
   Coord player_coord;
   Coord query_coord;
@@ -73,8 +103,12 @@ void test_get_vertical_cells() {
 
 int main() {
   test_apply_move();
+  test_apply_move_pvp();
   test_apply_move_monster();
   test_apply_move_flying();
+  test_respawn();
+  test_maintain_monsters();
+  test_maintain_items();
   test_get_cell();
   test_get_five_cells();
   test_get_cross_cells();
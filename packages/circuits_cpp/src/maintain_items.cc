@@ -0,0 +1,35 @@
+#include "frogzone.h"
+
+// Runs once per monster loop tick.  Counts down the respawn ticks of consumed
+// items, and puts an item back once its count reaches 0, unless a frog stands
+// on it, in which case it waits for the frog to leave.
+#pragma hls_top
+Items maintain_items(
+                     Items items,
+                     Obstacles4 players_coords) {
+  Items new_items = items;
+
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_ITEMS; i++) {
+    ItemData item = items.values[i];
+    if (item.is_consumed) {
+      bool blocked = false;
+      #pragma hls_unroll yes
+      for (int j = 0; j < NUM_PLAYERS; j++) {
+        if (item.loc == players_coords.values[j]) {
+          blocked = true;
+        }
+      }
+      unsigned char ticks = item.respawn_ticks;
+      if (ticks > 0) {
+        ticks -= 1;
+      }
+      new_items.values[i].respawn_ticks = ticks;
+      if (ticks == 0 && !blocked) {
+        new_items.values[i].is_consumed = false;
+      }
+    }
+  }
+
+  return new_items;
+}
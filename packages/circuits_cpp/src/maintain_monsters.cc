@@ -0,0 +1,45 @@
+#include "frogzone.h"
+#include "map.h"
+
+// Runs once per monster loop tick.  A dead monster revives at its initial
+// location with its initial stats after MONSTER_RESPAWN_TICKS ticks, unless a
+// frog stands there, in which case it waits for the next tick.  A wounded
+// monster regenerates 1 hp per tick up to its initial hp.  `dead_ticks` counts
+// the ticks each monster has been dead for.
+#pragma hls_top
+MaintainMonstersOut maintain_monsters(
+                                      Monsters monsters,
+                                      MonsterTicks dead_ticks,
+                                      Obstacles4 players_coords) {
+  Monsters new_monsters = monsters;
+  MonsterTicks new_dead_ticks = dead_ticks;
+
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_MONSTERS; i++) {
+    MonsterData monster = monsters.values[i];
+    MonsterData initial = initial_monsters[i];
+    if (monster.hp == 0) {
+      bool blocked = false;
+      #pragma hls_unroll yes
+      for (int j = 0; j < NUM_PLAYERS; j++) {
+        if (initial.loc == players_coords.values[j]) {
+          blocked = true;
+        }
+      }
+      unsigned char ticks = saturating_add(dead_ticks.values[i], 1);
+      if (ticks >= MONSTER_RESPAWN_TICKS && !blocked) {
+        new_monsters.values[i] = initial;
+        new_dead_ticks.values[i] = 0;
+      } else {
+        new_dead_ticks.values[i] = ticks;
+      }
+    } else if (monster.hp < initial.hp) {
+      new_monsters.values[i].hp = monster.hp + 1;
+    }
+  }
+
+  return MaintainMonstersOut{
+    .monsters = new_monsters,
+    .dead_ticks = new_dead_ticks,
+  };
+}
@@ -28,13212 +28,12608 @@ enum CellType {
 use CellType::*;
 
 
-static LEVEL_0: [((usize, bool, CellType), &[GateInput]); 20] = [
-    ((389, false, INV), &[Arg(1, 0)]),
-    ((391, false, INV), &[Arg(1, 2)]),
-    ((394, false, INV), &[Arg(1, 5)]),
-    ((404, false, INV), &[Arg(1, 8)]),
-    ((406, false, INV), &[Arg(1, 11)]),
-    ((407, false, INV), &[Arg(1, 12)]),
-    ((408, false, INV), &[Arg(1, 13)]),
-    ((410, false, INV), &[Arg(1, 7)]),
-    ((411, false, INV), &[Arg(1, 10)]),
-    ((412, false, INV), &[Arg(1, 15)]),
-    ((650, false, INV), &[Arg(2, 13)]),
-    ((651, false, INV), &[Arg(2, 14)]),
-    ((653, false, INV), &[Arg(2, 18)]),
-    ((655, false, INV), &[Arg(2, 23)]),
-    ((656, false, INV), &[Arg(2, 56)]),
-    ((657, false, INV), &[Arg(2, 57)]),
-    ((658, false, INV), &[Arg(2, 64)]),
-    ((659, false, INV), &[Arg(2, 65)]),
-    ((662, false, INV), &[Arg(2, 69)]),
-    ((663, false, INV), &[Arg(2, 71)]),
+static LEVEL_0: [((usize, bool, CellType), &[GateInput]); 1382] = [
+    ((51, false, XNOR2), &[Arg(1, 0), Arg(2, 8)]),
+    ((52, false, XNOR2), &[Arg(1, 1), Arg(2, 9)]),
+    ((53, false, XNOR2), &[Arg(1, 2), Arg(2, 10)]),
+    ((54, false, XNOR2), &[Arg(1, 3), Arg(2, 11)]),
+    ((55, false, XNOR2), &[Arg(1, 4), Arg(2, 12)]),
+    ((56, false, XNOR2), &[Arg(1, 5), Arg(2, 13)]),
+    ((57, false, XNOR2), &[Arg(1, 6), Arg(2, 14)]),
+    ((58, false, XNOR2), &[Arg(1, 7), Arg(2, 15)]),
+    ((74, false, XNOR2), &[Arg(1, 8), Arg(2, 16)]),
+    ((75, false, XNOR2), &[Arg(1, 9), Arg(2, 17)]),
+    ((76, false, XNOR2), &[Arg(1, 10), Arg(2, 18)]),
+    ((77, false, XNOR2), &[Arg(1, 11), Arg(2, 19)]),
+    ((78, false, XNOR2), &[Arg(1, 12), Arg(2, 20)]),
+    ((79, false, XNOR2), &[Arg(1, 13), Arg(2, 21)]),
+    ((80, false, XNOR2), &[Arg(1, 14), Arg(2, 22)]),
+    ((81, false, XNOR2), &[Arg(1, 15), Arg(2, 23)]),
+    ((98, false, NOR2), &[Arg(2, 24), Arg(2, 25)]),
+    ((99, false, NOR2), &[Arg(2, 26), Arg(2, 27)]),
+    ((100, false, NOR2), &[Arg(2, 28), Arg(2, 29)]),
+    ((101, false, NOR2), &[Arg(2, 30), Arg(2, 31)]),
+    ((106, false, XNOR2), &[Arg(1, 0), Arg(2, 56)]),
+    ((107, false, XNOR2), &[Arg(1, 1), Arg(2, 57)]),
+    ((108, false, XNOR2), &[Arg(1, 2), Arg(2, 58)]),
+    ((109, false, XNOR2), &[Arg(1, 3), Arg(2, 59)]),
+    ((110, false, XNOR2), &[Arg(1, 4), Arg(2, 60)]),
+    ((111, false, XNOR2), &[Arg(1, 5), Arg(2, 61)]),
+    ((112, false, XNOR2), &[Arg(1, 6), Arg(2, 62)]),
+    ((113, false, XNOR2), &[Arg(1, 7), Arg(2, 63)]),
+    ((129, false, XNOR2), &[Arg(1, 8), Arg(2, 64)]),
+    ((130, false, XNOR2), &[Arg(1, 9), Arg(2, 65)]),
+    ((131, false, XNOR2), &[Arg(1, 10), Arg(2, 66)]),
+    ((132, false, XNOR2), &[Arg(1, 11), Arg(2, 67)]),
+    ((133, false, XNOR2), &[Arg(1, 12), Arg(2, 68)]),
+    ((134, false, XNOR2), &[Arg(1, 13), Arg(2, 69)]),
+    ((135, false, XNOR2), &[Arg(1, 14), Arg(2, 70)]),
+    ((136, false, XNOR2), &[Arg(1, 15), Arg(2, 71)]),
+    ((153, false, NOR2), &[Arg(2, 72), Arg(2, 73)]),
+    ((154, false, NOR2), &[Arg(2, 74), Arg(2, 75)]),
+    ((155, false, NOR2), &[Arg(2, 76), Arg(2, 77)]),
+    ((156, false, NOR2), &[Arg(2, 78), Arg(2, 79)]),
+    ((161, false, XNOR2), &[Arg(1, 0), Arg(2, 104)]),
+    ((162, false, XNOR2), &[Arg(1, 1), Arg(2, 105)]),
+    ((163, false, XNOR2), &[Arg(1, 2), Arg(2, 106)]),
+    ((164, false, XNOR2), &[Arg(1, 3), Arg(2, 107)]),
+    ((165, false, XNOR2), &[Arg(1, 4), Arg(2, 108)]),
+    ((166, false, XNOR2), &[Arg(1, 5), Arg(2, 109)]),
+    ((167, false, XNOR2), &[Arg(1, 6), Arg(2, 110)]),
+    ((168, false, XNOR2), &[Arg(1, 7), Arg(2, 111)]),
+    ((184, false, XNOR2), &[Arg(1, 8), Arg(2, 112)]),
+    ((185, false, XNOR2), &[Arg(1, 9), Arg(2, 113)]),
+    ((186, false, XNOR2), &[Arg(1, 10), Arg(2, 114)]),
+    ((187, false, XNOR2), &[Arg(1, 11), Arg(2, 115)]),
+    ((188, false, XNOR2), &[Arg(1, 12), Arg(2, 116)]),
+    ((189, false, XNOR2), &[Arg(1, 13), Arg(2, 117)]),
+    ((190, false, XNOR2), &[Arg(1, 14), Arg(2, 118)]),
+    ((191, false, XNOR2), &[Arg(1, 15), Arg(2, 119)]),
+    ((208, false, NOR2), &[Arg(2, 120), Arg(2, 121)]),
+    ((209, false, NOR2), &[Arg(2, 122), Arg(2, 123)]),
+    ((210, false, NOR2), &[Arg(2, 124), Arg(2, 125)]),
+    ((211, false, NOR2), &[Arg(2, 126), Arg(2, 127)]),
+    ((216, false, XNOR2), &[Arg(1, 0), Arg(2, 152)]),
+    ((217, false, XNOR2), &[Arg(1, 1), Arg(2, 153)]),
+    ((218, false, XNOR2), &[Arg(1, 2), Arg(2, 154)]),
+    ((219, false, XNOR2), &[Arg(1, 3), Arg(2, 155)]),
+    ((220, false, XNOR2), &[Arg(1, 4), Arg(2, 156)]),
+    ((221, false, XNOR2), &[Arg(1, 5), Arg(2, 157)]),
+    ((222, false, XNOR2), &[Arg(1, 6), Arg(2, 158)]),
+    ((223, false, XNOR2), &[Arg(1, 7), Arg(2, 159)]),
+    ((239, false, XNOR2), &[Arg(1, 8), Arg(2, 160)]),
+    ((240, false, XNOR2), &[Arg(1, 9), Arg(2, 161)]),
+    ((241, false, XNOR2), &[Arg(1, 10), Arg(2, 162)]),
+    ((242, false, XNOR2), &[Arg(1, 11), Arg(2, 163)]),
+    ((243, false, XNOR2), &[Arg(1, 12), Arg(2, 164)]),
+    ((244, false, XNOR2), &[Arg(1, 13), Arg(2, 165)]),
+    ((245, false, XNOR2), &[Arg(1, 14), Arg(2, 166)]),
+    ((246, false, XNOR2), &[Arg(1, 15), Arg(2, 167)]),
+    ((263, false, NOR2), &[Arg(2, 168), Arg(2, 169)]),
+    ((264, false, NOR2), &[Arg(2, 170), Arg(2, 171)]),
+    ((265, false, NOR2), &[Arg(2, 172), Arg(2, 173)]),
+    ((266, false, NOR2), &[Arg(2, 174), Arg(2, 175)]),
+    ((271, false, XNOR2), &[Arg(1, 0), Arg(2, 200)]),
+    ((272, false, XNOR2), &[Arg(1, 1), Arg(2, 201)]),
+    ((273, false, XNOR2), &[Arg(1, 2), Arg(2, 202)]),
+    ((274, false, XNOR2), &[Arg(1, 3), Arg(2, 203)]),
+    ((275, false, XNOR2), &[Arg(1, 4), Arg(2, 204)]),
+    ((276, false, XNOR2), &[Arg(1, 5), Arg(2, 205)]),
+    ((277, false, XNOR2), &[Arg(1, 6), Arg(2, 206)]),
+    ((278, false, XNOR2), &[Arg(1, 7), Arg(2, 207)]),
+    ((294, false, XNOR2), &[Arg(1, 8), Arg(2, 208)]),
+    ((295, false, XNOR2), &[Arg(1, 9), Arg(2, 209)]),
+    ((296, false, XNOR2), &[Arg(1, 10), Arg(2, 210)]),
+    ((297, false, XNOR2), &[Arg(1, 11), Arg(2, 211)]),
+    ((298, false, XNOR2), &[Arg(1, 12), Arg(2, 212)]),
+    ((299, false, XNOR2), &[Arg(1, 13), Arg(2, 213)]),
+    ((300, false, XNOR2), &[Arg(1, 14), Arg(2, 214)]),
+    ((301, false, XNOR2), &[Arg(1, 15), Arg(2, 215)]),
+    ((318, false, NOR2), &[Arg(2, 216), Arg(2, 217)]),
+    ((319, false, NOR2), &[Arg(2, 218), Arg(2, 219)]),
+    ((320, false, NOR2), &[Arg(2, 220), Arg(2, 221)]),
+    ((321, false, NOR2), &[Arg(2, 222), Arg(2, 223)]),
+    ((326, false, XNOR2), &[Arg(1, 0), Arg(2, 248)]),
+    ((327, false, XNOR2), &[Arg(1, 1), Arg(2, 249)]),
+    ((328, false, XNOR2), &[Arg(1, 2), Arg(2, 250)]),
+    ((329, false, XNOR2), &[Arg(1, 3), Arg(2, 251)]),
+    ((330, false, XNOR2), &[Arg(1, 4), Arg(2, 252)]),
+    ((331, false, XNOR2), &[Arg(1, 5), Arg(2, 253)]),
+    ((332, false, XNOR2), &[Arg(1, 6), Arg(2, 254)]),
+    ((333, false, XNOR2), &[Arg(1, 7), Arg(2, 255)]),
+    ((349, false, XNOR2), &[Arg(1, 8), Arg(2, 256)]),
+    ((350, false, XNOR2), &[Arg(1, 9), Arg(2, 257)]),
+    ((351, false, XNOR2), &[Arg(1, 10), Arg(2, 258)]),
+    ((352, false, XNOR2), &[Arg(1, 11), Arg(2, 259)]),
+    ((353, false, XNOR2), &[Arg(1, 12), Arg(2, 260)]),
+    ((354, false, XNOR2), &[Arg(1, 13), Arg(2, 261)]),
+    ((355, false, XNOR2), &[Arg(1, 14), Arg(2, 262)]),
+    ((356, false, XNOR2), &[Arg(1, 15), Arg(2, 263)]),
+    ((373, false, NOR2), &[Arg(2, 264), Arg(2, 265)]),
+    ((374, false, NOR2), &[Arg(2, 266), Arg(2, 267)]),
+    ((375, false, NOR2), &[Arg(2, 268), Arg(2, 269)]),
+    ((376, false, NOR2), &[Arg(2, 270), Arg(2, 271)]),
+    ((381, false, XNOR2), &[Arg(1, 0), Arg(2, 296)]),
+    ((382, false, XNOR2), &[Arg(1, 1), Arg(2, 297)]),
+    ((383, false, XNOR2), &[Arg(1, 2), Arg(2, 298)]),
+    ((384, false, XNOR2), &[Arg(1, 3), Arg(2, 299)]),
+    ((385, false, XNOR2), &[Arg(1, 4), Arg(2, 300)]),
+    ((386, false, XNOR2), &[Arg(1, 5), Arg(2, 301)]),
+    ((387, false, XNOR2), &[Arg(1, 6), Arg(2, 302)]),
+    ((388, false, XNOR2), &[Arg(1, 7), Arg(2, 303)]),
+    ((404, false, XNOR2), &[Arg(1, 8), Arg(2, 304)]),
+    ((405, false, XNOR2), &[Arg(1, 9), Arg(2, 305)]),
+    ((406, false, XNOR2), &[Arg(1, 10), Arg(2, 306)]),
+    ((407, false, XNOR2), &[Arg(1, 11), Arg(2, 307)]),
+    ((408, false, XNOR2), &[Arg(1, 12), Arg(2, 308)]),
+    ((409, false, XNOR2), &[Arg(1, 13), Arg(2, 309)]),
+    ((410, false, XNOR2), &[Arg(1, 14), Arg(2, 310)]),
+    ((411, false, XNOR2), &[Arg(1, 15), Arg(2, 311)]),
+    ((428, false, NOR2), &[Arg(2, 312), Arg(2, 313)]),
+    ((429, false, NOR2), &[Arg(2, 314), Arg(2, 315)]),
+    ((430, false, NOR2), &[Arg(2, 316), Arg(2, 317)]),
+    ((431, false, NOR2), &[Arg(2, 318), Arg(2, 319)]),
+    ((436, false, XNOR2), &[Arg(1, 0), Arg(2, 344)]),
+    ((437, false, XNOR2), &[Arg(1, 1), Arg(2, 345)]),
+    ((438, false, XNOR2), &[Arg(1, 2), Arg(2, 346)]),
+    ((439, false, XNOR2), &[Arg(1, 3), Arg(2, 347)]),
+    ((440, false, XNOR2), &[Arg(1, 4), Arg(2, 348)]),
+    ((441, false, XNOR2), &[Arg(1, 5), Arg(2, 349)]),
+    ((442, false, XNOR2), &[Arg(1, 6), Arg(2, 350)]),
+    ((443, false, XNOR2), &[Arg(1, 7), Arg(2, 351)]),
+    ((459, false, XNOR2), &[Arg(1, 8), Arg(2, 352)]),
+    ((460, false, XNOR2), &[Arg(1, 9), Arg(2, 353)]),
+    ((461, false, XNOR2), &[Arg(1, 10), Arg(2, 354)]),
+    ((462, false, XNOR2), &[Arg(1, 11), Arg(2, 355)]),
+    ((463, false, XNOR2), &[Arg(1, 12), Arg(2, 356)]),
+    ((464, false, XNOR2), &[Arg(1, 13), Arg(2, 357)]),
+    ((465, false, XNOR2), &[Arg(1, 14), Arg(2, 358)]),
+    ((466, false, XNOR2), &[Arg(1, 15), Arg(2, 359)]),
+    ((483, false, NOR2), &[Arg(2, 360), Arg(2, 361)]),
+    ((484, false, NOR2), &[Arg(2, 362), Arg(2, 363)]),
+    ((485, false, NOR2), &[Arg(2, 364), Arg(2, 365)]),
+    ((486, false, NOR2), &[Arg(2, 366), Arg(2, 367)]),
+    ((491, false, XNOR2), &[Arg(1, 0), Arg(2, 392)]),
+    ((492, false, XNOR2), &[Arg(1, 1), Arg(2, 393)]),
+    ((493, false, XNOR2), &[Arg(1, 2), Arg(2, 394)]),
+    ((494, false, XNOR2), &[Arg(1, 3), Arg(2, 395)]),
+    ((495, false, XNOR2), &[Arg(1, 4), Arg(2, 396)]),
+    ((496, false, XNOR2), &[Arg(1, 5), Arg(2, 397)]),
+    ((497, false, XNOR2), &[Arg(1, 6), Arg(2, 398)]),
+    ((498, false, XNOR2), &[Arg(1, 7), Arg(2, 399)]),
+    ((514, false, XNOR2), &[Arg(1, 8), Arg(2, 400)]),
+    ((515, false, XNOR2), &[Arg(1, 9), Arg(2, 401)]),
+    ((516, false, XNOR2), &[Arg(1, 10), Arg(2, 402)]),
+    ((517, false, XNOR2), &[Arg(1, 11), Arg(2, 403)]),
+    ((518, false, XNOR2), &[Arg(1, 12), Arg(2, 404)]),
+    ((519, false, XNOR2), &[Arg(1, 13), Arg(2, 405)]),
+    ((520, false, XNOR2), &[Arg(1, 14), Arg(2, 406)]),
+    ((521, false, XNOR2), &[Arg(1, 15), Arg(2, 407)]),
+    ((538, false, NOR2), &[Arg(2, 408), Arg(2, 409)]),
+    ((539, false, NOR2), &[Arg(2, 410), Arg(2, 411)]),
+    ((540, false, NOR2), &[Arg(2, 412), Arg(2, 413)]),
+    ((541, false, NOR2), &[Arg(2, 414), Arg(2, 415)]),
+    ((546, false, XNOR2), &[Arg(1, 0), Arg(2, 440)]),
+    ((547, false, XNOR2), &[Arg(1, 1), Arg(2, 441)]),
+    ((548, false, XNOR2), &[Arg(1, 2), Arg(2, 442)]),
+    ((549, false, XNOR2), &[Arg(1, 3), Arg(2, 443)]),
+    ((550, false, XNOR2), &[Arg(1, 4), Arg(2, 444)]),
+    ((551, false, XNOR2), &[Arg(1, 5), Arg(2, 445)]),
+    ((552, false, XNOR2), &[Arg(1, 6), Arg(2, 446)]),
+    ((553, false, XNOR2), &[Arg(1, 7), Arg(2, 447)]),
+    ((569, false, XNOR2), &[Arg(1, 8), Arg(2, 448)]),
+    ((570, false, XNOR2), &[Arg(1, 9), Arg(2, 449)]),
+    ((571, false, XNOR2), &[Arg(1, 10), Arg(2, 450)]),
+    ((572, false, XNOR2), &[Arg(1, 11), Arg(2, 451)]),
+    ((573, false, XNOR2), &[Arg(1, 12), Arg(2, 452)]),
+    ((574, false, XNOR2), &[Arg(1, 13), Arg(2, 453)]),
+    ((575, false, XNOR2), &[Arg(1, 14), Arg(2, 454)]),
+    ((576, false, XNOR2), &[Arg(1, 15), Arg(2, 455)]),
+    ((593, false, NOR2), &[Arg(2, 456), Arg(2, 457)]),
+    ((594, false, NOR2), &[Arg(2, 458), Arg(2, 459)]),
+    ((595, false, NOR2), &[Arg(2, 460), Arg(2, 461)]),
+    ((596, false, NOR2), &[Arg(2, 462), Arg(2, 463)]),
+    ((601, false, XNOR2), &[Arg(1, 0), Arg(2, 488)]),
+    ((602, false, XNOR2), &[Arg(1, 1), Arg(2, 489)]),
+    ((603, false, XNOR2), &[Arg(1, 2), Arg(2, 490)]),
+    ((604, false, XNOR2), &[Arg(1, 3), Arg(2, 491)]),
+    ((605, false, XNOR2), &[Arg(1, 4), Arg(2, 492)]),
+    ((606, false, XNOR2), &[Arg(1, 5), Arg(2, 493)]),
+    ((607, false, XNOR2), &[Arg(1, 6), Arg(2, 494)]),
+    ((608, false, XNOR2), &[Arg(1, 7), Arg(2, 495)]),
+    ((624, false, XNOR2), &[Arg(1, 8), Arg(2, 496)]),
+    ((625, false, XNOR2), &[Arg(1, 9), Arg(2, 497)]),
+    ((626, false, XNOR2), &[Arg(1, 10), Arg(2, 498)]),
+    ((627, false, XNOR2), &[Arg(1, 11), Arg(2, 499)]),
+    ((628, false, XNOR2), &[Arg(1, 12), Arg(2, 500)]),
+    ((629, false, XNOR2), &[Arg(1, 13), Arg(2, 501)]),
+    ((630, false, XNOR2), &[Arg(1, 14), Arg(2, 502)]),
+    ((631, false, XNOR2), &[Arg(1, 15), Arg(2, 503)]),
+    ((648, false, NOR2), &[Arg(2, 504), Arg(2, 505)]),
+    ((649, false, NOR2), &[Arg(2, 506), Arg(2, 507)]),
+    ((650, false, NOR2), &[Arg(2, 508), Arg(2, 509)]),
+    ((651, false, NOR2), &[Arg(2, 510), Arg(2, 511)]),
+    ((656, false, XNOR2), &[Arg(1, 0), Arg(2, 536)]),
+    ((657, false, XNOR2), &[Arg(1, 1), Arg(2, 537)]),
+    ((658, false, XNOR2), &[Arg(1, 2), Arg(2, 538)]),
+    ((659, false, XNOR2), &[Arg(1, 3), Arg(2, 539)]),
+    ((660, false, XNOR2), &[Arg(1, 4), Arg(2, 540)]),
+    ((661, false, XNOR2), &[Arg(1, 5), Arg(2, 541)]),
+    ((662, false, XNOR2), &[Arg(1, 6), Arg(2, 542)]),
+    ((663, false, XNOR2), &[Arg(1, 7), Arg(2, 543)]),
+    ((679, false, XNOR2), &[Arg(1, 8), Arg(2, 544)]),
+    ((680, false, XNOR2), &[Arg(1, 9), Arg(2, 545)]),
+    ((681, false, XNOR2), &[Arg(1, 10), Arg(2, 546)]),
+    ((682, false, XNOR2), &[Arg(1, 11), Arg(2, 547)]),
+    ((683, false, XNOR2), &[Arg(1, 12), Arg(2, 548)]),
+    ((684, false, XNOR2), &[Arg(1, 13), Arg(2, 549)]),
+    ((685, false, XNOR2), &[Arg(1, 14), Arg(2, 550)]),
+    ((686, false, XNOR2), &[Arg(1, 15), Arg(2, 551)]),
+    ((703, false, NOR2), &[Arg(2, 552), Arg(2, 553)]),
+    ((704, false, NOR2), &[Arg(2, 554), Arg(2, 555)]),
+    ((705, false, NOR2), &[Arg(2, 556), Arg(2, 557)]),
+    ((706, false, NOR2), &[Arg(2, 558), Arg(2, 559)]),
+    ((711, false, XNOR2), &[Arg(1, 0), Arg(2, 584)]),
+    ((712, false, XNOR2), &[Arg(1, 1), Arg(2, 585)]),
+    ((713, false, XNOR2), &[Arg(1, 2), Arg(2, 586)]),
+    ((714, false, XNOR2), &[Arg(1, 3), Arg(2, 587)]),
+    ((715, false, XNOR2), &[Arg(1, 4), Arg(2, 588)]),
+    ((716, false, XNOR2), &[Arg(1, 5), Arg(2, 589)]),
+    ((717, false, XNOR2), &[Arg(1, 6), Arg(2, 590)]),
+    ((718, false, XNOR2), &[Arg(1, 7), Arg(2, 591)]),
+    ((734, false, XNOR2), &[Arg(1, 8), Arg(2, 592)]),
+    ((735, false, XNOR2), &[Arg(1, 9), Arg(2, 593)]),
+    ((736, false, XNOR2), &[Arg(1, 10), Arg(2, 594)]),
+    ((737, false, XNOR2), &[Arg(1, 11), Arg(2, 595)]),
+    ((738, false, XNOR2), &[Arg(1, 12), Arg(2, 596)]),
+    ((739, false, XNOR2), &[Arg(1, 13), Arg(2, 597)]),
+    ((740, false, XNOR2), &[Arg(1, 14), Arg(2, 598)]),
+    ((741, false, XNOR2), &[Arg(1, 15), Arg(2, 599)]),
+    ((758, false, NOR2), &[Arg(2, 600), Arg(2, 601)]),
+    ((759, false, NOR2), &[Arg(2, 602), Arg(2, 603)]),
+    ((760, false, NOR2), &[Arg(2, 604), Arg(2, 605)]),
+    ((761, false, NOR2), &[Arg(2, 606), Arg(2, 607)]),
+    ((766, false, XNOR2), &[Arg(1, 0), Arg(2, 632)]),
+    ((767, false, XNOR2), &[Arg(1, 1), Arg(2, 633)]),
+    ((768, false, XNOR2), &[Arg(1, 2), Arg(2, 634)]),
+    ((769, false, XNOR2), &[Arg(1, 3), Arg(2, 635)]),
+    ((770, false, XNOR2), &[Arg(1, 4), Arg(2, 636)]),
+    ((771, false, XNOR2), &[Arg(1, 5), Arg(2, 637)]),
+    ((772, false, XNOR2), &[Arg(1, 6), Arg(2, 638)]),
+    ((773, false, XNOR2), &[Arg(1, 7), Arg(2, 639)]),
+    ((789, false, XNOR2), &[Arg(1, 8), Arg(2, 640)]),
+    ((790, false, XNOR2), &[Arg(1, 9), Arg(2, 641)]),
+    ((791, false, XNOR2), &[Arg(1, 10), Arg(2, 642)]),
+    ((792, false, XNOR2), &[Arg(1, 11), Arg(2, 643)]),
+    ((793, false, XNOR2), &[Arg(1, 12), Arg(2, 644)]),
+    ((794, false, XNOR2), &[Arg(1, 13), Arg(2, 645)]),
+    ((795, false, XNOR2), &[Arg(1, 14), Arg(2, 646)]),
+    ((796, false, XNOR2), &[Arg(1, 15), Arg(2, 647)]),
+    ((813, false, NOR2), &[Arg(2, 648), Arg(2, 649)]),
+    ((814, false, NOR2), &[Arg(2, 650), Arg(2, 651)]),
+    ((815, false, NOR2), &[Arg(2, 652), Arg(2, 653)]),
+    ((816, false, NOR2), &[Arg(2, 654), Arg(2, 655)]),
+    ((821, false, XNOR2), &[Arg(1, 0), Arg(2, 680)]),
+    ((822, false, XNOR2), &[Arg(1, 1), Arg(2, 681)]),
+    ((823, false, XNOR2), &[Arg(1, 2), Arg(2, 682)]),
+    ((824, false, XNOR2), &[Arg(1, 3), Arg(2, 683)]),
+    ((825, false, XNOR2), &[Arg(1, 4), Arg(2, 684)]),
+    ((826, false, XNOR2), &[Arg(1, 5), Arg(2, 685)]),
+    ((827, false, XNOR2), &[Arg(1, 6), Arg(2, 686)]),
+    ((828, false, XNOR2), &[Arg(1, 7), Arg(2, 687)]),
+    ((844, false, XNOR2), &[Arg(1, 8), Arg(2, 688)]),
+    ((845, false, XNOR2), &[Arg(1, 9), Arg(2, 689)]),
+    ((846, false, XNOR2), &[Arg(1, 10), Arg(2, 690)]),
+    ((847, false, XNOR2), &[Arg(1, 11), Arg(2, 691)]),
+    ((848, false, XNOR2), &[Arg(1, 12), Arg(2, 692)]),
+    ((849, false, XNOR2), &[Arg(1, 13), Arg(2, 693)]),
+    ((850, false, XNOR2), &[Arg(1, 14), Arg(2, 694)]),
+    ((851, false, XNOR2), &[Arg(1, 15), Arg(2, 695)]),
+    ((868, false, NOR2), &[Arg(2, 696), Arg(2, 697)]),
+    ((869, false, NOR2), &[Arg(2, 698), Arg(2, 699)]),
+    ((870, false, NOR2), &[Arg(2, 700), Arg(2, 701)]),
+    ((871, false, NOR2), &[Arg(2, 702), Arg(2, 703)]),
+    ((876, false, XNOR2), &[Arg(1, 0), Arg(2, 728)]),
+    ((877, false, XNOR2), &[Arg(1, 1), Arg(2, 729)]),
+    ((878, false, XNOR2), &[Arg(1, 2), Arg(2, 730)]),
+    ((879, false, XNOR2), &[Arg(1, 3), Arg(2, 731)]),
+    ((880, false, XNOR2), &[Arg(1, 4), Arg(2, 732)]),
+    ((881, false, XNOR2), &[Arg(1, 5), Arg(2, 733)]),
+    ((882, false, XNOR2), &[Arg(1, 6), Arg(2, 734)]),
+    ((883, false, XNOR2), &[Arg(1, 7), Arg(2, 735)]),
+    ((899, false, XNOR2), &[Arg(1, 8), Arg(2, 736)]),
+    ((900, false, XNOR2), &[Arg(1, 9), Arg(2, 737)]),
+    ((901, false, XNOR2), &[Arg(1, 10), Arg(2, 738)]),
+    ((902, false, XNOR2), &[Arg(1, 11), Arg(2, 739)]),
+    ((903, false, XNOR2), &[Arg(1, 12), Arg(2, 740)]),
+    ((904, false, XNOR2), &[Arg(1, 13), Arg(2, 741)]),
+    ((905, false, XNOR2), &[Arg(1, 14), Arg(2, 742)]),
+    ((906, false, XNOR2), &[Arg(1, 15), Arg(2, 743)]),
+    ((923, false, NOR2), &[Arg(2, 744), Arg(2, 745)]),
+    ((924, false, NOR2), &[Arg(2, 746), Arg(2, 747)]),
+    ((925, false, NOR2), &[Arg(2, 748), Arg(2, 749)]),
+    ((926, false, NOR2), &[Arg(2, 750), Arg(2, 751)]),
+    ((931, false, XNOR2), &[Arg(1, 0), Arg(2, 776)]),
+    ((932, false, XNOR2), &[Arg(1, 1), Arg(2, 777)]),
+    ((933, false, XNOR2), &[Arg(1, 2), Arg(2, 778)]),
+    ((934, false, XNOR2), &[Arg(1, 3), Arg(2, 779)]),
+    ((935, false, XNOR2), &[Arg(1, 4), Arg(2, 780)]),
+    ((936, false, XNOR2), &[Arg(1, 5), Arg(2, 781)]),
+    ((937, false, XNOR2), &[Arg(1, 6), Arg(2, 782)]),
+    ((938, false, XNOR2), &[Arg(1, 7), Arg(2, 783)]),
+    ((954, false, XNOR2), &[Arg(1, 8), Arg(2, 784)]),
+    ((955, false, XNOR2), &[Arg(1, 9), Arg(2, 785)]),
+    ((956, false, XNOR2), &[Arg(1, 10), Arg(2, 786)]),
+    ((957, false, XNOR2), &[Arg(1, 11), Arg(2, 787)]),
+    ((958, false, XNOR2), &[Arg(1, 12), Arg(2, 788)]),
+    ((959, false, XNOR2), &[Arg(1, 13), Arg(2, 789)]),
+    ((960, false, XNOR2), &[Arg(1, 14), Arg(2, 790)]),
+    ((961, false, XNOR2), &[Arg(1, 15), Arg(2, 791)]),
+    ((978, false, NOR2), &[Arg(2, 792), Arg(2, 793)]),
+    ((979, false, NOR2), &[Arg(2, 794), Arg(2, 795)]),
+    ((980, false, NOR2), &[Arg(2, 796), Arg(2, 797)]),
+    ((981, false, NOR2), &[Arg(2, 798), Arg(2, 799)]),
+    ((986, false, XNOR2), &[Arg(1, 0), Arg(2, 824)]),
+    ((987, false, XNOR2), &[Arg(1, 1), Arg(2, 825)]),
+    ((988, false, XNOR2), &[Arg(1, 2), Arg(2, 826)]),
+    ((989, false, XNOR2), &[Arg(1, 3), Arg(2, 827)]),
+    ((990, false, XNOR2), &[Arg(1, 4), Arg(2, 828)]),
+    ((991, false, XNOR2), &[Arg(1, 5), Arg(2, 829)]),
+    ((992, false, XNOR2), &[Arg(1, 6), Arg(2, 830)]),
+    ((993, false, XNOR2), &[Arg(1, 7), Arg(2, 831)]),
+    ((1009, false, XNOR2), &[Arg(1, 8), Arg(2, 832)]),
+    ((1010, false, XNOR2), &[Arg(1, 9), Arg(2, 833)]),
+    ((1011, false, XNOR2), &[Arg(1, 10), Arg(2, 834)]),
+    ((1012, false, XNOR2), &[Arg(1, 11), Arg(2, 835)]),
+    ((1013, false, XNOR2), &[Arg(1, 12), Arg(2, 836)]),
+    ((1014, false, XNOR2), &[Arg(1, 13), Arg(2, 837)]),
+    ((1015, false, XNOR2), &[Arg(1, 14), Arg(2, 838)]),
+    ((1016, false, XNOR2), &[Arg(1, 15), Arg(2, 839)]),
+    ((1033, false, NOR2), &[Arg(2, 840), Arg(2, 841)]),
+    ((1034, false, NOR2), &[Arg(2, 842), Arg(2, 843)]),
+    ((1035, false, NOR2), &[Arg(2, 844), Arg(2, 845)]),
+    ((1036, false, NOR2), &[Arg(2, 846), Arg(2, 847)]),
+    ((1041, false, XNOR2), &[Arg(1, 0), Arg(2, 872)]),
+    ((1042, false, XNOR2), &[Arg(1, 1), Arg(2, 873)]),
+    ((1043, false, XNOR2), &[Arg(1, 2), Arg(2, 874)]),
+    ((1044, false, XNOR2), &[Arg(1, 3), Arg(2, 875)]),
+    ((1045, false, XNOR2), &[Arg(1, 4), Arg(2, 876)]),
+    ((1046, false, XNOR2), &[Arg(1, 5), Arg(2, 877)]),
+    ((1047, false, XNOR2), &[Arg(1, 6), Arg(2, 878)]),
+    ((1048, false, XNOR2), &[Arg(1, 7), Arg(2, 879)]),
+    ((1064, false, XNOR2), &[Arg(1, 8), Arg(2, 880)]),
+    ((1065, false, XNOR2), &[Arg(1, 9), Arg(2, 881)]),
+    ((1066, false, XNOR2), &[Arg(1, 10), Arg(2, 882)]),
+    ((1067, false, XNOR2), &[Arg(1, 11), Arg(2, 883)]),
+    ((1068, false, XNOR2), &[Arg(1, 12), Arg(2, 884)]),
+    ((1069, false, XNOR2), &[Arg(1, 13), Arg(2, 885)]),
+    ((1070, false, XNOR2), &[Arg(1, 14), Arg(2, 886)]),
+    ((1071, false, XNOR2), &[Arg(1, 15), Arg(2, 887)]),
+    ((1088, false, NOR2), &[Arg(2, 888), Arg(2, 889)]),
+    ((1089, false, NOR2), &[Arg(2, 890), Arg(2, 891)]),
+    ((1090, false, NOR2), &[Arg(2, 892), Arg(2, 893)]),
+    ((1091, false, NOR2), &[Arg(2, 894), Arg(2, 895)]),
+    ((1096, false, XNOR2), &[Arg(1, 0), Arg(2, 920)]),
+    ((1097, false, XNOR2), &[Arg(1, 1), Arg(2, 921)]),
+    ((1098, false, XNOR2), &[Arg(1, 2), Arg(2, 922)]),
+    ((1099, false, XNOR2), &[Arg(1, 3), Arg(2, 923)]),
+    ((1100, false, XNOR2), &[Arg(1, 4), Arg(2, 924)]),
+    ((1101, false, XNOR2), &[Arg(1, 5), Arg(2, 925)]),
+    ((1102, false, XNOR2), &[Arg(1, 6), Arg(2, 926)]),
+    ((1103, false, XNOR2), &[Arg(1, 7), Arg(2, 927)]),
+    ((1119, false, XNOR2), &[Arg(1, 8), Arg(2, 928)]),
+    ((1120, false, XNOR2), &[Arg(1, 9), Arg(2, 929)]),
+    ((1121, false, XNOR2), &[Arg(1, 10), Arg(2, 930)]),
+    ((1122, false, XNOR2), &[Arg(1, 11), Arg(2, 931)]),
+    ((1123, false, XNOR2), &[Arg(1, 12), Arg(2, 932)]),
+    ((1124, false, XNOR2), &[Arg(1, 13), Arg(2, 933)]),
+    ((1125, false, XNOR2), &[Arg(1, 14), Arg(2, 934)]),
+    ((1126, false, XNOR2), &[Arg(1, 15), Arg(2, 935)]),
+    ((1143, false, NOR2), &[Arg(2, 936), Arg(2, 937)]),
+    ((1144, false, NOR2), &[Arg(2, 938), Arg(2, 939)]),
+    ((1145, false, NOR2), &[Arg(2, 940), Arg(2, 941)]),
+    ((1146, false, NOR2), &[Arg(2, 942), Arg(2, 943)]),
+    ((1151, false, XNOR2), &[Arg(1, 0), Arg(2, 968)]),
+    ((1152, false, XNOR2), &[Arg(1, 1), Arg(2, 969)]),
+    ((1153, false, XNOR2), &[Arg(1, 2), Arg(2, 970)]),
+    ((1154, false, XNOR2), &[Arg(1, 3), Arg(2, 971)]),
+    ((1155, false, XNOR2), &[Arg(1, 4), Arg(2, 972)]),
+    ((1156, false, XNOR2), &[Arg(1, 5), Arg(2, 973)]),
+    ((1157, false, XNOR2), &[Arg(1, 6), Arg(2, 974)]),
+    ((1158, false, XNOR2), &[Arg(1, 7), Arg(2, 975)]),
+    ((1174, false, XNOR2), &[Arg(1, 8), Arg(2, 976)]),
+    ((1175, false, XNOR2), &[Arg(1, 9), Arg(2, 977)]),
+    ((1176, false, XNOR2), &[Arg(1, 10), Arg(2, 978)]),
+    ((1177, false, XNOR2), &[Arg(1, 11), Arg(2, 979)]),
+    ((1178, false, XNOR2), &[Arg(1, 12), Arg(2, 980)]),
+    ((1179, false, XNOR2), &[Arg(1, 13), Arg(2, 981)]),
+    ((1180, false, XNOR2), &[Arg(1, 14), Arg(2, 982)]),
+    ((1181, false, XNOR2), &[Arg(1, 15), Arg(2, 983)]),
+    ((1198, false, NOR2), &[Arg(2, 984), Arg(2, 985)]),
+    ((1199, false, NOR2), &[Arg(2, 986), Arg(2, 987)]),
+    ((1200, false, NOR2), &[Arg(2, 988), Arg(2, 989)]),
+    ((1201, false, NOR2), &[Arg(2, 990), Arg(2, 991)]),
+    ((1206, false, XNOR2), &[Arg(1, 0), Arg(2, 1016)]),
+    ((1207, false, XNOR2), &[Arg(1, 1), Arg(2, 1017)]),
+    ((1208, false, XNOR2), &[Arg(1, 2), Arg(2, 1018)]),
+    ((1209, false, XNOR2), &[Arg(1, 3), Arg(2, 1019)]),
+    ((1210, false, XNOR2), &[Arg(1, 4), Arg(2, 1020)]),
+    ((1211, false, XNOR2), &[Arg(1, 5), Arg(2, 1021)]),
+    ((1212, false, XNOR2), &[Arg(1, 6), Arg(2, 1022)]),
+    ((1213, false, XNOR2), &[Arg(1, 7), Arg(2, 1023)]),
+    ((1229, false, XNOR2), &[Arg(1, 8), Arg(2, 1024)]),
+    ((1230, false, XNOR2), &[Arg(1, 9), Arg(2, 1025)]),
+    ((1231, false, XNOR2), &[Arg(1, 10), Arg(2, 1026)]),
+    ((1232, false, XNOR2), &[Arg(1, 11), Arg(2, 1027)]),
+    ((1233, false, XNOR2), &[Arg(1, 12), Arg(2, 1028)]),
+    ((1234, false, XNOR2), &[Arg(1, 13), Arg(2, 1029)]),
+    ((1235, false, XNOR2), &[Arg(1, 14), Arg(2, 1030)]),
+    ((1236, false, XNOR2), &[Arg(1, 15), Arg(2, 1031)]),
+    ((1253, false, NOR2), &[Arg(2, 1032), Arg(2, 1033)]),
+    ((1254, false, NOR2), &[Arg(2, 1034), Arg(2, 1035)]),
+    ((1255, false, NOR2), &[Arg(2, 1036), Arg(2, 1037)]),
+    ((1256, false, NOR2), &[Arg(2, 1038), Arg(2, 1039)]),
+    ((1261, false, XNOR2), &[Arg(1, 0), Arg(2, 1064)]),
+    ((1262, false, XNOR2), &[Arg(1, 1), Arg(2, 1065)]),
+    ((1263, false, XNOR2), &[Arg(1, 2), Arg(2, 1066)]),
+    ((1264, false, XNOR2), &[Arg(1, 3), Arg(2, 1067)]),
+    ((1265, false, XNOR2), &[Arg(1, 4), Arg(2, 1068)]),
+    ((1266, false, XNOR2), &[Arg(1, 5), Arg(2, 1069)]),
+    ((1267, false, XNOR2), &[Arg(1, 6), Arg(2, 1070)]),
+    ((1268, false, XNOR2), &[Arg(1, 7), Arg(2, 1071)]),
+    ((1284, false, XNOR2), &[Arg(1, 8), Arg(2, 1072)]),
+    ((1285, false, XNOR2), &[Arg(1, 9), Arg(2, 1073)]),
+    ((1286, false, XNOR2), &[Arg(1, 10), Arg(2, 1074)]),
+    ((1287, false, XNOR2), &[Arg(1, 11), Arg(2, 1075)]),
+    ((1288, false, XNOR2), &[Arg(1, 12), Arg(2, 1076)]),
+    ((1289, false, XNOR2), &[Arg(1, 13), Arg(2, 1077)]),
+    ((1290, false, XNOR2), &[Arg(1, 14), Arg(2, 1078)]),
+    ((1291, false, XNOR2), &[Arg(1, 15), Arg(2, 1079)]),
+    ((1308, false, NOR2), &[Arg(2, 1080), Arg(2, 1081)]),
+    ((1309, false, NOR2), &[Arg(2, 1082), Arg(2, 1083)]),
+    ((1310, false, NOR2), &[Arg(2, 1084), Arg(2, 1085)]),
+    ((1311, false, NOR2), &[Arg(2, 1086), Arg(2, 1087)]),
+    ((1316, false, XNOR2), &[Arg(1, 0), Arg(3, 8)]),
+    ((1317, false, XNOR2), &[Arg(1, 1), Arg(3, 9)]),
+    ((1318, false, XNOR2), &[Arg(1, 2), Arg(3, 10)]),
+    ((1319, false, XNOR2), &[Arg(1, 3), Arg(3, 11)]),
+    ((1320, false, XNOR2), &[Arg(1, 4), Arg(3, 12)]),
+    ((1321, false, XNOR2), &[Arg(1, 5), Arg(3, 13)]),
+    ((1322, false, XNOR2), &[Arg(1, 6), Arg(3, 14)]),
+    ((1323, false, XNOR2), &[Arg(1, 7), Arg(3, 15)]),
+    ((1339, false, XNOR2), &[Arg(1, 8), Arg(3, 16)]),
+    ((1340, false, XNOR2), &[Arg(1, 9), Arg(3, 17)]),
+    ((1341, false, XNOR2), &[Arg(1, 10), Arg(3, 18)]),
+    ((1342, false, XNOR2), &[Arg(1, 11), Arg(3, 19)]),
+    ((1343, false, XNOR2), &[Arg(1, 12), Arg(3, 20)]),
+    ((1344, false, XNOR2), &[Arg(1, 13), Arg(3, 21)]),
+    ((1345, false, XNOR2), &[Arg(1, 14), Arg(3, 22)]),
+    ((1346, false, XNOR2), &[Arg(1, 15), Arg(3, 23)]),
+    ((1363, false, INV), &[Arg(3, 40)]),
+    ((1365, false, XNOR2), &[Arg(1, 0), Arg(3, 65)]),
+    ((1366, false, XNOR2), &[Arg(1, 1), Arg(3, 66)]),
+    ((1367, false, XNOR2), &[Arg(1, 2), Arg(3, 67)]),
+    ((1368, false, XNOR2), &[Arg(1, 3), Arg(3, 68)]),
+    ((1369, false, XNOR2), &[Arg(1, 4), Arg(3, 69)]),
+    ((1370, false, XNOR2), &[Arg(1, 5), Arg(3, 70)]),
+    ((1371, false, XNOR2), &[Arg(1, 6), Arg(3, 71)]),
+    ((1372, false, XNOR2), &[Arg(1, 7), Arg(3, 72)]),
+    ((1388, false, XNOR2), &[Arg(1, 8), Arg(3, 73)]),
+    ((1389, false, XNOR2), &[Arg(1, 9), Arg(3, 74)]),
+    ((1390, false, XNOR2), &[Arg(1, 10), Arg(3, 75)]),
+    ((1391, false, XNOR2), &[Arg(1, 11), Arg(3, 76)]),
+    ((1392, false, XNOR2), &[Arg(1, 12), Arg(3, 77)]),
+    ((1393, false, XNOR2), &[Arg(1, 13), Arg(3, 78)]),
+    ((1394, false, XNOR2), &[Arg(1, 14), Arg(3, 79)]),
+    ((1395, false, XNOR2), &[Arg(1, 15), Arg(3, 80)]),
+    ((1412, false, INV), &[Arg(3, 97)]),
+    ((1414, false, XNOR2), &[Arg(1, 0), Arg(3, 122)]),
+    ((1415, false, XNOR2), &[Arg(1, 1), Arg(3, 123)]),
+    ((1416, false, XNOR2), &[Arg(1, 2), Arg(3, 124)]),
+    ((1417, false, XNOR2), &[Arg(1, 3), Arg(3, 125)]),
+    ((1418, false, XNOR2), &[Arg(1, 4), Arg(3, 126)]),
+    ((1419, false, XNOR2), &[Arg(1, 5), Arg(3, 127)]),
+    ((1420, false, XNOR2), &[Arg(1, 6), Arg(3, 128)]),
+    ((1421, false, XNOR2), &[Arg(1, 7), Arg(3, 129)]),
+    ((1437, false, XNOR2), &[Arg(1, 8), Arg(3, 130)]),
+    ((1438, false, XNOR2), &[Arg(1, 9), Arg(3, 131)]),
+    ((1439, false, XNOR2), &[Arg(1, 10), Arg(3, 132)]),
+    ((1440, false, XNOR2), &[Arg(1, 11), Arg(3, 133)]),
+    ((1441, false, XNOR2), &[Arg(1, 12), Arg(3, 134)]),
+    ((1442, false, XNOR2), &[Arg(1, 13), Arg(3, 135)]),
+    ((1443, false, XNOR2), &[Arg(1, 14), Arg(3, 136)]),
+    ((1444, false, XNOR2), &[Arg(1, 15), Arg(3, 137)]),
+    ((1461, false, INV), &[Arg(3, 154)]),
+    ((1463, false, XNOR2), &[Arg(1, 0), Arg(3, 179)]),
+    ((1464, false, XNOR2), &[Arg(1, 1), Arg(3, 180)]),
+    ((1465, false, XNOR2), &[Arg(1, 2), Arg(3, 181)]),
+    ((1466, false, XNOR2), &[Arg(1, 3), Arg(3, 182)]),
+    ((1467, false, XNOR2), &[Arg(1, 4), Arg(3, 183)]),
+    ((1468, false, XNOR2), &[Arg(1, 5), Arg(3, 184)]),
+    ((1469, false, XNOR2), &[Arg(1, 6), Arg(3, 185)]),
+    ((1470, false, XNOR2), &[Arg(1, 7), Arg(3, 186)]),
+    ((1486, false, XNOR2), &[Arg(1, 8), Arg(3, 187)]),
+    ((1487, false, XNOR2), &[Arg(1, 9), Arg(3, 188)]),
+    ((1488, false, XNOR2), &[Arg(1, 10), Arg(3, 189)]),
+    ((1489, false, XNOR2), &[Arg(1, 11), Arg(3, 190)]),
+    ((1490, false, XNOR2), &[Arg(1, 12), Arg(3, 191)]),
+    ((1491, false, XNOR2), &[Arg(1, 13), Arg(3, 192)]),
+    ((1492, false, XNOR2), &[Arg(1, 14), Arg(3, 193)]),
+    ((1493, false, XNOR2), &[Arg(1, 15), Arg(3, 194)]),
+    ((1510, false, INV), &[Arg(3, 211)]),
+    ((1512, false, XNOR2), &[Arg(1, 0), Arg(3, 236)]),
+    ((1513, false, XNOR2), &[Arg(1, 1), Arg(3, 237)]),
+    ((1514, false, XNOR2), &[Arg(1, 2), Arg(3, 238)]),
+    ((1515, false, XNOR2), &[Arg(1, 3), Arg(3, 239)]),
+    ((1516, false, XNOR2), &[Arg(1, 4), Arg(3, 240)]),
+    ((1517, false, XNOR2), &[Arg(1, 5), Arg(3, 241)]),
+    ((1518, false, XNOR2), &[Arg(1, 6), Arg(3, 242)]),
+    ((1519, false, XNOR2), &[Arg(1, 7), Arg(3, 243)]),
+    ((1535, false, XNOR2), &[Arg(1, 8), Arg(3, 244)]),
+    ((1536, false, XNOR2), &[Arg(1, 9), Arg(3, 245)]),
+    ((1537, false, XNOR2), &[Arg(1, 10), Arg(3, 246)]),
+    ((1538, false, XNOR2), &[Arg(1, 11), Arg(3, 247)]),
+    ((1539, false, XNOR2), &[Arg(1, 12), Arg(3, 248)]),
+    ((1540, false, XNOR2), &[Arg(1, 13), Arg(3, 249)]),
+    ((1541, false, XNOR2), &[Arg(1, 14), Arg(3, 250)]),
+    ((1542, false, XNOR2), &[Arg(1, 15), Arg(3, 251)]),
+    ((1559, false, INV), &[Arg(3, 268)]),
+    ((1561, false, XNOR2), &[Arg(1, 0), Arg(3, 293)]),
+    ((1562, false, XNOR2), &[Arg(1, 1), Arg(3, 294)]),
+    ((1563, false, XNOR2), &[Arg(1, 2), Arg(3, 295)]),
+    ((1564, false, XNOR2), &[Arg(1, 3), Arg(3, 296)]),
+    ((1565, false, XNOR2), &[Arg(1, 4), Arg(3, 297)]),
+    ((1566, false, XNOR2), &[Arg(1, 5), Arg(3, 298)]),
+    ((1567, false, XNOR2), &[Arg(1, 6), Arg(3, 299)]),
+    ((1568, false, XNOR2), &[Arg(1, 7), Arg(3, 300)]),
+    ((1584, false, XNOR2), &[Arg(1, 8), Arg(3, 301)]),
+    ((1585, false, XNOR2), &[Arg(1, 9), Arg(3, 302)]),
+    ((1586, false, XNOR2), &[Arg(1, 10), Arg(3, 303)]),
+    ((1587, false, XNOR2), &[Arg(1, 11), Arg(3, 304)]),
+    ((1588, false, XNOR2), &[Arg(1, 12), Arg(3, 305)]),
+    ((1589, false, XNOR2), &[Arg(1, 13), Arg(3, 306)]),
+    ((1590, false, XNOR2), &[Arg(1, 14), Arg(3, 307)]),
+    ((1591, false, XNOR2), &[Arg(1, 15), Arg(3, 308)]),
+    ((1608, false, INV), &[Arg(3, 325)]),
+    ((1610, false, XNOR2), &[Arg(1, 0), Arg(3, 350)]),
+    ((1611, false, XNOR2), &[Arg(1, 1), Arg(3, 351)]),
+    ((1612, false, XNOR2), &[Arg(1, 2), Arg(3, 352)]),
+    ((1613, false, XNOR2), &[Arg(1, 3), Arg(3, 353)]),
+    ((1614, false, XNOR2), &[Arg(1, 4), Arg(3, 354)]),
+    ((1615, false, XNOR2), &[Arg(1, 5), Arg(3, 355)]),
+    ((1616, false, XNOR2), &[Arg(1, 6), Arg(3, 356)]),
+    ((1617, false, XNOR2), &[Arg(1, 7), Arg(3, 357)]),
+    ((1633, false, XNOR2), &[Arg(1, 8), Arg(3, 358)]),
+    ((1634, false, XNOR2), &[Arg(1, 9), Arg(3, 359)]),
+    ((1635, false, XNOR2), &[Arg(1, 10), Arg(3, 360)]),
+    ((1636, false, XNOR2), &[Arg(1, 11), Arg(3, 361)]),
+    ((1637, false, XNOR2), &[Arg(1, 12), Arg(3, 362)]),
+    ((1638, false, XNOR2), &[Arg(1, 13), Arg(3, 363)]),
+    ((1639, false, XNOR2), &[Arg(1, 14), Arg(3, 364)]),
+    ((1640, false, XNOR2), &[Arg(1, 15), Arg(3, 365)]),
+    ((1657, false, INV), &[Arg(3, 382)]),
+    ((1659, false, XNOR2), &[Arg(1, 0), Arg(3, 407)]),
+    ((1660, false, XNOR2), &[Arg(1, 1), Arg(3, 408)]),
+    ((1661, false, XNOR2), &[Arg(1, 2), Arg(3, 409)]),
+    ((1662, false, XNOR2), &[Arg(1, 3), Arg(3, 410)]),
+    ((1663, false, XNOR2), &[Arg(1, 4), Arg(3, 411)]),
+    ((1664, false, XNOR2), &[Arg(1, 5), Arg(3, 412)]),
+    ((1665, false, XNOR2), &[Arg(1, 6), Arg(3, 413)]),
+    ((1666, false, XNOR2), &[Arg(1, 7), Arg(3, 414)]),
+    ((1682, false, XNOR2), &[Arg(1, 8), Arg(3, 415)]),
+    ((1683, false, XNOR2), &[Arg(1, 9), Arg(3, 416)]),
+    ((1684, false, XNOR2), &[Arg(1, 10), Arg(3, 417)]),
+    ((1685, false, XNOR2), &[Arg(1, 11), Arg(3, 418)]),
+    ((1686, false, XNOR2), &[Arg(1, 12), Arg(3, 419)]),
+    ((1687, false, XNOR2), &[Arg(1, 13), Arg(3, 420)]),
+    ((1688, false, XNOR2), &[Arg(1, 14), Arg(3, 421)]),
+    ((1689, false, XNOR2), &[Arg(1, 15), Arg(3, 422)]),
+    ((1706, false, INV), &[Arg(3, 439)]),
+    ((1708, false, XNOR2), &[Arg(1, 0), Arg(3, 464)]),
+    ((1709, false, XNOR2), &[Arg(1, 1), Arg(3, 465)]),
+    ((1710, false, XNOR2), &[Arg(1, 2), Arg(3, 466)]),
+    ((1711, false, XNOR2), &[Arg(1, 3), Arg(3, 467)]),
+    ((1712, false, XNOR2), &[Arg(1, 4), Arg(3, 468)]),
+    ((1713, false, XNOR2), &[Arg(1, 5), Arg(3, 469)]),
+    ((1714, false, XNOR2), &[Arg(1, 6), Arg(3, 470)]),
+    ((1715, false, XNOR2), &[Arg(1, 7), Arg(3, 471)]),
+    ((1731, false, XNOR2), &[Arg(1, 8), Arg(3, 472)]),
+    ((1732, false, XNOR2), &[Arg(1, 9), Arg(3, 473)]),
+    ((1733, false, XNOR2), &[Arg(1, 10), Arg(3, 474)]),
+    ((1734, false, XNOR2), &[Arg(1, 11), Arg(3, 475)]),
+    ((1735, false, XNOR2), &[Arg(1, 12), Arg(3, 476)]),
+    ((1736, false, XNOR2), &[Arg(1, 13), Arg(3, 477)]),
+    ((1737, false, XNOR2), &[Arg(1, 14), Arg(3, 478)]),
+    ((1738, false, XNOR2), &[Arg(1, 15), Arg(3, 479)]),
+    ((1755, false, INV), &[Arg(3, 496)]),
+    ((1757, false, XNOR2), &[Arg(1, 0), Arg(3, 521)]),
+    ((1758, false, XNOR2), &[Arg(1, 1), Arg(3, 522)]),
+    ((1759, false, XNOR2), &[Arg(1, 2), Arg(3, 523)]),
+    ((1760, false, XNOR2), &[Arg(1, 3), Arg(3, 524)]),
+    ((1761, false, XNOR2), &[Arg(1, 4), Arg(3, 525)]),
+    ((1762, false, XNOR2), &[Arg(1, 5), Arg(3, 526)]),
+    ((1763, false, XNOR2), &[Arg(1, 6), Arg(3, 527)]),
+    ((1764, false, XNOR2), &[Arg(1, 7), Arg(3, 528)]),
+    ((1780, false, XNOR2), &[Arg(1, 8), Arg(3, 529)]),
+    ((1781, false, XNOR2), &[Arg(1, 9), Arg(3, 530)]),
+    ((1782, false, XNOR2), &[Arg(1, 10), Arg(3, 531)]),
+    ((1783, false, XNOR2), &[Arg(1, 11), Arg(3, 532)]),
+    ((1784, false, XNOR2), &[Arg(1, 12), Arg(3, 533)]),
+    ((1785, false, XNOR2), &[Arg(1, 13), Arg(3, 534)]),
+    ((1786, false, XNOR2), &[Arg(1, 14), Arg(3, 535)]),
+    ((1787, false, XNOR2), &[Arg(1, 15), Arg(3, 536)]),
+    ((1804, false, INV), &[Arg(3, 553)]),
+    ((1806, false, XNOR2), &[Arg(1, 0), Arg(3, 578)]),
+    ((1807, false, XNOR2), &[Arg(1, 1), Arg(3, 579)]),
+    ((1808, false, XNOR2), &[Arg(1, 2), Arg(3, 580)]),
+    ((1809, false, XNOR2), &[Arg(1, 3), Arg(3, 581)]),
+    ((1810, false, XNOR2), &[Arg(1, 4), Arg(3, 582)]),
+    ((1811, false, XNOR2), &[Arg(1, 5), Arg(3, 583)]),
+    ((1812, false, XNOR2), &[Arg(1, 6), Arg(3, 584)]),
+    ((1813, false, XNOR2), &[Arg(1, 7), Arg(3, 585)]),
+    ((1829, false, XNOR2), &[Arg(1, 8), Arg(3, 586)]),
+    ((1830, false, XNOR2), &[Arg(1, 9), Arg(3, 587)]),
+    ((1831, false, XNOR2), &[Arg(1, 10), Arg(3, 588)]),
+    ((1832, false, XNOR2), &[Arg(1, 11), Arg(3, 589)]),
+    ((1833, false, XNOR2), &[Arg(1, 12), Arg(3, 590)]),
+    ((1834, false, XNOR2), &[Arg(1, 13), Arg(3, 591)]),
+    ((1835, false, XNOR2), &[Arg(1, 14), Arg(3, 592)]),
+    ((1836, false, XNOR2), &[Arg(1, 15), Arg(3, 593)]),
+    ((1853, false, INV), &[Arg(3, 610)]),
+    ((1855, false, XNOR2), &[Arg(1, 0), Arg(3, 635)]),
+    ((1856, false, XNOR2), &[Arg(1, 1), Arg(3, 636)]),
+    ((1857, false, XNOR2), &[Arg(1, 2), Arg(3, 637)]),
+    ((1858, false, XNOR2), &[Arg(1, 3), Arg(3, 638)]),
+    ((1859, false, XNOR2), &[Arg(1, 4), Arg(3, 639)]),
+    ((1860, false, XNOR2), &[Arg(1, 5), Arg(3, 640)]),
+    ((1861, false, XNOR2), &[Arg(1, 6), Arg(3, 641)]),
+    ((1862, false, XNOR2), &[Arg(1, 7), Arg(3, 642)]),
+    ((1878, false, XNOR2), &[Arg(1, 8), Arg(3, 643)]),
+    ((1879, false, XNOR2), &[Arg(1, 9), Arg(3, 644)]),
+    ((1880, false, XNOR2), &[Arg(1, 10), Arg(3, 645)]),
+    ((1881, false, XNOR2), &[Arg(1, 11), Arg(3, 646)]),
+    ((1882, false, XNOR2), &[Arg(1, 12), Arg(3, 647)]),
+    ((1883, false, XNOR2), &[Arg(1, 13), Arg(3, 648)]),
+    ((1884, false, XNOR2), &[Arg(1, 14), Arg(3, 649)]),
+    ((1885, false, XNOR2), &[Arg(1, 15), Arg(3, 650)]),
+    ((1902, false, INV), &[Arg(3, 667)]),
+    ((1904, false, XNOR2), &[Arg(1, 0), Arg(4, 8)]),
+    ((1905, false, XNOR2), &[Arg(1, 1), Arg(4, 9)]),
+    ((1906, false, XNOR2), &[Arg(1, 2), Arg(4, 10)]),
+    ((1907, false, XNOR2), &[Arg(1, 3), Arg(4, 11)]),
+    ((1908, false, XNOR2), &[Arg(1, 4), Arg(4, 12)]),
+    ((1909, false, XNOR2), &[Arg(1, 5), Arg(4, 13)]),
+    ((1910, false, XNOR2), &[Arg(1, 6), Arg(4, 14)]),
+    ((1911, false, XNOR2), &[Arg(1, 7), Arg(4, 15)]),
+    ((1927, false, XNOR2), &[Arg(1, 8), Arg(4, 16)]),
+    ((1928, false, XNOR2), &[Arg(1, 9), Arg(4, 17)]),
+    ((1929, false, XNOR2), &[Arg(1, 10), Arg(4, 18)]),
+    ((1930, false, XNOR2), &[Arg(1, 11), Arg(4, 19)]),
+    ((1931, false, XNOR2), &[Arg(1, 12), Arg(4, 20)]),
+    ((1932, false, XNOR2), &[Arg(1, 13), Arg(4, 21)]),
+    ((1933, false, XNOR2), &[Arg(1, 14), Arg(4, 22)]),
+    ((1934, false, XNOR2), &[Arg(1, 15), Arg(4, 23)]),
+    ((1951, false, XNOR2), &[Arg(1, 0), Arg(4, 56)]),
+    ((1952, false, XNOR2), &[Arg(1, 1), Arg(4, 57)]),
+    ((1953, false, XNOR2), &[Arg(1, 2), Arg(4, 58)]),
+    ((1954, false, XNOR2), &[Arg(1, 3), Arg(4, 59)]),
+    ((1955, false, XNOR2), &[Arg(1, 4), Arg(4, 60)]),
+    ((1956, false, XNOR2), &[Arg(1, 5), Arg(4, 61)]),
+    ((1957, false, XNOR2), &[Arg(1, 6), Arg(4, 62)]),
+    ((1958, false, XNOR2), &[Arg(1, 7), Arg(4, 63)]),
+    ((1974, false, XNOR2), &[Arg(1, 8), Arg(4, 64)]),
+    ((1975, false, XNOR2), &[Arg(1, 9), Arg(4, 65)]),
+    ((1976, false, XNOR2), &[Arg(1, 10), Arg(4, 66)]),
+    ((1977, false, XNOR2), &[Arg(1, 11), Arg(4, 67)]),
+    ((1978, false, XNOR2), &[Arg(1, 12), Arg(4, 68)]),
+    ((1979, false, XNOR2), &[Arg(1, 13), Arg(4, 69)]),
+    ((1980, false, XNOR2), &[Arg(1, 14), Arg(4, 70)]),
+    ((1981, false, XNOR2), &[Arg(1, 15), Arg(4, 71)]),
+    ((1998, false, XNOR2), &[Arg(1, 0), Arg(4, 104)]),
+    ((1999, false, XNOR2), &[Arg(1, 1), Arg(4, 105)]),
+    ((2000, false, XNOR2), &[Arg(1, 2), Arg(4, 106)]),
+    ((2001, false, XNOR2), &[Arg(1, 3), Arg(4, 107)]),
+    ((2002, false, XNOR2), &[Arg(1, 4), Arg(4, 108)]),
+    ((2003, false, XNOR2), &[Arg(1, 5), Arg(4, 109)]),
+    ((2004, false, XNOR2), &[Arg(1, 6), Arg(4, 110)]),
+    ((2005, false, XNOR2), &[Arg(1, 7), Arg(4, 111)]),
+    ((2021, false, XNOR2), &[Arg(1, 8), Arg(4, 112)]),
+    ((2022, false, XNOR2), &[Arg(1, 9), Arg(4, 113)]),
+    ((2023, false, XNOR2), &[Arg(1, 10), Arg(4, 114)]),
+    ((2024, false, XNOR2), &[Arg(1, 11), Arg(4, 115)]),
+    ((2025, false, XNOR2), &[Arg(1, 12), Arg(4, 116)]),
+    ((2026, false, XNOR2), &[Arg(1, 13), Arg(4, 117)]),
+    ((2027, false, XNOR2), &[Arg(1, 14), Arg(4, 118)]),
+    ((2028, false, XNOR2), &[Arg(1, 15), Arg(4, 119)]),
+    ((2045, false, XNOR2), &[Arg(1, 0), Arg(4, 152)]),
+    ((2046, false, XNOR2), &[Arg(1, 1), Arg(4, 153)]),
+    ((2047, false, XNOR2), &[Arg(1, 2), Arg(4, 154)]),
+    ((2048, false, XNOR2), &[Arg(1, 3), Arg(4, 155)]),
+    ((2049, false, XNOR2), &[Arg(1, 4), Arg(4, 156)]),
+    ((2050, false, XNOR2), &[Arg(1, 5), Arg(4, 157)]),
+    ((2051, false, XNOR2), &[Arg(1, 6), Arg(4, 158)]),
+    ((2052, false, XNOR2), &[Arg(1, 7), Arg(4, 159)]),
+    ((2068, false, XNOR2), &[Arg(1, 8), Arg(4, 160)]),
+    ((2069, false, XNOR2), &[Arg(1, 9), Arg(4, 161)]),
+    ((2070, false, XNOR2), &[Arg(1, 10), Arg(4, 162)]),
+    ((2071, false, XNOR2), &[Arg(1, 11), Arg(4, 163)]),
+    ((2072, false, XNOR2), &[Arg(1, 12), Arg(4, 164)]),
+    ((2073, false, XNOR2), &[Arg(1, 13), Arg(4, 165)]),
+    ((2074, false, XNOR2), &[Arg(1, 14), Arg(4, 166)]),
+    ((2075, false, XNOR2), &[Arg(1, 15), Arg(4, 167)]),
+    ((2094, false, XOR2), &[Arg(2, 0), Arg(2, 48)]),
+    ((2097, false, XOR2), &[Arg(2, 1), Arg(2, 49)]),
+    ((2100, false, XOR2), &[Arg(2, 2), Arg(2, 50)]),
+    ((2103, false, XOR2), &[Arg(2, 3), Arg(2, 51)]),
+    ((2106, false, XOR2), &[Arg(2, 4), Arg(2, 52)]),
+    ((2109, false, XOR2), &[Arg(2, 5), Arg(2, 53)]),
+    ((2112, false, XOR2), &[Arg(2, 6), Arg(2, 54)]),
+    ((2115, false, XOR2), &[Arg(2, 7), Arg(2, 55)]),
+    ((2118, false, XOR2), &[Arg(2, 24), Arg(2, 72)]),
+    ((2121, false, XOR2), &[Arg(2, 25), Arg(2, 73)]),
+    ((2124, false, XOR2), &[Arg(2, 26), Arg(2, 74)]),
+    ((2127, false, XOR2), &[Arg(2, 27), Arg(2, 75)]),
+    ((2130, false, XOR2), &[Arg(2, 28), Arg(2, 76)]),
+    ((2133, false, XOR2), &[Arg(2, 29), Arg(2, 77)]),
+    ((2136, false, XOR2), &[Arg(2, 30), Arg(2, 78)]),
+    ((2139, false, XOR2), &[Arg(2, 31), Arg(2, 79)]),
+    ((2142, false, XOR2), &[Arg(2, 32), Arg(2, 80)]),
+    ((2145, false, XOR2), &[Arg(2, 33), Arg(2, 81)]),
+    ((2148, false, XOR2), &[Arg(2, 34), Arg(2, 82)]),
+    ((2151, false, XOR2), &[Arg(2, 35), Arg(2, 83)]),
+    ((2154, false, XOR2), &[Arg(2, 36), Arg(2, 84)]),
+    ((2157, false, XOR2), &[Arg(2, 37), Arg(2, 85)]),
+    ((2160, false, XOR2), &[Arg(2, 38), Arg(2, 86)]),
+    ((2163, false, XOR2), &[Arg(2, 39), Arg(2, 87)]),
+    ((2166, false, XOR2), &[Arg(2, 40), Arg(2, 88)]),
+    ((2169, false, XOR2), &[Arg(2, 41), Arg(2, 89)]),
+    ((2172, false, XOR2), &[Arg(2, 42), Arg(2, 90)]),
+    ((2175, false, XOR2), &[Arg(2, 43), Arg(2, 91)]),
+    ((2178, false, XOR2), &[Arg(2, 44), Arg(2, 92)]),
+    ((2181, false, XOR2), &[Arg(2, 45), Arg(2, 93)]),
+    ((2184, false, XOR2), &[Arg(2, 46), Arg(2, 94)]),
+    ((2187, false, XOR2), &[Arg(2, 47), Arg(2, 95)]),
+    ((2192, false, XOR2), &[Arg(2, 96), Arg(2, 144)]),
+    ((2195, false, XOR2), &[Arg(2, 97), Arg(2, 145)]),
+    ((2198, false, XOR2), &[Arg(2, 98), Arg(2, 146)]),
+    ((2201, false, XOR2), &[Arg(2, 99), Arg(2, 147)]),
+    ((2204, false, XOR2), &[Arg(2, 100), Arg(2, 148)]),
+    ((2207, false, XOR2), &[Arg(2, 101), Arg(2, 149)]),
+    ((2210, false, XOR2), &[Arg(2, 102), Arg(2, 150)]),
+    ((2213, false, XOR2), &[Arg(2, 103), Arg(2, 151)]),
+    ((2216, false, XOR2), &[Arg(2, 120), Arg(2, 168)]),
+    ((2219, false, XOR2), &[Arg(2, 121), Arg(2, 169)]),
+    ((2222, false, XOR2), &[Arg(2, 122), Arg(2, 170)]),
+    ((2225, false, XOR2), &[Arg(2, 123), Arg(2, 171)]),
+    ((2228, false, XOR2), &[Arg(2, 124), Arg(2, 172)]),
+    ((2231, false, XOR2), &[Arg(2, 125), Arg(2, 173)]),
+    ((2234, false, XOR2), &[Arg(2, 126), Arg(2, 174)]),
+    ((2237, false, XOR2), &[Arg(2, 127), Arg(2, 175)]),
+    ((2240, false, XOR2), &[Arg(2, 128), Arg(2, 176)]),
+    ((2243, false, XOR2), &[Arg(2, 129), Arg(2, 177)]),
+    ((2246, false, XOR2), &[Arg(2, 130), Arg(2, 178)]),
+    ((2249, false, XOR2), &[Arg(2, 131), Arg(2, 179)]),
+    ((2252, false, XOR2), &[Arg(2, 132), Arg(2, 180)]),
+    ((2255, false, XOR2), &[Arg(2, 133), Arg(2, 181)]),
+    ((2258, false, XOR2), &[Arg(2, 134), Arg(2, 182)]),
+    ((2261, false, XOR2), &[Arg(2, 135), Arg(2, 183)]),
+    ((2264, false, XOR2), &[Arg(2, 136), Arg(2, 184)]),
+    ((2267, false, XOR2), &[Arg(2, 137), Arg(2, 185)]),
+    ((2270, false, XOR2), &[Arg(2, 138), Arg(2, 186)]),
+    ((2273, false, XOR2), &[Arg(2, 139), Arg(2, 187)]),
+    ((2276, false, XOR2), &[Arg(2, 140), Arg(2, 188)]),
+    ((2279, false, XOR2), &[Arg(2, 141), Arg(2, 189)]),
+    ((2282, false, XOR2), &[Arg(2, 142), Arg(2, 190)]),
+    ((2285, false, XOR2), &[Arg(2, 143), Arg(2, 191)]),
+    ((2290, false, XOR2), &[Arg(2, 192), Arg(2, 240)]),
+    ((2293, false, XOR2), &[Arg(2, 193), Arg(2, 241)]),
+    ((2296, false, XOR2), &[Arg(2, 194), Arg(2, 242)]),
+    ((2299, false, XOR2), &[Arg(2, 195), Arg(2, 243)]),
+    ((2302, false, XOR2), &[Arg(2, 196), Arg(2, 244)]),
+    ((2305, false, XOR2), &[Arg(2, 197), Arg(2, 245)]),
+    ((2308, false, XOR2), &[Arg(2, 198), Arg(2, 246)]),
+    ((2311, false, XOR2), &[Arg(2, 199), Arg(2, 247)]),
+    ((2314, false, XOR2), &[Arg(2, 216), Arg(2, 264)]),
+    ((2317, false, XOR2), &[Arg(2, 217), Arg(2, 265)]),
+    ((2320, false, XOR2), &[Arg(2, 218), Arg(2, 266)]),
+    ((2323, false, XOR2), &[Arg(2, 219), Arg(2, 267)]),
+    ((2326, false, XOR2), &[Arg(2, 220), Arg(2, 268)]),
+    ((2329, false, XOR2), &[Arg(2, 221), Arg(2, 269)]),
+    ((2332, false, XOR2), &[Arg(2, 222), Arg(2, 270)]),
+    ((2335, false, XOR2), &[Arg(2, 223), Arg(2, 271)]),
+    ((2338, false, XOR2), &[Arg(2, 224), Arg(2, 272)]),
+    ((2341, false, XOR2), &[Arg(2, 225), Arg(2, 273)]),
+    ((2344, false, XOR2), &[Arg(2, 226), Arg(2, 274)]),
+    ((2347, false, XOR2), &[Arg(2, 227), Arg(2, 275)]),
+    ((2350, false, XOR2), &[Arg(2, 228), Arg(2, 276)]),
+    ((2353, false, XOR2), &[Arg(2, 229), Arg(2, 277)]),
+    ((2356, false, XOR2), &[Arg(2, 230), Arg(2, 278)]),
+    ((2359, false, XOR2), &[Arg(2, 231), Arg(2, 279)]),
+    ((2362, false, XOR2), &[Arg(2, 232), Arg(2, 280)]),
+    ((2365, false, XOR2), &[Arg(2, 233), Arg(2, 281)]),
+    ((2368, false, XOR2), &[Arg(2, 234), Arg(2, 282)]),
+    ((2371, false, XOR2), &[Arg(2, 235), Arg(2, 283)]),
+    ((2374, false, XOR2), &[Arg(2, 236), Arg(2, 284)]),
+    ((2377, false, XOR2), &[Arg(2, 237), Arg(2, 285)]),
+    ((2380, false, XOR2), &[Arg(2, 238), Arg(2, 286)]),
+    ((2383, false, XOR2), &[Arg(2, 239), Arg(2, 287)]),
+    ((2388, false, XOR2), &[Arg(2, 288), Arg(2, 336)]),
+    ((2391, false, XOR2), &[Arg(2, 289), Arg(2, 337)]),
+    ((2394, false, XOR2), &[Arg(2, 290), Arg(2, 338)]),
+    ((2397, false, XOR2), &[Arg(2, 291), Arg(2, 339)]),
+    ((2400, false, XOR2), &[Arg(2, 292), Arg(2, 340)]),
+    ((2403, false, XOR2), &[Arg(2, 293), Arg(2, 341)]),
+    ((2406, false, XOR2), &[Arg(2, 294), Arg(2, 342)]),
+    ((2409, false, XOR2), &[Arg(2, 295), Arg(2, 343)]),
+    ((2412, false, XOR2), &[Arg(2, 312), Arg(2, 360)]),
+    ((2415, false, XOR2), &[Arg(2, 313), Arg(2, 361)]),
+    ((2418, false, XOR2), &[Arg(2, 314), Arg(2, 362)]),
+    ((2421, false, XOR2), &[Arg(2, 315), Arg(2, 363)]),
+    ((2424, false, XOR2), &[Arg(2, 316), Arg(2, 364)]),
+    ((2427, false, XOR2), &[Arg(2, 317), Arg(2, 365)]),
+    ((2430, false, XOR2), &[Arg(2, 318), Arg(2, 366)]),
+    ((2433, false, XOR2), &[Arg(2, 319), Arg(2, 367)]),
+    ((2436, false, XOR2), &[Arg(2, 320), Arg(2, 368)]),
+    ((2439, false, XOR2), &[Arg(2, 321), Arg(2, 369)]),
+    ((2442, false, XOR2), &[Arg(2, 322), Arg(2, 370)]),
+    ((2445, false, XOR2), &[Arg(2, 323), Arg(2, 371)]),
+    ((2448, false, XOR2), &[Arg(2, 324), Arg(2, 372)]),
+    ((2451, false, XOR2), &[Arg(2, 325), Arg(2, 373)]),
+    ((2454, false, XOR2), &[Arg(2, 326), Arg(2, 374)]),
+    ((2457, false, XOR2), &[Arg(2, 327), Arg(2, 375)]),
+    ((2460, false, XOR2), &[Arg(2, 328), Arg(2, 376)]),
+    ((2463, false, XOR2), &[Arg(2, 329), Arg(2, 377)]),
+    ((2466, false, XOR2), &[Arg(2, 330), Arg(2, 378)]),
+    ((2469, false, XOR2), &[Arg(2, 331), Arg(2, 379)]),
+    ((2472, false, XOR2), &[Arg(2, 332), Arg(2, 380)]),
+    ((2475, false, XOR2), &[Arg(2, 333), Arg(2, 381)]),
+    ((2478, false, XOR2), &[Arg(2, 334), Arg(2, 382)]),
+    ((2481, false, XOR2), &[Arg(2, 335), Arg(2, 383)]),
+    ((2486, false, XOR2), &[Arg(2, 384), Arg(2, 432)]),
+    ((2489, false, XOR2), &[Arg(2, 385), Arg(2, 433)]),
+    ((2492, false, XOR2), &[Arg(2, 386), Arg(2, 434)]),
+    ((2495, false, XOR2), &[Arg(2, 387), Arg(2, 435)]),
+    ((2498, false, XOR2), &[Arg(2, 388), Arg(2, 436)]),
+    ((2501, false, XOR2), &[Arg(2, 389), Arg(2, 437)]),
+    ((2504, false, XOR2), &[Arg(2, 390), Arg(2, 438)]),
+    ((2507, false, XOR2), &[Arg(2, 391), Arg(2, 439)]),
+    ((2510, false, XOR2), &[Arg(2, 408), Arg(2, 456)]),
+    ((2513, false, XOR2), &[Arg(2, 409), Arg(2, 457)]),
+    ((2516, false, XOR2), &[Arg(2, 410), Arg(2, 458)]),
+    ((2519, false, XOR2), &[Arg(2, 411), Arg(2, 459)]),
+    ((2522, false, XOR2), &[Arg(2, 412), Arg(2, 460)]),
+    ((2525, false, XOR2), &[Arg(2, 413), Arg(2, 461)]),
+    ((2528, false, XOR2), &[Arg(2, 414), Arg(2, 462)]),
+    ((2531, false, XOR2), &[Arg(2, 415), Arg(2, 463)]),
+    ((2534, false, XOR2), &[Arg(2, 416), Arg(2, 464)]),
+    ((2537, false, XOR2), &[Arg(2, 417), Arg(2, 465)]),
+    ((2540, false, XOR2), &[Arg(2, 418), Arg(2, 466)]),
+    ((2543, false, XOR2), &[Arg(2, 419), Arg(2, 467)]),
+    ((2546, false, XOR2), &[Arg(2, 420), Arg(2, 468)]),
+    ((2549, false, XOR2), &[Arg(2, 421), Arg(2, 469)]),
+    ((2552, false, XOR2), &[Arg(2, 422), Arg(2, 470)]),
+    ((2555, false, XOR2), &[Arg(2, 423), Arg(2, 471)]),
+    ((2558, false, XOR2), &[Arg(2, 424), Arg(2, 472)]),
+    ((2561, false, XOR2), &[Arg(2, 425), Arg(2, 473)]),
+    ((2564, false, XOR2), &[Arg(2, 426), Arg(2, 474)]),
+    ((2567, false, XOR2), &[Arg(2, 427), Arg(2, 475)]),
+    ((2570, false, XOR2), &[Arg(2, 428), Arg(2, 476)]),
+    ((2573, false, XOR2), &[Arg(2, 429), Arg(2, 477)]),
+    ((2576, false, XOR2), &[Arg(2, 430), Arg(2, 478)]),
+    ((2579, false, XOR2), &[Arg(2, 431), Arg(2, 479)]),
+    ((2584, false, XOR2), &[Arg(2, 480), Arg(2, 528)]),
+    ((2587, false, XOR2), &[Arg(2, 481), Arg(2, 529)]),
+    ((2590, false, XOR2), &[Arg(2, 482), Arg(2, 530)]),
+    ((2593, false, XOR2), &[Arg(2, 483), Arg(2, 531)]),
+    ((2596, false, XOR2), &[Arg(2, 484), Arg(2, 532)]),
+    ((2599, false, XOR2), &[Arg(2, 485), Arg(2, 533)]),
+    ((2602, false, XOR2), &[Arg(2, 486), Arg(2, 534)]),
+    ((2605, false, XOR2), &[Arg(2, 487), Arg(2, 535)]),
+    ((2608, false, XOR2), &[Arg(2, 504), Arg(2, 552)]),
+    ((2611, false, XOR2), &[Arg(2, 505), Arg(2, 553)]),
+    ((2614, false, XOR2), &[Arg(2, 506), Arg(2, 554)]),
+    ((2617, false, XOR2), &[Arg(2, 507), Arg(2, 555)]),
+    ((2620, false, XOR2), &[Arg(2, 508), Arg(2, 556)]),
+    ((2623, false, XOR2), &[Arg(2, 509), Arg(2, 557)]),
+    ((2626, false, XOR2), &[Arg(2, 510), Arg(2, 558)]),
+    ((2629, false, XOR2), &[Arg(2, 511), Arg(2, 559)]),
+    ((2632, false, XOR2), &[Arg(2, 512), Arg(2, 560)]),
+    ((2635, false, XOR2), &[Arg(2, 513), Arg(2, 561)]),
+    ((2638, false, XOR2), &[Arg(2, 514), Arg(2, 562)]),
+    ((2641, false, XOR2), &[Arg(2, 515), Arg(2, 563)]),
+    ((2644, false, XOR2), &[Arg(2, 516), Arg(2, 564)]),
+    ((2647, false, XOR2), &[Arg(2, 517), Arg(2, 565)]),
+    ((2650, false, XOR2), &[Arg(2, 518), Arg(2, 566)]),
+    ((2653, false, XOR2), &[Arg(2, 519), Arg(2, 567)]),
+    ((2656, false, XOR2), &[Arg(2, 520), Arg(2, 568)]),
+    ((2659, false, XOR2), &[Arg(2, 521), Arg(2, 569)]),
+    ((2662, false, XOR2), &[Arg(2, 522), Arg(2, 570)]),
+    ((2665, false, XOR2), &[Arg(2, 523), Arg(2, 571)]),
+    ((2668, false, XOR2), &[Arg(2, 524), Arg(2, 572)]),
+    ((2671, false, XOR2), &[Arg(2, 525), Arg(2, 573)]),
+    ((2674, false, XOR2), &[Arg(2, 526), Arg(2, 574)]),
+    ((2677, false, XOR2), &[Arg(2, 527), Arg(2, 575)]),
+    ((2682, false, XOR2), &[Arg(2, 576), Arg(2, 624)]),
+    ((2685, false, XOR2), &[Arg(2, 577), Arg(2, 625)]),
+    ((2688, false, XOR2), &[Arg(2, 578), Arg(2, 626)]),
+    ((2691, false, XOR2), &[Arg(2, 579), Arg(2, 627)]),
+    ((2694, false, XOR2), &[Arg(2, 580), Arg(2, 628)]),
+    ((2697, false, XOR2), &[Arg(2, 581), Arg(2, 629)]),
+    ((2700, false, XOR2), &[Arg(2, 582), Arg(2, 630)]),
+    ((2703, false, XOR2), &[Arg(2, 583), Arg(2, 631)]),
+    ((2706, false, XOR2), &[Arg(2, 600), Arg(2, 648)]),
+    ((2709, false, XOR2), &[Arg(2, 601), Arg(2, 649)]),
+    ((2712, false, XOR2), &[Arg(2, 602), Arg(2, 650)]),
+    ((2715, false, XOR2), &[Arg(2, 603), Arg(2, 651)]),
+    ((2718, false, XOR2), &[Arg(2, 604), Arg(2, 652)]),
+    ((2721, false, XOR2), &[Arg(2, 605), Arg(2, 653)]),
+    ((2724, false, XOR2), &[Arg(2, 606), Arg(2, 654)]),
+    ((2727, false, XOR2), &[Arg(2, 607), Arg(2, 655)]),
+    ((2730, false, XOR2), &[Arg(2, 608), Arg(2, 656)]),
+    ((2733, false, XOR2), &[Arg(2, 609), Arg(2, 657)]),
+    ((2736, false, XOR2), &[Arg(2, 610), Arg(2, 658)]),
+    ((2739, false, XOR2), &[Arg(2, 611), Arg(2, 659)]),
+    ((2742, false, XOR2), &[Arg(2, 612), Arg(2, 660)]),
+    ((2745, false, XOR2), &[Arg(2, 613), Arg(2, 661)]),
+    ((2748, false, XOR2), &[Arg(2, 614), Arg(2, 662)]),
+    ((2751, false, XOR2), &[Arg(2, 615), Arg(2, 663)]),
+    ((2754, false, XOR2), &[Arg(2, 616), Arg(2, 664)]),
+    ((2757, false, XOR2), &[Arg(2, 617), Arg(2, 665)]),
+    ((2760, false, XOR2), &[Arg(2, 618), Arg(2, 666)]),
+    ((2763, false, XOR2), &[Arg(2, 619), Arg(2, 667)]),
+    ((2766, false, XOR2), &[Arg(2, 620), Arg(2, 668)]),
+    ((2769, false, XOR2), &[Arg(2, 621), Arg(2, 669)]),
+    ((2772, false, XOR2), &[Arg(2, 622), Arg(2, 670)]),
+    ((2775, false, XOR2), &[Arg(2, 623), Arg(2, 671)]),
+    ((2780, false, XOR2), &[Arg(2, 672), Arg(2, 720)]),
+    ((2783, false, XOR2), &[Arg(2, 673), Arg(2, 721)]),
+    ((2786, false, XOR2), &[Arg(2, 674), Arg(2, 722)]),
+    ((2789, false, XOR2), &[Arg(2, 675), Arg(2, 723)]),
+    ((2792, false, XOR2), &[Arg(2, 676), Arg(2, 724)]),
+    ((2795, false, XOR2), &[Arg(2, 677), Arg(2, 725)]),
+    ((2798, false, XOR2), &[Arg(2, 678), Arg(2, 726)]),
+    ((2801, false, XOR2), &[Arg(2, 679), Arg(2, 727)]),
+    ((2804, false, XOR2), &[Arg(2, 696), Arg(2, 744)]),
+    ((2807, false, XOR2), &[Arg(2, 697), Arg(2, 745)]),
+    ((2810, false, XOR2), &[Arg(2, 698), Arg(2, 746)]),
+    ((2813, false, XOR2), &[Arg(2, 699), Arg(2, 747)]),
+    ((2816, false, XOR2), &[Arg(2, 700), Arg(2, 748)]),
+    ((2819, false, XOR2), &[Arg(2, 701), Arg(2, 749)]),
+    ((2822, false, XOR2), &[Arg(2, 702), Arg(2, 750)]),
+    ((2825, false, XOR2), &[Arg(2, 703), Arg(2, 751)]),
+    ((2828, false, XOR2), &[Arg(2, 704), Arg(2, 752)]),
+    ((2831, false, XOR2), &[Arg(2, 705), Arg(2, 753)]),
+    ((2834, false, XOR2), &[Arg(2, 706), Arg(2, 754)]),
+    ((2837, false, XOR2), &[Arg(2, 707), Arg(2, 755)]),
+    ((2840, false, XOR2), &[Arg(2, 708), Arg(2, 756)]),
+    ((2843, false, XOR2), &[Arg(2, 709), Arg(2, 757)]),
+    ((2846, false, XOR2), &[Arg(2, 710), Arg(2, 758)]),
+    ((2849, false, XOR2), &[Arg(2, 711), Arg(2, 759)]),
+    ((2852, false, XOR2), &[Arg(2, 712), Arg(2, 760)]),
+    ((2855, false, XOR2), &[Arg(2, 713), Arg(2, 761)]),
+    ((2858, false, XOR2), &[Arg(2, 714), Arg(2, 762)]),
+    ((2861, false, XOR2), &[Arg(2, 715), Arg(2, 763)]),
+    ((2864, false, XOR2), &[Arg(2, 716), Arg(2, 764)]),
+    ((2867, false, XOR2), &[Arg(2, 717), Arg(2, 765)]),
+    ((2870, false, XOR2), &[Arg(2, 718), Arg(2, 766)]),
+    ((2873, false, XOR2), &[Arg(2, 719), Arg(2, 767)]),
+    ((2878, false, XOR2), &[Arg(2, 768), Arg(2, 816)]),
+    ((2881, false, XOR2), &[Arg(2, 769), Arg(2, 817)]),
+    ((2884, false, XOR2), &[Arg(2, 770), Arg(2, 818)]),
+    ((2887, false, XOR2), &[Arg(2, 771), Arg(2, 819)]),
+    ((2890, false, XOR2), &[Arg(2, 772), Arg(2, 820)]),
+    ((2893, false, XOR2), &[Arg(2, 773), Arg(2, 821)]),
+    ((2896, false, XOR2), &[Arg(2, 774), Arg(2, 822)]),
+    ((2899, false, XOR2), &[Arg(2, 775), Arg(2, 823)]),
+    ((2902, false, XOR2), &[Arg(2, 792), Arg(2, 840)]),
+    ((2905, false, XOR2), &[Arg(2, 793), Arg(2, 841)]),
+    ((2908, false, XOR2), &[Arg(2, 794), Arg(2, 842)]),
+    ((2911, false, XOR2), &[Arg(2, 795), Arg(2, 843)]),
+    ((2914, false, XOR2), &[Arg(2, 796), Arg(2, 844)]),
+    ((2917, false, XOR2), &[Arg(2, 797), Arg(2, 845)]),
+    ((2920, false, XOR2), &[Arg(2, 798), Arg(2, 846)]),
+    ((2923, false, XOR2), &[Arg(2, 799), Arg(2, 847)]),
+    ((2926, false, XOR2), &[Arg(2, 800), Arg(2, 848)]),
+    ((2929, false, XOR2), &[Arg(2, 801), Arg(2, 849)]),
+    ((2932, false, XOR2), &[Arg(2, 802), Arg(2, 850)]),
+    ((2935, false, XOR2), &[Arg(2, 803), Arg(2, 851)]),
+    ((2938, false, XOR2), &[Arg(2, 804), Arg(2, 852)]),
+    ((2941, false, XOR2), &[Arg(2, 805), Arg(2, 853)]),
+    ((2944, false, XOR2), &[Arg(2, 806), Arg(2, 854)]),
+    ((2947, false, XOR2), &[Arg(2, 807), Arg(2, 855)]),
+    ((2950, false, XOR2), &[Arg(2, 808), Arg(2, 856)]),
+    ((2953, false, XOR2), &[Arg(2, 809), Arg(2, 857)]),
+    ((2956, false, XOR2), &[Arg(2, 810), Arg(2, 858)]),
+    ((2959, false, XOR2), &[Arg(2, 811), Arg(2, 859)]),
+    ((2962, false, XOR2), &[Arg(2, 812), Arg(2, 860)]),
+    ((2965, false, XOR2), &[Arg(2, 813), Arg(2, 861)]),
+    ((2968, false, XOR2), &[Arg(2, 814), Arg(2, 862)]),
+    ((2971, false, XOR2), &[Arg(2, 815), Arg(2, 863)]),
+    ((2976, false, XOR2), &[Arg(2, 864), Arg(2, 912)]),
+    ((2979, false, XOR2), &[Arg(2, 865), Arg(2, 913)]),
+    ((2982, false, XOR2), &[Arg(2, 866), Arg(2, 914)]),
+    ((2985, false, XOR2), &[Arg(2, 867), Arg(2, 915)]),
+    ((2988, false, XOR2), &[Arg(2, 868), Arg(2, 916)]),
+    ((2991, false, XOR2), &[Arg(2, 869), Arg(2, 917)]),
+    ((2994, false, XOR2), &[Arg(2, 870), Arg(2, 918)]),
+    ((2997, false, XOR2), &[Arg(2, 871), Arg(2, 919)]),
+    ((3000, false, XOR2), &[Arg(2, 888), Arg(2, 936)]),
+    ((3003, false, XOR2), &[Arg(2, 889), Arg(2, 937)]),
+    ((3006, false, XOR2), &[Arg(2, 890), Arg(2, 938)]),
+    ((3009, false, XOR2), &[Arg(2, 891), Arg(2, 939)]),
+    ((3012, false, XOR2), &[Arg(2, 892), Arg(2, 940)]),
+    ((3015, false, XOR2), &[Arg(2, 893), Arg(2, 941)]),
+    ((3018, false, XOR2), &[Arg(2, 894), Arg(2, 942)]),
+    ((3021, false, XOR2), &[Arg(2, 895), Arg(2, 943)]),
+    ((3024, false, XOR2), &[Arg(2, 896), Arg(2, 944)]),
+    ((3027, false, XOR2), &[Arg(2, 897), Arg(2, 945)]),
+    ((3030, false, XOR2), &[Arg(2, 898), Arg(2, 946)]),
+    ((3033, false, XOR2), &[Arg(2, 899), Arg(2, 947)]),
+    ((3036, false, XOR2), &[Arg(2, 900), Arg(2, 948)]),
+    ((3039, false, XOR2), &[Arg(2, 901), Arg(2, 949)]),
+    ((3042, false, XOR2), &[Arg(2, 902), Arg(2, 950)]),
+    ((3045, false, XOR2), &[Arg(2, 903), Arg(2, 951)]),
+    ((3048, false, XOR2), &[Arg(2, 904), Arg(2, 952)]),
+    ((3051, false, XOR2), &[Arg(2, 905), Arg(2, 953)]),
+    ((3054, false, XOR2), &[Arg(2, 906), Arg(2, 954)]),
+    ((3057, false, XOR2), &[Arg(2, 907), Arg(2, 955)]),
+    ((3060, false, XOR2), &[Arg(2, 908), Arg(2, 956)]),
+    ((3063, false, XOR2), &[Arg(2, 909), Arg(2, 957)]),
+    ((3066, false, XOR2), &[Arg(2, 910), Arg(2, 958)]),
+    ((3069, false, XOR2), &[Arg(2, 911), Arg(2, 959)]),
+    ((3074, false, XOR2), &[Arg(2, 960), Arg(2, 1008)]),
+    ((3077, false, XOR2), &[Arg(2, 961), Arg(2, 1009)]),
+    ((3080, false, XOR2), &[Arg(2, 962), Arg(2, 1010)]),
+    ((3083, false, XOR2), &[Arg(2, 963), Arg(2, 1011)]),
+    ((3086, false, XOR2), &[Arg(2, 964), Arg(2, 1012)]),
+    ((3089, false, XOR2), &[Arg(2, 965), Arg(2, 1013)]),
+    ((3092, false, XOR2), &[Arg(2, 966), Arg(2, 1014)]),
+    ((3095, false, XOR2), &[Arg(2, 967), Arg(2, 1015)]),
+    ((3098, false, XOR2), &[Arg(2, 984), Arg(2, 1032)]),
+    ((3101, false, XOR2), &[Arg(2, 985), Arg(2, 1033)]),
+    ((3104, false, XOR2), &[Arg(2, 986), Arg(2, 1034)]),
+    ((3107, false, XOR2), &[Arg(2, 987), Arg(2, 1035)]),
+    ((3110, false, XOR2), &[Arg(2, 988), Arg(2, 1036)]),
+    ((3113, false, XOR2), &[Arg(2, 989), Arg(2, 1037)]),
+    ((3116, false, XOR2), &[Arg(2, 990), Arg(2, 1038)]),
+    ((3119, false, XOR2), &[Arg(2, 991), Arg(2, 1039)]),
+    ((3122, false, XOR2), &[Arg(2, 992), Arg(2, 1040)]),
+    ((3125, false, XOR2), &[Arg(2, 993), Arg(2, 1041)]),
+    ((3128, false, XOR2), &[Arg(2, 994), Arg(2, 1042)]),
+    ((3131, false, XOR2), &[Arg(2, 995), Arg(2, 1043)]),
+    ((3134, false, XOR2), &[Arg(2, 996), Arg(2, 1044)]),
+    ((3137, false, XOR2), &[Arg(2, 997), Arg(2, 1045)]),
+    ((3140, false, XOR2), &[Arg(2, 998), Arg(2, 1046)]),
+    ((3143, false, XOR2), &[Arg(2, 999), Arg(2, 1047)]),
+    ((3146, false, XOR2), &[Arg(2, 1000), Arg(2, 1048)]),
+    ((3149, false, XOR2), &[Arg(2, 1001), Arg(2, 1049)]),
+    ((3152, false, XOR2), &[Arg(2, 1002), Arg(2, 1050)]),
+    ((3155, false, XOR2), &[Arg(2, 1003), Arg(2, 1051)]),
+    ((3158, false, XOR2), &[Arg(2, 1004), Arg(2, 1052)]),
+    ((3161, false, XOR2), &[Arg(2, 1005), Arg(2, 1053)]),
+    ((3164, false, XOR2), &[Arg(2, 1006), Arg(2, 1054)]),
+    ((3167, false, XOR2), &[Arg(2, 1007), Arg(2, 1055)]),
+    ((3172, false, XOR2), &[Arg(2, 1056), Arg(3, 0)]),
+    ((3175, false, XOR2), &[Arg(2, 1057), Arg(3, 1)]),
+    ((3178, false, XOR2), &[Arg(2, 1058), Arg(3, 2)]),
+    ((3181, false, XOR2), &[Arg(2, 1059), Arg(3, 3)]),
+    ((3184, false, XOR2), &[Arg(2, 1060), Arg(3, 4)]),
+    ((3187, false, XOR2), &[Arg(2, 1061), Arg(3, 5)]),
+    ((3190, false, XOR2), &[Arg(2, 1062), Arg(3, 6)]),
+    ((3193, false, XOR2), &[Arg(2, 1063), Arg(3, 7)]),
+    ((3196, false, XOR2), &[Arg(2, 1080), Arg(3, 24)]),
+    ((3199, false, XOR2), &[Arg(2, 1081), Arg(3, 25)]),
+    ((3202, false, XOR2), &[Arg(2, 1082), Arg(3, 26)]),
+    ((3205, false, XOR2), &[Arg(2, 1083), Arg(3, 27)]),
+    ((3208, false, XOR2), &[Arg(2, 1084), Arg(3, 28)]),
+    ((3211, false, XOR2), &[Arg(2, 1085), Arg(3, 29)]),
+    ((3214, false, XOR2), &[Arg(2, 1086), Arg(3, 30)]),
+    ((3217, false, XOR2), &[Arg(2, 1087), Arg(3, 31)]),
+    ((3220, false, XOR2), &[Arg(2, 1088), Arg(3, 32)]),
+    ((3223, false, XOR2), &[Arg(2, 1089), Arg(3, 33)]),
+    ((3226, false, XOR2), &[Arg(2, 1090), Arg(3, 34)]),
+    ((3229, false, XOR2), &[Arg(2, 1091), Arg(3, 35)]),
+    ((3232, false, XOR2), &[Arg(2, 1092), Arg(3, 36)]),
+    ((3235, false, XOR2), &[Arg(2, 1093), Arg(3, 37)]),
+    ((3238, false, XOR2), &[Arg(2, 1094), Arg(3, 38)]),
+    ((3241, false, XOR2), &[Arg(2, 1095), Arg(3, 39)]),
+    ((3244, false, XOR2), &[Arg(2, 1096), Arg(3, 41)]),
+    ((3247, false, XOR2), &[Arg(2, 1097), Arg(3, 42)]),
+    ((3250, false, XOR2), &[Arg(2, 1098), Arg(3, 43)]),
+    ((3253, false, XOR2), &[Arg(2, 1099), Arg(3, 44)]),
+    ((3256, false, XOR2), &[Arg(2, 1100), Arg(3, 45)]),
+    ((3259, false, XOR2), &[Arg(2, 1101), Arg(3, 46)]),
+    ((3262, false, XOR2), &[Arg(2, 1102), Arg(3, 47)]),
+    ((3265, false, XOR2), &[Arg(2, 1103), Arg(3, 48)]),
+    ((3270, false, XOR2), &[Arg(3, 57), Arg(3, 114)]),
+    ((3273, false, XOR2), &[Arg(3, 58), Arg(3, 115)]),
+    ((3276, false, XOR2), &[Arg(3, 59), Arg(3, 116)]),
+    ((3279, false, XOR2), &[Arg(3, 60), Arg(3, 117)]),
+    ((3282, false, XOR2), &[Arg(3, 61), Arg(3, 118)]),
+    ((3285, false, XOR2), &[Arg(3, 62), Arg(3, 119)]),
+    ((3288, false, XOR2), &[Arg(3, 63), Arg(3, 120)]),
+    ((3291, false, XOR2), &[Arg(3, 64), Arg(3, 121)]),
+    ((3294, false, XOR2), &[Arg(3, 81), Arg(3, 138)]),
+    ((3297, false, XOR2), &[Arg(3, 82), Arg(3, 139)]),
+    ((3300, false, XOR2), &[Arg(3, 83), Arg(3, 140)]),
+    ((3303, false, XOR2), &[Arg(3, 84), Arg(3, 141)]),
+    ((3306, false, XOR2), &[Arg(3, 85), Arg(3, 142)]),
+    ((3309, false, XOR2), &[Arg(3, 86), Arg(3, 143)]),
+    ((3312, false, XOR2), &[Arg(3, 87), Arg(3, 144)]),
+    ((3315, false, XOR2), &[Arg(3, 88), Arg(3, 145)]),
+    ((3318, false, XOR2), &[Arg(3, 89), Arg(3, 146)]),
+    ((3321, false, XOR2), &[Arg(3, 90), Arg(3, 147)]),
+    ((3324, false, XOR2), &[Arg(3, 91), Arg(3, 148)]),
+    ((3327, false, XOR2), &[Arg(3, 92), Arg(3, 149)]),
+    ((3330, false, XOR2), &[Arg(3, 93), Arg(3, 150)]),
+    ((3333, false, XOR2), &[Arg(3, 94), Arg(3, 151)]),
+    ((3336, false, XOR2), &[Arg(3, 95), Arg(3, 152)]),
+    ((3339, false, XOR2), &[Arg(3, 96), Arg(3, 153)]),
+    ((3342, false, XOR2), &[Arg(3, 98), Arg(3, 155)]),
+    ((3345, false, XOR2), &[Arg(3, 99), Arg(3, 156)]),
+    ((3348, false, XOR2), &[Arg(3, 100), Arg(3, 157)]),
+    ((3351, false, XOR2), &[Arg(3, 101), Arg(3, 158)]),
+    ((3354, false, XOR2), &[Arg(3, 102), Arg(3, 159)]),
+    ((3357, false, XOR2), &[Arg(3, 103), Arg(3, 160)]),
+    ((3360, false, XOR2), &[Arg(3, 104), Arg(3, 161)]),
+    ((3363, false, XOR2), &[Arg(3, 105), Arg(3, 162)]),
+    ((3368, false, XOR2), &[Arg(3, 171), Arg(3, 228)]),
+    ((3371, false, XOR2), &[Arg(3, 172), Arg(3, 229)]),
+    ((3374, false, XOR2), &[Arg(3, 173), Arg(3, 230)]),
+    ((3377, false, XOR2), &[Arg(3, 174), Arg(3, 231)]),
+    ((3380, false, XOR2), &[Arg(3, 175), Arg(3, 232)]),
+    ((3383, false, XOR2), &[Arg(3, 176), Arg(3, 233)]),
+    ((3386, false, XOR2), &[Arg(3, 177), Arg(3, 234)]),
+    ((3389, false, XOR2), &[Arg(3, 178), Arg(3, 235)]),
+    ((3392, false, XOR2), &[Arg(3, 195), Arg(3, 252)]),
+    ((3395, false, XOR2), &[Arg(3, 196), Arg(3, 253)]),
+    ((3398, false, XOR2), &[Arg(3, 197), Arg(3, 254)]),
+    ((3401, false, XOR2), &[Arg(3, 198), Arg(3, 255)]),
+    ((3404, false, XOR2), &[Arg(3, 199), Arg(3, 256)]),
+    ((3407, false, XOR2), &[Arg(3, 200), Arg(3, 257)]),
+    ((3410, false, XOR2), &[Arg(3, 201), Arg(3, 258)]),
+    ((3413, false, XOR2), &[Arg(3, 202), Arg(3, 259)]),
+    ((3416, false, XOR2), &[Arg(3, 203), Arg(3, 260)]),
+    ((3419, false, XOR2), &[Arg(3, 204), Arg(3, 261)]),
+    ((3422, false, XOR2), &[Arg(3, 205), Arg(3, 262)]),
+    ((3425, false, XOR2), &[Arg(3, 206), Arg(3, 263)]),
+    ((3428, false, XOR2), &[Arg(3, 207), Arg(3, 264)]),
+    ((3431, false, XOR2), &[Arg(3, 208), Arg(3, 265)]),
+    ((3434, false, XOR2), &[Arg(3, 209), Arg(3, 266)]),
+    ((3437, false, XOR2), &[Arg(3, 210), Arg(3, 267)]),
+    ((3440, false, XOR2), &[Arg(3, 212), Arg(3, 269)]),
+    ((3443, false, XOR2), &[Arg(3, 213), Arg(3, 270)]),
+    ((3446, false, XOR2), &[Arg(3, 214), Arg(3, 271)]),
+    ((3449, false, XOR2), &[Arg(3, 215), Arg(3, 272)]),
+    ((3452, false, XOR2), &[Arg(3, 216), Arg(3, 273)]),
+    ((3455, false, XOR2), &[Arg(3, 217), Arg(3, 274)]),
+    ((3458, false, XOR2), &[Arg(3, 218), Arg(3, 275)]),
+    ((3461, false, XOR2), &[Arg(3, 219), Arg(3, 276)]),
+    ((3466, false, XOR2), &[Arg(3, 285), Arg(3, 342)]),
+    ((3469, false, XOR2), &[Arg(3, 286), Arg(3, 343)]),
+    ((3472, false, XOR2), &[Arg(3, 287), Arg(3, 344)]),
+    ((3475, false, XOR2), &[Arg(3, 288), Arg(3, 345)]),
+    ((3478, false, XOR2), &[Arg(3, 289), Arg(3, 346)]),
+    ((3481, false, XOR2), &[Arg(3, 290), Arg(3, 347)]),
+    ((3484, false, XOR2), &[Arg(3, 291), Arg(3, 348)]),
+    ((3487, false, XOR2), &[Arg(3, 292), Arg(3, 349)]),
+    ((3490, false, XOR2), &[Arg(3, 309), Arg(3, 366)]),
+    ((3493, false, XOR2), &[Arg(3, 310), Arg(3, 367)]),
+    ((3496, false, XOR2), &[Arg(3, 311), Arg(3, 368)]),
+    ((3499, false, XOR2), &[Arg(3, 312), Arg(3, 369)]),
+    ((3502, false, XOR2), &[Arg(3, 313), Arg(3, 370)]),
+    ((3505, false, XOR2), &[Arg(3, 314), Arg(3, 371)]),
+    ((3508, false, XOR2), &[Arg(3, 315), Arg(3, 372)]),
+    ((3511, false, XOR2), &[Arg(3, 316), Arg(3, 373)]),
+    ((3514, false, XOR2), &[Arg(3, 317), Arg(3, 374)]),
+    ((3517, false, XOR2), &[Arg(3, 318), Arg(3, 375)]),
+    ((3520, false, XOR2), &[Arg(3, 319), Arg(3, 376)]),
+    ((3523, false, XOR2), &[Arg(3, 320), Arg(3, 377)]),
+    ((3526, false, XOR2), &[Arg(3, 321), Arg(3, 378)]),
+    ((3529, false, XOR2), &[Arg(3, 322), Arg(3, 379)]),
+    ((3532, false, XOR2), &[Arg(3, 323), Arg(3, 380)]),
+    ((3535, false, XOR2), &[Arg(3, 324), Arg(3, 381)]),
+    ((3538, false, XOR2), &[Arg(3, 326), Arg(3, 383)]),
+    ((3541, false, XOR2), &[Arg(3, 327), Arg(3, 384)]),
+    ((3544, false, XOR2), &[Arg(3, 328), Arg(3, 385)]),
+    ((3547, false, XOR2), &[Arg(3, 329), Arg(3, 386)]),
+    ((3550, false, XOR2), &[Arg(3, 330), Arg(3, 387)]),
+    ((3553, false, XOR2), &[Arg(3, 331), Arg(3, 388)]),
+    ((3556, false, XOR2), &[Arg(3, 332), Arg(3, 389)]),
+    ((3559, false, XOR2), &[Arg(3, 333), Arg(3, 390)]),
+    ((3564, false, XOR2), &[Arg(3, 399), Arg(3, 456)]),
+    ((3567, false, XOR2), &[Arg(3, 400), Arg(3, 457)]),
+    ((3570, false, XOR2), &[Arg(3, 401), Arg(3, 458)]),
+    ((3573, false, XOR2), &[Arg(3, 402), Arg(3, 459)]),
+    ((3576, false, XOR2), &[Arg(3, 403), Arg(3, 460)]),
+    ((3579, false, XOR2), &[Arg(3, 404), Arg(3, 461)]),
+    ((3582, false, XOR2), &[Arg(3, 405), Arg(3, 462)]),
+    ((3585, false, XOR2), &[Arg(3, 406), Arg(3, 463)]),
+    ((3588, false, XOR2), &[Arg(3, 423), Arg(3, 480)]),
+    ((3591, false, XOR2), &[Arg(3, 424), Arg(3, 481)]),
+    ((3594, false, XOR2), &[Arg(3, 425), Arg(3, 482)]),
+    ((3597, false, XOR2), &[Arg(3, 426), Arg(3, 483)]),
+    ((3600, false, XOR2), &[Arg(3, 427), Arg(3, 484)]),
+    ((3603, false, XOR2), &[Arg(3, 428), Arg(3, 485)]),
+    ((3606, false, XOR2), &[Arg(3, 429), Arg(3, 486)]),
+    ((3609, false, XOR2), &[Arg(3, 430), Arg(3, 487)]),
+    ((3612, false, XOR2), &[Arg(3, 431), Arg(3, 488)]),
+    ((3615, false, XOR2), &[Arg(3, 432), Arg(3, 489)]),
+    ((3618, false, XOR2), &[Arg(3, 433), Arg(3, 490)]),
+    ((3621, false, XOR2), &[Arg(3, 434), Arg(3, 491)]),
+    ((3624, false, XOR2), &[Arg(3, 435), Arg(3, 492)]),
+    ((3627, false, XOR2), &[Arg(3, 436), Arg(3, 493)]),
+    ((3630, false, XOR2), &[Arg(3, 437), Arg(3, 494)]),
+    ((3633, false, XOR2), &[Arg(3, 438), Arg(3, 495)]),
+    ((3636, false, XOR2), &[Arg(3, 440), Arg(3, 497)]),
+    ((3639, false, XOR2), &[Arg(3, 441), Arg(3, 498)]),
+    ((3642, false, XOR2), &[Arg(3, 442), Arg(3, 499)]),
+    ((3645, false, XOR2), &[Arg(3, 443), Arg(3, 500)]),
+    ((3648, false, XOR2), &[Arg(3, 444), Arg(3, 501)]),
+    ((3651, false, XOR2), &[Arg(3, 445), Arg(3, 502)]),
+    ((3654, false, XOR2), &[Arg(3, 446), Arg(3, 503)]),
+    ((3657, false, XOR2), &[Arg(3, 447), Arg(3, 504)]),
+    ((3662, false, XOR2), &[Arg(3, 513), Arg(3, 570)]),
+    ((3665, false, XOR2), &[Arg(3, 514), Arg(3, 571)]),
+    ((3668, false, XOR2), &[Arg(3, 515), Arg(3, 572)]),
+    ((3671, false, XOR2), &[Arg(3, 516), Arg(3, 573)]),
+    ((3674, false, XOR2), &[Arg(3, 517), Arg(3, 574)]),
+    ((3677, false, XOR2), &[Arg(3, 518), Arg(3, 575)]),
+    ((3680, false, XOR2), &[Arg(3, 519), Arg(3, 576)]),
+    ((3683, false, XOR2), &[Arg(3, 520), Arg(3, 577)]),
+    ((3686, false, XOR2), &[Arg(3, 537), Arg(3, 594)]),
+    ((3689, false, XOR2), &[Arg(3, 538), Arg(3, 595)]),
+    ((3692, false, XOR2), &[Arg(3, 539), Arg(3, 596)]),
+    ((3695, false, XOR2), &[Arg(3, 540), Arg(3, 597)]),
+    ((3698, false, XOR2), &[Arg(3, 541), Arg(3, 598)]),
+    ((3701, false, XOR2), &[Arg(3, 542), Arg(3, 599)]),
+    ((3704, false, XOR2), &[Arg(3, 543), Arg(3, 600)]),
+    ((3707, false, XOR2), &[Arg(3, 544), Arg(3, 601)]),
+    ((3710, false, XOR2), &[Arg(3, 545), Arg(3, 602)]),
+    ((3713, false, XOR2), &[Arg(3, 546), Arg(3, 603)]),
+    ((3716, false, XOR2), &[Arg(3, 547), Arg(3, 604)]),
+    ((3719, false, XOR2), &[Arg(3, 548), Arg(3, 605)]),
+    ((3722, false, XOR2), &[Arg(3, 549), Arg(3, 606)]),
+    ((3725, false, XOR2), &[Arg(3, 550), Arg(3, 607)]),
+    ((3728, false, XOR2), &[Arg(3, 551), Arg(3, 608)]),
+    ((3731, false, XOR2), &[Arg(3, 552), Arg(3, 609)]),
+    ((3734, false, XOR2), &[Arg(3, 554), Arg(3, 611)]),
+    ((3737, false, XOR2), &[Arg(3, 555), Arg(3, 612)]),
+    ((3740, false, XOR2), &[Arg(3, 556), Arg(3, 613)]),
+    ((3743, false, XOR2), &[Arg(3, 557), Arg(3, 614)]),
+    ((3746, false, XOR2), &[Arg(3, 558), Arg(3, 615)]),
+    ((3749, false, XOR2), &[Arg(3, 559), Arg(3, 616)]),
+    ((3752, false, XOR2), &[Arg(3, 560), Arg(3, 617)]),
+    ((3755, false, XOR2), &[Arg(3, 561), Arg(3, 618)]),
+    ((3760, false, XOR2), &[Arg(3, 627), Arg(4, 0)]),
+    ((3763, false, XOR2), &[Arg(3, 628), Arg(4, 1)]),
+    ((3766, false, XOR2), &[Arg(3, 629), Arg(4, 2)]),
+    ((3769, false, XOR2), &[Arg(3, 630), Arg(4, 3)]),
+    ((3772, false, XOR2), &[Arg(3, 631), Arg(4, 4)]),
+    ((3775, false, XOR2), &[Arg(3, 632), Arg(4, 5)]),
+    ((3778, false, XOR2), &[Arg(3, 633), Arg(4, 6)]),
+    ((3781, false, XOR2), &[Arg(3, 634), Arg(4, 7)]),
+    ((3784, false, XOR2), &[Arg(3, 651), Arg(4, 24)]),
+    ((3787, false, XOR2), &[Arg(3, 652), Arg(4, 25)]),
+    ((3790, false, XOR2), &[Arg(3, 653), Arg(4, 26)]),
+    ((3793, false, XOR2), &[Arg(3, 654), Arg(4, 27)]),
+    ((3796, false, XOR2), &[Arg(3, 655), Arg(4, 28)]),
+    ((3799, false, XOR2), &[Arg(3, 656), Arg(4, 29)]),
+    ((3802, false, XOR2), &[Arg(3, 657), Arg(4, 30)]),
+    ((3805, false, XOR2), &[Arg(3, 658), Arg(4, 31)]),
+    ((3808, false, XOR2), &[Arg(3, 659), Arg(4, 32)]),
+    ((3811, false, XOR2), &[Arg(3, 660), Arg(4, 33)]),
+    ((3814, false, XOR2), &[Arg(3, 661), Arg(4, 34)]),
+    ((3817, false, XOR2), &[Arg(3, 662), Arg(4, 35)]),
+    ((3820, false, XOR2), &[Arg(3, 663), Arg(4, 36)]),
+    ((3823, false, XOR2), &[Arg(3, 664), Arg(4, 37)]),
+    ((3826, false, XOR2), &[Arg(3, 665), Arg(4, 38)]),
+    ((3829, false, XOR2), &[Arg(3, 666), Arg(4, 39)]),
+    ((3832, false, XOR2), &[Arg(3, 668), Arg(4, 40)]),
+    ((3835, false, XOR2), &[Arg(3, 669), Arg(4, 41)]),
+    ((3838, false, XOR2), &[Arg(3, 670), Arg(4, 42)]),
+    ((3841, false, XOR2), &[Arg(3, 671), Arg(4, 43)]),
+    ((3844, false, XOR2), &[Arg(3, 672), Arg(4, 44)]),
+    ((3847, false, XOR2), &[Arg(3, 673), Arg(4, 45)]),
+    ((3850, false, XOR2), &[Arg(3, 674), Arg(4, 46)]),
+    ((3853, false, XOR2), &[Arg(3, 675), Arg(4, 47)]),
+    ((3858, false, XOR2), &[Arg(4, 48), Arg(4, 96)]),
+    ((3861, false, XOR2), &[Arg(4, 49), Arg(4, 97)]),
+    ((3864, false, XOR2), &[Arg(4, 50), Arg(4, 98)]),
+    ((3867, false, XOR2), &[Arg(4, 51), Arg(4, 99)]),
+    ((3870, false, XOR2), &[Arg(4, 52), Arg(4, 100)]),
+    ((3873, false, XOR2), &[Arg(4, 53), Arg(4, 101)]),
+    ((3876, false, XOR2), &[Arg(4, 54), Arg(4, 102)]),
+    ((3879, false, XOR2), &[Arg(4, 55), Arg(4, 103)]),
+    ((3882, false, XOR2), &[Arg(4, 72), Arg(4, 120)]),
+    ((3885, false, XOR2), &[Arg(4, 73), Arg(4, 121)]),
+    ((3888, false, XOR2), &[Arg(4, 74), Arg(4, 122)]),
+    ((3891, false, XOR2), &[Arg(4, 75), Arg(4, 123)]),
+    ((3894, false, XOR2), &[Arg(4, 76), Arg(4, 124)]),
+    ((3897, false, XOR2), &[Arg(4, 77), Arg(4, 125)]),
+    ((3900, false, XOR2), &[Arg(4, 78), Arg(4, 126)]),
+    ((3903, false, XOR2), &[Arg(4, 79), Arg(4, 127)]),
+    ((3906, false, XOR2), &[Arg(4, 80), Arg(4, 128)]),
+    ((3909, false, XOR2), &[Arg(4, 81), Arg(4, 129)]),
+    ((3912, false, XOR2), &[Arg(4, 82), Arg(4, 130)]),
+    ((3915, false, XOR2), &[Arg(4, 83), Arg(4, 131)]),
+    ((3918, false, XOR2), &[Arg(4, 84), Arg(4, 132)]),
+    ((3921, false, XOR2), &[Arg(4, 85), Arg(4, 133)]),
+    ((3924, false, XOR2), &[Arg(4, 86), Arg(4, 134)]),
+    ((3927, false, XOR2), &[Arg(4, 87), Arg(4, 135)]),
+    ((3930, false, XOR2), &[Arg(4, 88), Arg(4, 136)]),
+    ((3933, false, XOR2), &[Arg(4, 89), Arg(4, 137)]),
+    ((3936, false, XOR2), &[Arg(4, 90), Arg(4, 138)]),
+    ((3939, false, XOR2), &[Arg(4, 91), Arg(4, 139)]),
+    ((3942, false, XOR2), &[Arg(4, 92), Arg(4, 140)]),
+    ((3945, false, XOR2), &[Arg(4, 93), Arg(4, 141)]),
+    ((3948, false, XOR2), &[Arg(4, 94), Arg(4, 142)]),
+    ((3951, false, XOR2), &[Arg(4, 95), Arg(4, 143)]),
+    ((5864, false, INV), &[Arg(0, 0)]),
+    ((5866, false, INV), &[Arg(0, 1)]),
+    ((5868, false, INV), &[Arg(0, 2)]),
+    ((5870, false, INV), &[Arg(0, 3)]),
+    ((5872, false, INV), &[Arg(0, 4)]),
+    ((5874, false, INV), &[Arg(0, 5)]),
+    ((5876, false, INV), &[Arg(0, 6)]),
+    ((5878, false, INV), &[Arg(0, 7)]),
+    ((5880, false, XOR2), &[Arg(0, 0), Arg(1, 0)]),
+    ((5881, false, XOR2), &[Arg(0, 1), Arg(1, 1)]),
+    ((5882, false, XOR2), &[Arg(0, 2), Arg(1, 2)]),
+    ((5883, false, XOR2), &[Arg(0, 3), Arg(1, 3)]),
+    ((5884, false, XOR2), &[Arg(0, 4), Arg(1, 4)]),
+    ((5885, false, XOR2), &[Arg(0, 5), Arg(1, 5)]),
+    ((5886, false, XOR2), &[Arg(0, 6), Arg(1, 6)]),
+    ((5887, false, XOR2), &[Arg(0, 7), Arg(1, 7)]),
+    ((5962, false, INV), &[Arg(1, 0)]),
+    ((5964, false, INV), &[Arg(1, 1)]),
+    ((5966, false, INV), &[Arg(1, 2)]),
+    ((5968, false, INV), &[Arg(1, 3)]),
+    ((5970, false, INV), &[Arg(1, 4)]),
+    ((5972, false, INV), &[Arg(1, 5)]),
+    ((5974, false, INV), &[Arg(1, 6)]),
+    ((6061, false, INV), &[Arg(0, 8)]),
+    ((6063, false, INV), &[Arg(0, 9)]),
+    ((6065, false, INV), &[Arg(0, 10)]),
+    ((6067, false, INV), &[Arg(0, 11)]),
+    ((6069, false, INV), &[Arg(0, 12)]),
+    ((6071, false, INV), &[Arg(0, 13)]),
+    ((6073, false, INV), &[Arg(0, 14)]),
+    ((6075, false, INV), &[Arg(0, 15)]),
+    ((6077, false, XOR2), &[Arg(0, 8), Arg(1, 8)]),
+    ((6078, false, XOR2), &[Arg(0, 9), Arg(1, 9)]),
+    ((6079, false, XOR2), &[Arg(0, 10), Arg(1, 10)]),
+    ((6080, false, XOR2), &[Arg(0, 11), Arg(1, 11)]),
+    ((6081, false, XOR2), &[Arg(0, 12), Arg(1, 12)]),
+    ((6082, false, XOR2), &[Arg(0, 13), Arg(1, 13)]),
+    ((6083, false, XOR2), &[Arg(0, 14), Arg(1, 14)]),
+    ((6084, false, XOR2), &[Arg(0, 15), Arg(1, 15)]),
+    ((6159, false, INV), &[Arg(1, 8)]),
+    ((6161, false, INV), &[Arg(1, 9)]),
+    ((6163, false, INV), &[Arg(1, 10)]),
+    ((6165, false, INV), &[Arg(1, 11)]),
+    ((6167, false, INV), &[Arg(1, 12)]),
+    ((6169, false, INV), &[Arg(1, 13)]),
+    ((6171, false, INV), &[Arg(1, 14)]),
 ];
 
-static LEVEL_1: [((usize, bool, CellType), &[GateInput]); 38] = [
-    ((390, false, INV), &[Arg(1, 1)]),
-    ((392, false, INV), &[Arg(1, 3)]),
-    ((395, false, INV), &[Arg(1, 6)]),
-    ((405, false, INV), &[Arg(1, 9)]),
-    ((648, false, INV), &[Arg(2, 9)]),
-    ((649, false, INV), &[Arg(2, 10)]),
-    ((652, false, INV), &[Arg(2, 15)]),
-    ((654, false, INV), &[Arg(2, 21)]),
-    ((660, false, INV), &[Arg(2, 67)]),
-    ((661, false, INV), &[Arg(2, 68)]),
-    ((664, false, INV), &[Arg(2, 104)]),
-    ((665, false, INV), &[Arg(2, 107)]),
-    ((666, false, INV), &[Arg(2, 110)]),
-    ((668, false, INV), &[Arg(2, 115)]),
-    ((669, false, INV), &[Arg(2, 116)]),
-    ((671, false, INV), &[Arg(2, 119)]),
-    ((2514, false, NAND2), &[Tv(412), Arg(2, 71)]),
-    ((2515, false, NAND2), &[Arg(1, 1), Tv(657)]),
-    ((2517, false, NAND2), &[Tv(389), Arg(2, 56)]),
-    ((2518, false, NAND2), &[Arg(1, 8), Tv(658)]),
-    ((2521, false, NAND2), &[Tv(406), Arg(2, 67)]),
-    ((2522, false, NAND2), &[Tv(408), Arg(2, 69)]),
-    ((2524, false, NAND2), &[Tv(407), Arg(2, 68)]),
-    ((2525, false, NAND2), &[Arg(1, 15), Tv(663)]),
-    ((2532, false, NAND2), &[Tv(404), Arg(2, 64)]),
-    ((2533, false, NAND2), &[Arg(1, 0), Tv(656)]),
-    ((2535, false, NAND2), &[Arg(1, 13), Tv(662)]),
-    ((2536, false, NAND2), &[Arg(1, 9), Tv(659)]),
-    ((2632, false, NAND2), &[Tv(410), Arg(2, 15)]),
-    ((2633, false, NAND2), &[Tv(411), Arg(2, 18)]),
-    ((2635, false, NAND2), &[Tv(394), Arg(2, 13)]),
-    ((2636, false, NAND2), &[Arg(1, 15), Tv(655)]),
-    ((2643, false, NAND2), &[Arg(1, 6), Tv(651)]),
-    ((2644, false, NAND2), &[Arg(1, 10), Tv(653)]),
-    ((2648, false, NAND2), &[Tv(412), Arg(2, 23)]),
-    ((2649, false, NAND2), &[Arg(1, 5), Tv(650)]),
-    ((2651, false, NAND2), &[Tv(408), Arg(2, 21)]),
-    ((2652, false, NAND2), &[Tv(391), Arg(2, 10)]),
+static LEVEL_1: [((usize, bool, CellType), &[GateInput]); 726] = [
+    ((59, false, INV), &[Tv(51)]),
+    ((60, false, INV), &[Tv(52)]),
+    ((62, false, INV), &[Tv(53)]),
+    ((63, false, INV), &[Tv(54)]),
+    ((65, false, INV), &[Tv(55)]),
+    ((66, false, INV), &[Tv(56)]),
+    ((68, false, INV), &[Tv(57)]),
+    ((69, false, INV), &[Tv(58)]),
+    ((82, false, INV), &[Tv(74)]),
+    ((83, false, INV), &[Tv(75)]),
+    ((85, false, INV), &[Tv(76)]),
+    ((86, false, INV), &[Tv(77)]),
+    ((88, false, INV), &[Tv(78)]),
+    ((89, false, INV), &[Tv(79)]),
+    ((91, false, INV), &[Tv(80)]),
+    ((92, false, INV), &[Tv(81)]),
+    ((102, false, AND2), &[Tv(98), Tv(99)]),
+    ((103, false, AND2), &[Tv(100), Tv(101)]),
+    ((114, false, INV), &[Tv(106)]),
+    ((115, false, INV), &[Tv(107)]),
+    ((117, false, INV), &[Tv(108)]),
+    ((118, false, INV), &[Tv(109)]),
+    ((120, false, INV), &[Tv(110)]),
+    ((121, false, INV), &[Tv(111)]),
+    ((123, false, INV), &[Tv(112)]),
+    ((124, false, INV), &[Tv(113)]),
+    ((137, false, INV), &[Tv(129)]),
+    ((138, false, INV), &[Tv(130)]),
+    ((140, false, INV), &[Tv(131)]),
+    ((141, false, INV), &[Tv(132)]),
+    ((143, false, INV), &[Tv(133)]),
+    ((144, false, INV), &[Tv(134)]),
+    ((146, false, INV), &[Tv(135)]),
+    ((147, false, INV), &[Tv(136)]),
+    ((157, false, AND2), &[Tv(153), Tv(154)]),
+    ((158, false, AND2), &[Tv(155), Tv(156)]),
+    ((169, false, INV), &[Tv(161)]),
+    ((170, false, INV), &[Tv(162)]),
+    ((172, false, INV), &[Tv(163)]),
+    ((173, false, INV), &[Tv(164)]),
+    ((175, false, INV), &[Tv(165)]),
+    ((176, false, INV), &[Tv(166)]),
+    ((178, false, INV), &[Tv(167)]),
+    ((179, false, INV), &[Tv(168)]),
+    ((192, false, INV), &[Tv(184)]),
+    ((193, false, INV), &[Tv(185)]),
+    ((195, false, INV), &[Tv(186)]),
+    ((196, false, INV), &[Tv(187)]),
+    ((198, false, INV), &[Tv(188)]),
+    ((199, false, INV), &[Tv(189)]),
+    ((201, false, INV), &[Tv(190)]),
+    ((202, false, INV), &[Tv(191)]),
+    ((212, false, AND2), &[Tv(208), Tv(209)]),
+    ((213, false, AND2), &[Tv(210), Tv(211)]),
+    ((224, false, INV), &[Tv(216)]),
+    ((225, false, INV), &[Tv(217)]),
+    ((227, false, INV), &[Tv(218)]),
+    ((228, false, INV), &[Tv(219)]),
+    ((230, false, INV), &[Tv(220)]),
+    ((231, false, INV), &[Tv(221)]),
+    ((233, false, INV), &[Tv(222)]),
+    ((234, false, INV), &[Tv(223)]),
+    ((247, false, INV), &[Tv(239)]),
+    ((248, false, INV), &[Tv(240)]),
+    ((250, false, INV), &[Tv(241)]),
+    ((251, false, INV), &[Tv(242)]),
+    ((253, false, INV), &[Tv(243)]),
+    ((254, false, INV), &[Tv(244)]),
+    ((256, false, INV), &[Tv(245)]),
+    ((257, false, INV), &[Tv(246)]),
+    ((267, false, AND2), &[Tv(263), Tv(264)]),
+    ((268, false, AND2), &[Tv(265), Tv(266)]),
+    ((279, false, INV), &[Tv(271)]),
+    ((280, false, INV), &[Tv(272)]),
+    ((282, false, INV), &[Tv(273)]),
+    ((283, false, INV), &[Tv(274)]),
+    ((285, false, INV), &[Tv(275)]),
+    ((286, false, INV), &[Tv(276)]),
+    ((288, false, INV), &[Tv(277)]),
+    ((289, false, INV), &[Tv(278)]),
+    ((302, false, INV), &[Tv(294)]),
+    ((303, false, INV), &[Tv(295)]),
+    ((305, false, INV), &[Tv(296)]),
+    ((306, false, INV), &[Tv(297)]),
+    ((308, false, INV), &[Tv(298)]),
+    ((309, false, INV), &[Tv(299)]),
+    ((311, false, INV), &[Tv(300)]),
+    ((312, false, INV), &[Tv(301)]),
+    ((322, false, AND2), &[Tv(318), Tv(319)]),
+    ((323, false, AND2), &[Tv(320), Tv(321)]),
+    ((334, false, INV), &[Tv(326)]),
+    ((335, false, INV), &[Tv(327)]),
+    ((337, false, INV), &[Tv(328)]),
+    ((338, false, INV), &[Tv(329)]),
+    ((340, false, INV), &[Tv(330)]),
+    ((341, false, INV), &[Tv(331)]),
+    ((343, false, INV), &[Tv(332)]),
+    ((344, false, INV), &[Tv(333)]),
+    ((357, false, INV), &[Tv(349)]),
+    ((358, false, INV), &[Tv(350)]),
+    ((360, false, INV), &[Tv(351)]),
+    ((361, false, INV), &[Tv(352)]),
+    ((363, false, INV), &[Tv(353)]),
+    ((364, false, INV), &[Tv(354)]),
+    ((366, false, INV), &[Tv(355)]),
+    ((367, false, INV), &[Tv(356)]),
+    ((377, false, AND2), &[Tv(373), Tv(374)]),
+    ((378, false, AND2), &[Tv(375), Tv(376)]),
+    ((389, false, INV), &[Tv(381)]),
+    ((390, false, INV), &[Tv(382)]),
+    ((392, false, INV), &[Tv(383)]),
+    ((393, false, INV), &[Tv(384)]),
+    ((395, false, INV), &[Tv(385)]),
+    ((396, false, INV), &[Tv(386)]),
+    ((398, false, INV), &[Tv(387)]),
+    ((399, false, INV), &[Tv(388)]),
+    ((412, false, INV), &[Tv(404)]),
+    ((413, false, INV), &[Tv(405)]),
+    ((415, false, INV), &[Tv(406)]),
+    ((416, false, INV), &[Tv(407)]),
+    ((418, false, INV), &[Tv(408)]),
+    ((419, false, INV), &[Tv(409)]),
+    ((421, false, INV), &[Tv(410)]),
+    ((422, false, INV), &[Tv(411)]),
+    ((432, false, AND2), &[Tv(428), Tv(429)]),
+    ((433, false, AND2), &[Tv(430), Tv(431)]),
+    ((444, false, INV), &[Tv(436)]),
+    ((445, false, INV), &[Tv(437)]),
+    ((447, false, INV), &[Tv(438)]),
+    ((448, false, INV), &[Tv(439)]),
+    ((450, false, INV), &[Tv(440)]),
+    ((451, false, INV), &[Tv(441)]),
+    ((453, false, INV), &[Tv(442)]),
+    ((454, false, INV), &[Tv(443)]),
+    ((467, false, INV), &[Tv(459)]),
+    ((468, false, INV), &[Tv(460)]),
+    ((470, false, INV), &[Tv(461)]),
+    ((471, false, INV), &[Tv(462)]),
+    ((473, false, INV), &[Tv(463)]),
+    ((474, false, INV), &[Tv(464)]),
+    ((476, false, INV), &[Tv(465)]),
+    ((477, false, INV), &[Tv(466)]),
+    ((487, false, AND2), &[Tv(483), Tv(484)]),
+    ((488, false, AND2), &[Tv(485), Tv(486)]),
+    ((499, false, INV), &[Tv(491)]),
+    ((500, false, INV), &[Tv(492)]),
+    ((502, false, INV), &[Tv(493)]),
+    ((503, false, INV), &[Tv(494)]),
+    ((505, false, INV), &[Tv(495)]),
+    ((506, false, INV), &[Tv(496)]),
+    ((508, false, INV), &[Tv(497)]),
+    ((509, false, INV), &[Tv(498)]),
+    ((522, false, INV), &[Tv(514)]),
+    ((523, false, INV), &[Tv(515)]),
+    ((525, false, INV), &[Tv(516)]),
+    ((526, false, INV), &[Tv(517)]),
+    ((528, false, INV), &[Tv(518)]),
+    ((529, false, INV), &[Tv(519)]),
+    ((531, false, INV), &[Tv(520)]),
+    ((532, false, INV), &[Tv(521)]),
+    ((542, false, AND2), &[Tv(538), Tv(539)]),
+    ((543, false, AND2), &[Tv(540), Tv(541)]),
+    ((554, false, INV), &[Tv(546)]),
+    ((555, false, INV), &[Tv(547)]),
+    ((557, false, INV), &[Tv(548)]),
+    ((558, false, INV), &[Tv(549)]),
+    ((560, false, INV), &[Tv(550)]),
+    ((561, false, INV), &[Tv(551)]),
+    ((563, false, INV), &[Tv(552)]),
+    ((564, false, INV), &[Tv(553)]),
+    ((577, false, INV), &[Tv(569)]),
+    ((578, false, INV), &[Tv(570)]),
+    ((580, false, INV), &[Tv(571)]),
+    ((581, false, INV), &[Tv(572)]),
+    ((583, false, INV), &[Tv(573)]),
+    ((584, false, INV), &[Tv(574)]),
+    ((586, false, INV), &[Tv(575)]),
+    ((587, false, INV), &[Tv(576)]),
+    ((597, false, AND2), &[Tv(593), Tv(594)]),
+    ((598, false, AND2), &[Tv(595), Tv(596)]),
+    ((609, false, INV), &[Tv(601)]),
+    ((610, false, INV), &[Tv(602)]),
+    ((612, false, INV), &[Tv(603)]),
+    ((613, false, INV), &[Tv(604)]),
+    ((615, false, INV), &[Tv(605)]),
+    ((616, false, INV), &[Tv(606)]),
+    ((618, false, INV), &[Tv(607)]),
+    ((619, false, INV), &[Tv(608)]),
+    ((632, false, INV), &[Tv(624)]),
+    ((633, false, INV), &[Tv(625)]),
+    ((635, false, INV), &[Tv(626)]),
+    ((636, false, INV), &[Tv(627)]),
+    ((638, false, INV), &[Tv(628)]),
+    ((639, false, INV), &[Tv(629)]),
+    ((641, false, INV), &[Tv(630)]),
+    ((642, false, INV), &[Tv(631)]),
+    ((652, false, AND2), &[Tv(648), Tv(649)]),
+    ((653, false, AND2), &[Tv(650), Tv(651)]),
+    ((664, false, INV), &[Tv(656)]),
+    ((665, false, INV), &[Tv(657)]),
+    ((667, false, INV), &[Tv(658)]),
+    ((668, false, INV), &[Tv(659)]),
+    ((670, false, INV), &[Tv(660)]),
+    ((671, false, INV), &[Tv(661)]),
+    ((673, false, INV), &[Tv(662)]),
+    ((674, false, INV), &[Tv(663)]),
+    ((687, false, INV), &[Tv(679)]),
+    ((688, false, INV), &[Tv(680)]),
+    ((690, false, INV), &[Tv(681)]),
+    ((691, false, INV), &[Tv(682)]),
+    ((693, false, INV), &[Tv(683)]),
+    ((694, false, INV), &[Tv(684)]),
+    ((696, false, INV), &[Tv(685)]),
+    ((697, false, INV), &[Tv(686)]),
+    ((707, false, AND2), &[Tv(703), Tv(704)]),
+    ((708, false, AND2), &[Tv(705), Tv(706)]),
+    ((719, false, INV), &[Tv(711)]),
+    ((720, false, INV), &[Tv(712)]),
+    ((722, false, INV), &[Tv(713)]),
+    ((723, false, INV), &[Tv(714)]),
+    ((725, false, INV), &[Tv(715)]),
+    ((726, false, INV), &[Tv(716)]),
+    ((728, false, INV), &[Tv(717)]),
+    ((729, false, INV), &[Tv(718)]),
+    ((742, false, INV), &[Tv(734)]),
+    ((743, false, INV), &[Tv(735)]),
+    ((745, false, INV), &[Tv(736)]),
+    ((746, false, INV), &[Tv(737)]),
+    ((748, false, INV), &[Tv(738)]),
+    ((749, false, INV), &[Tv(739)]),
+    ((751, false, INV), &[Tv(740)]),
+    ((752, false, INV), &[Tv(741)]),
+    ((762, false, AND2), &[Tv(758), Tv(759)]),
+    ((763, false, AND2), &[Tv(760), Tv(761)]),
+    ((774, false, INV), &[Tv(766)]),
+    ((775, false, INV), &[Tv(767)]),
+    ((777, false, INV), &[Tv(768)]),
+    ((778, false, INV), &[Tv(769)]),
+    ((780, false, INV), &[Tv(770)]),
+    ((781, false, INV), &[Tv(771)]),
+    ((783, false, INV), &[Tv(772)]),
+    ((784, false, INV), &[Tv(773)]),
+    ((797, false, INV), &[Tv(789)]),
+    ((798, false, INV), &[Tv(790)]),
+    ((800, false, INV), &[Tv(791)]),
+    ((801, false, INV), &[Tv(792)]),
+    ((803, false, INV), &[Tv(793)]),
+    ((804, false, INV), &[Tv(794)]),
+    ((806, false, INV), &[Tv(795)]),
+    ((807, false, INV), &[Tv(796)]),
+    ((817, false, AND2), &[Tv(813), Tv(814)]),
+    ((818, false, AND2), &[Tv(815), Tv(816)]),
+    ((829, false, INV), &[Tv(821)]),
+    ((830, false, INV), &[Tv(822)]),
+    ((832, false, INV), &[Tv(823)]),
+    ((833, false, INV), &[Tv(824)]),
+    ((835, false, INV), &[Tv(825)]),
+    ((836, false, INV), &[Tv(826)]),
+    ((838, false, INV), &[Tv(827)]),
+    ((839, false, INV), &[Tv(828)]),
+    ((852, false, INV), &[Tv(844)]),
+    ((853, false, INV), &[Tv(845)]),
+    ((855, false, INV), &[Tv(846)]),
+    ((856, false, INV), &[Tv(847)]),
+    ((858, false, INV), &[Tv(848)]),
+    ((859, false, INV), &[Tv(849)]),
+    ((861, false, INV), &[Tv(850)]),
+    ((862, false, INV), &[Tv(851)]),
+    ((872, false, AND2), &[Tv(868), Tv(869)]),
+    ((873, false, AND2), &[Tv(870), Tv(871)]),
+    ((884, false, INV), &[Tv(876)]),
+    ((885, false, INV), &[Tv(877)]),
+    ((887, false, INV), &[Tv(878)]),
+    ((888, false, INV), &[Tv(879)]),
+    ((890, false, INV), &[Tv(880)]),
+    ((891, false, INV), &[Tv(881)]),
+    ((893, false, INV), &[Tv(882)]),
+    ((894, false, INV), &[Tv(883)]),
+    ((907, false, INV), &[Tv(899)]),
+    ((908, false, INV), &[Tv(900)]),
+    ((910, false, INV), &[Tv(901)]),
+    ((911, false, INV), &[Tv(902)]),
+    ((913, false, INV), &[Tv(903)]),
+    ((914, false, INV), &[Tv(904)]),
+    ((916, false, INV), &[Tv(905)]),
+    ((917, false, INV), &[Tv(906)]),
+    ((927, false, AND2), &[Tv(923), Tv(924)]),
+    ((928, false, AND2), &[Tv(925), Tv(926)]),
+    ((939, false, INV), &[Tv(931)]),
+    ((940, false, INV), &[Tv(932)]),
+    ((942, false, INV), &[Tv(933)]),
+    ((943, false, INV), &[Tv(934)]),
+    ((945, false, INV), &[Tv(935)]),
+    ((946, false, INV), &[Tv(936)]),
+    ((948, false, INV), &[Tv(937)]),
+    ((949, false, INV), &[Tv(938)]),
+    ((962, false, INV), &[Tv(954)]),
+    ((963, false, INV), &[Tv(955)]),
+    ((965, false, INV), &[Tv(956)]),
+    ((966, false, INV), &[Tv(957)]),
+    ((968, false, INV), &[Tv(958)]),
+    ((969, false, INV), &[Tv(959)]),
+    ((971, false, INV), &[Tv(960)]),
+    ((972, false, INV), &[Tv(961)]),
+    ((982, false, AND2), &[Tv(978), Tv(979)]),
+    ((983, false, AND2), &[Tv(980), Tv(981)]),
+    ((994, false, INV), &[Tv(986)]),
+    ((995, false, INV), &[Tv(987)]),
+    ((997, false, INV), &[Tv(988)]),
+    ((998, false, INV), &[Tv(989)]),
+    ((1000, false, INV), &[Tv(990)]),
+    ((1001, false, INV), &[Tv(991)]),
+    ((1003, false, INV), &[Tv(992)]),
+    ((1004, false, INV), &[Tv(993)]),
+    ((1017, false, INV), &[Tv(1009)]),
+    ((1018, false, INV), &[Tv(1010)]),
+    ((1020, false, INV), &[Tv(1011)]),
+    ((1021, false, INV), &[Tv(1012)]),
+    ((1023, false, INV), &[Tv(1013)]),
+    ((1024, false, INV), &[Tv(1014)]),
+    ((1026, false, INV), &[Tv(1015)]),
+    ((1027, false, INV), &[Tv(1016)]),
+    ((1037, false, AND2), &[Tv(1033), Tv(1034)]),
+    ((1038, false, AND2), &[Tv(1035), Tv(1036)]),
+    ((1049, false, INV), &[Tv(1041)]),
+    ((1050, false, INV), &[Tv(1042)]),
+    ((1052, false, INV), &[Tv(1043)]),
+    ((1053, false, INV), &[Tv(1044)]),
+    ((1055, false, INV), &[Tv(1045)]),
+    ((1056, false, INV), &[Tv(1046)]),
+    ((1058, false, INV), &[Tv(1047)]),
+    ((1059, false, INV), &[Tv(1048)]),
+    ((1072, false, INV), &[Tv(1064)]),
+    ((1073, false, INV), &[Tv(1065)]),
+    ((1075, false, INV), &[Tv(1066)]),
+    ((1076, false, INV), &[Tv(1067)]),
+    ((1078, false, INV), &[Tv(1068)]),
+    ((1079, false, INV), &[Tv(1069)]),
+    ((1081, false, INV), &[Tv(1070)]),
+    ((1082, false, INV), &[Tv(1071)]),
+    ((1092, false, AND2), &[Tv(1088), Tv(1089)]),
+    ((1093, false, AND2), &[Tv(1090), Tv(1091)]),
+    ((1104, false, INV), &[Tv(1096)]),
+    ((1105, false, INV), &[Tv(1097)]),
+    ((1107, false, INV), &[Tv(1098)]),
+    ((1108, false, INV), &[Tv(1099)]),
+    ((1110, false, INV), &[Tv(1100)]),
+    ((1111, false, INV), &[Tv(1101)]),
+    ((1113, false, INV), &[Tv(1102)]),
+    ((1114, false, INV), &[Tv(1103)]),
+    ((1127, false, INV), &[Tv(1119)]),
+    ((1128, false, INV), &[Tv(1120)]),
+    ((1130, false, INV), &[Tv(1121)]),
+    ((1131, false, INV), &[Tv(1122)]),
+    ((1133, false, INV), &[Tv(1123)]),
+    ((1134, false, INV), &[Tv(1124)]),
+    ((1136, false, INV), &[Tv(1125)]),
+    ((1137, false, INV), &[Tv(1126)]),
+    ((1147, false, AND2), &[Tv(1143), Tv(1144)]),
+    ((1148, false, AND2), &[Tv(1145), Tv(1146)]),
+    ((1159, false, INV), &[Tv(1151)]),
+    ((1160, false, INV), &[Tv(1152)]),
+    ((1162, false, INV), &[Tv(1153)]),
+    ((1163, false, INV), &[Tv(1154)]),
+    ((1165, false, INV), &[Tv(1155)]),
+    ((1166, false, INV), &[Tv(1156)]),
+    ((1168, false, INV), &[Tv(1157)]),
+    ((1169, false, INV), &[Tv(1158)]),
+    ((1182, false, INV), &[Tv(1174)]),
+    ((1183, false, INV), &[Tv(1175)]),
+    ((1185, false, INV), &[Tv(1176)]),
+    ((1186, false, INV), &[Tv(1177)]),
+    ((1188, false, INV), &[Tv(1178)]),
+    ((1189, false, INV), &[Tv(1179)]),
+    ((1191, false, INV), &[Tv(1180)]),
+    ((1192, false, INV), &[Tv(1181)]),
+    ((1202, false, AND2), &[Tv(1198), Tv(1199)]),
+    ((1203, false, AND2), &[Tv(1200), Tv(1201)]),
+    ((1214, false, INV), &[Tv(1206)]),
+    ((1215, false, INV), &[Tv(1207)]),
+    ((1217, false, INV), &[Tv(1208)]),
+    ((1218, false, INV), &[Tv(1209)]),
+    ((1220, false, INV), &[Tv(1210)]),
+    ((1221, false, INV), &[Tv(1211)]),
+    ((1223, false, INV), &[Tv(1212)]),
+    ((1224, false, INV), &[Tv(1213)]),
+    ((1237, false, INV), &[Tv(1229)]),
+    ((1238, false, INV), &[Tv(1230)]),
+    ((1240, false, INV), &[Tv(1231)]),
+    ((1241, false, INV), &[Tv(1232)]),
+    ((1243, false, INV), &[Tv(1233)]),
+    ((1244, false, INV), &[Tv(1234)]),
+    ((1246, false, INV), &[Tv(1235)]),
+    ((1247, false, INV), &[Tv(1236)]),
+    ((1257, false, AND2), &[Tv(1253), Tv(1254)]),
+    ((1258, false, AND2), &[Tv(1255), Tv(1256)]),
+    ((1269, false, INV), &[Tv(1261)]),
+    ((1270, false, INV), &[Tv(1262)]),
+    ((1272, false, INV), &[Tv(1263)]),
+    ((1273, false, INV), &[Tv(1264)]),
+    ((1275, false, INV), &[Tv(1265)]),
+    ((1276, false, INV), &[Tv(1266)]),
+    ((1278, false, INV), &[Tv(1267)]),
+    ((1279, false, INV), &[Tv(1268)]),
+    ((1292, false, INV), &[Tv(1284)]),
+    ((1293, false, INV), &[Tv(1285)]),
+    ((1295, false, INV), &[Tv(1286)]),
+    ((1296, false, INV), &[Tv(1287)]),
+    ((1298, false, INV), &[Tv(1288)]),
+    ((1299, false, INV), &[Tv(1289)]),
+    ((1301, false, INV), &[Tv(1290)]),
+    ((1302, false, INV), &[Tv(1291)]),
+    ((1312, false, AND2), &[Tv(1308), Tv(1309)]),
+    ((1313, false, AND2), &[Tv(1310), Tv(1311)]),
+    ((1324, false, INV), &[Tv(1316)]),
+    ((1325, false, INV), &[Tv(1317)]),
+    ((1327, false, INV), &[Tv(1318)]),
+    ((1328, false, INV), &[Tv(1319)]),
+    ((1330, false, INV), &[Tv(1320)]),
+    ((1331, false, INV), &[Tv(1321)]),
+    ((1333, false, INV), &[Tv(1322)]),
+    ((1334, false, INV), &[Tv(1323)]),
+    ((1347, false, INV), &[Tv(1339)]),
+    ((1348, false, INV), &[Tv(1340)]),
+    ((1350, false, INV), &[Tv(1341)]),
+    ((1351, false, INV), &[Tv(1342)]),
+    ((1353, false, INV), &[Tv(1343)]),
+    ((1354, false, INV), &[Tv(1344)]),
+    ((1356, false, INV), &[Tv(1345)]),
+    ((1357, false, INV), &[Tv(1346)]),
+    ((1373, false, INV), &[Tv(1365)]),
+    ((1374, false, INV), &[Tv(1366)]),
+    ((1376, false, INV), &[Tv(1367)]),
+    ((1377, false, INV), &[Tv(1368)]),
+    ((1379, false, INV), &[Tv(1369)]),
+    ((1380, false, INV), &[Tv(1370)]),
+    ((1382, false, INV), &[Tv(1371)]),
+    ((1383, false, INV), &[Tv(1372)]),
+    ((1396, false, INV), &[Tv(1388)]),
+    ((1397, false, INV), &[Tv(1389)]),
+    ((1399, false, INV), &[Tv(1390)]),
+    ((1400, false, INV), &[Tv(1391)]),
+    ((1402, false, INV), &[Tv(1392)]),
+    ((1403, false, INV), &[Tv(1393)]),
+    ((1405, false, INV), &[Tv(1394)]),
+    ((1406, false, INV), &[Tv(1395)]),
+    ((1422, false, INV), &[Tv(1414)]),
+    ((1423, false, INV), &[Tv(1415)]),
+    ((1425, false, INV), &[Tv(1416)]),
+    ((1426, false, INV), &[Tv(1417)]),
+    ((1428, false, INV), &[Tv(1418)]),
+    ((1429, false, INV), &[Tv(1419)]),
+    ((1431, false, INV), &[Tv(1420)]),
+    ((1432, false, INV), &[Tv(1421)]),
+    ((1445, false, INV), &[Tv(1437)]),
+    ((1446, false, INV), &[Tv(1438)]),
+    ((1448, false, INV), &[Tv(1439)]),
+    ((1449, false, INV), &[Tv(1440)]),
+    ((1451, false, INV), &[Tv(1441)]),
+    ((1452, false, INV), &[Tv(1442)]),
+    ((1454, false, INV), &[Tv(1443)]),
+    ((1455, false, INV), &[Tv(1444)]),
+    ((1471, false, INV), &[Tv(1463)]),
+    ((1472, false, INV), &[Tv(1464)]),
+    ((1474, false, INV), &[Tv(1465)]),
+    ((1475, false, INV), &[Tv(1466)]),
+    ((1477, false, INV), &[Tv(1467)]),
+    ((1478, false, INV), &[Tv(1468)]),
+    ((1480, false, INV), &[Tv(1469)]),
+    ((1481, false, INV), &[Tv(1470)]),
+    ((1494, false, INV), &[Tv(1486)]),
+    ((1495, false, INV), &[Tv(1487)]),
+    ((1497, false, INV), &[Tv(1488)]),
+    ((1498, false, INV), &[Tv(1489)]),
+    ((1500, false, INV), &[Tv(1490)]),
+    ((1501, false, INV), &[Tv(1491)]),
+    ((1503, false, INV), &[Tv(1492)]),
+    ((1504, false, INV), &[Tv(1493)]),
+    ((1520, false, INV), &[Tv(1512)]),
+    ((1521, false, INV), &[Tv(1513)]),
+    ((1523, false, INV), &[Tv(1514)]),
+    ((1524, false, INV), &[Tv(1515)]),
+    ((1526, false, INV), &[Tv(1516)]),
+    ((1527, false, INV), &[Tv(1517)]),
+    ((1529, false, INV), &[Tv(1518)]),
+    ((1530, false, INV), &[Tv(1519)]),
+    ((1543, false, INV), &[Tv(1535)]),
+    ((1544, false, INV), &[Tv(1536)]),
+    ((1546, false, INV), &[Tv(1537)]),
+    ((1547, false, INV), &[Tv(1538)]),
+    ((1549, false, INV), &[Tv(1539)]),
+    ((1550, false, INV), &[Tv(1540)]),
+    ((1552, false, INV), &[Tv(1541)]),
+    ((1553, false, INV), &[Tv(1542)]),
+    ((1569, false, INV), &[Tv(1561)]),
+    ((1570, false, INV), &[Tv(1562)]),
+    ((1572, false, INV), &[Tv(1563)]),
+    ((1573, false, INV), &[Tv(1564)]),
+    ((1575, false, INV), &[Tv(1565)]),
+    ((1576, false, INV), &[Tv(1566)]),
+    ((1578, false, INV), &[Tv(1567)]),
+    ((1579, false, INV), &[Tv(1568)]),
+    ((1592, false, INV), &[Tv(1584)]),
+    ((1593, false, INV), &[Tv(1585)]),
+    ((1595, false, INV), &[Tv(1586)]),
+    ((1596, false, INV), &[Tv(1587)]),
+    ((1598, false, INV), &[Tv(1588)]),
+    ((1599, false, INV), &[Tv(1589)]),
+    ((1601, false, INV), &[Tv(1590)]),
+    ((1602, false, INV), &[Tv(1591)]),
+    ((1618, false, INV), &[Tv(1610)]),
+    ((1619, false, INV), &[Tv(1611)]),
+    ((1621, false, INV), &[Tv(1612)]),
+    ((1622, false, INV), &[Tv(1613)]),
+    ((1624, false, INV), &[Tv(1614)]),
+    ((1625, false, INV), &[Tv(1615)]),
+    ((1627, false, INV), &[Tv(1616)]),
+    ((1628, false, INV), &[Tv(1617)]),
+    ((1641, false, INV), &[Tv(1633)]),
+    ((1642, false, INV), &[Tv(1634)]),
+    ((1644, false, INV), &[Tv(1635)]),
+    ((1645, false, INV), &[Tv(1636)]),
+    ((1647, false, INV), &[Tv(1637)]),
+    ((1648, false, INV), &[Tv(1638)]),
+    ((1650, false, INV), &[Tv(1639)]),
+    ((1651, false, INV), &[Tv(1640)]),
+    ((1667, false, INV), &[Tv(1659)]),
+    ((1668, false, INV), &[Tv(1660)]),
+    ((1670, false, INV), &[Tv(1661)]),
+    ((1671, false, INV), &[Tv(1662)]),
+    ((1673, false, INV), &[Tv(1663)]),
+    ((1674, false, INV), &[Tv(1664)]),
+    ((1676, false, INV), &[Tv(1665)]),
+    ((1677, false, INV), &[Tv(1666)]),
+    ((1690, false, INV), &[Tv(1682)]),
+    ((1691, false, INV), &[Tv(1683)]),
+    ((1693, false, INV), &[Tv(1684)]),
+    ((1694, false, INV), &[Tv(1685)]),
+    ((1696, false, INV), &[Tv(1686)]),
+    ((1697, false, INV), &[Tv(1687)]),
+    ((1699, false, INV), &[Tv(1688)]),
+    ((1700, false, INV), &[Tv(1689)]),
+    ((1716, false, INV), &[Tv(1708)]),
+    ((1717, false, INV), &[Tv(1709)]),
+    ((1719, false, INV), &[Tv(1710)]),
+    ((1720, false, INV), &[Tv(1711)]),
+    ((1722, false, INV), &[Tv(1712)]),
+    ((1723, false, INV), &[Tv(1713)]),
+    ((1725, false, INV), &[Tv(1714)]),
+    ((1726, false, INV), &[Tv(1715)]),
+    ((1739, false, INV), &[Tv(1731)]),
+    ((1740, false, INV), &[Tv(1732)]),
+    ((1742, false, INV), &[Tv(1733)]),
+    ((1743, false, INV), &[Tv(1734)]),
+    ((1745, false, INV), &[Tv(1735)]),
+    ((1746, false, INV), &[Tv(1736)]),
+    ((1748, false, INV), &[Tv(1737)]),
+    ((1749, false, INV), &[Tv(1738)]),
+    ((1765, false, INV), &[Tv(1757)]),
+    ((1766, false, INV), &[Tv(1758)]),
+    ((1768, false, INV), &[Tv(1759)]),
+    ((1769, false, INV), &[Tv(1760)]),
+    ((1771, false, INV), &[Tv(1761)]),
+    ((1772, false, INV), &[Tv(1762)]),
+    ((1774, false, INV), &[Tv(1763)]),
+    ((1775, false, INV), &[Tv(1764)]),
+    ((1788, false, INV), &[Tv(1780)]),
+    ((1789, false, INV), &[Tv(1781)]),
+    ((1791, false, INV), &[Tv(1782)]),
+    ((1792, false, INV), &[Tv(1783)]),
+    ((1794, false, INV), &[Tv(1784)]),
+    ((1795, false, INV), &[Tv(1785)]),
+    ((1797, false, INV), &[Tv(1786)]),
+    ((1798, false, INV), &[Tv(1787)]),
+    ((1814, false, INV), &[Tv(1806)]),
+    ((1815, false, INV), &[Tv(1807)]),
+    ((1817, false, INV), &[Tv(1808)]),
+    ((1818, false, INV), &[Tv(1809)]),
+    ((1820, false, INV), &[Tv(1810)]),
+    ((1821, false, INV), &[Tv(1811)]),
+    ((1823, false, INV), &[Tv(1812)]),
+    ((1824, false, INV), &[Tv(1813)]),
+    ((1837, false, INV), &[Tv(1829)]),
+    ((1838, false, INV), &[Tv(1830)]),
+    ((1840, false, INV), &[Tv(1831)]),
+    ((1841, false, INV), &[Tv(1832)]),
+    ((1843, false, INV), &[Tv(1833)]),
+    ((1844, false, INV), &[Tv(1834)]),
+    ((1846, false, INV), &[Tv(1835)]),
+    ((1847, false, INV), &[Tv(1836)]),
+    ((1863, false, INV), &[Tv(1855)]),
+    ((1864, false, INV), &[Tv(1856)]),
+    ((1866, false, INV), &[Tv(1857)]),
+    ((1867, false, INV), &[Tv(1858)]),
+    ((1869, false, INV), &[Tv(1859)]),
+    ((1870, false, INV), &[Tv(1860)]),
+    ((1872, false, INV), &[Tv(1861)]),
+    ((1873, false, INV), &[Tv(1862)]),
+    ((1886, false, INV), &[Tv(1878)]),
+    ((1887, false, INV), &[Tv(1879)]),
+    ((1889, false, INV), &[Tv(1880)]),
+    ((1890, false, INV), &[Tv(1881)]),
+    ((1892, false, INV), &[Tv(1882)]),
+    ((1893, false, INV), &[Tv(1883)]),
+    ((1895, false, INV), &[Tv(1884)]),
+    ((1896, false, INV), &[Tv(1885)]),
+    ((1912, false, INV), &[Tv(1904)]),
+    ((1913, false, INV), &[Tv(1905)]),
+    ((1915, false, INV), &[Tv(1906)]),
+    ((1916, false, INV), &[Tv(1907)]),
+    ((1918, false, INV), &[Tv(1908)]),
+    ((1919, false, INV), &[Tv(1909)]),
+    ((1921, false, INV), &[Tv(1910)]),
+    ((1922, false, INV), &[Tv(1911)]),
+    ((1935, false, INV), &[Tv(1927)]),
+    ((1936, false, INV), &[Tv(1928)]),
+    ((1938, false, INV), &[Tv(1929)]),
+    ((1939, false, INV), &[Tv(1930)]),
+    ((1941, false, INV), &[Tv(1931)]),
+    ((1942, false, INV), &[Tv(1932)]),
+    ((1944, false, INV), &[Tv(1933)]),
+    ((1945, false, INV), &[Tv(1934)]),
+    ((1959, false, INV), &[Tv(1951)]),
+    ((1960, false, INV), &[Tv(1952)]),
+    ((1962, false, INV), &[Tv(1953)]),
+    ((1963, false, INV), &[Tv(1954)]),
+    ((1965, false, INV), &[Tv(1955)]),
+    ((1966, false, INV), &[Tv(1956)]),
+    ((1968, false, INV), &[Tv(1957)]),
+    ((1969, false, INV), &[Tv(1958)]),
+    ((1982, false, INV), &[Tv(1974)]),
+    ((1983, false, INV), &[Tv(1975)]),
+    ((1985, false, INV), &[Tv(1976)]),
+    ((1986, false, INV), &[Tv(1977)]),
+    ((1988, false, INV), &[Tv(1978)]),
+    ((1989, false, INV), &[Tv(1979)]),
+    ((1991, false, INV), &[Tv(1980)]),
+    ((1992, false, INV), &[Tv(1981)]),
+    ((2006, false, INV), &[Tv(1998)]),
+    ((2007, false, INV), &[Tv(1999)]),
+    ((2009, false, INV), &[Tv(2000)]),
+    ((2010, false, INV), &[Tv(2001)]),
+    ((2012, false, INV), &[Tv(2002)]),
+    ((2013, false, INV), &[Tv(2003)]),
+    ((2015, false, INV), &[Tv(2004)]),
+    ((2016, false, INV), &[Tv(2005)]),
+    ((2029, false, INV), &[Tv(2021)]),
+    ((2030, false, INV), &[Tv(2022)]),
+    ((2032, false, INV), &[Tv(2023)]),
+    ((2033, false, INV), &[Tv(2024)]),
+    ((2035, false, INV), &[Tv(2025)]),
+    ((2036, false, INV), &[Tv(2026)]),
+    ((2038, false, INV), &[Tv(2027)]),
+    ((2039, false, INV), &[Tv(2028)]),
+    ((2053, false, INV), &[Tv(2045)]),
+    ((2054, false, INV), &[Tv(2046)]),
+    ((2056, false, INV), &[Tv(2047)]),
+    ((2057, false, INV), &[Tv(2048)]),
+    ((2059, false, INV), &[Tv(2049)]),
+    ((2060, false, INV), &[Tv(2050)]),
+    ((2062, false, INV), &[Tv(2051)]),
+    ((2063, false, INV), &[Tv(2052)]),
+    ((2076, false, INV), &[Tv(2068)]),
+    ((2077, false, INV), &[Tv(2069)]),
+    ((2079, false, INV), &[Tv(2070)]),
+    ((2080, false, INV), &[Tv(2071)]),
+    ((2082, false, INV), &[Tv(2072)]),
+    ((2083, false, INV), &[Tv(2073)]),
+    ((2085, false, INV), &[Tv(2074)]),
+    ((2086, false, INV), &[Tv(2075)]),
+    ((5865, false, NAND2), &[Tv(5864), Arg(1, 0)]),
+    ((5867, false, AND2), &[Tv(5866), Arg(1, 1)]),
+    ((5869, false, AND2), &[Tv(5868), Arg(1, 2)]),
+    ((5871, false, AND2), &[Tv(5870), Arg(1, 3)]),
+    ((5873, false, AND2), &[Tv(5872), Arg(1, 4)]),
+    ((5875, false, AND2), &[Tv(5874), Arg(1, 5)]),
+    ((5877, false, AND2), &[Tv(5876), Arg(1, 6)]),
+    ((5879, false, NAND2), &[Tv(5878), Arg(1, 7)]),
+    ((5892, false, INV), &[Tv(5882)]),
+    ((5896, false, NOR2), &[Tv(5881), Tv(5882)]),
+    ((5897, false, INV), &[Tv(5883)]),
+    ((5901, false, NOR2), &[Tv(5882), Tv(5883)]),
+    ((5902, false, INV), &[Tv(5884)]),
+    ((5906, false, NOR2), &[Tv(5883), Tv(5884)]),
+    ((5907, false, INV), &[Tv(5885)]),
+    ((5911, false, NOR2), &[Tv(5884), Tv(5885)]),
+    ((5912, false, INV), &[Tv(5886)]),
+    ((5916, false, NOR2), &[Tv(5885), Tv(5886)]),
+    ((5917, false, INV), &[Tv(5887)]),
+    ((5922, false, NOR2), &[Tv(5886), Tv(5887)]),
+    ((5963, false, NAND2), &[Arg(0, 0), Tv(5962)]),
+    ((5965, false, AND2), &[Arg(0, 1), Tv(5964)]),
+    ((5967, false, AND2), &[Arg(0, 2), Tv(5966)]),
+    ((5969, false, AND2), &[Arg(0, 3), Tv(5968)]),
+    ((5971, false, AND2), &[Arg(0, 4), Tv(5970)]),
+    ((5973, false, AND2), &[Arg(0, 5), Tv(5972)]),
+    ((5975, false, NAND2), &[Arg(0, 6), Tv(5974)]),
+    ((6046, false, INV), &[Tv(5880)]),
+    ((6062, false, NAND2), &[Tv(6061), Arg(1, 8)]),
+    ((6064, false, AND2), &[Tv(6063), Arg(1, 9)]),
+    ((6066, false, AND2), &[Tv(6065), Arg(1, 10)]),
+    ((6068, false, AND2), &[Tv(6067), Arg(1, 11)]),
+    ((6070, false, AND2), &[Tv(6069), Arg(1, 12)]),
+    ((6072, false, AND2), &[Tv(6071), Arg(1, 13)]),
+    ((6074, false, AND2), &[Tv(6073), Arg(1, 14)]),
+    ((6076, false, NAND2), &[Tv(6075), Arg(1, 15)]),
+    ((6089, false, INV), &[Tv(6079)]),
+    ((6093, false, NOR2), &[Tv(6078), Tv(6079)]),
+    ((6094, false, INV), &[Tv(6080)]),
+    ((6098, false, NOR2), &[Tv(6079), Tv(6080)]),
+    ((6099, false, INV), &[Tv(6081)]),
+    ((6103, false, NOR2), &[Tv(6080), Tv(6081)]),
+    ((6104, false, INV), &[Tv(6082)]),
+    ((6108, false, NOR2), &[Tv(6081), Tv(6082)]),
+    ((6109, false, INV), &[Tv(6083)]),
+    ((6113, false, NOR2), &[Tv(6082), Tv(6083)]),
+    ((6114, false, INV), &[Tv(6084)]),
+    ((6119, false, NOR2), &[Tv(6083), Tv(6084)]),
+    ((6160, false, NAND2), &[Arg(0, 8), Tv(6159)]),
+    ((6162, false, AND2), &[Arg(0, 9), Tv(6161)]),
+    ((6164, false, AND2), &[Arg(0, 10), Tv(6163)]),
+    ((6166, false, AND2), &[Arg(0, 11), Tv(6165)]),
+    ((6168, false, AND2), &[Arg(0, 12), Tv(6167)]),
+    ((6170, false, AND2), &[Arg(0, 13), Tv(6169)]),
+    ((6172, false, NAND2), &[Arg(0, 14), Tv(6171)]),
+    ((6243, false, INV), &[Tv(6077)]),
 ];
 
-static LEVEL_2: [((usize, bool, CellType), &[GateInput]); 46] = [
-    ((393, false, INV), &[Arg(1, 4)]),
-    ((667, false, INV), &[Arg(2, 114)]),
-    ((670, false, INV), &[Arg(2, 117)]),
-    ((672, false, INV), &[Arg(2, 152)]),
-    ((673, false, INV), &[Arg(2, 154)]),
-    ((675, false, INV), &[Arg(2, 156)]),
-    ((676, false, INV), &[Arg(2, 157)]),
-    ((679, false, INV), &[Arg(2, 163)]),
-    ((2054, false, NAND2), &[Arg(1, 0), Tv(664)]),
-    ((2055, false, NAND2), &[Arg(1, 3), Tv(665)]),
-    ((2058, false, NAND2), &[Tv(408), Arg(2, 117)]),
-    ((2059, false, NAND2), &[Tv(411), Arg(2, 114)]),
-    ((2064, false, NAND2), &[Tv(412), Arg(2, 119)]),
-    ((2065, false, NAND2), &[Arg(1, 11), Tv(668)]),
-    ((2067, false, NAND2), &[Tv(392), Arg(2, 107)]),
-    ((2068, false, NAND2), &[Arg(1, 6), Tv(666)]),
-    ((2071, false, NAND2), &[Tv(389), Arg(2, 104)]),
-    ((2072, false, NAND2), &[Tv(407), Arg(2, 116)]),
-    ((2074, false, NAND2), &[Arg(1, 15), Tv(671)]),
-    ((2075, false, NAND2), &[Arg(1, 12), Tv(669)]),
-    ((2496, false, NAND2), &[Arg(1, 11), Tv(660)]),
-    ((2497, false, NAND2), &[Tv(390), Arg(2, 57)]),
-    ((2501, false, NAND2), &[Tv(405), Arg(2, 65)]),
-    ((2502, false, NAND2), &[Arg(1, 12), Tv(661)]),
-    ((2516, false, AND2), &[Tv(2514), Tv(2515)]),
-    ((2519, false, AND2), &[Tv(2517), Tv(2518)]),
-    ((2523, false, AND2), &[Tv(2521), Tv(2522)]),
-    ((2526, false, AND2), &[Tv(2524), Tv(2525)]),
-    ((2529, false, XNOR2), &[Arg(1, 7), Arg(2, 63)]),
-    ((2530, false, XNOR2), &[Arg(1, 2), Arg(2, 58)]),
-    ((2534, false, AND2), &[Tv(2532), Tv(2533)]),
-    ((2537, false, AND2), &[Tv(2535), Tv(2536)]),
-    ((2612, false, NAND2), &[Tv(390), Arg(2, 9)]),
-    ((2613, false, NAND2), &[Arg(1, 7), Tv(652)]),
-    ((2616, false, NAND2), &[Arg(1, 2), Tv(649)]),
-    ((2617, false, NAND2), &[Arg(1, 1), Tv(648)]),
-    ((2624, false, NAND2), &[Arg(1, 13), Tv(654)]),
-    ((2625, false, NAND2), &[Tv(395), Arg(2, 14)]),
-    ((2634, false, AND2), &[Tv(2632), Tv(2633)]),
-    ((2637, false, AND2), &[Tv(2635), Tv(2636)]),
-    ((2639, false, XNOR2), &[Arg(1, 12), Arg(2, 20)]),
-    ((2640, false, XNOR2), &[Arg(1, 14), Arg(2, 22)]),
-    ((2645, false, AND2), &[Tv(2643), Tv(2644)]),
-    ((2646, false, XNOR2), &[Arg(1, 8), Arg(2, 16)]),
-    ((2650, false, AND2), &[Tv(2648), Tv(2649)]),
-    ((2653, false, AND2), &[Tv(2651), Tv(2652)]),
+static LEVEL_2: [((usize, bool, CellType), &[GateInput]); 373] = [
+    ((61, false, NOR2), &[Tv(59), Tv(60)]),
+    ((64, false, NOR2), &[Tv(62), Tv(63)]),
+    ((67, false, NOR2), &[Tv(65), Tv(66)]),
+    ((70, false, NOR2), &[Tv(68), Tv(69)]),
+    ((84, false, NOR2), &[Tv(82), Tv(83)]),
+    ((87, false, NOR2), &[Tv(85), Tv(86)]),
+    ((90, false, NOR2), &[Tv(88), Tv(89)]),
+    ((93, false, NOR2), &[Tv(91), Tv(92)]),
+    ((104, false, NAND2), &[Tv(102), Tv(103)]),
+    ((116, false, NOR2), &[Tv(114), Tv(115)]),
+    ((119, false, NOR2), &[Tv(117), Tv(118)]),
+    ((122, false, NOR2), &[Tv(120), Tv(121)]),
+    ((125, false, NOR2), &[Tv(123), Tv(124)]),
+    ((139, false, NOR2), &[Tv(137), Tv(138)]),
+    ((142, false, NOR2), &[Tv(140), Tv(141)]),
+    ((145, false, NOR2), &[Tv(143), Tv(144)]),
+    ((148, false, NOR2), &[Tv(146), Tv(147)]),
+    ((159, false, NAND2), &[Tv(157), Tv(158)]),
+    ((171, false, NOR2), &[Tv(169), Tv(170)]),
+    ((174, false, NOR2), &[Tv(172), Tv(173)]),
+    ((177, false, NOR2), &[Tv(175), Tv(176)]),
+    ((180, false, NOR2), &[Tv(178), Tv(179)]),
+    ((194, false, NOR2), &[Tv(192), Tv(193)]),
+    ((197, false, NOR2), &[Tv(195), Tv(196)]),
+    ((200, false, NOR2), &[Tv(198), Tv(199)]),
+    ((203, false, NOR2), &[Tv(201), Tv(202)]),
+    ((214, false, NAND2), &[Tv(212), Tv(213)]),
+    ((226, false, NOR2), &[Tv(224), Tv(225)]),
+    ((229, false, NOR2), &[Tv(227), Tv(228)]),
+    ((232, false, NOR2), &[Tv(230), Tv(231)]),
+    ((235, false, NOR2), &[Tv(233), Tv(234)]),
+    ((249, false, NOR2), &[Tv(247), Tv(248)]),
+    ((252, false, NOR2), &[Tv(250), Tv(251)]),
+    ((255, false, NOR2), &[Tv(253), Tv(254)]),
+    ((258, false, NOR2), &[Tv(256), Tv(257)]),
+    ((269, false, NAND2), &[Tv(267), Tv(268)]),
+    ((281, false, NOR2), &[Tv(279), Tv(280)]),
+    ((284, false, NOR2), &[Tv(282), Tv(283)]),
+    ((287, false, NOR2), &[Tv(285), Tv(286)]),
+    ((290, false, NOR2), &[Tv(288), Tv(289)]),
+    ((304, false, NOR2), &[Tv(302), Tv(303)]),
+    ((307, false, NOR2), &[Tv(305), Tv(306)]),
+    ((310, false, NOR2), &[Tv(308), Tv(309)]),
+    ((313, false, NOR2), &[Tv(311), Tv(312)]),
+    ((324, false, NAND2), &[Tv(322), Tv(323)]),
+    ((336, false, NOR2), &[Tv(334), Tv(335)]),
+    ((339, false, NOR2), &[Tv(337), Tv(338)]),
+    ((342, false, NOR2), &[Tv(340), Tv(341)]),
+    ((345, false, NOR2), &[Tv(343), Tv(344)]),
+    ((359, false, NOR2), &[Tv(357), Tv(358)]),
+    ((362, false, NOR2), &[Tv(360), Tv(361)]),
+    ((365, false, NOR2), &[Tv(363), Tv(364)]),
+    ((368, false, NOR2), &[Tv(366), Tv(367)]),
+    ((379, false, NAND2), &[Tv(377), Tv(378)]),
+    ((391, false, NOR2), &[Tv(389), Tv(390)]),
+    ((394, false, NOR2), &[Tv(392), Tv(393)]),
+    ((397, false, NOR2), &[Tv(395), Tv(396)]),
+    ((400, false, NOR2), &[Tv(398), Tv(399)]),
+    ((414, false, NOR2), &[Tv(412), Tv(413)]),
+    ((417, false, NOR2), &[Tv(415), Tv(416)]),
+    ((420, false, NOR2), &[Tv(418), Tv(419)]),
+    ((423, false, NOR2), &[Tv(421), Tv(422)]),
+    ((434, false, NAND2), &[Tv(432), Tv(433)]),
+    ((446, false, NOR2), &[Tv(444), Tv(445)]),
+    ((449, false, NOR2), &[Tv(447), Tv(448)]),
+    ((452, false, NOR2), &[Tv(450), Tv(451)]),
+    ((455, false, NOR2), &[Tv(453), Tv(454)]),
+    ((469, false, NOR2), &[Tv(467), Tv(468)]),
+    ((472, false, NOR2), &[Tv(470), Tv(471)]),
+    ((475, false, NOR2), &[Tv(473), Tv(474)]),
+    ((478, false, NOR2), &[Tv(476), Tv(477)]),
+    ((489, false, NAND2), &[Tv(487), Tv(488)]),
+    ((501, false, NOR2), &[Tv(499), Tv(500)]),
+    ((504, false, NOR2), &[Tv(502), Tv(503)]),
+    ((507, false, NOR2), &[Tv(505), Tv(506)]),
+    ((510, false, NOR2), &[Tv(508), Tv(509)]),
+    ((524, false, NOR2), &[Tv(522), Tv(523)]),
+    ((527, false, NOR2), &[Tv(525), Tv(526)]),
+    ((530, false, NOR2), &[Tv(528), Tv(529)]),
+    ((533, false, NOR2), &[Tv(531), Tv(532)]),
+    ((544, false, NAND2), &[Tv(542), Tv(543)]),
+    ((556, false, NOR2), &[Tv(554), Tv(555)]),
+    ((559, false, NOR2), &[Tv(557), Tv(558)]),
+    ((562, false, NOR2), &[Tv(560), Tv(561)]),
+    ((565, false, NOR2), &[Tv(563), Tv(564)]),
+    ((579, false, NOR2), &[Tv(577), Tv(578)]),
+    ((582, false, NOR2), &[Tv(580), Tv(581)]),
+    ((585, false, NOR2), &[Tv(583), Tv(584)]),
+    ((588, false, NOR2), &[Tv(586), Tv(587)]),
+    ((599, false, NAND2), &[Tv(597), Tv(598)]),
+    ((611, false, NOR2), &[Tv(609), Tv(610)]),
+    ((614, false, NOR2), &[Tv(612), Tv(613)]),
+    ((617, false, NOR2), &[Tv(615), Tv(616)]),
+    ((620, false, NOR2), &[Tv(618), Tv(619)]),
+    ((634, false, NOR2), &[Tv(632), Tv(633)]),
+    ((637, false, NOR2), &[Tv(635), Tv(636)]),
+    ((640, false, NOR2), &[Tv(638), Tv(639)]),
+    ((643, false, NOR2), &[Tv(641), Tv(642)]),
+    ((654, false, NAND2), &[Tv(652), Tv(653)]),
+    ((666, false, NOR2), &[Tv(664), Tv(665)]),
+    ((669, false, NOR2), &[Tv(667), Tv(668)]),
+    ((672, false, NOR2), &[Tv(670), Tv(671)]),
+    ((675, false, NOR2), &[Tv(673), Tv(674)]),
+    ((689, false, NOR2), &[Tv(687), Tv(688)]),
+    ((692, false, NOR2), &[Tv(690), Tv(691)]),
+    ((695, false, NOR2), &[Tv(693), Tv(694)]),
+    ((698, false, NOR2), &[Tv(696), Tv(697)]),
+    ((709, false, NAND2), &[Tv(707), Tv(708)]),
+    ((721, false, NOR2), &[Tv(719), Tv(720)]),
+    ((724, false, NOR2), &[Tv(722), Tv(723)]),
+    ((727, false, NOR2), &[Tv(725), Tv(726)]),
+    ((730, false, NOR2), &[Tv(728), Tv(729)]),
+    ((744, false, NOR2), &[Tv(742), Tv(743)]),
+    ((747, false, NOR2), &[Tv(745), Tv(746)]),
+    ((750, false, NOR2), &[Tv(748), Tv(749)]),
+    ((753, false, NOR2), &[Tv(751), Tv(752)]),
+    ((764, false, NAND2), &[Tv(762), Tv(763)]),
+    ((776, false, NOR2), &[Tv(774), Tv(775)]),
+    ((779, false, NOR2), &[Tv(777), Tv(778)]),
+    ((782, false, NOR2), &[Tv(780), Tv(781)]),
+    ((785, false, NOR2), &[Tv(783), Tv(784)]),
+    ((799, false, NOR2), &[Tv(797), Tv(798)]),
+    ((802, false, NOR2), &[Tv(800), Tv(801)]),
+    ((805, false, NOR2), &[Tv(803), Tv(804)]),
+    ((808, false, NOR2), &[Tv(806), Tv(807)]),
+    ((819, false, NAND2), &[Tv(817), Tv(818)]),
+    ((831, false, NOR2), &[Tv(829), Tv(830)]),
+    ((834, false, NOR2), &[Tv(832), Tv(833)]),
+    ((837, false, NOR2), &[Tv(835), Tv(836)]),
+    ((840, false, NOR2), &[Tv(838), Tv(839)]),
+    ((854, false, NOR2), &[Tv(852), Tv(853)]),
+    ((857, false, NOR2), &[Tv(855), Tv(856)]),
+    ((860, false, NOR2), &[Tv(858), Tv(859)]),
+    ((863, false, NOR2), &[Tv(861), Tv(862)]),
+    ((874, false, NAND2), &[Tv(872), Tv(873)]),
+    ((886, false, NOR2), &[Tv(884), Tv(885)]),
+    ((889, false, NOR2), &[Tv(887), Tv(888)]),
+    ((892, false, NOR2), &[Tv(890), Tv(891)]),
+    ((895, false, NOR2), &[Tv(893), Tv(894)]),
+    ((909, false, NOR2), &[Tv(907), Tv(908)]),
+    ((912, false, NOR2), &[Tv(910), Tv(911)]),
+    ((915, false, NOR2), &[Tv(913), Tv(914)]),
+    ((918, false, NOR2), &[Tv(916), Tv(917)]),
+    ((929, false, NAND2), &[Tv(927), Tv(928)]),
+    ((941, false, NOR2), &[Tv(939), Tv(940)]),
+    ((944, false, NOR2), &[Tv(942), Tv(943)]),
+    ((947, false, NOR2), &[Tv(945), Tv(946)]),
+    ((950, false, NOR2), &[Tv(948), Tv(949)]),
+    ((964, false, NOR2), &[Tv(962), Tv(963)]),
+    ((967, false, NOR2), &[Tv(965), Tv(966)]),
+    ((970, false, NOR2), &[Tv(968), Tv(969)]),
+    ((973, false, NOR2), &[Tv(971), Tv(972)]),
+    ((984, false, NAND2), &[Tv(982), Tv(983)]),
+    ((996, false, NOR2), &[Tv(994), Tv(995)]),
+    ((999, false, NOR2), &[Tv(997), Tv(998)]),
+    ((1002, false, NOR2), &[Tv(1000), Tv(1001)]),
+    ((1005, false, NOR2), &[Tv(1003), Tv(1004)]),
+    ((1019, false, NOR2), &[Tv(1017), Tv(1018)]),
+    ((1022, false, NOR2), &[Tv(1020), Tv(1021)]),
+    ((1025, false, NOR2), &[Tv(1023), Tv(1024)]),
+    ((1028, false, NOR2), &[Tv(1026), Tv(1027)]),
+    ((1039, false, NAND2), &[Tv(1037), Tv(1038)]),
+    ((1051, false, NOR2), &[Tv(1049), Tv(1050)]),
+    ((1054, false, NOR2), &[Tv(1052), Tv(1053)]),
+    ((1057, false, NOR2), &[Tv(1055), Tv(1056)]),
+    ((1060, false, NOR2), &[Tv(1058), Tv(1059)]),
+    ((1074, false, NOR2), &[Tv(1072), Tv(1073)]),
+    ((1077, false, NOR2), &[Tv(1075), Tv(1076)]),
+    ((1080, false, NOR2), &[Tv(1078), Tv(1079)]),
+    ((1083, false, NOR2), &[Tv(1081), Tv(1082)]),
+    ((1094, false, NAND2), &[Tv(1092), Tv(1093)]),
+    ((1106, false, NOR2), &[Tv(1104), Tv(1105)]),
+    ((1109, false, NOR2), &[Tv(1107), Tv(1108)]),
+    ((1112, false, NOR2), &[Tv(1110), Tv(1111)]),
+    ((1115, false, NOR2), &[Tv(1113), Tv(1114)]),
+    ((1129, false, NOR2), &[Tv(1127), Tv(1128)]),
+    ((1132, false, NOR2), &[Tv(1130), Tv(1131)]),
+    ((1135, false, NOR2), &[Tv(1133), Tv(1134)]),
+    ((1138, false, NOR2), &[Tv(1136), Tv(1137)]),
+    ((1149, false, NAND2), &[Tv(1147), Tv(1148)]),
+    ((1161, false, NOR2), &[Tv(1159), Tv(1160)]),
+    ((1164, false, NOR2), &[Tv(1162), Tv(1163)]),
+    ((1167, false, NOR2), &[Tv(1165), Tv(1166)]),
+    ((1170, false, NOR2), &[Tv(1168), Tv(1169)]),
+    ((1184, false, NOR2), &[Tv(1182), Tv(1183)]),
+    ((1187, false, NOR2), &[Tv(1185), Tv(1186)]),
+    ((1190, false, NOR2), &[Tv(1188), Tv(1189)]),
+    ((1193, false, NOR2), &[Tv(1191), Tv(1192)]),
+    ((1204, false, NAND2), &[Tv(1202), Tv(1203)]),
+    ((1216, false, NOR2), &[Tv(1214), Tv(1215)]),
+    ((1219, false, NOR2), &[Tv(1217), Tv(1218)]),
+    ((1222, false, NOR2), &[Tv(1220), Tv(1221)]),
+    ((1225, false, NOR2), &[Tv(1223), Tv(1224)]),
+    ((1239, false, NOR2), &[Tv(1237), Tv(1238)]),
+    ((1242, false, NOR2), &[Tv(1240), Tv(1241)]),
+    ((1245, false, NOR2), &[Tv(1243), Tv(1244)]),
+    ((1248, false, NOR2), &[Tv(1246), Tv(1247)]),
+    ((1259, false, NAND2), &[Tv(1257), Tv(1258)]),
+    ((1271, false, NOR2), &[Tv(1269), Tv(1270)]),
+    ((1274, false, NOR2), &[Tv(1272), Tv(1273)]),
+    ((1277, false, NOR2), &[Tv(1275), Tv(1276)]),
+    ((1280, false, NOR2), &[Tv(1278), Tv(1279)]),
+    ((1294, false, NOR2), &[Tv(1292), Tv(1293)]),
+    ((1297, false, NOR2), &[Tv(1295), Tv(1296)]),
+    ((1300, false, NOR2), &[Tv(1298), Tv(1299)]),
+    ((1303, false, NOR2), &[Tv(1301), Tv(1302)]),
+    ((1314, false, NAND2), &[Tv(1312), Tv(1313)]),
+    ((1326, false, NOR2), &[Tv(1324), Tv(1325)]),
+    ((1329, false, NOR2), &[Tv(1327), Tv(1328)]),
+    ((1332, false, NOR2), &[Tv(1330), Tv(1331)]),
+    ((1335, false, NOR2), &[Tv(1333), Tv(1334)]),
+    ((1349, false, NOR2), &[Tv(1347), Tv(1348)]),
+    ((1352, false, NOR2), &[Tv(1350), Tv(1351)]),
+    ((1355, false, NOR2), &[Tv(1353), Tv(1354)]),
+    ((1358, false, NOR2), &[Tv(1356), Tv(1357)]),
+    ((1375, false, NOR2), &[Tv(1373), Tv(1374)]),
+    ((1378, false, NOR2), &[Tv(1376), Tv(1377)]),
+    ((1381, false, NOR2), &[Tv(1379), Tv(1380)]),
+    ((1384, false, NOR2), &[Tv(1382), Tv(1383)]),
+    ((1398, false, NOR2), &[Tv(1396), Tv(1397)]),
+    ((1401, false, NOR2), &[Tv(1399), Tv(1400)]),
+    ((1404, false, NOR2), &[Tv(1402), Tv(1403)]),
+    ((1407, false, NOR2), &[Tv(1405), Tv(1406)]),
+    ((1424, false, NOR2), &[Tv(1422), Tv(1423)]),
+    ((1427, false, NOR2), &[Tv(1425), Tv(1426)]),
+    ((1430, false, NOR2), &[Tv(1428), Tv(1429)]),
+    ((1433, false, NOR2), &[Tv(1431), Tv(1432)]),
+    ((1447, false, NOR2), &[Tv(1445), Tv(1446)]),
+    ((1450, false, NOR2), &[Tv(1448), Tv(1449)]),
+    ((1453, false, NOR2), &[Tv(1451), Tv(1452)]),
+    ((1456, false, NOR2), &[Tv(1454), Tv(1455)]),
+    ((1473, false, NOR2), &[Tv(1471), Tv(1472)]),
+    ((1476, false, NOR2), &[Tv(1474), Tv(1475)]),
+    ((1479, false, NOR2), &[Tv(1477), Tv(1478)]),
+    ((1482, false, NOR2), &[Tv(1480), Tv(1481)]),
+    ((1496, false, NOR2), &[Tv(1494), Tv(1495)]),
+    ((1499, false, NOR2), &[Tv(1497), Tv(1498)]),
+    ((1502, false, NOR2), &[Tv(1500), Tv(1501)]),
+    ((1505, false, NOR2), &[Tv(1503), Tv(1504)]),
+    ((1522, false, NOR2), &[Tv(1520), Tv(1521)]),
+    ((1525, false, NOR2), &[Tv(1523), Tv(1524)]),
+    ((1528, false, NOR2), &[Tv(1526), Tv(1527)]),
+    ((1531, false, NOR2), &[Tv(1529), Tv(1530)]),
+    ((1545, false, NOR2), &[Tv(1543), Tv(1544)]),
+    ((1548, false, NOR2), &[Tv(1546), Tv(1547)]),
+    ((1551, false, NOR2), &[Tv(1549), Tv(1550)]),
+    ((1554, false, NOR2), &[Tv(1552), Tv(1553)]),
+    ((1571, false, NOR2), &[Tv(1569), Tv(1570)]),
+    ((1574, false, NOR2), &[Tv(1572), Tv(1573)]),
+    ((1577, false, NOR2), &[Tv(1575), Tv(1576)]),
+    ((1580, false, NOR2), &[Tv(1578), Tv(1579)]),
+    ((1594, false, NOR2), &[Tv(1592), Tv(1593)]),
+    ((1597, false, NOR2), &[Tv(1595), Tv(1596)]),
+    ((1600, false, NOR2), &[Tv(1598), Tv(1599)]),
+    ((1603, false, NOR2), &[Tv(1601), Tv(1602)]),
+    ((1620, false, NOR2), &[Tv(1618), Tv(1619)]),
+    ((1623, false, NOR2), &[Tv(1621), Tv(1622)]),
+    ((1626, false, NOR2), &[Tv(1624), Tv(1625)]),
+    ((1629, false, NOR2), &[Tv(1627), Tv(1628)]),
+    ((1643, false, NOR2), &[Tv(1641), Tv(1642)]),
+    ((1646, false, NOR2), &[Tv(1644), Tv(1645)]),
+    ((1649, false, NOR2), &[Tv(1647), Tv(1648)]),
+    ((1652, false, NOR2), &[Tv(1650), Tv(1651)]),
+    ((1669, false, NOR2), &[Tv(1667), Tv(1668)]),
+    ((1672, false, NOR2), &[Tv(1670), Tv(1671)]),
+    ((1675, false, NOR2), &[Tv(1673), Tv(1674)]),
+    ((1678, false, NOR2), &[Tv(1676), Tv(1677)]),
+    ((1692, false, NOR2), &[Tv(1690), Tv(1691)]),
+    ((1695, false, NOR2), &[Tv(1693), Tv(1694)]),
+    ((1698, false, NOR2), &[Tv(1696), Tv(1697)]),
+    ((1701, false, NOR2), &[Tv(1699), Tv(1700)]),
+    ((1718, false, NOR2), &[Tv(1716), Tv(1717)]),
+    ((1721, false, NOR2), &[Tv(1719), Tv(1720)]),
+    ((1724, false, NOR2), &[Tv(1722), Tv(1723)]),
+    ((1727, false, NOR2), &[Tv(1725), Tv(1726)]),
+    ((1741, false, NOR2), &[Tv(1739), Tv(1740)]),
+    ((1744, false, NOR2), &[Tv(1742), Tv(1743)]),
+    ((1747, false, NOR2), &[Tv(1745), Tv(1746)]),
+    ((1750, false, NOR2), &[Tv(1748), Tv(1749)]),
+    ((1767, false, NOR2), &[Tv(1765), Tv(1766)]),
+    ((1770, false, NOR2), &[Tv(1768), Tv(1769)]),
+    ((1773, false, NOR2), &[Tv(1771), Tv(1772)]),
+    ((1776, false, NOR2), &[Tv(1774), Tv(1775)]),
+    ((1790, false, NOR2), &[Tv(1788), Tv(1789)]),
+    ((1793, false, NOR2), &[Tv(1791), Tv(1792)]),
+    ((1796, false, NOR2), &[Tv(1794), Tv(1795)]),
+    ((1799, false, NOR2), &[Tv(1797), Tv(1798)]),
+    ((1816, false, NOR2), &[Tv(1814), Tv(1815)]),
+    ((1819, false, NOR2), &[Tv(1817), Tv(1818)]),
+    ((1822, false, NOR2), &[Tv(1820), Tv(1821)]),
+    ((1825, false, NOR2), &[Tv(1823), Tv(1824)]),
+    ((1839, false, NOR2), &[Tv(1837), Tv(1838)]),
+    ((1842, false, NOR2), &[Tv(1840), Tv(1841)]),
+    ((1845, false, NOR2), &[Tv(1843), Tv(1844)]),
+    ((1848, false, NOR2), &[Tv(1846), Tv(1847)]),
+    ((1865, false, NOR2), &[Tv(1863), Tv(1864)]),
+    ((1868, false, NOR2), &[Tv(1866), Tv(1867)]),
+    ((1871, false, NOR2), &[Tv(1869), Tv(1870)]),
+    ((1874, false, NOR2), &[Tv(1872), Tv(1873)]),
+    ((1888, false, NOR2), &[Tv(1886), Tv(1887)]),
+    ((1891, false, NOR2), &[Tv(1889), Tv(1890)]),
+    ((1894, false, NOR2), &[Tv(1892), Tv(1893)]),
+    ((1897, false, NOR2), &[Tv(1895), Tv(1896)]),
+    ((1914, false, NOR2), &[Tv(1912), Tv(1913)]),
+    ((1917, false, NOR2), &[Tv(1915), Tv(1916)]),
+    ((1920, false, NOR2), &[Tv(1918), Tv(1919)]),
+    ((1923, false, NOR2), &[Tv(1921), Tv(1922)]),
+    ((1937, false, NOR2), &[Tv(1935), Tv(1936)]),
+    ((1940, false, NOR2), &[Tv(1938), Tv(1939)]),
+    ((1943, false, NOR2), &[Tv(1941), Tv(1942)]),
+    ((1946, false, NOR2), &[Tv(1944), Tv(1945)]),
+    ((1961, false, NOR2), &[Tv(1959), Tv(1960)]),
+    ((1964, false, NOR2), &[Tv(1962), Tv(1963)]),
+    ((1967, false, NOR2), &[Tv(1965), Tv(1966)]),
+    ((1970, false, NOR2), &[Tv(1968), Tv(1969)]),
+    ((1984, false, NOR2), &[Tv(1982), Tv(1983)]),
+    ((1987, false, NOR2), &[Tv(1985), Tv(1986)]),
+    ((1990, false, NOR2), &[Tv(1988), Tv(1989)]),
+    ((1993, false, NOR2), &[Tv(1991), Tv(1992)]),
+    ((2008, false, NOR2), &[Tv(2006), Tv(2007)]),
+    ((2011, false, NOR2), &[Tv(2009), Tv(2010)]),
+    ((2014, false, NOR2), &[Tv(2012), Tv(2013)]),
+    ((2017, false, NOR2), &[Tv(2015), Tv(2016)]),
+    ((2031, false, NOR2), &[Tv(2029), Tv(2030)]),
+    ((2034, false, NOR2), &[Tv(2032), Tv(2033)]),
+    ((2037, false, NOR2), &[Tv(2035), Tv(2036)]),
+    ((2040, false, NOR2), &[Tv(2038), Tv(2039)]),
+    ((2055, false, NOR2), &[Tv(2053), Tv(2054)]),
+    ((2058, false, NOR2), &[Tv(2056), Tv(2057)]),
+    ((2061, false, NOR2), &[Tv(2059), Tv(2060)]),
+    ((2064, false, NOR2), &[Tv(2062), Tv(2063)]),
+    ((2078, false, NOR2), &[Tv(2076), Tv(2077)]),
+    ((2081, false, NOR2), &[Tv(2079), Tv(2080)]),
+    ((2084, false, NOR2), &[Tv(2082), Tv(2083)]),
+    ((2087, false, NOR2), &[Tv(2085), Tv(2086)]),
+    ((5888, false, AND2), &[Tv(5865), Tv(5880)]),
+    ((5893, false, NAND2), &[Tv(5867), Tv(5892)]),
+    ((5898, false, NAND2), &[Tv(5869), Tv(5897)]),
+    ((5903, false, NAND2), &[Tv(5871), Tv(5902)]),
+    ((5908, false, NAND2), &[Tv(5873), Tv(5907)]),
+    ((5913, false, NAND2), &[Tv(5875), Tv(5912)]),
+    ((5918, false, NAND2), &[Tv(5877), Tv(5917)]),
+    ((5919, false, INV), &[Tv(5879)]),
+    ((5932, false, AND2), &[Tv(5896), Tv(5906)]),
+    ((5936, false, AND2), &[Tv(5901), Tv(5911)]),
+    ((5940, false, AND2), &[Tv(5906), Tv(5916)]),
+    ((5944, false, AND2), &[Tv(5911), Tv(5922)]),
+    ((5976, false, AND2), &[Tv(5880), Tv(5963)]),
+    ((5980, false, NAND2), &[Tv(5892), Tv(5965)]),
+    ((5983, false, NAND2), &[Tv(5897), Tv(5967)]),
+    ((5986, false, NAND2), &[Tv(5902), Tv(5969)]),
+    ((5989, false, NAND2), &[Tv(5907), Tv(5971)]),
+    ((5992, false, NAND2), &[Tv(5912), Tv(5973)]),
+    ((5993, false, INV), &[Tv(5975)]),
+    ((6085, false, AND2), &[Tv(6062), Tv(6077)]),
+    ((6090, false, NAND2), &[Tv(6064), Tv(6089)]),
+    ((6095, false, NAND2), &[Tv(6066), Tv(6094)]),
+    ((6100, false, NAND2), &[Tv(6068), Tv(6099)]),
+    ((6105, false, NAND2), &[Tv(6070), Tv(6104)]),
+    ((6110, false, NAND2), &[Tv(6072), Tv(6109)]),
+    ((6115, false, NAND2), &[Tv(6074), Tv(6114)]),
+    ((6116, false, INV), &[Tv(6076)]),
+    ((6129, false, AND2), &[Tv(6093), Tv(6103)]),
+    ((6133, false, AND2), &[Tv(6098), Tv(6108)]),
+    ((6137, false, AND2), &[Tv(6103), Tv(6113)]),
+    ((6141, false, AND2), &[Tv(6108), Tv(6119)]),
+    ((6173, false, AND2), &[Tv(6077), Tv(6160)]),
+    ((6177, false, NAND2), &[Tv(6089), Tv(6162)]),
+    ((6180, false, NAND2), &[Tv(6094), Tv(6164)]),
+    ((6183, false, NAND2), &[Tv(6099), Tv(6166)]),
+    ((6186, false, NAND2), &[Tv(6104), Tv(6168)]),
+    ((6189, false, NAND2), &[Tv(6109), Tv(6170)]),
+    ((6190, false, INV), &[Tv(6172)]),
 ];
 
-static LEVEL_3: [((usize, bool, CellType), &[GateInput]); 65] = [
-    ((674, false, INV), &[Arg(2, 155)]),
-    ((677, false, INV), &[Arg(2, 158)]),
-    ((678, false, INV), &[Arg(2, 162)]),
-    ((680, false, INV), &[Arg(2, 164)]),
-    ((681, false, INV), &[Arg(2, 167)]),
-    ((684, false, INV), &[Arg(2, 203)]),
-    ((685, false, INV), &[Arg(2, 205)]),
-    ((689, false, INV), &[Arg(2, 210)]),
-    ((690, false, INV), &[Arg(2, 214)]),
-    ((1998, false, NAND2), &[Tv(392), Arg(2, 155)]),
-    ((1999, false, NAND2), &[Tv(412), Arg(2, 167)]),
-    ((2001, false, NAND2), &[Tv(391), Arg(2, 154)]),
-    ((2002, false, NAND2), &[Arg(1, 11), Tv(679)]),
-    ((2009, false, NAND2), &[Tv(395), Arg(2, 158)]),
-    ((2010, false, NAND2), &[Arg(1, 4), Tv(675)]),
-    ((2012, false, NAND2), &[Tv(393), Arg(2, 156)]),
-    ((2013, false, NAND2), &[Arg(1, 0), Tv(672)]),
-    ((2016, false, NAND2), &[Tv(406), Arg(2, 163)]),
-    ((2017, false, NAND2), &[Arg(1, 5), Tv(676)]),
-    ((2019, false, NAND2), &[Tv(394), Arg(2, 157)]),
-    ((2020, false, NAND2), &[Arg(1, 2), Tv(673)]),
-    ((2037, false, NAND2), &[Arg(1, 10), Tv(667)]),
-    ((2038, false, NAND2), &[Tv(406), Arg(2, 115)]),
-    ((2045, false, NAND2), &[Arg(1, 13), Tv(670)]),
-    ((2046, false, NAND2), &[Tv(395), Arg(2, 110)]),
-    ((2053, false, XNOR2), &[Arg(1, 7), Arg(2, 111)]),
-    ((2056, false, AND2), &[Tv(2054), Tv(2055)]),
-    ((2060, false, AND2), &[Tv(2058), Tv(2059)]),
-    ((2061, false, XNOR2), &[Arg(1, 5), Arg(2, 109)]),
-    ((2066, false, AND2), &[Tv(2064), Tv(2065)]),
-    ((2069, false, AND2), &[Tv(2067), Tv(2068)]),
-    ((2073, false, AND2), &[Tv(2071), Tv(2072)]),
-    ((2076, false, AND2), &[Tv(2074), Tv(2075)]),
-    ((2488, false, NOR2), &[Arg(2, 74), Arg(2, 75)]),
-    ((2489, false, NOR2), &[Arg(2, 73), Arg(2, 72)]),
-    ((2491, false, NOR2), &[Arg(2, 76), Arg(2, 77)]),
-    ((2492, false, NOR2), &[Arg(2, 78), Arg(2, 79)]),
-    ((2495, false, XNOR2), &[Arg(1, 10), Arg(2, 66)]),
-    ((2498, false, AND2), &[Tv(2496), Tv(2497)]),
-    ((2500, false, XNOR2), &[Arg(1, 3), Arg(2, 59)]),
-    ((2503, false, AND2), &[Tv(2501), Tv(2502)]),
-    ((2507, false, XNOR2), &[Arg(1, 5), Arg(2, 61)]),
-    ((2508, false, XNOR2), &[Arg(1, 4), Arg(2, 60)]),
-    ((2510, false, XNOR2), &[Arg(1, 6), Arg(2, 62)]),
-    ((2511, false, XNOR2), &[Arg(1, 14), Arg(2, 70)]),
-    ((2520, false, AND2), &[Tv(2516), Tv(2519)]),
-    ((2527, false, AND2), &[Tv(2523), Tv(2526)]),
-    ((2531, false, AND2), &[Tv(2529), Tv(2530)]),
-    ((2538, false, AND2), &[Tv(2534), Tv(2537)]),
-    ((2604, false, NOR2), &[Arg(2, 26), Arg(2, 27)]),
-    ((2605, false, NOR2), &[Arg(2, 25), Arg(2, 24)]),
-    ((2607, false, NOR2), &[Arg(2, 30), Arg(2, 31)]),
-    ((2608, false, NOR2), &[Arg(2, 28), Arg(2, 29)]),
-    ((2611, false, XNOR2), &[Arg(1, 9), Arg(2, 17)]),
-    ((2614, false, AND2), &[Tv(2612), Tv(2613)]),
-    ((2618, false, AND2), &[Tv(2616), Tv(2617)]),
-    ((2619, false, XNOR2), &[Arg(1, 4), Arg(2, 12)]),
-    ((2623, false, XNOR2), &[Arg(1, 0), Arg(2, 8)]),
-    ((2626, false, AND2), &[Tv(2624), Tv(2625)]),
-    ((2628, false, XNOR2), &[Arg(1, 3), Arg(2, 11)]),
-    ((2629, false, XNOR2), &[Arg(1, 11), Arg(2, 19)]),
-    ((2638, false, AND2), &[Tv(2634), Tv(2637)]),
-    ((2641, false, AND2), &[Tv(2639), Tv(2640)]),
-    ((2647, false, AND2), &[Tv(2645), Tv(2646)]),
-    ((2654, false, AND2), &[Tv(2650), Tv(2653)]),
+static LEVEL_3: [((usize, bool, CellType), &[GateInput]); 190] = [
+    ((71, false, AND2), &[Tv(61), Tv(64)]),
+    ((72, false, AND2), &[Tv(67), Tv(70)]),
+    ((94, false, AND2), &[Tv(84), Tv(87)]),
+    ((95, false, AND2), &[Tv(90), Tv(93)]),
+    ((126, false, AND2), &[Tv(116), Tv(119)]),
+    ((127, false, AND2), &[Tv(122), Tv(125)]),
+    ((149, false, AND2), &[Tv(139), Tv(142)]),
+    ((150, false, AND2), &[Tv(145), Tv(148)]),
+    ((181, false, AND2), &[Tv(171), Tv(174)]),
+    ((182, false, AND2), &[Tv(177), Tv(180)]),
+    ((204, false, AND2), &[Tv(194), Tv(197)]),
+    ((205, false, AND2), &[Tv(200), Tv(203)]),
+    ((236, false, AND2), &[Tv(226), Tv(229)]),
+    ((237, false, AND2), &[Tv(232), Tv(235)]),
+    ((259, false, AND2), &[Tv(249), Tv(252)]),
+    ((260, false, AND2), &[Tv(255), Tv(258)]),
+    ((291, false, AND2), &[Tv(281), Tv(284)]),
+    ((292, false, AND2), &[Tv(287), Tv(290)]),
+    ((314, false, AND2), &[Tv(304), Tv(307)]),
+    ((315, false, AND2), &[Tv(310), Tv(313)]),
+    ((346, false, AND2), &[Tv(336), Tv(339)]),
+    ((347, false, AND2), &[Tv(342), Tv(345)]),
+    ((369, false, AND2), &[Tv(359), Tv(362)]),
+    ((370, false, AND2), &[Tv(365), Tv(368)]),
+    ((401, false, AND2), &[Tv(391), Tv(394)]),
+    ((402, false, AND2), &[Tv(397), Tv(400)]),
+    ((424, false, AND2), &[Tv(414), Tv(417)]),
+    ((425, false, AND2), &[Tv(420), Tv(423)]),
+    ((456, false, AND2), &[Tv(446), Tv(449)]),
+    ((457, false, AND2), &[Tv(452), Tv(455)]),
+    ((479, false, AND2), &[Tv(469), Tv(472)]),
+    ((480, false, AND2), &[Tv(475), Tv(478)]),
+    ((511, false, AND2), &[Tv(501), Tv(504)]),
+    ((512, false, AND2), &[Tv(507), Tv(510)]),
+    ((534, false, AND2), &[Tv(524), Tv(527)]),
+    ((535, false, AND2), &[Tv(530), Tv(533)]),
+    ((566, false, AND2), &[Tv(556), Tv(559)]),
+    ((567, false, AND2), &[Tv(562), Tv(565)]),
+    ((589, false, AND2), &[Tv(579), Tv(582)]),
+    ((590, false, AND2), &[Tv(585), Tv(588)]),
+    ((621, false, AND2), &[Tv(611), Tv(614)]),
+    ((622, false, AND2), &[Tv(617), Tv(620)]),
+    ((644, false, AND2), &[Tv(634), Tv(637)]),
+    ((645, false, AND2), &[Tv(640), Tv(643)]),
+    ((676, false, AND2), &[Tv(666), Tv(669)]),
+    ((677, false, AND2), &[Tv(672), Tv(675)]),
+    ((699, false, AND2), &[Tv(689), Tv(692)]),
+    ((700, false, AND2), &[Tv(695), Tv(698)]),
+    ((731, false, AND2), &[Tv(721), Tv(724)]),
+    ((732, false, AND2), &[Tv(727), Tv(730)]),
+    ((754, false, AND2), &[Tv(744), Tv(747)]),
+    ((755, false, AND2), &[Tv(750), Tv(753)]),
+    ((786, false, AND2), &[Tv(776), Tv(779)]),
+    ((787, false, AND2), &[Tv(782), Tv(785)]),
+    ((809, false, AND2), &[Tv(799), Tv(802)]),
+    ((810, false, AND2), &[Tv(805), Tv(808)]),
+    ((841, false, AND2), &[Tv(831), Tv(834)]),
+    ((842, false, AND2), &[Tv(837), Tv(840)]),
+    ((864, false, AND2), &[Tv(854), Tv(857)]),
+    ((865, false, AND2), &[Tv(860), Tv(863)]),
+    ((896, false, AND2), &[Tv(886), Tv(889)]),
+    ((897, false, AND2), &[Tv(892), Tv(895)]),
+    ((919, false, AND2), &[Tv(909), Tv(912)]),
+    ((920, false, AND2), &[Tv(915), Tv(918)]),
+    ((951, false, AND2), &[Tv(941), Tv(944)]),
+    ((952, false, AND2), &[Tv(947), Tv(950)]),
+    ((974, false, AND2), &[Tv(964), Tv(967)]),
+    ((975, false, AND2), &[Tv(970), Tv(973)]),
+    ((1006, false, AND2), &[Tv(996), Tv(999)]),
+    ((1007, false, AND2), &[Tv(1002), Tv(1005)]),
+    ((1029, false, AND2), &[Tv(1019), Tv(1022)]),
+    ((1030, false, AND2), &[Tv(1025), Tv(1028)]),
+    ((1061, false, AND2), &[Tv(1051), Tv(1054)]),
+    ((1062, false, AND2), &[Tv(1057), Tv(1060)]),
+    ((1084, false, AND2), &[Tv(1074), Tv(1077)]),
+    ((1085, false, AND2), &[Tv(1080), Tv(1083)]),
+    ((1116, false, AND2), &[Tv(1106), Tv(1109)]),
+    ((1117, false, AND2), &[Tv(1112), Tv(1115)]),
+    ((1139, false, AND2), &[Tv(1129), Tv(1132)]),
+    ((1140, false, AND2), &[Tv(1135), Tv(1138)]),
+    ((1171, false, AND2), &[Tv(1161), Tv(1164)]),
+    ((1172, false, AND2), &[Tv(1167), Tv(1170)]),
+    ((1194, false, AND2), &[Tv(1184), Tv(1187)]),
+    ((1195, false, AND2), &[Tv(1190), Tv(1193)]),
+    ((1226, false, AND2), &[Tv(1216), Tv(1219)]),
+    ((1227, false, AND2), &[Tv(1222), Tv(1225)]),
+    ((1249, false, AND2), &[Tv(1239), Tv(1242)]),
+    ((1250, false, AND2), &[Tv(1245), Tv(1248)]),
+    ((1281, false, AND2), &[Tv(1271), Tv(1274)]),
+    ((1282, false, AND2), &[Tv(1277), Tv(1280)]),
+    ((1304, false, AND2), &[Tv(1294), Tv(1297)]),
+    ((1305, false, AND2), &[Tv(1300), Tv(1303)]),
+    ((1336, false, AND2), &[Tv(1326), Tv(1329)]),
+    ((1337, false, AND2), &[Tv(1332), Tv(1335)]),
+    ((1359, false, AND2), &[Tv(1349), Tv(1352)]),
+    ((1360, false, AND2), &[Tv(1355), Tv(1358)]),
+    ((1385, false, AND2), &[Tv(1375), Tv(1378)]),
+    ((1386, false, AND2), &[Tv(1381), Tv(1384)]),
+    ((1408, false, AND2), &[Tv(1398), Tv(1401)]),
+    ((1409, false, AND2), &[Tv(1404), Tv(1407)]),
+    ((1434, false, AND2), &[Tv(1424), Tv(1427)]),
+    ((1435, false, AND2), &[Tv(1430), Tv(1433)]),
+    ((1457, false, AND2), &[Tv(1447), Tv(1450)]),
+    ((1458, false, AND2), &[Tv(1453), Tv(1456)]),
+    ((1483, false, AND2), &[Tv(1473), Tv(1476)]),
+    ((1484, false, AND2), &[Tv(1479), Tv(1482)]),
+    ((1506, false, AND2), &[Tv(1496), Tv(1499)]),
+    ((1507, false, AND2), &[Tv(1502), Tv(1505)]),
+    ((1532, false, AND2), &[Tv(1522), Tv(1525)]),
+    ((1533, false, AND2), &[Tv(1528), Tv(1531)]),
+    ((1555, false, AND2), &[Tv(1545), Tv(1548)]),
+    ((1556, false, AND2), &[Tv(1551), Tv(1554)]),
+    ((1581, false, AND2), &[Tv(1571), Tv(1574)]),
+    ((1582, false, AND2), &[Tv(1577), Tv(1580)]),
+    ((1604, false, AND2), &[Tv(1594), Tv(1597)]),
+    ((1605, false, AND2), &[Tv(1600), Tv(1603)]),
+    ((1630, false, AND2), &[Tv(1620), Tv(1623)]),
+    ((1631, false, AND2), &[Tv(1626), Tv(1629)]),
+    ((1653, false, AND2), &[Tv(1643), Tv(1646)]),
+    ((1654, false, AND2), &[Tv(1649), Tv(1652)]),
+    ((1679, false, AND2), &[Tv(1669), Tv(1672)]),
+    ((1680, false, AND2), &[Tv(1675), Tv(1678)]),
+    ((1702, false, AND2), &[Tv(1692), Tv(1695)]),
+    ((1703, false, AND2), &[Tv(1698), Tv(1701)]),
+    ((1728, false, AND2), &[Tv(1718), Tv(1721)]),
+    ((1729, false, AND2), &[Tv(1724), Tv(1727)]),
+    ((1751, false, AND2), &[Tv(1741), Tv(1744)]),
+    ((1752, false, AND2), &[Tv(1747), Tv(1750)]),
+    ((1777, false, AND2), &[Tv(1767), Tv(1770)]),
+    ((1778, false, AND2), &[Tv(1773), Tv(1776)]),
+    ((1800, false, AND2), &[Tv(1790), Tv(1793)]),
+    ((1801, false, AND2), &[Tv(1796), Tv(1799)]),
+    ((1826, false, AND2), &[Tv(1816), Tv(1819)]),
+    ((1827, false, AND2), &[Tv(1822), Tv(1825)]),
+    ((1849, false, AND2), &[Tv(1839), Tv(1842)]),
+    ((1850, false, AND2), &[Tv(1845), Tv(1848)]),
+    ((1875, false, AND2), &[Tv(1865), Tv(1868)]),
+    ((1876, false, AND2), &[Tv(1871), Tv(1874)]),
+    ((1898, false, AND2), &[Tv(1888), Tv(1891)]),
+    ((1899, false, AND2), &[Tv(1894), Tv(1897)]),
+    ((1924, false, AND2), &[Tv(1914), Tv(1917)]),
+    ((1925, false, AND2), &[Tv(1920), Tv(1923)]),
+    ((1947, false, AND2), &[Tv(1937), Tv(1940)]),
+    ((1948, false, AND2), &[Tv(1943), Tv(1946)]),
+    ((1971, false, AND2), &[Tv(1961), Tv(1964)]),
+    ((1972, false, AND2), &[Tv(1967), Tv(1970)]),
+    ((1994, false, AND2), &[Tv(1984), Tv(1987)]),
+    ((1995, false, AND2), &[Tv(1990), Tv(1993)]),
+    ((2018, false, AND2), &[Tv(2008), Tv(2011)]),
+    ((2019, false, AND2), &[Tv(2014), Tv(2017)]),
+    ((2041, false, AND2), &[Tv(2031), Tv(2034)]),
+    ((2042, false, AND2), &[Tv(2037), Tv(2040)]),
+    ((2065, false, AND2), &[Tv(2055), Tv(2058)]),
+    ((2066, false, AND2), &[Tv(2061), Tv(2064)]),
+    ((2088, false, AND2), &[Tv(2078), Tv(2081)]),
+    ((2089, false, AND2), &[Tv(2084), Tv(2087)]),
+    ((5889, false, OR2), &[Tv(5881), Tv(5888)]),
+    ((5894, false, INV), &[Tv(5893)]),
+    ((5899, false, INV), &[Tv(5898)]),
+    ((5904, false, INV), &[Tv(5903)]),
+    ((5909, false, INV), &[Tv(5908)]),
+    ((5914, false, INV), &[Tv(5913)]),
+    ((5920, false, INV), &[Tv(5918)]),
+    ((5923, false, INV), &[Tv(5888)]),
+    ((5955, false, XOR2), &[Tv(5881), Tv(5888)]),
+    ((5977, false, OR2), &[Tv(5881), Tv(5976)]),
+    ((5981, false, INV), &[Tv(5980)]),
+    ((5984, false, INV), &[Tv(5983)]),
+    ((5987, false, INV), &[Tv(5986)]),
+    ((5990, false, INV), &[Tv(5989)]),
+    ((5994, false, INV), &[Tv(5992)]),
+    ((5996, false, INV), &[Tv(5976)]),
+    ((6018, false, XOR2), &[Tv(5881), Tv(5976)]),
+    ((6086, false, OR2), &[Tv(6078), Tv(6085)]),
+    ((6091, false, INV), &[Tv(6090)]),
+    ((6096, false, INV), &[Tv(6095)]),
+    ((6101, false, INV), &[Tv(6100)]),
+    ((6106, false, INV), &[Tv(6105)]),
+    ((6111, false, INV), &[Tv(6110)]),
+    ((6117, false, INV), &[Tv(6115)]),
+    ((6120, false, INV), &[Tv(6085)]),
+    ((6152, false, XOR2), &[Tv(6078), Tv(6085)]),
+    ((6174, false, OR2), &[Tv(6078), Tv(6173)]),
+    ((6178, false, INV), &[Tv(6177)]),
+    ((6181, false, INV), &[Tv(6180)]),
+    ((6184, false, INV), &[Tv(6183)]),
+    ((6187, false, INV), &[Tv(6186)]),
+    ((6191, false, INV), &[Tv(6189)]),
+    ((6193, false, INV), &[Tv(6173)]),
+    ((6215, false, XOR2), &[Tv(6078), Tv(6173)]),
 ];
 
-static LEVEL_4: [((usize, bool, CellType), &[GateInput]); 69] = [
-    ((409, false, INV), &[Arg(1, 14)]),
-    ((682, false, INV), &[Arg(2, 201)]),
-    ((683, false, INV), &[Arg(2, 202)]),
-    ((686, false, INV), &[Arg(2, 206)]),
-    ((687, false, INV), &[Arg(2, 207)]),
-    ((688, false, INV), &[Arg(2, 208)]),
-    ((691, false, INV), &[Arg(2, 250)]),
-    ((692, false, INV), &[Arg(2, 252)]),
-    ((694, false, INV), &[Arg(2, 258)]),
-    ((697, false, INV), &[Arg(2, 261)]),
-    ((698, false, INV), &[Arg(2, 262)]),
-    ((1976, false, NAND2), &[Arg(1, 15), Tv(681)]),
-    ((1977, false, NAND2), &[Arg(1, 12), Tv(680)]),
-    ((1980, false, NAND2), &[Tv(389), Arg(2, 152)]),
-    ((1981, false, NAND2), &[Tv(411), Arg(2, 162)]),
-    ((1983, false, NAND2), &[Arg(1, 3), Tv(674)]),
-    ((1984, false, NAND2), &[Arg(1, 6), Tv(677)]),
-    ((1990, false, NAND2), &[Arg(1, 10), Tv(678)]),
-    ((1991, false, NAND2), &[Tv(407), Arg(2, 164)]),
-    ((2000, false, AND2), &[Tv(1998), Tv(1999)]),
-    ((2003, false, AND2), &[Tv(2001), Tv(2002)]),
-    ((2005, false, XNOR2), &[Arg(1, 13), Arg(2, 165)]),
-    ((2006, false, XNOR2), &[Arg(1, 9), Arg(2, 161)]),
-    ((2011, false, AND2), &[Tv(2009), Tv(2010)]),
-    ((2014, false, AND2), &[Tv(2012), Tv(2013)]),
-    ((2018, false, AND2), &[Tv(2016), Tv(2017)]),
-    ((2021, false, AND2), &[Tv(2019), Tv(2020)]),
-    ((2027, false, NOR2), &[Arg(2, 122), Arg(2, 123)]),
-    ((2028, false, NOR2), &[Arg(2, 121), Arg(2, 120)]),
-    ((2030, false, NOR2), &[Arg(2, 124), Arg(2, 125)]),
-    ((2031, false, NOR2), &[Arg(2, 126), Arg(2, 127)]),
-    ((2034, false, XNOR2), &[Arg(1, 1), Arg(2, 105)]),
-    ((2035, false, XNOR2), &[Arg(1, 2), Arg(2, 106)]),
-    ((2039, false, AND2), &[Tv(2037), Tv(2038)]),
-    ((2040, false, XNOR2), &[Arg(1, 4), Arg(2, 108)]),
-    ((2044, false, XNOR2), &[Arg(1, 9), Arg(2, 113)]),
-    ((2047, false, AND2), &[Tv(2045), Tv(2046)]),
-    ((2049, false, XNOR2), &[Arg(1, 14), Arg(2, 118)]),
-    ((2050, false, XNOR2), &[Arg(1, 8), Arg(2, 112)]),
-    ((2057, false, AND2), &[Tv(2053), Tv(2056)]),
-    ((2062, false, AND2), &[Tv(2060), Tv(2061)]),
-    ((2070, false, AND2), &[Tv(2066), Tv(2069)]),
-    ((2077, false, AND2), &[Tv(2073), Tv(2076)]),
-    ((2114, false, NAND2), &[Arg(1, 14), Tv(690)]),
-    ((2115, false, NAND2), &[Tv(394), Arg(2, 205)]),
-    ((2122, false, NAND2), &[Tv(404), Arg(2, 208)]),
-    ((2123, false, NAND2), &[Tv(411), Arg(2, 210)]),
-    ((2125, false, NAND2), &[Arg(1, 10), Tv(689)]),
-    ((2126, false, NAND2), &[Arg(1, 3), Tv(684)]),
-    ((2129, false, NAND2), &[Tv(395), Arg(2, 206)]),
-    ((2130, false, NAND2), &[Tv(410), Arg(2, 207)]),
-    ((2132, false, NAND2), &[Tv(392), Arg(2, 203)]),
-    ((2133, false, NAND2), &[Arg(1, 5), Tv(685)]),
-    ((2490, false, AND2), &[Tv(2488), Tv(2489)]),
-    ((2493, false, AND2), &[Tv(2491), Tv(2492)]),
-    ((2499, false, AND2), &[Tv(2495), Tv(2498)]),
-    ((2504, false, AND2), &[Tv(2500), Tv(2503)]),
-    ((2509, false, AND2), &[Tv(2507), Tv(2508)]),
-    ((2512, false, AND2), &[Tv(2510), Tv(2511)]),
-    ((2528, false, AND2), &[Tv(2520), Tv(2527)]),
-    ((2539, false, AND2), &[Tv(2531), Tv(2538)]),
-    ((2606, false, AND2), &[Tv(2604), Tv(2605)]),
-    ((2609, false, AND2), &[Tv(2607), Tv(2608)]),
-    ((2615, false, AND2), &[Tv(2611), Tv(2614)]),
-    ((2620, false, AND2), &[Tv(2618), Tv(2619)]),
-    ((2627, false, AND2), &[Tv(2623), Tv(2626)]),
-    ((2630, false, AND2), &[Tv(2628), Tv(2629)]),
-    ((2642, false, AND2), &[Tv(2638), Tv(2641)]),
-    ((2655, false, AND2), &[Tv(2647), Tv(2654)]),
-];
-
-static LEVEL_5: [((usize, bool, CellType), &[GateInput]); 71] = [
-    ((2793, false, NAND2), &[Arg(1, 4), Tv(692)]),
-    ((2794, false, NAND2), &[Tv(411), Arg(2, 258)]),
-    ((2796, false, NAND2), &[Arg(1, 14), Tv(698)]),
-    ((2797, false, NAND2), &[Arg(1, 2), Tv(691)]),
-    ((2804, false, NAND2), &[Arg(1, 13), Tv(697)]),
-    ((2805, false, NAND2), &[Arg(1, 10), Tv(694)]),
-    ((2807, false, NAND2), &[Tv(408), Arg(2, 261)]),
-    ((2808, false, NAND2), &[Tv(409), Arg(2, 262)]),
-    ((2811, false, NAND2), &[Tv(406), Arg(2, 259)]),
-    ((2812, false, NAND2), &[Tv(393), Arg(2, 252)]),
-    ((693, false, INV), &[Arg(2, 254)]),
-    ((695, false, INV), &[Arg(2, 259)]),
-    ((696, false, INV), &[Arg(2, 260)]),
-    ((701, false, INV), &[Arg(2, 299)]),
-    ((702, false, INV), &[Arg(2, 301)]),
-    ((706, false, INV), &[Arg(2, 306)]),
-    ((707, false, INV), &[Arg(2, 310)]),
-    ((730, false, INV), &[Arg(2, 442)]),
-    ((732, false, INV), &[Arg(2, 444)]),
-    ((733, false, INV), &[Arg(2, 446)]),
-    ((735, false, INV), &[Arg(2, 449)]),
-    ((736, false, INV), &[Arg(2, 453)]),
-    ((737, false, INV), &[Arg(2, 455)]),
-    ((1968, false, NOR2), &[Arg(2, 170), Arg(2, 171)]),
-    ((1969, false, NOR2), &[Arg(2, 169), Arg(2, 168)]),
-    ((1971, false, NOR2), &[Arg(2, 174), Arg(2, 175)]),
-    ((1972, false, NOR2), &[Arg(2, 172), Arg(2, 173)]),
-    ((1975, false, XNOR2), &[Arg(1, 14), Arg(2, 166)]),
-    ((1978, false, AND2), &[Tv(1976), Tv(1977)]),
-    ((1982, false, AND2), &[Tv(1980), Tv(1981)]),
-    ((1985, false, AND2), &[Tv(1983), Tv(1984)]),
-    ((1989, false, XNOR2), &[Arg(1, 7), Arg(2, 159)]),
-    ((1992, false, AND2), &[Tv(1990), Tv(1991)]),
-    ((1994, false, XNOR2), &[Arg(1, 1), Arg(2, 153)]),
-    ((1995, false, XNOR2), &[Arg(1, 8), Arg(2, 160)]),
-    ((2004, false, AND2), &[Tv(2000), Tv(2003)]),
-    ((2007, false, AND2), &[Tv(2005), Tv(2006)]),
-    ((2015, false, AND2), &[Tv(2011), Tv(2014)]),
-    ((2022, false, AND2), &[Tv(2018), Tv(2021)]),
-    ((2029, false, AND2), &[Tv(2027), Tv(2028)]),
-    ((2032, false, AND2), &[Tv(2030), Tv(2031)]),
-    ((2036, false, AND2), &[Tv(2034), Tv(2035)]),
-    ((2041, false, AND2), &[Tv(2039), Tv(2040)]),
-    ((2048, false, AND2), &[Tv(2044), Tv(2047)]),
-    ((2051, false, AND2), &[Tv(2049), Tv(2050)]),
-    ((2063, false, AND2), &[Tv(2057), Tv(2062)]),
-    ((2078, false, AND2), &[Tv(2070), Tv(2077)]),
-    ((2091, false, NAND2), &[Tv(391), Arg(2, 202)]),
-    ((2092, false, NAND2), &[Arg(1, 8), Tv(688)]),
-    ((2095, false, NAND2), &[Arg(1, 6), Tv(686)]),
-    ((2096, false, NAND2), &[Tv(409), Arg(2, 214)]),
-    ((2098, false, NAND2), &[Arg(1, 7), Tv(687)]),
-    ((2099, false, NAND2), &[Tv(390), Arg(2, 201)]),
-    ((2105, false, NAND2), &[Arg(1, 2), Tv(683)]),
-    ((2106, false, NAND2), &[Arg(1, 1), Tv(682)]),
-    ((2113, false, XNOR2), &[Arg(1, 13), Arg(2, 213)]),
-    ((2116, false, AND2), &[Tv(2114), Tv(2115)]),
-    ((2118, false, XNOR2), &[Arg(1, 4), Arg(2, 204)]),
-    ((2119, false, XNOR2), &[Arg(1, 12), Arg(2, 212)]),
-    ((2124, false, AND2), &[Tv(2122), Tv(2123)]),
-    ((2127, false, AND2), &[Tv(2125), Tv(2126)]),
-    ((2131, false, AND2), &[Tv(2129), Tv(2130)]),
-    ((2134, false, AND2), &[Tv(2132), Tv(2133)]),
-    ((2494, false, NAND2), &[Tv(2490), Tv(2493)]),
-    ((2505, false, AND2), &[Tv(2499), Tv(2504)]),
-    ((2513, false, AND2), &[Tv(2509), Tv(2512)]),
-    ((2540, false, AND2), &[Tv(2528), Tv(2539)]),
-    ((2610, false, NAND2), &[Tv(2606), Tv(2609)]),
-    ((2621, false, AND2), &[Tv(2615), Tv(2620)]),
-    ((2631, false, AND2), &[Tv(2627), Tv(2630)]),
-    ((2656, false, AND2), &[Tv(2642), Tv(2655)]),
-];
-
-static LEVEL_6: [((usize, bool, CellType), &[GateInput]); 89] = [
-    ((2773, false, NAND2), &[Arg(1, 12), Tv(696)]),
-    ((2775, false, NAND2), &[Tv(407), Arg(2, 260)]),
-    ((2776, false, NAND2), &[Tv(391), Arg(2, 250)]),
-    ((2780, false, NAND2), &[Arg(1, 6), Tv(693)]),
-    ((2781, false, NAND2), &[Arg(1, 11), Tv(695)]),
-    ((2795, false, AND2), &[Tv(2793), Tv(2794)]),
-    ((2798, false, AND2), &[Tv(2796), Tv(2797)]),
-    ((2800, false, XNOR2), &[Arg(1, 8), Arg(2, 256)]),
-    ((2801, false, XNOR2), &[Arg(1, 7), Arg(2, 255)]),
-    ((2806, false, AND2), &[Tv(2804), Tv(2805)]),
-    ((2809, false, AND2), &[Tv(2807), Tv(2808)]),
-    ((2813, false, AND2), &[Tv(2811), Tv(2812)]),
-    ((2814, false, XNOR2), &[Arg(1, 1), Arg(2, 249)]),
-    ((699, false, INV), &[Arg(2, 297)]),
-    ((700, false, INV), &[Arg(2, 298)]),
-    ((703, false, INV), &[Arg(2, 302)]),
-    ((704, false, INV), &[Arg(2, 303)]),
-    ((705, false, INV), &[Arg(2, 304)]),
-    ((708, false, INV), &[Arg(2, 344)]),
-    ((709, false, INV), &[Arg(2, 345)]),
-    ((710, false, INV), &[Arg(2, 346)]),
-    ((713, false, INV), &[Arg(2, 352)]),
-    ((714, false, INV), &[Arg(2, 353)]),
-    ((715, false, INV), &[Arg(2, 354)]),
-    ((717, false, INV), &[Arg(2, 357)]),
-    ((718, false, INV), &[Arg(2, 393)]),
-    ((719, false, INV), &[Arg(2, 394)]),
-    ((720, false, INV), &[Arg(2, 395)]),
-    ((721, false, INV), &[Arg(2, 396)]),
-    ((724, false, INV), &[Arg(2, 399)]),
-    ((725, false, INV), &[Arg(2, 400)]),
-    ((726, false, INV), &[Arg(2, 404)]),
-    ((727, false, INV), &[Arg(2, 405)]),
-    ((728, false, INV), &[Arg(2, 406)]),
-    ((731, false, INV), &[Arg(2, 443)]),
-    ((734, false, INV), &[Arg(2, 447)]),
-    ((1874, false, NAND2), &[Arg(1, 4), Tv(732)]),
-    ((1875, false, NAND2), &[Arg(1, 2), Tv(730)]),
-    ((1877, false, NAND2), &[Tv(391), Arg(2, 442)]),
-    ((1878, false, NAND2), &[Tv(405), Arg(2, 449)]),
-    ((1885, false, NAND2), &[Arg(1, 15), Tv(737)]),
-    ((1886, false, NAND2), &[Tv(408), Arg(2, 453)]),
-    ((1888, false, NAND2), &[Arg(1, 13), Tv(736)]),
-    ((1889, false, NAND2), &[Arg(1, 9), Tv(735)]),
-    ((1892, false, NAND2), &[Arg(1, 6), Tv(733)]),
-    ((1893, false, NAND2), &[Tv(393), Arg(2, 444)]),
-    ((1970, false, AND2), &[Tv(1968), Tv(1969)]),
-    ((1973, false, AND2), &[Tv(1971), Tv(1972)]),
-    ((1979, false, AND2), &[Tv(1975), Tv(1978)]),
-    ((1986, false, AND2), &[Tv(1982), Tv(1985)]),
-    ((1993, false, AND2), &[Tv(1989), Tv(1992)]),
-    ((1996, false, AND2), &[Tv(1994), Tv(1995)]),
-    ((2008, false, AND2), &[Tv(2004), Tv(2007)]),
-    ((2023, false, AND2), &[Tv(2015), Tv(2022)]),
-    ((2033, false, NAND2), &[Tv(2029), Tv(2032)]),
-    ((2042, false, AND2), &[Tv(2036), Tv(2041)]),
-    ((2052, false, AND2), &[Tv(2048), Tv(2051)]),
-    ((2079, false, AND2), &[Tv(2063), Tv(2078)]),
-    ((2083, false, NOR2), &[Arg(2, 218), Arg(2, 219)]),
-    ((2084, false, NOR2), &[Arg(2, 217), Arg(2, 216)]),
-    ((2086, false, NOR2), &[Arg(2, 222), Arg(2, 223)]),
-    ((2087, false, NOR2), &[Arg(2, 220), Arg(2, 221)]),
-    ((2090, false, XNOR2), &[Arg(1, 15), Arg(2, 215)]),
-    ((2093, false, AND2), &[Tv(2091), Tv(2092)]),
-    ((2097, false, AND2), &[Tv(2095), Tv(2096)]),
-    ((2100, false, AND2), &[Tv(2098), Tv(2099)]),
-    ((2104, false, XNOR2), &[Arg(1, 0), Arg(2, 200)]),
-    ((2107, false, AND2), &[Tv(2105), Tv(2106)]),
-    ((2109, false, XNOR2), &[Arg(1, 9), Arg(2, 209)]),
-    ((2110, false, XNOR2), &[Arg(1, 11), Arg(2, 211)]),
-    ((2117, false, AND2), &[Tv(2113), Tv(2116)]),
-    ((2120, false, AND2), &[Tv(2118), Tv(2119)]),
-    ((2128, false, AND2), &[Tv(2124), Tv(2127)]),
-    ((2135, false, AND2), &[Tv(2131), Tv(2134)]),
-    ((2462, false, NAND2), &[Arg(1, 14), Tv(707)]),
-    ((2463, false, NAND2), &[Tv(394), Arg(2, 301)]),
-    ((2470, false, NAND2), &[Tv(404), Arg(2, 304)]),
-    ((2471, false, NAND2), &[Tv(411), Arg(2, 306)]),
-    ((2473, false, NAND2), &[Arg(1, 10), Tv(706)]),
-    ((2474, false, NAND2), &[Arg(1, 3), Tv(701)]),
-    ((2477, false, NAND2), &[Tv(395), Arg(2, 302)]),
-    ((2478, false, NAND2), &[Tv(410), Arg(2, 303)]),
-    ((2480, false, NAND2), &[Tv(392), Arg(2, 299)]),
-    ((2481, false, NAND2), &[Arg(1, 5), Tv(702)]),
-    ((2506, false, AND2), &[Tv(2494), Tv(2505)]),
-    ((2541, false, AND2), &[Tv(2513), Tv(2540)]),
-    ((2622, false, AND2), &[Tv(2610), Tv(2621)]),
-    ((2657, false, AND2), &[Tv(2631), Tv(2656)]),
-    ((2772, false, NAND2), &[Tv(395), Arg(2, 254)]),
-];
-
-static LEVEL_7: [((usize, bool, CellType), &[GateInput]); 133] = [
-    ((2774, false, AND2), &[Tv(2772), Tv(2773)]),
-    ((2777, false, AND2), &[Tv(2775), Tv(2776)]),
-    ((2779, false, XNOR2), &[Arg(1, 15), Arg(2, 263)]),
-    ((2782, false, AND2), &[Tv(2780), Tv(2781)]),
-    ((2786, false, XNOR2), &[Arg(1, 3), Arg(2, 251)]),
-    ((2787, false, XNOR2), &[Arg(1, 5), Arg(2, 253)]),
-    ((2789, false, XNOR2), &[Arg(1, 9), Arg(2, 257)]),
-    ((2790, false, XNOR2), &[Arg(1, 0), Arg(2, 248)]),
-    ((2799, false, AND2), &[Tv(2795), Tv(2798)]),
-    ((2802, false, AND2), &[Tv(2800), Tv(2801)]),
-    ((2810, false, AND2), &[Tv(2806), Tv(2809)]),
-    ((2815, false, AND2), &[Tv(2813), Tv(2814)]),
-    ((64, false, INV), &[Arg(2, 76)]),
-    ((65, false, INV), &[Arg(2, 77)]),
-    ((66, false, INV), &[Arg(2, 78)]),
-    ((67, false, INV), &[Arg(2, 79)]),
-    ((68, false, INV), &[Arg(2, 74)]),
-    ((69, false, INV), &[Arg(2, 75)]),
-    ((70, false, INV), &[Arg(2, 73)]),
-    ((71, false, INV), &[Arg(2, 72)]),
-    ((74, false, INV), &[Arg(2, 50)]),
-    ((75, false, INV), &[Arg(2, 51)]),
-    ((76, false, INV), &[Arg(2, 52)]),
-    ((77, false, INV), &[Arg(2, 53)]),
-    ((78, false, INV), &[Arg(2, 54)]),
-    ((79, false, INV), &[Arg(2, 55)]),
-    ((189, false, INV), &[Arg(2, 80)]),
-    ((190, false, INV), &[Arg(2, 81)]),
-    ((191, false, INV), &[Arg(2, 82)]),
-    ((192, false, INV), &[Arg(2, 83)]),
-    ((193, false, INV), &[Arg(2, 84)]),
-    ((194, false, INV), &[Arg(2, 85)]),
-    ((195, false, INV), &[Arg(2, 86)]),
-    ((196, false, INV), &[Arg(2, 87)]),
-    ((285, false, INV), &[Arg(2, 88)]),
-    ((286, false, INV), &[Arg(2, 89)]),
-    ((287, false, INV), &[Arg(2, 90)]),
-    ((288, false, INV), &[Arg(2, 91)]),
-    ((289, false, INV), &[Arg(2, 92)]),
-    ((290, false, INV), &[Arg(2, 93)]),
-    ((291, false, INV), &[Arg(2, 94)]),
-    ((292, false, INV), &[Arg(2, 95)]),
-    ((413, false, INV), &[Arg(2, 494)]),
-    ((414, false, INV), &[Arg(2, 495)]),
-    ((416, false, INV), &[Arg(2, 498)]),
-    ((417, false, INV), &[Arg(2, 500)]),
-    ((418, false, INV), &[Arg(2, 501)]),
-    ((711, false, INV), &[Arg(2, 348)]),
-    ((712, false, INV), &[Arg(2, 350)]),
-    ((716, false, INV), &[Arg(2, 356)]),
-    ((722, false, INV), &[Arg(2, 397)]),
-    ((723, false, INV), &[Arg(2, 398)]),
-    ((729, false, INV), &[Arg(2, 407)]),
-    ((738, false, INV), &[Arg(2, 488)]),
-    ((740, false, INV), &[Arg(2, 491)]),
-    ((1853, false, NAND2), &[Tv(392), Arg(2, 443)]),
-    ((1854, false, NAND2), &[Arg(1, 7), Tv(734)]),
-    ((1856, false, NAND2), &[Tv(410), Arg(2, 447)]),
-    ((1857, false, NAND2), &[Tv(395), Arg(2, 446)]),
-    ((1861, false, NAND2), &[Arg(1, 3), Tv(731)]),
-    ((1862, false, NAND2), &[Tv(412), Arg(2, 455)]),
-    ((1876, false, AND2), &[Tv(1874), Tv(1875)]),
-    ((1879, false, AND2), &[Tv(1877), Tv(1878)]),
-    ((1881, false, XNOR2), &[Arg(1, 11), Arg(2, 451)]),
-    ((1882, false, XNOR2), &[Arg(1, 5), Arg(2, 445)]),
-    ((1887, false, AND2), &[Tv(1885), Tv(1886)]),
-    ((1890, false, AND2), &[Tv(1888), Tv(1889)]),
-    ((1894, false, AND2), &[Tv(1892), Tv(1893)]),
-    ((1895, false, XNOR2), &[Arg(1, 10), Arg(2, 450)]),
-    ((1932, false, NAND2), &[Tv(410), Arg(2, 399)]),
-    ((1933, false, NAND2), &[Arg(1, 3), Tv(720)]),
-    ((1935, false, NAND2), &[Tv(392), Arg(2, 395)]),
-    ((1936, false, NAND2), &[Arg(1, 13), Tv(727)]),
-    ((1939, false, NAND2), &[Arg(1, 8), Tv(725)]),
-    ((1940, false, NAND2), &[Arg(1, 14), Tv(728)]),
-    ((1942, false, NAND2), &[Tv(409), Arg(2, 406)]),
-    ((1943, false, NAND2), &[Arg(1, 7), Tv(724)]),
-    ((1947, false, NAND2), &[Tv(407), Arg(2, 404)]),
-    ((1948, false, NAND2), &[Arg(1, 2), Tv(719)]),
-    ((1950, false, NAND2), &[Arg(1, 1), Tv(718)]),
-    ((1951, false, NAND2), &[Tv(393), Arg(2, 396)]),
-    ((1954, false, NAND2), &[Arg(1, 12), Tv(726)]),
-    ((1955, false, NAND2), &[Tv(408), Arg(2, 405)]),
-    ((1957, false, NAND2), &[Tv(412), Arg(2, 407)]),
-    ((1958, false, NAND2), &[Arg(1, 4), Tv(721)]),
-    ((1974, false, NAND2), &[Tv(1970), Tv(1973)]),
-    ((1987, false, AND2), &[Tv(1979), Tv(1986)]),
-    ((1997, false, AND2), &[Tv(1993), Tv(1996)]),
-    ((2024, false, AND2), &[Tv(2008), Tv(2023)]),
-    ((2043, false, AND2), &[Tv(2033), Tv(2042)]),
-    ((2080, false, AND2), &[Tv(2052), Tv(2079)]),
-    ((2085, false, AND2), &[Tv(2083), Tv(2084)]),
-    ((2088, false, AND2), &[Tv(2086), Tv(2087)]),
-    ((2094, false, AND2), &[Tv(2090), Tv(2093)]),
-    ((2101, false, AND2), &[Tv(2097), Tv(2100)]),
-    ((2108, false, AND2), &[Tv(2104), Tv(2107)]),
-    ((2111, false, AND2), &[Tv(2109), Tv(2110)]),
-    ((2121, false, AND2), &[Tv(2117), Tv(2120)]),
-    ((2136, false, AND2), &[Tv(2128), Tv(2135)]),
-    ((2439, false, NAND2), &[Tv(391), Arg(2, 298)]),
-    ((2440, false, NAND2), &[Arg(1, 8), Tv(705)]),
-    ((2443, false, NAND2), &[Arg(1, 6), Tv(703)]),
-    ((2444, false, NAND2), &[Tv(409), Arg(2, 310)]),
-    ((2446, false, NAND2), &[Arg(1, 7), Tv(704)]),
-    ((2447, false, NAND2), &[Tv(390), Arg(2, 297)]),
-    ((2453, false, NAND2), &[Arg(1, 2), Tv(700)]),
-    ((2454, false, NAND2), &[Arg(1, 1), Tv(699)]),
-    ((2461, false, XNOR2), &[Arg(1, 13), Arg(2, 309)]),
-    ((2464, false, AND2), &[Tv(2462), Tv(2463)]),
-    ((2466, false, XNOR2), &[Arg(1, 4), Arg(2, 300)]),
-    ((2467, false, XNOR2), &[Arg(1, 12), Arg(2, 308)]),
-    ((2472, false, AND2), &[Tv(2470), Tv(2471)]),
-    ((2475, false, AND2), &[Tv(2473), Tv(2474)]),
-    ((2479, false, AND2), &[Tv(2477), Tv(2478)]),
-    ((2482, false, AND2), &[Tv(2480), Tv(2481)]),
-    ((2542, false, NAND2), &[Tv(2506), Tv(2541)]),
-    ((2573, false, NAND2), &[Tv(395), Arg(2, 350)]),
-    ((2574, false, NAND2), &[Arg(1, 1), Tv(709)]),
-    ((2576, false, NAND2), &[Tv(389), Arg(2, 344)]),
-    ((2577, false, NAND2), &[Arg(1, 13), Tv(717)]),
-    ((2584, false, NAND2), &[Tv(393), Arg(2, 348)]),
-    ((2585, false, NAND2), &[Arg(1, 10), Tv(715)]),
-    ((2587, false, NAND2), &[Tv(411), Arg(2, 354)]),
-    ((2588, false, NAND2), &[Arg(1, 2), Tv(710)]),
-    ((2591, false, NAND2), &[Arg(1, 9), Tv(714)]),
-    ((2592, false, NAND2), &[Arg(1, 8), Tv(713)]),
-    ((2594, false, NAND2), &[Tv(404), Arg(2, 352)]),
-    ((2595, false, NAND2), &[Arg(1, 0), Tv(708)]),
-    ((2658, false, AND2), &[Tv(2622), Tv(2657)]),
-    ((2765, false, NOR2), &[Arg(2, 266), Arg(2, 267)]),
-    ((2766, false, NOR2), &[Arg(2, 265), Arg(2, 264)]),
-    ((2768, false, NOR2), &[Arg(2, 270), Arg(2, 271)]),
-    ((2769, false, NOR2), &[Arg(2, 268), Arg(2, 269)]),
-];
-
-static LEVEL_8: [((usize, bool, CellType), &[GateInput]); 160] = [
-    ((2778, false, AND2), &[Tv(2774), Tv(2777)]),
-    ((2783, false, AND2), &[Tv(2779), Tv(2782)]),
-    ((2788, false, AND2), &[Tv(2786), Tv(2787)]),
-    ((2791, false, AND2), &[Tv(2789), Tv(2790)]),
-    ((2803, false, AND2), &[Tv(2799), Tv(2802)]),
-    ((2816, false, AND2), &[Tv(2810), Tv(2815)]),
-    ((3241, false, AND2), &[Arg(2, 2), Tv(2658)]),
-    ((3243, false, OR2), &[Tv(74), Tv(2542)]),
-    ((3348, false, AND2), &[Arg(2, 3), Tv(2658)]),
-    ((3350, false, OR2), &[Tv(75), Tv(2542)]),
-    ((3455, false, AND2), &[Arg(2, 4), Tv(2658)]),
-    ((3457, false, OR2), &[Tv(76), Tv(2542)]),
-    ((3562, false, AND2), &[Arg(2, 5), Tv(2658)]),
-    ((3564, false, OR2), &[Tv(77), Tv(2542)]),
-    ((3669, false, AND2), &[Arg(2, 6), Tv(2658)]),
-    ((3671, false, OR2), &[Tv(78), Tv(2542)]),
-    ((3776, false, AND2), &[Arg(2, 7), Tv(2658)]),
-    ((3778, false, OR2), &[Tv(79), Tv(2542)]),
-    ((3883, false, AND2), &[Arg(2, 24), Tv(2658)]),
-    ((3885, false, OR2), &[Tv(71), Tv(2542)]),
-    ((3990, false, AND2), &[Arg(2, 25), Tv(2658)]),
-    ((3992, false, OR2), &[Tv(70), Tv(2542)]),
-    ((4097, false, AND2), &[Arg(2, 26), Tv(2658)]),
-    ((4099, false, OR2), &[Tv(68), Tv(2542)]),
-    ((4204, false, AND2), &[Arg(2, 27), Tv(2658)]),
-    ((4206, false, OR2), &[Tv(69), Tv(2542)]),
-    ((4311, false, AND2), &[Arg(2, 28), Tv(2658)]),
-    ((4313, false, OR2), &[Tv(64), Tv(2542)]),
-    ((4418, false, AND2), &[Arg(2, 29), Tv(2658)]),
-    ((4420, false, OR2), &[Tv(65), Tv(2542)]),
-    ((4525, false, AND2), &[Arg(2, 30), Tv(2658)]),
-    ((4527, false, OR2), &[Tv(66), Tv(2542)]),
-    ((4632, false, AND2), &[Arg(2, 31), Tv(2658)]),
-    ((4634, false, OR2), &[Tv(67), Tv(2542)]),
-    ((4739, false, AND2), &[Arg(2, 32), Tv(2658)]),
-    ((4741, false, OR2), &[Tv(189), Tv(2542)]),
-    ((4846, false, AND2), &[Arg(2, 33), Tv(2658)]),
-    ((4848, false, OR2), &[Tv(190), Tv(2542)]),
-    ((4953, false, AND2), &[Arg(2, 34), Tv(2658)]),
-    ((4955, false, OR2), &[Tv(191), Tv(2542)]),
-    ((5060, false, AND2), &[Arg(2, 35), Tv(2658)]),
-    ((5062, false, OR2), &[Tv(192), Tv(2542)]),
-    ((5167, false, AND2), &[Arg(2, 36), Tv(2658)]),
-    ((5169, false, OR2), &[Tv(193), Tv(2542)]),
-    ((5274, false, AND2), &[Arg(2, 37), Tv(2658)]),
-    ((5276, false, OR2), &[Tv(194), Tv(2542)]),
-    ((5381, false, AND2), &[Arg(2, 38), Tv(2658)]),
-    ((5383, false, OR2), &[Tv(195), Tv(2542)]),
-    ((5488, false, AND2), &[Arg(2, 39), Tv(2658)]),
-    ((5490, false, OR2), &[Tv(196), Tv(2542)]),
-    ((5595, false, AND2), &[Arg(2, 40), Tv(2658)]),
-    ((5597, false, OR2), &[Tv(285), Tv(2542)]),
-    ((5702, false, AND2), &[Arg(2, 41), Tv(2658)]),
-    ((5704, false, OR2), &[Tv(286), Tv(2542)]),
-    ((5809, false, AND2), &[Arg(2, 42), Tv(2658)]),
-    ((5811, false, OR2), &[Tv(287), Tv(2542)]),
-    ((5916, false, AND2), &[Arg(2, 43), Tv(2658)]),
-    ((5918, false, OR2), &[Tv(288), Tv(2542)]),
-    ((6023, false, AND2), &[Arg(2, 44), Tv(2658)]),
-    ((6025, false, OR2), &[Tv(289), Tv(2542)]),
-    ((6130, false, AND2), &[Arg(2, 45), Tv(2658)]),
-    ((6132, false, OR2), &[Tv(290), Tv(2542)]),
-    ((6237, false, AND2), &[Arg(2, 46), Tv(2658)]),
-    ((6239, false, OR2), &[Tv(291), Tv(2542)]),
-    ((6344, false, AND2), &[Arg(2, 47), Tv(2658)]),
-    ((6346, false, OR2), &[Tv(292), Tv(2542)]),
-    ((415, false, INV), &[Arg(2, 496)]),
-    ((419, false, INV), &[Arg(2, 503)]),
-    ((437, false, INV), &[Arg(2, 634)]),
-    ((440, false, INV), &[Arg(2, 639)]),
-    ((443, false, INV), &[Arg(2, 645)]),
-    ((444, false, INV), &[Arg(2, 647)]),
-    ((739, false, INV), &[Arg(2, 489)]),
-    ((1846, false, NOR2), &[Arg(2, 458), Arg(2, 459)]),
-    ((1847, false, NOR2), &[Arg(2, 457), Arg(2, 456)]),
-    ((1849, false, NOR2), &[Arg(2, 461), Arg(2, 462)]),
-    ((1850, false, NOR2), &[Arg(2, 460), Arg(2, 463)]),
-    ((1855, false, AND2), &[Tv(1853), Tv(1854)]),
-    ((1858, false, AND2), &[Tv(1856), Tv(1857)]),
-    ((1860, false, XNOR2), &[Arg(1, 8), Arg(2, 448)]),
-    ((1863, false, AND2), &[Tv(1861), Tv(1862)]),
-    ((1867, false, XNOR2), &[Arg(1, 1), Arg(2, 441)]),
-    ((1868, false, XNOR2), &[Arg(1, 12), Arg(2, 452)]),
-    ((1870, false, XNOR2), &[Arg(1, 14), Arg(2, 454)]),
-    ((1871, false, XNOR2), &[Arg(1, 0), Arg(2, 440)]),
-    ((1880, false, AND2), &[Tv(1876), Tv(1879)]),
-    ((1883, false, AND2), &[Tv(1881), Tv(1882)]),
-    ((1891, false, AND2), &[Tv(1887), Tv(1890)]),
-    ((1896, false, AND2), &[Tv(1894), Tv(1895)]),
-    ((1909, false, NAND2), &[Tv(390), Arg(2, 393)]),
-    ((1910, false, NAND2), &[Arg(1, 15), Tv(729)]),
-    ((1912, false, NAND2), &[Tv(394), Arg(2, 397)]),
-    ((1913, false, NAND2), &[Tv(395), Arg(2, 398)]),
-    ((1916, false, NAND2), &[Arg(1, 5), Tv(722)]),
-    ((1917, false, NAND2), &[Tv(404), Arg(2, 400)]),
-    ((1924, false, NAND2), &[Arg(1, 6), Tv(723)]),
-    ((1925, false, NAND2), &[Tv(391), Arg(2, 394)]),
-    ((1934, false, AND2), &[Tv(1932), Tv(1933)]),
-    ((1937, false, AND2), &[Tv(1935), Tv(1936)]),
-    ((1941, false, AND2), &[Tv(1939), Tv(1940)]),
-    ((1944, false, AND2), &[Tv(1942), Tv(1943)]),
-    ((1949, false, AND2), &[Tv(1947), Tv(1948)]),
-    ((1952, false, AND2), &[Tv(1950), Tv(1951)]),
-    ((1956, false, AND2), &[Tv(1954), Tv(1955)]),
-    ((1959, false, AND2), &[Tv(1957), Tv(1958)]),
-    ((1988, false, AND2), &[Tv(1974), Tv(1987)]),
-    ((2025, false, AND2), &[Tv(1997), Tv(2024)]),
-    ((2081, false, NAND2), &[Tv(2043), Tv(2080)]),
-    ((2089, false, NAND2), &[Tv(2085), Tv(2088)]),
-    ((2102, false, AND2), &[Tv(2094), Tv(2101)]),
-    ((2112, false, AND2), &[Tv(2108), Tv(2111)]),
-    ((2137, false, AND2), &[Tv(2121), Tv(2136)]),
-    ((2431, false, NOR2), &[Arg(2, 314), Arg(2, 315)]),
-    ((2432, false, NOR2), &[Arg(2, 313), Arg(2, 312)]),
-    ((2434, false, NOR2), &[Arg(2, 318), Arg(2, 319)]),
-    ((2435, false, NOR2), &[Arg(2, 316), Arg(2, 317)]),
-    ((2438, false, XNOR2), &[Arg(1, 15), Arg(2, 311)]),
-    ((2441, false, AND2), &[Tv(2439), Tv(2440)]),
-    ((2445, false, AND2), &[Tv(2443), Tv(2444)]),
-    ((2448, false, AND2), &[Tv(2446), Tv(2447)]),
-    ((2452, false, XNOR2), &[Arg(1, 0), Arg(2, 296)]),
-    ((2455, false, AND2), &[Tv(2453), Tv(2454)]),
-    ((2457, false, XNOR2), &[Arg(1, 9), Arg(2, 305)]),
-    ((2458, false, XNOR2), &[Arg(1, 11), Arg(2, 307)]),
-    ((2465, false, AND2), &[Tv(2461), Tv(2464)]),
-    ((2468, false, AND2), &[Tv(2466), Tv(2467)]),
-    ((2476, false, AND2), &[Tv(2472), Tv(2475)]),
-    ((2483, false, AND2), &[Tv(2479), Tv(2482)]),
-    ((2551, false, NAND2), &[Tv(405), Arg(2, 353)]),
-    ((2552, false, NAND2), &[Arg(1, 12), Tv(716)]),
-    ((2555, false, NAND2), &[Tv(391), Arg(2, 346)]),
-    ((2556, false, NAND2), &[Arg(1, 6), Tv(712)]),
-    ((2558, false, NAND2), &[Tv(390), Arg(2, 345)]),
-    ((2559, false, NAND2), &[Arg(1, 4), Tv(711)]),
-    ((2565, false, NAND2), &[Tv(408), Arg(2, 357)]),
-    ((2566, false, NAND2), &[Tv(407), Arg(2, 356)]),
-    ((2575, false, AND2), &[Tv(2573), Tv(2574)]),
-    ((2578, false, AND2), &[Tv(2576), Tv(2577)]),
-    ((2580, false, XNOR2), &[Arg(1, 15), Arg(2, 359)]),
-    ((2581, false, XNOR2), &[Arg(1, 7), Arg(2, 351)]),
-    ((2586, false, AND2), &[Tv(2584), Tv(2585)]),
-    ((2589, false, AND2), &[Tv(2587), Tv(2588)]),
-    ((2593, false, AND2), &[Tv(2591), Tv(2592)]),
-    ((2596, false, AND2), &[Tv(2594), Tv(2595)]),
-    ((2734, false, NAND2), &[Arg(1, 13), Tv(418)]),
-    ((2735, false, NAND2), &[Arg(1, 3), Tv(740)]),
-    ((2737, false, NAND2), &[Tv(392), Arg(2, 491)]),
-    ((2738, false, NAND2), &[Arg(1, 6), Tv(413)]),
-    ((2741, false, NAND2), &[Arg(1, 0), Tv(738)]),
-    ((2742, false, NAND2), &[Arg(1, 7), Tv(414)]),
-    ((2744, false, NAND2), &[Tv(390), Arg(2, 489)]),
-    ((2745, false, NAND2), &[Arg(1, 10), Tv(416)]),
-    ((2749, false, NAND2), &[Tv(412), Arg(2, 503)]),
-    ((2750, false, NAND2), &[Arg(1, 12), Tv(417)]),
-    ((2752, false, NAND2), &[Tv(404), Arg(2, 496)]),
-    ((2753, false, NAND2), &[Tv(407), Arg(2, 500)]),
-    ((2756, false, NAND2), &[Tv(395), Arg(2, 494)]),
-    ((2757, false, NAND2), &[Tv(389), Arg(2, 488)]),
-    ((2767, false, AND2), &[Tv(2765), Tv(2766)]),
-    ((2770, false, AND2), &[Tv(2768), Tv(2769)]),
-];
-
-static LEVEL_9: [((usize, bool, CellType), &[GateInput]); 218] = [
-    ((2784, false, AND2), &[Tv(2778), Tv(2783)]),
-    ((2792, false, AND2), &[Tv(2788), Tv(2791)]),
-    ((2817, false, AND2), &[Tv(2803), Tv(2816)]),
-    ((3242, false, NAND2), &[Tv(2542), Tv(3241)]),
-    ((3244, false, AND2), &[Tv(2081), Tv(3243)]),
-    ((3246, false, OR2), &[Arg(2, 98), Tv(2081)]),
-    ((3349, false, NAND2), &[Tv(2542), Tv(3348)]),
-    ((3351, false, AND2), &[Tv(2081), Tv(3350)]),
-    ((3353, false, OR2), &[Arg(2, 99), Tv(2081)]),
-    ((3456, false, NAND2), &[Tv(2542), Tv(3455)]),
-    ((3458, false, AND2), &[Tv(2081), Tv(3457)]),
-    ((3460, false, OR2), &[Arg(2, 100), Tv(2081)]),
-    ((3563, false, NAND2), &[Tv(2542), Tv(3562)]),
-    ((3565, false, AND2), &[Tv(2081), Tv(3564)]),
-    ((3567, false, OR2), &[Arg(2, 101), Tv(2081)]),
-    ((3670, false, NAND2), &[Tv(2542), Tv(3669)]),
-    ((3672, false, AND2), &[Tv(2081), Tv(3671)]),
-    ((3674, false, OR2), &[Arg(2, 102), Tv(2081)]),
-    ((3777, false, NAND2), &[Tv(2542), Tv(3776)]),
-    ((3779, false, AND2), &[Tv(2081), Tv(3778)]),
-    ((3781, false, OR2), &[Arg(2, 103), Tv(2081)]),
-    ((3884, false, NAND2), &[Tv(2542), Tv(3883)]),
-    ((3886, false, AND2), &[Tv(2081), Tv(3885)]),
-    ((3888, false, OR2), &[Arg(2, 120), Tv(2081)]),
-    ((3991, false, NAND2), &[Tv(2542), Tv(3990)]),
-    ((3993, false, AND2), &[Tv(2081), Tv(3992)]),
-    ((3995, false, OR2), &[Arg(2, 121), Tv(2081)]),
-    ((4098, false, NAND2), &[Tv(2542), Tv(4097)]),
-    ((4100, false, AND2), &[Tv(2081), Tv(4099)]),
-    ((4102, false, OR2), &[Arg(2, 122), Tv(2081)]),
-    ((4205, false, NAND2), &[Tv(2542), Tv(4204)]),
-    ((4207, false, AND2), &[Tv(2081), Tv(4206)]),
-    ((4209, false, OR2), &[Arg(2, 123), Tv(2081)]),
-    ((4312, false, NAND2), &[Tv(2542), Tv(4311)]),
-    ((4314, false, AND2), &[Tv(2081), Tv(4313)]),
-    ((4316, false, OR2), &[Arg(2, 124), Tv(2081)]),
-    ((4419, false, NAND2), &[Tv(2542), Tv(4418)]),
-    ((4421, false, AND2), &[Tv(2081), Tv(4420)]),
-    ((4423, false, OR2), &[Arg(2, 125), Tv(2081)]),
-    ((4526, false, NAND2), &[Tv(2542), Tv(4525)]),
-    ((4528, false, AND2), &[Tv(2081), Tv(4527)]),
-    ((4530, false, OR2), &[Arg(2, 126), Tv(2081)]),
-    ((4633, false, NAND2), &[Tv(2542), Tv(4632)]),
-    ((4635, false, AND2), &[Tv(2081), Tv(4634)]),
-    ((4637, false, OR2), &[Arg(2, 127), Tv(2081)]),
-    ((4740, false, NAND2), &[Tv(2542), Tv(4739)]),
-    ((4742, false, AND2), &[Tv(2081), Tv(4741)]),
-    ((4744, false, OR2), &[Arg(2, 128), Tv(2081)]),
-    ((4847, false, NAND2), &[Tv(2542), Tv(4846)]),
-    ((4849, false, AND2), &[Tv(2081), Tv(4848)]),
-    ((4851, false, OR2), &[Arg(2, 129), Tv(2081)]),
-    ((4954, false, NAND2), &[Tv(2542), Tv(4953)]),
-    ((4956, false, AND2), &[Tv(2081), Tv(4955)]),
-    ((4958, false, OR2), &[Arg(2, 130), Tv(2081)]),
-    ((5061, false, NAND2), &[Tv(2542), Tv(5060)]),
-    ((5063, false, AND2), &[Tv(2081), Tv(5062)]),
-    ((5065, false, OR2), &[Arg(2, 131), Tv(2081)]),
-    ((5168, false, NAND2), &[Tv(2542), Tv(5167)]),
-    ((5170, false, AND2), &[Tv(2081), Tv(5169)]),
-    ((5172, false, OR2), &[Arg(2, 132), Tv(2081)]),
-    ((5275, false, NAND2), &[Tv(2542), Tv(5274)]),
-    ((5277, false, AND2), &[Tv(2081), Tv(5276)]),
-    ((5279, false, OR2), &[Arg(2, 133), Tv(2081)]),
-    ((5382, false, NAND2), &[Tv(2542), Tv(5381)]),
-    ((5384, false, AND2), &[Tv(2081), Tv(5383)]),
-    ((5386, false, OR2), &[Arg(2, 134), Tv(2081)]),
-    ((5489, false, NAND2), &[Tv(2542), Tv(5488)]),
-    ((5491, false, AND2), &[Tv(2081), Tv(5490)]),
-    ((5493, false, OR2), &[Arg(2, 135), Tv(2081)]),
-    ((5596, false, NAND2), &[Tv(2542), Tv(5595)]),
-    ((5598, false, AND2), &[Tv(2081), Tv(5597)]),
-    ((5600, false, OR2), &[Arg(2, 136), Tv(2081)]),
-    ((5703, false, NAND2), &[Tv(2542), Tv(5702)]),
-    ((5705, false, AND2), &[Tv(2081), Tv(5704)]),
-    ((5707, false, OR2), &[Arg(2, 137), Tv(2081)]),
-    ((5810, false, NAND2), &[Tv(2542), Tv(5809)]),
-    ((5812, false, AND2), &[Tv(2081), Tv(5811)]),
-    ((5814, false, OR2), &[Arg(2, 138), Tv(2081)]),
-    ((5917, false, NAND2), &[Tv(2542), Tv(5916)]),
-    ((5919, false, AND2), &[Tv(2081), Tv(5918)]),
-    ((5921, false, OR2), &[Arg(2, 139), Tv(2081)]),
-    ((6024, false, NAND2), &[Tv(2542), Tv(6023)]),
-    ((6026, false, AND2), &[Tv(2081), Tv(6025)]),
-    ((6028, false, OR2), &[Arg(2, 140), Tv(2081)]),
-    ((6131, false, NAND2), &[Tv(2542), Tv(6130)]),
-    ((6133, false, AND2), &[Tv(2081), Tv(6132)]),
-    ((6135, false, OR2), &[Arg(2, 141), Tv(2081)]),
-    ((6238, false, NAND2), &[Tv(2542), Tv(6237)]),
-    ((6240, false, AND2), &[Tv(2081), Tv(6239)]),
-    ((6242, false, OR2), &[Arg(2, 142), Tv(2081)]),
-    ((6345, false, NAND2), &[Tv(2542), Tv(6344)]),
-    ((6347, false, AND2), &[Tv(2081), Tv(6346)]),
-    ((6349, false, OR2), &[Arg(2, 143), Tv(2081)]),
-    ((56, false, INV), &[Arg(2, 172)]),
-    ((57, false, INV), &[Arg(2, 173)]),
-    ((58, false, INV), &[Arg(2, 174)]),
-    ((59, false, INV), &[Arg(2, 175)]),
-    ((60, false, INV), &[Arg(2, 170)]),
-    ((61, false, INV), &[Arg(2, 171)]),
-    ((62, false, INV), &[Arg(2, 169)]),
-    ((63, false, INV), &[Arg(2, 168)]),
-    ((72, false, INV), &[Arg(2, 48)]),
-    ((73, false, INV), &[Arg(2, 49)]),
-    ((82, false, INV), &[Arg(2, 146)]),
-    ((83, false, INV), &[Arg(2, 147)]),
-    ((84, false, INV), &[Arg(2, 148)]),
-    ((85, false, INV), &[Arg(2, 149)]),
-    ((86, false, INV), &[Arg(2, 150)]),
-    ((87, false, INV), &[Arg(2, 151)]),
-    ((197, false, INV), &[Arg(2, 176)]),
-    ((198, false, INV), &[Arg(2, 177)]),
-    ((199, false, INV), &[Arg(2, 178)]),
-    ((200, false, INV), &[Arg(2, 179)]),
-    ((201, false, INV), &[Arg(2, 180)]),
-    ((202, false, INV), &[Arg(2, 181)]),
-    ((203, false, INV), &[Arg(2, 182)]),
-    ((204, false, INV), &[Arg(2, 183)]),
-    ((293, false, INV), &[Arg(2, 184)]),
-    ((294, false, INV), &[Arg(2, 185)]),
-    ((295, false, INV), &[Arg(2, 186)]),
-    ((296, false, INV), &[Arg(2, 187)]),
-    ((297, false, INV), &[Arg(2, 188)]),
-    ((298, false, INV), &[Arg(2, 189)]),
-    ((299, false, INV), &[Arg(2, 190)]),
-    ((300, false, INV), &[Arg(2, 191)]),
-    ((420, false, INV), &[Arg(2, 536)]),
-    ((421, false, INV), &[Arg(2, 537)]),
-    ((422, false, INV), &[Arg(2, 538)]),
-    ((424, false, INV), &[Arg(2, 540)]),
-    ((427, false, INV), &[Arg(2, 545)]),
-    ((429, false, INV), &[Arg(2, 547)]),
-    ((432, false, INV), &[Arg(2, 551)]),
-    ((433, false, INV), &[Arg(2, 593)]),
-    ((434, false, INV), &[Arg(2, 597)]),
-    ((435, false, INV), &[Arg(2, 599)]),
-    ((436, false, INV), &[Arg(2, 633)]),
-    ((438, false, INV), &[Arg(2, 637)]),
-    ((439, false, INV), &[Arg(2, 638)]),
-    ((441, false, INV), &[Arg(2, 640)]),
-    ((442, false, INV), &[Arg(2, 644)]),
-    ((1848, false, AND2), &[Tv(1846), Tv(1847)]),
+static LEVEL_4: [((usize, bool, CellType), &[GateInput]); 114] = [
+    ((73, false, AND2), &[Tv(71), Tv(72)]),
+    ((96, false, AND2), &[Tv(94), Tv(95)]),
+    ((128, false, AND2), &[Tv(126), Tv(127)]),
+    ((151, false, AND2), &[Tv(149), Tv(150)]),
+    ((183, false, AND2), &[Tv(181), Tv(182)]),
+    ((206, false, AND2), &[Tv(204), Tv(205)]),
+    ((238, false, AND2), &[Tv(236), Tv(237)]),
+    ((261, false, AND2), &[Tv(259), Tv(260)]),
+    ((293, false, AND2), &[Tv(291), Tv(292)]),
+    ((316, false, AND2), &[Tv(314), Tv(315)]),
+    ((348, false, AND2), &[Tv(346), Tv(347)]),
+    ((371, false, AND2), &[Tv(369), Tv(370)]),
+    ((403, false, AND2), &[Tv(401), Tv(402)]),
+    ((426, false, AND2), &[Tv(424), Tv(425)]),
+    ((458, false, AND2), &[Tv(456), Tv(457)]),
+    ((481, false, AND2), &[Tv(479), Tv(480)]),
+    ((513, false, AND2), &[Tv(511), Tv(512)]),
+    ((536, false, AND2), &[Tv(534), Tv(535)]),
+    ((568, false, AND2), &[Tv(566), Tv(567)]),
+    ((591, false, AND2), &[Tv(589), Tv(590)]),
+    ((623, false, AND2), &[Tv(621), Tv(622)]),
+    ((646, false, AND2), &[Tv(644), Tv(645)]),
+    ((678, false, AND2), &[Tv(676), Tv(677)]),
+    ((701, false, AND2), &[Tv(699), Tv(700)]),
+    ((733, false, AND2), &[Tv(731), Tv(732)]),
+    ((756, false, AND2), &[Tv(754), Tv(755)]),
+    ((788, false, AND2), &[Tv(786), Tv(787)]),
+    ((811, false, AND2), &[Tv(809), Tv(810)]),
+    ((843, false, AND2), &[Tv(841), Tv(842)]),
+    ((866, false, AND2), &[Tv(864), Tv(865)]),
+    ((898, false, AND2), &[Tv(896), Tv(897)]),
+    ((921, false, AND2), &[Tv(919), Tv(920)]),
+    ((953, false, AND2), &[Tv(951), Tv(952)]),
+    ((976, false, AND2), &[Tv(974), Tv(975)]),
+    ((1008, false, AND2), &[Tv(1006), Tv(1007)]),
+    ((1031, false, AND2), &[Tv(1029), Tv(1030)]),
+    ((1063, false, AND2), &[Tv(1061), Tv(1062)]),
+    ((1086, false, AND2), &[Tv(1084), Tv(1085)]),
+    ((1118, false, AND2), &[Tv(1116), Tv(1117)]),
+    ((1141, false, AND2), &[Tv(1139), Tv(1140)]),
+    ((1173, false, AND2), &[Tv(1171), Tv(1172)]),
+    ((1196, false, AND2), &[Tv(1194), Tv(1195)]),
+    ((1228, false, AND2), &[Tv(1226), Tv(1227)]),
+    ((1251, false, AND2), &[Tv(1249), Tv(1250)]),
+    ((1283, false, AND2), &[Tv(1281), Tv(1282)]),
+    ((1306, false, AND2), &[Tv(1304), Tv(1305)]),
+    ((1338, false, AND2), &[Tv(1336), Tv(1337)]),
+    ((1361, false, AND2), &[Tv(1359), Tv(1360)]),
+    ((1387, false, AND2), &[Tv(1385), Tv(1386)]),
+    ((1410, false, AND2), &[Tv(1408), Tv(1409)]),
+    ((1436, false, AND2), &[Tv(1434), Tv(1435)]),
+    ((1459, false, AND2), &[Tv(1457), Tv(1458)]),
+    ((1485, false, AND2), &[Tv(1483), Tv(1484)]),
+    ((1508, false, AND2), &[Tv(1506), Tv(1507)]),
+    ((1534, false, AND2), &[Tv(1532), Tv(1533)]),
+    ((1557, false, AND2), &[Tv(1555), Tv(1556)]),
+    ((1583, false, AND2), &[Tv(1581), Tv(1582)]),
+    ((1606, false, AND2), &[Tv(1604), Tv(1605)]),
+    ((1632, false, AND2), &[Tv(1630), Tv(1631)]),
+    ((1655, false, AND2), &[Tv(1653), Tv(1654)]),
+    ((1681, false, AND2), &[Tv(1679), Tv(1680)]),
+    ((1704, false, AND2), &[Tv(1702), Tv(1703)]),
+    ((1730, false, AND2), &[Tv(1728), Tv(1729)]),
+    ((1753, false, AND2), &[Tv(1751), Tv(1752)]),
+    ((1779, false, AND2), &[Tv(1777), Tv(1778)]),
+    ((1802, false, AND2), &[Tv(1800), Tv(1801)]),
+    ((1828, false, AND2), &[Tv(1826), Tv(1827)]),
     ((1851, false, AND2), &[Tv(1849), Tv(1850)]),
-    ((1859, false, AND2), &[Tv(1855), Tv(1858)]),
-    ((1864, false, AND2), &[Tv(1860), Tv(1863)]),
-    ((1869, false, AND2), &[Tv(1867), Tv(1868)]),
-    ((1872, false, AND2), &[Tv(1870), Tv(1871)]),
-    ((1884, false, AND2), &[Tv(1880), Tv(1883)]),
-    ((1897, false, AND2), &[Tv(1891), Tv(1896)]),
-    ((1902, false, NOR2), &[Arg(2, 410), Arg(2, 411)]),
-    ((1903, false, NOR2), &[Arg(2, 409), Arg(2, 408)]),
-    ((1905, false, NOR2), &[Arg(2, 413), Arg(2, 414)]),
-    ((1906, false, NOR2), &[Arg(2, 412), Arg(2, 415)]),
-    ((1911, false, AND2), &[Tv(1909), Tv(1910)]),
-    ((1914, false, AND2), &[Tv(1912), Tv(1913)]),
-    ((1918, false, AND2), &[Tv(1916), Tv(1917)]),
-    ((1919, false, XNOR2), &[Arg(1, 10), Arg(2, 402)]),
-    ((1923, false, XNOR2), &[Arg(1, 11), Arg(2, 403)]),
+    ((1877, false, AND2), &[Tv(1875), Tv(1876)]),
+    ((1900, false, AND2), &[Tv(1898), Tv(1899)]),
     ((1926, false, AND2), &[Tv(1924), Tv(1925)]),
-    ((1928, false, XNOR2), &[Arg(1, 0), Arg(2, 392)]),
-    ((1929, false, XNOR2), &[Arg(1, 9), Arg(2, 401)]),
-    ((1938, false, AND2), &[Tv(1934), Tv(1937)]),
-    ((1945, false, AND2), &[Tv(1941), Tv(1944)]),
-    ((1953, false, AND2), &[Tv(1949), Tv(1952)]),
-    ((1960, false, AND2), &[Tv(1956), Tv(1959)]),
-    ((2026, false, NAND2), &[Tv(1988), Tv(2025)]),
-    ((2103, false, AND2), &[Tv(2089), Tv(2102)]),
-    ((2138, false, AND2), &[Tv(2112), Tv(2137)]),
-    ((2235, false, NAND2), &[Tv(407), Arg(2, 644)]),
-    ((2236, false, NAND2), &[Arg(1, 15), Tv(444)]),
-    ((2238, false, NAND2), &[Tv(391), Arg(2, 634)]),
-    ((2239, false, NAND2), &[Tv(394), Arg(2, 637)]),
-    ((2243, false, NAND2), &[Arg(1, 13), Tv(443)]),
-    ((2244, false, NAND2), &[Tv(395), Arg(2, 638)]),
-    ((2248, false, NAND2), &[Tv(404), Arg(2, 640)]),
-    ((2249, false, NAND2), &[Arg(1, 7), Tv(440)]),
-    ((2251, false, NAND2), &[Tv(410), Arg(2, 639)]),
-    ((2252, false, NAND2), &[Tv(408), Arg(2, 645)]),
-    ((2256, false, NAND2), &[Tv(390), Arg(2, 633)]),
-    ((2257, false, NAND2), &[Arg(1, 2), Tv(437)]),
-    ((2433, false, AND2), &[Tv(2431), Tv(2432)]),
-    ((2436, false, AND2), &[Tv(2434), Tv(2435)]),
-    ((2442, false, AND2), &[Tv(2438), Tv(2441)]),
-    ((2449, false, AND2), &[Tv(2445), Tv(2448)]),
-    ((2456, false, AND2), &[Tv(2452), Tv(2455)]),
-    ((2459, false, AND2), &[Tv(2457), Tv(2458)]),
-    ((2469, false, AND2), &[Tv(2465), Tv(2468)]),
-    ((2484, false, AND2), &[Tv(2476), Tv(2483)]),
-    ((2543, false, NOR2), &[Arg(2, 362), Arg(2, 363)]),
-    ((2544, false, NOR2), &[Arg(2, 361), Arg(2, 360)]),
-    ((2546, false, NOR2), &[Arg(2, 365), Arg(2, 366)]),
-    ((2547, false, NOR2), &[Arg(2, 364), Arg(2, 367)]),
-    ((2550, false, XNOR2), &[Arg(1, 3), Arg(2, 347)]),
-    ((2553, false, AND2), &[Tv(2551), Tv(2552)]),
-    ((2557, false, AND2), &[Tv(2555), Tv(2556)]),
-    ((2560, false, AND2), &[Tv(2558), Tv(2559)]),
-    ((2564, false, XNOR2), &[Arg(1, 14), Arg(2, 358)]),
-    ((2567, false, AND2), &[Tv(2565), Tv(2566)]),
-    ((2569, false, XNOR2), &[Arg(1, 5), Arg(2, 349)]),
-    ((2570, false, XNOR2), &[Arg(1, 11), Arg(2, 355)]),
-    ((2579, false, AND2), &[Tv(2575), Tv(2578)]),
-    ((2582, false, AND2), &[Tv(2580), Tv(2581)]),
-    ((2590, false, AND2), &[Tv(2586), Tv(2589)]),
-    ((2597, false, AND2), &[Tv(2593), Tv(2596)]),
-    ((2713, false, NAND2), &[Arg(1, 8), Tv(415)]),
-    ((2714, false, NAND2), &[Tv(410), Arg(2, 495)]),
-    ((2716, false, NAND2), &[Arg(1, 15), Tv(419)]),
-    ((2717, false, NAND2), &[Tv(411), Arg(2, 498)]),
-    ((2720, false, NAND2), &[Arg(1, 1), Tv(739)]),
-    ((2721, false, NAND2), &[Tv(408), Arg(2, 501)]),
-    ((2736, false, AND2), &[Tv(2734), Tv(2735)]),
-    ((2739, false, AND2), &[Tv(2737), Tv(2738)]),
-    ((2743, false, AND2), &[Tv(2741), Tv(2742)]),
-    ((2746, false, AND2), &[Tv(2744), Tv(2745)]),
-    ((2751, false, AND2), &[Tv(2749), Tv(2750)]),
-    ((2754, false, AND2), &[Tv(2752), Tv(2753)]),
-    ((2758, false, AND2), &[Tv(2756), Tv(2757)]),
-    ((2759, false, XNOR2), &[Arg(1, 9), Arg(2, 497)]),
-    ((2771, false, NAND2), &[Tv(2767), Tv(2770)]),
-];
-
-static LEVEL_10: [((usize, bool, CellType), &[GateInput]); 179] = [
-    ((2785, false, AND2), &[Tv(2771), Tv(2784)]),
-    ((2818, false, AND2), &[Tv(2792), Tv(2817)]),
-    ((3021, false, AND2), &[Arg(2, 0), Tv(2658)]),
-    ((3023, false, OR2), &[Tv(72), Tv(2542)]),
-    ((3133, false, AND2), &[Arg(2, 1), Tv(2658)]),
-    ((3135, false, OR2), &[Tv(73), Tv(2542)]),
-    ((3245, false, NAND2), &[Tv(3242), Tv(3244)]),
-    ((3247, false, AND2), &[Tv(2026), Tv(3246)]),
-    ((3249, false, OR2), &[Tv(82), Tv(2026)]),
-    ((3352, false, NAND2), &[Tv(3349), Tv(3351)]),
-    ((3354, false, AND2), &[Tv(2026), Tv(3353)]),
-    ((3356, false, OR2), &[Tv(83), Tv(2026)]),
-    ((3459, false, NAND2), &[Tv(3456), Tv(3458)]),
-    ((3461, false, AND2), &[Tv(2026), Tv(3460)]),
-    ((3463, false, OR2), &[Tv(84), Tv(2026)]),
-    ((3566, false, NAND2), &[Tv(3563), Tv(3565)]),
-    ((3568, false, AND2), &[Tv(2026), Tv(3567)]),
-    ((3570, false, OR2), &[Tv(85), Tv(2026)]),
-    ((3673, false, NAND2), &[Tv(3670), Tv(3672)]),
-    ((3675, false, AND2), &[Tv(2026), Tv(3674)]),
-    ((3677, false, OR2), &[Tv(86), Tv(2026)]),
-    ((3780, false, NAND2), &[Tv(3777), Tv(3779)]),
-    ((3782, false, AND2), &[Tv(2026), Tv(3781)]),
-    ((3784, false, OR2), &[Tv(87), Tv(2026)]),
-    ((3887, false, NAND2), &[Tv(3884), Tv(3886)]),
-    ((3889, false, AND2), &[Tv(2026), Tv(3888)]),
-    ((3891, false, OR2), &[Tv(63), Tv(2026)]),
-    ((3994, false, NAND2), &[Tv(3991), Tv(3993)]),
-    ((3996, false, AND2), &[Tv(2026), Tv(3995)]),
-    ((3998, false, OR2), &[Tv(62), Tv(2026)]),
-    ((4101, false, NAND2), &[Tv(4098), Tv(4100)]),
-    ((4103, false, AND2), &[Tv(2026), Tv(4102)]),
-    ((4105, false, OR2), &[Tv(60), Tv(2026)]),
-    ((4208, false, NAND2), &[Tv(4205), Tv(4207)]),
-    ((4210, false, AND2), &[Tv(2026), Tv(4209)]),
-    ((4212, false, OR2), &[Tv(61), Tv(2026)]),
-    ((4315, false, NAND2), &[Tv(4312), Tv(4314)]),
-    ((4317, false, AND2), &[Tv(2026), Tv(4316)]),
-    ((4319, false, OR2), &[Tv(56), Tv(2026)]),
-    ((4422, false, NAND2), &[Tv(4419), Tv(4421)]),
-    ((4424, false, AND2), &[Tv(2026), Tv(4423)]),
-    ((4426, false, OR2), &[Tv(57), Tv(2026)]),
-    ((4529, false, NAND2), &[Tv(4526), Tv(4528)]),
-    ((4531, false, AND2), &[Tv(2026), Tv(4530)]),
-    ((4533, false, OR2), &[Tv(58), Tv(2026)]),
-    ((4636, false, NAND2), &[Tv(4633), Tv(4635)]),
-    ((4638, false, AND2), &[Tv(2026), Tv(4637)]),
-    ((4640, false, OR2), &[Tv(59), Tv(2026)]),
-    ((4743, false, NAND2), &[Tv(4740), Tv(4742)]),
-    ((4745, false, AND2), &[Tv(2026), Tv(4744)]),
-    ((4747, false, OR2), &[Tv(197), Tv(2026)]),
-    ((4850, false, NAND2), &[Tv(4847), Tv(4849)]),
-    ((4852, false, AND2), &[Tv(2026), Tv(4851)]),
-    ((4854, false, OR2), &[Tv(198), Tv(2026)]),
-    ((4957, false, NAND2), &[Tv(4954), Tv(4956)]),
-    ((4959, false, AND2), &[Tv(2026), Tv(4958)]),
-    ((4961, false, OR2), &[Tv(199), Tv(2026)]),
-    ((5064, false, NAND2), &[Tv(5061), Tv(5063)]),
-    ((5066, false, AND2), &[Tv(2026), Tv(5065)]),
-    ((5068, false, OR2), &[Tv(200), Tv(2026)]),
-    ((5171, false, NAND2), &[Tv(5168), Tv(5170)]),
-    ((5173, false, AND2), &[Tv(2026), Tv(5172)]),
-    ((5175, false, OR2), &[Tv(201), Tv(2026)]),
-    ((5278, false, NAND2), &[Tv(5275), Tv(5277)]),
-    ((5280, false, AND2), &[Tv(2026), Tv(5279)]),
-    ((5282, false, OR2), &[Tv(202), Tv(2026)]),
-    ((5385, false, NAND2), &[Tv(5382), Tv(5384)]),
-    ((5387, false, AND2), &[Tv(2026), Tv(5386)]),
-    ((5389, false, OR2), &[Tv(203), Tv(2026)]),
-    ((5492, false, NAND2), &[Tv(5489), Tv(5491)]),
-    ((5494, false, AND2), &[Tv(2026), Tv(5493)]),
-    ((5496, false, OR2), &[Tv(204), Tv(2026)]),
-    ((5599, false, NAND2), &[Tv(5596), Tv(5598)]),
-    ((5601, false, AND2), &[Tv(2026), Tv(5600)]),
-    ((5603, false, OR2), &[Tv(293), Tv(2026)]),
-    ((5706, false, NAND2), &[Tv(5703), Tv(5705)]),
-    ((5708, false, AND2), &[Tv(2026), Tv(5707)]),
-    ((5710, false, OR2), &[Tv(294), Tv(2026)]),
-    ((5813, false, NAND2), &[Tv(5810), Tv(5812)]),
-    ((5815, false, AND2), &[Tv(2026), Tv(5814)]),
-    ((5817, false, OR2), &[Tv(295), Tv(2026)]),
-    ((5920, false, NAND2), &[Tv(5917), Tv(5919)]),
-    ((5922, false, AND2), &[Tv(2026), Tv(5921)]),
-    ((5924, false, OR2), &[Tv(296), Tv(2026)]),
-    ((6027, false, NAND2), &[Tv(6024), Tv(6026)]),
-    ((6029, false, AND2), &[Tv(2026), Tv(6028)]),
-    ((6031, false, OR2), &[Tv(297), Tv(2026)]),
-    ((6134, false, NAND2), &[Tv(6131), Tv(6133)]),
-    ((6136, false, AND2), &[Tv(2026), Tv(6135)]),
-    ((6138, false, OR2), &[Tv(298), Tv(2026)]),
-    ((6241, false, NAND2), &[Tv(6238), Tv(6240)]),
-    ((6243, false, AND2), &[Tv(2026), Tv(6242)]),
-    ((6245, false, OR2), &[Tv(299), Tv(2026)]),
-    ((6348, false, NAND2), &[Tv(6345), Tv(6347)]),
-    ((6350, false, AND2), &[Tv(2026), Tv(6349)]),
-    ((6352, false, OR2), &[Tv(300), Tv(2026)]),
-    ((423, false, INV), &[Arg(2, 539)]),
-    ((425, false, INV), &[Arg(2, 541)]),
-    ((426, false, INV), &[Arg(2, 542)]),
-    ((428, false, INV), &[Arg(2, 546)]),
-    ((430, false, INV), &[Arg(2, 548)]),
-    ((431, false, INV), &[Arg(2, 549)]),
-    ((1852, false, NAND2), &[Tv(1848), Tv(1851)]),
-    ((1865, false, AND2), &[Tv(1859), Tv(1864)]),
-    ((1873, false, AND2), &[Tv(1869), Tv(1872)]),
-    ((1898, false, AND2), &[Tv(1884), Tv(1897)]),
-    ((1904, false, AND2), &[Tv(1902), Tv(1903)]),
-    ((1907, false, AND2), &[Tv(1905), Tv(1906)]),
-    ((1915, false, AND2), &[Tv(1911), Tv(1914)]),
-    ((1920, false, AND2), &[Tv(1918), Tv(1919)]),
-    ((1927, false, AND2), &[Tv(1923), Tv(1926)]),
-    ((1930, false, AND2), &[Tv(1928), Tv(1929)]),
-    ((1946, false, AND2), &[Tv(1938), Tv(1945)]),
-    ((1961, false, AND2), &[Tv(1953), Tv(1960)]),
-    ((2139, false, NAND2), &[Tv(2103), Tv(2138)]),
-    ((2172, false, NAND2), &[Tv(394), Arg(2, 541)]),
-    ((2173, false, NAND2), &[Arg(1, 15), Tv(432)]),
-    ((2175, false, NAND2), &[Arg(1, 1), Tv(421)]),
-    ((2176, false, NAND2), &[Arg(1, 2), Tv(422)]),
-    ((2179, false, NAND2), &[Tv(392), Arg(2, 539)]),
-    ((2180, false, NAND2), &[Arg(1, 11), Tv(429)]),
-    ((2182, false, NAND2), &[Arg(1, 4), Tv(424)]),
-    ((2183, false, NAND2), &[Tv(390), Arg(2, 537)]),
-    ((2187, false, NAND2), &[Tv(406), Arg(2, 547)]),
-    ((2188, false, NAND2), &[Arg(1, 9), Tv(427)]),
-    ((2190, false, NAND2), &[Tv(408), Arg(2, 549)]),
-    ((2191, false, NAND2), &[Tv(412), Arg(2, 551)]),
-    ((2194, false, NAND2), &[Tv(391), Arg(2, 538)]),
-    ((2195, false, NAND2), &[Arg(1, 0), Tv(420)]),
-    ((2197, false, NAND2), &[Tv(389), Arg(2, 536)]),
-    ((2198, false, NAND2), &[Tv(405), Arg(2, 545)]),
-    ((2217, false, NAND2), &[Arg(1, 1), Tv(436)]),
-    ((2218, false, NAND2), &[Arg(1, 12), Tv(442)]),
-    ((2220, false, NAND2), &[Tv(412), Arg(2, 647)]),
-    ((2221, false, NAND2), &[Arg(1, 8), Tv(441)]),
-    ((2227, false, NAND2), &[Arg(1, 5), Tv(438)]),
-    ((2228, false, NAND2), &[Arg(1, 6), Tv(439)]),
-    ((2237, false, AND2), &[Tv(2235), Tv(2236)]),
-    ((2240, false, AND2), &[Tv(2238), Tv(2239)]),
-    ((2242, false, XNOR2), &[Arg(1, 10), Arg(2, 642)]),
-    ((2245, false, AND2), &[Tv(2243), Tv(2244)]),
-    ((2250, false, AND2), &[Tv(2248), Tv(2249)]),
-    ((2253, false, AND2), &[Tv(2251), Tv(2252)]),
-    ((2255, false, XNOR2), &[Arg(1, 4), Arg(2, 636)]),
-    ((2258, false, AND2), &[Tv(2256), Tv(2257)]),
-    ((2287, false, NAND2), &[Arg(1, 13), Tv(434)]),
-    ((2288, false, NAND2), &[Arg(1, 9), Tv(433)]),
-    ((2299, false, NAND2), &[Arg(1, 15), Tv(435)]),
-    ((2300, false, NAND2), &[Tv(408), Arg(2, 597)]),
-    ((2302, false, NAND2), &[Tv(405), Arg(2, 593)]),
-    ((2303, false, NAND2), &[Tv(412), Arg(2, 599)]),
-    ((2437, false, NAND2), &[Tv(2433), Tv(2436)]),
-    ((2450, false, AND2), &[Tv(2442), Tv(2449)]),
-    ((2460, false, AND2), &[Tv(2456), Tv(2459)]),
-    ((2485, false, AND2), &[Tv(2469), Tv(2484)]),
-    ((2545, false, AND2), &[Tv(2543), Tv(2544)]),
-    ((2548, false, AND2), &[Tv(2546), Tv(2547)]),
-    ((2554, false, AND2), &[Tv(2550), Tv(2553)]),
-    ((2561, false, AND2), &[Tv(2557), Tv(2560)]),
-    ((2568, false, AND2), &[Tv(2564), Tv(2567)]),
-    ((2571, false, AND2), &[Tv(2569), Tv(2570)]),
-    ((2583, false, AND2), &[Tv(2579), Tv(2582)]),
-    ((2598, false, AND2), &[Tv(2590), Tv(2597)]),
-    ((2706, false, NOR2), &[Arg(2, 506), Arg(2, 507)]),
-    ((2707, false, NOR2), &[Arg(2, 505), Arg(2, 504)]),
-    ((2709, false, NOR2), &[Arg(2, 509), Arg(2, 510)]),
-    ((2710, false, NOR2), &[Arg(2, 508), Arg(2, 511)]),
-    ((2715, false, AND2), &[Tv(2713), Tv(2714)]),
-    ((2718, false, AND2), &[Tv(2716), Tv(2717)]),
-    ((2722, false, AND2), &[Tv(2720), Tv(2721)]),
-    ((2723, false, XNOR2), &[Arg(1, 2), Arg(2, 490)]),
-    ((2727, false, XNOR2), &[Arg(1, 11), Arg(2, 499)]),
-    ((2728, false, XNOR2), &[Arg(1, 5), Arg(2, 493)]),
-    ((2730, false, XNOR2), &[Arg(1, 14), Arg(2, 502)]),
-    ((2731, false, XNOR2), &[Arg(1, 4), Arg(2, 492)]),
-    ((2740, false, AND2), &[Tv(2736), Tv(2739)]),
-    ((2747, false, AND2), &[Tv(2743), Tv(2746)]),
-    ((2755, false, AND2), &[Tv(2751), Tv(2754)]),
-    ((2760, false, AND2), &[Tv(2758), Tv(2759)]),
-];
-
-static LEVEL_11: [((usize, bool, CellType), &[GateInput]); 191] = [
-    ((2819, false, NAND2), &[Tv(2785), Tv(2818)]),
-    ((3022, false, NAND2), &[Tv(2542), Tv(3021)]),
-    ((3024, false, AND2), &[Tv(2081), Tv(3023)]),
-    ((3026, false, OR2), &[Arg(2, 96), Tv(2081)]),
-    ((3134, false, NAND2), &[Tv(2542), Tv(3133)]),
-    ((3136, false, AND2), &[Tv(2081), Tv(3135)]),
-    ((3138, false, OR2), &[Arg(2, 97), Tv(2081)]),
-    ((3248, false, NAND2), &[Tv(3245), Tv(3247)]),
-    ((3250, false, AND2), &[Tv(2139), Tv(3249)]),
-    ((3252, false, OR2), &[Arg(2, 194), Tv(2139)]),
-    ((3355, false, NAND2), &[Tv(3352), Tv(3354)]),
-    ((3357, false, AND2), &[Tv(2139), Tv(3356)]),
-    ((3359, false, OR2), &[Arg(2, 195), Tv(2139)]),
-    ((3462, false, NAND2), &[Tv(3459), Tv(3461)]),
-    ((3464, false, AND2), &[Tv(2139), Tv(3463)]),
-    ((3466, false, OR2), &[Arg(2, 196), Tv(2139)]),
-    ((3569, false, NAND2), &[Tv(3566), Tv(3568)]),
-    ((3571, false, AND2), &[Tv(2139), Tv(3570)]),
-    ((3573, false, OR2), &[Arg(2, 197), Tv(2139)]),
-    ((3676, false, NAND2), &[Tv(3673), Tv(3675)]),
-    ((3678, false, AND2), &[Tv(2139), Tv(3677)]),
-    ((3680, false, OR2), &[Arg(2, 198), Tv(2139)]),
-    ((3783, false, NAND2), &[Tv(3780), Tv(3782)]),
-    ((3785, false, AND2), &[Tv(2139), Tv(3784)]),
-    ((3787, false, OR2), &[Arg(2, 199), Tv(2139)]),
-    ((3890, false, NAND2), &[Tv(3887), Tv(3889)]),
-    ((3892, false, AND2), &[Tv(2139), Tv(3891)]),
-    ((3894, false, OR2), &[Arg(2, 216), Tv(2139)]),
-    ((3997, false, NAND2), &[Tv(3994), Tv(3996)]),
-    ((3999, false, AND2), &[Tv(2139), Tv(3998)]),
-    ((4001, false, OR2), &[Arg(2, 217), Tv(2139)]),
-    ((4104, false, NAND2), &[Tv(4101), Tv(4103)]),
-    ((4106, false, AND2), &[Tv(2139), Tv(4105)]),
-    ((4108, false, OR2), &[Arg(2, 218), Tv(2139)]),
-    ((4211, false, NAND2), &[Tv(4208), Tv(4210)]),
-    ((4213, false, AND2), &[Tv(2139), Tv(4212)]),
-    ((4215, false, OR2), &[Arg(2, 219), Tv(2139)]),
-    ((4318, false, NAND2), &[Tv(4315), Tv(4317)]),
-    ((4320, false, AND2), &[Tv(2139), Tv(4319)]),
-    ((4322, false, OR2), &[Arg(2, 220), Tv(2139)]),
-    ((4425, false, NAND2), &[Tv(4422), Tv(4424)]),
-    ((4427, false, AND2), &[Tv(2139), Tv(4426)]),
-    ((4429, false, OR2), &[Arg(2, 221), Tv(2139)]),
-    ((4532, false, NAND2), &[Tv(4529), Tv(4531)]),
-    ((4534, false, AND2), &[Tv(2139), Tv(4533)]),
-    ((4536, false, OR2), &[Arg(2, 222), Tv(2139)]),
-    ((4639, false, NAND2), &[Tv(4636), Tv(4638)]),
-    ((4641, false, AND2), &[Tv(2139), Tv(4640)]),
-    ((4643, false, OR2), &[Arg(2, 223), Tv(2139)]),
-    ((4746, false, NAND2), &[Tv(4743), Tv(4745)]),
-    ((4748, false, AND2), &[Tv(2139), Tv(4747)]),
-    ((4750, false, OR2), &[Arg(2, 224), Tv(2139)]),
-    ((4853, false, NAND2), &[Tv(4850), Tv(4852)]),
-    ((4855, false, AND2), &[Tv(2139), Tv(4854)]),
-    ((4857, false, OR2), &[Arg(2, 225), Tv(2139)]),
-    ((4960, false, NAND2), &[Tv(4957), Tv(4959)]),
-    ((4962, false, AND2), &[Tv(2139), Tv(4961)]),
-    ((4964, false, OR2), &[Arg(2, 226), Tv(2139)]),
-    ((5067, false, NAND2), &[Tv(5064), Tv(5066)]),
-    ((5069, false, AND2), &[Tv(2139), Tv(5068)]),
-    ((5071, false, OR2), &[Arg(2, 227), Tv(2139)]),
-    ((5174, false, NAND2), &[Tv(5171), Tv(5173)]),
-    ((5176, false, AND2), &[Tv(2139), Tv(5175)]),
-    ((5178, false, OR2), &[Arg(2, 228), Tv(2139)]),
-    ((5281, false, NAND2), &[Tv(5278), Tv(5280)]),
-    ((5283, false, AND2), &[Tv(2139), Tv(5282)]),
-    ((5285, false, OR2), &[Arg(2, 229), Tv(2139)]),
-    ((5388, false, NAND2), &[Tv(5385), Tv(5387)]),
-    ((5390, false, AND2), &[Tv(2139), Tv(5389)]),
-    ((5392, false, OR2), &[Arg(2, 230), Tv(2139)]),
-    ((5495, false, NAND2), &[Tv(5492), Tv(5494)]),
-    ((5497, false, AND2), &[Tv(2139), Tv(5496)]),
-    ((5499, false, OR2), &[Arg(2, 231), Tv(2139)]),
-    ((5602, false, NAND2), &[Tv(5599), Tv(5601)]),
-    ((5604, false, AND2), &[Tv(2139), Tv(5603)]),
-    ((5606, false, OR2), &[Arg(2, 232), Tv(2139)]),
-    ((5709, false, NAND2), &[Tv(5706), Tv(5708)]),
-    ((5711, false, AND2), &[Tv(2139), Tv(5710)]),
-    ((5713, false, OR2), &[Arg(2, 233), Tv(2139)]),
-    ((5816, false, NAND2), &[Tv(5813), Tv(5815)]),
-    ((5818, false, AND2), &[Tv(2139), Tv(5817)]),
-    ((5820, false, OR2), &[Arg(2, 234), Tv(2139)]),
-    ((5923, false, NAND2), &[Tv(5920), Tv(5922)]),
-    ((5925, false, AND2), &[Tv(2139), Tv(5924)]),
-    ((5927, false, OR2), &[Arg(2, 235), Tv(2139)]),
-    ((6030, false, NAND2), &[Tv(6027), Tv(6029)]),
-    ((6032, false, AND2), &[Tv(2139), Tv(6031)]),
-    ((6034, false, OR2), &[Arg(2, 236), Tv(2139)]),
-    ((6137, false, NAND2), &[Tv(6134), Tv(6136)]),
-    ((6139, false, AND2), &[Tv(2139), Tv(6138)]),
-    ((6141, false, OR2), &[Arg(2, 237), Tv(2139)]),
-    ((6244, false, NAND2), &[Tv(6241), Tv(6243)]),
-    ((6246, false, AND2), &[Tv(2139), Tv(6245)]),
-    ((6248, false, OR2), &[Arg(2, 238), Tv(2139)]),
-    ((6351, false, NAND2), &[Tv(6348), Tv(6350)]),
-    ((6353, false, AND2), &[Tv(2139), Tv(6352)]),
-    ((6355, false, OR2), &[Arg(2, 239), Tv(2139)]),
-    ((48, false, INV), &[Arg(2, 268)]),
-    ((49, false, INV), &[Arg(2, 269)]),
-    ((50, false, INV), &[Arg(2, 270)]),
-    ((51, false, INV), &[Arg(2, 271)]),
-    ((52, false, INV), &[Arg(2, 266)]),
-    ((53, false, INV), &[Arg(2, 267)]),
-    ((54, false, INV), &[Arg(2, 265)]),
-    ((55, false, INV), &[Arg(2, 264)]),
-    ((80, false, INV), &[Arg(2, 144)]),
-    ((81, false, INV), &[Arg(2, 145)]),
-    ((90, false, INV), &[Arg(2, 242)]),
-    ((91, false, INV), &[Arg(2, 243)]),
-    ((92, false, INV), &[Arg(2, 244)]),
-    ((93, false, INV), &[Arg(2, 245)]),
-    ((94, false, INV), &[Arg(2, 246)]),
-    ((95, false, INV), &[Arg(2, 247)]),
-    ((205, false, INV), &[Arg(2, 272)]),
-    ((206, false, INV), &[Arg(2, 273)]),
-    ((207, false, INV), &[Arg(2, 274)]),
-    ((208, false, INV), &[Arg(2, 275)]),
-    ((209, false, INV), &[Arg(2, 276)]),
-    ((210, false, INV), &[Arg(2, 277)]),
-    ((211, false, INV), &[Arg(2, 278)]),
-    ((212, false, INV), &[Arg(2, 279)]),
-    ((301, false, INV), &[Arg(2, 280)]),
-    ((302, false, INV), &[Arg(2, 281)]),
-    ((303, false, INV), &[Arg(2, 282)]),
-    ((304, false, INV), &[Arg(2, 283)]),
-    ((305, false, INV), &[Arg(2, 284)]),
-    ((306, false, INV), &[Arg(2, 285)]),
-    ((307, false, INV), &[Arg(2, 286)]),
-    ((308, false, INV), &[Arg(2, 287)]),
-    ((1866, false, AND2), &[Tv(1852), Tv(1865)]),
-    ((1899, false, AND2), &[Tv(1873), Tv(1898)]),
-    ((1908, false, NAND2), &[Tv(1904), Tv(1907)]),
-    ((1921, false, AND2), &[Tv(1915), Tv(1920)]),
-    ((1931, false, AND2), &[Tv(1927), Tv(1930)]),
-    ((1962, false, AND2), &[Tv(1946), Tv(1961)]),
-    ((2147, false, NAND2), &[Tv(407), Arg(2, 548)]),
-    ((2148, false, NAND2), &[Tv(393), Arg(2, 540)]),
-    ((2152, false, NAND2), &[Tv(395), Arg(2, 542)]),
-    ((2153, false, NAND2), &[Tv(411), Arg(2, 546)]),
-    ((2155, false, NAND2), &[Arg(1, 10), Tv(428)]),
-    ((2156, false, NAND2), &[Arg(1, 12), Tv(430)]),
-    ((2161, false, NAND2), &[Arg(1, 5), Tv(425)]),
-    ((2162, false, NAND2), &[Arg(1, 13), Tv(431)]),
-    ((2164, false, NAND2), &[Arg(1, 6), Tv(426)]),
-    ((2165, false, NAND2), &[Arg(1, 3), Tv(423)]),
-    ((2174, false, AND2), &[Tv(2172), Tv(2173)]),
-    ((2177, false, AND2), &[Tv(2175), Tv(2176)]),
-    ((2181, false, AND2), &[Tv(2179), Tv(2180)]),
-    ((2184, false, AND2), &[Tv(2182), Tv(2183)]),
-    ((2189, false, AND2), &[Tv(2187), Tv(2188)]),
-    ((2192, false, AND2), &[Tv(2190), Tv(2191)]),
-    ((2196, false, AND2), &[Tv(2194), Tv(2195)]),
-    ((2199, false, AND2), &[Tv(2197), Tv(2198)]),
-    ((2207, false, NOR2), &[Arg(2, 650), Arg(2, 651)]),
-    ((2208, false, NOR2), &[Arg(2, 649), Arg(2, 648)]),
-    ((2210, false, NOR2), &[Arg(2, 652), Arg(2, 653)]),
-    ((2211, false, NOR2), &[Arg(2, 654), Arg(2, 655)]),
-    ((2214, false, XNOR2), &[Arg(1, 11), Arg(2, 643)]),
-    ((2215, false, XNOR2), &[Arg(1, 3), Arg(2, 635)]),
-    ((2219, false, AND2), &[Tv(2217), Tv(2218)]),
-    ((2222, false, AND2), &[Tv(2220), Tv(2221)]),
-    ((2226, false, XNOR2), &[Arg(1, 14), Arg(2, 646)]),
-    ((2229, false, AND2), &[Tv(2227), Tv(2228)]),
-    ((2231, false, XNOR2), &[Arg(1, 0), Arg(2, 632)]),
-    ((2232, false, XNOR2), &[Arg(1, 9), Arg(2, 641)]),
-    ((2241, false, AND2), &[Tv(2237), Tv(2240)]),
-    ((2246, false, AND2), &[Tv(2242), Tv(2245)]),
-    ((2254, false, AND2), &[Tv(2250), Tv(2253)]),
-    ((2259, false, AND2), &[Tv(2255), Tv(2258)]),
-    ((2289, false, AND2), &[Tv(2287), Tv(2288)]),
-    ((2290, false, XNOR2), &[Arg(1, 7), Arg(2, 591)]),
-    ((2292, false, XNOR2), &[Arg(1, 2), Arg(2, 586)]),
-    ((2293, false, XNOR2), &[Arg(1, 4), Arg(2, 588)]),
-    ((2296, false, XNOR2), &[Arg(1, 3), Arg(2, 587)]),
-    ((2297, false, XNOR2), &[Arg(1, 6), Arg(2, 590)]),
-    ((2301, false, AND2), &[Tv(2299), Tv(2300)]),
-    ((2304, false, AND2), &[Tv(2302), Tv(2303)]),
-    ((2451, false, AND2), &[Tv(2437), Tv(2450)]),
-    ((2486, false, AND2), &[Tv(2460), Tv(2485)]),
-    ((2549, false, NAND2), &[Tv(2545), Tv(2548)]),
-    ((2562, false, AND2), &[Tv(2554), Tv(2561)]),
-    ((2572, false, AND2), &[Tv(2568), Tv(2571)]),
-    ((2599, false, AND2), &[Tv(2583), Tv(2598)]),
-    ((2708, false, AND2), &[Tv(2706), Tv(2707)]),
-    ((2711, false, AND2), &[Tv(2709), Tv(2710)]),
-    ((2719, false, AND2), &[Tv(2715), Tv(2718)]),
-    ((2724, false, AND2), &[Tv(2722), Tv(2723)]),
-    ((2729, false, AND2), &[Tv(2727), Tv(2728)]),
-    ((2732, false, AND2), &[Tv(2730), Tv(2731)]),
-    ((2748, false, AND2), &[Tv(2740), Tv(2747)]),
-    ((2761, false, AND2), &[Tv(2755), Tv(2760)]),
-];
-
-static LEVEL_12: [((usize, bool, CellType), &[GateInput]); 158] = [
-    ((3025, false, NAND2), &[Tv(3022), Tv(3024)]),
-    ((3027, false, AND2), &[Tv(2026), Tv(3026)]),
-    ((3029, false, OR2), &[Tv(80), Tv(2026)]),
-    ((3137, false, NAND2), &[Tv(3134), Tv(3136)]),
-    ((3139, false, AND2), &[Tv(2026), Tv(3138)]),
-    ((3141, false, OR2), &[Tv(81), Tv(2026)]),
-    ((3251, false, NAND2), &[Tv(3248), Tv(3250)]),
-    ((3253, false, AND2), &[Tv(2819), Tv(3252)]),
-    ((3255, false, OR2), &[Tv(90), Tv(2819)]),
-    ((3358, false, NAND2), &[Tv(3355), Tv(3357)]),
-    ((3360, false, AND2), &[Tv(2819), Tv(3359)]),
-    ((3362, false, OR2), &[Tv(91), Tv(2819)]),
-    ((3465, false, NAND2), &[Tv(3462), Tv(3464)]),
-    ((3467, false, AND2), &[Tv(2819), Tv(3466)]),
-    ((3469, false, OR2), &[Tv(92), Tv(2819)]),
-    ((3572, false, NAND2), &[Tv(3569), Tv(3571)]),
-    ((3574, false, AND2), &[Tv(2819), Tv(3573)]),
-    ((3576, false, OR2), &[Tv(93), Tv(2819)]),
-    ((3679, false, NAND2), &[Tv(3676), Tv(3678)]),
-    ((3681, false, AND2), &[Tv(2819), Tv(3680)]),
-    ((3683, false, OR2), &[Tv(94), Tv(2819)]),
-    ((3786, false, NAND2), &[Tv(3783), Tv(3785)]),
-    ((3788, false, AND2), &[Tv(2819), Tv(3787)]),
-    ((3790, false, OR2), &[Tv(95), Tv(2819)]),
-    ((3893, false, NAND2), &[Tv(3890), Tv(3892)]),
-    ((3895, false, AND2), &[Tv(2819), Tv(3894)]),
-    ((3897, false, OR2), &[Tv(55), Tv(2819)]),
-    ((4000, false, NAND2), &[Tv(3997), Tv(3999)]),
-    ((4002, false, AND2), &[Tv(2819), Tv(4001)]),
-    ((4004, false, OR2), &[Tv(54), Tv(2819)]),
-    ((4107, false, NAND2), &[Tv(4104), Tv(4106)]),
-    ((4109, false, AND2), &[Tv(2819), Tv(4108)]),
-    ((4111, false, OR2), &[Tv(52), Tv(2819)]),
-    ((4214, false, NAND2), &[Tv(4211), Tv(4213)]),
-    ((4216, false, AND2), &[Tv(2819), Tv(4215)]),
-    ((4218, false, OR2), &[Tv(53), Tv(2819)]),
-    ((4321, false, NAND2), &[Tv(4318), Tv(4320)]),
-    ((4323, false, AND2), &[Tv(2819), Tv(4322)]),
-    ((4325, false, OR2), &[Tv(48), Tv(2819)]),
-    ((4428, false, NAND2), &[Tv(4425), Tv(4427)]),
-    ((4430, false, AND2), &[Tv(2819), Tv(4429)]),
-    ((4432, false, OR2), &[Tv(49), Tv(2819)]),
-    ((4535, false, NAND2), &[Tv(4532), Tv(4534)]),
-    ((4537, false, AND2), &[Tv(2819), Tv(4536)]),
-    ((4539, false, OR2), &[Tv(50), Tv(2819)]),
-    ((4642, false, NAND2), &[Tv(4639), Tv(4641)]),
-    ((4644, false, AND2), &[Tv(2819), Tv(4643)]),
-    ((4646, false, OR2), &[Tv(51), Tv(2819)]),
-    ((4749, false, NAND2), &[Tv(4746), Tv(4748)]),
-    ((4751, false, AND2), &[Tv(2819), Tv(4750)]),
-    ((4753, false, OR2), &[Tv(205), Tv(2819)]),
-    ((4856, false, NAND2), &[Tv(4853), Tv(4855)]),
-    ((4858, false, AND2), &[Tv(2819), Tv(4857)]),
-    ((4860, false, OR2), &[Tv(206), Tv(2819)]),
-    ((4963, false, NAND2), &[Tv(4960), Tv(4962)]),
-    ((4965, false, AND2), &[Tv(2819), Tv(4964)]),
-    ((4967, false, OR2), &[Tv(207), Tv(2819)]),
-    ((5070, false, NAND2), &[Tv(5067), Tv(5069)]),
-    ((5072, false, AND2), &[Tv(2819), Tv(5071)]),
-    ((5074, false, OR2), &[Tv(208), Tv(2819)]),
-    ((5177, false, NAND2), &[Tv(5174), Tv(5176)]),
-    ((5179, false, AND2), &[Tv(2819), Tv(5178)]),
-    ((5181, false, OR2), &[Tv(209), Tv(2819)]),
-    ((5284, false, NAND2), &[Tv(5281), Tv(5283)]),
-    ((5286, false, AND2), &[Tv(2819), Tv(5285)]),
-    ((5288, false, OR2), &[Tv(210), Tv(2819)]),
-    ((5391, false, NAND2), &[Tv(5388), Tv(5390)]),
-    ((5393, false, AND2), &[Tv(2819), Tv(5392)]),
-    ((5395, false, OR2), &[Tv(211), Tv(2819)]),
-    ((5498, false, NAND2), &[Tv(5495), Tv(5497)]),
-    ((5500, false, AND2), &[Tv(2819), Tv(5499)]),
-    ((5502, false, OR2), &[Tv(212), Tv(2819)]),
-    ((5605, false, NAND2), &[Tv(5602), Tv(5604)]),
-    ((5607, false, AND2), &[Tv(2819), Tv(5606)]),
-    ((5609, false, OR2), &[Tv(301), Tv(2819)]),
-    ((5712, false, NAND2), &[Tv(5709), Tv(5711)]),
-    ((5714, false, AND2), &[Tv(2819), Tv(5713)]),
-    ((5716, false, OR2), &[Tv(302), Tv(2819)]),
-    ((5819, false, NAND2), &[Tv(5816), Tv(5818)]),
-    ((5821, false, AND2), &[Tv(2819), Tv(5820)]),
-    ((5823, false, OR2), &[Tv(303), Tv(2819)]),
-    ((5926, false, NAND2), &[Tv(5923), Tv(5925)]),
-    ((5928, false, AND2), &[Tv(2819), Tv(5927)]),
-    ((5930, false, OR2), &[Tv(304), Tv(2819)]),
-    ((6033, false, NAND2), &[Tv(6030), Tv(6032)]),
-    ((6035, false, AND2), &[Tv(2819), Tv(6034)]),
-    ((6037, false, OR2), &[Tv(305), Tv(2819)]),
-    ((6140, false, NAND2), &[Tv(6137), Tv(6139)]),
-    ((6142, false, AND2), &[Tv(2819), Tv(6141)]),
-    ((6144, false, OR2), &[Tv(306), Tv(2819)]),
-    ((6247, false, NAND2), &[Tv(6244), Tv(6246)]),
-    ((6249, false, AND2), &[Tv(2819), Tv(6248)]),
-    ((6251, false, OR2), &[Tv(307), Tv(2819)]),
-    ((6354, false, NAND2), &[Tv(6351), Tv(6353)]),
-    ((6356, false, AND2), &[Tv(2819), Tv(6355)]),
-    ((6358, false, OR2), &[Tv(308), Tv(2819)]),
-    ((445, false, INV), &[Arg(2, 681)]),
-    ((446, false, INV), &[Arg(2, 683)]),
-    ((447, false, INV), &[Arg(2, 685)]),
-    ((449, false, INV), &[Arg(2, 687)]),
-    ((450, false, INV), &[Arg(2, 688)]),
-    ((452, false, INV), &[Arg(2, 692)]),
-    ((454, false, INV), &[Arg(2, 695)]),
-    ((476, false, INV), &[Arg(2, 829)]),
-    ((477, false, INV), &[Arg(2, 831)]),
-    ((478, false, INV), &[Arg(2, 832)]),
-    ((480, false, INV), &[Arg(2, 837)]),
-    ((481, false, INV), &[Arg(2, 839)]),
-    ((1900, false, AND2), &[Tv(1866), Tv(1899)]),
-    ((1922, false, AND2), &[Tv(1908), Tv(1921)]),
-    ((1963, false, AND2), &[Tv(1931), Tv(1962)]),
-    ((2140, false, NOR2), &[Arg(2, 554), Arg(2, 555)]),
-    ((2141, false, NOR2), &[Arg(2, 553), Arg(2, 552)]),
-    ((2143, false, NOR2), &[Arg(2, 556), Arg(2, 557)]),
-    ((2144, false, NOR2), &[Arg(2, 558), Arg(2, 559)]),
-    ((2149, false, AND2), &[Tv(2147), Tv(2148)]),
-    ((2150, false, XNOR2), &[Arg(1, 8), Arg(2, 544)]),
-    ((2154, false, AND2), &[Tv(2152), Tv(2153)]),
-    ((2157, false, AND2), &[Tv(2155), Tv(2156)]),
-    ((2163, false, AND2), &[Tv(2161), Tv(2162)]),
-    ((2166, false, AND2), &[Tv(2164), Tv(2165)]),
-    ((2168, false, XNOR2), &[Arg(1, 7), Arg(2, 543)]),
-    ((2169, false, XNOR2), &[Arg(1, 14), Arg(2, 550)]),
-    ((2178, false, AND2), &[Tv(2174), Tv(2177)]),
-    ((2185, false, AND2), &[Tv(2181), Tv(2184)]),
-    ((2193, false, AND2), &[Tv(2189), Tv(2192)]),
-    ((2200, false, AND2), &[Tv(2196), Tv(2199)]),
-    ((2209, false, AND2), &[Tv(2207), Tv(2208)]),
-    ((2212, false, AND2), &[Tv(2210), Tv(2211)]),
-    ((2216, false, AND2), &[Tv(2214), Tv(2215)]),
-    ((2223, false, AND2), &[Tv(2219), Tv(2222)]),
-    ((2230, false, AND2), &[Tv(2226), Tv(2229)]),
-    ((2233, false, AND2), &[Tv(2231), Tv(2232)]),
-    ((2247, false, AND2), &[Tv(2241), Tv(2246)]),
-    ((2260, false, AND2), &[Tv(2254), Tv(2259)]),
-    ((2265, false, NOR2), &[Arg(2, 602), Arg(2, 603)]),
-    ((2266, false, NOR2), &[Arg(2, 601), Arg(2, 600)]),
-    ((2268, false, NOR2), &[Arg(2, 605), Arg(2, 606)]),
-    ((2269, false, NOR2), &[Arg(2, 604), Arg(2, 607)]),
-    ((2272, false, XNOR2), &[Arg(1, 8), Arg(2, 592)]),
-    ((2273, false, XNOR2), &[Arg(1, 14), Arg(2, 598)]),
-    ((2275, false, XNOR2), &[Arg(1, 5), Arg(2, 589)]),
-    ((2276, false, XNOR2), &[Arg(1, 10), Arg(2, 594)]),
-    ((2280, false, XNOR2), &[Arg(1, 0), Arg(2, 584)]),
-    ((2281, false, XNOR2), &[Arg(1, 11), Arg(2, 595)]),
-    ((2283, false, XNOR2), &[Arg(1, 12), Arg(2, 596)]),
-    ((2284, false, XNOR2), &[Arg(1, 1), Arg(2, 585)]),
-    ((2291, false, AND2), &[Tv(2289), Tv(2290)]),
-    ((2294, false, AND2), &[Tv(2292), Tv(2293)]),
-    ((2298, false, AND2), &[Tv(2296), Tv(2297)]),
-    ((2305, false, AND2), &[Tv(2301), Tv(2304)]),
-    ((2487, false, NAND2), &[Tv(2451), Tv(2486)]),
-    ((2563, false, AND2), &[Tv(2549), Tv(2562)]),
-    ((2600, false, AND2), &[Tv(2572), Tv(2599)]),
-    ((2712, false, NAND2), &[Tv(2708), Tv(2711)]),
-    ((2725, false, AND2), &[Tv(2719), Tv(2724)]),
-    ((2733, false, AND2), &[Tv(2729), Tv(2732)]),
-    ((2762, false, AND2), &[Tv(2748), Tv(2761)]),
-];
-
-static LEVEL_13: [((usize, bool, CellType), &[GateInput]); 230] = [
-    ((3028, false, NAND2), &[Tv(3025), Tv(3027)]),
-    ((3030, false, AND2), &[Tv(2139), Tv(3029)]),
-    ((3032, false, OR2), &[Arg(2, 192), Tv(2139)]),
-    ((3140, false, NAND2), &[Tv(3137), Tv(3139)]),
-    ((3142, false, AND2), &[Tv(2139), Tv(3141)]),
-    ((3144, false, OR2), &[Arg(2, 193), Tv(2139)]),
-    ((3254, false, NAND2), &[Tv(3251), Tv(3253)]),
-    ((3256, false, AND2), &[Tv(2487), Tv(3255)]),
-    ((3258, false, OR2), &[Arg(2, 290), Tv(2487)]),
-    ((3361, false, NAND2), &[Tv(3358), Tv(3360)]),
-    ((3363, false, AND2), &[Tv(2487), Tv(3362)]),
-    ((3365, false, OR2), &[Arg(2, 291), Tv(2487)]),
-    ((3468, false, NAND2), &[Tv(3465), Tv(3467)]),
-    ((3470, false, AND2), &[Tv(2487), Tv(3469)]),
-    ((3472, false, OR2), &[Arg(2, 292), Tv(2487)]),
-    ((3575, false, NAND2), &[Tv(3572), Tv(3574)]),
-    ((3577, false, AND2), &[Tv(2487), Tv(3576)]),
-    ((3579, false, OR2), &[Arg(2, 293), Tv(2487)]),
-    ((3682, false, NAND2), &[Tv(3679), Tv(3681)]),
-    ((3684, false, AND2), &[Tv(2487), Tv(3683)]),
-    ((3686, false, OR2), &[Arg(2, 294), Tv(2487)]),
-    ((3789, false, NAND2), &[Tv(3786), Tv(3788)]),
-    ((3791, false, AND2), &[Tv(2487), Tv(3790)]),
-    ((3793, false, OR2), &[Arg(2, 295), Tv(2487)]),
-    ((3896, false, NAND2), &[Tv(3893), Tv(3895)]),
-    ((3898, false, AND2), &[Tv(2487), Tv(3897)]),
-    ((3900, false, OR2), &[Arg(2, 312), Tv(2487)]),
-    ((4003, false, NAND2), &[Tv(4000), Tv(4002)]),
-    ((4005, false, AND2), &[Tv(2487), Tv(4004)]),
-    ((4007, false, OR2), &[Arg(2, 313), Tv(2487)]),
-    ((4110, false, NAND2), &[Tv(4107), Tv(4109)]),
-    ((4112, false, AND2), &[Tv(2487), Tv(4111)]),
-    ((4114, false, OR2), &[Arg(2, 314), Tv(2487)]),
-    ((4217, false, NAND2), &[Tv(4214), Tv(4216)]),
-    ((4219, false, AND2), &[Tv(2487), Tv(4218)]),
-    ((4221, false, OR2), &[Arg(2, 315), Tv(2487)]),
-    ((4324, false, NAND2), &[Tv(4321), Tv(4323)]),
-    ((4326, false, AND2), &[Tv(2487), Tv(4325)]),
-    ((4328, false, OR2), &[Arg(2, 316), Tv(2487)]),
-    ((4431, false, NAND2), &[Tv(4428), Tv(4430)]),
-    ((4433, false, AND2), &[Tv(2487), Tv(4432)]),
-    ((4435, false, OR2), &[Arg(2, 317), Tv(2487)]),
-    ((4538, false, NAND2), &[Tv(4535), Tv(4537)]),
-    ((4540, false, AND2), &[Tv(2487), Tv(4539)]),
-    ((4542, false, OR2), &[Arg(2, 318), Tv(2487)]),
-    ((4645, false, NAND2), &[Tv(4642), Tv(4644)]),
-    ((4647, false, AND2), &[Tv(2487), Tv(4646)]),
-    ((4649, false, OR2), &[Arg(2, 319), Tv(2487)]),
-    ((4752, false, NAND2), &[Tv(4749), Tv(4751)]),
-    ((4754, false, AND2), &[Tv(2487), Tv(4753)]),
-    ((4756, false, OR2), &[Arg(2, 320), Tv(2487)]),
-    ((4859, false, NAND2), &[Tv(4856), Tv(4858)]),
-    ((4861, false, AND2), &[Tv(2487), Tv(4860)]),
-    ((4863, false, OR2), &[Arg(2, 321), Tv(2487)]),
-    ((4966, false, NAND2), &[Tv(4963), Tv(4965)]),
-    ((4968, false, AND2), &[Tv(2487), Tv(4967)]),
-    ((4970, false, OR2), &[Arg(2, 322), Tv(2487)]),
-    ((5073, false, NAND2), &[Tv(5070), Tv(5072)]),
-    ((5075, false, AND2), &[Tv(2487), Tv(5074)]),
-    ((5077, false, OR2), &[Arg(2, 323), Tv(2487)]),
-    ((5180, false, NAND2), &[Tv(5177), Tv(5179)]),
-    ((5182, false, AND2), &[Tv(2487), Tv(5181)]),
-    ((5184, false, OR2), &[Arg(2, 324), Tv(2487)]),
-    ((5287, false, NAND2), &[Tv(5284), Tv(5286)]),
-    ((5289, false, AND2), &[Tv(2487), Tv(5288)]),
-    ((5291, false, OR2), &[Arg(2, 325), Tv(2487)]),
-    ((5394, false, NAND2), &[Tv(5391), Tv(5393)]),
-    ((5396, false, AND2), &[Tv(2487), Tv(5395)]),
-    ((5398, false, OR2), &[Arg(2, 326), Tv(2487)]),
-    ((5501, false, NAND2), &[Tv(5498), Tv(5500)]),
-    ((5503, false, AND2), &[Tv(2487), Tv(5502)]),
-    ((5505, false, OR2), &[Arg(2, 327), Tv(2487)]),
-    ((5608, false, NAND2), &[Tv(5605), Tv(5607)]),
-    ((5610, false, AND2), &[Tv(2487), Tv(5609)]),
-    ((5612, false, OR2), &[Arg(2, 328), Tv(2487)]),
-    ((5715, false, NAND2), &[Tv(5712), Tv(5714)]),
-    ((5717, false, AND2), &[Tv(2487), Tv(5716)]),
-    ((5719, false, OR2), &[Arg(2, 329), Tv(2487)]),
-    ((5822, false, NAND2), &[Tv(5819), Tv(5821)]),
-    ((5824, false, AND2), &[Tv(2487), Tv(5823)]),
-    ((5826, false, OR2), &[Arg(2, 330), Tv(2487)]),
-    ((5929, false, NAND2), &[Tv(5926), Tv(5928)]),
-    ((5931, false, AND2), &[Tv(2487), Tv(5930)]),
-    ((5933, false, OR2), &[Arg(2, 331), Tv(2487)]),
-    ((6036, false, NAND2), &[Tv(6033), Tv(6035)]),
-    ((6038, false, AND2), &[Tv(2487), Tv(6037)]),
-    ((6040, false, OR2), &[Arg(2, 332), Tv(2487)]),
-    ((6143, false, NAND2), &[Tv(6140), Tv(6142)]),
-    ((6145, false, AND2), &[Tv(2487), Tv(6144)]),
-    ((6147, false, OR2), &[Arg(2, 333), Tv(2487)]),
-    ((6250, false, NAND2), &[Tv(6247), Tv(6249)]),
-    ((6252, false, AND2), &[Tv(2487), Tv(6251)]),
-    ((6254, false, OR2), &[Arg(2, 334), Tv(2487)]),
-    ((6357, false, NAND2), &[Tv(6354), Tv(6356)]),
-    ((6359, false, AND2), &[Tv(2487), Tv(6358)]),
-    ((6361, false, OR2), &[Arg(2, 335), Tv(2487)]),
-    ((24, false, INV), &[Arg(2, 460)]),
-    ((25, false, INV), &[Arg(2, 461)]),
-    ((26, false, INV), &[Arg(2, 462)]),
-    ((27, false, INV), &[Arg(2, 463)]),
-    ((28, false, INV), &[Arg(2, 458)]),
-    ((29, false, INV), &[Arg(2, 459)]),
-    ((30, false, INV), &[Arg(2, 457)]),
-    ((31, false, INV), &[Arg(2, 456)]),
-    ((40, false, INV), &[Arg(2, 364)]),
-    ((41, false, INV), &[Arg(2, 365)]),
-    ((42, false, INV), &[Arg(2, 366)]),
-    ((43, false, INV), &[Arg(2, 367)]),
-    ((44, false, INV), &[Arg(2, 362)]),
-    ((45, false, INV), &[Arg(2, 363)]),
-    ((46, false, INV), &[Arg(2, 361)]),
-    ((47, false, INV), &[Arg(2, 360)]),
-    ((89, false, INV), &[Arg(2, 241)]),
-    ((98, false, INV), &[Arg(2, 338)]),
-    ((99, false, INV), &[Arg(2, 339)]),
-    ((100, false, INV), &[Arg(2, 340)]),
-    ((101, false, INV), &[Arg(2, 341)]),
-    ((102, false, INV), &[Arg(2, 342)]),
-    ((103, false, INV), &[Arg(2, 343)]),
-    ((112, false, INV), &[Arg(2, 434)]),
-    ((113, false, INV), &[Arg(2, 435)]),
-    ((114, false, INV), &[Arg(2, 436)]),
-    ((115, false, INV), &[Arg(2, 437)]),
-    ((116, false, INV), &[Arg(2, 438)]),
-    ((117, false, INV), &[Arg(2, 439)]),
-    ((213, false, INV), &[Arg(2, 368)]),
-    ((214, false, INV), &[Arg(2, 369)]),
-    ((215, false, INV), &[Arg(2, 370)]),
-    ((216, false, INV), &[Arg(2, 371)]),
-    ((217, false, INV), &[Arg(2, 372)]),
-    ((218, false, INV), &[Arg(2, 373)]),
-    ((219, false, INV), &[Arg(2, 374)]),
-    ((220, false, INV), &[Arg(2, 375)]),
-    ((229, false, INV), &[Arg(2, 464)]),
-    ((230, false, INV), &[Arg(2, 465)]),
-    ((231, false, INV), &[Arg(2, 466)]),
-    ((232, false, INV), &[Arg(2, 467)]),
-    ((233, false, INV), &[Arg(2, 468)]),
-    ((234, false, INV), &[Arg(2, 469)]),
-    ((235, false, INV), &[Arg(2, 470)]),
-    ((236, false, INV), &[Arg(2, 471)]),
-    ((309, false, INV), &[Arg(2, 376)]),
-    ((310, false, INV), &[Arg(2, 377)]),
-    ((311, false, INV), &[Arg(2, 378)]),
-    ((312, false, INV), &[Arg(2, 379)]),
-    ((313, false, INV), &[Arg(2, 380)]),
-    ((314, false, INV), &[Arg(2, 381)]),
-    ((315, false, INV), &[Arg(2, 382)]),
-    ((316, false, INV), &[Arg(2, 383)]),
-    ((325, false, INV), &[Arg(2, 472)]),
-    ((326, false, INV), &[Arg(2, 473)]),
-    ((327, false, INV), &[Arg(2, 474)]),
-    ((328, false, INV), &[Arg(2, 475)]),
-    ((329, false, INV), &[Arg(2, 476)]),
-    ((330, false, INV), &[Arg(2, 477)]),
-    ((331, false, INV), &[Arg(2, 478)]),
-    ((332, false, INV), &[Arg(2, 479)]),
-    ((448, false, INV), &[Arg(2, 686)]),
-    ((451, false, INV), &[Arg(2, 689)]),
-    ((453, false, INV), &[Arg(2, 694)]),
-    ((455, false, INV), &[Arg(2, 728)]),
-    ((456, false, INV), &[Arg(2, 729)]),
-    ((457, false, INV), &[Arg(2, 730)]),
-    ((458, false, INV), &[Arg(2, 732)]),
-    ((461, false, INV), &[Arg(2, 740)]),
-    ((463, false, INV), &[Arg(2, 743)]),
-    ((464, false, INV), &[Arg(2, 776)]),
-    ((466, false, INV), &[Arg(2, 778)]),
-    ((467, false, INV), &[Arg(2, 780)]),
-    ((468, false, INV), &[Arg(2, 784)]),
-    ((469, false, INV), &[Arg(2, 785)]),
-    ((472, false, INV), &[Arg(2, 789)]),
-    ((473, false, INV), &[Arg(2, 791)]),
-    ((474, false, INV), &[Arg(2, 825)]),
-    ((475, false, INV), &[Arg(2, 826)]),
-    ((479, false, INV), &[Arg(2, 835)]),
-    ((482, false, INV), &[Arg(2, 873)]),
-    ((484, false, INV), &[Arg(2, 875)]),
-    ((486, false, INV), &[Arg(2, 881)]),
-    ((487, false, INV), &[Arg(2, 882)]),
-    ((488, false, INV), &[Arg(2, 884)]),
-    ((489, false, INV), &[Arg(2, 885)]),
-    ((490, false, INV), &[Arg(2, 887)]),
-    ((1758, false, NAND2), &[Arg(1, 8), Tv(478)]),
-    ((1759, false, NAND2), &[Arg(1, 7), Tv(477)]),
-    ((1761, false, NAND2), &[Tv(410), Arg(2, 831)]),
-    ((1762, false, NAND2), &[Arg(1, 5), Tv(476)]),
-    ((1769, false, NAND2), &[Arg(1, 15), Tv(481)]),
-    ((1770, false, NAND2), &[Tv(408), Arg(2, 837)]),
-    ((1772, false, NAND2), &[Tv(412), Arg(2, 839)]),
-    ((1773, false, NAND2), &[Arg(1, 13), Tv(480)]),
-    ((1776, false, NAND2), &[Tv(391), Arg(2, 826)]),
-    ((1777, false, NAND2), &[Tv(404), Arg(2, 832)]),
-    ((1901, false, INV), &[Tv(1900)]),
-    ((1964, false, NAND2), &[Tv(1922), Tv(1963)]),
-    ((2142, false, AND2), &[Tv(2140), Tv(2141)]),
-    ((2145, false, AND2), &[Tv(2143), Tv(2144)]),
-    ((2151, false, AND2), &[Tv(2149), Tv(2150)]),
-    ((2158, false, AND2), &[Tv(2154), Tv(2157)]),
-    ((2167, false, AND2), &[Tv(2163), Tv(2166)]),
-    ((2170, false, AND2), &[Tv(2168), Tv(2169)]),
-    ((2186, false, AND2), &[Tv(2178), Tv(2185)]),
-    ((2201, false, AND2), &[Tv(2193), Tv(2200)]),
-    ((2213, false, NAND2), &[Tv(2209), Tv(2212)]),
-    ((2224, false, AND2), &[Tv(2216), Tv(2223)]),
-    ((2234, false, AND2), &[Tv(2230), Tv(2233)]),
-    ((2261, false, AND2), &[Tv(2247), Tv(2260)]),
-    ((2267, false, AND2), &[Tv(2265), Tv(2266)]),
-    ((2270, false, AND2), &[Tv(2268), Tv(2269)]),
-    ((2274, false, AND2), &[Tv(2272), Tv(2273)]),
-    ((2277, false, AND2), &[Tv(2275), Tv(2276)]),
-    ((2282, false, AND2), &[Tv(2280), Tv(2281)]),
-    ((2285, false, AND2), &[Tv(2283), Tv(2284)]),
-    ((2295, false, AND2), &[Tv(2291), Tv(2294)]),
-    ((2306, false, AND2), &[Tv(2298), Tv(2305)]),
-    ((2341, false, NAND2), &[Arg(1, 5), Tv(447)]),
-    ((2342, false, NAND2), &[Arg(1, 7), Tv(449)]),
-    ((2344, false, NAND2), &[Tv(410), Arg(2, 687)]),
-    ((2345, false, NAND2), &[Tv(409), Arg(2, 694)]),
-    ((2349, false, NAND2), &[Tv(412), Arg(2, 695)]),
-    ((2350, false, NAND2), &[Arg(1, 3), Tv(446)]),
-    ((2354, false, NAND2), &[Arg(1, 1), Tv(445)]),
-    ((2355, false, NAND2), &[Tv(404), Arg(2, 688)]),
-    ((2357, false, NAND2), &[Tv(394), Arg(2, 685)]),
-    ((2358, false, NAND2), &[Arg(1, 8), Tv(450)]),
-    ((2362, false, NAND2), &[Arg(1, 12), Tv(452)]),
-    ((2363, false, NAND2), &[Arg(1, 15), Tv(454)]),
-    ((2601, false, NAND2), &[Tv(2563), Tv(2600)]),
-    ((2726, false, AND2), &[Tv(2712), Tv(2725)]),
-    ((2763, false, AND2), &[Tv(2733), Tv(2762)]),
-];
-
-static LEVEL_14: [((usize, bool, CellType), &[GateInput]); 249] = [
-    ((3031, false, NAND2), &[Tv(3028), Tv(3030)]),
-    ((3033, false, AND2), &[Tv(2819), Tv(3032)]),
-    ((3043, false, NOR2), &[Tv(1900), Tv(1964)]),
-    ((3143, false, NAND2), &[Tv(3140), Tv(3142)]),
-    ((3145, false, AND2), &[Tv(2819), Tv(3144)]),
-    ((3147, false, OR2), &[Tv(89), Tv(2819)]),
-    ((3257, false, NAND2), &[Tv(3254), Tv(3256)]),
-    ((3259, false, AND2), &[Tv(2601), Tv(3258)]),
-    ((3261, false, OR2), &[Tv(98), Tv(2601)]),
-    ((3265, false, NAND2), &[Tv(112), Tv(1900)]),
-    ((3364, false, NAND2), &[Tv(3361), Tv(3363)]),
-    ((3366, false, AND2), &[Tv(2601), Tv(3365)]),
-    ((3368, false, OR2), &[Tv(99), Tv(2601)]),
-    ((3372, false, NAND2), &[Tv(113), Tv(1900)]),
-    ((3471, false, NAND2), &[Tv(3468), Tv(3470)]),
-    ((3473, false, AND2), &[Tv(2601), Tv(3472)]),
-    ((3475, false, OR2), &[Tv(100), Tv(2601)]),
-    ((3479, false, NAND2), &[Tv(114), Tv(1900)]),
-    ((3578, false, NAND2), &[Tv(3575), Tv(3577)]),
-    ((3580, false, AND2), &[Tv(2601), Tv(3579)]),
-    ((3582, false, OR2), &[Tv(101), Tv(2601)]),
-    ((3586, false, NAND2), &[Tv(115), Tv(1900)]),
-    ((3685, false, NAND2), &[Tv(3682), Tv(3684)]),
-    ((3687, false, AND2), &[Tv(2601), Tv(3686)]),
-    ((3689, false, OR2), &[Tv(102), Tv(2601)]),
-    ((3693, false, NAND2), &[Tv(116), Tv(1900)]),
-    ((3792, false, NAND2), &[Tv(3789), Tv(3791)]),
-    ((3794, false, AND2), &[Tv(2601), Tv(3793)]),
-    ((3796, false, OR2), &[Tv(103), Tv(2601)]),
-    ((3800, false, NAND2), &[Tv(117), Tv(1900)]),
-    ((3899, false, NAND2), &[Tv(3896), Tv(3898)]),
-    ((3901, false, AND2), &[Tv(2601), Tv(3900)]),
-    ((3903, false, OR2), &[Tv(47), Tv(2601)]),
-    ((3907, false, NAND2), &[Tv(31), Tv(1900)]),
-    ((4006, false, NAND2), &[Tv(4003), Tv(4005)]),
-    ((4008, false, AND2), &[Tv(2601), Tv(4007)]),
-    ((4010, false, OR2), &[Tv(46), Tv(2601)]),
-    ((4014, false, NAND2), &[Tv(30), Tv(1900)]),
-    ((4113, false, NAND2), &[Tv(4110), Tv(4112)]),
-    ((4115, false, AND2), &[Tv(2601), Tv(4114)]),
-    ((4117, false, OR2), &[Tv(44), Tv(2601)]),
-    ((4121, false, NAND2), &[Tv(28), Tv(1900)]),
-    ((4220, false, NAND2), &[Tv(4217), Tv(4219)]),
-    ((4222, false, AND2), &[Tv(2601), Tv(4221)]),
-    ((4224, false, OR2), &[Tv(45), Tv(2601)]),
-    ((4228, false, NAND2), &[Tv(29), Tv(1900)]),
-    ((4327, false, NAND2), &[Tv(4324), Tv(4326)]),
-    ((4329, false, AND2), &[Tv(2601), Tv(4328)]),
-    ((4331, false, OR2), &[Tv(40), Tv(2601)]),
-    ((4335, false, NAND2), &[Tv(24), Tv(1900)]),
-    ((4434, false, NAND2), &[Tv(4431), Tv(4433)]),
-    ((4436, false, AND2), &[Tv(2601), Tv(4435)]),
-    ((4438, false, OR2), &[Tv(41), Tv(2601)]),
-    ((4442, false, NAND2), &[Tv(25), Tv(1900)]),
-    ((4541, false, NAND2), &[Tv(4538), Tv(4540)]),
-    ((4543, false, AND2), &[Tv(2601), Tv(4542)]),
-    ((4545, false, OR2), &[Tv(42), Tv(2601)]),
-    ((4549, false, NAND2), &[Tv(26), Tv(1900)]),
-    ((4648, false, NAND2), &[Tv(4645), Tv(4647)]),
-    ((4650, false, AND2), &[Tv(2601), Tv(4649)]),
-    ((4652, false, OR2), &[Tv(43), Tv(2601)]),
-    ((4656, false, NAND2), &[Tv(27), Tv(1900)]),
-    ((4755, false, NAND2), &[Tv(4752), Tv(4754)]),
-    ((4757, false, AND2), &[Tv(2601), Tv(4756)]),
-    ((4759, false, OR2), &[Tv(213), Tv(2601)]),
-    ((4763, false, NAND2), &[Tv(229), Tv(1900)]),
-    ((4862, false, NAND2), &[Tv(4859), Tv(4861)]),
-    ((4864, false, AND2), &[Tv(2601), Tv(4863)]),
-    ((4866, false, OR2), &[Tv(214), Tv(2601)]),
-    ((4870, false, NAND2), &[Tv(230), Tv(1900)]),
-    ((4969, false, NAND2), &[Tv(4966), Tv(4968)]),
-    ((4971, false, AND2), &[Tv(2601), Tv(4970)]),
-    ((4973, false, OR2), &[Tv(215), Tv(2601)]),
-    ((4977, false, NAND2), &[Tv(231), Tv(1900)]),
-    ((5076, false, NAND2), &[Tv(5073), Tv(5075)]),
-    ((5078, false, AND2), &[Tv(2601), Tv(5077)]),
-    ((5080, false, OR2), &[Tv(216), Tv(2601)]),
-    ((5084, false, NAND2), &[Tv(232), Tv(1900)]),
-    ((5183, false, NAND2), &[Tv(5180), Tv(5182)]),
-    ((5185, false, AND2), &[Tv(2601), Tv(5184)]),
-    ((5187, false, OR2), &[Tv(217), Tv(2601)]),
-    ((5191, false, NAND2), &[Tv(233), Tv(1900)]),
-    ((5290, false, NAND2), &[Tv(5287), Tv(5289)]),
-    ((5292, false, AND2), &[Tv(2601), Tv(5291)]),
-    ((5294, false, OR2), &[Tv(218), Tv(2601)]),
-    ((5298, false, NAND2), &[Tv(234), Tv(1900)]),
-    ((5397, false, NAND2), &[Tv(5394), Tv(5396)]),
-    ((5399, false, AND2), &[Tv(2601), Tv(5398)]),
-    ((5401, false, OR2), &[Tv(219), Tv(2601)]),
-    ((5405, false, NAND2), &[Tv(235), Tv(1900)]),
-    ((5504, false, NAND2), &[Tv(5501), Tv(5503)]),
-    ((5506, false, AND2), &[Tv(2601), Tv(5505)]),
-    ((5508, false, OR2), &[Tv(220), Tv(2601)]),
-    ((5512, false, NAND2), &[Tv(236), Tv(1900)]),
-    ((5611, false, NAND2), &[Tv(5608), Tv(5610)]),
-    ((5613, false, AND2), &[Tv(2601), Tv(5612)]),
-    ((5615, false, OR2), &[Tv(309), Tv(2601)]),
-    ((5619, false, NAND2), &[Tv(325), Tv(1900)]),
-    ((5718, false, NAND2), &[Tv(5715), Tv(5717)]),
-    ((5720, false, AND2), &[Tv(2601), Tv(5719)]),
-    ((5722, false, OR2), &[Tv(310), Tv(2601)]),
-    ((5726, false, NAND2), &[Tv(326), Tv(1900)]),
-    ((5825, false, NAND2), &[Tv(5822), Tv(5824)]),
-    ((5827, false, AND2), &[Tv(2601), Tv(5826)]),
-    ((5829, false, OR2), &[Tv(311), Tv(2601)]),
-    ((5833, false, NAND2), &[Tv(327), Tv(1900)]),
-    ((5932, false, NAND2), &[Tv(5929), Tv(5931)]),
-    ((5934, false, AND2), &[Tv(2601), Tv(5933)]),
-    ((5936, false, OR2), &[Tv(312), Tv(2601)]),
-    ((5940, false, NAND2), &[Tv(328), Tv(1900)]),
-    ((6039, false, NAND2), &[Tv(6036), Tv(6038)]),
-    ((6041, false, AND2), &[Tv(2601), Tv(6040)]),
-    ((6043, false, OR2), &[Tv(313), Tv(2601)]),
-    ((6047, false, NAND2), &[Tv(329), Tv(1900)]),
-    ((6146, false, NAND2), &[Tv(6143), Tv(6145)]),
-    ((6148, false, AND2), &[Tv(2601), Tv(6147)]),
-    ((6150, false, OR2), &[Tv(314), Tv(2601)]),
-    ((6154, false, NAND2), &[Tv(330), Tv(1900)]),
-    ((6253, false, NAND2), &[Tv(6250), Tv(6252)]),
-    ((6255, false, AND2), &[Tv(2601), Tv(6254)]),
-    ((6257, false, OR2), &[Tv(315), Tv(2601)]),
-    ((6261, false, NAND2), &[Tv(331), Tv(1900)]),
-    ((6360, false, NAND2), &[Tv(6357), Tv(6359)]),
-    ((6362, false, AND2), &[Tv(2601), Tv(6361)]),
-    ((6364, false, OR2), &[Tv(316), Tv(2601)]),
-    ((6368, false, NAND2), &[Tv(332), Tv(1900)]),
-    ((32, false, INV), &[Arg(2, 412)]),
-    ((33, false, INV), &[Arg(2, 413)]),
-    ((34, false, INV), &[Arg(2, 414)]),
-    ((35, false, INV), &[Arg(2, 415)]),
-    ((36, false, INV), &[Arg(2, 410)]),
-    ((37, false, INV), &[Arg(2, 411)]),
-    ((38, false, INV), &[Arg(2, 409)]),
-    ((39, false, INV), &[Arg(2, 408)]),
-    ((88, false, INV), &[Arg(2, 240)]),
-    ((105, false, INV), &[Arg(2, 386)]),
-    ((106, false, INV), &[Arg(2, 387)]),
-    ((107, false, INV), &[Arg(2, 388)]),
-    ((108, false, INV), &[Arg(2, 389)]),
-    ((109, false, INV), &[Arg(2, 390)]),
-    ((110, false, INV), &[Arg(2, 391)]),
-    ((221, false, INV), &[Arg(2, 416)]),
-    ((222, false, INV), &[Arg(2, 417)]),
-    ((223, false, INV), &[Arg(2, 418)]),
-    ((224, false, INV), &[Arg(2, 419)]),
-    ((225, false, INV), &[Arg(2, 420)]),
-    ((226, false, INV), &[Arg(2, 421)]),
-    ((227, false, INV), &[Arg(2, 422)]),
-    ((228, false, INV), &[Arg(2, 423)]),
-    ((317, false, INV), &[Arg(2, 424)]),
-    ((318, false, INV), &[Arg(2, 425)]),
-    ((319, false, INV), &[Arg(2, 426)]),
-    ((320, false, INV), &[Arg(2, 427)]),
-    ((321, false, INV), &[Arg(2, 428)]),
-    ((322, false, INV), &[Arg(2, 429)]),
-    ((323, false, INV), &[Arg(2, 430)]),
-    ((324, false, INV), &[Arg(2, 431)]),
-    ((459, false, INV), &[Arg(2, 737)]),
-    ((460, false, INV), &[Arg(2, 739)]),
-    ((462, false, INV), &[Arg(2, 742)]),
-    ((465, false, INV), &[Arg(2, 777)]),
-    ((470, false, INV), &[Arg(2, 786)]),
-    ((471, false, INV), &[Arg(2, 788)]),
-    ((483, false, INV), &[Arg(2, 874)]),
-    ((485, false, INV), &[Arg(2, 879)]),
-    ((1633, false, NAND2), &[Tv(392), Arg(2, 875)]),
-    ((1634, false, NAND2), &[Arg(1, 10), Tv(487)]),
-    ((1636, false, NAND2), &[Tv(411), Arg(2, 882)]),
-    ((1637, false, NAND2), &[Arg(1, 12), Tv(488)]),
-    ((1640, false, NAND2), &[Tv(391), Arg(2, 874)]),
-    ((1641, false, NAND2), &[Tv(405), Arg(2, 881)]),
-    ((1643, false, NAND2), &[Tv(412), Arg(2, 887)]),
-    ((1644, false, NAND2), &[Arg(1, 3), Tv(484)]),
-    ((1648, false, NAND2), &[Arg(1, 1), Tv(482)]),
-    ((1649, false, NAND2), &[Arg(1, 13), Tv(489)]),
-    ((1651, false, NAND2), &[Arg(1, 9), Tv(486)]),
-    ((1652, false, NAND2), &[Tv(390), Arg(2, 873)]),
-    ((1655, false, NAND2), &[Tv(408), Arg(2, 885)]),
-    ((1656, false, NAND2), &[Tv(407), Arg(2, 884)]),
-    ((1658, false, NAND2), &[Tv(410), Arg(2, 879)]),
-    ((1659, false, NAND2), &[Arg(1, 15), Tv(490)]),
-    ((1737, false, NAND2), &[Tv(390), Arg(2, 825)]),
-    ((1738, false, NAND2), &[Arg(1, 11), Tv(479)]),
-    ((1740, false, NAND2), &[Tv(406), Arg(2, 835)]),
-    ((1741, false, NAND2), &[Tv(394), Arg(2, 829)]),
-    ((1745, false, NAND2), &[Arg(1, 1), Tv(474)]),
-    ((1746, false, NAND2), &[Arg(1, 2), Tv(475)]),
-    ((1760, false, AND2), &[Tv(1758), Tv(1759)]),
-    ((1763, false, AND2), &[Tv(1761), Tv(1762)]),
-    ((1765, false, XNOR2), &[Arg(1, 10), Arg(2, 834)]),
-    ((1766, false, XNOR2), &[Arg(1, 3), Arg(2, 827)]),
-    ((1771, false, AND2), &[Tv(1769), Tv(1770)]),
-    ((1774, false, AND2), &[Tv(1772), Tv(1773)]),
-    ((1778, false, AND2), &[Tv(1776), Tv(1777)]),
-    ((1779, false, XNOR2), &[Arg(1, 6), Arg(2, 830)]),
-    ((1814, false, NAND2), &[Tv(405), Arg(2, 785)]),
-    ((1815, false, NAND2), &[Tv(407), Arg(2, 788)]),
-    ((1817, false, NAND2), &[Tv(391), Arg(2, 778)]),
-    ((1818, false, NAND2), &[Arg(1, 13), Tv(472)]),
-    ((1822, false, NAND2), &[Arg(1, 15), Tv(473)]),
-    ((1823, false, NAND2), &[Arg(1, 9), Tv(469)]),
-    ((1827, false, NAND2), &[Tv(390), Arg(2, 777)]),
-    ((1828, false, NAND2), &[Tv(389), Arg(2, 776)]),
-    ((1830, false, NAND2), &[Tv(411), Arg(2, 786)]),
-    ((1831, false, NAND2), &[Arg(1, 8), Tv(468)]),
-    ((1834, false, NAND2), &[Arg(1, 0), Tv(464)]),
-    ((1835, false, NAND2), &[Arg(1, 4), Tv(467)]),
-    ((1837, false, NAND2), &[Tv(393), Arg(2, 780)]),
-    ((1838, false, NAND2), &[Arg(1, 2), Tv(466)]),
-    ((1965, false, AND2), &[Tv(1901), Tv(1964)]),
-    ((2146, false, NAND2), &[Tv(2142), Tv(2145)]),
-    ((2159, false, AND2), &[Tv(2151), Tv(2158)]),
-    ((2171, false, AND2), &[Tv(2167), Tv(2170)]),
-    ((2202, false, AND2), &[Tv(2186), Tv(2201)]),
-    ((2225, false, AND2), &[Tv(2213), Tv(2224)]),
-    ((2262, false, AND2), &[Tv(2234), Tv(2261)]),
-    ((2271, false, NAND2), &[Tv(2267), Tv(2270)]),
-    ((2278, false, AND2), &[Tv(2274), Tv(2277)]),
-    ((2286, false, AND2), &[Tv(2282), Tv(2285)]),
-    ((2307, false, AND2), &[Tv(2295), Tv(2306)]),
-    ((2318, false, NAND2), &[Tv(395), Arg(2, 686)]),
-    ((2319, false, NAND2), &[Tv(405), Arg(2, 689)]),
-    ((2321, false, NAND2), &[Tv(390), Arg(2, 681)]),
-    ((2322, false, NAND2), &[Arg(1, 14), Tv(453)]),
-    ((2325, false, NAND2), &[Tv(392), Arg(2, 683)]),
-    ((2326, false, NAND2), &[Tv(407), Arg(2, 692)]),
-    ((2328, false, NAND2), &[Arg(1, 9), Tv(451)]),
-    ((2329, false, NAND2), &[Arg(1, 6), Tv(448)]),
-    ((2343, false, AND2), &[Tv(2341), Tv(2342)]),
-    ((2346, false, AND2), &[Tv(2344), Tv(2345)]),
-    ((2348, false, XNOR2), &[Arg(1, 2), Arg(2, 682)]),
-    ((2351, false, AND2), &[Tv(2349), Tv(2350)]),
-    ((2356, false, AND2), &[Tv(2354), Tv(2355)]),
-    ((2359, false, AND2), &[Tv(2357), Tv(2358)]),
-    ((2361, false, XNOR2), &[Arg(1, 4), Arg(2, 684)]),
-    ((2364, false, AND2), &[Tv(2362), Tv(2363)]),
-    ((2399, false, NAND2), &[Tv(391), Arg(2, 730)]),
-    ((2400, false, NAND2), &[Tv(407), Arg(2, 740)]),
-    ((2402, false, NAND2), &[Arg(1, 15), Tv(463)]),
-    ((2403, false, NAND2), &[Tv(405), Arg(2, 737)]),
-    ((2407, false, NAND2), &[Arg(1, 1), Tv(456)]),
-    ((2408, false, NAND2), &[Arg(1, 2), Tv(457)]),
-    ((2412, false, NAND2), &[Tv(406), Arg(2, 739)]),
-    ((2413, false, NAND2), &[Arg(1, 0), Tv(455)]),
-    ((2415, false, NAND2), &[Tv(389), Arg(2, 728)]),
-    ((2416, false, NAND2), &[Arg(1, 4), Tv(458)]),
-    ((2419, false, NAND2), &[Tv(390), Arg(2, 729)]),
-    ((2420, false, NAND2), &[Arg(1, 12), Tv(461)]),
-    ((2764, false, NAND2), &[Tv(2726), Tv(2763)]),
-];
-
-static LEVEL_15: [((usize, bool, CellType), &[GateInput]); 247] = [
-    ((3020, false, OR2), &[Tv(88), Tv(2819)]),
-    ((3034, false, NAND2), &[Tv(3031), Tv(3033)]),
-    ((3146, false, NAND2), &[Tv(3143), Tv(3145)]),
-    ((3148, false, AND2), &[Tv(2487), Tv(3147)]),
-    ((3150, false, OR2), &[Arg(2, 289), Tv(2487)]),
-    ((3260, false, NAND2), &[Tv(3257), Tv(3259)]),
-    ((3262, false, AND2), &[Tv(1965), Tv(3261)]),
-    ((3264, false, NAND2), &[Tv(105), Tv(3043)]),
-    ((3266, false, AND2), &[Tv(2764), Tv(3265)]),
-    ((3367, false, NAND2), &[Tv(3364), Tv(3366)]),
-    ((3369, false, AND2), &[Tv(1965), Tv(3368)]),
-    ((3371, false, NAND2), &[Tv(106), Tv(3043)]),
-    ((3373, false, AND2), &[Tv(2764), Tv(3372)]),
-    ((3474, false, NAND2), &[Tv(3471), Tv(3473)]),
-    ((3476, false, AND2), &[Tv(1965), Tv(3475)]),
-    ((3478, false, NAND2), &[Tv(107), Tv(3043)]),
-    ((3480, false, AND2), &[Tv(2764), Tv(3479)]),
-    ((3581, false, NAND2), &[Tv(3578), Tv(3580)]),
-    ((3583, false, AND2), &[Tv(1965), Tv(3582)]),
-    ((3585, false, NAND2), &[Tv(108), Tv(3043)]),
-    ((3587, false, AND2), &[Tv(2764), Tv(3586)]),
-    ((3688, false, NAND2), &[Tv(3685), Tv(3687)]),
-    ((3690, false, AND2), &[Tv(1965), Tv(3689)]),
-    ((3692, false, NAND2), &[Tv(109), Tv(3043)]),
-    ((3694, false, AND2), &[Tv(2764), Tv(3693)]),
-    ((3795, false, NAND2), &[Tv(3792), Tv(3794)]),
-    ((3797, false, AND2), &[Tv(1965), Tv(3796)]),
-    ((3799, false, NAND2), &[Tv(110), Tv(3043)]),
-    ((3801, false, AND2), &[Tv(2764), Tv(3800)]),
-    ((3902, false, NAND2), &[Tv(3899), Tv(3901)]),
-    ((3904, false, AND2), &[Tv(1965), Tv(3903)]),
-    ((3906, false, NAND2), &[Tv(39), Tv(3043)]),
-    ((3908, false, AND2), &[Tv(2764), Tv(3907)]),
-    ((4009, false, NAND2), &[Tv(4006), Tv(4008)]),
-    ((4011, false, AND2), &[Tv(1965), Tv(4010)]),
-    ((4013, false, NAND2), &[Tv(38), Tv(3043)]),
-    ((4015, false, AND2), &[Tv(2764), Tv(4014)]),
-    ((4116, false, NAND2), &[Tv(4113), Tv(4115)]),
-    ((4118, false, AND2), &[Tv(1965), Tv(4117)]),
-    ((4120, false, NAND2), &[Tv(36), Tv(3043)]),
-    ((4122, false, AND2), &[Tv(2764), Tv(4121)]),
-    ((4223, false, NAND2), &[Tv(4220), Tv(4222)]),
-    ((4225, false, AND2), &[Tv(1965), Tv(4224)]),
-    ((4227, false, NAND2), &[Tv(37), Tv(3043)]),
-    ((4229, false, AND2), &[Tv(2764), Tv(4228)]),
-    ((4330, false, NAND2), &[Tv(4327), Tv(4329)]),
-    ((4332, false, AND2), &[Tv(1965), Tv(4331)]),
-    ((4334, false, NAND2), &[Tv(32), Tv(3043)]),
-    ((4336, false, AND2), &[Tv(2764), Tv(4335)]),
-    ((4437, false, NAND2), &[Tv(4434), Tv(4436)]),
-    ((4439, false, AND2), &[Tv(1965), Tv(4438)]),
-    ((4441, false, NAND2), &[Tv(33), Tv(3043)]),
-    ((4443, false, AND2), &[Tv(2764), Tv(4442)]),
-    ((4544, false, NAND2), &[Tv(4541), Tv(4543)]),
-    ((4546, false, AND2), &[Tv(1965), Tv(4545)]),
-    ((4548, false, NAND2), &[Tv(34), Tv(3043)]),
-    ((4550, false, AND2), &[Tv(2764), Tv(4549)]),
-    ((4651, false, NAND2), &[Tv(4648), Tv(4650)]),
-    ((4653, false, AND2), &[Tv(1965), Tv(4652)]),
-    ((4655, false, NAND2), &[Tv(35), Tv(3043)]),
-    ((4657, false, AND2), &[Tv(2764), Tv(4656)]),
-    ((4758, false, NAND2), &[Tv(4755), Tv(4757)]),
-    ((4760, false, AND2), &[Tv(1965), Tv(4759)]),
-    ((4762, false, NAND2), &[Tv(221), Tv(3043)]),
-    ((4764, false, AND2), &[Tv(2764), Tv(4763)]),
-    ((4865, false, NAND2), &[Tv(4862), Tv(4864)]),
-    ((4867, false, AND2), &[Tv(1965), Tv(4866)]),
-    ((4869, false, NAND2), &[Tv(222), Tv(3043)]),
-    ((4871, false, AND2), &[Tv(2764), Tv(4870)]),
-    ((4972, false, NAND2), &[Tv(4969), Tv(4971)]),
-    ((4974, false, AND2), &[Tv(1965), Tv(4973)]),
-    ((4976, false, NAND2), &[Tv(223), Tv(3043)]),
-    ((4978, false, AND2), &[Tv(2764), Tv(4977)]),
-    ((5079, false, NAND2), &[Tv(5076), Tv(5078)]),
-    ((5081, false, AND2), &[Tv(1965), Tv(5080)]),
-    ((5083, false, NAND2), &[Tv(224), Tv(3043)]),
-    ((5085, false, AND2), &[Tv(2764), Tv(5084)]),
-    ((5186, false, NAND2), &[Tv(5183), Tv(5185)]),
-    ((5188, false, AND2), &[Tv(1965), Tv(5187)]),
-    ((5190, false, NAND2), &[Tv(225), Tv(3043)]),
-    ((5192, false, AND2), &[Tv(2764), Tv(5191)]),
-    ((5293, false, NAND2), &[Tv(5290), Tv(5292)]),
-    ((5295, false, AND2), &[Tv(1965), Tv(5294)]),
-    ((5297, false, NAND2), &[Tv(226), Tv(3043)]),
-    ((5299, false, AND2), &[Tv(2764), Tv(5298)]),
-    ((5400, false, NAND2), &[Tv(5397), Tv(5399)]),
-    ((5402, false, AND2), &[Tv(1965), Tv(5401)]),
-    ((5404, false, NAND2), &[Tv(227), Tv(3043)]),
-    ((5406, false, AND2), &[Tv(2764), Tv(5405)]),
-    ((5507, false, NAND2), &[Tv(5504), Tv(5506)]),
-    ((5509, false, AND2), &[Tv(1965), Tv(5508)]),
-    ((5511, false, NAND2), &[Tv(228), Tv(3043)]),
-    ((5513, false, AND2), &[Tv(2764), Tv(5512)]),
-    ((5614, false, NAND2), &[Tv(5611), Tv(5613)]),
-    ((5616, false, AND2), &[Tv(1965), Tv(5615)]),
-    ((5618, false, NAND2), &[Tv(317), Tv(3043)]),
-    ((5620, false, AND2), &[Tv(2764), Tv(5619)]),
-    ((5721, false, NAND2), &[Tv(5718), Tv(5720)]),
-    ((5723, false, AND2), &[Tv(1965), Tv(5722)]),
-    ((5725, false, NAND2), &[Tv(318), Tv(3043)]),
-    ((5727, false, AND2), &[Tv(2764), Tv(5726)]),
-    ((5828, false, NAND2), &[Tv(5825), Tv(5827)]),
-    ((5830, false, AND2), &[Tv(1965), Tv(5829)]),
-    ((5832, false, NAND2), &[Tv(319), Tv(3043)]),
-    ((5834, false, AND2), &[Tv(2764), Tv(5833)]),
-    ((5935, false, NAND2), &[Tv(5932), Tv(5934)]),
-    ((5937, false, AND2), &[Tv(1965), Tv(5936)]),
-    ((5939, false, NAND2), &[Tv(320), Tv(3043)]),
-    ((5941, false, AND2), &[Tv(2764), Tv(5940)]),
-    ((6042, false, NAND2), &[Tv(6039), Tv(6041)]),
-    ((6044, false, AND2), &[Tv(1965), Tv(6043)]),
-    ((6046, false, NAND2), &[Tv(321), Tv(3043)]),
-    ((6048, false, AND2), &[Tv(2764), Tv(6047)]),
-    ((6149, false, NAND2), &[Tv(6146), Tv(6148)]),
-    ((6151, false, AND2), &[Tv(1965), Tv(6150)]),
-    ((6153, false, NAND2), &[Tv(322), Tv(3043)]),
-    ((6155, false, AND2), &[Tv(2764), Tv(6154)]),
-    ((6256, false, NAND2), &[Tv(6253), Tv(6255)]),
-    ((6258, false, AND2), &[Tv(1965), Tv(6257)]),
-    ((6260, false, NAND2), &[Tv(323), Tv(3043)]),
-    ((6262, false, AND2), &[Tv(2764), Tv(6261)]),
-    ((6363, false, NAND2), &[Tv(6360), Tv(6362)]),
-    ((6365, false, AND2), &[Tv(1965), Tv(6364)]),
-    ((6367, false, NAND2), &[Tv(324), Tv(3043)]),
-    ((6369, false, AND2), &[Tv(2764), Tv(6368)]),
-    ((16, false, INV), &[Arg(2, 508)]),
-    ((17, false, INV), &[Arg(2, 509)]),
-    ((18, false, INV), &[Arg(2, 510)]),
-    ((19, false, INV), &[Arg(2, 511)]),
-    ((20, false, INV), &[Arg(2, 506)]),
-    ((21, false, INV), &[Arg(2, 507)]),
-    ((22, false, INV), &[Arg(2, 505)]),
-    ((23, false, INV), &[Arg(2, 504)]),
-    ((96, false, INV), &[Arg(2, 288)]),
-    ((97, false, INV), &[Arg(2, 337)]),
-    ((111, false, INV), &[Arg(2, 433)]),
-    ((119, false, INV), &[Arg(2, 482)]),
-    ((120, false, INV), &[Arg(2, 483)]),
-    ((121, false, INV), &[Arg(2, 484)]),
-    ((122, false, INV), &[Arg(2, 485)]),
-    ((123, false, INV), &[Arg(2, 486)]),
-    ((124, false, INV), &[Arg(2, 487)]),
-    ((237, false, INV), &[Arg(2, 512)]),
-    ((238, false, INV), &[Arg(2, 513)]),
-    ((239, false, INV), &[Arg(2, 514)]),
-    ((240, false, INV), &[Arg(2, 515)]),
-    ((241, false, INV), &[Arg(2, 516)]),
-    ((242, false, INV), &[Arg(2, 517)]),
-    ((243, false, INV), &[Arg(2, 518)]),
-    ((244, false, INV), &[Arg(2, 519)]),
-    ((333, false, INV), &[Arg(2, 520)]),
-    ((334, false, INV), &[Arg(2, 521)]),
-    ((335, false, INV), &[Arg(2, 522)]),
-    ((336, false, INV), &[Arg(2, 523)]),
-    ((337, false, INV), &[Arg(2, 524)]),
-    ((338, false, INV), &[Arg(2, 525)]),
-    ((339, false, INV), &[Arg(2, 526)]),
-    ((340, false, INV), &[Arg(2, 527)]),
-    ((491, false, INV), &[Arg(2, 921)]),
-    ((492, false, INV), &[Arg(2, 922)]),
-    ((493, false, INV), &[Arg(2, 923)]),
-    ((494, false, INV), &[Arg(2, 924)]),
-    ((495, false, INV), &[Arg(2, 926)]),
-    ((497, false, INV), &[Arg(2, 931)]),
-    ((498, false, INV), &[Arg(2, 932)]),
-    ((512, false, INV), &[Arg(2, 1016)]),
-    ((514, false, INV), &[Arg(2, 1018)]),
-    ((515, false, INV), &[Arg(2, 1020)]),
-    ((516, false, INV), &[Arg(2, 1024)]),
-    ((517, false, INV), &[Arg(2, 1025)]),
-    ((520, false, INV), &[Arg(2, 1029)]),
-    ((521, false, INV), &[Arg(2, 1031)]),
-    ((1625, false, NAND2), &[Arg(1, 7), Tv(485)]),
-    ((1626, false, NAND2), &[Arg(1, 2), Tv(483)]),
-    ((1635, false, AND2), &[Tv(1633), Tv(1634)]),
-    ((1638, false, AND2), &[Tv(1636), Tv(1637)]),
-    ((1642, false, AND2), &[Tv(1640), Tv(1641)]),
-    ((1645, false, AND2), &[Tv(1643), Tv(1644)]),
-    ((1650, false, AND2), &[Tv(1648), Tv(1649)]),
-    ((1653, false, AND2), &[Tv(1651), Tv(1652)]),
-    ((1657, false, AND2), &[Tv(1655), Tv(1656)]),
-    ((1660, false, AND2), &[Tv(1658), Tv(1659)]),
-    ((1730, false, NOR2), &[Arg(2, 842), Arg(2, 843)]),
-    ((1731, false, NOR2), &[Arg(2, 841), Arg(2, 840)]),
-    ((1733, false, NOR2), &[Arg(2, 845), Arg(2, 846)]),
-    ((1734, false, NOR2), &[Arg(2, 844), Arg(2, 847)]),
-    ((1739, false, AND2), &[Tv(1737), Tv(1738)]),
-    ((1742, false, AND2), &[Tv(1740), Tv(1741)]),
-    ((1744, false, XNOR2), &[Arg(1, 9), Arg(2, 833)]),
-    ((1747, false, AND2), &[Tv(1745), Tv(1746)]),
-    ((1751, false, XNOR2), &[Arg(1, 12), Arg(2, 836)]),
-    ((1752, false, XNOR2), &[Arg(1, 4), Arg(2, 828)]),
-    ((1754, false, XNOR2), &[Arg(1, 14), Arg(2, 838)]),
-    ((1755, false, XNOR2), &[Arg(1, 0), Arg(2, 824)]),
-    ((1764, false, AND2), &[Tv(1760), Tv(1763)]),
-    ((1767, false, AND2), &[Tv(1765), Tv(1766)]),
-    ((1775, false, AND2), &[Tv(1771), Tv(1774)]),
-    ((1780, false, AND2), &[Tv(1778), Tv(1779)]),
-    ((1796, false, NAND2), &[Tv(404), Arg(2, 784)]),
-    ((1797, false, NAND2), &[Tv(408), Arg(2, 789)]),
-    ((1799, false, NAND2), &[Arg(1, 12), Tv(471)]),
-    ((1800, false, NAND2), &[Arg(1, 10), Tv(470)]),
-    ((1806, false, NAND2), &[Tv(412), Arg(2, 791)]),
-    ((1807, false, NAND2), &[Arg(1, 1), Tv(465)]),
-    ((1816, false, AND2), &[Tv(1814), Tv(1815)]),
-    ((1819, false, AND2), &[Tv(1817), Tv(1818)]),
-    ((1821, false, XNOR2), &[Arg(1, 3), Arg(2, 779)]),
-    ((1824, false, AND2), &[Tv(1822), Tv(1823)]),
-    ((1829, false, AND2), &[Tv(1827), Tv(1828)]),
-    ((1832, false, AND2), &[Tv(1830), Tv(1831)]),
-    ((1836, false, AND2), &[Tv(1834), Tv(1835)]),
-    ((1839, false, AND2), &[Tv(1837), Tv(1838)]),
-    ((2160, false, AND2), &[Tv(2146), Tv(2159)]),
-    ((2203, false, AND2), &[Tv(2171), Tv(2202)]),
-    ((2263, false, AND2), &[Tv(2225), Tv(2262)]),
-    ((2279, false, AND2), &[Tv(2271), Tv(2278)]),
-    ((2308, false, AND2), &[Tv(2286), Tv(2307)]),
-    ((2311, false, NOR2), &[Arg(2, 698), Arg(2, 699)]),
-    ((2312, false, NOR2), &[Arg(2, 697), Arg(2, 696)]),
-    ((2314, false, OR2), &[Arg(2, 701), Arg(2, 702)]),
-    ((2315, false, OR2), &[Arg(2, 700), Arg(2, 703)]),
-    ((2320, false, AND2), &[Tv(2318), Tv(2319)]),
-    ((2323, false, AND2), &[Tv(2321), Tv(2322)]),
-    ((2327, false, AND2), &[Tv(2325), Tv(2326)]),
-    ((2330, false, AND2), &[Tv(2328), Tv(2329)]),
-    ((2334, false, XNOR2), &[Arg(1, 10), Arg(2, 690)]),
-    ((2335, false, XNOR2), &[Arg(1, 0), Arg(2, 680)]),
-    ((2337, false, XNOR2), &[Arg(1, 11), Arg(2, 691)]),
-    ((2338, false, XNOR2), &[Arg(1, 13), Arg(2, 693)]),
-    ((2347, false, AND2), &[Tv(2343), Tv(2346)]),
-    ((2352, false, AND2), &[Tv(2348), Tv(2351)]),
-    ((2360, false, AND2), &[Tv(2356), Tv(2359)]),
-    ((2365, false, AND2), &[Tv(2361), Tv(2364)]),
-    ((2381, false, NAND2), &[Tv(393), Arg(2, 732)]),
-    ((2382, false, NAND2), &[Arg(1, 14), Tv(462)]),
-    ((2384, false, NAND2), &[Tv(409), Arg(2, 742)]),
-    ((2385, false, NAND2), &[Arg(1, 11), Tv(460)]),
-    ((2391, false, NAND2), &[Arg(1, 9), Tv(459)]),
-    ((2392, false, NAND2), &[Tv(412), Arg(2, 743)]),
-    ((2401, false, AND2), &[Tv(2399), Tv(2400)]),
-    ((2404, false, AND2), &[Tv(2402), Tv(2403)]),
-    ((2406, false, XNOR2), &[Arg(1, 3), Arg(2, 731)]),
-    ((2409, false, AND2), &[Tv(2407), Tv(2408)]),
-    ((2414, false, AND2), &[Tv(2412), Tv(2413)]),
-    ((2417, false, AND2), &[Tv(2415), Tv(2416)]),
-    ((2421, false, AND2), &[Tv(2419), Tv(2420)]),
-    ((2422, false, XNOR2), &[Arg(1, 6), Arg(2, 734)]),
-];
-
-static LEVEL_16: [((usize, bool, CellType), &[GateInput]); 208] = [
-    ((3035, false, NAND2), &[Tv(3020), Tv(3034)]),
-    ((3037, false, OR2), &[Tv(96), Tv(2487)]),
-    ((3149, false, NAND2), &[Tv(3146), Tv(3148)]),
-    ((3151, false, AND2), &[Tv(2601), Tv(3150)]),
-    ((3153, false, OR2), &[Tv(97), Tv(2601)]),
-    ((3157, false, NAND2), &[Tv(111), Tv(1900)]),
-    ((3263, false, NAND2), &[Tv(3260), Tv(3262)]),
-    ((3267, false, AND2), &[Tv(3264), Tv(3266)]),
-    ((3269, false, OR2), &[Tv(119), Tv(2764)]),
-    ((3370, false, NAND2), &[Tv(3367), Tv(3369)]),
-    ((3374, false, AND2), &[Tv(3371), Tv(3373)]),
-    ((3376, false, OR2), &[Tv(120), Tv(2764)]),
-    ((3477, false, NAND2), &[Tv(3474), Tv(3476)]),
-    ((3481, false, AND2), &[Tv(3478), Tv(3480)]),
-    ((3483, false, OR2), &[Tv(121), Tv(2764)]),
-    ((3584, false, NAND2), &[Tv(3581), Tv(3583)]),
-    ((3588, false, AND2), &[Tv(3585), Tv(3587)]),
-    ((3590, false, OR2), &[Tv(122), Tv(2764)]),
-    ((3691, false, NAND2), &[Tv(3688), Tv(3690)]),
-    ((3695, false, AND2), &[Tv(3692), Tv(3694)]),
-    ((3697, false, OR2), &[Tv(123), Tv(2764)]),
-    ((3798, false, NAND2), &[Tv(3795), Tv(3797)]),
-    ((3802, false, AND2), &[Tv(3799), Tv(3801)]),
-    ((3804, false, OR2), &[Tv(124), Tv(2764)]),
-    ((3905, false, NAND2), &[Tv(3902), Tv(3904)]),
-    ((3909, false, AND2), &[Tv(3906), Tv(3908)]),
-    ((3911, false, OR2), &[Tv(23), Tv(2764)]),
-    ((4012, false, NAND2), &[Tv(4009), Tv(4011)]),
-    ((4016, false, AND2), &[Tv(4013), Tv(4015)]),
-    ((4018, false, OR2), &[Tv(22), Tv(2764)]),
-    ((4119, false, NAND2), &[Tv(4116), Tv(4118)]),
-    ((4123, false, AND2), &[Tv(4120), Tv(4122)]),
-    ((4125, false, OR2), &[Tv(20), Tv(2764)]),
-    ((4226, false, NAND2), &[Tv(4223), Tv(4225)]),
-    ((4230, false, AND2), &[Tv(4227), Tv(4229)]),
-    ((4232, false, OR2), &[Tv(21), Tv(2764)]),
-    ((4333, false, NAND2), &[Tv(4330), Tv(4332)]),
-    ((4337, false, AND2), &[Tv(4334), Tv(4336)]),
-    ((4339, false, OR2), &[Tv(16), Tv(2764)]),
-    ((4440, false, NAND2), &[Tv(4437), Tv(4439)]),
-    ((4444, false, AND2), &[Tv(4441), Tv(4443)]),
-    ((4446, false, OR2), &[Tv(17), Tv(2764)]),
-    ((4547, false, NAND2), &[Tv(4544), Tv(4546)]),
-    ((4551, false, AND2), &[Tv(4548), Tv(4550)]),
-    ((4553, false, OR2), &[Tv(18), Tv(2764)]),
-    ((4654, false, NAND2), &[Tv(4651), Tv(4653)]),
-    ((4658, false, AND2), &[Tv(4655), Tv(4657)]),
-    ((4660, false, OR2), &[Tv(19), Tv(2764)]),
-    ((4761, false, NAND2), &[Tv(4758), Tv(4760)]),
-    ((4765, false, AND2), &[Tv(4762), Tv(4764)]),
-    ((4767, false, OR2), &[Tv(237), Tv(2764)]),
-    ((4868, false, NAND2), &[Tv(4865), Tv(4867)]),
-    ((4872, false, AND2), &[Tv(4869), Tv(4871)]),
-    ((4874, false, OR2), &[Tv(238), Tv(2764)]),
-    ((4975, false, NAND2), &[Tv(4972), Tv(4974)]),
-    ((4979, false, AND2), &[Tv(4976), Tv(4978)]),
-    ((4981, false, OR2), &[Tv(239), Tv(2764)]),
-    ((5082, false, NAND2), &[Tv(5079), Tv(5081)]),
-    ((5086, false, AND2), &[Tv(5083), Tv(5085)]),
-    ((5088, false, OR2), &[Tv(240), Tv(2764)]),
-    ((5189, false, NAND2), &[Tv(5186), Tv(5188)]),
-    ((5193, false, AND2), &[Tv(5190), Tv(5192)]),
-    ((5195, false, OR2), &[Tv(241), Tv(2764)]),
-    ((5296, false, NAND2), &[Tv(5293), Tv(5295)]),
-    ((5300, false, AND2), &[Tv(5297), Tv(5299)]),
-    ((5302, false, OR2), &[Tv(242), Tv(2764)]),
-    ((5403, false, NAND2), &[Tv(5400), Tv(5402)]),
-    ((5407, false, AND2), &[Tv(5404), Tv(5406)]),
-    ((5409, false, OR2), &[Tv(243), Tv(2764)]),
-    ((5510, false, NAND2), &[Tv(5507), Tv(5509)]),
-    ((5514, false, AND2), &[Tv(5511), Tv(5513)]),
-    ((5516, false, OR2), &[Tv(244), Tv(2764)]),
-    ((5617, false, NAND2), &[Tv(5614), Tv(5616)]),
-    ((5621, false, AND2), &[Tv(5618), Tv(5620)]),
-    ((5623, false, OR2), &[Tv(333), Tv(2764)]),
-    ((5724, false, NAND2), &[Tv(5721), Tv(5723)]),
-    ((5728, false, AND2), &[Tv(5725), Tv(5727)]),
-    ((5730, false, OR2), &[Tv(334), Tv(2764)]),
-    ((5831, false, NAND2), &[Tv(5828), Tv(5830)]),
-    ((5835, false, AND2), &[Tv(5832), Tv(5834)]),
-    ((5837, false, OR2), &[Tv(335), Tv(2764)]),
-    ((5938, false, NAND2), &[Tv(5935), Tv(5937)]),
-    ((5942, false, AND2), &[Tv(5939), Tv(5941)]),
-    ((5944, false, OR2), &[Tv(336), Tv(2764)]),
-    ((6045, false, NAND2), &[Tv(6042), Tv(6044)]),
-    ((6049, false, AND2), &[Tv(6046), Tv(6048)]),
-    ((6051, false, OR2), &[Tv(337), Tv(2764)]),
-    ((6152, false, NAND2), &[Tv(6149), Tv(6151)]),
-    ((6156, false, AND2), &[Tv(6153), Tv(6155)]),
-    ((6158, false, OR2), &[Tv(338), Tv(2764)]),
-    ((6259, false, NAND2), &[Tv(6256), Tv(6258)]),
-    ((6263, false, AND2), &[Tv(6260), Tv(6262)]),
-    ((6265, false, OR2), &[Tv(339), Tv(2764)]),
-    ((6366, false, NAND2), &[Tv(6363), Tv(6365)]),
-    ((6370, false, AND2), &[Tv(6367), Tv(6369)]),
-    ((6372, false, OR2), &[Tv(340), Tv(2764)]),
-    ((104, false, INV), &[Arg(2, 385)]),
-    ((496, false, INV), &[Arg(2, 930)]),
-    ((499, false, INV), &[Arg(2, 933)]),
-    ((500, false, INV), &[Arg(2, 934)]),
-    ((501, false, INV), &[Arg(2, 935)]),
-    ((502, false, INV), &[Arg(2, 968)]),
-    ((504, false, INV), &[Arg(2, 970)]),
-    ((505, false, INV), &[Arg(2, 972)]),
-    ((506, false, INV), &[Arg(2, 976)]),
-    ((507, false, INV), &[Arg(2, 977)]),
-    ((510, false, INV), &[Arg(2, 981)]),
-    ((511, false, INV), &[Arg(2, 983)]),
-    ((513, false, INV), &[Arg(2, 1017)]),
-    ((518, false, INV), &[Arg(2, 1026)]),
-    ((519, false, INV), &[Arg(2, 1028)]),
-    ((1517, false, NAND2), &[Tv(405), Arg(2, 1025)]),
-    ((1518, false, NAND2), &[Tv(407), Arg(2, 1028)]),
-    ((1520, false, NAND2), &[Tv(391), Arg(2, 1018)]),
-    ((1521, false, NAND2), &[Arg(1, 13), Tv(520)]),
-    ((1525, false, NAND2), &[Arg(1, 15), Tv(521)]),
-    ((1526, false, NAND2), &[Arg(1, 9), Tv(517)]),
-    ((1530, false, NAND2), &[Tv(390), Arg(2, 1017)]),
-    ((1531, false, NAND2), &[Tv(389), Arg(2, 1016)]),
-    ((1533, false, NAND2), &[Tv(411), Arg(2, 1026)]),
-    ((1534, false, NAND2), &[Arg(1, 8), Tv(516)]),
-    ((1537, false, NAND2), &[Arg(1, 0), Tv(512)]),
-    ((1538, false, NAND2), &[Arg(1, 4), Tv(515)]),
-    ((1540, false, NAND2), &[Tv(393), Arg(2, 1020)]),
-    ((1541, false, NAND2), &[Arg(1, 2), Tv(514)]),
-    ((1609, false, NOR2), &[Arg(2, 890), Arg(2, 891)]),
-    ((1610, false, NOR2), &[Arg(2, 889), Arg(2, 888)]),
-    ((1612, false, NOR2), &[Arg(2, 894), Arg(2, 895)]),
-    ((1613, false, NOR2), &[Arg(2, 892), Arg(2, 893)]),
-    ((1616, false, XNOR2), &[Arg(1, 4), Arg(2, 876)]),
-    ((1617, false, XNOR2), &[Arg(1, 11), Arg(2, 883)]),
-    ((1619, false, XNOR2), &[Arg(1, 0), Arg(2, 872)]),
-    ((1620, false, XNOR2), &[Arg(1, 8), Arg(2, 880)]),
-    ((1624, false, XNOR2), &[Arg(1, 14), Arg(2, 886)]),
-    ((1627, false, AND2), &[Tv(1625), Tv(1626)]),
-    ((1629, false, XNOR2), &[Arg(1, 5), Arg(2, 877)]),
-    ((1630, false, XNOR2), &[Arg(1, 6), Arg(2, 878)]),
-    ((1639, false, AND2), &[Tv(1635), Tv(1638)]),
-    ((1646, false, AND2), &[Tv(1642), Tv(1645)]),
-    ((1654, false, AND2), &[Tv(1650), Tv(1653)]),
-    ((1661, false, AND2), &[Tv(1657), Tv(1660)]),
-    ((1695, false, NAND2), &[Tv(395), Arg(2, 926)]),
-    ((1696, false, NAND2), &[Arg(1, 1), Tv(491)]),
-    ((1698, false, NAND2), &[Tv(390), Arg(2, 921)]),
-    ((1699, false, NAND2), &[Arg(1, 12), Tv(498)]),
-    ((1702, false, NAND2), &[Arg(1, 4), Tv(494)]),
-    ((1703, false, NAND2), &[Arg(1, 11), Tv(497)]),
-    ((1705, false, NAND2), &[Tv(406), Arg(2, 931)]),
-    ((1706, false, NAND2), &[Arg(1, 6), Tv(495)]),
-    ((1710, false, NAND2), &[Arg(1, 3), Tv(493)]),
-    ((1711, false, NAND2), &[Tv(411), Arg(2, 930)]),
-    ((1713, false, NAND2), &[Tv(412), Arg(2, 935)]),
-    ((1714, false, NAND2), &[Tv(392), Arg(2, 923)]),
-    ((1717, false, NAND2), &[Tv(407), Arg(2, 932)]),
-    ((1718, false, NAND2), &[Arg(1, 2), Tv(492)]),
-    ((1720, false, NAND2), &[Tv(409), Arg(2, 934)]),
-    ((1721, false, NAND2), &[Tv(391), Arg(2, 922)]),
-    ((1732, false, AND2), &[Tv(1730), Tv(1731)]),
-    ((1735, false, AND2), &[Tv(1733), Tv(1734)]),
-    ((1743, false, AND2), &[Tv(1739), Tv(1742)]),
-    ((1748, false, AND2), &[Tv(1744), Tv(1747)]),
-    ((1753, false, AND2), &[Tv(1751), Tv(1752)]),
-    ((1756, false, AND2), &[Tv(1754), Tv(1755)]),
-    ((1768, false, AND2), &[Tv(1764), Tv(1767)]),
-    ((1781, false, AND2), &[Tv(1775), Tv(1780)]),
-    ((1786, false, NOR2), &[Arg(2, 794), Arg(2, 795)]),
-    ((1787, false, NOR2), &[Arg(2, 793), Arg(2, 792)]),
-    ((1789, false, NOR2), &[Arg(2, 797), Arg(2, 798)]),
-    ((1790, false, NOR2), &[Arg(2, 796), Arg(2, 799)]),
-    ((1793, false, XNOR2), &[Arg(1, 7), Arg(2, 783)]),
-    ((1794, false, XNOR2), &[Arg(1, 11), Arg(2, 787)]),
-    ((1798, false, AND2), &[Tv(1796), Tv(1797)]),
-    ((1801, false, AND2), &[Tv(1799), Tv(1800)]),
-    ((1805, false, XNOR2), &[Arg(1, 6), Arg(2, 782)]),
-    ((1808, false, AND2), &[Tv(1806), Tv(1807)]),
-    ((1810, false, XNOR2), &[Arg(1, 5), Arg(2, 781)]),
-    ((1811, false, XNOR2), &[Arg(1, 14), Arg(2, 790)]),
-    ((1820, false, AND2), &[Tv(1816), Tv(1819)]),
-    ((1825, false, AND2), &[Tv(1821), Tv(1824)]),
-    ((1833, false, AND2), &[Tv(1829), Tv(1832)]),
-    ((1840, false, AND2), &[Tv(1836), Tv(1839)]),
-    ((2204, false, NAND2), &[Tv(2160), Tv(2203)]),
-    ((2264, false, INV), &[Tv(2263)]),
-    ((2309, false, NAND2), &[Tv(2279), Tv(2308)]),
-    ((2313, false, NAND2), &[Tv(2311), Tv(2312)]),
-    ((2316, false, OR2), &[Tv(2314), Tv(2315)]),
-    ((2324, false, AND2), &[Tv(2320), Tv(2323)]),
-    ((2331, false, AND2), &[Tv(2327), Tv(2330)]),
-    ((2336, false, AND2), &[Tv(2334), Tv(2335)]),
-    ((2339, false, AND2), &[Tv(2337), Tv(2338)]),
-    ((2353, false, AND2), &[Tv(2347), Tv(2352)]),
-    ((2366, false, AND2), &[Tv(2360), Tv(2365)]),
-    ((2371, false, NOR2), &[Arg(2, 746), Arg(2, 747)]),
-    ((2372, false, NOR2), &[Arg(2, 745), Arg(2, 744)]),
-    ((2374, false, NOR2), &[Arg(2, 748), Arg(2, 749)]),
-    ((2375, false, NOR2), &[Arg(2, 750), Arg(2, 751)]),
-    ((2378, false, XNOR2), &[Arg(1, 5), Arg(2, 733)]),
-    ((2379, false, XNOR2), &[Arg(1, 7), Arg(2, 735)]),
-    ((2383, false, AND2), &[Tv(2381), Tv(2382)]),
-    ((2386, false, AND2), &[Tv(2384), Tv(2385)]),
-    ((2390, false, XNOR2), &[Arg(1, 13), Arg(2, 741)]),
-    ((2393, false, AND2), &[Tv(2391), Tv(2392)]),
-    ((2395, false, XNOR2), &[Arg(1, 8), Arg(2, 736)]),
-    ((2396, false, XNOR2), &[Arg(1, 10), Arg(2, 738)]),
-    ((2405, false, AND2), &[Tv(2401), Tv(2404)]),
-    ((2410, false, AND2), &[Tv(2406), Tv(2409)]),
-    ((2418, false, AND2), &[Tv(2414), Tv(2417)]),
-    ((2423, false, AND2), &[Tv(2421), Tv(2422)]),
-];
-
-static LEVEL_17: [((usize, bool, CellType), &[GateInput]); 178] = [
-    ((3036, false, NAND2), &[Tv(2487), Tv(3035)]),
-    ((3038, false, AND2), &[Tv(2601), Tv(3037)]),
-    ((3040, false, OR2), &[Arg(2, 336), Tv(2601)]),
-    ((3045, false, NAND2), &[Arg(2, 432), Tv(1900)]),
-    ((3054, false, NOR2), &[Tv(2263), Tv(2309)]),
-    ((3152, false, NAND2), &[Tv(3149), Tv(3151)]),
-    ((3154, false, AND2), &[Tv(1965), Tv(3153)]),
-    ((3156, false, NAND2), &[Tv(104), Tv(3043)]),
-    ((3158, false, AND2), &[Tv(2764), Tv(3157)]),
-    ((3268, false, NAND2), &[Tv(3263), Tv(3267)]),
-    ((3270, false, AND2), &[Tv(2204), Tv(3269)]),
-    ((3272, false, OR2), &[Arg(2, 530), Tv(2204)]),
-    ((3375, false, NAND2), &[Tv(3370), Tv(3374)]),
-    ((3377, false, AND2), &[Tv(2204), Tv(3376)]),
-    ((3379, false, OR2), &[Arg(2, 531), Tv(2204)]),
-    ((3482, false, NAND2), &[Tv(3477), Tv(3481)]),
-    ((3484, false, AND2), &[Tv(2204), Tv(3483)]),
-    ((3486, false, OR2), &[Arg(2, 532), Tv(2204)]),
-    ((3589, false, NAND2), &[Tv(3584), Tv(3588)]),
-    ((3591, false, AND2), &[Tv(2204), Tv(3590)]),
-    ((3593, false, OR2), &[Arg(2, 533), Tv(2204)]),
-    ((3696, false, NAND2), &[Tv(3691), Tv(3695)]),
-    ((3698, false, AND2), &[Tv(2204), Tv(3697)]),
-    ((3700, false, OR2), &[Arg(2, 534), Tv(2204)]),
-    ((3803, false, NAND2), &[Tv(3798), Tv(3802)]),
-    ((3805, false, AND2), &[Tv(2204), Tv(3804)]),
-    ((3807, false, OR2), &[Arg(2, 535), Tv(2204)]),
-    ((3910, false, NAND2), &[Tv(3905), Tv(3909)]),
-    ((3912, false, AND2), &[Tv(2204), Tv(3911)]),
-    ((3914, false, OR2), &[Arg(2, 552), Tv(2204)]),
-    ((4017, false, NAND2), &[Tv(4012), Tv(4016)]),
-    ((4019, false, AND2), &[Tv(2204), Tv(4018)]),
-    ((4021, false, OR2), &[Arg(2, 553), Tv(2204)]),
-    ((4124, false, NAND2), &[Tv(4119), Tv(4123)]),
-    ((4126, false, AND2), &[Tv(2204), Tv(4125)]),
-    ((4128, false, OR2), &[Arg(2, 554), Tv(2204)]),
-    ((4231, false, NAND2), &[Tv(4226), Tv(4230)]),
-    ((4233, false, AND2), &[Tv(2204), Tv(4232)]),
-    ((4235, false, OR2), &[Arg(2, 555), Tv(2204)]),
-    ((4338, false, NAND2), &[Tv(4333), Tv(4337)]),
-    ((4340, false, AND2), &[Tv(2204), Tv(4339)]),
-    ((4342, false, OR2), &[Arg(2, 556), Tv(2204)]),
-    ((4445, false, NAND2), &[Tv(4440), Tv(4444)]),
-    ((4447, false, AND2), &[Tv(2204), Tv(4446)]),
-    ((4449, false, OR2), &[Arg(2, 557), Tv(2204)]),
-    ((4552, false, NAND2), &[Tv(4547), Tv(4551)]),
-    ((4554, false, AND2), &[Tv(2204), Tv(4553)]),
-    ((4556, false, OR2), &[Arg(2, 558), Tv(2204)]),
-    ((4659, false, NAND2), &[Tv(4654), Tv(4658)]),
-    ((4661, false, AND2), &[Tv(2204), Tv(4660)]),
-    ((4663, false, OR2), &[Arg(2, 559), Tv(2204)]),
-    ((4766, false, NAND2), &[Tv(4761), Tv(4765)]),
-    ((4768, false, AND2), &[Tv(2204), Tv(4767)]),
-    ((4770, false, OR2), &[Arg(2, 560), Tv(2204)]),
-    ((4873, false, NAND2), &[Tv(4868), Tv(4872)]),
-    ((4875, false, AND2), &[Tv(2204), Tv(4874)]),
-    ((4877, false, OR2), &[Arg(2, 561), Tv(2204)]),
-    ((4980, false, NAND2), &[Tv(4975), Tv(4979)]),
-    ((4982, false, AND2), &[Tv(2204), Tv(4981)]),
-    ((4984, false, OR2), &[Arg(2, 562), Tv(2204)]),
-    ((5087, false, NAND2), &[Tv(5082), Tv(5086)]),
-    ((5089, false, AND2), &[Tv(2204), Tv(5088)]),
-    ((5091, false, OR2), &[Arg(2, 563), Tv(2204)]),
-    ((5194, false, NAND2), &[Tv(5189), Tv(5193)]),
-    ((5196, false, AND2), &[Tv(2204), Tv(5195)]),
-    ((5198, false, OR2), &[Arg(2, 564), Tv(2204)]),
-    ((5301, false, NAND2), &[Tv(5296), Tv(5300)]),
-    ((5303, false, AND2), &[Tv(2204), Tv(5302)]),
-    ((5305, false, OR2), &[Arg(2, 565), Tv(2204)]),
-    ((5408, false, NAND2), &[Tv(5403), Tv(5407)]),
-    ((5410, false, AND2), &[Tv(2204), Tv(5409)]),
-    ((5412, false, OR2), &[Arg(2, 566), Tv(2204)]),
-    ((5515, false, NAND2), &[Tv(5510), Tv(5514)]),
-    ((5517, false, AND2), &[Tv(2204), Tv(5516)]),
-    ((5519, false, OR2), &[Arg(2, 567), Tv(2204)]),
-    ((5622, false, NAND2), &[Tv(5617), Tv(5621)]),
-    ((5624, false, AND2), &[Tv(2204), Tv(5623)]),
-    ((5626, false, OR2), &[Arg(2, 568), Tv(2204)]),
-    ((5729, false, NAND2), &[Tv(5724), Tv(5728)]),
-    ((5731, false, AND2), &[Tv(2204), Tv(5730)]),
-    ((5733, false, OR2), &[Arg(2, 569), Tv(2204)]),
-    ((5836, false, NAND2), &[Tv(5831), Tv(5835)]),
-    ((5838, false, AND2), &[Tv(2204), Tv(5837)]),
-    ((5840, false, OR2), &[Arg(2, 570), Tv(2204)]),
-    ((5943, false, NAND2), &[Tv(5938), Tv(5942)]),
-    ((5945, false, AND2), &[Tv(2204), Tv(5944)]),
-    ((5947, false, OR2), &[Arg(2, 571), Tv(2204)]),
-    ((6050, false, NAND2), &[Tv(6045), Tv(6049)]),
-    ((6052, false, AND2), &[Tv(2204), Tv(6051)]),
-    ((6054, false, OR2), &[Arg(2, 572), Tv(2204)]),
-    ((6157, false, NAND2), &[Tv(6152), Tv(6156)]),
-    ((6159, false, AND2), &[Tv(2204), Tv(6158)]),
-    ((6161, false, OR2), &[Arg(2, 573), Tv(2204)]),
-    ((6264, false, NAND2), &[Tv(6259), Tv(6263)]),
-    ((6266, false, AND2), &[Tv(2204), Tv(6265)]),
-    ((6268, false, OR2), &[Arg(2, 574), Tv(2204)]),
-    ((6371, false, NAND2), &[Tv(6366), Tv(6370)]),
-    ((6373, false, AND2), &[Tv(2204), Tv(6372)]),
-    ((6375, false, OR2), &[Arg(2, 575), Tv(2204)]),
-    ((118, false, INV), &[Arg(2, 481)]),
-    ((503, false, INV), &[Arg(2, 969)]),
-    ((508, false, INV), &[Arg(2, 978)]),
-    ((509, false, INV), &[Arg(2, 980)]),
-    ((1499, false, NAND2), &[Tv(404), Arg(2, 1024)]),
-    ((1500, false, NAND2), &[Tv(408), Arg(2, 1029)]),
-    ((1502, false, NAND2), &[Arg(1, 12), Tv(519)]),
-    ((1503, false, NAND2), &[Arg(1, 10), Tv(518)]),
-    ((1509, false, NAND2), &[Tv(412), Arg(2, 1031)]),
-    ((1510, false, NAND2), &[Arg(1, 1), Tv(513)]),
-    ((1519, false, AND2), &[Tv(1517), Tv(1518)]),
-    ((1522, false, AND2), &[Tv(1520), Tv(1521)]),
-    ((1524, false, XNOR2), &[Arg(1, 3), Arg(2, 1019)]),
-    ((1527, false, AND2), &[Tv(1525), Tv(1526)]),
-    ((1532, false, AND2), &[Tv(1530), Tv(1531)]),
-    ((1535, false, AND2), &[Tv(1533), Tv(1534)]),
-    ((1539, false, AND2), &[Tv(1537), Tv(1538)]),
-    ((1542, false, AND2), &[Tv(1540), Tv(1541)]),
-    ((1577, false, NAND2), &[Tv(405), Arg(2, 977)]),
-    ((1578, false, NAND2), &[Tv(407), Arg(2, 980)]),
-    ((1580, false, NAND2), &[Tv(391), Arg(2, 970)]),
-    ((1581, false, NAND2), &[Arg(1, 13), Tv(510)]),
-    ((1585, false, NAND2), &[Arg(1, 15), Tv(511)]),
-    ((1586, false, NAND2), &[Arg(1, 9), Tv(507)]),
-    ((1590, false, NAND2), &[Tv(390), Arg(2, 969)]),
-    ((1591, false, NAND2), &[Tv(389), Arg(2, 968)]),
-    ((1593, false, NAND2), &[Tv(411), Arg(2, 978)]),
-    ((1594, false, NAND2), &[Arg(1, 8), Tv(506)]),
-    ((1597, false, NAND2), &[Arg(1, 0), Tv(502)]),
-    ((1598, false, NAND2), &[Arg(1, 4), Tv(505)]),
-    ((1600, false, NAND2), &[Tv(393), Arg(2, 972)]),
-    ((1601, false, NAND2), &[Arg(1, 2), Tv(504)]),
-    ((1611, false, AND2), &[Tv(1609), Tv(1610)]),
-    ((1614, false, AND2), &[Tv(1612), Tv(1613)]),
-    ((1618, false, AND2), &[Tv(1616), Tv(1617)]),
-    ((1621, false, AND2), &[Tv(1619), Tv(1620)]),
-    ((1628, false, AND2), &[Tv(1624), Tv(1627)]),
-    ((1631, false, AND2), &[Tv(1629), Tv(1630)]),
-    ((1647, false, AND2), &[Tv(1639), Tv(1646)]),
-    ((1662, false, AND2), &[Tv(1654), Tv(1661)]),
-    ((1674, false, NAND2), &[Arg(1, 15), Tv(501)]),
-    ((1675, false, NAND2), &[Tv(393), Arg(2, 924)]),
-    ((1680, false, NAND2), &[Arg(1, 13), Tv(499)]),
-    ((1681, false, NAND2), &[Arg(1, 10), Tv(496)]),
-    ((1687, false, NAND2), &[Arg(1, 14), Tv(500)]),
-    ((1688, false, NAND2), &[Tv(408), Arg(2, 933)]),
-    ((1697, false, AND2), &[Tv(1695), Tv(1696)]),
-    ((1700, false, AND2), &[Tv(1698), Tv(1699)]),
-    ((1704, false, AND2), &[Tv(1702), Tv(1703)]),
-    ((1707, false, AND2), &[Tv(1705), Tv(1706)]),
-    ((1712, false, AND2), &[Tv(1710), Tv(1711)]),
-    ((1715, false, AND2), &[Tv(1713), Tv(1714)]),
-    ((1719, false, AND2), &[Tv(1717), Tv(1718)]),
-    ((1722, false, AND2), &[Tv(1720), Tv(1721)]),
-    ((1736, false, NAND2), &[Tv(1732), Tv(1735)]),
-    ((1749, false, AND2), &[Tv(1743), Tv(1748)]),
-    ((1757, false, AND2), &[Tv(1753), Tv(1756)]),
-    ((1782, false, AND2), &[Tv(1768), Tv(1781)]),
-    ((1788, false, AND2), &[Tv(1786), Tv(1787)]),
-    ((1791, false, AND2), &[Tv(1789), Tv(1790)]),
-    ((1795, false, AND2), &[Tv(1793), Tv(1794)]),
-    ((1802, false, AND2), &[Tv(1798), Tv(1801)]),
-    ((1809, false, AND2), &[Tv(1805), Tv(1808)]),
-    ((1812, false, AND2), &[Tv(1810), Tv(1811)]),
-    ((1826, false, AND2), &[Tv(1820), Tv(1825)]),
-    ((1841, false, AND2), &[Tv(1833), Tv(1840)]),
-    ((2310, false, AND2), &[Tv(2264), Tv(2309)]),
-    ((2317, false, OR2), &[Tv(2313), Tv(2316)]),
-    ((2332, false, AND2), &[Tv(2324), Tv(2331)]),
-    ((2340, false, AND2), &[Tv(2336), Tv(2339)]),
-    ((2367, false, AND2), &[Tv(2353), Tv(2366)]),
-    ((2373, false, AND2), &[Tv(2371), Tv(2372)]),
-    ((2376, false, AND2), &[Tv(2374), Tv(2375)]),
-    ((2380, false, AND2), &[Tv(2378), Tv(2379)]),
-    ((2387, false, AND2), &[Tv(2383), Tv(2386)]),
-    ((2394, false, AND2), &[Tv(2390), Tv(2393)]),
-    ((2397, false, AND2), &[Tv(2395), Tv(2396)]),
-    ((2411, false, AND2), &[Tv(2405), Tv(2410)]),
-    ((2424, false, AND2), &[Tv(2418), Tv(2423)]),
-];
-
-static LEVEL_18: [((usize, bool, CellType), &[GateInput]); 189] = [
-    ((3039, false, NAND2), &[Tv(3036), Tv(3038)]),
-    ((3041, false, AND2), &[Tv(1965), Tv(3040)]),
-    ((3044, false, NAND2), &[Arg(2, 384), Tv(3043)]),
-    ((3046, false, AND2), &[Tv(2764), Tv(3045)]),
-    ((3155, false, NAND2), &[Tv(3152), Tv(3154)]),
-    ((3159, false, AND2), &[Tv(3156), Tv(3158)]),
-    ((3161, false, OR2), &[Tv(118), Tv(2764)]),
-    ((3271, false, NAND2), &[Tv(3268), Tv(3270)]),
-    ((3273, false, AND2), &[Tv(2310), Tv(3272)]),
-    ((3275, false, NAND2), &[Arg(2, 626), Tv(2263)]),
-    ((3276, false, NAND2), &[Arg(2, 578), Tv(3054)]),
-    ((3378, false, NAND2), &[Tv(3375), Tv(3377)]),
-    ((3380, false, AND2), &[Tv(2310), Tv(3379)]),
-    ((3382, false, NAND2), &[Arg(2, 627), Tv(2263)]),
-    ((3383, false, NAND2), &[Arg(2, 579), Tv(3054)]),
-    ((3485, false, NAND2), &[Tv(3482), Tv(3484)]),
-    ((3487, false, AND2), &[Tv(2310), Tv(3486)]),
-    ((3489, false, NAND2), &[Arg(2, 628), Tv(2263)]),
-    ((3490, false, NAND2), &[Arg(2, 580), Tv(3054)]),
-    ((3592, false, NAND2), &[Tv(3589), Tv(3591)]),
-    ((3594, false, AND2), &[Tv(2310), Tv(3593)]),
-    ((3596, false, NAND2), &[Arg(2, 629), Tv(2263)]),
-    ((3597, false, NAND2), &[Arg(2, 581), Tv(3054)]),
-    ((3699, false, NAND2), &[Tv(3696), Tv(3698)]),
-    ((3701, false, AND2), &[Tv(2310), Tv(3700)]),
-    ((3703, false, NAND2), &[Arg(2, 630), Tv(2263)]),
-    ((3704, false, NAND2), &[Arg(2, 582), Tv(3054)]),
-    ((3806, false, NAND2), &[Tv(3803), Tv(3805)]),
-    ((3808, false, AND2), &[Tv(2310), Tv(3807)]),
-    ((3810, false, NAND2), &[Arg(2, 631), Tv(2263)]),
-    ((3811, false, NAND2), &[Arg(2, 583), Tv(3054)]),
-    ((3913, false, NAND2), &[Tv(3910), Tv(3912)]),
-    ((3915, false, AND2), &[Tv(2310), Tv(3914)]),
-    ((3917, false, NAND2), &[Arg(2, 648), Tv(2263)]),
-    ((3918, false, NAND2), &[Arg(2, 600), Tv(3054)]),
-    ((4020, false, NAND2), &[Tv(4017), Tv(4019)]),
-    ((4022, false, AND2), &[Tv(2310), Tv(4021)]),
-    ((4024, false, NAND2), &[Arg(2, 649), Tv(2263)]),
-    ((4025, false, NAND2), &[Arg(2, 601), Tv(3054)]),
-    ((4127, false, NAND2), &[Tv(4124), Tv(4126)]),
-    ((4129, false, AND2), &[Tv(2310), Tv(4128)]),
-    ((4131, false, NAND2), &[Arg(2, 650), Tv(2263)]),
-    ((4132, false, NAND2), &[Arg(2, 602), Tv(3054)]),
-    ((4234, false, NAND2), &[Tv(4231), Tv(4233)]),
-    ((4236, false, AND2), &[Tv(2310), Tv(4235)]),
-    ((4238, false, NAND2), &[Arg(2, 651), Tv(2263)]),
-    ((4239, false, NAND2), &[Arg(2, 603), Tv(3054)]),
-    ((4341, false, NAND2), &[Tv(4338), Tv(4340)]),
-    ((4343, false, AND2), &[Tv(2310), Tv(4342)]),
-    ((4345, false, NAND2), &[Arg(2, 652), Tv(2263)]),
-    ((4346, false, NAND2), &[Arg(2, 604), Tv(3054)]),
-    ((4448, false, NAND2), &[Tv(4445), Tv(4447)]),
-    ((4450, false, AND2), &[Tv(2310), Tv(4449)]),
-    ((4452, false, NAND2), &[Arg(2, 653), Tv(2263)]),
-    ((4453, false, NAND2), &[Arg(2, 605), Tv(3054)]),
-    ((4555, false, NAND2), &[Tv(4552), Tv(4554)]),
-    ((4557, false, AND2), &[Tv(2310), Tv(4556)]),
-    ((4559, false, NAND2), &[Arg(2, 606), Tv(3054)]),
-    ((4560, false, NAND2), &[Arg(2, 654), Tv(2263)]),
-    ((4662, false, NAND2), &[Tv(4659), Tv(4661)]),
-    ((4664, false, AND2), &[Tv(2310), Tv(4663)]),
-    ((4666, false, NAND2), &[Arg(2, 655), Tv(2263)]),
-    ((4667, false, NAND2), &[Arg(2, 607), Tv(3054)]),
-    ((4769, false, NAND2), &[Tv(4766), Tv(4768)]),
-    ((4771, false, AND2), &[Tv(2310), Tv(4770)]),
-    ((4773, false, NAND2), &[Arg(2, 608), Tv(3054)]),
-    ((4774, false, NAND2), &[Arg(2, 656), Tv(2263)]),
-    ((4876, false, NAND2), &[Tv(4873), Tv(4875)]),
-    ((4878, false, AND2), &[Tv(2310), Tv(4877)]),
-    ((4880, false, NAND2), &[Arg(2, 609), Tv(3054)]),
-    ((4881, false, NAND2), &[Arg(2, 657), Tv(2263)]),
-    ((4983, false, NAND2), &[Tv(4980), Tv(4982)]),
-    ((4985, false, AND2), &[Tv(2310), Tv(4984)]),
-    ((4987, false, NAND2), &[Arg(2, 610), Tv(3054)]),
-    ((4988, false, NAND2), &[Arg(2, 658), Tv(2263)]),
-    ((5090, false, NAND2), &[Tv(5087), Tv(5089)]),
-    ((5092, false, AND2), &[Tv(2310), Tv(5091)]),
-    ((5094, false, NAND2), &[Arg(2, 659), Tv(2263)]),
-    ((5095, false, NAND2), &[Arg(2, 611), Tv(3054)]),
-    ((5197, false, NAND2), &[Tv(5194), Tv(5196)]),
-    ((5199, false, AND2), &[Tv(2310), Tv(5198)]),
-    ((5201, false, NAND2), &[Arg(2, 660), Tv(2263)]),
-    ((5202, false, NAND2), &[Arg(2, 612), Tv(3054)]),
-    ((5304, false, NAND2), &[Tv(5301), Tv(5303)]),
-    ((5306, false, AND2), &[Tv(2310), Tv(5305)]),
-    ((5308, false, NAND2), &[Arg(2, 661), Tv(2263)]),
-    ((5309, false, NAND2), &[Arg(2, 613), Tv(3054)]),
-    ((5411, false, NAND2), &[Tv(5408), Tv(5410)]),
-    ((5413, false, AND2), &[Tv(2310), Tv(5412)]),
-    ((5415, false, NAND2), &[Arg(2, 614), Tv(3054)]),
-    ((5416, false, NAND2), &[Arg(2, 662), Tv(2263)]),
-    ((5518, false, NAND2), &[Tv(5515), Tv(5517)]),
-    ((5520, false, AND2), &[Tv(2310), Tv(5519)]),
-    ((5522, false, NAND2), &[Arg(2, 663), Tv(2263)]),
-    ((5523, false, NAND2), &[Arg(2, 615), Tv(3054)]),
-    ((5625, false, NAND2), &[Tv(5622), Tv(5624)]),
-    ((5627, false, AND2), &[Tv(2310), Tv(5626)]),
-    ((5629, false, NAND2), &[Arg(2, 616), Tv(3054)]),
-    ((5630, false, NAND2), &[Arg(2, 664), Tv(2263)]),
-    ((5732, false, NAND2), &[Tv(5729), Tv(5731)]),
-    ((5734, false, AND2), &[Tv(2310), Tv(5733)]),
-    ((5736, false, NAND2), &[Arg(2, 617), Tv(3054)]),
-    ((5737, false, NAND2), &[Arg(2, 665), Tv(2263)]),
-    ((5839, false, NAND2), &[Tv(5836), Tv(5838)]),
-    ((5841, false, AND2), &[Tv(2310), Tv(5840)]),
-    ((5843, false, NAND2), &[Arg(2, 666), Tv(2263)]),
-    ((5844, false, NAND2), &[Arg(2, 618), Tv(3054)]),
-    ((5946, false, NAND2), &[Tv(5943), Tv(5945)]),
-    ((5948, false, AND2), &[Tv(2310), Tv(5947)]),
-    ((5950, false, NAND2), &[Arg(2, 667), Tv(2263)]),
-    ((5951, false, NAND2), &[Arg(2, 619), Tv(3054)]),
-    ((6053, false, NAND2), &[Tv(6050), Tv(6052)]),
-    ((6055, false, AND2), &[Tv(2310), Tv(6054)]),
-    ((6057, false, NAND2), &[Arg(2, 668), Tv(2263)]),
-    ((6058, false, NAND2), &[Arg(2, 620), Tv(3054)]),
-    ((6160, false, NAND2), &[Tv(6157), Tv(6159)]),
-    ((6162, false, AND2), &[Tv(2310), Tv(6161)]),
-    ((6164, false, NAND2), &[Arg(2, 669), Tv(2263)]),
-    ((6165, false, NAND2), &[Arg(2, 621), Tv(3054)]),
-    ((6267, false, NAND2), &[Tv(6264), Tv(6266)]),
-    ((6269, false, AND2), &[Tv(2310), Tv(6268)]),
-    ((6271, false, NAND2), &[Arg(2, 622), Tv(3054)]),
-    ((6272, false, NAND2), &[Arg(2, 670), Tv(2263)]),
-    ((6374, false, NAND2), &[Tv(6371), Tv(6373)]),
-    ((6376, false, AND2), &[Tv(2310), Tv(6375)]),
-    ((6378, false, NAND2), &[Arg(2, 671), Tv(2263)]),
-    ((6379, false, NAND2), &[Arg(2, 623), Tv(3054)]),
-    ((1489, false, NOR2), &[Arg(2, 1034), Arg(2, 1035)]),
-    ((1490, false, NOR2), &[Arg(2, 1033), Arg(2, 1032)]),
-    ((1492, false, NOR2), &[Arg(2, 1037), Arg(2, 1038)]),
-    ((1493, false, NOR2), &[Arg(2, 1036), Arg(2, 1039)]),
-    ((1496, false, XNOR2), &[Arg(1, 7), Arg(2, 1023)]),
-    ((1497, false, XNOR2), &[Arg(1, 11), Arg(2, 1027)]),
-    ((1501, false, AND2), &[Tv(1499), Tv(1500)]),
-    ((1504, false, AND2), &[Tv(1502), Tv(1503)]),
-    ((1508, false, XNOR2), &[Arg(1, 6), Arg(2, 1022)]),
-    ((1511, false, AND2), &[Tv(1509), Tv(1510)]),
-    ((1513, false, XNOR2), &[Arg(1, 5), Arg(2, 1021)]),
-    ((1514, false, XNOR2), &[Arg(1, 14), Arg(2, 1030)]),
-    ((1523, false, AND2), &[Tv(1519), Tv(1522)]),
-    ((1528, false, AND2), &[Tv(1524), Tv(1527)]),
-    ((1536, false, AND2), &[Tv(1532), Tv(1535)]),
-    ((1543, false, AND2), &[Tv(1539), Tv(1542)]),
-    ((1559, false, NAND2), &[Tv(404), Arg(2, 976)]),
-    ((1560, false, NAND2), &[Tv(408), Arg(2, 981)]),
-    ((1562, false, NAND2), &[Arg(1, 12), Tv(509)]),
-    ((1563, false, NAND2), &[Arg(1, 10), Tv(508)]),
-    ((1569, false, NAND2), &[Tv(412), Arg(2, 983)]),
-    ((1570, false, NAND2), &[Arg(1, 1), Tv(503)]),
-    ((1579, false, AND2), &[Tv(1577), Tv(1578)]),
-    ((1582, false, AND2), &[Tv(1580), Tv(1581)]),
-    ((1584, false, XNOR2), &[Arg(1, 3), Arg(2, 971)]),
-    ((1587, false, AND2), &[Tv(1585), Tv(1586)]),
-    ((1592, false, AND2), &[Tv(1590), Tv(1591)]),
-    ((1595, false, AND2), &[Tv(1593), Tv(1594)]),
-    ((1599, false, AND2), &[Tv(1597), Tv(1598)]),
-    ((1602, false, AND2), &[Tv(1600), Tv(1601)]),
-    ((1615, false, NAND2), &[Tv(1611), Tv(1614)]),
-    ((1622, false, AND2), &[Tv(1618), Tv(1621)]),
-    ((1632, false, AND2), &[Tv(1628), Tv(1631)]),
-    ((1663, false, AND2), &[Tv(1647), Tv(1662)]),
-    ((1667, false, NOR2), &[Arg(2, 938), Arg(2, 939)]),
-    ((1668, false, NOR2), &[Arg(2, 937), Arg(2, 936)]),
-    ((1670, false, NOR2), &[Arg(2, 941), Arg(2, 942)]),
-    ((1671, false, NOR2), &[Arg(2, 940), Arg(2, 943)]),
-    ((1676, false, AND2), &[Tv(1674), Tv(1675)]),
-    ((1677, false, XNOR2), &[Arg(1, 5), Arg(2, 925)]),
-    ((1679, false, XNOR2), &[Arg(1, 0), Arg(2, 920)]),
-    ((1682, false, AND2), &[Tv(1680), Tv(1681)]),
-    ((1686, false, XNOR2), &[Arg(1, 9), Arg(2, 929)]),
-    ((1689, false, AND2), &[Tv(1687), Tv(1688)]),
-    ((1691, false, XNOR2), &[Arg(1, 7), Arg(2, 927)]),
-    ((1692, false, XNOR2), &[Arg(1, 8), Arg(2, 928)]),
-    ((1701, false, AND2), &[Tv(1697), Tv(1700)]),
-    ((1708, false, AND2), &[Tv(1704), Tv(1707)]),
-    ((1716, false, AND2), &[Tv(1712), Tv(1715)]),
-    ((1723, false, AND2), &[Tv(1719), Tv(1722)]),
-    ((1750, false, AND2), &[Tv(1736), Tv(1749)]),
-    ((1783, false, AND2), &[Tv(1757), Tv(1782)]),
-    ((1792, false, NAND2), &[Tv(1788), Tv(1791)]),
-    ((1803, false, AND2), &[Tv(1795), Tv(1802)]),
-    ((1813, false, AND2), &[Tv(1809), Tv(1812)]),
-    ((1842, false, AND2), &[Tv(1826), Tv(1841)]),
-    ((2333, false, AND2), &[Tv(2317), Tv(2332)]),
-    ((2368, false, AND2), &[Tv(2340), Tv(2367)]),
-    ((2377, false, NAND2), &[Tv(2373), Tv(2376)]),
-    ((2388, false, AND2), &[Tv(2380), Tv(2387)]),
-    ((2398, false, AND2), &[Tv(2394), Tv(2397)]),
-    ((2425, false, AND2), &[Tv(2411), Tv(2424)]),
-];
-
-static LEVEL_19: [((usize, bool, CellType), &[GateInput]); 105] = [
-    ((3042, false, NAND2), &[Tv(3039), Tv(3041)]),
-    ((3047, false, AND2), &[Tv(3044), Tv(3046)]),
-    ((3160, false, NAND2), &[Tv(3155), Tv(3159)]),
-    ((3162, false, AND2), &[Tv(2204), Tv(3161)]),
-    ((3164, false, OR2), &[Arg(2, 529), Tv(2204)]),
-    ((3274, false, NAND2), &[Tv(3271), Tv(3273)]),
-    ((3277, false, AND2), &[Tv(3275), Tv(3276)]),
-    ((3381, false, NAND2), &[Tv(3378), Tv(3380)]),
-    ((3384, false, AND2), &[Tv(3382), Tv(3383)]),
-    ((3488, false, NAND2), &[Tv(3485), Tv(3487)]),
-    ((3491, false, AND2), &[Tv(3489), Tv(3490)]),
-    ((3595, false, NAND2), &[Tv(3592), Tv(3594)]),
-    ((3598, false, AND2), &[Tv(3596), Tv(3597)]),
-    ((3702, false, NAND2), &[Tv(3699), Tv(3701)]),
-    ((3705, false, AND2), &[Tv(3703), Tv(3704)]),
-    ((3809, false, NAND2), &[Tv(3806), Tv(3808)]),
-    ((3812, false, AND2), &[Tv(3810), Tv(3811)]),
-    ((3916, false, NAND2), &[Tv(3913), Tv(3915)]),
-    ((3919, false, AND2), &[Tv(3917), Tv(3918)]),
-    ((4023, false, NAND2), &[Tv(4020), Tv(4022)]),
-    ((4026, false, AND2), &[Tv(4024), Tv(4025)]),
-    ((4130, false, NAND2), &[Tv(4127), Tv(4129)]),
-    ((4133, false, AND2), &[Tv(4131), Tv(4132)]),
-    ((4237, false, NAND2), &[Tv(4234), Tv(4236)]),
-    ((4240, false, AND2), &[Tv(4238), Tv(4239)]),
-    ((4344, false, NAND2), &[Tv(4341), Tv(4343)]),
-    ((4347, false, AND2), &[Tv(4345), Tv(4346)]),
-    ((4451, false, NAND2), &[Tv(4448), Tv(4450)]),
-    ((4454, false, AND2), &[Tv(4452), Tv(4453)]),
-    ((4558, false, NAND2), &[Tv(4555), Tv(4557)]),
-    ((4561, false, AND2), &[Tv(4559), Tv(4560)]),
-    ((4665, false, NAND2), &[Tv(4662), Tv(4664)]),
-    ((4668, false, AND2), &[Tv(4666), Tv(4667)]),
-    ((4772, false, NAND2), &[Tv(4769), Tv(4771)]),
-    ((4775, false, AND2), &[Tv(4773), Tv(4774)]),
-    ((4879, false, NAND2), &[Tv(4876), Tv(4878)]),
-    ((4882, false, AND2), &[Tv(4880), Tv(4881)]),
-    ((4986, false, NAND2), &[Tv(4983), Tv(4985)]),
-    ((4989, false, AND2), &[Tv(4987), Tv(4988)]),
-    ((5093, false, NAND2), &[Tv(5090), Tv(5092)]),
-    ((5096, false, AND2), &[Tv(5094), Tv(5095)]),
-    ((5200, false, NAND2), &[Tv(5197), Tv(5199)]),
-    ((5203, false, AND2), &[Tv(5201), Tv(5202)]),
-    ((5307, false, NAND2), &[Tv(5304), Tv(5306)]),
-    ((5310, false, AND2), &[Tv(5308), Tv(5309)]),
-    ((5414, false, NAND2), &[Tv(5411), Tv(5413)]),
-    ((5417, false, AND2), &[Tv(5415), Tv(5416)]),
-    ((5521, false, NAND2), &[Tv(5518), Tv(5520)]),
-    ((5524, false, AND2), &[Tv(5522), Tv(5523)]),
-    ((5628, false, NAND2), &[Tv(5625), Tv(5627)]),
-    ((5631, false, AND2), &[Tv(5629), Tv(5630)]),
-    ((5735, false, NAND2), &[Tv(5732), Tv(5734)]),
-    ((5738, false, AND2), &[Tv(5736), Tv(5737)]),
-    ((5842, false, NAND2), &[Tv(5839), Tv(5841)]),
-    ((5845, false, AND2), &[Tv(5843), Tv(5844)]),
-    ((5949, false, NAND2), &[Tv(5946), Tv(5948)]),
-    ((5952, false, AND2), &[Tv(5950), Tv(5951)]),
-    ((6056, false, NAND2), &[Tv(6053), Tv(6055)]),
-    ((6059, false, AND2), &[Tv(6057), Tv(6058)]),
-    ((6163, false, NAND2), &[Tv(6160), Tv(6162)]),
-    ((6166, false, AND2), &[Tv(6164), Tv(6165)]),
-    ((6270, false, NAND2), &[Tv(6267), Tv(6269)]),
-    ((6273, false, AND2), &[Tv(6271), Tv(6272)]),
-    ((6377, false, NAND2), &[Tv(6374), Tv(6376)]),
-    ((6380, false, AND2), &[Tv(6378), Tv(6379)]),
-    ((1491, false, AND2), &[Tv(1489), Tv(1490)]),
-    ((1494, false, AND2), &[Tv(1492), Tv(1493)]),
-    ((1498, false, AND2), &[Tv(1496), Tv(1497)]),
-    ((1505, false, AND2), &[Tv(1501), Tv(1504)]),
-    ((1512, false, AND2), &[Tv(1508), Tv(1511)]),
-    ((1515, false, AND2), &[Tv(1513), Tv(1514)]),
-    ((1529, false, AND2), &[Tv(1523), Tv(1528)]),
-    ((1544, false, AND2), &[Tv(1536), Tv(1543)]),
-    ((1549, false, NOR2), &[Arg(2, 986), Arg(2, 987)]),
-    ((1550, false, NOR2), &[Arg(2, 985), Arg(2, 984)]),
-    ((1552, false, NOR2), &[Arg(2, 989), Arg(2, 990)]),
-    ((1553, false, NOR2), &[Arg(2, 988), Arg(2, 991)]),
-    ((1556, false, XNOR2), &[Arg(1, 7), Arg(2, 975)]),
-    ((1557, false, XNOR2), &[Arg(1, 11), Arg(2, 979)]),
-    ((1561, false, AND2), &[Tv(1559), Tv(1560)]),
-    ((1564, false, AND2), &[Tv(1562), Tv(1563)]),
-    ((1568, false, XNOR2), &[Arg(1, 6), Arg(2, 974)]),
-    ((1571, false, AND2), &[Tv(1569), Tv(1570)]),
-    ((1573, false, XNOR2), &[Arg(1, 5), Arg(2, 973)]),
-    ((1574, false, XNOR2), &[Arg(1, 14), Arg(2, 982)]),
-    ((1583, false, AND2), &[Tv(1579), Tv(1582)]),
-    ((1588, false, AND2), &[Tv(1584), Tv(1587)]),
-    ((1596, false, AND2), &[Tv(1592), Tv(1595)]),
-    ((1603, false, AND2), &[Tv(1599), Tv(1602)]),
-    ((1623, false, AND2), &[Tv(1615), Tv(1622)]),
-    ((1664, false, AND2), &[Tv(1632), Tv(1663)]),
-    ((1669, false, AND2), &[Tv(1667), Tv(1668)]),
-    ((1672, false, AND2), &[Tv(1670), Tv(1671)]),
-    ((1678, false, AND2), &[Tv(1676), Tv(1677)]),
-    ((1683, false, AND2), &[Tv(1679), Tv(1682)]),
-    ((1690, false, AND2), &[Tv(1686), Tv(1689)]),
-    ((1693, false, AND2), &[Tv(1691), Tv(1692)]),
-    ((1709, false, AND2), &[Tv(1701), Tv(1708)]),
-    ((1724, false, AND2), &[Tv(1716), Tv(1723)]),
-    ((1784, false, AND2), &[Tv(1750), Tv(1783)]),
-    ((1804, false, AND2), &[Tv(1792), Tv(1803)]),
-    ((1843, false, AND2), &[Tv(1813), Tv(1842)]),
-    ((2369, false, AND2), &[Tv(2333), Tv(2368)]),
-    ((2389, false, AND2), &[Tv(2377), Tv(2388)]),
-    ((2426, false, AND2), &[Tv(2398), Tv(2425)]),
-];
-
-static LEVEL_20: [((usize, bool, CellType), &[GateInput]); 95] = [
-    ((3019, false, OR2), &[Arg(2, 480), Tv(2764)]),
-    ((3048, false, NAND2), &[Tv(3042), Tv(3047)]),
-    ((3163, false, NAND2), &[Tv(3160), Tv(3162)]),
-    ((3165, false, AND2), &[Tv(2310), Tv(3164)]),
-    ((3167, false, NAND2), &[Arg(2, 577), Tv(3054)]),
-    ((3168, false, NAND2), &[Arg(2, 625), Tv(2263)]),
-    ((3278, false, NAND2), &[Tv(3274), Tv(3277)]),
-    ((3280, false, NAND2), &[Arg(2, 674), Tv(2369)]),
-    ((3385, false, NAND2), &[Tv(3381), Tv(3384)]),
-    ((3387, false, NAND2), &[Arg(2, 675), Tv(2369)]),
-    ((3492, false, NAND2), &[Tv(3488), Tv(3491)]),
-    ((3494, false, NAND2), &[Arg(2, 676), Tv(2369)]),
-    ((3599, false, NAND2), &[Tv(3595), Tv(3598)]),
-    ((3601, false, NAND2), &[Arg(2, 677), Tv(2369)]),
-    ((3706, false, NAND2), &[Tv(3702), Tv(3705)]),
-    ((3708, false, NAND2), &[Arg(2, 678), Tv(2369)]),
-    ((3813, false, NAND2), &[Tv(3809), Tv(3812)]),
-    ((3815, false, NAND2), &[Arg(2, 679), Tv(2369)]),
-    ((3920, false, NAND2), &[Tv(3916), Tv(3919)]),
-    ((3922, false, NAND2), &[Arg(2, 696), Tv(2369)]),
-    ((4027, false, NAND2), &[Tv(4023), Tv(4026)]),
-    ((4029, false, NAND2), &[Arg(2, 697), Tv(2369)]),
-    ((4134, false, NAND2), &[Tv(4130), Tv(4133)]),
-    ((4136, false, NAND2), &[Arg(2, 698), Tv(2369)]),
-    ((4241, false, NAND2), &[Tv(4237), Tv(4240)]),
-    ((4243, false, NAND2), &[Arg(2, 699), Tv(2369)]),
-    ((4348, false, NAND2), &[Tv(4344), Tv(4347)]),
-    ((4350, false, NAND2), &[Arg(2, 700), Tv(2369)]),
-    ((4455, false, NAND2), &[Tv(4451), Tv(4454)]),
-    ((4457, false, NAND2), &[Arg(2, 701), Tv(2369)]),
-    ((4562, false, NAND2), &[Tv(4558), Tv(4561)]),
-    ((4564, false, NAND2), &[Arg(2, 702), Tv(2369)]),
-    ((4669, false, NAND2), &[Tv(4665), Tv(4668)]),
-    ((4671, false, NAND2), &[Arg(2, 703), Tv(2369)]),
-    ((4776, false, NAND2), &[Tv(4772), Tv(4775)]),
-    ((4778, false, NAND2), &[Arg(2, 704), Tv(2369)]),
-    ((4883, false, NAND2), &[Tv(4879), Tv(4882)]),
-    ((4885, false, NAND2), &[Arg(2, 705), Tv(2369)]),
-    ((4990, false, NAND2), &[Tv(4986), Tv(4989)]),
-    ((4992, false, NAND2), &[Arg(2, 706), Tv(2369)]),
-    ((5097, false, NAND2), &[Tv(5093), Tv(5096)]),
-    ((5099, false, NAND2), &[Arg(2, 707), Tv(2369)]),
-    ((5204, false, NAND2), &[Tv(5200), Tv(5203)]),
-    ((5206, false, NAND2), &[Arg(2, 708), Tv(2369)]),
-    ((5311, false, NAND2), &[Tv(5307), Tv(5310)]),
-    ((5313, false, NAND2), &[Arg(2, 709), Tv(2369)]),
-    ((5418, false, NAND2), &[Tv(5414), Tv(5417)]),
-    ((5420, false, NAND2), &[Arg(2, 710), Tv(2369)]),
-    ((5525, false, NAND2), &[Tv(5521), Tv(5524)]),
-    ((5527, false, NAND2), &[Arg(2, 711), Tv(2369)]),
-    ((5632, false, NAND2), &[Tv(5628), Tv(5631)]),
-    ((5634, false, NAND2), &[Arg(2, 712), Tv(2369)]),
-    ((5739, false, NAND2), &[Tv(5735), Tv(5738)]),
-    ((5741, false, NAND2), &[Arg(2, 713), Tv(2369)]),
-    ((5846, false, NAND2), &[Tv(5842), Tv(5845)]),
-    ((5848, false, NAND2), &[Arg(2, 714), Tv(2369)]),
-    ((5953, false, NAND2), &[Tv(5949), Tv(5952)]),
-    ((5955, false, NAND2), &[Arg(2, 715), Tv(2369)]),
-    ((6060, false, NAND2), &[Tv(6056), Tv(6059)]),
-    ((6062, false, NAND2), &[Arg(2, 716), Tv(2369)]),
-    ((6167, false, NAND2), &[Tv(6163), Tv(6166)]),
-    ((6169, false, NAND2), &[Arg(2, 717), Tv(2369)]),
-    ((6274, false, NAND2), &[Tv(6270), Tv(6273)]),
-    ((6276, false, NAND2), &[Arg(2, 718), Tv(2369)]),
-    ((6381, false, NAND2), &[Tv(6377), Tv(6380)]),
-    ((6383, false, NAND2), &[Arg(2, 719), Tv(2369)]),
-    ((396, false, INV), &[Arg(0, 8)]),
-    ((522, false, INV), &[Arg(2, 1073)]),
-    ((523, false, INV), &[Arg(2, 1077)]),
-    ((524, false, INV), &[Arg(2, 1079)]),
-    ((538, false, INV), &[Arg(3, 59)]),
-    ((548, false, INV), &[Arg(3, 109)]),
-    ((551, false, INV), &[Arg(3, 114)]),
-    ((552, false, INV), &[Arg(3, 116)]),
-    ((1495, false, NAND2), &[Tv(1491), Tv(1494)]),
-    ((1506, false, AND2), &[Tv(1498), Tv(1505)]),
-    ((1516, false, AND2), &[Tv(1512), Tv(1515)]),
-    ((1545, false, AND2), &[Tv(1529), Tv(1544)]),
-    ((1551, false, AND2), &[Tv(1549), Tv(1550)]),
-    ((1554, false, AND2), &[Tv(1552), Tv(1553)]),
-    ((1558, false, AND2), &[Tv(1556), Tv(1557)]),
-    ((1565, false, AND2), &[Tv(1561), Tv(1564)]),
-    ((1572, false, AND2), &[Tv(1568), Tv(1571)]),
-    ((1575, false, AND2), &[Tv(1573), Tv(1574)]),
-    ((1589, false, AND2), &[Tv(1583), Tv(1588)]),
-    ((1604, false, AND2), &[Tv(1596), Tv(1603)]),
-    ((1665, false, AND2), &[Tv(1623), Tv(1664)]),
-    ((1673, false, NAND2), &[Tv(1669), Tv(1672)]),
-    ((1684, false, AND2), &[Tv(1678), Tv(1683)]),
-    ((1694, false, AND2), &[Tv(1690), Tv(1693)]),
-    ((1725, false, AND2), &[Tv(1709), Tv(1724)]),
-    ((1785, false, INV), &[Tv(1784)]),
-    ((1844, false, NAND2), &[Tv(1804), Tv(1843)]),
-    ((2370, false, NAND2), &[Tv(2333), Tv(2368)]),
-    ((2427, false, NAND2), &[Tv(2389), Tv(2426)]),
-];
-
-static LEVEL_21: [((usize, bool, CellType), &[GateInput]); 171] = [
-    ((2841, false, NAND2), &[Arg(0, 9), Tv(405)]),
-    ((2842, false, AND2), &[Tv(396), Arg(1, 8)]),
-    ((3049, false, NAND2), &[Tv(3019), Tv(3048)]),
-    ((3051, false, OR2), &[Arg(2, 528), Tv(2204)]),
-    ((3056, false, NAND2), &[Arg(2, 624), Tv(2263)]),
-    ((3066, false, NOR2), &[Tv(1784), Tv(1844)]),
-    ((3166, false, NAND2), &[Tv(3163), Tv(3165)]),
-    ((3169, false, AND2), &[Tv(3167), Tv(3168)]),
-    ((3279, false, NAND2), &[Tv(2370), Tv(3278)]),
-    ((3281, false, AND2), &[Tv(2427), Tv(3280)]),
-    ((3283, false, OR2), &[Arg(2, 722), Tv(2427)]),
-    ((3287, false, NAND2), &[Arg(2, 818), Tv(1784)]),
-    ((3386, false, NAND2), &[Tv(2370), Tv(3385)]),
-    ((3388, false, AND2), &[Tv(2427), Tv(3387)]),
-    ((3390, false, OR2), &[Arg(2, 723), Tv(2427)]),
-    ((3394, false, NAND2), &[Arg(2, 819), Tv(1784)]),
-    ((3493, false, NAND2), &[Tv(2370), Tv(3492)]),
-    ((3495, false, AND2), &[Tv(2427), Tv(3494)]),
-    ((3497, false, OR2), &[Arg(2, 724), Tv(2427)]),
-    ((3501, false, NAND2), &[Arg(2, 820), Tv(1784)]),
-    ((3600, false, NAND2), &[Tv(2370), Tv(3599)]),
-    ((3602, false, AND2), &[Tv(2427), Tv(3601)]),
-    ((3604, false, OR2), &[Arg(2, 725), Tv(2427)]),
-    ((3608, false, NAND2), &[Arg(2, 821), Tv(1784)]),
-    ((3707, false, NAND2), &[Tv(2370), Tv(3706)]),
-    ((3709, false, AND2), &[Tv(2427), Tv(3708)]),
-    ((3711, false, OR2), &[Arg(2, 726), Tv(2427)]),
-    ((3715, false, NAND2), &[Arg(2, 822), Tv(1784)]),
-    ((3814, false, NAND2), &[Tv(2370), Tv(3813)]),
-    ((3816, false, AND2), &[Tv(2427), Tv(3815)]),
-    ((3818, false, OR2), &[Arg(2, 727), Tv(2427)]),
-    ((3822, false, NAND2), &[Arg(2, 823), Tv(1784)]),
-    ((3921, false, NAND2), &[Tv(2370), Tv(3920)]),
-    ((3923, false, AND2), &[Tv(2427), Tv(3922)]),
-    ((3925, false, OR2), &[Arg(2, 744), Tv(2427)]),
-    ((3929, false, NAND2), &[Arg(2, 840), Tv(1784)]),
-    ((4028, false, NAND2), &[Tv(2370), Tv(4027)]),
-    ((4030, false, AND2), &[Tv(2427), Tv(4029)]),
-    ((4032, false, OR2), &[Arg(2, 745), Tv(2427)]),
-    ((4036, false, NAND2), &[Arg(2, 841), Tv(1784)]),
-    ((4135, false, NAND2), &[Tv(2370), Tv(4134)]),
-    ((4137, false, AND2), &[Tv(2427), Tv(4136)]),
-    ((4139, false, OR2), &[Arg(2, 746), Tv(2427)]),
-    ((4143, false, NAND2), &[Arg(2, 842), Tv(1784)]),
-    ((4242, false, NAND2), &[Tv(2370), Tv(4241)]),
-    ((4244, false, AND2), &[Tv(2427), Tv(4243)]),
-    ((4246, false, OR2), &[Arg(2, 747), Tv(2427)]),
-    ((4250, false, NAND2), &[Arg(2, 843), Tv(1784)]),
-    ((4349, false, NAND2), &[Tv(2370), Tv(4348)]),
-    ((4351, false, AND2), &[Tv(2427), Tv(4350)]),
-    ((4353, false, OR2), &[Arg(2, 748), Tv(2427)]),
-    ((4357, false, NAND2), &[Arg(2, 844), Tv(1784)]),
-    ((4456, false, NAND2), &[Tv(2370), Tv(4455)]),
-    ((4458, false, AND2), &[Tv(2427), Tv(4457)]),
-    ((4460, false, OR2), &[Arg(2, 749), Tv(2427)]),
-    ((4464, false, NAND2), &[Arg(2, 845), Tv(1784)]),
-    ((4563, false, NAND2), &[Tv(2370), Tv(4562)]),
-    ((4565, false, AND2), &[Tv(2427), Tv(4564)]),
-    ((4567, false, OR2), &[Arg(2, 750), Tv(2427)]),
-    ((4571, false, NAND2), &[Arg(2, 846), Tv(1784)]),
-    ((4670, false, NAND2), &[Tv(2370), Tv(4669)]),
-    ((4672, false, AND2), &[Tv(2427), Tv(4671)]),
-    ((4674, false, OR2), &[Arg(2, 751), Tv(2427)]),
-    ((4678, false, NAND2), &[Arg(2, 847), Tv(1784)]),
-    ((4777, false, NAND2), &[Tv(2370), Tv(4776)]),
-    ((4779, false, AND2), &[Tv(2427), Tv(4778)]),
-    ((4781, false, OR2), &[Arg(2, 752), Tv(2427)]),
-    ((4785, false, NAND2), &[Arg(2, 848), Tv(1784)]),
-    ((4884, false, NAND2), &[Tv(2370), Tv(4883)]),
-    ((4886, false, AND2), &[Tv(2427), Tv(4885)]),
-    ((4888, false, OR2), &[Arg(2, 753), Tv(2427)]),
-    ((4892, false, NAND2), &[Arg(2, 849), Tv(1784)]),
-    ((4991, false, NAND2), &[Tv(2370), Tv(4990)]),
-    ((4993, false, AND2), &[Tv(2427), Tv(4992)]),
-    ((4995, false, OR2), &[Arg(2, 754), Tv(2427)]),
-    ((4999, false, NAND2), &[Arg(2, 850), Tv(1784)]),
-    ((5098, false, NAND2), &[Tv(2370), Tv(5097)]),
-    ((5100, false, AND2), &[Tv(2427), Tv(5099)]),
-    ((5102, false, OR2), &[Arg(2, 755), Tv(2427)]),
-    ((5106, false, NAND2), &[Arg(2, 851), Tv(1784)]),
-    ((5205, false, NAND2), &[Tv(2370), Tv(5204)]),
-    ((5207, false, AND2), &[Tv(2427), Tv(5206)]),
-    ((5209, false, OR2), &[Arg(2, 756), Tv(2427)]),
-    ((5213, false, NAND2), &[Arg(2, 852), Tv(1784)]),
-    ((5312, false, NAND2), &[Tv(2370), Tv(5311)]),
-    ((5314, false, AND2), &[Tv(2427), Tv(5313)]),
-    ((5316, false, OR2), &[Arg(2, 757), Tv(2427)]),
-    ((5320, false, NAND2), &[Arg(2, 853), Tv(1784)]),
-    ((5419, false, NAND2), &[Tv(2370), Tv(5418)]),
-    ((5421, false, AND2), &[Tv(2427), Tv(5420)]),
-    ((5423, false, OR2), &[Arg(2, 758), Tv(2427)]),
-    ((5427, false, NAND2), &[Arg(2, 854), Tv(1784)]),
-    ((5526, false, NAND2), &[Tv(2370), Tv(5525)]),
-    ((5528, false, AND2), &[Tv(2427), Tv(5527)]),
-    ((5530, false, OR2), &[Arg(2, 759), Tv(2427)]),
-    ((5534, false, NAND2), &[Arg(2, 855), Tv(1784)]),
-    ((5633, false, NAND2), &[Tv(2370), Tv(5632)]),
-    ((5635, false, AND2), &[Tv(2427), Tv(5634)]),
-    ((5637, false, OR2), &[Arg(2, 760), Tv(2427)]),
-    ((5641, false, NAND2), &[Arg(2, 856), Tv(1784)]),
-    ((5740, false, NAND2), &[Tv(2370), Tv(5739)]),
-    ((5742, false, AND2), &[Tv(2427), Tv(5741)]),
-    ((5744, false, OR2), &[Arg(2, 761), Tv(2427)]),
-    ((5748, false, NAND2), &[Arg(2, 857), Tv(1784)]),
-    ((5847, false, NAND2), &[Tv(2370), Tv(5846)]),
-    ((5849, false, AND2), &[Tv(2427), Tv(5848)]),
-    ((5851, false, OR2), &[Arg(2, 762), Tv(2427)]),
-    ((5855, false, NAND2), &[Arg(2, 858), Tv(1784)]),
-    ((5954, false, NAND2), &[Tv(2370), Tv(5953)]),
-    ((5956, false, AND2), &[Tv(2427), Tv(5955)]),
-    ((5958, false, OR2), &[Arg(2, 763), Tv(2427)]),
-    ((5962, false, NAND2), &[Arg(2, 859), Tv(1784)]),
-    ((6061, false, NAND2), &[Tv(2370), Tv(6060)]),
-    ((6063, false, AND2), &[Tv(2427), Tv(6062)]),
-    ((6065, false, OR2), &[Arg(2, 764), Tv(2427)]),
-    ((6069, false, NAND2), &[Arg(2, 860), Tv(1784)]),
-    ((6168, false, NAND2), &[Tv(2370), Tv(6167)]),
-    ((6170, false, AND2), &[Tv(2427), Tv(6169)]),
-    ((6172, false, OR2), &[Arg(2, 765), Tv(2427)]),
-    ((6176, false, NAND2), &[Arg(2, 861), Tv(1784)]),
-    ((6275, false, NAND2), &[Tv(2370), Tv(6274)]),
-    ((6277, false, AND2), &[Tv(2427), Tv(6276)]),
-    ((6279, false, OR2), &[Arg(2, 766), Tv(2427)]),
-    ((6283, false, NAND2), &[Arg(2, 862), Tv(1784)]),
-    ((6382, false, NAND2), &[Tv(2370), Tv(6381)]),
-    ((6384, false, AND2), &[Tv(2427), Tv(6383)]),
-    ((6386, false, OR2), &[Arg(2, 767), Tv(2427)]),
-    ((6390, false, NAND2), &[Arg(2, 863), Tv(1784)]),
-    ((125, false, INV), &[Arg(2, 672)]),
-    ((381, false, INV), &[Arg(0, 0)]),
-    ((397, false, INV), &[Arg(0, 9)]),
-    ((527, false, INV), &[Arg(3, 10)]),
-    ((530, false, INV), &[Arg(3, 14)]),
-    ((531, false, INV), &[Arg(3, 18)]),
-    ((532, false, INV), &[Arg(3, 21)]),
-    ((533, false, INV), &[Arg(3, 22)]),
-    ((534, false, INV), &[Arg(3, 23)]),
-    ((537, false, INV), &[Arg(3, 58)]),
-    ((546, false, INV), &[Arg(3, 107)]),
-    ((549, false, INV), &[Arg(3, 111)]),
-    ((550, false, INV), &[Arg(3, 112)]),
-    ((553, false, INV), &[Arg(3, 119)]),
-    ((1178, false, NAND2), &[Arg(1, 3), Tv(548)]),
-    ((1180, false, NAND2), &[Tv(392), Arg(3, 109)]),
-    ((1181, false, NAND2), &[Tv(404), Arg(3, 114)]),
-    ((1184, false, NAND2), &[Tv(390), Arg(3, 107)]),
-    ((1185, false, NAND2), &[Tv(391), Arg(3, 108)]),
-    ((1188, false, NAND2), &[Tv(408), Arg(3, 119)]),
-    ((1192, false, NAND2), &[Tv(389), Arg(3, 106)]),
-    ((1193, false, NAND2), &[Arg(1, 8), Tv(551)]),
-    ((1207, false, NAND2), &[Tv(411), Arg(3, 116)]),
-    ((1210, false, NAND2), &[Arg(1, 10), Tv(552)]),
-    ((1225, false, NAND2), &[Tv(395), Arg(3, 63)]),
-    ((1235, false, NAND2), &[Arg(1, 2), Tv(538)]),
-    ((1507, false, AND2), &[Tv(1495), Tv(1506)]),
-    ((1546, false, AND2), &[Tv(1516), Tv(1545)]),
-    ((1555, false, NAND2), &[Tv(1551), Tv(1554)]),
-    ((1566, false, AND2), &[Tv(1558), Tv(1565)]),
-    ((1576, false, AND2), &[Tv(1572), Tv(1575)]),
-    ((1605, false, AND2), &[Tv(1589), Tv(1604)]),
-    ((1666, false, INV), &[Tv(1665)]),
-    ((1685, false, AND2), &[Tv(1673), Tv(1684)]),
-    ((1726, false, AND2), &[Tv(1694), Tv(1725)]),
-    ((1845, false, AND2), &[Tv(1785), Tv(1844)]),
-    ((2428, false, AND2), &[Tv(2370), Tv(2427)]),
-    ((2682, false, NAND2), &[Arg(1, 13), Tv(523)]),
-    ((2683, false, NAND2), &[Arg(1, 9), Tv(522)]),
-    ((2694, false, NAND2), &[Arg(1, 15), Tv(524)]),
-    ((2695, false, NAND2), &[Tv(408), Arg(2, 1077)]),
-    ((2697, false, NAND2), &[Tv(405), Arg(2, 1073)]),
-    ((2698, false, NAND2), &[Tv(412), Arg(2, 1079)]),
-];
-
-static LEVEL_22: [((usize, bool, CellType), &[GateInput]); 187] = [
-    ((2840, false, NAND2), &[Tv(397), Arg(1, 9)]),
-    ((2843, false, NAND2), &[Tv(2841), Tv(2842)]),
-    ((2901, false, NAND2), &[Arg(0, 1), Tv(390)]),
-    ((2902, false, AND2), &[Tv(381), Arg(1, 0)]),
-    ((3050, false, NAND2), &[Tv(2204), Tv(3049)]),
-    ((3052, false, AND2), &[Tv(2310), Tv(3051)]),
-    ((3055, false, NAND2), &[Arg(2, 576), Tv(3054)]),
-    ((3057, false, AND2), &[Tv(2428), Tv(3056)]),
-    ((3060, false, AND2), &[Tv(125), Tv(2369)]),
-    ((3062, false, OR2), &[Arg(2, 720), Tv(2427)]),
-    ((3170, false, NAND2), &[Tv(3166), Tv(3169)]),
-    ((3172, false, NAND2), &[Arg(2, 673), Tv(2369)]),
-    ((3282, false, NAND2), &[Tv(3279), Tv(3281)]),
-    ((3284, false, AND2), &[Tv(1845), Tv(3283)]),
-    ((3286, false, NAND2), &[Arg(2, 770), Tv(3066)]),
-    ((3288, false, AND2), &[Tv(1666), Tv(3287)]),
-    ((3389, false, NAND2), &[Tv(3386), Tv(3388)]),
-    ((3391, false, AND2), &[Tv(1845), Tv(3390)]),
-    ((3393, false, NAND2), &[Arg(2, 771), Tv(3066)]),
-    ((3395, false, AND2), &[Tv(1666), Tv(3394)]),
-    ((3496, false, NAND2), &[Tv(3493), Tv(3495)]),
-    ((3498, false, AND2), &[Tv(1845), Tv(3497)]),
-    ((3500, false, NAND2), &[Arg(2, 772), Tv(3066)]),
-    ((3502, false, AND2), &[Tv(1666), Tv(3501)]),
-    ((3603, false, NAND2), &[Tv(3600), Tv(3602)]),
-    ((3605, false, AND2), &[Tv(1845), Tv(3604)]),
-    ((3607, false, NAND2), &[Arg(2, 773), Tv(3066)]),
-    ((3609, false, AND2), &[Tv(1666), Tv(3608)]),
-    ((3710, false, NAND2), &[Tv(3707), Tv(3709)]),
-    ((3712, false, AND2), &[Tv(1845), Tv(3711)]),
-    ((3714, false, NAND2), &[Arg(2, 774), Tv(3066)]),
-    ((3716, false, AND2), &[Tv(1666), Tv(3715)]),
-    ((3817, false, NAND2), &[Tv(3814), Tv(3816)]),
-    ((3819, false, AND2), &[Tv(1845), Tv(3818)]),
-    ((3821, false, NAND2), &[Arg(2, 775), Tv(3066)]),
-    ((3823, false, AND2), &[Tv(1666), Tv(3822)]),
-    ((3924, false, NAND2), &[Tv(3921), Tv(3923)]),
-    ((3926, false, AND2), &[Tv(1845), Tv(3925)]),
-    ((3928, false, NAND2), &[Arg(2, 792), Tv(3066)]),
-    ((3930, false, AND2), &[Tv(1666), Tv(3929)]),
-    ((4031, false, NAND2), &[Tv(4028), Tv(4030)]),
-    ((4033, false, AND2), &[Tv(1845), Tv(4032)]),
-    ((4035, false, NAND2), &[Arg(2, 793), Tv(3066)]),
-    ((4037, false, AND2), &[Tv(1666), Tv(4036)]),
-    ((4138, false, NAND2), &[Tv(4135), Tv(4137)]),
-    ((4140, false, AND2), &[Tv(1845), Tv(4139)]),
-    ((4142, false, NAND2), &[Arg(2, 794), Tv(3066)]),
-    ((4144, false, AND2), &[Tv(1666), Tv(4143)]),
-    ((4245, false, NAND2), &[Tv(4242), Tv(4244)]),
-    ((4247, false, AND2), &[Tv(1845), Tv(4246)]),
-    ((4249, false, NAND2), &[Arg(2, 795), Tv(3066)]),
-    ((4251, false, AND2), &[Tv(1666), Tv(4250)]),
-    ((4352, false, NAND2), &[Tv(4349), Tv(4351)]),
-    ((4354, false, AND2), &[Tv(1845), Tv(4353)]),
-    ((4356, false, NAND2), &[Arg(2, 796), Tv(3066)]),
-    ((4358, false, AND2), &[Tv(1666), Tv(4357)]),
-    ((4459, false, NAND2), &[Tv(4456), Tv(4458)]),
-    ((4461, false, AND2), &[Tv(1845), Tv(4460)]),
-    ((4463, false, NAND2), &[Arg(2, 797), Tv(3066)]),
-    ((4465, false, AND2), &[Tv(1666), Tv(4464)]),
-    ((4566, false, NAND2), &[Tv(4563), Tv(4565)]),
-    ((4568, false, AND2), &[Tv(1845), Tv(4567)]),
-    ((4570, false, NAND2), &[Arg(2, 798), Tv(3066)]),
-    ((4572, false, AND2), &[Tv(1666), Tv(4571)]),
-    ((4673, false, NAND2), &[Tv(4670), Tv(4672)]),
-    ((4675, false, AND2), &[Tv(1845), Tv(4674)]),
-    ((4677, false, NAND2), &[Arg(2, 799), Tv(3066)]),
-    ((4679, false, AND2), &[Tv(1666), Tv(4678)]),
-    ((4780, false, NAND2), &[Tv(4777), Tv(4779)]),
-    ((4782, false, AND2), &[Tv(1845), Tv(4781)]),
-    ((4784, false, NAND2), &[Arg(2, 800), Tv(3066)]),
-    ((4786, false, AND2), &[Tv(1666), Tv(4785)]),
-    ((4887, false, NAND2), &[Tv(4884), Tv(4886)]),
-    ((4889, false, AND2), &[Tv(1845), Tv(4888)]),
-    ((4891, false, NAND2), &[Arg(2, 801), Tv(3066)]),
-    ((4893, false, AND2), &[Tv(1666), Tv(4892)]),
-    ((4994, false, NAND2), &[Tv(4991), Tv(4993)]),
-    ((4996, false, AND2), &[Tv(1845), Tv(4995)]),
-    ((4998, false, NAND2), &[Arg(2, 802), Tv(3066)]),
-    ((5000, false, AND2), &[Tv(1666), Tv(4999)]),
-    ((5101, false, NAND2), &[Tv(5098), Tv(5100)]),
-    ((5103, false, AND2), &[Tv(1845), Tv(5102)]),
-    ((5105, false, NAND2), &[Arg(2, 803), Tv(3066)]),
-    ((5107, false, AND2), &[Tv(1666), Tv(5106)]),
-    ((5208, false, NAND2), &[Tv(5205), Tv(5207)]),
-    ((5210, false, AND2), &[Tv(1845), Tv(5209)]),
-    ((5212, false, NAND2), &[Arg(2, 804), Tv(3066)]),
-    ((5214, false, AND2), &[Tv(1666), Tv(5213)]),
-    ((5315, false, NAND2), &[Tv(5312), Tv(5314)]),
-    ((5317, false, AND2), &[Tv(1845), Tv(5316)]),
-    ((5319, false, NAND2), &[Arg(2, 805), Tv(3066)]),
-    ((5321, false, AND2), &[Tv(1666), Tv(5320)]),
-    ((5422, false, NAND2), &[Tv(5419), Tv(5421)]),
-    ((5424, false, AND2), &[Tv(1845), Tv(5423)]),
-    ((5426, false, NAND2), &[Arg(2, 806), Tv(3066)]),
-    ((5428, false, AND2), &[Tv(1666), Tv(5427)]),
-    ((5529, false, NAND2), &[Tv(5526), Tv(5528)]),
-    ((5531, false, AND2), &[Tv(1845), Tv(5530)]),
-    ((5533, false, NAND2), &[Arg(2, 807), Tv(3066)]),
-    ((5535, false, AND2), &[Tv(1666), Tv(5534)]),
-    ((5636, false, NAND2), &[Tv(5633), Tv(5635)]),
-    ((5638, false, AND2), &[Tv(1845), Tv(5637)]),
-    ((5640, false, NAND2), &[Arg(2, 808), Tv(3066)]),
-    ((5642, false, AND2), &[Tv(1666), Tv(5641)]),
-    ((5743, false, NAND2), &[Tv(5740), Tv(5742)]),
-    ((5745, false, AND2), &[Tv(1845), Tv(5744)]),
-    ((5747, false, NAND2), &[Arg(2, 809), Tv(3066)]),
-    ((5749, false, AND2), &[Tv(1666), Tv(5748)]),
-    ((5850, false, NAND2), &[Tv(5847), Tv(5849)]),
-    ((5852, false, AND2), &[Tv(1845), Tv(5851)]),
-    ((5854, false, NAND2), &[Arg(2, 810), Tv(3066)]),
-    ((5856, false, AND2), &[Tv(1666), Tv(5855)]),
-    ((5957, false, NAND2), &[Tv(5954), Tv(5956)]),
-    ((5959, false, AND2), &[Tv(1845), Tv(5958)]),
-    ((5961, false, NAND2), &[Arg(2, 811), Tv(3066)]),
-    ((5963, false, AND2), &[Tv(1666), Tv(5962)]),
-    ((6064, false, NAND2), &[Tv(6061), Tv(6063)]),
-    ((6066, false, AND2), &[Tv(1845), Tv(6065)]),
-    ((6068, false, NAND2), &[Arg(2, 812), Tv(3066)]),
-    ((6070, false, AND2), &[Tv(1666), Tv(6069)]),
-    ((6171, false, NAND2), &[Tv(6168), Tv(6170)]),
-    ((6173, false, AND2), &[Tv(1845), Tv(6172)]),
-    ((6175, false, NAND2), &[Arg(2, 813), Tv(3066)]),
-    ((6177, false, AND2), &[Tv(1666), Tv(6176)]),
-    ((6278, false, NAND2), &[Tv(6275), Tv(6277)]),
-    ((6280, false, AND2), &[Tv(1845), Tv(6279)]),
-    ((6282, false, NAND2), &[Arg(2, 814), Tv(3066)]),
-    ((6284, false, AND2), &[Tv(1666), Tv(6283)]),
-    ((6385, false, NAND2), &[Tv(6382), Tv(6384)]),
-    ((6387, false, AND2), &[Tv(1845), Tv(6386)]),
-    ((6389, false, NAND2), &[Arg(2, 815), Tv(3066)]),
-    ((6391, false, AND2), &[Tv(1666), Tv(6390)]),
-    ((382, false, INV), &[Arg(0, 1)]),
-    ((525, false, INV), &[Arg(3, 8)]),
-    ((526, false, INV), &[Arg(3, 9)]),
-    ((528, false, INV), &[Arg(3, 11)]),
-    ((529, false, INV), &[Arg(3, 12)]),
-    ((535, false, INV), &[Arg(3, 40)]),
-    ((536, false, INV), &[Arg(3, 57)]),
-    ((540, false, INV), &[Arg(3, 63)]),
-    ((544, false, INV), &[Arg(3, 89)]),
-    ((545, false, INV), &[Arg(3, 106)]),
-    ((547, false, INV), &[Arg(3, 108)]),
-    ((554, false, INV), &[Arg(3, 138)]),
-    ((955, false, NAND2), &[Tv(412), Arg(3, 23)]),
-    ((956, false, NAND2), &[Tv(395), Arg(3, 14)]),
-    ((958, false, NAND2), &[Tv(390), Arg(3, 9)]),
-    ((959, false, NAND2), &[Arg(1, 15), Tv(534)]),
-    ((962, false, NAND2), &[Arg(1, 10), Tv(531)]),
-    ((963, false, NAND2), &[Arg(1, 14), Tv(533)]),
-    ((965, false, NAND2), &[Arg(1, 2), Tv(527)]),
-    ((969, false, NAND2), &[Tv(411), Arg(3, 18)]),
-    ((970, false, NAND2), &[Arg(1, 6), Tv(530)]),
-    ((975, false, NAND2), &[Tv(391), Arg(3, 10)]),
-    ((976, false, NAND2), &[Arg(1, 13), Tv(532)]),
-    ((1173, false, NAND2), &[Arg(1, 1), Tv(546)]),
-    ((1174, false, NAND2), &[Tv(395), Arg(3, 112)]),
-    ((1182, false, XNOR2), &[Arg(1, 14), Arg(3, 120)]),
-    ((1187, false, XNOR2), &[Arg(1, 11), Arg(3, 117)]),
-    ((1189, false, NAND2), &[Arg(1, 5), Tv(549)]),
-    ((1190, false, NAND2), &[Arg(1, 6), Tv(550)]),
-    ((1194, false, NAND2), &[Arg(1, 13), Tv(553)]),
-    ((1199, false, XNOR2), &[Arg(1, 7), Arg(3, 113)]),
-    ((1200, false, AND2), &[Tv(1178), Tv(1184)]),
-    ((1202, false, AND2), &[Tv(1185), Tv(1193)]),
-    ((1208, false, AND2), &[Tv(1181), Tv(1207)]),
-    ((1211, false, AND2), &[Tv(1192), Tv(1210)]),
-    ((1217, false, AND2), &[Tv(1180), Tv(1188)]),
-    ((1224, false, NAND2), &[Tv(391), Arg(3, 59)]),
-    ((1228, false, NAND2), &[Tv(408), Arg(3, 70)]),
-    ((1236, false, NAND2), &[Tv(409), Arg(3, 71)]),
-    ((1237, false, NAND2), &[Tv(389), Arg(3, 57)]),
-    ((1239, false, NAND2), &[Arg(1, 1), Tv(537)]),
-    ((1252, false, AND2), &[Tv(1225), Tv(1235)]),
-    ((1253, false, XNOR2), &[Arg(1, 4), Arg(3, 61)]),
-    ((1547, false, AND2), &[Tv(1507), Tv(1546)]),
-    ((1567, false, AND2), &[Tv(1555), Tv(1566)]),
-    ((1606, false, AND2), &[Tv(1576), Tv(1605)]),
-    ((1727, false, NAND2), &[Tv(1685), Tv(1726)]),
-    ((2684, false, AND2), &[Tv(2682), Tv(2683)]),
-    ((2685, false, XNOR2), &[Arg(1, 7), Arg(2, 1071)]),
-    ((2687, false, XNOR2), &[Arg(1, 2), Arg(2, 1066)]),
-    ((2688, false, XNOR2), &[Arg(1, 4), Arg(2, 1068)]),
-    ((2691, false, XNOR2), &[Arg(1, 3), Arg(2, 1067)]),
-    ((2692, false, XNOR2), &[Arg(1, 6), Arg(2, 1070)]),
-    ((2696, false, AND2), &[Tv(2694), Tv(2695)]),
-    ((2699, false, AND2), &[Tv(2697), Tv(2698)]),
-];
-
-static LEVEL_23: [((usize, bool, CellType), &[GateInput]); 170] = [
-    ((2839, false, NAND2), &[Arg(0, 10), Tv(411)]),
-    ((2844, false, NAND2), &[Tv(2840), Tv(2843)]),
-    ((2856, false, NAND2), &[Arg(0, 8), Tv(404)]),
-    ((2862, false, XNOR2), &[Arg(0, 9), Arg(1, 9)]),
-    ((2900, false, NAND2), &[Tv(382), Arg(1, 1)]),
-    ((2903, false, NAND2), &[Tv(2901), Tv(2902)]),
-    ((3053, false, NAND2), &[Tv(3050), Tv(3052)]),
-    ((3058, false, AND2), &[Tv(3055), Tv(3057)]),
-    ((3061, false, NAND2), &[Tv(2427), Tv(3060)]),
-    ((3063, false, AND2), &[Tv(1845), Tv(3062)]),
-    ((3068, false, NAND2), &[Arg(2, 816), Tv(1784)]),
-    ((3171, false, NAND2), &[Tv(2370), Tv(3170)]),
-    ((3173, false, AND2), &[Tv(2427), Tv(3172)]),
-    ((3175, false, OR2), &[Arg(2, 721), Tv(2427)]),
-    ((3179, false, NAND2), &[Arg(2, 817), Tv(1784)]),
-    ((3285, false, NAND2), &[Tv(3282), Tv(3284)]),
-    ((3289, false, AND2), &[Tv(3286), Tv(3288)]),
-    ((3392, false, NAND2), &[Tv(3389), Tv(3391)]),
-    ((3396, false, AND2), &[Tv(3393), Tv(3395)]),
-    ((3499, false, NAND2), &[Tv(3496), Tv(3498)]),
-    ((3503, false, AND2), &[Tv(3500), Tv(3502)]),
-    ((3606, false, NAND2), &[Tv(3603), Tv(3605)]),
-    ((3610, false, AND2), &[Tv(3607), Tv(3609)]),
-    ((3713, false, NAND2), &[Tv(3710), Tv(3712)]),
-    ((3717, false, AND2), &[Tv(3714), Tv(3716)]),
-    ((3820, false, NAND2), &[Tv(3817), Tv(3819)]),
-    ((3824, false, AND2), &[Tv(3821), Tv(3823)]),
-    ((3927, false, NAND2), &[Tv(3924), Tv(3926)]),
-    ((3931, false, AND2), &[Tv(3928), Tv(3930)]),
-    ((4034, false, NAND2), &[Tv(4031), Tv(4033)]),
-    ((4038, false, AND2), &[Tv(4035), Tv(4037)]),
-    ((4141, false, NAND2), &[Tv(4138), Tv(4140)]),
-    ((4145, false, AND2), &[Tv(4142), Tv(4144)]),
-    ((4248, false, NAND2), &[Tv(4245), Tv(4247)]),
-    ((4252, false, AND2), &[Tv(4249), Tv(4251)]),
-    ((4355, false, NAND2), &[Tv(4352), Tv(4354)]),
-    ((4359, false, AND2), &[Tv(4356), Tv(4358)]),
-    ((4462, false, NAND2), &[Tv(4459), Tv(4461)]),
-    ((4466, false, AND2), &[Tv(4463), Tv(4465)]),
-    ((4569, false, NAND2), &[Tv(4566), Tv(4568)]),
-    ((4573, false, AND2), &[Tv(4570), Tv(4572)]),
-    ((4676, false, NAND2), &[Tv(4673), Tv(4675)]),
-    ((4680, false, AND2), &[Tv(4677), Tv(4679)]),
-    ((4783, false, NAND2), &[Tv(4780), Tv(4782)]),
-    ((4787, false, AND2), &[Tv(4784), Tv(4786)]),
-    ((4890, false, NAND2), &[Tv(4887), Tv(4889)]),
-    ((4894, false, AND2), &[Tv(4891), Tv(4893)]),
-    ((4997, false, NAND2), &[Tv(4994), Tv(4996)]),
-    ((5001, false, AND2), &[Tv(4998), Tv(5000)]),
-    ((5104, false, NAND2), &[Tv(5101), Tv(5103)]),
-    ((5108, false, AND2), &[Tv(5105), Tv(5107)]),
-    ((5211, false, NAND2), &[Tv(5208), Tv(5210)]),
-    ((5215, false, AND2), &[Tv(5212), Tv(5214)]),
-    ((5318, false, NAND2), &[Tv(5315), Tv(5317)]),
-    ((5322, false, AND2), &[Tv(5319), Tv(5321)]),
-    ((5425, false, NAND2), &[Tv(5422), Tv(5424)]),
-    ((5429, false, AND2), &[Tv(5426), Tv(5428)]),
-    ((5532, false, NAND2), &[Tv(5529), Tv(5531)]),
-    ((5536, false, AND2), &[Tv(5533), Tv(5535)]),
-    ((5639, false, NAND2), &[Tv(5636), Tv(5638)]),
-    ((5643, false, AND2), &[Tv(5640), Tv(5642)]),
-    ((5746, false, NAND2), &[Tv(5743), Tv(5745)]),
-    ((5750, false, AND2), &[Tv(5747), Tv(5749)]),
-    ((5853, false, NAND2), &[Tv(5850), Tv(5852)]),
-    ((5857, false, AND2), &[Tv(5854), Tv(5856)]),
-    ((5960, false, NAND2), &[Tv(5957), Tv(5959)]),
-    ((5964, false, AND2), &[Tv(5961), Tv(5963)]),
-    ((6067, false, NAND2), &[Tv(6064), Tv(6066)]),
-    ((6071, false, AND2), &[Tv(6068), Tv(6070)]),
-    ((6174, false, NAND2), &[Tv(6171), Tv(6173)]),
-    ((6178, false, AND2), &[Tv(6175), Tv(6177)]),
-    ((6281, false, NAND2), &[Tv(6278), Tv(6280)]),
-    ((6285, false, AND2), &[Tv(6282), Tv(6284)]),
-    ((6388, false, NAND2), &[Tv(6385), Tv(6387)]),
-    ((6392, false, AND2), &[Tv(6389), Tv(6391)]),
-    ((8, false, INV), &[Arg(2, 892)]),
-    ((9, false, INV), &[Arg(2, 893)]),
-    ((10, false, INV), &[Arg(2, 894)]),
-    ((11, false, INV), &[Arg(2, 895)]),
-    ((12, false, INV), &[Arg(2, 890)]),
-    ((13, false, INV), &[Arg(2, 891)]),
-    ((14, false, INV), &[Arg(2, 889)]),
-    ((15, false, INV), &[Arg(2, 888)]),
-    ((126, false, INV), &[Arg(2, 864)]),
-    ((127, false, INV), &[Arg(2, 865)]),
-    ((128, false, INV), &[Arg(2, 866)]),
-    ((129, false, INV), &[Arg(2, 867)]),
-    ((130, false, INV), &[Arg(2, 868)]),
-    ((131, false, INV), &[Arg(2, 869)]),
-    ((132, false, INV), &[Arg(2, 870)]),
-    ((133, false, INV), &[Arg(2, 871)]),
-    ((245, false, INV), &[Arg(2, 896)]),
-    ((246, false, INV), &[Arg(2, 897)]),
-    ((247, false, INV), &[Arg(2, 898)]),
-    ((248, false, INV), &[Arg(2, 899)]),
-    ((249, false, INV), &[Arg(2, 900)]),
-    ((250, false, INV), &[Arg(2, 901)]),
-    ((251, false, INV), &[Arg(2, 902)]),
-    ((252, false, INV), &[Arg(2, 903)]),
-    ((341, false, INV), &[Arg(2, 904)]),
-    ((342, false, INV), &[Arg(2, 905)]),
-    ((343, false, INV), &[Arg(2, 906)]),
-    ((344, false, INV), &[Arg(2, 907)]),
-    ((345, false, INV), &[Arg(2, 908)]),
-    ((346, false, INV), &[Arg(2, 909)]),
-    ((347, false, INV), &[Arg(2, 910)]),
-    ((348, false, INV), &[Arg(2, 911)]),
-    ((398, false, INV), &[Arg(0, 10)]),
-    ((539, false, INV), &[Arg(3, 60)]),
-    ((541, false, INV), &[Arg(3, 65)]),
-    ((542, false, INV), &[Arg(3, 70)]),
-    ((543, false, INV), &[Arg(3, 71)]),
-    ((589, false, INV), &[Arg(3, 304)]),
-    ((936, false, NAND2), &[Arg(1, 4), Tv(529)]),
-    ((937, false, NAND2), &[Tv(392), Arg(3, 11)]),
-    ((941, false, NAND2), &[Arg(1, 1), Tv(526)]),
-    ((942, false, NAND2), &[Tv(409), Arg(3, 22)]),
-    ((944, false, NAND2), &[Arg(1, 3), Tv(528)]),
-    ((945, false, NAND2), &[Arg(1, 0), Tv(525)]),
-    ((957, false, AND2), &[Tv(955), Tv(956)]),
-    ((960, false, AND2), &[Tv(958), Tv(959)]),
-    ((964, false, AND2), &[Tv(962), Tv(963)]),
-    ((966, false, AND2), &[Tv(535), Tv(965)]),
-    ((971, false, AND2), &[Tv(969), Tv(970)]),
-    ((972, false, XNOR2), &[Arg(1, 8), Arg(3, 16)]),
-    ((974, false, XNOR2), &[Arg(1, 7), Arg(3, 15)]),
-    ((977, false, AND2), &[Tv(975), Tv(976)]),
-    ((1175, false, NAND2), &[Arg(1, 2), Tv(547)]),
-    ((1176, false, NAND2), &[Arg(1, 0), Tv(545)]),
-    ((1179, false, XNOR2), &[Arg(1, 9), Arg(3, 115)]),
-    ((1183, false, XNOR2), &[Arg(1, 12), Arg(3, 118)]),
-    ((1186, false, XNOR2), &[Arg(1, 4), Arg(3, 110)]),
-    ((1196, false, AND2), &[Tv(554), Tv(1174)]),
-    ((1201, false, AND2), &[Tv(1199), Tv(1200)]),
-    ((1203, false, AND2), &[Tv(1173), Tv(1202)]),
-    ((1209, false, AND2), &[Tv(1190), Tv(1208)]),
-    ((1212, false, AND2), &[Tv(1182), Tv(1211)]),
-    ((1214, false, AND2), &[Tv(1189), Tv(1194)]),
-    ((1218, false, AND2), &[Tv(1187), Tv(1217)]),
-    ((1226, false, NAND2), &[Arg(1, 0), Tv(536)]),
-    ((1227, false, NAND2), &[Arg(1, 6), Tv(540)]),
-    ((1229, false, NAND2), &[Tv(392), Arg(3, 60)]),
-    ((1232, false, NAND2), &[Tv(404), Arg(3, 65)]),
-    ((1234, false, XNOR2), &[Arg(1, 9), Arg(3, 66)]),
-    ((1238, false, AND2), &[Tv(1236), Tv(1237)]),
-    ((1251, false, XNOR2), &[Arg(1, 15), Arg(3, 72)]),
-    ((1254, false, AND2), &[Tv(1252), Tv(1253)]),
-    ((1260, false, AND2), &[Tv(544), Tv(1228)]),
-    ((1263, false, AND2), &[Tv(1224), Tv(1239)]),
-    ((1264, false, XNOR2), &[Arg(1, 5), Arg(3, 62)]),
-    ((1266, false, XNOR2), &[Arg(1, 10), Arg(3, 67)]),
-    ((1548, false, INV), &[Tv(1547)]),
-    ((1607, false, NAND2), &[Tv(1567), Tv(1606)]),
-    ((1728, false, AND2), &[Tv(1666), Tv(1727)]),
-    ((2660, false, NOR2), &[Arg(2, 1082), Arg(2, 1083)]),
-    ((2661, false, NOR2), &[Arg(2, 1081), Arg(2, 1080)]),
-    ((2663, false, NOR2), &[Arg(2, 1086), Arg(2, 1087)]),
-    ((2664, false, NOR2), &[Arg(2, 1084), Arg(2, 1085)]),
-    ((2667, false, XNOR2), &[Arg(1, 8), Arg(2, 1072)]),
-    ((2668, false, XNOR2), &[Arg(1, 14), Arg(2, 1078)]),
-    ((2670, false, XNOR2), &[Arg(1, 5), Arg(2, 1069)]),
-    ((2671, false, XNOR2), &[Arg(1, 10), Arg(2, 1074)]),
-    ((2675, false, XNOR2), &[Arg(1, 0), Arg(2, 1064)]),
-    ((2676, false, XNOR2), &[Arg(1, 11), Arg(2, 1075)]),
-    ((2678, false, XNOR2), &[Arg(1, 12), Arg(2, 1076)]),
-    ((2679, false, XNOR2), &[Arg(1, 1), Arg(2, 1065)]),
-    ((2686, false, AND2), &[Tv(2684), Tv(2685)]),
-    ((2689, false, AND2), &[Tv(2687), Tv(2688)]),
-    ((2693, false, AND2), &[Tv(2691), Tv(2692)]),
-    ((2700, false, AND2), &[Tv(2696), Tv(2699)]),
-];
-
-static LEVEL_24: [((usize, bool, CellType), &[GateInput]); 123] = [
-    ((2838, false, NAND2), &[Tv(398), Arg(1, 10)]),
-    ((2845, false, NAND2), &[Tv(2839), Tv(2844)]),
-    ((2875, false, NAND2), &[Tv(2856), Tv(2862)]),
-    ((2899, false, NAND2), &[Arg(0, 2), Tv(391)]),
-    ((2904, false, NAND2), &[Tv(2900), Tv(2903)]),
-    ((3059, false, NAND2), &[Tv(3053), Tv(3058)]),
-    ((3064, false, AND2), &[Tv(3061), Tv(3063)]),
-    ((3067, false, NAND2), &[Arg(2, 768), Tv(3066)]),
-    ((3069, false, AND2), &[Tv(1728), Tv(3068)]),
-    ((3072, false, AND2), &[Tv(126), Tv(1665)]),
-    ((3074, false, OR2), &[Arg(2, 912), Tv(1727)]),
-    ((3174, false, NAND2), &[Tv(3171), Tv(3173)]),
-    ((3176, false, AND2), &[Tv(1845), Tv(3175)]),
-    ((3178, false, NAND2), &[Arg(2, 769), Tv(3066)]),
-    ((3180, false, AND2), &[Tv(1728), Tv(3179)]),
-    ((3183, false, AND2), &[Tv(127), Tv(1665)]),
-    ((3185, false, OR2), &[Arg(2, 913), Tv(1727)]),
-    ((3240, false, NAND2), &[Tv(128), Tv(1665)]),
-    ((3290, false, NAND2), &[Tv(3285), Tv(3289)]),
-    ((3347, false, NAND2), &[Tv(129), Tv(1665)]),
-    ((3397, false, NAND2), &[Tv(3392), Tv(3396)]),
-    ((3454, false, NAND2), &[Tv(130), Tv(1665)]),
-    ((3504, false, NAND2), &[Tv(3499), Tv(3503)]),
-    ((3561, false, NAND2), &[Tv(131), Tv(1665)]),
-    ((3611, false, NAND2), &[Tv(3606), Tv(3610)]),
-    ((3668, false, NAND2), &[Tv(132), Tv(1665)]),
-    ((3718, false, NAND2), &[Tv(3713), Tv(3717)]),
-    ((3775, false, NAND2), &[Tv(133), Tv(1665)]),
-    ((3825, false, NAND2), &[Tv(3820), Tv(3824)]),
-    ((3882, false, NAND2), &[Tv(15), Tv(1665)]),
-    ((3932, false, NAND2), &[Tv(3927), Tv(3931)]),
-    ((3989, false, NAND2), &[Tv(14), Tv(1665)]),
-    ((4039, false, NAND2), &[Tv(4034), Tv(4038)]),
-    ((4096, false, NAND2), &[Tv(12), Tv(1665)]),
-    ((4146, false, NAND2), &[Tv(4141), Tv(4145)]),
-    ((4203, false, NAND2), &[Tv(13), Tv(1665)]),
-    ((4253, false, NAND2), &[Tv(4248), Tv(4252)]),
-    ((4310, false, NAND2), &[Tv(8), Tv(1665)]),
-    ((4360, false, NAND2), &[Tv(4355), Tv(4359)]),
-    ((4417, false, NAND2), &[Tv(9), Tv(1665)]),
-    ((4467, false, NAND2), &[Tv(4462), Tv(4466)]),
-    ((4524, false, NAND2), &[Tv(10), Tv(1665)]),
-    ((4574, false, NAND2), &[Tv(4569), Tv(4573)]),
-    ((4631, false, NAND2), &[Tv(11), Tv(1665)]),
-    ((4681, false, NAND2), &[Tv(4676), Tv(4680)]),
-    ((4738, false, NAND2), &[Tv(245), Tv(1665)]),
-    ((4788, false, NAND2), &[Tv(4783), Tv(4787)]),
-    ((4845, false, NAND2), &[Tv(246), Tv(1665)]),
-    ((4895, false, NAND2), &[Tv(4890), Tv(4894)]),
-    ((4952, false, NAND2), &[Tv(247), Tv(1665)]),
-    ((5002, false, NAND2), &[Tv(4997), Tv(5001)]),
-    ((5059, false, NAND2), &[Tv(248), Tv(1665)]),
-    ((5109, false, NAND2), &[Tv(5104), Tv(5108)]),
-    ((5166, false, NAND2), &[Tv(249), Tv(1665)]),
-    ((5216, false, NAND2), &[Tv(5211), Tv(5215)]),
-    ((5273, false, NAND2), &[Tv(250), Tv(1665)]),
-    ((5323, false, NAND2), &[Tv(5318), Tv(5322)]),
-    ((5380, false, NAND2), &[Tv(251), Tv(1665)]),
-    ((5430, false, NAND2), &[Tv(5425), Tv(5429)]),
-    ((5487, false, NAND2), &[Tv(252), Tv(1665)]),
-    ((5537, false, NAND2), &[Tv(5532), Tv(5536)]),
-    ((5594, false, NAND2), &[Tv(341), Tv(1665)]),
-    ((5644, false, NAND2), &[Tv(5639), Tv(5643)]),
-    ((5701, false, NAND2), &[Tv(342), Tv(1665)]),
-    ((5751, false, NAND2), &[Tv(5746), Tv(5750)]),
-    ((5808, false, NAND2), &[Tv(343), Tv(1665)]),
-    ((5858, false, NAND2), &[Tv(5853), Tv(5857)]),
-    ((5915, false, NAND2), &[Tv(344), Tv(1665)]),
-    ((5965, false, NAND2), &[Tv(5960), Tv(5964)]),
-    ((6022, false, NAND2), &[Tv(345), Tv(1665)]),
-    ((6072, false, NAND2), &[Tv(6067), Tv(6071)]),
-    ((6129, false, NAND2), &[Tv(346), Tv(1665)]),
-    ((6179, false, NAND2), &[Tv(6174), Tv(6178)]),
-    ((6236, false, NAND2), &[Tv(347), Tv(1665)]),
-    ((6286, false, NAND2), &[Tv(6281), Tv(6285)]),
-    ((6343, false, NAND2), &[Tv(348), Tv(1665)]),
-    ((6393, false, NAND2), &[Tv(6388), Tv(6392)]),
-    ((383, false, INV), &[Arg(0, 2)]),
-    ((556, false, INV), &[Arg(3, 157)]),
-    ((557, false, INV), &[Arg(3, 159)]),
-    ((932, false, NAND2), &[Tv(408), Arg(3, 21)]),
-    ((933, false, NAND2), &[Tv(389), Arg(3, 8)]),
-    ((938, false, AND2), &[Tv(936), Tv(937)]),
-    ((939, false, XNOR2), &[Arg(1, 5), Arg(3, 13)]),
-    ((943, false, AND2), &[Tv(941), Tv(942)]),
-    ((946, false, AND2), &[Tv(944), Tv(945)]),
-    ((951, false, XNOR2), &[Arg(1, 9), Arg(3, 17)]),
-    ((952, false, XNOR2), &[Arg(1, 11), Arg(3, 19)]),
-    ((961, false, AND2), &[Tv(957), Tv(960)]),
-    ((967, false, AND2), &[Tv(964), Tv(966)]),
-    ((973, false, AND2), &[Tv(971), Tv(972)]),
-    ((978, false, AND2), &[Tv(974), Tv(977)]),
-    ((1177, false, AND2), &[Tv(1175), Tv(1176)]),
-    ((1191, false, NAND2), &[Tv(394), Arg(3, 111)]),
-    ((1195, false, XNOR2), &[Arg(1, 15), Arg(3, 121)]),
-    ((1197, false, AND2), &[Tv(1179), Tv(1196)]),
-    ((1204, false, AND2), &[Tv(1201), Tv(1203)]),
-    ((1213, false, AND2), &[Tv(1209), Tv(1212)]),
-    ((1215, false, AND2), &[Tv(1183), Tv(1214)]),
-    ((1219, false, AND2), &[Tv(1186), Tv(1218)]),
-    ((1230, false, NAND2), &[Tv(390), Arg(3, 58)]),
-    ((1231, false, NAND2), &[Arg(1, 8), Tv(541)]),
-    ((1233, false, NAND2), &[Arg(1, 3), Tv(539)]),
-    ((1240, false, NAND2), &[Arg(1, 13), Tv(542)]),
-    ((1241, false, NAND2), &[Arg(1, 14), Tv(543)]),
-    ((1249, false, AND2), &[Tv(1227), Tv(1232)]),
-    ((1255, false, AND2), &[Tv(1251), Tv(1254)]),
-    ((1256, false, AND2), &[Tv(1226), Tv(1229)]),
-    ((1259, false, XNOR2), &[Arg(1, 11), Arg(3, 68)]),
-    ((1261, false, AND2), &[Tv(1234), Tv(1260)]),
-    ((1265, false, AND2), &[Tv(1263), Tv(1264)]),
-    ((1267, false, AND2), &[Tv(1238), Tv(1266)]),
-    ((1278, false, NAND2), &[Tv(395), Arg(3, 308)]),
-    ((1280, false, NAND2), &[Arg(1, 2), Tv(589)]),
-    ((1608, false, AND2), &[Tv(1548), Tv(1607)]),
-    ((2662, false, AND2), &[Tv(2660), Tv(2661)]),
-    ((2665, false, AND2), &[Tv(2663), Tv(2664)]),
-    ((2669, false, AND2), &[Tv(2667), Tv(2668)]),
-    ((2672, false, AND2), &[Tv(2670), Tv(2671)]),
-    ((2677, false, AND2), &[Tv(2675), Tv(2676)]),
-    ((2680, false, AND2), &[Tv(2678), Tv(2679)]),
-    ((2690, false, AND2), &[Tv(2686), Tv(2689)]),
-    ((2701, false, AND2), &[Tv(2693), Tv(2700)]),
+    ((1949, false, AND2), &[Tv(1947), Tv(1948)]),
+    ((1973, false, AND2), &[Tv(1971), Tv(1972)]),
+    ((1996, false, AND2), &[Tv(1994), Tv(1995)]),
+    ((2020, false, AND2), &[Tv(2018), Tv(2019)]),
+    ((2043, false, AND2), &[Tv(2041), Tv(2042)]),
+    ((2067, false, AND2), &[Tv(2065), Tv(2066)]),
+    ((2090, false, AND2), &[Tv(2088), Tv(2089)]),
+    ((5890, false, INV), &[Tv(5889)]),
+    ((5895, false, NOR2), &[Tv(5869), Tv(5894)]),
+    ((5900, false, NOR2), &[Tv(5871), Tv(5899)]),
+    ((5905, false, NOR2), &[Tv(5873), Tv(5904)]),
+    ((5910, false, NOR2), &[Tv(5875), Tv(5909)]),
+    ((5915, false, NOR2), &[Tv(5877), Tv(5914)]),
+    ((5921, false, NOR2), &[Tv(5919), Tv(5920)]),
+    ((5924, false, NAND2), &[Tv(5923), Tv(5896)]),
+    ((5945, false, NAND2), &[Tv(5923), Tv(5932)]),
+    ((5978, false, INV), &[Tv(5977)]),
+    ((5982, false, NOR2), &[Tv(5967), Tv(5981)]),
+    ((5985, false, NOR2), &[Tv(5969), Tv(5984)]),
+    ((5988, false, NOR2), &[Tv(5971), Tv(5987)]),
+    ((5991, false, NOR2), &[Tv(5973), Tv(5990)]),
+    ((5995, false, NOR2), &[Tv(5993), Tv(5994)]),
+    ((5997, false, NAND2), &[Tv(5896), Tv(5996)]),
+    ((6011, false, NAND2), &[Tv(5932), Tv(5996)]),
+    ((6025, false, XOR2), &[Tv(5955), Tv(6018)]),
+    ((6087, false, INV), &[Tv(6086)]),
+    ((6092, false, NOR2), &[Tv(6066), Tv(6091)]),
+    ((6097, false, NOR2), &[Tv(6068), Tv(6096)]),
+    ((6102, false, NOR2), &[Tv(6070), Tv(6101)]),
+    ((6107, false, NOR2), &[Tv(6072), Tv(6106)]),
+    ((6112, false, NOR2), &[Tv(6074), Tv(6111)]),
+    ((6118, false, NOR2), &[Tv(6116), Tv(6117)]),
+    ((6121, false, NAND2), &[Tv(6120), Tv(6093)]),
+    ((6142, false, NAND2), &[Tv(6120), Tv(6129)]),
+    ((6175, false, INV), &[Tv(6174)]),
+    ((6179, false, NOR2), &[Tv(6164), Tv(6178)]),
+    ((6182, false, NOR2), &[Tv(6166), Tv(6181)]),
+    ((6185, false, NOR2), &[Tv(6168), Tv(6184)]),
+    ((6188, false, NOR2), &[Tv(6170), Tv(6187)]),
+    ((6192, false, NOR2), &[Tv(6190), Tv(6191)]),
+    ((6194, false, NAND2), &[Tv(6093), Tv(6193)]),
+    ((6208, false, NAND2), &[Tv(6129), Tv(6193)]),
+    ((6222, false, XOR2), &[Tv(6152), Tv(6215)]),
 ];
 
-static LEVEL_25: [((usize, bool, CellType), &[GateInput]); 125] = [
-    ((2837, false, NAND2), &[Arg(0, 11), Tv(406)]),
-    ((2846, false, NAND2), &[Tv(2838), Tv(2845)]),
-    ((2876, false, AND2), &[Tv(2840), Tv(2875)]),
-    ((2898, false, NAND2), &[Tv(383), Arg(1, 2)]),
-    ((2905, false, NAND2), &[Tv(2899), Tv(2904)]),
-    ((2915, false, AND2), &[Arg(0, 0), Tv(389)]),
-    ((3065, false, NAND2), &[Tv(3059), Tv(3064)]),
-    ((3070, false, AND2), &[Tv(3067), Tv(3069)]),
-    ((3073, false, NAND2), &[Tv(1727), Tv(3072)]),
-    ((3075, false, AND2), &[Tv(1608), Tv(3074)]),
-    ((3079, false, NOR2), &[Tv(1547), Tv(1607)]),
-    ((3177, false, NAND2), &[Tv(3174), Tv(3176)]),
-    ((3181, false, AND2), &[Tv(3178), Tv(3180)]),
-    ((3184, false, NAND2), &[Tv(1727), Tv(3183)]),
-    ((3186, false, AND2), &[Tv(1608), Tv(3185)]),
-    ((3291, false, NAND2), &[Tv(3240), Tv(3290)]),
-    ((3293, false, OR2), &[Arg(2, 914), Tv(1727)]),
-    ((3398, false, NAND2), &[Tv(3347), Tv(3397)]),
-    ((3400, false, OR2), &[Arg(2, 915), Tv(1727)]),
-    ((3505, false, NAND2), &[Tv(3454), Tv(3504)]),
-    ((3507, false, OR2), &[Arg(2, 916), Tv(1727)]),
-    ((3612, false, NAND2), &[Tv(3561), Tv(3611)]),
-    ((3614, false, OR2), &[Arg(2, 917), Tv(1727)]),
-    ((3719, false, NAND2), &[Tv(3668), Tv(3718)]),
-    ((3721, false, OR2), &[Arg(2, 918), Tv(1727)]),
-    ((3826, false, NAND2), &[Tv(3775), Tv(3825)]),
-    ((3828, false, OR2), &[Arg(2, 919), Tv(1727)]),
-    ((3933, false, NAND2), &[Tv(3882), Tv(3932)]),
-    ((3935, false, OR2), &[Arg(2, 936), Tv(1727)]),
-    ((4040, false, NAND2), &[Tv(3989), Tv(4039)]),
-    ((4042, false, OR2), &[Arg(2, 937), Tv(1727)]),
-    ((4147, false, NAND2), &[Tv(4096), Tv(4146)]),
-    ((4149, false, OR2), &[Arg(2, 938), Tv(1727)]),
-    ((4254, false, NAND2), &[Tv(4203), Tv(4253)]),
-    ((4256, false, OR2), &[Arg(2, 939), Tv(1727)]),
-    ((4361, false, NAND2), &[Tv(4310), Tv(4360)]),
-    ((4363, false, OR2), &[Arg(2, 940), Tv(1727)]),
-    ((4468, false, NAND2), &[Tv(4417), Tv(4467)]),
-    ((4470, false, OR2), &[Arg(2, 941), Tv(1727)]),
-    ((4575, false, NAND2), &[Tv(4524), Tv(4574)]),
-    ((4577, false, OR2), &[Arg(2, 942), Tv(1727)]),
-    ((4682, false, NAND2), &[Tv(4631), Tv(4681)]),
-    ((4684, false, OR2), &[Arg(2, 943), Tv(1727)]),
-    ((4789, false, NAND2), &[Tv(4738), Tv(4788)]),
-    ((4791, false, OR2), &[Arg(2, 944), Tv(1727)]),
-    ((4896, false, NAND2), &[Tv(4845), Tv(4895)]),
-    ((4898, false, OR2), &[Arg(2, 945), Tv(1727)]),
-    ((5003, false, NAND2), &[Tv(4952), Tv(5002)]),
-    ((5005, false, OR2), &[Arg(2, 946), Tv(1727)]),
-    ((5110, false, NAND2), &[Tv(5059), Tv(5109)]),
-    ((5112, false, OR2), &[Arg(2, 947), Tv(1727)]),
-    ((5217, false, NAND2), &[Tv(5166), Tv(5216)]),
-    ((5219, false, OR2), &[Arg(2, 948), Tv(1727)]),
-    ((5324, false, NAND2), &[Tv(5273), Tv(5323)]),
-    ((5326, false, OR2), &[Arg(2, 949), Tv(1727)]),
-    ((5431, false, NAND2), &[Tv(5380), Tv(5430)]),
-    ((5433, false, OR2), &[Arg(2, 950), Tv(1727)]),
-    ((5538, false, NAND2), &[Tv(5487), Tv(5537)]),
-    ((5540, false, OR2), &[Arg(2, 951), Tv(1727)]),
-    ((5645, false, NAND2), &[Tv(5594), Tv(5644)]),
-    ((5647, false, OR2), &[Arg(2, 952), Tv(1727)]),
-    ((5752, false, NAND2), &[Tv(5701), Tv(5751)]),
-    ((5754, false, OR2), &[Arg(2, 953), Tv(1727)]),
-    ((5859, false, NAND2), &[Tv(5808), Tv(5858)]),
-    ((5861, false, OR2), &[Arg(2, 954), Tv(1727)]),
-    ((5966, false, NAND2), &[Tv(5915), Tv(5965)]),
-    ((5968, false, OR2), &[Arg(2, 955), Tv(1727)]),
-    ((6073, false, NAND2), &[Tv(6022), Tv(6072)]),
-    ((6075, false, OR2), &[Arg(2, 956), Tv(1727)]),
-    ((6180, false, NAND2), &[Tv(6129), Tv(6179)]),
-    ((6182, false, OR2), &[Arg(2, 957), Tv(1727)]),
-    ((6287, false, NAND2), &[Tv(6236), Tv(6286)]),
-    ((6289, false, OR2), &[Arg(2, 958), Tv(1727)]),
-    ((6394, false, NAND2), &[Tv(6343), Tv(6393)]),
-    ((6396, false, OR2), &[Arg(2, 959), Tv(1727)]),
-    ((399, false, INV), &[Arg(0, 11)]),
-    ((555, false, INV), &[Arg(3, 155)]),
-    ((559, false, INV), &[Arg(3, 163)]),
-    ((560, false, INV), &[Arg(3, 165)]),
-    ((561, false, INV), &[Arg(3, 169)]),
-    ((564, false, INV), &[Arg(3, 205)]),
-    ((565, false, INV), &[Arg(3, 206)]),
-    ((567, false, INV), &[Arg(3, 208)]),
-    ((569, false, INV), &[Arg(3, 212)]),
-    ((571, false, INV), &[Arg(3, 217)]),
-    ((578, false, INV), &[Arg(3, 257)]),
-    ((579, false, INV), &[Arg(3, 258)]),
-    ((580, false, INV), &[Arg(3, 259)]),
-    ((584, false, INV), &[Arg(3, 263)]),
-    ((585, false, INV), &[Arg(3, 264)]),
-    ((587, false, INV), &[Arg(3, 302)]),
-    ((588, false, INV), &[Arg(3, 303)]),
-    ((590, false, INV), &[Arg(3, 305)]),
-    ((592, false, INV), &[Arg(3, 308)]),
-    ((594, false, INV), &[Arg(3, 315)]),
-    ((596, false, INV), &[Arg(3, 334)]),
-    ((931, false, NAND2), &[Tv(393), Arg(3, 12)]),
-    ((934, false, AND2), &[Tv(932), Tv(933)]),
-    ((940, false, AND2), &[Tv(938), Tv(939)]),
-    ((947, false, AND2), &[Tv(943), Tv(946)]),
-    ((950, false, XNOR2), &[Arg(1, 12), Arg(3, 20)]),
-    ((953, false, AND2), &[Tv(951), Tv(952)]),
-    ((968, false, AND2), &[Tv(961), Tv(967)]),
-    ((979, false, AND2), &[Tv(973), Tv(978)]),
-    ((990, false, NAND2), &[Arg(1, 4), Tv(557)]),
-    ((991, false, NAND2), &[Arg(1, 2), Tv(556)]),
-    ((1198, false, AND2), &[Tv(1195), Tv(1197)]),
-    ((1205, false, AND2), &[Tv(1177), Tv(1204)]),
-    ((1216, false, AND2), &[Tv(1213), Tv(1215)]),
-    ((1220, false, AND2), &[Tv(1191), Tv(1219)]),
-    ((1242, false, AND2), &[Tv(1240), Tv(1241)]),
-    ((1243, false, XNOR2), &[Arg(1, 7), Arg(3, 64)]),
-    ((1244, false, AND2), &[Tv(1230), Tv(1233)]),
-    ((1246, false, XNOR2), &[Arg(1, 12), Arg(3, 69)]),
-    ((1250, false, AND2), &[Tv(1231), Tv(1249)]),
-    ((1257, false, AND2), &[Tv(1255), Tv(1256)]),
-    ((1262, false, AND2), &[Tv(1259), Tv(1261)]),
-    ((1268, false, AND2), &[Tv(1265), Tv(1267)]),
-    ((1281, false, NAND2), &[Tv(408), Arg(3, 315)]),
-    ((1309, false, AND2), &[Tv(1278), Tv(1280)]),
-    ((1310, false, XNOR2), &[Arg(1, 4), Arg(3, 306)]),
-    ((2666, false, NAND2), &[Tv(2662), Tv(2665)]),
-    ((2673, false, AND2), &[Tv(2669), Tv(2672)]),
-    ((2681, false, AND2), &[Tv(2677), Tv(2680)]),
-    ((2702, false, AND2), &[Tv(2690), Tv(2701)]),
+static LEVEL_5: [((usize, bool, CellType), &[GateInput]); 61] = [
+    ((97, false, AND2), &[Tv(73), Tv(96)]),
+    ((152, false, AND2), &[Tv(128), Tv(151)]),
+    ((207, false, AND2), &[Tv(183), Tv(206)]),
+    ((262, false, AND2), &[Tv(238), Tv(261)]),
+    ((317, false, AND2), &[Tv(293), Tv(316)]),
+    ((372, false, AND2), &[Tv(348), Tv(371)]),
+    ((427, false, AND2), &[Tv(403), Tv(426)]),
+    ((482, false, AND2), &[Tv(458), Tv(481)]),
+    ((537, false, AND2), &[Tv(513), Tv(536)]),
+    ((592, false, AND2), &[Tv(568), Tv(591)]),
+    ((647, false, AND2), &[Tv(623), Tv(646)]),
+    ((702, false, AND2), &[Tv(678), Tv(701)]),
+    ((757, false, AND2), &[Tv(733), Tv(756)]),
+    ((812, false, AND2), &[Tv(788), Tv(811)]),
+    ((867, false, AND2), &[Tv(843), Tv(866)]),
+    ((922, false, AND2), &[Tv(898), Tv(921)]),
+    ((977, false, AND2), &[Tv(953), Tv(976)]),
+    ((1032, false, AND2), &[Tv(1008), Tv(1031)]),
+    ((1087, false, AND2), &[Tv(1063), Tv(1086)]),
+    ((1142, false, AND2), &[Tv(1118), Tv(1141)]),
+    ((1197, false, AND2), &[Tv(1173), Tv(1196)]),
+    ((1252, false, AND2), &[Tv(1228), Tv(1251)]),
+    ((1307, false, AND2), &[Tv(1283), Tv(1306)]),
+    ((1362, false, AND2), &[Tv(1338), Tv(1361)]),
+    ((1411, false, AND2), &[Tv(1387), Tv(1410)]),
+    ((1460, false, AND2), &[Tv(1436), Tv(1459)]),
+    ((1509, false, AND2), &[Tv(1485), Tv(1508)]),
+    ((1558, false, AND2), &[Tv(1534), Tv(1557)]),
+    ((1607, false, AND2), &[Tv(1583), Tv(1606)]),
+    ((1656, false, AND2), &[Tv(1632), Tv(1655)]),
+    ((1705, false, AND2), &[Tv(1681), Tv(1704)]),
+    ((1754, false, AND2), &[Tv(1730), Tv(1753)]),
+    ((1803, false, AND2), &[Tv(1779), Tv(1802)]),
+    ((1852, false, AND2), &[Tv(1828), Tv(1851)]),
+    ((1901, false, AND2), &[Tv(1877), Tv(1900)]),
+    ((1950, false, AND2), &[Tv(1926), Tv(1949)]),
+    ((1997, false, NAND2), &[Tv(1973), Tv(1996)]),
+    ((2044, false, AND2), &[Tv(2020), Tv(2043)]),
+    ((2091, false, AND2), &[Tv(2067), Tv(2090)]),
+    ((5891, false, NOR2), &[Tv(5867), Tv(5890)]),
+    ((5925, false, AND2), &[Tv(5895), Tv(5924)]),
+    ((5929, false, INV), &[Tv(5895)]),
+    ((5933, false, INV), &[Tv(5900)]),
+    ((5937, false, INV), &[Tv(5905)]),
+    ((5941, false, INV), &[Tv(5910)]),
+    ((5979, false, NOR2), &[Tv(5965), Tv(5978)]),
+    ((5998, false, AND2), &[Tv(5982), Tv(5997)]),
+    ((6002, false, INV), &[Tv(5982)]),
+    ((6005, false, INV), &[Tv(5985)]),
+    ((6008, false, INV), &[Tv(5988)]),
+    ((6088, false, NOR2), &[Tv(6064), Tv(6087)]),
+    ((6122, false, AND2), &[Tv(6092), Tv(6121)]),
+    ((6126, false, INV), &[Tv(6092)]),
+    ((6130, false, INV), &[Tv(6097)]),
+    ((6134, false, INV), &[Tv(6102)]),
+    ((6138, false, INV), &[Tv(6107)]),
+    ((6176, false, NOR2), &[Tv(6162), Tv(6175)]),
+    ((6195, false, AND2), &[Tv(6179), Tv(6194)]),
+    ((6199, false, INV), &[Tv(6179)]),
+    ((6202, false, INV), &[Tv(6182)]),
+    ((6205, false, INV), &[Tv(6185)]),
 ];
 
-static LEVEL_26: [((usize, bool, CellType), &[GateInput]); 212] = [
-    ((2836, false, NAND2), &[Tv(399), Arg(1, 11)]),
-    ((2847, false, NAND2), &[Tv(2837), Tv(2846)]),
-    ((2877, false, NAND2), &[Tv(2838), Tv(2876)]),
-    ((2897, false, NAND2), &[Arg(0, 3), Tv(392)]),
-    ((2906, false, NAND2), &[Tv(2898), Tv(2905)]),
-    ((2937, false, NAND2), &[Tv(2900), Tv(2915)]),
-    ((3071, false, NAND2), &[Tv(3065), Tv(3070)]),
-    ((3076, false, AND2), &[Tv(3073), Tv(3075)]),
-    ((3078, false, NAND2), &[Arg(2, 1008), Tv(1547)]),
-    ((3080, false, NAND2), &[Arg(2, 960), Tv(3079)]),
-    ((3182, false, NAND2), &[Tv(3177), Tv(3181)]),
-    ((3187, false, AND2), &[Tv(3184), Tv(3186)]),
-    ((3189, false, NAND2), &[Arg(2, 1009), Tv(1547)]),
-    ((3190, false, NAND2), &[Arg(2, 961), Tv(3079)]),
-    ((3292, false, NAND2), &[Tv(1727), Tv(3291)]),
-    ((3294, false, AND2), &[Tv(1608), Tv(3293)]),
-    ((3296, false, NAND2), &[Arg(2, 1010), Tv(1547)]),
-    ((3297, false, NAND2), &[Arg(2, 962), Tv(3079)]),
-    ((3399, false, NAND2), &[Tv(1727), Tv(3398)]),
-    ((3401, false, AND2), &[Tv(1608), Tv(3400)]),
-    ((3403, false, NAND2), &[Arg(2, 1011), Tv(1547)]),
-    ((3404, false, NAND2), &[Arg(2, 963), Tv(3079)]),
-    ((3506, false, NAND2), &[Tv(1727), Tv(3505)]),
-    ((3508, false, AND2), &[Tv(1608), Tv(3507)]),
-    ((3510, false, NAND2), &[Arg(2, 1012), Tv(1547)]),
-    ((3511, false, NAND2), &[Arg(2, 964), Tv(3079)]),
-    ((3613, false, NAND2), &[Tv(1727), Tv(3612)]),
-    ((3615, false, AND2), &[Tv(1608), Tv(3614)]),
-    ((3617, false, NAND2), &[Arg(2, 1013), Tv(1547)]),
-    ((3618, false, NAND2), &[Arg(2, 965), Tv(3079)]),
-    ((3720, false, NAND2), &[Tv(1727), Tv(3719)]),
-    ((3722, false, AND2), &[Tv(1608), Tv(3721)]),
-    ((3724, false, NAND2), &[Arg(2, 1014), Tv(1547)]),
-    ((3725, false, NAND2), &[Arg(2, 966), Tv(3079)]),
-    ((3827, false, NAND2), &[Tv(1727), Tv(3826)]),
-    ((3829, false, AND2), &[Tv(1608), Tv(3828)]),
-    ((3831, false, NAND2), &[Arg(2, 1015), Tv(1547)]),
-    ((3832, false, NAND2), &[Arg(2, 967), Tv(3079)]),
-    ((3934, false, NAND2), &[Tv(1727), Tv(3933)]),
-    ((3936, false, AND2), &[Tv(1608), Tv(3935)]),
-    ((3938, false, NAND2), &[Arg(2, 1032), Tv(1547)]),
-    ((3939, false, NAND2), &[Arg(2, 984), Tv(3079)]),
-    ((4041, false, NAND2), &[Tv(1727), Tv(4040)]),
-    ((4043, false, AND2), &[Tv(1608), Tv(4042)]),
-    ((4045, false, NAND2), &[Arg(2, 1033), Tv(1547)]),
-    ((4046, false, NAND2), &[Arg(2, 985), Tv(3079)]),
-    ((4148, false, NAND2), &[Tv(1727), Tv(4147)]),
-    ((4150, false, AND2), &[Tv(1608), Tv(4149)]),
-    ((4152, false, NAND2), &[Arg(2, 1034), Tv(1547)]),
-    ((4153, false, NAND2), &[Arg(2, 986), Tv(3079)]),
-    ((4255, false, NAND2), &[Tv(1727), Tv(4254)]),
-    ((4257, false, AND2), &[Tv(1608), Tv(4256)]),
-    ((4259, false, NAND2), &[Arg(2, 1035), Tv(1547)]),
-    ((4260, false, NAND2), &[Arg(2, 987), Tv(3079)]),
-    ((4362, false, NAND2), &[Tv(1727), Tv(4361)]),
-    ((4364, false, AND2), &[Tv(1608), Tv(4363)]),
-    ((4366, false, NAND2), &[Arg(2, 1036), Tv(1547)]),
-    ((4367, false, NAND2), &[Arg(2, 988), Tv(3079)]),
-    ((4469, false, NAND2), &[Tv(1727), Tv(4468)]),
-    ((4471, false, AND2), &[Tv(1608), Tv(4470)]),
-    ((4473, false, NAND2), &[Arg(2, 1037), Tv(1547)]),
-    ((4474, false, NAND2), &[Arg(2, 989), Tv(3079)]),
-    ((4576, false, NAND2), &[Tv(1727), Tv(4575)]),
-    ((4578, false, AND2), &[Tv(1608), Tv(4577)]),
-    ((4580, false, NAND2), &[Arg(2, 1038), Tv(1547)]),
-    ((4581, false, NAND2), &[Arg(2, 990), Tv(3079)]),
-    ((4683, false, NAND2), &[Tv(1727), Tv(4682)]),
-    ((4685, false, AND2), &[Tv(1608), Tv(4684)]),
-    ((4687, false, NAND2), &[Arg(2, 1039), Tv(1547)]),
-    ((4688, false, NAND2), &[Arg(2, 991), Tv(3079)]),
-    ((4790, false, NAND2), &[Tv(1727), Tv(4789)]),
-    ((4792, false, AND2), &[Tv(1608), Tv(4791)]),
-    ((4794, false, NAND2), &[Arg(2, 1040), Tv(1547)]),
-    ((4795, false, NAND2), &[Arg(2, 992), Tv(3079)]),
-    ((4897, false, NAND2), &[Tv(1727), Tv(4896)]),
-    ((4899, false, AND2), &[Tv(1608), Tv(4898)]),
-    ((4901, false, NAND2), &[Arg(2, 1041), Tv(1547)]),
-    ((4902, false, NAND2), &[Arg(2, 993), Tv(3079)]),
-    ((5004, false, NAND2), &[Tv(1727), Tv(5003)]),
-    ((5006, false, AND2), &[Tv(1608), Tv(5005)]),
-    ((5008, false, NAND2), &[Arg(2, 1042), Tv(1547)]),
-    ((5009, false, NAND2), &[Arg(2, 994), Tv(3079)]),
-    ((5111, false, NAND2), &[Tv(1727), Tv(5110)]),
-    ((5113, false, AND2), &[Tv(1608), Tv(5112)]),
-    ((5115, false, NAND2), &[Arg(2, 1043), Tv(1547)]),
-    ((5116, false, NAND2), &[Arg(2, 995), Tv(3079)]),
-    ((5218, false, NAND2), &[Tv(1727), Tv(5217)]),
-    ((5220, false, AND2), &[Tv(1608), Tv(5219)]),
-    ((5222, false, NAND2), &[Arg(2, 1044), Tv(1547)]),
-    ((5223, false, NAND2), &[Arg(2, 996), Tv(3079)]),
-    ((5325, false, NAND2), &[Tv(1727), Tv(5324)]),
-    ((5327, false, AND2), &[Tv(1608), Tv(5326)]),
-    ((5329, false, NAND2), &[Arg(2, 1045), Tv(1547)]),
-    ((5330, false, NAND2), &[Arg(2, 997), Tv(3079)]),
-    ((5432, false, NAND2), &[Tv(1727), Tv(5431)]),
-    ((5434, false, AND2), &[Tv(1608), Tv(5433)]),
-    ((5436, false, NAND2), &[Arg(2, 1046), Tv(1547)]),
-    ((5437, false, NAND2), &[Arg(2, 998), Tv(3079)]),
-    ((5539, false, NAND2), &[Tv(1727), Tv(5538)]),
-    ((5541, false, AND2), &[Tv(1608), Tv(5540)]),
-    ((5543, false, NAND2), &[Arg(2, 1047), Tv(1547)]),
-    ((5544, false, NAND2), &[Arg(2, 999), Tv(3079)]),
-    ((5646, false, NAND2), &[Tv(1727), Tv(5645)]),
-    ((5648, false, AND2), &[Tv(1608), Tv(5647)]),
-    ((5650, false, NAND2), &[Arg(2, 1048), Tv(1547)]),
-    ((5651, false, NAND2), &[Arg(2, 1000), Tv(3079)]),
-    ((5753, false, NAND2), &[Tv(1727), Tv(5752)]),
-    ((5755, false, AND2), &[Tv(1608), Tv(5754)]),
-    ((5757, false, NAND2), &[Arg(2, 1049), Tv(1547)]),
-    ((5758, false, NAND2), &[Arg(2, 1001), Tv(3079)]),
-    ((5860, false, NAND2), &[Tv(1727), Tv(5859)]),
-    ((5862, false, AND2), &[Tv(1608), Tv(5861)]),
-    ((5864, false, NAND2), &[Arg(2, 1050), Tv(1547)]),
-    ((5865, false, NAND2), &[Arg(2, 1002), Tv(3079)]),
-    ((5967, false, NAND2), &[Tv(1727), Tv(5966)]),
-    ((5969, false, AND2), &[Tv(1608), Tv(5968)]),
-    ((5971, false, NAND2), &[Arg(2, 1051), Tv(1547)]),
-    ((5972, false, NAND2), &[Arg(2, 1003), Tv(3079)]),
-    ((6074, false, NAND2), &[Tv(1727), Tv(6073)]),
-    ((6076, false, AND2), &[Tv(1608), Tv(6075)]),
-    ((6078, false, NAND2), &[Arg(2, 1052), Tv(1547)]),
-    ((6079, false, NAND2), &[Arg(2, 1004), Tv(3079)]),
-    ((6181, false, NAND2), &[Tv(1727), Tv(6180)]),
-    ((6183, false, AND2), &[Tv(1608), Tv(6182)]),
-    ((6185, false, NAND2), &[Arg(2, 1053), Tv(1547)]),
-    ((6186, false, NAND2), &[Arg(2, 1005), Tv(3079)]),
-    ((6288, false, NAND2), &[Tv(1727), Tv(6287)]),
-    ((6290, false, AND2), &[Tv(1608), Tv(6289)]),
-    ((6292, false, NAND2), &[Arg(2, 1054), Tv(1547)]),
-    ((6293, false, NAND2), &[Arg(2, 1006), Tv(3079)]),
-    ((6395, false, NAND2), &[Tv(1727), Tv(6394)]),
-    ((6397, false, AND2), &[Tv(1608), Tv(6396)]),
-    ((6399, false, NAND2), &[Arg(2, 1055), Tv(1547)]),
-    ((6400, false, NAND2), &[Arg(2, 1007), Tv(3079)]),
-    ((384, false, INV), &[Arg(0, 3)]),
-    ((558, false, INV), &[Arg(3, 161)]),
-    ((562, false, INV), &[Arg(3, 187)]),
-    ((563, false, INV), &[Arg(3, 204)]),
-    ((574, false, INV), &[Arg(3, 236)]),
-    ((575, false, INV), &[Arg(3, 253)]),
-    ((577, false, INV), &[Arg(3, 256)]),
-    ((581, false, INV), &[Arg(3, 260)]),
-    ((591, false, INV), &[Arg(3, 307)]),
-    ((593, false, INV), &[Arg(3, 310)]),
-    ((877, false, NAND2), &[Tv(391), Arg(3, 206)]),
-    ((878, false, NAND2), &[Arg(1, 1), Tv(564)]),
-    ((882, false, NAND2), &[Tv(393), Arg(3, 208)]),
-    ((883, false, NAND2), &[Arg(1, 4), Tv(567)]),
-    ((887, false, NAND2), &[Tv(411), Arg(3, 214)]),
-    ((890, false, NAND2), &[Tv(412), Arg(3, 219)]),
-    ((891, false, NAND2), &[Tv(395), Arg(3, 210)]),
-    ((892, false, NAND2), &[Tv(392), Arg(3, 207)]),
-    ((893, false, NAND2), &[Arg(1, 8), Tv(569)]),
-    ((894, false, NAND2), &[Arg(1, 2), Tv(565)]),
-    ((904, false, NAND2), &[Tv(389), Arg(3, 204)]),
-    ((918, false, NAND2), &[Tv(408), Arg(3, 217)]),
-    ((922, false, NAND2), &[Arg(1, 13), Tv(571)]),
-    ((935, false, AND2), &[Tv(931), Tv(934)]),
-    ((948, false, AND2), &[Tv(940), Tv(947)]),
-    ((954, false, AND2), &[Tv(950), Tv(953)]),
-    ((980, false, AND2), &[Tv(968), Tv(979)]),
-    ((984, false, NAND2), &[Arg(1, 8), Tv(559)]),
-    ((985, false, NAND2), &[Tv(389), Arg(3, 155)]),
-    ((986, false, NAND2), &[Tv(391), Arg(3, 157)]),
-    ((992, false, XNOR2), &[Arg(1, 15), Arg(3, 170)]),
-    ((994, false, XNOR2), &[Arg(1, 13), Arg(3, 168)]),
-    ((995, false, NAND2), &[Arg(1, 10), Tv(560)]),
-    ((996, false, NAND2), &[Tv(411), Arg(3, 165)]),
-    ((997, false, NAND2), &[Arg(1, 0), Tv(555)]),
-    ((998, false, XNOR2), &[Arg(1, 3), Arg(3, 158)]),
-    ((999, false, NAND2), &[Tv(395), Arg(3, 161)]),
-    ((1000, false, NAND2), &[Tv(393), Arg(3, 159)]),
-    ((1001, false, NAND2), &[Arg(1, 14), Tv(561)]),
-    ((1002, false, XNOR2), &[Arg(1, 5), Arg(3, 160)]),
-    ((1004, false, XNOR2), &[Arg(1, 1), Arg(3, 156)]),
-    ((1007, false, AND2), &[Tv(990), Tv(991)]),
-    ((1206, false, AND2), &[Tv(1198), Tv(1205)]),
-    ((1221, false, AND2), &[Tv(1216), Tv(1220)]),
-    ((1245, false, AND2), &[Tv(1243), Tv(1244)]),
-    ((1247, false, AND2), &[Tv(1242), Tv(1246)]),
-    ((1258, false, AND2), &[Tv(1250), Tv(1257)]),
-    ((1269, false, AND2), &[Tv(1262), Tv(1268)]),
-    ((1274, false, NAND2), &[Arg(1, 6), Tv(592)]),
-    ((1275, false, NAND2), &[Tv(394), Arg(3, 307)]),
-    ((1276, false, NAND2), &[Tv(392), Arg(3, 305)]),
-    ((1279, false, NAND2), &[Tv(391), Arg(3, 304)]),
-    ((1282, false, XNOR2), &[Arg(1, 9), Arg(3, 311)]),
-    ((1284, false, NAND2), &[Tv(409), Arg(3, 316)]),
-    ((1285, false, NAND2), &[Arg(1, 0), Tv(587)]),
-    ((1286, false, NAND2), &[Tv(389), Arg(3, 302)]),
-    ((1287, false, NAND2), &[Arg(1, 1), Tv(588)]),
-    ((1289, false, NAND2), &[Arg(1, 13), Tv(594)]),
-    ((1290, false, NAND2), &[Tv(390), Arg(3, 303)]),
-    ((1291, false, NAND2), &[Arg(1, 3), Tv(590)]),
-    ((1305, false, NAND2), &[Tv(404), Arg(3, 310)]),
-    ((1308, false, XNOR2), &[Arg(1, 15), Arg(3, 317)]),
-    ((1311, false, AND2), &[Tv(1309), Tv(1310)]),
-    ((1316, false, AND2), &[Tv(596), Tv(1281)]),
-    ((1326, false, NAND2), &[Arg(1, 10), Tv(584)]),
-    ((1328, false, NAND2), &[Tv(394), Arg(3, 258)]),
-    ((1330, false, NAND2), &[Tv(390), Arg(3, 254)]),
-    ((1331, false, NAND2), &[Arg(1, 4), Tv(578)]),
-    ((1332, false, NAND2), &[Arg(1, 6), Tv(580)]),
-    ((1333, false, NAND2), &[Arg(1, 11), Tv(585)]),
-    ((1338, false, NAND2), &[Tv(392), Arg(3, 256)]),
-    ((1340, false, NAND2), &[Tv(406), Arg(3, 264)]),
-    ((1342, false, NAND2), &[Tv(410), Arg(3, 260)]),
-    ((1343, false, NAND2), &[Arg(1, 5), Tv(579)]),
-    ((1345, false, NAND2), &[Tv(393), Arg(3, 257)]),
-    ((1348, false, NAND2), &[Tv(405), Arg(3, 262)]),
-    ((2674, false, AND2), &[Tv(2666), Tv(2673)]),
-    ((2703, false, AND2), &[Tv(2681), Tv(2702)]),
+static LEVEL_6: [((usize, bool, CellType), &[GateInput]); 131] = [
+    ((105, false, NAND2), &[Tv(97), Tv(104)]),
+    ((160, false, AND2), &[Tv(152), Tv(159)]),
+    ((215, false, NAND2), &[Tv(207), Tv(214)]),
+    ((270, false, AND2), &[Tv(262), Tv(269)]),
+    ((325, false, NAND2), &[Tv(317), Tv(324)]),
+    ((380, false, AND2), &[Tv(372), Tv(379)]),
+    ((435, false, NAND2), &[Tv(427), Tv(434)]),
+    ((490, false, AND2), &[Tv(482), Tv(489)]),
+    ((545, false, NAND2), &[Tv(537), Tv(544)]),
+    ((600, false, AND2), &[Tv(592), Tv(599)]),
+    ((655, false, NAND2), &[Tv(647), Tv(654)]),
+    ((710, false, AND2), &[Tv(702), Tv(709)]),
+    ((765, false, NAND2), &[Tv(757), Tv(764)]),
+    ((820, false, AND2), &[Tv(812), Tv(819)]),
+    ((875, false, NAND2), &[Tv(867), Tv(874)]),
+    ((930, false, AND2), &[Tv(922), Tv(929)]),
+    ((985, false, NAND2), &[Tv(977), Tv(984)]),
+    ((1040, false, AND2), &[Tv(1032), Tv(1039)]),
+    ((1095, false, NAND2), &[Tv(1087), Tv(1094)]),
+    ((1150, false, AND2), &[Tv(1142), Tv(1149)]),
+    ((1205, false, NAND2), &[Tv(1197), Tv(1204)]),
+    ((1260, false, AND2), &[Tv(1252), Tv(1259)]),
+    ((1315, false, NAND2), &[Tv(1307), Tv(1314)]),
+    ((1364, false, AND2), &[Tv(1363), Tv(1362)]),
+    ((1413, false, NAND2), &[Tv(1412), Tv(1411)]),
+    ((1462, false, AND2), &[Tv(1461), Tv(1460)]),
+    ((1511, false, NAND2), &[Tv(1510), Tv(1509)]),
+    ((1560, false, AND2), &[Tv(1559), Tv(1558)]),
+    ((1609, false, NAND2), &[Tv(1608), Tv(1607)]),
+    ((1658, false, AND2), &[Tv(1657), Tv(1656)]),
+    ((1707, false, NAND2), &[Tv(1706), Tv(1705)]),
+    ((1756, false, AND2), &[Tv(1755), Tv(1754)]),
+    ((1805, false, NAND2), &[Tv(1804), Tv(1803)]),
+    ((1854, false, AND2), &[Tv(1853), Tv(1852)]),
+    ((1903, false, NAND2), &[Tv(1902), Tv(1901)]),
+    ((3761, false, AND2), &[Tv(1950), Tv(3760)]),
+    ((3764, false, AND2), &[Tv(1950), Tv(3763)]),
+    ((3767, false, AND2), &[Tv(1950), Tv(3766)]),
+    ((3770, false, AND2), &[Tv(1950), Tv(3769)]),
+    ((3773, false, AND2), &[Tv(1950), Tv(3772)]),
+    ((3776, false, AND2), &[Tv(1950), Tv(3775)]),
+    ((3779, false, AND2), &[Tv(1950), Tv(3778)]),
+    ((3782, false, AND2), &[Tv(1950), Tv(3781)]),
+    ((3785, false, AND2), &[Tv(1950), Tv(3784)]),
+    ((3788, false, AND2), &[Tv(1950), Tv(3787)]),
+    ((3791, false, AND2), &[Tv(1950), Tv(3790)]),
+    ((3794, false, AND2), &[Tv(1950), Tv(3793)]),
+    ((3797, false, AND2), &[Tv(1950), Tv(3796)]),
+    ((3800, false, AND2), &[Tv(1950), Tv(3799)]),
+    ((3803, false, AND2), &[Tv(1950), Tv(3802)]),
+    ((3806, false, AND2), &[Tv(1950), Tv(3805)]),
+    ((3809, false, AND2), &[Tv(1950), Tv(3808)]),
+    ((3812, false, AND2), &[Tv(1950), Tv(3811)]),
+    ((3815, false, AND2), &[Tv(1950), Tv(3814)]),
+    ((3818, false, AND2), &[Tv(1950), Tv(3817)]),
+    ((3821, false, AND2), &[Tv(1950), Tv(3820)]),
+    ((3824, false, AND2), &[Tv(1950), Tv(3823)]),
+    ((3827, false, AND2), &[Tv(1950), Tv(3826)]),
+    ((3830, false, AND2), &[Tv(1950), Tv(3829)]),
+    ((3833, false, AND2), &[Tv(1950), Tv(3832)]),
+    ((3836, false, AND2), &[Tv(1950), Tv(3835)]),
+    ((3839, false, AND2), &[Tv(1950), Tv(3838)]),
+    ((3842, false, AND2), &[Tv(1950), Tv(3841)]),
+    ((3845, false, AND2), &[Tv(1950), Tv(3844)]),
+    ((3848, false, AND2), &[Tv(1950), Tv(3847)]),
+    ((3851, false, AND2), &[Tv(1950), Tv(3850)]),
+    ((3854, false, AND2), &[Tv(1950), Tv(3853)]),
+    ((3856, false, INV), &[Tv(1997)]),
+    ((3859, false, AND2), &[Tv(2044), Tv(3858)]),
+    ((3862, false, AND2), &[Tv(2044), Tv(3861)]),
+    ((3865, false, AND2), &[Tv(2044), Tv(3864)]),
+    ((3868, false, AND2), &[Tv(2044), Tv(3867)]),
+    ((3871, false, AND2), &[Tv(2044), Tv(3870)]),
+    ((3874, false, AND2), &[Tv(2044), Tv(3873)]),
+    ((3877, false, AND2), &[Tv(2044), Tv(3876)]),
+    ((3880, false, AND2), &[Tv(2044), Tv(3879)]),
+    ((3883, false, AND2), &[Tv(2044), Tv(3882)]),
+    ((3886, false, AND2), &[Tv(2044), Tv(3885)]),
+    ((3889, false, AND2), &[Tv(2044), Tv(3888)]),
+    ((3892, false, AND2), &[Tv(2044), Tv(3891)]),
+    ((3895, false, AND2), &[Tv(2044), Tv(3894)]),
+    ((3898, false, AND2), &[Tv(2044), Tv(3897)]),
+    ((3901, false, AND2), &[Tv(2044), Tv(3900)]),
+    ((3904, false, AND2), &[Tv(2044), Tv(3903)]),
+    ((3907, false, AND2), &[Tv(2044), Tv(3906)]),
+    ((3910, false, AND2), &[Tv(2044), Tv(3909)]),
+    ((3913, false, AND2), &[Tv(2044), Tv(3912)]),
+    ((3916, false, AND2), &[Tv(2044), Tv(3915)]),
+    ((3919, false, AND2), &[Tv(2044), Tv(3918)]),
+    ((3922, false, AND2), &[Tv(2044), Tv(3921)]),
+    ((3925, false, AND2), &[Tv(2044), Tv(3924)]),
+    ((3928, false, AND2), &[Tv(2044), Tv(3927)]),
+    ((3931, false, AND2), &[Tv(2044), Tv(3930)]),
+    ((3934, false, AND2), &[Tv(2044), Tv(3933)]),
+    ((3937, false, AND2), &[Tv(2044), Tv(3936)]),
+    ((3940, false, AND2), &[Tv(2044), Tv(3939)]),
+    ((3943, false, AND2), &[Tv(2044), Tv(3942)]),
+    ((3946, false, AND2), &[Tv(2044), Tv(3945)]),
+    ((3949, false, AND2), &[Tv(2044), Tv(3948)]),
+    ((3952, false, AND2), &[Tv(2044), Tv(3951)]),
+    ((4838, false, INV), &[Tv(2091)]),
+    ((5926, false, INV), &[Tv(5891)]),
+    ((5930, false, NAND2), &[Tv(5929), Tv(5906)]),
+    ((5934, false, NAND2), &[Tv(5933), Tv(5911)]),
+    ((5938, false, NAND2), &[Tv(5937), Tv(5916)]),
+    ((5942, false, NAND2), &[Tv(5941), Tv(5922)]),
+    ((5949, false, INV), &[Tv(5925)]),
+    ((5956, false, XOR2), &[Tv(5882), Tv(5891)]),
+    ((5957, false, XOR2), &[Tv(5883), Tv(5925)]),
+    ((5999, false, INV), &[Tv(5979)]),
+    ((6003, false, NAND2), &[Tv(5906), Tv(6002)]),
+    ((6006, false, NAND2), &[Tv(5911), Tv(6005)]),
+    ((6009, false, NAND2), &[Tv(5916), Tv(6008)]),
+    ((6015, false, INV), &[Tv(5998)]),
+    ((6019, false, XOR2), &[Tv(5882), Tv(5979)]),
+    ((6020, false, XOR2), &[Tv(5883), Tv(5998)]),
+    ((6123, false, INV), &[Tv(6088)]),
+    ((6127, false, NAND2), &[Tv(6126), Tv(6103)]),
+    ((6131, false, NAND2), &[Tv(6130), Tv(6108)]),
+    ((6135, false, NAND2), &[Tv(6134), Tv(6113)]),
+    ((6139, false, NAND2), &[Tv(6138), Tv(6119)]),
+    ((6146, false, INV), &[Tv(6122)]),
+    ((6153, false, XOR2), &[Tv(6079), Tv(6088)]),
+    ((6154, false, XOR2), &[Tv(6080), Tv(6122)]),
+    ((6196, false, INV), &[Tv(6176)]),
+    ((6200, false, NAND2), &[Tv(6103), Tv(6199)]),
+    ((6203, false, NAND2), &[Tv(6108), Tv(6202)]),
+    ((6206, false, NAND2), &[Tv(6113), Tv(6205)]),
+    ((6212, false, INV), &[Tv(6195)]),
+    ((6216, false, XOR2), &[Tv(6079), Tv(6176)]),
+    ((6217, false, XOR2), &[Tv(6080), Tv(6195)]),
 ];
 
-static LEVEL_27: [((usize, bool, CellType), &[GateInput]); 174] = [
-    ((2835, false, NAND2), &[Arg(0, 12), Tv(407)]),
-    ((2848, false, NAND2), &[Tv(2836), Tv(2847)]),
-    ((2878, false, NAND2), &[Tv(2839), Tv(2877)]),
-    ((2896, false, NAND2), &[Tv(384), Arg(1, 3)]),
-    ((2907, false, NAND2), &[Tv(2897), Tv(2906)]),
-    ((2938, false, NAND2), &[Tv(2901), Tv(2937)]),
-    ((3077, false, NAND2), &[Tv(3071), Tv(3076)]),
-    ((3081, false, AND2), &[Tv(3078), Tv(3080)]),
-    ((3188, false, NAND2), &[Tv(3182), Tv(3187)]),
-    ((3191, false, AND2), &[Tv(3189), Tv(3190)]),
-    ((3295, false, NAND2), &[Tv(3292), Tv(3294)]),
-    ((3298, false, AND2), &[Tv(3296), Tv(3297)]),
-    ((3402, false, NAND2), &[Tv(3399), Tv(3401)]),
-    ((3405, false, AND2), &[Tv(3403), Tv(3404)]),
-    ((3509, false, NAND2), &[Tv(3506), Tv(3508)]),
-    ((3512, false, AND2), &[Tv(3510), Tv(3511)]),
-    ((3616, false, NAND2), &[Tv(3613), Tv(3615)]),
-    ((3619, false, AND2), &[Tv(3617), Tv(3618)]),
-    ((3723, false, NAND2), &[Tv(3720), Tv(3722)]),
-    ((3726, false, AND2), &[Tv(3724), Tv(3725)]),
-    ((3830, false, NAND2), &[Tv(3827), Tv(3829)]),
-    ((3833, false, AND2), &[Tv(3831), Tv(3832)]),
-    ((3937, false, NAND2), &[Tv(3934), Tv(3936)]),
-    ((3940, false, AND2), &[Tv(3938), Tv(3939)]),
-    ((4044, false, NAND2), &[Tv(4041), Tv(4043)]),
-    ((4047, false, AND2), &[Tv(4045), Tv(4046)]),
-    ((4151, false, NAND2), &[Tv(4148), Tv(4150)]),
-    ((4154, false, AND2), &[Tv(4152), Tv(4153)]),
-    ((4258, false, NAND2), &[Tv(4255), Tv(4257)]),
-    ((4261, false, AND2), &[Tv(4259), Tv(4260)]),
-    ((4365, false, NAND2), &[Tv(4362), Tv(4364)]),
-    ((4368, false, AND2), &[Tv(4366), Tv(4367)]),
-    ((4472, false, NAND2), &[Tv(4469), Tv(4471)]),
-    ((4475, false, AND2), &[Tv(4473), Tv(4474)]),
-    ((4579, false, NAND2), &[Tv(4576), Tv(4578)]),
-    ((4582, false, AND2), &[Tv(4580), Tv(4581)]),
-    ((4686, false, NAND2), &[Tv(4683), Tv(4685)]),
-    ((4689, false, AND2), &[Tv(4687), Tv(4688)]),
-    ((4793, false, NAND2), &[Tv(4790), Tv(4792)]),
-    ((4796, false, AND2), &[Tv(4794), Tv(4795)]),
-    ((4900, false, NAND2), &[Tv(4897), Tv(4899)]),
-    ((4903, false, AND2), &[Tv(4901), Tv(4902)]),
-    ((5007, false, NAND2), &[Tv(5004), Tv(5006)]),
-    ((5010, false, AND2), &[Tv(5008), Tv(5009)]),
-    ((5114, false, NAND2), &[Tv(5111), Tv(5113)]),
-    ((5117, false, AND2), &[Tv(5115), Tv(5116)]),
-    ((5221, false, NAND2), &[Tv(5218), Tv(5220)]),
-    ((5224, false, AND2), &[Tv(5222), Tv(5223)]),
-    ((5328, false, NAND2), &[Tv(5325), Tv(5327)]),
-    ((5331, false, AND2), &[Tv(5329), Tv(5330)]),
-    ((5435, false, NAND2), &[Tv(5432), Tv(5434)]),
-    ((5438, false, AND2), &[Tv(5436), Tv(5437)]),
-    ((5542, false, NAND2), &[Tv(5539), Tv(5541)]),
-    ((5545, false, AND2), &[Tv(5543), Tv(5544)]),
-    ((5649, false, NAND2), &[Tv(5646), Tv(5648)]),
-    ((5652, false, AND2), &[Tv(5650), Tv(5651)]),
-    ((5756, false, NAND2), &[Tv(5753), Tv(5755)]),
-    ((5759, false, AND2), &[Tv(5757), Tv(5758)]),
-    ((5863, false, NAND2), &[Tv(5860), Tv(5862)]),
-    ((5866, false, AND2), &[Tv(5864), Tv(5865)]),
-    ((5970, false, NAND2), &[Tv(5967), Tv(5969)]),
-    ((5973, false, AND2), &[Tv(5971), Tv(5972)]),
-    ((6077, false, NAND2), &[Tv(6074), Tv(6076)]),
-    ((6080, false, AND2), &[Tv(6078), Tv(6079)]),
-    ((6184, false, NAND2), &[Tv(6181), Tv(6183)]),
-    ((6187, false, AND2), &[Tv(6185), Tv(6186)]),
-    ((6291, false, NAND2), &[Tv(6288), Tv(6290)]),
-    ((6294, false, AND2), &[Tv(6292), Tv(6293)]),
-    ((6398, false, NAND2), &[Tv(6395), Tv(6397)]),
-    ((6401, false, AND2), &[Tv(6399), Tv(6400)]),
-    ((0, false, INV), &[Arg(2, 1084)]),
-    ((1, false, INV), &[Arg(2, 1085)]),
-    ((2, false, INV), &[Arg(2, 1086)]),
-    ((3, false, INV), &[Arg(2, 1087)]),
-    ((4, false, INV), &[Arg(2, 1082)]),
-    ((5, false, INV), &[Arg(2, 1083)]),
-    ((6, false, INV), &[Arg(2, 1081)]),
-    ((7, false, INV), &[Arg(2, 1080)]),
-    ((134, false, INV), &[Arg(2, 1056)]),
-    ((135, false, INV), &[Arg(2, 1057)]),
-    ((136, false, INV), &[Arg(2, 1058)]),
-    ((137, false, INV), &[Arg(2, 1059)]),
-    ((138, false, INV), &[Arg(2, 1060)]),
-    ((139, false, INV), &[Arg(2, 1061)]),
-    ((140, false, INV), &[Arg(2, 1062)]),
-    ((141, false, INV), &[Arg(2, 1063)]),
-    ((253, false, INV), &[Arg(2, 1088)]),
-    ((254, false, INV), &[Arg(2, 1089)]),
-    ((255, false, INV), &[Arg(2, 1090)]),
-    ((256, false, INV), &[Arg(2, 1091)]),
-    ((257, false, INV), &[Arg(2, 1092)]),
-    ((258, false, INV), &[Arg(2, 1093)]),
-    ((259, false, INV), &[Arg(2, 1094)]),
-    ((260, false, INV), &[Arg(2, 1095)]),
-    ((349, false, INV), &[Arg(2, 1096)]),
-    ((350, false, INV), &[Arg(2, 1097)]),
-    ((351, false, INV), &[Arg(2, 1098)]),
-    ((352, false, INV), &[Arg(2, 1099)]),
-    ((353, false, INV), &[Arg(2, 1100)]),
-    ((354, false, INV), &[Arg(2, 1101)]),
-    ((355, false, INV), &[Arg(2, 1102)]),
-    ((356, false, INV), &[Arg(2, 1103)]),
-    ((400, false, INV), &[Arg(0, 12)]),
-    ((566, false, INV), &[Arg(3, 207)]),
-    ((568, false, INV), &[Arg(3, 210)]),
-    ((570, false, INV), &[Arg(3, 214)]),
-    ((572, false, INV), &[Arg(3, 218)]),
-    ((573, false, INV), &[Arg(3, 219)]),
-    ((576, false, INV), &[Arg(3, 254)]),
-    ((583, false, INV), &[Arg(3, 262)]),
-    ((586, false, INV), &[Arg(3, 285)]),
-    ((595, false, INV), &[Arg(3, 316)]),
-    ((628, false, INV), &[Arg(3, 500)]),
-    ((879, false, AND2), &[Tv(877), Tv(878)]),
-    ((886, false, XNOR2), &[Arg(1, 5), Arg(3, 209)]),
-    ((895, false, NAND2), &[Tv(409), Arg(3, 218)]),
-    ((905, false, AND2), &[Tv(887), Tv(904)]),
-    ((908, false, NAND2), &[Arg(1, 0), Tv(563)]),
-    ((909, false, AND2), &[Tv(883), Tv(892)]),
-    ((911, false, AND2), &[Tv(890), Tv(891)]),
-    ((912, false, AND2), &[Tv(882), Tv(894)]),
-    ((917, false, XNOR2), &[Arg(1, 9), Arg(3, 213)]),
-    ((919, false, AND2), &[Tv(574), Tv(918)]),
-    ((923, false, AND2), &[Tv(893), Tv(922)]),
-    ((924, false, XNOR2), &[Arg(1, 12), Arg(3, 216)]),
-    ((949, false, AND2), &[Tv(935), Tv(948)]),
-    ((981, false, AND2), &[Tv(954), Tv(980)]),
-    ((983, false, NAND2), &[Tv(404), Arg(3, 163)]),
-    ((987, false, AND2), &[Tv(985), Tv(986)]),
-    ((988, false, NAND2), &[Tv(409), Arg(3, 169)]),
-    ((989, false, NAND2), &[Arg(1, 6), Tv(558)]),
-    ((993, false, XNOR2), &[Arg(1, 12), Arg(3, 167)]),
-    ((1005, false, AND2), &[Tv(994), Tv(1004)]),
-    ((1008, false, XNOR2), &[Arg(1, 7), Arg(3, 162)]),
-    ((1010, false, AND2), &[Tv(984), Tv(996)]),
-    ((1011, false, XNOR2), &[Arg(1, 9), Arg(3, 164)]),
-    ((1013, false, AND2), &[Tv(1000), Tv(1002)]),
-    ((1016, false, AND2), &[Tv(995), Tv(1001)]),
-    ((1017, false, AND2), &[Tv(562), Tv(997)]),
-    ((1021, false, AND2), &[Tv(999), Tv(1007)]),
-    ((1022, false, AND2), &[Tv(992), Tv(998)]),
-    ((1222, false, AND2), &[Tv(1206), Tv(1221)]),
-    ((1248, false, AND2), &[Tv(1245), Tv(1247)]),
-    ((1270, false, AND2), &[Tv(1258), Tv(1269)]),
-    ((1277, false, NAND2), &[Arg(1, 5), Tv(591)]),
-    ((1283, false, XNOR2), &[Arg(1, 11), Arg(3, 313)]),
-    ((1288, false, XNOR2), &[Arg(1, 7), Arg(3, 309)]),
-    ((1293, false, AND2), &[Tv(1284), Tv(1286)]),
-    ((1294, false, XNOR2), &[Arg(1, 10), Arg(3, 312)]),
-    ((1297, false, AND2), &[Tv(1275), Tv(1279)]),
-    ((1298, false, XNOR2), &[Arg(1, 12), Arg(3, 314)]),
-    ((1300, false, AND2), &[Tv(1290), Tv(1291)]),
-    ((1304, false, NAND2), &[Arg(1, 8), Tv(593)]),
-    ((1306, false, AND2), &[Tv(1274), Tv(1305)]),
-    ((1312, false, AND2), &[Tv(1308), Tv(1311)]),
-    ((1313, false, AND2), &[Tv(1276), Tv(1285)]),
-    ((1317, false, AND2), &[Tv(1282), Tv(1316)]),
-    ((1319, false, AND2), &[Tv(1287), Tv(1289)]),
-    ((1325, false, NAND2), &[Tv(395), Arg(3, 259)]),
-    ((1335, false, NAND2), &[Arg(1, 0), Tv(575)]),
-    ((1337, false, NAND2), &[Arg(1, 7), Tv(581)]),
-    ((1339, false, NAND2), &[Tv(411), Arg(3, 263)]),
-    ((1341, false, NAND2), &[Arg(1, 3), Tv(577)]),
-    ((1347, false, NAND2), &[Tv(389), Arg(3, 253)]),
-    ((1354, false, XNOR2), &[Arg(1, 13), Arg(3, 266)]),
-    ((1355, false, AND2), &[Tv(1338), Tv(1348)]),
-    ((1357, false, AND2), &[Tv(1328), Tv(1332)]),
-    ((1362, false, XNOR2), &[Arg(1, 12), Arg(3, 265)]),
-    ((1363, false, AND2), &[Tv(1330), Tv(1333)]),
-    ((1365, false, AND2), &[Tv(1326), Tv(1340)]),
-    ((1368, false, AND2), &[Tv(1331), Tv(1343)]),
-    ((1372, false, AND2), &[Tv(1342), Tv(1345)]),
-    ((1373, false, XNOR2), &[Arg(1, 2), Arg(3, 255)]),
-    ((2704, false, NAND2), &[Tv(2674), Tv(2703)]),
+static LEVEL_7: [((usize, bool, CellType), &[GateInput]); 657] = [
+    ((2092, false, INV), &[Tv(105)]),
+    ((2095, false, AND2), &[Tv(160), Tv(2094)]),
+    ((2098, false, AND2), &[Tv(160), Tv(2097)]),
+    ((2101, false, AND2), &[Tv(160), Tv(2100)]),
+    ((2104, false, AND2), &[Tv(160), Tv(2103)]),
+    ((2107, false, AND2), &[Tv(160), Tv(2106)]),
+    ((2110, false, AND2), &[Tv(160), Tv(2109)]),
+    ((2113, false, AND2), &[Tv(160), Tv(2112)]),
+    ((2116, false, AND2), &[Tv(160), Tv(2115)]),
+    ((2119, false, AND2), &[Tv(160), Tv(2118)]),
+    ((2122, false, AND2), &[Tv(160), Tv(2121)]),
+    ((2125, false, AND2), &[Tv(160), Tv(2124)]),
+    ((2128, false, AND2), &[Tv(160), Tv(2127)]),
+    ((2131, false, AND2), &[Tv(160), Tv(2130)]),
+    ((2134, false, AND2), &[Tv(160), Tv(2133)]),
+    ((2137, false, AND2), &[Tv(160), Tv(2136)]),
+    ((2140, false, AND2), &[Tv(160), Tv(2139)]),
+    ((2143, false, AND2), &[Tv(160), Tv(2142)]),
+    ((2146, false, AND2), &[Tv(160), Tv(2145)]),
+    ((2149, false, AND2), &[Tv(160), Tv(2148)]),
+    ((2152, false, AND2), &[Tv(160), Tv(2151)]),
+    ((2155, false, AND2), &[Tv(160), Tv(2154)]),
+    ((2158, false, AND2), &[Tv(160), Tv(2157)]),
+    ((2161, false, AND2), &[Tv(160), Tv(2160)]),
+    ((2164, false, AND2), &[Tv(160), Tv(2163)]),
+    ((2167, false, AND2), &[Tv(160), Tv(2166)]),
+    ((2170, false, AND2), &[Tv(160), Tv(2169)]),
+    ((2173, false, AND2), &[Tv(160), Tv(2172)]),
+    ((2176, false, AND2), &[Tv(160), Tv(2175)]),
+    ((2179, false, AND2), &[Tv(160), Tv(2178)]),
+    ((2182, false, AND2), &[Tv(160), Tv(2181)]),
+    ((2185, false, AND2), &[Tv(160), Tv(2184)]),
+    ((2188, false, AND2), &[Tv(160), Tv(2187)]),
+    ((2190, false, INV), &[Tv(215)]),
+    ((2193, false, AND2), &[Tv(270), Tv(2192)]),
+    ((2196, false, AND2), &[Tv(270), Tv(2195)]),
+    ((2199, false, AND2), &[Tv(270), Tv(2198)]),
+    ((2202, false, AND2), &[Tv(270), Tv(2201)]),
+    ((2205, false, AND2), &[Tv(270), Tv(2204)]),
+    ((2208, false, AND2), &[Tv(270), Tv(2207)]),
+    ((2211, false, AND2), &[Tv(270), Tv(2210)]),
+    ((2214, false, AND2), &[Tv(270), Tv(2213)]),
+    ((2217, false, AND2), &[Tv(270), Tv(2216)]),
+    ((2220, false, AND2), &[Tv(270), Tv(2219)]),
+    ((2223, false, AND2), &[Tv(270), Tv(2222)]),
+    ((2226, false, AND2), &[Tv(270), Tv(2225)]),
+    ((2229, false, AND2), &[Tv(270), Tv(2228)]),
+    ((2232, false, AND2), &[Tv(270), Tv(2231)]),
+    ((2235, false, AND2), &[Tv(270), Tv(2234)]),
+    ((2238, false, AND2), &[Tv(270), Tv(2237)]),
+    ((2241, false, AND2), &[Tv(270), Tv(2240)]),
+    ((2244, false, AND2), &[Tv(270), Tv(2243)]),
+    ((2247, false, AND2), &[Tv(270), Tv(2246)]),
+    ((2250, false, AND2), &[Tv(270), Tv(2249)]),
+    ((2253, false, AND2), &[Tv(270), Tv(2252)]),
+    ((2256, false, AND2), &[Tv(270), Tv(2255)]),
+    ((2259, false, AND2), &[Tv(270), Tv(2258)]),
+    ((2262, false, AND2), &[Tv(270), Tv(2261)]),
+    ((2265, false, AND2), &[Tv(270), Tv(2264)]),
+    ((2268, false, AND2), &[Tv(270), Tv(2267)]),
+    ((2271, false, AND2), &[Tv(270), Tv(2270)]),
+    ((2274, false, AND2), &[Tv(270), Tv(2273)]),
+    ((2277, false, AND2), &[Tv(270), Tv(2276)]),
+    ((2280, false, AND2), &[Tv(270), Tv(2279)]),
+    ((2283, false, AND2), &[Tv(270), Tv(2282)]),
+    ((2286, false, AND2), &[Tv(270), Tv(2285)]),
+    ((2288, false, INV), &[Tv(325)]),
+    ((2291, false, AND2), &[Tv(380), Tv(2290)]),
+    ((2294, false, AND2), &[Tv(380), Tv(2293)]),
+    ((2297, false, AND2), &[Tv(380), Tv(2296)]),
+    ((2300, false, AND2), &[Tv(380), Tv(2299)]),
+    ((2303, false, AND2), &[Tv(380), Tv(2302)]),
+    ((2306, false, AND2), &[Tv(380), Tv(2305)]),
+    ((2309, false, AND2), &[Tv(380), Tv(2308)]),
+    ((2312, false, AND2), &[Tv(380), Tv(2311)]),
+    ((2315, false, AND2), &[Tv(380), Tv(2314)]),
+    ((2318, false, AND2), &[Tv(380), Tv(2317)]),
+    ((2321, false, AND2), &[Tv(380), Tv(2320)]),
+    ((2324, false, AND2), &[Tv(380), Tv(2323)]),
+    ((2327, false, AND2), &[Tv(380), Tv(2326)]),
+    ((2330, false, AND2), &[Tv(380), Tv(2329)]),
+    ((2333, false, AND2), &[Tv(380), Tv(2332)]),
+    ((2336, false, AND2), &[Tv(380), Tv(2335)]),
+    ((2339, false, AND2), &[Tv(380), Tv(2338)]),
+    ((2342, false, AND2), &[Tv(380), Tv(2341)]),
+    ((2345, false, AND2), &[Tv(380), Tv(2344)]),
+    ((2348, false, AND2), &[Tv(380), Tv(2347)]),
+    ((2351, false, AND2), &[Tv(380), Tv(2350)]),
+    ((2354, false, AND2), &[Tv(380), Tv(2353)]),
+    ((2357, false, AND2), &[Tv(380), Tv(2356)]),
+    ((2360, false, AND2), &[Tv(380), Tv(2359)]),
+    ((2363, false, AND2), &[Tv(380), Tv(2362)]),
+    ((2366, false, AND2), &[Tv(380), Tv(2365)]),
+    ((2369, false, AND2), &[Tv(380), Tv(2368)]),
+    ((2372, false, AND2), &[Tv(380), Tv(2371)]),
+    ((2375, false, AND2), &[Tv(380), Tv(2374)]),
+    ((2378, false, AND2), &[Tv(380), Tv(2377)]),
+    ((2381, false, AND2), &[Tv(380), Tv(2380)]),
+    ((2384, false, AND2), &[Tv(380), Tv(2383)]),
+    ((2386, false, INV), &[Tv(435)]),
+    ((2389, false, AND2), &[Tv(490), Tv(2388)]),
+    ((2392, false, AND2), &[Tv(490), Tv(2391)]),
+    ((2395, false, AND2), &[Tv(490), Tv(2394)]),
+    ((2398, false, AND2), &[Tv(490), Tv(2397)]),
+    ((2401, false, AND2), &[Tv(490), Tv(2400)]),
+    ((2404, false, AND2), &[Tv(490), Tv(2403)]),
+    ((2407, false, AND2), &[Tv(490), Tv(2406)]),
+    ((2410, false, AND2), &[Tv(490), Tv(2409)]),
+    ((2413, false, AND2), &[Tv(490), Tv(2412)]),
+    ((2416, false, AND2), &[Tv(490), Tv(2415)]),
+    ((2419, false, AND2), &[Tv(490), Tv(2418)]),
+    ((2422, false, AND2), &[Tv(490), Tv(2421)]),
+    ((2425, false, AND2), &[Tv(490), Tv(2424)]),
+    ((2428, false, AND2), &[Tv(490), Tv(2427)]),
+    ((2431, false, AND2), &[Tv(490), Tv(2430)]),
+    ((2434, false, AND2), &[Tv(490), Tv(2433)]),
+    ((2437, false, AND2), &[Tv(490), Tv(2436)]),
+    ((2440, false, AND2), &[Tv(490), Tv(2439)]),
+    ((2443, false, AND2), &[Tv(490), Tv(2442)]),
+    ((2446, false, AND2), &[Tv(490), Tv(2445)]),
+    ((2449, false, AND2), &[Tv(490), Tv(2448)]),
+    ((2452, false, AND2), &[Tv(490), Tv(2451)]),
+    ((2455, false, AND2), &[Tv(490), Tv(2454)]),
+    ((2458, false, AND2), &[Tv(490), Tv(2457)]),
+    ((2461, false, AND2), &[Tv(490), Tv(2460)]),
+    ((2464, false, AND2), &[Tv(490), Tv(2463)]),
+    ((2467, false, AND2), &[Tv(490), Tv(2466)]),
+    ((2470, false, AND2), &[Tv(490), Tv(2469)]),
+    ((2473, false, AND2), &[Tv(490), Tv(2472)]),
+    ((2476, false, AND2), &[Tv(490), Tv(2475)]),
+    ((2479, false, AND2), &[Tv(490), Tv(2478)]),
+    ((2482, false, AND2), &[Tv(490), Tv(2481)]),
+    ((2484, false, INV), &[Tv(545)]),
+    ((2487, false, AND2), &[Tv(600), Tv(2486)]),
+    ((2490, false, AND2), &[Tv(600), Tv(2489)]),
+    ((2493, false, AND2), &[Tv(600), Tv(2492)]),
+    ((2496, false, AND2), &[Tv(600), Tv(2495)]),
+    ((2499, false, AND2), &[Tv(600), Tv(2498)]),
+    ((2502, false, AND2), &[Tv(600), Tv(2501)]),
+    ((2505, false, AND2), &[Tv(600), Tv(2504)]),
+    ((2508, false, AND2), &[Tv(600), Tv(2507)]),
+    ((2511, false, AND2), &[Tv(600), Tv(2510)]),
+    ((2514, false, AND2), &[Tv(600), Tv(2513)]),
+    ((2517, false, AND2), &[Tv(600), Tv(2516)]),
+    ((2520, false, AND2), &[Tv(600), Tv(2519)]),
+    ((2523, false, AND2), &[Tv(600), Tv(2522)]),
+    ((2526, false, AND2), &[Tv(600), Tv(2525)]),
+    ((2529, false, AND2), &[Tv(600), Tv(2528)]),
+    ((2532, false, AND2), &[Tv(600), Tv(2531)]),
+    ((2535, false, AND2), &[Tv(600), Tv(2534)]),
+    ((2538, false, AND2), &[Tv(600), Tv(2537)]),
+    ((2541, false, AND2), &[Tv(600), Tv(2540)]),
+    ((2544, false, AND2), &[Tv(600), Tv(2543)]),
+    ((2547, false, AND2), &[Tv(600), Tv(2546)]),
+    ((2550, false, AND2), &[Tv(600), Tv(2549)]),
+    ((2553, false, AND2), &[Tv(600), Tv(2552)]),
+    ((2556, false, AND2), &[Tv(600), Tv(2555)]),
+    ((2559, false, AND2), &[Tv(600), Tv(2558)]),
+    ((2562, false, AND2), &[Tv(600), Tv(2561)]),
+    ((2565, false, AND2), &[Tv(600), Tv(2564)]),
+    ((2568, false, AND2), &[Tv(600), Tv(2567)]),
+    ((2571, false, AND2), &[Tv(600), Tv(2570)]),
+    ((2574, false, AND2), &[Tv(600), Tv(2573)]),
+    ((2577, false, AND2), &[Tv(600), Tv(2576)]),
+    ((2580, false, AND2), &[Tv(600), Tv(2579)]),
+    ((2582, false, INV), &[Tv(655)]),
+    ((2585, false, AND2), &[Tv(710), Tv(2584)]),
+    ((2588, false, AND2), &[Tv(710), Tv(2587)]),
+    ((2591, false, AND2), &[Tv(710), Tv(2590)]),
+    ((2594, false, AND2), &[Tv(710), Tv(2593)]),
+    ((2597, false, AND2), &[Tv(710), Tv(2596)]),
+    ((2600, false, AND2), &[Tv(710), Tv(2599)]),
+    ((2603, false, AND2), &[Tv(710), Tv(2602)]),
+    ((2606, false, AND2), &[Tv(710), Tv(2605)]),
+    ((2609, false, AND2), &[Tv(710), Tv(2608)]),
+    ((2612, false, AND2), &[Tv(710), Tv(2611)]),
+    ((2615, false, AND2), &[Tv(710), Tv(2614)]),
+    ((2618, false, AND2), &[Tv(710), Tv(2617)]),
+    ((2621, false, AND2), &[Tv(710), Tv(2620)]),
+    ((2624, false, AND2), &[Tv(710), Tv(2623)]),
+    ((2627, false, AND2), &[Tv(710), Tv(2626)]),
+    ((2630, false, AND2), &[Tv(710), Tv(2629)]),
+    ((2633, false, AND2), &[Tv(710), Tv(2632)]),
+    ((2636, false, AND2), &[Tv(710), Tv(2635)]),
+    ((2639, false, AND2), &[Tv(710), Tv(2638)]),
+    ((2642, false, AND2), &[Tv(710), Tv(2641)]),
+    ((2645, false, AND2), &[Tv(710), Tv(2644)]),
+    ((2648, false, AND2), &[Tv(710), Tv(2647)]),
+    ((2651, false, AND2), &[Tv(710), Tv(2650)]),
+    ((2654, false, AND2), &[Tv(710), Tv(2653)]),
+    ((2657, false, AND2), &[Tv(710), Tv(2656)]),
+    ((2660, false, AND2), &[Tv(710), Tv(2659)]),
+    ((2663, false, AND2), &[Tv(710), Tv(2662)]),
+    ((2666, false, AND2), &[Tv(710), Tv(2665)]),
+    ((2669, false, AND2), &[Tv(710), Tv(2668)]),
+    ((2672, false, AND2), &[Tv(710), Tv(2671)]),
+    ((2675, false, AND2), &[Tv(710), Tv(2674)]),
+    ((2678, false, AND2), &[Tv(710), Tv(2677)]),
+    ((2680, false, INV), &[Tv(765)]),
+    ((2683, false, AND2), &[Tv(820), Tv(2682)]),
+    ((2686, false, AND2), &[Tv(820), Tv(2685)]),
+    ((2689, false, AND2), &[Tv(820), Tv(2688)]),
+    ((2692, false, AND2), &[Tv(820), Tv(2691)]),
+    ((2695, false, AND2), &[Tv(820), Tv(2694)]),
+    ((2698, false, AND2), &[Tv(820), Tv(2697)]),
+    ((2701, false, AND2), &[Tv(820), Tv(2700)]),
+    ((2704, false, AND2), &[Tv(820), Tv(2703)]),
+    ((2707, false, AND2), &[Tv(820), Tv(2706)]),
+    ((2710, false, AND2), &[Tv(820), Tv(2709)]),
+    ((2713, false, AND2), &[Tv(820), Tv(2712)]),
+    ((2716, false, AND2), &[Tv(820), Tv(2715)]),
+    ((2719, false, AND2), &[Tv(820), Tv(2718)]),
+    ((2722, false, AND2), &[Tv(820), Tv(2721)]),
+    ((2725, false, AND2), &[Tv(820), Tv(2724)]),
+    ((2728, false, AND2), &[Tv(820), Tv(2727)]),
+    ((2731, false, AND2), &[Tv(820), Tv(2730)]),
+    ((2734, false, AND2), &[Tv(820), Tv(2733)]),
+    ((2737, false, AND2), &[Tv(820), Tv(2736)]),
+    ((2740, false, AND2), &[Tv(820), Tv(2739)]),
+    ((2743, false, AND2), &[Tv(820), Tv(2742)]),
+    ((2746, false, AND2), &[Tv(820), Tv(2745)]),
+    ((2749, false, AND2), &[Tv(820), Tv(2748)]),
+    ((2752, false, AND2), &[Tv(820), Tv(2751)]),
+    ((2755, false, AND2), &[Tv(820), Tv(2754)]),
+    ((2758, false, AND2), &[Tv(820), Tv(2757)]),
+    ((2761, false, AND2), &[Tv(820), Tv(2760)]),
+    ((2764, false, AND2), &[Tv(820), Tv(2763)]),
+    ((2767, false, AND2), &[Tv(820), Tv(2766)]),
+    ((2770, false, AND2), &[Tv(820), Tv(2769)]),
+    ((2773, false, AND2), &[Tv(820), Tv(2772)]),
+    ((2776, false, AND2), &[Tv(820), Tv(2775)]),
+    ((2778, false, INV), &[Tv(875)]),
+    ((2781, false, AND2), &[Tv(930), Tv(2780)]),
+    ((2784, false, AND2), &[Tv(930), Tv(2783)]),
+    ((2787, false, AND2), &[Tv(930), Tv(2786)]),
+    ((2790, false, AND2), &[Tv(930), Tv(2789)]),
+    ((2793, false, AND2), &[Tv(930), Tv(2792)]),
+    ((2796, false, AND2), &[Tv(930), Tv(2795)]),
+    ((2799, false, AND2), &[Tv(930), Tv(2798)]),
+    ((2802, false, AND2), &[Tv(930), Tv(2801)]),
+    ((2805, false, AND2), &[Tv(930), Tv(2804)]),
+    ((2808, false, AND2), &[Tv(930), Tv(2807)]),
+    ((2811, false, AND2), &[Tv(930), Tv(2810)]),
+    ((2814, false, AND2), &[Tv(930), Tv(2813)]),
+    ((2817, false, AND2), &[Tv(930), Tv(2816)]),
+    ((2820, false, AND2), &[Tv(930), Tv(2819)]),
+    ((2823, false, AND2), &[Tv(930), Tv(2822)]),
+    ((2826, false, AND2), &[Tv(930), Tv(2825)]),
+    ((2829, false, AND2), &[Tv(930), Tv(2828)]),
+    ((2832, false, AND2), &[Tv(930), Tv(2831)]),
+    ((2835, false, AND2), &[Tv(930), Tv(2834)]),
+    ((2838, false, AND2), &[Tv(930), Tv(2837)]),
+    ((2841, false, AND2), &[Tv(930), Tv(2840)]),
+    ((2844, false, AND2), &[Tv(930), Tv(2843)]),
+    ((2847, false, AND2), &[Tv(930), Tv(2846)]),
+    ((2850, false, AND2), &[Tv(930), Tv(2849)]),
+    ((2853, false, AND2), &[Tv(930), Tv(2852)]),
+    ((2856, false, AND2), &[Tv(930), Tv(2855)]),
+    ((2859, false, AND2), &[Tv(930), Tv(2858)]),
+    ((2862, false, AND2), &[Tv(930), Tv(2861)]),
+    ((2865, false, AND2), &[Tv(930), Tv(2864)]),
+    ((2868, false, AND2), &[Tv(930), Tv(2867)]),
+    ((2871, false, AND2), &[Tv(930), Tv(2870)]),
+    ((2874, false, AND2), &[Tv(930), Tv(2873)]),
+    ((2876, false, INV), &[Tv(985)]),
+    ((2879, false, AND2), &[Tv(1040), Tv(2878)]),
+    ((2882, false, AND2), &[Tv(1040), Tv(2881)]),
+    ((2885, false, AND2), &[Tv(1040), Tv(2884)]),
+    ((2888, false, AND2), &[Tv(1040), Tv(2887)]),
+    ((2891, false, AND2), &[Tv(1040), Tv(2890)]),
+    ((2894, false, AND2), &[Tv(1040), Tv(2893)]),
+    ((2897, false, AND2), &[Tv(1040), Tv(2896)]),
+    ((2900, false, AND2), &[Tv(1040), Tv(2899)]),
+    ((2903, false, AND2), &[Tv(1040), Tv(2902)]),
+    ((2906, false, AND2), &[Tv(1040), Tv(2905)]),
+    ((2909, false, AND2), &[Tv(1040), Tv(2908)]),
+    ((2912, false, AND2), &[Tv(1040), Tv(2911)]),
+    ((2915, false, AND2), &[Tv(1040), Tv(2914)]),
+    ((2918, false, AND2), &[Tv(1040), Tv(2917)]),
+    ((2921, false, AND2), &[Tv(1040), Tv(2920)]),
+    ((2924, false, AND2), &[Tv(1040), Tv(2923)]),
+    ((2927, false, AND2), &[Tv(1040), Tv(2926)]),
+    ((2930, false, AND2), &[Tv(1040), Tv(2929)]),
+    ((2933, false, AND2), &[Tv(1040), Tv(2932)]),
+    ((2936, false, AND2), &[Tv(1040), Tv(2935)]),
+    ((2939, false, AND2), &[Tv(1040), Tv(2938)]),
+    ((2942, false, AND2), &[Tv(1040), Tv(2941)]),
+    ((2945, false, AND2), &[Tv(1040), Tv(2944)]),
+    ((2948, false, AND2), &[Tv(1040), Tv(2947)]),
+    ((2951, false, AND2), &[Tv(1040), Tv(2950)]),
+    ((2954, false, AND2), &[Tv(1040), Tv(2953)]),
+    ((2957, false, AND2), &[Tv(1040), Tv(2956)]),
+    ((2960, false, AND2), &[Tv(1040), Tv(2959)]),
+    ((2963, false, AND2), &[Tv(1040), Tv(2962)]),
+    ((2966, false, AND2), &[Tv(1040), Tv(2965)]),
+    ((2969, false, AND2), &[Tv(1040), Tv(2968)]),
+    ((2972, false, AND2), &[Tv(1040), Tv(2971)]),
+    ((2974, false, INV), &[Tv(1095)]),
+    ((2977, false, AND2), &[Tv(1150), Tv(2976)]),
+    ((2980, false, AND2), &[Tv(1150), Tv(2979)]),
+    ((2983, false, AND2), &[Tv(1150), Tv(2982)]),
+    ((2986, false, AND2), &[Tv(1150), Tv(2985)]),
+    ((2989, false, AND2), &[Tv(1150), Tv(2988)]),
+    ((2992, false, AND2), &[Tv(1150), Tv(2991)]),
+    ((2995, false, AND2), &[Tv(1150), Tv(2994)]),
+    ((2998, false, AND2), &[Tv(1150), Tv(2997)]),
+    ((3001, false, AND2), &[Tv(1150), Tv(3000)]),
+    ((3004, false, AND2), &[Tv(1150), Tv(3003)]),
+    ((3007, false, AND2), &[Tv(1150), Tv(3006)]),
+    ((3010, false, AND2), &[Tv(1150), Tv(3009)]),
+    ((3013, false, AND2), &[Tv(1150), Tv(3012)]),
+    ((3016, false, AND2), &[Tv(1150), Tv(3015)]),
+    ((3019, false, AND2), &[Tv(1150), Tv(3018)]),
+    ((3022, false, AND2), &[Tv(1150), Tv(3021)]),
+    ((3025, false, AND2), &[Tv(1150), Tv(3024)]),
+    ((3028, false, AND2), &[Tv(1150), Tv(3027)]),
+    ((3031, false, AND2), &[Tv(1150), Tv(3030)]),
+    ((3034, false, AND2), &[Tv(1150), Tv(3033)]),
+    ((3037, false, AND2), &[Tv(1150), Tv(3036)]),
+    ((3040, false, AND2), &[Tv(1150), Tv(3039)]),
+    ((3043, false, AND2), &[Tv(1150), Tv(3042)]),
+    ((3046, false, AND2), &[Tv(1150), Tv(3045)]),
+    ((3049, false, AND2), &[Tv(1150), Tv(3048)]),
+    ((3052, false, AND2), &[Tv(1150), Tv(3051)]),
+    ((3055, false, AND2), &[Tv(1150), Tv(3054)]),
+    ((3058, false, AND2), &[Tv(1150), Tv(3057)]),
+    ((3061, false, AND2), &[Tv(1150), Tv(3060)]),
+    ((3064, false, AND2), &[Tv(1150), Tv(3063)]),
+    ((3067, false, AND2), &[Tv(1150), Tv(3066)]),
+    ((3070, false, AND2), &[Tv(1150), Tv(3069)]),
+    ((3072, false, INV), &[Tv(1205)]),
+    ((3075, false, AND2), &[Tv(1260), Tv(3074)]),
+    ((3078, false, AND2), &[Tv(1260), Tv(3077)]),
+    ((3081, false, AND2), &[Tv(1260), Tv(3080)]),
+    ((3084, false, AND2), &[Tv(1260), Tv(3083)]),
+    ((3087, false, AND2), &[Tv(1260), Tv(3086)]),
+    ((3090, false, AND2), &[Tv(1260), Tv(3089)]),
+    ((3093, false, AND2), &[Tv(1260), Tv(3092)]),
+    ((3096, false, AND2), &[Tv(1260), Tv(3095)]),
+    ((3099, false, AND2), &[Tv(1260), Tv(3098)]),
+    ((3102, false, AND2), &[Tv(1260), Tv(3101)]),
+    ((3105, false, AND2), &[Tv(1260), Tv(3104)]),
+    ((3108, false, AND2), &[Tv(1260), Tv(3107)]),
+    ((3111, false, AND2), &[Tv(1260), Tv(3110)]),
+    ((3114, false, AND2), &[Tv(1260), Tv(3113)]),
+    ((3117, false, AND2), &[Tv(1260), Tv(3116)]),
+    ((3120, false, AND2), &[Tv(1260), Tv(3119)]),
+    ((3123, false, AND2), &[Tv(1260), Tv(3122)]),
+    ((3126, false, AND2), &[Tv(1260), Tv(3125)]),
+    ((3129, false, AND2), &[Tv(1260), Tv(3128)]),
+    ((3132, false, AND2), &[Tv(1260), Tv(3131)]),
+    ((3135, false, AND2), &[Tv(1260), Tv(3134)]),
+    ((3138, false, AND2), &[Tv(1260), Tv(3137)]),
+    ((3141, false, AND2), &[Tv(1260), Tv(3140)]),
+    ((3144, false, AND2), &[Tv(1260), Tv(3143)]),
+    ((3147, false, AND2), &[Tv(1260), Tv(3146)]),
+    ((3150, false, AND2), &[Tv(1260), Tv(3149)]),
+    ((3153, false, AND2), &[Tv(1260), Tv(3152)]),
+    ((3156, false, AND2), &[Tv(1260), Tv(3155)]),
+    ((3159, false, AND2), &[Tv(1260), Tv(3158)]),
+    ((3162, false, AND2), &[Tv(1260), Tv(3161)]),
+    ((3165, false, AND2), &[Tv(1260), Tv(3164)]),
+    ((3168, false, AND2), &[Tv(1260), Tv(3167)]),
+    ((3170, false, INV), &[Tv(1315)]),
+    ((3173, false, AND2), &[Tv(1364), Tv(3172)]),
+    ((3176, false, AND2), &[Tv(1364), Tv(3175)]),
+    ((3179, false, AND2), &[Tv(1364), Tv(3178)]),
+    ((3182, false, AND2), &[Tv(1364), Tv(3181)]),
+    ((3185, false, AND2), &[Tv(1364), Tv(3184)]),
+    ((3188, false, AND2), &[Tv(1364), Tv(3187)]),
+    ((3191, false, AND2), &[Tv(1364), Tv(3190)]),
+    ((3194, false, AND2), &[Tv(1364), Tv(3193)]),
+    ((3197, false, AND2), &[Tv(1364), Tv(3196)]),
+    ((3200, false, AND2), &[Tv(1364), Tv(3199)]),
+    ((3203, false, AND2), &[Tv(1364), Tv(3202)]),
+    ((3206, false, AND2), &[Tv(1364), Tv(3205)]),
+    ((3209, false, AND2), &[Tv(1364), Tv(3208)]),
+    ((3212, false, AND2), &[Tv(1364), Tv(3211)]),
+    ((3215, false, AND2), &[Tv(1364), Tv(3214)]),
+    ((3218, false, AND2), &[Tv(1364), Tv(3217)]),
+    ((3221, false, AND2), &[Tv(1364), Tv(3220)]),
+    ((3224, false, AND2), &[Tv(1364), Tv(3223)]),
+    ((3227, false, AND2), &[Tv(1364), Tv(3226)]),
+    ((3230, false, AND2), &[Tv(1364), Tv(3229)]),
+    ((3233, false, AND2), &[Tv(1364), Tv(3232)]),
+    ((3236, false, AND2), &[Tv(1364), Tv(3235)]),
+    ((3239, false, AND2), &[Tv(1364), Tv(3238)]),
+    ((3242, false, AND2), &[Tv(1364), Tv(3241)]),
+    ((3245, false, AND2), &[Tv(1364), Tv(3244)]),
+    ((3248, false, AND2), &[Tv(1364), Tv(3247)]),
+    ((3251, false, AND2), &[Tv(1364), Tv(3250)]),
+    ((3254, false, AND2), &[Tv(1364), Tv(3253)]),
+    ((3257, false, AND2), &[Tv(1364), Tv(3256)]),
+    ((3260, false, AND2), &[Tv(1364), Tv(3259)]),
+    ((3263, false, AND2), &[Tv(1364), Tv(3262)]),
+    ((3266, false, AND2), &[Tv(1364), Tv(3265)]),
+    ((3268, false, INV), &[Tv(1413)]),
+    ((3271, false, AND2), &[Tv(1462), Tv(3270)]),
+    ((3274, false, AND2), &[Tv(1462), Tv(3273)]),
+    ((3277, false, AND2), &[Tv(1462), Tv(3276)]),
+    ((3280, false, AND2), &[Tv(1462), Tv(3279)]),
+    ((3283, false, AND2), &[Tv(1462), Tv(3282)]),
+    ((3286, false, AND2), &[Tv(1462), Tv(3285)]),
+    ((3289, false, AND2), &[Tv(1462), Tv(3288)]),
+    ((3292, false, AND2), &[Tv(1462), Tv(3291)]),
+    ((3295, false, AND2), &[Tv(1462), Tv(3294)]),
+    ((3298, false, AND2), &[Tv(1462), Tv(3297)]),
+    ((3301, false, AND2), &[Tv(1462), Tv(3300)]),
+    ((3304, false, AND2), &[Tv(1462), Tv(3303)]),
+    ((3307, false, AND2), &[Tv(1462), Tv(3306)]),
+    ((3310, false, AND2), &[Tv(1462), Tv(3309)]),
+    ((3313, false, AND2), &[Tv(1462), Tv(3312)]),
+    ((3316, false, AND2), &[Tv(1462), Tv(3315)]),
+    ((3319, false, AND2), &[Tv(1462), Tv(3318)]),
+    ((3322, false, AND2), &[Tv(1462), Tv(3321)]),
+    ((3325, false, AND2), &[Tv(1462), Tv(3324)]),
+    ((3328, false, AND2), &[Tv(1462), Tv(3327)]),
+    ((3331, false, AND2), &[Tv(1462), Tv(3330)]),
+    ((3334, false, AND2), &[Tv(1462), Tv(3333)]),
+    ((3337, false, AND2), &[Tv(1462), Tv(3336)]),
+    ((3340, false, AND2), &[Tv(1462), Tv(3339)]),
+    ((3343, false, AND2), &[Tv(1462), Tv(3342)]),
+    ((3346, false, AND2), &[Tv(1462), Tv(3345)]),
+    ((3349, false, AND2), &[Tv(1462), Tv(3348)]),
+    ((3352, false, AND2), &[Tv(1462), Tv(3351)]),
+    ((3355, false, AND2), &[Tv(1462), Tv(3354)]),
+    ((3358, false, AND2), &[Tv(1462), Tv(3357)]),
+    ((3361, false, AND2), &[Tv(1462), Tv(3360)]),
+    ((3364, false, AND2), &[Tv(1462), Tv(3363)]),
+    ((3366, false, INV), &[Tv(1511)]),
+    ((3369, false, AND2), &[Tv(1560), Tv(3368)]),
+    ((3372, false, AND2), &[Tv(1560), Tv(3371)]),
+    ((3375, false, AND2), &[Tv(1560), Tv(3374)]),
+    ((3378, false, AND2), &[Tv(1560), Tv(3377)]),
+    ((3381, false, AND2), &[Tv(1560), Tv(3380)]),
+    ((3384, false, AND2), &[Tv(1560), Tv(3383)]),
+    ((3387, false, AND2), &[Tv(1560), Tv(3386)]),
+    ((3390, false, AND2), &[Tv(1560), Tv(3389)]),
+    ((3393, false, AND2), &[Tv(1560), Tv(3392)]),
+    ((3396, false, AND2), &[Tv(1560), Tv(3395)]),
+    ((3399, false, AND2), &[Tv(1560), Tv(3398)]),
+    ((3402, false, AND2), &[Tv(1560), Tv(3401)]),
+    ((3405, false, AND2), &[Tv(1560), Tv(3404)]),
+    ((3408, false, AND2), &[Tv(1560), Tv(3407)]),
+    ((3411, false, AND2), &[Tv(1560), Tv(3410)]),
+    ((3414, false, AND2), &[Tv(1560), Tv(3413)]),
+    ((3417, false, AND2), &[Tv(1560), Tv(3416)]),
+    ((3420, false, AND2), &[Tv(1560), Tv(3419)]),
+    ((3423, false, AND2), &[Tv(1560), Tv(3422)]),
+    ((3426, false, AND2), &[Tv(1560), Tv(3425)]),
+    ((3429, false, AND2), &[Tv(1560), Tv(3428)]),
+    ((3432, false, AND2), &[Tv(1560), Tv(3431)]),
+    ((3435, false, AND2), &[Tv(1560), Tv(3434)]),
+    ((3438, false, AND2), &[Tv(1560), Tv(3437)]),
+    ((3441, false, AND2), &[Tv(1560), Tv(3440)]),
+    ((3444, false, AND2), &[Tv(1560), Tv(3443)]),
+    ((3447, false, AND2), &[Tv(1560), Tv(3446)]),
+    ((3450, false, AND2), &[Tv(1560), Tv(3449)]),
+    ((3453, false, AND2), &[Tv(1560), Tv(3452)]),
+    ((3456, false, AND2), &[Tv(1560), Tv(3455)]),
+    ((3459, false, AND2), &[Tv(1560), Tv(3458)]),
+    ((3462, false, AND2), &[Tv(1560), Tv(3461)]),
+    ((3464, false, INV), &[Tv(1609)]),
+    ((3467, false, AND2), &[Tv(1658), Tv(3466)]),
+    ((3470, false, AND2), &[Tv(1658), Tv(3469)]),
+    ((3473, false, AND2), &[Tv(1658), Tv(3472)]),
+    ((3476, false, AND2), &[Tv(1658), Tv(3475)]),
+    ((3479, false, AND2), &[Tv(1658), Tv(3478)]),
+    ((3482, false, AND2), &[Tv(1658), Tv(3481)]),
+    ((3485, false, AND2), &[Tv(1658), Tv(3484)]),
+    ((3488, false, AND2), &[Tv(1658), Tv(3487)]),
+    ((3491, false, AND2), &[Tv(1658), Tv(3490)]),
+    ((3494, false, AND2), &[Tv(1658), Tv(3493)]),
+    ((3497, false, AND2), &[Tv(1658), Tv(3496)]),
+    ((3500, false, AND2), &[Tv(1658), Tv(3499)]),
+    ((3503, false, AND2), &[Tv(1658), Tv(3502)]),
+    ((3506, false, AND2), &[Tv(1658), Tv(3505)]),
+    ((3509, false, AND2), &[Tv(1658), Tv(3508)]),
+    ((3512, false, AND2), &[Tv(1658), Tv(3511)]),
+    ((3515, false, AND2), &[Tv(1658), Tv(3514)]),
+    ((3518, false, AND2), &[Tv(1658), Tv(3517)]),
+    ((3521, false, AND2), &[Tv(1658), Tv(3520)]),
+    ((3524, false, AND2), &[Tv(1658), Tv(3523)]),
+    ((3527, false, AND2), &[Tv(1658), Tv(3526)]),
+    ((3530, false, AND2), &[Tv(1658), Tv(3529)]),
+    ((3533, false, AND2), &[Tv(1658), Tv(3532)]),
+    ((3536, false, AND2), &[Tv(1658), Tv(3535)]),
+    ((3539, false, AND2), &[Tv(1658), Tv(3538)]),
+    ((3542, false, AND2), &[Tv(1658), Tv(3541)]),
+    ((3545, false, AND2), &[Tv(1658), Tv(3544)]),
+    ((3548, false, AND2), &[Tv(1658), Tv(3547)]),
+    ((3551, false, AND2), &[Tv(1658), Tv(3550)]),
+    ((3554, false, AND2), &[Tv(1658), Tv(3553)]),
+    ((3557, false, AND2), &[Tv(1658), Tv(3556)]),
+    ((3560, false, AND2), &[Tv(1658), Tv(3559)]),
+    ((3562, false, INV), &[Tv(1707)]),
+    ((3565, false, AND2), &[Tv(1756), Tv(3564)]),
+    ((3568, false, AND2), &[Tv(1756), Tv(3567)]),
+    ((3571, false, AND2), &[Tv(1756), Tv(3570)]),
+    ((3574, false, AND2), &[Tv(1756), Tv(3573)]),
+    ((3577, false, AND2), &[Tv(1756), Tv(3576)]),
+    ((3580, false, AND2), &[Tv(1756), Tv(3579)]),
+    ((3583, false, AND2), &[Tv(1756), Tv(3582)]),
+    ((3586, false, AND2), &[Tv(1756), Tv(3585)]),
+    ((3589, false, AND2), &[Tv(1756), Tv(3588)]),
+    ((3592, false, AND2), &[Tv(1756), Tv(3591)]),
+    ((3595, false, AND2), &[Tv(1756), Tv(3594)]),
+    ((3598, false, AND2), &[Tv(1756), Tv(3597)]),
+    ((3601, false, AND2), &[Tv(1756), Tv(3600)]),
+    ((3604, false, AND2), &[Tv(1756), Tv(3603)]),
+    ((3607, false, AND2), &[Tv(1756), Tv(3606)]),
+    ((3610, false, AND2), &[Tv(1756), Tv(3609)]),
+    ((3613, false, AND2), &[Tv(1756), Tv(3612)]),
+    ((3616, false, AND2), &[Tv(1756), Tv(3615)]),
+    ((3619, false, AND2), &[Tv(1756), Tv(3618)]),
+    ((3622, false, AND2), &[Tv(1756), Tv(3621)]),
+    ((3625, false, AND2), &[Tv(1756), Tv(3624)]),
+    ((3628, false, AND2), &[Tv(1756), Tv(3627)]),
+    ((3631, false, AND2), &[Tv(1756), Tv(3630)]),
+    ((3634, false, AND2), &[Tv(1756), Tv(3633)]),
+    ((3637, false, AND2), &[Tv(1756), Tv(3636)]),
+    ((3640, false, AND2), &[Tv(1756), Tv(3639)]),
+    ((3643, false, AND2), &[Tv(1756), Tv(3642)]),
+    ((3646, false, AND2), &[Tv(1756), Tv(3645)]),
+    ((3649, false, AND2), &[Tv(1756), Tv(3648)]),
+    ((3652, false, AND2), &[Tv(1756), Tv(3651)]),
+    ((3655, false, AND2), &[Tv(1756), Tv(3654)]),
+    ((3658, false, AND2), &[Tv(1756), Tv(3657)]),
+    ((3660, false, INV), &[Tv(1805)]),
+    ((3663, false, AND2), &[Tv(1854), Tv(3662)]),
+    ((3666, false, AND2), &[Tv(1854), Tv(3665)]),
+    ((3669, false, AND2), &[Tv(1854), Tv(3668)]),
+    ((3672, false, AND2), &[Tv(1854), Tv(3671)]),
+    ((3675, false, AND2), &[Tv(1854), Tv(3674)]),
+    ((3678, false, AND2), &[Tv(1854), Tv(3677)]),
+    ((3681, false, AND2), &[Tv(1854), Tv(3680)]),
+    ((3684, false, AND2), &[Tv(1854), Tv(3683)]),
+    ((3687, false, AND2), &[Tv(1854), Tv(3686)]),
+    ((3690, false, AND2), &[Tv(1854), Tv(3689)]),
+    ((3693, false, AND2), &[Tv(1854), Tv(3692)]),
+    ((3696, false, AND2), &[Tv(1854), Tv(3695)]),
+    ((3699, false, AND2), &[Tv(1854), Tv(3698)]),
+    ((3702, false, AND2), &[Tv(1854), Tv(3701)]),
+    ((3705, false, AND2), &[Tv(1854), Tv(3704)]),
+    ((3708, false, AND2), &[Tv(1854), Tv(3707)]),
+    ((3711, false, AND2), &[Tv(1854), Tv(3710)]),
+    ((3714, false, AND2), &[Tv(1854), Tv(3713)]),
+    ((3717, false, AND2), &[Tv(1854), Tv(3716)]),
+    ((3720, false, AND2), &[Tv(1854), Tv(3719)]),
+    ((3723, false, AND2), &[Tv(1854), Tv(3722)]),
+    ((3726, false, AND2), &[Tv(1854), Tv(3725)]),
+    ((3729, false, AND2), &[Tv(1854), Tv(3728)]),
+    ((3732, false, AND2), &[Tv(1854), Tv(3731)]),
+    ((3735, false, AND2), &[Tv(1854), Tv(3734)]),
+    ((3738, false, AND2), &[Tv(1854), Tv(3737)]),
+    ((3741, false, AND2), &[Tv(1854), Tv(3740)]),
+    ((3744, false, AND2), &[Tv(1854), Tv(3743)]),
+    ((3747, false, AND2), &[Tv(1854), Tv(3746)]),
+    ((3750, false, AND2), &[Tv(1854), Tv(3749)]),
+    ((3753, false, AND2), &[Tv(1854), Tv(3752)]),
+    ((3756, false, AND2), &[Tv(1854), Tv(3755)]),
+    ((3758, false, INV), &[Tv(1903)]),
+    ((3762, false, XOR2), &[Arg(3, 627), Tv(3761)]),
+    ((3765, false, XOR2), &[Arg(3, 628), Tv(3764)]),
+    ((3768, false, XOR2), &[Arg(3, 629), Tv(3767)]),
+    ((3771, false, XOR2), &[Arg(3, 630), Tv(3770)]),
+    ((3774, false, XOR2), &[Arg(3, 631), Tv(3773)]),
+    ((3777, false, XOR2), &[Arg(3, 632), Tv(3776)]),
+    ((3780, false, XOR2), &[Arg(3, 633), Tv(3779)]),
+    ((3783, false, XOR2), &[Arg(3, 634), Tv(3782)]),
+    ((3786, false, XOR2), &[Arg(3, 651), Tv(3785)]),
+    ((3789, false, XOR2), &[Arg(3, 652), Tv(3788)]),
+    ((3792, false, XOR2), &[Arg(3, 653), Tv(3791)]),
+    ((3795, false, XOR2), &[Arg(3, 654), Tv(3794)]),
+    ((3798, false, XOR2), &[Arg(3, 655), Tv(3797)]),
+    ((3801, false, XOR2), &[Arg(3, 656), Tv(3800)]),
+    ((3804, false, XOR2), &[Arg(3, 657), Tv(3803)]),
+    ((3807, false, XOR2), &[Arg(3, 658), Tv(3806)]),
+    ((3810, false, XOR2), &[Arg(3, 659), Tv(3809)]),
+    ((3813, false, XOR2), &[Arg(3, 660), Tv(3812)]),
+    ((3816, false, XOR2), &[Arg(3, 661), Tv(3815)]),
+    ((3819, false, XOR2), &[Arg(3, 662), Tv(3818)]),
+    ((3822, false, XOR2), &[Arg(3, 663), Tv(3821)]),
+    ((3825, false, XOR2), &[Arg(3, 664), Tv(3824)]),
+    ((3828, false, XOR2), &[Arg(3, 665), Tv(3827)]),
+    ((3831, false, XOR2), &[Arg(3, 666), Tv(3830)]),
+    ((3834, false, XOR2), &[Arg(3, 668), Tv(3833)]),
+    ((3837, false, XOR2), &[Arg(3, 669), Tv(3836)]),
+    ((3840, false, XOR2), &[Arg(3, 670), Tv(3839)]),
+    ((3843, false, XOR2), &[Arg(3, 671), Tv(3842)]),
+    ((3846, false, XOR2), &[Arg(3, 672), Tv(3845)]),
+    ((3849, false, XOR2), &[Arg(3, 673), Tv(3848)]),
+    ((3852, false, XOR2), &[Arg(3, 674), Tv(3851)]),
+    ((3855, false, XOR2), &[Arg(3, 675), Tv(3854)]),
+    ((3857, false, NOR2), &[Tv(3856), Tv(2044)]),
+    ((3860, false, XOR2), &[Arg(4, 48), Tv(3859)]),
+    ((3863, false, XOR2), &[Arg(4, 49), Tv(3862)]),
+    ((3866, false, XOR2), &[Arg(4, 50), Tv(3865)]),
+    ((3869, false, XOR2), &[Arg(4, 51), Tv(3868)]),
+    ((3872, false, XOR2), &[Arg(4, 52), Tv(3871)]),
+    ((3875, false, XOR2), &[Arg(4, 53), Tv(3874)]),
+    ((3878, false, XOR2), &[Arg(4, 54), Tv(3877)]),
+    ((3881, false, XOR2), &[Arg(4, 55), Tv(3880)]),
+    ((3884, false, XOR2), &[Arg(4, 72), Tv(3883)]),
+    ((3887, false, XOR2), &[Arg(4, 73), Tv(3886)]),
+    ((3890, false, XOR2), &[Arg(4, 74), Tv(3889)]),
+    ((3893, false, XOR2), &[Arg(4, 75), Tv(3892)]),
+    ((3896, false, XOR2), &[Arg(4, 76), Tv(3895)]),
+    ((3899, false, XOR2), &[Arg(4, 77), Tv(3898)]),
+    ((3902, false, XOR2), &[Arg(4, 78), Tv(3901)]),
+    ((3905, false, XOR2), &[Arg(4, 79), Tv(3904)]),
+    ((3908, false, XOR2), &[Arg(4, 80), Tv(3907)]),
+    ((3911, false, XOR2), &[Arg(4, 81), Tv(3910)]),
+    ((3914, false, XOR2), &[Arg(4, 82), Tv(3913)]),
+    ((3917, false, XOR2), &[Arg(4, 83), Tv(3916)]),
+    ((3920, false, XOR2), &[Arg(4, 84), Tv(3919)]),
+    ((3923, false, XOR2), &[Arg(4, 85), Tv(3922)]),
+    ((3926, false, XOR2), &[Arg(4, 86), Tv(3925)]),
+    ((3929, false, XOR2), &[Arg(4, 87), Tv(3928)]),
+    ((3932, false, XOR2), &[Arg(4, 88), Tv(3931)]),
+    ((3935, false, XOR2), &[Arg(4, 89), Tv(3934)]),
+    ((3938, false, XOR2), &[Arg(4, 90), Tv(3937)]),
+    ((3941, false, XOR2), &[Arg(4, 91), Tv(3940)]),
+    ((3944, false, XOR2), &[Arg(4, 92), Tv(3943)]),
+    ((3947, false, XOR2), &[Arg(4, 93), Tv(3946)]),
+    ((3950, false, XOR2), &[Arg(4, 94), Tv(3949)]),
+    ((3953, false, XOR2), &[Arg(4, 95), Tv(3952)]),
+    ((5927, false, NAND2), &[Tv(5926), Tv(5901)]),
+    ((5931, false, AND2), &[Tv(5905), Tv(5930)]),
+    ((5935, false, AND2), &[Tv(5910), Tv(5934)]),
+    ((5939, false, AND2), &[Tv(5915), Tv(5938)]),
+    ((5943, false, AND2), &[Tv(5921), Tv(5942)]),
+    ((5947, false, NAND2), &[Tv(5926), Tv(5936)]),
+    ((5950, false, NAND2), &[Tv(5949), Tv(5940)]),
+    ((6000, false, NAND2), &[Tv(5901), Tv(5999)]),
+    ((6004, false, AND2), &[Tv(5988), Tv(6003)]),
+    ((6007, false, AND2), &[Tv(5991), Tv(6006)]),
+    ((6010, false, AND2), &[Tv(5995), Tv(6009)]),
+    ((6013, false, NAND2), &[Tv(5936), Tv(5999)]),
+    ((6016, false, NAND2), &[Tv(5940), Tv(6015)]),
+    ((6028, false, XOR2), &[Tv(5956), Tv(6019)]),
+    ((6031, false, XOR2), &[Tv(5957), Tv(6020)]),
+    ((6124, false, NAND2), &[Tv(6123), Tv(6098)]),
+    ((6128, false, AND2), &[Tv(6102), Tv(6127)]),
+    ((6132, false, AND2), &[Tv(6107), Tv(6131)]),
+    ((6136, false, AND2), &[Tv(6112), Tv(6135)]),
+    ((6140, false, AND2), &[Tv(6118), Tv(6139)]),
+    ((6144, false, NAND2), &[Tv(6123), Tv(6133)]),
+    ((6147, false, NAND2), &[Tv(6146), Tv(6137)]),
+    ((6197, false, NAND2), &[Tv(6098), Tv(6196)]),
+    ((6201, false, AND2), &[Tv(6185), Tv(6200)]),
+    ((6204, false, AND2), &[Tv(6188), Tv(6203)]),
+    ((6207, false, AND2), &[Tv(6192), Tv(6206)]),
+    ((6210, false, NAND2), &[Tv(6133), Tv(6196)]),
+    ((6213, false, NAND2), &[Tv(6137), Tv(6212)]),
+    ((6225, false, XOR2), &[Tv(6153), Tv(6216)]),
+    ((6228, false, XOR2), &[Tv(6154), Tv(6217)]),
 ];
 
-static LEVEL_28: [((usize, bool, CellType), &[GateInput]); 128] = [
-    ((2830, false, XNOR2), &[Arg(0, 14), Arg(1, 14)]),
-    ((2834, false, NAND2), &[Tv(400), Arg(1, 12)]),
-    ((2849, false, NAND2), &[Tv(2835), Tv(2848)]),
-    ((2854, false, XNOR2), &[Arg(0, 11), Arg(1, 11)]),
-    ((2857, false, XNOR2), &[Arg(0, 8), Arg(1, 8)]),
-    ((2858, false, XNOR2), &[Arg(0, 10), Arg(1, 10)]),
-    ((2861, false, XNOR2), &[Arg(0, 12), Arg(1, 12)]),
-    ((2864, false, XNOR2), &[Arg(0, 13), Arg(1, 13)]),
-    ((2866, false, XNOR2), &[Arg(0, 15), Arg(1, 15)]),
-    ((2879, false, NAND2), &[Tv(2836), Tv(2878)]),
-    ((2895, false, NAND2), &[Arg(0, 4), Tv(393)]),
-    ((2908, false, NAND2), &[Tv(2896), Tv(2907)]),
-    ((2939, false, NAND2), &[Tv(2898), Tv(2938)]),
-    ((3082, false, NAND2), &[Tv(3077), Tv(3081)]),
-    ((3084, false, OR2), &[Tv(134), Tv(2704)]),
-    ((3192, false, NAND2), &[Tv(3188), Tv(3191)]),
-    ((3194, false, OR2), &[Tv(135), Tv(2704)]),
-    ((3299, false, NAND2), &[Tv(3295), Tv(3298)]),
-    ((3301, false, OR2), &[Tv(136), Tv(2704)]),
-    ((3406, false, NAND2), &[Tv(3402), Tv(3405)]),
-    ((3408, false, OR2), &[Tv(137), Tv(2704)]),
-    ((3513, false, NAND2), &[Tv(3509), Tv(3512)]),
-    ((3515, false, OR2), &[Tv(138), Tv(2704)]),
-    ((3620, false, NAND2), &[Tv(3616), Tv(3619)]),
-    ((3622, false, OR2), &[Tv(139), Tv(2704)]),
-    ((3727, false, NAND2), &[Tv(3723), Tv(3726)]),
-    ((3729, false, OR2), &[Tv(140), Tv(2704)]),
-    ((3834, false, NAND2), &[Tv(3830), Tv(3833)]),
-    ((3836, false, OR2), &[Tv(141), Tv(2704)]),
-    ((3941, false, NAND2), &[Tv(3937), Tv(3940)]),
-    ((3943, false, OR2), &[Tv(7), Tv(2704)]),
-    ((4048, false, NAND2), &[Tv(4044), Tv(4047)]),
-    ((4050, false, OR2), &[Tv(6), Tv(2704)]),
-    ((4155, false, NAND2), &[Tv(4151), Tv(4154)]),
-    ((4157, false, OR2), &[Tv(4), Tv(2704)]),
-    ((4262, false, NAND2), &[Tv(4258), Tv(4261)]),
-    ((4264, false, OR2), &[Tv(5), Tv(2704)]),
-    ((4369, false, NAND2), &[Tv(4365), Tv(4368)]),
-    ((4371, false, OR2), &[Tv(0), Tv(2704)]),
-    ((4476, false, NAND2), &[Tv(4472), Tv(4475)]),
-    ((4478, false, OR2), &[Tv(1), Tv(2704)]),
-    ((4583, false, NAND2), &[Tv(4579), Tv(4582)]),
-    ((4585, false, OR2), &[Tv(2), Tv(2704)]),
-    ((4690, false, NAND2), &[Tv(4686), Tv(4689)]),
-    ((4692, false, OR2), &[Tv(3), Tv(2704)]),
-    ((4797, false, NAND2), &[Tv(4793), Tv(4796)]),
-    ((4799, false, OR2), &[Tv(253), Tv(2704)]),
-    ((4904, false, NAND2), &[Tv(4900), Tv(4903)]),
-    ((4906, false, OR2), &[Tv(254), Tv(2704)]),
-    ((5011, false, NAND2), &[Tv(5007), Tv(5010)]),
-    ((5013, false, OR2), &[Tv(255), Tv(2704)]),
-    ((5118, false, NAND2), &[Tv(5114), Tv(5117)]),
-    ((5120, false, OR2), &[Tv(256), Tv(2704)]),
-    ((5225, false, NAND2), &[Tv(5221), Tv(5224)]),
-    ((5227, false, OR2), &[Tv(257), Tv(2704)]),
-    ((5332, false, NAND2), &[Tv(5328), Tv(5331)]),
-    ((5334, false, OR2), &[Tv(258), Tv(2704)]),
-    ((5439, false, NAND2), &[Tv(5435), Tv(5438)]),
-    ((5441, false, OR2), &[Tv(259), Tv(2704)]),
-    ((5546, false, NAND2), &[Tv(5542), Tv(5545)]),
-    ((5548, false, OR2), &[Tv(260), Tv(2704)]),
-    ((5653, false, NAND2), &[Tv(5649), Tv(5652)]),
-    ((5655, false, OR2), &[Tv(349), Tv(2704)]),
-    ((5760, false, NAND2), &[Tv(5756), Tv(5759)]),
-    ((5762, false, OR2), &[Tv(350), Tv(2704)]),
-    ((5867, false, NAND2), &[Tv(5863), Tv(5866)]),
-    ((5869, false, OR2), &[Tv(351), Tv(2704)]),
-    ((5974, false, NAND2), &[Tv(5970), Tv(5973)]),
-    ((5976, false, OR2), &[Tv(352), Tv(2704)]),
-    ((6081, false, NAND2), &[Tv(6077), Tv(6080)]),
-    ((6083, false, OR2), &[Tv(353), Tv(2704)]),
-    ((6188, false, NAND2), &[Tv(6184), Tv(6187)]),
-    ((6190, false, OR2), &[Tv(354), Tv(2704)]),
-    ((6295, false, NAND2), &[Tv(6291), Tv(6294)]),
-    ((6297, false, OR2), &[Tv(355), Tv(2704)]),
-    ((6402, false, NAND2), &[Tv(6398), Tv(6401)]),
-    ((6404, false, OR2), &[Tv(356), Tv(2704)]),
-    ((385, false, INV), &[Arg(0, 4)]),
-    ((582, false, INV), &[Arg(3, 261)]),
-    ((598, false, INV), &[Arg(3, 353)]),
-    ((599, false, INV), &[Arg(3, 355)]),
-    ((880, false, NAND2), &[Arg(1, 14), Tv(572)]),
-    ((881, false, NAND2), &[Tv(404), Arg(3, 212)]),
-    ((884, false, NAND2), &[Arg(1, 10), Tv(570)]),
-    ((885, false, NAND2), &[Tv(390), Arg(3, 205)]),
-    ((888, false, NAND2), &[Arg(1, 15), Tv(573)]),
-    ((889, false, NAND2), &[Arg(1, 3), Tv(566)]),
-    ((896, false, NAND2), &[Arg(1, 6), Tv(568)]),
-    ((906, false, AND2), &[Tv(895), Tv(905)]),
-    ((910, false, AND2), &[Tv(908), Tv(909)]),
-    ((913, false, AND2), &[Tv(911), Tv(912)]),
-    ((916, false, XNOR2), &[Arg(1, 11), Arg(3, 215)]),
-    ((920, false, AND2), &[Tv(917), Tv(919)]),
-    ((925, false, AND2), &[Tv(923), Tv(924)]),
-    ((926, false, AND2), &[Tv(879), Tv(886)]),
-    ((982, false, NAND2), &[Tv(949), Tv(981)]),
-    ((1003, false, XNOR2), &[Arg(1, 11), Arg(3, 166)]),
-    ((1006, false, AND2), &[Tv(987), Tv(988)]),
-    ((1009, false, AND2), &[Tv(983), Tv(989)]),
-    ((1012, false, AND2), &[Tv(1010), Tv(1011)]),
-    ((1014, false, AND2), &[Tv(1008), Tv(1013)]),
-    ((1018, false, AND2), &[Tv(1016), Tv(1017)]),
-    ((1023, false, AND2), &[Tv(1021), Tv(1022)]),
-    ((1025, false, AND2), &[Tv(993), Tv(1005)]),
-    ((1075, false, NAND2), &[Tv(395), Arg(3, 504)]),
-    ((1080, false, NAND2), &[Arg(1, 2), Tv(628)]),
-    ((1223, false, INV), &[Tv(1222)]),
-    ((1271, false, NAND2), &[Tv(1248), Tv(1270)]),
-    ((1292, false, NAND2), &[Arg(1, 14), Tv(595)]),
-    ((1295, false, AND2), &[Tv(1293), Tv(1294)]),
-    ((1299, false, AND2), &[Tv(1297), Tv(1298)]),
-    ((1301, false, AND2), &[Tv(1288), Tv(1300)]),
-    ((1307, false, AND2), &[Tv(1304), Tv(1306)]),
-    ((1314, false, AND2), &[Tv(1312), Tv(1313)]),
-    ((1318, false, AND2), &[Tv(1283), Tv(1317)]),
-    ((1320, false, AND2), &[Tv(1277), Tv(1319)]),
-    ((1327, false, NAND2), &[Tv(404), Arg(3, 261)]),
-    ((1336, false, NAND2), &[Arg(1, 1), Tv(576)]),
-    ((1344, false, NAND2), &[Arg(1, 9), Tv(583)]),
-    ((1346, false, XNOR2), &[Arg(1, 15), Arg(3, 268)]),
-    ((1349, false, AND2), &[Tv(586), Tv(1347)]),
-    ((1352, false, AND2), &[Tv(1335), Tv(1337)]),
-    ((1356, false, AND2), &[Tv(1354), Tv(1355)]),
-    ((1358, false, AND2), &[Tv(1339), Tv(1357)]),
-    ((1364, false, AND2), &[Tv(1362), Tv(1363)]),
-    ((1366, false, AND2), &[Tv(1341), Tv(1365)]),
-    ((1369, false, AND2), &[Tv(1325), Tv(1368)]),
-    ((1374, false, AND2), &[Tv(1372), Tv(1373)]),
+static LEVEL_8: [((usize, bool, CellType), &[GateInput]); 611] = [
+    ((2093, false, NOR2), &[Tv(2092), Tv(160)]),
+    ((2096, false, XOR2), &[Arg(2, 0), Tv(2095)]),
+    ((2099, false, XOR2), &[Arg(2, 1), Tv(2098)]),
+    ((2102, false, XOR2), &[Arg(2, 2), Tv(2101)]),
+    ((2105, false, XOR2), &[Arg(2, 3), Tv(2104)]),
+    ((2108, false, XOR2), &[Arg(2, 4), Tv(2107)]),
+    ((2111, false, XOR2), &[Arg(2, 5), Tv(2110)]),
+    ((2114, false, XOR2), &[Arg(2, 6), Tv(2113)]),
+    ((2117, false, XOR2), &[Arg(2, 7), Tv(2116)]),
+    ((2120, false, XOR2), &[Arg(2, 24), Tv(2119)]),
+    ((2123, false, XOR2), &[Arg(2, 25), Tv(2122)]),
+    ((2126, false, XOR2), &[Arg(2, 26), Tv(2125)]),
+    ((2129, false, XOR2), &[Arg(2, 27), Tv(2128)]),
+    ((2132, false, XOR2), &[Arg(2, 28), Tv(2131)]),
+    ((2135, false, XOR2), &[Arg(2, 29), Tv(2134)]),
+    ((2138, false, XOR2), &[Arg(2, 30), Tv(2137)]),
+    ((2141, false, XOR2), &[Arg(2, 31), Tv(2140)]),
+    ((2144, false, XOR2), &[Arg(2, 32), Tv(2143)]),
+    ((2147, false, XOR2), &[Arg(2, 33), Tv(2146)]),
+    ((2150, false, XOR2), &[Arg(2, 34), Tv(2149)]),
+    ((2153, false, XOR2), &[Arg(2, 35), Tv(2152)]),
+    ((2156, false, XOR2), &[Arg(2, 36), Tv(2155)]),
+    ((2159, false, XOR2), &[Arg(2, 37), Tv(2158)]),
+    ((2162, false, XOR2), &[Arg(2, 38), Tv(2161)]),
+    ((2165, false, XOR2), &[Arg(2, 39), Tv(2164)]),
+    ((2168, false, XOR2), &[Arg(2, 40), Tv(2167)]),
+    ((2171, false, XOR2), &[Arg(2, 41), Tv(2170)]),
+    ((2174, false, XOR2), &[Arg(2, 42), Tv(2173)]),
+    ((2177, false, XOR2), &[Arg(2, 43), Tv(2176)]),
+    ((2180, false, XOR2), &[Arg(2, 44), Tv(2179)]),
+    ((2183, false, XOR2), &[Arg(2, 45), Tv(2182)]),
+    ((2186, false, XOR2), &[Arg(2, 46), Tv(2185)]),
+    ((2189, false, XOR2), &[Arg(2, 47), Tv(2188)]),
+    ((2191, false, NOR2), &[Tv(2190), Tv(270)]),
+    ((2194, false, XOR2), &[Arg(2, 96), Tv(2193)]),
+    ((2197, false, XOR2), &[Arg(2, 97), Tv(2196)]),
+    ((2200, false, XOR2), &[Arg(2, 98), Tv(2199)]),
+    ((2203, false, XOR2), &[Arg(2, 99), Tv(2202)]),
+    ((2206, false, XOR2), &[Arg(2, 100), Tv(2205)]),
+    ((2209, false, XOR2), &[Arg(2, 101), Tv(2208)]),
+    ((2212, false, XOR2), &[Arg(2, 102), Tv(2211)]),
+    ((2215, false, XOR2), &[Arg(2, 103), Tv(2214)]),
+    ((2218, false, XOR2), &[Arg(2, 120), Tv(2217)]),
+    ((2221, false, XOR2), &[Arg(2, 121), Tv(2220)]),
+    ((2224, false, XOR2), &[Arg(2, 122), Tv(2223)]),
+    ((2227, false, XOR2), &[Arg(2, 123), Tv(2226)]),
+    ((2230, false, XOR2), &[Arg(2, 124), Tv(2229)]),
+    ((2233, false, XOR2), &[Arg(2, 125), Tv(2232)]),
+    ((2236, false, XOR2), &[Arg(2, 126), Tv(2235)]),
+    ((2239, false, XOR2), &[Arg(2, 127), Tv(2238)]),
+    ((2242, false, XOR2), &[Arg(2, 128), Tv(2241)]),
+    ((2245, false, XOR2), &[Arg(2, 129), Tv(2244)]),
+    ((2248, false, XOR2), &[Arg(2, 130), Tv(2247)]),
+    ((2251, false, XOR2), &[Arg(2, 131), Tv(2250)]),
+    ((2254, false, XOR2), &[Arg(2, 132), Tv(2253)]),
+    ((2257, false, XOR2), &[Arg(2, 133), Tv(2256)]),
+    ((2260, false, XOR2), &[Arg(2, 134), Tv(2259)]),
+    ((2263, false, XOR2), &[Arg(2, 135), Tv(2262)]),
+    ((2266, false, XOR2), &[Arg(2, 136), Tv(2265)]),
+    ((2269, false, XOR2), &[Arg(2, 137), Tv(2268)]),
+    ((2272, false, XOR2), &[Arg(2, 138), Tv(2271)]),
+    ((2275, false, XOR2), &[Arg(2, 139), Tv(2274)]),
+    ((2278, false, XOR2), &[Arg(2, 140), Tv(2277)]),
+    ((2281, false, XOR2), &[Arg(2, 141), Tv(2280)]),
+    ((2284, false, XOR2), &[Arg(2, 142), Tv(2283)]),
+    ((2287, false, XOR2), &[Arg(2, 143), Tv(2286)]),
+    ((2289, false, NOR2), &[Tv(2288), Tv(380)]),
+    ((2292, false, XOR2), &[Arg(2, 192), Tv(2291)]),
+    ((2295, false, XOR2), &[Arg(2, 193), Tv(2294)]),
+    ((2298, false, XOR2), &[Arg(2, 194), Tv(2297)]),
+    ((2301, false, XOR2), &[Arg(2, 195), Tv(2300)]),
+    ((2304, false, XOR2), &[Arg(2, 196), Tv(2303)]),
+    ((2307, false, XOR2), &[Arg(2, 197), Tv(2306)]),
+    ((2310, false, XOR2), &[Arg(2, 198), Tv(2309)]),
+    ((2313, false, XOR2), &[Arg(2, 199), Tv(2312)]),
+    ((2316, false, XOR2), &[Arg(2, 216), Tv(2315)]),
+    ((2319, false, XOR2), &[Arg(2, 217), Tv(2318)]),
+    ((2322, false, XOR2), &[Arg(2, 218), Tv(2321)]),
+    ((2325, false, XOR2), &[Arg(2, 219), Tv(2324)]),
+    ((2328, false, XOR2), &[Arg(2, 220), Tv(2327)]),
+    ((2331, false, XOR2), &[Arg(2, 221), Tv(2330)]),
+    ((2334, false, XOR2), &[Arg(2, 222), Tv(2333)]),
+    ((2337, false, XOR2), &[Arg(2, 223), Tv(2336)]),
+    ((2340, false, XOR2), &[Arg(2, 224), Tv(2339)]),
+    ((2343, false, XOR2), &[Arg(2, 225), Tv(2342)]),
+    ((2346, false, XOR2), &[Arg(2, 226), Tv(2345)]),
+    ((2349, false, XOR2), &[Arg(2, 227), Tv(2348)]),
+    ((2352, false, XOR2), &[Arg(2, 228), Tv(2351)]),
+    ((2355, false, XOR2), &[Arg(2, 229), Tv(2354)]),
+    ((2358, false, XOR2), &[Arg(2, 230), Tv(2357)]),
+    ((2361, false, XOR2), &[Arg(2, 231), Tv(2360)]),
+    ((2364, false, XOR2), &[Arg(2, 232), Tv(2363)]),
+    ((2367, false, XOR2), &[Arg(2, 233), Tv(2366)]),
+    ((2370, false, XOR2), &[Arg(2, 234), Tv(2369)]),
+    ((2373, false, XOR2), &[Arg(2, 235), Tv(2372)]),
+    ((2376, false, XOR2), &[Arg(2, 236), Tv(2375)]),
+    ((2379, false, XOR2), &[Arg(2, 237), Tv(2378)]),
+    ((2382, false, XOR2), &[Arg(2, 238), Tv(2381)]),
+    ((2385, false, XOR2), &[Arg(2, 239), Tv(2384)]),
+    ((2387, false, NOR2), &[Tv(2386), Tv(490)]),
+    ((2390, false, XOR2), &[Arg(2, 288), Tv(2389)]),
+    ((2393, false, XOR2), &[Arg(2, 289), Tv(2392)]),
+    ((2396, false, XOR2), &[Arg(2, 290), Tv(2395)]),
+    ((2399, false, XOR2), &[Arg(2, 291), Tv(2398)]),
+    ((2402, false, XOR2), &[Arg(2, 292), Tv(2401)]),
+    ((2405, false, XOR2), &[Arg(2, 293), Tv(2404)]),
+    ((2408, false, XOR2), &[Arg(2, 294), Tv(2407)]),
+    ((2411, false, XOR2), &[Arg(2, 295), Tv(2410)]),
+    ((2414, false, XOR2), &[Arg(2, 312), Tv(2413)]),
+    ((2417, false, XOR2), &[Arg(2, 313), Tv(2416)]),
+    ((2420, false, XOR2), &[Arg(2, 314), Tv(2419)]),
+    ((2423, false, XOR2), &[Arg(2, 315), Tv(2422)]),
+    ((2426, false, XOR2), &[Arg(2, 316), Tv(2425)]),
+    ((2429, false, XOR2), &[Arg(2, 317), Tv(2428)]),
+    ((2432, false, XOR2), &[Arg(2, 318), Tv(2431)]),
+    ((2435, false, XOR2), &[Arg(2, 319), Tv(2434)]),
+    ((2438, false, XOR2), &[Arg(2, 320), Tv(2437)]),
+    ((2441, false, XOR2), &[Arg(2, 321), Tv(2440)]),
+    ((2444, false, XOR2), &[Arg(2, 322), Tv(2443)]),
+    ((2447, false, XOR2), &[Arg(2, 323), Tv(2446)]),
+    ((2450, false, XOR2), &[Arg(2, 324), Tv(2449)]),
+    ((2453, false, XOR2), &[Arg(2, 325), Tv(2452)]),
+    ((2456, false, XOR2), &[Arg(2, 326), Tv(2455)]),
+    ((2459, false, XOR2), &[Arg(2, 327), Tv(2458)]),
+    ((2462, false, XOR2), &[Arg(2, 328), Tv(2461)]),
+    ((2465, false, XOR2), &[Arg(2, 329), Tv(2464)]),
+    ((2468, false, XOR2), &[Arg(2, 330), Tv(2467)]),
+    ((2471, false, XOR2), &[Arg(2, 331), Tv(2470)]),
+    ((2474, false, XOR2), &[Arg(2, 332), Tv(2473)]),
+    ((2477, false, XOR2), &[Arg(2, 333), Tv(2476)]),
+    ((2480, false, XOR2), &[Arg(2, 334), Tv(2479)]),
+    ((2483, false, XOR2), &[Arg(2, 335), Tv(2482)]),
+    ((2485, false, NOR2), &[Tv(2484), Tv(600)]),
+    ((2488, false, XOR2), &[Arg(2, 384), Tv(2487)]),
+    ((2491, false, XOR2), &[Arg(2, 385), Tv(2490)]),
+    ((2494, false, XOR2), &[Arg(2, 386), Tv(2493)]),
+    ((2497, false, XOR2), &[Arg(2, 387), Tv(2496)]),
+    ((2500, false, XOR2), &[Arg(2, 388), Tv(2499)]),
+    ((2503, false, XOR2), &[Arg(2, 389), Tv(2502)]),
+    ((2506, false, XOR2), &[Arg(2, 390), Tv(2505)]),
+    ((2509, false, XOR2), &[Arg(2, 391), Tv(2508)]),
+    ((2512, false, XOR2), &[Arg(2, 408), Tv(2511)]),
+    ((2515, false, XOR2), &[Arg(2, 409), Tv(2514)]),
+    ((2518, false, XOR2), &[Arg(2, 410), Tv(2517)]),
+    ((2521, false, XOR2), &[Arg(2, 411), Tv(2520)]),
+    ((2524, false, XOR2), &[Arg(2, 412), Tv(2523)]),
+    ((2527, false, XOR2), &[Arg(2, 413), Tv(2526)]),
+    ((2530, false, XOR2), &[Arg(2, 414), Tv(2529)]),
+    ((2533, false, XOR2), &[Arg(2, 415), Tv(2532)]),
+    ((2536, false, XOR2), &[Arg(2, 416), Tv(2535)]),
+    ((2539, false, XOR2), &[Arg(2, 417), Tv(2538)]),
+    ((2542, false, XOR2), &[Arg(2, 418), Tv(2541)]),
+    ((2545, false, XOR2), &[Arg(2, 419), Tv(2544)]),
+    ((2548, false, XOR2), &[Arg(2, 420), Tv(2547)]),
+    ((2551, false, XOR2), &[Arg(2, 421), Tv(2550)]),
+    ((2554, false, XOR2), &[Arg(2, 422), Tv(2553)]),
+    ((2557, false, XOR2), &[Arg(2, 423), Tv(2556)]),
+    ((2560, false, XOR2), &[Arg(2, 424), Tv(2559)]),
+    ((2563, false, XOR2), &[Arg(2, 425), Tv(2562)]),
+    ((2566, false, XOR2), &[Arg(2, 426), Tv(2565)]),
+    ((2569, false, XOR2), &[Arg(2, 427), Tv(2568)]),
+    ((2572, false, XOR2), &[Arg(2, 428), Tv(2571)]),
+    ((2575, false, XOR2), &[Arg(2, 429), Tv(2574)]),
+    ((2578, false, XOR2), &[Arg(2, 430), Tv(2577)]),
+    ((2581, false, XOR2), &[Arg(2, 431), Tv(2580)]),
+    ((2583, false, NOR2), &[Tv(2582), Tv(710)]),
+    ((2586, false, XOR2), &[Arg(2, 480), Tv(2585)]),
+    ((2589, false, XOR2), &[Arg(2, 481), Tv(2588)]),
+    ((2592, false, XOR2), &[Arg(2, 482), Tv(2591)]),
+    ((2595, false, XOR2), &[Arg(2, 483), Tv(2594)]),
+    ((2598, false, XOR2), &[Arg(2, 484), Tv(2597)]),
+    ((2601, false, XOR2), &[Arg(2, 485), Tv(2600)]),
+    ((2604, false, XOR2), &[Arg(2, 486), Tv(2603)]),
+    ((2607, false, XOR2), &[Arg(2, 487), Tv(2606)]),
+    ((2610, false, XOR2), &[Arg(2, 504), Tv(2609)]),
+    ((2613, false, XOR2), &[Arg(2, 505), Tv(2612)]),
+    ((2616, false, XOR2), &[Arg(2, 506), Tv(2615)]),
+    ((2619, false, XOR2), &[Arg(2, 507), Tv(2618)]),
+    ((2622, false, XOR2), &[Arg(2, 508), Tv(2621)]),
+    ((2625, false, XOR2), &[Arg(2, 509), Tv(2624)]),
+    ((2628, false, XOR2), &[Arg(2, 510), Tv(2627)]),
+    ((2631, false, XOR2), &[Arg(2, 511), Tv(2630)]),
+    ((2634, false, XOR2), &[Arg(2, 512), Tv(2633)]),
+    ((2637, false, XOR2), &[Arg(2, 513), Tv(2636)]),
+    ((2640, false, XOR2), &[Arg(2, 514), Tv(2639)]),
+    ((2643, false, XOR2), &[Arg(2, 515), Tv(2642)]),
+    ((2646, false, XOR2), &[Arg(2, 516), Tv(2645)]),
+    ((2649, false, XOR2), &[Arg(2, 517), Tv(2648)]),
+    ((2652, false, XOR2), &[Arg(2, 518), Tv(2651)]),
+    ((2655, false, XOR2), &[Arg(2, 519), Tv(2654)]),
+    ((2658, false, XOR2), &[Arg(2, 520), Tv(2657)]),
+    ((2661, false, XOR2), &[Arg(2, 521), Tv(2660)]),
+    ((2664, false, XOR2), &[Arg(2, 522), Tv(2663)]),
+    ((2667, false, XOR2), &[Arg(2, 523), Tv(2666)]),
+    ((2670, false, XOR2), &[Arg(2, 524), Tv(2669)]),
+    ((2673, false, XOR2), &[Arg(2, 525), Tv(2672)]),
+    ((2676, false, XOR2), &[Arg(2, 526), Tv(2675)]),
+    ((2679, false, XOR2), &[Arg(2, 527), Tv(2678)]),
+    ((2681, false, NOR2), &[Tv(2680), Tv(820)]),
+    ((2684, false, XOR2), &[Arg(2, 576), Tv(2683)]),
+    ((2687, false, XOR2), &[Arg(2, 577), Tv(2686)]),
+    ((2690, false, XOR2), &[Arg(2, 578), Tv(2689)]),
+    ((2693, false, XOR2), &[Arg(2, 579), Tv(2692)]),
+    ((2696, false, XOR2), &[Arg(2, 580), Tv(2695)]),
+    ((2699, false, XOR2), &[Arg(2, 581), Tv(2698)]),
+    ((2702, false, XOR2), &[Arg(2, 582), Tv(2701)]),
+    ((2705, false, XOR2), &[Arg(2, 583), Tv(2704)]),
+    ((2708, false, XOR2), &[Arg(2, 600), Tv(2707)]),
+    ((2711, false, XOR2), &[Arg(2, 601), Tv(2710)]),
+    ((2714, false, XOR2), &[Arg(2, 602), Tv(2713)]),
+    ((2717, false, XOR2), &[Arg(2, 603), Tv(2716)]),
+    ((2720, false, XOR2), &[Arg(2, 604), Tv(2719)]),
+    ((2723, false, XOR2), &[Arg(2, 605), Tv(2722)]),
+    ((2726, false, XOR2), &[Arg(2, 606), Tv(2725)]),
+    ((2729, false, XOR2), &[Arg(2, 607), Tv(2728)]),
+    ((2732, false, XOR2), &[Arg(2, 608), Tv(2731)]),
+    ((2735, false, XOR2), &[Arg(2, 609), Tv(2734)]),
+    ((2738, false, XOR2), &[Arg(2, 610), Tv(2737)]),
+    ((2741, false, XOR2), &[Arg(2, 611), Tv(2740)]),
+    ((2744, false, XOR2), &[Arg(2, 612), Tv(2743)]),
+    ((2747, false, XOR2), &[Arg(2, 613), Tv(2746)]),
+    ((2750, false, XOR2), &[Arg(2, 614), Tv(2749)]),
+    ((2753, false, XOR2), &[Arg(2, 615), Tv(2752)]),
+    ((2756, false, XOR2), &[Arg(2, 616), Tv(2755)]),
+    ((2759, false, XOR2), &[Arg(2, 617), Tv(2758)]),
+    ((2762, false, XOR2), &[Arg(2, 618), Tv(2761)]),
+    ((2765, false, XOR2), &[Arg(2, 619), Tv(2764)]),
+    ((2768, false, XOR2), &[Arg(2, 620), Tv(2767)]),
+    ((2771, false, XOR2), &[Arg(2, 621), Tv(2770)]),
+    ((2774, false, XOR2), &[Arg(2, 622), Tv(2773)]),
+    ((2777, false, XOR2), &[Arg(2, 623), Tv(2776)]),
+    ((2779, false, NOR2), &[Tv(2778), Tv(930)]),
+    ((2782, false, XOR2), &[Arg(2, 672), Tv(2781)]),
+    ((2785, false, XOR2), &[Arg(2, 673), Tv(2784)]),
+    ((2788, false, XOR2), &[Arg(2, 674), Tv(2787)]),
+    ((2791, false, XOR2), &[Arg(2, 675), Tv(2790)]),
+    ((2794, false, XOR2), &[Arg(2, 676), Tv(2793)]),
+    ((2797, false, XOR2), &[Arg(2, 677), Tv(2796)]),
+    ((2800, false, XOR2), &[Arg(2, 678), Tv(2799)]),
+    ((2803, false, XOR2), &[Arg(2, 679), Tv(2802)]),
+    ((2806, false, XOR2), &[Arg(2, 696), Tv(2805)]),
+    ((2809, false, XOR2), &[Arg(2, 697), Tv(2808)]),
+    ((2812, false, XOR2), &[Arg(2, 698), Tv(2811)]),
+    ((2815, false, XOR2), &[Arg(2, 699), Tv(2814)]),
+    ((2818, false, XOR2), &[Arg(2, 700), Tv(2817)]),
+    ((2821, false, XOR2), &[Arg(2, 701), Tv(2820)]),
+    ((2824, false, XOR2), &[Arg(2, 702), Tv(2823)]),
+    ((2827, false, XOR2), &[Arg(2, 703), Tv(2826)]),
+    ((2830, false, XOR2), &[Arg(2, 704), Tv(2829)]),
+    ((2833, false, XOR2), &[Arg(2, 705), Tv(2832)]),
+    ((2836, false, XOR2), &[Arg(2, 706), Tv(2835)]),
+    ((2839, false, XOR2), &[Arg(2, 707), Tv(2838)]),
+    ((2842, false, XOR2), &[Arg(2, 708), Tv(2841)]),
+    ((2845, false, XOR2), &[Arg(2, 709), Tv(2844)]),
+    ((2848, false, XOR2), &[Arg(2, 710), Tv(2847)]),
+    ((2851, false, XOR2), &[Arg(2, 711), Tv(2850)]),
+    ((2854, false, XOR2), &[Arg(2, 712), Tv(2853)]),
+    ((2857, false, XOR2), &[Arg(2, 713), Tv(2856)]),
+    ((2860, false, XOR2), &[Arg(2, 714), Tv(2859)]),
+    ((2863, false, XOR2), &[Arg(2, 715), Tv(2862)]),
+    ((2866, false, XOR2), &[Arg(2, 716), Tv(2865)]),
+    ((2869, false, XOR2), &[Arg(2, 717), Tv(2868)]),
+    ((2872, false, XOR2), &[Arg(2, 718), Tv(2871)]),
+    ((2875, false, XOR2), &[Arg(2, 719), Tv(2874)]),
+    ((2877, false, NOR2), &[Tv(2876), Tv(1040)]),
+    ((2880, false, XOR2), &[Arg(2, 768), Tv(2879)]),
+    ((2883, false, XOR2), &[Arg(2, 769), Tv(2882)]),
+    ((2886, false, XOR2), &[Arg(2, 770), Tv(2885)]),
+    ((2889, false, XOR2), &[Arg(2, 771), Tv(2888)]),
+    ((2892, false, XOR2), &[Arg(2, 772), Tv(2891)]),
+    ((2895, false, XOR2), &[Arg(2, 773), Tv(2894)]),
+    ((2898, false, XOR2), &[Arg(2, 774), Tv(2897)]),
+    ((2901, false, XOR2), &[Arg(2, 775), Tv(2900)]),
+    ((2904, false, XOR2), &[Arg(2, 792), Tv(2903)]),
+    ((2907, false, XOR2), &[Arg(2, 793), Tv(2906)]),
+    ((2910, false, XOR2), &[Arg(2, 794), Tv(2909)]),
+    ((2913, false, XOR2), &[Arg(2, 795), Tv(2912)]),
+    ((2916, false, XOR2), &[Arg(2, 796), Tv(2915)]),
+    ((2919, false, XOR2), &[Arg(2, 797), Tv(2918)]),
+    ((2922, false, XOR2), &[Arg(2, 798), Tv(2921)]),
+    ((2925, false, XOR2), &[Arg(2, 799), Tv(2924)]),
+    ((2928, false, XOR2), &[Arg(2, 800), Tv(2927)]),
+    ((2931, false, XOR2), &[Arg(2, 801), Tv(2930)]),
+    ((2934, false, XOR2), &[Arg(2, 802), Tv(2933)]),
+    ((2937, false, XOR2), &[Arg(2, 803), Tv(2936)]),
+    ((2940, false, XOR2), &[Arg(2, 804), Tv(2939)]),
+    ((2943, false, XOR2), &[Arg(2, 805), Tv(2942)]),
+    ((2946, false, XOR2), &[Arg(2, 806), Tv(2945)]),
+    ((2949, false, XOR2), &[Arg(2, 807), Tv(2948)]),
+    ((2952, false, XOR2), &[Arg(2, 808), Tv(2951)]),
+    ((2955, false, XOR2), &[Arg(2, 809), Tv(2954)]),
+    ((2958, false, XOR2), &[Arg(2, 810), Tv(2957)]),
+    ((2961, false, XOR2), &[Arg(2, 811), Tv(2960)]),
+    ((2964, false, XOR2), &[Arg(2, 812), Tv(2963)]),
+    ((2967, false, XOR2), &[Arg(2, 813), Tv(2966)]),
+    ((2970, false, XOR2), &[Arg(2, 814), Tv(2969)]),
+    ((2973, false, XOR2), &[Arg(2, 815), Tv(2972)]),
+    ((2975, false, NOR2), &[Tv(2974), Tv(1150)]),
+    ((2978, false, XOR2), &[Arg(2, 864), Tv(2977)]),
+    ((2981, false, XOR2), &[Arg(2, 865), Tv(2980)]),
+    ((2984, false, XOR2), &[Arg(2, 866), Tv(2983)]),
+    ((2987, false, XOR2), &[Arg(2, 867), Tv(2986)]),
+    ((2990, false, XOR2), &[Arg(2, 868), Tv(2989)]),
+    ((2993, false, XOR2), &[Arg(2, 869), Tv(2992)]),
+    ((2996, false, XOR2), &[Arg(2, 870), Tv(2995)]),
+    ((2999, false, XOR2), &[Arg(2, 871), Tv(2998)]),
+    ((3002, false, XOR2), &[Arg(2, 888), Tv(3001)]),
+    ((3005, false, XOR2), &[Arg(2, 889), Tv(3004)]),
+    ((3008, false, XOR2), &[Arg(2, 890), Tv(3007)]),
+    ((3011, false, XOR2), &[Arg(2, 891), Tv(3010)]),
+    ((3014, false, XOR2), &[Arg(2, 892), Tv(3013)]),
+    ((3017, false, XOR2), &[Arg(2, 893), Tv(3016)]),
+    ((3020, false, XOR2), &[Arg(2, 894), Tv(3019)]),
+    ((3023, false, XOR2), &[Arg(2, 895), Tv(3022)]),
+    ((3026, false, XOR2), &[Arg(2, 896), Tv(3025)]),
+    ((3029, false, XOR2), &[Arg(2, 897), Tv(3028)]),
+    ((3032, false, XOR2), &[Arg(2, 898), Tv(3031)]),
+    ((3035, false, XOR2), &[Arg(2, 899), Tv(3034)]),
+    ((3038, false, XOR2), &[Arg(2, 900), Tv(3037)]),
+    ((3041, false, XOR2), &[Arg(2, 901), Tv(3040)]),
+    ((3044, false, XOR2), &[Arg(2, 902), Tv(3043)]),
+    ((3047, false, XOR2), &[Arg(2, 903), Tv(3046)]),
+    ((3050, false, XOR2), &[Arg(2, 904), Tv(3049)]),
+    ((3053, false, XOR2), &[Arg(2, 905), Tv(3052)]),
+    ((3056, false, XOR2), &[Arg(2, 906), Tv(3055)]),
+    ((3059, false, XOR2), &[Arg(2, 907), Tv(3058)]),
+    ((3062, false, XOR2), &[Arg(2, 908), Tv(3061)]),
+    ((3065, false, XOR2), &[Arg(2, 909), Tv(3064)]),
+    ((3068, false, XOR2), &[Arg(2, 910), Tv(3067)]),
+    ((3071, false, XOR2), &[Arg(2, 911), Tv(3070)]),
+    ((3073, false, NOR2), &[Tv(3072), Tv(1260)]),
+    ((3076, false, XOR2), &[Arg(2, 960), Tv(3075)]),
+    ((3079, false, XOR2), &[Arg(2, 961), Tv(3078)]),
+    ((3082, false, XOR2), &[Arg(2, 962), Tv(3081)]),
+    ((3085, false, XOR2), &[Arg(2, 963), Tv(3084)]),
+    ((3088, false, XOR2), &[Arg(2, 964), Tv(3087)]),
+    ((3091, false, XOR2), &[Arg(2, 965), Tv(3090)]),
+    ((3094, false, XOR2), &[Arg(2, 966), Tv(3093)]),
+    ((3097, false, XOR2), &[Arg(2, 967), Tv(3096)]),
+    ((3100, false, XOR2), &[Arg(2, 984), Tv(3099)]),
+    ((3103, false, XOR2), &[Arg(2, 985), Tv(3102)]),
+    ((3106, false, XOR2), &[Arg(2, 986), Tv(3105)]),
+    ((3109, false, XOR2), &[Arg(2, 987), Tv(3108)]),
+    ((3112, false, XOR2), &[Arg(2, 988), Tv(3111)]),
+    ((3115, false, XOR2), &[Arg(2, 989), Tv(3114)]),
+    ((3118, false, XOR2), &[Arg(2, 990), Tv(3117)]),
+    ((3121, false, XOR2), &[Arg(2, 991), Tv(3120)]),
+    ((3124, false, XOR2), &[Arg(2, 992), Tv(3123)]),
+    ((3127, false, XOR2), &[Arg(2, 993), Tv(3126)]),
+    ((3130, false, XOR2), &[Arg(2, 994), Tv(3129)]),
+    ((3133, false, XOR2), &[Arg(2, 995), Tv(3132)]),
+    ((3136, false, XOR2), &[Arg(2, 996), Tv(3135)]),
+    ((3139, false, XOR2), &[Arg(2, 997), Tv(3138)]),
+    ((3142, false, XOR2), &[Arg(2, 998), Tv(3141)]),
+    ((3145, false, XOR2), &[Arg(2, 999), Tv(3144)]),
+    ((3148, false, XOR2), &[Arg(2, 1000), Tv(3147)]),
+    ((3151, false, XOR2), &[Arg(2, 1001), Tv(3150)]),
+    ((3154, false, XOR2), &[Arg(2, 1002), Tv(3153)]),
+    ((3157, false, XOR2), &[Arg(2, 1003), Tv(3156)]),
+    ((3160, false, XOR2), &[Arg(2, 1004), Tv(3159)]),
+    ((3163, false, XOR2), &[Arg(2, 1005), Tv(3162)]),
+    ((3166, false, XOR2), &[Arg(2, 1006), Tv(3165)]),
+    ((3169, false, XOR2), &[Arg(2, 1007), Tv(3168)]),
+    ((3171, false, NOR2), &[Tv(3170), Tv(1364)]),
+    ((3174, false, XOR2), &[Arg(2, 1056), Tv(3173)]),
+    ((3177, false, XOR2), &[Arg(2, 1057), Tv(3176)]),
+    ((3180, false, XOR2), &[Arg(2, 1058), Tv(3179)]),
+    ((3183, false, XOR2), &[Arg(2, 1059), Tv(3182)]),
+    ((3186, false, XOR2), &[Arg(2, 1060), Tv(3185)]),
+    ((3189, false, XOR2), &[Arg(2, 1061), Tv(3188)]),
+    ((3192, false, XOR2), &[Arg(2, 1062), Tv(3191)]),
+    ((3195, false, XOR2), &[Arg(2, 1063), Tv(3194)]),
+    ((3198, false, XOR2), &[Arg(2, 1080), Tv(3197)]),
+    ((3201, false, XOR2), &[Arg(2, 1081), Tv(3200)]),
+    ((3204, false, XOR2), &[Arg(2, 1082), Tv(3203)]),
+    ((3207, false, XOR2), &[Arg(2, 1083), Tv(3206)]),
+    ((3210, false, XOR2), &[Arg(2, 1084), Tv(3209)]),
+    ((3213, false, XOR2), &[Arg(2, 1085), Tv(3212)]),
+    ((3216, false, XOR2), &[Arg(2, 1086), Tv(3215)]),
+    ((3219, false, XOR2), &[Arg(2, 1087), Tv(3218)]),
+    ((3222, false, XOR2), &[Arg(2, 1088), Tv(3221)]),
+    ((3225, false, XOR2), &[Arg(2, 1089), Tv(3224)]),
+    ((3228, false, XOR2), &[Arg(2, 1090), Tv(3227)]),
+    ((3231, false, XOR2), &[Arg(2, 1091), Tv(3230)]),
+    ((3234, false, XOR2), &[Arg(2, 1092), Tv(3233)]),
+    ((3237, false, XOR2), &[Arg(2, 1093), Tv(3236)]),
+    ((3240, false, XOR2), &[Arg(2, 1094), Tv(3239)]),
+    ((3243, false, XOR2), &[Arg(2, 1095), Tv(3242)]),
+    ((3246, false, XOR2), &[Arg(2, 1096), Tv(3245)]),
+    ((3249, false, XOR2), &[Arg(2, 1097), Tv(3248)]),
+    ((3252, false, XOR2), &[Arg(2, 1098), Tv(3251)]),
+    ((3255, false, XOR2), &[Arg(2, 1099), Tv(3254)]),
+    ((3258, false, XOR2), &[Arg(2, 1100), Tv(3257)]),
+    ((3261, false, XOR2), &[Arg(2, 1101), Tv(3260)]),
+    ((3264, false, XOR2), &[Arg(2, 1102), Tv(3263)]),
+    ((3267, false, XOR2), &[Arg(2, 1103), Tv(3266)]),
+    ((3269, false, NOR2), &[Tv(3268), Tv(1462)]),
+    ((3272, false, XOR2), &[Arg(3, 57), Tv(3271)]),
+    ((3275, false, XOR2), &[Arg(3, 58), Tv(3274)]),
+    ((3278, false, XOR2), &[Arg(3, 59), Tv(3277)]),
+    ((3281, false, XOR2), &[Arg(3, 60), Tv(3280)]),
+    ((3284, false, XOR2), &[Arg(3, 61), Tv(3283)]),
+    ((3287, false, XOR2), &[Arg(3, 62), Tv(3286)]),
+    ((3290, false, XOR2), &[Arg(3, 63), Tv(3289)]),
+    ((3293, false, XOR2), &[Arg(3, 64), Tv(3292)]),
+    ((3296, false, XOR2), &[Arg(3, 81), Tv(3295)]),
+    ((3299, false, XOR2), &[Arg(3, 82), Tv(3298)]),
+    ((3302, false, XOR2), &[Arg(3, 83), Tv(3301)]),
+    ((3305, false, XOR2), &[Arg(3, 84), Tv(3304)]),
+    ((3308, false, XOR2), &[Arg(3, 85), Tv(3307)]),
+    ((3311, false, XOR2), &[Arg(3, 86), Tv(3310)]),
+    ((3314, false, XOR2), &[Arg(3, 87), Tv(3313)]),
+    ((3317, false, XOR2), &[Arg(3, 88), Tv(3316)]),
+    ((3320, false, XOR2), &[Arg(3, 89), Tv(3319)]),
+    ((3323, false, XOR2), &[Arg(3, 90), Tv(3322)]),
+    ((3326, false, XOR2), &[Arg(3, 91), Tv(3325)]),
+    ((3329, false, XOR2), &[Arg(3, 92), Tv(3328)]),
+    ((3332, false, XOR2), &[Arg(3, 93), Tv(3331)]),
+    ((3335, false, XOR2), &[Arg(3, 94), Tv(3334)]),
+    ((3338, false, XOR2), &[Arg(3, 95), Tv(3337)]),
+    ((3341, false, XOR2), &[Arg(3, 96), Tv(3340)]),
+    ((3344, false, XOR2), &[Arg(3, 98), Tv(3343)]),
+    ((3347, false, XOR2), &[Arg(3, 99), Tv(3346)]),
+    ((3350, false, XOR2), &[Arg(3, 100), Tv(3349)]),
+    ((3353, false, XOR2), &[Arg(3, 101), Tv(3352)]),
+    ((3356, false, XOR2), &[Arg(3, 102), Tv(3355)]),
+    ((3359, false, XOR2), &[Arg(3, 103), Tv(3358)]),
+    ((3362, false, XOR2), &[Arg(3, 104), Tv(3361)]),
+    ((3365, false, XOR2), &[Arg(3, 105), Tv(3364)]),
+    ((3367, false, NOR2), &[Tv(3366), Tv(1560)]),
+    ((3370, false, XOR2), &[Arg(3, 171), Tv(3369)]),
+    ((3373, false, XOR2), &[Arg(3, 172), Tv(3372)]),
+    ((3376, false, XOR2), &[Arg(3, 173), Tv(3375)]),
+    ((3379, false, XOR2), &[Arg(3, 174), Tv(3378)]),
+    ((3382, false, XOR2), &[Arg(3, 175), Tv(3381)]),
+    ((3385, false, XOR2), &[Arg(3, 176), Tv(3384)]),
+    ((3388, false, XOR2), &[Arg(3, 177), Tv(3387)]),
+    ((3391, false, XOR2), &[Arg(3, 178), Tv(3390)]),
+    ((3394, false, XOR2), &[Arg(3, 195), Tv(3393)]),
+    ((3397, false, XOR2), &[Arg(3, 196), Tv(3396)]),
+    ((3400, false, XOR2), &[Arg(3, 197), Tv(3399)]),
+    ((3403, false, XOR2), &[Arg(3, 198), Tv(3402)]),
+    ((3406, false, XOR2), &[Arg(3, 199), Tv(3405)]),
+    ((3409, false, XOR2), &[Arg(3, 200), Tv(3408)]),
+    ((3412, false, XOR2), &[Arg(3, 201), Tv(3411)]),
+    ((3415, false, XOR2), &[Arg(3, 202), Tv(3414)]),
+    ((3418, false, XOR2), &[Arg(3, 203), Tv(3417)]),
+    ((3421, false, XOR2), &[Arg(3, 204), Tv(3420)]),
+    ((3424, false, XOR2), &[Arg(3, 205), Tv(3423)]),
+    ((3427, false, XOR2), &[Arg(3, 206), Tv(3426)]),
+    ((3430, false, XOR2), &[Arg(3, 207), Tv(3429)]),
+    ((3433, false, XOR2), &[Arg(3, 208), Tv(3432)]),
+    ((3436, false, XOR2), &[Arg(3, 209), Tv(3435)]),
+    ((3439, false, XOR2), &[Arg(3, 210), Tv(3438)]),
+    ((3442, false, XOR2), &[Arg(3, 212), Tv(3441)]),
+    ((3445, false, XOR2), &[Arg(3, 213), Tv(3444)]),
+    ((3448, false, XOR2), &[Arg(3, 214), Tv(3447)]),
+    ((3451, false, XOR2), &[Arg(3, 215), Tv(3450)]),
+    ((3454, false, XOR2), &[Arg(3, 216), Tv(3453)]),
+    ((3457, false, XOR2), &[Arg(3, 217), Tv(3456)]),
+    ((3460, false, XOR2), &[Arg(3, 218), Tv(3459)]),
+    ((3463, false, XOR2), &[Arg(3, 219), Tv(3462)]),
+    ((3465, false, NOR2), &[Tv(3464), Tv(1658)]),
+    ((3468, false, XOR2), &[Arg(3, 285), Tv(3467)]),
+    ((3471, false, XOR2), &[Arg(3, 286), Tv(3470)]),
+    ((3474, false, XOR2), &[Arg(3, 287), Tv(3473)]),
+    ((3477, false, XOR2), &[Arg(3, 288), Tv(3476)]),
+    ((3480, false, XOR2), &[Arg(3, 289), Tv(3479)]),
+    ((3483, false, XOR2), &[Arg(3, 290), Tv(3482)]),
+    ((3486, false, XOR2), &[Arg(3, 291), Tv(3485)]),
+    ((3489, false, XOR2), &[Arg(3, 292), Tv(3488)]),
+    ((3492, false, XOR2), &[Arg(3, 309), Tv(3491)]),
+    ((3495, false, XOR2), &[Arg(3, 310), Tv(3494)]),
+    ((3498, false, XOR2), &[Arg(3, 311), Tv(3497)]),
+    ((3501, false, XOR2), &[Arg(3, 312), Tv(3500)]),
+    ((3504, false, XOR2), &[Arg(3, 313), Tv(3503)]),
+    ((3507, false, XOR2), &[Arg(3, 314), Tv(3506)]),
+    ((3510, false, XOR2), &[Arg(3, 315), Tv(3509)]),
+    ((3513, false, XOR2), &[Arg(3, 316), Tv(3512)]),
+    ((3516, false, XOR2), &[Arg(3, 317), Tv(3515)]),
+    ((3519, false, XOR2), &[Arg(3, 318), Tv(3518)]),
+    ((3522, false, XOR2), &[Arg(3, 319), Tv(3521)]),
+    ((3525, false, XOR2), &[Arg(3, 320), Tv(3524)]),
+    ((3528, false, XOR2), &[Arg(3, 321), Tv(3527)]),
+    ((3531, false, XOR2), &[Arg(3, 322), Tv(3530)]),
+    ((3534, false, XOR2), &[Arg(3, 323), Tv(3533)]),
+    ((3537, false, XOR2), &[Arg(3, 324), Tv(3536)]),
+    ((3540, false, XOR2), &[Arg(3, 326), Tv(3539)]),
+    ((3543, false, XOR2), &[Arg(3, 327), Tv(3542)]),
+    ((3546, false, XOR2), &[Arg(3, 328), Tv(3545)]),
+    ((3549, false, XOR2), &[Arg(3, 329), Tv(3548)]),
+    ((3552, false, XOR2), &[Arg(3, 330), Tv(3551)]),
+    ((3555, false, XOR2), &[Arg(3, 331), Tv(3554)]),
+    ((3558, false, XOR2), &[Arg(3, 332), Tv(3557)]),
+    ((3561, false, XOR2), &[Arg(3, 333), Tv(3560)]),
+    ((3563, false, NOR2), &[Tv(3562), Tv(1756)]),
+    ((3566, false, XOR2), &[Arg(3, 399), Tv(3565)]),
+    ((3569, false, XOR2), &[Arg(3, 400), Tv(3568)]),
+    ((3572, false, XOR2), &[Arg(3, 401), Tv(3571)]),
+    ((3575, false, XOR2), &[Arg(3, 402), Tv(3574)]),
+    ((3578, false, XOR2), &[Arg(3, 403), Tv(3577)]),
+    ((3581, false, XOR2), &[Arg(3, 404), Tv(3580)]),
+    ((3584, false, XOR2), &[Arg(3, 405), Tv(3583)]),
+    ((3587, false, XOR2), &[Arg(3, 406), Tv(3586)]),
+    ((3590, false, XOR2), &[Arg(3, 423), Tv(3589)]),
+    ((3593, false, XOR2), &[Arg(3, 424), Tv(3592)]),
+    ((3596, false, XOR2), &[Arg(3, 425), Tv(3595)]),
+    ((3599, false, XOR2), &[Arg(3, 426), Tv(3598)]),
+    ((3602, false, XOR2), &[Arg(3, 427), Tv(3601)]),
+    ((3605, false, XOR2), &[Arg(3, 428), Tv(3604)]),
+    ((3608, false, XOR2), &[Arg(3, 429), Tv(3607)]),
+    ((3611, false, XOR2), &[Arg(3, 430), Tv(3610)]),
+    ((3614, false, XOR2), &[Arg(3, 431), Tv(3613)]),
+    ((3617, false, XOR2), &[Arg(3, 432), Tv(3616)]),
+    ((3620, false, XOR2), &[Arg(3, 433), Tv(3619)]),
+    ((3623, false, XOR2), &[Arg(3, 434), Tv(3622)]),
+    ((3626, false, XOR2), &[Arg(3, 435), Tv(3625)]),
+    ((3629, false, XOR2), &[Arg(3, 436), Tv(3628)]),
+    ((3632, false, XOR2), &[Arg(3, 437), Tv(3631)]),
+    ((3635, false, XOR2), &[Arg(3, 438), Tv(3634)]),
+    ((3638, false, XOR2), &[Arg(3, 440), Tv(3637)]),
+    ((3641, false, XOR2), &[Arg(3, 441), Tv(3640)]),
+    ((3644, false, XOR2), &[Arg(3, 442), Tv(3643)]),
+    ((3647, false, XOR2), &[Arg(3, 443), Tv(3646)]),
+    ((3650, false, XOR2), &[Arg(3, 444), Tv(3649)]),
+    ((3653, false, XOR2), &[Arg(3, 445), Tv(3652)]),
+    ((3656, false, XOR2), &[Arg(3, 446), Tv(3655)]),
+    ((3659, false, XOR2), &[Arg(3, 447), Tv(3658)]),
+    ((3661, false, NOR2), &[Tv(3660), Tv(1854)]),
+    ((3664, false, XOR2), &[Arg(3, 513), Tv(3663)]),
+    ((3667, false, XOR2), &[Arg(3, 514), Tv(3666)]),
+    ((3670, false, XOR2), &[Arg(3, 515), Tv(3669)]),
+    ((3673, false, XOR2), &[Arg(3, 516), Tv(3672)]),
+    ((3676, false, XOR2), &[Arg(3, 517), Tv(3675)]),
+    ((3679, false, XOR2), &[Arg(3, 518), Tv(3678)]),
+    ((3682, false, XOR2), &[Arg(3, 519), Tv(3681)]),
+    ((3685, false, XOR2), &[Arg(3, 520), Tv(3684)]),
+    ((3688, false, XOR2), &[Arg(3, 537), Tv(3687)]),
+    ((3691, false, XOR2), &[Arg(3, 538), Tv(3690)]),
+    ((3694, false, XOR2), &[Arg(3, 539), Tv(3693)]),
+    ((3697, false, XOR2), &[Arg(3, 540), Tv(3696)]),
+    ((3700, false, XOR2), &[Arg(3, 541), Tv(3699)]),
+    ((3703, false, XOR2), &[Arg(3, 542), Tv(3702)]),
+    ((3706, false, XOR2), &[Arg(3, 543), Tv(3705)]),
+    ((3709, false, XOR2), &[Arg(3, 544), Tv(3708)]),
+    ((3712, false, XOR2), &[Arg(3, 545), Tv(3711)]),
+    ((3715, false, XOR2), &[Arg(3, 546), Tv(3714)]),
+    ((3718, false, XOR2), &[Arg(3, 547), Tv(3717)]),
+    ((3721, false, XOR2), &[Arg(3, 548), Tv(3720)]),
+    ((3724, false, XOR2), &[Arg(3, 549), Tv(3723)]),
+    ((3727, false, XOR2), &[Arg(3, 550), Tv(3726)]),
+    ((3730, false, XOR2), &[Arg(3, 551), Tv(3729)]),
+    ((3733, false, XOR2), &[Arg(3, 552), Tv(3732)]),
+    ((3736, false, XOR2), &[Arg(3, 554), Tv(3735)]),
+    ((3739, false, XOR2), &[Arg(3, 555), Tv(3738)]),
+    ((3742, false, XOR2), &[Arg(3, 556), Tv(3741)]),
+    ((3745, false, XOR2), &[Arg(3, 557), Tv(3744)]),
+    ((3748, false, XOR2), &[Arg(3, 558), Tv(3747)]),
+    ((3751, false, XOR2), &[Arg(3, 559), Tv(3750)]),
+    ((3754, false, XOR2), &[Arg(3, 560), Tv(3753)]),
+    ((3757, false, XOR2), &[Arg(3, 561), Tv(3756)]),
+    ((3759, false, NOR2), &[Tv(3758), Tv(1950)]),
+    ((4839, false, AND2), &[Tv(4838), Tv(3857)]),
+    ((4840, false, XOR2), &[Arg(4, 144), Tv(3860)]),
+    ((4843, false, XOR2), &[Arg(4, 145), Tv(3863)]),
+    ((4846, false, XOR2), &[Arg(4, 146), Tv(3866)]),
+    ((4849, false, XOR2), &[Arg(4, 147), Tv(3869)]),
+    ((4852, false, XOR2), &[Arg(4, 148), Tv(3872)]),
+    ((4855, false, XOR2), &[Arg(4, 149), Tv(3875)]),
+    ((4858, false, XOR2), &[Arg(4, 150), Tv(3878)]),
+    ((4861, false, XOR2), &[Arg(4, 151), Tv(3881)]),
+    ((4864, false, XOR2), &[Arg(4, 168), Tv(3884)]),
+    ((4867, false, XOR2), &[Arg(4, 169), Tv(3887)]),
+    ((4870, false, XOR2), &[Arg(4, 170), Tv(3890)]),
+    ((4873, false, XOR2), &[Arg(4, 171), Tv(3893)]),
+    ((4876, false, XOR2), &[Arg(4, 172), Tv(3896)]),
+    ((4879, false, XOR2), &[Arg(4, 173), Tv(3899)]),
+    ((4882, false, XOR2), &[Arg(4, 174), Tv(3902)]),
+    ((4885, false, XOR2), &[Arg(4, 175), Tv(3905)]),
+    ((4888, false, XOR2), &[Arg(4, 176), Tv(3908)]),
+    ((4891, false, XOR2), &[Arg(4, 177), Tv(3911)]),
+    ((4894, false, XOR2), &[Arg(4, 178), Tv(3914)]),
+    ((4897, false, XOR2), &[Arg(4, 179), Tv(3917)]),
+    ((4900, false, XOR2), &[Arg(4, 180), Tv(3920)]),
+    ((4903, false, XOR2), &[Arg(4, 181), Tv(3923)]),
+    ((4906, false, XOR2), &[Arg(4, 182), Tv(3926)]),
+    ((4909, false, XOR2), &[Arg(4, 183), Tv(3929)]),
+    ((4912, false, XOR2), &[Arg(4, 184), Tv(3932)]),
+    ((4915, false, XOR2), &[Arg(4, 185), Tv(3935)]),
+    ((4918, false, XOR2), &[Arg(4, 186), Tv(3938)]),
+    ((4921, false, XOR2), &[Arg(4, 187), Tv(3941)]),
+    ((4924, false, XOR2), &[Arg(4, 188), Tv(3944)]),
+    ((4927, false, XOR2), &[Arg(4, 189), Tv(3947)]),
+    ((4930, false, XOR2), &[Arg(4, 190), Tv(3950)]),
+    ((4933, false, XOR2), &[Arg(4, 191), Tv(3953)]),
+    ((5928, false, AND2), &[Tv(5900), Tv(5927)]),
+    ((5946, false, AND2), &[Tv(5931), Tv(5945)]),
+    ((5948, false, AND2), &[Tv(5935), Tv(5947)]),
+    ((5951, false, AND2), &[Tv(5939), Tv(5950)]),
+    ((6001, false, AND2), &[Tv(5985), Tv(6000)]),
+    ((6012, false, AND2), &[Tv(6004), Tv(6011)]),
+    ((6014, false, AND2), &[Tv(6007), Tv(6013)]),
+    ((6017, false, AND2), &[Tv(6010), Tv(6016)]),
+    ((6125, false, AND2), &[Tv(6097), Tv(6124)]),
+    ((6143, false, AND2), &[Tv(6128), Tv(6142)]),
+    ((6145, false, AND2), &[Tv(6132), Tv(6144)]),
+    ((6148, false, AND2), &[Tv(6136), Tv(6147)]),
+    ((6198, false, AND2), &[Tv(6182), Tv(6197)]),
+    ((6209, false, AND2), &[Tv(6201), Tv(6208)]),
+    ((6211, false, AND2), &[Tv(6204), Tv(6210)]),
+    ((6214, false, AND2), &[Tv(6207), Tv(6213)]),
 ];
 
-static LEVEL_29: [((usize, bool, CellType), &[GateInput]); 163] = [
-    ((2833, false, NAND2), &[Arg(0, 13), Tv(408)]),
-    ((2850, false, NAND2), &[Tv(2834), Tv(2849)]),
-    ((2855, false, AND2), &[Tv(2830), Tv(2854)]),
-    ((2859, false, AND2), &[Tv(2857), Tv(2858)]),
-    ((2863, false, AND2), &[Tv(2861), Tv(2862)]),
-    ((2867, false, AND2), &[Tv(2864), Tv(2866)]),
-    ((2880, false, NAND2), &[Tv(2837), Tv(2879)]),
-    ((2890, false, XNOR2), &[Arg(0, 5), Arg(1, 5)]),
-    ((2894, false, NAND2), &[Tv(385), Arg(1, 4)]),
-    ((2909, false, NAND2), &[Tv(2895), Tv(2908)]),
-    ((2914, false, XNOR2), &[Arg(0, 2), Arg(1, 2)]),
-    ((2916, false, XNOR2), &[Arg(0, 0), Arg(1, 0)]),
-    ((2918, false, XNOR2), &[Arg(0, 4), Arg(1, 4)]),
-    ((2919, false, XNOR2), &[Arg(0, 3), Arg(1, 3)]),
-    ((2920, false, XNOR2), &[Arg(0, 1), Arg(1, 1)]),
-    ((2923, false, XNOR2), &[Arg(0, 7), Arg(1, 7)]),
-    ((2925, false, XNOR2), &[Arg(0, 6), Arg(1, 6)]),
-    ((2940, false, NAND2), &[Tv(2899), Tv(2939)]),
-    ((3083, false, NAND2), &[Tv(2704), Tv(3082)]),
-    ((3085, false, AND2), &[Tv(982), Tv(3084)]),
-    ((3087, false, OR2), &[Arg(3, 0), Tv(982)]),
-    ((3090, false, NOR2), &[Tv(1222), Tv(1271)]),
-    ((3193, false, NAND2), &[Tv(2704), Tv(3192)]),
-    ((3195, false, AND2), &[Tv(982), Tv(3194)]),
-    ((3197, false, OR2), &[Arg(3, 1), Tv(982)]),
-    ((3300, false, NAND2), &[Tv(2704), Tv(3299)]),
-    ((3302, false, AND2), &[Tv(982), Tv(3301)]),
-    ((3304, false, OR2), &[Arg(3, 2), Tv(982)]),
-    ((3407, false, NAND2), &[Tv(2704), Tv(3406)]),
-    ((3409, false, AND2), &[Tv(982), Tv(3408)]),
-    ((3411, false, OR2), &[Arg(3, 3), Tv(982)]),
-    ((3514, false, NAND2), &[Tv(2704), Tv(3513)]),
-    ((3516, false, AND2), &[Tv(982), Tv(3515)]),
-    ((3518, false, OR2), &[Arg(3, 4), Tv(982)]),
-    ((3621, false, NAND2), &[Tv(2704), Tv(3620)]),
-    ((3623, false, AND2), &[Tv(982), Tv(3622)]),
-    ((3625, false, OR2), &[Arg(3, 5), Tv(982)]),
-    ((3728, false, NAND2), &[Tv(2704), Tv(3727)]),
-    ((3730, false, AND2), &[Tv(982), Tv(3729)]),
-    ((3732, false, OR2), &[Arg(3, 6), Tv(982)]),
-    ((3835, false, NAND2), &[Tv(2704), Tv(3834)]),
-    ((3837, false, AND2), &[Tv(982), Tv(3836)]),
-    ((3839, false, OR2), &[Arg(3, 7), Tv(982)]),
-    ((3942, false, NAND2), &[Tv(2704), Tv(3941)]),
-    ((3944, false, AND2), &[Tv(982), Tv(3943)]),
-    ((3946, false, OR2), &[Arg(3, 24), Tv(982)]),
-    ((4049, false, NAND2), &[Tv(2704), Tv(4048)]),
-    ((4051, false, AND2), &[Tv(982), Tv(4050)]),
-    ((4053, false, OR2), &[Arg(3, 25), Tv(982)]),
-    ((4156, false, NAND2), &[Tv(2704), Tv(4155)]),
-    ((4158, false, AND2), &[Tv(982), Tv(4157)]),
-    ((4160, false, OR2), &[Arg(3, 26), Tv(982)]),
-    ((4263, false, NAND2), &[Tv(2704), Tv(4262)]),
-    ((4265, false, AND2), &[Tv(982), Tv(4264)]),
-    ((4267, false, OR2), &[Arg(3, 27), Tv(982)]),
-    ((4370, false, NAND2), &[Tv(2704), Tv(4369)]),
-    ((4372, false, AND2), &[Tv(982), Tv(4371)]),
-    ((4374, false, OR2), &[Arg(3, 28), Tv(982)]),
-    ((4477, false, NAND2), &[Tv(2704), Tv(4476)]),
-    ((4479, false, AND2), &[Tv(982), Tv(4478)]),
-    ((4481, false, OR2), &[Arg(3, 29), Tv(982)]),
-    ((4584, false, NAND2), &[Tv(2704), Tv(4583)]),
-    ((4586, false, AND2), &[Tv(982), Tv(4585)]),
-    ((4588, false, OR2), &[Arg(3, 30), Tv(982)]),
-    ((4691, false, NAND2), &[Tv(2704), Tv(4690)]),
-    ((4693, false, AND2), &[Tv(982), Tv(4692)]),
-    ((4695, false, OR2), &[Arg(3, 31), Tv(982)]),
-    ((4798, false, NAND2), &[Tv(2704), Tv(4797)]),
-    ((4800, false, AND2), &[Tv(982), Tv(4799)]),
-    ((4802, false, OR2), &[Arg(3, 32), Tv(982)]),
-    ((4905, false, NAND2), &[Tv(2704), Tv(4904)]),
-    ((4907, false, AND2), &[Tv(982), Tv(4906)]),
-    ((4909, false, OR2), &[Arg(3, 33), Tv(982)]),
-    ((5012, false, NAND2), &[Tv(2704), Tv(5011)]),
-    ((5014, false, AND2), &[Tv(982), Tv(5013)]),
-    ((5016, false, OR2), &[Arg(3, 34), Tv(982)]),
-    ((5119, false, NAND2), &[Tv(2704), Tv(5118)]),
-    ((5121, false, AND2), &[Tv(982), Tv(5120)]),
-    ((5123, false, OR2), &[Arg(3, 35), Tv(982)]),
-    ((5226, false, NAND2), &[Tv(2704), Tv(5225)]),
-    ((5228, false, AND2), &[Tv(982), Tv(5227)]),
-    ((5230, false, OR2), &[Arg(3, 36), Tv(982)]),
-    ((5333, false, NAND2), &[Tv(2704), Tv(5332)]),
-    ((5335, false, AND2), &[Tv(982), Tv(5334)]),
-    ((5337, false, OR2), &[Arg(3, 37), Tv(982)]),
-    ((5440, false, NAND2), &[Tv(2704), Tv(5439)]),
-    ((5442, false, AND2), &[Tv(982), Tv(5441)]),
-    ((5444, false, OR2), &[Arg(3, 38), Tv(982)]),
-    ((5547, false, NAND2), &[Tv(2704), Tv(5546)]),
-    ((5549, false, AND2), &[Tv(982), Tv(5548)]),
-    ((5551, false, OR2), &[Arg(3, 39), Tv(982)]),
-    ((5654, false, NAND2), &[Tv(2704), Tv(5653)]),
-    ((5656, false, AND2), &[Tv(982), Tv(5655)]),
-    ((5658, false, OR2), &[Arg(3, 41), Tv(982)]),
-    ((5761, false, NAND2), &[Tv(2704), Tv(5760)]),
-    ((5763, false, AND2), &[Tv(982), Tv(5762)]),
-    ((5765, false, OR2), &[Arg(3, 42), Tv(982)]),
-    ((5868, false, NAND2), &[Tv(2704), Tv(5867)]),
-    ((5870, false, AND2), &[Tv(982), Tv(5869)]),
-    ((5872, false, OR2), &[Arg(3, 43), Tv(982)]),
-    ((5975, false, NAND2), &[Tv(2704), Tv(5974)]),
-    ((5977, false, AND2), &[Tv(982), Tv(5976)]),
-    ((5979, false, OR2), &[Arg(3, 44), Tv(982)]),
-    ((6082, false, NAND2), &[Tv(2704), Tv(6081)]),
-    ((6084, false, AND2), &[Tv(982), Tv(6083)]),
-    ((6086, false, OR2), &[Arg(3, 45), Tv(982)]),
-    ((6189, false, NAND2), &[Tv(2704), Tv(6188)]),
-    ((6191, false, AND2), &[Tv(982), Tv(6190)]),
-    ((6193, false, OR2), &[Arg(3, 46), Tv(982)]),
-    ((6296, false, NAND2), &[Tv(2704), Tv(6295)]),
-    ((6298, false, AND2), &[Tv(982), Tv(6297)]),
-    ((6300, false, OR2), &[Arg(3, 47), Tv(982)]),
-    ((6403, false, NAND2), &[Tv(2704), Tv(6402)]),
-    ((6405, false, AND2), &[Tv(982), Tv(6404)]),
-    ((6407, false, OR2), &[Arg(3, 48), Tv(982)]),
-    ((401, false, INV), &[Arg(0, 13)]),
-    ((402, false, INV), &[Arg(0, 14)]),
-    ((403, false, INV), &[Arg(0, 15)]),
-    ((597, false, INV), &[Arg(3, 351)]),
-    ((601, false, INV), &[Arg(3, 359)]),
-    ((602, false, INV), &[Arg(3, 361)]),
-    ((603, false, INV), &[Arg(3, 365)]),
-    ((607, false, INV), &[Arg(3, 402)]),
-    ((609, false, INV), &[Arg(3, 404)]),
-    ((611, false, INV), &[Arg(3, 406)]),
-    ((619, false, INV), &[Arg(3, 451)]),
-    ((620, false, INV), &[Arg(3, 453)]),
-    ((626, false, INV), &[Arg(3, 498)]),
-    ((627, false, INV), &[Arg(3, 499)]),
-    ((629, false, INV), &[Arg(3, 501)]),
-    ((631, false, INV), &[Arg(3, 504)]),
-    ((633, false, INV), &[Arg(3, 511)]),
-    ((635, false, INV), &[Arg(3, 530)]),
-    ((897, false, XNOR2), &[Arg(1, 7), Arg(3, 211)]),
-    ((898, false, AND2), &[Tv(885), Tv(889)]),
-    ((900, false, AND2), &[Tv(880), Tv(884)]),
-    ((901, false, AND2), &[Tv(881), Tv(896)]),
-    ((907, false, AND2), &[Tv(888), Tv(906)]),
-    ((914, false, AND2), &[Tv(910), Tv(913)]),
-    ((921, false, AND2), &[Tv(916), Tv(920)]),
-    ((927, false, AND2), &[Tv(925), Tv(926)]),
-    ((1015, false, AND2), &[Tv(1012), Tv(1014)]),
-    ((1019, false, AND2), &[Tv(1009), Tv(1018)]),
-    ((1024, false, AND2), &[Tv(1003), Tv(1023)]),
-    ((1026, false, AND2), &[Tv(1006), Tv(1025)]),
-    ((1079, false, NAND2), &[Tv(408), Arg(3, 511)]),
-    ((1110, false, AND2), &[Tv(1075), Tv(1080)]),
-    ((1111, false, XNOR2), &[Arg(1, 4), Arg(3, 502)]),
-    ((1272, false, AND2), &[Tv(1223), Tv(1271)]),
-    ((1296, false, AND2), &[Tv(1292), Tv(1295)]),
-    ((1302, false, AND2), &[Tv(1299), Tv(1301)]),
-    ((1315, false, AND2), &[Tv(1307), Tv(1314)]),
-    ((1321, false, AND2), &[Tv(1318), Tv(1320)]),
-    ((1329, false, NAND2), &[Arg(1, 8), Tv(582)]),
-    ((1334, false, XNOR2), &[Arg(1, 14), Arg(3, 267)]),
-    ((1350, false, AND2), &[Tv(1346), Tv(1349)]),
-    ((1353, false, AND2), &[Tv(1336), Tv(1352)]),
-    ((1359, false, AND2), &[Tv(1356), Tv(1358)]),
-    ((1367, false, AND2), &[Tv(1364), Tv(1366)]),
-    ((1370, false, AND2), &[Tv(1327), Tv(1369)]),
-    ((1375, false, AND2), &[Tv(1344), Tv(1374)]),
-    ((1439, false, NAND2), &[Arg(1, 2), Tv(598)]),
-    ((1455, false, NAND2), &[Arg(1, 4), Tv(599)]),
+static LEVEL_9: [((usize, bool, CellType), &[GateInput]); 357] = [
+    ((3954, false, AND2), &[Tv(2093), Tv(2191)]),
+    ((3955, false, XOR2), &[Tv(2096), Tv(2194)]),
+    ((3956, false, INV), &[Tv(2191)]),
+    ((3959, false, XOR2), &[Tv(2099), Tv(2197)]),
+    ((3962, false, XOR2), &[Tv(2102), Tv(2200)]),
+    ((3965, false, XOR2), &[Tv(2105), Tv(2203)]),
+    ((3968, false, XOR2), &[Tv(2108), Tv(2206)]),
+    ((3971, false, XOR2), &[Tv(2111), Tv(2209)]),
+    ((3974, false, XOR2), &[Tv(2114), Tv(2212)]),
+    ((3977, false, XOR2), &[Tv(2117), Tv(2215)]),
+    ((3980, false, XOR2), &[Tv(2120), Tv(2218)]),
+    ((3983, false, XOR2), &[Tv(2123), Tv(2221)]),
+    ((3986, false, XOR2), &[Tv(2126), Tv(2224)]),
+    ((3989, false, XOR2), &[Tv(2129), Tv(2227)]),
+    ((3992, false, XOR2), &[Tv(2132), Tv(2230)]),
+    ((3995, false, XOR2), &[Tv(2135), Tv(2233)]),
+    ((3998, false, XOR2), &[Tv(2138), Tv(2236)]),
+    ((4001, false, XOR2), &[Tv(2141), Tv(2239)]),
+    ((4004, false, XOR2), &[Tv(2144), Tv(2242)]),
+    ((4007, false, XOR2), &[Tv(2147), Tv(2245)]),
+    ((4010, false, XOR2), &[Tv(2150), Tv(2248)]),
+    ((4013, false, XOR2), &[Tv(2153), Tv(2251)]),
+    ((4016, false, XOR2), &[Tv(2156), Tv(2254)]),
+    ((4019, false, XOR2), &[Tv(2159), Tv(2257)]),
+    ((4022, false, XOR2), &[Tv(2162), Tv(2260)]),
+    ((4025, false, XOR2), &[Tv(2165), Tv(2263)]),
+    ((4028, false, XOR2), &[Tv(2168), Tv(2266)]),
+    ((4031, false, XOR2), &[Tv(2171), Tv(2269)]),
+    ((4034, false, XOR2), &[Tv(2174), Tv(2272)]),
+    ((4037, false, XOR2), &[Tv(2177), Tv(2275)]),
+    ((4040, false, XOR2), &[Tv(2180), Tv(2278)]),
+    ((4043, false, XOR2), &[Tv(2183), Tv(2281)]),
+    ((4046, false, XOR2), &[Tv(2186), Tv(2284)]),
+    ((4049, false, XOR2), &[Tv(2189), Tv(2287)]),
+    ((4052, false, AND2), &[Tv(2289), Tv(2387)]),
+    ((4053, false, XOR2), &[Tv(2292), Tv(2390)]),
+    ((4054, false, INV), &[Tv(2387)]),
+    ((4057, false, XOR2), &[Tv(2295), Tv(2393)]),
+    ((4060, false, XOR2), &[Tv(2298), Tv(2396)]),
+    ((4063, false, XOR2), &[Tv(2301), Tv(2399)]),
+    ((4066, false, XOR2), &[Tv(2304), Tv(2402)]),
+    ((4069, false, XOR2), &[Tv(2307), Tv(2405)]),
+    ((4072, false, XOR2), &[Tv(2310), Tv(2408)]),
+    ((4075, false, XOR2), &[Tv(2313), Tv(2411)]),
+    ((4078, false, XOR2), &[Tv(2316), Tv(2414)]),
+    ((4081, false, XOR2), &[Tv(2319), Tv(2417)]),
+    ((4084, false, XOR2), &[Tv(2322), Tv(2420)]),
+    ((4087, false, XOR2), &[Tv(2325), Tv(2423)]),
+    ((4090, false, XOR2), &[Tv(2328), Tv(2426)]),
+    ((4093, false, XOR2), &[Tv(2331), Tv(2429)]),
+    ((4096, false, XOR2), &[Tv(2334), Tv(2432)]),
+    ((4099, false, XOR2), &[Tv(2337), Tv(2435)]),
+    ((4102, false, XOR2), &[Tv(2340), Tv(2438)]),
+    ((4105, false, XOR2), &[Tv(2343), Tv(2441)]),
+    ((4108, false, XOR2), &[Tv(2346), Tv(2444)]),
+    ((4111, false, XOR2), &[Tv(2349), Tv(2447)]),
+    ((4114, false, XOR2), &[Tv(2352), Tv(2450)]),
+    ((4117, false, XOR2), &[Tv(2355), Tv(2453)]),
+    ((4120, false, XOR2), &[Tv(2358), Tv(2456)]),
+    ((4123, false, XOR2), &[Tv(2361), Tv(2459)]),
+    ((4126, false, XOR2), &[Tv(2364), Tv(2462)]),
+    ((4129, false, XOR2), &[Tv(2367), Tv(2465)]),
+    ((4132, false, XOR2), &[Tv(2370), Tv(2468)]),
+    ((4135, false, XOR2), &[Tv(2373), Tv(2471)]),
+    ((4138, false, XOR2), &[Tv(2376), Tv(2474)]),
+    ((4141, false, XOR2), &[Tv(2379), Tv(2477)]),
+    ((4144, false, XOR2), &[Tv(2382), Tv(2480)]),
+    ((4147, false, XOR2), &[Tv(2385), Tv(2483)]),
+    ((4150, false, AND2), &[Tv(2485), Tv(2583)]),
+    ((4151, false, XOR2), &[Tv(2488), Tv(2586)]),
+    ((4152, false, INV), &[Tv(2583)]),
+    ((4155, false, XOR2), &[Tv(2491), Tv(2589)]),
+    ((4158, false, XOR2), &[Tv(2494), Tv(2592)]),
+    ((4161, false, XOR2), &[Tv(2497), Tv(2595)]),
+    ((4164, false, XOR2), &[Tv(2500), Tv(2598)]),
+    ((4167, false, XOR2), &[Tv(2503), Tv(2601)]),
+    ((4170, false, XOR2), &[Tv(2506), Tv(2604)]),
+    ((4173, false, XOR2), &[Tv(2509), Tv(2607)]),
+    ((4176, false, XOR2), &[Tv(2512), Tv(2610)]),
+    ((4179, false, XOR2), &[Tv(2515), Tv(2613)]),
+    ((4182, false, XOR2), &[Tv(2518), Tv(2616)]),
+    ((4185, false, XOR2), &[Tv(2521), Tv(2619)]),
+    ((4188, false, XOR2), &[Tv(2524), Tv(2622)]),
+    ((4191, false, XOR2), &[Tv(2527), Tv(2625)]),
+    ((4194, false, XOR2), &[Tv(2530), Tv(2628)]),
+    ((4197, false, XOR2), &[Tv(2533), Tv(2631)]),
+    ((4200, false, XOR2), &[Tv(2536), Tv(2634)]),
+    ((4203, false, XOR2), &[Tv(2539), Tv(2637)]),
+    ((4206, false, XOR2), &[Tv(2542), Tv(2640)]),
+    ((4209, false, XOR2), &[Tv(2545), Tv(2643)]),
+    ((4212, false, XOR2), &[Tv(2548), Tv(2646)]),
+    ((4215, false, XOR2), &[Tv(2551), Tv(2649)]),
+    ((4218, false, XOR2), &[Tv(2554), Tv(2652)]),
+    ((4221, false, XOR2), &[Tv(2557), Tv(2655)]),
+    ((4224, false, XOR2), &[Tv(2560), Tv(2658)]),
+    ((4227, false, XOR2), &[Tv(2563), Tv(2661)]),
+    ((4230, false, XOR2), &[Tv(2566), Tv(2664)]),
+    ((4233, false, XOR2), &[Tv(2569), Tv(2667)]),
+    ((4236, false, XOR2), &[Tv(2572), Tv(2670)]),
+    ((4239, false, XOR2), &[Tv(2575), Tv(2673)]),
+    ((4242, false, XOR2), &[Tv(2578), Tv(2676)]),
+    ((4245, false, XOR2), &[Tv(2581), Tv(2679)]),
+    ((4248, false, AND2), &[Tv(2681), Tv(2779)]),
+    ((4249, false, XOR2), &[Tv(2684), Tv(2782)]),
+    ((4250, false, INV), &[Tv(2779)]),
+    ((4253, false, XOR2), &[Tv(2687), Tv(2785)]),
+    ((4256, false, XOR2), &[Tv(2690), Tv(2788)]),
+    ((4259, false, XOR2), &[Tv(2693), Tv(2791)]),
+    ((4262, false, XOR2), &[Tv(2696), Tv(2794)]),
+    ((4265, false, XOR2), &[Tv(2699), Tv(2797)]),
+    ((4268, false, XOR2), &[Tv(2702), Tv(2800)]),
+    ((4271, false, XOR2), &[Tv(2705), Tv(2803)]),
+    ((4274, false, XOR2), &[Tv(2708), Tv(2806)]),
+    ((4277, false, XOR2), &[Tv(2711), Tv(2809)]),
+    ((4280, false, XOR2), &[Tv(2714), Tv(2812)]),
+    ((4283, false, XOR2), &[Tv(2717), Tv(2815)]),
+    ((4286, false, XOR2), &[Tv(2720), Tv(2818)]),
+    ((4289, false, XOR2), &[Tv(2723), Tv(2821)]),
+    ((4292, false, XOR2), &[Tv(2726), Tv(2824)]),
+    ((4295, false, XOR2), &[Tv(2729), Tv(2827)]),
+    ((4298, false, XOR2), &[Tv(2732), Tv(2830)]),
+    ((4301, false, XOR2), &[Tv(2735), Tv(2833)]),
+    ((4304, false, XOR2), &[Tv(2738), Tv(2836)]),
+    ((4307, false, XOR2), &[Tv(2741), Tv(2839)]),
+    ((4310, false, XOR2), &[Tv(2744), Tv(2842)]),
+    ((4313, false, XOR2), &[Tv(2747), Tv(2845)]),
+    ((4316, false, XOR2), &[Tv(2750), Tv(2848)]),
+    ((4319, false, XOR2), &[Tv(2753), Tv(2851)]),
+    ((4322, false, XOR2), &[Tv(2756), Tv(2854)]),
+    ((4325, false, XOR2), &[Tv(2759), Tv(2857)]),
+    ((4328, false, XOR2), &[Tv(2762), Tv(2860)]),
+    ((4331, false, XOR2), &[Tv(2765), Tv(2863)]),
+    ((4334, false, XOR2), &[Tv(2768), Tv(2866)]),
+    ((4337, false, XOR2), &[Tv(2771), Tv(2869)]),
+    ((4340, false, XOR2), &[Tv(2774), Tv(2872)]),
+    ((4343, false, XOR2), &[Tv(2777), Tv(2875)]),
+    ((4346, false, AND2), &[Tv(2877), Tv(2975)]),
+    ((4347, false, XOR2), &[Tv(2880), Tv(2978)]),
+    ((4348, false, INV), &[Tv(2975)]),
+    ((4351, false, XOR2), &[Tv(2883), Tv(2981)]),
+    ((4354, false, XOR2), &[Tv(2886), Tv(2984)]),
+    ((4357, false, XOR2), &[Tv(2889), Tv(2987)]),
+    ((4360, false, XOR2), &[Tv(2892), Tv(2990)]),
+    ((4363, false, XOR2), &[Tv(2895), Tv(2993)]),
+    ((4366, false, XOR2), &[Tv(2898), Tv(2996)]),
+    ((4369, false, XOR2), &[Tv(2901), Tv(2999)]),
+    ((4372, false, XOR2), &[Tv(2904), Tv(3002)]),
+    ((4375, false, XOR2), &[Tv(2907), Tv(3005)]),
+    ((4378, false, XOR2), &[Tv(2910), Tv(3008)]),
+    ((4381, false, XOR2), &[Tv(2913), Tv(3011)]),
+    ((4384, false, XOR2), &[Tv(2916), Tv(3014)]),
+    ((4387, false, XOR2), &[Tv(2919), Tv(3017)]),
+    ((4390, false, XOR2), &[Tv(2922), Tv(3020)]),
+    ((4393, false, XOR2), &[Tv(2925), Tv(3023)]),
+    ((4396, false, XOR2), &[Tv(2928), Tv(3026)]),
+    ((4399, false, XOR2), &[Tv(2931), Tv(3029)]),
+    ((4402, false, XOR2), &[Tv(2934), Tv(3032)]),
+    ((4405, false, XOR2), &[Tv(2937), Tv(3035)]),
+    ((4408, false, XOR2), &[Tv(2940), Tv(3038)]),
+    ((4411, false, XOR2), &[Tv(2943), Tv(3041)]),
+    ((4414, false, XOR2), &[Tv(2946), Tv(3044)]),
+    ((4417, false, XOR2), &[Tv(2949), Tv(3047)]),
+    ((4420, false, XOR2), &[Tv(2952), Tv(3050)]),
+    ((4423, false, XOR2), &[Tv(2955), Tv(3053)]),
+    ((4426, false, XOR2), &[Tv(2958), Tv(3056)]),
+    ((4429, false, XOR2), &[Tv(2961), Tv(3059)]),
+    ((4432, false, XOR2), &[Tv(2964), Tv(3062)]),
+    ((4435, false, XOR2), &[Tv(2967), Tv(3065)]),
+    ((4438, false, XOR2), &[Tv(2970), Tv(3068)]),
+    ((4441, false, XOR2), &[Tv(2973), Tv(3071)]),
+    ((4444, false, AND2), &[Tv(3073), Tv(3171)]),
+    ((4445, false, INV), &[Tv(3171)]),
+    ((4447, false, XOR2), &[Tv(3076), Tv(3174)]),
+    ((4450, false, XOR2), &[Tv(3079), Tv(3177)]),
+    ((4453, false, XOR2), &[Tv(3082), Tv(3180)]),
+    ((4456, false, XOR2), &[Tv(3085), Tv(3183)]),
+    ((4459, false, XOR2), &[Tv(3088), Tv(3186)]),
+    ((4462, false, XOR2), &[Tv(3091), Tv(3189)]),
+    ((4465, false, XOR2), &[Tv(3094), Tv(3192)]),
+    ((4468, false, XOR2), &[Tv(3097), Tv(3195)]),
+    ((4471, false, XOR2), &[Tv(3100), Tv(3198)]),
+    ((4474, false, XOR2), &[Tv(3103), Tv(3201)]),
+    ((4477, false, XOR2), &[Tv(3106), Tv(3204)]),
+    ((4480, false, XOR2), &[Tv(3109), Tv(3207)]),
+    ((4483, false, XOR2), &[Tv(3112), Tv(3210)]),
+    ((4486, false, XOR2), &[Tv(3115), Tv(3213)]),
+    ((4489, false, XOR2), &[Tv(3118), Tv(3216)]),
+    ((4492, false, XOR2), &[Tv(3121), Tv(3219)]),
+    ((4495, false, XOR2), &[Tv(3124), Tv(3222)]),
+    ((4498, false, XOR2), &[Tv(3127), Tv(3225)]),
+    ((4501, false, XOR2), &[Tv(3130), Tv(3228)]),
+    ((4504, false, XOR2), &[Tv(3133), Tv(3231)]),
+    ((4507, false, XOR2), &[Tv(3136), Tv(3234)]),
+    ((4510, false, XOR2), &[Tv(3139), Tv(3237)]),
+    ((4513, false, XOR2), &[Tv(3142), Tv(3240)]),
+    ((4516, false, XOR2), &[Tv(3145), Tv(3243)]),
+    ((4519, false, XOR2), &[Tv(3148), Tv(3246)]),
+    ((4522, false, XOR2), &[Tv(3151), Tv(3249)]),
+    ((4525, false, XOR2), &[Tv(3154), Tv(3252)]),
+    ((4528, false, XOR2), &[Tv(3157), Tv(3255)]),
+    ((4531, false, XOR2), &[Tv(3160), Tv(3258)]),
+    ((4534, false, XOR2), &[Tv(3163), Tv(3261)]),
+    ((4537, false, XOR2), &[Tv(3166), Tv(3264)]),
+    ((4540, false, XOR2), &[Tv(3169), Tv(3267)]),
+    ((4543, false, AND2), &[Tv(3269), Tv(3367)]),
+    ((4544, false, XOR2), &[Tv(3272), Tv(3370)]),
+    ((4545, false, INV), &[Tv(3367)]),
+    ((4548, false, XOR2), &[Tv(3275), Tv(3373)]),
+    ((4551, false, XOR2), &[Tv(3278), Tv(3376)]),
+    ((4554, false, XOR2), &[Tv(3281), Tv(3379)]),
+    ((4557, false, XOR2), &[Tv(3284), Tv(3382)]),
+    ((4560, false, XOR2), &[Tv(3287), Tv(3385)]),
+    ((4563, false, XOR2), &[Tv(3290), Tv(3388)]),
+    ((4566, false, XOR2), &[Tv(3293), Tv(3391)]),
+    ((4569, false, XOR2), &[Tv(3296), Tv(3394)]),
+    ((4572, false, XOR2), &[Tv(3299), Tv(3397)]),
+    ((4575, false, XOR2), &[Tv(3302), Tv(3400)]),
+    ((4578, false, XOR2), &[Tv(3305), Tv(3403)]),
+    ((4581, false, XOR2), &[Tv(3308), Tv(3406)]),
+    ((4584, false, XOR2), &[Tv(3311), Tv(3409)]),
+    ((4587, false, XOR2), &[Tv(3314), Tv(3412)]),
+    ((4590, false, XOR2), &[Tv(3317), Tv(3415)]),
+    ((4593, false, XOR2), &[Tv(3320), Tv(3418)]),
+    ((4596, false, XOR2), &[Tv(3323), Tv(3421)]),
+    ((4599, false, XOR2), &[Tv(3326), Tv(3424)]),
+    ((4602, false, XOR2), &[Tv(3329), Tv(3427)]),
+    ((4605, false, XOR2), &[Tv(3332), Tv(3430)]),
+    ((4608, false, XOR2), &[Tv(3335), Tv(3433)]),
+    ((4611, false, XOR2), &[Tv(3338), Tv(3436)]),
+    ((4614, false, XOR2), &[Tv(3341), Tv(3439)]),
+    ((4617, false, XOR2), &[Tv(3344), Tv(3442)]),
+    ((4620, false, XOR2), &[Tv(3347), Tv(3445)]),
+    ((4623, false, XOR2), &[Tv(3350), Tv(3448)]),
+    ((4626, false, XOR2), &[Tv(3353), Tv(3451)]),
+    ((4629, false, XOR2), &[Tv(3356), Tv(3454)]),
+    ((4632, false, XOR2), &[Tv(3359), Tv(3457)]),
+    ((4635, false, XOR2), &[Tv(3362), Tv(3460)]),
+    ((4638, false, XOR2), &[Tv(3365), Tv(3463)]),
+    ((4641, false, AND2), &[Tv(3465), Tv(3563)]),
+    ((4642, false, XOR2), &[Tv(3468), Tv(3566)]),
+    ((4643, false, INV), &[Tv(3563)]),
+    ((4646, false, XOR2), &[Tv(3471), Tv(3569)]),
+    ((4649, false, XOR2), &[Tv(3474), Tv(3572)]),
+    ((4652, false, XOR2), &[Tv(3477), Tv(3575)]),
+    ((4655, false, XOR2), &[Tv(3480), Tv(3578)]),
+    ((4658, false, XOR2), &[Tv(3483), Tv(3581)]),
+    ((4661, false, XOR2), &[Tv(3486), Tv(3584)]),
+    ((4664, false, XOR2), &[Tv(3489), Tv(3587)]),
+    ((4667, false, XOR2), &[Tv(3492), Tv(3590)]),
+    ((4670, false, XOR2), &[Tv(3495), Tv(3593)]),
+    ((4673, false, XOR2), &[Tv(3498), Tv(3596)]),
+    ((4676, false, XOR2), &[Tv(3501), Tv(3599)]),
+    ((4679, false, XOR2), &[Tv(3504), Tv(3602)]),
+    ((4682, false, XOR2), &[Tv(3507), Tv(3605)]),
+    ((4685, false, XOR2), &[Tv(3510), Tv(3608)]),
+    ((4688, false, XOR2), &[Tv(3513), Tv(3611)]),
+    ((4691, false, XOR2), &[Tv(3516), Tv(3614)]),
+    ((4694, false, XOR2), &[Tv(3519), Tv(3617)]),
+    ((4697, false, XOR2), &[Tv(3522), Tv(3620)]),
+    ((4700, false, XOR2), &[Tv(3525), Tv(3623)]),
+    ((4703, false, XOR2), &[Tv(3528), Tv(3626)]),
+    ((4706, false, XOR2), &[Tv(3531), Tv(3629)]),
+    ((4709, false, XOR2), &[Tv(3534), Tv(3632)]),
+    ((4712, false, XOR2), &[Tv(3537), Tv(3635)]),
+    ((4715, false, XOR2), &[Tv(3540), Tv(3638)]),
+    ((4718, false, XOR2), &[Tv(3543), Tv(3641)]),
+    ((4721, false, XOR2), &[Tv(3546), Tv(3644)]),
+    ((4724, false, XOR2), &[Tv(3549), Tv(3647)]),
+    ((4727, false, XOR2), &[Tv(3552), Tv(3650)]),
+    ((4730, false, XOR2), &[Tv(3555), Tv(3653)]),
+    ((4733, false, XOR2), &[Tv(3558), Tv(3656)]),
+    ((4736, false, XOR2), &[Tv(3561), Tv(3659)]),
+    ((4739, false, AND2), &[Tv(3661), Tv(3759)]),
+    ((4740, false, INV), &[Tv(3759)]),
+    ((4742, false, XOR2), &[Tv(3664), Tv(3762)]),
+    ((4745, false, XOR2), &[Tv(3667), Tv(3765)]),
+    ((4748, false, XOR2), &[Tv(3670), Tv(3768)]),
+    ((4751, false, XOR2), &[Tv(3673), Tv(3771)]),
+    ((4754, false, XOR2), &[Tv(3676), Tv(3774)]),
+    ((4757, false, XOR2), &[Tv(3679), Tv(3777)]),
+    ((4760, false, XOR2), &[Tv(3682), Tv(3780)]),
+    ((4763, false, XOR2), &[Tv(3685), Tv(3783)]),
+    ((4766, false, XOR2), &[Tv(3688), Tv(3786)]),
+    ((4769, false, XOR2), &[Tv(3691), Tv(3789)]),
+    ((4772, false, XOR2), &[Tv(3694), Tv(3792)]),
+    ((4775, false, XOR2), &[Tv(3697), Tv(3795)]),
+    ((4778, false, XOR2), &[Tv(3700), Tv(3798)]),
+    ((4781, false, XOR2), &[Tv(3703), Tv(3801)]),
+    ((4784, false, XOR2), &[Tv(3706), Tv(3804)]),
+    ((4787, false, XOR2), &[Tv(3709), Tv(3807)]),
+    ((4790, false, XOR2), &[Tv(3712), Tv(3810)]),
+    ((4793, false, XOR2), &[Tv(3715), Tv(3813)]),
+    ((4796, false, XOR2), &[Tv(3718), Tv(3816)]),
+    ((4799, false, XOR2), &[Tv(3721), Tv(3819)]),
+    ((4802, false, XOR2), &[Tv(3724), Tv(3822)]),
+    ((4805, false, XOR2), &[Tv(3727), Tv(3825)]),
+    ((4808, false, XOR2), &[Tv(3730), Tv(3828)]),
+    ((4811, false, XOR2), &[Tv(3733), Tv(3831)]),
+    ((4814, false, XOR2), &[Tv(3736), Tv(3834)]),
+    ((4817, false, XOR2), &[Tv(3739), Tv(3837)]),
+    ((4820, false, XOR2), &[Tv(3742), Tv(3840)]),
+    ((4823, false, XOR2), &[Tv(3745), Tv(3843)]),
+    ((4826, false, XOR2), &[Tv(3748), Tv(3846)]),
+    ((4829, false, XOR2), &[Tv(3751), Tv(3849)]),
+    ((4832, false, XOR2), &[Tv(3754), Tv(3852)]),
+    ((4835, false, XOR2), &[Tv(3757), Tv(3855)]),
+    ((4841, false, AND2), &[Tv(2091), Tv(4840)]),
+    ((4844, false, AND2), &[Tv(2091), Tv(4843)]),
+    ((4847, false, AND2), &[Tv(2091), Tv(4846)]),
+    ((4850, false, AND2), &[Tv(2091), Tv(4849)]),
+    ((4853, false, AND2), &[Tv(2091), Tv(4852)]),
+    ((4856, false, AND2), &[Tv(2091), Tv(4855)]),
+    ((4859, false, AND2), &[Tv(2091), Tv(4858)]),
+    ((4862, false, AND2), &[Tv(2091), Tv(4861)]),
+    ((4865, false, AND2), &[Tv(2091), Tv(4864)]),
+    ((4868, false, AND2), &[Tv(2091), Tv(4867)]),
+    ((4871, false, AND2), &[Tv(2091), Tv(4870)]),
+    ((4874, false, AND2), &[Tv(2091), Tv(4873)]),
+    ((4877, false, AND2), &[Tv(2091), Tv(4876)]),
+    ((4880, false, AND2), &[Tv(2091), Tv(4879)]),
+    ((4883, false, AND2), &[Tv(2091), Tv(4882)]),
+    ((4886, false, AND2), &[Tv(2091), Tv(4885)]),
+    ((4889, false, AND2), &[Tv(2091), Tv(4888)]),
+    ((4892, false, AND2), &[Tv(2091), Tv(4891)]),
+    ((4895, false, AND2), &[Tv(2091), Tv(4894)]),
+    ((4898, false, AND2), &[Tv(2091), Tv(4897)]),
+    ((4901, false, AND2), &[Tv(2091), Tv(4900)]),
+    ((4904, false, AND2), &[Tv(2091), Tv(4903)]),
+    ((4907, false, AND2), &[Tv(2091), Tv(4906)]),
+    ((4910, false, AND2), &[Tv(2091), Tv(4909)]),
+    ((4913, false, AND2), &[Tv(2091), Tv(4912)]),
+    ((4916, false, AND2), &[Tv(2091), Tv(4915)]),
+    ((4919, false, AND2), &[Tv(2091), Tv(4918)]),
+    ((4922, false, AND2), &[Tv(2091), Tv(4921)]),
+    ((4925, false, AND2), &[Tv(2091), Tv(4924)]),
+    ((4928, false, AND2), &[Tv(2091), Tv(4927)]),
+    ((4931, false, AND2), &[Tv(2091), Tv(4930)]),
+    ((4934, false, AND2), &[Tv(2091), Tv(4933)]),
+    ((5332, false, INV), &[Tv(4839)]),
+    ((5952, false, INV), &[Tv(5928)]),
+    ((5958, false, XOR2), &[Tv(5884), Tv(5928)]),
+    ((5959, false, XOR2), &[Tv(5885), Tv(5946)]),
+    ((5960, false, XOR2), &[Tv(5886), Tv(5948)]),
+    ((5961, false, XOR2), &[Tv(5887), Tv(5951)]),
+    ((6021, false, XOR2), &[Tv(5884), Tv(6001)]),
+    ((6022, false, XOR2), &[Tv(5885), Tv(6012)]),
+    ((6023, false, XOR2), &[Tv(5886), Tv(6014)]),
+    ((6024, false, XOR2), &[Tv(5887), Tv(6017)]),
+    ((6149, false, INV), &[Tv(6125)]),
+    ((6155, false, XOR2), &[Tv(6081), Tv(6125)]),
+    ((6156, false, XOR2), &[Tv(6082), Tv(6143)]),
+    ((6157, false, XOR2), &[Tv(6083), Tv(6145)]),
+    ((6158, false, XOR2), &[Tv(6084), Tv(6148)]),
+    ((6218, false, XOR2), &[Tv(6081), Tv(6198)]),
+    ((6219, false, XOR2), &[Tv(6082), Tv(6209)]),
+    ((6220, false, XOR2), &[Tv(6083), Tv(6211)]),
+    ((6221, false, XOR2), &[Tv(6084), Tv(6214)]),
 ];
 
-static LEVEL_30: [((usize, bool, CellType), &[GateInput]); 216] = [
-    ((2828, false, NAND2), &[Tv(402), Arg(1, 14)]),
-    ((2832, false, NAND2), &[Tv(401), Arg(1, 13)]),
-    ((2851, false, NAND2), &[Tv(2833), Tv(2850)]),
-    ((2860, false, AND2), &[Tv(2855), Tv(2859)]),
-    ((2865, false, NAND2), &[Tv(403), Arg(1, 15)]),
-    ((2868, false, AND2), &[Tv(2863), Tv(2867)]),
-    ((2881, false, NAND2), &[Tv(2834), Tv(2880)]),
-    ((2888, false, NAND2), &[Arg(0, 5), Tv(394)]),
-    ((2910, false, NAND2), &[Tv(2894), Tv(2909)]),
-    ((2921, false, AND2), &[Tv(2890), Tv(2920)]),
-    ((2926, false, AND2), &[Tv(2919), Tv(2923)]),
-    ((2927, false, AND2), &[Tv(2914), Tv(2925)]),
-    ((2929, false, AND2), &[Tv(2916), Tv(2918)]),
-    ((2941, false, NAND2), &[Tv(2896), Tv(2940)]),
-    ((3086, false, NAND2), &[Tv(3083), Tv(3085)]),
-    ((3088, false, AND2), &[Tv(1272), Tv(3087)]),
-    ((3091, false, NAND2), &[Arg(3, 49), Tv(3090)]),
-    ((3092, false, NAND2), &[Arg(3, 98), Tv(1222)]),
-    ((3196, false, NAND2), &[Tv(3193), Tv(3195)]),
-    ((3198, false, AND2), &[Tv(1272), Tv(3197)]),
-    ((3200, false, NAND2), &[Arg(3, 99), Tv(1222)]),
-    ((3201, false, NAND2), &[Arg(3, 50), Tv(3090)]),
-    ((3303, false, NAND2), &[Tv(3300), Tv(3302)]),
-    ((3305, false, AND2), &[Tv(1272), Tv(3304)]),
-    ((3307, false, NAND2), &[Arg(3, 100), Tv(1222)]),
-    ((3308, false, NAND2), &[Arg(3, 51), Tv(3090)]),
-    ((3410, false, NAND2), &[Tv(3407), Tv(3409)]),
-    ((3412, false, AND2), &[Tv(1272), Tv(3411)]),
-    ((3414, false, NAND2), &[Arg(3, 101), Tv(1222)]),
-    ((3415, false, NAND2), &[Arg(3, 52), Tv(3090)]),
-    ((3517, false, NAND2), &[Tv(3514), Tv(3516)]),
-    ((3519, false, AND2), &[Tv(1272), Tv(3518)]),
-    ((3521, false, NAND2), &[Arg(3, 102), Tv(1222)]),
-    ((3522, false, NAND2), &[Arg(3, 53), Tv(3090)]),
-    ((3624, false, NAND2), &[Tv(3621), Tv(3623)]),
-    ((3626, false, AND2), &[Tv(1272), Tv(3625)]),
-    ((3628, false, NAND2), &[Arg(3, 103), Tv(1222)]),
-    ((3629, false, NAND2), &[Arg(3, 54), Tv(3090)]),
-    ((3731, false, NAND2), &[Tv(3728), Tv(3730)]),
-    ((3733, false, AND2), &[Tv(1272), Tv(3732)]),
-    ((3735, false, NAND2), &[Arg(3, 104), Tv(1222)]),
-    ((3736, false, NAND2), &[Arg(3, 55), Tv(3090)]),
-    ((3838, false, NAND2), &[Tv(3835), Tv(3837)]),
-    ((3840, false, AND2), &[Tv(1272), Tv(3839)]),
-    ((3842, false, NAND2), &[Arg(3, 105), Tv(1222)]),
-    ((3843, false, NAND2), &[Arg(3, 56), Tv(3090)]),
-    ((3945, false, NAND2), &[Tv(3942), Tv(3944)]),
-    ((3947, false, AND2), &[Tv(1272), Tv(3946)]),
-    ((3949, false, NAND2), &[Arg(3, 122), Tv(1222)]),
-    ((3950, false, NAND2), &[Arg(3, 73), Tv(3090)]),
-    ((4052, false, NAND2), &[Tv(4049), Tv(4051)]),
-    ((4054, false, AND2), &[Tv(1272), Tv(4053)]),
-    ((4056, false, NAND2), &[Arg(3, 123), Tv(1222)]),
-    ((4057, false, NAND2), &[Arg(3, 74), Tv(3090)]),
-    ((4159, false, NAND2), &[Tv(4156), Tv(4158)]),
-    ((4161, false, AND2), &[Tv(1272), Tv(4160)]),
-    ((4163, false, NAND2), &[Arg(3, 124), Tv(1222)]),
-    ((4164, false, NAND2), &[Arg(3, 75), Tv(3090)]),
-    ((4266, false, NAND2), &[Tv(4263), Tv(4265)]),
-    ((4268, false, AND2), &[Tv(1272), Tv(4267)]),
-    ((4270, false, NAND2), &[Arg(3, 125), Tv(1222)]),
-    ((4271, false, NAND2), &[Arg(3, 76), Tv(3090)]),
-    ((4373, false, NAND2), &[Tv(4370), Tv(4372)]),
-    ((4375, false, AND2), &[Tv(1272), Tv(4374)]),
-    ((4377, false, NAND2), &[Arg(3, 126), Tv(1222)]),
-    ((4378, false, NAND2), &[Arg(3, 77), Tv(3090)]),
-    ((4480, false, NAND2), &[Tv(4477), Tv(4479)]),
-    ((4482, false, AND2), &[Tv(1272), Tv(4481)]),
-    ((4484, false, NAND2), &[Arg(3, 127), Tv(1222)]),
-    ((4485, false, NAND2), &[Arg(3, 78), Tv(3090)]),
-    ((4587, false, NAND2), &[Tv(4584), Tv(4586)]),
-    ((4589, false, AND2), &[Tv(1272), Tv(4588)]),
-    ((4591, false, NAND2), &[Arg(3, 128), Tv(1222)]),
-    ((4592, false, NAND2), &[Arg(3, 79), Tv(3090)]),
-    ((4694, false, NAND2), &[Tv(4691), Tv(4693)]),
-    ((4696, false, AND2), &[Tv(1272), Tv(4695)]),
-    ((4698, false, NAND2), &[Arg(3, 129), Tv(1222)]),
-    ((4699, false, NAND2), &[Arg(3, 80), Tv(3090)]),
-    ((4801, false, NAND2), &[Tv(4798), Tv(4800)]),
-    ((4803, false, AND2), &[Tv(1272), Tv(4802)]),
-    ((4805, false, NAND2), &[Arg(3, 130), Tv(1222)]),
-    ((4806, false, NAND2), &[Arg(3, 81), Tv(3090)]),
-    ((4908, false, NAND2), &[Tv(4905), Tv(4907)]),
-    ((4910, false, AND2), &[Tv(1272), Tv(4909)]),
-    ((4912, false, NAND2), &[Arg(3, 131), Tv(1222)]),
-    ((4913, false, NAND2), &[Arg(3, 82), Tv(3090)]),
-    ((5015, false, NAND2), &[Tv(5012), Tv(5014)]),
-    ((5017, false, AND2), &[Tv(1272), Tv(5016)]),
-    ((5019, false, NAND2), &[Arg(3, 132), Tv(1222)]),
-    ((5020, false, NAND2), &[Arg(3, 83), Tv(3090)]),
-    ((5122, false, NAND2), &[Tv(5119), Tv(5121)]),
-    ((5124, false, AND2), &[Tv(1272), Tv(5123)]),
-    ((5126, false, NAND2), &[Arg(3, 133), Tv(1222)]),
-    ((5127, false, NAND2), &[Arg(3, 84), Tv(3090)]),
-    ((5229, false, NAND2), &[Tv(5226), Tv(5228)]),
-    ((5231, false, AND2), &[Tv(1272), Tv(5230)]),
-    ((5233, false, NAND2), &[Arg(3, 134), Tv(1222)]),
-    ((5234, false, NAND2), &[Arg(3, 85), Tv(3090)]),
-    ((5336, false, NAND2), &[Tv(5333), Tv(5335)]),
-    ((5338, false, AND2), &[Tv(1272), Tv(5337)]),
-    ((5340, false, NAND2), &[Arg(3, 135), Tv(1222)]),
-    ((5341, false, NAND2), &[Arg(3, 86), Tv(3090)]),
-    ((5443, false, NAND2), &[Tv(5440), Tv(5442)]),
-    ((5445, false, AND2), &[Tv(1272), Tv(5444)]),
-    ((5447, false, NAND2), &[Arg(3, 136), Tv(1222)]),
-    ((5448, false, NAND2), &[Arg(3, 87), Tv(3090)]),
-    ((5550, false, NAND2), &[Tv(5547), Tv(5549)]),
-    ((5552, false, AND2), &[Tv(1272), Tv(5551)]),
-    ((5554, false, NAND2), &[Arg(3, 137), Tv(1222)]),
-    ((5555, false, NAND2), &[Arg(3, 88), Tv(3090)]),
-    ((5657, false, NAND2), &[Tv(5654), Tv(5656)]),
-    ((5659, false, AND2), &[Tv(1272), Tv(5658)]),
-    ((5661, false, NAND2), &[Arg(3, 139), Tv(1222)]),
-    ((5662, false, NAND2), &[Arg(3, 90), Tv(3090)]),
-    ((5764, false, NAND2), &[Tv(5761), Tv(5763)]),
-    ((5766, false, AND2), &[Tv(1272), Tv(5765)]),
-    ((5768, false, NAND2), &[Arg(3, 140), Tv(1222)]),
-    ((5769, false, NAND2), &[Arg(3, 91), Tv(3090)]),
-    ((5871, false, NAND2), &[Tv(5868), Tv(5870)]),
-    ((5873, false, AND2), &[Tv(1272), Tv(5872)]),
-    ((5875, false, NAND2), &[Arg(3, 141), Tv(1222)]),
-    ((5876, false, NAND2), &[Arg(3, 92), Tv(3090)]),
-    ((5978, false, NAND2), &[Tv(5975), Tv(5977)]),
-    ((5980, false, AND2), &[Tv(1272), Tv(5979)]),
-    ((5982, false, NAND2), &[Arg(3, 142), Tv(1222)]),
-    ((5983, false, NAND2), &[Arg(3, 93), Tv(3090)]),
-    ((6085, false, NAND2), &[Tv(6082), Tv(6084)]),
-    ((6087, false, AND2), &[Tv(1272), Tv(6086)]),
-    ((6089, false, NAND2), &[Arg(3, 143), Tv(1222)]),
-    ((6090, false, NAND2), &[Arg(3, 94), Tv(3090)]),
-    ((6192, false, NAND2), &[Tv(6189), Tv(6191)]),
-    ((6194, false, AND2), &[Tv(1272), Tv(6193)]),
-    ((6196, false, NAND2), &[Arg(3, 144), Tv(1222)]),
-    ((6197, false, NAND2), &[Arg(3, 95), Tv(3090)]),
-    ((6299, false, NAND2), &[Tv(6296), Tv(6298)]),
-    ((6301, false, AND2), &[Tv(1272), Tv(6300)]),
-    ((6303, false, NAND2), &[Arg(3, 145), Tv(1222)]),
-    ((6304, false, NAND2), &[Arg(3, 96), Tv(3090)]),
-    ((6406, false, NAND2), &[Tv(6403), Tv(6405)]),
-    ((6408, false, AND2), &[Tv(1272), Tv(6407)]),
-    ((6410, false, NAND2), &[Arg(3, 146), Tv(1222)]),
-    ((6411, false, NAND2), &[Arg(3, 97), Tv(3090)]),
-    ((386, false, INV), &[Arg(0, 5)]),
-    ((387, false, INV), &[Arg(0, 6)]),
-    ((388, false, INV), &[Arg(0, 7)]),
-    ((600, false, INV), &[Arg(3, 357)]),
-    ((604, false, INV), &[Arg(3, 383)]),
-    ((605, false, INV), &[Arg(3, 400)]),
-    ((606, false, INV), &[Arg(3, 401)]),
-    ((608, false, INV), &[Arg(3, 403)]),
-    ((612, false, INV), &[Arg(3, 408)]),
-    ((613, false, INV), &[Arg(3, 410)]),
-    ((614, false, INV), &[Arg(3, 413)]),
-    ((615, false, INV), &[Arg(3, 414)]),
-    ((616, false, INV), &[Arg(3, 415)]),
-    ((617, false, INV), &[Arg(3, 432)]),
-    ((618, false, INV), &[Arg(3, 449)]),
-    ((622, false, INV), &[Arg(3, 457)]),
-    ((623, false, INV), &[Arg(3, 459)]),
-    ((624, false, INV), &[Arg(3, 463)]),
-    ((630, false, INV), &[Arg(3, 503)]),
-    ((632, false, INV), &[Arg(3, 506)]),
-    ((637, false, INV), &[Arg(3, 549)]),
-    ((638, false, INV), &[Arg(3, 551)]),
-    ((899, false, AND2), &[Tv(897), Tv(898)]),
-    ((902, false, AND2), &[Tv(900), Tv(901)]),
-    ((915, false, AND2), &[Tv(907), Tv(914)]),
-    ((928, false, AND2), &[Tv(921), Tv(927)]),
-    ((1020, false, AND2), &[Tv(1015), Tv(1019)]),
-    ((1027, false, AND2), &[Tv(1024), Tv(1026)]),
-    ((1076, false, NAND2), &[Tv(392), Arg(3, 501)]),
-    ((1078, false, NAND2), &[Arg(1, 13), Tv(633)]),
-    ((1082, false, XNOR2), &[Arg(1, 9), Arg(3, 507)]),
-    ((1084, false, NAND2), &[Arg(1, 0), Tv(626)]),
-    ((1085, false, NAND2), &[Tv(409), Arg(3, 512)]),
-    ((1086, false, NAND2), &[Tv(390), Arg(3, 499)]),
-    ((1087, false, NAND2), &[Arg(1, 1), Tv(627)]),
-    ((1088, false, NAND2), &[Tv(394), Arg(3, 503)]),
-    ((1089, false, NAND2), &[Arg(1, 6), Tv(631)]),
-    ((1090, false, NAND2), &[Tv(389), Arg(3, 498)]),
-    ((1092, false, NAND2), &[Tv(391), Arg(3, 500)]),
-    ((1094, false, NAND2), &[Arg(1, 3), Tv(629)]),
-    ((1106, false, NAND2), &[Tv(404), Arg(3, 506)]),
-    ((1109, false, XNOR2), &[Arg(1, 15), Arg(3, 513)]),
-    ((1112, false, AND2), &[Tv(1110), Tv(1111)]),
-    ((1117, false, AND2), &[Tv(635), Tv(1079)]),
-    ((1137, false, NAND2), &[Arg(1, 2), Tv(619)]),
-    ((1140, false, NAND2), &[Arg(1, 4), Tv(620)]),
-    ((1303, false, AND2), &[Tv(1296), Tv(1302)]),
-    ((1322, false, AND2), &[Tv(1315), Tv(1321)]),
-    ((1351, false, AND2), &[Tv(1334), Tv(1350)]),
-    ((1360, false, AND2), &[Tv(1353), Tv(1359)]),
-    ((1371, false, AND2), &[Tv(1367), Tv(1370)]),
-    ((1376, false, AND2), &[Tv(1329), Tv(1375)]),
-    ((1389, false, NAND2), &[Arg(1, 6), Tv(611)]),
-    ((1393, false, NAND2), &[Arg(1, 2), Tv(607)]),
-    ((1394, false, NAND2), &[Tv(393), Arg(3, 404)]),
-    ((1398, false, NAND2), &[Tv(411), Arg(3, 410)]),
-    ((1403, false, NAND2), &[Tv(395), Arg(3, 406)]),
-    ((1404, false, NAND2), &[Arg(1, 4), Tv(609)]),
-    ((1405, false, NAND2), &[Tv(408), Arg(3, 413)]),
-    ((1406, false, NAND2), &[Tv(412), Arg(3, 415)]),
-    ((1408, false, NAND2), &[Tv(392), Arg(3, 403)]),
-    ((1437, false, NAND2), &[Arg(1, 8), Tv(601)]),
-    ((1438, false, NAND2), &[Tv(391), Arg(3, 353)]),
-    ((1440, false, NAND2), &[Tv(393), Arg(3, 355)]),
-    ((1441, false, NAND2), &[Arg(1, 14), Tv(603)]),
-    ((1442, false, NAND2), &[Arg(1, 10), Tv(602)]),
-    ((1444, false, NAND2), &[Tv(409), Arg(3, 365)]),
-    ((1445, false, XNOR2), &[Arg(1, 12), Arg(3, 363)]),
-    ((1446, false, XNOR2), &[Arg(1, 1), Arg(3, 352)]),
-    ((1448, false, NAND2), &[Arg(1, 0), Tv(597)]),
-    ((1453, false, NAND2), &[Tv(411), Arg(3, 361)]),
-    ((1454, false, XNOR2), &[Arg(1, 5), Arg(3, 356)]),
-    ((1458, false, NAND2), &[Tv(395), Arg(3, 357)]),
-    ((1473, false, AND2), &[Tv(1439), Tv(1455)]),
+static LEVEL_10: [((usize, bool, CellType), &[GateInput]); 341] = [
+    ((3957, false, AND2), &[Tv(3956), Tv(3955)]),
+    ((3960, false, AND2), &[Tv(3956), Tv(3959)]),
+    ((3963, false, AND2), &[Tv(3956), Tv(3962)]),
+    ((3966, false, AND2), &[Tv(3956), Tv(3965)]),
+    ((3969, false, AND2), &[Tv(3956), Tv(3968)]),
+    ((3972, false, AND2), &[Tv(3956), Tv(3971)]),
+    ((3975, false, AND2), &[Tv(3956), Tv(3974)]),
+    ((3978, false, AND2), &[Tv(3956), Tv(3977)]),
+    ((3981, false, AND2), &[Tv(3956), Tv(3980)]),
+    ((3984, false, AND2), &[Tv(3956), Tv(3983)]),
+    ((3987, false, AND2), &[Tv(3956), Tv(3986)]),
+    ((3990, false, AND2), &[Tv(3956), Tv(3989)]),
+    ((3993, false, AND2), &[Tv(3956), Tv(3992)]),
+    ((3996, false, AND2), &[Tv(3956), Tv(3995)]),
+    ((3999, false, AND2), &[Tv(3956), Tv(3998)]),
+    ((4002, false, AND2), &[Tv(3956), Tv(4001)]),
+    ((4005, false, AND2), &[Tv(3956), Tv(4004)]),
+    ((4008, false, AND2), &[Tv(3956), Tv(4007)]),
+    ((4011, false, AND2), &[Tv(3956), Tv(4010)]),
+    ((4014, false, AND2), &[Tv(3956), Tv(4013)]),
+    ((4017, false, AND2), &[Tv(3956), Tv(4016)]),
+    ((4020, false, AND2), &[Tv(3956), Tv(4019)]),
+    ((4023, false, AND2), &[Tv(3956), Tv(4022)]),
+    ((4026, false, AND2), &[Tv(3956), Tv(4025)]),
+    ((4029, false, AND2), &[Tv(3956), Tv(4028)]),
+    ((4032, false, AND2), &[Tv(3956), Tv(4031)]),
+    ((4035, false, AND2), &[Tv(3956), Tv(4034)]),
+    ((4038, false, AND2), &[Tv(3956), Tv(4037)]),
+    ((4041, false, AND2), &[Tv(3956), Tv(4040)]),
+    ((4044, false, AND2), &[Tv(3956), Tv(4043)]),
+    ((4047, false, AND2), &[Tv(3956), Tv(4046)]),
+    ((4050, false, AND2), &[Tv(3956), Tv(4049)]),
+    ((4055, false, AND2), &[Tv(4054), Tv(4053)]),
+    ((4058, false, AND2), &[Tv(4054), Tv(4057)]),
+    ((4061, false, AND2), &[Tv(4054), Tv(4060)]),
+    ((4064, false, AND2), &[Tv(4054), Tv(4063)]),
+    ((4067, false, AND2), &[Tv(4054), Tv(4066)]),
+    ((4070, false, AND2), &[Tv(4054), Tv(4069)]),
+    ((4073, false, AND2), &[Tv(4054), Tv(4072)]),
+    ((4076, false, AND2), &[Tv(4054), Tv(4075)]),
+    ((4079, false, AND2), &[Tv(4054), Tv(4078)]),
+    ((4082, false, AND2), &[Tv(4054), Tv(4081)]),
+    ((4085, false, AND2), &[Tv(4054), Tv(4084)]),
+    ((4088, false, AND2), &[Tv(4054), Tv(4087)]),
+    ((4091, false, AND2), &[Tv(4054), Tv(4090)]),
+    ((4094, false, AND2), &[Tv(4054), Tv(4093)]),
+    ((4097, false, AND2), &[Tv(4054), Tv(4096)]),
+    ((4100, false, AND2), &[Tv(4054), Tv(4099)]),
+    ((4103, false, AND2), &[Tv(4054), Tv(4102)]),
+    ((4106, false, AND2), &[Tv(4054), Tv(4105)]),
+    ((4109, false, AND2), &[Tv(4054), Tv(4108)]),
+    ((4112, false, AND2), &[Tv(4054), Tv(4111)]),
+    ((4115, false, AND2), &[Tv(4054), Tv(4114)]),
+    ((4118, false, AND2), &[Tv(4054), Tv(4117)]),
+    ((4121, false, AND2), &[Tv(4054), Tv(4120)]),
+    ((4124, false, AND2), &[Tv(4054), Tv(4123)]),
+    ((4127, false, AND2), &[Tv(4054), Tv(4126)]),
+    ((4130, false, AND2), &[Tv(4054), Tv(4129)]),
+    ((4133, false, AND2), &[Tv(4054), Tv(4132)]),
+    ((4136, false, AND2), &[Tv(4054), Tv(4135)]),
+    ((4139, false, AND2), &[Tv(4054), Tv(4138)]),
+    ((4142, false, AND2), &[Tv(4054), Tv(4141)]),
+    ((4145, false, AND2), &[Tv(4054), Tv(4144)]),
+    ((4148, false, AND2), &[Tv(4054), Tv(4147)]),
+    ((4153, false, AND2), &[Tv(4152), Tv(4151)]),
+    ((4156, false, AND2), &[Tv(4152), Tv(4155)]),
+    ((4159, false, AND2), &[Tv(4152), Tv(4158)]),
+    ((4162, false, AND2), &[Tv(4152), Tv(4161)]),
+    ((4165, false, AND2), &[Tv(4152), Tv(4164)]),
+    ((4168, false, AND2), &[Tv(4152), Tv(4167)]),
+    ((4171, false, AND2), &[Tv(4152), Tv(4170)]),
+    ((4174, false, AND2), &[Tv(4152), Tv(4173)]),
+    ((4177, false, AND2), &[Tv(4152), Tv(4176)]),
+    ((4180, false, AND2), &[Tv(4152), Tv(4179)]),
+    ((4183, false, AND2), &[Tv(4152), Tv(4182)]),
+    ((4186, false, AND2), &[Tv(4152), Tv(4185)]),
+    ((4189, false, AND2), &[Tv(4152), Tv(4188)]),
+    ((4192, false, AND2), &[Tv(4152), Tv(4191)]),
+    ((4195, false, AND2), &[Tv(4152), Tv(4194)]),
+    ((4198, false, AND2), &[Tv(4152), Tv(4197)]),
+    ((4201, false, AND2), &[Tv(4152), Tv(4200)]),
+    ((4204, false, AND2), &[Tv(4152), Tv(4203)]),
+    ((4207, false, AND2), &[Tv(4152), Tv(4206)]),
+    ((4210, false, AND2), &[Tv(4152), Tv(4209)]),
+    ((4213, false, AND2), &[Tv(4152), Tv(4212)]),
+    ((4216, false, AND2), &[Tv(4152), Tv(4215)]),
+    ((4219, false, AND2), &[Tv(4152), Tv(4218)]),
+    ((4222, false, AND2), &[Tv(4152), Tv(4221)]),
+    ((4225, false, AND2), &[Tv(4152), Tv(4224)]),
+    ((4228, false, AND2), &[Tv(4152), Tv(4227)]),
+    ((4231, false, AND2), &[Tv(4152), Tv(4230)]),
+    ((4234, false, AND2), &[Tv(4152), Tv(4233)]),
+    ((4237, false, AND2), &[Tv(4152), Tv(4236)]),
+    ((4240, false, AND2), &[Tv(4152), Tv(4239)]),
+    ((4243, false, AND2), &[Tv(4152), Tv(4242)]),
+    ((4246, false, AND2), &[Tv(4152), Tv(4245)]),
+    ((4251, false, AND2), &[Tv(4250), Tv(4249)]),
+    ((4254, false, AND2), &[Tv(4250), Tv(4253)]),
+    ((4257, false, AND2), &[Tv(4250), Tv(4256)]),
+    ((4260, false, AND2), &[Tv(4250), Tv(4259)]),
+    ((4263, false, AND2), &[Tv(4250), Tv(4262)]),
+    ((4266, false, AND2), &[Tv(4250), Tv(4265)]),
+    ((4269, false, AND2), &[Tv(4250), Tv(4268)]),
+    ((4272, false, AND2), &[Tv(4250), Tv(4271)]),
+    ((4275, false, AND2), &[Tv(4250), Tv(4274)]),
+    ((4278, false, AND2), &[Tv(4250), Tv(4277)]),
+    ((4281, false, AND2), &[Tv(4250), Tv(4280)]),
+    ((4284, false, AND2), &[Tv(4250), Tv(4283)]),
+    ((4287, false, AND2), &[Tv(4250), Tv(4286)]),
+    ((4290, false, AND2), &[Tv(4250), Tv(4289)]),
+    ((4293, false, AND2), &[Tv(4250), Tv(4292)]),
+    ((4296, false, AND2), &[Tv(4250), Tv(4295)]),
+    ((4299, false, AND2), &[Tv(4250), Tv(4298)]),
+    ((4302, false, AND2), &[Tv(4250), Tv(4301)]),
+    ((4305, false, AND2), &[Tv(4250), Tv(4304)]),
+    ((4308, false, AND2), &[Tv(4250), Tv(4307)]),
+    ((4311, false, AND2), &[Tv(4250), Tv(4310)]),
+    ((4314, false, AND2), &[Tv(4250), Tv(4313)]),
+    ((4317, false, AND2), &[Tv(4250), Tv(4316)]),
+    ((4320, false, AND2), &[Tv(4250), Tv(4319)]),
+    ((4323, false, AND2), &[Tv(4250), Tv(4322)]),
+    ((4326, false, AND2), &[Tv(4250), Tv(4325)]),
+    ((4329, false, AND2), &[Tv(4250), Tv(4328)]),
+    ((4332, false, AND2), &[Tv(4250), Tv(4331)]),
+    ((4335, false, AND2), &[Tv(4250), Tv(4334)]),
+    ((4338, false, AND2), &[Tv(4250), Tv(4337)]),
+    ((4341, false, AND2), &[Tv(4250), Tv(4340)]),
+    ((4344, false, AND2), &[Tv(4250), Tv(4343)]),
+    ((4349, false, AND2), &[Tv(4348), Tv(4347)]),
+    ((4352, false, AND2), &[Tv(4348), Tv(4351)]),
+    ((4355, false, AND2), &[Tv(4348), Tv(4354)]),
+    ((4358, false, AND2), &[Tv(4348), Tv(4357)]),
+    ((4361, false, AND2), &[Tv(4348), Tv(4360)]),
+    ((4364, false, AND2), &[Tv(4348), Tv(4363)]),
+    ((4367, false, AND2), &[Tv(4348), Tv(4366)]),
+    ((4370, false, AND2), &[Tv(4348), Tv(4369)]),
+    ((4373, false, AND2), &[Tv(4348), Tv(4372)]),
+    ((4376, false, AND2), &[Tv(4348), Tv(4375)]),
+    ((4379, false, AND2), &[Tv(4348), Tv(4378)]),
+    ((4382, false, AND2), &[Tv(4348), Tv(4381)]),
+    ((4385, false, AND2), &[Tv(4348), Tv(4384)]),
+    ((4388, false, AND2), &[Tv(4348), Tv(4387)]),
+    ((4391, false, AND2), &[Tv(4348), Tv(4390)]),
+    ((4394, false, AND2), &[Tv(4348), Tv(4393)]),
+    ((4397, false, AND2), &[Tv(4348), Tv(4396)]),
+    ((4400, false, AND2), &[Tv(4348), Tv(4399)]),
+    ((4403, false, AND2), &[Tv(4348), Tv(4402)]),
+    ((4406, false, AND2), &[Tv(4348), Tv(4405)]),
+    ((4409, false, AND2), &[Tv(4348), Tv(4408)]),
+    ((4412, false, AND2), &[Tv(4348), Tv(4411)]),
+    ((4415, false, AND2), &[Tv(4348), Tv(4414)]),
+    ((4418, false, AND2), &[Tv(4348), Tv(4417)]),
+    ((4421, false, AND2), &[Tv(4348), Tv(4420)]),
+    ((4424, false, AND2), &[Tv(4348), Tv(4423)]),
+    ((4427, false, AND2), &[Tv(4348), Tv(4426)]),
+    ((4430, false, AND2), &[Tv(4348), Tv(4429)]),
+    ((4433, false, AND2), &[Tv(4348), Tv(4432)]),
+    ((4436, false, AND2), &[Tv(4348), Tv(4435)]),
+    ((4439, false, AND2), &[Tv(4348), Tv(4438)]),
+    ((4442, false, AND2), &[Tv(4348), Tv(4441)]),
+    ((4446, false, AND2), &[Tv(1364), Tv(4445)]),
+    ((4448, false, AND2), &[Tv(4445), Tv(4447)]),
+    ((4451, false, AND2), &[Tv(4445), Tv(4450)]),
+    ((4454, false, AND2), &[Tv(4445), Tv(4453)]),
+    ((4457, false, AND2), &[Tv(4445), Tv(4456)]),
+    ((4460, false, AND2), &[Tv(4445), Tv(4459)]),
+    ((4463, false, AND2), &[Tv(4445), Tv(4462)]),
+    ((4466, false, AND2), &[Tv(4445), Tv(4465)]),
+    ((4469, false, AND2), &[Tv(4445), Tv(4468)]),
+    ((4472, false, AND2), &[Tv(4445), Tv(4471)]),
+    ((4475, false, AND2), &[Tv(4445), Tv(4474)]),
+    ((4478, false, AND2), &[Tv(4445), Tv(4477)]),
+    ((4481, false, AND2), &[Tv(4445), Tv(4480)]),
+    ((4484, false, AND2), &[Tv(4445), Tv(4483)]),
+    ((4487, false, AND2), &[Tv(4445), Tv(4486)]),
+    ((4490, false, AND2), &[Tv(4445), Tv(4489)]),
+    ((4493, false, AND2), &[Tv(4445), Tv(4492)]),
+    ((4496, false, AND2), &[Tv(4445), Tv(4495)]),
+    ((4499, false, AND2), &[Tv(4445), Tv(4498)]),
+    ((4502, false, AND2), &[Tv(4445), Tv(4501)]),
+    ((4505, false, AND2), &[Tv(4445), Tv(4504)]),
+    ((4508, false, AND2), &[Tv(4445), Tv(4507)]),
+    ((4511, false, AND2), &[Tv(4445), Tv(4510)]),
+    ((4514, false, AND2), &[Tv(4445), Tv(4513)]),
+    ((4517, false, AND2), &[Tv(4445), Tv(4516)]),
+    ((4520, false, AND2), &[Tv(4445), Tv(4519)]),
+    ((4523, false, AND2), &[Tv(4445), Tv(4522)]),
+    ((4526, false, AND2), &[Tv(4445), Tv(4525)]),
+    ((4529, false, AND2), &[Tv(4445), Tv(4528)]),
+    ((4532, false, AND2), &[Tv(4445), Tv(4531)]),
+    ((4535, false, AND2), &[Tv(4445), Tv(4534)]),
+    ((4538, false, AND2), &[Tv(4445), Tv(4537)]),
+    ((4541, false, AND2), &[Tv(4445), Tv(4540)]),
+    ((4546, false, AND2), &[Tv(4545), Tv(4544)]),
+    ((4549, false, AND2), &[Tv(4545), Tv(4548)]),
+    ((4552, false, AND2), &[Tv(4545), Tv(4551)]),
+    ((4555, false, AND2), &[Tv(4545), Tv(4554)]),
+    ((4558, false, AND2), &[Tv(4545), Tv(4557)]),
+    ((4561, false, AND2), &[Tv(4545), Tv(4560)]),
+    ((4564, false, AND2), &[Tv(4545), Tv(4563)]),
+    ((4567, false, AND2), &[Tv(4545), Tv(4566)]),
+    ((4570, false, AND2), &[Tv(4545), Tv(4569)]),
+    ((4573, false, AND2), &[Tv(4545), Tv(4572)]),
+    ((4576, false, AND2), &[Tv(4545), Tv(4575)]),
+    ((4579, false, AND2), &[Tv(4545), Tv(4578)]),
+    ((4582, false, AND2), &[Tv(4545), Tv(4581)]),
+    ((4585, false, AND2), &[Tv(4545), Tv(4584)]),
+    ((4588, false, AND2), &[Tv(4545), Tv(4587)]),
+    ((4591, false, AND2), &[Tv(4545), Tv(4590)]),
+    ((4594, false, AND2), &[Tv(4545), Tv(4593)]),
+    ((4597, false, AND2), &[Tv(4545), Tv(4596)]),
+    ((4600, false, AND2), &[Tv(4545), Tv(4599)]),
+    ((4603, false, AND2), &[Tv(4545), Tv(4602)]),
+    ((4606, false, AND2), &[Tv(4545), Tv(4605)]),
+    ((4609, false, AND2), &[Tv(4545), Tv(4608)]),
+    ((4612, false, AND2), &[Tv(4545), Tv(4611)]),
+    ((4615, false, AND2), &[Tv(4545), Tv(4614)]),
+    ((4618, false, AND2), &[Tv(4545), Tv(4617)]),
+    ((4621, false, AND2), &[Tv(4545), Tv(4620)]),
+    ((4624, false, AND2), &[Tv(4545), Tv(4623)]),
+    ((4627, false, AND2), &[Tv(4545), Tv(4626)]),
+    ((4630, false, AND2), &[Tv(4545), Tv(4629)]),
+    ((4633, false, AND2), &[Tv(4545), Tv(4632)]),
+    ((4636, false, AND2), &[Tv(4545), Tv(4635)]),
+    ((4639, false, AND2), &[Tv(4545), Tv(4638)]),
+    ((4644, false, AND2), &[Tv(4643), Tv(4642)]),
+    ((4647, false, AND2), &[Tv(4643), Tv(4646)]),
+    ((4650, false, AND2), &[Tv(4643), Tv(4649)]),
+    ((4653, false, AND2), &[Tv(4643), Tv(4652)]),
+    ((4656, false, AND2), &[Tv(4643), Tv(4655)]),
+    ((4659, false, AND2), &[Tv(4643), Tv(4658)]),
+    ((4662, false, AND2), &[Tv(4643), Tv(4661)]),
+    ((4665, false, AND2), &[Tv(4643), Tv(4664)]),
+    ((4668, false, AND2), &[Tv(4643), Tv(4667)]),
+    ((4671, false, AND2), &[Tv(4643), Tv(4670)]),
+    ((4674, false, AND2), &[Tv(4643), Tv(4673)]),
+    ((4677, false, AND2), &[Tv(4643), Tv(4676)]),
+    ((4680, false, AND2), &[Tv(4643), Tv(4679)]),
+    ((4683, false, AND2), &[Tv(4643), Tv(4682)]),
+    ((4686, false, AND2), &[Tv(4643), Tv(4685)]),
+    ((4689, false, AND2), &[Tv(4643), Tv(4688)]),
+    ((4692, false, AND2), &[Tv(4643), Tv(4691)]),
+    ((4695, false, AND2), &[Tv(4643), Tv(4694)]),
+    ((4698, false, AND2), &[Tv(4643), Tv(4697)]),
+    ((4701, false, AND2), &[Tv(4643), Tv(4700)]),
+    ((4704, false, AND2), &[Tv(4643), Tv(4703)]),
+    ((4707, false, AND2), &[Tv(4643), Tv(4706)]),
+    ((4710, false, AND2), &[Tv(4643), Tv(4709)]),
+    ((4713, false, AND2), &[Tv(4643), Tv(4712)]),
+    ((4716, false, AND2), &[Tv(4643), Tv(4715)]),
+    ((4719, false, AND2), &[Tv(4643), Tv(4718)]),
+    ((4722, false, AND2), &[Tv(4643), Tv(4721)]),
+    ((4725, false, AND2), &[Tv(4643), Tv(4724)]),
+    ((4728, false, AND2), &[Tv(4643), Tv(4727)]),
+    ((4731, false, AND2), &[Tv(4643), Tv(4730)]),
+    ((4734, false, AND2), &[Tv(4643), Tv(4733)]),
+    ((4737, false, AND2), &[Tv(4643), Tv(4736)]),
+    ((4741, false, NAND2), &[Tv(1950), Tv(4740)]),
+    ((4743, false, AND2), &[Tv(4740), Tv(4742)]),
+    ((4746, false, AND2), &[Tv(4740), Tv(4745)]),
+    ((4749, false, AND2), &[Tv(4740), Tv(4748)]),
+    ((4752, false, AND2), &[Tv(4740), Tv(4751)]),
+    ((4755, false, AND2), &[Tv(4740), Tv(4754)]),
+    ((4758, false, AND2), &[Tv(4740), Tv(4757)]),
+    ((4761, false, AND2), &[Tv(4740), Tv(4760)]),
+    ((4764, false, AND2), &[Tv(4740), Tv(4763)]),
+    ((4767, false, AND2), &[Tv(4740), Tv(4766)]),
+    ((4770, false, AND2), &[Tv(4740), Tv(4769)]),
+    ((4773, false, AND2), &[Tv(4740), Tv(4772)]),
+    ((4776, false, AND2), &[Tv(4740), Tv(4775)]),
+    ((4779, false, AND2), &[Tv(4740), Tv(4778)]),
+    ((4782, false, AND2), &[Tv(4740), Tv(4781)]),
+    ((4785, false, AND2), &[Tv(4740), Tv(4784)]),
+    ((4788, false, AND2), &[Tv(4740), Tv(4787)]),
+    ((4791, false, AND2), &[Tv(4740), Tv(4790)]),
+    ((4794, false, AND2), &[Tv(4740), Tv(4793)]),
+    ((4797, false, AND2), &[Tv(4740), Tv(4796)]),
+    ((4800, false, AND2), &[Tv(4740), Tv(4799)]),
+    ((4803, false, AND2), &[Tv(4740), Tv(4802)]),
+    ((4806, false, AND2), &[Tv(4740), Tv(4805)]),
+    ((4809, false, AND2), &[Tv(4740), Tv(4808)]),
+    ((4812, false, AND2), &[Tv(4740), Tv(4811)]),
+    ((4815, false, AND2), &[Tv(4740), Tv(4814)]),
+    ((4818, false, AND2), &[Tv(4740), Tv(4817)]),
+    ((4821, false, AND2), &[Tv(4740), Tv(4820)]),
+    ((4824, false, AND2), &[Tv(4740), Tv(4823)]),
+    ((4827, false, AND2), &[Tv(4740), Tv(4826)]),
+    ((4830, false, AND2), &[Tv(4740), Tv(4829)]),
+    ((4833, false, AND2), &[Tv(4740), Tv(4832)]),
+    ((4836, false, AND2), &[Tv(4740), Tv(4835)]),
+    ((4842, false, XOR2), &[Tv(3860), Tv(4841)]),
+    ((4845, false, XOR2), &[Tv(3863), Tv(4844)]),
+    ((4848, false, XOR2), &[Tv(3866), Tv(4847)]),
+    ((4851, false, XOR2), &[Tv(3869), Tv(4850)]),
+    ((4854, false, XOR2), &[Tv(3872), Tv(4853)]),
+    ((4857, false, XOR2), &[Tv(3875), Tv(4856)]),
+    ((4860, false, XOR2), &[Tv(3878), Tv(4859)]),
+    ((4863, false, XOR2), &[Tv(3881), Tv(4862)]),
+    ((4866, false, XOR2), &[Tv(3884), Tv(4865)]),
+    ((4869, false, XOR2), &[Tv(3887), Tv(4868)]),
+    ((4872, false, XOR2), &[Tv(3890), Tv(4871)]),
+    ((4875, false, XOR2), &[Tv(3893), Tv(4874)]),
+    ((4878, false, XOR2), &[Tv(3896), Tv(4877)]),
+    ((4881, false, XOR2), &[Tv(3899), Tv(4880)]),
+    ((4884, false, XOR2), &[Tv(3902), Tv(4883)]),
+    ((4887, false, XOR2), &[Tv(3905), Tv(4886)]),
+    ((4890, false, XOR2), &[Tv(3908), Tv(4889)]),
+    ((4893, false, XOR2), &[Tv(3911), Tv(4892)]),
+    ((4896, false, XOR2), &[Tv(3914), Tv(4895)]),
+    ((4899, false, XOR2), &[Tv(3917), Tv(4898)]),
+    ((4902, false, XOR2), &[Tv(3920), Tv(4901)]),
+    ((4905, false, XOR2), &[Tv(3923), Tv(4904)]),
+    ((4908, false, XOR2), &[Tv(3926), Tv(4907)]),
+    ((4911, false, XOR2), &[Tv(3929), Tv(4910)]),
+    ((4914, false, XOR2), &[Tv(3932), Tv(4913)]),
+    ((4917, false, XOR2), &[Tv(3935), Tv(4916)]),
+    ((4920, false, XOR2), &[Tv(3938), Tv(4919)]),
+    ((4923, false, XOR2), &[Tv(3941), Tv(4922)]),
+    ((4926, false, XOR2), &[Tv(3944), Tv(4925)]),
+    ((4929, false, XOR2), &[Tv(3947), Tv(4928)]),
+    ((4932, false, XOR2), &[Tv(3950), Tv(4931)]),
+    ((4935, false, XOR2), &[Tv(3953), Tv(4934)]),
+    ((4936, false, AND2), &[Tv(3954), Tv(4052)]),
+    ((4938, false, INV), &[Tv(4052)]),
+    ((5034, false, AND2), &[Tv(4150), Tv(4248)]),
+    ((5036, false, INV), &[Tv(4248)]),
+    ((5132, false, AND2), &[Tv(4346), Tv(4444)]),
+    ((5133, false, INV), &[Tv(4444)]),
+    ((5231, false, AND2), &[Tv(4543), Tv(4641)]),
+    ((5233, false, INV), &[Tv(4641)]),
+    ((5329, false, AND2), &[Tv(4739), Tv(4839)]),
+    ((5953, false, NAND2), &[Tv(5952), Tv(5944)]),
+    ((6034, false, XOR2), &[Tv(5958), Tv(6021)]),
+    ((6037, false, XOR2), &[Tv(5959), Tv(6022)]),
+    ((6040, false, XOR2), &[Tv(5960), Tv(6023)]),
+    ((6043, false, XOR2), &[Tv(5961), Tv(6024)]),
+    ((6150, false, NAND2), &[Tv(6149), Tv(6141)]),
+    ((6231, false, XOR2), &[Tv(6155), Tv(6218)]),
+    ((6234, false, XOR2), &[Tv(6156), Tv(6219)]),
+    ((6237, false, XOR2), &[Tv(6157), Tv(6220)]),
+    ((6240, false, XOR2), &[Tv(6158), Tv(6221)]),
 ];
 
-static LEVEL_31: [((usize, bool, CellType), &[GateInput]); 184] = [
-    ((2829, false, NAND2), &[Arg(0, 14), Tv(409)]),
-    ((2852, false, NAND2), &[Tv(2832), Tv(2851)]),
-    ((2869, false, NAND2), &[Tv(2860), Tv(2868)]),
-    ((2870, false, AND2), &[Tv(2828), Tv(2865)]),
-    ((2882, false, NAND2), &[Tv(2835), Tv(2881)]),
-    ((2889, false, NAND2), &[Tv(386), Arg(1, 5)]),
-    ((2911, false, NAND2), &[Tv(2888), Tv(2910)]),
-    ((2922, false, NAND2), &[Tv(388), Arg(1, 7)]),
-    ((2924, false, NAND2), &[Tv(387), Arg(1, 6)]),
-    ((2928, false, AND2), &[Tv(2921), Tv(2926)]),
-    ((2930, false, AND2), &[Tv(2927), Tv(2929)]),
-    ((2942, false, NAND2), &[Tv(2897), Tv(2941)]),
-    ((3089, false, NAND2), &[Tv(3086), Tv(3088)]),
-    ((3093, false, AND2), &[Tv(3091), Tv(3092)]),
-    ((3199, false, NAND2), &[Tv(3196), Tv(3198)]),
-    ((3202, false, AND2), &[Tv(3200), Tv(3201)]),
-    ((3306, false, NAND2), &[Tv(3303), Tv(3305)]),
-    ((3309, false, AND2), &[Tv(3307), Tv(3308)]),
-    ((3413, false, NAND2), &[Tv(3410), Tv(3412)]),
-    ((3416, false, AND2), &[Tv(3414), Tv(3415)]),
-    ((3520, false, NAND2), &[Tv(3517), Tv(3519)]),
-    ((3523, false, AND2), &[Tv(3521), Tv(3522)]),
-    ((3627, false, NAND2), &[Tv(3624), Tv(3626)]),
-    ((3630, false, AND2), &[Tv(3628), Tv(3629)]),
-    ((3734, false, NAND2), &[Tv(3731), Tv(3733)]),
-    ((3737, false, AND2), &[Tv(3735), Tv(3736)]),
-    ((3841, false, NAND2), &[Tv(3838), Tv(3840)]),
-    ((3844, false, AND2), &[Tv(3842), Tv(3843)]),
-    ((3948, false, NAND2), &[Tv(3945), Tv(3947)]),
-    ((3951, false, AND2), &[Tv(3949), Tv(3950)]),
-    ((4055, false, NAND2), &[Tv(4052), Tv(4054)]),
-    ((4058, false, AND2), &[Tv(4056), Tv(4057)]),
-    ((4162, false, NAND2), &[Tv(4159), Tv(4161)]),
-    ((4165, false, AND2), &[Tv(4163), Tv(4164)]),
-    ((4269, false, NAND2), &[Tv(4266), Tv(4268)]),
-    ((4272, false, AND2), &[Tv(4270), Tv(4271)]),
-    ((4376, false, NAND2), &[Tv(4373), Tv(4375)]),
-    ((4379, false, AND2), &[Tv(4377), Tv(4378)]),
-    ((4483, false, NAND2), &[Tv(4480), Tv(4482)]),
-    ((4486, false, AND2), &[Tv(4484), Tv(4485)]),
-    ((4590, false, NAND2), &[Tv(4587), Tv(4589)]),
-    ((4593, false, AND2), &[Tv(4591), Tv(4592)]),
-    ((4697, false, NAND2), &[Tv(4694), Tv(4696)]),
-    ((4700, false, AND2), &[Tv(4698), Tv(4699)]),
-    ((4804, false, NAND2), &[Tv(4801), Tv(4803)]),
-    ((4807, false, AND2), &[Tv(4805), Tv(4806)]),
-    ((4911, false, NAND2), &[Tv(4908), Tv(4910)]),
-    ((4914, false, AND2), &[Tv(4912), Tv(4913)]),
-    ((5018, false, NAND2), &[Tv(5015), Tv(5017)]),
-    ((5021, false, AND2), &[Tv(5019), Tv(5020)]),
-    ((5125, false, NAND2), &[Tv(5122), Tv(5124)]),
-    ((5128, false, AND2), &[Tv(5126), Tv(5127)]),
-    ((5232, false, NAND2), &[Tv(5229), Tv(5231)]),
-    ((5235, false, AND2), &[Tv(5233), Tv(5234)]),
-    ((5339, false, NAND2), &[Tv(5336), Tv(5338)]),
-    ((5342, false, AND2), &[Tv(5340), Tv(5341)]),
-    ((5446, false, NAND2), &[Tv(5443), Tv(5445)]),
-    ((5449, false, AND2), &[Tv(5447), Tv(5448)]),
-    ((5553, false, NAND2), &[Tv(5550), Tv(5552)]),
-    ((5556, false, AND2), &[Tv(5554), Tv(5555)]),
-    ((5660, false, NAND2), &[Tv(5657), Tv(5659)]),
-    ((5663, false, AND2), &[Tv(5661), Tv(5662)]),
-    ((5767, false, NAND2), &[Tv(5764), Tv(5766)]),
-    ((5770, false, AND2), &[Tv(5768), Tv(5769)]),
-    ((5874, false, NAND2), &[Tv(5871), Tv(5873)]),
-    ((5877, false, AND2), &[Tv(5875), Tv(5876)]),
-    ((5981, false, NAND2), &[Tv(5978), Tv(5980)]),
-    ((5984, false, AND2), &[Tv(5982), Tv(5983)]),
-    ((6088, false, NAND2), &[Tv(6085), Tv(6087)]),
-    ((6091, false, AND2), &[Tv(6089), Tv(6090)]),
-    ((6195, false, NAND2), &[Tv(6192), Tv(6194)]),
-    ((6198, false, AND2), &[Tv(6196), Tv(6197)]),
-    ((6302, false, NAND2), &[Tv(6299), Tv(6301)]),
-    ((6305, false, AND2), &[Tv(6303), Tv(6304)]),
-    ((6409, false, NAND2), &[Tv(6406), Tv(6408)]),
-    ((6412, false, AND2), &[Tv(6410), Tv(6411)]),
-    ((142, false, INV), &[Arg(3, 147)]),
-    ((143, false, INV), &[Arg(3, 148)]),
-    ((144, false, INV), &[Arg(3, 149)]),
-    ((145, false, INV), &[Arg(3, 150)]),
-    ((146, false, INV), &[Arg(3, 151)]),
-    ((147, false, INV), &[Arg(3, 152)]),
-    ((148, false, INV), &[Arg(3, 153)]),
-    ((149, false, INV), &[Arg(3, 154)]),
-    ((165, false, INV), &[Arg(3, 171)]),
-    ((166, false, INV), &[Arg(3, 172)]),
-    ((167, false, INV), &[Arg(3, 173)]),
-    ((168, false, INV), &[Arg(3, 174)]),
-    ((169, false, INV), &[Arg(3, 175)]),
-    ((170, false, INV), &[Arg(3, 176)]),
-    ((171, false, INV), &[Arg(3, 177)]),
-    ((172, false, INV), &[Arg(3, 178)]),
-    ((261, false, INV), &[Arg(3, 179)]),
-    ((262, false, INV), &[Arg(3, 180)]),
-    ((263, false, INV), &[Arg(3, 181)]),
-    ((264, false, INV), &[Arg(3, 182)]),
-    ((265, false, INV), &[Arg(3, 183)]),
-    ((266, false, INV), &[Arg(3, 184)]),
-    ((267, false, INV), &[Arg(3, 185)]),
-    ((268, false, INV), &[Arg(3, 186)]),
-    ((357, false, INV), &[Arg(3, 188)]),
-    ((358, false, INV), &[Arg(3, 189)]),
-    ((359, false, INV), &[Arg(3, 190)]),
-    ((360, false, INV), &[Arg(3, 191)]),
-    ((361, false, INV), &[Arg(3, 192)]),
-    ((362, false, INV), &[Arg(3, 193)]),
-    ((363, false, INV), &[Arg(3, 194)]),
-    ((364, false, INV), &[Arg(3, 195)]),
-    ((621, false, INV), &[Arg(3, 455)]),
-    ((625, false, INV), &[Arg(3, 481)]),
-    ((634, false, INV), &[Arg(3, 512)]),
-    ((636, false, INV), &[Arg(3, 547)]),
-    ((640, false, INV), &[Arg(3, 555)]),
-    ((641, false, INV), &[Arg(3, 557)]),
-    ((642, false, INV), &[Arg(3, 561)]),
-    ((903, false, AND2), &[Tv(899), Tv(902)]),
-    ((929, false, AND2), &[Tv(915), Tv(928)]),
-    ((1028, false, NAND2), &[Tv(1020), Tv(1027)]),
-    ((1036, false, NAND2), &[Arg(1, 2), Tv(637)]),
-    ((1047, false, NAND2), &[Arg(1, 4), Tv(638)]),
-    ((1077, false, NAND2), &[Arg(1, 5), Tv(630)]),
-    ((1081, false, XNOR2), &[Arg(1, 10), Arg(3, 508)]),
-    ((1083, false, XNOR2), &[Arg(1, 11), Arg(3, 509)]),
-    ((1093, false, XNOR2), &[Arg(1, 12), Arg(3, 510)]),
-    ((1095, false, AND2), &[Tv(1085), Tv(1090)]),
-    ((1098, false, AND2), &[Tv(1088), Tv(1092)]),
-    ((1100, false, XNOR2), &[Arg(1, 7), Arg(3, 505)]),
-    ((1101, false, AND2), &[Tv(1086), Tv(1094)]),
-    ((1105, false, NAND2), &[Arg(1, 8), Tv(632)]),
-    ((1107, false, AND2), &[Tv(1089), Tv(1106)]),
-    ((1113, false, AND2), &[Tv(1109), Tv(1112)]),
-    ((1114, false, AND2), &[Tv(1076), Tv(1084)]),
-    ((1118, false, AND2), &[Tv(1082), Tv(1117)]),
-    ((1120, false, AND2), &[Tv(1078), Tv(1087)]),
-    ((1127, false, NAND2), &[Tv(409), Arg(3, 463)]),
-    ((1128, false, NAND2), &[Tv(393), Arg(3, 453)]),
-    ((1129, false, NAND2), &[Tv(395), Arg(3, 455)]),
-    ((1131, false, NAND2), &[Arg(1, 14), Tv(624)]),
-    ((1132, false, XNOR2), &[Arg(1, 1), Arg(3, 450)]),
-    ((1134, false, NAND2), &[Arg(1, 8), Tv(622)]),
-    ((1135, false, XNOR2), &[Arg(1, 12), Arg(3, 461)]),
-    ((1136, false, NAND2), &[Arg(1, 0), Tv(618)]),
-    ((1138, false, NAND2), &[Tv(411), Arg(3, 459)]),
-    ((1139, false, NAND2), &[Arg(1, 10), Tv(623)]),
-    ((1142, false, NAND2), &[Tv(391), Arg(3, 451)]),
-    ((1144, false, XNOR2), &[Arg(1, 5), Arg(3, 454)]),
-    ((1163, false, AND2), &[Tv(1137), Tv(1140)]),
-    ((1323, false, AND2), &[Tv(1303), Tv(1322)]),
-    ((1361, false, AND2), &[Tv(1351), Tv(1360)]),
-    ((1377, false, AND2), &[Tv(1371), Tv(1376)]),
-    ((1380, false, NAND2), &[Arg(1, 1), Tv(606)]),
-    ((1381, false, NAND2), &[Tv(394), Arg(3, 405)]),
-    ((1384, false, NAND2), &[Tv(391), Arg(3, 402)]),
-    ((1385, false, NAND2), &[Arg(1, 10), Tv(613)]),
-    ((1386, false, NAND2), &[Arg(1, 14), Tv(615)]),
-    ((1391, false, XNOR2), &[Arg(1, 9), Arg(3, 409)]),
-    ((1395, false, AND2), &[Tv(1393), Tv(1394)]),
-    ((1396, false, NAND2), &[Tv(390), Arg(3, 401)]),
-    ((1397, false, NAND2), &[Arg(1, 0), Tv(605)]),
-    ((1399, false, NAND2), &[Tv(389), Arg(3, 400)]),
-    ((1400, false, NAND2), &[Arg(1, 3), Tv(608)]),
-    ((1401, false, NAND2), &[Arg(1, 13), Tv(614)]),
-    ((1402, false, NAND2), &[Arg(1, 15), Tv(616)]),
-    ((1407, false, NAND2), &[Arg(1, 8), Tv(612)]),
-    ((1419, false, AND2), &[Tv(1389), Tv(1398)]),
-    ((1422, false, AND2), &[Tv(1404), Tv(1408)]),
-    ((1424, false, AND2), &[Tv(1403), Tv(1406)]),
-    ((1428, false, AND2), &[Tv(617), Tv(1405)]),
-    ((1436, false, NAND2), &[Tv(404), Arg(3, 359)]),
-    ((1443, false, NAND2), &[Tv(389), Arg(3, 351)]),
-    ((1447, false, XNOR2), &[Arg(1, 7), Arg(3, 358)]),
-    ((1449, false, AND2), &[Tv(604), Tv(1448)]),
-    ((1450, false, XNOR2), &[Arg(1, 3), Arg(3, 354)]),
-    ((1451, false, XNOR2), &[Arg(1, 15), Arg(3, 366)]),
-    ((1452, false, XNOR2), &[Arg(1, 13), Arg(3, 364)]),
-    ((1456, false, AND2), &[Tv(1438), Tv(1444)]),
-    ((1459, false, NAND2), &[Arg(1, 6), Tv(600)]),
-    ((1461, false, AND2), &[Tv(1437), Tv(1453)]),
-    ((1462, false, XNOR2), &[Arg(1, 9), Arg(3, 360)]),
-    ((1464, false, AND2), &[Tv(1440), Tv(1454)]),
-    ((1467, false, AND2), &[Tv(1441), Tv(1442)]),
-    ((1471, false, XNOR2), &[Arg(1, 11), Arg(3, 362)]),
-    ((1474, false, AND2), &[Tv(1458), Tv(1473)]),
-    ((1477, false, AND2), &[Tv(1445), Tv(1446)]),
+static LEVEL_11: [((usize, bool, CellType), &[GateInput]); 297] = [
+    ((3958, false, XOR2), &[Tv(2096), Tv(3957)]),
+    ((3961, false, XOR2), &[Tv(2099), Tv(3960)]),
+    ((3964, false, XOR2), &[Tv(2102), Tv(3963)]),
+    ((3967, false, XOR2), &[Tv(2105), Tv(3966)]),
+    ((3970, false, XOR2), &[Tv(2108), Tv(3969)]),
+    ((3973, false, XOR2), &[Tv(2111), Tv(3972)]),
+    ((3976, false, XOR2), &[Tv(2114), Tv(3975)]),
+    ((3979, false, XOR2), &[Tv(2117), Tv(3978)]),
+    ((3982, false, XOR2), &[Tv(2120), Tv(3981)]),
+    ((3985, false, XOR2), &[Tv(2123), Tv(3984)]),
+    ((3988, false, XOR2), &[Tv(2126), Tv(3987)]),
+    ((3991, false, XOR2), &[Tv(2129), Tv(3990)]),
+    ((3994, false, XOR2), &[Tv(2132), Tv(3993)]),
+    ((3997, false, XOR2), &[Tv(2135), Tv(3996)]),
+    ((4000, false, XOR2), &[Tv(2138), Tv(3999)]),
+    ((4003, false, XOR2), &[Tv(2141), Tv(4002)]),
+    ((4006, false, XOR2), &[Tv(2144), Tv(4005)]),
+    ((4009, false, XOR2), &[Tv(2147), Tv(4008)]),
+    ((4012, false, XOR2), &[Tv(2150), Tv(4011)]),
+    ((4015, false, XOR2), &[Tv(2153), Tv(4014)]),
+    ((4018, false, XOR2), &[Tv(2156), Tv(4017)]),
+    ((4021, false, XOR2), &[Tv(2159), Tv(4020)]),
+    ((4024, false, XOR2), &[Tv(2162), Tv(4023)]),
+    ((4027, false, XOR2), &[Tv(2165), Tv(4026)]),
+    ((4030, false, XOR2), &[Tv(2168), Tv(4029)]),
+    ((4033, false, XOR2), &[Tv(2171), Tv(4032)]),
+    ((4036, false, XOR2), &[Tv(2174), Tv(4035)]),
+    ((4039, false, XOR2), &[Tv(2177), Tv(4038)]),
+    ((4042, false, XOR2), &[Tv(2180), Tv(4041)]),
+    ((4045, false, XOR2), &[Tv(2183), Tv(4044)]),
+    ((4048, false, XOR2), &[Tv(2186), Tv(4047)]),
+    ((4051, false, XOR2), &[Tv(2189), Tv(4050)]),
+    ((4056, false, XOR2), &[Tv(2292), Tv(4055)]),
+    ((4059, false, XOR2), &[Tv(2295), Tv(4058)]),
+    ((4062, false, XOR2), &[Tv(2298), Tv(4061)]),
+    ((4065, false, XOR2), &[Tv(2301), Tv(4064)]),
+    ((4068, false, XOR2), &[Tv(2304), Tv(4067)]),
+    ((4071, false, XOR2), &[Tv(2307), Tv(4070)]),
+    ((4074, false, XOR2), &[Tv(2310), Tv(4073)]),
+    ((4077, false, XOR2), &[Tv(2313), Tv(4076)]),
+    ((4080, false, XOR2), &[Tv(2316), Tv(4079)]),
+    ((4083, false, XOR2), &[Tv(2319), Tv(4082)]),
+    ((4086, false, XOR2), &[Tv(2322), Tv(4085)]),
+    ((4089, false, XOR2), &[Tv(2325), Tv(4088)]),
+    ((4092, false, XOR2), &[Tv(2328), Tv(4091)]),
+    ((4095, false, XOR2), &[Tv(2331), Tv(4094)]),
+    ((4098, false, XOR2), &[Tv(2334), Tv(4097)]),
+    ((4101, false, XOR2), &[Tv(2337), Tv(4100)]),
+    ((4104, false, XOR2), &[Tv(2340), Tv(4103)]),
+    ((4107, false, XOR2), &[Tv(2343), Tv(4106)]),
+    ((4110, false, XOR2), &[Tv(2346), Tv(4109)]),
+    ((4113, false, XOR2), &[Tv(2349), Tv(4112)]),
+    ((4116, false, XOR2), &[Tv(2352), Tv(4115)]),
+    ((4119, false, XOR2), &[Tv(2355), Tv(4118)]),
+    ((4122, false, XOR2), &[Tv(2358), Tv(4121)]),
+    ((4125, false, XOR2), &[Tv(2361), Tv(4124)]),
+    ((4128, false, XOR2), &[Tv(2364), Tv(4127)]),
+    ((4131, false, XOR2), &[Tv(2367), Tv(4130)]),
+    ((4134, false, XOR2), &[Tv(2370), Tv(4133)]),
+    ((4137, false, XOR2), &[Tv(2373), Tv(4136)]),
+    ((4140, false, XOR2), &[Tv(2376), Tv(4139)]),
+    ((4143, false, XOR2), &[Tv(2379), Tv(4142)]),
+    ((4146, false, XOR2), &[Tv(2382), Tv(4145)]),
+    ((4149, false, XOR2), &[Tv(2385), Tv(4148)]),
+    ((4154, false, XOR2), &[Tv(2488), Tv(4153)]),
+    ((4157, false, XOR2), &[Tv(2491), Tv(4156)]),
+    ((4160, false, XOR2), &[Tv(2494), Tv(4159)]),
+    ((4163, false, XOR2), &[Tv(2497), Tv(4162)]),
+    ((4166, false, XOR2), &[Tv(2500), Tv(4165)]),
+    ((4169, false, XOR2), &[Tv(2503), Tv(4168)]),
+    ((4172, false, XOR2), &[Tv(2506), Tv(4171)]),
+    ((4175, false, XOR2), &[Tv(2509), Tv(4174)]),
+    ((4178, false, XOR2), &[Tv(2512), Tv(4177)]),
+    ((4181, false, XOR2), &[Tv(2515), Tv(4180)]),
+    ((4184, false, XOR2), &[Tv(2518), Tv(4183)]),
+    ((4187, false, XOR2), &[Tv(2521), Tv(4186)]),
+    ((4190, false, XOR2), &[Tv(2524), Tv(4189)]),
+    ((4193, false, XOR2), &[Tv(2527), Tv(4192)]),
+    ((4196, false, XOR2), &[Tv(2530), Tv(4195)]),
+    ((4199, false, XOR2), &[Tv(2533), Tv(4198)]),
+    ((4202, false, XOR2), &[Tv(2536), Tv(4201)]),
+    ((4205, false, XOR2), &[Tv(2539), Tv(4204)]),
+    ((4208, false, XOR2), &[Tv(2542), Tv(4207)]),
+    ((4211, false, XOR2), &[Tv(2545), Tv(4210)]),
+    ((4214, false, XOR2), &[Tv(2548), Tv(4213)]),
+    ((4217, false, XOR2), &[Tv(2551), Tv(4216)]),
+    ((4220, false, XOR2), &[Tv(2554), Tv(4219)]),
+    ((4223, false, XOR2), &[Tv(2557), Tv(4222)]),
+    ((4226, false, XOR2), &[Tv(2560), Tv(4225)]),
+    ((4229, false, XOR2), &[Tv(2563), Tv(4228)]),
+    ((4232, false, XOR2), &[Tv(2566), Tv(4231)]),
+    ((4235, false, XOR2), &[Tv(2569), Tv(4234)]),
+    ((4238, false, XOR2), &[Tv(2572), Tv(4237)]),
+    ((4241, false, XOR2), &[Tv(2575), Tv(4240)]),
+    ((4244, false, XOR2), &[Tv(2578), Tv(4243)]),
+    ((4247, false, XOR2), &[Tv(2581), Tv(4246)]),
+    ((4252, false, XOR2), &[Tv(2684), Tv(4251)]),
+    ((4255, false, XOR2), &[Tv(2687), Tv(4254)]),
+    ((4258, false, XOR2), &[Tv(2690), Tv(4257)]),
+    ((4261, false, XOR2), &[Tv(2693), Tv(4260)]),
+    ((4264, false, XOR2), &[Tv(2696), Tv(4263)]),
+    ((4267, false, XOR2), &[Tv(2699), Tv(4266)]),
+    ((4270, false, XOR2), &[Tv(2702), Tv(4269)]),
+    ((4273, false, XOR2), &[Tv(2705), Tv(4272)]),
+    ((4276, false, XOR2), &[Tv(2708), Tv(4275)]),
+    ((4279, false, XOR2), &[Tv(2711), Tv(4278)]),
+    ((4282, false, XOR2), &[Tv(2714), Tv(4281)]),
+    ((4285, false, XOR2), &[Tv(2717), Tv(4284)]),
+    ((4288, false, XOR2), &[Tv(2720), Tv(4287)]),
+    ((4291, false, XOR2), &[Tv(2723), Tv(4290)]),
+    ((4294, false, XOR2), &[Tv(2726), Tv(4293)]),
+    ((4297, false, XOR2), &[Tv(2729), Tv(4296)]),
+    ((4300, false, XOR2), &[Tv(2732), Tv(4299)]),
+    ((4303, false, XOR2), &[Tv(2735), Tv(4302)]),
+    ((4306, false, XOR2), &[Tv(2738), Tv(4305)]),
+    ((4309, false, XOR2), &[Tv(2741), Tv(4308)]),
+    ((4312, false, XOR2), &[Tv(2744), Tv(4311)]),
+    ((4315, false, XOR2), &[Tv(2747), Tv(4314)]),
+    ((4318, false, XOR2), &[Tv(2750), Tv(4317)]),
+    ((4321, false, XOR2), &[Tv(2753), Tv(4320)]),
+    ((4324, false, XOR2), &[Tv(2756), Tv(4323)]),
+    ((4327, false, XOR2), &[Tv(2759), Tv(4326)]),
+    ((4330, false, XOR2), &[Tv(2762), Tv(4329)]),
+    ((4333, false, XOR2), &[Tv(2765), Tv(4332)]),
+    ((4336, false, XOR2), &[Tv(2768), Tv(4335)]),
+    ((4339, false, XOR2), &[Tv(2771), Tv(4338)]),
+    ((4342, false, XOR2), &[Tv(2774), Tv(4341)]),
+    ((4345, false, XOR2), &[Tv(2777), Tv(4344)]),
+    ((4350, false, XOR2), &[Tv(2880), Tv(4349)]),
+    ((4353, false, XOR2), &[Tv(2883), Tv(4352)]),
+    ((4356, false, XOR2), &[Tv(2886), Tv(4355)]),
+    ((4359, false, XOR2), &[Tv(2889), Tv(4358)]),
+    ((4362, false, XOR2), &[Tv(2892), Tv(4361)]),
+    ((4365, false, XOR2), &[Tv(2895), Tv(4364)]),
+    ((4368, false, XOR2), &[Tv(2898), Tv(4367)]),
+    ((4371, false, XOR2), &[Tv(2901), Tv(4370)]),
+    ((4374, false, XOR2), &[Tv(2904), Tv(4373)]),
+    ((4377, false, XOR2), &[Tv(2907), Tv(4376)]),
+    ((4380, false, XOR2), &[Tv(2910), Tv(4379)]),
+    ((4383, false, XOR2), &[Tv(2913), Tv(4382)]),
+    ((4386, false, XOR2), &[Tv(2916), Tv(4385)]),
+    ((4389, false, XOR2), &[Tv(2919), Tv(4388)]),
+    ((4392, false, XOR2), &[Tv(2922), Tv(4391)]),
+    ((4395, false, XOR2), &[Tv(2925), Tv(4394)]),
+    ((4398, false, XOR2), &[Tv(2928), Tv(4397)]),
+    ((4401, false, XOR2), &[Tv(2931), Tv(4400)]),
+    ((4404, false, XOR2), &[Tv(2934), Tv(4403)]),
+    ((4407, false, XOR2), &[Tv(2937), Tv(4406)]),
+    ((4410, false, XOR2), &[Tv(2940), Tv(4409)]),
+    ((4413, false, XOR2), &[Tv(2943), Tv(4412)]),
+    ((4416, false, XOR2), &[Tv(2946), Tv(4415)]),
+    ((4419, false, XOR2), &[Tv(2949), Tv(4418)]),
+    ((4422, false, XOR2), &[Tv(2952), Tv(4421)]),
+    ((4425, false, XOR2), &[Tv(2955), Tv(4424)]),
+    ((4428, false, XOR2), &[Tv(2958), Tv(4427)]),
+    ((4431, false, XOR2), &[Tv(2961), Tv(4430)]),
+    ((4434, false, XOR2), &[Tv(2964), Tv(4433)]),
+    ((4437, false, XOR2), &[Tv(2967), Tv(4436)]),
+    ((4440, false, XOR2), &[Tv(2970), Tv(4439)]),
+    ((4443, false, XOR2), &[Tv(2973), Tv(4442)]),
+    ((4449, false, XOR2), &[Tv(3076), Tv(4448)]),
+    ((4452, false, XOR2), &[Tv(3079), Tv(4451)]),
+    ((4455, false, XOR2), &[Tv(3082), Tv(4454)]),
+    ((4458, false, XOR2), &[Tv(3085), Tv(4457)]),
+    ((4461, false, XOR2), &[Tv(3088), Tv(4460)]),
+    ((4464, false, XOR2), &[Tv(3091), Tv(4463)]),
+    ((4467, false, XOR2), &[Tv(3094), Tv(4466)]),
+    ((4470, false, XOR2), &[Tv(3097), Tv(4469)]),
+    ((4473, false, XOR2), &[Tv(3100), Tv(4472)]),
+    ((4476, false, XOR2), &[Tv(3103), Tv(4475)]),
+    ((4479, false, XOR2), &[Tv(3106), Tv(4478)]),
+    ((4482, false, XOR2), &[Tv(3109), Tv(4481)]),
+    ((4485, false, XOR2), &[Tv(3112), Tv(4484)]),
+    ((4488, false, XOR2), &[Tv(3115), Tv(4487)]),
+    ((4491, false, XOR2), &[Tv(3118), Tv(4490)]),
+    ((4494, false, XOR2), &[Tv(3121), Tv(4493)]),
+    ((4497, false, XOR2), &[Tv(3124), Tv(4496)]),
+    ((4500, false, XOR2), &[Tv(3127), Tv(4499)]),
+    ((4503, false, XOR2), &[Tv(3130), Tv(4502)]),
+    ((4506, false, XOR2), &[Tv(3133), Tv(4505)]),
+    ((4509, false, XOR2), &[Tv(3136), Tv(4508)]),
+    ((4512, false, XOR2), &[Tv(3139), Tv(4511)]),
+    ((4515, false, XOR2), &[Tv(3142), Tv(4514)]),
+    ((4518, false, XOR2), &[Tv(3145), Tv(4517)]),
+    ((4521, false, XOR2), &[Tv(3148), Tv(4520)]),
+    ((4524, false, XOR2), &[Tv(3151), Tv(4523)]),
+    ((4527, false, XOR2), &[Tv(3154), Tv(4526)]),
+    ((4530, false, XOR2), &[Tv(3157), Tv(4529)]),
+    ((4533, false, XOR2), &[Tv(3160), Tv(4532)]),
+    ((4536, false, XOR2), &[Tv(3163), Tv(4535)]),
+    ((4539, false, XOR2), &[Tv(3166), Tv(4538)]),
+    ((4542, false, XOR2), &[Tv(3169), Tv(4541)]),
+    ((4547, false, XOR2), &[Tv(3272), Tv(4546)]),
+    ((4550, false, XOR2), &[Tv(3275), Tv(4549)]),
+    ((4553, false, XOR2), &[Tv(3278), Tv(4552)]),
+    ((4556, false, XOR2), &[Tv(3281), Tv(4555)]),
+    ((4559, false, XOR2), &[Tv(3284), Tv(4558)]),
+    ((4562, false, XOR2), &[Tv(3287), Tv(4561)]),
+    ((4565, false, XOR2), &[Tv(3290), Tv(4564)]),
+    ((4568, false, XOR2), &[Tv(3293), Tv(4567)]),
+    ((4571, false, XOR2), &[Tv(3296), Tv(4570)]),
+    ((4574, false, XOR2), &[Tv(3299), Tv(4573)]),
+    ((4577, false, XOR2), &[Tv(3302), Tv(4576)]),
+    ((4580, false, XOR2), &[Tv(3305), Tv(4579)]),
+    ((4583, false, XOR2), &[Tv(3308), Tv(4582)]),
+    ((4586, false, XOR2), &[Tv(3311), Tv(4585)]),
+    ((4589, false, XOR2), &[Tv(3314), Tv(4588)]),
+    ((4592, false, XOR2), &[Tv(3317), Tv(4591)]),
+    ((4595, false, XOR2), &[Tv(3320), Tv(4594)]),
+    ((4598, false, XOR2), &[Tv(3323), Tv(4597)]),
+    ((4601, false, XOR2), &[Tv(3326), Tv(4600)]),
+    ((4604, false, XOR2), &[Tv(3329), Tv(4603)]),
+    ((4607, false, XOR2), &[Tv(3332), Tv(4606)]),
+    ((4610, false, XOR2), &[Tv(3335), Tv(4609)]),
+    ((4613, false, XOR2), &[Tv(3338), Tv(4612)]),
+    ((4616, false, XOR2), &[Tv(3341), Tv(4615)]),
+    ((4619, false, XOR2), &[Tv(3344), Tv(4618)]),
+    ((4622, false, XOR2), &[Tv(3347), Tv(4621)]),
+    ((4625, false, XOR2), &[Tv(3350), Tv(4624)]),
+    ((4628, false, XOR2), &[Tv(3353), Tv(4627)]),
+    ((4631, false, XOR2), &[Tv(3356), Tv(4630)]),
+    ((4634, false, XOR2), &[Tv(3359), Tv(4633)]),
+    ((4637, false, XOR2), &[Tv(3362), Tv(4636)]),
+    ((4640, false, XOR2), &[Tv(3365), Tv(4639)]),
+    ((4645, false, XOR2), &[Tv(3468), Tv(4644)]),
+    ((4648, false, XOR2), &[Tv(3471), Tv(4647)]),
+    ((4651, false, XOR2), &[Tv(3474), Tv(4650)]),
+    ((4654, false, XOR2), &[Tv(3477), Tv(4653)]),
+    ((4657, false, XOR2), &[Tv(3480), Tv(4656)]),
+    ((4660, false, XOR2), &[Tv(3483), Tv(4659)]),
+    ((4663, false, XOR2), &[Tv(3486), Tv(4662)]),
+    ((4666, false, XOR2), &[Tv(3489), Tv(4665)]),
+    ((4669, false, XOR2), &[Tv(3492), Tv(4668)]),
+    ((4672, false, XOR2), &[Tv(3495), Tv(4671)]),
+    ((4675, false, XOR2), &[Tv(3498), Tv(4674)]),
+    ((4678, false, XOR2), &[Tv(3501), Tv(4677)]),
+    ((4681, false, XOR2), &[Tv(3504), Tv(4680)]),
+    ((4684, false, XOR2), &[Tv(3507), Tv(4683)]),
+    ((4687, false, XOR2), &[Tv(3510), Tv(4686)]),
+    ((4690, false, XOR2), &[Tv(3513), Tv(4689)]),
+    ((4693, false, XOR2), &[Tv(3516), Tv(4692)]),
+    ((4696, false, XOR2), &[Tv(3519), Tv(4695)]),
+    ((4699, false, XOR2), &[Tv(3522), Tv(4698)]),
+    ((4702, false, XOR2), &[Tv(3525), Tv(4701)]),
+    ((4705, false, XOR2), &[Tv(3528), Tv(4704)]),
+    ((4708, false, XOR2), &[Tv(3531), Tv(4707)]),
+    ((4711, false, XOR2), &[Tv(3534), Tv(4710)]),
+    ((4714, false, XOR2), &[Tv(3537), Tv(4713)]),
+    ((4717, false, XOR2), &[Tv(3540), Tv(4716)]),
+    ((4720, false, XOR2), &[Tv(3543), Tv(4719)]),
+    ((4723, false, XOR2), &[Tv(3546), Tv(4722)]),
+    ((4726, false, XOR2), &[Tv(3549), Tv(4725)]),
+    ((4729, false, XOR2), &[Tv(3552), Tv(4728)]),
+    ((4732, false, XOR2), &[Tv(3555), Tv(4731)]),
+    ((4735, false, XOR2), &[Tv(3558), Tv(4734)]),
+    ((4738, false, XOR2), &[Tv(3561), Tv(4737)]),
+    ((4744, false, XOR2), &[Tv(3664), Tv(4743)]),
+    ((4747, false, XOR2), &[Tv(3667), Tv(4746)]),
+    ((4750, false, XOR2), &[Tv(3670), Tv(4749)]),
+    ((4753, false, XOR2), &[Tv(3673), Tv(4752)]),
+    ((4756, false, XOR2), &[Tv(3676), Tv(4755)]),
+    ((4759, false, XOR2), &[Tv(3679), Tv(4758)]),
+    ((4762, false, XOR2), &[Tv(3682), Tv(4761)]),
+    ((4765, false, XOR2), &[Tv(3685), Tv(4764)]),
+    ((4768, false, XOR2), &[Tv(3688), Tv(4767)]),
+    ((4771, false, XOR2), &[Tv(3691), Tv(4770)]),
+    ((4774, false, XOR2), &[Tv(3694), Tv(4773)]),
+    ((4777, false, XOR2), &[Tv(3697), Tv(4776)]),
+    ((4780, false, XOR2), &[Tv(3700), Tv(4779)]),
+    ((4783, false, XOR2), &[Tv(3703), Tv(4782)]),
+    ((4786, false, XOR2), &[Tv(3706), Tv(4785)]),
+    ((4789, false, XOR2), &[Tv(3709), Tv(4788)]),
+    ((4792, false, XOR2), &[Tv(3712), Tv(4791)]),
+    ((4795, false, XOR2), &[Tv(3715), Tv(4794)]),
+    ((4798, false, XOR2), &[Tv(3718), Tv(4797)]),
+    ((4801, false, XOR2), &[Tv(3721), Tv(4800)]),
+    ((4804, false, XOR2), &[Tv(3724), Tv(4803)]),
+    ((4807, false, XOR2), &[Tv(3727), Tv(4806)]),
+    ((4810, false, XOR2), &[Tv(3730), Tv(4809)]),
+    ((4813, false, XOR2), &[Tv(3733), Tv(4812)]),
+    ((4816, false, XOR2), &[Tv(3736), Tv(4815)]),
+    ((4819, false, XOR2), &[Tv(3739), Tv(4818)]),
+    ((4822, false, XOR2), &[Tv(3742), Tv(4821)]),
+    ((4825, false, XOR2), &[Tv(3745), Tv(4824)]),
+    ((4828, false, XOR2), &[Tv(3748), Tv(4827)]),
+    ((4831, false, XOR2), &[Tv(3751), Tv(4830)]),
+    ((4834, false, XOR2), &[Tv(3754), Tv(4833)]),
+    ((4837, false, XOR2), &[Tv(3757), Tv(4836)]),
+    ((5134, false, NAND2), &[Tv(5133), Tv(4446)]),
+    ((5330, false, AND2), &[Tv(4741), Tv(4839)]),
+    ((5428, false, AND2), &[Tv(4936), Tv(5034)]),
+    ((5430, false, INV), &[Tv(5034)]),
+    ((5526, false, AND2), &[Tv(5132), Tv(5231)]),
+    ((5529, false, INV), &[Tv(5231)]),
+    ((5727, false, INV), &[Tv(5329)]),
+    ((5954, false, AND2), &[Tv(5943), Tv(5953)]),
+    ((6151, false, AND2), &[Tv(6140), Tv(6150)]),
 ];
 
-static LEVEL_32: [((usize, bool, CellType), &[GateInput]); 139] = [
-    ((2853, false, NAND2), &[Tv(2829), Tv(2852)]),
-    ((2871, false, AND2), &[Tv(2869), Tv(2870)]),
-    ((2883, false, NAND2), &[Tv(2832), Tv(2882)]),
-    ((2893, false, NAND2), &[Arg(0, 6), Tv(395)]),
-    ((2912, false, NAND2), &[Tv(2889), Tv(2911)]),
-    ((2931, false, NAND2), &[Tv(2928), Tv(2930)]),
-    ((2932, false, AND2), &[Tv(2922), Tv(2924)]),
-    ((2943, false, NAND2), &[Tv(2894), Tv(2942)]),
-    ((3094, false, NAND2), &[Tv(3089), Tv(3093)]),
-    ((3096, false, OR2), &[Tv(142), Tv(1028)]),
-    ((3203, false, NAND2), &[Tv(3199), Tv(3202)]),
-    ((3205, false, OR2), &[Tv(143), Tv(1028)]),
-    ((3310, false, NAND2), &[Tv(3306), Tv(3309)]),
-    ((3312, false, OR2), &[Tv(144), Tv(1028)]),
-    ((3417, false, NAND2), &[Tv(3413), Tv(3416)]),
-    ((3419, false, OR2), &[Tv(145), Tv(1028)]),
-    ((3524, false, NAND2), &[Tv(3520), Tv(3523)]),
-    ((3526, false, OR2), &[Tv(146), Tv(1028)]),
-    ((3631, false, NAND2), &[Tv(3627), Tv(3630)]),
-    ((3633, false, OR2), &[Tv(147), Tv(1028)]),
-    ((3738, false, NAND2), &[Tv(3734), Tv(3737)]),
-    ((3740, false, OR2), &[Tv(148), Tv(1028)]),
-    ((3845, false, NAND2), &[Tv(3841), Tv(3844)]),
-    ((3847, false, OR2), &[Tv(149), Tv(1028)]),
-    ((3952, false, NAND2), &[Tv(3948), Tv(3951)]),
-    ((3954, false, OR2), &[Tv(165), Tv(1028)]),
-    ((4059, false, NAND2), &[Tv(4055), Tv(4058)]),
-    ((4061, false, OR2), &[Tv(166), Tv(1028)]),
-    ((4166, false, NAND2), &[Tv(4162), Tv(4165)]),
-    ((4168, false, OR2), &[Tv(167), Tv(1028)]),
-    ((4273, false, NAND2), &[Tv(4269), Tv(4272)]),
-    ((4275, false, OR2), &[Tv(168), Tv(1028)]),
-    ((4380, false, NAND2), &[Tv(4376), Tv(4379)]),
-    ((4382, false, OR2), &[Tv(169), Tv(1028)]),
-    ((4487, false, NAND2), &[Tv(4483), Tv(4486)]),
-    ((4489, false, OR2), &[Tv(170), Tv(1028)]),
-    ((4594, false, NAND2), &[Tv(4590), Tv(4593)]),
-    ((4596, false, OR2), &[Tv(171), Tv(1028)]),
-    ((4701, false, NAND2), &[Tv(4697), Tv(4700)]),
-    ((4703, false, OR2), &[Tv(172), Tv(1028)]),
-    ((4808, false, NAND2), &[Tv(4804), Tv(4807)]),
-    ((4810, false, OR2), &[Tv(261), Tv(1028)]),
-    ((4915, false, NAND2), &[Tv(4911), Tv(4914)]),
-    ((4917, false, OR2), &[Tv(262), Tv(1028)]),
-    ((5022, false, NAND2), &[Tv(5018), Tv(5021)]),
-    ((5024, false, OR2), &[Tv(263), Tv(1028)]),
-    ((5129, false, NAND2), &[Tv(5125), Tv(5128)]),
-    ((5131, false, OR2), &[Tv(264), Tv(1028)]),
-    ((5236, false, NAND2), &[Tv(5232), Tv(5235)]),
-    ((5238, false, OR2), &[Tv(265), Tv(1028)]),
-    ((5343, false, NAND2), &[Tv(5339), Tv(5342)]),
-    ((5345, false, OR2), &[Tv(266), Tv(1028)]),
-    ((5450, false, NAND2), &[Tv(5446), Tv(5449)]),
-    ((5452, false, OR2), &[Tv(267), Tv(1028)]),
-    ((5557, false, NAND2), &[Tv(5553), Tv(5556)]),
-    ((5559, false, OR2), &[Tv(268), Tv(1028)]),
-    ((5664, false, NAND2), &[Tv(5660), Tv(5663)]),
-    ((5666, false, OR2), &[Tv(357), Tv(1028)]),
-    ((5771, false, NAND2), &[Tv(5767), Tv(5770)]),
-    ((5773, false, OR2), &[Tv(358), Tv(1028)]),
-    ((5878, false, NAND2), &[Tv(5874), Tv(5877)]),
-    ((5880, false, OR2), &[Tv(359), Tv(1028)]),
-    ((5985, false, NAND2), &[Tv(5981), Tv(5984)]),
-    ((5987, false, OR2), &[Tv(360), Tv(1028)]),
-    ((6092, false, NAND2), &[Tv(6088), Tv(6091)]),
-    ((6094, false, OR2), &[Tv(361), Tv(1028)]),
-    ((6199, false, NAND2), &[Tv(6195), Tv(6198)]),
-    ((6201, false, OR2), &[Tv(362), Tv(1028)]),
-    ((6306, false, NAND2), &[Tv(6302), Tv(6305)]),
-    ((6308, false, OR2), &[Tv(363), Tv(1028)]),
-    ((6413, false, NAND2), &[Tv(6409), Tv(6412)]),
-    ((6415, false, OR2), &[Tv(364), Tv(1028)]),
-    ((610, false, INV), &[Arg(3, 405)]),
-    ((639, false, INV), &[Arg(3, 553)]),
-    ((643, false, INV), &[Arg(3, 579)]),
-    ((930, false, NAND2), &[Tv(903), Tv(929)]),
-    ((1030, false, NAND2), &[Arg(1, 8), Tv(640)]),
-    ((1031, false, NAND2), &[Tv(389), Arg(3, 547)]),
-    ((1032, false, NAND2), &[Tv(391), Arg(3, 549)]),
-    ((1035, false, NAND2), &[Arg(1, 10), Tv(641)]),
-    ((1038, false, XNOR2), &[Arg(1, 15), Arg(3, 562)]),
-    ((1040, false, XNOR2), &[Arg(1, 13), Arg(3, 560)]),
-    ((1041, false, NAND2), &[Arg(1, 14), Tv(642)]),
-    ((1042, false, NAND2), &[Tv(395), Arg(3, 553)]),
-    ((1043, false, NAND2), &[Tv(411), Arg(3, 557)]),
-    ((1044, false, NAND2), &[Arg(1, 0), Tv(636)]),
-    ((1046, false, NAND2), &[Tv(393), Arg(3, 551)]),
-    ((1048, false, XNOR2), &[Arg(1, 5), Arg(3, 552)]),
-    ((1050, false, XNOR2), &[Arg(1, 1), Arg(3, 548)]),
-    ((1053, false, AND2), &[Tv(1036), Tv(1047)]),
-    ((1054, false, XNOR2), &[Arg(1, 3), Arg(3, 550)]),
-    ((1091, false, NAND2), &[Arg(1, 14), Tv(634)]),
-    ((1096, false, AND2), &[Tv(1081), Tv(1095)]),
-    ((1099, false, AND2), &[Tv(1093), Tv(1098)]),
-    ((1102, false, AND2), &[Tv(1100), Tv(1101)]),
-    ((1108, false, AND2), &[Tv(1105), Tv(1107)]),
-    ((1115, false, AND2), &[Tv(1113), Tv(1114)]),
-    ((1119, false, AND2), &[Tv(1083), Tv(1118)]),
-    ((1121, false, AND2), &[Tv(1077), Tv(1120)]),
-    ((1126, false, NAND2), &[Tv(404), Arg(3, 457)]),
-    ((1130, false, NAND2), &[Tv(389), Arg(3, 449)]),
-    ((1133, false, XNOR2), &[Arg(1, 7), Arg(3, 456)]),
-    ((1141, false, NAND2), &[Arg(1, 6), Tv(621)]),
-    ((1143, false, AND2), &[Tv(1134), Tv(1138)]),
-    ((1145, false, XNOR2), &[Arg(1, 3), Arg(3, 452)]),
-    ((1146, false, XNOR2), &[Arg(1, 13), Arg(3, 462)]),
-    ((1147, false, AND2), &[Tv(1127), Tv(1142)]),
-    ((1149, false, XNOR2), &[Arg(1, 15), Arg(3, 464)]),
-    ((1151, false, XNOR2), &[Arg(1, 9), Arg(3, 458)]),
-    ((1153, false, AND2), &[Tv(1128), Tv(1144)]),
-    ((1156, false, AND2), &[Tv(1131), Tv(1139)]),
-    ((1157, false, AND2), &[Tv(625), Tv(1136)]),
-    ((1161, false, XNOR2), &[Arg(1, 11), Arg(3, 460)]),
-    ((1164, false, AND2), &[Tv(1129), Tv(1163)]),
-    ((1167, false, AND2), &[Tv(1132), Tv(1135)]),
-    ((1324, false, INV), &[Tv(1323)]),
-    ((1378, false, NAND2), &[Tv(1361), Tv(1377)]),
-    ((1382, false, NAND2), &[Tv(409), Arg(3, 414)]),
-    ((1383, false, NAND2), &[Tv(404), Arg(3, 408)]),
-    ((1387, false, AND2), &[Tv(1385), Tv(1386)]),
-    ((1390, false, XNOR2), &[Arg(1, 12), Arg(3, 412)]),
-    ((1392, false, XNOR2), &[Arg(1, 11), Arg(3, 411)]),
-    ((1409, false, AND2), &[Tv(1380), Tv(1401)]),
-    ((1412, false, AND2), &[Tv(1381), Tv(1384)]),
-    ((1414, false, XNOR2), &[Arg(1, 7), Arg(3, 407)]),
-    ((1415, false, AND2), &[Tv(1396), Tv(1400)]),
-    ((1420, false, AND2), &[Tv(1402), Tv(1419)]),
-    ((1423, false, AND2), &[Tv(1397), Tv(1422)]),
-    ((1425, false, AND2), &[Tv(1395), Tv(1424)]),
-    ((1429, false, AND2), &[Tv(1391), Tv(1428)]),
-    ((1431, false, AND2), &[Tv(1399), Tv(1407)]),
-    ((1457, false, AND2), &[Tv(1443), Tv(1456)]),
-    ((1460, false, AND2), &[Tv(1436), Tv(1459)]),
-    ((1463, false, AND2), &[Tv(1461), Tv(1462)]),
-    ((1465, false, AND2), &[Tv(1447), Tv(1464)]),
-    ((1468, false, AND2), &[Tv(1449), Tv(1467)]),
-    ((1472, false, AND2), &[Tv(1450), Tv(1471)]),
-    ((1475, false, AND2), &[Tv(1451), Tv(1474)]),
-    ((1478, false, AND2), &[Tv(1452), Tv(1477)]),
+static LEVEL_12: [((usize, bool, CellType), &[GateInput]); 178] = [
+    ((4937, false, XOR2), &[Tv(3958), Tv(4056)]),
+    ((4941, false, XOR2), &[Tv(3961), Tv(4059)]),
+    ((4944, false, XOR2), &[Tv(3964), Tv(4062)]),
+    ((4947, false, XOR2), &[Tv(3967), Tv(4065)]),
+    ((4950, false, XOR2), &[Tv(3970), Tv(4068)]),
+    ((4953, false, XOR2), &[Tv(3973), Tv(4071)]),
+    ((4956, false, XOR2), &[Tv(3976), Tv(4074)]),
+    ((4959, false, XOR2), &[Tv(3979), Tv(4077)]),
+    ((4962, false, XOR2), &[Tv(3982), Tv(4080)]),
+    ((4965, false, XOR2), &[Tv(3985), Tv(4083)]),
+    ((4968, false, XOR2), &[Tv(3988), Tv(4086)]),
+    ((4971, false, XOR2), &[Tv(3991), Tv(4089)]),
+    ((4974, false, XOR2), &[Tv(3994), Tv(4092)]),
+    ((4977, false, XOR2), &[Tv(3997), Tv(4095)]),
+    ((4980, false, XOR2), &[Tv(4000), Tv(4098)]),
+    ((4983, false, XOR2), &[Tv(4003), Tv(4101)]),
+    ((4986, false, XOR2), &[Tv(4006), Tv(4104)]),
+    ((4989, false, XOR2), &[Tv(4009), Tv(4107)]),
+    ((4992, false, XOR2), &[Tv(4012), Tv(4110)]),
+    ((4995, false, XOR2), &[Tv(4015), Tv(4113)]),
+    ((4998, false, XOR2), &[Tv(4018), Tv(4116)]),
+    ((5001, false, XOR2), &[Tv(4021), Tv(4119)]),
+    ((5004, false, XOR2), &[Tv(4024), Tv(4122)]),
+    ((5007, false, XOR2), &[Tv(4027), Tv(4125)]),
+    ((5010, false, XOR2), &[Tv(4030), Tv(4128)]),
+    ((5013, false, XOR2), &[Tv(4033), Tv(4131)]),
+    ((5016, false, XOR2), &[Tv(4036), Tv(4134)]),
+    ((5019, false, XOR2), &[Tv(4039), Tv(4137)]),
+    ((5022, false, XOR2), &[Tv(4042), Tv(4140)]),
+    ((5025, false, XOR2), &[Tv(4045), Tv(4143)]),
+    ((5028, false, XOR2), &[Tv(4048), Tv(4146)]),
+    ((5031, false, XOR2), &[Tv(4051), Tv(4149)]),
+    ((5035, false, XOR2), &[Tv(4154), Tv(4252)]),
+    ((5039, false, XOR2), &[Tv(4157), Tv(4255)]),
+    ((5042, false, XOR2), &[Tv(4160), Tv(4258)]),
+    ((5045, false, XOR2), &[Tv(4163), Tv(4261)]),
+    ((5048, false, XOR2), &[Tv(4166), Tv(4264)]),
+    ((5051, false, XOR2), &[Tv(4169), Tv(4267)]),
+    ((5054, false, XOR2), &[Tv(4172), Tv(4270)]),
+    ((5057, false, XOR2), &[Tv(4175), Tv(4273)]),
+    ((5060, false, XOR2), &[Tv(4178), Tv(4276)]),
+    ((5063, false, XOR2), &[Tv(4181), Tv(4279)]),
+    ((5066, false, XOR2), &[Tv(4184), Tv(4282)]),
+    ((5069, false, XOR2), &[Tv(4187), Tv(4285)]),
+    ((5072, false, XOR2), &[Tv(4190), Tv(4288)]),
+    ((5075, false, XOR2), &[Tv(4193), Tv(4291)]),
+    ((5078, false, XOR2), &[Tv(4196), Tv(4294)]),
+    ((5081, false, XOR2), &[Tv(4199), Tv(4297)]),
+    ((5084, false, XOR2), &[Tv(4202), Tv(4300)]),
+    ((5087, false, XOR2), &[Tv(4205), Tv(4303)]),
+    ((5090, false, XOR2), &[Tv(4208), Tv(4306)]),
+    ((5093, false, XOR2), &[Tv(4211), Tv(4309)]),
+    ((5096, false, XOR2), &[Tv(4214), Tv(4312)]),
+    ((5099, false, XOR2), &[Tv(4217), Tv(4315)]),
+    ((5102, false, XOR2), &[Tv(4220), Tv(4318)]),
+    ((5105, false, XOR2), &[Tv(4223), Tv(4321)]),
+    ((5108, false, XOR2), &[Tv(4226), Tv(4324)]),
+    ((5111, false, XOR2), &[Tv(4229), Tv(4327)]),
+    ((5114, false, XOR2), &[Tv(4232), Tv(4330)]),
+    ((5117, false, XOR2), &[Tv(4235), Tv(4333)]),
+    ((5120, false, XOR2), &[Tv(4238), Tv(4336)]),
+    ((5123, false, XOR2), &[Tv(4241), Tv(4339)]),
+    ((5126, false, XOR2), &[Tv(4244), Tv(4342)]),
+    ((5129, false, XOR2), &[Tv(4247), Tv(4345)]),
+    ((5135, false, XOR2), &[Tv(4350), Tv(4449)]),
+    ((5138, false, XOR2), &[Tv(4353), Tv(4452)]),
+    ((5141, false, XOR2), &[Tv(4356), Tv(4455)]),
+    ((5144, false, XOR2), &[Tv(4359), Tv(4458)]),
+    ((5147, false, XOR2), &[Tv(4362), Tv(4461)]),
+    ((5150, false, XOR2), &[Tv(4365), Tv(4464)]),
+    ((5153, false, XOR2), &[Tv(4368), Tv(4467)]),
+    ((5156, false, XOR2), &[Tv(4371), Tv(4470)]),
+    ((5159, false, XOR2), &[Tv(4374), Tv(4473)]),
+    ((5162, false, XOR2), &[Tv(4377), Tv(4476)]),
+    ((5165, false, XOR2), &[Tv(4380), Tv(4479)]),
+    ((5168, false, XOR2), &[Tv(4383), Tv(4482)]),
+    ((5171, false, XOR2), &[Tv(4386), Tv(4485)]),
+    ((5174, false, XOR2), &[Tv(4389), Tv(4488)]),
+    ((5177, false, XOR2), &[Tv(4392), Tv(4491)]),
+    ((5180, false, XOR2), &[Tv(4395), Tv(4494)]),
+    ((5183, false, XOR2), &[Tv(4398), Tv(4497)]),
+    ((5186, false, XOR2), &[Tv(4401), Tv(4500)]),
+    ((5189, false, XOR2), &[Tv(4404), Tv(4503)]),
+    ((5192, false, XOR2), &[Tv(4407), Tv(4506)]),
+    ((5195, false, XOR2), &[Tv(4410), Tv(4509)]),
+    ((5198, false, XOR2), &[Tv(4413), Tv(4512)]),
+    ((5201, false, XOR2), &[Tv(4416), Tv(4515)]),
+    ((5204, false, XOR2), &[Tv(4419), Tv(4518)]),
+    ((5207, false, XOR2), &[Tv(4422), Tv(4521)]),
+    ((5210, false, XOR2), &[Tv(4425), Tv(4524)]),
+    ((5213, false, XOR2), &[Tv(4428), Tv(4527)]),
+    ((5216, false, XOR2), &[Tv(4431), Tv(4530)]),
+    ((5219, false, XOR2), &[Tv(4434), Tv(4533)]),
+    ((5222, false, XOR2), &[Tv(4437), Tv(4536)]),
+    ((5225, false, XOR2), &[Tv(4440), Tv(4539)]),
+    ((5228, false, XOR2), &[Tv(4443), Tv(4542)]),
+    ((5232, false, XOR2), &[Tv(4547), Tv(4645)]),
+    ((5236, false, XOR2), &[Tv(4550), Tv(4648)]),
+    ((5239, false, XOR2), &[Tv(4553), Tv(4651)]),
+    ((5242, false, XOR2), &[Tv(4556), Tv(4654)]),
+    ((5245, false, XOR2), &[Tv(4559), Tv(4657)]),
+    ((5248, false, XOR2), &[Tv(4562), Tv(4660)]),
+    ((5251, false, XOR2), &[Tv(4565), Tv(4663)]),
+    ((5254, false, XOR2), &[Tv(4568), Tv(4666)]),
+    ((5257, false, XOR2), &[Tv(4571), Tv(4669)]),
+    ((5260, false, XOR2), &[Tv(4574), Tv(4672)]),
+    ((5263, false, XOR2), &[Tv(4577), Tv(4675)]),
+    ((5266, false, XOR2), &[Tv(4580), Tv(4678)]),
+    ((5269, false, XOR2), &[Tv(4583), Tv(4681)]),
+    ((5272, false, XOR2), &[Tv(4586), Tv(4684)]),
+    ((5275, false, XOR2), &[Tv(4589), Tv(4687)]),
+    ((5278, false, XOR2), &[Tv(4592), Tv(4690)]),
+    ((5281, false, XOR2), &[Tv(4595), Tv(4693)]),
+    ((5284, false, XOR2), &[Tv(4598), Tv(4696)]),
+    ((5287, false, XOR2), &[Tv(4601), Tv(4699)]),
+    ((5290, false, XOR2), &[Tv(4604), Tv(4702)]),
+    ((5293, false, XOR2), &[Tv(4607), Tv(4705)]),
+    ((5296, false, XOR2), &[Tv(4610), Tv(4708)]),
+    ((5299, false, XOR2), &[Tv(4613), Tv(4711)]),
+    ((5302, false, XOR2), &[Tv(4616), Tv(4714)]),
+    ((5305, false, XOR2), &[Tv(4619), Tv(4717)]),
+    ((5308, false, XOR2), &[Tv(4622), Tv(4720)]),
+    ((5311, false, XOR2), &[Tv(4625), Tv(4723)]),
+    ((5314, false, XOR2), &[Tv(4628), Tv(4726)]),
+    ((5317, false, XOR2), &[Tv(4631), Tv(4729)]),
+    ((5320, false, XOR2), &[Tv(4634), Tv(4732)]),
+    ((5323, false, XOR2), &[Tv(4637), Tv(4735)]),
+    ((5326, false, XOR2), &[Tv(4640), Tv(4738)]),
+    ((5331, false, XOR2), &[Tv(4744), Tv(4842)]),
+    ((5335, false, XOR2), &[Tv(4747), Tv(4845)]),
+    ((5338, false, XOR2), &[Tv(4750), Tv(4848)]),
+    ((5341, false, XOR2), &[Tv(4753), Tv(4851)]),
+    ((5344, false, XOR2), &[Tv(4756), Tv(4854)]),
+    ((5347, false, XOR2), &[Tv(4759), Tv(4857)]),
+    ((5350, false, XOR2), &[Tv(4762), Tv(4860)]),
+    ((5353, false, XOR2), &[Tv(4765), Tv(4863)]),
+    ((5356, false, XOR2), &[Tv(4768), Tv(4866)]),
+    ((5359, false, XOR2), &[Tv(4771), Tv(4869)]),
+    ((5362, false, XOR2), &[Tv(4774), Tv(4872)]),
+    ((5365, false, XOR2), &[Tv(4777), Tv(4875)]),
+    ((5368, false, XOR2), &[Tv(4780), Tv(4878)]),
+    ((5371, false, XOR2), &[Tv(4783), Tv(4881)]),
+    ((5374, false, XOR2), &[Tv(4786), Tv(4884)]),
+    ((5377, false, XOR2), &[Tv(4789), Tv(4887)]),
+    ((5380, false, XOR2), &[Tv(4792), Tv(4890)]),
+    ((5383, false, XOR2), &[Tv(4795), Tv(4893)]),
+    ((5386, false, XOR2), &[Tv(4798), Tv(4896)]),
+    ((5389, false, XOR2), &[Tv(4801), Tv(4899)]),
+    ((5392, false, XOR2), &[Tv(4804), Tv(4902)]),
+    ((5395, false, XOR2), &[Tv(4807), Tv(4905)]),
+    ((5398, false, XOR2), &[Tv(4810), Tv(4908)]),
+    ((5401, false, XOR2), &[Tv(4813), Tv(4911)]),
+    ((5404, false, XOR2), &[Tv(4816), Tv(4914)]),
+    ((5407, false, XOR2), &[Tv(4819), Tv(4917)]),
+    ((5410, false, XOR2), &[Tv(4822), Tv(4920)]),
+    ((5413, false, XOR2), &[Tv(4825), Tv(4923)]),
+    ((5416, false, XOR2), &[Tv(4828), Tv(4926)]),
+    ((5419, false, XOR2), &[Tv(4831), Tv(4929)]),
+    ((5422, false, XOR2), &[Tv(4834), Tv(4932)]),
+    ((5425, false, XOR2), &[Tv(4837), Tv(4935)]),
+    ((5527, false, NAND2), &[Tv(5134), Tv(5231)]),
+    ((5625, false, AND2), &[Tv(5428), Tv(5526)]),
+    ((5629, false, INV), &[Tv(5526)]),
+    ((5730, false, OR2), &[Tv(5329), Tv(5330)]),
+    ((6026, false, AND2), &[Tv(5954), Tv(6025)]),
+    ((6029, false, AND2), &[Tv(5954), Tv(6028)]),
+    ((6032, false, AND2), &[Tv(5954), Tv(6031)]),
+    ((6035, false, AND2), &[Tv(5954), Tv(6034)]),
+    ((6038, false, AND2), &[Tv(5954), Tv(6037)]),
+    ((6041, false, AND2), &[Tv(5954), Tv(6040)]),
+    ((6044, false, AND2), &[Tv(5954), Tv(6043)]),
+    ((6223, false, AND2), &[Tv(6151), Tv(6222)]),
+    ((6226, false, AND2), &[Tv(6151), Tv(6225)]),
+    ((6229, false, AND2), &[Tv(6151), Tv(6228)]),
+    ((6232, false, AND2), &[Tv(6151), Tv(6231)]),
+    ((6235, false, AND2), &[Tv(6151), Tv(6234)]),
+    ((6238, false, AND2), &[Tv(6151), Tv(6237)]),
+    ((6241, false, AND2), &[Tv(6151), Tv(6240)]),
 ];
 
-static LEVEL_33: [((usize, bool, CellType), &[GateInput]); 143] = [
-    ((2831, false, NAND2), &[Arg(0, 15), Tv(412)]),
-    ((2872, false, NAND2), &[Tv(2853), Tv(2871)]),
-    ((2884, false, AND2), &[Tv(2833), Tv(2883)]),
-    ((2913, false, NAND2), &[Tv(2893), Tv(2912)]),
-    ((2933, false, AND2), &[Tv(2931), Tv(2932)]),
-    ((2944, false, AND2), &[Tv(2895), Tv(2943)]),
-    ((3095, false, NAND2), &[Tv(1028), Tv(3094)]),
-    ((3097, false, AND2), &[Tv(930), Tv(3096)]),
-    ((3099, false, OR2), &[Arg(3, 196), Tv(930)]),
-    ((3103, false, NOR2), &[Tv(1323), Tv(1378)]),
-    ((3204, false, NAND2), &[Tv(1028), Tv(3203)]),
-    ((3206, false, AND2), &[Tv(930), Tv(3205)]),
-    ((3208, false, OR2), &[Arg(3, 197), Tv(930)]),
-    ((3311, false, NAND2), &[Tv(1028), Tv(3310)]),
-    ((3313, false, AND2), &[Tv(930), Tv(3312)]),
-    ((3315, false, OR2), &[Arg(3, 198), Tv(930)]),
-    ((3418, false, NAND2), &[Tv(1028), Tv(3417)]),
-    ((3420, false, AND2), &[Tv(930), Tv(3419)]),
-    ((3422, false, OR2), &[Arg(3, 199), Tv(930)]),
-    ((3525, false, NAND2), &[Tv(1028), Tv(3524)]),
-    ((3527, false, AND2), &[Tv(930), Tv(3526)]),
-    ((3529, false, OR2), &[Arg(3, 200), Tv(930)]),
-    ((3632, false, NAND2), &[Tv(1028), Tv(3631)]),
-    ((3634, false, AND2), &[Tv(930), Tv(3633)]),
-    ((3636, false, OR2), &[Arg(3, 201), Tv(930)]),
-    ((3739, false, NAND2), &[Tv(1028), Tv(3738)]),
-    ((3741, false, AND2), &[Tv(930), Tv(3740)]),
-    ((3743, false, OR2), &[Arg(3, 202), Tv(930)]),
-    ((3846, false, NAND2), &[Tv(1028), Tv(3845)]),
-    ((3848, false, AND2), &[Tv(930), Tv(3847)]),
-    ((3850, false, OR2), &[Arg(3, 203), Tv(930)]),
-    ((3953, false, NAND2), &[Tv(1028), Tv(3952)]),
-    ((3955, false, AND2), &[Tv(930), Tv(3954)]),
-    ((3957, false, OR2), &[Arg(3, 220), Tv(930)]),
-    ((4060, false, NAND2), &[Tv(1028), Tv(4059)]),
-    ((4062, false, AND2), &[Tv(930), Tv(4061)]),
-    ((4064, false, OR2), &[Arg(3, 221), Tv(930)]),
-    ((4167, false, NAND2), &[Tv(1028), Tv(4166)]),
-    ((4169, false, AND2), &[Tv(930), Tv(4168)]),
-    ((4171, false, OR2), &[Arg(3, 222), Tv(930)]),
-    ((4274, false, NAND2), &[Tv(1028), Tv(4273)]),
-    ((4276, false, AND2), &[Tv(930), Tv(4275)]),
-    ((4278, false, OR2), &[Arg(3, 223), Tv(930)]),
-    ((4381, false, NAND2), &[Tv(1028), Tv(4380)]),
-    ((4383, false, AND2), &[Tv(930), Tv(4382)]),
-    ((4385, false, OR2), &[Arg(3, 224), Tv(930)]),
-    ((4488, false, NAND2), &[Tv(1028), Tv(4487)]),
-    ((4490, false, AND2), &[Tv(930), Tv(4489)]),
-    ((4492, false, OR2), &[Arg(3, 225), Tv(930)]),
-    ((4595, false, NAND2), &[Tv(1028), Tv(4594)]),
-    ((4597, false, AND2), &[Tv(930), Tv(4596)]),
-    ((4599, false, OR2), &[Arg(3, 226), Tv(930)]),
-    ((4702, false, NAND2), &[Tv(1028), Tv(4701)]),
-    ((4704, false, AND2), &[Tv(930), Tv(4703)]),
-    ((4706, false, OR2), &[Arg(3, 227), Tv(930)]),
-    ((4809, false, NAND2), &[Tv(1028), Tv(4808)]),
-    ((4811, false, AND2), &[Tv(930), Tv(4810)]),
-    ((4813, false, OR2), &[Arg(3, 228), Tv(930)]),
-    ((4916, false, NAND2), &[Tv(1028), Tv(4915)]),
-    ((4918, false, AND2), &[Tv(930), Tv(4917)]),
-    ((4920, false, OR2), &[Arg(3, 229), Tv(930)]),
-    ((5023, false, NAND2), &[Tv(1028), Tv(5022)]),
-    ((5025, false, AND2), &[Tv(930), Tv(5024)]),
-    ((5027, false, OR2), &[Arg(3, 230), Tv(930)]),
-    ((5130, false, NAND2), &[Tv(1028), Tv(5129)]),
-    ((5132, false, AND2), &[Tv(930), Tv(5131)]),
-    ((5134, false, OR2), &[Arg(3, 231), Tv(930)]),
-    ((5237, false, NAND2), &[Tv(1028), Tv(5236)]),
-    ((5239, false, AND2), &[Tv(930), Tv(5238)]),
-    ((5241, false, OR2), &[Arg(3, 232), Tv(930)]),
-    ((5344, false, NAND2), &[Tv(1028), Tv(5343)]),
-    ((5346, false, AND2), &[Tv(930), Tv(5345)]),
-    ((5348, false, OR2), &[Arg(3, 233), Tv(930)]),
-    ((5451, false, NAND2), &[Tv(1028), Tv(5450)]),
-    ((5453, false, AND2), &[Tv(930), Tv(5452)]),
-    ((5455, false, OR2), &[Arg(3, 234), Tv(930)]),
-    ((5558, false, NAND2), &[Tv(1028), Tv(5557)]),
-    ((5560, false, AND2), &[Tv(930), Tv(5559)]),
-    ((5562, false, OR2), &[Arg(3, 235), Tv(930)]),
-    ((5665, false, NAND2), &[Tv(1028), Tv(5664)]),
-    ((5667, false, AND2), &[Tv(930), Tv(5666)]),
-    ((5669, false, OR2), &[Arg(3, 237), Tv(930)]),
-    ((5772, false, NAND2), &[Tv(1028), Tv(5771)]),
-    ((5774, false, AND2), &[Tv(930), Tv(5773)]),
-    ((5776, false, OR2), &[Arg(3, 238), Tv(930)]),
-    ((5879, false, NAND2), &[Tv(1028), Tv(5878)]),
-    ((5881, false, AND2), &[Tv(930), Tv(5880)]),
-    ((5883, false, OR2), &[Arg(3, 239), Tv(930)]),
-    ((5986, false, NAND2), &[Tv(1028), Tv(5985)]),
-    ((5988, false, AND2), &[Tv(930), Tv(5987)]),
-    ((5990, false, OR2), &[Arg(3, 240), Tv(930)]),
-    ((6093, false, NAND2), &[Tv(1028), Tv(6092)]),
-    ((6095, false, AND2), &[Tv(930), Tv(6094)]),
-    ((6097, false, OR2), &[Arg(3, 241), Tv(930)]),
-    ((6200, false, NAND2), &[Tv(1028), Tv(6199)]),
-    ((6202, false, AND2), &[Tv(930), Tv(6201)]),
-    ((6204, false, OR2), &[Arg(3, 242), Tv(930)]),
-    ((6307, false, NAND2), &[Tv(1028), Tv(6306)]),
-    ((6309, false, AND2), &[Tv(930), Tv(6308)]),
-    ((6311, false, OR2), &[Arg(3, 243), Tv(930)]),
-    ((6414, false, NAND2), &[Tv(1028), Tv(6413)]),
-    ((6416, false, AND2), &[Tv(930), Tv(6415)]),
-    ((6418, false, OR2), &[Arg(3, 244), Tv(930)]),
-    ((646, false, INV), &[Arg(4, 109)]),
-    ((1029, false, NAND2), &[Tv(404), Arg(3, 555)]),
-    ((1033, false, AND2), &[Tv(1031), Tv(1032)]),
-    ((1034, false, NAND2), &[Tv(409), Arg(3, 561)]),
-    ((1037, false, NAND2), &[Arg(1, 6), Tv(639)]),
-    ((1039, false, XNOR2), &[Arg(1, 12), Arg(3, 559)]),
-    ((1045, false, XNOR2), &[Arg(1, 7), Arg(3, 554)]),
-    ((1051, false, AND2), &[Tv(1040), Tv(1050)]),
-    ((1056, false, AND2), &[Tv(1030), Tv(1043)]),
-    ((1057, false, XNOR2), &[Arg(1, 9), Arg(3, 556)]),
-    ((1059, false, AND2), &[Tv(1046), Tv(1048)]),
-    ((1062, false, AND2), &[Tv(1035), Tv(1041)]),
-    ((1063, false, AND2), &[Tv(643), Tv(1044)]),
-    ((1067, false, AND2), &[Tv(1042), Tv(1053)]),
-    ((1068, false, AND2), &[Tv(1038), Tv(1054)]),
-    ((1097, false, AND2), &[Tv(1091), Tv(1096)]),
-    ((1103, false, AND2), &[Tv(1099), Tv(1102)]),
-    ((1116, false, AND2), &[Tv(1108), Tv(1115)]),
-    ((1122, false, AND2), &[Tv(1119), Tv(1121)]),
-    ((1148, false, AND2), &[Tv(1130), Tv(1147)]),
-    ((1150, false, AND2), &[Tv(1126), Tv(1141)]),
-    ((1152, false, AND2), &[Tv(1143), Tv(1151)]),
-    ((1154, false, AND2), &[Tv(1133), Tv(1153)]),
-    ((1158, false, AND2), &[Tv(1156), Tv(1157)]),
-    ((1162, false, AND2), &[Tv(1145), Tv(1161)]),
-    ((1165, false, AND2), &[Tv(1149), Tv(1164)]),
-    ((1168, false, AND2), &[Tv(1146), Tv(1167)]),
-    ((1379, false, AND2), &[Tv(1324), Tv(1378)]),
-    ((1388, false, NAND2), &[Arg(1, 5), Tv(610)]),
-    ((1410, false, AND2), &[Tv(1382), Tv(1409)]),
-    ((1413, false, AND2), &[Tv(1390), Tv(1412)]),
-    ((1416, false, AND2), &[Tv(1414), Tv(1415)]),
-    ((1421, false, AND2), &[Tv(1383), Tv(1420)]),
-    ((1426, false, AND2), &[Tv(1423), Tv(1425)]),
-    ((1430, false, AND2), &[Tv(1392), Tv(1429)]),
-    ((1432, false, AND2), &[Tv(1387), Tv(1431)]),
-    ((1466, false, AND2), &[Tv(1463), Tv(1465)]),
-    ((1469, false, AND2), &[Tv(1460), Tv(1468)]),
-    ((1476, false, AND2), &[Tv(1472), Tv(1475)]),
-    ((1479, false, AND2), &[Tv(1457), Tv(1478)]),
+static LEVEL_13: [((usize, bool, CellType), &[GateInput]); 177] = [
+    ((4939, false, AND2), &[Tv(4938), Tv(4937)]),
+    ((4942, false, AND2), &[Tv(4938), Tv(4941)]),
+    ((4945, false, AND2), &[Tv(4938), Tv(4944)]),
+    ((4948, false, AND2), &[Tv(4938), Tv(4947)]),
+    ((4951, false, AND2), &[Tv(4938), Tv(4950)]),
+    ((4954, false, AND2), &[Tv(4938), Tv(4953)]),
+    ((4957, false, AND2), &[Tv(4938), Tv(4956)]),
+    ((4960, false, AND2), &[Tv(4938), Tv(4959)]),
+    ((4963, false, AND2), &[Tv(4938), Tv(4962)]),
+    ((4966, false, AND2), &[Tv(4938), Tv(4965)]),
+    ((4969, false, AND2), &[Tv(4938), Tv(4968)]),
+    ((4972, false, AND2), &[Tv(4938), Tv(4971)]),
+    ((4975, false, AND2), &[Tv(4938), Tv(4974)]),
+    ((4978, false, AND2), &[Tv(4938), Tv(4977)]),
+    ((4981, false, AND2), &[Tv(4938), Tv(4980)]),
+    ((4984, false, AND2), &[Tv(4938), Tv(4983)]),
+    ((4987, false, AND2), &[Tv(4938), Tv(4986)]),
+    ((4990, false, AND2), &[Tv(4938), Tv(4989)]),
+    ((4993, false, AND2), &[Tv(4938), Tv(4992)]),
+    ((4996, false, AND2), &[Tv(4938), Tv(4995)]),
+    ((4999, false, AND2), &[Tv(4938), Tv(4998)]),
+    ((5002, false, AND2), &[Tv(4938), Tv(5001)]),
+    ((5005, false, AND2), &[Tv(4938), Tv(5004)]),
+    ((5008, false, AND2), &[Tv(4938), Tv(5007)]),
+    ((5011, false, AND2), &[Tv(4938), Tv(5010)]),
+    ((5014, false, AND2), &[Tv(4938), Tv(5013)]),
+    ((5017, false, AND2), &[Tv(4938), Tv(5016)]),
+    ((5020, false, AND2), &[Tv(4938), Tv(5019)]),
+    ((5023, false, AND2), &[Tv(4938), Tv(5022)]),
+    ((5026, false, AND2), &[Tv(4938), Tv(5025)]),
+    ((5029, false, AND2), &[Tv(4938), Tv(5028)]),
+    ((5032, false, AND2), &[Tv(4938), Tv(5031)]),
+    ((5037, false, AND2), &[Tv(5036), Tv(5035)]),
+    ((5040, false, AND2), &[Tv(5036), Tv(5039)]),
+    ((5043, false, AND2), &[Tv(5036), Tv(5042)]),
+    ((5046, false, AND2), &[Tv(5036), Tv(5045)]),
+    ((5049, false, AND2), &[Tv(5036), Tv(5048)]),
+    ((5052, false, AND2), &[Tv(5036), Tv(5051)]),
+    ((5055, false, AND2), &[Tv(5036), Tv(5054)]),
+    ((5058, false, AND2), &[Tv(5036), Tv(5057)]),
+    ((5061, false, AND2), &[Tv(5036), Tv(5060)]),
+    ((5064, false, AND2), &[Tv(5036), Tv(5063)]),
+    ((5067, false, AND2), &[Tv(5036), Tv(5066)]),
+    ((5070, false, AND2), &[Tv(5036), Tv(5069)]),
+    ((5073, false, AND2), &[Tv(5036), Tv(5072)]),
+    ((5076, false, AND2), &[Tv(5036), Tv(5075)]),
+    ((5079, false, AND2), &[Tv(5036), Tv(5078)]),
+    ((5082, false, AND2), &[Tv(5036), Tv(5081)]),
+    ((5085, false, AND2), &[Tv(5036), Tv(5084)]),
+    ((5088, false, AND2), &[Tv(5036), Tv(5087)]),
+    ((5091, false, AND2), &[Tv(5036), Tv(5090)]),
+    ((5094, false, AND2), &[Tv(5036), Tv(5093)]),
+    ((5097, false, AND2), &[Tv(5036), Tv(5096)]),
+    ((5100, false, AND2), &[Tv(5036), Tv(5099)]),
+    ((5103, false, AND2), &[Tv(5036), Tv(5102)]),
+    ((5106, false, AND2), &[Tv(5036), Tv(5105)]),
+    ((5109, false, AND2), &[Tv(5036), Tv(5108)]),
+    ((5112, false, AND2), &[Tv(5036), Tv(5111)]),
+    ((5115, false, AND2), &[Tv(5036), Tv(5114)]),
+    ((5118, false, AND2), &[Tv(5036), Tv(5117)]),
+    ((5121, false, AND2), &[Tv(5036), Tv(5120)]),
+    ((5124, false, AND2), &[Tv(5036), Tv(5123)]),
+    ((5127, false, AND2), &[Tv(5036), Tv(5126)]),
+    ((5130, false, AND2), &[Tv(5036), Tv(5129)]),
+    ((5136, false, AND2), &[Tv(5133), Tv(5135)]),
+    ((5139, false, AND2), &[Tv(5133), Tv(5138)]),
+    ((5142, false, AND2), &[Tv(5133), Tv(5141)]),
+    ((5145, false, AND2), &[Tv(5133), Tv(5144)]),
+    ((5148, false, AND2), &[Tv(5133), Tv(5147)]),
+    ((5151, false, AND2), &[Tv(5133), Tv(5150)]),
+    ((5154, false, AND2), &[Tv(5133), Tv(5153)]),
+    ((5157, false, AND2), &[Tv(5133), Tv(5156)]),
+    ((5160, false, AND2), &[Tv(5133), Tv(5159)]),
+    ((5163, false, AND2), &[Tv(5133), Tv(5162)]),
+    ((5166, false, AND2), &[Tv(5133), Tv(5165)]),
+    ((5169, false, AND2), &[Tv(5133), Tv(5168)]),
+    ((5172, false, AND2), &[Tv(5133), Tv(5171)]),
+    ((5175, false, AND2), &[Tv(5133), Tv(5174)]),
+    ((5178, false, AND2), &[Tv(5133), Tv(5177)]),
+    ((5181, false, AND2), &[Tv(5133), Tv(5180)]),
+    ((5184, false, AND2), &[Tv(5133), Tv(5183)]),
+    ((5187, false, AND2), &[Tv(5133), Tv(5186)]),
+    ((5190, false, AND2), &[Tv(5133), Tv(5189)]),
+    ((5193, false, AND2), &[Tv(5133), Tv(5192)]),
+    ((5196, false, AND2), &[Tv(5133), Tv(5195)]),
+    ((5199, false, AND2), &[Tv(5133), Tv(5198)]),
+    ((5202, false, AND2), &[Tv(5133), Tv(5201)]),
+    ((5205, false, AND2), &[Tv(5133), Tv(5204)]),
+    ((5208, false, AND2), &[Tv(5133), Tv(5207)]),
+    ((5211, false, AND2), &[Tv(5133), Tv(5210)]),
+    ((5214, false, AND2), &[Tv(5133), Tv(5213)]),
+    ((5217, false, AND2), &[Tv(5133), Tv(5216)]),
+    ((5220, false, AND2), &[Tv(5133), Tv(5219)]),
+    ((5223, false, AND2), &[Tv(5133), Tv(5222)]),
+    ((5226, false, AND2), &[Tv(5133), Tv(5225)]),
+    ((5229, false, AND2), &[Tv(5133), Tv(5228)]),
+    ((5234, false, AND2), &[Tv(5233), Tv(5232)]),
+    ((5237, false, AND2), &[Tv(5233), Tv(5236)]),
+    ((5240, false, AND2), &[Tv(5233), Tv(5239)]),
+    ((5243, false, AND2), &[Tv(5233), Tv(5242)]),
+    ((5246, false, AND2), &[Tv(5233), Tv(5245)]),
+    ((5249, false, AND2), &[Tv(5233), Tv(5248)]),
+    ((5252, false, AND2), &[Tv(5233), Tv(5251)]),
+    ((5255, false, AND2), &[Tv(5233), Tv(5254)]),
+    ((5258, false, AND2), &[Tv(5233), Tv(5257)]),
+    ((5261, false, AND2), &[Tv(5233), Tv(5260)]),
+    ((5264, false, AND2), &[Tv(5233), Tv(5263)]),
+    ((5267, false, AND2), &[Tv(5233), Tv(5266)]),
+    ((5270, false, AND2), &[Tv(5233), Tv(5269)]),
+    ((5273, false, AND2), &[Tv(5233), Tv(5272)]),
+    ((5276, false, AND2), &[Tv(5233), Tv(5275)]),
+    ((5279, false, AND2), &[Tv(5233), Tv(5278)]),
+    ((5282, false, AND2), &[Tv(5233), Tv(5281)]),
+    ((5285, false, AND2), &[Tv(5233), Tv(5284)]),
+    ((5288, false, AND2), &[Tv(5233), Tv(5287)]),
+    ((5291, false, AND2), &[Tv(5233), Tv(5290)]),
+    ((5294, false, AND2), &[Tv(5233), Tv(5293)]),
+    ((5297, false, AND2), &[Tv(5233), Tv(5296)]),
+    ((5300, false, AND2), &[Tv(5233), Tv(5299)]),
+    ((5303, false, AND2), &[Tv(5233), Tv(5302)]),
+    ((5306, false, AND2), &[Tv(5233), Tv(5305)]),
+    ((5309, false, AND2), &[Tv(5233), Tv(5308)]),
+    ((5312, false, AND2), &[Tv(5233), Tv(5311)]),
+    ((5315, false, AND2), &[Tv(5233), Tv(5314)]),
+    ((5318, false, AND2), &[Tv(5233), Tv(5317)]),
+    ((5321, false, AND2), &[Tv(5233), Tv(5320)]),
+    ((5324, false, AND2), &[Tv(5233), Tv(5323)]),
+    ((5327, false, AND2), &[Tv(5233), Tv(5326)]),
+    ((5333, false, AND2), &[Tv(5332), Tv(5331)]),
+    ((5336, false, AND2), &[Tv(5332), Tv(5335)]),
+    ((5339, false, AND2), &[Tv(5332), Tv(5338)]),
+    ((5342, false, AND2), &[Tv(5332), Tv(5341)]),
+    ((5345, false, AND2), &[Tv(5332), Tv(5344)]),
+    ((5348, false, AND2), &[Tv(5332), Tv(5347)]),
+    ((5351, false, AND2), &[Tv(5332), Tv(5350)]),
+    ((5354, false, AND2), &[Tv(5332), Tv(5353)]),
+    ((5357, false, AND2), &[Tv(5332), Tv(5356)]),
+    ((5360, false, AND2), &[Tv(5332), Tv(5359)]),
+    ((5363, false, AND2), &[Tv(5332), Tv(5362)]),
+    ((5366, false, AND2), &[Tv(5332), Tv(5365)]),
+    ((5369, false, AND2), &[Tv(5332), Tv(5368)]),
+    ((5372, false, AND2), &[Tv(5332), Tv(5371)]),
+    ((5375, false, AND2), &[Tv(5332), Tv(5374)]),
+    ((5378, false, AND2), &[Tv(5332), Tv(5377)]),
+    ((5381, false, AND2), &[Tv(5332), Tv(5380)]),
+    ((5384, false, AND2), &[Tv(5332), Tv(5383)]),
+    ((5387, false, AND2), &[Tv(5332), Tv(5386)]),
+    ((5390, false, AND2), &[Tv(5332), Tv(5389)]),
+    ((5393, false, AND2), &[Tv(5332), Tv(5392)]),
+    ((5396, false, AND2), &[Tv(5332), Tv(5395)]),
+    ((5399, false, AND2), &[Tv(5332), Tv(5398)]),
+    ((5402, false, AND2), &[Tv(5332), Tv(5401)]),
+    ((5405, false, AND2), &[Tv(5332), Tv(5404)]),
+    ((5408, false, AND2), &[Tv(5332), Tv(5407)]),
+    ((5411, false, AND2), &[Tv(5332), Tv(5410)]),
+    ((5414, false, AND2), &[Tv(5332), Tv(5413)]),
+    ((5417, false, AND2), &[Tv(5332), Tv(5416)]),
+    ((5420, false, AND2), &[Tv(5332), Tv(5419)]),
+    ((5423, false, AND2), &[Tv(5332), Tv(5422)]),
+    ((5426, false, AND2), &[Tv(5332), Tv(5425)]),
+    ((5626, false, INV), &[Tv(5527)]),
+    ((5725, false, AND2), &[Tv(5329), Tv(5625)]),
+    ((5829, false, INV), &[Tv(5730)]),
+    ((6027, false, XOR2), &[Tv(5955), Tv(6026)]),
+    ((6030, false, XNOR2), &[Tv(5956), Tv(6029)]),
+    ((6033, false, XNOR2), &[Tv(5957), Tv(6032)]),
+    ((6036, false, XNOR2), &[Tv(5958), Tv(6035)]),
+    ((6039, false, XNOR2), &[Tv(5959), Tv(6038)]),
+    ((6042, false, XNOR2), &[Tv(5960), Tv(6041)]),
+    ((6045, false, XNOR2), &[Tv(5961), Tv(6044)]),
+    ((6224, false, XOR2), &[Tv(6152), Tv(6223)]),
+    ((6227, false, XNOR2), &[Tv(6153), Tv(6226)]),
+    ((6230, false, XNOR2), &[Tv(6154), Tv(6229)]),
+    ((6233, false, XNOR2), &[Tv(6155), Tv(6232)]),
+    ((6236, false, XNOR2), &[Tv(6156), Tv(6235)]),
+    ((6239, false, XNOR2), &[Tv(6157), Tv(6238)]),
+    ((6242, false, XNOR2), &[Tv(6158), Tv(6241)]),
 ];
 
-static LEVEL_34: [((usize, bool, CellType), &[GateInput]); 169] = [
-    ((2873, false, AND2), &[Tv(2831), Tv(2872)]),
-    ((2892, false, NAND2), &[Arg(0, 7), Tv(410)]),
-    ((2934, false, NAND2), &[Tv(2913), Tv(2933)]),
-    ((2981, false, NAND2), &[Tv(2888), Tv(2944)]),
-    ((2987, false, NAND2), &[Tv(2829), Tv(2884)]),
-    ((3098, false, NAND2), &[Tv(3095), Tv(3097)]),
-    ((3100, false, AND2), &[Tv(1379), Tv(3099)]),
-    ((3102, false, NAND2), &[Arg(3, 294), Tv(1323)]),
-    ((3104, false, NAND2), &[Arg(3, 245), Tv(3103)]),
-    ((3207, false, NAND2), &[Tv(3204), Tv(3206)]),
-    ((3209, false, AND2), &[Tv(1379), Tv(3208)]),
-    ((3211, false, NAND2), &[Arg(3, 295), Tv(1323)]),
-    ((3212, false, NAND2), &[Arg(3, 246), Tv(3103)]),
-    ((3314, false, NAND2), &[Tv(3311), Tv(3313)]),
-    ((3316, false, AND2), &[Tv(1379), Tv(3315)]),
-    ((3318, false, NAND2), &[Arg(3, 296), Tv(1323)]),
-    ((3319, false, NAND2), &[Arg(3, 247), Tv(3103)]),
-    ((3421, false, NAND2), &[Tv(3418), Tv(3420)]),
-    ((3423, false, AND2), &[Tv(1379), Tv(3422)]),
-    ((3425, false, NAND2), &[Arg(3, 297), Tv(1323)]),
-    ((3426, false, NAND2), &[Arg(3, 248), Tv(3103)]),
-    ((3528, false, NAND2), &[Tv(3525), Tv(3527)]),
-    ((3530, false, AND2), &[Tv(1379), Tv(3529)]),
-    ((3532, false, NAND2), &[Arg(3, 298), Tv(1323)]),
-    ((3533, false, NAND2), &[Arg(3, 249), Tv(3103)]),
-    ((3635, false, NAND2), &[Tv(3632), Tv(3634)]),
-    ((3637, false, AND2), &[Tv(1379), Tv(3636)]),
-    ((3639, false, NAND2), &[Arg(3, 299), Tv(1323)]),
-    ((3640, false, NAND2), &[Arg(3, 250), Tv(3103)]),
-    ((3742, false, NAND2), &[Tv(3739), Tv(3741)]),
-    ((3744, false, AND2), &[Tv(1379), Tv(3743)]),
-    ((3746, false, NAND2), &[Arg(3, 300), Tv(1323)]),
-    ((3747, false, NAND2), &[Arg(3, 251), Tv(3103)]),
-    ((3849, false, NAND2), &[Tv(3846), Tv(3848)]),
-    ((3851, false, AND2), &[Tv(1379), Tv(3850)]),
-    ((3853, false, NAND2), &[Arg(3, 301), Tv(1323)]),
-    ((3854, false, NAND2), &[Arg(3, 252), Tv(3103)]),
-    ((3956, false, NAND2), &[Tv(3953), Tv(3955)]),
-    ((3958, false, AND2), &[Tv(1379), Tv(3957)]),
-    ((3960, false, NAND2), &[Arg(3, 318), Tv(1323)]),
-    ((3961, false, NAND2), &[Arg(3, 269), Tv(3103)]),
-    ((4063, false, NAND2), &[Tv(4060), Tv(4062)]),
-    ((4065, false, AND2), &[Tv(1379), Tv(4064)]),
-    ((4067, false, NAND2), &[Arg(3, 319), Tv(1323)]),
-    ((4068, false, NAND2), &[Arg(3, 270), Tv(3103)]),
-    ((4170, false, NAND2), &[Tv(4167), Tv(4169)]),
-    ((4172, false, AND2), &[Tv(1379), Tv(4171)]),
-    ((4174, false, NAND2), &[Arg(3, 320), Tv(1323)]),
-    ((4175, false, NAND2), &[Arg(3, 271), Tv(3103)]),
-    ((4277, false, NAND2), &[Tv(4274), Tv(4276)]),
-    ((4279, false, AND2), &[Tv(1379), Tv(4278)]),
-    ((4281, false, NAND2), &[Arg(3, 321), Tv(1323)]),
-    ((4282, false, NAND2), &[Arg(3, 272), Tv(3103)]),
-    ((4384, false, NAND2), &[Tv(4381), Tv(4383)]),
-    ((4386, false, AND2), &[Tv(1379), Tv(4385)]),
-    ((4388, false, NAND2), &[Arg(3, 322), Tv(1323)]),
-    ((4389, false, NAND2), &[Arg(3, 273), Tv(3103)]),
-    ((4491, false, NAND2), &[Tv(4488), Tv(4490)]),
-    ((4493, false, AND2), &[Tv(1379), Tv(4492)]),
-    ((4495, false, NAND2), &[Arg(3, 323), Tv(1323)]),
-    ((4496, false, NAND2), &[Arg(3, 274), Tv(3103)]),
-    ((4598, false, NAND2), &[Tv(4595), Tv(4597)]),
-    ((4600, false, AND2), &[Tv(1379), Tv(4599)]),
-    ((4602, false, NAND2), &[Arg(3, 324), Tv(1323)]),
-    ((4603, false, NAND2), &[Arg(3, 275), Tv(3103)]),
-    ((4705, false, NAND2), &[Tv(4702), Tv(4704)]),
-    ((4707, false, AND2), &[Tv(1379), Tv(4706)]),
-    ((4709, false, NAND2), &[Arg(3, 325), Tv(1323)]),
-    ((4710, false, NAND2), &[Arg(3, 276), Tv(3103)]),
-    ((4812, false, NAND2), &[Tv(4809), Tv(4811)]),
-    ((4814, false, AND2), &[Tv(1379), Tv(4813)]),
-    ((4816, false, NAND2), &[Arg(3, 326), Tv(1323)]),
-    ((4817, false, NAND2), &[Arg(3, 277), Tv(3103)]),
-    ((4919, false, NAND2), &[Tv(4916), Tv(4918)]),
-    ((4921, false, AND2), &[Tv(1379), Tv(4920)]),
-    ((4923, false, NAND2), &[Arg(3, 327), Tv(1323)]),
-    ((4924, false, NAND2), &[Arg(3, 278), Tv(3103)]),
-    ((5026, false, NAND2), &[Tv(5023), Tv(5025)]),
-    ((5028, false, AND2), &[Tv(1379), Tv(5027)]),
-    ((5030, false, NAND2), &[Arg(3, 328), Tv(1323)]),
-    ((5031, false, NAND2), &[Arg(3, 279), Tv(3103)]),
-    ((5133, false, NAND2), &[Tv(5130), Tv(5132)]),
-    ((5135, false, AND2), &[Tv(1379), Tv(5134)]),
-    ((5137, false, NAND2), &[Arg(3, 329), Tv(1323)]),
-    ((5138, false, NAND2), &[Arg(3, 280), Tv(3103)]),
-    ((5240, false, NAND2), &[Tv(5237), Tv(5239)]),
-    ((5242, false, AND2), &[Tv(1379), Tv(5241)]),
-    ((5244, false, NAND2), &[Arg(3, 330), Tv(1323)]),
-    ((5245, false, NAND2), &[Arg(3, 281), Tv(3103)]),
-    ((5347, false, NAND2), &[Tv(5344), Tv(5346)]),
-    ((5349, false, AND2), &[Tv(1379), Tv(5348)]),
-    ((5351, false, NAND2), &[Arg(3, 331), Tv(1323)]),
-    ((5352, false, NAND2), &[Arg(3, 282), Tv(3103)]),
-    ((5454, false, NAND2), &[Tv(5451), Tv(5453)]),
-    ((5456, false, AND2), &[Tv(1379), Tv(5455)]),
-    ((5458, false, NAND2), &[Arg(3, 332), Tv(1323)]),
-    ((5459, false, NAND2), &[Arg(3, 283), Tv(3103)]),
-    ((5561, false, NAND2), &[Tv(5558), Tv(5560)]),
-    ((5563, false, AND2), &[Tv(1379), Tv(5562)]),
-    ((5565, false, NAND2), &[Arg(3, 333), Tv(1323)]),
-    ((5566, false, NAND2), &[Arg(3, 284), Tv(3103)]),
-    ((5668, false, NAND2), &[Tv(5665), Tv(5667)]),
-    ((5670, false, AND2), &[Tv(1379), Tv(5669)]),
-    ((5672, false, NAND2), &[Arg(3, 335), Tv(1323)]),
-    ((5673, false, NAND2), &[Arg(3, 286), Tv(3103)]),
-    ((5775, false, NAND2), &[Tv(5772), Tv(5774)]),
-    ((5777, false, AND2), &[Tv(1379), Tv(5776)]),
-    ((5779, false, NAND2), &[Arg(3, 336), Tv(1323)]),
-    ((5780, false, NAND2), &[Arg(3, 287), Tv(3103)]),
-    ((5882, false, NAND2), &[Tv(5879), Tv(5881)]),
-    ((5884, false, AND2), &[Tv(1379), Tv(5883)]),
-    ((5886, false, NAND2), &[Arg(3, 337), Tv(1323)]),
-    ((5887, false, NAND2), &[Arg(3, 288), Tv(3103)]),
-    ((5989, false, NAND2), &[Tv(5986), Tv(5988)]),
-    ((5991, false, AND2), &[Tv(1379), Tv(5990)]),
-    ((5993, false, NAND2), &[Arg(3, 338), Tv(1323)]),
-    ((5994, false, NAND2), &[Arg(3, 289), Tv(3103)]),
-    ((6096, false, NAND2), &[Tv(6093), Tv(6095)]),
-    ((6098, false, AND2), &[Tv(1379), Tv(6097)]),
-    ((6100, false, NAND2), &[Arg(3, 339), Tv(1323)]),
-    ((6101, false, NAND2), &[Arg(3, 290), Tv(3103)]),
-    ((6203, false, NAND2), &[Tv(6200), Tv(6202)]),
-    ((6205, false, AND2), &[Tv(1379), Tv(6204)]),
-    ((6207, false, NAND2), &[Arg(3, 340), Tv(1323)]),
-    ((6208, false, NAND2), &[Arg(3, 291), Tv(3103)]),
-    ((6310, false, NAND2), &[Tv(6307), Tv(6309)]),
-    ((6312, false, AND2), &[Tv(1379), Tv(6311)]),
-    ((6314, false, NAND2), &[Arg(3, 341), Tv(1323)]),
-    ((6315, false, NAND2), &[Arg(3, 292), Tv(3103)]),
-    ((6417, false, NAND2), &[Tv(6414), Tv(6416)]),
-    ((6419, false, AND2), &[Tv(1379), Tv(6418)]),
-    ((6421, false, NAND2), &[Arg(3, 342), Tv(1323)]),
-    ((6422, false, NAND2), &[Arg(3, 293), Tv(3103)]),
-    ((644, false, INV), &[Arg(4, 13)]),
-    ((645, false, INV), &[Arg(4, 61)]),
-    ((741, false, NAND2), &[Arg(1, 5), Tv(646)]),
-    ((742, false, NAND2), &[Tv(394), Arg(4, 109)]),
-    ((744, false, XNOR2), &[Arg(1, 11), Arg(4, 115)]),
-    ((745, false, XNOR2), &[Arg(1, 8), Arg(4, 112)]),
-    ((748, false, XNOR2), &[Arg(1, 3), Arg(4, 107)]),
-    ((749, false, XNOR2), &[Arg(1, 4), Arg(4, 108)]),
-    ((750, false, XNOR2), &[Arg(1, 12), Arg(4, 116)]),
-    ((751, false, XNOR2), &[Arg(1, 7), Arg(4, 111)]),
-    ((752, false, XNOR2), &[Arg(1, 13), Arg(4, 117)]),
-    ((753, false, XNOR2), &[Arg(1, 14), Arg(4, 118)]),
-    ((754, false, XNOR2), &[Arg(1, 6), Arg(4, 110)]),
-    ((755, false, XNOR2), &[Arg(1, 15), Arg(4, 119)]),
-    ((764, false, XNOR2), &[Arg(1, 2), Arg(4, 106)]),
-    ((766, false, XNOR2), &[Arg(1, 9), Arg(4, 113)]),
-    ((1049, false, XNOR2), &[Arg(1, 11), Arg(3, 558)]),
-    ((1052, false, AND2), &[Tv(1033), Tv(1034)]),
-    ((1055, false, AND2), &[Tv(1029), Tv(1037)]),
-    ((1058, false, AND2), &[Tv(1056), Tv(1057)]),
-    ((1060, false, AND2), &[Tv(1045), Tv(1059)]),
-    ((1064, false, AND2), &[Tv(1062), Tv(1063)]),
-    ((1069, false, AND2), &[Tv(1067), Tv(1068)]),
-    ((1071, false, AND2), &[Tv(1039), Tv(1051)]),
-    ((1104, false, AND2), &[Tv(1097), Tv(1103)]),
-    ((1123, false, AND2), &[Tv(1116), Tv(1122)]),
-    ((1155, false, AND2), &[Tv(1152), Tv(1154)]),
-    ((1159, false, AND2), &[Tv(1150), Tv(1158)]),
-    ((1166, false, AND2), &[Tv(1162), Tv(1165)]),
-    ((1169, false, AND2), &[Tv(1148), Tv(1168)]),
-    ((1411, false, AND2), &[Tv(1388), Tv(1410)]),
-    ((1417, false, AND2), &[Tv(1413), Tv(1416)]),
-    ((1427, false, AND2), &[Tv(1421), Tv(1426)]),
-    ((1433, false, AND2), &[Tv(1430), Tv(1432)]),
-    ((1470, false, AND2), &[Tv(1466), Tv(1469)]),
-    ((1480, false, AND2), &[Tv(1476), Tv(1479)]),
+static LEVEL_14: [((usize, bool, CellType), &[GateInput]); 177] = [
+    ((4940, false, XOR2), &[Tv(3958), Tv(4939)]),
+    ((4943, false, XOR2), &[Tv(3961), Tv(4942)]),
+    ((4946, false, XOR2), &[Tv(3964), Tv(4945)]),
+    ((4949, false, XOR2), &[Tv(3967), Tv(4948)]),
+    ((4952, false, XOR2), &[Tv(3970), Tv(4951)]),
+    ((4955, false, XOR2), &[Tv(3973), Tv(4954)]),
+    ((4958, false, XOR2), &[Tv(3976), Tv(4957)]),
+    ((4961, false, XOR2), &[Tv(3979), Tv(4960)]),
+    ((4964, false, XOR2), &[Tv(3982), Tv(4963)]),
+    ((4967, false, XOR2), &[Tv(3985), Tv(4966)]),
+    ((4970, false, XOR2), &[Tv(3988), Tv(4969)]),
+    ((4973, false, XOR2), &[Tv(3991), Tv(4972)]),
+    ((4976, false, XOR2), &[Tv(3994), Tv(4975)]),
+    ((4979, false, XOR2), &[Tv(3997), Tv(4978)]),
+    ((4982, false, XOR2), &[Tv(4000), Tv(4981)]),
+    ((4985, false, XOR2), &[Tv(4003), Tv(4984)]),
+    ((4988, false, XOR2), &[Tv(4006), Tv(4987)]),
+    ((4991, false, XOR2), &[Tv(4009), Tv(4990)]),
+    ((4994, false, XOR2), &[Tv(4012), Tv(4993)]),
+    ((4997, false, XOR2), &[Tv(4015), Tv(4996)]),
+    ((5000, false, XOR2), &[Tv(4018), Tv(4999)]),
+    ((5003, false, XOR2), &[Tv(4021), Tv(5002)]),
+    ((5006, false, XOR2), &[Tv(4024), Tv(5005)]),
+    ((5009, false, XOR2), &[Tv(4027), Tv(5008)]),
+    ((5012, false, XOR2), &[Tv(4030), Tv(5011)]),
+    ((5015, false, XOR2), &[Tv(4033), Tv(5014)]),
+    ((5018, false, XOR2), &[Tv(4036), Tv(5017)]),
+    ((5021, false, XOR2), &[Tv(4039), Tv(5020)]),
+    ((5024, false, XOR2), &[Tv(4042), Tv(5023)]),
+    ((5027, false, XOR2), &[Tv(4045), Tv(5026)]),
+    ((5030, false, XOR2), &[Tv(4048), Tv(5029)]),
+    ((5033, false, XOR2), &[Tv(4051), Tv(5032)]),
+    ((5038, false, XOR2), &[Tv(4154), Tv(5037)]),
+    ((5041, false, XOR2), &[Tv(4157), Tv(5040)]),
+    ((5044, false, XOR2), &[Tv(4160), Tv(5043)]),
+    ((5047, false, XOR2), &[Tv(4163), Tv(5046)]),
+    ((5050, false, XOR2), &[Tv(4166), Tv(5049)]),
+    ((5053, false, XOR2), &[Tv(4169), Tv(5052)]),
+    ((5056, false, XOR2), &[Tv(4172), Tv(5055)]),
+    ((5059, false, XOR2), &[Tv(4175), Tv(5058)]),
+    ((5062, false, XOR2), &[Tv(4178), Tv(5061)]),
+    ((5065, false, XOR2), &[Tv(4181), Tv(5064)]),
+    ((5068, false, XOR2), &[Tv(4184), Tv(5067)]),
+    ((5071, false, XOR2), &[Tv(4187), Tv(5070)]),
+    ((5074, false, XOR2), &[Tv(4190), Tv(5073)]),
+    ((5077, false, XOR2), &[Tv(4193), Tv(5076)]),
+    ((5080, false, XOR2), &[Tv(4196), Tv(5079)]),
+    ((5083, false, XOR2), &[Tv(4199), Tv(5082)]),
+    ((5086, false, XOR2), &[Tv(4202), Tv(5085)]),
+    ((5089, false, XOR2), &[Tv(4205), Tv(5088)]),
+    ((5092, false, XOR2), &[Tv(4208), Tv(5091)]),
+    ((5095, false, XOR2), &[Tv(4211), Tv(5094)]),
+    ((5098, false, XOR2), &[Tv(4214), Tv(5097)]),
+    ((5101, false, XOR2), &[Tv(4217), Tv(5100)]),
+    ((5104, false, XOR2), &[Tv(4220), Tv(5103)]),
+    ((5107, false, XOR2), &[Tv(4223), Tv(5106)]),
+    ((5110, false, XOR2), &[Tv(4226), Tv(5109)]),
+    ((5113, false, XOR2), &[Tv(4229), Tv(5112)]),
+    ((5116, false, XOR2), &[Tv(4232), Tv(5115)]),
+    ((5119, false, XOR2), &[Tv(4235), Tv(5118)]),
+    ((5122, false, XOR2), &[Tv(4238), Tv(5121)]),
+    ((5125, false, XOR2), &[Tv(4241), Tv(5124)]),
+    ((5128, false, XOR2), &[Tv(4244), Tv(5127)]),
+    ((5131, false, XOR2), &[Tv(4247), Tv(5130)]),
+    ((5137, false, XOR2), &[Tv(4350), Tv(5136)]),
+    ((5140, false, XOR2), &[Tv(4353), Tv(5139)]),
+    ((5143, false, XOR2), &[Tv(4356), Tv(5142)]),
+    ((5146, false, XOR2), &[Tv(4359), Tv(5145)]),
+    ((5149, false, XOR2), &[Tv(4362), Tv(5148)]),
+    ((5152, false, XOR2), &[Tv(4365), Tv(5151)]),
+    ((5155, false, XOR2), &[Tv(4368), Tv(5154)]),
+    ((5158, false, XOR2), &[Tv(4371), Tv(5157)]),
+    ((5161, false, XOR2), &[Tv(4374), Tv(5160)]),
+    ((5164, false, XOR2), &[Tv(4377), Tv(5163)]),
+    ((5167, false, XOR2), &[Tv(4380), Tv(5166)]),
+    ((5170, false, XOR2), &[Tv(4383), Tv(5169)]),
+    ((5173, false, XOR2), &[Tv(4386), Tv(5172)]),
+    ((5176, false, XOR2), &[Tv(4389), Tv(5175)]),
+    ((5179, false, XOR2), &[Tv(4392), Tv(5178)]),
+    ((5182, false, XOR2), &[Tv(4395), Tv(5181)]),
+    ((5185, false, XOR2), &[Tv(4398), Tv(5184)]),
+    ((5188, false, XOR2), &[Tv(4401), Tv(5187)]),
+    ((5191, false, XOR2), &[Tv(4404), Tv(5190)]),
+    ((5194, false, XOR2), &[Tv(4407), Tv(5193)]),
+    ((5197, false, XOR2), &[Tv(4410), Tv(5196)]),
+    ((5200, false, XOR2), &[Tv(4413), Tv(5199)]),
+    ((5203, false, XOR2), &[Tv(4416), Tv(5202)]),
+    ((5206, false, XOR2), &[Tv(4419), Tv(5205)]),
+    ((5209, false, XOR2), &[Tv(4422), Tv(5208)]),
+    ((5212, false, XOR2), &[Tv(4425), Tv(5211)]),
+    ((5215, false, XOR2), &[Tv(4428), Tv(5214)]),
+    ((5218, false, XOR2), &[Tv(4431), Tv(5217)]),
+    ((5221, false, XOR2), &[Tv(4434), Tv(5220)]),
+    ((5224, false, XOR2), &[Tv(4437), Tv(5223)]),
+    ((5227, false, XOR2), &[Tv(4440), Tv(5226)]),
+    ((5230, false, XOR2), &[Tv(4443), Tv(5229)]),
+    ((5235, false, XOR2), &[Tv(4547), Tv(5234)]),
+    ((5238, false, XOR2), &[Tv(4550), Tv(5237)]),
+    ((5241, false, XOR2), &[Tv(4553), Tv(5240)]),
+    ((5244, false, XOR2), &[Tv(4556), Tv(5243)]),
+    ((5247, false, XOR2), &[Tv(4559), Tv(5246)]),
+    ((5250, false, XOR2), &[Tv(4562), Tv(5249)]),
+    ((5253, false, XOR2), &[Tv(4565), Tv(5252)]),
+    ((5256, false, XOR2), &[Tv(4568), Tv(5255)]),
+    ((5259, false, XOR2), &[Tv(4571), Tv(5258)]),
+    ((5262, false, XOR2), &[Tv(4574), Tv(5261)]),
+    ((5265, false, XOR2), &[Tv(4577), Tv(5264)]),
+    ((5268, false, XOR2), &[Tv(4580), Tv(5267)]),
+    ((5271, false, XOR2), &[Tv(4583), Tv(5270)]),
+    ((5274, false, XOR2), &[Tv(4586), Tv(5273)]),
+    ((5277, false, XOR2), &[Tv(4589), Tv(5276)]),
+    ((5280, false, XOR2), &[Tv(4592), Tv(5279)]),
+    ((5283, false, XOR2), &[Tv(4595), Tv(5282)]),
+    ((5286, false, XOR2), &[Tv(4598), Tv(5285)]),
+    ((5289, false, XOR2), &[Tv(4601), Tv(5288)]),
+    ((5292, false, XOR2), &[Tv(4604), Tv(5291)]),
+    ((5295, false, XOR2), &[Tv(4607), Tv(5294)]),
+    ((5298, false, XOR2), &[Tv(4610), Tv(5297)]),
+    ((5301, false, XOR2), &[Tv(4613), Tv(5300)]),
+    ((5304, false, XOR2), &[Tv(4616), Tv(5303)]),
+    ((5307, false, XOR2), &[Tv(4619), Tv(5306)]),
+    ((5310, false, XOR2), &[Tv(4622), Tv(5309)]),
+    ((5313, false, XOR2), &[Tv(4625), Tv(5312)]),
+    ((5316, false, XOR2), &[Tv(4628), Tv(5315)]),
+    ((5319, false, XOR2), &[Tv(4631), Tv(5318)]),
+    ((5322, false, XOR2), &[Tv(4634), Tv(5321)]),
+    ((5325, false, XOR2), &[Tv(4637), Tv(5324)]),
+    ((5328, false, XOR2), &[Tv(4640), Tv(5327)]),
+    ((5334, false, XOR2), &[Tv(4744), Tv(5333)]),
+    ((5337, false, XOR2), &[Tv(4747), Tv(5336)]),
+    ((5340, false, XOR2), &[Tv(4750), Tv(5339)]),
+    ((5343, false, XOR2), &[Tv(4753), Tv(5342)]),
+    ((5346, false, XOR2), &[Tv(4756), Tv(5345)]),
+    ((5349, false, XOR2), &[Tv(4759), Tv(5348)]),
+    ((5352, false, XOR2), &[Tv(4762), Tv(5351)]),
+    ((5355, false, XOR2), &[Tv(4765), Tv(5354)]),
+    ((5358, false, XOR2), &[Tv(4768), Tv(5357)]),
+    ((5361, false, XOR2), &[Tv(4771), Tv(5360)]),
+    ((5364, false, XOR2), &[Tv(4774), Tv(5363)]),
+    ((5367, false, XOR2), &[Tv(4777), Tv(5366)]),
+    ((5370, false, XOR2), &[Tv(4780), Tv(5369)]),
+    ((5373, false, XOR2), &[Tv(4783), Tv(5372)]),
+    ((5376, false, XOR2), &[Tv(4786), Tv(5375)]),
+    ((5379, false, XOR2), &[Tv(4789), Tv(5378)]),
+    ((5382, false, XOR2), &[Tv(4792), Tv(5381)]),
+    ((5385, false, XOR2), &[Tv(4795), Tv(5384)]),
+    ((5388, false, XOR2), &[Tv(4798), Tv(5387)]),
+    ((5391, false, XOR2), &[Tv(4801), Tv(5390)]),
+    ((5394, false, XOR2), &[Tv(4804), Tv(5393)]),
+    ((5397, false, XOR2), &[Tv(4807), Tv(5396)]),
+    ((5400, false, XOR2), &[Tv(4810), Tv(5399)]),
+    ((5403, false, XOR2), &[Tv(4813), Tv(5402)]),
+    ((5406, false, XOR2), &[Tv(4816), Tv(5405)]),
+    ((5409, false, XOR2), &[Tv(4819), Tv(5408)]),
+    ((5412, false, XOR2), &[Tv(4822), Tv(5411)]),
+    ((5415, false, XOR2), &[Tv(4825), Tv(5414)]),
+    ((5418, false, XOR2), &[Tv(4828), Tv(5417)]),
+    ((5421, false, XOR2), &[Tv(4831), Tv(5420)]),
+    ((5424, false, XOR2), &[Tv(4834), Tv(5423)]),
+    ((5427, false, XOR2), &[Tv(4837), Tv(5426)]),
+    ((5627, false, NOR2), &[Tv(5526), Tv(5626)]),
+    ((5830, false, NOR2), &[Tv(5725), Tv(5829)]),
+    ((5831, false, INV), &[Tv(5725)]),
+    ((6047, false, NAND2), &[Tv(6046), Tv(6027)]),
+    ((6049, false, INV), &[Tv(6030)]),
+    ((6050, false, INV), &[Tv(6033)]),
+    ((6052, false, INV), &[Tv(6036)]),
+    ((6053, false, INV), &[Tv(6039)]),
+    ((6055, false, INV), &[Tv(6042)]),
+    ((6056, false, INV), &[Tv(6045)]),
+    ((6244, false, NAND2), &[Tv(6243), Tv(6224)]),
+    ((6246, false, INV), &[Tv(6227)]),
+    ((6247, false, INV), &[Tv(6230)]),
+    ((6249, false, INV), &[Tv(6233)]),
+    ((6250, false, INV), &[Tv(6236)]),
+    ((6252, false, INV), &[Tv(6239)]),
+    ((6253, false, INV), &[Tv(6242)]),
 ];
 
-static LEVEL_35: [((usize, bool, CellType), &[GateInput]); 146] = [
-    ((2874, false, INV), &[Tv(2873)]),
-    ((2935, false, AND2), &[Tv(2892), Tv(2934)]),
-    ((2936, false, NAND2), &[Tv(2892), Tv(2934)]),
-    ((2982, false, AND2), &[Tv(2889), Tv(2981)]),
-    ((2988, false, NAND2), &[Tv(2828), Tv(2987)]),
-    ((3101, false, NAND2), &[Tv(3098), Tv(3100)]),
-    ((3105, false, AND2), &[Tv(3102), Tv(3104)]),
-    ((3210, false, NAND2), &[Tv(3207), Tv(3209)]),
-    ((3213, false, AND2), &[Tv(3211), Tv(3212)]),
-    ((3317, false, NAND2), &[Tv(3314), Tv(3316)]),
-    ((3320, false, AND2), &[Tv(3318), Tv(3319)]),
-    ((3424, false, NAND2), &[Tv(3421), Tv(3423)]),
-    ((3427, false, AND2), &[Tv(3425), Tv(3426)]),
-    ((3531, false, NAND2), &[Tv(3528), Tv(3530)]),
-    ((3534, false, AND2), &[Tv(3532), Tv(3533)]),
-    ((3638, false, NAND2), &[Tv(3635), Tv(3637)]),
-    ((3641, false, AND2), &[Tv(3639), Tv(3640)]),
-    ((3745, false, NAND2), &[Tv(3742), Tv(3744)]),
-    ((3748, false, AND2), &[Tv(3746), Tv(3747)]),
-    ((3852, false, NAND2), &[Tv(3849), Tv(3851)]),
-    ((3855, false, AND2), &[Tv(3853), Tv(3854)]),
-    ((3959, false, NAND2), &[Tv(3956), Tv(3958)]),
-    ((3962, false, AND2), &[Tv(3960), Tv(3961)]),
-    ((4066, false, NAND2), &[Tv(4063), Tv(4065)]),
-    ((4069, false, AND2), &[Tv(4067), Tv(4068)]),
-    ((4173, false, NAND2), &[Tv(4170), Tv(4172)]),
-    ((4176, false, AND2), &[Tv(4174), Tv(4175)]),
-    ((4280, false, NAND2), &[Tv(4277), Tv(4279)]),
-    ((4283, false, AND2), &[Tv(4281), Tv(4282)]),
-    ((4387, false, NAND2), &[Tv(4384), Tv(4386)]),
-    ((4390, false, AND2), &[Tv(4388), Tv(4389)]),
-    ((4494, false, NAND2), &[Tv(4491), Tv(4493)]),
-    ((4497, false, AND2), &[Tv(4495), Tv(4496)]),
-    ((4601, false, NAND2), &[Tv(4598), Tv(4600)]),
-    ((4604, false, AND2), &[Tv(4602), Tv(4603)]),
-    ((4708, false, NAND2), &[Tv(4705), Tv(4707)]),
-    ((4711, false, AND2), &[Tv(4709), Tv(4710)]),
-    ((4815, false, NAND2), &[Tv(4812), Tv(4814)]),
-    ((4818, false, AND2), &[Tv(4816), Tv(4817)]),
-    ((4922, false, NAND2), &[Tv(4919), Tv(4921)]),
-    ((4925, false, AND2), &[Tv(4923), Tv(4924)]),
-    ((5029, false, NAND2), &[Tv(5026), Tv(5028)]),
-    ((5032, false, AND2), &[Tv(5030), Tv(5031)]),
-    ((5136, false, NAND2), &[Tv(5133), Tv(5135)]),
-    ((5139, false, AND2), &[Tv(5137), Tv(5138)]),
-    ((5243, false, NAND2), &[Tv(5240), Tv(5242)]),
-    ((5246, false, AND2), &[Tv(5244), Tv(5245)]),
-    ((5350, false, NAND2), &[Tv(5347), Tv(5349)]),
-    ((5353, false, AND2), &[Tv(5351), Tv(5352)]),
-    ((5457, false, NAND2), &[Tv(5454), Tv(5456)]),
-    ((5460, false, AND2), &[Tv(5458), Tv(5459)]),
-    ((5564, false, NAND2), &[Tv(5561), Tv(5563)]),
-    ((5567, false, AND2), &[Tv(5565), Tv(5566)]),
-    ((5671, false, NAND2), &[Tv(5668), Tv(5670)]),
-    ((5674, false, AND2), &[Tv(5672), Tv(5673)]),
-    ((5778, false, NAND2), &[Tv(5775), Tv(5777)]),
-    ((5781, false, AND2), &[Tv(5779), Tv(5780)]),
-    ((5885, false, NAND2), &[Tv(5882), Tv(5884)]),
-    ((5888, false, AND2), &[Tv(5886), Tv(5887)]),
-    ((5992, false, NAND2), &[Tv(5989), Tv(5991)]),
-    ((5995, false, AND2), &[Tv(5993), Tv(5994)]),
-    ((6099, false, NAND2), &[Tv(6096), Tv(6098)]),
-    ((6102, false, AND2), &[Tv(6100), Tv(6101)]),
-    ((6206, false, NAND2), &[Tv(6203), Tv(6205)]),
-    ((6209, false, AND2), &[Tv(6207), Tv(6208)]),
-    ((6313, false, NAND2), &[Tv(6310), Tv(6312)]),
-    ((6316, false, AND2), &[Tv(6314), Tv(6315)]),
-    ((6420, false, NAND2), &[Tv(6417), Tv(6419)]),
-    ((6423, false, AND2), &[Tv(6421), Tv(6422)]),
-    ((150, false, INV), &[Arg(3, 343)]),
-    ((151, false, INV), &[Arg(3, 344)]),
-    ((152, false, INV), &[Arg(3, 345)]),
-    ((153, false, INV), &[Arg(3, 346)]),
-    ((154, false, INV), &[Arg(3, 347)]),
-    ((155, false, INV), &[Arg(3, 348)]),
-    ((156, false, INV), &[Arg(3, 349)]),
-    ((157, false, INV), &[Arg(3, 350)]),
-    ((173, false, INV), &[Arg(3, 367)]),
-    ((174, false, INV), &[Arg(3, 368)]),
-    ((175, false, INV), &[Arg(3, 369)]),
-    ((176, false, INV), &[Arg(3, 370)]),
-    ((177, false, INV), &[Arg(3, 371)]),
-    ((178, false, INV), &[Arg(3, 372)]),
-    ((179, false, INV), &[Arg(3, 373)]),
-    ((180, false, INV), &[Arg(3, 374)]),
-    ((269, false, INV), &[Arg(3, 375)]),
-    ((270, false, INV), &[Arg(3, 376)]),
-    ((271, false, INV), &[Arg(3, 377)]),
-    ((272, false, INV), &[Arg(3, 378)]),
-    ((273, false, INV), &[Arg(3, 379)]),
-    ((274, false, INV), &[Arg(3, 380)]),
-    ((275, false, INV), &[Arg(3, 381)]),
-    ((276, false, INV), &[Arg(3, 382)]),
-    ((365, false, INV), &[Arg(3, 384)]),
-    ((366, false, INV), &[Arg(3, 385)]),
-    ((367, false, INV), &[Arg(3, 386)]),
-    ((368, false, INV), &[Arg(3, 387)]),
-    ((369, false, INV), &[Arg(3, 388)]),
-    ((370, false, INV), &[Arg(3, 389)]),
-    ((371, false, INV), &[Arg(3, 390)]),
-    ((372, false, INV), &[Arg(3, 391)]),
-    ((647, false, INV), &[Arg(4, 157)]),
-    ((747, false, XNOR2), &[Arg(1, 1), Arg(4, 105)]),
-    ((757, false, AND2), &[Tv(748), Tv(754)]),
-    ((758, false, AND2), &[Tv(749), Tv(750)]),
-    ((760, false, AND2), &[Tv(751), Tv(752)]),
-    ((761, false, AND2), &[Tv(744), Tv(753)]),
-    ((765, false, AND2), &[Tv(742), Tv(764)]),
-    ((767, false, AND2), &[Tv(745), Tv(766)]),
-    ((769, false, AND2), &[Tv(741), Tv(755)]),
-    ((775, false, XNOR2), &[Arg(1, 15), Arg(4, 71)]),
-    ((776, false, NAND2), &[Arg(1, 5), Tv(645)]),
-    ((777, false, XNOR2), &[Arg(1, 12), Arg(4, 68)]),
-    ((779, false, XNOR2), &[Arg(1, 6), Arg(4, 62)]),
-    ((781, false, XNOR2), &[Arg(1, 13), Arg(4, 69)]),
-    ((783, false, NAND2), &[Tv(394), Arg(4, 61)]),
-    ((784, false, XNOR2), &[Arg(1, 8), Arg(4, 64)]),
-    ((785, false, XNOR2), &[Arg(1, 4), Arg(4, 60)]),
-    ((788, false, XNOR2), &[Arg(1, 3), Arg(4, 59)]),
-    ((790, false, XNOR2), &[Arg(1, 7), Arg(4, 63)]),
-    ((798, false, XNOR2), &[Arg(1, 2), Arg(4, 58)]),
-    ((800, false, XNOR2), &[Arg(1, 9), Arg(4, 65)]),
-    ((809, false, NAND2), &[Arg(1, 5), Tv(644)]),
-    ((810, false, NAND2), &[Tv(394), Arg(4, 13)]),
-    ((811, false, XNOR2), &[Arg(1, 8), Arg(4, 16)]),
-    ((812, false, XNOR2), &[Arg(1, 4), Arg(4, 12)]),
-    ((813, false, XNOR2), &[Arg(1, 9), Arg(4, 17)]),
-    ((814, false, XNOR2), &[Arg(1, 3), Arg(4, 11)]),
-    ((815, false, XNOR2), &[Arg(1, 14), Arg(4, 22)]),
-    ((817, false, XNOR2), &[Arg(1, 12), Arg(4, 20)]),
-    ((819, false, XNOR2), &[Arg(1, 6), Arg(4, 14)]),
-    ((820, false, XNOR2), &[Arg(1, 13), Arg(4, 21)]),
-    ((821, false, XNOR2), &[Arg(1, 15), Arg(4, 23)]),
-    ((822, false, XNOR2), &[Arg(1, 11), Arg(4, 19)]),
-    ((824, false, XNOR2), &[Arg(1, 7), Arg(4, 15)]),
-    ((833, false, XNOR2), &[Arg(1, 2), Arg(4, 10)]),
-    ((1061, false, AND2), &[Tv(1058), Tv(1060)]),
-    ((1065, false, AND2), &[Tv(1055), Tv(1064)]),
-    ((1070, false, AND2), &[Tv(1049), Tv(1069)]),
-    ((1072, false, AND2), &[Tv(1052), Tv(1071)]),
-    ((1124, false, AND2), &[Tv(1104), Tv(1123)]),
-    ((1160, false, AND2), &[Tv(1155), Tv(1159)]),
-    ((1170, false, AND2), &[Tv(1166), Tv(1169)]),
-    ((1418, false, AND2), &[Tv(1411), Tv(1417)]),
-    ((1434, false, AND2), &[Tv(1427), Tv(1433)]),
-    ((1481, false, NAND2), &[Tv(1470), Tv(1480)]),
+static LEVEL_15: [((usize, bool, CellType), &[GateInput]); 73] = [
+    ((5429, false, XOR2), &[Tv(4940), Tv(5038)]),
+    ((5433, false, XOR2), &[Tv(4943), Tv(5041)]),
+    ((5436, false, XOR2), &[Tv(4946), Tv(5044)]),
+    ((5439, false, XOR2), &[Tv(4949), Tv(5047)]),
+    ((5442, false, XOR2), &[Tv(4952), Tv(5050)]),
+    ((5445, false, XOR2), &[Tv(4955), Tv(5053)]),
+    ((5448, false, XOR2), &[Tv(4958), Tv(5056)]),
+    ((5451, false, XOR2), &[Tv(4961), Tv(5059)]),
+    ((5454, false, XOR2), &[Tv(4964), Tv(5062)]),
+    ((5457, false, XOR2), &[Tv(4967), Tv(5065)]),
+    ((5460, false, XOR2), &[Tv(4970), Tv(5068)]),
+    ((5463, false, XOR2), &[Tv(4973), Tv(5071)]),
+    ((5466, false, XOR2), &[Tv(4976), Tv(5074)]),
+    ((5469, false, XOR2), &[Tv(4979), Tv(5077)]),
+    ((5472, false, XOR2), &[Tv(4982), Tv(5080)]),
+    ((5475, false, XOR2), &[Tv(4985), Tv(5083)]),
+    ((5478, false, XOR2), &[Tv(4988), Tv(5086)]),
+    ((5481, false, XOR2), &[Tv(4991), Tv(5089)]),
+    ((5484, false, XOR2), &[Tv(4994), Tv(5092)]),
+    ((5487, false, XOR2), &[Tv(4997), Tv(5095)]),
+    ((5490, false, XOR2), &[Tv(5000), Tv(5098)]),
+    ((5493, false, XOR2), &[Tv(5003), Tv(5101)]),
+    ((5496, false, XOR2), &[Tv(5006), Tv(5104)]),
+    ((5499, false, XOR2), &[Tv(5009), Tv(5107)]),
+    ((5502, false, XOR2), &[Tv(5012), Tv(5110)]),
+    ((5505, false, XOR2), &[Tv(5015), Tv(5113)]),
+    ((5508, false, XOR2), &[Tv(5018), Tv(5116)]),
+    ((5511, false, XOR2), &[Tv(5021), Tv(5119)]),
+    ((5514, false, XOR2), &[Tv(5024), Tv(5122)]),
+    ((5517, false, XOR2), &[Tv(5027), Tv(5125)]),
+    ((5520, false, XOR2), &[Tv(5030), Tv(5128)]),
+    ((5523, false, XOR2), &[Tv(5033), Tv(5131)]),
+    ((5528, false, XOR2), &[Tv(5137), Tv(5235)]),
+    ((5532, false, XOR2), &[Tv(5140), Tv(5238)]),
+    ((5535, false, XOR2), &[Tv(5143), Tv(5241)]),
+    ((5538, false, XOR2), &[Tv(5146), Tv(5244)]),
+    ((5541, false, XOR2), &[Tv(5149), Tv(5247)]),
+    ((5544, false, XOR2), &[Tv(5152), Tv(5250)]),
+    ((5547, false, XOR2), &[Tv(5155), Tv(5253)]),
+    ((5550, false, XOR2), &[Tv(5158), Tv(5256)]),
+    ((5553, false, XOR2), &[Tv(5161), Tv(5259)]),
+    ((5556, false, XOR2), &[Tv(5164), Tv(5262)]),
+    ((5559, false, XOR2), &[Tv(5167), Tv(5265)]),
+    ((5562, false, XOR2), &[Tv(5170), Tv(5268)]),
+    ((5565, false, XOR2), &[Tv(5173), Tv(5271)]),
+    ((5568, false, XOR2), &[Tv(5176), Tv(5274)]),
+    ((5571, false, XOR2), &[Tv(5179), Tv(5277)]),
+    ((5574, false, XOR2), &[Tv(5182), Tv(5280)]),
+    ((5577, false, XOR2), &[Tv(5185), Tv(5283)]),
+    ((5580, false, XOR2), &[Tv(5188), Tv(5286)]),
+    ((5583, false, XOR2), &[Tv(5191), Tv(5289)]),
+    ((5586, false, XOR2), &[Tv(5194), Tv(5292)]),
+    ((5589, false, XOR2), &[Tv(5197), Tv(5295)]),
+    ((5592, false, XOR2), &[Tv(5200), Tv(5298)]),
+    ((5595, false, XOR2), &[Tv(5203), Tv(5301)]),
+    ((5598, false, XOR2), &[Tv(5206), Tv(5304)]),
+    ((5601, false, XOR2), &[Tv(5209), Tv(5307)]),
+    ((5604, false, XOR2), &[Tv(5212), Tv(5310)]),
+    ((5607, false, XOR2), &[Tv(5215), Tv(5313)]),
+    ((5610, false, XOR2), &[Tv(5218), Tv(5316)]),
+    ((5613, false, XOR2), &[Tv(5221), Tv(5319)]),
+    ((5616, false, XOR2), &[Tv(5224), Tv(5322)]),
+    ((5619, false, XOR2), &[Tv(5227), Tv(5325)]),
+    ((5622, false, XOR2), &[Tv(5230), Tv(5328)]),
+    ((5726, false, XOR2), &[Tv(5330), Tv(5627)]),
+    ((6048, false, NAND2), &[Tv(6027), Tv(6047)]),
+    ((6051, false, NOR2), &[Tv(6049), Tv(6050)]),
+    ((6054, false, NOR2), &[Tv(6052), Tv(6053)]),
+    ((6057, false, NOR2), &[Tv(6055), Tv(6056)]),
+    ((6245, false, NAND2), &[Tv(6224), Tv(6244)]),
+    ((6248, false, NOR2), &[Tv(6246), Tv(6247)]),
+    ((6251, false, NOR2), &[Tv(6249), Tv(6250)]),
+    ((6254, false, NOR2), &[Tv(6252), Tv(6253)]),
 ];
 
-static LEVEL_36: [((usize, bool, CellType), &[GateInput]); 120] = [
-    ((2945, false, NAND2), &[Tv(2935), Tv(2944)]),
-    ((2946, false, OR2), &[Tv(2910), Tv(2935)]),
-    ((2948, false, NAND2), &[Tv(2902), Tv(2936)]),
-    ((2949, false, NAND2), &[Tv(2915), Tv(2935)]),
-    ((2953, false, XNOR2), &[Tv(2856), Tv(2862)]),
-    ((2956, false, NAND2), &[Tv(2906), Tv(2936)]),
-    ((2957, false, NAND2), &[Tv(2935), Tv(2940)]),
-    ((2960, false, NOR2), &[Tv(2874), Tv(2878)]),
-    ((2961, false, NOR2), &[Tv(2846), Tv(2873)]),
-    ((2966, false, NAND2), &[Tv(2873), Tv(2876)]),
-    ((2967, false, NAND2), &[Tv(2844), Tv(2874)]),
-    ((2974, false, NOR2), &[Tv(2874), Tv(2882)]),
-    ((2975, false, NOR2), &[Tv(2850), Tv(2873)]),
-    ((2978, false, NOR2), &[Tv(2874), Tv(2880)]),
-    ((2979, false, NOR2), &[Tv(2848), Tv(2873)]),
-    ((2989, false, NAND2), &[Tv(2831), Tv(2988)]),
-    ((2992, false, NAND2), &[Tv(2924), Tv(2982)]),
-    ((2993, false, AND2), &[Tv(2892), Tv(2893)]),
-    ((3106, false, NAND2), &[Tv(3101), Tv(3105)]),
-    ((3108, false, OR2), &[Tv(150), Tv(1481)]),
-    ((3214, false, NAND2), &[Tv(3210), Tv(3213)]),
-    ((3216, false, OR2), &[Tv(151), Tv(1481)]),
-    ((3321, false, NAND2), &[Tv(3317), Tv(3320)]),
-    ((3323, false, OR2), &[Tv(152), Tv(1481)]),
-    ((3428, false, NAND2), &[Tv(3424), Tv(3427)]),
-    ((3430, false, OR2), &[Tv(153), Tv(1481)]),
-    ((3535, false, NAND2), &[Tv(3531), Tv(3534)]),
-    ((3537, false, OR2), &[Tv(154), Tv(1481)]),
-    ((3642, false, NAND2), &[Tv(3638), Tv(3641)]),
-    ((3644, false, OR2), &[Tv(155), Tv(1481)]),
-    ((3749, false, NAND2), &[Tv(3745), Tv(3748)]),
-    ((3751, false, OR2), &[Tv(156), Tv(1481)]),
-    ((3856, false, NAND2), &[Tv(3852), Tv(3855)]),
-    ((3858, false, OR2), &[Tv(157), Tv(1481)]),
-    ((3963, false, NAND2), &[Tv(3959), Tv(3962)]),
-    ((3965, false, OR2), &[Tv(173), Tv(1481)]),
-    ((4070, false, NAND2), &[Tv(4066), Tv(4069)]),
-    ((4072, false, OR2), &[Tv(174), Tv(1481)]),
-    ((4177, false, NAND2), &[Tv(4173), Tv(4176)]),
-    ((4179, false, OR2), &[Tv(175), Tv(1481)]),
-    ((4284, false, NAND2), &[Tv(4280), Tv(4283)]),
-    ((4286, false, OR2), &[Tv(176), Tv(1481)]),
-    ((4391, false, NAND2), &[Tv(4387), Tv(4390)]),
-    ((4393, false, OR2), &[Tv(177), Tv(1481)]),
-    ((4498, false, NAND2), &[Tv(4494), Tv(4497)]),
-    ((4500, false, OR2), &[Tv(178), Tv(1481)]),
-    ((4605, false, NAND2), &[Tv(4601), Tv(4604)]),
-    ((4607, false, OR2), &[Tv(179), Tv(1481)]),
-    ((4712, false, NAND2), &[Tv(4708), Tv(4711)]),
-    ((4714, false, OR2), &[Tv(180), Tv(1481)]),
-    ((4819, false, NAND2), &[Tv(4815), Tv(4818)]),
-    ((4821, false, OR2), &[Tv(269), Tv(1481)]),
-    ((4926, false, NAND2), &[Tv(4922), Tv(4925)]),
-    ((4928, false, OR2), &[Tv(270), Tv(1481)]),
-    ((5033, false, NAND2), &[Tv(5029), Tv(5032)]),
-    ((5035, false, OR2), &[Tv(271), Tv(1481)]),
-    ((5140, false, NAND2), &[Tv(5136), Tv(5139)]),
-    ((5142, false, OR2), &[Tv(272), Tv(1481)]),
-    ((5247, false, NAND2), &[Tv(5243), Tv(5246)]),
-    ((5249, false, OR2), &[Tv(273), Tv(1481)]),
-    ((5354, false, NAND2), &[Tv(5350), Tv(5353)]),
-    ((5356, false, OR2), &[Tv(274), Tv(1481)]),
-    ((5461, false, NAND2), &[Tv(5457), Tv(5460)]),
-    ((5463, false, OR2), &[Tv(275), Tv(1481)]),
-    ((5568, false, NAND2), &[Tv(5564), Tv(5567)]),
-    ((5570, false, OR2), &[Tv(276), Tv(1481)]),
-    ((5675, false, NAND2), &[Tv(5671), Tv(5674)]),
-    ((5677, false, OR2), &[Tv(365), Tv(1481)]),
-    ((5782, false, NAND2), &[Tv(5778), Tv(5781)]),
-    ((5784, false, OR2), &[Tv(366), Tv(1481)]),
-    ((5889, false, NAND2), &[Tv(5885), Tv(5888)]),
-    ((5891, false, OR2), &[Tv(367), Tv(1481)]),
-    ((5996, false, NAND2), &[Tv(5992), Tv(5995)]),
-    ((5998, false, OR2), &[Tv(368), Tv(1481)]),
-    ((6103, false, NAND2), &[Tv(6099), Tv(6102)]),
-    ((6105, false, OR2), &[Tv(369), Tv(1481)]),
-    ((6210, false, NAND2), &[Tv(6206), Tv(6209)]),
-    ((6212, false, OR2), &[Tv(370), Tv(1481)]),
-    ((6317, false, NAND2), &[Tv(6313), Tv(6316)]),
-    ((6319, false, OR2), &[Tv(371), Tv(1481)]),
-    ((6424, false, NAND2), &[Tv(6420), Tv(6423)]),
-    ((6426, false, OR2), &[Tv(372), Tv(1481)]),
-    ((759, false, AND2), &[Tv(757), Tv(758)]),
-    ((762, false, AND2), &[Tv(760), Tv(761)]),
-    ((768, false, AND2), &[Tv(765), Tv(767)]),
-    ((770, false, AND2), &[Tv(747), Tv(769)]),
-    ((782, false, XNOR2), &[Arg(1, 1), Arg(4, 57)]),
-    ((786, false, XNOR2), &[Arg(1, 14), Arg(4, 70)]),
-    ((789, false, AND2), &[Tv(779), Tv(788)]),
-    ((791, false, AND2), &[Tv(777), Tv(785)]),
-    ((793, false, AND2), &[Tv(781), Tv(790)]),
-    ((799, false, AND2), &[Tv(783), Tv(798)]),
-    ((801, false, AND2), &[Tv(784), Tv(800)]),
-    ((803, false, AND2), &[Tv(775), Tv(776)]),
-    ((823, false, XNOR2), &[Arg(1, 1), Arg(4, 9)]),
-    ((826, false, AND2), &[Tv(814), Tv(819)]),
-    ((827, false, AND2), &[Tv(812), Tv(817)]),
-    ((829, false, AND2), &[Tv(820), Tv(824)]),
-    ((830, false, AND2), &[Tv(815), Tv(822)]),
-    ((834, false, AND2), &[Tv(810), Tv(833)]),
-    ((835, false, AND2), &[Tv(811), Tv(813)]),
-    ((837, false, AND2), &[Tv(809), Tv(821)]),
-    ((842, false, XNOR2), &[Arg(1, 15), Arg(4, 167)]),
-    ((843, false, NAND2), &[Arg(1, 5), Tv(647)]),
-    ((844, false, XNOR2), &[Arg(1, 12), Arg(4, 164)]),
-    ((846, false, XNOR2), &[Arg(1, 6), Arg(4, 158)]),
-    ((848, false, XNOR2), &[Arg(1, 13), Arg(4, 165)]),
-    ((850, false, NAND2), &[Tv(394), Arg(4, 157)]),
-    ((851, false, XNOR2), &[Arg(1, 8), Arg(4, 160)]),
-    ((852, false, XNOR2), &[Arg(1, 4), Arg(4, 156)]),
-    ((855, false, XNOR2), &[Arg(1, 3), Arg(4, 155)]),
-    ((857, false, XNOR2), &[Arg(1, 7), Arg(4, 159)]),
-    ((865, false, XNOR2), &[Arg(1, 2), Arg(4, 154)]),
-    ((867, false, XNOR2), &[Arg(1, 9), Arg(4, 161)]),
-    ((1066, false, AND2), &[Tv(1061), Tv(1065)]),
-    ((1073, false, AND2), &[Tv(1070), Tv(1072)]),
-    ((1125, false, INV), &[Tv(1124)]),
-    ((1171, false, NAND2), &[Tv(1160), Tv(1170)]),
-    ((1435, false, NAND2), &[Tv(1418), Tv(1434)]),
-    ((2659, false, INV), &[Tv(2658)]),
+static LEVEL_16: [((usize, bool, CellType), &[GateInput]); 69] = [
+    ((5431, false, AND2), &[Tv(5430), Tv(5429)]),
+    ((5434, false, AND2), &[Tv(5430), Tv(5433)]),
+    ((5437, false, AND2), &[Tv(5430), Tv(5436)]),
+    ((5440, false, AND2), &[Tv(5430), Tv(5439)]),
+    ((5443, false, AND2), &[Tv(5430), Tv(5442)]),
+    ((5446, false, AND2), &[Tv(5430), Tv(5445)]),
+    ((5449, false, AND2), &[Tv(5430), Tv(5448)]),
+    ((5452, false, AND2), &[Tv(5430), Tv(5451)]),
+    ((5455, false, AND2), &[Tv(5430), Tv(5454)]),
+    ((5458, false, AND2), &[Tv(5430), Tv(5457)]),
+    ((5461, false, AND2), &[Tv(5430), Tv(5460)]),
+    ((5464, false, AND2), &[Tv(5430), Tv(5463)]),
+    ((5467, false, AND2), &[Tv(5430), Tv(5466)]),
+    ((5470, false, AND2), &[Tv(5430), Tv(5469)]),
+    ((5473, false, AND2), &[Tv(5430), Tv(5472)]),
+    ((5476, false, AND2), &[Tv(5430), Tv(5475)]),
+    ((5479, false, AND2), &[Tv(5430), Tv(5478)]),
+    ((5482, false, AND2), &[Tv(5430), Tv(5481)]),
+    ((5485, false, AND2), &[Tv(5430), Tv(5484)]),
+    ((5488, false, AND2), &[Tv(5430), Tv(5487)]),
+    ((5491, false, AND2), &[Tv(5430), Tv(5490)]),
+    ((5494, false, AND2), &[Tv(5430), Tv(5493)]),
+    ((5497, false, AND2), &[Tv(5430), Tv(5496)]),
+    ((5500, false, AND2), &[Tv(5430), Tv(5499)]),
+    ((5503, false, AND2), &[Tv(5430), Tv(5502)]),
+    ((5506, false, AND2), &[Tv(5430), Tv(5505)]),
+    ((5509, false, AND2), &[Tv(5430), Tv(5508)]),
+    ((5512, false, AND2), &[Tv(5430), Tv(5511)]),
+    ((5515, false, AND2), &[Tv(5430), Tv(5514)]),
+    ((5518, false, AND2), &[Tv(5430), Tv(5517)]),
+    ((5521, false, AND2), &[Tv(5430), Tv(5520)]),
+    ((5524, false, AND2), &[Tv(5430), Tv(5523)]),
+    ((5530, false, AND2), &[Tv(5529), Tv(5528)]),
+    ((5533, false, AND2), &[Tv(5529), Tv(5532)]),
+    ((5536, false, AND2), &[Tv(5529), Tv(5535)]),
+    ((5539, false, AND2), &[Tv(5529), Tv(5538)]),
+    ((5542, false, AND2), &[Tv(5529), Tv(5541)]),
+    ((5545, false, AND2), &[Tv(5529), Tv(5544)]),
+    ((5548, false, AND2), &[Tv(5529), Tv(5547)]),
+    ((5551, false, AND2), &[Tv(5529), Tv(5550)]),
+    ((5554, false, AND2), &[Tv(5529), Tv(5553)]),
+    ((5557, false, AND2), &[Tv(5529), Tv(5556)]),
+    ((5560, false, AND2), &[Tv(5529), Tv(5559)]),
+    ((5563, false, AND2), &[Tv(5529), Tv(5562)]),
+    ((5566, false, AND2), &[Tv(5529), Tv(5565)]),
+    ((5569, false, AND2), &[Tv(5529), Tv(5568)]),
+    ((5572, false, AND2), &[Tv(5529), Tv(5571)]),
+    ((5575, false, AND2), &[Tv(5529), Tv(5574)]),
+    ((5578, false, AND2), &[Tv(5529), Tv(5577)]),
+    ((5581, false, AND2), &[Tv(5529), Tv(5580)]),
+    ((5584, false, AND2), &[Tv(5529), Tv(5583)]),
+    ((5587, false, AND2), &[Tv(5529), Tv(5586)]),
+    ((5590, false, AND2), &[Tv(5529), Tv(5589)]),
+    ((5593, false, AND2), &[Tv(5529), Tv(5592)]),
+    ((5596, false, AND2), &[Tv(5529), Tv(5595)]),
+    ((5599, false, AND2), &[Tv(5529), Tv(5598)]),
+    ((5602, false, AND2), &[Tv(5529), Tv(5601)]),
+    ((5605, false, AND2), &[Tv(5529), Tv(5604)]),
+    ((5608, false, AND2), &[Tv(5529), Tv(5607)]),
+    ((5611, false, AND2), &[Tv(5529), Tv(5610)]),
+    ((5614, false, AND2), &[Tv(5529), Tv(5613)]),
+    ((5617, false, AND2), &[Tv(5529), Tv(5616)]),
+    ((5620, false, AND2), &[Tv(5529), Tv(5619)]),
+    ((5623, false, AND2), &[Tv(5529), Tv(5622)]),
+    ((5728, false, AND2), &[Tv(5727), Tv(5726)]),
+    ((6058, false, AND2), &[Tv(6048), Tv(6051)]),
+    ((6059, false, AND2), &[Tv(6054), Tv(6057)]),
+    ((6255, false, AND2), &[Tv(6245), Tv(6248)]),
+    ((6256, false, AND2), &[Tv(6251), Tv(6254)]),
 ];
 
-static LEVEL_37: [((usize, bool, CellType), &[GateInput]); 142] = [
-    ((2820, false, AND2), &[Tv(2764), Tv(2819)]),
-    ((2891, false, INV), &[Tv(2890)]),
-    ((2947, false, AND2), &[Tv(2945), Tv(2946)]),
-    ((2950, false, NAND2), &[Tv(2948), Tv(2949)]),
-    ((2954, false, XNOR2), &[Tv(2873), Tv(2953)]),
-    ((2958, false, NAND2), &[Tv(2956), Tv(2957)]),
-    ((2962, false, NOR2), &[Tv(2960), Tv(2961)]),
-    ((2963, false, NAND2), &[Tv(2935), Tv(2938)]),
-    ((2964, false, NAND2), &[Tv(2904), Tv(2936)]),
-    ((2968, false, AND2), &[Tv(2966), Tv(2967)]),
-    ((2976, false, OR2), &[Tv(2974), Tv(2975)]),
-    ((2980, false, NOR2), &[Tv(2978), Tv(2979)]),
-    ((2983, false, NAND2), &[Tv(2912), Tv(2936)]),
-    ((2984, false, NAND2), &[Tv(2935), Tv(2982)]),
-    ((2990, false, AND2), &[Tv(2872), Tv(2989)]),
-    ((2994, false, NAND2), &[Tv(2992), Tv(2993)]),
-    ((3107, false, NAND2), &[Tv(1481), Tv(3106)]),
-    ((3109, false, AND2), &[Tv(1435), Tv(3108)]),
-    ((3111, false, OR2), &[Arg(3, 392), Tv(1435)]),
-    ((3114, false, NAND2), &[Arg(3, 490), Tv(1124)]),
-    ((3116, false, NOR2), &[Tv(1124), Tv(1171)]),
-    ((3215, false, NAND2), &[Tv(1481), Tv(3214)]),
-    ((3217, false, AND2), &[Tv(1435), Tv(3216)]),
-    ((3219, false, OR2), &[Arg(3, 393), Tv(1435)]),
-    ((3322, false, NAND2), &[Tv(1481), Tv(3321)]),
-    ((3324, false, AND2), &[Tv(1435), Tv(3323)]),
-    ((3326, false, OR2), &[Arg(3, 394), Tv(1435)]),
-    ((3429, false, NAND2), &[Tv(1481), Tv(3428)]),
-    ((3431, false, AND2), &[Tv(1435), Tv(3430)]),
-    ((3433, false, OR2), &[Arg(3, 395), Tv(1435)]),
-    ((3536, false, NAND2), &[Tv(1481), Tv(3535)]),
-    ((3538, false, AND2), &[Tv(1435), Tv(3537)]),
-    ((3540, false, OR2), &[Arg(3, 396), Tv(1435)]),
-    ((3643, false, NAND2), &[Tv(1481), Tv(3642)]),
-    ((3645, false, AND2), &[Tv(1435), Tv(3644)]),
-    ((3647, false, OR2), &[Arg(3, 397), Tv(1435)]),
-    ((3750, false, NAND2), &[Tv(1481), Tv(3749)]),
-    ((3752, false, AND2), &[Tv(1435), Tv(3751)]),
-    ((3754, false, OR2), &[Arg(3, 398), Tv(1435)]),
-    ((3857, false, NAND2), &[Tv(1481), Tv(3856)]),
-    ((3859, false, AND2), &[Tv(1435), Tv(3858)]),
-    ((3861, false, OR2), &[Arg(3, 399), Tv(1435)]),
-    ((3964, false, NAND2), &[Tv(1481), Tv(3963)]),
-    ((3966, false, AND2), &[Tv(1435), Tv(3965)]),
-    ((3968, false, OR2), &[Arg(3, 416), Tv(1435)]),
-    ((4071, false, NAND2), &[Tv(1481), Tv(4070)]),
-    ((4073, false, AND2), &[Tv(1435), Tv(4072)]),
-    ((4075, false, OR2), &[Arg(3, 417), Tv(1435)]),
-    ((4178, false, NAND2), &[Tv(1481), Tv(4177)]),
-    ((4180, false, AND2), &[Tv(1435), Tv(4179)]),
-    ((4182, false, OR2), &[Arg(3, 418), Tv(1435)]),
-    ((4285, false, NAND2), &[Tv(1481), Tv(4284)]),
-    ((4287, false, AND2), &[Tv(1435), Tv(4286)]),
-    ((4289, false, OR2), &[Arg(3, 419), Tv(1435)]),
-    ((4392, false, NAND2), &[Tv(1481), Tv(4391)]),
-    ((4394, false, AND2), &[Tv(1435), Tv(4393)]),
-    ((4396, false, OR2), &[Arg(3, 420), Tv(1435)]),
-    ((4499, false, NAND2), &[Tv(1481), Tv(4498)]),
-    ((4501, false, AND2), &[Tv(1435), Tv(4500)]),
-    ((4503, false, OR2), &[Arg(3, 421), Tv(1435)]),
-    ((4606, false, NAND2), &[Tv(1481), Tv(4605)]),
-    ((4608, false, AND2), &[Tv(1435), Tv(4607)]),
-    ((4610, false, OR2), &[Arg(3, 422), Tv(1435)]),
-    ((4713, false, NAND2), &[Tv(1481), Tv(4712)]),
-    ((4715, false, AND2), &[Tv(1435), Tv(4714)]),
-    ((4717, false, OR2), &[Arg(3, 423), Tv(1435)]),
-    ((4820, false, NAND2), &[Tv(1481), Tv(4819)]),
-    ((4822, false, AND2), &[Tv(1435), Tv(4821)]),
-    ((4824, false, OR2), &[Arg(3, 424), Tv(1435)]),
-    ((4927, false, NAND2), &[Tv(1481), Tv(4926)]),
-    ((4929, false, AND2), &[Tv(1435), Tv(4928)]),
-    ((4931, false, OR2), &[Arg(3, 425), Tv(1435)]),
-    ((5034, false, NAND2), &[Tv(1481), Tv(5033)]),
-    ((5036, false, AND2), &[Tv(1435), Tv(5035)]),
-    ((5038, false, OR2), &[Arg(3, 426), Tv(1435)]),
-    ((5141, false, NAND2), &[Tv(1481), Tv(5140)]),
-    ((5143, false, AND2), &[Tv(1435), Tv(5142)]),
-    ((5145, false, OR2), &[Arg(3, 427), Tv(1435)]),
-    ((5248, false, NAND2), &[Tv(1481), Tv(5247)]),
-    ((5250, false, AND2), &[Tv(1435), Tv(5249)]),
-    ((5252, false, OR2), &[Arg(3, 428), Tv(1435)]),
-    ((5355, false, NAND2), &[Tv(1481), Tv(5354)]),
-    ((5357, false, AND2), &[Tv(1435), Tv(5356)]),
-    ((5359, false, OR2), &[Arg(3, 429), Tv(1435)]),
-    ((5462, false, NAND2), &[Tv(1481), Tv(5461)]),
-    ((5464, false, AND2), &[Tv(1435), Tv(5463)]),
-    ((5466, false, OR2), &[Arg(3, 430), Tv(1435)]),
-    ((5569, false, NAND2), &[Tv(1481), Tv(5568)]),
-    ((5571, false, AND2), &[Tv(1435), Tv(5570)]),
-    ((5573, false, OR2), &[Arg(3, 431), Tv(1435)]),
-    ((5676, false, NAND2), &[Tv(1481), Tv(5675)]),
-    ((5678, false, AND2), &[Tv(1435), Tv(5677)]),
-    ((5680, false, OR2), &[Arg(3, 433), Tv(1435)]),
-    ((5783, false, NAND2), &[Tv(1481), Tv(5782)]),
-    ((5785, false, AND2), &[Tv(1435), Tv(5784)]),
-    ((5787, false, OR2), &[Arg(3, 434), Tv(1435)]),
-    ((5890, false, NAND2), &[Tv(1481), Tv(5889)]),
-    ((5892, false, AND2), &[Tv(1435), Tv(5891)]),
-    ((5894, false, OR2), &[Arg(3, 435), Tv(1435)]),
-    ((5997, false, NAND2), &[Tv(1481), Tv(5996)]),
-    ((5999, false, AND2), &[Tv(1435), Tv(5998)]),
-    ((6001, false, OR2), &[Arg(3, 436), Tv(1435)]),
-    ((6104, false, NAND2), &[Tv(1481), Tv(6103)]),
-    ((6106, false, AND2), &[Tv(1435), Tv(6105)]),
-    ((6108, false, OR2), &[Arg(3, 437), Tv(1435)]),
-    ((6211, false, NAND2), &[Tv(1481), Tv(6210)]),
-    ((6213, false, AND2), &[Tv(1435), Tv(6212)]),
-    ((6215, false, OR2), &[Arg(3, 438), Tv(1435)]),
-    ((6318, false, NAND2), &[Tv(1481), Tv(6317)]),
-    ((6320, false, AND2), &[Tv(1435), Tv(6319)]),
-    ((6322, false, OR2), &[Arg(3, 439), Tv(1435)]),
-    ((6425, false, NAND2), &[Tv(1481), Tv(6424)]),
-    ((6427, false, AND2), &[Tv(1435), Tv(6426)]),
-    ((6429, false, OR2), &[Arg(3, 440), Tv(1435)]),
-    ((743, false, XNOR2), &[Arg(1, 10), Arg(4, 114)]),
-    ((746, false, XNOR2), &[Arg(1, 0), Arg(4, 104)]),
-    ((763, false, AND2), &[Tv(759), Tv(762)]),
-    ((771, false, AND2), &[Tv(768), Tv(770)]),
-    ((780, false, XNOR2), &[Arg(1, 0), Arg(4, 56)]),
-    ((787, false, XNOR2), &[Arg(1, 10), Arg(4, 66)]),
-    ((792, false, AND2), &[Tv(789), Tv(791)]),
-    ((794, false, AND2), &[Tv(786), Tv(793)]),
-    ((802, false, AND2), &[Tv(799), Tv(801)]),
-    ((804, false, AND2), &[Tv(782), Tv(803)]),
-    ((828, false, AND2), &[Tv(826), Tv(827)]),
-    ((831, false, AND2), &[Tv(829), Tv(830)]),
-    ((836, false, AND2), &[Tv(834), Tv(835)]),
-    ((838, false, AND2), &[Tv(823), Tv(837)]),
-    ((849, false, XNOR2), &[Arg(1, 1), Arg(4, 153)]),
-    ((853, false, XNOR2), &[Arg(1, 14), Arg(4, 166)]),
-    ((856, false, AND2), &[Tv(846), Tv(855)]),
-    ((858, false, AND2), &[Tv(844), Tv(852)]),
-    ((860, false, AND2), &[Tv(848), Tv(857)]),
-    ((866, false, AND2), &[Tv(850), Tv(865)]),
-    ((868, false, AND2), &[Tv(851), Tv(867)]),
-    ((870, false, AND2), &[Tv(842), Tv(843)]),
-    ((1074, false, NAND2), &[Tv(1066), Tv(1073)]),
-    ((1172, false, AND2), &[Tv(1125), Tv(1171)]),
-    ((2082, false, AND2), &[Tv(2026), Tv(2081)]),
-    ((2205, false, AND2), &[Tv(2139), Tv(2204)]),
-    ((2602, false, AND2), &[Tv(2542), Tv(2601)]),
-    ((2705, false, AND2), &[Tv(2659), Tv(2704)]),
+static LEVEL_17: [((usize, bool, CellType), &[GateInput]); 67] = [
+    ((5432, false, XOR2), &[Tv(4940), Tv(5431)]),
+    ((5435, false, XOR2), &[Tv(4943), Tv(5434)]),
+    ((5438, false, XOR2), &[Tv(4946), Tv(5437)]),
+    ((5441, false, XOR2), &[Tv(4949), Tv(5440)]),
+    ((5444, false, XOR2), &[Tv(4952), Tv(5443)]),
+    ((5447, false, XOR2), &[Tv(4955), Tv(5446)]),
+    ((5450, false, XOR2), &[Tv(4958), Tv(5449)]),
+    ((5453, false, XOR2), &[Tv(4961), Tv(5452)]),
+    ((5456, false, XOR2), &[Tv(4964), Tv(5455)]),
+    ((5459, false, XOR2), &[Tv(4967), Tv(5458)]),
+    ((5462, false, XOR2), &[Tv(4970), Tv(5461)]),
+    ((5465, false, XOR2), &[Tv(4973), Tv(5464)]),
+    ((5468, false, XOR2), &[Tv(4976), Tv(5467)]),
+    ((5471, false, XOR2), &[Tv(4979), Tv(5470)]),
+    ((5474, false, XOR2), &[Tv(4982), Tv(5473)]),
+    ((5477, false, XOR2), &[Tv(4985), Tv(5476)]),
+    ((5480, false, XOR2), &[Tv(4988), Tv(5479)]),
+    ((5483, false, XOR2), &[Tv(4991), Tv(5482)]),
+    ((5486, false, XOR2), &[Tv(4994), Tv(5485)]),
+    ((5489, false, XOR2), &[Tv(4997), Tv(5488)]),
+    ((5492, false, XOR2), &[Tv(5000), Tv(5491)]),
+    ((5495, false, XOR2), &[Tv(5003), Tv(5494)]),
+    ((5498, false, XOR2), &[Tv(5006), Tv(5497)]),
+    ((5501, false, XOR2), &[Tv(5009), Tv(5500)]),
+    ((5504, false, XOR2), &[Tv(5012), Tv(5503)]),
+    ((5507, false, XOR2), &[Tv(5015), Tv(5506)]),
+    ((5510, false, XOR2), &[Tv(5018), Tv(5509)]),
+    ((5513, false, XOR2), &[Tv(5021), Tv(5512)]),
+    ((5516, false, XOR2), &[Tv(5024), Tv(5515)]),
+    ((5519, false, XOR2), &[Tv(5027), Tv(5518)]),
+    ((5522, false, XOR2), &[Tv(5030), Tv(5521)]),
+    ((5525, false, XOR2), &[Tv(5033), Tv(5524)]),
+    ((5531, false, XOR2), &[Tv(5137), Tv(5530)]),
+    ((5534, false, XOR2), &[Tv(5140), Tv(5533)]),
+    ((5537, false, XOR2), &[Tv(5143), Tv(5536)]),
+    ((5540, false, XOR2), &[Tv(5146), Tv(5539)]),
+    ((5543, false, XOR2), &[Tv(5149), Tv(5542)]),
+    ((5546, false, XOR2), &[Tv(5152), Tv(5545)]),
+    ((5549, false, XOR2), &[Tv(5155), Tv(5548)]),
+    ((5552, false, XOR2), &[Tv(5158), Tv(5551)]),
+    ((5555, false, XOR2), &[Tv(5161), Tv(5554)]),
+    ((5558, false, XOR2), &[Tv(5164), Tv(5557)]),
+    ((5561, false, XOR2), &[Tv(5167), Tv(5560)]),
+    ((5564, false, XOR2), &[Tv(5170), Tv(5563)]),
+    ((5567, false, XOR2), &[Tv(5173), Tv(5566)]),
+    ((5570, false, XOR2), &[Tv(5176), Tv(5569)]),
+    ((5573, false, XOR2), &[Tv(5179), Tv(5572)]),
+    ((5576, false, XOR2), &[Tv(5182), Tv(5575)]),
+    ((5579, false, XOR2), &[Tv(5185), Tv(5578)]),
+    ((5582, false, XOR2), &[Tv(5188), Tv(5581)]),
+    ((5585, false, XOR2), &[Tv(5191), Tv(5584)]),
+    ((5588, false, XOR2), &[Tv(5194), Tv(5587)]),
+    ((5591, false, XOR2), &[Tv(5197), Tv(5590)]),
+    ((5594, false, XOR2), &[Tv(5200), Tv(5593)]),
+    ((5597, false, XOR2), &[Tv(5203), Tv(5596)]),
+    ((5600, false, XOR2), &[Tv(5206), Tv(5599)]),
+    ((5603, false, XOR2), &[Tv(5209), Tv(5602)]),
+    ((5606, false, XOR2), &[Tv(5212), Tv(5605)]),
+    ((5609, false, XOR2), &[Tv(5215), Tv(5608)]),
+    ((5612, false, XOR2), &[Tv(5218), Tv(5611)]),
+    ((5615, false, XOR2), &[Tv(5221), Tv(5614)]),
+    ((5618, false, XOR2), &[Tv(5224), Tv(5617)]),
+    ((5621, false, XOR2), &[Tv(5227), Tv(5620)]),
+    ((5624, false, XOR2), &[Tv(5230), Tv(5623)]),
+    ((5729, false, XNOR2), &[Tv(5627), Tv(5728)]),
+    ((6060, false, AND2), &[Tv(6058), Tv(6059)]),
+    ((6257, false, AND2), &[Tv(6255), Tv(6256)]),
 ];
 
-static LEVEL_38: [((usize, bool, CellType), &[GateInput]); 161] = [
-    ((2821, false, AND2), &[Tv(2705), Tv(2820)]),
-    ((2917, false, XOR2), &[Arg(0, 0), Arg(1, 0)]),
-    ((2951, false, XNOR2), &[Tv(2920), Tv(2950)]),
-    ((2955, false, NOR2), &[Tv(2857), Tv(2954)]),
-    ((2959, false, XNOR2), &[Tv(2919), Tv(2958)]),
-    ((2965, false, AND2), &[Tv(2963), Tv(2964)]),
-    ((2969, false, XNOR2), &[Tv(2858), Tv(2968)]),
-    ((2977, false, XNOR2), &[Tv(2864), Tv(2976)]),
-    ((2985, false, NAND2), &[Tv(2983), Tv(2984)]),
-    ((2991, false, OR2), &[Tv(2866), Tv(2990)]),
-    ((2995, false, AND2), &[Tv(2934), Tv(2994)]),
-    ((2997, false, XNOR2), &[Tv(2861), Tv(2980)]),
-    ((2999, false, XNOR2), &[Tv(2854), Tv(2962)]),
-    ((3007, false, XNOR2), &[Tv(2891), Tv(2947)]),
-    ((3110, false, NAND2), &[Tv(3107), Tv(3109)]),
-    ((3112, false, AND2), &[Tv(1172), Tv(3111)]),
-    ((3115, false, AND2), &[Tv(1074), Tv(3114)]),
-    ((3117, false, NAND2), &[Arg(3, 441), Tv(3116)]),
-    ((3218, false, NAND2), &[Tv(3215), Tv(3217)]),
-    ((3220, false, AND2), &[Tv(1172), Tv(3219)]),
-    ((3222, false, NAND2), &[Arg(3, 491), Tv(1124)]),
-    ((3223, false, NAND2), &[Arg(3, 442), Tv(3116)]),
-    ((3325, false, NAND2), &[Tv(3322), Tv(3324)]),
-    ((3327, false, AND2), &[Tv(1172), Tv(3326)]),
-    ((3329, false, NAND2), &[Arg(3, 492), Tv(1124)]),
-    ((3330, false, NAND2), &[Arg(3, 443), Tv(3116)]),
-    ((3432, false, NAND2), &[Tv(3429), Tv(3431)]),
-    ((3434, false, AND2), &[Tv(1172), Tv(3433)]),
-    ((3436, false, NAND2), &[Arg(3, 493), Tv(1124)]),
-    ((3437, false, NAND2), &[Arg(3, 444), Tv(3116)]),
-    ((3539, false, NAND2), &[Tv(3536), Tv(3538)]),
-    ((3541, false, AND2), &[Tv(1172), Tv(3540)]),
-    ((3543, false, NAND2), &[Arg(3, 494), Tv(1124)]),
-    ((3544, false, NAND2), &[Arg(3, 445), Tv(3116)]),
-    ((3646, false, NAND2), &[Tv(3643), Tv(3645)]),
-    ((3648, false, AND2), &[Tv(1172), Tv(3647)]),
-    ((3650, false, NAND2), &[Arg(3, 495), Tv(1124)]),
-    ((3651, false, NAND2), &[Arg(3, 446), Tv(3116)]),
-    ((3753, false, NAND2), &[Tv(3750), Tv(3752)]),
-    ((3755, false, AND2), &[Tv(1172), Tv(3754)]),
-    ((3757, false, NAND2), &[Arg(3, 496), Tv(1124)]),
-    ((3758, false, NAND2), &[Arg(3, 447), Tv(3116)]),
-    ((3860, false, NAND2), &[Tv(3857), Tv(3859)]),
-    ((3862, false, AND2), &[Tv(1172), Tv(3861)]),
-    ((3864, false, NAND2), &[Arg(3, 497), Tv(1124)]),
-    ((3865, false, NAND2), &[Arg(3, 448), Tv(3116)]),
-    ((3967, false, NAND2), &[Tv(3964), Tv(3966)]),
-    ((3969, false, AND2), &[Tv(1172), Tv(3968)]),
-    ((3971, false, NAND2), &[Arg(3, 514), Tv(1124)]),
-    ((3972, false, NAND2), &[Arg(3, 465), Tv(3116)]),
-    ((4074, false, NAND2), &[Tv(4071), Tv(4073)]),
-    ((4076, false, AND2), &[Tv(1172), Tv(4075)]),
-    ((4078, false, NAND2), &[Arg(3, 515), Tv(1124)]),
-    ((4079, false, NAND2), &[Arg(3, 466), Tv(3116)]),
-    ((4181, false, NAND2), &[Tv(4178), Tv(4180)]),
-    ((4183, false, AND2), &[Tv(1172), Tv(4182)]),
-    ((4185, false, NAND2), &[Arg(3, 516), Tv(1124)]),
-    ((4186, false, NAND2), &[Arg(3, 467), Tv(3116)]),
-    ((4288, false, NAND2), &[Tv(4285), Tv(4287)]),
-    ((4290, false, AND2), &[Tv(1172), Tv(4289)]),
-    ((4292, false, NAND2), &[Arg(3, 517), Tv(1124)]),
-    ((4293, false, NAND2), &[Arg(3, 468), Tv(3116)]),
-    ((4395, false, NAND2), &[Tv(4392), Tv(4394)]),
-    ((4397, false, AND2), &[Tv(1172), Tv(4396)]),
-    ((4399, false, NAND2), &[Arg(3, 518), Tv(1124)]),
-    ((4400, false, NAND2), &[Arg(3, 469), Tv(3116)]),
-    ((4502, false, NAND2), &[Tv(4499), Tv(4501)]),
-    ((4504, false, AND2), &[Tv(1172), Tv(4503)]),
-    ((4506, false, NAND2), &[Arg(3, 519), Tv(1124)]),
-    ((4507, false, NAND2), &[Arg(3, 470), Tv(3116)]),
-    ((4609, false, NAND2), &[Tv(4606), Tv(4608)]),
-    ((4611, false, AND2), &[Tv(1172), Tv(4610)]),
-    ((4613, false, NAND2), &[Arg(3, 520), Tv(1124)]),
-    ((4614, false, NAND2), &[Arg(3, 471), Tv(3116)]),
-    ((4716, false, NAND2), &[Tv(4713), Tv(4715)]),
-    ((4718, false, AND2), &[Tv(1172), Tv(4717)]),
-    ((4720, false, NAND2), &[Arg(3, 521), Tv(1124)]),
-    ((4721, false, NAND2), &[Arg(3, 472), Tv(3116)]),
-    ((4823, false, NAND2), &[Tv(4820), Tv(4822)]),
-    ((4825, false, AND2), &[Tv(1172), Tv(4824)]),
-    ((4827, false, NAND2), &[Arg(3, 522), Tv(1124)]),
-    ((4828, false, NAND2), &[Arg(3, 473), Tv(3116)]),
-    ((4930, false, NAND2), &[Tv(4927), Tv(4929)]),
-    ((4932, false, AND2), &[Tv(1172), Tv(4931)]),
-    ((4934, false, NAND2), &[Arg(3, 523), Tv(1124)]),
-    ((4935, false, NAND2), &[Arg(3, 474), Tv(3116)]),
-    ((5037, false, NAND2), &[Tv(5034), Tv(5036)]),
-    ((5039, false, AND2), &[Tv(1172), Tv(5038)]),
-    ((5041, false, NAND2), &[Arg(3, 524), Tv(1124)]),
-    ((5042, false, NAND2), &[Arg(3, 475), Tv(3116)]),
-    ((5144, false, NAND2), &[Tv(5141), Tv(5143)]),
-    ((5146, false, AND2), &[Tv(1172), Tv(5145)]),
-    ((5148, false, NAND2), &[Arg(3, 525), Tv(1124)]),
-    ((5149, false, NAND2), &[Arg(3, 476), Tv(3116)]),
-    ((5251, false, NAND2), &[Tv(5248), Tv(5250)]),
-    ((5253, false, AND2), &[Tv(1172), Tv(5252)]),
-    ((5255, false, NAND2), &[Arg(3, 526), Tv(1124)]),
-    ((5256, false, NAND2), &[Arg(3, 477), Tv(3116)]),
-    ((5358, false, NAND2), &[Tv(5355), Tv(5357)]),
-    ((5360, false, AND2), &[Tv(1172), Tv(5359)]),
-    ((5362, false, NAND2), &[Arg(3, 527), Tv(1124)]),
-    ((5363, false, NAND2), &[Arg(3, 478), Tv(3116)]),
-    ((5465, false, NAND2), &[Tv(5462), Tv(5464)]),
-    ((5467, false, AND2), &[Tv(1172), Tv(5466)]),
-    ((5469, false, NAND2), &[Arg(3, 528), Tv(1124)]),
-    ((5470, false, NAND2), &[Arg(3, 479), Tv(3116)]),
-    ((5572, false, NAND2), &[Tv(5569), Tv(5571)]),
-    ((5574, false, AND2), &[Tv(1172), Tv(5573)]),
-    ((5576, false, NAND2), &[Arg(3, 529), Tv(1124)]),
-    ((5577, false, NAND2), &[Arg(3, 480), Tv(3116)]),
-    ((5679, false, NAND2), &[Tv(5676), Tv(5678)]),
-    ((5681, false, AND2), &[Tv(1172), Tv(5680)]),
-    ((5683, false, NAND2), &[Arg(3, 531), Tv(1124)]),
-    ((5684, false, NAND2), &[Arg(3, 482), Tv(3116)]),
-    ((5786, false, NAND2), &[Tv(5783), Tv(5785)]),
-    ((5788, false, AND2), &[Tv(1172), Tv(5787)]),
-    ((5790, false, NAND2), &[Arg(3, 532), Tv(1124)]),
-    ((5791, false, NAND2), &[Arg(3, 483), Tv(3116)]),
-    ((5893, false, NAND2), &[Tv(5890), Tv(5892)]),
-    ((5895, false, AND2), &[Tv(1172), Tv(5894)]),
-    ((5897, false, NAND2), &[Arg(3, 533), Tv(1124)]),
-    ((5898, false, NAND2), &[Arg(3, 484), Tv(3116)]),
-    ((6000, false, NAND2), &[Tv(5997), Tv(5999)]),
-    ((6002, false, AND2), &[Tv(1172), Tv(6001)]),
-    ((6004, false, NAND2), &[Arg(3, 534), Tv(1124)]),
-    ((6005, false, NAND2), &[Arg(3, 485), Tv(3116)]),
-    ((6107, false, NAND2), &[Tv(6104), Tv(6106)]),
-    ((6109, false, AND2), &[Tv(1172), Tv(6108)]),
-    ((6111, false, NAND2), &[Arg(3, 535), Tv(1124)]),
-    ((6112, false, NAND2), &[Arg(3, 486), Tv(3116)]),
-    ((6214, false, NAND2), &[Tv(6211), Tv(6213)]),
-    ((6216, false, AND2), &[Tv(1172), Tv(6215)]),
-    ((6218, false, NAND2), &[Arg(3, 536), Tv(1124)]),
-    ((6219, false, NAND2), &[Arg(3, 487), Tv(3116)]),
-    ((6321, false, NAND2), &[Tv(6318), Tv(6320)]),
-    ((6323, false, AND2), &[Tv(1172), Tv(6322)]),
-    ((6325, false, NAND2), &[Arg(3, 537), Tv(1124)]),
-    ((6326, false, NAND2), &[Arg(3, 488), Tv(3116)]),
-    ((6428, false, NAND2), &[Tv(6425), Tv(6427)]),
-    ((6430, false, AND2), &[Tv(1172), Tv(6429)]),
-    ((6432, false, NAND2), &[Arg(3, 538), Tv(1124)]),
-    ((6433, false, NAND2), &[Arg(3, 489), Tv(3116)]),
-    ((756, false, AND2), &[Tv(743), Tv(746)]),
-    ((772, false, AND2), &[Tv(763), Tv(771)]),
-    ((778, false, XNOR2), &[Arg(1, 11), Arg(4, 67)]),
-    ((795, false, AND2), &[Tv(792), Tv(794)]),
-    ((796, false, AND2), &[Tv(780), Tv(787)]),
-    ((805, false, AND2), &[Tv(802), Tv(804)]),
-    ((816, false, XNOR2), &[Arg(1, 0), Arg(4, 8)]),
-    ((818, false, XNOR2), &[Arg(1, 10), Arg(4, 18)]),
-    ((832, false, AND2), &[Tv(828), Tv(831)]),
-    ((839, false, AND2), &[Tv(836), Tv(838)]),
-    ((847, false, XNOR2), &[Arg(1, 0), Arg(4, 152)]),
-    ((854, false, XNOR2), &[Arg(1, 10), Arg(4, 162)]),
-    ((859, false, AND2), &[Tv(856), Tv(858)]),
-    ((861, false, AND2), &[Tv(853), Tv(860)]),
-    ((869, false, AND2), &[Tv(866), Tv(868)]),
-    ((871, false, AND2), &[Tv(849), Tv(870)]),
-    ((2206, false, AND2), &[Tv(2082), Tv(2205)]),
-    ((2429, false, AND2), &[Tv(2310), Tv(2428)]),
-    ((2603, false, AND2), &[Tv(2487), Tv(2602)]),
+static LEVEL_18: [((usize, bool, CellType), &[GateInput]); 34] = [
+    ((5628, false, XOR2), &[Tv(5432), Tv(5531)]),
+    ((5632, false, XOR2), &[Tv(5435), Tv(5534)]),
+    ((5635, false, XOR2), &[Tv(5438), Tv(5537)]),
+    ((5638, false, XOR2), &[Tv(5441), Tv(5540)]),
+    ((5641, false, XOR2), &[Tv(5444), Tv(5543)]),
+    ((5644, false, XOR2), &[Tv(5447), Tv(5546)]),
+    ((5647, false, XOR2), &[Tv(5450), Tv(5549)]),
+    ((5650, false, XOR2), &[Tv(5453), Tv(5552)]),
+    ((5653, false, XOR2), &[Tv(5456), Tv(5555)]),
+    ((5656, false, XOR2), &[Tv(5459), Tv(5558)]),
+    ((5659, false, XOR2), &[Tv(5462), Tv(5561)]),
+    ((5662, false, XOR2), &[Tv(5465), Tv(5564)]),
+    ((5665, false, XOR2), &[Tv(5468), Tv(5567)]),
+    ((5668, false, XOR2), &[Tv(5471), Tv(5570)]),
+    ((5671, false, XOR2), &[Tv(5474), Tv(5573)]),
+    ((5674, false, XOR2), &[Tv(5477), Tv(5576)]),
+    ((5677, false, XOR2), &[Tv(5480), Tv(5579)]),
+    ((5680, false, XOR2), &[Tv(5483), Tv(5582)]),
+    ((5683, false, XOR2), &[Tv(5486), Tv(5585)]),
+    ((5686, false, XOR2), &[Tv(5489), Tv(5588)]),
+    ((5689, false, XOR2), &[Tv(5492), Tv(5591)]),
+    ((5692, false, XOR2), &[Tv(5495), Tv(5594)]),
+    ((5695, false, XOR2), &[Tv(5498), Tv(5597)]),
+    ((5698, false, XOR2), &[Tv(5501), Tv(5600)]),
+    ((5701, false, XOR2), &[Tv(5504), Tv(5603)]),
+    ((5704, false, XOR2), &[Tv(5507), Tv(5606)]),
+    ((5707, false, XOR2), &[Tv(5510), Tv(5609)]),
+    ((5710, false, XOR2), &[Tv(5513), Tv(5612)]),
+    ((5713, false, XOR2), &[Tv(5516), Tv(5615)]),
+    ((5716, false, XOR2), &[Tv(5519), Tv(5618)]),
+    ((5719, false, XOR2), &[Tv(5522), Tv(5621)]),
+    ((5722, false, XOR2), &[Tv(5525), Tv(5624)]),
+    ((5827, false, INV), &[Tv(5729)]),
+    ((6258, false, AND2), &[Tv(6060), Tv(6257)]),
 ];
 
-static LEVEL_39: [((usize, bool, CellType), &[GateInput]); 121] = [
-    ((2822, false, AND2), &[Tv(2603), Tv(2821)]),
-    ((2885, false, NAND2), &[Tv(2873), Tv(2884)]),
-    ((2886, false, OR2), &[Tv(2852), Tv(2873)]),
-    ((2952, false, NAND2), &[Tv(2917), Tv(2951)]),
-    ((2970, false, NAND2), &[Tv(2908), Tv(2936)]),
-    ((2971, false, NAND2), &[Tv(2935), Tv(2942)]),
-    ((2986, false, XNOR2), &[Tv(2925), Tv(2985)]),
-    ((2996, false, OR2), &[Tv(2923), Tv(2995)]),
-    ((2998, false, XNOR2), &[Tv(2914), Tv(2965)]),
-    ((3002, false, NOR2), &[Tv(2955), Tv(2999)]),
-    ((3004, false, OR2), &[Tv(2959), Tv(2997)]),
-    ((3008, false, AND2), &[Tv(2991), Tv(3007)]),
-    ((3010, false, AND2), &[Tv(2969), Tv(2977)]),
-    ((3113, false, NAND2), &[Tv(3110), Tv(3112)]),
-    ((3118, false, AND2), &[Tv(3115), Tv(3117)]),
-    ((3221, false, NAND2), &[Tv(3218), Tv(3220)]),
-    ((3224, false, AND2), &[Tv(3222), Tv(3223)]),
-    ((3328, false, NAND2), &[Tv(3325), Tv(3327)]),
-    ((3331, false, AND2), &[Tv(3329), Tv(3330)]),
-    ((3435, false, NAND2), &[Tv(3432), Tv(3434)]),
-    ((3438, false, AND2), &[Tv(3436), Tv(3437)]),
-    ((3542, false, NAND2), &[Tv(3539), Tv(3541)]),
-    ((3545, false, AND2), &[Tv(3543), Tv(3544)]),
-    ((3649, false, NAND2), &[Tv(3646), Tv(3648)]),
-    ((3652, false, AND2), &[Tv(3650), Tv(3651)]),
-    ((3756, false, NAND2), &[Tv(3753), Tv(3755)]),
-    ((3759, false, AND2), &[Tv(3757), Tv(3758)]),
-    ((3863, false, NAND2), &[Tv(3860), Tv(3862)]),
-    ((3866, false, AND2), &[Tv(3864), Tv(3865)]),
-    ((3970, false, NAND2), &[Tv(3967), Tv(3969)]),
-    ((3973, false, AND2), &[Tv(3971), Tv(3972)]),
-    ((4077, false, NAND2), &[Tv(4074), Tv(4076)]),
-    ((4080, false, AND2), &[Tv(4078), Tv(4079)]),
-    ((4184, false, NAND2), &[Tv(4181), Tv(4183)]),
-    ((4187, false, AND2), &[Tv(4185), Tv(4186)]),
-    ((4291, false, NAND2), &[Tv(4288), Tv(4290)]),
-    ((4294, false, AND2), &[Tv(4292), Tv(4293)]),
-    ((4398, false, NAND2), &[Tv(4395), Tv(4397)]),
-    ((4401, false, AND2), &[Tv(4399), Tv(4400)]),
-    ((4505, false, NAND2), &[Tv(4502), Tv(4504)]),
-    ((4508, false, AND2), &[Tv(4506), Tv(4507)]),
-    ((4612, false, NAND2), &[Tv(4609), Tv(4611)]),
-    ((4615, false, AND2), &[Tv(4613), Tv(4614)]),
-    ((4719, false, NAND2), &[Tv(4716), Tv(4718)]),
-    ((4722, false, AND2), &[Tv(4720), Tv(4721)]),
-    ((4826, false, NAND2), &[Tv(4823), Tv(4825)]),
-    ((4829, false, AND2), &[Tv(4827), Tv(4828)]),
-    ((4933, false, NAND2), &[Tv(4930), Tv(4932)]),
-    ((4936, false, AND2), &[Tv(4934), Tv(4935)]),
-    ((5040, false, NAND2), &[Tv(5037), Tv(5039)]),
-    ((5043, false, AND2), &[Tv(5041), Tv(5042)]),
-    ((5147, false, NAND2), &[Tv(5144), Tv(5146)]),
-    ((5150, false, AND2), &[Tv(5148), Tv(5149)]),
-    ((5254, false, NAND2), &[Tv(5251), Tv(5253)]),
-    ((5257, false, AND2), &[Tv(5255), Tv(5256)]),
-    ((5361, false, NAND2), &[Tv(5358), Tv(5360)]),
-    ((5364, false, AND2), &[Tv(5362), Tv(5363)]),
-    ((5468, false, NAND2), &[Tv(5465), Tv(5467)]),
-    ((5471, false, AND2), &[Tv(5469), Tv(5470)]),
-    ((5575, false, NAND2), &[Tv(5572), Tv(5574)]),
-    ((5578, false, AND2), &[Tv(5576), Tv(5577)]),
-    ((5682, false, NAND2), &[Tv(5679), Tv(5681)]),
-    ((5685, false, AND2), &[Tv(5683), Tv(5684)]),
-    ((5789, false, NAND2), &[Tv(5786), Tv(5788)]),
-    ((5792, false, AND2), &[Tv(5790), Tv(5791)]),
-    ((5896, false, NAND2), &[Tv(5893), Tv(5895)]),
-    ((5899, false, AND2), &[Tv(5897), Tv(5898)]),
-    ((6003, false, NAND2), &[Tv(6000), Tv(6002)]),
-    ((6006, false, AND2), &[Tv(6004), Tv(6005)]),
-    ((6110, false, NAND2), &[Tv(6107), Tv(6109)]),
-    ((6113, false, AND2), &[Tv(6111), Tv(6112)]),
-    ((6217, false, NAND2), &[Tv(6214), Tv(6216)]),
-    ((6220, false, AND2), &[Tv(6218), Tv(6219)]),
-    ((6324, false, NAND2), &[Tv(6321), Tv(6323)]),
-    ((6327, false, AND2), &[Tv(6325), Tv(6326)]),
-    ((6431, false, NAND2), &[Tv(6428), Tv(6430)]),
-    ((6434, false, AND2), &[Tv(6432), Tv(6433)]),
-    ((158, false, INV), &[Arg(3, 540)]),
-    ((159, false, INV), &[Arg(3, 541)]),
-    ((160, false, INV), &[Arg(3, 542)]),
-    ((161, false, INV), &[Arg(3, 543)]),
-    ((162, false, INV), &[Arg(3, 544)]),
-    ((163, false, INV), &[Arg(3, 545)]),
-    ((164, false, INV), &[Arg(3, 546)]),
-    ((181, false, INV), &[Arg(3, 563)]),
-    ((182, false, INV), &[Arg(3, 564)]),
-    ((183, false, INV), &[Arg(3, 565)]),
-    ((184, false, INV), &[Arg(3, 566)]),
-    ((185, false, INV), &[Arg(3, 567)]),
-    ((186, false, INV), &[Arg(3, 568)]),
-    ((187, false, INV), &[Arg(3, 569)]),
-    ((188, false, INV), &[Arg(3, 570)]),
-    ((277, false, INV), &[Arg(3, 571)]),
-    ((278, false, INV), &[Arg(3, 572)]),
-    ((279, false, INV), &[Arg(3, 573)]),
-    ((280, false, INV), &[Arg(3, 574)]),
-    ((281, false, INV), &[Arg(3, 575)]),
-    ((282, false, INV), &[Arg(3, 576)]),
-    ((283, false, INV), &[Arg(3, 577)]),
-    ((284, false, INV), &[Arg(3, 578)]),
-    ((373, false, INV), &[Arg(3, 580)]),
-    ((374, false, INV), &[Arg(3, 581)]),
-    ((375, false, INV), &[Arg(3, 582)]),
-    ((376, false, INV), &[Arg(3, 583)]),
-    ((377, false, INV), &[Arg(3, 584)]),
-    ((378, false, INV), &[Arg(3, 585)]),
-    ((379, false, INV), &[Arg(3, 586)]),
-    ((380, false, INV), &[Arg(3, 587)]),
-    ((773, false, AND2), &[Tv(756), Tv(772)]),
-    ((797, false, AND2), &[Tv(795), Tv(796)]),
-    ((806, false, AND2), &[Tv(778), Tv(805)]),
-    ((825, false, AND2), &[Tv(816), Tv(818)]),
-    ((840, false, AND2), &[Tv(832), Tv(839)]),
-    ((845, false, XNOR2), &[Arg(1, 11), Arg(4, 163)]),
-    ((862, false, AND2), &[Tv(859), Tv(861)]),
-    ((863, false, AND2), &[Tv(847), Tv(854)]),
-    ((872, false, AND2), &[Tv(869), Tv(871)]),
-    ((1485, false, AND2), &[Tv(1074), Tv(1481)]),
-    ((1729, false, AND2), &[Tv(1608), Tv(1728)]),
-    ((1966, false, AND2), &[Tv(1845), Tv(1965)]),
-    ((2430, false, AND2), &[Tv(2206), Tv(2429)]),
+static LEVEL_19: [((usize, bool, CellType), &[GateInput]); 35] = [
+    ((1, true, AND2), &[Tv(5830), Tv(6258)]),
+    ((2, true, AND2), &[Tv(5725), Tv(6258)]),
+    ((5630, false, AND2), &[Tv(5629), Tv(5628)]),
+    ((5633, false, AND2), &[Tv(5629), Tv(5632)]),
+    ((5636, false, AND2), &[Tv(5629), Tv(5635)]),
+    ((5639, false, AND2), &[Tv(5629), Tv(5638)]),
+    ((5642, false, AND2), &[Tv(5629), Tv(5641)]),
+    ((5645, false, AND2), &[Tv(5629), Tv(5644)]),
+    ((5648, false, AND2), &[Tv(5629), Tv(5647)]),
+    ((5651, false, AND2), &[Tv(5629), Tv(5650)]),
+    ((5654, false, AND2), &[Tv(5629), Tv(5653)]),
+    ((5657, false, AND2), &[Tv(5629), Tv(5656)]),
+    ((5660, false, AND2), &[Tv(5629), Tv(5659)]),
+    ((5663, false, AND2), &[Tv(5629), Tv(5662)]),
+    ((5666, false, AND2), &[Tv(5629), Tv(5665)]),
+    ((5669, false, AND2), &[Tv(5629), Tv(5668)]),
+    ((5672, false, AND2), &[Tv(5629), Tv(5671)]),
+    ((5675, false, AND2), &[Tv(5629), Tv(5674)]),
+    ((5678, false, AND2), &[Tv(5629), Tv(5677)]),
+    ((5681, false, AND2), &[Tv(5629), Tv(5680)]),
+    ((5684, false, AND2), &[Tv(5629), Tv(5683)]),
+    ((5687, false, AND2), &[Tv(5629), Tv(5686)]),
+    ((5690, false, AND2), &[Tv(5629), Tv(5689)]),
+    ((5693, false, AND2), &[Tv(5629), Tv(5692)]),
+    ((5696, false, AND2), &[Tv(5629), Tv(5695)]),
+    ((5699, false, AND2), &[Tv(5629), Tv(5698)]),
+    ((5702, false, AND2), &[Tv(5629), Tv(5701)]),
+    ((5705, false, AND2), &[Tv(5629), Tv(5704)]),
+    ((5708, false, AND2), &[Tv(5629), Tv(5707)]),
+    ((5711, false, AND2), &[Tv(5629), Tv(5710)]),
+    ((5714, false, AND2), &[Tv(5629), Tv(5713)]),
+    ((5717, false, AND2), &[Tv(5629), Tv(5716)]),
+    ((5720, false, AND2), &[Tv(5629), Tv(5719)]),
+    ((5723, false, AND2), &[Tv(5629), Tv(5722)]),
+    ((5828, false, NOR2), &[Tv(5725), Tv(5827)]),
 ];
 
-static LEVEL_40: [((usize, bool, CellType), &[GateInput]); 81] = [
-    ((2823, false, AND2), &[Tv(2430), Tv(2822)]),
-    ((2887, false, AND2), &[Tv(2885), Tv(2886)]),
-    ((2972, false, NAND2), &[Tv(2970), Tv(2971)]),
-    ((3003, false, AND2), &[Tv(2952), Tv(3002)]),
-    ((3005, false, NOR2), &[Tv(2986), Tv(3004)]),
-    ((3009, false, AND2), &[Tv(2998), Tv(3008)]),
-    ((3011, false, AND2), &[Tv(2996), Tv(3010)]),
-    ((3018, false, OR2), &[Arg(3, 539), Tv(1074)]),
-    ((3119, false, NAND2), &[Tv(3113), Tv(3118)]),
-    ((3225, false, NAND2), &[Tv(3221), Tv(3224)]),
-    ((3227, false, OR2), &[Tv(158), Tv(1074)]),
-    ((3332, false, NAND2), &[Tv(3328), Tv(3331)]),
-    ((3334, false, OR2), &[Tv(159), Tv(1074)]),
-    ((3439, false, NAND2), &[Tv(3435), Tv(3438)]),
-    ((3441, false, OR2), &[Tv(160), Tv(1074)]),
-    ((3546, false, NAND2), &[Tv(3542), Tv(3545)]),
-    ((3548, false, OR2), &[Tv(161), Tv(1074)]),
-    ((3653, false, NAND2), &[Tv(3649), Tv(3652)]),
-    ((3655, false, OR2), &[Tv(162), Tv(1074)]),
-    ((3760, false, NAND2), &[Tv(3756), Tv(3759)]),
-    ((3762, false, OR2), &[Tv(163), Tv(1074)]),
-    ((3867, false, NAND2), &[Tv(3863), Tv(3866)]),
-    ((3869, false, OR2), &[Tv(164), Tv(1074)]),
-    ((3974, false, NAND2), &[Tv(3970), Tv(3973)]),
-    ((3976, false, OR2), &[Tv(181), Tv(1074)]),
-    ((4081, false, NAND2), &[Tv(4077), Tv(4080)]),
-    ((4083, false, OR2), &[Tv(182), Tv(1074)]),
-    ((4188, false, NAND2), &[Tv(4184), Tv(4187)]),
-    ((4190, false, OR2), &[Tv(183), Tv(1074)]),
-    ((4295, false, NAND2), &[Tv(4291), Tv(4294)]),
-    ((4297, false, OR2), &[Tv(184), Tv(1074)]),
-    ((4402, false, NAND2), &[Tv(4398), Tv(4401)]),
-    ((4404, false, OR2), &[Tv(185), Tv(1074)]),
-    ((4509, false, NAND2), &[Tv(4505), Tv(4508)]),
-    ((4511, false, OR2), &[Tv(186), Tv(1074)]),
-    ((4616, false, NAND2), &[Tv(4612), Tv(4615)]),
-    ((4618, false, OR2), &[Tv(187), Tv(1074)]),
-    ((4723, false, NAND2), &[Tv(4719), Tv(4722)]),
-    ((4725, false, OR2), &[Tv(188), Tv(1074)]),
-    ((4830, false, NAND2), &[Tv(4826), Tv(4829)]),
-    ((4832, false, OR2), &[Tv(277), Tv(1074)]),
-    ((4937, false, NAND2), &[Tv(4933), Tv(4936)]),
-    ((4939, false, OR2), &[Tv(278), Tv(1074)]),
-    ((5044, false, NAND2), &[Tv(5040), Tv(5043)]),
-    ((5046, false, OR2), &[Tv(279), Tv(1074)]),
-    ((5151, false, NAND2), &[Tv(5147), Tv(5150)]),
-    ((5153, false, OR2), &[Tv(280), Tv(1074)]),
-    ((5258, false, NAND2), &[Tv(5254), Tv(5257)]),
-    ((5260, false, OR2), &[Tv(281), Tv(1074)]),
-    ((5365, false, NAND2), &[Tv(5361), Tv(5364)]),
-    ((5367, false, OR2), &[Tv(282), Tv(1074)]),
-    ((5472, false, NAND2), &[Tv(5468), Tv(5471)]),
-    ((5474, false, OR2), &[Tv(283), Tv(1074)]),
-    ((5579, false, NAND2), &[Tv(5575), Tv(5578)]),
-    ((5581, false, OR2), &[Tv(284), Tv(1074)]),
-    ((5686, false, NAND2), &[Tv(5682), Tv(5685)]),
-    ((5688, false, OR2), &[Tv(373), Tv(1074)]),
-    ((5793, false, NAND2), &[Tv(5789), Tv(5792)]),
-    ((5795, false, OR2), &[Tv(374), Tv(1074)]),
-    ((5900, false, NAND2), &[Tv(5896), Tv(5899)]),
-    ((5902, false, OR2), &[Tv(375), Tv(1074)]),
-    ((6007, false, NAND2), &[Tv(6003), Tv(6006)]),
-    ((6009, false, OR2), &[Tv(376), Tv(1074)]),
-    ((6114, false, NAND2), &[Tv(6110), Tv(6113)]),
-    ((6116, false, OR2), &[Tv(377), Tv(1074)]),
-    ((6221, false, NAND2), &[Tv(6217), Tv(6220)]),
-    ((6223, false, OR2), &[Tv(378), Tv(1074)]),
-    ((6328, false, NAND2), &[Tv(6324), Tv(6327)]),
-    ((6330, false, OR2), &[Tv(379), Tv(1074)]),
-    ((6435, false, NAND2), &[Tv(6431), Tv(6434)]),
-    ((6437, false, OR2), &[Tv(380), Tv(1074)]),
-    ((774, false, INV), &[Tv(773)]),
-    ((807, false, NAND2), &[Tv(797), Tv(806)]),
-    ((841, false, NAND2), &[Tv(825), Tv(840)]),
-    ((864, false, AND2), &[Tv(862), Tv(863)]),
-    ((873, false, AND2), &[Tv(845), Tv(872)]),
-    ((1273, false, AND2), &[Tv(1172), Tv(1272)]),
-    ((1482, false, AND2), &[Tv(982), Tv(1028)]),
-    ((1483, false, AND2), &[Tv(930), Tv(1435)]),
-    ((1486, false, AND2), &[Tv(1379), Tv(1485)]),
-    ((1967, false, AND2), &[Tv(1729), Tv(1966)]),
+static LEVEL_20: [((usize, bool, CellType), &[GateInput]); 33] = [
+    ((0, true, AND2), &[Tv(5828), Tv(6258)]),
+    ((5631, false, XOR2), &[Tv(5432), Tv(5630)]),
+    ((5634, false, XOR2), &[Tv(5435), Tv(5633)]),
+    ((5637, false, XOR2), &[Tv(5438), Tv(5636)]),
+    ((5640, false, XOR2), &[Tv(5441), Tv(5639)]),
+    ((5643, false, XOR2), &[Tv(5444), Tv(5642)]),
+    ((5646, false, XOR2), &[Tv(5447), Tv(5645)]),
+    ((5649, false, XOR2), &[Tv(5450), Tv(5648)]),
+    ((5652, false, XOR2), &[Tv(5453), Tv(5651)]),
+    ((5655, false, XOR2), &[Tv(5456), Tv(5654)]),
+    ((5658, false, XOR2), &[Tv(5459), Tv(5657)]),
+    ((5661, false, XOR2), &[Tv(5462), Tv(5660)]),
+    ((5664, false, XOR2), &[Tv(5465), Tv(5663)]),
+    ((5667, false, XOR2), &[Tv(5468), Tv(5666)]),
+    ((5670, false, XOR2), &[Tv(5471), Tv(5669)]),
+    ((5673, false, XOR2), &[Tv(5474), Tv(5672)]),
+    ((5676, false, XOR2), &[Tv(5477), Tv(5675)]),
+    ((5679, false, XOR2), &[Tv(5480), Tv(5678)]),
+    ((5682, false, XOR2), &[Tv(5483), Tv(5681)]),
+    ((5685, false, XOR2), &[Tv(5486), Tv(5684)]),
+    ((5688, false, XOR2), &[Tv(5489), Tv(5687)]),
+    ((5691, false, XOR2), &[Tv(5492), Tv(5690)]),
+    ((5694, false, XOR2), &[Tv(5495), Tv(5693)]),
+    ((5697, false, XOR2), &[Tv(5498), Tv(5696)]),
+    ((5700, false, XOR2), &[Tv(5501), Tv(5699)]),
+    ((5703, false, XOR2), &[Tv(5504), Tv(5702)]),
+    ((5706, false, XOR2), &[Tv(5507), Tv(5705)]),
+    ((5709, false, XOR2), &[Tv(5510), Tv(5708)]),
+    ((5712, false, XOR2), &[Tv(5513), Tv(5711)]),
+    ((5715, false, XOR2), &[Tv(5516), Tv(5714)]),
+    ((5718, false, XOR2), &[Tv(5519), Tv(5717)]),
+    ((5721, false, XOR2), &[Tv(5522), Tv(5720)]),
+    ((5724, false, XOR2), &[Tv(5525), Tv(5723)]),
 ];
 
-static LEVEL_41: [((usize, bool, CellType), &[GateInput]); 137] = [
-    ((2824, false, AND2), &[Tv(1967), Tv(2823)]),
-    ((2973, false, XNOR2), &[Tv(2918), Tv(2972)]),
-    ((3000, false, XNOR2), &[Tv(2830), Tv(2887)]),
-    ((3006, false, AND2), &[Tv(3003), Tv(3005)]),
-    ((3012, false, AND2), &[Tv(3009), Tv(3011)]),
-    ((3120, false, NAND2), &[Tv(3018), Tv(3119)]),
-    ((3122, false, OR2), &[Arg(4, 0), Tv(841)]),
-    ((3125, false, NAND2), &[Arg(4, 96), Tv(773)]),
-    ((3127, false, NOR2), &[Tv(773), Tv(807)]),
-    ((3226, false, NAND2), &[Tv(1074), Tv(3225)]),
-    ((3228, false, AND2), &[Tv(841), Tv(3227)]),
-    ((3230, false, OR2), &[Arg(4, 1), Tv(841)]),
-    ((3234, false, NAND2), &[Arg(4, 97), Tv(773)]),
-    ((3333, false, NAND2), &[Tv(1074), Tv(3332)]),
-    ((3335, false, AND2), &[Tv(841), Tv(3334)]),
-    ((3337, false, OR2), &[Arg(4, 2), Tv(841)]),
-    ((3341, false, NAND2), &[Arg(4, 98), Tv(773)]),
-    ((3440, false, NAND2), &[Tv(1074), Tv(3439)]),
-    ((3442, false, AND2), &[Tv(841), Tv(3441)]),
-    ((3444, false, OR2), &[Arg(4, 3), Tv(841)]),
-    ((3448, false, NAND2), &[Arg(4, 99), Tv(773)]),
-    ((3547, false, NAND2), &[Tv(1074), Tv(3546)]),
-    ((3549, false, AND2), &[Tv(841), Tv(3548)]),
-    ((3551, false, OR2), &[Arg(4, 4), Tv(841)]),
-    ((3555, false, NAND2), &[Arg(4, 100), Tv(773)]),
-    ((3654, false, NAND2), &[Tv(1074), Tv(3653)]),
-    ((3656, false, AND2), &[Tv(841), Tv(3655)]),
-    ((3658, false, OR2), &[Arg(4, 5), Tv(841)]),
-    ((3662, false, NAND2), &[Arg(4, 101), Tv(773)]),
-    ((3761, false, NAND2), &[Tv(1074), Tv(3760)]),
-    ((3763, false, AND2), &[Tv(841), Tv(3762)]),
-    ((3765, false, OR2), &[Arg(4, 6), Tv(841)]),
-    ((3769, false, NAND2), &[Arg(4, 102), Tv(773)]),
-    ((3868, false, NAND2), &[Tv(1074), Tv(3867)]),
-    ((3870, false, AND2), &[Tv(841), Tv(3869)]),
-    ((3872, false, OR2), &[Arg(4, 7), Tv(841)]),
-    ((3876, false, NAND2), &[Arg(4, 103), Tv(773)]),
-    ((3975, false, NAND2), &[Tv(1074), Tv(3974)]),
-    ((3977, false, AND2), &[Tv(841), Tv(3976)]),
-    ((3979, false, OR2), &[Arg(4, 24), Tv(841)]),
-    ((3983, false, NAND2), &[Arg(4, 120), Tv(773)]),
-    ((4082, false, NAND2), &[Tv(1074), Tv(4081)]),
-    ((4084, false, AND2), &[Tv(841), Tv(4083)]),
-    ((4086, false, OR2), &[Arg(4, 25), Tv(841)]),
-    ((4090, false, NAND2), &[Arg(4, 121), Tv(773)]),
-    ((4189, false, NAND2), &[Tv(1074), Tv(4188)]),
-    ((4191, false, AND2), &[Tv(841), Tv(4190)]),
-    ((4193, false, OR2), &[Arg(4, 26), Tv(841)]),
-    ((4197, false, NAND2), &[Arg(4, 122), Tv(773)]),
-    ((4296, false, NAND2), &[Tv(1074), Tv(4295)]),
-    ((4298, false, AND2), &[Tv(841), Tv(4297)]),
-    ((4300, false, OR2), &[Arg(4, 27), Tv(841)]),
-    ((4304, false, NAND2), &[Arg(4, 123), Tv(773)]),
-    ((4403, false, NAND2), &[Tv(1074), Tv(4402)]),
-    ((4405, false, AND2), &[Tv(841), Tv(4404)]),
-    ((4407, false, OR2), &[Arg(4, 28), Tv(841)]),
-    ((4411, false, NAND2), &[Arg(4, 124), Tv(773)]),
-    ((4510, false, NAND2), &[Tv(1074), Tv(4509)]),
-    ((4512, false, AND2), &[Tv(841), Tv(4511)]),
-    ((4514, false, OR2), &[Arg(4, 29), Tv(841)]),
-    ((4518, false, NAND2), &[Arg(4, 125), Tv(773)]),
-    ((4617, false, NAND2), &[Tv(1074), Tv(4616)]),
-    ((4619, false, AND2), &[Tv(841), Tv(4618)]),
-    ((4621, false, OR2), &[Arg(4, 30), Tv(841)]),
-    ((4625, false, NAND2), &[Arg(4, 126), Tv(773)]),
-    ((4724, false, NAND2), &[Tv(1074), Tv(4723)]),
-    ((4726, false, AND2), &[Tv(841), Tv(4725)]),
-    ((4728, false, OR2), &[Arg(4, 31), Tv(841)]),
-    ((4732, false, NAND2), &[Arg(4, 127), Tv(773)]),
-    ((4831, false, NAND2), &[Tv(1074), Tv(4830)]),
-    ((4833, false, AND2), &[Tv(841), Tv(4832)]),
-    ((4835, false, OR2), &[Arg(4, 32), Tv(841)]),
-    ((4839, false, NAND2), &[Arg(4, 128), Tv(773)]),
-    ((4938, false, NAND2), &[Tv(1074), Tv(4937)]),
-    ((4940, false, AND2), &[Tv(841), Tv(4939)]),
-    ((4942, false, OR2), &[Arg(4, 33), Tv(841)]),
-    ((4946, false, NAND2), &[Arg(4, 129), Tv(773)]),
-    ((5045, false, NAND2), &[Tv(1074), Tv(5044)]),
-    ((5047, false, AND2), &[Tv(841), Tv(5046)]),
-    ((5049, false, OR2), &[Arg(4, 34), Tv(841)]),
-    ((5053, false, NAND2), &[Arg(4, 130), Tv(773)]),
-    ((5152, false, NAND2), &[Tv(1074), Tv(5151)]),
-    ((5154, false, AND2), &[Tv(841), Tv(5153)]),
-    ((5156, false, OR2), &[Arg(4, 35), Tv(841)]),
-    ((5160, false, NAND2), &[Arg(4, 131), Tv(773)]),
-    ((5259, false, NAND2), &[Tv(1074), Tv(5258)]),
-    ((5261, false, AND2), &[Tv(841), Tv(5260)]),
-    ((5263, false, OR2), &[Arg(4, 36), Tv(841)]),
-    ((5267, false, NAND2), &[Arg(4, 132), Tv(773)]),
-    ((5366, false, NAND2), &[Tv(1074), Tv(5365)]),
-    ((5368, false, AND2), &[Tv(841), Tv(5367)]),
-    ((5370, false, OR2), &[Arg(4, 37), Tv(841)]),
-    ((5374, false, NAND2), &[Arg(4, 133), Tv(773)]),
-    ((5473, false, NAND2), &[Tv(1074), Tv(5472)]),
-    ((5475, false, AND2), &[Tv(841), Tv(5474)]),
-    ((5477, false, OR2), &[Arg(4, 38), Tv(841)]),
-    ((5481, false, NAND2), &[Arg(4, 134), Tv(773)]),
-    ((5580, false, NAND2), &[Tv(1074), Tv(5579)]),
-    ((5582, false, AND2), &[Tv(841), Tv(5581)]),
-    ((5584, false, OR2), &[Arg(4, 39), Tv(841)]),
-    ((5588, false, NAND2), &[Arg(4, 135), Tv(773)]),
-    ((5687, false, NAND2), &[Tv(1074), Tv(5686)]),
-    ((5689, false, AND2), &[Tv(841), Tv(5688)]),
-    ((5691, false, OR2), &[Arg(4, 40), Tv(841)]),
-    ((5695, false, NAND2), &[Arg(4, 136), Tv(773)]),
-    ((5794, false, NAND2), &[Tv(1074), Tv(5793)]),
-    ((5796, false, AND2), &[Tv(841), Tv(5795)]),
-    ((5798, false, OR2), &[Arg(4, 41), Tv(841)]),
-    ((5802, false, NAND2), &[Arg(4, 137), Tv(773)]),
-    ((5901, false, NAND2), &[Tv(1074), Tv(5900)]),
-    ((5903, false, AND2), &[Tv(841), Tv(5902)]),
-    ((5905, false, OR2), &[Arg(4, 42), Tv(841)]),
-    ((5909, false, NAND2), &[Arg(4, 138), Tv(773)]),
-    ((6008, false, NAND2), &[Tv(1074), Tv(6007)]),
-    ((6010, false, AND2), &[Tv(841), Tv(6009)]),
-    ((6012, false, OR2), &[Arg(4, 43), Tv(841)]),
-    ((6016, false, NAND2), &[Arg(4, 139), Tv(773)]),
-    ((6115, false, NAND2), &[Tv(1074), Tv(6114)]),
-    ((6117, false, AND2), &[Tv(841), Tv(6116)]),
-    ((6119, false, OR2), &[Arg(4, 44), Tv(841)]),
-    ((6123, false, NAND2), &[Arg(4, 140), Tv(773)]),
-    ((6222, false, NAND2), &[Tv(1074), Tv(6221)]),
-    ((6224, false, AND2), &[Tv(841), Tv(6223)]),
-    ((6226, false, OR2), &[Arg(4, 45), Tv(841)]),
-    ((6230, false, NAND2), &[Arg(4, 141), Tv(773)]),
-    ((6329, false, NAND2), &[Tv(1074), Tv(6328)]),
-    ((6331, false, AND2), &[Tv(841), Tv(6330)]),
-    ((6333, false, OR2), &[Arg(4, 46), Tv(841)]),
-    ((6337, false, NAND2), &[Arg(4, 142), Tv(773)]),
-    ((6436, false, NAND2), &[Tv(1074), Tv(6435)]),
-    ((6438, false, AND2), &[Tv(841), Tv(6437)]),
-    ((6440, false, OR2), &[Arg(4, 47), Tv(841)]),
-    ((6444, false, NAND2), &[Arg(4, 143), Tv(773)]),
-    ((808, false, AND2), &[Tv(774), Tv(807)]),
-    ((874, false, NAND2), &[Tv(864), Tv(873)]),
-    ((1484, false, AND2), &[Tv(1482), Tv(1483)]),
-    ((1487, false, AND2), &[Tv(1273), Tv(1486)]),
+static LEVEL_21: [((usize, bool, CellType), &[GateInput]); 32] = [
+    ((5731, false, XOR2), &[Tv(5334), Tv(5631)]),
+    ((5734, false, XOR2), &[Tv(5337), Tv(5634)]),
+    ((5737, false, XOR2), &[Tv(5340), Tv(5637)]),
+    ((5740, false, XOR2), &[Tv(5343), Tv(5640)]),
+    ((5743, false, XOR2), &[Tv(5346), Tv(5643)]),
+    ((5746, false, XOR2), &[Tv(5349), Tv(5646)]),
+    ((5749, false, XOR2), &[Tv(5352), Tv(5649)]),
+    ((5752, false, XOR2), &[Tv(5355), Tv(5652)]),
+    ((5755, false, XOR2), &[Tv(5358), Tv(5655)]),
+    ((5758, false, XOR2), &[Tv(5361), Tv(5658)]),
+    ((5761, false, XOR2), &[Tv(5364), Tv(5661)]),
+    ((5764, false, XOR2), &[Tv(5367), Tv(5664)]),
+    ((5767, false, XOR2), &[Tv(5370), Tv(5667)]),
+    ((5770, false, XOR2), &[Tv(5373), Tv(5670)]),
+    ((5773, false, XOR2), &[Tv(5376), Tv(5673)]),
+    ((5776, false, XOR2), &[Tv(5379), Tv(5676)]),
+    ((5779, false, XOR2), &[Tv(5382), Tv(5679)]),
+    ((5782, false, XOR2), &[Tv(5385), Tv(5682)]),
+    ((5785, false, XOR2), &[Tv(5388), Tv(5685)]),
+    ((5788, false, XOR2), &[Tv(5391), Tv(5688)]),
+    ((5791, false, XOR2), &[Tv(5394), Tv(5691)]),
+    ((5794, false, XOR2), &[Tv(5397), Tv(5694)]),
+    ((5797, false, XOR2), &[Tv(5400), Tv(5697)]),
+    ((5800, false, XOR2), &[Tv(5403), Tv(5700)]),
+    ((5803, false, XOR2), &[Tv(5406), Tv(5703)]),
+    ((5806, false, XOR2), &[Tv(5409), Tv(5706)]),
+    ((5809, false, XOR2), &[Tv(5412), Tv(5709)]),
+    ((5812, false, XOR2), &[Tv(5415), Tv(5712)]),
+    ((5815, false, XOR2), &[Tv(5418), Tv(5715)]),
+    ((5818, false, XOR2), &[Tv(5421), Tv(5718)]),
+    ((5821, false, XOR2), &[Tv(5424), Tv(5721)]),
+    ((5824, false, XOR2), &[Tv(5427), Tv(5724)]),
 ];
 
-static LEVEL_42: [((usize, bool, CellType), &[GateInput]); 133] = [
-    ((2825, false, INV), &[Tv(2824)]),
-    ((3001, false, NOR2), &[Tv(2973), Tv(3000)]),
-    ((3013, false, AND2), &[Tv(3006), Tv(3012)]),
-    ((3121, false, NAND2), &[Tv(841), Tv(3120)]),
-    ((3123, false, AND2), &[Tv(808), Tv(3122)]),
-    ((3126, false, AND2), &[Tv(874), Tv(3125)]),
-    ((3128, false, NAND2), &[Arg(4, 48), Tv(3127)]),
-    ((3229, false, NAND2), &[Tv(3226), Tv(3228)]),
-    ((3231, false, AND2), &[Tv(808), Tv(3230)]),
-    ((3233, false, NAND2), &[Arg(4, 49), Tv(3127)]),
-    ((3235, false, AND2), &[Tv(874), Tv(3234)]),
-    ((3336, false, NAND2), &[Tv(3333), Tv(3335)]),
-    ((3338, false, AND2), &[Tv(808), Tv(3337)]),
-    ((3340, false, NAND2), &[Arg(4, 50), Tv(3127)]),
-    ((3342, false, AND2), &[Tv(874), Tv(3341)]),
-    ((3443, false, NAND2), &[Tv(3440), Tv(3442)]),
-    ((3445, false, AND2), &[Tv(808), Tv(3444)]),
-    ((3447, false, NAND2), &[Arg(4, 51), Tv(3127)]),
-    ((3449, false, AND2), &[Tv(874), Tv(3448)]),
-    ((3550, false, NAND2), &[Tv(3547), Tv(3549)]),
-    ((3552, false, AND2), &[Tv(808), Tv(3551)]),
-    ((3554, false, NAND2), &[Arg(4, 52), Tv(3127)]),
-    ((3556, false, AND2), &[Tv(874), Tv(3555)]),
-    ((3657, false, NAND2), &[Tv(3654), Tv(3656)]),
-    ((3659, false, AND2), &[Tv(808), Tv(3658)]),
-    ((3661, false, NAND2), &[Arg(4, 53), Tv(3127)]),
-    ((3663, false, AND2), &[Tv(874), Tv(3662)]),
-    ((3764, false, NAND2), &[Tv(3761), Tv(3763)]),
-    ((3766, false, AND2), &[Tv(808), Tv(3765)]),
-    ((3768, false, NAND2), &[Arg(4, 54), Tv(3127)]),
-    ((3770, false, AND2), &[Tv(874), Tv(3769)]),
-    ((3871, false, NAND2), &[Tv(3868), Tv(3870)]),
-    ((3873, false, AND2), &[Tv(808), Tv(3872)]),
-    ((3875, false, NAND2), &[Arg(4, 55), Tv(3127)]),
-    ((3877, false, AND2), &[Tv(874), Tv(3876)]),
-    ((3978, false, NAND2), &[Tv(3975), Tv(3977)]),
-    ((3980, false, AND2), &[Tv(808), Tv(3979)]),
-    ((3982, false, NAND2), &[Arg(4, 72), Tv(3127)]),
-    ((3984, false, AND2), &[Tv(874), Tv(3983)]),
-    ((4085, false, NAND2), &[Tv(4082), Tv(4084)]),
-    ((4087, false, AND2), &[Tv(808), Tv(4086)]),
-    ((4089, false, NAND2), &[Arg(4, 73), Tv(3127)]),
-    ((4091, false, AND2), &[Tv(874), Tv(4090)]),
-    ((4192, false, NAND2), &[Tv(4189), Tv(4191)]),
-    ((4194, false, AND2), &[Tv(808), Tv(4193)]),
-    ((4196, false, NAND2), &[Arg(4, 74), Tv(3127)]),
-    ((4198, false, AND2), &[Tv(874), Tv(4197)]),
-    ((4299, false, NAND2), &[Tv(4296), Tv(4298)]),
-    ((4301, false, AND2), &[Tv(808), Tv(4300)]),
-    ((4303, false, NAND2), &[Arg(4, 75), Tv(3127)]),
-    ((4305, false, AND2), &[Tv(874), Tv(4304)]),
-    ((4406, false, NAND2), &[Tv(4403), Tv(4405)]),
-    ((4408, false, AND2), &[Tv(808), Tv(4407)]),
-    ((4410, false, NAND2), &[Arg(4, 76), Tv(3127)]),
-    ((4412, false, AND2), &[Tv(874), Tv(4411)]),
-    ((4513, false, NAND2), &[Tv(4510), Tv(4512)]),
-    ((4515, false, AND2), &[Tv(808), Tv(4514)]),
-    ((4517, false, NAND2), &[Arg(4, 77), Tv(3127)]),
-    ((4519, false, AND2), &[Tv(874), Tv(4518)]),
-    ((4620, false, NAND2), &[Tv(4617), Tv(4619)]),
-    ((4622, false, AND2), &[Tv(808), Tv(4621)]),
-    ((4624, false, NAND2), &[Arg(4, 78), Tv(3127)]),
-    ((4626, false, AND2), &[Tv(874), Tv(4625)]),
-    ((4727, false, NAND2), &[Tv(4724), Tv(4726)]),
-    ((4729, false, AND2), &[Tv(808), Tv(4728)]),
-    ((4731, false, NAND2), &[Arg(4, 79), Tv(3127)]),
-    ((4733, false, AND2), &[Tv(874), Tv(4732)]),
-    ((4834, false, NAND2), &[Tv(4831), Tv(4833)]),
-    ((4836, false, AND2), &[Tv(808), Tv(4835)]),
-    ((4838, false, NAND2), &[Arg(4, 80), Tv(3127)]),
-    ((4840, false, AND2), &[Tv(874), Tv(4839)]),
-    ((4941, false, NAND2), &[Tv(4938), Tv(4940)]),
-    ((4943, false, AND2), &[Tv(808), Tv(4942)]),
-    ((4945, false, NAND2), &[Arg(4, 81), Tv(3127)]),
-    ((4947, false, AND2), &[Tv(874), Tv(4946)]),
-    ((5048, false, NAND2), &[Tv(5045), Tv(5047)]),
-    ((5050, false, AND2), &[Tv(808), Tv(5049)]),
-    ((5052, false, NAND2), &[Arg(4, 82), Tv(3127)]),
-    ((5054, false, AND2), &[Tv(874), Tv(5053)]),
-    ((5155, false, NAND2), &[Tv(5152), Tv(5154)]),
-    ((5157, false, AND2), &[Tv(808), Tv(5156)]),
-    ((5159, false, NAND2), &[Arg(4, 83), Tv(3127)]),
-    ((5161, false, AND2), &[Tv(874), Tv(5160)]),
-    ((5262, false, NAND2), &[Tv(5259), Tv(5261)]),
-    ((5264, false, AND2), &[Tv(808), Tv(5263)]),
-    ((5266, false, NAND2), &[Arg(4, 84), Tv(3127)]),
-    ((5268, false, AND2), &[Tv(874), Tv(5267)]),
-    ((5369, false, NAND2), &[Tv(5366), Tv(5368)]),
-    ((5371, false, AND2), &[Tv(808), Tv(5370)]),
-    ((5373, false, NAND2), &[Arg(4, 85), Tv(3127)]),
-    ((5375, false, AND2), &[Tv(874), Tv(5374)]),
-    ((5476, false, NAND2), &[Tv(5473), Tv(5475)]),
-    ((5478, false, AND2), &[Tv(808), Tv(5477)]),
-    ((5480, false, NAND2), &[Arg(4, 86), Tv(3127)]),
-    ((5482, false, AND2), &[Tv(874), Tv(5481)]),
-    ((5583, false, NAND2), &[Tv(5580), Tv(5582)]),
-    ((5585, false, AND2), &[Tv(808), Tv(5584)]),
-    ((5587, false, NAND2), &[Arg(4, 87), Tv(3127)]),
-    ((5589, false, AND2), &[Tv(874), Tv(5588)]),
-    ((5690, false, NAND2), &[Tv(5687), Tv(5689)]),
-    ((5692, false, AND2), &[Tv(808), Tv(5691)]),
-    ((5694, false, NAND2), &[Arg(4, 88), Tv(3127)]),
-    ((5696, false, AND2), &[Tv(874), Tv(5695)]),
-    ((5797, false, NAND2), &[Tv(5794), Tv(5796)]),
-    ((5799, false, AND2), &[Tv(808), Tv(5798)]),
-    ((5801, false, NAND2), &[Arg(4, 89), Tv(3127)]),
-    ((5803, false, AND2), &[Tv(874), Tv(5802)]),
-    ((5904, false, NAND2), &[Tv(5901), Tv(5903)]),
-    ((5906, false, AND2), &[Tv(808), Tv(5905)]),
-    ((5908, false, NAND2), &[Arg(4, 90), Tv(3127)]),
-    ((5910, false, AND2), &[Tv(874), Tv(5909)]),
-    ((6011, false, NAND2), &[Tv(6008), Tv(6010)]),
-    ((6013, false, AND2), &[Tv(808), Tv(6012)]),
-    ((6015, false, NAND2), &[Arg(4, 91), Tv(3127)]),
-    ((6017, false, AND2), &[Tv(874), Tv(6016)]),
-    ((6118, false, NAND2), &[Tv(6115), Tv(6117)]),
-    ((6120, false, AND2), &[Tv(808), Tv(6119)]),
-    ((6122, false, NAND2), &[Arg(4, 92), Tv(3127)]),
-    ((6124, false, AND2), &[Tv(874), Tv(6123)]),
-    ((6225, false, NAND2), &[Tv(6222), Tv(6224)]),
-    ((6227, false, AND2), &[Tv(808), Tv(6226)]),
-    ((6229, false, NAND2), &[Arg(4, 93), Tv(3127)]),
-    ((6231, false, AND2), &[Tv(874), Tv(6230)]),
-    ((6332, false, NAND2), &[Tv(6329), Tv(6331)]),
-    ((6334, false, AND2), &[Tv(808), Tv(6333)]),
-    ((6336, false, NAND2), &[Arg(4, 94), Tv(3127)]),
-    ((6338, false, AND2), &[Tv(874), Tv(6337)]),
-    ((6439, false, NAND2), &[Tv(6436), Tv(6438)]),
-    ((6441, false, AND2), &[Tv(808), Tv(6440)]),
-    ((6443, false, NAND2), &[Arg(4, 95), Tv(3127)]),
-    ((6445, false, AND2), &[Tv(874), Tv(6444)]),
-    ((875, false, AND2), &[Tv(841), Tv(874)]),
-    ((1488, false, AND2), &[Tv(1484), Tv(1487)]),
+static LEVEL_22: [((usize, bool, CellType), &[GateInput]); 32] = [
+    ((5732, false, AND2), &[Tv(5727), Tv(5731)]),
+    ((5735, false, AND2), &[Tv(5727), Tv(5734)]),
+    ((5738, false, AND2), &[Tv(5727), Tv(5737)]),
+    ((5741, false, AND2), &[Tv(5727), Tv(5740)]),
+    ((5744, false, AND2), &[Tv(5727), Tv(5743)]),
+    ((5747, false, AND2), &[Tv(5727), Tv(5746)]),
+    ((5750, false, AND2), &[Tv(5727), Tv(5749)]),
+    ((5753, false, AND2), &[Tv(5727), Tv(5752)]),
+    ((5756, false, AND2), &[Tv(5727), Tv(5755)]),
+    ((5759, false, AND2), &[Tv(5727), Tv(5758)]),
+    ((5762, false, AND2), &[Tv(5727), Tv(5761)]),
+    ((5765, false, AND2), &[Tv(5727), Tv(5764)]),
+    ((5768, false, AND2), &[Tv(5727), Tv(5767)]),
+    ((5771, false, AND2), &[Tv(5727), Tv(5770)]),
+    ((5774, false, AND2), &[Tv(5727), Tv(5773)]),
+    ((5777, false, AND2), &[Tv(5727), Tv(5776)]),
+    ((5780, false, AND2), &[Tv(5727), Tv(5779)]),
+    ((5783, false, AND2), &[Tv(5727), Tv(5782)]),
+    ((5786, false, AND2), &[Tv(5727), Tv(5785)]),
+    ((5789, false, AND2), &[Tv(5727), Tv(5788)]),
+    ((5792, false, AND2), &[Tv(5727), Tv(5791)]),
+    ((5795, false, AND2), &[Tv(5727), Tv(5794)]),
+    ((5798, false, AND2), &[Tv(5727), Tv(5797)]),
+    ((5801, false, AND2), &[Tv(5727), Tv(5800)]),
+    ((5804, false, AND2), &[Tv(5727), Tv(5803)]),
+    ((5807, false, AND2), &[Tv(5727), Tv(5806)]),
+    ((5810, false, AND2), &[Tv(5727), Tv(5809)]),
+    ((5813, false, AND2), &[Tv(5727), Tv(5812)]),
+    ((5816, false, AND2), &[Tv(5727), Tv(5815)]),
+    ((5819, false, AND2), &[Tv(5727), Tv(5818)]),
+    ((5822, false, AND2), &[Tv(5727), Tv(5821)]),
+    ((5825, false, AND2), &[Tv(5727), Tv(5824)]),
 ];
 
-static LEVEL_43: [((usize, bool, CellType), &[GateInput]); 99] = [
-    ((2826, false, NAND2), &[Tv(1488), Tv(2825)]),
-    ((3014, false, AND2), &[Tv(3001), Tv(3013)]),
-    ((3124, false, NAND2), &[Tv(3121), Tv(3123)]),
-    ((3129, false, AND2), &[Tv(3126), Tv(3128)]),
-    ((3131, false, OR2), &[Arg(4, 144), Tv(874)]),
-    ((3232, false, NAND2), &[Tv(3229), Tv(3231)]),
-    ((3236, false, AND2), &[Tv(3233), Tv(3235)]),
-    ((3238, false, OR2), &[Arg(4, 145), Tv(874)]),
-    ((3339, false, NAND2), &[Tv(3336), Tv(3338)]),
-    ((3343, false, AND2), &[Tv(3340), Tv(3342)]),
-    ((3345, false, OR2), &[Arg(4, 146), Tv(874)]),
-    ((3446, false, NAND2), &[Tv(3443), Tv(3445)]),
-    ((3450, false, AND2), &[Tv(3447), Tv(3449)]),
-    ((3452, false, OR2), &[Arg(4, 147), Tv(874)]),
-    ((3553, false, NAND2), &[Tv(3550), Tv(3552)]),
-    ((3557, false, AND2), &[Tv(3554), Tv(3556)]),
-    ((3559, false, OR2), &[Arg(4, 148), Tv(874)]),
-    ((3660, false, NAND2), &[Tv(3657), Tv(3659)]),
-    ((3664, false, AND2), &[Tv(3661), Tv(3663)]),
-    ((3666, false, OR2), &[Arg(4, 149), Tv(874)]),
-    ((3767, false, NAND2), &[Tv(3764), Tv(3766)]),
-    ((3771, false, AND2), &[Tv(3768), Tv(3770)]),
-    ((3773, false, OR2), &[Arg(4, 150), Tv(874)]),
-    ((3874, false, NAND2), &[Tv(3871), Tv(3873)]),
-    ((3878, false, AND2), &[Tv(3875), Tv(3877)]),
-    ((3880, false, OR2), &[Arg(4, 151), Tv(874)]),
-    ((3981, false, NAND2), &[Tv(3978), Tv(3980)]),
-    ((3985, false, AND2), &[Tv(3982), Tv(3984)]),
-    ((3987, false, OR2), &[Arg(4, 168), Tv(874)]),
-    ((4088, false, NAND2), &[Tv(4085), Tv(4087)]),
-    ((4092, false, AND2), &[Tv(4089), Tv(4091)]),
-    ((4094, false, OR2), &[Arg(4, 169), Tv(874)]),
-    ((4195, false, NAND2), &[Tv(4192), Tv(4194)]),
-    ((4199, false, AND2), &[Tv(4196), Tv(4198)]),
-    ((4201, false, OR2), &[Arg(4, 170), Tv(874)]),
-    ((4302, false, NAND2), &[Tv(4299), Tv(4301)]),
-    ((4306, false, AND2), &[Tv(4303), Tv(4305)]),
-    ((4308, false, OR2), &[Arg(4, 171), Tv(874)]),
-    ((4409, false, NAND2), &[Tv(4406), Tv(4408)]),
-    ((4413, false, AND2), &[Tv(4410), Tv(4412)]),
-    ((4415, false, OR2), &[Arg(4, 172), Tv(874)]),
-    ((4516, false, NAND2), &[Tv(4513), Tv(4515)]),
-    ((4520, false, AND2), &[Tv(4517), Tv(4519)]),
-    ((4522, false, OR2), &[Arg(4, 173), Tv(874)]),
-    ((4623, false, NAND2), &[Tv(4620), Tv(4622)]),
-    ((4627, false, AND2), &[Tv(4624), Tv(4626)]),
-    ((4629, false, OR2), &[Arg(4, 174), Tv(874)]),
-    ((4730, false, NAND2), &[Tv(4727), Tv(4729)]),
-    ((4734, false, AND2), &[Tv(4731), Tv(4733)]),
-    ((4736, false, OR2), &[Arg(4, 175), Tv(874)]),
-    ((4837, false, NAND2), &[Tv(4834), Tv(4836)]),
-    ((4841, false, AND2), &[Tv(4838), Tv(4840)]),
-    ((4843, false, OR2), &[Arg(4, 176), Tv(874)]),
-    ((4944, false, NAND2), &[Tv(4941), Tv(4943)]),
-    ((4948, false, AND2), &[Tv(4945), Tv(4947)]),
-    ((4950, false, OR2), &[Arg(4, 177), Tv(874)]),
-    ((5051, false, NAND2), &[Tv(5048), Tv(5050)]),
-    ((5055, false, AND2), &[Tv(5052), Tv(5054)]),
-    ((5057, false, OR2), &[Arg(4, 178), Tv(874)]),
-    ((5158, false, NAND2), &[Tv(5155), Tv(5157)]),
-    ((5162, false, AND2), &[Tv(5159), Tv(5161)]),
-    ((5164, false, OR2), &[Arg(4, 179), Tv(874)]),
-    ((5265, false, NAND2), &[Tv(5262), Tv(5264)]),
-    ((5269, false, AND2), &[Tv(5266), Tv(5268)]),
-    ((5271, false, OR2), &[Arg(4, 180), Tv(874)]),
-    ((5372, false, NAND2), &[Tv(5369), Tv(5371)]),
-    ((5376, false, AND2), &[Tv(5373), Tv(5375)]),
-    ((5378, false, OR2), &[Arg(4, 181), Tv(874)]),
-    ((5479, false, NAND2), &[Tv(5476), Tv(5478)]),
-    ((5483, false, AND2), &[Tv(5480), Tv(5482)]),
-    ((5485, false, OR2), &[Arg(4, 182), Tv(874)]),
-    ((5586, false, NAND2), &[Tv(5583), Tv(5585)]),
-    ((5590, false, AND2), &[Tv(5587), Tv(5589)]),
-    ((5592, false, OR2), &[Arg(4, 183), Tv(874)]),
-    ((5693, false, NAND2), &[Tv(5690), Tv(5692)]),
-    ((5697, false, AND2), &[Tv(5694), Tv(5696)]),
-    ((5699, false, OR2), &[Arg(4, 184), Tv(874)]),
-    ((5800, false, NAND2), &[Tv(5797), Tv(5799)]),
-    ((5804, false, AND2), &[Tv(5801), Tv(5803)]),
-    ((5806, false, OR2), &[Arg(4, 185), Tv(874)]),
-    ((5907, false, NAND2), &[Tv(5904), Tv(5906)]),
-    ((5911, false, AND2), &[Tv(5908), Tv(5910)]),
-    ((5913, false, OR2), &[Arg(4, 186), Tv(874)]),
-    ((6014, false, NAND2), &[Tv(6011), Tv(6013)]),
-    ((6018, false, AND2), &[Tv(6015), Tv(6017)]),
-    ((6020, false, OR2), &[Arg(4, 187), Tv(874)]),
-    ((6121, false, NAND2), &[Tv(6118), Tv(6120)]),
-    ((6125, false, AND2), &[Tv(6122), Tv(6124)]),
-    ((6127, false, OR2), &[Arg(4, 188), Tv(874)]),
-    ((6228, false, NAND2), &[Tv(6225), Tv(6227)]),
-    ((6232, false, AND2), &[Tv(6229), Tv(6231)]),
-    ((6234, false, OR2), &[Arg(4, 189), Tv(874)]),
-    ((6335, false, NAND2), &[Tv(6332), Tv(6334)]),
-    ((6339, false, AND2), &[Tv(6336), Tv(6338)]),
-    ((6341, false, OR2), &[Arg(4, 190), Tv(874)]),
-    ((6442, false, NAND2), &[Tv(6439), Tv(6441)]),
-    ((6446, false, AND2), &[Tv(6443), Tv(6445)]),
-    ((6448, false, OR2), &[Arg(4, 191), Tv(874)]),
-    ((876, false, AND2), &[Tv(808), Tv(875)]),
+static LEVEL_23: [((usize, bool, CellType), &[GateInput]); 32] = [
+    ((5733, false, XOR2), &[Tv(5631), Tv(5732)]),
+    ((5736, false, XOR2), &[Tv(5634), Tv(5735)]),
+    ((5739, false, XOR2), &[Tv(5637), Tv(5738)]),
+    ((5742, false, XOR2), &[Tv(5640), Tv(5741)]),
+    ((5745, false, XOR2), &[Tv(5643), Tv(5744)]),
+    ((5748, false, XOR2), &[Tv(5646), Tv(5747)]),
+    ((5751, false, XOR2), &[Tv(5649), Tv(5750)]),
+    ((5754, false, XOR2), &[Tv(5652), Tv(5753)]),
+    ((5757, false, XOR2), &[Tv(5655), Tv(5756)]),
+    ((5760, false, XOR2), &[Tv(5658), Tv(5759)]),
+    ((5763, false, XOR2), &[Tv(5661), Tv(5762)]),
+    ((5766, false, XOR2), &[Tv(5664), Tv(5765)]),
+    ((5769, false, XOR2), &[Tv(5667), Tv(5768)]),
+    ((5772, false, XOR2), &[Tv(5670), Tv(5771)]),
+    ((5775, false, XOR2), &[Tv(5673), Tv(5774)]),
+    ((5778, false, XOR2), &[Tv(5676), Tv(5777)]),
+    ((5781, false, XOR2), &[Tv(5679), Tv(5780)]),
+    ((5784, false, XOR2), &[Tv(5682), Tv(5783)]),
+    ((5787, false, XOR2), &[Tv(5685), Tv(5786)]),
+    ((5790, false, XOR2), &[Tv(5688), Tv(5789)]),
+    ((5793, false, XOR2), &[Tv(5691), Tv(5792)]),
+    ((5796, false, XOR2), &[Tv(5694), Tv(5795)]),
+    ((5799, false, XOR2), &[Tv(5697), Tv(5798)]),
+    ((5802, false, XOR2), &[Tv(5700), Tv(5801)]),
+    ((5805, false, XOR2), &[Tv(5703), Tv(5804)]),
+    ((5808, false, XOR2), &[Tv(5706), Tv(5807)]),
+    ((5811, false, XOR2), &[Tv(5709), Tv(5810)]),
+    ((5814, false, XOR2), &[Tv(5712), Tv(5813)]),
+    ((5817, false, XOR2), &[Tv(5715), Tv(5816)]),
+    ((5820, false, XOR2), &[Tv(5718), Tv(5819)]),
+    ((5823, false, XOR2), &[Tv(5721), Tv(5822)]),
+    ((5826, false, XOR2), &[Tv(5724), Tv(5825)]),
 ];
 
-static LEVEL_44: [((usize, bool, CellType), &[GateInput]); 68] = [
-    ((2827, false, NAND2), &[Tv(876), Tv(2826)]),
-    ((3015, false, AND2), &[Tv(1488), Tv(2824)]),
-    ((3016, false, NAND2), &[Tv(1488), Tv(2824)]),
-    ((3017, false, AND2), &[Tv(876), Tv(3014)]),
-    ((3130, false, NAND2), &[Tv(3124), Tv(3129)]),
-    ((3132, false, AND2), &[Tv(3014), Tv(3131)]),
-    ((3237, false, NAND2), &[Tv(3232), Tv(3236)]),
-    ((3239, false, AND2), &[Tv(3014), Tv(3238)]),
-    ((3344, false, NAND2), &[Tv(3339), Tv(3343)]),
-    ((3346, false, AND2), &[Tv(3014), Tv(3345)]),
-    ((3451, false, NAND2), &[Tv(3446), Tv(3450)]),
-    ((3453, false, AND2), &[Tv(3014), Tv(3452)]),
-    ((3558, false, NAND2), &[Tv(3553), Tv(3557)]),
-    ((3560, false, AND2), &[Tv(3014), Tv(3559)]),
-    ((3665, false, NAND2), &[Tv(3660), Tv(3664)]),
-    ((3667, false, AND2), &[Tv(3014), Tv(3666)]),
-    ((3772, false, NAND2), &[Tv(3767), Tv(3771)]),
-    ((3774, false, AND2), &[Tv(3014), Tv(3773)]),
-    ((3879, false, NAND2), &[Tv(3874), Tv(3878)]),
-    ((3881, false, AND2), &[Tv(3014), Tv(3880)]),
-    ((3986, false, NAND2), &[Tv(3981), Tv(3985)]),
-    ((3988, false, AND2), &[Tv(3014), Tv(3987)]),
-    ((4093, false, NAND2), &[Tv(4088), Tv(4092)]),
-    ((4095, false, AND2), &[Tv(3014), Tv(4094)]),
-    ((4200, false, NAND2), &[Tv(4195), Tv(4199)]),
-    ((4202, false, AND2), &[Tv(3014), Tv(4201)]),
-    ((4307, false, NAND2), &[Tv(4302), Tv(4306)]),
-    ((4309, false, AND2), &[Tv(3014), Tv(4308)]),
-    ((4414, false, NAND2), &[Tv(4409), Tv(4413)]),
-    ((4416, false, AND2), &[Tv(3014), Tv(4415)]),
-    ((4521, false, NAND2), &[Tv(4516), Tv(4520)]),
-    ((4523, false, AND2), &[Tv(3014), Tv(4522)]),
-    ((4628, false, NAND2), &[Tv(4623), Tv(4627)]),
-    ((4630, false, AND2), &[Tv(3014), Tv(4629)]),
-    ((4735, false, NAND2), &[Tv(4730), Tv(4734)]),
-    ((4737, false, AND2), &[Tv(3014), Tv(4736)]),
-    ((4842, false, NAND2), &[Tv(4837), Tv(4841)]),
-    ((4844, false, AND2), &[Tv(3014), Tv(4843)]),
-    ((4949, false, NAND2), &[Tv(4944), Tv(4948)]),
-    ((4951, false, AND2), &[Tv(3014), Tv(4950)]),
-    ((5056, false, NAND2), &[Tv(5051), Tv(5055)]),
-    ((5058, false, AND2), &[Tv(3014), Tv(5057)]),
-    ((5163, false, NAND2), &[Tv(5158), Tv(5162)]),
-    ((5165, false, AND2), &[Tv(3014), Tv(5164)]),
-    ((5270, false, NAND2), &[Tv(5265), Tv(5269)]),
-    ((5272, false, AND2), &[Tv(3014), Tv(5271)]),
-    ((5377, false, NAND2), &[Tv(5372), Tv(5376)]),
-    ((5379, false, AND2), &[Tv(3014), Tv(5378)]),
-    ((5484, false, NAND2), &[Tv(5479), Tv(5483)]),
-    ((5486, false, AND2), &[Tv(3014), Tv(5485)]),
-    ((5591, false, NAND2), &[Tv(5586), Tv(5590)]),
-    ((5593, false, AND2), &[Tv(3014), Tv(5592)]),
-    ((5698, false, NAND2), &[Tv(5693), Tv(5697)]),
-    ((5700, false, AND2), &[Tv(3014), Tv(5699)]),
-    ((5805, false, NAND2), &[Tv(5800), Tv(5804)]),
-    ((5807, false, AND2), &[Tv(3014), Tv(5806)]),
-    ((5912, false, NAND2), &[Tv(5907), Tv(5911)]),
-    ((5914, false, AND2), &[Tv(3014), Tv(5913)]),
-    ((6019, false, NAND2), &[Tv(6014), Tv(6018)]),
-    ((6021, false, AND2), &[Tv(3014), Tv(6020)]),
-    ((6126, false, NAND2), &[Tv(6121), Tv(6125)]),
-    ((6128, false, AND2), &[Tv(3014), Tv(6127)]),
-    ((6233, false, NAND2), &[Tv(6228), Tv(6232)]),
-    ((6235, false, AND2), &[Tv(3014), Tv(6234)]),
-    ((6340, false, NAND2), &[Tv(6335), Tv(6339)]),
-    ((6342, false, AND2), &[Tv(3014), Tv(6341)]),
-    ((6447, false, NAND2), &[Tv(6442), Tv(6446)]),
-    ((6449, false, AND2), &[Tv(3014), Tv(6448)]),
+static LEVEL_24: [((usize, bool, CellType), &[GateInput]); 32] = [
+    ((5832, false, AND2), &[Tv(5831), Tv(5733)]),
+    ((5833, false, AND2), &[Tv(5831), Tv(5736)]),
+    ((5834, false, AND2), &[Tv(5831), Tv(5739)]),
+    ((5835, false, AND2), &[Tv(5831), Tv(5742)]),
+    ((5836, false, AND2), &[Tv(5831), Tv(5745)]),
+    ((5837, false, AND2), &[Tv(5831), Tv(5748)]),
+    ((5838, false, AND2), &[Tv(5831), Tv(5751)]),
+    ((5839, false, AND2), &[Tv(5831), Tv(5754)]),
+    ((5840, false, AND2), &[Tv(5831), Tv(5757)]),
+    ((5841, false, AND2), &[Tv(5831), Tv(5760)]),
+    ((5842, false, AND2), &[Tv(5831), Tv(5763)]),
+    ((5843, false, AND2), &[Tv(5831), Tv(5766)]),
+    ((5844, false, AND2), &[Tv(5831), Tv(5769)]),
+    ((5845, false, AND2), &[Tv(5831), Tv(5772)]),
+    ((5846, false, AND2), &[Tv(5831), Tv(5775)]),
+    ((5847, false, AND2), &[Tv(5831), Tv(5778)]),
+    ((5848, false, AND2), &[Tv(5831), Tv(5781)]),
+    ((5849, false, AND2), &[Tv(5831), Tv(5784)]),
+    ((5850, false, AND2), &[Tv(5831), Tv(5787)]),
+    ((5851, false, AND2), &[Tv(5831), Tv(5790)]),
+    ((5852, false, AND2), &[Tv(5831), Tv(5793)]),
+    ((5853, false, AND2), &[Tv(5831), Tv(5796)]),
+    ((5854, false, AND2), &[Tv(5831), Tv(5799)]),
+    ((5855, false, AND2), &[Tv(5831), Tv(5802)]),
+    ((5856, false, AND2), &[Tv(5831), Tv(5805)]),
+    ((5857, false, AND2), &[Tv(5831), Tv(5808)]),
+    ((5858, false, AND2), &[Tv(5831), Tv(5811)]),
+    ((5859, false, AND2), &[Tv(5831), Tv(5814)]),
+    ((5860, false, AND2), &[Tv(5831), Tv(5817)]),
+    ((5861, false, AND2), &[Tv(5831), Tv(5820)]),
+    ((5862, false, AND2), &[Tv(5831), Tv(5823)]),
+    ((5863, false, AND2), &[Tv(5831), Tv(5826)]),
 ];
 
-static LEVEL_45: [((usize, bool, CellType), &[GateInput]); 35] = [
-    ((0, true, AND2), &[Tv(2827), Tv(3014)]),
-    ((1, true, AND2), &[Tv(3016), Tv(3017)]),
-    ((2, true, AND2), &[Tv(3015), Tv(3017)]),
-    ((3, true, AND2), &[Tv(3130), Tv(3132)]),
-    ((4, true, AND2), &[Tv(3237), Tv(3239)]),
-    ((5, true, AND2), &[Tv(3344), Tv(3346)]),
-    ((6, true, AND2), &[Tv(3451), Tv(3453)]),
-    ((7, true, AND2), &[Tv(3558), Tv(3560)]),
-    ((8, true, AND2), &[Tv(3665), Tv(3667)]),
-    ((9, true, AND2), &[Tv(3772), Tv(3774)]),
-    ((10, true, AND2), &[Tv(3879), Tv(3881)]),
-    ((11, true, AND2), &[Tv(3986), Tv(3988)]),
-    ((12, true, AND2), &[Tv(4093), Tv(4095)]),
-    ((13, true, AND2), &[Tv(4200), Tv(4202)]),
-    ((14, true, AND2), &[Tv(4307), Tv(4309)]),
-    ((15, true, AND2), &[Tv(4414), Tv(4416)]),
-    ((16, true, AND2), &[Tv(4521), Tv(4523)]),
-    ((17, true, AND2), &[Tv(4628), Tv(4630)]),
-    ((18, true, AND2), &[Tv(4735), Tv(4737)]),
-    ((19, true, AND2), &[Tv(4842), Tv(4844)]),
-    ((20, true, AND2), &[Tv(4949), Tv(4951)]),
-    ((21, true, AND2), &[Tv(5056), Tv(5058)]),
-    ((22, true, AND2), &[Tv(5163), Tv(5165)]),
-    ((23, true, AND2), &[Tv(5270), Tv(5272)]),
-    ((24, true, AND2), &[Tv(5377), Tv(5379)]),
-    ((25, true, AND2), &[Tv(5484), Tv(5486)]),
-    ((26, true, AND2), &[Tv(5591), Tv(5593)]),
-    ((27, true, AND2), &[Tv(5698), Tv(5700)]),
-    ((28, true, AND2), &[Tv(5805), Tv(5807)]),
-    ((29, true, AND2), &[Tv(5912), Tv(5914)]),
-    ((30, true, AND2), &[Tv(6019), Tv(6021)]),
-    ((31, true, AND2), &[Tv(6126), Tv(6128)]),
-    ((32, true, AND2), &[Tv(6233), Tv(6235)]),
-    ((33, true, AND2), &[Tv(6340), Tv(6342)]),
-    ((34, true, AND2), &[Tv(6447), Tv(6449)]),
+static LEVEL_25: [((usize, bool, CellType), &[GateInput]); 32] = [
+    ((3, true, AND2), &[Tv(5832), Tv(6258)]),
+    ((4, true, AND2), &[Tv(5833), Tv(6258)]),
+    ((5, true, AND2), &[Tv(5834), Tv(6258)]),
+    ((6, true, AND2), &[Tv(5835), Tv(6258)]),
+    ((7, true, AND2), &[Tv(5836), Tv(6258)]),
+    ((8, true, AND2), &[Tv(5837), Tv(6258)]),
+    ((9, true, AND2), &[Tv(5838), Tv(6258)]),
+    ((10, true, AND2), &[Tv(5839), Tv(6258)]),
+    ((11, true, AND2), &[Tv(5840), Tv(6258)]),
+    ((12, true, AND2), &[Tv(5841), Tv(6258)]),
+    ((13, true, AND2), &[Tv(5842), Tv(6258)]),
+    ((14, true, AND2), &[Tv(5843), Tv(6258)]),
+    ((15, true, AND2), &[Tv(5844), Tv(6258)]),
+    ((16, true, AND2), &[Tv(5845), Tv(6258)]),
+    ((17, true, AND2), &[Tv(5846), Tv(6258)]),
+    ((18, true, AND2), &[Tv(5847), Tv(6258)]),
+    ((19, true, AND2), &[Tv(5848), Tv(6258)]),
+    ((20, true, AND2), &[Tv(5849), Tv(6258)]),
+    ((21, true, AND2), &[Tv(5850), Tv(6258)]),
+    ((22, true, AND2), &[Tv(5851), Tv(6258)]),
+    ((23, true, AND2), &[Tv(5852), Tv(6258)]),
+    ((24, true, AND2), &[Tv(5853), Tv(6258)]),
+    ((25, true, AND2), &[Tv(5854), Tv(6258)]),
+    ((26, true, AND2), &[Tv(5855), Tv(6258)]),
+    ((27, true, AND2), &[Tv(5856), Tv(6258)]),
+    ((28, true, AND2), &[Tv(5857), Tv(6258)]),
+    ((29, true, AND2), &[Tv(5858), Tv(6258)]),
+    ((30, true, AND2), &[Tv(5859), Tv(6258)]),
+    ((31, true, AND2), &[Tv(5860), Tv(6258)]),
+    ((32, true, AND2), &[Tv(5861), Tv(6258)]),
+    ((33, true, AND2), &[Tv(5862), Tv(6258)]),
+    ((34, true, AND2), &[Tv(5863), Tv(6258)]),
 ];
 
-static PRUNE_21: [usize; 93] = [
-  3387,
-  1673,
-  3385,
-  5099,
-  5097,
-  3708,
-  3168,
-  552,
-  1589,
-  5420,
-  3706,
-  5418,
-  548,
-  1495,
-  4457,
-  6169,
-  4455,
-  3019,
-  4778,
+static PRUNE_1: [usize; 746] = [
+  51,
+  52,
+  53,
+  54,
+  55,
+  56,
+  57,
+  58,
+  74,
+  75,
+  76,
+  77,
+  78,
+  79,
+  80,
+  81,
+  98,
+  99,
+  100,
+  101,
+  106,
+  107,
+  108,
+  109,
+  110,
+  111,
+  112,
+  113,
+  129,
+  130,
+  131,
+  132,
+  133,
+  134,
+  135,
+  136,
+  153,
+  154,
+  155,
+  156,
+  161,
+  162,
+  163,
+  164,
+  165,
+  166,
+  167,
+  168,
+  184,
+  185,
+  186,
+  187,
+  188,
+  189,
+  190,
+  191,
+  208,
+  209,
+  210,
+  211,
+  216,
+  217,
+  218,
+  219,
+  220,
+  221,
+  222,
+  223,
+  239,
+  240,
+  241,
+  242,
+  243,
+  244,
+  245,
+  246,
+  263,
+  264,
+  265,
+  266,
+  271,
+  272,
+  273,
+  274,
+  275,
+  276,
+  277,
+  278,
+  294,
+  295,
+  296,
+  297,
+  298,
+  299,
+  300,
+  301,
+  318,
+  319,
+  320,
+  321,
+  326,
+  327,
+  328,
+  329,
+  330,
+  331,
+  332,
+  333,
+  349,
+  350,
+  351,
+  352,
+  353,
+  354,
+  355,
+  356,
+  373,
+  374,
+  375,
+  376,
+  381,
+  382,
+  383,
+  384,
+  385,
+  386,
+  387,
+  388,
+  404,
+  405,
+  406,
+  407,
+  408,
+  409,
+  410,
+  411,
+  428,
+  429,
+  430,
+  431,
+  436,
+  437,
+  438,
+  439,
+  440,
+  441,
+  442,
+  443,
+  459,
+  460,
+  461,
+  462,
+  463,
+  464,
+  465,
+  466,
+  483,
+  484,
+  485,
+  486,
+  491,
+  492,
+  493,
+  494,
+  495,
+  496,
+  497,
+  498,
+  514,
+  515,
+  516,
+  517,
+  518,
+  519,
+  520,
+  521,
   538,
-  1575,
-  4776,
-  524,
-  1516,
-  522,
-  1604,
-  1694,
-  3815,
-  3048,
-  5527,
-  3813,
-  5525,
-  6167,
-  1565,
-  4136,
-  4134,
-  5848,
-  5846,
-  4885,
-  1684,
-  1545,
-  1725,
-  4883,
-  3494,
-  3492,
-  5206,
-  5204,
-  1506,
-  1551,
-  6274,
-  4243,
-  5955,
-  4241,
+  539,
+  540,
+  541,
+  546,
+  547,
+  548,
+  549,
+  550,
   551,
-  3167,
-  4564,
-  6276,
-  2263,
-  3165,
-  3163,
-  4562,
-  1572,
-  3601,
-  396,
-  5313,
-  3599,
-  5311,
-  5953,
-  3922,
-  3920,
-  5634,
-  1844,
-  5632,
-  523,
-  4671,
-  1785,
-  6383,
-  1558,
-  4669,
-  3280,
-  4992,
-  3278,
-  4990,
-  6060,
-  4029,
-  4027,
-  5741,
-  5739,
-  6381,
-  4350,
-  1554,
-  4348,
-  6062,
-];
-
-static PRUNE_26: [usize; 126] = [
-  5326,
-  1268,
-  3070,
-  3612,
-  5324,
-  947,
-  1309,
-  3293,
-  587,
-  3933,
-  5647,
-  5645,
-  3614,
-  953,
-  399,
-  4684,
-  4682,
-  6396,
-  579,
-  6394,
-  405,
-  5005,
-  585,
-  3291,
-  1262,
-  990,
-  2702,
-  5003,
+  552,
+  553,
   569,
-  567,
-  5754,
-  4040,
-  1244,
-  1242,
-  3181,
-  5752,
-  3721,
-  565,
-  1205,
-  4363,
-  4361,
-  6075,
-  1250,
-  6073,
+  570,
   571,
-  1246,
-  4042,
-  931,
-  3398,
-  5112,
-  1727,
-  5110,
-  555,
-  1547,
-  3079,
-  3177,
-  561,
-  3719,
-  968,
-  5433,
-  2905,
-  5431,
-  3400,
-  1281,
-  559,
-  2666,
-  4470,
-  4468,
-  950,
-  6182,
-  590,
-  588,
-  1220,
-  1310,
-  406,
-  6180,
-  4791,
-  4789,
-  596,
-  3075,
+  572,
+  573,
+  574,
+  575,
+  576,
+  593,
   594,
-  3073,
-  592,
-  940,
-  1257,
-  580,
-  578,
-  3826,
-  5540,
-  5538,
-  3507,
-  991,
-  4149,
-  584,
-  3065,
-  5861,
-  1216,
-  4147,
-  5859,
-  2838,
-  3828,
-  4898,
-  3184,
-  1243,
-  1198,
-  4896,
-  564,
-  5219,
-  934,
-  3505,
-  979,
-  5217,
-  3186,
-  4256,
-  4254,
-  5968,
-  5966,
-  2900,
-  3935,
-  2673,
-  4577,
-  2681,
-  6289,
-  4575,
-  560,
-  6287,
-];
-
-static PRUNE_14: [usize; 229] = [
-  2350,
-  231,
-  2170,
-  4966,
-  456,
-  2213,
-  4649,
-  229,
-  4647,
-  47,
-  6361,
-  1761,
-  6359,
-  1759,
-  45,
-  5287,
-  1769,
-  100,
-  3258,
-  3256,
-  2354,
-  4970,
-  235,
-  325,
-  233,
-  4968,
-  458,
-  98,
-  3254,
-  219,
-  309,
-  4007,
-  2158,
-  489,
-  217,
-  6036,
-  4005,
-  2201,
-  5719,
-  487,
-  4003,
-  5717,
-  215,
-  2344,
-  4645,
-  315,
-  313,
-  6357,
-  4328,
-  43,
-  41,
-  6040,
-  2342,
-  448,
-  4326,
-  6038,
-  2295,
-  4324,
-  311,
-  27,
-  117,
-  5394,
-  479,
-  3365,
-  5077,
-  3363,
-  475,
-  2234,
-  25,
-  115,
-  1964,
-  113,
-  3361,
-  5075,
-  5715,
-  3686,
-  213,
-  5398,
-  31,
-  3144,
-  2285,
-  3142,
-  3684,
-  3140,
-  5396,
-  3682,
-  29,
-  467,
-  4752,
-  332,
-  330,
-  2224,
-  4435,
-  4433,
-  1772,
-  103,
-  6147,
-  6145,
-  2357,
-  4431,
-  328,
-  2267,
-  463,
-  2142,
-  473,
-  5073,
-  2277,
-  4756,
-  4754,
-  1776,
-  469,
-  2363,
-  3793,
-  230,
-  1762,
-  5505,
-  455,
-  2349,
-  5822,
-  453,
-  3791,
-  2167,
-  3789,
-  316,
-  451,
-  5503,
-  46,
-  326,
-  3032,
-  461,
-  4114,
-  2355,
-  1770,
-  101,
-  236,
-  99,
-  234,
-  6143,
-  3030,
-  4112,
-  5826,
-  2261,
-  4110,
-  2306,
-  457,
-  5824,
-  1901,
-  232,
-  3028,
-  5180,
-  490,
-  488,
-  218,
-  4863,
-  216,
-  4861,
-  486,
-  5501,
-  1758,
-  44,
-  89,
-  2345,
-  3472,
-  5184,
-  3470,
-  314,
-  312,
-  2341,
-  42,
-  40,
-  220,
-  310,
-  5182,
-  3468,
-  4221,
-  2282,
-  28,
-  4538,
-  116,
-  2145,
-  6250,
-  26,
-  24,
-  5933,
-  4219,
-  474,
-  4217,
-  5931,
-  114,
-  484,
-  214,
-  4859,
-  2151,
-  482,
-  4542,
-  4540,
-  30,
-  6254,
-  6252,
-  5608,
-  3579,
-  2362,
-  3577,
-  5291,
-  1773,
-  331,
-  466,
-  2270,
-  464,
-  2358,
-  329,
-  102,
-  327,
-  5289,
-  2763,
-  3575,
-  3900,
-  2186,
-  112,
-  2726,
-  472,
-  5929,
-  3898,
-  5612,
-  2274,
-  3896,
-  468,
-  1777,
-  5610,
-];
-
-static PRUNE_45: [usize; 69] = [
-  5056,
-  4737,
-  5377,
-  3346,
-  3344,
-  5058,
-  3015,
-  4414,
-  6126,
-  4095,
-  5807,
-  4093,
-  4735,
-  6449,
-  6447,
-  4416,
-  6128,
-  3017,
-  5484,
-  3453,
-  5165,
-  3451,
-  5805,
-  3774,
-  2827,
-  3772,
-  5486,
-  4842,
-  4523,
-  6235,
-  5163,
-  3132,
-  4844,
-  3130,
-  5912,
-  3881,
-  3879,
-  5593,
-  4521,
-  6233,
-  4202,
-  4200,
-  5914,
-  5270,
-  3239,
-  3014,
-  3237,
-  4951,
-  5591,
-  3560,
-  3016,
-  3558,
-  5272,
-  4628,
-  6342,
-  6340,
-  4309,
-  6021,
-  4307,
-  4949,
-  4630,
-  5698,
-  3667,
-  3665,
-  5379,
-  6019,
-  3988,
-  3986,
-  5700,
-];
-
-static PRUNE_19: [usize; 187] = [
-  1493,
-  4876,
-  4559,
-  5416,
-  4557,
-  1536,
-  6271,
-  1716,
-  1671,
-  1579,
-  6269,
-  3383,
-  4555,
-  1489,
-  1679,
-  4025,
-  5197,
-  1497,
-  1632,
-  1587,
-  5737,
-  4880,
-  4878,
-  1677,
-  3704,
-  3917,
-  4774,
-  1708,
-  5629,
-  5946,
-  1569,
-  3915,
-  3913,
-  5627,
-  4238,
-  5095,
-  6267,
-  1622,
-  1667,
-  4236,
-  5950,
-  5948,
-  1528,
-  1663,
-  4234,
-  5304,
-  4132,
-  3275,
-  1514,
-  3273,
-  1559,
-  5844,
-  4987,
-  3046,
-  4985,
-  3811,
-  3271,
-  1602,
-  1692,
-  5625,
-  3596,
-  4453,
-  1792,
-  5308,
-  6165,
-  3594,
-  2377,
-  1563,
-  5306,
-  3592,
-  5202,
-  1504,
-  4662,
-  6374,
-  4345,
-  6057,
-  1592,
-  4343,
-  1682,
-  4341,
-  6055,
-  3044,
-  4983,
-  1508,
-  4666,
-  5523,
-  4664,
-  6378,
-  3490,
-  6376,
-  1686,
-  5732,
-  3703,
-  1584,
-  4560,
-  5415,
-  3701,
-  6272,
-  3161,
-  1492,
-  1582,
-  1490,
-  3159,
-  1670,
-  3699,
-  5413,
-  4881,
-  4024,
-  1813,
-  1543,
-  6053,
-  1723,
-  1676,
-  4022,
-  5736,
-  2398,
-  1496,
-  4020,
-  5734,
-  1842,
-  5090,
-  1570,
-  4773,
-  5630,
-  4771,
-  1615,
-  1750,
-  3597,
-  1523,
-  2425,
-  1803,
-  5411,
-  4239,
-  3382,
-  1668,
-  3380,
-  2388,
-  5094,
-  5951,
-  3155,
-  5092,
-  3918,
-  3378,
-  4131,
-  4988,
-  1560,
-  6160,
-  1783,
-  5843,
-  4129,
-  1513,
-  4127,
-  1511,
-  5841,
-  4769,
-  1701,
-  4452,
-  5309,
-  6164,
-  4450,
-  6162,
-  1562,
-  4448,
-  3276,
-  5518,
-  1595,
-  3489,
-  4346,
-  5201,
-  6058,
-  3487,
-  1501,
-  3485,
-  5199,
-  3810,
-  5839,
-  4667,
-  1691,
-  6379,
-  1689,
-  5522,
-  1599,
-  3041,
-  3808,
-  3039,
-  5520,
-  3806,
-];
-
-static PRUNE_7: [usize; 89] = [
-  726,
-  2800,
-  2033,
-  2798,
-  1986,
-  724,
-  2481,
-  2806,
-  2084,
-  2622,
-  728,
-  2023,
-  1888,
-  1886,
-  2473,
-  714,
-  2471,
-  2109,
-  2117,
-  720,
-  1892,
-  2657,
-  2477,
-  718,
-  2463,
-  704,
-  2506,
-  1874,
-  2776,
-  2052,
-  700,
-  2107,
-  710,
-  708,
-  1970,
-  2780,
-  1878,
-  2541,
-  2813,
-  2809,
-  2087,
-  2042,
-  2097,
-  2772,
-  2093,
-  2801,
-  2079,
-  727,
-  725,
-  2120,
-  1993,
-  2083,
-  731,
-  2128,
-  715,
-  2474,
-  2110,
-  713,
-  1885,
-  2470,
-  1893,
-  2795,
-  721,
-  2480,
-  2478,
-  719,
-  1889,
-  1979,
-  717,
-  1877,
-  1875,
-  2100,
-  703,
-  2462,
-  2775,
-  699,
-  2008,
-  709,
-  1973,
-  2781,
-  705,
-  2104,
-  2090,
-  2135,
-  1996,
-  2086,
-  734,
-  2773,
-  2814,
-];
-
-static PRUNE_38: [usize; 153] = [
-  4289,
-  771,
-  2980,
-  6318,
-  4287,
-  6001,
-  4285,
-  5999,
-  4610,
-  4927,
-  2984,
-  2082,
-  6322,
-  4608,
-  6320,
-  4606,
-  2487,
-  5676,
-  3647,
-  3645,
-  849,
-  5359,
-  2428,
-  804,
-  5357,
-  802,
-  2968,
-  3643,
-  5997,
-  3111,
-  3968,
-  2976,
-  5680,
-  3109,
-  3966,
-  763,
-  853,
-  2205,
-  3107,
-  3964,
-  5678,
-  794,
-  5034,
-  792,
-  4717,
-  2958,
-  6429,
-  2866,
-  4715,
-  6427,
-  4713,
-  2954,
-  2864,
-  2919,
-  2964,
-  3326,
-  5355,
-  2602,
-  3324,
-  5038,
-  5036,
-  3322,
-  2962,
-  4075,
-  6104,
-  2858,
-  5787,
-  4073,
-  870,
-  2854,
-  780,
-  5785,
-  4071,
-  743,
-  4396,
-  6425,
-  2950,
-  4394,
-  6108,
-  831,
-  4392,
-  6106,
-  2891,
-  5462,
-  3433,
-  860,
-  3431,
-  3116,
-  5145,
-  3429,
-  5143,
-  2934,
-  3114,
-  858,
-  2310,
-  5783,
-  868,
-  3754,
-  3752,
-  5466,
-  866,
-  3750,
-  2983,
-  5464,
-  4503,
-  6215,
-  4501,
-  4499,
-  6213,
-  2705,
-  856,
-  5141,
-  4824,
-  4822,
-  1124,
-  4820,
-  5890,
-  3861,
-  3859,
-  5573,
-  838,
-  746,
-  836,
-  2820,
-  5571,
-  3857,
-  4182,
-  2920,
-  5894,
-  2963,
-  6211,
-  4180,
-  5892,
-  4178,
-  2947,
-  2857,
-  5248,
-  828,
-  4931,
-  3217,
-  4929,
-  3215,
-  2990,
-  5569,
-  3540,
-  787,
-  3538,
-  2861,
-  5252,
-  5250,
-  2994,
-  3219,
-  3536,
-];
-
-static PRUNE_12: [usize; 190] = [
-  3252,
-  5281,
-  2215,
-  3250,
-  4964,
-  49,
-  94,
-  2708,
-  92,
-  2211,
-  4962,
-  2301,
-  3248,
-  2761,
-  55,
-  3573,
-  2174,
-  2219,
-  5602,
-  3571,
-  5285,
-  53,
-  51,
-  3569,
-  5283,
-  2293,
-  6353,
-  4639,
-  1931,
-  6351,
-  4322,
-  4320,
-  6034,
-  2156,
-  307,
-  2246,
-  2199,
-  305,
-  2289,
-  80,
-  2254,
-  4960,
-  90,
-  2164,
-  4643,
-  2162,
-  2207,
-  6355,
-  4641,
-  2297,
-  1921,
-  3680,
-  207,
-  3138,
-  5709,
-  2189,
-  3136,
-  5392,
-  3678,
-  205,
-  3676,
-  2729,
-  5390,
-  1962,
-  2549,
-  303,
-  4318,
-  6032,
-  2152,
-  4001,
-  6030,
-  5713,
-  211,
-  3999,
-  301,
-  5711,
-  209,
-  3997,
-  2148,
-  5067,
-  2226,
-  2451,
-  2181,
-  4750,
-  2719,
-  4748,
-  2177,
-  2222,
-  3359,
-  5388,
-  3134,
-  2232,
-  5071,
-  3357,
-  1866,
-  3355,
-  5069,
-  95,
-  1899,
-  3026,
-  4425,
-  2486,
-  2711,
-  6139,
-  50,
-  6137,
-  48,
-  93,
-  2304,
-  4108,
-  2214,
-  2259,
-  4106,
-  5820,
-  3024,
-  3022,
-  5818,
-  4104,
-  2165,
-  2210,
-  2572,
-  91,
-  4746,
-  54,
-  4429,
-  4427,
-  6141,
-  52,
-  3466,
-  2292,
-  5178,
-  5495,
-  308,
-  306,
-  2155,
-  3464,
-  2562,
-  81,
-  3462,
-  2290,
-  5176,
-  3787,
-  5816,
-  2208,
-  2161,
-  2296,
-  2748,
-  3785,
-  5499,
-  5497,
-  3783,
-  2192,
-  2147,
-  4853,
-  208,
-  206,
-  4536,
-  2732,
-  6248,
-  4534,
-  5174,
-  304,
-  2153,
-  4857,
-  212,
-  302,
-  2196,
-  2241,
-  210,
-  2599,
-  4855,
-  4211,
-  5923,
-  3894,
-  5606,
-  1908,
-  3892,
-  3890,
-  5604,
-  2231,
-  6246,
-  4532,
-  6244,
-  4215,
-  4213,
-  5927,
-  2184,
-  2229,
-  5925,
-  2724,
-];
-
-static PRUNE_43: [usize; 134] = [
-  3342,
-  5054,
-  3657,
-  5371,
-  3340,
-  5369,
-  3338,
-  5052,
-  3663,
-  3123,
-  5692,
-  3121,
-  3978,
-  5690,
-  5375,
-  3661,
-  3659,
-  5373,
-  4729,
-  6443,
-  6441,
-  4727,
-  4412,
-  4410,
-  6124,
-  3013,
-  6439,
-  3336,
-  5050,
-  4733,
-  808,
-  6445,
-  5048,
-  4731,
-  3770,
-  4087,
-  5799,
-  4085,
-  5797,
-  3001,
-  5482,
-  3768,
-  3766,
-  5480,
-  6122,
-  4408,
-  4406,
-  6120,
-  4091,
-  4089,
-  6118,
-  5803,
-  5801,
-  2825,
-  3128,
-  3443,
-  5157,
-  3126,
-  4840,
-  5155,
-  4838,
-  3449,
-  3764,
-  5478,
-  5476,
-  3447,
-  5161,
-  3445,
-  874,
-  5159,
-  4515,
-  6229,
-  6227,
-  4513,
-  4198,
-  4196,
-  5910,
-  5908,
-  6225,
-  4836,
-  4519,
-  6231,
-  4517,
-  4834,
-  3556,
-  3871,
-  5585,
-  5583,
-  3554,
-  5268,
-  3552,
-  5266,
-  4194,
-  4192,
-  5906,
-  3877,
-  5904,
-  5589,
-  3875,
-  5587,
-  3873,
-  4943,
-  3229,
-  4626,
-  6338,
-  4941,
-  4624,
-  3235,
-  5264,
-  4947,
-  3550,
-  3233,
-  5262,
-  3231,
-  4945,
-  4301,
-  6015,
-  4299,
-  3984,
-  6013,
-  6011,
-  3982,
-  5696,
-  5694,
-  3980,
-  6336,
-  4622,
-  4620,
-  6334,
-  4305,
-  6017,
-  4303,
-  875,
-  6332,
-];
-
-static PRUNE_31: [usize; 215] = [
-  1448,
-  3522,
-  1403,
-  1446,
-  1086,
-  4694,
-  6408,
-  1084,
-  6406,
-  5234,
-  4377,
-  4375,
-  6089,
-  902,
-  1444,
-  632,
-  5017,
-  1454,
-  3303,
-  5015,
-  2941,
-  1092,
-  3843,
-  2851,
-  1137,
-  1090,
-  4698,
-  1360,
-  5555,
-  6410,
-  4696,
-  1088,
-  1405,
-  638,
-  5766,
-  4052,
-  2835,
-  1076,
-  4592,
-  3735,
-  624,
-  622,
-  5764,
-  1389,
-  3733,
-  1027,
-  2921,
-  6304,
-  5447,
-  1117,
-  3201,
-  6087,
-  1442,
-  630,
-  1082,
-  2929,
-  4913,
-  4373,
-  1440,
-  6085,
-  4056,
-  1438,
-  1393,
-  5768,
-  1078,
-  2927,
-  4054,
-  1303,
-  2868,
-  3410,
-  614,
-  5124,
-  5122,
-  612,
-  1109,
-  387,
-  3950,
-  4805,
-  5662,
-  3091,
-  3731,
-  5445,
-  5443,
-  4271,
-  3414,
-  618,
-  5983,
-  616,
-  3412,
-  1473,
-  5126,
-  3308,
-  1322,
-  4480,
-  6194,
-  915,
-  4163,
-  600,
-  5020,
-  6192,
-  4161,
-  1140,
-  5875,
-  608,
-  4803,
-  606,
-  3629,
-  4801,
-  2860,
-  4484,
-  5341,
-  6196,
-  4482,
-  1371,
-  604,
-  3838,
-  1087,
-  5552,
-  3521,
-  4378,
-  6090,
-  1445,
-  5233,
-  1085,
-  5550,
-  1398,
-  3519,
-  5873,
-  4159,
-  1453,
-  1408,
-  2897,
-  3842,
-  4699,
-  5871,
-  1406,
-  1404,
-  637,
-  1089,
-  6411,
-  3840,
-  5554,
-  4910,
-  1437,
-  3196,
-  4908,
-  623,
-  3736,
-  5448,
-  4591,
-  4589,
-  6303,
-  3517,
-  5231,
-  5229,
-  3200,
-  1351,
-  1441,
-  4057,
-  4912,
-  1394,
-  5769,
-  3198,
-  899,
-  2881,
-  2926,
-  5980,
-  388,
-  613,
-  1020,
-  3949,
-  4806,
-  4266,
-  928,
-  386,
-  5978,
-  3092,
-  1376,
-  5661,
-  1106,
-  2865,
-  3947,
-  3415,
-  4587,
-  6301,
-  6299,
-  1112,
-  4270,
-  5127,
-  617,
-  4268,
-  615,
-  390,
-  5982,
-  3624,
-  5338,
-  5336,
-  3307,
-  4164,
-  1458,
-  3305,
-  5876,
-  5019,
-  1094,
-  3088,
-  3945,
-  5659,
-  3628,
-  4485,
-  6197,
-  605,
-  5657,
-  3086,
-  3626,
-  5340,
-];
-
-static PRUNE_5: [usize; 68] = [
-  2620,
-  2125,
-  2035,
-  2123,
-  2031,
-  2528,
-  687,
-  2039,
-  2129,
-  1990,
-  683,
-  2655,
-  2021,
-  1976,
-  2606,
-  1984,
-  2027,
-  2070,
-  1980,
-  2115,
-  2011,
-  2504,
-  2062,
-  2512,
-  692,
-  2044,
-  2539,
-  688,
-  2627,
-  2132,
-  698,
-  2005,
-  2050,
-  2003,
-  694,
-  2034,
-  682,
-  2077,
-  2122,
-  2030,
-  2130,
-  2040,
-  2490,
-  686,
-  1991,
-  2126,
-  2609,
-  1977,
-  2028,
-  1983,
-  2615,
-  1981,
-  2114,
-  2057,
-  2642,
-  2018,
-  2014,
-  2509,
-  2000,
-  691,
-  2630,
-  2133,
-  2493,
-  2006,
-  697,
-  2499,
-  2049,
-  2047,
-];
-
-static PRUNE_36: [usize; 163] = [
-  276,
-  366,
-  5778,
-  274,
-  4604,
-  3745,
-  6316,
-  272,
-  814,
-  769,
-  767,
-  5457,
-  812,
-  777,
-  822,
-  3213,
-  6099,
-  4925,
-  370,
-  4066,
-  368,
-  820,
-  775,
-  2892,
-  3962,
-  3105,
-  2880,
-  174,
-  2878,
-  757,
-  5674,
-  1434,
-  3101,
-  4815,
-  2831,
-  1072,
-  2876,
-  4283,
-  180,
-  270,
-  178,
-  1170,
-  810,
-  765,
-  5995,
-  3424,
-  761,
-  5136,
-  176,
-  2882,
-  6206,
-  3320,
-  5032,
-  747,
-  4173,
-  790,
-  2874,
-  3641,
-  1070,
-  1160,
-  800,
-  798,
-  5353,
-  2915,
-  4494,
-  647,
-  152,
-  5564,
-  4390,
-  2856,
-  150,
-  3531,
-  6102,
-  5243,
-  2944,
-  788,
-  833,
-  5885,
-  1418,
-  2862,
-  156,
-  4711,
-  154,
-  6423,
-  3852,
-  784,
-  3748,
-  2846,
-  275,
-  365,
-  817,
-  5460,
-  815,
-  2844,
-  273,
-  271,
-  813,
-  4601,
-  371,
-  4069,
-  2940,
-  2850,
-  776,
-  821,
-  819,
-  369,
-  5781,
-  3210,
-  367,
-  4922,
-  2848,
-  2893,
-  760,
-  5992,
-  2924,
-  173,
-  4818,
-  3959,
-  758,
-  179,
-  6313,
-  811,
-  3427,
-  5139,
-  2658,
-  809,
-  269,
-  177,
-  175,
-  4280,
-  4176,
-  1065,
-  3317,
-  5888,
-  5029,
-  2910,
-  1061,
-  394,
-  5671,
-  4497,
-  3638,
-  6209,
-  5350,
-  151,
-  2902,
-  6420,
-  3534,
-  783,
-  5246,
-  781,
-  372,
-  2988,
-  4387,
-  779,
-  824,
-  157,
-  3855,
-  5567,
-  155,
-  785,
-  2906,
-  4708,
-  153,
-];
-
-static PRUNE_24: [usize; 165] = [
-  5643,
-  1176,
-  3927,
-  1266,
-  5639,
-  3610,
-  589,
-  2663,
-  5322,
-  2661,
-  1264,
-  3068,
-  542,
-  10,
-  2671,
-  957,
-  5964,
-  4248,
-  1227,
-  3931,
-  8,
-  2667,
-  5960,
-  2700,
-  941,
-  129,
-  127,
-  3285,
-  3058,
-  1209,
-  1254,
-  4997,
-  937,
-  4680,
-  3066,
-  133,
-  1260,
-  3606,
-  945,
-  1665,
-  5318,
-  131,
-  3289,
-  5001,
-  974,
-  6071,
-  4355,
-  252,
-  342,
-  250,
-  4038,
-  2686,
-  972,
-  248,
-  6067,
-  6392,
-  348,
-  4676,
-  1203,
-  346,
-  1201,
-  344,
-  6388,
-  4359,
-  3173,
-  2903,
-  1234,
-  1232,
-  3171,
-  2676,
-  3713,
-  15,
-  13,
-  960,
-  5425,
-  3396,
-  5108,
-  5750,
-  3179,
-  4034,
-  1238,
-  246,
-  966,
-  5746,
-  3717,
-  5429,
-  2678,
-  964,
-  3175,
-  2664,
-  543,
-  4783,
-  1175,
-  1218,
-  1263,
-  4466,
-  1183,
-  11,
-  9,
-  3392,
-  2670,
-  2668,
-  1226,
-  5104,
-  4787,
-  1179,
-  3061,
-  5857,
-  398,
-  4141,
-  128,
-  126,
-  3824,
-  5536,
-  936,
-  5853,
-  2660,
-  541,
-  6178,
-  4462,
-  539,
-  1214,
-  3063,
-  4145,
-  944,
-  132,
-  942,
-  130,
-  1212,
-  6174,
-  343,
-  251,
-  341,
-  3499,
-  5211,
-  971,
-  1196,
-  4894,
-  249,
-  1251,
-  3053,
-  3820,
-  347,
-  2693,
-  5532,
-  977,
-  3503,
-  5215,
-  2689,
-  345,
-  6285,
-  1548,
-  2675,
-  14,
-  4252,
-  4569,
-  1186,
-  1229,
-  6281,
-  12,
-  247,
-  245,
-  4890,
-  4573,
-  2679,
-];
-
-static PRUNE_29: [usize; 119] = [
-  1223,
-  5011,
-  1358,
-  1356,
-  906,
-  4692,
-  5332,
-  1364,
-  3301,
-  2939,
-  910,
-  2849,
-  3299,
-  5013,
-  1301,
-  1346,
-  896,
-  6081,
-  1299,
-  1344,
-  4050,
-  4048,
-  5762,
-  1307,
-  1352,
-  4690,
-  6404,
-  1080,
-  6402,
-  4371,
-  2837,
-  6083,
-  4369,
-  5439,
-  884,
-  3408,
-  385,
-  925,
-  880,
-  5120,
-  3406,
-  1025,
-  888,
-  1295,
-  5760,
-  1023,
-  3729,
-  1336,
-  5441,
-  3727,
-  1369,
-  1009,
-  4797,
-  4478,
-  2899,
-  598,
-  6190,
-  1320,
-  5118,
-  4799,
-  5867,
-  3836,
-  3834,
-  5548,
-  1318,
-  913,
-  4476,
-  1003,
-  6188,
-  1271,
-  4157,
-  1314,
-  5869,
-  4155,
-  5225,
-  3194,
-  2879,
-  1075,
-  4906,
-  3192,
-  5546,
-  3515,
-  1349,
-  582,
-  5227,
-  3513,
-  4583,
-  1018,
-  6295,
-  881,
-  926,
-  4264,
-  5976,
-  4262,
-  4904,
-  889,
-  4585,
-  6297,
-  885,
-  1292,
-  5653,
-  3622,
-  916,
-  3082,
-  1366,
-  599,
-  1006,
-  3620,
-  5334,
-  1014,
-  1012,
-  1374,
-  5974,
-  3943,
-  1327,
-  3084,
-  3941,
-  5655,
-  920,
-];
-
-static PRUNE_17: [usize; 208] = [
-  1538,
-  2395,
-  3477,
-  1718,
-  501,
-  4874,
-  499,
-  1808,
-  5189,
-  2393,
-  1624,
-  1714,
-  4872,
-  1534,
-  3483,
-  507,
-  505,
-  2264,
-  2309,
-  5195,
-  3798,
-  3481,
-  5510,
-  1720,
-  1810,
-  1900,
-  1540,
-  5193,
-  1630,
-  1753,
-  1798,
-  6263,
-  4547,
-  4232,
-  1526,
-  2383,
-  1616,
-  1706,
-  1661,
-  4230,
-  6259,
-  5944,
-  2336,
-  5942,
-  2379,
-  1794,
-  4868,
-  4553,
-  1710,
-  4551,
-  6265,
-  1530,
-  1620,
-  1696,
-  3590,
-  1786,
-  2418,
-  2371,
-  5302,
-  3905,
-  2324,
-  3588,
-  5617,
-  5300,
-  3911,
-  1612,
-  1702,
-  1610,
-  1790,
-  4226,
-  1520,
-  1518,
-  2375,
-  5938,
-  3909,
-  5623,
-  5621,
-  1743,
-  1698,
-  1833,
-  2316,
-  3263,
-  1639,
-  4975,
-  4660,
-  6372,
-  4658,
-  510,
-  3584,
-  1735,
-  3269,
-  518,
-  4981,
-  3267,
-  2410,
-  1825,
-  5296,
-  4979,
-  1629,
-  1627,
-  4333,
-  1717,
-  4018,
-  500,
-  1537,
-  6045,
-  5730,
-  4016,
-  3157,
-  496,
-  5728,
-  1805,
-  506,
-  6370,
-  4654,
-  1768,
-  4339,
-  1541,
-  4337,
-  6051,
-  1721,
-  1811,
-  504,
-  2353,
-  502,
-  6049,
-  2396,
-  6366,
-  1617,
-  3376,
-  3151,
-  2339,
-  3149,
-  3374,
-  5088,
-  1525,
-  3691,
-  5403,
-  1705,
-  1840,
-  1703,
-  1793,
-  1748,
-  5086,
-  1613,
-  3697,
-  2390,
-  1533,
-  1713,
-  1531,
-  1756,
-  1711,
-  4012,
-  5724,
-  2386,
-  3153,
-  1801,
-  3695,
-  5409,
-  1619,
-  5407,
-  2372,
-  4761,
-  1695,
-  4446,
-  6158,
-  4444,
-  519,
-  2378,
-  2423,
-  1521,
-  3370,
-  4767,
-  1789,
-  4765,
-  2331,
-  5082,
-  1654,
-  1609,
-  1699,
-  1517,
-  1787,
-  2374,
-  1820,
-  3804,
-  3037,
-  511,
-  2405,
-  4119,
-  5831,
-  2313,
-  104,
-  3035,
-  5516,
-  3802,
-  5514,
-  1646,
-  1781,
-  6156,
-  4440,
-  2366,
-  4125,
-  6152,
-  4123,
-  5837,
-  5835,
-  513,
-  1732,
-];
-
-static PRUNE_22: [usize; 169] = [
-  546,
-  4244,
-  5958,
-  1178,
-  3929,
-  5956,
-  5641,
-  4242,
-  3608,
-  5954,
-  3925,
-  6279,
-  4565,
-  4250,
-  3166,
-  550,
-  5962,
-  1225,
-  1180,
-  6277,
-  4563,
-  6275,
-  4246,
-  4999,
-  3602,
-  5316,
-  3600,
-  2698,
-  534,
-  5314,
-  3283,
-  5312,
-  397,
-  532,
-  1207,
-  3056,
-  530,
-  3281,
-  4995,
-  125,
-  2841,
-  5637,
-  3923,
-  3921,
-  5635,
-  5320,
-  3604,
-  3287,
-  5633,
-  6386,
-  4672,
-  4357,
-  6069,
-  6384,
-  4670,
-  6382,
-  2369,
-  4353,
-  4678,
-  2694,
-  4993,
-  3054,
-  3279,
-  4991,
-  6390,
-  4674,
-  4030,
-  5744,
-  3715,
-  5742,
-  4028,
-  5427,
-  3711,
-  553,
-  3394,
-  1185,
-  3169,
-  5740,
-  1555,
-  4351,
-  2682,
-  6065,
-  4036,
-  381,
-  5748,
-  1193,
-  4349,
-  6063,
-  4032,
-  6061,
-  4785,
-  3388,
-  3386,
-  5100,
-  5098,
-  4781,
-  2842,
-  5423,
-  3709,
-  3707,
-  5421,
+  595,
+  596,
+  601,
+  602,
+  603,
+  604,
+  605,
+  606,
+  607,
+  608,
+  624,
+  625,
+  626,
+  627,
+  628,
+  629,
+  630,
+  631,
+  648,
+  649,
+  650,
+  651,
+  656,
+  657,
+  658,
+  659,
+  660,
+  661,
+  662,
+  663,
+  679,
+  680,
+  681,
+  682,
+  683,
+  684,
+  685,
+  686,
+  703,
+  704,
+  705,
+  706,
+  711,
+  712,
+  713,
+  714,
+  715,
+  716,
+  717,
+  718,
+  734,
+  735,
+  736,
+  737,
+  738,
+  739,
+  740,
+  741,
+  758,
+  759,
+  760,
+  761,
+  766,
+  767,
+  768,
+  769,
+  770,
+  771,
+  772,
+  773,
+  789,
+  790,
+  791,
+  792,
+  793,
+  794,
+  795,
+  796,
+  813,
+  814,
+  815,
+  816,
+  821,
+  822,
+  823,
+  824,
+  825,
+  826,
+  827,
+  828,
+  844,
+  845,
+  846,
+  847,
+  848,
+  849,
+  850,
+  851,
+  868,
+  869,
+  870,
+  871,
+  876,
+  877,
+  878,
+  879,
+  880,
+  881,
+  882,
+  883,
+  899,
+  900,
+  901,
+  902,
+  903,
+  904,
+  905,
+  906,
+  923,
+  924,
+  925,
+  926,
+  931,
+  932,
+  933,
+  934,
+  935,
+  936,
+  937,
+  938,
+  954,
+  955,
+  956,
+  957,
+  958,
+  959,
+  960,
+  961,
+  978,
+  979,
+  980,
+  981,
+  986,
+  987,
+  988,
+  989,
+  990,
+  991,
+  992,
+  993,
+  1009,
+  1010,
+  1011,
+  1012,
+  1013,
+  1014,
+  1015,
+  1016,
+  1033,
+  1034,
+  1035,
+  1036,
+  1041,
+  1042,
+  1043,
+  1044,
+  1045,
+  1046,
+  1047,
+  1048,
+  1064,
+  1065,
+  1066,
+  1067,
+  1068,
+  1069,
+  1070,
+  1071,
+  1088,
+  1089,
+  1090,
+  1091,
+  1096,
+  1097,
+  1098,
+  1099,
+  1100,
+  1101,
+  1102,
+  1103,
+  1119,
+  1120,
+  1121,
+  1122,
+  1123,
+  1124,
+  1125,
+  1126,
+  1143,
+  1144,
+  1145,
+  1146,
+  1151,
+  1152,
+  1153,
+  1154,
+  1155,
+  1156,
+  1157,
+  1158,
+  1174,
+  1175,
+  1176,
+  1177,
+  1178,
+  1179,
+  1180,
   1181,
-  5106,
-  3390,
-  5419,
-  549,
-  5102,
-  4458,
-  6172,
-  4143,
-  533,
-  5855,
+  1198,
+  1199,
+  1200,
+  1201,
+  1206,
+  1207,
+  1208,
+  1209,
   1210,
-  4456,
-  6170,
-  2697,
-  2695,
-  6168,
-  4139,
-  531,
-  4464,
-  6176,
-  1576,
-  4779,
-  537,
-  4777,
-  4460,
-  3049,
-  3816,
-  5530,
-  5528,
-  3814,
-  1605,
-  5213,
-  3497,
-  5209,
-  5526,
-  4137,
-  1566,
-  3822,
-  5851,
-  5849,
-  5534,
-  3818,
-  527,
-  4135,
-  3501,
-  5847,
-  3051,
-  4571,
-  1188,
-  1685,
-  6283,
-  4886,
-  4884,
-  1546,
-  1184,
-  4567,
-  1726,
-  4892,
-  2683,
-  3495,
-  3493,
-  5207,
-  1507,
-  5205,
-  1192,
-  4888,
+  1211,
+  1212,
+  1213,
+  1229,
+  1230,
+  1231,
+  1232,
+  1233,
+  1234,
   1235,
-];
-
-static PRUNE_41: [usize; 84] = [
-  3974,
-  5688,
-  5686,
-  3655,
-  3653,
-  5367,
-  6009,
-  4295,
-  6007,
-  3119,
-  3976,
-  773,
-  1074,
-  5044,
-  3011,
-  4725,
-  5365,
-  3334,
-  5046,
-  1483,
-  3332,
-  2972,
-  4402,
-  2823,
-  6116,
-  6114,
-  3003,
-  4083,
-  3009,
-  6437,
-  4723,
-  6435,
-  3005,
-  4404,
-  3760,
-  5472,
-  3441,
-  5153,
-  3439,
-  4081,
-  5795,
-  5793,
-  3762,
-  5474,
-  4830,
-  2887,
-  4511,
-  1273,
-  5151,
-  774,
-  864,
-  4832,
-  5902,
-  4188,
-  1482,
-  5900,
-  3869,
-  2830,
-  4509,
-  6223,
+  1236,
+  1253,
+  1254,
+  1255,
+  1256,
+  1261,
+  1262,
+  1263,
+  1264,
+  1265,
+  1266,
+  1267,
+  1268,
+  1284,
+  1285,
+  1286,
+  1287,
+  1288,
+  1289,
+  1290,
+  1291,
+  1308,
+  1309,
+  1310,
+  1311,
+  1316,
+  1317,
+  1318,
+  1319,
+  1320,
+  1321,
+  1322,
+  1323,
+  1339,
+  1340,
+  1341,
+  1342,
+  1343,
+  1344,
+  1345,
+  1346,
+  1365,
+  1366,
+  1367,
+  1368,
+  1369,
+  1370,
+  1371,
+  1372,
+  1388,
+  1389,
+  1390,
+  1391,
+  1392,
+  1393,
+  1394,
+  1395,
+  1414,
+  1415,
+  1416,
+  1417,
+  1418,
+  1419,
+  1420,
+  1421,
+  1437,
+  1438,
+  1439,
+  1440,
+  1441,
+  1442,
+  1443,
+  1444,
+  1463,
+  1464,
+  1465,
+  1466,
+  1467,
+  1468,
+  1469,
+  1470,
   1486,
-  6221,
-  3018,
-  4190,
-  807,
-  1967,
-  5258,
-  3227,
-  3225,
-  4939,
-  3867,
-  5581,
-  2918,
-  5579,
-  3548,
-  3546,
-  5260,
-  4616,
-  873,
-  6330,
-  6328,
-  4297,
-  4937,
-  4618,
+  1487,
+  1488,
+  1489,
+  1490,
+  1491,
+  1492,
+  1493,
+  1512,
+  1513,
+  1514,
+  1515,
+  1516,
+  1517,
+  1518,
+  1519,
+  1535,
+  1536,
+  1537,
+  1538,
+  1539,
+  1540,
+  1541,
+  1542,
+  1561,
+  1562,
+  1563,
+  1564,
+  1565,
+  1566,
+  1567,
+  1568,
+  1584,
+  1585,
+  1586,
+  1587,
+  1588,
+  1589,
+  1590,
+  1591,
+  1610,
+  1611,
+  1612,
+  1613,
+  1614,
+  1615,
+  1616,
+  1617,
+  1633,
+  1634,
+  1635,
+  1636,
+  1637,
+  1638,
+  1639,
+  1640,
+  1659,
+  1660,
+  1661,
+  1662,
+  1663,
+  1664,
+  1665,
+  1666,
+  1682,
+  1683,
+  1684,
+  1685,
+  1686,
+  1687,
+  1688,
+  1689,
+  1708,
+  1709,
+  1710,
+  1711,
+  1712,
+  1713,
+  1714,
+  1715,
+  1731,
+  1732,
+  1733,
+  1734,
+  1735,
+  1736,
+  1737,
+  1738,
+  1757,
+  1758,
+  1759,
+  1760,
+  1761,
+  1762,
+  1763,
+  1764,
+  1780,
+  1781,
+  1782,
+  1783,
+  1784,
+  1785,
+  1786,
+  1787,
+  1806,
+  1807,
+  1808,
+  1809,
+  1810,
+  1811,
+  1812,
+  1813,
+  1829,
+  1830,
+  1831,
+  1832,
+  1833,
+  1834,
+  1835,
+  1836,
+  1855,
+  1856,
+  1857,
+  1858,
+  1859,
+  1860,
+  1861,
+  1862,
+  1878,
+  1879,
+  1880,
+  1881,
+  1882,
+  1883,
+  1884,
+  1885,
+  1904,
+  1905,
+  1906,
+  1907,
+  1908,
+  1909,
+  1910,
+  1911,
+  1927,
+  1928,
+  1929,
+  1930,
+  1931,
+  1932,
+  1933,
+  1934,
+  1951,
+  1952,
+  1953,
+  1954,
+  1955,
+  1956,
+  1957,
+  1958,
+  1974,
+  1975,
+  1976,
+  1977,
+  1978,
+  1979,
+  1980,
+  1981,
+  1998,
+  1999,
+  2000,
+  2001,
+  2002,
+  2003,
+  2004,
+  2005,
+  2021,
+  2022,
+  2023,
+  2024,
+  2025,
+  2026,
+  2027,
+  2028,
+  2045,
+  2046,
+  2047,
+  2048,
+  2049,
+  2050,
+  2051,
+  2052,
+  2068,
+  2069,
+  2070,
+  2071,
+  2072,
+  2073,
+  2074,
+  2075,
+  5864,
+  5866,
+  5868,
+  5870,
+  5872,
+  5874,
+  5876,
+  5878,
+  5962,
+  5964,
+  5966,
+  5968,
+  5970,
+  5972,
+  5974,
+  6061,
+  6063,
+  6065,
+  6067,
+  6069,
+  6071,
+  6073,
+  6075,
+  6159,
+  6161,
+  6163,
+  6165,
+  6167,
+  6169,
+  6171,
 ];
 
-static PRUNE_10: [usize; 217] = [
-  3884,
-  5596,
-  3567,
-  1851,
-  3565,
-  5279,
-  5277,
-  2436,
-  2751,
-  3563,
-  2256,
-  2716,
-  1859,
-  4205,
-  5919,
-  5917,
-  3888,
-  3886,
-  5600,
-  2714,
-  2579,
-  2759,
-  1902,
-  5598,
-  1945,
-  2442,
-  2248,
-  4954,
-  84,
-  2743,
-  82,
-  4637,
-  442,
-  2244,
-  4635,
-  1929,
-  1884,
-  6349,
-  3246,
-  2569,
-  5275,
-  2567,
-  2792,
-  86,
-  3244,
-  2252,
-  4958,
-  3242,
-  4956,
-  72,
-  6026,
-  2553,
-  4312,
-  6024,
-  297,
-  2236,
-  3995,
-  432,
-  295,
-  5707,
-  3993,
-  1919,
-  1872,
-  203,
-  2459,
-  4633,
-  2739,
-  2784,
-  6347,
-  2469,
-  2557,
-  4316,
-  6345,
-  438,
-  436,
-  6028,
-  4314,
-  299,
-  2238,
-  2103,
-  434,
-  1923,
-  422,
-  3353,
-  1911,
-  197,
-  420,
-  5382,
-  1864,
+static PRUNE_2: [usize; 692] = [
+  59,
   60,
-  2721,
-  58,
-  5065,
-  2449,
-  3351,
-  3349,
-  5063,
-  2582,
-  2547,
-  293,
-  3991,
-  5705,
-  2817,
-  1960,
-  3674,
-  5703,
-  201,
-  3672,
-  199,
-  2138,
-  2590,
-  5386,
-  2543,
-  3670,
   62,
-  5384,
-  424,
-  4740,
-  4423,
-  1897,
-  2754,
-  2484,
-  2257,
-  6135,
-  4421,
-  5061,
-  2717,
-  56,
-  1905,
-  1903,
-  4744,
-  2713,
-  2758,
-  4742,
-  2564,
-  2249,
-  5812,
-  4098,
-  5810,
+  63,
+  65,
+  66,
+  68,
+  69,
+  82,
   83,
-  3781,
-  3779,
-  5493,
-  5491,
-  2560,
-  1928,
-  441,
-  1848,
-  1938,
-  6133,
-  4419,
-  2570,
-  6131,
-  2433,
-  4102,
-  2251,
-  4100,
-  5814,
-  87,
   85,
-  2746,
-  433,
-  73,
-  298,
-  4851,
-  296,
-  5168,
-  2597,
-  2550,
-  2235,
-  429,
-  1918,
-  204,
-  294,
-  4849,
-  3460,
-  3777,
-  2243,
-  439,
-  5489,
-  1926,
-  2736,
-  5172,
-  3458,
-  5170,
-  3456,
-  300,
-  435,
-  2239,
-  4526,
-  61,
-  6240,
-  6238,
-  2720,
-  421,
-  4209,
-  5921,
-  4207,
-  59,
-  1953,
-  1906,
-  57,
-  202,
-  427,
-  4847,
-  4530,
-  1869,
-  2771,
-  1914,
-  2546,
-  2456,
-  2544,
-  200,
-  4528,
-  63,
-  6242,
+  86,
+  88,
+  89,
+  91,
+  92,
+  102,
+  103,
+  114,
+  115,
+  117,
+  118,
+  120,
+  121,
+  123,
+  124,
+  137,
+  138,
+  140,
+  141,
+  143,
+  144,
+  146,
+  147,
+  157,
+  158,
+  169,
+  170,
+  172,
+  173,
+  175,
+  176,
+  178,
+  179,
+  192,
+  193,
+  195,
+  196,
   198,
-];
-
-static PRUNE_15: [usize; 246] = [
-  5191,
-  321,
-  319,
-  2348,
-  1763,
-  3792,
-  5506,
+  199,
+  201,
+  202,
+  212,
+  213,
+  224,
+  225,
   227,
-  5504,
-  3475,
-  3473,
-  5187,
-  317,
-  2346,
-  1634,
-  5512,
-  1814,
-  4115,
-  462,
-  5829,
-  2356,
-  460,
-  4113,
-  2399,
-  5827,
-  3031,
-  5825,
+  228,
+  230,
+  231,
+  233,
+  234,
+  247,
+  248,
+  250,
+  251,
+  253,
+  254,
+  256,
+  257,
+  267,
+  268,
+  279,
+  280,
+  282,
+  283,
+  285,
+  286,
+  288,
+  289,
+  302,
+  303,
+  305,
+  306,
+  308,
+  309,
+  311,
+  312,
+  322,
   323,
-  3796,
-  2262,
-  1765,
-  5508,
-  3794,
-  2307,
-  3479,
-  4864,
-  39,
-  4549,
-  37,
-  6261,
-  1659,
-  4862,
-  485,
-  35,
-  4545,
-  5185,
-  4870,
-  225,
-  223,
-  3471,
-  88,
-  5183,
-  221,
-  4866,
-  1741,
-  1831,
-  2328,
-  5936,
+  334,
+  335,
+  337,
+  338,
+  340,
+  341,
+  343,
+  344,
+  357,
+  358,
+  360,
+  361,
+  363,
+  364,
+  366,
+  367,
+  377,
+  378,
+  389,
+  390,
+  392,
+  393,
+  395,
+  396,
+  398,
+  399,
+  412,
+  413,
+  415,
+  416,
+  418,
+  419,
+  421,
+  422,
+  432,
+  433,
+  444,
+  445,
+  447,
+  448,
+  450,
+  451,
+  453,
+  454,
+  467,
+  468,
+  470,
+  471,
+  473,
+  474,
+  476,
+  477,
+  487,
+  488,
+  499,
+  500,
+  502,
+  503,
+  505,
+  506,
+  508,
+  509,
+  522,
+  523,
+  525,
+  526,
+  528,
+  529,
+  531,
+  532,
+  542,
+  543,
+  554,
+  555,
+  557,
+  558,
+  560,
+  561,
+  563,
+  564,
+  577,
+  578,
+  580,
+  581,
+  583,
+  584,
+  586,
+  587,
+  597,
+  598,
+  609,
+  610,
+  612,
+  613,
+  615,
+  616,
+  618,
+  619,
+  632,
+  633,
+  635,
+  636,
+  638,
+  639,
+  641,
+  642,
+  652,
+  653,
+  664,
+  665,
+  667,
+  668,
+  670,
+  671,
+  673,
+  674,
+  687,
+  688,
+  690,
+  691,
+  693,
+  694,
+  696,
+  697,
+  707,
+  708,
+  719,
+  720,
+  722,
+  723,
+  725,
+  726,
+  728,
+  729,
+  742,
+  743,
+  745,
+  746,
+  748,
+  749,
+  751,
+  752,
+  762,
+  763,
+  774,
+  775,
+  777,
+  778,
+  780,
+  781,
+  783,
+  784,
+  797,
+  798,
+  800,
+  801,
+  803,
+  804,
+  806,
+  807,
+  817,
+  818,
+  829,
+  830,
+  832,
+  833,
+  835,
+  836,
+  838,
+  839,
+  852,
+  853,
+  855,
+  856,
+  858,
+  859,
+  861,
+  862,
+  872,
+  873,
+  884,
+  885,
+  887,
+  888,
+  890,
+  891,
+  893,
+  894,
+  907,
+  908,
+  910,
+  911,
+  913,
+  914,
+  916,
+  917,
+  927,
+  928,
+  939,
+  940,
+  942,
+  943,
+  945,
+  946,
+  948,
+  949,
+  962,
+  963,
+  965,
+  966,
+  968,
+  969,
+  971,
+  972,
+  982,
+  983,
+  994,
+  995,
+  997,
+  998,
+  1000,
+  1001,
+  1003,
+  1004,
+  1017,
+  1018,
+  1020,
+  1021,
+  1023,
+  1024,
+  1026,
+  1027,
+  1037,
+  1038,
+  1049,
+  1050,
+  1052,
+  1053,
+  1055,
+  1056,
+  1058,
+  1059,
+  1072,
+  1073,
+  1075,
+  1076,
+  1078,
+  1079,
+  1081,
+  1082,
+  1092,
+  1093,
+  1104,
+  1105,
+  1107,
+  1108,
+  1110,
+  1111,
+  1113,
+  1114,
+  1127,
+  1128,
+  1130,
+  1131,
+  1133,
+  1134,
+  1136,
+  1137,
+  1147,
+  1148,
+  1159,
+  1160,
+  1162,
+  1163,
+  1165,
+  1166,
+  1168,
+  1169,
+  1182,
+  1183,
+  1185,
+  1186,
+  1188,
+  1189,
+  1191,
+  1192,
+  1202,
+  1203,
+  1214,
+  1215,
+  1217,
+  1218,
+  1220,
+  1221,
+  1223,
+  1224,
+  1237,
+  1238,
+  1240,
+  1241,
+  1243,
+  1244,
+  1246,
+  1247,
+  1257,
+  1258,
+  1269,
+  1270,
+  1272,
+  1273,
+  1275,
+  1276,
+  1278,
+  1279,
+  1292,
+  1293,
+  1295,
+  1296,
+  1298,
+  1299,
+  1301,
+  1302,
+  1312,
+  1313,
+  1324,
+  1325,
+  1327,
+  1328,
+  1330,
+  1331,
+  1333,
+  1334,
+  1347,
+  1348,
+  1350,
+  1351,
+  1353,
+  1354,
+  1356,
+  1357,
+  1373,
+  1374,
+  1376,
+  1377,
+  1379,
+  1380,
+  1382,
+  1383,
+  1396,
+  1397,
+  1399,
+  1400,
+  1402,
+  1403,
+  1405,
+  1406,
+  1422,
+  1423,
+  1425,
+  1426,
+  1428,
+  1429,
+  1431,
+  1432,
+  1445,
+  1446,
+  1448,
+  1449,
+  1451,
+  1452,
+  1454,
+  1455,
+  1471,
+  1472,
+  1474,
+  1475,
+  1477,
+  1478,
+  1480,
+  1481,
+  1494,
+  1495,
+  1497,
+  1498,
+  1500,
+  1501,
+  1503,
+  1504,
+  1520,
+  1521,
+  1523,
+  1524,
+  1526,
+  1527,
+  1529,
+  1530,
+  1543,
+  1544,
+  1546,
+  1547,
+  1549,
+  1550,
+  1552,
+  1553,
+  1569,
+  1570,
+  1572,
+  1573,
+  1575,
+  1576,
+  1578,
+  1579,
+  1592,
+  1593,
+  1595,
+  1596,
+  1598,
+  1599,
+  1601,
+  1602,
+  1618,
+  1619,
+  1621,
+  1622,
+  1624,
+  1625,
+  1627,
+  1628,
+  1641,
+  1642,
+  1644,
+  1645,
+  1647,
+  1648,
+  1650,
   1651,
-  4222,
-  4220,
-  2416,
-  2326,
-  5934,
-  1649,
-  5619,
-  2146,
-  3903,
-  5932,
-  1827,
-  3586,
-  5615,
-  1737,
-  33,
-  1837,
-  4543,
-  6257,
-  4228,
-  6255,
-  1655,
+  1667,
+  1668,
+  1670,
+  1671,
+  1673,
+  1674,
+  1676,
+  1677,
+  1690,
+  1691,
+  1693,
+  1694,
+  1696,
+  1697,
+  1699,
+  1700,
+  1716,
+  1717,
+  1719,
+  1720,
+  1722,
+  1723,
+  1725,
+  1726,
+  1739,
+  1740,
+  1742,
+  1743,
   1745,
-  4541,
-  1835,
-  5940,
-  483,
-  4224,
-  2420,
-  3907,
-  6253,
-  2361,
-  4977,
-  2271,
-  107,
-  465,
-  3578,
+  1746,
+  1748,
+  1749,
+  1765,
+  1766,
+  1768,
+  1769,
+  1771,
+  1772,
   1774,
-  5292,
-  105,
+  1775,
+  1788,
+  1789,
+  1791,
+  1792,
+  1794,
+  1795,
+  1797,
+  1798,
+  1814,
+  1815,
   1817,
-  2359,
-  3261,
-  5290,
-  1637,
-  3259,
-  2402,
-  4973,
-  2412,
-  5298,
-  3901,
-  2322,
-  471,
-  5613,
-  3899,
-  109,
-  5611,
-  1643,
-  1778,
+  1818,
+  1820,
+  1821,
   1823,
-  3582,
-  1641,
-  5294,
-  3580,
-  2318,
-  3265,
-  2408,
-  4650,
-  320,
-  4335,
-  6364,
-  6362,
-  6047,
-  4331,
-  228,
-  4648,
-  318,
+  1824,
+  1837,
+  1838,
+  1840,
+  1841,
+  1843,
+  1844,
+  1846,
+  1847,
+  1863,
+  1864,
+  1866,
+  1867,
+  1869,
+  1870,
+  1872,
+  1873,
+  1886,
+  1887,
+  1889,
+  1890,
+  1892,
+  1893,
+  1895,
+  1896,
+  1912,
+  1913,
+  1915,
+  1916,
+  1918,
+  1919,
+  1921,
+  1922,
+  1935,
+  1936,
+  1938,
+  1939,
+  1941,
+  1942,
+  1944,
+  1945,
+  1959,
+  1960,
+  1962,
+  1963,
+  1965,
+  1966,
+  1968,
+  1969,
+  1982,
+  1983,
+  1985,
+  1986,
+  1988,
+  1989,
+  1991,
+  1992,
+  2006,
+  2007,
+  2009,
+  2010,
+  2012,
+  2013,
+  2015,
+  2016,
+  2029,
+  2030,
+  2032,
+  2033,
+  2035,
+  2036,
+  2038,
+  2039,
+  2053,
+  2054,
+  2056,
+  2057,
+  2059,
+  2060,
+  2062,
+  2063,
+  2076,
+  2077,
+  2079,
+  2080,
+  2082,
+  2083,
+  2085,
+  2086,
+  5865,
+  5879,
+  5880,
+  5892,
+  5897,
+  5902,
+  5907,
+  5912,
+  5917,
+  5963,
+  5975,
+  6062,
+  6076,
+  6077,
+  6089,
+  6094,
+  6099,
+  6104,
+  6109,
+  6114,
+  6160,
+  6172,
+];
+
+static PRUNE_3: [usize; 340] = [
+  61,
+  64,
+  67,
+  70,
+  84,
+  87,
+  90,
+  93,
+  116,
+  119,
+  122,
+  125,
+  139,
+  142,
+  145,
+  148,
+  171,
+  174,
+  177,
+  180,
+  194,
+  197,
+  200,
+  203,
   226,
-  4014,
-  6360,
-  1760,
-  3257,
-  4971,
-  1815,
-  4656,
-  6368,
-  2400,
-  1633,
-  1766,
-  324,
-  459,
-  4969,
-  2171,
-  2351,
-  4652,
-  322,
-  5405,
-  38,
-  2159,
-  4008,
-  2202,
-  4006,
-  5720,
-  5718,
-  3689,
-  36,
-  1658,
-  34,
-  1838,
-  3687,
-  5401,
-  3147,
-  3372,
-  224,
-  4329,
-  6043,
-  6041,
-  4327,
-  2343,
-  5726,
-  4010,
-  222,
-  3693,
-  5722,
-  6039,
-  5078,
-  4763,
-  3364,
-  1740,
-  1830,
-  1648,
-  1738,
-  1828,
-  2415,
-  2325,
-  5076,
-  2278,
-  2413,
-  4759,
-  5084,
-  1656,
-  3145,
-  2286,
-  3143,
-  1746,
-  32,
-  3685,
-  5399,
-  1834,
-  5397,
-  3368,
-  3366,
-  2419,
+  229,
+  232,
+  235,
+  249,
+  252,
+  255,
+  258,
+  281,
+  284,
+  287,
+  290,
+  304,
+  307,
+  310,
+  313,
+  336,
+  339,
+  342,
+  345,
+  359,
+  362,
+  365,
+  368,
+  391,
+  394,
+  397,
+  400,
+  414,
+  417,
+  420,
+  423,
+  446,
+  449,
+  452,
+  455,
+  469,
+  472,
+  475,
+  478,
+  501,
+  504,
+  507,
+  510,
+  524,
+  527,
+  530,
+  533,
+  556,
+  559,
+  562,
+  565,
+  579,
+  582,
+  585,
+  588,
+  611,
+  614,
+  617,
+  620,
+  634,
+  637,
+  640,
+  643,
+  666,
+  669,
+  672,
+  675,
+  689,
+  692,
+  695,
+  698,
+  721,
+  724,
+  727,
+  730,
+  744,
+  747,
+  750,
+  753,
+  776,
+  779,
+  782,
+  785,
+  799,
+  802,
+  805,
+  808,
+  831,
+  834,
+  837,
+  840,
+  854,
+  857,
+  860,
+  863,
+  886,
+  889,
+  892,
+  895,
+  909,
+  912,
+  915,
+  918,
+  941,
+  944,
+  947,
+  950,
+  964,
+  967,
+  970,
+  973,
+  996,
+  999,
+  1002,
+  1005,
+  1019,
+  1022,
+  1025,
+  1028,
+  1051,
+  1054,
+  1057,
+  1060,
+  1074,
+  1077,
+  1080,
+  1083,
+  1106,
+  1109,
+  1112,
+  1115,
+  1129,
+  1132,
+  1135,
+  1138,
+  1161,
+  1164,
+  1167,
+  1170,
+  1184,
+  1187,
+  1190,
+  1193,
+  1216,
+  1219,
+  1222,
+  1225,
+  1239,
+  1242,
+  1245,
+  1248,
+  1271,
+  1274,
+  1277,
+  1280,
+  1294,
+  1297,
+  1300,
+  1303,
+  1326,
+  1329,
+  1332,
+  1335,
+  1349,
+  1352,
+  1355,
+  1358,
+  1375,
+  1378,
+  1381,
+  1384,
+  1398,
+  1401,
+  1404,
+  1407,
+  1424,
+  1427,
+  1430,
+  1433,
+  1447,
+  1450,
+  1453,
+  1456,
+  1473,
+  1476,
+  1479,
+  1482,
+  1496,
+  1499,
+  1502,
+  1505,
+  1522,
+  1525,
+  1528,
+  1531,
+  1545,
+  1548,
+  1551,
+  1554,
+  1571,
+  1574,
+  1577,
+  1580,
+  1594,
+  1597,
+  1600,
+  1603,
+  1620,
+  1623,
+  1626,
+  1629,
+  1643,
+  1646,
+  1649,
   1652,
-  5080,
-  2329,
-  106,
-  6150,
-  2407,
-  1640,
-  4436,
-  1818,
-  4434,
-  6148,
-  2225,
-  5833,
-  2403,
-  4117,
-  3800,
-  3033,
-  1636,
-  1771,
-  6146,
-  4757,
-  4442,
-  1779,
-  110,
-  6154,
-  2321,
-  1644,
-  2319,
-  2364,
-  4755,
-  470,
-  4121,
-  4438,
+  1669,
+  1672,
+  1675,
+  1678,
+  1692,
+  1695,
+  1698,
+  1701,
+  1718,
+  1721,
+  1724,
+  1727,
+  1741,
+  1744,
+  1747,
+  1750,
+  1767,
+  1770,
+  1773,
+  1776,
+  1790,
+  1793,
+  1796,
+  1799,
+  1816,
+  1819,
   1822,
-  108,
+  1825,
+  1839,
+  1842,
+  1845,
+  1848,
+  1865,
+  1868,
+  1871,
+  1874,
+  1888,
+  1891,
+  1894,
+  1897,
+  1914,
+  1917,
+  1920,
+  1923,
+  1937,
+  1940,
+  1943,
+  1946,
+  1961,
+  1964,
+  1967,
+  1970,
+  1984,
+  1987,
+  1990,
+  1993,
+  2008,
+  2011,
+  2014,
+  2017,
+  2031,
+  2034,
+  2037,
+  2040,
+  2055,
+  2058,
+  2061,
+  2064,
+  2078,
+  2081,
+  2084,
+  2087,
+  5881,
+  5888,
+  5893,
+  5898,
+  5903,
+  5908,
+  5913,
+  5918,
+  5976,
+  5980,
+  5983,
+  5986,
+  5989,
+  5992,
+  6078,
+  6085,
+  6090,
+  6095,
+  6100,
+  6105,
+  6110,
+  6115,
+  6173,
+  6177,
+  6180,
+  6183,
+  6186,
+  6189,
 ];
 
-static PRUNE_3: [usize; 45] = [
-  2530,
-  679,
-  2526,
-  2616,
-  2534,
-  2624,
-  2068,
-  2653,
-  2516,
-  667,
-  2064,
-  675,
-  2074,
-  2072,
-  2612,
-  673,
-  2054,
-  2639,
-  2058,
-  2645,
-  2496,
-  2537,
-  2502,
-  2637,
-  2529,
-  2075,
-  2617,
-  2625,
-  670,
-  2519,
-  2067,
-  2065,
-  2650,
+static PRUNE_4: [usize; 214] = [
+  71,
+  72,
+  94,
+  95,
+  126,
+  127,
+  149,
+  150,
+  181,
+  182,
+  204,
+  205,
+  236,
+  237,
+  259,
+  260,
+  291,
+  292,
+  314,
+  315,
+  346,
+  347,
+  369,
+  370,
+  401,
+  402,
+  424,
+  425,
+  456,
+  457,
+  479,
+  480,
+  511,
+  512,
+  534,
+  535,
+  566,
+  567,
+  589,
+  590,
+  621,
+  622,
+  644,
+  645,
   676,
-  2613,
-  2523,
-  2071,
-  672,
-  2055,
-  2640,
-  2059,
-  2646,
-  2497,
-  2501,
-  2634,
-];
-
-static PRUNE_34: [usize; 142] = [
-  6093,
-  4064,
-  5776,
-  4062,
-  1039,
-  2888,
-  2933,
-  4060,
-  5774,
-  4385,
-  6414,
-  6097,
-  4383,
-  1045,
-  6095,
-  4381,
-  5451,
+  677,
+  699,
+  700,
+  731,
+  732,
+  754,
+  755,
+  786,
+  787,
+  809,
+  810,
+  841,
+  842,
+  864,
+  865,
+  896,
+  897,
+  919,
+  920,
+  951,
+  952,
+  974,
+  975,
+  1006,
+  1007,
   1029,
-  5134,
-  3420,
-  3418,
-  1479,
-  5132,
-  3103,
-  1432,
-  1162,
-  3743,
-  1037,
-  5772,
-  3741,
-  5455,
-  5453,
-  3739,
-  1033,
-  3422,
-  1168,
-  4492,
-  1426,
-  1469,
-  6204,
-  1154,
-  2913,
-  1152,
-  4490,
+  1030,
+  1061,
   1062,
-  4488,
-  1150,
-  6202,
-  2829,
-  4813,
-  5130,
-  3099,
-  1430,
-  1068,
-  3097,
-  4811,
-  1158,
-  3095,
-  4809,
-  5879,
-  3850,
-  1097,
-  3848,
-  5562,
-  5560,
-  3529,
-  3846,
-  1410,
-  6200,
-  4171,
-  4169,
-  5883,
-  1103,
-  1148,
-  1056,
-  1416,
-  5881,
-  4167,
-  5237,
-  3206,
-  410,
-  4920,
-  3204,
-  4918,
-  4916,
-  5558,
-  3527,
-  5241,
-  5239,
-  3525,
-  3208,
-  6307,
-  4278,
+  1084,
+  1085,
+  1116,
+  1117,
+  1139,
+  1140,
+  1171,
+  1172,
+  1194,
+  1195,
+  1226,
+  1227,
+  1249,
+  1250,
+  1281,
+  1282,
+  1304,
+  1305,
+  1336,
+  1337,
+  1359,
+  1360,
+  1385,
+  1386,
+  1408,
+  1409,
+  1434,
+  1435,
+  1457,
+  1458,
+  1483,
+  1484,
+  1506,
+  1507,
+  1532,
+  1533,
+  1555,
+  1556,
+  1581,
+  1582,
+  1604,
+  1605,
+  1630,
+  1631,
+  1653,
+  1654,
+  1679,
+  1680,
+  1702,
+  1703,
+  1728,
+  1729,
+  1751,
+  1752,
+  1777,
+  1778,
+  1800,
+  1801,
+  1826,
+  1827,
+  1849,
+  1850,
+  1875,
+  1876,
+  1898,
+  1899,
+  1924,
+  1925,
+  1947,
+  1948,
+  1971,
+  1972,
+  1994,
+  1995,
+  2018,
+  2019,
+  2041,
+  2042,
+  2065,
+  2066,
+  2088,
+  2089,
+  5869,
+  5871,
+  5873,
+  5875,
+  5877,
+  5889,
+  5894,
+  5896,
+  5899,
+  5904,
+  5909,
+  5914,
+  5919,
+  5920,
+  5923,
+  5932,
+  5967,
+  5969,
+  5971,
+  5973,
+  5977,
+  5981,
+  5984,
+  5987,
   5990,
-  4276,
-  1165,
-  1388,
-  4274,
-  5988,
-  4599,
-  4597,
-  6311,
-  1034,
-  4595,
-  1122,
-  6309,
-  3636,
-  5665,
-  1063,
-  3634,
-  5348,
-  3632,
-  5346,
-  1466,
-  1421,
-  3315,
-  1476,
-  5986,
-  1116,
-  3957,
-  3955,
-  5669,
-  5667,
-  1067,
-  3953,
-  4706,
-  646,
-  1323,
-  1413,
-  1051,
-  6418,
-  4704,
-  4702,
-  6416,
-  1059,
-  5344,
-  1057,
-  3313,
-  5027,
-  3311,
-  5025,
-  5023,
+  5993,
+  5994,
+  5996,
+  6018,
+  6066,
+  6068,
+  6070,
+  6072,
+  6074,
+  6086,
+  6091,
+  6093,
+  6096,
+  6101,
+  6106,
+  6111,
+  6116,
+  6117,
+  6120,
+  6129,
+  6164,
+  6166,
+  6168,
+  6170,
+  6174,
+  6178,
+  6181,
+  6184,
+  6187,
+  6190,
+  6191,
+  6193,
+  6215,
 ];
 
-static PRUNE_39: [usize; 176] = [
-  5146,
-  3432,
-  816,
-  3117,
-  3115,
-  3972,
-  861,
-  2935,
-  5684,
-  5144,
-  859,
-  4827,
-  5467,
-  4293,
-  3436,
-  3753,
-  5465,
-  818,
-  5148,
-  3434,
-  6005,
-  2925,
-  4504,
-  6218,
-  4502,
-  2923,
-  1481,
-  3330,
-  6216,
-  6214,
-  4185,
-  847,
-  5042,
-  5897,
-  4183,
-  4825,
-  2884,
-  4823,
-  3651,
-  5363,
-  1845,
-  4506,
-  839,
-  5574,
-  4400,
-  3860,
-  3543,
-  5572,
-  2821,
-  6112,
-  3541,
-  5255,
-  4181,
-  5895,
-  5893,
-  1608,
-  4721,
-  2917,
-  3007,
-  3864,
-  3862,
-  5576,
-  796,
-  6433,
-  3218,
-  4932,
-  2991,
-  4930,
-  3758,
-  5470,
-  4613,
-  778,
-  5253,
-  3539,
-  2999,
-  4079,
-  3222,
-  2997,
-  2995,
-  5251,
-  3220,
-  4934,
-  5791,
-  2936,
-  4290,
-  772,
-  6002,
-  4288,
-  6000,
-  3971,
-  4828,
-  2977,
-  5683,
-  3112,
-  3969,
-  2942,
-  6325,
-  2852,
-  4611,
-  4609,
-  6323,
-  2985,
-  3437,
-  4292,
-  5149,
-  6321,
-  6004,
-  805,
-  3646,
-  5360,
-  2429,
-  3329,
-  4186,
-  2969,
-  5358,
-  756,
-  5041,
-  3327,
-  5898,
-  854,
-  3967,
-  5681,
-  3110,
-  2206,
-  3650,
-  4507,
-  5679,
-  3648,
-  6219,
-  5362,
-  2959,
-  4718,
-  2914,
-  6432,
-  1965,
-  6430,
-  4716,
-  3544,
-  4399,
-  5256,
-  6111,
-  6428,
-  2955,
-  3325,
-  5039,
-  2965,
-  3865,
-  2603,
-  2873,
-  5577,
-  4720,
-  5037,
-  795,
-  1728,
-  871,
-  5788,
-  4074,
-  5786,
-  3757,
-  4614,
-  3755,
-  6326,
-  5469,
-  869,
-  2908,
-  4397,
-  832,
-  4395,
-  3223,
-  6109,
-  6107,
-  2951,
-  4078,
-  4935,
-  4076,
-  5790,
+static PRUNE_5: [usize; 94] = [
+  73,
+  96,
+  128,
+  151,
+  183,
+  206,
+  238,
+  261,
+  293,
+  316,
+  348,
+  371,
+  403,
+  426,
+  458,
+  481,
+  513,
+  536,
+  568,
+  591,
+  623,
+  646,
+  678,
+  701,
+  733,
+  756,
+  788,
+  811,
+  843,
+  866,
+  898,
+  921,
+  953,
+  976,
+  1008,
+  1031,
+  1063,
+  1086,
+  1118,
+  1141,
+  1173,
+  1196,
+  1228,
+  1251,
+  1283,
+  1306,
+  1338,
+  1361,
+  1387,
+  1410,
+  1436,
+  1459,
+  1485,
+  1508,
+  1534,
+  1557,
+  1583,
+  1606,
+  1632,
+  1655,
+  1681,
+  1704,
+  1730,
+  1753,
+  1779,
+  1802,
+  1828,
+  1851,
+  1877,
+  1900,
+  1926,
+  1949,
+  1973,
+  1996,
+  2020,
+  2043,
+  2067,
+  2090,
+  5867,
+  5890,
+  5895,
+  5924,
+  5965,
+  5978,
+  5982,
+  5997,
+  6064,
+  6087,
+  6092,
+  6121,
+  6162,
+  6175,
+  6179,
+  6194,
 ];
 
-static PRUNE_27: [usize; 212] = [
-  591,
-  6183,
-  3297,
-  2937,
-  1221,
-  1311,
-  4152,
-  5009,
-  996,
-  4469,
-  904,
-  994,
-  6181,
-  407,
-  4150,
-  5864,
-  1002,
-  3618,
-  3076,
-  4790,
-  4473,
-  5330,
-  1000,
-  4471,
-  2847,
-  998,
-  6185,
-  593,
-  3827,
-  5541,
-  986,
-  894,
-  5539,
+static PRUNE_6: [usize; 169] = [
+  97,
+  104,
+  152,
+  159,
+  207,
+  214,
+  262,
+  269,
+  317,
+  324,
+  372,
+  379,
+  427,
+  434,
+  482,
+  489,
+  537,
+  544,
+  592,
+  599,
+  647,
+  654,
+  702,
+  709,
+  757,
+  764,
+  812,
+  819,
+  867,
+  874,
+  922,
+  929,
+  977,
   984,
-  4367,
-  3510,
-  5222,
-  577,
-  3508,
+  1032,
+  1039,
+  1087,
+  1094,
+  1142,
+  1149,
+  1197,
+  1204,
+  1252,
+  1259,
+  1307,
+  1314,
+  1362,
+  1363,
+  1411,
+  1412,
+  1460,
+  1461,
+  1509,
+  1510,
+  1558,
+  1559,
+  1607,
+  1608,
+  1656,
+  1657,
+  1705,
+  1706,
+  1754,
+  1755,
+  1803,
+  1804,
+  1852,
+  1853,
+  1901,
+  1902,
+  1997,
+  3760,
+  3763,
+  3766,
+  3769,
+  3772,
+  3775,
+  3778,
+  3781,
+  3784,
+  3787,
+  3790,
+  3793,
+  3796,
+  3799,
+  3802,
+  3805,
+  3808,
+  3811,
+  3814,
+  3817,
+  3820,
+  3823,
+  3826,
+  3829,
+  3832,
+  3835,
+  3838,
+  3841,
+  3844,
+  3847,
+  3850,
+  3853,
+  3858,
+  3861,
+  3864,
+  3867,
+  3870,
+  3873,
+  3876,
+  3879,
+  3882,
+  3885,
+  3888,
+  3891,
+  3894,
+  3897,
+  3900,
+  3903,
+  3906,
+  3909,
+  3912,
+  3915,
+  3918,
+  3921,
+  3924,
+  3927,
+  3930,
+  3933,
+  3936,
+  3939,
+  3942,
+  3945,
+  3948,
+  3951,
+  5882,
+  5883,
+  5891,
+  5906,
+  5911,
+  5916,
+  5922,
+  5925,
+  5929,
+  5933,
+  5937,
+  5941,
+  5979,
+  5998,
+  6002,
+  6005,
+  6008,
   6079,
-  1342,
-  575,
-  892,
-  5862,
-  4148,
-  992,
-  3831,
-  4688,
-  2839,
-  1305,
-  6400,
-  5543,
-  5860,
-  581,
-  3829,
-  1258,
-  1348,
-  3725,
-  1289,
-  4897,
-  1332,
-  1287,
-  882,
-  5437,
-  4580,
-  4578,
-  6292,
-  980,
-  935,
-  5220,
-  3506,
-  890,
-  5218,
-  3189,
-  4046,
-  1340,
-  3187,
-  1338,
-  5758,
-  4901,
-  4899,
-  1291,
-  4255,
-  5969,
-  1279,
-  4795,
-  2901,
-  3938,
-  1007,
+  6080,
+  6088,
+  6103,
+  6108,
+  6113,
+  6119,
+  6122,
+  6126,
+  6130,
+  6134,
+  6138,
+  6176,
+  6195,
+  6199,
+  6202,
+  6205,
+];
+
+static PRUNE_7: [usize; 674] = [
+  105,
+  215,
+  325,
+  435,
+  545,
+  655,
+  765,
+  875,
+  985,
+  1095,
+  1205,
+  1315,
+  1413,
+  1511,
+  1609,
+  1707,
+  1805,
+  1903,
+  2044,
+  2094,
+  2097,
+  2100,
+  2103,
+  2106,
+  2109,
+  2112,
+  2115,
+  2118,
+  2121,
+  2124,
+  2127,
+  2130,
+  2133,
+  2136,
+  2139,
+  2142,
+  2145,
+  2148,
+  2151,
+  2154,
+  2157,
+  2160,
+  2163,
+  2166,
+  2169,
+  2172,
+  2175,
+  2178,
+  2181,
+  2184,
+  2187,
+  2192,
+  2195,
+  2198,
+  2201,
+  2204,
+  2207,
+  2210,
+  2213,
+  2216,
+  2219,
+  2222,
+  2225,
+  2228,
+  2231,
+  2234,
+  2237,
+  2240,
+  2243,
+  2246,
+  2249,
+  2252,
+  2255,
+  2258,
+  2261,
+  2264,
+  2267,
+  2270,
+  2273,
+  2276,
+  2279,
+  2282,
+  2285,
+  2290,
+  2293,
+  2296,
+  2299,
+  2302,
+  2305,
+  2308,
+  2311,
+  2314,
+  2317,
+  2320,
+  2323,
+  2326,
+  2329,
+  2332,
+  2335,
+  2338,
+  2341,
+  2344,
+  2347,
+  2350,
+  2353,
+  2356,
+  2359,
+  2362,
+  2365,
+  2368,
+  2371,
+  2374,
+  2377,
+  2380,
+  2383,
+  2388,
+  2391,
+  2394,
+  2397,
+  2400,
+  2403,
+  2406,
+  2409,
+  2412,
+  2415,
+  2418,
+  2421,
+  2424,
+  2427,
+  2430,
+  2433,
+  2436,
+  2439,
+  2442,
+  2445,
+  2448,
+  2451,
+  2454,
+  2457,
+  2460,
+  2463,
+  2466,
+  2469,
+  2472,
+  2475,
+  2478,
+  2481,
+  2486,
+  2489,
+  2492,
+  2495,
+  2498,
+  2501,
+  2504,
+  2507,
+  2510,
+  2513,
+  2516,
+  2519,
+  2522,
+  2525,
+  2528,
+  2531,
+  2534,
+  2537,
+  2540,
+  2543,
+  2546,
+  2549,
+  2552,
+  2555,
+  2558,
+  2561,
+  2564,
+  2567,
+  2570,
+  2573,
+  2576,
+  2579,
+  2584,
+  2587,
+  2590,
+  2593,
+  2596,
+  2599,
+  2602,
+  2605,
+  2608,
+  2611,
+  2614,
+  2617,
+  2620,
+  2623,
+  2626,
+  2629,
+  2632,
+  2635,
+  2638,
+  2641,
+  2644,
+  2647,
+  2650,
+  2653,
+  2656,
+  2659,
+  2662,
+  2665,
+  2668,
+  2671,
   2674,
-  5967,
-  3936,
-  5650,
-  1275,
-  3404,
-  6290,
-  878,
-  1330,
-  1285,
-  563,
-  1328,
-  5116,
-  4576,
-  6288,
-  4259,
-  5971,
-  1326,
-  4257,
-  997,
-  3071,
-  5327,
-  3613,
-  5325,
-  995,
-  4153,
-  3296,
-  3294,
-  5008,
-  5865,
-  5006,
-  1308,
-  948,
-  3934,
-  5648,
-  3617,
-  5646,
-  1001,
-  4474,
-  3615,
-  5329,
-  6186,
-  1316,
-  1269,
-  999,
-  954,
-  3511,
-  1345,
-  4683,
-  985,
-  6397,
-  2877,
-  4366,
-  5223,
-  6395,
-  893,
-  891,
-  4364,
-  1343,
-  6078,
-  3832,
-  3292,
-  5004,
-  5544,
+  2677,
+  2682,
+  2685,
+  2688,
+  2691,
+  2694,
+  2697,
+  2700,
   2703,
-  4687,
-  6399,
-  4685,
-  4041,
-  1245,
-  1290,
-  5755,
-  3724,
-  4581,
-  5753,
-  883,
-  6293,
-  5436,
-  1333,
-  3182,
-  1331,
-  3722,
-  1286,
-  384,
-  6076,
-  3190,
-  574,
-  4362,
-  1206,
-  4045,
-  4902,
-  887,
-  6074,
-  1247,
-  5757,
-  4043,
-  918,
-  558,
-  3399,
-  5113,
-  3939,
-  5111,
-  1276,
-  3080,
-  5651,
-  4794,
-  1004,
-  3078,
-  1274,
-  4792,
-  1284,
-  5434,
-  3720,
-  922,
-  877,
-  5432,
-  562,
-  3403,
-  4260,
-  5115,
-  5972,
-  3401,
-  1282,
-];
-
-static PRUNE_32: [usize; 183] = [
-  636,
-  4379,
-  411,
-  364,
-  1401,
-  1131,
-  1129,
-  634,
-  6091,
-  3520,
-  5232,
-  362,
-  1399,
-  5874,
-  642,
-  1407,
-  1452,
-  640,
-  145,
-  1047,
-  4700,
-  1135,
-  2894,
-  3841,
-  6412,
-  143,
-  5553,
-  1450,
-  264,
-  1391,
-  3737,
-  5449,
-  172,
-  1436,
-  262,
-  395,
-  1477,
-  4590,
-  170,
-  4058,
-  1397,
-  1127,
-  360,
-  5770,
-  268,
-  358,
-  1395,
-  3199,
-  266,
-  4911,
-  929,
-  1381,
-  1471,
-  5981,
-  1424,
-  4807,
-  3093,
-  3948,
-  1422,
-  1377,
-  2911,
-  1107,
-  5660,
-  1105,
-  1467,
-  168,
-  3416,
-  6302,
-  393,
-  1385,
-  5128,
-  1428,
-  4269,
-  1113,
-  166,
-  391,
+  2706,
+  2709,
+  2712,
+  2715,
+  2718,
+  2721,
+  2724,
+  2727,
+  2730,
+  2733,
+  2736,
+  2739,
+  2742,
+  2745,
+  2748,
+  2751,
+  2754,
+  2757,
+  2760,
+  2763,
+  2766,
+  2769,
+  2772,
+  2775,
+  2780,
+  2783,
+  2786,
+  2789,
+  2792,
+  2795,
+  2798,
+  2801,
+  2804,
+  2807,
+  2810,
+  2813,
+  2816,
+  2819,
+  2822,
+  2825,
+  2828,
+  2831,
+  2834,
+  2837,
+  2840,
+  2843,
+  2846,
+  2849,
+  2852,
+  2855,
+  2858,
+  2861,
+  2864,
+  2867,
   2870,
-  389,
-  4165,
-  1459,
-  1144,
-  1142,
-  5877,
-  148,
-  1095,
-  3306,
-  5018,
-  4486,
+  2873,
+  2878,
+  2881,
+  2884,
+  2887,
+  2890,
+  2893,
+  2896,
+  2899,
+  2902,
+  2905,
+  2908,
+  2911,
+  2914,
+  2917,
+  2920,
+  2923,
+  2926,
+  2929,
+  2932,
+  2935,
+  2938,
+  2941,
+  2944,
+  2947,
+  2950,
+  2953,
+  2956,
+  2959,
+  2962,
+  2965,
+  2968,
+  2971,
+  2976,
+  2979,
+  2982,
+  2985,
+  2988,
+  2991,
+  2994,
+  2997,
+  3000,
+  3003,
+  3006,
+  3009,
+  3012,
+  3015,
+  3018,
+  3021,
+  3024,
+  3027,
+  3030,
+  3033,
+  3036,
+  3039,
+  3042,
+  3045,
+  3048,
+  3051,
+  3054,
+  3057,
+  3060,
+  3063,
+  3066,
+  3069,
+  3074,
+  3077,
+  3080,
+  3083,
+  3086,
   3089,
-  1101,
-  3627,
-  6198,
-  5339,
-  1461,
-  1132,
-  6409,
-  3523,
-  5235,
-  1447,
-  1402,
-  1400,
-  363,
-  1128,
-  361,
+  3092,
+  3095,
+  3098,
+  3101,
+  3104,
+  3107,
+  3110,
+  3113,
+  3116,
+  3119,
+  3122,
+  3125,
+  3128,
+  3131,
+  3134,
+  3137,
+  3140,
+  3143,
+  3146,
+  3149,
+  3152,
+  3155,
+  3158,
+  3161,
+  3164,
+  3167,
+  3172,
+  3175,
+  3178,
+  3181,
+  3184,
+  3187,
+  3190,
+  3193,
+  3196,
+  3199,
   3202,
-  903,
-  4376,
-  1083,
-  3844,
-  146,
-  1093,
-  1138,
-  5556,
-  144,
-  1136,
-  641,
-  1361,
-  1451,
-  1449,
-  4697,
-  142,
-  1134,
-  5767,
-  625,
-  1120,
-  4593,
-  263,
-  2922,
-  171,
-  2832,
-  1163,
-  261,
-  3734,
-  1118,
-  6305,
-  5446,
-  621,
-  1036,
-  2930,
-  1081,
-  1443,
-  6088,
-  359,
-  1396,
-  4914,
-  357,
-  4055,
-  2928,
-  267,
-  1077,
-  265,
-  3951,
-  1380,
-  2869,
-  5663,
-  4804,
-  1386,
-  4272,
-  169,
-  1114,
-  1384,
-  167,
-  165,
-  1474,
-  5984,
-  3413,
-  5125,
-  1098,
-  6195,
+  3205,
+  3208,
+  3211,
+  3214,
+  3217,
+  3220,
+  3223,
+  3226,
+  3229,
+  3232,
+  3235,
+  3238,
+  3241,
+  3244,
+  3247,
+  3250,
+  3253,
+  3256,
+  3259,
+  3262,
+  3265,
+  3270,
+  3273,
+  3276,
+  3279,
+  3282,
+  3285,
+  3288,
+  3291,
+  3294,
+  3297,
+  3300,
+  3303,
+  3306,
   3309,
-  5021,
-  1456,
-  4162,
-  149,
-  147,
-  1139,
+  3312,
+  3315,
+  3318,
+  3321,
+  3324,
+  3327,
+  3330,
+  3333,
+  3336,
+  3339,
+  3342,
+  3345,
+  3348,
+  3351,
+  3354,
+  3357,
+  3360,
+  3363,
+  3368,
+  3371,
+  3374,
+  3377,
+  3380,
+  3383,
+  3386,
+  3389,
+  3392,
+  3395,
+  3398,
+  3401,
+  3404,
+  3407,
+  3410,
+  3413,
+  3416,
+  3419,
+  3422,
+  3425,
+  3428,
+  3431,
+  3434,
+  3437,
+  3440,
+  3443,
+  3446,
+  3449,
+  3452,
+  3455,
+  3458,
+  3461,
+  3466,
+  3469,
+  3472,
+  3475,
+  3478,
+  3481,
+  3484,
+  3487,
+  3490,
+  3493,
+  3496,
+  3499,
+  3502,
+  3505,
+  3508,
+  3511,
+  3514,
+  3517,
+  3520,
+  3523,
+  3526,
+  3529,
+  3532,
+  3535,
+  3538,
+  3541,
+  3544,
+  3547,
+  3550,
+  3553,
+  3556,
+  3559,
+  3564,
+  3567,
+  3570,
+  3573,
+  3576,
+  3579,
+  3582,
+  3585,
+  3588,
+  3591,
+  3594,
+  3597,
+  3600,
+  3603,
+  3606,
+  3609,
+  3612,
+  3615,
+  3618,
+  3621,
+  3624,
+  3627,
   3630,
-  5342,
-  1419,
-  1464,
-  1462,
-  4483,
-  1100,
-];
-
-static PRUNE_20: [usize; 105] = [
-  1583,
-  3162,
-  3160,
-  3702,
-  6273,
-  5414,
-  1491,
-  1669,
-  4240,
-  1804,
-  1724,
-  1544,
-  4023,
-  3164,
-  5735,
-  4561,
-  5631,
-  1843,
-  1571,
-  2426,
-  4772,
-  3598,
-  5952,
-  3381,
-  2389,
-  5093,
-  1573,
-  3919,
-  4989,
-  1561,
-  4130,
-  5842,
-  4668,
-  1512,
-  1557,
-  5310,
-  4451,
-  3277,
-  6163,
-  6059,
-  1549,
-  3488,
-  5200,
-  4026,
-  1690,
-  3042,
-  6380,
+  3633,
+  3636,
+  3639,
+  3642,
+  3645,
+  3648,
+  3651,
+  3654,
+  3657,
+  3662,
+  3665,
+  3668,
+  3671,
+  3674,
+  3677,
+  3680,
+  3683,
+  3686,
+  3689,
+  3692,
+  3695,
+  3698,
+  3701,
+  3704,
+  3707,
+  3710,
+  3713,
+  3716,
+  3719,
+  3722,
+  3725,
+  3728,
+  3731,
+  3734,
+  3737,
+  3740,
+  3743,
+  3746,
+  3749,
+  3752,
+  3755,
+  3761,
+  3764,
+  3767,
+  3770,
+  3773,
+  3776,
+  3779,
+  3782,
+  3785,
+  3788,
+  3791,
+  3794,
+  3797,
+  3800,
+  3803,
+  3806,
   3809,
-  5521,
-  1553,
-  1596,
-  4347,
-  5417,
-  1672,
-  4558,
-  3384,
-  6270,
-  5738,
-  1498,
-  1588,
-  1678,
-  4879,
-  1494,
-  3705,
+  3812,
+  3815,
+  3818,
+  3821,
+  3824,
+  3827,
+  3830,
+  3833,
+  3836,
+  3839,
+  3842,
+  3845,
+  3848,
+  3851,
+  3854,
+  3856,
+  3859,
+  3862,
+  3865,
+  3868,
+  3871,
+  3874,
+  3877,
+  3880,
+  3883,
+  3886,
+  3889,
+  3892,
+  3895,
+  3898,
+  3901,
+  3904,
+  3907,
+  3910,
+  3913,
   3916,
-  5628,
-  4454,
-  1568,
-  5096,
-  1623,
-  4237,
+  3919,
+  3922,
+  3925,
+  3928,
+  3931,
+  3934,
+  3937,
+  3940,
+  3943,
+  3946,
+  3949,
+  3952,
+  5901,
+  5905,
+  5910,
+  5915,
+  5921,
+  5926,
+  5930,
+  5934,
+  5936,
+  5938,
+  5940,
+  5942,
   5949,
-  4775,
-  1574,
-  1529,
-  1709,
-  1664,
-  3274,
-  1515,
-  5845,
-  3047,
-  1603,
-  1693,
-  4986,
-  2368,
-  1556,
-  3812,
-  2333,
-  6166,
-  3595,
-  1564,
-  5307,
-  4133,
-  5203,
-  1505,
-  1550,
-  4344,
-  1683,
-  6056,
-  4882,
-  5524,
-  4665,
-  1552,
-  3491,
-  6377,
+  5988,
+  5991,
+  5995,
+  5999,
+  6003,
+  6006,
+  6009,
+  6015,
+  6019,
+  6020,
+  6098,
+  6102,
+  6107,
+  6112,
+  6118,
+  6123,
+  6127,
+  6131,
+  6133,
+  6135,
+  6137,
+  6139,
+  6146,
+  6185,
+  6188,
+  6192,
+  6196,
+  6200,
+  6203,
+  6206,
+  6212,
+  6216,
+  6217,
 ];
 
-static PRUNE_8: [usize; 131] = [
-  2440,
-  2080,
-  1853,
-  1943,
-  2573,
-  722,
-  2121,
-  417,
+static PRUNE_8: [usize; 612] = [
+  160,
+  270,
+  380,
+  490,
+  600,
+  710,
+  820,
+  930,
+  1040,
+  1150,
+  1260,
+  1462,
+  1560,
+  1658,
+  1756,
+  1854,
+  2092,
+  2095,
+  2098,
+  2101,
+  2104,
+  2107,
+  2110,
+  2113,
+  2116,
+  2119,
+  2122,
+  2125,
+  2128,
+  2131,
+  2134,
+  2137,
+  2140,
+  2143,
+  2146,
+  2149,
+  2152,
+  2155,
+  2158,
+  2161,
+  2164,
+  2167,
+  2170,
+  2173,
+  2176,
+  2179,
+  2182,
+  2185,
+  2188,
+  2190,
+  2193,
+  2196,
+  2199,
+  2202,
+  2205,
+  2208,
+  2211,
+  2214,
+  2217,
+  2220,
+  2223,
+  2226,
+  2229,
+  2232,
+  2235,
+  2238,
+  2241,
+  2244,
+  2247,
+  2250,
+  2253,
+  2256,
+  2259,
+  2262,
+  2265,
+  2268,
+  2271,
+  2274,
+  2277,
+  2280,
+  2283,
+  2286,
+  2288,
+  2291,
+  2294,
+  2297,
+  2300,
+  2303,
+  2306,
+  2309,
+  2312,
+  2315,
+  2318,
+  2321,
+  2324,
+  2327,
+  2330,
+  2333,
+  2336,
+  2339,
+  2342,
+  2345,
+  2348,
+  2351,
+  2354,
+  2357,
+  2360,
+  2363,
+  2366,
+  2369,
+  2372,
+  2375,
+  2378,
+  2381,
+  2384,
+  2386,
+  2389,
+  2392,
+  2395,
+  2398,
+  2401,
+  2404,
+  2407,
+  2410,
+  2413,
+  2416,
+  2419,
+  2422,
+  2425,
+  2428,
+  2431,
+  2434,
+  2437,
+  2440,
+  2443,
   2446,
-  2444,
-  190,
-  413,
-  1857,
-  1947,
-  2802,
-  2577,
-  2790,
-  1933,
-  712,
-  2111,
-  2786,
-  1974,
-  2479,
-  1894,
-  1939,
-  1935,
-  1890,
-  2475,
-  716,
-  1876,
+  2449,
+  2452,
+  2455,
+  2458,
   2461,
-  2101,
-  70,
-  2774,
-  68,
-  2594,
-  1882,
-  78,
+  2464,
   2467,
-  76,
-  2782,
-  74,
-  287,
-  2136,
-  195,
-  285,
-  1954,
-  2766,
-  1862,
-  1997,
-  193,
-  2584,
-  2447,
-  418,
-  2592,
-  66,
-  291,
-  64,
-  2815,
-  1958,
-  289,
-  2588,
-  2768,
-  2453,
-  2576,
-  1854,
-  2574,
-  2799,
-  2439,
+  2470,
+  2473,
+  2476,
+  2479,
   2482,
-  723,
-  1987,
-  1942,
-  1895,
-  1940,
-  2085,
-  416,
-  1950,
-  191,
-  189,
-  1948,
-  2443,
-  729,
-  414,
-  1856,
-  2472,
-  1932,
-  1887,
-  2789,
-  2787,
-  79,
-  711,
-  1936,
-  2024,
-  2779,
-  71,
-  2777,
-  2595,
-  69,
-  2108,
-  77,
-  1881,
-  1879,
-  75,
-  2466,
-  2464,
-  196,
-  1955,
-  286,
-  738,
-  2587,
+  2484,
+  2487,
+  2490,
+  2493,
+  2496,
+  2499,
+  2502,
+  2505,
+  2508,
+  2511,
+  2514,
+  2517,
+  2520,
+  2523,
+  2526,
+  2529,
+  2532,
+  2535,
+  2538,
+  2541,
+  2544,
+  2547,
+  2550,
+  2553,
+  2556,
+  2559,
+  2562,
+  2565,
+  2568,
+  2571,
+  2574,
+  2577,
+  2580,
+  2582,
   2585,
-  2810,
-  2765,
-  2043,
-  194,
-  2088,
-  1951,
-  192,
-  1861,
-  292,
-  67,
+  2588,
   2591,
-  65,
-  2454,
-  740,
-  2094,
-  290,
-  288,
-  2769,
-  1957,
-];
-
-static PRUNE_13: [usize; 156] = [
-  3027,
-  5823,
-  4109,
-  2260,
-  2305,
-  2168,
-  3025,
-  454,
-  4107,
-  5821,
-  3790,
-  452,
-  2166,
-  5819,
-  4430,
-  6144,
-  2266,
-  6142,
-  3029,
-  4428,
-  2712,
-  6140,
-  4111,
-  5179,
-  2563,
-  3465,
-  5177,
-  2291,
-  4860,
-  2154,
-  2209,
-  450,
-  3788,
-  5502,
-  3786,
-  5500,
-  3469,
-  5498,
-  3467,
-  5181,
-  446,
-  2733,
-  6251,
-  4537,
-  2283,
-  2281,
-  477,
-  2144,
-  4535,
-  6249,
-  4218,
-  6247,
-  4858,
-  481,
-  2150,
-  4856,
-  2193,
+  2594,
+  2597,
   2600,
-  4539,
-  5609,
-  3895,
-  3893,
-  5607,
-  2269,
-  3576,
-  5605,
-  5288,
-  4216,
-  5930,
-  5928,
-  2230,
-  2275,
-  2140,
-  2185,
-  4214,
-  3897,
+  2603,
+  2606,
+  2609,
+  2612,
+  2615,
+  2618,
+  2621,
+  2624,
+  2627,
+  2630,
+  2633,
+  2636,
+  2639,
+  2642,
+  2645,
+  2648,
+  2651,
+  2654,
+  2657,
+  2660,
+  2663,
+  2666,
+  2669,
+  2672,
+  2675,
+  2678,
+  2680,
+  2683,
+  2686,
+  2689,
+  2692,
+  2695,
+  2698,
+  2701,
+  2704,
+  2707,
+  2710,
+  2713,
+  2716,
+  2719,
+  2722,
   2725,
-  2273,
-  5926,
-  2169,
-  3251,
-  4965,
-  4963,
-  2212,
-  4646,
-  2265,
-  2762,
-  3574,
-  3572,
-  5286,
-  5284,
-  3255,
-  4967,
-  2216,
-  3253,
-  4323,
-  6037,
-  445,
-  2294,
-  6035,
-  2247,
-  2157,
-  4004,
-  2200,
-  4321,
-  6033,
-  4644,
-  6358,
-  2163,
-  449,
-  2298,
-  447,
-  6356,
-  4642,
-  6354,
-  4325,
-  3139,
-  478,
-  3681,
-  1922,
-  3679,
-  5393,
-  5391,
-  2280,
-  3137,
-  476,
-  3362,
-  2233,
-  3360,
-  5074,
-  1963,
-  2143,
-  4002,
-  5716,
-  5714,
-  4000,
-  3683,
-  5395,
-  2149,
-  480,
-  5712,
-  3141,
-  2284,
-  4751,
-  2223,
-  2268,
-  2178,
-  4749,
-  4432,
-  2141,
-  5072,
-  2276,
-  3358,
-  5070,
-  4753,
-  2272,
-];
-
-static PRUNE_44: [usize; 100] = [
-  4199,
-  6228,
-  5913,
-  5911,
-  3880,
-  4195,
-  4520,
-  6234,
-  6232,
-  4516,
-  4201,
-  3557,
-  5586,
-  5269,
-  3553,
-  3238,
-  4950,
+  2728,
+  2731,
+  2734,
+  2737,
+  2740,
+  2743,
+  2746,
+  2749,
+  2752,
+  2755,
+  2758,
+  2761,
+  2764,
+  2767,
+  2770,
+  2773,
+  2776,
+  2778,
+  2781,
+  2784,
+  2787,
+  2790,
+  2793,
+  2796,
+  2799,
+  2802,
+  2805,
+  2808,
+  2811,
+  2814,
+  2817,
+  2820,
+  2823,
+  2826,
+  2829,
+  2832,
+  2835,
+  2838,
+  2841,
+  2844,
+  2847,
+  2850,
+  2853,
+  2856,
+  2859,
+  2862,
+  2865,
+  2868,
+  2871,
+  2874,
+  2876,
+  2879,
+  2882,
+  2885,
+  2888,
+  2891,
+  2894,
+  2897,
+  2900,
+  2903,
+  2906,
+  2909,
+  2912,
+  2915,
+  2918,
+  2921,
+  2924,
+  2927,
+  2930,
+  2933,
+  2936,
+  2939,
+  2942,
+  2945,
+  2948,
+  2951,
+  2954,
+  2957,
+  2960,
+  2963,
+  2966,
+  2969,
+  2972,
+  2974,
+  2977,
+  2980,
+  2983,
+  2986,
+  2989,
+  2992,
+  2995,
+  2998,
+  3001,
+  3004,
+  3007,
+  3010,
+  3013,
+  3016,
+  3019,
+  3022,
+  3025,
+  3028,
+  3031,
+  3034,
+  3037,
+  3040,
+  3043,
+  3046,
+  3049,
+  3052,
+  3055,
+  3058,
+  3061,
+  3064,
+  3067,
+  3070,
+  3072,
+  3075,
+  3078,
+  3081,
+  3084,
+  3087,
+  3090,
+  3093,
+  3096,
+  3099,
+  3102,
+  3105,
+  3108,
+  3111,
+  3114,
+  3117,
+  3120,
+  3123,
+  3126,
+  3129,
+  3132,
+  3135,
+  3138,
+  3141,
+  3144,
+  3147,
+  3150,
+  3153,
+  3156,
+  3159,
+  3162,
+  3165,
+  3168,
+  3170,
+  3173,
+  3176,
+  3179,
+  3182,
+  3185,
+  3188,
+  3191,
+  3194,
+  3197,
+  3200,
+  3203,
+  3206,
+  3209,
+  3212,
+  3215,
+  3218,
+  3221,
+  3224,
+  3227,
+  3230,
+  3233,
   3236,
-  5907,
-  3878,
-  5592,
-  5590,
-  3559,
-  5271,
-  3874,
-  6341,
-  4627,
-  6339,
-  4623,
-  4308,
-  5265,
-  4948,
-  3232,
-  4944,
-  4629,
-  6014,
-  5699,
-  3985,
-  5697,
-  3666,
-  3981,
-  3124,
-  4306,
-  6020,
-  6018,
-  6335,
-  876,
-  4302,
-  3987,
-  5372,
-  5055,
-  3339,
-  4736,
-  5693,
-  3664,
-  5378,
-  5376,
-  3345,
+  3239,
+  3242,
+  3245,
+  3248,
+  3251,
+  3254,
+  3257,
+  3260,
+  3263,
+  3266,
+  3268,
+  3271,
+  3274,
+  3277,
+  3280,
+  3283,
+  3286,
+  3289,
+  3292,
+  3295,
+  3298,
+  3301,
+  3304,
+  3307,
+  3310,
+  3313,
+  3316,
+  3319,
+  3322,
+  3325,
+  3328,
+  3331,
+  3334,
+  3337,
+  3340,
   3343,
-  5057,
-  3660,
-  6127,
-  4413,
-  6442,
-  6125,
-  4409,
-  4094,
-  6448,
-  4734,
-  5051,
-  1488,
-  6446,
-  4730,
-  4415,
-  5800,
-  2824,
-  5485,
-  3771,
-  5483,
-  3452,
-  5164,
-  3767,
-  6121,
-  4092,
-  5806,
-  5804,
-  2826,
-  3773,
-  4088,
-  5158,
-  4841,
-  4837,
-  4522,
+  3346,
+  3349,
+  3352,
+  3355,
+  3358,
+  3361,
+  3364,
+  3366,
+  3369,
+  3372,
+  3375,
+  3378,
+  3381,
+  3384,
+  3387,
+  3390,
+  3393,
+  3396,
+  3399,
+  3402,
+  3405,
+  3408,
+  3411,
+  3414,
+  3417,
+  3420,
+  3423,
+  3426,
+  3429,
+  3432,
+  3435,
+  3438,
+  3441,
+  3444,
+  3447,
   3450,
-  5479,
-  5162,
-  3446,
-  3131,
-  4843,
-  3129,
-];
-
-static PRUNE_1: [usize; 10] = [
-  659,
-  657,
-  655,
-  663,
-  653,
-  651,
-  658,
-  656,
-  662,
-  650,
+  3453,
+  3456,
+  3459,
+  3462,
+  3464,
+  3467,
+  3470,
+  3473,
+  3476,
+  3479,
+  3482,
+  3485,
+  3488,
+  3491,
+  3494,
+  3497,
+  3500,
+  3503,
+  3506,
+  3509,
+  3512,
+  3515,
+  3518,
+  3521,
+  3524,
+  3527,
+  3530,
+  3533,
+  3536,
+  3539,
+  3542,
+  3545,
+  3548,
+  3551,
+  3554,
+  3557,
+  3560,
+  3562,
+  3565,
+  3568,
+  3571,
+  3574,
+  3577,
+  3580,
+  3583,
+  3586,
+  3589,
+  3592,
+  3595,
+  3598,
+  3601,
+  3604,
+  3607,
+  3610,
+  3613,
+  3616,
+  3619,
+  3622,
+  3625,
+  3628,
+  3631,
+  3634,
+  3637,
+  3640,
+  3643,
+  3646,
+  3649,
+  3652,
+  3655,
+  3658,
+  3660,
+  3663,
+  3666,
+  3669,
+  3672,
+  3675,
+  3678,
+  3681,
+  3684,
+  3687,
+  3690,
+  3693,
+  3696,
+  3699,
+  3702,
+  3705,
+  3708,
+  3711,
+  3714,
+  3717,
+  3720,
+  3723,
+  3726,
+  3729,
+  3732,
+  3735,
+  3738,
+  3741,
+  3744,
+  3747,
+  3750,
+  3753,
+  3756,
+  3758,
+  3857,
+  4838,
+  5900,
+  5927,
+  5931,
+  5935,
+  5939,
+  5945,
+  5947,
+  5950,
+  5985,
+  6000,
+  6004,
+  6007,
+  6010,
+  6011,
+  6013,
+  6016,
+  6097,
+  6124,
+  6128,
+  6132,
+  6136,
+  6142,
+  6144,
+  6147,
+  6182,
+  6197,
+  6201,
+  6204,
+  6207,
+  6208,
+  6210,
+  6213,
 ];
 
-static PRUNE_37: [usize; 134] = [
-  2982,
-  5461,
-  2890,
-  2978,
-  3430,
-  3428,
-  5142,
-  857,
-  867,
-  4070,
-  5782,
-  865,
-  3751,
-  3749,
-  5463,
-  4819,
-  851,
-  759,
-  4500,
-  2966,
-  5140,
-  1125,
-  2974,
-  855,
-  2659,
-  2704,
-  4821,
-  4177,
-  5891,
-  5889,
-  2956,
-  3858,
-  837,
-  5570,
-  2819,
-  3856,
-  835,
-  6212,
-  4498,
-  843,
-  6210,
-  4179,
-  2872,
-  1066,
-  2960,
-  2948,
-  2993,
-  2946,
-  782,
-  827,
-  5247,
-  2764,
-  3216,
-  823,
-  2989,
-  3214,
-  4928,
-  5568,
-  786,
-  3537,
-  3535,
-  5249,
-  829,
-  4605,
-  6319,
-  6317,
-  770,
-  4286,
-  2979,
-  768,
-  4926,
-  4607,
-  2081,
-  2938,
-  850,
-  3106,
-  3963,
-  2204,
-  5675,
-  1073,
-  2967,
-  848,
-  803,
-  3644,
-  801,
-  5356,
-  846,
-  3642,
-  5998,
-  4284,
-  5996,
-  1171,
-  2975,
-  3108,
-  3965,
-  2026,
-  5677,
-  762,
-  852,
-  5033,
-  2912,
-  2957,
-  793,
-  791,
-  4714,
-  844,
-  799,
-  5354,
-  2961,
-  3323,
+static PRUNE_9: [usize; 363] = [
+  2091,
+  2093,
+  2191,
+  2194,
+  2197,
+  2200,
+  2203,
+  2206,
+  2209,
+  2212,
+  2215,
+  2218,
+  2221,
+  2224,
+  2227,
+  2230,
+  2233,
+  2236,
+  2239,
+  2242,
+  2245,
+  2248,
+  2251,
+  2254,
+  2257,
+  2260,
+  2263,
+  2266,
+  2269,
+  2272,
+  2275,
+  2278,
+  2281,
+  2284,
+  2287,
+  2289,
+  2387,
+  2390,
+  2393,
+  2396,
+  2399,
+  2402,
+  2405,
+  2408,
+  2411,
+  2414,
+  2417,
+  2420,
+  2423,
+  2426,
+  2429,
+  2432,
+  2435,
+  2438,
+  2441,
+  2444,
+  2447,
+  2450,
+  2453,
+  2456,
+  2459,
+  2462,
+  2465,
+  2468,
+  2471,
+  2474,
+  2477,
+  2480,
+  2483,
+  2485,
+  2583,
+  2586,
+  2589,
+  2592,
+  2595,
+  2598,
   2601,
-  842,
-  3321,
-  5035,
-  2992,
-  4391,
-  2542,
-  6105,
-  826,
-  6103,
-  4072,
-  2945,
-  5784,
-  2953,
-  4712,
-  789,
-  834,
-  6426,
-  6424,
-  4393,
-  2139,
-  2949,
-  830,
-  2904,
-];
-
-static PRUNE_6: [usize; 71] = [
-  2078,
-  732,
-  730,
-  1994,
-  1992,
-  2127,
-  2804,
-  2113,
+  2604,
+  2607,
   2610,
-  1978,
-  2796,
-  2119,
-  2029,
-  1982,
-  2794,
-  702,
-  2099,
-  2007,
-  1972,
-  706,
-  2015,
-  2105,
-  1968,
-  737,
-  2091,
-  2134,
+  2613,
+  2616,
+  2619,
+  2622,
+  2625,
+  2628,
   2631,
-  2811,
-  735,
-  2494,
-  733,
-  2095,
-  2048,
-  696,
-  1989,
-  2124,
-  2621,
-  2032,
-  1985,
+  2634,
+  2637,
+  2640,
+  2643,
+  2646,
+  2649,
+  2652,
+  2655,
+  2658,
+  2661,
+  2664,
+  2667,
+  2670,
+  2673,
+  2676,
+  2679,
+  2681,
+  2779,
+  2782,
+  2785,
+  2788,
+  2791,
+  2794,
   2797,
-  2807,
-  1995,
-  2805,
-  2036,
-  2022,
-  1975,
-  2118,
-  2116,
-  2793,
-  2656,
-  701,
-  2505,
-  2098,
-  2513,
-  2063,
-  2106,
-  1971,
-  1969,
-  707,
+  2800,
+  2803,
+  2806,
+  2809,
   2812,
-  693,
-  2540,
-  736,
-  2808,
-  2131,
-  2041,
-  2096,
-  2051,
-  695,
-  2004,
-  2092,
+  2815,
+  2818,
+  2821,
+  2824,
+  2827,
+  2830,
+  2833,
+  2836,
+  2839,
+  2842,
+  2845,
+  2848,
+  2851,
+  2854,
+  2857,
+  2860,
+  2863,
+  2866,
+  2869,
+  2872,
+  2875,
+  2877,
+  2975,
+  2978,
+  2981,
+  2984,
+  2987,
+  2990,
+  2993,
+  2996,
+  2999,
+  3002,
+  3005,
+  3008,
+  3011,
+  3014,
+  3017,
+  3020,
+  3023,
+  3026,
+  3029,
+  3032,
+  3035,
+  3038,
+  3041,
+  3044,
+  3047,
+  3050,
+  3053,
+  3056,
+  3059,
+  3062,
+  3065,
+  3068,
+  3071,
+  3073,
+  3171,
+  3174,
+  3177,
+  3180,
+  3183,
+  3186,
+  3189,
+  3192,
+  3195,
+  3198,
+  3201,
+  3204,
+  3207,
+  3210,
+  3213,
+  3216,
+  3219,
+  3222,
+  3225,
+  3228,
+  3231,
+  3234,
+  3237,
+  3240,
+  3243,
+  3246,
+  3249,
+  3252,
+  3255,
+  3258,
+  3261,
+  3264,
+  3267,
+  3269,
+  3367,
+  3370,
+  3373,
+  3376,
+  3379,
+  3382,
+  3385,
+  3388,
+  3391,
+  3394,
+  3397,
+  3400,
+  3403,
+  3406,
+  3409,
+  3412,
+  3415,
+  3418,
+  3421,
+  3424,
+  3427,
+  3430,
+  3433,
+  3436,
+  3439,
+  3442,
+  3445,
+  3448,
+  3451,
+  3454,
+  3457,
+  3460,
+  3463,
+  3465,
+  3563,
+  3566,
+  3569,
+  3572,
+  3575,
+  3578,
+  3581,
+  3584,
+  3587,
+  3590,
+  3593,
+  3596,
+  3599,
+  3602,
+  3605,
+  3608,
+  3611,
+  3614,
+  3617,
+  3620,
+  3623,
+  3626,
+  3629,
+  3632,
+  3635,
+  3638,
+  3641,
+  3644,
+  3647,
+  3650,
+  3653,
+  3656,
+  3659,
+  3661,
+  3759,
+  3762,
+  3765,
+  3768,
+  3771,
+  3774,
+  3777,
+  3780,
+  3783,
+  3786,
+  3789,
+  3792,
+  3795,
+  3798,
+  3801,
+  3804,
+  3807,
+  3810,
+  3813,
+  3816,
+  3819,
+  3822,
+  3825,
+  3828,
+  3831,
+  3834,
+  3837,
+  3840,
+  3843,
+  3846,
+  3849,
+  3852,
+  3855,
+  4840,
+  4843,
+  4846,
+  4849,
+  4852,
+  4855,
+  4858,
+  4861,
+  4864,
+  4867,
+  4870,
+  4873,
+  4876,
+  4879,
+  4882,
+  4885,
+  4888,
+  4891,
+  4894,
+  4897,
+  4900,
+  4903,
+  4906,
+  4909,
+  4912,
+  4915,
+  4918,
+  4921,
+  4924,
+  4927,
+  4930,
+  4933,
+  5884,
+  5885,
+  5886,
+  5887,
+  5928,
+  5946,
+  5948,
+  5951,
+  6001,
+  6012,
+  6014,
+  6017,
+  6081,
+  6082,
+  6083,
+  6084,
+  6125,
+  6143,
+  6145,
+  6148,
+  6198,
+  6209,
+  6211,
+  6214,
 ];
 
-static PRUNE_25: [usize; 121] = [
-  3072,
-  3882,
-  2665,
-  951,
-  2845,
-  5594,
-  4467,
-  6179,
-  1219,
-  4203,
-  4788,
-  3074,
-  2669,
-  5915,
-  1256,
-  939,
-  3825,
-  3240,
-  4952,
-  5537,
-  3064,
-  1215,
-  3561,
-  5273,
-  943,
-  4146,
-  5858,
-  1213,
-  3185,
+static PRUNE_10: [usize; 384] = [
+  1364,
+  1950,
+  3860,
+  3863,
+  3866,
+  3869,
+  3872,
+  3875,
+  3878,
+  3881,
+  3884,
+  3887,
+  3890,
+  3893,
+  3896,
+  3899,
+  3902,
+  3905,
+  3908,
+  3911,
+  3914,
+  3917,
+  3920,
+  3923,
+  3926,
+  3929,
+  3932,
+  3935,
+  3938,
+  3941,
+  3944,
+  3947,
+  3950,
+  3953,
+  3954,
+  3955,
+  3956,
+  3959,
+  3962,
+  3965,
+  3968,
+  3971,
+  3974,
+  3977,
+  3980,
+  3983,
+  3986,
+  3989,
+  3992,
+  3995,
+  3998,
+  4001,
+  4004,
+  4007,
+  4010,
+  4013,
+  4016,
+  4019,
+  4022,
+  4025,
+  4028,
+  4031,
+  4034,
+  4037,
+  4040,
+  4043,
+  4046,
+  4049,
+  4052,
+  4053,
+  4054,
+  4057,
+  4060,
+  4063,
+  4066,
+  4069,
+  4072,
+  4075,
+  4078,
+  4081,
+  4084,
+  4087,
+  4090,
+  4093,
+  4096,
+  4099,
+  4102,
+  4105,
+  4108,
+  4111,
+  4114,
+  4117,
+  4120,
+  4123,
+  4126,
+  4129,
+  4132,
+  4135,
+  4138,
+  4141,
+  4144,
+  4147,
+  4150,
+  4151,
+  4152,
+  4155,
+  4158,
+  4161,
+  4164,
+  4167,
+  4170,
+  4173,
+  4176,
+  4179,
+  4182,
+  4185,
+  4188,
+  4191,
+  4194,
+  4197,
+  4200,
+  4203,
+  4206,
+  4209,
+  4212,
+  4215,
+  4218,
+  4221,
+  4224,
+  4227,
+  4230,
+  4233,
+  4236,
+  4239,
+  4242,
+  4245,
+  4248,
+  4249,
+  4250,
+  4253,
+  4256,
+  4259,
+  4262,
+  4265,
+  4268,
+  4271,
+  4274,
+  4277,
+  4280,
+  4283,
+  4286,
+  4289,
+  4292,
+  4295,
+  4298,
+  4301,
+  4304,
+  4307,
   4310,
-  3183,
-  1197,
+  4313,
+  4316,
+  4319,
+  4322,
+  4325,
+  4328,
+  4331,
+  4334,
+  4337,
+  4340,
+  4343,
+  4346,
+  4347,
+  4348,
+  4351,
+  4354,
+  4357,
+  4360,
+  4363,
+  4366,
+  4369,
+  4372,
+  4375,
+  4378,
+  4381,
+  4384,
+  4387,
+  4390,
+  4393,
+  4396,
+  4399,
+  4402,
+  4405,
+  4408,
+  4411,
+  4414,
+  4417,
+  4420,
+  4423,
+  4426,
+  4429,
+  4432,
+  4435,
+  4438,
+  4441,
+  4444,
+  4445,
+  4447,
+  4450,
+  4453,
+  4456,
+  4459,
+  4462,
+  4465,
+  4468,
+  4471,
+  4474,
+  4477,
+  4480,
+  4483,
+  4486,
+  4489,
+  4492,
+  4495,
+  4498,
+  4501,
+  4504,
+  4507,
+  4510,
+  4513,
+  4516,
+  4519,
+  4522,
+  4525,
+  4528,
+  4531,
+  4534,
+  4537,
+  4540,
+  4543,
+  4544,
+  4545,
+  4548,
+  4551,
+  4554,
+  4557,
+  4560,
+  4563,
+  4566,
+  4569,
+  4572,
+  4575,
+  4578,
+  4581,
+  4584,
+  4587,
+  4590,
+  4593,
+  4596,
+  4599,
+  4602,
+  4605,
+  4608,
+  4611,
+  4614,
+  4617,
+  4620,
+  4623,
+  4626,
+  4629,
+  4632,
+  4635,
+  4638,
+  4641,
+  4642,
+  4643,
+  4646,
+  4649,
+  4652,
+  4655,
+  4658,
+  4661,
+  4664,
+  4667,
+  4670,
+  4673,
+  4676,
+  4679,
+  4682,
+  4685,
+  4688,
+  4691,
+  4694,
+  4697,
+  4700,
+  4703,
+  4706,
+  4709,
+  4712,
+  4715,
+  4718,
+  4721,
+  4724,
+  4727,
+  4730,
+  4733,
+  4736,
+  4739,
+  4740,
+  4742,
+  4745,
+  4748,
+  4751,
+  4754,
+  4757,
+  4760,
+  4763,
+  4766,
+  4769,
+  4772,
+  4775,
+  4778,
+  4781,
+  4784,
+  4787,
+  4790,
+  4793,
+  4796,
+  4799,
+  4802,
+  4805,
+  4808,
+  4811,
+  4814,
+  4817,
+  4820,
+  4823,
+  4826,
+  4829,
+  4832,
+  4835,
+  4841,
+  4844,
+  4847,
+  4850,
+  4853,
+  4856,
+  4859,
+  4862,
+  4865,
+  4868,
+  4871,
+  4874,
+  4877,
+  4880,
+  4883,
+  4886,
+  4889,
+  4892,
   4895,
+  4898,
+  4901,
+  4904,
+  4907,
+  4910,
+  4913,
+  4916,
+  4919,
+  4922,
+  4925,
+  4928,
+  4931,
+  4934,
+  5944,
+  5952,
+  6021,
   6022,
-  3504,
-  933,
-  5216,
-  978,
-  4631,
-  6343,
+  6023,
+  6024,
+  6141,
+  6149,
+  6218,
+  6219,
+  6220,
+  6221,
+];
+
+static PRUNE_11: [usize; 586] = [
+  2096,
+  2099,
+  2102,
+  2105,
+  2108,
+  2111,
+  2114,
+  2117,
+  2120,
+  2123,
+  2126,
+  2129,
+  2132,
+  2135,
+  2138,
+  2141,
+  2144,
+  2147,
+  2150,
+  2153,
+  2156,
+  2159,
+  2162,
+  2165,
+  2168,
+  2171,
+  2174,
+  2177,
+  2180,
+  2183,
+  2186,
+  2189,
+  2292,
+  2295,
+  2298,
+  2301,
+  2304,
+  2307,
+  2310,
+  2313,
+  2316,
+  2319,
+  2322,
+  2325,
+  2328,
+  2331,
+  2334,
+  2337,
+  2340,
+  2343,
+  2346,
+  2349,
+  2352,
+  2355,
+  2358,
+  2361,
+  2364,
+  2367,
+  2370,
+  2373,
+  2376,
+  2379,
+  2382,
+  2385,
+  2488,
+  2491,
+  2494,
+  2497,
+  2500,
+  2503,
+  2506,
+  2509,
+  2512,
+  2515,
+  2518,
+  2521,
+  2524,
+  2527,
+  2530,
+  2533,
+  2536,
+  2539,
+  2542,
+  2545,
+  2548,
+  2551,
+  2554,
+  2557,
+  2560,
+  2563,
+  2566,
+  2569,
+  2572,
+  2575,
+  2578,
+  2581,
+  2684,
+  2687,
   2690,
-  557,
-  3668,
-  5380,
-  4253,
-  5965,
-  1230,
-  1240,
-  383,
-  1195,
-  3989,
-  5701,
-  4574,
-  1191,
-  2680,
-  6286,
-  1177,
-  1267,
-  952,
-  3611,
-  4738,
-  1265,
-  3069,
-  3067,
-  2662,
-  5323,
-  2672,
-  3347,
-  3932,
-  5059,
-  5644,
-  4096,
-  3059,
-  1255,
-  4681,
-  5808,
-  938,
-  6393,
-  946,
-  2840,
+  2693,
+  2696,
+  2699,
+  2702,
+  2705,
+  2708,
+  2711,
+  2714,
+  2717,
+  2720,
+  2723,
+  2726,
+  2729,
+  2732,
+  2735,
+  2738,
+  2741,
+  2744,
+  2747,
+  2750,
+  2753,
+  2756,
+  2759,
+  2762,
+  2765,
+  2768,
+  2771,
+  2774,
+  2777,
+  2880,
+  2883,
+  2886,
+  2889,
+  2892,
+  2895,
+  2898,
+  2901,
+  2904,
+  2907,
+  2910,
+  2913,
+  2916,
+  2919,
+  2922,
+  2925,
+  2928,
+  2931,
+  2934,
+  2937,
+  2940,
+  2943,
+  2946,
+  2949,
+  2952,
+  2955,
+  2958,
+  2961,
+  2964,
+  2967,
+  2970,
+  2973,
+  3076,
+  3079,
+  3082,
+  3085,
+  3088,
+  3091,
+  3094,
+  3097,
+  3100,
+  3103,
+  3106,
+  3109,
+  3112,
+  3115,
+  3118,
+  3121,
+  3124,
+  3127,
+  3130,
+  3133,
+  3136,
+  3139,
+  3142,
+  3145,
+  3148,
+  3151,
+  3154,
+  3157,
+  3160,
+  3163,
+  3166,
+  3169,
+  3272,
+  3275,
+  3278,
+  3281,
+  3284,
+  3287,
   3290,
-  4417,
-  1261,
-  1259,
-  5002,
-  2701,
-  6129,
-  973,
-  3454,
-  4039,
-  5166,
-  3180,
-  5751,
-  1241,
-  2875,
-  1249,
-  1204,
-  3775,
-  932,
-  5487,
-  4360,
-  1607,
-  6072,
-  2677,
-  3397,
-  4524,
-  1233,
-  1278,
-  556,
-  5109,
-  961,
-  1231,
-  6236,
+  3293,
+  3296,
+  3299,
+  3302,
+  3305,
+  3308,
+  3311,
+  3314,
+  3317,
+  3320,
+  3323,
+  3326,
+  3329,
+  3332,
+  3335,
+  3338,
+  3341,
+  3344,
+  3347,
+  3350,
+  3353,
+  3356,
+  3359,
+  3362,
+  3365,
+  3468,
+  3471,
+  3474,
+  3477,
+  3480,
+  3483,
+  3486,
+  3489,
+  3492,
+  3495,
+  3498,
+  3501,
+  3504,
+  3507,
+  3510,
+  3513,
+  3516,
+  3519,
+  3522,
+  3525,
+  3528,
+  3531,
+  3534,
+  3537,
+  3540,
+  3543,
+  3546,
+  3549,
+  3552,
+  3555,
+  3558,
+  3561,
+  3664,
+  3667,
+  3670,
+  3673,
+  3676,
+  3679,
+  3682,
+  3685,
+  3688,
+  3691,
+  3694,
+  3697,
+  3700,
+  3703,
+  3706,
+  3709,
+  3712,
+  3715,
   3718,
-  967,
-  3178,
-  4845,
-  3176,
-  1280,
-  3174,
-  5430,
-];
-
-static PRUNE_30: [usize; 155] = [
-  5868,
-  3839,
-  5551,
-  3837,
-  3835,
-  5549,
-  3518,
-  6189,
-  597,
-  4160,
-  4158,
-  5872,
-  2896,
-  5870,
-  1315,
+  3721,
+  3724,
+  3727,
+  3730,
+  3733,
+  3736,
+  3739,
+  3742,
+  3745,
+  3748,
+  3751,
+  3754,
+  3757,
+  3957,
+  3960,
+  3963,
+  3966,
+  3969,
+  3972,
+  3975,
+  3978,
+  3981,
+  3984,
+  3987,
+  3990,
+  3993,
+  3996,
+  3999,
+  4002,
+  4005,
+  4008,
+  4011,
+  4014,
+  4017,
+  4020,
+  4023,
+  4026,
+  4029,
+  4032,
+  4035,
+  4038,
+  4041,
+  4044,
+  4047,
+  4050,
+  4055,
+  4058,
+  4061,
+  4064,
+  4067,
+  4070,
+  4073,
+  4076,
+  4079,
+  4082,
+  4085,
+  4088,
+  4091,
+  4094,
+  4097,
+  4100,
+  4103,
+  4106,
+  4109,
+  4112,
+  4115,
+  4118,
+  4121,
+  4124,
+  4127,
+  4130,
+  4133,
+  4136,
+  4139,
+  4142,
+  4145,
+  4148,
+  4153,
   4156,
-  3195,
-  4909,
-  4907,
-  3193,
-  4905,
-  5547,
-  403,
-  3516,
-  5230,
-  900,
-  3514,
-  898,
-  1350,
-  5228,
-  3197,
-  626,
-  5226,
-  401,
-  6296,
-  1019,
-  4267,
-  5979,
-  4265,
-  1334,
-  927,
-  1015,
-  2909,
+  4159,
+  4162,
+  4165,
+  4168,
+  4171,
+  4174,
+  4177,
+  4180,
+  4183,
+  4186,
+  4189,
+  4192,
+  4195,
+  4198,
+  4201,
+  4204,
+  4207,
+  4210,
+  4213,
+  4216,
+  4219,
+  4222,
+  4225,
+  4228,
+  4231,
+  4234,
+  4237,
+  4240,
+  4243,
+  4246,
+  4251,
+  4254,
+  4257,
+  4260,
   4263,
-  5977,
-  4588,
-  620,
-  6300,
-  4586,
-  1111,
-  6298,
-  4584,
-  602,
-  3083,
-  5654,
-  1367,
-  5337,
-  3623,
-  3621,
-  5335,
-  1455,
-  3304,
-  5333,
-  3946,
-  5975,
-  1375,
-  3087,
-  3944,
-  5658,
-  5656,
-  3942,
-  921,
-  3625,
-  3085,
-  4695,
-  1222,
-  907,
-  6407,
-  635,
-  633,
-  4693,
-  408,
-  4691,
-  1353,
-  6405,
-  5016,
-  3302,
-  3300,
-  5014,
-  5012,
-  1359,
-  6082,
-  1302,
-  4053,
-  4051,
-  5765,
-  2834,
-  5763,
-  3732,
-  1026,
-  4049,
-  6403,
-  901,
-  631,
-  4374,
-  629,
-  4372,
-  6086,
-  402,
-  1439,
-  1079,
-  627,
-  6084,
-  897,
+  4266,
+  4269,
+  4272,
+  4275,
+  4278,
+  4281,
+  4284,
+  4287,
+  4290,
+  4293,
+  4296,
+  4299,
+  4302,
+  4305,
+  4308,
+  4311,
+  4314,
+  4317,
+  4320,
+  4323,
+  4326,
+  4329,
+  4332,
+  4335,
+  4338,
+  4341,
+  4344,
+  4349,
+  4352,
+  4355,
+  4358,
+  4361,
+  4364,
+  4367,
   4370,
-  5123,
-  1110,
-  5440,
-  2867,
-  3409,
-  3407,
-  5121,
-  611,
-  609,
-  5119,
-  3090,
-  619,
-  5761,
-  1296,
-  392,
-  1024,
-  3730,
-  5444,
-  2916,
-  5442,
-  3728,
-  3411,
+  4373,
+  4376,
+  4379,
+  4382,
+  4385,
+  4388,
+  4391,
+  4394,
+  4397,
+  4400,
+  4403,
+  4406,
+  4409,
+  4412,
+  4415,
+  4418,
+  4421,
+  4424,
+  4427,
+  4430,
+  4433,
+  4436,
+  4439,
+  4442,
+  4446,
+  4448,
+  4451,
+  4454,
+  4457,
+  4460,
+  4463,
+  4466,
+  4469,
+  4472,
+  4475,
+  4478,
   4481,
-  603,
-  6193,
-  4479,
-  601,
-  1321,
-  2855,
-  4477,
-  6191,
-  914,
-  4802,
-  2863,
-  1329,
-  607,
+  4484,
+  4487,
+  4490,
+  4493,
+  4496,
+  4499,
+  4502,
+  4505,
+  4508,
+  4511,
+  4514,
+  4517,
+  4520,
+  4523,
+  4526,
+  4529,
+  4532,
+  4535,
+  4538,
+  4541,
+  4546,
+  4549,
+  4552,
+  4555,
+  4558,
+  4561,
+  4564,
+  4567,
+  4570,
+  4573,
+  4576,
+  4579,
+  4582,
+  4585,
+  4588,
+  4591,
+  4594,
+  4597,
+  4600,
+  4603,
+  4606,
+  4609,
+  4612,
+  4615,
+  4618,
+  4621,
+  4624,
+  4627,
+  4630,
+  4633,
+  4636,
+  4639,
+  4644,
+  4647,
+  4650,
+  4653,
+  4656,
+  4659,
+  4662,
+  4665,
+  4668,
+  4671,
+  4674,
+  4677,
+  4680,
+  4683,
+  4686,
+  4689,
+  4692,
+  4695,
+  4698,
+  4701,
+  4704,
+  4707,
+  4710,
+  4713,
+  4716,
+  4719,
+  4722,
+  4725,
+  4728,
+  4731,
+  4734,
+  4737,
+  4741,
+  4743,
+  4746,
+  4749,
+  4752,
+  4755,
+  4758,
+  4761,
+  4764,
+  4767,
+  4770,
+  4773,
+  4776,
+  4779,
+  4782,
+  4785,
+  4788,
+  4791,
+  4794,
+  4797,
   4800,
-  4798,
-  1370,
-  2859,
+  4803,
+  4806,
+  4809,
+  4812,
+  4815,
+  4818,
+  4821,
+  4824,
+  4827,
+  4830,
+  4833,
+  4836,
+  4839,
+  4936,
+  5034,
+  5132,
+  5943,
+  5953,
+  6140,
+  6150,
 ];
 
-static PRUNE_18: [usize; 177] = [
-  1628,
-  5733,
-  4019,
-  4017,
-  5731,
-  3700,
-  1581,
-  5412,
-  3158,
-  5729,
-  4340,
-  6054,
-  1499,
-  6052,
-  4338,
-  1542,
-  4021,
-  1722,
-  1812,
-  2397,
-  503,
-  1585,
-  1675,
-  6050,
-  1841,
-  3375,
-  5089,
-  1524,
-  1614,
-  5087,
-  1749,
-  1704,
-  4770,
-  3156,
-  1577,
-  1532,
-  3698,
-  5410,
-  1712,
-  1802,
-  3696,
-  1757,
-  5408,
-  3154,
-  3379,
-  2387,
-  5091,
-  2340,
-  3377,
-  1618,
-  3152,
-  4447,
-  2373,
-  6161,
-  6159,
+static PRUNE_12: [usize; 179] = [
+  4056,
+  4059,
+  4062,
+  4065,
+  4068,
+  4071,
+  4074,
+  4077,
+  4080,
+  4083,
+  4086,
+  4089,
+  4092,
+  4095,
+  4098,
+  4101,
+  4104,
+  4107,
+  4110,
+  4113,
+  4116,
+  4119,
+  4122,
+  4125,
   4128,
-  4445,
-  1782,
-  6157,
-  1647,
-  4768,
-  1522,
-  2424,
-  1700,
-  2332,
-  4766,
-  4449,
-  1788,
-  1594,
-  3805,
-  5519,
-  3038,
-  3803,
-  3036,
-  5517,
-  5515,
-  1502,
-  3486,
-  1500,
-  3484,
-  1590,
-  1680,
-  5198,
-  508,
-  1510,
-  1600,
-  4126,
-  2367,
-  5840,
-  5838,
-  4124,
-  3807,
-  3040,
-  1598,
-  1688,
-  5836,
-  4875,
-  1719,
-  1674,
-  1809,
-  2394,
-  1580,
-  1535,
-  4873,
-  1715,
-  4556,
-  5196,
-  3482,
-  1586,
-  5194,
-  1631,
-  4877,
-  1539,
-  1662,
-  1707,
-  5947,
-  4233,
-  1527,
-  4231,
-  5945,
-  3914,
-  1795,
-  2380,
-  5943,
-  4554,
-  6268,
-  1578,
-  6266,
-  1621,
-  4552,
-  6264,
-  4235,
-  118,
-  3589,
-  5303,
-  5301,
-  3272,
-  3270,
-  4984,
-  1601,
-  3045,
-  1611,
-  3912,
-  5626,
-  3910,
-  5624,
-  1791,
-  5622,
-  3593,
-  2376,
-  1519,
-  1697,
-  3591,
-  5305,
-  6375,
-  4661,
-  1593,
-  1503,
-  509,
-  4659,
-  6373,
+  4131,
+  4134,
+  4137,
+  4140,
+  4143,
+  4146,
+  4149,
+  4252,
+  4255,
+  4258,
+  4261,
+  4264,
+  4267,
+  4270,
+  4273,
+  4276,
+  4279,
+  4282,
+  4285,
+  4288,
+  4291,
+  4294,
+  4297,
+  4300,
+  4303,
+  4306,
+  4309,
+  4312,
+  4315,
+  4318,
+  4321,
+  4324,
+  4327,
+  4330,
+  4333,
+  4336,
+  4339,
   4342,
-  1591,
-  1681,
-  6371,
-  3043,
-  1736,
-  1826,
-  3268,
-  2411,
-  1509,
-  4982,
-  1597,
-  1687,
-  4980,
-  2317,
+  4345,
+  4449,
+  4452,
+  4455,
+  4458,
+  4461,
+  4464,
+  4467,
+  4470,
+  4473,
+  4476,
+  4479,
+  4482,
+  4485,
+  4488,
+  4491,
+  4494,
+  4497,
+  4500,
+  4503,
+  4506,
+  4509,
+  4512,
+  4515,
+  4518,
+  4521,
+  4524,
+  4527,
+  4530,
+  4533,
+  4536,
+  4539,
+  4542,
+  4645,
+  4648,
+  4651,
+  4654,
+  4657,
+  4660,
   4663,
+  4666,
+  4669,
+  4672,
+  4675,
+  4678,
+  4681,
+  4684,
+  4687,
+  4690,
+  4693,
+  4696,
+  4699,
+  4702,
+  4705,
+  4708,
+  4711,
+  4714,
+  4717,
+  4720,
+  4723,
+  4726,
+  4729,
+  4732,
+  4735,
+  4738,
+  4842,
+  4845,
+  4848,
+  4851,
+  4854,
+  4857,
+  4860,
+  4863,
+  4866,
+  4869,
+  4872,
+  4875,
+  4878,
+  4881,
+  4884,
+  4887,
+  4890,
+  4893,
+  4896,
+  4899,
+  4902,
+  4905,
+  4908,
+  4911,
+  4914,
+  4917,
+  4920,
+  4923,
+  4926,
+  4929,
+  4932,
+  4935,
+  5134,
+  5231,
+  5428,
+  5954,
+  6025,
+  6028,
+  6031,
+  6034,
+  6037,
+  6040,
+  6043,
+  6151,
+  6222,
+  6225,
+  6228,
+  6231,
+  6234,
+  6237,
+  6240,
 ];
 
-static PRUNE_23: [usize; 186] = [
-  5101,
-  4786,
-  4784,
-  544,
-  2843,
-  4782,
-  1174,
-  4465,
-  5422,
-  3393,
-  5107,
-  3391,
-  5105,
-  1182,
-  955,
-  5103,
-  3389,
-  5856,
-  1211,
-  3060,
-  4459,
-  6171,
-  4142,
-  5854,
-  4140,
-  1252,
-  4138,
-  5852,
-  2696,
-  3823,
-  540,
-  4780,
-  1217,
-  6177,
-  4463,
-  4461,
-  6175,
-  536,
-  4144,
-  6173,
-  3062,
-  2688,
-  3500,
-  5529,
-  5214,
-  1606,
-  3498,
-  5212,
-  1199,
-  1784,
-  2684,
-  970,
-  3496,
+static PRUNE_13: [usize; 197] = [
+  4937,
+  4938,
+  4941,
+  4944,
+  4947,
+  4950,
+  4953,
+  4956,
+  4959,
+  4962,
+  4965,
+  4968,
+  4971,
+  4974,
+  4977,
+  4980,
+  4983,
+  4986,
+  4989,
+  4992,
+  4995,
+  4998,
+  5001,
+  5004,
+  5007,
+  5010,
+  5013,
+  5016,
+  5019,
+  5022,
+  5025,
+  5028,
+  5031,
+  5035,
+  5036,
+  5039,
+  5042,
+  5045,
+  5048,
+  5051,
+  5054,
+  5057,
+  5060,
+  5063,
+  5066,
+  5069,
+  5072,
+  5075,
+  5078,
+  5081,
+  5084,
+  5087,
+  5090,
+  5093,
+  5096,
+  5099,
+  5102,
+  5105,
+  5108,
+  5111,
+  5114,
+  5117,
+  5120,
+  5123,
+  5126,
+  5129,
+  5133,
+  5135,
+  5138,
+  5141,
+  5144,
+  5147,
+  5150,
+  5153,
+  5156,
+  5159,
+  5162,
+  5165,
+  5168,
+  5171,
+  5174,
+  5177,
+  5180,
+  5183,
+  5186,
+  5189,
+  5192,
+  5195,
+  5198,
+  5201,
+  5204,
+  5207,
   5210,
-  5535,
-  3821,
-  1567,
-  2692,
-  528,
-  5850,
-  526,
-  3819,
-  3052,
-  5533,
-  3817,
-  3050,
-  3502,
-  5531,
-  976,
-  4887,
-  1189,
-  1187,
-  962,
-  4570,
-  6284,
-  6282,
-  4568,
-  4251,
-  5208,
-  4893,
-  4891,
-  1236,
-  4572,
-  4889,
-  3928,
-  5642,
-  545,
+  5213,
+  5216,
+  5219,
+  5222,
+  5225,
+  5228,
+  5232,
+  5233,
+  5236,
+  5239,
+  5242,
+  5245,
+  5248,
+  5251,
+  5254,
+  5257,
+  5260,
+  5263,
+  5266,
+  5269,
+  5272,
+  5275,
+  5278,
+  5281,
+  5284,
+  5287,
+  5290,
+  5293,
+  5296,
+  5299,
+  5302,
+  5305,
+  5308,
+  5311,
+  5314,
+  5317,
+  5320,
+  5323,
+  5326,
+  5329,
+  5331,
+  5332,
+  5335,
+  5338,
+  5341,
+  5344,
+  5347,
+  5350,
+  5353,
+  5356,
+  5359,
+  5362,
+  5365,
+  5368,
+  5371,
+  5374,
+  5377,
+  5380,
+  5383,
+  5386,
+  5389,
+  5392,
+  5395,
+  5398,
+  5401,
+  5404,
+  5407,
+  5410,
+  5413,
+  5416,
+  5419,
+  5422,
+  5425,
+  5527,
+  5625,
+  5730,
+  5955,
+  5956,
   5957,
-  5640,
-  3926,
-  3924,
-  1173,
-  5638,
-  3609,
-  1228,
-  5963,
-  4566,
-  958,
-  6280,
-  956,
-  4249,
-  6278,
-  4247,
-  5961,
+  5958,
   5959,
-  4245,
-  547,
-  3930,
-  1224,
-  2699,
-  5315,
-  535,
-  3286,
-  5000,
-  4998,
-  2427,
-  3284,
-  3057,
-  1208,
-  1253,
-  3282,
-  4996,
-  3607,
-  5636,
-  5321,
-  1666,
-  5319,
-  3288,
-  3605,
-  3603,
-  5317,
-  975,
-  6387,
-  1200,
-  4673,
-  6385,
-  4356,
-  6070,
-  2687,
-  4354,
-  2685,
-  6068,
-  2370,
-  6066,
-  4037,
-  529,
-  4994,
-  4679,
-  3055,
-  6391,
-  4677,
-  2691,
-  4675,
-  6389,
-  4358,
-  1202,
-  525,
-  3714,
-  963,
-  5428,
-  5426,
-  5743,
-  3172,
-  3170,
-  554,
-  3712,
-  3710,
-  3395,
-  5424,
-  959,
-  5749,
-  1194,
-  4035,
-  1239,
-  382,
-  4352,
-  969,
-  6064,
-  1237,
-  4033,
-  5747,
-  1190,
-  5745,
-  4031,
-  965,
-  3716,
+  5960,
+  5961,
+  6026,
+  6029,
+  6032,
+  6035,
+  6038,
+  6041,
+  6044,
+  6152,
+  6153,
+  6154,
+  6155,
+  6156,
+  6157,
+  6158,
+  6223,
+  6226,
+  6229,
+  6232,
+  6235,
+  6238,
+  6241,
 ];
 
-static PRUNE_11: [usize; 178] = [
-  6138,
-  2485,
-  4424,
-  2258,
-  2303,
-  2755,
-  1898,
-  2710,
-  4422,
-  6136,
-  2571,
-  2706,
-  6134,
-  3023,
-  4105,
-  1904,
-  2221,
-  4745,
-  4743,
-  2217,
-  2172,
-  4426,
-  5496,
-  3782,
-  3780,
-  5494,
-  3463,
-  2561,
-  5492,
-  4103,
-  2299,
-  5817,
-  3021,
-  5815,
-  2747,
-  4101,
-  5813,
-  3784,
-  2250,
-  2598,
-  2191,
-  4852,
-  4850,
-  430,
-  2731,
-  4533,
-  1927,
-  3461,
-  5175,
-  3459,
-  5173,
-  2242,
-  2287,
-  2197,
-  2240,
-  5171,
-  2195,
-  4854,
-  5924,
-  4210,
-  2179,
-  1907,
-  4208,
-  5922,
-  3891,
-  5920,
-  6245,
-  2187,
-  428,
-  426,
-  2727,
-  4531,
-  1915,
-  6243,
-  2545,
-  4529,
-  6241,
-  2723,
-  2183,
-  2228,
-  4212,
-  5282,
-  3568,
-  3566,
-  5280,
-  1852,
-  2709,
-  3249,
-  2302,
-  2437,
-  2255,
-  4961,
-  2300,
-  2707,
-  5278,
-  2175,
-  3889,
-  2220,
-  5603,
-  5601,
-  2715,
-  2760,
-  3887,
-  3570,
-  2218,
-  2173,
-  1946,
-  5599,
-  6352,
-  4638,
-  1930,
-  4636,
-  6350,
-  6348,
-  2740,
-  2785,
-  2245,
-  4319,
-  3247,
-  2568,
-  3245,
-  4959,
-  2253,
-  4957,
-  4640,
-  3996,
-  2237,
-  5710,
-  1920,
-  5708,
-  2730,
-  3677,
-  1873,
-  431,
-  2190,
+static PRUNE_14: [usize; 337] = [
+  3958,
+  3961,
+  3964,
+  3967,
+  3970,
+  3973,
+  3976,
+  3979,
+  3982,
+  3985,
+  3988,
+  3991,
   3994,
-  2460,
-  3135,
-  5706,
-  2188,
-  2288,
-  4317,
-  6031,
-  2198,
-  6029,
-  4315,
-  6027,
-  2554,
-  3998,
-  2194,
-  2722,
-  1865,
-  3354,
-  5068,
-  2450,
-  3352,
-  2180,
-  5066,
-  5064,
-  2176,
+  3997,
+  4000,
+  4003,
+  4006,
+  4009,
+  4012,
+  4015,
+  4018,
+  4021,
+  4024,
+  4027,
+  4030,
+  4033,
+  4036,
+  4039,
+  4042,
+  4045,
+  4048,
+  4051,
+  4154,
+  4157,
+  4160,
+  4163,
+  4166,
+  4169,
+  4172,
+  4175,
+  4178,
+  4181,
+  4184,
+  4187,
+  4190,
+  4193,
+  4196,
+  4199,
+  4202,
+  4205,
+  4208,
+  4211,
+  4214,
+  4217,
+  4220,
+  4223,
+  4226,
+  4229,
+  4232,
+  4235,
+  4238,
+  4241,
+  4244,
+  4247,
+  4350,
+  4353,
+  4356,
+  4359,
+  4362,
+  4365,
+  4368,
+  4371,
+  4374,
+  4377,
+  4380,
+  4383,
+  4386,
+  4389,
+  4392,
+  4395,
+  4398,
+  4401,
+  4404,
+  4407,
+  4410,
+  4413,
+  4416,
+  4419,
+  4422,
+  4425,
+  4428,
+  4431,
+  4434,
+  4437,
+  4440,
+  4443,
+  4547,
+  4550,
+  4553,
+  4556,
+  4559,
+  4562,
+  4565,
+  4568,
+  4571,
+  4574,
+  4577,
+  4580,
+  4583,
+  4586,
+  4589,
+  4592,
+  4595,
+  4598,
+  4601,
+  4604,
+  4607,
+  4610,
+  4613,
+  4616,
+  4619,
+  4622,
+  4625,
+  4628,
+  4631,
+  4634,
+  4637,
+  4640,
+  4744,
   4747,
-  2583,
-  2718,
-  1961,
-  3133,
-  2548,
-  3675,
-  2818,
-  2728,
-  5389,
-  3673,
-  5387,
-  3356,
-  425,
-  2182,
-  423,
-  2227,
-  5385,
-];
-
-static PRUNE_42: [usize; 135] = [
+  4750,
+  4753,
+  4756,
+  4759,
+  4762,
+  4765,
+  4768,
+  4771,
+  4774,
+  4777,
+  4780,
+  4783,
+  4786,
+  4789,
+  4792,
+  4795,
+  4798,
+  4801,
+  4804,
+  4807,
+  4810,
+  4813,
+  4816,
+  4819,
+  4822,
+  4825,
+  4828,
   4831,
-  4514,
-  4512,
-  6226,
-  4197,
-  6224,
-  4510,
-  5909,
-  5152,
-  4835,
-  4833,
-  4518,
-  6230,
-  3872,
-  5901,
-  5584,
-  3555,
-  5267,
-  3870,
-  5582,
-  3868,
-  1487,
-  4193,
-  3876,
-  6222,
-  5905,
-  4191,
-  4189,
-  5903,
-  5588,
-  3230,
-  5259,
-  3228,
+  4834,
+  4837,
+  4939,
   4942,
-  3226,
-  4940,
-  4625,
-  6337,
-  5580,
-  3551,
-  5263,
-  3549,
-  3234,
-  4946,
-  3547,
-  841,
-  5261,
-  4300,
-  4617,
-  3983,
-  6329,
-  4298,
-  6012,
-  4296,
-  6010,
-  5695,
-  4938,
-  4621,
-  4619,
-  4304,
-  6333,
-  6331,
-  6016,
-  5687,
-  3658,
-  5370,
-  3656,
-  3341,
-  5053,
-  5368,
-  3654,
-  3979,
-  3122,
-  3662,
-  5691,
-  6008,
-  5374,
-  3120,
-  3977,
-  3975,
-  5689,
-  5045,
-  4728,
-  4726,
-  6440,
-  3012,
-  4411,
-  6438,
-  6123,
-  5366,
-  3337,
-  3335,
+  4945,
+  4948,
+  4951,
+  4954,
+  4957,
+  4960,
+  4963,
+  4966,
+  4969,
+  4972,
+  4975,
+  4978,
+  4981,
+  4984,
+  4987,
+  4990,
+  4993,
+  4996,
+  4999,
+  5002,
+  5005,
+  5008,
+  5011,
+  5014,
+  5017,
+  5020,
+  5023,
+  5026,
+  5029,
+  5032,
+  5037,
+  5040,
+  5043,
+  5046,
   5049,
-  5047,
-  1484,
-  4732,
-  2973,
-  6444,
-  3333,
-  4086,
-  3769,
-  6115,
-  5481,
-  4084,
-  5798,
-  4082,
-  5796,
-  3000,
-  4724,
-  4090,
-  6436,
-  4407,
-  6119,
-  4405,
-  4403,
-  6117,
-  3006,
-  5802,
-  5473,
-  3444,
-  3442,
-  5156,
-  3127,
-  3125,
+  5052,
+  5055,
+  5058,
+  5061,
+  5064,
+  5067,
+  5070,
+  5073,
+  5076,
+  5079,
+  5082,
+  5085,
+  5088,
+  5091,
+  5094,
+  5097,
+  5100,
+  5103,
+  5106,
+  5109,
+  5112,
+  5115,
+  5118,
+  5121,
+  5124,
+  5127,
+  5130,
+  5136,
+  5139,
+  5142,
+  5145,
+  5148,
+  5151,
   5154,
-  4839,
-  3440,
-  5794,
-  3765,
-  5477,
-  3763,
-  3448,
+  5157,
   5160,
-  3761,
-  5475,
-];
-
-static PRUNE_16: [usize; 246] = [
-  6048,
-  4334,
-  6363,
-  1806,
-  4332,
-  1626,
-  6046,
-  4330,
-  6044,
-  4015,
-  2391,
-  497,
-  6369,
-  2311,
-  4972,
-  2401,
-  4655,
-  6367,
-  1767,
-  4653,
-  96,
-  4651,
-  6365,
-  2352,
-  4336,
-  3150,
-  5721,
-  2203,
-  5406,
-  3692,
-  1796,
-  3690,
-  1751,
-  5404,
-  2338,
-  3373,
-  2381,
-  3148,
-  3146,
-  5085,
-  2334,
-  3688,
-  1839,
-  5402,
-  4013,
-  495,
-  6042,
-  5727,
-  5725,
-  4011,
-  1755,
-  3694,
-  1800,
-  493,
-  2385,
-  491,
-  5723,
-  4009,
-  2160,
-  5079,
-  4762,
-  340,
-  4760,
-  2279,
-  520,
-  1739,
-  1829,
-  23,
-  4758,
-  2414,
-  4443,
-  123,
-  1657,
-  5400,
-  3371,
-  1747,
-  3369,
-  2422,
-  5083,
-  2330,
-  3367,
-  5081,
-  121,
-  4764,
-  1653,
-  119,
-  4120,
-  512,
-  5834,
-  2406,
-  242,
-  5832,
-  240,
-  6149,
-  1819,
-  3801,
-  2404,
-  4118,
-  2314,
-  1635,
-  238,
-  5830,
-  3034,
-  4116,
-  2312,
-  1645,
-  4441,
-  1780,
-  6155,
-  338,
-  336,
-  2365,
-  516,
-  2320,
-  21,
-  111,
-  244,
-  334,
-  1733,
-  6153,
-  4439,
-  19,
-  4437,
-  1731,
-  1821,
-  17,
-  6151,
-  4122,
-  514,
-  5507,
-  3478,
-  5192,
-  1764,
-  3476,
-  1807,
+  5163,
+  5166,
+  5169,
+  5172,
+  5175,
+  5178,
+  5181,
+  5184,
+  5187,
   5190,
-  498,
-  2347,
-  2392,
-  5188,
-  1625,
-  4871,
-  3474,
-  5828,
-  5513,
-  3799,
-  3797,
-  5511,
-  3480,
-  2308,
-  3795,
-  97,
-  5509,
-  6262,
-  4548,
-  1752,
-  1797,
-  4865,
-  2384,
-  2337,
-  2382,
-  1660,
-  4546,
-  6260,
-  6258,
-  124,
-  4544,
-  2335,
-  4229,
-  5186,
-  3020,
-  4869,
-  494,
-  4867,
-  492,
-  4550,
-  1799,
-  1754,
-  5935,
-  2417,
-  2327,
-  1832,
-  5620,
-  3906,
-  5618,
-  3904,
-  1650,
-  3587,
-  521,
-  2323,
-  3902,
-  339,
-  5616,
-  4227,
-  5941,
-  1836,
-  122,
-  6256,
-  120,
-  2421,
-  4225,
-  5939,
-  1744,
-  4223,
-  1742,
-  3908,
-  5937,
-  1775,
-  16,
-  241,
-  3264,
-  5293,
-  1730,
-  1638,
-  3262,
-  2360,
-  4976,
-  2315,
-  4974,
-  3260,
-  239,
-  4657,
-  237,
-  1816,
-  337,
-  5614,
-  3585,
-  5299,
-  22,
-  3583,
-  1734,
-  1824,
-  20,
+  5193,
+  5196,
+  5199,
+  5202,
+  5205,
+  5208,
+  5211,
+  5214,
+  5217,
+  5220,
+  5223,
+  5226,
+  5229,
+  5234,
+  5237,
+  5240,
+  5243,
+  5246,
+  5249,
+  5252,
+  5255,
+  5258,
+  5261,
+  5264,
+  5267,
+  5270,
+  5273,
+  5276,
+  5279,
+  5282,
+  5285,
+  5288,
+  5291,
+  5294,
   5297,
-  517,
-  2409,
-  515,
-  3266,
-  1642,
+  5300,
+  5303,
+  5306,
+  5309,
+  5312,
+  5315,
+  5318,
+  5321,
+  5324,
+  5327,
+  5333,
+  5336,
+  5339,
+  5342,
+  5345,
+  5348,
+  5351,
+  5354,
+  5357,
+  5360,
+  5363,
+  5366,
+  5369,
+  5372,
+  5375,
+  5378,
+  5381,
+  5384,
+  5387,
+  5390,
+  5393,
+  5396,
+  5399,
+  5402,
+  5405,
+  5408,
+  5411,
+  5414,
+  5417,
+  5420,
+  5423,
+  5426,
+  5526,
+  5626,
+  5829,
+  6030,
+  6033,
+  6036,
+  6039,
+  6042,
+  6045,
+  6046,
+  6227,
+  6230,
+  6233,
+  6236,
+  6239,
+  6242,
+  6243,
+];
+
+static PRUNE_15: [usize; 81] = [
+  5038,
+  5041,
+  5044,
+  5047,
+  5050,
+  5053,
+  5056,
+  5059,
+  5062,
+  5065,
+  5068,
+  5071,
+  5074,
+  5077,
+  5080,
+  5083,
+  5086,
+  5089,
+  5092,
+  5095,
+  5098,
+  5101,
+  5104,
+  5107,
+  5110,
+  5113,
+  5116,
+  5119,
+  5122,
+  5125,
+  5128,
+  5131,
+  5235,
+  5238,
+  5241,
+  5244,
+  5247,
+  5250,
+  5253,
+  5256,
+  5259,
+  5262,
+  5265,
+  5268,
+  5271,
+  5274,
+  5277,
+  5280,
+  5283,
+  5286,
+  5289,
+  5292,
   5295,
-  335,
-  243,
-  333,
-  4978,
-  18,
-  3581,
+  5298,
+  5301,
+  5304,
+  5307,
+  5310,
+  5313,
+  5316,
+  5319,
+  5322,
+  5325,
+  5328,
+  5330,
+  6027,
+  6047,
+  6049,
+  6050,
+  6052,
+  6053,
+  6055,
+  6056,
+  6224,
+  6244,
+  6246,
+  6247,
+  6249,
+  6250,
+  6252,
+  6253,
 ];
 
-static PRUNE_4: [usize; 65] = [
-  681,
-  2076,
-  2618,
-  677,
-  2626,
-  2491,
-  2489,
-  685,
-  2037,
-  2608,
-  2066,
-  2019,
-  2614,
-  2520,
-  2508,
-  2056,
-  2641,
-  2009,
-  2017,
-  2604,
-  2647,
-  2510,
-  2060,
-  2013,
-  2046,
-  2001,
-  1999,
-  2629,
-  690,
-  2492,
-  2500,
-  2498,
-  2531,
-  2619,
-  680,
-  2527,
-  678,
-  2038,
-  684,
-  2488,
-  2623,
-  2654,
-  2020,
-  2607,
-  2605,
-  674,
-  2073,
-  2069,
-  2611,
-  2012,
-  2010,
-  2507,
-  2503,
-  2053,
-  2061,
-  2016,
-  2511,
-  2045,
-  2495,
-  1998,
-  2538,
-  689,
-  2628,
-  2638,
-  2002,
+static PRUNE_16: [usize; 75] = [
+  5429,
+  5430,
+  5433,
+  5436,
+  5439,
+  5442,
+  5445,
+  5448,
+  5451,
+  5454,
+  5457,
+  5460,
+  5463,
+  5466,
+  5469,
+  5472,
+  5475,
+  5478,
+  5481,
+  5484,
+  5487,
+  5490,
+  5493,
+  5496,
+  5499,
+  5502,
+  5505,
+  5508,
+  5511,
+  5514,
+  5517,
+  5520,
+  5523,
+  5528,
+  5529,
+  5532,
+  5535,
+  5538,
+  5541,
+  5544,
+  5547,
+  5550,
+  5553,
+  5556,
+  5559,
+  5562,
+  5565,
+  5568,
+  5571,
+  5574,
+  5577,
+  5580,
+  5583,
+  5586,
+  5589,
+  5592,
+  5595,
+  5598,
+  5601,
+  5604,
+  5607,
+  5610,
+  5613,
+  5616,
+  5619,
+  5622,
+  5726,
+  6048,
+  6051,
+  6054,
+  6057,
+  6245,
+  6248,
+  6251,
+  6254,
 ];
 
-static PRUNE_9: [usize; 159] = [
-  2575,
-  4741,
-  1988,
-  4739,
-  1941,
-  2753,
-  1896,
-  2483,
-  2438,
-  1849,
-  4420,
-  2581,
-  3348,
-  5062,
-  5060,
-  1949,
-  415,
-  2757,
-  1855,
-  5811,
-  4097,
-  444,
-  2745,
-  2788,
-  5809,
-  440,
-  2741,
-  3778,
-  2434,
-  1937,
-  4418,
-  2749,
-  6132,
-  1847,
-  6130,
-  2432,
-  4099,
-  2565,
-  2025,
-  2778,
-  3455,
-  5169,
-  2551,
-  2596,
+static PRUNE_17: [usize; 134] = [
+  4940,
+  4943,
+  4946,
+  4949,
+  4952,
+  4955,
+  4958,
+  4961,
+  4964,
+  4967,
+  4970,
+  4973,
+  4976,
+  4979,
+  4982,
+  4985,
+  4988,
+  4991,
+  4994,
+  4997,
+  5000,
+  5003,
+  5006,
+  5009,
+  5012,
+  5015,
+  5018,
+  5021,
+  5024,
+  5027,
+  5030,
+  5033,
+  5137,
+  5140,
+  5143,
+  5146,
+  5149,
+  5152,
+  5155,
+  5158,
+  5161,
+  5164,
   5167,
-  1917,
-  3776,
-  5490,
-  2559,
-  2737,
-  2465,
-  1925,
-  1880,
+  5170,
+  5173,
+  5176,
+  5179,
+  5182,
+  5185,
+  5188,
+  5191,
+  5194,
+  5197,
+  5200,
+  5203,
+  5206,
+  5209,
+  5212,
+  5215,
+  5218,
+  5221,
+  5224,
+  5227,
+  5230,
+  5431,
+  5434,
+  5437,
+  5440,
+  5443,
+  5446,
+  5449,
+  5452,
+  5455,
+  5458,
+  5461,
+  5464,
+  5467,
+  5470,
+  5473,
+  5476,
+  5479,
+  5482,
+  5485,
   5488,
-  2555,
-  3457,
-  2735,
-  1956,
-  2089,
-  2586,
-  6239,
-  1909,
-  4525,
-  6237,
-  1952,
-  4206,
-  2457,
-  4848,
-  4846,
-  1870,
-  1913,
-  1868,
-  2770,
-  2455,
-  739,
-  4527,
-  1944,
+  5491,
+  5494,
+  5497,
+  5500,
+  5503,
+  5506,
+  5509,
+  5512,
+  5515,
+  5518,
+  5521,
+  5524,
+  5530,
+  5533,
+  5536,
+  5539,
+  5542,
+  5545,
+  5548,
+  5551,
+  5554,
+  5557,
+  5560,
+  5563,
+  5566,
+  5569,
+  5572,
+  5575,
+  5578,
+  5581,
+  5584,
+  5587,
+  5590,
+  5593,
+  5596,
+  5599,
+  5602,
+  5605,
+  5608,
+  5611,
+  5614,
+  5617,
+  5620,
+  5623,
+  5627,
+  5728,
+  6058,
+  6059,
+  6255,
+  6256,
+];
+
+static PRUNE_18: [usize; 35] = [
+  5531,
+  5534,
+  5537,
+  5540,
+  5543,
+  5546,
+  5549,
+  5552,
+  5555,
+  5558,
+  5561,
+  5564,
+  5567,
+  5570,
+  5573,
+  5576,
+  5579,
+  5582,
+  5585,
+  5588,
+  5591,
+  5594,
   5597,
-  3883,
-  2756,
-  5595,
-  3564,
-  1850,
-  2752,
-  5918,
-  1860,
-  1858,
-  4204,
-  2445,
-  2580,
-  2578,
-  5916,
-  2441,
-  2803,
-  3885,
-  2744,
-  4955,
-  3241,
-  443,
-  4953,
-  2112,
-  2742,
-  1883,
-  4634,
-  2750,
-  3562,
-  2435,
-  5276,
-  5274,
-  3243,
-  1891,
-  1846,
-  1934,
-  2791,
-  2431,
-  2476,
-  2566,
-  6025,
-  2734,
-  2102,
-  2552,
-  4311,
-  6023,
-  2458,
-  3992,
-  2558,
-  2468,
-  437,
-  2738,
-  4632,
-  2783,
-  6346,
-  1924,
-  6344,
-  2556,
-  4313,
-  2767,
-  3669,
-  1910,
-  5383,
-  2452,
-  5381,
-  419,
-  3350,
-  2448,
-  1863,
-  3990,
-  5704,
-  2593,
-  1916,
-  1871,
-  2816,
-  1959,
-  2589,
-  5702,
-  2137,
-  3671,
-  1867,
-  1912,
+  5600,
+  5603,
+  5606,
+  5609,
+  5612,
+  5615,
+  5618,
+  5621,
+  5624,
+  5729,
+  6060,
+  6257,
 ];
 
-static PRUNE_40: [usize; 127] = [
-  6003,
-  4829,
-  184,
-  182,
-  3113,
-  3970,
-  6324,
-  3438,
-  5150,
-  280,
-  2986,
-  1272,
-  188,
-  278,
-  863,
-  186,
-  4291,
-  2970,
-  806,
-  5361,
-  4187,
-  3328,
-  5899,
-  5040,
-  982,
-  2886,
-  5682,
-  1172,
-  4508,
-  6220,
-  3649,
-  1485,
-  2430,
-  3545,
-  6431,
-  1966,
-  162,
-  5257,
-  1379,
-  160,
-  158,
-  4398,
-  3866,
-  845,
-  5578,
-  164,
-  4719,
-  5789,
-  377,
-  375,
-  872,
-  4615,
-  1729,
-  825,
-  3756,
-  6327,
-  283,
-  373,
-  6110,
-  3224,
-  4936,
-  2952,
-  4077,
-  379,
-  185,
-  3973,
-  862,
-  183,
-  5685,
-  4826,
-  181,
-  281,
-  5468,
-  279,
-  4294,
-  3435,
-  6006,
-  3118,
-  5147,
-  187,
-  277,
-  6217,
-  3331,
-  5043,
-  1435,
-  1028,
-  4184,
-  3652,
-  2885,
-  5364,
-  2971,
-  4505,
-  5575,
-  930,
-  3004,
-  163,
-  2822,
-  161,
-  3002,
-  4401,
-  6113,
-  3542,
-  159,
-  5896,
-  3010,
-  3008,
-  4722,
-  6434,
-  3863,
-  797,
-  840,
-  3759,
-  376,
-  374,
-  5471,
-  284,
-  4612,
-  282,
-  2998,
-  5254,
-  4080,
-  3221,
-  2996,
-  5792,
-  380,
-  378,
-  4933,
+static PRUNE_19: [usize; 36] = [
+  5628,
+  5629,
+  5632,
+  5635,
+  5638,
+  5641,
+  5644,
+  5647,
+  5650,
+  5653,
+  5656,
+  5659,
+  5662,
+  5665,
+  5668,
+  5671,
+  5674,
+  5677,
+  5680,
+  5683,
+  5686,
+  5689,
+  5692,
+  5695,
+  5698,
+  5701,
+  5704,
+  5707,
+  5710,
+  5713,
+  5716,
+  5719,
+  5722,
+  5725,
+  5827,
+  5830,
 ];
 
-static PRUNE_35: [usize; 168] = [
-  3207,
-  4921,
-  4919,
-  3747,
-  4602,
+static PRUNE_20: [usize; 65] = [
+  5432,
+  5435,
+  5438,
+  5441,
+  5444,
+  5447,
+  5450,
+  5453,
+  5456,
   5459,
-  6314,
-  3528,
-  5242,
-  3211,
-  4068,
-  5240,
-  5780,
-  4923,
-  3209,
-  1166,
-  4277,
-  5991,
-  5989,
-  3960,
-  4817,
-  3958,
+  5462,
+  5465,
+  5468,
+  5471,
+  5474,
+  5477,
+  5480,
+  5483,
+  5486,
+  5489,
+  5492,
+  5495,
+  5498,
+  5501,
+  5504,
+  5507,
+  5510,
+  5513,
+  5516,
+  5519,
+  5522,
+  5525,
+  5630,
+  5633,
+  5636,
+  5639,
+  5642,
+  5645,
+  5648,
+  5651,
+  5654,
+  5657,
+  5660,
+  5663,
+  5666,
+  5669,
   5672,
-  4600,
-  6312,
-  3426,
-  4598,
-  6310,
-  4281,
-  5138,
-  4279,
-  5993,
-  1123,
+  5675,
+  5678,
+  5681,
+  5684,
+  5687,
+  5690,
+  5693,
+  5696,
+  5699,
+  5702,
+  5705,
+  5708,
+  5711,
+  5714,
+  5717,
+  5720,
+  5723,
+  5828,
+];
+
+static PRUNE_21: [usize; 32] = [
+  5334,
+  5337,
+  5340,
+  5343,
+  5346,
   5349,
-  3635,
-  1064,
-  749,
-  3318,
-  4175,
-  5887,
-  5030,
-  5347,
-  1060,
-  3316,
-  745,
-  5670,
-  755,
-  753,
-  3639,
-  4496,
-  3956,
-  5668,
-  751,
-  5351,
-  6208,
-  3637,
-  4707,
-  6421,
-  4705,
-  3533,
-  6419,
-  1052,
-  6417,
-  4388,
-  5245,
-  645,
-  6100,
-  4386,
-  3314,
-  5028,
-  5026,
-  1058,
-  3854,
-  4709,
-  5566,
-  741,
-  5777,
-  2981,
-  4603,
-  4063,
-  5775,
-  2889,
-  3746,
-  5458,
-  3744,
-  6315,
-  2987,
-  3212,
-  4384,
-  6098,
-  6096,
-  4924,
-  4067,
-  4065,
-  5779,
-  3421,
-  5135,
-  3104,
-  1480,
-  5133,
-  3961,
-  3102,
-  4816,
-  5673,
-  1433,
-  3742,
-  5456,
-  766,
-  4282,
-  764,
-  3425,
-  5994,
-  5454,
-  1169,
-  3423,
-  5137,
-  3319,
-  1470,
-  1155,
-  6205,
-  748,
-  4491,
-  6203,
-  4174,
-  5031,
-  4172,
-  5886,
-  4814,
-  754,
-  1071,
-  3100,
-  1159,
-  4812,
-  3098,
-  3640,
-  2828,
-  1069,
-  4495,
   5352,
-  752,
-  6207,
-  4493,
-  750,
-  1427,
-  5563,
-  3849,
-  4389,
-  3532,
-  1411,
-  644,
-  5561,
-  1049,
-  5244,
-  6101,
-  3530,
-  1104,
-  744,
-  4170,
-  5884,
-  742,
-  1417,
-  3853,
-  5882,
-  4710,
-  3851,
-  6422,
-  5565,
-  1055,
+  5355,
+  5358,
+  5361,
+  5364,
+  5367,
+  5370,
+  5373,
+  5376,
+  5379,
+  5382,
+  5385,
+  5388,
+  5391,
+  5394,
+  5397,
+  5400,
+  5403,
+  5406,
+  5409,
+  5412,
+  5415,
+  5418,
+  5421,
+  5424,
+  5427,
 ];
 
-static PRUNE_28: [usize; 169] = [
-  4154,
-  1313,
-  5866,
-  4,
-  139,
-  949,
-  2,
-  137,
-  3295,
-  0,
-  1354,
-  5007,
-  912,
-  4475,
-  1319,
-  1362,
-  595,
-  1317,
-  1270,
-  6187,
-  3616,
-  6,
-  5328,
-  908,
-  141,
-  354,
-  6398,
-  3512,
-  5224,
-  4365,
-  352,
-  350,
-  1297,
-  3191,
-  260,
-  135,
-  3833,
-  5545,
-  988,
-  628,
-  583,
-  4686,
-  356,
-  4582,
-  1017,
-  6294,
-  3723,
-  5435,
-  258,
-  6077,
-  4903,
-  573,
-  4044,
-  256,
-  1293,
-  1248,
-  5756,
-  254,
-  886,
-  1021,
-  3940,
-  1277,
+static PRUNE_22: [usize; 33] = [
+  5727,
+  5731,
+  5734,
+  5737,
+  5740,
+  5743,
+  5746,
+  5749,
+  5752,
+  5755,
+  5758,
+  5761,
+  5764,
+  5767,
+  5770,
+  5773,
+  5776,
+  5779,
+  5782,
+  5785,
+  5788,
+  5791,
+  5794,
+  5797,
+  5800,
+  5803,
+  5806,
+  5809,
+  5812,
+  5815,
+  5818,
+  5821,
+  5824,
+];
+
+static PRUNE_23: [usize; 64] = [
+  5631,
+  5634,
+  5637,
+  5640,
+  5643,
+  5646,
+  5649,
   5652,
-  3081,
-  917,
-  1005,
-  4793,
-  1365,
-  4261,
-  1283,
-  5973,
-  1373,
-  923,
-  1013,
-  2907,
-  1011,
-  3402,
-  919,
-  5114,
-  140,
-  5,
-  6184,
-  3,
-  905,
-  5010,
-  1357,
-  1312,
-  4151,
-  1355,
-  138,
-  5863,
-  1,
-  993,
-  136,
-  3077,
-  1363,
-  3619,
-  911,
-  5331,
-  4472,
-  909,
-  7,
-  3298,
-  4368,
-  895,
-  355,
-  400,
-  1347,
-  1300,
-  6080,
-  353,
-  1298,
-  351,
-  3509,
-  983,
-  981,
-  576,
-  5221,
-  134,
-  4689,
-  586,
-  1306,
-  1304,
-  989,
-  3830,
-  6401,
-  2836,
-  987,
-  5542,
-  1335,
-  568,
-  3726,
-  253,
-  5438,
-  4579,
-  1288,
-  566,
-  3405,
-  1016,
-  259,
-  4047,
-  349,
-  1341,
-  257,
-  3188,
-  1339,
-  1294,
+  5655,
+  5658,
+  5661,
+  5664,
+  5667,
+  5670,
+  5673,
+  5676,
+  5679,
+  5682,
+  5685,
+  5688,
+  5691,
+  5694,
+  5697,
+  5700,
+  5703,
+  5706,
+  5709,
+  5712,
+  5715,
+  5718,
+  5721,
+  5724,
+  5732,
+  5735,
+  5738,
+  5741,
+  5744,
+  5747,
+  5750,
+  5753,
+  5756,
   5759,
-  1022,
-  255,
-  572,
-  4900,
-  1337,
-  570,
-  1008,
-  5970,
-  4796,
-  1368,
-  3937,
-  5649,
-  2898,
-  879,
-  924,
-  6291,
-  5117,
-  4258,
-  1372,
-  1325,
-  1010,
+  5762,
+  5765,
+  5768,
+  5771,
+  5774,
+  5777,
+  5780,
+  5783,
+  5786,
+  5789,
+  5792,
+  5795,
+  5798,
+  5801,
+  5804,
+  5807,
+  5810,
+  5813,
+  5816,
+  5819,
+  5822,
+  5825,
 ];
 
-static PRUNE_2: [usize; 34] = [
-  2536,
-  2532,
-  669,
-  2518,
-  2651,
-  2524,
-  2522,
-  671,
-  2643,
-  665,
-  2514,
-  2649,
-  661,
-  2635,
-  2633,
-  649,
-  2535,
-  2533,
-  668,
-  2652,
-  2517,
-  666,
-  2515,
-  2525,
-  2521,
-  654,
-  664,
-  2648,
-  660,
-  2644,
-  648,
-  2632,
-  2636,
-  652,
+static PRUNE_24: [usize; 33] = [
+  5733,
+  5736,
+  5739,
+  5742,
+  5745,
+  5748,
+  5751,
+  5754,
+  5757,
+  5760,
+  5763,
+  5766,
+  5769,
+  5772,
+  5775,
+  5778,
+  5781,
+  5784,
+  5787,
+  5790,
+  5793,
+  5796,
+  5799,
+  5802,
+  5805,
+  5808,
+  5811,
+  5814,
+  5817,
+  5820,
+  5823,
+  5826,
+  5831,
 ];
 
-static PRUNE_33: [usize; 141] = [
-  5236,
-  1133,
-  409,
-  3205,
-  1041,
-  4917,
-  3203,
-  1409,
-  5557,
-  3526,
-  3524,
-  1043,
-  5238,
-  1121,
-  4594,
-  6308,
-  1031,
-  6306,
-  1119,
-  2833,
-  4275,
-  1164,
-  5987,
-  1387,
-  4915,
-  2931,
-  1035,
-  4596,
-  5664,
-  3633,
-  3631,
-  610,
-  5345,
-  1115,
-  4273,
-  5985,
-  1475,
-  1383,
-  3954,
-  1156,
-  3952,
-  5666,
-  5022,
-  1324,
-  1414,
-  1054,
-  1099,
-  4703,
-  1050,
-  1412,
-  1457,
-  643,
-  5343,
-  1465,
-  1420,
-  3312,
-  1463,
-  1146,
-  5024,
-  3310,
-  1042,
-  4380,
-  6094,
-  6092,
-  4061,
-  1040,
-  1130,
-  5773,
-  2932,
-  4059,
-  1038,
-  6415,
-  1048,
-  4701,
-  1091,
-  1046,
-  6413,
-  2895,
-  4382,
-  639,
-  412,
-  1044,
-  5450,
-  1392,
-  1390,
-  1030,
-  3419,
-  1478,
-  3417,
-  5131,
-  1126,
-  404,
-  5771,
-  2883,
-  3740,
-  3738,
-  1032,
-  5452,
-  1167,
-  3094,
-  1425,
-  4808,
-  1153,
-  1108,
-  4489,
-  1378,
-  1468,
-  1423,
-  1151,
-  5129,
-  1431,
-  1161,
-  1157,
-  2871,
-  1429,
-  3096,
-  4810,
-  1382,
-  1472,
-  1053,
-  1143,
-  1460,
-  4166,
-  5878,
-  1096,
-  1141,
-  3847,
-  2853,
-  5559,
-  2943,
-  3845,
-  1149,
-  4487,
-  6201,
-  6199,
-  4168,
-  1147,
-  1102,
-  5880,
-  1145,
-  1415,
+static PRUNE_25: [usize; 33] = [
+  5832,
+  5833,
+  5834,
+  5835,
+  5836,
+  5837,
+  5838,
+  5839,
+  5840,
+  5841,
+  5842,
+  5843,
+  5844,
+  5845,
+  5846,
+  5847,
+  5848,
+  5849,
+  5850,
+  5851,
+  5852,
+  5853,
+  5854,
+  5855,
+  5856,
+  5857,
+  5858,
+  5859,
+  5860,
+  5861,
+  5862,
+  5863,
+  6258,
 ];
 
 fn prune<E: BoolEvaluator>(
@@ -13295,99 +12691,58 @@ pub fn get_cell<E: BoolEvaluator>(items: &Vec<FheBool<E>>, monsters: &Vec<FheBoo
     };
 
         run_level(&mut temp_nodes, &LEVEL_0);
-    run_level(&mut temp_nodes, &LEVEL_1);
-    prune(&mut temp_nodes, &PRUNE_1);
-    run_level(&mut temp_nodes, &LEVEL_2);
-    prune(&mut temp_nodes, &PRUNE_2);
-    run_level(&mut temp_nodes, &LEVEL_3);
-    prune(&mut temp_nodes, &PRUNE_3);
-    run_level(&mut temp_nodes, &LEVEL_4);
-    prune(&mut temp_nodes, &PRUNE_4);
-    run_level(&mut temp_nodes, &LEVEL_5);
-    prune(&mut temp_nodes, &PRUNE_5);
-    run_level(&mut temp_nodes, &LEVEL_6);
-    prune(&mut temp_nodes, &PRUNE_6);
-    run_level(&mut temp_nodes, &LEVEL_7);
-    prune(&mut temp_nodes, &PRUNE_7);
-    run_level(&mut temp_nodes, &LEVEL_8);
-    prune(&mut temp_nodes, &PRUNE_8);
-    run_level(&mut temp_nodes, &LEVEL_9);
-    prune(&mut temp_nodes, &PRUNE_9);
-    run_level(&mut temp_nodes, &LEVEL_10);
-    prune(&mut temp_nodes, &PRUNE_10);
-    run_level(&mut temp_nodes, &LEVEL_11);
-    prune(&mut temp_nodes, &PRUNE_11);
-    run_level(&mut temp_nodes, &LEVEL_12);
-    prune(&mut temp_nodes, &PRUNE_12);
-    run_level(&mut temp_nodes, &LEVEL_13);
-    prune(&mut temp_nodes, &PRUNE_13);
-    run_level(&mut temp_nodes, &LEVEL_14);
-    prune(&mut temp_nodes, &PRUNE_14);
-    run_level(&mut temp_nodes, &LEVEL_15);
-    prune(&mut temp_nodes, &PRUNE_15);
-    run_level(&mut temp_nodes, &LEVEL_16);
-    prune(&mut temp_nodes, &PRUNE_16);
-    run_level(&mut temp_nodes, &LEVEL_17);
-    prune(&mut temp_nodes, &PRUNE_17);
-    run_level(&mut temp_nodes, &LEVEL_18);
-    prune(&mut temp_nodes, &PRUNE_18);
-    run_level(&mut temp_nodes, &LEVEL_19);
-    prune(&mut temp_nodes, &PRUNE_19);
-    run_level(&mut temp_nodes, &LEVEL_20);
-    prune(&mut temp_nodes, &PRUNE_20);
-    run_level(&mut temp_nodes, &LEVEL_21);
-    prune(&mut temp_nodes, &PRUNE_21);
-    run_level(&mut temp_nodes, &LEVEL_22);
-    prune(&mut temp_nodes, &PRUNE_22);
-    run_level(&mut temp_nodes, &LEVEL_23);
-    prune(&mut temp_nodes, &PRUNE_23);
-    run_level(&mut temp_nodes, &LEVEL_24);
-    prune(&mut temp_nodes, &PRUNE_24);
-    run_level(&mut temp_nodes, &LEVEL_25);
-    prune(&mut temp_nodes, &PRUNE_25);
-    run_level(&mut temp_nodes, &LEVEL_26);
-    prune(&mut temp_nodes, &PRUNE_26);
-    run_level(&mut temp_nodes, &LEVEL_27);
-    prune(&mut temp_nodes, &PRUNE_27);
-    run_level(&mut temp_nodes, &LEVEL_28);
-    prune(&mut temp_nodes, &PRUNE_28);
-    run_level(&mut temp_nodes, &LEVEL_29);
-    prune(&mut temp_nodes, &PRUNE_29);
-    run_level(&mut temp_nodes, &LEVEL_30);
-    prune(&mut temp_nodes, &PRUNE_30);
-    run_level(&mut temp_nodes, &LEVEL_31);
-    prune(&mut temp_nodes, &PRUNE_31);
-    run_level(&mut temp_nodes, &LEVEL_32);
-    prune(&mut temp_nodes, &PRUNE_32);
-    run_level(&mut temp_nodes, &LEVEL_33);
-    prune(&mut temp_nodes, &PRUNE_33);
-    run_level(&mut temp_nodes, &LEVEL_34);
-    prune(&mut temp_nodes, &PRUNE_34);
-    run_level(&mut temp_nodes, &LEVEL_35);
-    prune(&mut temp_nodes, &PRUNE_35);
-    run_level(&mut temp_nodes, &LEVEL_36);
-    prune(&mut temp_nodes, &PRUNE_36);
-    run_level(&mut temp_nodes, &LEVEL_37);
-    prune(&mut temp_nodes, &PRUNE_37);
-    run_level(&mut temp_nodes, &LEVEL_38);
-    prune(&mut temp_nodes, &PRUNE_38);
-    run_level(&mut temp_nodes, &LEVEL_39);
-    prune(&mut temp_nodes, &PRUNE_39);
-    run_level(&mut temp_nodes, &LEVEL_40);
-    prune(&mut temp_nodes, &PRUNE_40);
-    run_level(&mut temp_nodes, &LEVEL_41);
-    prune(&mut temp_nodes, &PRUNE_41);
-    run_level(&mut temp_nodes, &LEVEL_42);
-    prune(&mut temp_nodes, &PRUNE_42);
-    run_level(&mut temp_nodes, &LEVEL_43);
-    prune(&mut temp_nodes, &PRUNE_43);
-    run_level(&mut temp_nodes, &LEVEL_44);
-    prune(&mut temp_nodes, &PRUNE_44);
-    run_level(&mut temp_nodes, &LEVEL_45);
-    prune(&mut temp_nodes, &PRUNE_45);
+        run_level(&mut temp_nodes, &LEVEL_1);
+        prune(&mut temp_nodes, &PRUNE_1);
+        run_level(&mut temp_nodes, &LEVEL_2);
+        prune(&mut temp_nodes, &PRUNE_2);
+        run_level(&mut temp_nodes, &LEVEL_3);
+        prune(&mut temp_nodes, &PRUNE_3);
+        run_level(&mut temp_nodes, &LEVEL_4);
+        prune(&mut temp_nodes, &PRUNE_4);
+        run_level(&mut temp_nodes, &LEVEL_5);
+        prune(&mut temp_nodes, &PRUNE_5);
+        run_level(&mut temp_nodes, &LEVEL_6);
+        prune(&mut temp_nodes, &PRUNE_6);
+        run_level(&mut temp_nodes, &LEVEL_7);
+        prune(&mut temp_nodes, &PRUNE_7);
+        run_level(&mut temp_nodes, &LEVEL_8);
+        prune(&mut temp_nodes, &PRUNE_8);
+        run_level(&mut temp_nodes, &LEVEL_9);
+        prune(&mut temp_nodes, &PRUNE_9);
+        run_level(&mut temp_nodes, &LEVEL_10);
+        prune(&mut temp_nodes, &PRUNE_10);
+        run_level(&mut temp_nodes, &LEVEL_11);
+        prune(&mut temp_nodes, &PRUNE_11);
+        run_level(&mut temp_nodes, &LEVEL_12);
+        prune(&mut temp_nodes, &PRUNE_12);
+        run_level(&mut temp_nodes, &LEVEL_13);
+        prune(&mut temp_nodes, &PRUNE_13);
+        run_level(&mut temp_nodes, &LEVEL_14);
+        prune(&mut temp_nodes, &PRUNE_14);
+        run_level(&mut temp_nodes, &LEVEL_15);
+        prune(&mut temp_nodes, &PRUNE_15);
+        run_level(&mut temp_nodes, &LEVEL_16);
+        prune(&mut temp_nodes, &PRUNE_16);
+        run_level(&mut temp_nodes, &LEVEL_17);
+        prune(&mut temp_nodes, &PRUNE_17);
+        run_level(&mut temp_nodes, &LEVEL_18);
+        prune(&mut temp_nodes, &PRUNE_18);
+        run_level(&mut temp_nodes, &LEVEL_19);
+        prune(&mut temp_nodes, &PRUNE_19);
+        run_level(&mut temp_nodes, &LEVEL_20);
+        prune(&mut temp_nodes, &PRUNE_20);
+        run_level(&mut temp_nodes, &LEVEL_21);
+        prune(&mut temp_nodes, &PRUNE_21);
+        run_level(&mut temp_nodes, &LEVEL_22);
+        prune(&mut temp_nodes, &PRUNE_22);
+        run_level(&mut temp_nodes, &LEVEL_23);
+        prune(&mut temp_nodes, &PRUNE_23);
+        run_level(&mut temp_nodes, &LEVEL_24);
+        prune(&mut temp_nodes, &PRUNE_24);
+        run_level(&mut temp_nodes, &LEVEL_25);
+        prune(&mut temp_nodes, &PRUNE_25);
 
     
 
     out.into_iter().map(|c| c.unwrap()).collect()
 }
-
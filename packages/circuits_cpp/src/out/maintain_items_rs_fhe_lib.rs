@@ -0,0 +1,8228 @@
+
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use phantom_zone_evaluator::boolean::{fhew::prelude::*, FheBool};
+
+enum GateInput {
+    Arg(usize, usize), // arg + index
+    Output(usize), // reuse of output wire
+    Tv(usize),  // temp value
+    Cst(bool),  // constant
+}
+
+use GateInput::*;
+
+#[derive(PartialEq, Eq, Hash)]
+enum CellType {
+    AND2,
+    NAND2,
+    XOR2,
+    XNOR2,
+    OR2,
+    NOR2,
+    INV,
+    // TODO: Add back MUX2
+}
+
+use CellType::*;
+
+
+static LEVEL_0: [((usize, bool, CellType), &[GateInput]); 936] = [
+    ((604, false, XNOR2), &[Arg(0, 0), Arg(1, 0)]),
+    ((605, false, XNOR2), &[Arg(0, 1), Arg(1, 1)]),
+    ((606, false, XNOR2), &[Arg(0, 2), Arg(1, 2)]),
+    ((607, false, XNOR2), &[Arg(0, 3), Arg(1, 3)]),
+    ((608, false, XNOR2), &[Arg(0, 4), Arg(1, 4)]),
+    ((609, false, XNOR2), &[Arg(0, 5), Arg(1, 5)]),
+    ((610, false, XNOR2), &[Arg(0, 6), Arg(1, 6)]),
+    ((611, false, XNOR2), &[Arg(0, 7), Arg(1, 7)]),
+    ((627, false, XNOR2), &[Arg(0, 8), Arg(1, 8)]),
+    ((628, false, XNOR2), &[Arg(0, 9), Arg(1, 9)]),
+    ((629, false, XNOR2), &[Arg(0, 10), Arg(1, 10)]),
+    ((630, false, XNOR2), &[Arg(0, 11), Arg(1, 11)]),
+    ((631, false, XNOR2), &[Arg(0, 12), Arg(1, 12)]),
+    ((632, false, XNOR2), &[Arg(0, 13), Arg(1, 13)]),
+    ((633, false, XNOR2), &[Arg(0, 14), Arg(1, 14)]),
+    ((634, false, XNOR2), &[Arg(0, 15), Arg(1, 15)]),
+    ((651, false, XNOR2), &[Arg(0, 0), Arg(1, 16)]),
+    ((652, false, XNOR2), &[Arg(0, 1), Arg(1, 17)]),
+    ((653, false, XNOR2), &[Arg(0, 2), Arg(1, 18)]),
+    ((654, false, XNOR2), &[Arg(0, 3), Arg(1, 19)]),
+    ((655, false, XNOR2), &[Arg(0, 4), Arg(1, 20)]),
+    ((656, false, XNOR2), &[Arg(0, 5), Arg(1, 21)]),
+    ((657, false, XNOR2), &[Arg(0, 6), Arg(1, 22)]),
+    ((658, false, XNOR2), &[Arg(0, 7), Arg(1, 23)]),
+    ((674, false, XNOR2), &[Arg(0, 8), Arg(1, 24)]),
+    ((675, false, XNOR2), &[Arg(0, 9), Arg(1, 25)]),
+    ((676, false, XNOR2), &[Arg(0, 10), Arg(1, 26)]),
+    ((677, false, XNOR2), &[Arg(0, 11), Arg(1, 27)]),
+    ((678, false, XNOR2), &[Arg(0, 12), Arg(1, 28)]),
+    ((679, false, XNOR2), &[Arg(0, 13), Arg(1, 29)]),
+    ((680, false, XNOR2), &[Arg(0, 14), Arg(1, 30)]),
+    ((681, false, XNOR2), &[Arg(0, 15), Arg(1, 31)]),
+    ((698, false, XNOR2), &[Arg(0, 0), Arg(1, 32)]),
+    ((699, false, XNOR2), &[Arg(0, 1), Arg(1, 33)]),
+    ((700, false, XNOR2), &[Arg(0, 2), Arg(1, 34)]),
+    ((701, false, XNOR2), &[Arg(0, 3), Arg(1, 35)]),
+    ((702, false, XNOR2), &[Arg(0, 4), Arg(1, 36)]),
+    ((703, false, XNOR2), &[Arg(0, 5), Arg(1, 37)]),
+    ((704, false, XNOR2), &[Arg(0, 6), Arg(1, 38)]),
+    ((705, false, XNOR2), &[Arg(0, 7), Arg(1, 39)]),
+    ((721, false, XNOR2), &[Arg(0, 8), Arg(1, 40)]),
+    ((722, false, XNOR2), &[Arg(0, 9), Arg(1, 41)]),
+    ((723, false, XNOR2), &[Arg(0, 10), Arg(1, 42)]),
+    ((724, false, XNOR2), &[Arg(0, 11), Arg(1, 43)]),
+    ((725, false, XNOR2), &[Arg(0, 12), Arg(1, 44)]),
+    ((726, false, XNOR2), &[Arg(0, 13), Arg(1, 45)]),
+    ((727, false, XNOR2), &[Arg(0, 14), Arg(1, 46)]),
+    ((728, false, XNOR2), &[Arg(0, 15), Arg(1, 47)]),
+    ((745, false, XNOR2), &[Arg(0, 0), Arg(1, 48)]),
+    ((746, false, XNOR2), &[Arg(0, 1), Arg(1, 49)]),
+    ((747, false, XNOR2), &[Arg(0, 2), Arg(1, 50)]),
+    ((748, false, XNOR2), &[Arg(0, 3), Arg(1, 51)]),
+    ((749, false, XNOR2), &[Arg(0, 4), Arg(1, 52)]),
+    ((750, false, XNOR2), &[Arg(0, 5), Arg(1, 53)]),
+    ((751, false, XNOR2), &[Arg(0, 6), Arg(1, 54)]),
+    ((752, false, XNOR2), &[Arg(0, 7), Arg(1, 55)]),
+    ((768, false, XNOR2), &[Arg(0, 8), Arg(1, 56)]),
+    ((769, false, XNOR2), &[Arg(0, 9), Arg(1, 57)]),
+    ((770, false, XNOR2), &[Arg(0, 10), Arg(1, 58)]),
+    ((771, false, XNOR2), &[Arg(0, 11), Arg(1, 59)]),
+    ((772, false, XNOR2), &[Arg(0, 12), Arg(1, 60)]),
+    ((773, false, XNOR2), &[Arg(0, 13), Arg(1, 61)]),
+    ((774, false, XNOR2), &[Arg(0, 14), Arg(1, 62)]),
+    ((775, false, XNOR2), &[Arg(0, 15), Arg(1, 63)]),
+    ((799, false, NOR2), &[Arg(0, 41), Arg(0, 42)]),
+    ((800, false, NOR2), &[Arg(0, 43), Arg(0, 44)]),
+    ((801, false, NOR2), &[Arg(0, 45), Arg(0, 46)]),
+    ((802, false, NOR2), &[Arg(0, 47), Arg(0, 48)]),
+    ((806, false, INV), &[Arg(0, 42)]),
+    ((810, false, INV), &[Arg(0, 43)]),
+    ((814, false, NOR2), &[Arg(0, 42), Arg(0, 43)]),
+    ((815, false, INV), &[Arg(0, 44)]),
+    ((819, false, INV), &[Arg(0, 45)]),
+    ((823, false, NOR2), &[Arg(0, 44), Arg(0, 45)]),
+    ((824, false, INV), &[Arg(0, 46)]),
+    ((828, false, INV), &[Arg(0, 47)]),
+    ((832, false, NOR2), &[Arg(0, 46), Arg(0, 47)]),
+    ((857, false, XOR2), &[Arg(0, 41), Arg(0, 42)]),
+    ((913, false, XNOR2), &[Arg(0, 49), Arg(1, 0)]),
+    ((914, false, XNOR2), &[Arg(0, 50), Arg(1, 1)]),
+    ((915, false, XNOR2), &[Arg(0, 51), Arg(1, 2)]),
+    ((916, false, XNOR2), &[Arg(0, 52), Arg(1, 3)]),
+    ((917, false, XNOR2), &[Arg(0, 53), Arg(1, 4)]),
+    ((918, false, XNOR2), &[Arg(0, 54), Arg(1, 5)]),
+    ((919, false, XNOR2), &[Arg(0, 55), Arg(1, 6)]),
+    ((920, false, XNOR2), &[Arg(0, 56), Arg(1, 7)]),
+    ((936, false, XNOR2), &[Arg(0, 57), Arg(1, 8)]),
+    ((937, false, XNOR2), &[Arg(0, 58), Arg(1, 9)]),
+    ((938, false, XNOR2), &[Arg(0, 59), Arg(1, 10)]),
+    ((939, false, XNOR2), &[Arg(0, 60), Arg(1, 11)]),
+    ((940, false, XNOR2), &[Arg(0, 61), Arg(1, 12)]),
+    ((941, false, XNOR2), &[Arg(0, 62), Arg(1, 13)]),
+    ((942, false, XNOR2), &[Arg(0, 63), Arg(1, 14)]),
+    ((943, false, XNOR2), &[Arg(0, 64), Arg(1, 15)]),
+    ((960, false, XNOR2), &[Arg(0, 49), Arg(1, 16)]),
+    ((961, false, XNOR2), &[Arg(0, 50), Arg(1, 17)]),
+    ((962, false, XNOR2), &[Arg(0, 51), Arg(1, 18)]),
+    ((963, false, XNOR2), &[Arg(0, 52), Arg(1, 19)]),
+    ((964, false, XNOR2), &[Arg(0, 53), Arg(1, 20)]),
+    ((965, false, XNOR2), &[Arg(0, 54), Arg(1, 21)]),
+    ((966, false, XNOR2), &[Arg(0, 55), Arg(1, 22)]),
+    ((967, false, XNOR2), &[Arg(0, 56), Arg(1, 23)]),
+    ((983, false, XNOR2), &[Arg(0, 57), Arg(1, 24)]),
+    ((984, false, XNOR2), &[Arg(0, 58), Arg(1, 25)]),
+    ((985, false, XNOR2), &[Arg(0, 59), Arg(1, 26)]),
+    ((986, false, XNOR2), &[Arg(0, 60), Arg(1, 27)]),
+    ((987, false, XNOR2), &[Arg(0, 61), Arg(1, 28)]),
+    ((988, false, XNOR2), &[Arg(0, 62), Arg(1, 29)]),
+    ((989, false, XNOR2), &[Arg(0, 63), Arg(1, 30)]),
+    ((990, false, XNOR2), &[Arg(0, 64), Arg(1, 31)]),
+    ((1007, false, XNOR2), &[Arg(0, 49), Arg(1, 32)]),
+    ((1008, false, XNOR2), &[Arg(0, 50), Arg(1, 33)]),
+    ((1009, false, XNOR2), &[Arg(0, 51), Arg(1, 34)]),
+    ((1010, false, XNOR2), &[Arg(0, 52), Arg(1, 35)]),
+    ((1011, false, XNOR2), &[Arg(0, 53), Arg(1, 36)]),
+    ((1012, false, XNOR2), &[Arg(0, 54), Arg(1, 37)]),
+    ((1013, false, XNOR2), &[Arg(0, 55), Arg(1, 38)]),
+    ((1014, false, XNOR2), &[Arg(0, 56), Arg(1, 39)]),
+    ((1030, false, XNOR2), &[Arg(0, 57), Arg(1, 40)]),
+    ((1031, false, XNOR2), &[Arg(0, 58), Arg(1, 41)]),
+    ((1032, false, XNOR2), &[Arg(0, 59), Arg(1, 42)]),
+    ((1033, false, XNOR2), &[Arg(0, 60), Arg(1, 43)]),
+    ((1034, false, XNOR2), &[Arg(0, 61), Arg(1, 44)]),
+    ((1035, false, XNOR2), &[Arg(0, 62), Arg(1, 45)]),
+    ((1036, false, XNOR2), &[Arg(0, 63), Arg(1, 46)]),
+    ((1037, false, XNOR2), &[Arg(0, 64), Arg(1, 47)]),
+    ((1054, false, XNOR2), &[Arg(0, 49), Arg(1, 48)]),
+    ((1055, false, XNOR2), &[Arg(0, 50), Arg(1, 49)]),
+    ((1056, false, XNOR2), &[Arg(0, 51), Arg(1, 50)]),
+    ((1057, false, XNOR2), &[Arg(0, 52), Arg(1, 51)]),
+    ((1058, false, XNOR2), &[Arg(0, 53), Arg(1, 52)]),
+    ((1059, false, XNOR2), &[Arg(0, 54), Arg(1, 53)]),
+    ((1060, false, XNOR2), &[Arg(0, 55), Arg(1, 54)]),
+    ((1061, false, XNOR2), &[Arg(0, 56), Arg(1, 55)]),
+    ((1077, false, XNOR2), &[Arg(0, 57), Arg(1, 56)]),
+    ((1078, false, XNOR2), &[Arg(0, 58), Arg(1, 57)]),
+    ((1079, false, XNOR2), &[Arg(0, 59), Arg(1, 58)]),
+    ((1080, false, XNOR2), &[Arg(0, 60), Arg(1, 59)]),
+    ((1081, false, XNOR2), &[Arg(0, 61), Arg(1, 60)]),
+    ((1082, false, XNOR2), &[Arg(0, 62), Arg(1, 61)]),
+    ((1083, false, XNOR2), &[Arg(0, 63), Arg(1, 62)]),
+    ((1084, false, XNOR2), &[Arg(0, 64), Arg(1, 63)]),
+    ((1108, false, NOR2), &[Arg(0, 90), Arg(0, 91)]),
+    ((1109, false, NOR2), &[Arg(0, 92), Arg(0, 93)]),
+    ((1110, false, NOR2), &[Arg(0, 94), Arg(0, 95)]),
+    ((1111, false, NOR2), &[Arg(0, 96), Arg(0, 97)]),
+    ((1115, false, INV), &[Arg(0, 91)]),
+    ((1119, false, INV), &[Arg(0, 92)]),
+    ((1123, false, NOR2), &[Arg(0, 91), Arg(0, 92)]),
+    ((1124, false, INV), &[Arg(0, 93)]),
+    ((1128, false, INV), &[Arg(0, 94)]),
+    ((1132, false, NOR2), &[Arg(0, 93), Arg(0, 94)]),
+    ((1133, false, INV), &[Arg(0, 95)]),
+    ((1137, false, INV), &[Arg(0, 96)]),
+    ((1141, false, NOR2), &[Arg(0, 95), Arg(0, 96)]),
+    ((1166, false, XOR2), &[Arg(0, 90), Arg(0, 91)]),
+    ((1222, false, XNOR2), &[Arg(0, 98), Arg(1, 0)]),
+    ((1223, false, XNOR2), &[Arg(0, 99), Arg(1, 1)]),
+    ((1224, false, XNOR2), &[Arg(0, 100), Arg(1, 2)]),
+    ((1225, false, XNOR2), &[Arg(0, 101), Arg(1, 3)]),
+    ((1226, false, XNOR2), &[Arg(0, 102), Arg(1, 4)]),
+    ((1227, false, XNOR2), &[Arg(0, 103), Arg(1, 5)]),
+    ((1228, false, XNOR2), &[Arg(0, 104), Arg(1, 6)]),
+    ((1229, false, XNOR2), &[Arg(0, 105), Arg(1, 7)]),
+    ((1245, false, XNOR2), &[Arg(0, 106), Arg(1, 8)]),
+    ((1246, false, XNOR2), &[Arg(0, 107), Arg(1, 9)]),
+    ((1247, false, XNOR2), &[Arg(0, 108), Arg(1, 10)]),
+    ((1248, false, XNOR2), &[Arg(0, 109), Arg(1, 11)]),
+    ((1249, false, XNOR2), &[Arg(0, 110), Arg(1, 12)]),
+    ((1250, false, XNOR2), &[Arg(0, 111), Arg(1, 13)]),
+    ((1251, false, XNOR2), &[Arg(0, 112), Arg(1, 14)]),
+    ((1252, false, XNOR2), &[Arg(0, 113), Arg(1, 15)]),
+    ((1269, false, XNOR2), &[Arg(0, 98), Arg(1, 16)]),
+    ((1270, false, XNOR2), &[Arg(0, 99), Arg(1, 17)]),
+    ((1271, false, XNOR2), &[Arg(0, 100), Arg(1, 18)]),
+    ((1272, false, XNOR2), &[Arg(0, 101), Arg(1, 19)]),
+    ((1273, false, XNOR2), &[Arg(0, 102), Arg(1, 20)]),
+    ((1274, false, XNOR2), &[Arg(0, 103), Arg(1, 21)]),
+    ((1275, false, XNOR2), &[Arg(0, 104), Arg(1, 22)]),
+    ((1276, false, XNOR2), &[Arg(0, 105), Arg(1, 23)]),
+    ((1292, false, XNOR2), &[Arg(0, 106), Arg(1, 24)]),
+    ((1293, false, XNOR2), &[Arg(0, 107), Arg(1, 25)]),
+    ((1294, false, XNOR2), &[Arg(0, 108), Arg(1, 26)]),
+    ((1295, false, XNOR2), &[Arg(0, 109), Arg(1, 27)]),
+    ((1296, false, XNOR2), &[Arg(0, 110), Arg(1, 28)]),
+    ((1297, false, XNOR2), &[Arg(0, 111), Arg(1, 29)]),
+    ((1298, false, XNOR2), &[Arg(0, 112), Arg(1, 30)]),
+    ((1299, false, XNOR2), &[Arg(0, 113), Arg(1, 31)]),
+    ((1316, false, XNOR2), &[Arg(0, 98), Arg(1, 32)]),
+    ((1317, false, XNOR2), &[Arg(0, 99), Arg(1, 33)]),
+    ((1318, false, XNOR2), &[Arg(0, 100), Arg(1, 34)]),
+    ((1319, false, XNOR2), &[Arg(0, 101), Arg(1, 35)]),
+    ((1320, false, XNOR2), &[Arg(0, 102), Arg(1, 36)]),
+    ((1321, false, XNOR2), &[Arg(0, 103), Arg(1, 37)]),
+    ((1322, false, XNOR2), &[Arg(0, 104), Arg(1, 38)]),
+    ((1323, false, XNOR2), &[Arg(0, 105), Arg(1, 39)]),
+    ((1339, false, XNOR2), &[Arg(0, 106), Arg(1, 40)]),
+    ((1340, false, XNOR2), &[Arg(0, 107), Arg(1, 41)]),
+    ((1341, false, XNOR2), &[Arg(0, 108), Arg(1, 42)]),
+    ((1342, false, XNOR2), &[Arg(0, 109), Arg(1, 43)]),
+    ((1343, false, XNOR2), &[Arg(0, 110), Arg(1, 44)]),
+    ((1344, false, XNOR2), &[Arg(0, 111), Arg(1, 45)]),
+    ((1345, false, XNOR2), &[Arg(0, 112), Arg(1, 46)]),
+    ((1346, false, XNOR2), &[Arg(0, 113), Arg(1, 47)]),
+    ((1363, false, XNOR2), &[Arg(0, 98), Arg(1, 48)]),
+    ((1364, false, XNOR2), &[Arg(0, 99), Arg(1, 49)]),
+    ((1365, false, XNOR2), &[Arg(0, 100), Arg(1, 50)]),
+    ((1366, false, XNOR2), &[Arg(0, 101), Arg(1, 51)]),
+    ((1367, false, XNOR2), &[Arg(0, 102), Arg(1, 52)]),
+    ((1368, false, XNOR2), &[Arg(0, 103), Arg(1, 53)]),
+    ((1369, false, XNOR2), &[Arg(0, 104), Arg(1, 54)]),
+    ((1370, false, XNOR2), &[Arg(0, 105), Arg(1, 55)]),
+    ((1386, false, XNOR2), &[Arg(0, 106), Arg(1, 56)]),
+    ((1387, false, XNOR2), &[Arg(0, 107), Arg(1, 57)]),
+    ((1388, false, XNOR2), &[Arg(0, 108), Arg(1, 58)]),
+    ((1389, false, XNOR2), &[Arg(0, 109), Arg(1, 59)]),
+    ((1390, false, XNOR2), &[Arg(0, 110), Arg(1, 60)]),
+    ((1391, false, XNOR2), &[Arg(0, 111), Arg(1, 61)]),
+    ((1392, false, XNOR2), &[Arg(0, 112), Arg(1, 62)]),
+    ((1393, false, XNOR2), &[Arg(0, 113), Arg(1, 63)]),
+    ((1417, false, NOR2), &[Arg(0, 139), Arg(0, 140)]),
+    ((1418, false, NOR2), &[Arg(0, 141), Arg(0, 142)]),
+    ((1419, false, NOR2), &[Arg(0, 143), Arg(0, 144)]),
+    ((1420, false, NOR2), &[Arg(0, 145), Arg(0, 146)]),
+    ((1424, false, INV), &[Arg(0, 140)]),
+    ((1428, false, INV), &[Arg(0, 141)]),
+    ((1432, false, NOR2), &[Arg(0, 140), Arg(0, 141)]),
+    ((1433, false, INV), &[Arg(0, 142)]),
+    ((1437, false, INV), &[Arg(0, 143)]),
+    ((1441, false, NOR2), &[Arg(0, 142), Arg(0, 143)]),
+    ((1442, false, INV), &[Arg(0, 144)]),
+    ((1446, false, INV), &[Arg(0, 145)]),
+    ((1450, false, NOR2), &[Arg(0, 144), Arg(0, 145)]),
+    ((1475, false, XOR2), &[Arg(0, 139), Arg(0, 140)]),
+    ((1531, false, XNOR2), &[Arg(0, 147), Arg(1, 0)]),
+    ((1532, false, XNOR2), &[Arg(0, 148), Arg(1, 1)]),
+    ((1533, false, XNOR2), &[Arg(0, 149), Arg(1, 2)]),
+    ((1534, false, XNOR2), &[Arg(0, 150), Arg(1, 3)]),
+    ((1535, false, XNOR2), &[Arg(0, 151), Arg(1, 4)]),
+    ((1536, false, XNOR2), &[Arg(0, 152), Arg(1, 5)]),
+    ((1537, false, XNOR2), &[Arg(0, 153), Arg(1, 6)]),
+    ((1538, false, XNOR2), &[Arg(0, 154), Arg(1, 7)]),
+    ((1554, false, XNOR2), &[Arg(0, 155), Arg(1, 8)]),
+    ((1555, false, XNOR2), &[Arg(0, 156), Arg(1, 9)]),
+    ((1556, false, XNOR2), &[Arg(0, 157), Arg(1, 10)]),
+    ((1557, false, XNOR2), &[Arg(0, 158), Arg(1, 11)]),
+    ((1558, false, XNOR2), &[Arg(0, 159), Arg(1, 12)]),
+    ((1559, false, XNOR2), &[Arg(0, 160), Arg(1, 13)]),
+    ((1560, false, XNOR2), &[Arg(0, 161), Arg(1, 14)]),
+    ((1561, false, XNOR2), &[Arg(0, 162), Arg(1, 15)]),
+    ((1578, false, XNOR2), &[Arg(0, 147), Arg(1, 16)]),
+    ((1579, false, XNOR2), &[Arg(0, 148), Arg(1, 17)]),
+    ((1580, false, XNOR2), &[Arg(0, 149), Arg(1, 18)]),
+    ((1581, false, XNOR2), &[Arg(0, 150), Arg(1, 19)]),
+    ((1582, false, XNOR2), &[Arg(0, 151), Arg(1, 20)]),
+    ((1583, false, XNOR2), &[Arg(0, 152), Arg(1, 21)]),
+    ((1584, false, XNOR2), &[Arg(0, 153), Arg(1, 22)]),
+    ((1585, false, XNOR2), &[Arg(0, 154), Arg(1, 23)]),
+    ((1601, false, XNOR2), &[Arg(0, 155), Arg(1, 24)]),
+    ((1602, false, XNOR2), &[Arg(0, 156), Arg(1, 25)]),
+    ((1603, false, XNOR2), &[Arg(0, 157), Arg(1, 26)]),
+    ((1604, false, XNOR2), &[Arg(0, 158), Arg(1, 27)]),
+    ((1605, false, XNOR2), &[Arg(0, 159), Arg(1, 28)]),
+    ((1606, false, XNOR2), &[Arg(0, 160), Arg(1, 29)]),
+    ((1607, false, XNOR2), &[Arg(0, 161), Arg(1, 30)]),
+    ((1608, false, XNOR2), &[Arg(0, 162), Arg(1, 31)]),
+    ((1625, false, XNOR2), &[Arg(0, 147), Arg(1, 32)]),
+    ((1626, false, XNOR2), &[Arg(0, 148), Arg(1, 33)]),
+    ((1627, false, XNOR2), &[Arg(0, 149), Arg(1, 34)]),
+    ((1628, false, XNOR2), &[Arg(0, 150), Arg(1, 35)]),
+    ((1629, false, XNOR2), &[Arg(0, 151), Arg(1, 36)]),
+    ((1630, false, XNOR2), &[Arg(0, 152), Arg(1, 37)]),
+    ((1631, false, XNOR2), &[Arg(0, 153), Arg(1, 38)]),
+    ((1632, false, XNOR2), &[Arg(0, 154), Arg(1, 39)]),
+    ((1648, false, XNOR2), &[Arg(0, 155), Arg(1, 40)]),
+    ((1649, false, XNOR2), &[Arg(0, 156), Arg(1, 41)]),
+    ((1650, false, XNOR2), &[Arg(0, 157), Arg(1, 42)]),
+    ((1651, false, XNOR2), &[Arg(0, 158), Arg(1, 43)]),
+    ((1652, false, XNOR2), &[Arg(0, 159), Arg(1, 44)]),
+    ((1653, false, XNOR2), &[Arg(0, 160), Arg(1, 45)]),
+    ((1654, false, XNOR2), &[Arg(0, 161), Arg(1, 46)]),
+    ((1655, false, XNOR2), &[Arg(0, 162), Arg(1, 47)]),
+    ((1672, false, XNOR2), &[Arg(0, 147), Arg(1, 48)]),
+    ((1673, false, XNOR2), &[Arg(0, 148), Arg(1, 49)]),
+    ((1674, false, XNOR2), &[Arg(0, 149), Arg(1, 50)]),
+    ((1675, false, XNOR2), &[Arg(0, 150), Arg(1, 51)]),
+    ((1676, false, XNOR2), &[Arg(0, 151), Arg(1, 52)]),
+    ((1677, false, XNOR2), &[Arg(0, 152), Arg(1, 53)]),
+    ((1678, false, XNOR2), &[Arg(0, 153), Arg(1, 54)]),
+    ((1679, false, XNOR2), &[Arg(0, 154), Arg(1, 55)]),
+    ((1695, false, XNOR2), &[Arg(0, 155), Arg(1, 56)]),
+    ((1696, false, XNOR2), &[Arg(0, 156), Arg(1, 57)]),
+    ((1697, false, XNOR2), &[Arg(0, 157), Arg(1, 58)]),
+    ((1698, false, XNOR2), &[Arg(0, 158), Arg(1, 59)]),
+    ((1699, false, XNOR2), &[Arg(0, 159), Arg(1, 60)]),
+    ((1700, false, XNOR2), &[Arg(0, 160), Arg(1, 61)]),
+    ((1701, false, XNOR2), &[Arg(0, 161), Arg(1, 62)]),
+    ((1702, false, XNOR2), &[Arg(0, 162), Arg(1, 63)]),
+    ((1726, false, NOR2), &[Arg(0, 188), Arg(0, 189)]),
+    ((1727, false, NOR2), &[Arg(0, 190), Arg(0, 191)]),
+    ((1728, false, NOR2), &[Arg(0, 192), Arg(0, 193)]),
+    ((1729, false, NOR2), &[Arg(0, 194), Arg(0, 195)]),
+    ((1733, false, INV), &[Arg(0, 189)]),
+    ((1737, false, INV), &[Arg(0, 190)]),
+    ((1741, false, NOR2), &[Arg(0, 189), Arg(0, 190)]),
+    ((1742, false, INV), &[Arg(0, 191)]),
+    ((1746, false, INV), &[Arg(0, 192)]),
+    ((1750, false, NOR2), &[Arg(0, 191), Arg(0, 192)]),
+    ((1751, false, INV), &[Arg(0, 193)]),
+    ((1755, false, INV), &[Arg(0, 194)]),
+    ((1759, false, NOR2), &[Arg(0, 193), Arg(0, 194)]),
+    ((1784, false, XOR2), &[Arg(0, 188), Arg(0, 189)]),
+    ((1840, false, XNOR2), &[Arg(0, 196), Arg(1, 0)]),
+    ((1841, false, XNOR2), &[Arg(0, 197), Arg(1, 1)]),
+    ((1842, false, XNOR2), &[Arg(0, 198), Arg(1, 2)]),
+    ((1843, false, XNOR2), &[Arg(0, 199), Arg(1, 3)]),
+    ((1844, false, XNOR2), &[Arg(0, 200), Arg(1, 4)]),
+    ((1845, false, XNOR2), &[Arg(0, 201), Arg(1, 5)]),
+    ((1846, false, XNOR2), &[Arg(0, 202), Arg(1, 6)]),
+    ((1847, false, XNOR2), &[Arg(0, 203), Arg(1, 7)]),
+    ((1863, false, XNOR2), &[Arg(0, 204), Arg(1, 8)]),
+    ((1864, false, XNOR2), &[Arg(0, 205), Arg(1, 9)]),
+    ((1865, false, XNOR2), &[Arg(0, 206), Arg(1, 10)]),
+    ((1866, false, XNOR2), &[Arg(0, 207), Arg(1, 11)]),
+    ((1867, false, XNOR2), &[Arg(0, 208), Arg(1, 12)]),
+    ((1868, false, XNOR2), &[Arg(0, 209), Arg(1, 13)]),
+    ((1869, false, XNOR2), &[Arg(0, 210), Arg(1, 14)]),
+    ((1870, false, XNOR2), &[Arg(0, 211), Arg(1, 15)]),
+    ((1887, false, XNOR2), &[Arg(0, 196), Arg(1, 16)]),
+    ((1888, false, XNOR2), &[Arg(0, 197), Arg(1, 17)]),
+    ((1889, false, XNOR2), &[Arg(0, 198), Arg(1, 18)]),
+    ((1890, false, XNOR2), &[Arg(0, 199), Arg(1, 19)]),
+    ((1891, false, XNOR2), &[Arg(0, 200), Arg(1, 20)]),
+    ((1892, false, XNOR2), &[Arg(0, 201), Arg(1, 21)]),
+    ((1893, false, XNOR2), &[Arg(0, 202), Arg(1, 22)]),
+    ((1894, false, XNOR2), &[Arg(0, 203), Arg(1, 23)]),
+    ((1910, false, XNOR2), &[Arg(0, 204), Arg(1, 24)]),
+    ((1911, false, XNOR2), &[Arg(0, 205), Arg(1, 25)]),
+    ((1912, false, XNOR2), &[Arg(0, 206), Arg(1, 26)]),
+    ((1913, false, XNOR2), &[Arg(0, 207), Arg(1, 27)]),
+    ((1914, false, XNOR2), &[Arg(0, 208), Arg(1, 28)]),
+    ((1915, false, XNOR2), &[Arg(0, 209), Arg(1, 29)]),
+    ((1916, false, XNOR2), &[Arg(0, 210), Arg(1, 30)]),
+    ((1917, false, XNOR2), &[Arg(0, 211), Arg(1, 31)]),
+    ((1934, false, XNOR2), &[Arg(0, 196), Arg(1, 32)]),
+    ((1935, false, XNOR2), &[Arg(0, 197), Arg(1, 33)]),
+    ((1936, false, XNOR2), &[Arg(0, 198), Arg(1, 34)]),
+    ((1937, false, XNOR2), &[Arg(0, 199), Arg(1, 35)]),
+    ((1938, false, XNOR2), &[Arg(0, 200), Arg(1, 36)]),
+    ((1939, false, XNOR2), &[Arg(0, 201), Arg(1, 37)]),
+    ((1940, false, XNOR2), &[Arg(0, 202), Arg(1, 38)]),
+    ((1941, false, XNOR2), &[Arg(0, 203), Arg(1, 39)]),
+    ((1957, false, XNOR2), &[Arg(0, 204), Arg(1, 40)]),
+    ((1958, false, XNOR2), &[Arg(0, 205), Arg(1, 41)]),
+    ((1959, false, XNOR2), &[Arg(0, 206), Arg(1, 42)]),
+    ((1960, false, XNOR2), &[Arg(0, 207), Arg(1, 43)]),
+    ((1961, false, XNOR2), &[Arg(0, 208), Arg(1, 44)]),
+    ((1962, false, XNOR2), &[Arg(0, 209), Arg(1, 45)]),
+    ((1963, false, XNOR2), &[Arg(0, 210), Arg(1, 46)]),
+    ((1964, false, XNOR2), &[Arg(0, 211), Arg(1, 47)]),
+    ((1981, false, XNOR2), &[Arg(0, 196), Arg(1, 48)]),
+    ((1982, false, XNOR2), &[Arg(0, 197), Arg(1, 49)]),
+    ((1983, false, XNOR2), &[Arg(0, 198), Arg(1, 50)]),
+    ((1984, false, XNOR2), &[Arg(0, 199), Arg(1, 51)]),
+    ((1985, false, XNOR2), &[Arg(0, 200), Arg(1, 52)]),
+    ((1986, false, XNOR2), &[Arg(0, 201), Arg(1, 53)]),
+    ((1987, false, XNOR2), &[Arg(0, 202), Arg(1, 54)]),
+    ((1988, false, XNOR2), &[Arg(0, 203), Arg(1, 55)]),
+    ((2004, false, XNOR2), &[Arg(0, 204), Arg(1, 56)]),
+    ((2005, false, XNOR2), &[Arg(0, 205), Arg(1, 57)]),
+    ((2006, false, XNOR2), &[Arg(0, 206), Arg(1, 58)]),
+    ((2007, false, XNOR2), &[Arg(0, 207), Arg(1, 59)]),
+    ((2008, false, XNOR2), &[Arg(0, 208), Arg(1, 60)]),
+    ((2009, false, XNOR2), &[Arg(0, 209), Arg(1, 61)]),
+    ((2010, false, XNOR2), &[Arg(0, 210), Arg(1, 62)]),
+    ((2011, false, XNOR2), &[Arg(0, 211), Arg(1, 63)]),
+    ((2035, false, NOR2), &[Arg(0, 237), Arg(0, 238)]),
+    ((2036, false, NOR2), &[Arg(0, 239), Arg(0, 240)]),
+    ((2037, false, NOR2), &[Arg(0, 241), Arg(0, 242)]),
+    ((2038, false, NOR2), &[Arg(0, 243), Arg(0, 244)]),
+    ((2042, false, INV), &[Arg(0, 238)]),
+    ((2046, false, INV), &[Arg(0, 239)]),
+    ((2050, false, NOR2), &[Arg(0, 238), Arg(0, 239)]),
+    ((2051, false, INV), &[Arg(0, 240)]),
+    ((2055, false, INV), &[Arg(0, 241)]),
+    ((2059, false, NOR2), &[Arg(0, 240), Arg(0, 241)]),
+    ((2060, false, INV), &[Arg(0, 242)]),
+    ((2064, false, INV), &[Arg(0, 243)]),
+    ((2068, false, NOR2), &[Arg(0, 242), Arg(0, 243)]),
+    ((2093, false, XOR2), &[Arg(0, 237), Arg(0, 238)]),
+    ((2149, false, XNOR2), &[Arg(0, 245), Arg(1, 0)]),
+    ((2150, false, XNOR2), &[Arg(0, 246), Arg(1, 1)]),
+    ((2151, false, XNOR2), &[Arg(0, 247), Arg(1, 2)]),
+    ((2152, false, XNOR2), &[Arg(0, 248), Arg(1, 3)]),
+    ((2153, false, XNOR2), &[Arg(0, 249), Arg(1, 4)]),
+    ((2154, false, XNOR2), &[Arg(0, 250), Arg(1, 5)]),
+    ((2155, false, XNOR2), &[Arg(0, 251), Arg(1, 6)]),
+    ((2156, false, XNOR2), &[Arg(0, 252), Arg(1, 7)]),
+    ((2172, false, XNOR2), &[Arg(0, 253), Arg(1, 8)]),
+    ((2173, false, XNOR2), &[Arg(0, 254), Arg(1, 9)]),
+    ((2174, false, XNOR2), &[Arg(0, 255), Arg(1, 10)]),
+    ((2175, false, XNOR2), &[Arg(0, 256), Arg(1, 11)]),
+    ((2176, false, XNOR2), &[Arg(0, 257), Arg(1, 12)]),
+    ((2177, false, XNOR2), &[Arg(0, 258), Arg(1, 13)]),
+    ((2178, false, XNOR2), &[Arg(0, 259), Arg(1, 14)]),
+    ((2179, false, XNOR2), &[Arg(0, 260), Arg(1, 15)]),
+    ((2196, false, XNOR2), &[Arg(0, 245), Arg(1, 16)]),
+    ((2197, false, XNOR2), &[Arg(0, 246), Arg(1, 17)]),
+    ((2198, false, XNOR2), &[Arg(0, 247), Arg(1, 18)]),
+    ((2199, false, XNOR2), &[Arg(0, 248), Arg(1, 19)]),
+    ((2200, false, XNOR2), &[Arg(0, 249), Arg(1, 20)]),
+    ((2201, false, XNOR2), &[Arg(0, 250), Arg(1, 21)]),
+    ((2202, false, XNOR2), &[Arg(0, 251), Arg(1, 22)]),
+    ((2203, false, XNOR2), &[Arg(0, 252), Arg(1, 23)]),
+    ((2219, false, XNOR2), &[Arg(0, 253), Arg(1, 24)]),
+    ((2220, false, XNOR2), &[Arg(0, 254), Arg(1, 25)]),
+    ((2221, false, XNOR2), &[Arg(0, 255), Arg(1, 26)]),
+    ((2222, false, XNOR2), &[Arg(0, 256), Arg(1, 27)]),
+    ((2223, false, XNOR2), &[Arg(0, 257), Arg(1, 28)]),
+    ((2224, false, XNOR2), &[Arg(0, 258), Arg(1, 29)]),
+    ((2225, false, XNOR2), &[Arg(0, 259), Arg(1, 30)]),
+    ((2226, false, XNOR2), &[Arg(0, 260), Arg(1, 31)]),
+    ((2243, false, XNOR2), &[Arg(0, 245), Arg(1, 32)]),
+    ((2244, false, XNOR2), &[Arg(0, 246), Arg(1, 33)]),
+    ((2245, false, XNOR2), &[Arg(0, 247), Arg(1, 34)]),
+    ((2246, false, XNOR2), &[Arg(0, 248), Arg(1, 35)]),
+    ((2247, false, XNOR2), &[Arg(0, 249), Arg(1, 36)]),
+    ((2248, false, XNOR2), &[Arg(0, 250), Arg(1, 37)]),
+    ((2249, false, XNOR2), &[Arg(0, 251), Arg(1, 38)]),
+    ((2250, false, XNOR2), &[Arg(0, 252), Arg(1, 39)]),
+    ((2266, false, XNOR2), &[Arg(0, 253), Arg(1, 40)]),
+    ((2267, false, XNOR2), &[Arg(0, 254), Arg(1, 41)]),
+    ((2268, false, XNOR2), &[Arg(0, 255), Arg(1, 42)]),
+    ((2269, false, XNOR2), &[Arg(0, 256), Arg(1, 43)]),
+    ((2270, false, XNOR2), &[Arg(0, 257), Arg(1, 44)]),
+    ((2271, false, XNOR2), &[Arg(0, 258), Arg(1, 45)]),
+    ((2272, false, XNOR2), &[Arg(0, 259), Arg(1, 46)]),
+    ((2273, false, XNOR2), &[Arg(0, 260), Arg(1, 47)]),
+    ((2290, false, XNOR2), &[Arg(0, 245), Arg(1, 48)]),
+    ((2291, false, XNOR2), &[Arg(0, 246), Arg(1, 49)]),
+    ((2292, false, XNOR2), &[Arg(0, 247), Arg(1, 50)]),
+    ((2293, false, XNOR2), &[Arg(0, 248), Arg(1, 51)]),
+    ((2294, false, XNOR2), &[Arg(0, 249), Arg(1, 52)]),
+    ((2295, false, XNOR2), &[Arg(0, 250), Arg(1, 53)]),
+    ((2296, false, XNOR2), &[Arg(0, 251), Arg(1, 54)]),
+    ((2297, false, XNOR2), &[Arg(0, 252), Arg(1, 55)]),
+    ((2313, false, XNOR2), &[Arg(0, 253), Arg(1, 56)]),
+    ((2314, false, XNOR2), &[Arg(0, 254), Arg(1, 57)]),
+    ((2315, false, XNOR2), &[Arg(0, 255), Arg(1, 58)]),
+    ((2316, false, XNOR2), &[Arg(0, 256), Arg(1, 59)]),
+    ((2317, false, XNOR2), &[Arg(0, 257), Arg(1, 60)]),
+    ((2318, false, XNOR2), &[Arg(0, 258), Arg(1, 61)]),
+    ((2319, false, XNOR2), &[Arg(0, 259), Arg(1, 62)]),
+    ((2320, false, XNOR2), &[Arg(0, 260), Arg(1, 63)]),
+    ((2344, false, NOR2), &[Arg(0, 286), Arg(0, 287)]),
+    ((2345, false, NOR2), &[Arg(0, 288), Arg(0, 289)]),
+    ((2346, false, NOR2), &[Arg(0, 290), Arg(0, 291)]),
+    ((2347, false, NOR2), &[Arg(0, 292), Arg(0, 293)]),
+    ((2351, false, INV), &[Arg(0, 287)]),
+    ((2355, false, INV), &[Arg(0, 288)]),
+    ((2359, false, NOR2), &[Arg(0, 287), Arg(0, 288)]),
+    ((2360, false, INV), &[Arg(0, 289)]),
+    ((2364, false, INV), &[Arg(0, 290)]),
+    ((2368, false, NOR2), &[Arg(0, 289), Arg(0, 290)]),
+    ((2369, false, INV), &[Arg(0, 291)]),
+    ((2373, false, INV), &[Arg(0, 292)]),
+    ((2377, false, NOR2), &[Arg(0, 291), Arg(0, 292)]),
+    ((2402, false, XOR2), &[Arg(0, 286), Arg(0, 287)]),
+    ((2458, false, XNOR2), &[Arg(0, 294), Arg(1, 0)]),
+    ((2459, false, XNOR2), &[Arg(0, 295), Arg(1, 1)]),
+    ((2460, false, XNOR2), &[Arg(0, 296), Arg(1, 2)]),
+    ((2461, false, XNOR2), &[Arg(0, 297), Arg(1, 3)]),
+    ((2462, false, XNOR2), &[Arg(0, 298), Arg(1, 4)]),
+    ((2463, false, XNOR2), &[Arg(0, 299), Arg(1, 5)]),
+    ((2464, false, XNOR2), &[Arg(0, 300), Arg(1, 6)]),
+    ((2465, false, XNOR2), &[Arg(0, 301), Arg(1, 7)]),
+    ((2481, false, XNOR2), &[Arg(0, 302), Arg(1, 8)]),
+    ((2482, false, XNOR2), &[Arg(0, 303), Arg(1, 9)]),
+    ((2483, false, XNOR2), &[Arg(0, 304), Arg(1, 10)]),
+    ((2484, false, XNOR2), &[Arg(0, 305), Arg(1, 11)]),
+    ((2485, false, XNOR2), &[Arg(0, 306), Arg(1, 12)]),
+    ((2486, false, XNOR2), &[Arg(0, 307), Arg(1, 13)]),
+    ((2487, false, XNOR2), &[Arg(0, 308), Arg(1, 14)]),
+    ((2488, false, XNOR2), &[Arg(0, 309), Arg(1, 15)]),
+    ((2505, false, XNOR2), &[Arg(0, 294), Arg(1, 16)]),
+    ((2506, false, XNOR2), &[Arg(0, 295), Arg(1, 17)]),
+    ((2507, false, XNOR2), &[Arg(0, 296), Arg(1, 18)]),
+    ((2508, false, XNOR2), &[Arg(0, 297), Arg(1, 19)]),
+    ((2509, false, XNOR2), &[Arg(0, 298), Arg(1, 20)]),
+    ((2510, false, XNOR2), &[Arg(0, 299), Arg(1, 21)]),
+    ((2511, false, XNOR2), &[Arg(0, 300), Arg(1, 22)]),
+    ((2512, false, XNOR2), &[Arg(0, 301), Arg(1, 23)]),
+    ((2528, false, XNOR2), &[Arg(0, 302), Arg(1, 24)]),
+    ((2529, false, XNOR2), &[Arg(0, 303), Arg(1, 25)]),
+    ((2530, false, XNOR2), &[Arg(0, 304), Arg(1, 26)]),
+    ((2531, false, XNOR2), &[Arg(0, 305), Arg(1, 27)]),
+    ((2532, false, XNOR2), &[Arg(0, 306), Arg(1, 28)]),
+    ((2533, false, XNOR2), &[Arg(0, 307), Arg(1, 29)]),
+    ((2534, false, XNOR2), &[Arg(0, 308), Arg(1, 30)]),
+    ((2535, false, XNOR2), &[Arg(0, 309), Arg(1, 31)]),
+    ((2552, false, XNOR2), &[Arg(0, 294), Arg(1, 32)]),
+    ((2553, false, XNOR2), &[Arg(0, 295), Arg(1, 33)]),
+    ((2554, false, XNOR2), &[Arg(0, 296), Arg(1, 34)]),
+    ((2555, false, XNOR2), &[Arg(0, 297), Arg(1, 35)]),
+    ((2556, false, XNOR2), &[Arg(0, 298), Arg(1, 36)]),
+    ((2557, false, XNOR2), &[Arg(0, 299), Arg(1, 37)]),
+    ((2558, false, XNOR2), &[Arg(0, 300), Arg(1, 38)]),
+    ((2559, false, XNOR2), &[Arg(0, 301), Arg(1, 39)]),
+    ((2575, false, XNOR2), &[Arg(0, 302), Arg(1, 40)]),
+    ((2576, false, XNOR2), &[Arg(0, 303), Arg(1, 41)]),
+    ((2577, false, XNOR2), &[Arg(0, 304), Arg(1, 42)]),
+    ((2578, false, XNOR2), &[Arg(0, 305), Arg(1, 43)]),
+    ((2579, false, XNOR2), &[Arg(0, 306), Arg(1, 44)]),
+    ((2580, false, XNOR2), &[Arg(0, 307), Arg(1, 45)]),
+    ((2581, false, XNOR2), &[Arg(0, 308), Arg(1, 46)]),
+    ((2582, false, XNOR2), &[Arg(0, 309), Arg(1, 47)]),
+    ((2599, false, XNOR2), &[Arg(0, 294), Arg(1, 48)]),
+    ((2600, false, XNOR2), &[Arg(0, 295), Arg(1, 49)]),
+    ((2601, false, XNOR2), &[Arg(0, 296), Arg(1, 50)]),
+    ((2602, false, XNOR2), &[Arg(0, 297), Arg(1, 51)]),
+    ((2603, false, XNOR2), &[Arg(0, 298), Arg(1, 52)]),
+    ((2604, false, XNOR2), &[Arg(0, 299), Arg(1, 53)]),
+    ((2605, false, XNOR2), &[Arg(0, 300), Arg(1, 54)]),
+    ((2606, false, XNOR2), &[Arg(0, 301), Arg(1, 55)]),
+    ((2622, false, XNOR2), &[Arg(0, 302), Arg(1, 56)]),
+    ((2623, false, XNOR2), &[Arg(0, 303), Arg(1, 57)]),
+    ((2624, false, XNOR2), &[Arg(0, 304), Arg(1, 58)]),
+    ((2625, false, XNOR2), &[Arg(0, 305), Arg(1, 59)]),
+    ((2626, false, XNOR2), &[Arg(0, 306), Arg(1, 60)]),
+    ((2627, false, XNOR2), &[Arg(0, 307), Arg(1, 61)]),
+    ((2628, false, XNOR2), &[Arg(0, 308), Arg(1, 62)]),
+    ((2629, false, XNOR2), &[Arg(0, 309), Arg(1, 63)]),
+    ((2653, false, NOR2), &[Arg(0, 335), Arg(0, 336)]),
+    ((2654, false, NOR2), &[Arg(0, 337), Arg(0, 338)]),
+    ((2655, false, NOR2), &[Arg(0, 339), Arg(0, 340)]),
+    ((2656, false, NOR2), &[Arg(0, 341), Arg(0, 342)]),
+    ((2660, false, INV), &[Arg(0, 336)]),
+    ((2664, false, INV), &[Arg(0, 337)]),
+    ((2668, false, NOR2), &[Arg(0, 336), Arg(0, 337)]),
+    ((2669, false, INV), &[Arg(0, 338)]),
+    ((2673, false, INV), &[Arg(0, 339)]),
+    ((2677, false, NOR2), &[Arg(0, 338), Arg(0, 339)]),
+    ((2678, false, INV), &[Arg(0, 340)]),
+    ((2682, false, INV), &[Arg(0, 341)]),
+    ((2686, false, NOR2), &[Arg(0, 340), Arg(0, 341)]),
+    ((2711, false, XOR2), &[Arg(0, 335), Arg(0, 336)]),
+    ((2767, false, XNOR2), &[Arg(0, 343), Arg(1, 0)]),
+    ((2768, false, XNOR2), &[Arg(0, 344), Arg(1, 1)]),
+    ((2769, false, XNOR2), &[Arg(0, 345), Arg(1, 2)]),
+    ((2770, false, XNOR2), &[Arg(0, 346), Arg(1, 3)]),
+    ((2771, false, XNOR2), &[Arg(0, 347), Arg(1, 4)]),
+    ((2772, false, XNOR2), &[Arg(0, 348), Arg(1, 5)]),
+    ((2773, false, XNOR2), &[Arg(0, 349), Arg(1, 6)]),
+    ((2774, false, XNOR2), &[Arg(0, 350), Arg(1, 7)]),
+    ((2790, false, XNOR2), &[Arg(0, 351), Arg(1, 8)]),
+    ((2791, false, XNOR2), &[Arg(0, 352), Arg(1, 9)]),
+    ((2792, false, XNOR2), &[Arg(0, 353), Arg(1, 10)]),
+    ((2793, false, XNOR2), &[Arg(0, 354), Arg(1, 11)]),
+    ((2794, false, XNOR2), &[Arg(0, 355), Arg(1, 12)]),
+    ((2795, false, XNOR2), &[Arg(0, 356), Arg(1, 13)]),
+    ((2796, false, XNOR2), &[Arg(0, 357), Arg(1, 14)]),
+    ((2797, false, XNOR2), &[Arg(0, 358), Arg(1, 15)]),
+    ((2814, false, XNOR2), &[Arg(0, 343), Arg(1, 16)]),
+    ((2815, false, XNOR2), &[Arg(0, 344), Arg(1, 17)]),
+    ((2816, false, XNOR2), &[Arg(0, 345), Arg(1, 18)]),
+    ((2817, false, XNOR2), &[Arg(0, 346), Arg(1, 19)]),
+    ((2818, false, XNOR2), &[Arg(0, 347), Arg(1, 20)]),
+    ((2819, false, XNOR2), &[Arg(0, 348), Arg(1, 21)]),
+    ((2820, false, XNOR2), &[Arg(0, 349), Arg(1, 22)]),
+    ((2821, false, XNOR2), &[Arg(0, 350), Arg(1, 23)]),
+    ((2837, false, XNOR2), &[Arg(0, 351), Arg(1, 24)]),
+    ((2838, false, XNOR2), &[Arg(0, 352), Arg(1, 25)]),
+    ((2839, false, XNOR2), &[Arg(0, 353), Arg(1, 26)]),
+    ((2840, false, XNOR2), &[Arg(0, 354), Arg(1, 27)]),
+    ((2841, false, XNOR2), &[Arg(0, 355), Arg(1, 28)]),
+    ((2842, false, XNOR2), &[Arg(0, 356), Arg(1, 29)]),
+    ((2843, false, XNOR2), &[Arg(0, 357), Arg(1, 30)]),
+    ((2844, false, XNOR2), &[Arg(0, 358), Arg(1, 31)]),
+    ((2861, false, XNOR2), &[Arg(0, 343), Arg(1, 32)]),
+    ((2862, false, XNOR2), &[Arg(0, 344), Arg(1, 33)]),
+    ((2863, false, XNOR2), &[Arg(0, 345), Arg(1, 34)]),
+    ((2864, false, XNOR2), &[Arg(0, 346), Arg(1, 35)]),
+    ((2865, false, XNOR2), &[Arg(0, 347), Arg(1, 36)]),
+    ((2866, false, XNOR2), &[Arg(0, 348), Arg(1, 37)]),
+    ((2867, false, XNOR2), &[Arg(0, 349), Arg(1, 38)]),
+    ((2868, false, XNOR2), &[Arg(0, 350), Arg(1, 39)]),
+    ((2884, false, XNOR2), &[Arg(0, 351), Arg(1, 40)]),
+    ((2885, false, XNOR2), &[Arg(0, 352), Arg(1, 41)]),
+    ((2886, false, XNOR2), &[Arg(0, 353), Arg(1, 42)]),
+    ((2887, false, XNOR2), &[Arg(0, 354), Arg(1, 43)]),
+    ((2888, false, XNOR2), &[Arg(0, 355), Arg(1, 44)]),
+    ((2889, false, XNOR2), &[Arg(0, 356), Arg(1, 45)]),
+    ((2890, false, XNOR2), &[Arg(0, 357), Arg(1, 46)]),
+    ((2891, false, XNOR2), &[Arg(0, 358), Arg(1, 47)]),
+    ((2908, false, XNOR2), &[Arg(0, 343), Arg(1, 48)]),
+    ((2909, false, XNOR2), &[Arg(0, 344), Arg(1, 49)]),
+    ((2910, false, XNOR2), &[Arg(0, 345), Arg(1, 50)]),
+    ((2911, false, XNOR2), &[Arg(0, 346), Arg(1, 51)]),
+    ((2912, false, XNOR2), &[Arg(0, 347), Arg(1, 52)]),
+    ((2913, false, XNOR2), &[Arg(0, 348), Arg(1, 53)]),
+    ((2914, false, XNOR2), &[Arg(0, 349), Arg(1, 54)]),
+    ((2915, false, XNOR2), &[Arg(0, 350), Arg(1, 55)]),
+    ((2931, false, XNOR2), &[Arg(0, 351), Arg(1, 56)]),
+    ((2932, false, XNOR2), &[Arg(0, 352), Arg(1, 57)]),
+    ((2933, false, XNOR2), &[Arg(0, 353), Arg(1, 58)]),
+    ((2934, false, XNOR2), &[Arg(0, 354), Arg(1, 59)]),
+    ((2935, false, XNOR2), &[Arg(0, 355), Arg(1, 60)]),
+    ((2936, false, XNOR2), &[Arg(0, 356), Arg(1, 61)]),
+    ((2937, false, XNOR2), &[Arg(0, 357), Arg(1, 62)]),
+    ((2938, false, XNOR2), &[Arg(0, 358), Arg(1, 63)]),
+    ((2962, false, NOR2), &[Arg(0, 384), Arg(0, 385)]),
+    ((2963, false, NOR2), &[Arg(0, 386), Arg(0, 387)]),
+    ((2964, false, NOR2), &[Arg(0, 388), Arg(0, 389)]),
+    ((2965, false, NOR2), &[Arg(0, 390), Arg(0, 391)]),
+    ((2969, false, INV), &[Arg(0, 385)]),
+    ((2973, false, INV), &[Arg(0, 386)]),
+    ((2977, false, NOR2), &[Arg(0, 385), Arg(0, 386)]),
+    ((2978, false, INV), &[Arg(0, 387)]),
+    ((2982, false, INV), &[Arg(0, 388)]),
+    ((2986, false, NOR2), &[Arg(0, 387), Arg(0, 388)]),
+    ((2987, false, INV), &[Arg(0, 389)]),
+    ((2991, false, INV), &[Arg(0, 390)]),
+    ((2995, false, NOR2), &[Arg(0, 389), Arg(0, 390)]),
+    ((3020, false, XOR2), &[Arg(0, 384), Arg(0, 385)]),
+    ((3076, false, XNOR2), &[Arg(0, 392), Arg(1, 0)]),
+    ((3077, false, XNOR2), &[Arg(0, 393), Arg(1, 1)]),
+    ((3078, false, XNOR2), &[Arg(0, 394), Arg(1, 2)]),
+    ((3079, false, XNOR2), &[Arg(0, 395), Arg(1, 3)]),
+    ((3080, false, XNOR2), &[Arg(0, 396), Arg(1, 4)]),
+    ((3081, false, XNOR2), &[Arg(0, 397), Arg(1, 5)]),
+    ((3082, false, XNOR2), &[Arg(0, 398), Arg(1, 6)]),
+    ((3083, false, XNOR2), &[Arg(0, 399), Arg(1, 7)]),
+    ((3099, false, XNOR2), &[Arg(0, 400), Arg(1, 8)]),
+    ((3100, false, XNOR2), &[Arg(0, 401), Arg(1, 9)]),
+    ((3101, false, XNOR2), &[Arg(0, 402), Arg(1, 10)]),
+    ((3102, false, XNOR2), &[Arg(0, 403), Arg(1, 11)]),
+    ((3103, false, XNOR2), &[Arg(0, 404), Arg(1, 12)]),
+    ((3104, false, XNOR2), &[Arg(0, 405), Arg(1, 13)]),
+    ((3105, false, XNOR2), &[Arg(0, 406), Arg(1, 14)]),
+    ((3106, false, XNOR2), &[Arg(0, 407), Arg(1, 15)]),
+    ((3123, false, XNOR2), &[Arg(0, 392), Arg(1, 16)]),
+    ((3124, false, XNOR2), &[Arg(0, 393), Arg(1, 17)]),
+    ((3125, false, XNOR2), &[Arg(0, 394), Arg(1, 18)]),
+    ((3126, false, XNOR2), &[Arg(0, 395), Arg(1, 19)]),
+    ((3127, false, XNOR2), &[Arg(0, 396), Arg(1, 20)]),
+    ((3128, false, XNOR2), &[Arg(0, 397), Arg(1, 21)]),
+    ((3129, false, XNOR2), &[Arg(0, 398), Arg(1, 22)]),
+    ((3130, false, XNOR2), &[Arg(0, 399), Arg(1, 23)]),
+    ((3146, false, XNOR2), &[Arg(0, 400), Arg(1, 24)]),
+    ((3147, false, XNOR2), &[Arg(0, 401), Arg(1, 25)]),
+    ((3148, false, XNOR2), &[Arg(0, 402), Arg(1, 26)]),
+    ((3149, false, XNOR2), &[Arg(0, 403), Arg(1, 27)]),
+    ((3150, false, XNOR2), &[Arg(0, 404), Arg(1, 28)]),
+    ((3151, false, XNOR2), &[Arg(0, 405), Arg(1, 29)]),
+    ((3152, false, XNOR2), &[Arg(0, 406), Arg(1, 30)]),
+    ((3153, false, XNOR2), &[Arg(0, 407), Arg(1, 31)]),
+    ((3170, false, XNOR2), &[Arg(0, 392), Arg(1, 32)]),
+    ((3171, false, XNOR2), &[Arg(0, 393), Arg(1, 33)]),
+    ((3172, false, XNOR2), &[Arg(0, 394), Arg(1, 34)]),
+    ((3173, false, XNOR2), &[Arg(0, 395), Arg(1, 35)]),
+    ((3174, false, XNOR2), &[Arg(0, 396), Arg(1, 36)]),
+    ((3175, false, XNOR2), &[Arg(0, 397), Arg(1, 37)]),
+    ((3176, false, XNOR2), &[Arg(0, 398), Arg(1, 38)]),
+    ((3177, false, XNOR2), &[Arg(0, 399), Arg(1, 39)]),
+    ((3193, false, XNOR2), &[Arg(0, 400), Arg(1, 40)]),
+    ((3194, false, XNOR2), &[Arg(0, 401), Arg(1, 41)]),
+    ((3195, false, XNOR2), &[Arg(0, 402), Arg(1, 42)]),
+    ((3196, false, XNOR2), &[Arg(0, 403), Arg(1, 43)]),
+    ((3197, false, XNOR2), &[Arg(0, 404), Arg(1, 44)]),
+    ((3198, false, XNOR2), &[Arg(0, 405), Arg(1, 45)]),
+    ((3199, false, XNOR2), &[Arg(0, 406), Arg(1, 46)]),
+    ((3200, false, XNOR2), &[Arg(0, 407), Arg(1, 47)]),
+    ((3217, false, XNOR2), &[Arg(0, 392), Arg(1, 48)]),
+    ((3218, false, XNOR2), &[Arg(0, 393), Arg(1, 49)]),
+    ((3219, false, XNOR2), &[Arg(0, 394), Arg(1, 50)]),
+    ((3220, false, XNOR2), &[Arg(0, 395), Arg(1, 51)]),
+    ((3221, false, XNOR2), &[Arg(0, 396), Arg(1, 52)]),
+    ((3222, false, XNOR2), &[Arg(0, 397), Arg(1, 53)]),
+    ((3223, false, XNOR2), &[Arg(0, 398), Arg(1, 54)]),
+    ((3224, false, XNOR2), &[Arg(0, 399), Arg(1, 55)]),
+    ((3240, false, XNOR2), &[Arg(0, 400), Arg(1, 56)]),
+    ((3241, false, XNOR2), &[Arg(0, 401), Arg(1, 57)]),
+    ((3242, false, XNOR2), &[Arg(0, 402), Arg(1, 58)]),
+    ((3243, false, XNOR2), &[Arg(0, 403), Arg(1, 59)]),
+    ((3244, false, XNOR2), &[Arg(0, 404), Arg(1, 60)]),
+    ((3245, false, XNOR2), &[Arg(0, 405), Arg(1, 61)]),
+    ((3246, false, XNOR2), &[Arg(0, 406), Arg(1, 62)]),
+    ((3247, false, XNOR2), &[Arg(0, 407), Arg(1, 63)]),
+    ((3271, false, NOR2), &[Arg(0, 433), Arg(0, 434)]),
+    ((3272, false, NOR2), &[Arg(0, 435), Arg(0, 436)]),
+    ((3273, false, NOR2), &[Arg(0, 437), Arg(0, 438)]),
+    ((3274, false, NOR2), &[Arg(0, 439), Arg(0, 440)]),
+    ((3278, false, INV), &[Arg(0, 434)]),
+    ((3282, false, INV), &[Arg(0, 435)]),
+    ((3286, false, NOR2), &[Arg(0, 434), Arg(0, 435)]),
+    ((3287, false, INV), &[Arg(0, 436)]),
+    ((3291, false, INV), &[Arg(0, 437)]),
+    ((3295, false, NOR2), &[Arg(0, 436), Arg(0, 437)]),
+    ((3296, false, INV), &[Arg(0, 438)]),
+    ((3300, false, INV), &[Arg(0, 439)]),
+    ((3304, false, NOR2), &[Arg(0, 438), Arg(0, 439)]),
+    ((3329, false, XOR2), &[Arg(0, 433), Arg(0, 434)]),
+    ((3385, false, XNOR2), &[Arg(0, 441), Arg(1, 0)]),
+    ((3386, false, XNOR2), &[Arg(0, 442), Arg(1, 1)]),
+    ((3387, false, XNOR2), &[Arg(0, 443), Arg(1, 2)]),
+    ((3388, false, XNOR2), &[Arg(0, 444), Arg(1, 3)]),
+    ((3389, false, XNOR2), &[Arg(0, 445), Arg(1, 4)]),
+    ((3390, false, XNOR2), &[Arg(0, 446), Arg(1, 5)]),
+    ((3391, false, XNOR2), &[Arg(0, 447), Arg(1, 6)]),
+    ((3392, false, XNOR2), &[Arg(0, 448), Arg(1, 7)]),
+    ((3408, false, XNOR2), &[Arg(0, 449), Arg(1, 8)]),
+    ((3409, false, XNOR2), &[Arg(0, 450), Arg(1, 9)]),
+    ((3410, false, XNOR2), &[Arg(0, 451), Arg(1, 10)]),
+    ((3411, false, XNOR2), &[Arg(0, 452), Arg(1, 11)]),
+    ((3412, false, XNOR2), &[Arg(0, 453), Arg(1, 12)]),
+    ((3413, false, XNOR2), &[Arg(0, 454), Arg(1, 13)]),
+    ((3414, false, XNOR2), &[Arg(0, 455), Arg(1, 14)]),
+    ((3415, false, XNOR2), &[Arg(0, 456), Arg(1, 15)]),
+    ((3432, false, XNOR2), &[Arg(0, 441), Arg(1, 16)]),
+    ((3433, false, XNOR2), &[Arg(0, 442), Arg(1, 17)]),
+    ((3434, false, XNOR2), &[Arg(0, 443), Arg(1, 18)]),
+    ((3435, false, XNOR2), &[Arg(0, 444), Arg(1, 19)]),
+    ((3436, false, XNOR2), &[Arg(0, 445), Arg(1, 20)]),
+    ((3437, false, XNOR2), &[Arg(0, 446), Arg(1, 21)]),
+    ((3438, false, XNOR2), &[Arg(0, 447), Arg(1, 22)]),
+    ((3439, false, XNOR2), &[Arg(0, 448), Arg(1, 23)]),
+    ((3455, false, XNOR2), &[Arg(0, 449), Arg(1, 24)]),
+    ((3456, false, XNOR2), &[Arg(0, 450), Arg(1, 25)]),
+    ((3457, false, XNOR2), &[Arg(0, 451), Arg(1, 26)]),
+    ((3458, false, XNOR2), &[Arg(0, 452), Arg(1, 27)]),
+    ((3459, false, XNOR2), &[Arg(0, 453), Arg(1, 28)]),
+    ((3460, false, XNOR2), &[Arg(0, 454), Arg(1, 29)]),
+    ((3461, false, XNOR2), &[Arg(0, 455), Arg(1, 30)]),
+    ((3462, false, XNOR2), &[Arg(0, 456), Arg(1, 31)]),
+    ((3479, false, XNOR2), &[Arg(0, 441), Arg(1, 32)]),
+    ((3480, false, XNOR2), &[Arg(0, 442), Arg(1, 33)]),
+    ((3481, false, XNOR2), &[Arg(0, 443), Arg(1, 34)]),
+    ((3482, false, XNOR2), &[Arg(0, 444), Arg(1, 35)]),
+    ((3483, false, XNOR2), &[Arg(0, 445), Arg(1, 36)]),
+    ((3484, false, XNOR2), &[Arg(0, 446), Arg(1, 37)]),
+    ((3485, false, XNOR2), &[Arg(0, 447), Arg(1, 38)]),
+    ((3486, false, XNOR2), &[Arg(0, 448), Arg(1, 39)]),
+    ((3502, false, XNOR2), &[Arg(0, 449), Arg(1, 40)]),
+    ((3503, false, XNOR2), &[Arg(0, 450), Arg(1, 41)]),
+    ((3504, false, XNOR2), &[Arg(0, 451), Arg(1, 42)]),
+    ((3505, false, XNOR2), &[Arg(0, 452), Arg(1, 43)]),
+    ((3506, false, XNOR2), &[Arg(0, 453), Arg(1, 44)]),
+    ((3507, false, XNOR2), &[Arg(0, 454), Arg(1, 45)]),
+    ((3508, false, XNOR2), &[Arg(0, 455), Arg(1, 46)]),
+    ((3509, false, XNOR2), &[Arg(0, 456), Arg(1, 47)]),
+    ((3526, false, XNOR2), &[Arg(0, 441), Arg(1, 48)]),
+    ((3527, false, XNOR2), &[Arg(0, 442), Arg(1, 49)]),
+    ((3528, false, XNOR2), &[Arg(0, 443), Arg(1, 50)]),
+    ((3529, false, XNOR2), &[Arg(0, 444), Arg(1, 51)]),
+    ((3530, false, XNOR2), &[Arg(0, 445), Arg(1, 52)]),
+    ((3531, false, XNOR2), &[Arg(0, 446), Arg(1, 53)]),
+    ((3532, false, XNOR2), &[Arg(0, 447), Arg(1, 54)]),
+    ((3533, false, XNOR2), &[Arg(0, 448), Arg(1, 55)]),
+    ((3549, false, XNOR2), &[Arg(0, 449), Arg(1, 56)]),
+    ((3550, false, XNOR2), &[Arg(0, 450), Arg(1, 57)]),
+    ((3551, false, XNOR2), &[Arg(0, 451), Arg(1, 58)]),
+    ((3552, false, XNOR2), &[Arg(0, 452), Arg(1, 59)]),
+    ((3553, false, XNOR2), &[Arg(0, 453), Arg(1, 60)]),
+    ((3554, false, XNOR2), &[Arg(0, 454), Arg(1, 61)]),
+    ((3555, false, XNOR2), &[Arg(0, 455), Arg(1, 62)]),
+    ((3556, false, XNOR2), &[Arg(0, 456), Arg(1, 63)]),
+    ((3580, false, NOR2), &[Arg(0, 482), Arg(0, 483)]),
+    ((3581, false, NOR2), &[Arg(0, 484), Arg(0, 485)]),
+    ((3582, false, NOR2), &[Arg(0, 486), Arg(0, 487)]),
+    ((3583, false, NOR2), &[Arg(0, 488), Arg(0, 489)]),
+    ((3587, false, INV), &[Arg(0, 483)]),
+    ((3591, false, INV), &[Arg(0, 484)]),
+    ((3595, false, NOR2), &[Arg(0, 483), Arg(0, 484)]),
+    ((3596, false, INV), &[Arg(0, 485)]),
+    ((3600, false, INV), &[Arg(0, 486)]),
+    ((3604, false, NOR2), &[Arg(0, 485), Arg(0, 486)]),
+    ((3605, false, INV), &[Arg(0, 487)]),
+    ((3609, false, INV), &[Arg(0, 488)]),
+    ((3613, false, NOR2), &[Arg(0, 487), Arg(0, 488)]),
+    ((3638, false, XOR2), &[Arg(0, 482), Arg(0, 483)]),
+    ((3694, false, XNOR2), &[Arg(0, 490), Arg(1, 0)]),
+    ((3695, false, XNOR2), &[Arg(0, 491), Arg(1, 1)]),
+    ((3696, false, XNOR2), &[Arg(0, 492), Arg(1, 2)]),
+    ((3697, false, XNOR2), &[Arg(0, 493), Arg(1, 3)]),
+    ((3698, false, XNOR2), &[Arg(0, 494), Arg(1, 4)]),
+    ((3699, false, XNOR2), &[Arg(0, 495), Arg(1, 5)]),
+    ((3700, false, XNOR2), &[Arg(0, 496), Arg(1, 6)]),
+    ((3701, false, XNOR2), &[Arg(0, 497), Arg(1, 7)]),
+    ((3717, false, XNOR2), &[Arg(0, 498), Arg(1, 8)]),
+    ((3718, false, XNOR2), &[Arg(0, 499), Arg(1, 9)]),
+    ((3719, false, XNOR2), &[Arg(0, 500), Arg(1, 10)]),
+    ((3720, false, XNOR2), &[Arg(0, 501), Arg(1, 11)]),
+    ((3721, false, XNOR2), &[Arg(0, 502), Arg(1, 12)]),
+    ((3722, false, XNOR2), &[Arg(0, 503), Arg(1, 13)]),
+    ((3723, false, XNOR2), &[Arg(0, 504), Arg(1, 14)]),
+    ((3724, false, XNOR2), &[Arg(0, 505), Arg(1, 15)]),
+    ((3741, false, XNOR2), &[Arg(0, 490), Arg(1, 16)]),
+    ((3742, false, XNOR2), &[Arg(0, 491), Arg(1, 17)]),
+    ((3743, false, XNOR2), &[Arg(0, 492), Arg(1, 18)]),
+    ((3744, false, XNOR2), &[Arg(0, 493), Arg(1, 19)]),
+    ((3745, false, XNOR2), &[Arg(0, 494), Arg(1, 20)]),
+    ((3746, false, XNOR2), &[Arg(0, 495), Arg(1, 21)]),
+    ((3747, false, XNOR2), &[Arg(0, 496), Arg(1, 22)]),
+    ((3748, false, XNOR2), &[Arg(0, 497), Arg(1, 23)]),
+    ((3764, false, XNOR2), &[Arg(0, 498), Arg(1, 24)]),
+    ((3765, false, XNOR2), &[Arg(0, 499), Arg(1, 25)]),
+    ((3766, false, XNOR2), &[Arg(0, 500), Arg(1, 26)]),
+    ((3767, false, XNOR2), &[Arg(0, 501), Arg(1, 27)]),
+    ((3768, false, XNOR2), &[Arg(0, 502), Arg(1, 28)]),
+    ((3769, false, XNOR2), &[Arg(0, 503), Arg(1, 29)]),
+    ((3770, false, XNOR2), &[Arg(0, 504), Arg(1, 30)]),
+    ((3771, false, XNOR2), &[Arg(0, 505), Arg(1, 31)]),
+    ((3788, false, XNOR2), &[Arg(0, 490), Arg(1, 32)]),
+    ((3789, false, XNOR2), &[Arg(0, 491), Arg(1, 33)]),
+    ((3790, false, XNOR2), &[Arg(0, 492), Arg(1, 34)]),
+    ((3791, false, XNOR2), &[Arg(0, 493), Arg(1, 35)]),
+    ((3792, false, XNOR2), &[Arg(0, 494), Arg(1, 36)]),
+    ((3793, false, XNOR2), &[Arg(0, 495), Arg(1, 37)]),
+    ((3794, false, XNOR2), &[Arg(0, 496), Arg(1, 38)]),
+    ((3795, false, XNOR2), &[Arg(0, 497), Arg(1, 39)]),
+    ((3811, false, XNOR2), &[Arg(0, 498), Arg(1, 40)]),
+    ((3812, false, XNOR2), &[Arg(0, 499), Arg(1, 41)]),
+    ((3813, false, XNOR2), &[Arg(0, 500), Arg(1, 42)]),
+    ((3814, false, XNOR2), &[Arg(0, 501), Arg(1, 43)]),
+    ((3815, false, XNOR2), &[Arg(0, 502), Arg(1, 44)]),
+    ((3816, false, XNOR2), &[Arg(0, 503), Arg(1, 45)]),
+    ((3817, false, XNOR2), &[Arg(0, 504), Arg(1, 46)]),
+    ((3818, false, XNOR2), &[Arg(0, 505), Arg(1, 47)]),
+    ((3835, false, XNOR2), &[Arg(0, 490), Arg(1, 48)]),
+    ((3836, false, XNOR2), &[Arg(0, 491), Arg(1, 49)]),
+    ((3837, false, XNOR2), &[Arg(0, 492), Arg(1, 50)]),
+    ((3838, false, XNOR2), &[Arg(0, 493), Arg(1, 51)]),
+    ((3839, false, XNOR2), &[Arg(0, 494), Arg(1, 52)]),
+    ((3840, false, XNOR2), &[Arg(0, 495), Arg(1, 53)]),
+    ((3841, false, XNOR2), &[Arg(0, 496), Arg(1, 54)]),
+    ((3842, false, XNOR2), &[Arg(0, 497), Arg(1, 55)]),
+    ((3858, false, XNOR2), &[Arg(0, 498), Arg(1, 56)]),
+    ((3859, false, XNOR2), &[Arg(0, 499), Arg(1, 57)]),
+    ((3860, false, XNOR2), &[Arg(0, 500), Arg(1, 58)]),
+    ((3861, false, XNOR2), &[Arg(0, 501), Arg(1, 59)]),
+    ((3862, false, XNOR2), &[Arg(0, 502), Arg(1, 60)]),
+    ((3863, false, XNOR2), &[Arg(0, 503), Arg(1, 61)]),
+    ((3864, false, XNOR2), &[Arg(0, 504), Arg(1, 62)]),
+    ((3865, false, XNOR2), &[Arg(0, 505), Arg(1, 63)]),
+    ((3889, false, NOR2), &[Arg(0, 531), Arg(0, 532)]),
+    ((3890, false, NOR2), &[Arg(0, 533), Arg(0, 534)]),
+    ((3891, false, NOR2), &[Arg(0, 535), Arg(0, 536)]),
+    ((3892, false, NOR2), &[Arg(0, 537), Arg(0, 538)]),
+    ((3896, false, INV), &[Arg(0, 532)]),
+    ((3900, false, INV), &[Arg(0, 533)]),
+    ((3904, false, NOR2), &[Arg(0, 532), Arg(0, 533)]),
+    ((3905, false, INV), &[Arg(0, 534)]),
+    ((3909, false, INV), &[Arg(0, 535)]),
+    ((3913, false, NOR2), &[Arg(0, 534), Arg(0, 535)]),
+    ((3914, false, INV), &[Arg(0, 536)]),
+    ((3918, false, INV), &[Arg(0, 537)]),
+    ((3922, false, NOR2), &[Arg(0, 536), Arg(0, 537)]),
+    ((3947, false, XOR2), &[Arg(0, 531), Arg(0, 532)]),
+    ((4003, false, XNOR2), &[Arg(0, 539), Arg(1, 0)]),
+    ((4004, false, XNOR2), &[Arg(0, 540), Arg(1, 1)]),
+    ((4005, false, XNOR2), &[Arg(0, 541), Arg(1, 2)]),
+    ((4006, false, XNOR2), &[Arg(0, 542), Arg(1, 3)]),
+    ((4007, false, XNOR2), &[Arg(0, 543), Arg(1, 4)]),
+    ((4008, false, XNOR2), &[Arg(0, 544), Arg(1, 5)]),
+    ((4009, false, XNOR2), &[Arg(0, 545), Arg(1, 6)]),
+    ((4010, false, XNOR2), &[Arg(0, 546), Arg(1, 7)]),
+    ((4026, false, XNOR2), &[Arg(0, 547), Arg(1, 8)]),
+    ((4027, false, XNOR2), &[Arg(0, 548), Arg(1, 9)]),
+    ((4028, false, XNOR2), &[Arg(0, 549), Arg(1, 10)]),
+    ((4029, false, XNOR2), &[Arg(0, 550), Arg(1, 11)]),
+    ((4030, false, XNOR2), &[Arg(0, 551), Arg(1, 12)]),
+    ((4031, false, XNOR2), &[Arg(0, 552), Arg(1, 13)]),
+    ((4032, false, XNOR2), &[Arg(0, 553), Arg(1, 14)]),
+    ((4033, false, XNOR2), &[Arg(0, 554), Arg(1, 15)]),
+    ((4050, false, XNOR2), &[Arg(0, 539), Arg(1, 16)]),
+    ((4051, false, XNOR2), &[Arg(0, 540), Arg(1, 17)]),
+    ((4052, false, XNOR2), &[Arg(0, 541), Arg(1, 18)]),
+    ((4053, false, XNOR2), &[Arg(0, 542), Arg(1, 19)]),
+    ((4054, false, XNOR2), &[Arg(0, 543), Arg(1, 20)]),
+    ((4055, false, XNOR2), &[Arg(0, 544), Arg(1, 21)]),
+    ((4056, false, XNOR2), &[Arg(0, 545), Arg(1, 22)]),
+    ((4057, false, XNOR2), &[Arg(0, 546), Arg(1, 23)]),
+    ((4073, false, XNOR2), &[Arg(0, 547), Arg(1, 24)]),
+    ((4074, false, XNOR2), &[Arg(0, 548), Arg(1, 25)]),
+    ((4075, false, XNOR2), &[Arg(0, 549), Arg(1, 26)]),
+    ((4076, false, XNOR2), &[Arg(0, 550), Arg(1, 27)]),
+    ((4077, false, XNOR2), &[Arg(0, 551), Arg(1, 28)]),
+    ((4078, false, XNOR2), &[Arg(0, 552), Arg(1, 29)]),
+    ((4079, false, XNOR2), &[Arg(0, 553), Arg(1, 30)]),
+    ((4080, false, XNOR2), &[Arg(0, 554), Arg(1, 31)]),
+    ((4097, false, XNOR2), &[Arg(0, 539), Arg(1, 32)]),
+    ((4098, false, XNOR2), &[Arg(0, 540), Arg(1, 33)]),
+    ((4099, false, XNOR2), &[Arg(0, 541), Arg(1, 34)]),
+    ((4100, false, XNOR2), &[Arg(0, 542), Arg(1, 35)]),
+    ((4101, false, XNOR2), &[Arg(0, 543), Arg(1, 36)]),
+    ((4102, false, XNOR2), &[Arg(0, 544), Arg(1, 37)]),
+    ((4103, false, XNOR2), &[Arg(0, 545), Arg(1, 38)]),
+    ((4104, false, XNOR2), &[Arg(0, 546), Arg(1, 39)]),
+    ((4120, false, XNOR2), &[Arg(0, 547), Arg(1, 40)]),
+    ((4121, false, XNOR2), &[Arg(0, 548), Arg(1, 41)]),
+    ((4122, false, XNOR2), &[Arg(0, 549), Arg(1, 42)]),
+    ((4123, false, XNOR2), &[Arg(0, 550), Arg(1, 43)]),
+    ((4124, false, XNOR2), &[Arg(0, 551), Arg(1, 44)]),
+    ((4125, false, XNOR2), &[Arg(0, 552), Arg(1, 45)]),
+    ((4126, false, XNOR2), &[Arg(0, 553), Arg(1, 46)]),
+    ((4127, false, XNOR2), &[Arg(0, 554), Arg(1, 47)]),
+    ((4144, false, XNOR2), &[Arg(0, 539), Arg(1, 48)]),
+    ((4145, false, XNOR2), &[Arg(0, 540), Arg(1, 49)]),
+    ((4146, false, XNOR2), &[Arg(0, 541), Arg(1, 50)]),
+    ((4147, false, XNOR2), &[Arg(0, 542), Arg(1, 51)]),
+    ((4148, false, XNOR2), &[Arg(0, 543), Arg(1, 52)]),
+    ((4149, false, XNOR2), &[Arg(0, 544), Arg(1, 53)]),
+    ((4150, false, XNOR2), &[Arg(0, 545), Arg(1, 54)]),
+    ((4151, false, XNOR2), &[Arg(0, 546), Arg(1, 55)]),
+    ((4167, false, XNOR2), &[Arg(0, 547), Arg(1, 56)]),
+    ((4168, false, XNOR2), &[Arg(0, 548), Arg(1, 57)]),
+    ((4169, false, XNOR2), &[Arg(0, 549), Arg(1, 58)]),
+    ((4170, false, XNOR2), &[Arg(0, 550), Arg(1, 59)]),
+    ((4171, false, XNOR2), &[Arg(0, 551), Arg(1, 60)]),
+    ((4172, false, XNOR2), &[Arg(0, 552), Arg(1, 61)]),
+    ((4173, false, XNOR2), &[Arg(0, 553), Arg(1, 62)]),
+    ((4174, false, XNOR2), &[Arg(0, 554), Arg(1, 63)]),
+    ((4198, false, NOR2), &[Arg(0, 580), Arg(0, 581)]),
+    ((4199, false, NOR2), &[Arg(0, 582), Arg(0, 583)]),
+    ((4200, false, NOR2), &[Arg(0, 584), Arg(0, 585)]),
+    ((4201, false, NOR2), &[Arg(0, 586), Arg(0, 587)]),
+    ((4205, false, INV), &[Arg(0, 581)]),
+    ((4209, false, INV), &[Arg(0, 582)]),
+    ((4213, false, NOR2), &[Arg(0, 581), Arg(0, 582)]),
+    ((4214, false, INV), &[Arg(0, 583)]),
+    ((4218, false, INV), &[Arg(0, 584)]),
+    ((4222, false, NOR2), &[Arg(0, 583), Arg(0, 584)]),
+    ((4223, false, INV), &[Arg(0, 585)]),
+    ((4227, false, INV), &[Arg(0, 586)]),
+    ((4231, false, NOR2), &[Arg(0, 585), Arg(0, 586)]),
+    ((4256, false, XOR2), &[Arg(0, 580), Arg(0, 581)]),
+];
+
+static LEVEL_1: [((usize, bool, CellType), &[GateInput]); 924] = [
+    ((612, false, INV), &[Tv(604)]),
+    ((613, false, INV), &[Tv(605)]),
+    ((615, false, INV), &[Tv(606)]),
+    ((616, false, INV), &[Tv(607)]),
+    ((618, false, INV), &[Tv(608)]),
+    ((619, false, INV), &[Tv(609)]),
+    ((621, false, INV), &[Tv(610)]),
+    ((622, false, INV), &[Tv(611)]),
+    ((635, false, INV), &[Tv(627)]),
+    ((636, false, INV), &[Tv(628)]),
+    ((638, false, INV), &[Tv(629)]),
+    ((639, false, INV), &[Tv(630)]),
+    ((641, false, INV), &[Tv(631)]),
+    ((642, false, INV), &[Tv(632)]),
+    ((644, false, INV), &[Tv(633)]),
+    ((645, false, INV), &[Tv(634)]),
+    ((659, false, INV), &[Tv(651)]),
+    ((660, false, INV), &[Tv(652)]),
+    ((662, false, INV), &[Tv(653)]),
+    ((663, false, INV), &[Tv(654)]),
+    ((665, false, INV), &[Tv(655)]),
+    ((666, false, INV), &[Tv(656)]),
+    ((668, false, INV), &[Tv(657)]),
+    ((669, false, INV), &[Tv(658)]),
+    ((682, false, INV), &[Tv(674)]),
+    ((683, false, INV), &[Tv(675)]),
+    ((685, false, INV), &[Tv(676)]),
+    ((686, false, INV), &[Tv(677)]),
+    ((688, false, INV), &[Tv(678)]),
+    ((689, false, INV), &[Tv(679)]),
+    ((691, false, INV), &[Tv(680)]),
+    ((692, false, INV), &[Tv(681)]),
+    ((706, false, INV), &[Tv(698)]),
+    ((707, false, INV), &[Tv(699)]),
+    ((709, false, INV), &[Tv(700)]),
+    ((710, false, INV), &[Tv(701)]),
+    ((712, false, INV), &[Tv(702)]),
+    ((713, false, INV), &[Tv(703)]),
+    ((715, false, INV), &[Tv(704)]),
+    ((716, false, INV), &[Tv(705)]),
+    ((729, false, INV), &[Tv(721)]),
+    ((730, false, INV), &[Tv(722)]),
+    ((732, false, INV), &[Tv(723)]),
+    ((733, false, INV), &[Tv(724)]),
+    ((735, false, INV), &[Tv(725)]),
+    ((736, false, INV), &[Tv(726)]),
+    ((738, false, INV), &[Tv(727)]),
+    ((739, false, INV), &[Tv(728)]),
+    ((753, false, INV), &[Tv(745)]),
+    ((754, false, INV), &[Tv(746)]),
+    ((756, false, INV), &[Tv(747)]),
+    ((757, false, INV), &[Tv(748)]),
+    ((759, false, INV), &[Tv(749)]),
+    ((760, false, INV), &[Tv(750)]),
+    ((762, false, INV), &[Tv(751)]),
+    ((763, false, INV), &[Tv(752)]),
+    ((776, false, INV), &[Tv(768)]),
+    ((777, false, INV), &[Tv(769)]),
+    ((779, false, INV), &[Tv(770)]),
+    ((780, false, INV), &[Tv(771)]),
+    ((782, false, INV), &[Tv(772)]),
+    ((783, false, INV), &[Tv(773)]),
+    ((785, false, INV), &[Tv(774)]),
+    ((786, false, INV), &[Tv(775)]),
+    ((803, false, AND2), &[Tv(799), Tv(800)]),
+    ((804, false, AND2), &[Tv(801), Tv(802)]),
+    ((807, false, NAND2), &[Arg(0, 41), Tv(806)]),
+    ((811, false, NAND2), &[Arg(0, 42), Tv(810)]),
+    ((816, false, NAND2), &[Arg(0, 43), Tv(815)]),
+    ((820, false, NAND2), &[Arg(0, 44), Tv(819)]),
+    ((825, false, NAND2), &[Arg(0, 45), Tv(824)]),
+    ((829, false, NAND2), &[Arg(0, 46), Tv(828)]),
+    ((833, false, NAND2), &[Arg(0, 41), Tv(814)]),
+    ((841, false, AND2), &[Tv(814), Tv(823)]),
+    ((845, false, AND2), &[Tv(800), Tv(801)]),
+    ((849, false, AND2), &[Tv(823), Tv(832)]),
+    ((865, false, XNOR2), &[Arg(0, 42), Tv(857)]),
+    ((921, false, INV), &[Tv(913)]),
+    ((922, false, INV), &[Tv(914)]),
+    ((924, false, INV), &[Tv(915)]),
+    ((925, false, INV), &[Tv(916)]),
+    ((927, false, INV), &[Tv(917)]),
+    ((928, false, INV), &[Tv(918)]),
+    ((930, false, INV), &[Tv(919)]),
+    ((931, false, INV), &[Tv(920)]),
+    ((944, false, INV), &[Tv(936)]),
+    ((945, false, INV), &[Tv(937)]),
+    ((947, false, INV), &[Tv(938)]),
+    ((948, false, INV), &[Tv(939)]),
+    ((950, false, INV), &[Tv(940)]),
+    ((951, false, INV), &[Tv(941)]),
+    ((953, false, INV), &[Tv(942)]),
+    ((954, false, INV), &[Tv(943)]),
+    ((968, false, INV), &[Tv(960)]),
+    ((969, false, INV), &[Tv(961)]),
+    ((971, false, INV), &[Tv(962)]),
+    ((972, false, INV), &[Tv(963)]),
+    ((974, false, INV), &[Tv(964)]),
+    ((975, false, INV), &[Tv(965)]),
+    ((977, false, INV), &[Tv(966)]),
+    ((978, false, INV), &[Tv(967)]),
+    ((991, false, INV), &[Tv(983)]),
+    ((992, false, INV), &[Tv(984)]),
+    ((994, false, INV), &[Tv(985)]),
+    ((995, false, INV), &[Tv(986)]),
+    ((997, false, INV), &[Tv(987)]),
+    ((998, false, INV), &[Tv(988)]),
+    ((1000, false, INV), &[Tv(989)]),
+    ((1001, false, INV), &[Tv(990)]),
+    ((1015, false, INV), &[Tv(1007)]),
+    ((1016, false, INV), &[Tv(1008)]),
+    ((1018, false, INV), &[Tv(1009)]),
+    ((1019, false, INV), &[Tv(1010)]),
+    ((1021, false, INV), &[Tv(1011)]),
+    ((1022, false, INV), &[Tv(1012)]),
+    ((1024, false, INV), &[Tv(1013)]),
+    ((1025, false, INV), &[Tv(1014)]),
+    ((1038, false, INV), &[Tv(1030)]),
+    ((1039, false, INV), &[Tv(1031)]),
+    ((1041, false, INV), &[Tv(1032)]),
+    ((1042, false, INV), &[Tv(1033)]),
+    ((1044, false, INV), &[Tv(1034)]),
+    ((1045, false, INV), &[Tv(1035)]),
+    ((1047, false, INV), &[Tv(1036)]),
+    ((1048, false, INV), &[Tv(1037)]),
+    ((1062, false, INV), &[Tv(1054)]),
+    ((1063, false, INV), &[Tv(1055)]),
+    ((1065, false, INV), &[Tv(1056)]),
+    ((1066, false, INV), &[Tv(1057)]),
+    ((1068, false, INV), &[Tv(1058)]),
+    ((1069, false, INV), &[Tv(1059)]),
+    ((1071, false, INV), &[Tv(1060)]),
+    ((1072, false, INV), &[Tv(1061)]),
+    ((1085, false, INV), &[Tv(1077)]),
+    ((1086, false, INV), &[Tv(1078)]),
+    ((1088, false, INV), &[Tv(1079)]),
+    ((1089, false, INV), &[Tv(1080)]),
+    ((1091, false, INV), &[Tv(1081)]),
+    ((1092, false, INV), &[Tv(1082)]),
+    ((1094, false, INV), &[Tv(1083)]),
+    ((1095, false, INV), &[Tv(1084)]),
+    ((1112, false, AND2), &[Tv(1108), Tv(1109)]),
+    ((1113, false, AND2), &[Tv(1110), Tv(1111)]),
+    ((1116, false, NAND2), &[Arg(0, 90), Tv(1115)]),
+    ((1120, false, NAND2), &[Arg(0, 91), Tv(1119)]),
+    ((1125, false, NAND2), &[Arg(0, 92), Tv(1124)]),
+    ((1129, false, NAND2), &[Arg(0, 93), Tv(1128)]),
+    ((1134, false, NAND2), &[Arg(0, 94), Tv(1133)]),
+    ((1138, false, NAND2), &[Arg(0, 95), Tv(1137)]),
+    ((1142, false, NAND2), &[Arg(0, 90), Tv(1123)]),
+    ((1150, false, AND2), &[Tv(1123), Tv(1132)]),
+    ((1154, false, AND2), &[Tv(1109), Tv(1110)]),
+    ((1158, false, AND2), &[Tv(1132), Tv(1141)]),
+    ((1174, false, XNOR2), &[Arg(0, 91), Tv(1166)]),
+    ((1230, false, INV), &[Tv(1222)]),
+    ((1231, false, INV), &[Tv(1223)]),
+    ((1233, false, INV), &[Tv(1224)]),
+    ((1234, false, INV), &[Tv(1225)]),
+    ((1236, false, INV), &[Tv(1226)]),
+    ((1237, false, INV), &[Tv(1227)]),
+    ((1239, false, INV), &[Tv(1228)]),
+    ((1240, false, INV), &[Tv(1229)]),
+    ((1253, false, INV), &[Tv(1245)]),
+    ((1254, false, INV), &[Tv(1246)]),
+    ((1256, false, INV), &[Tv(1247)]),
+    ((1257, false, INV), &[Tv(1248)]),
+    ((1259, false, INV), &[Tv(1249)]),
+    ((1260, false, INV), &[Tv(1250)]),
+    ((1262, false, INV), &[Tv(1251)]),
+    ((1263, false, INV), &[Tv(1252)]),
+    ((1277, false, INV), &[Tv(1269)]),
+    ((1278, false, INV), &[Tv(1270)]),
+    ((1280, false, INV), &[Tv(1271)]),
+    ((1281, false, INV), &[Tv(1272)]),
+    ((1283, false, INV), &[Tv(1273)]),
+    ((1284, false, INV), &[Tv(1274)]),
+    ((1286, false, INV), &[Tv(1275)]),
+    ((1287, false, INV), &[Tv(1276)]),
+    ((1300, false, INV), &[Tv(1292)]),
+    ((1301, false, INV), &[Tv(1293)]),
+    ((1303, false, INV), &[Tv(1294)]),
+    ((1304, false, INV), &[Tv(1295)]),
+    ((1306, false, INV), &[Tv(1296)]),
+    ((1307, false, INV), &[Tv(1297)]),
+    ((1309, false, INV), &[Tv(1298)]),
+    ((1310, false, INV), &[Tv(1299)]),
+    ((1324, false, INV), &[Tv(1316)]),
+    ((1325, false, INV), &[Tv(1317)]),
+    ((1327, false, INV), &[Tv(1318)]),
+    ((1328, false, INV), &[Tv(1319)]),
+    ((1330, false, INV), &[Tv(1320)]),
+    ((1331, false, INV), &[Tv(1321)]),
+    ((1333, false, INV), &[Tv(1322)]),
+    ((1334, false, INV), &[Tv(1323)]),
+    ((1347, false, INV), &[Tv(1339)]),
+    ((1348, false, INV), &[Tv(1340)]),
+    ((1350, false, INV), &[Tv(1341)]),
+    ((1351, false, INV), &[Tv(1342)]),
+    ((1353, false, INV), &[Tv(1343)]),
+    ((1354, false, INV), &[Tv(1344)]),
+    ((1356, false, INV), &[Tv(1345)]),
+    ((1357, false, INV), &[Tv(1346)]),
+    ((1371, false, INV), &[Tv(1363)]),
+    ((1372, false, INV), &[Tv(1364)]),
+    ((1374, false, INV), &[Tv(1365)]),
+    ((1375, false, INV), &[Tv(1366)]),
+    ((1377, false, INV), &[Tv(1367)]),
+    ((1378, false, INV), &[Tv(1368)]),
+    ((1380, false, INV), &[Tv(1369)]),
+    ((1381, false, INV), &[Tv(1370)]),
+    ((1394, false, INV), &[Tv(1386)]),
+    ((1395, false, INV), &[Tv(1387)]),
+    ((1397, false, INV), &[Tv(1388)]),
+    ((1398, false, INV), &[Tv(1389)]),
+    ((1400, false, INV), &[Tv(1390)]),
+    ((1401, false, INV), &[Tv(1391)]),
+    ((1403, false, INV), &[Tv(1392)]),
+    ((1404, false, INV), &[Tv(1393)]),
+    ((1421, false, AND2), &[Tv(1417), Tv(1418)]),
+    ((1422, false, AND2), &[Tv(1419), Tv(1420)]),
+    ((1425, false, NAND2), &[Arg(0, 139), Tv(1424)]),
+    ((1429, false, NAND2), &[Arg(0, 140), Tv(1428)]),
+    ((1434, false, NAND2), &[Arg(0, 141), Tv(1433)]),
+    ((1438, false, NAND2), &[Arg(0, 142), Tv(1437)]),
+    ((1443, false, NAND2), &[Arg(0, 143), Tv(1442)]),
+    ((1447, false, NAND2), &[Arg(0, 144), Tv(1446)]),
+    ((1451, false, NAND2), &[Arg(0, 139), Tv(1432)]),
+    ((1459, false, AND2), &[Tv(1432), Tv(1441)]),
+    ((1463, false, AND2), &[Tv(1418), Tv(1419)]),
+    ((1467, false, AND2), &[Tv(1441), Tv(1450)]),
+    ((1483, false, XNOR2), &[Arg(0, 140), Tv(1475)]),
+    ((1539, false, INV), &[Tv(1531)]),
+    ((1540, false, INV), &[Tv(1532)]),
+    ((1542, false, INV), &[Tv(1533)]),
+    ((1543, false, INV), &[Tv(1534)]),
+    ((1545, false, INV), &[Tv(1535)]),
+    ((1546, false, INV), &[Tv(1536)]),
+    ((1548, false, INV), &[Tv(1537)]),
+    ((1549, false, INV), &[Tv(1538)]),
+    ((1562, false, INV), &[Tv(1554)]),
+    ((1563, false, INV), &[Tv(1555)]),
+    ((1565, false, INV), &[Tv(1556)]),
+    ((1566, false, INV), &[Tv(1557)]),
+    ((1568, false, INV), &[Tv(1558)]),
+    ((1569, false, INV), &[Tv(1559)]),
+    ((1571, false, INV), &[Tv(1560)]),
+    ((1572, false, INV), &[Tv(1561)]),
+    ((1586, false, INV), &[Tv(1578)]),
+    ((1587, false, INV), &[Tv(1579)]),
+    ((1589, false, INV), &[Tv(1580)]),
+    ((1590, false, INV), &[Tv(1581)]),
+    ((1592, false, INV), &[Tv(1582)]),
+    ((1593, false, INV), &[Tv(1583)]),
+    ((1595, false, INV), &[Tv(1584)]),
+    ((1596, false, INV), &[Tv(1585)]),
+    ((1609, false, INV), &[Tv(1601)]),
+    ((1610, false, INV), &[Tv(1602)]),
+    ((1612, false, INV), &[Tv(1603)]),
+    ((1613, false, INV), &[Tv(1604)]),
+    ((1615, false, INV), &[Tv(1605)]),
+    ((1616, false, INV), &[Tv(1606)]),
+    ((1618, false, INV), &[Tv(1607)]),
+    ((1619, false, INV), &[Tv(1608)]),
+    ((1633, false, INV), &[Tv(1625)]),
+    ((1634, false, INV), &[Tv(1626)]),
+    ((1636, false, INV), &[Tv(1627)]),
+    ((1637, false, INV), &[Tv(1628)]),
+    ((1639, false, INV), &[Tv(1629)]),
+    ((1640, false, INV), &[Tv(1630)]),
+    ((1642, false, INV), &[Tv(1631)]),
+    ((1643, false, INV), &[Tv(1632)]),
+    ((1656, false, INV), &[Tv(1648)]),
+    ((1657, false, INV), &[Tv(1649)]),
+    ((1659, false, INV), &[Tv(1650)]),
+    ((1660, false, INV), &[Tv(1651)]),
+    ((1662, false, INV), &[Tv(1652)]),
+    ((1663, false, INV), &[Tv(1653)]),
+    ((1665, false, INV), &[Tv(1654)]),
+    ((1666, false, INV), &[Tv(1655)]),
+    ((1680, false, INV), &[Tv(1672)]),
+    ((1681, false, INV), &[Tv(1673)]),
+    ((1683, false, INV), &[Tv(1674)]),
+    ((1684, false, INV), &[Tv(1675)]),
+    ((1686, false, INV), &[Tv(1676)]),
+    ((1687, false, INV), &[Tv(1677)]),
+    ((1689, false, INV), &[Tv(1678)]),
+    ((1690, false, INV), &[Tv(1679)]),
+    ((1703, false, INV), &[Tv(1695)]),
+    ((1704, false, INV), &[Tv(1696)]),
+    ((1706, false, INV), &[Tv(1697)]),
+    ((1707, false, INV), &[Tv(1698)]),
+    ((1709, false, INV), &[Tv(1699)]),
+    ((1710, false, INV), &[Tv(1700)]),
+    ((1712, false, INV), &[Tv(1701)]),
+    ((1713, false, INV), &[Tv(1702)]),
+    ((1730, false, AND2), &[Tv(1726), Tv(1727)]),
+    ((1731, false, AND2), &[Tv(1728), Tv(1729)]),
+    ((1734, false, NAND2), &[Arg(0, 188), Tv(1733)]),
+    ((1738, false, NAND2), &[Arg(0, 189), Tv(1737)]),
+    ((1743, false, NAND2), &[Arg(0, 190), Tv(1742)]),
+    ((1747, false, NAND2), &[Arg(0, 191), Tv(1746)]),
+    ((1752, false, NAND2), &[Arg(0, 192), Tv(1751)]),
+    ((1756, false, NAND2), &[Arg(0, 193), Tv(1755)]),
+    ((1760, false, NAND2), &[Arg(0, 188), Tv(1741)]),
+    ((1768, false, AND2), &[Tv(1741), Tv(1750)]),
+    ((1772, false, AND2), &[Tv(1727), Tv(1728)]),
+    ((1776, false, AND2), &[Tv(1750), Tv(1759)]),
+    ((1792, false, XNOR2), &[Arg(0, 189), Tv(1784)]),
+    ((1848, false, INV), &[Tv(1840)]),
+    ((1849, false, INV), &[Tv(1841)]),
+    ((1851, false, INV), &[Tv(1842)]),
+    ((1852, false, INV), &[Tv(1843)]),
+    ((1854, false, INV), &[Tv(1844)]),
+    ((1855, false, INV), &[Tv(1845)]),
+    ((1857, false, INV), &[Tv(1846)]),
+    ((1858, false, INV), &[Tv(1847)]),
+    ((1871, false, INV), &[Tv(1863)]),
+    ((1872, false, INV), &[Tv(1864)]),
+    ((1874, false, INV), &[Tv(1865)]),
+    ((1875, false, INV), &[Tv(1866)]),
+    ((1877, false, INV), &[Tv(1867)]),
+    ((1878, false, INV), &[Tv(1868)]),
+    ((1880, false, INV), &[Tv(1869)]),
+    ((1881, false, INV), &[Tv(1870)]),
+    ((1895, false, INV), &[Tv(1887)]),
+    ((1896, false, INV), &[Tv(1888)]),
+    ((1898, false, INV), &[Tv(1889)]),
+    ((1899, false, INV), &[Tv(1890)]),
+    ((1901, false, INV), &[Tv(1891)]),
+    ((1902, false, INV), &[Tv(1892)]),
+    ((1904, false, INV), &[Tv(1893)]),
+    ((1905, false, INV), &[Tv(1894)]),
+    ((1918, false, INV), &[Tv(1910)]),
+    ((1919, false, INV), &[Tv(1911)]),
+    ((1921, false, INV), &[Tv(1912)]),
+    ((1922, false, INV), &[Tv(1913)]),
+    ((1924, false, INV), &[Tv(1914)]),
+    ((1925, false, INV), &[Tv(1915)]),
+    ((1927, false, INV), &[Tv(1916)]),
+    ((1928, false, INV), &[Tv(1917)]),
+    ((1942, false, INV), &[Tv(1934)]),
+    ((1943, false, INV), &[Tv(1935)]),
+    ((1945, false, INV), &[Tv(1936)]),
+    ((1946, false, INV), &[Tv(1937)]),
+    ((1948, false, INV), &[Tv(1938)]),
+    ((1949, false, INV), &[Tv(1939)]),
+    ((1951, false, INV), &[Tv(1940)]),
+    ((1952, false, INV), &[Tv(1941)]),
+    ((1965, false, INV), &[Tv(1957)]),
+    ((1966, false, INV), &[Tv(1958)]),
+    ((1968, false, INV), &[Tv(1959)]),
+    ((1969, false, INV), &[Tv(1960)]),
+    ((1971, false, INV), &[Tv(1961)]),
+    ((1972, false, INV), &[Tv(1962)]),
+    ((1974, false, INV), &[Tv(1963)]),
+    ((1975, false, INV), &[Tv(1964)]),
+    ((1989, false, INV), &[Tv(1981)]),
+    ((1990, false, INV), &[Tv(1982)]),
+    ((1992, false, INV), &[Tv(1983)]),
+    ((1993, false, INV), &[Tv(1984)]),
+    ((1995, false, INV), &[Tv(1985)]),
+    ((1996, false, INV), &[Tv(1986)]),
+    ((1998, false, INV), &[Tv(1987)]),
+    ((1999, false, INV), &[Tv(1988)]),
+    ((2012, false, INV), &[Tv(2004)]),
+    ((2013, false, INV), &[Tv(2005)]),
+    ((2015, false, INV), &[Tv(2006)]),
+    ((2016, false, INV), &[Tv(2007)]),
+    ((2018, false, INV), &[Tv(2008)]),
+    ((2019, false, INV), &[Tv(2009)]),
+    ((2021, false, INV), &[Tv(2010)]),
+    ((2022, false, INV), &[Tv(2011)]),
+    ((2039, false, AND2), &[Tv(2035), Tv(2036)]),
+    ((2040, false, AND2), &[Tv(2037), Tv(2038)]),
+    ((2043, false, NAND2), &[Arg(0, 237), Tv(2042)]),
+    ((2047, false, NAND2), &[Arg(0, 238), Tv(2046)]),
+    ((2052, false, NAND2), &[Arg(0, 239), Tv(2051)]),
+    ((2056, false, NAND2), &[Arg(0, 240), Tv(2055)]),
+    ((2061, false, NAND2), &[Arg(0, 241), Tv(2060)]),
+    ((2065, false, NAND2), &[Arg(0, 242), Tv(2064)]),
+    ((2069, false, NAND2), &[Arg(0, 237), Tv(2050)]),
+    ((2077, false, AND2), &[Tv(2050), Tv(2059)]),
+    ((2081, false, AND2), &[Tv(2036), Tv(2037)]),
+    ((2085, false, AND2), &[Tv(2059), Tv(2068)]),
+    ((2101, false, XNOR2), &[Arg(0, 238), Tv(2093)]),
+    ((2157, false, INV), &[Tv(2149)]),
+    ((2158, false, INV), &[Tv(2150)]),
+    ((2160, false, INV), &[Tv(2151)]),
+    ((2161, false, INV), &[Tv(2152)]),
+    ((2163, false, INV), &[Tv(2153)]),
+    ((2164, false, INV), &[Tv(2154)]),
+    ((2166, false, INV), &[Tv(2155)]),
+    ((2167, false, INV), &[Tv(2156)]),
+    ((2180, false, INV), &[Tv(2172)]),
+    ((2181, false, INV), &[Tv(2173)]),
+    ((2183, false, INV), &[Tv(2174)]),
+    ((2184, false, INV), &[Tv(2175)]),
+    ((2186, false, INV), &[Tv(2176)]),
+    ((2187, false, INV), &[Tv(2177)]),
+    ((2189, false, INV), &[Tv(2178)]),
+    ((2190, false, INV), &[Tv(2179)]),
+    ((2204, false, INV), &[Tv(2196)]),
+    ((2205, false, INV), &[Tv(2197)]),
+    ((2207, false, INV), &[Tv(2198)]),
+    ((2208, false, INV), &[Tv(2199)]),
+    ((2210, false, INV), &[Tv(2200)]),
+    ((2211, false, INV), &[Tv(2201)]),
+    ((2213, false, INV), &[Tv(2202)]),
+    ((2214, false, INV), &[Tv(2203)]),
+    ((2227, false, INV), &[Tv(2219)]),
+    ((2228, false, INV), &[Tv(2220)]),
+    ((2230, false, INV), &[Tv(2221)]),
+    ((2231, false, INV), &[Tv(2222)]),
+    ((2233, false, INV), &[Tv(2223)]),
+    ((2234, false, INV), &[Tv(2224)]),
+    ((2236, false, INV), &[Tv(2225)]),
+    ((2237, false, INV), &[Tv(2226)]),
+    ((2251, false, INV), &[Tv(2243)]),
+    ((2252, false, INV), &[Tv(2244)]),
+    ((2254, false, INV), &[Tv(2245)]),
+    ((2255, false, INV), &[Tv(2246)]),
+    ((2257, false, INV), &[Tv(2247)]),
+    ((2258, false, INV), &[Tv(2248)]),
+    ((2260, false, INV), &[Tv(2249)]),
+    ((2261, false, INV), &[Tv(2250)]),
+    ((2274, false, INV), &[Tv(2266)]),
+    ((2275, false, INV), &[Tv(2267)]),
+    ((2277, false, INV), &[Tv(2268)]),
+    ((2278, false, INV), &[Tv(2269)]),
+    ((2280, false, INV), &[Tv(2270)]),
+    ((2281, false, INV), &[Tv(2271)]),
+    ((2283, false, INV), &[Tv(2272)]),
+    ((2284, false, INV), &[Tv(2273)]),
+    ((2298, false, INV), &[Tv(2290)]),
+    ((2299, false, INV), &[Tv(2291)]),
+    ((2301, false, INV), &[Tv(2292)]),
+    ((2302, false, INV), &[Tv(2293)]),
+    ((2304, false, INV), &[Tv(2294)]),
+    ((2305, false, INV), &[Tv(2295)]),
+    ((2307, false, INV), &[Tv(2296)]),
+    ((2308, false, INV), &[Tv(2297)]),
+    ((2321, false, INV), &[Tv(2313)]),
+    ((2322, false, INV), &[Tv(2314)]),
+    ((2324, false, INV), &[Tv(2315)]),
+    ((2325, false, INV), &[Tv(2316)]),
+    ((2327, false, INV), &[Tv(2317)]),
+    ((2328, false, INV), &[Tv(2318)]),
+    ((2330, false, INV), &[Tv(2319)]),
+    ((2331, false, INV), &[Tv(2320)]),
+    ((2348, false, AND2), &[Tv(2344), Tv(2345)]),
+    ((2349, false, AND2), &[Tv(2346), Tv(2347)]),
+    ((2352, false, NAND2), &[Arg(0, 286), Tv(2351)]),
+    ((2356, false, NAND2), &[Arg(0, 287), Tv(2355)]),
+    ((2361, false, NAND2), &[Arg(0, 288), Tv(2360)]),
+    ((2365, false, NAND2), &[Arg(0, 289), Tv(2364)]),
+    ((2370, false, NAND2), &[Arg(0, 290), Tv(2369)]),
+    ((2374, false, NAND2), &[Arg(0, 291), Tv(2373)]),
+    ((2378, false, NAND2), &[Arg(0, 286), Tv(2359)]),
+    ((2386, false, AND2), &[Tv(2359), Tv(2368)]),
+    ((2390, false, AND2), &[Tv(2345), Tv(2346)]),
+    ((2394, false, AND2), &[Tv(2368), Tv(2377)]),
+    ((2410, false, XNOR2), &[Arg(0, 287), Tv(2402)]),
+    ((2466, false, INV), &[Tv(2458)]),
+    ((2467, false, INV), &[Tv(2459)]),
+    ((2469, false, INV), &[Tv(2460)]),
+    ((2470, false, INV), &[Tv(2461)]),
+    ((2472, false, INV), &[Tv(2462)]),
+    ((2473, false, INV), &[Tv(2463)]),
+    ((2475, false, INV), &[Tv(2464)]),
+    ((2476, false, INV), &[Tv(2465)]),
+    ((2489, false, INV), &[Tv(2481)]),
+    ((2490, false, INV), &[Tv(2482)]),
+    ((2492, false, INV), &[Tv(2483)]),
+    ((2493, false, INV), &[Tv(2484)]),
+    ((2495, false, INV), &[Tv(2485)]),
+    ((2496, false, INV), &[Tv(2486)]),
+    ((2498, false, INV), &[Tv(2487)]),
+    ((2499, false, INV), &[Tv(2488)]),
+    ((2513, false, INV), &[Tv(2505)]),
+    ((2514, false, INV), &[Tv(2506)]),
+    ((2516, false, INV), &[Tv(2507)]),
+    ((2517, false, INV), &[Tv(2508)]),
+    ((2519, false, INV), &[Tv(2509)]),
+    ((2520, false, INV), &[Tv(2510)]),
+    ((2522, false, INV), &[Tv(2511)]),
+    ((2523, false, INV), &[Tv(2512)]),
+    ((2536, false, INV), &[Tv(2528)]),
+    ((2537, false, INV), &[Tv(2529)]),
+    ((2539, false, INV), &[Tv(2530)]),
+    ((2540, false, INV), &[Tv(2531)]),
+    ((2542, false, INV), &[Tv(2532)]),
+    ((2543, false, INV), &[Tv(2533)]),
+    ((2545, false, INV), &[Tv(2534)]),
+    ((2546, false, INV), &[Tv(2535)]),
+    ((2560, false, INV), &[Tv(2552)]),
+    ((2561, false, INV), &[Tv(2553)]),
+    ((2563, false, INV), &[Tv(2554)]),
+    ((2564, false, INV), &[Tv(2555)]),
+    ((2566, false, INV), &[Tv(2556)]),
+    ((2567, false, INV), &[Tv(2557)]),
+    ((2569, false, INV), &[Tv(2558)]),
+    ((2570, false, INV), &[Tv(2559)]),
+    ((2583, false, INV), &[Tv(2575)]),
+    ((2584, false, INV), &[Tv(2576)]),
+    ((2586, false, INV), &[Tv(2577)]),
+    ((2587, false, INV), &[Tv(2578)]),
+    ((2589, false, INV), &[Tv(2579)]),
+    ((2590, false, INV), &[Tv(2580)]),
+    ((2592, false, INV), &[Tv(2581)]),
+    ((2593, false, INV), &[Tv(2582)]),
+    ((2607, false, INV), &[Tv(2599)]),
+    ((2608, false, INV), &[Tv(2600)]),
+    ((2610, false, INV), &[Tv(2601)]),
+    ((2611, false, INV), &[Tv(2602)]),
+    ((2613, false, INV), &[Tv(2603)]),
+    ((2614, false, INV), &[Tv(2604)]),
+    ((2616, false, INV), &[Tv(2605)]),
+    ((2617, false, INV), &[Tv(2606)]),
+    ((2630, false, INV), &[Tv(2622)]),
+    ((2631, false, INV), &[Tv(2623)]),
+    ((2633, false, INV), &[Tv(2624)]),
+    ((2634, false, INV), &[Tv(2625)]),
+    ((2636, false, INV), &[Tv(2626)]),
+    ((2637, false, INV), &[Tv(2627)]),
+    ((2639, false, INV), &[Tv(2628)]),
+    ((2640, false, INV), &[Tv(2629)]),
+    ((2657, false, AND2), &[Tv(2653), Tv(2654)]),
+    ((2658, false, AND2), &[Tv(2655), Tv(2656)]),
+    ((2661, false, NAND2), &[Arg(0, 335), Tv(2660)]),
+    ((2665, false, NAND2), &[Arg(0, 336), Tv(2664)]),
+    ((2670, false, NAND2), &[Arg(0, 337), Tv(2669)]),
+    ((2674, false, NAND2), &[Arg(0, 338), Tv(2673)]),
+    ((2679, false, NAND2), &[Arg(0, 339), Tv(2678)]),
+    ((2683, false, NAND2), &[Arg(0, 340), Tv(2682)]),
+    ((2687, false, NAND2), &[Arg(0, 335), Tv(2668)]),
+    ((2695, false, AND2), &[Tv(2668), Tv(2677)]),
+    ((2699, false, AND2), &[Tv(2654), Tv(2655)]),
+    ((2703, false, AND2), &[Tv(2677), Tv(2686)]),
+    ((2719, false, XNOR2), &[Arg(0, 336), Tv(2711)]),
+    ((2775, false, INV), &[Tv(2767)]),
+    ((2776, false, INV), &[Tv(2768)]),
+    ((2778, false, INV), &[Tv(2769)]),
+    ((2779, false, INV), &[Tv(2770)]),
+    ((2781, false, INV), &[Tv(2771)]),
+    ((2782, false, INV), &[Tv(2772)]),
+    ((2784, false, INV), &[Tv(2773)]),
+    ((2785, false, INV), &[Tv(2774)]),
+    ((2798, false, INV), &[Tv(2790)]),
+    ((2799, false, INV), &[Tv(2791)]),
+    ((2801, false, INV), &[Tv(2792)]),
+    ((2802, false, INV), &[Tv(2793)]),
+    ((2804, false, INV), &[Tv(2794)]),
+    ((2805, false, INV), &[Tv(2795)]),
+    ((2807, false, INV), &[Tv(2796)]),
+    ((2808, false, INV), &[Tv(2797)]),
+    ((2822, false, INV), &[Tv(2814)]),
+    ((2823, false, INV), &[Tv(2815)]),
+    ((2825, false, INV), &[Tv(2816)]),
+    ((2826, false, INV), &[Tv(2817)]),
+    ((2828, false, INV), &[Tv(2818)]),
+    ((2829, false, INV), &[Tv(2819)]),
+    ((2831, false, INV), &[Tv(2820)]),
+    ((2832, false, INV), &[Tv(2821)]),
+    ((2845, false, INV), &[Tv(2837)]),
+    ((2846, false, INV), &[Tv(2838)]),
+    ((2848, false, INV), &[Tv(2839)]),
+    ((2849, false, INV), &[Tv(2840)]),
+    ((2851, false, INV), &[Tv(2841)]),
+    ((2852, false, INV), &[Tv(2842)]),
+    ((2854, false, INV), &[Tv(2843)]),
+    ((2855, false, INV), &[Tv(2844)]),
+    ((2869, false, INV), &[Tv(2861)]),
+    ((2870, false, INV), &[Tv(2862)]),
+    ((2872, false, INV), &[Tv(2863)]),
+    ((2873, false, INV), &[Tv(2864)]),
+    ((2875, false, INV), &[Tv(2865)]),
+    ((2876, false, INV), &[Tv(2866)]),
+    ((2878, false, INV), &[Tv(2867)]),
+    ((2879, false, INV), &[Tv(2868)]),
+    ((2892, false, INV), &[Tv(2884)]),
+    ((2893, false, INV), &[Tv(2885)]),
+    ((2895, false, INV), &[Tv(2886)]),
+    ((2896, false, INV), &[Tv(2887)]),
+    ((2898, false, INV), &[Tv(2888)]),
+    ((2899, false, INV), &[Tv(2889)]),
+    ((2901, false, INV), &[Tv(2890)]),
+    ((2902, false, INV), &[Tv(2891)]),
+    ((2916, false, INV), &[Tv(2908)]),
+    ((2917, false, INV), &[Tv(2909)]),
+    ((2919, false, INV), &[Tv(2910)]),
+    ((2920, false, INV), &[Tv(2911)]),
+    ((2922, false, INV), &[Tv(2912)]),
+    ((2923, false, INV), &[Tv(2913)]),
+    ((2925, false, INV), &[Tv(2914)]),
+    ((2926, false, INV), &[Tv(2915)]),
+    ((2939, false, INV), &[Tv(2931)]),
+    ((2940, false, INV), &[Tv(2932)]),
+    ((2942, false, INV), &[Tv(2933)]),
+    ((2943, false, INV), &[Tv(2934)]),
+    ((2945, false, INV), &[Tv(2935)]),
+    ((2946, false, INV), &[Tv(2936)]),
+    ((2948, false, INV), &[Tv(2937)]),
+    ((2949, false, INV), &[Tv(2938)]),
+    ((2966, false, AND2), &[Tv(2962), Tv(2963)]),
+    ((2967, false, AND2), &[Tv(2964), Tv(2965)]),
+    ((2970, false, NAND2), &[Arg(0, 384), Tv(2969)]),
+    ((2974, false, NAND2), &[Arg(0, 385), Tv(2973)]),
+    ((2979, false, NAND2), &[Arg(0, 386), Tv(2978)]),
+    ((2983, false, NAND2), &[Arg(0, 387), Tv(2982)]),
+    ((2988, false, NAND2), &[Arg(0, 388), Tv(2987)]),
+    ((2992, false, NAND2), &[Arg(0, 389), Tv(2991)]),
+    ((2996, false, NAND2), &[Arg(0, 384), Tv(2977)]),
+    ((3004, false, AND2), &[Tv(2977), Tv(2986)]),
+    ((3008, false, AND2), &[Tv(2963), Tv(2964)]),
+    ((3012, false, AND2), &[Tv(2986), Tv(2995)]),
+    ((3028, false, XNOR2), &[Arg(0, 385), Tv(3020)]),
+    ((3084, false, INV), &[Tv(3076)]),
+    ((3085, false, INV), &[Tv(3077)]),
+    ((3087, false, INV), &[Tv(3078)]),
+    ((3088, false, INV), &[Tv(3079)]),
+    ((3090, false, INV), &[Tv(3080)]),
+    ((3091, false, INV), &[Tv(3081)]),
+    ((3093, false, INV), &[Tv(3082)]),
+    ((3094, false, INV), &[Tv(3083)]),
+    ((3107, false, INV), &[Tv(3099)]),
+    ((3108, false, INV), &[Tv(3100)]),
+    ((3110, false, INV), &[Tv(3101)]),
+    ((3111, false, INV), &[Tv(3102)]),
+    ((3113, false, INV), &[Tv(3103)]),
+    ((3114, false, INV), &[Tv(3104)]),
+    ((3116, false, INV), &[Tv(3105)]),
+    ((3117, false, INV), &[Tv(3106)]),
+    ((3131, false, INV), &[Tv(3123)]),
+    ((3132, false, INV), &[Tv(3124)]),
+    ((3134, false, INV), &[Tv(3125)]),
+    ((3135, false, INV), &[Tv(3126)]),
+    ((3137, false, INV), &[Tv(3127)]),
+    ((3138, false, INV), &[Tv(3128)]),
+    ((3140, false, INV), &[Tv(3129)]),
+    ((3141, false, INV), &[Tv(3130)]),
+    ((3154, false, INV), &[Tv(3146)]),
+    ((3155, false, INV), &[Tv(3147)]),
+    ((3157, false, INV), &[Tv(3148)]),
+    ((3158, false, INV), &[Tv(3149)]),
+    ((3160, false, INV), &[Tv(3150)]),
+    ((3161, false, INV), &[Tv(3151)]),
+    ((3163, false, INV), &[Tv(3152)]),
+    ((3164, false, INV), &[Tv(3153)]),
+    ((3178, false, INV), &[Tv(3170)]),
+    ((3179, false, INV), &[Tv(3171)]),
+    ((3181, false, INV), &[Tv(3172)]),
+    ((3182, false, INV), &[Tv(3173)]),
+    ((3184, false, INV), &[Tv(3174)]),
+    ((3185, false, INV), &[Tv(3175)]),
+    ((3187, false, INV), &[Tv(3176)]),
+    ((3188, false, INV), &[Tv(3177)]),
+    ((3201, false, INV), &[Tv(3193)]),
+    ((3202, false, INV), &[Tv(3194)]),
+    ((3204, false, INV), &[Tv(3195)]),
+    ((3205, false, INV), &[Tv(3196)]),
+    ((3207, false, INV), &[Tv(3197)]),
+    ((3208, false, INV), &[Tv(3198)]),
+    ((3210, false, INV), &[Tv(3199)]),
+    ((3211, false, INV), &[Tv(3200)]),
+    ((3225, false, INV), &[Tv(3217)]),
+    ((3226, false, INV), &[Tv(3218)]),
+    ((3228, false, INV), &[Tv(3219)]),
+    ((3229, false, INV), &[Tv(3220)]),
+    ((3231, false, INV), &[Tv(3221)]),
+    ((3232, false, INV), &[Tv(3222)]),
+    ((3234, false, INV), &[Tv(3223)]),
+    ((3235, false, INV), &[Tv(3224)]),
+    ((3248, false, INV), &[Tv(3240)]),
+    ((3249, false, INV), &[Tv(3241)]),
+    ((3251, false, INV), &[Tv(3242)]),
+    ((3252, false, INV), &[Tv(3243)]),
+    ((3254, false, INV), &[Tv(3244)]),
+    ((3255, false, INV), &[Tv(3245)]),
+    ((3257, false, INV), &[Tv(3246)]),
+    ((3258, false, INV), &[Tv(3247)]),
+    ((3275, false, AND2), &[Tv(3271), Tv(3272)]),
+    ((3276, false, AND2), &[Tv(3273), Tv(3274)]),
+    ((3279, false, NAND2), &[Arg(0, 433), Tv(3278)]),
+    ((3283, false, NAND2), &[Arg(0, 434), Tv(3282)]),
+    ((3288, false, NAND2), &[Arg(0, 435), Tv(3287)]),
+    ((3292, false, NAND2), &[Arg(0, 436), Tv(3291)]),
+    ((3297, false, NAND2), &[Arg(0, 437), Tv(3296)]),
+    ((3301, false, NAND2), &[Arg(0, 438), Tv(3300)]),
+    ((3305, false, NAND2), &[Arg(0, 433), Tv(3286)]),
+    ((3313, false, AND2), &[Tv(3286), Tv(3295)]),
+    ((3317, false, AND2), &[Tv(3272), Tv(3273)]),
+    ((3321, false, AND2), &[Tv(3295), Tv(3304)]),
+    ((3337, false, XNOR2), &[Arg(0, 434), Tv(3329)]),
+    ((3393, false, INV), &[Tv(3385)]),
+    ((3394, false, INV), &[Tv(3386)]),
+    ((3396, false, INV), &[Tv(3387)]),
+    ((3397, false, INV), &[Tv(3388)]),
+    ((3399, false, INV), &[Tv(3389)]),
+    ((3400, false, INV), &[Tv(3390)]),
+    ((3402, false, INV), &[Tv(3391)]),
+    ((3403, false, INV), &[Tv(3392)]),
+    ((3416, false, INV), &[Tv(3408)]),
+    ((3417, false, INV), &[Tv(3409)]),
+    ((3419, false, INV), &[Tv(3410)]),
+    ((3420, false, INV), &[Tv(3411)]),
+    ((3422, false, INV), &[Tv(3412)]),
+    ((3423, false, INV), &[Tv(3413)]),
+    ((3425, false, INV), &[Tv(3414)]),
+    ((3426, false, INV), &[Tv(3415)]),
+    ((3440, false, INV), &[Tv(3432)]),
+    ((3441, false, INV), &[Tv(3433)]),
+    ((3443, false, INV), &[Tv(3434)]),
+    ((3444, false, INV), &[Tv(3435)]),
+    ((3446, false, INV), &[Tv(3436)]),
+    ((3447, false, INV), &[Tv(3437)]),
+    ((3449, false, INV), &[Tv(3438)]),
+    ((3450, false, INV), &[Tv(3439)]),
+    ((3463, false, INV), &[Tv(3455)]),
+    ((3464, false, INV), &[Tv(3456)]),
+    ((3466, false, INV), &[Tv(3457)]),
+    ((3467, false, INV), &[Tv(3458)]),
+    ((3469, false, INV), &[Tv(3459)]),
+    ((3470, false, INV), &[Tv(3460)]),
+    ((3472, false, INV), &[Tv(3461)]),
+    ((3473, false, INV), &[Tv(3462)]),
+    ((3487, false, INV), &[Tv(3479)]),
+    ((3488, false, INV), &[Tv(3480)]),
+    ((3490, false, INV), &[Tv(3481)]),
+    ((3491, false, INV), &[Tv(3482)]),
+    ((3493, false, INV), &[Tv(3483)]),
+    ((3494, false, INV), &[Tv(3484)]),
+    ((3496, false, INV), &[Tv(3485)]),
+    ((3497, false, INV), &[Tv(3486)]),
+    ((3510, false, INV), &[Tv(3502)]),
+    ((3511, false, INV), &[Tv(3503)]),
+    ((3513, false, INV), &[Tv(3504)]),
+    ((3514, false, INV), &[Tv(3505)]),
+    ((3516, false, INV), &[Tv(3506)]),
+    ((3517, false, INV), &[Tv(3507)]),
+    ((3519, false, INV), &[Tv(3508)]),
+    ((3520, false, INV), &[Tv(3509)]),
+    ((3534, false, INV), &[Tv(3526)]),
+    ((3535, false, INV), &[Tv(3527)]),
+    ((3537, false, INV), &[Tv(3528)]),
+    ((3538, false, INV), &[Tv(3529)]),
+    ((3540, false, INV), &[Tv(3530)]),
+    ((3541, false, INV), &[Tv(3531)]),
+    ((3543, false, INV), &[Tv(3532)]),
+    ((3544, false, INV), &[Tv(3533)]),
+    ((3557, false, INV), &[Tv(3549)]),
+    ((3558, false, INV), &[Tv(3550)]),
+    ((3560, false, INV), &[Tv(3551)]),
+    ((3561, false, INV), &[Tv(3552)]),
+    ((3563, false, INV), &[Tv(3553)]),
+    ((3564, false, INV), &[Tv(3554)]),
+    ((3566, false, INV), &[Tv(3555)]),
+    ((3567, false, INV), &[Tv(3556)]),
+    ((3584, false, AND2), &[Tv(3580), Tv(3581)]),
+    ((3585, false, AND2), &[Tv(3582), Tv(3583)]),
+    ((3588, false, NAND2), &[Arg(0, 482), Tv(3587)]),
+    ((3592, false, NAND2), &[Arg(0, 483), Tv(3591)]),
+    ((3597, false, NAND2), &[Arg(0, 484), Tv(3596)]),
+    ((3601, false, NAND2), &[Arg(0, 485), Tv(3600)]),
+    ((3606, false, NAND2), &[Arg(0, 486), Tv(3605)]),
+    ((3610, false, NAND2), &[Arg(0, 487), Tv(3609)]),
+    ((3614, false, NAND2), &[Arg(0, 482), Tv(3595)]),
+    ((3622, false, AND2), &[Tv(3595), Tv(3604)]),
+    ((3626, false, AND2), &[Tv(3581), Tv(3582)]),
+    ((3630, false, AND2), &[Tv(3604), Tv(3613)]),
+    ((3646, false, XNOR2), &[Arg(0, 483), Tv(3638)]),
+    ((3702, false, INV), &[Tv(3694)]),
+    ((3703, false, INV), &[Tv(3695)]),
+    ((3705, false, INV), &[Tv(3696)]),
+    ((3706, false, INV), &[Tv(3697)]),
+    ((3708, false, INV), &[Tv(3698)]),
+    ((3709, false, INV), &[Tv(3699)]),
+    ((3711, false, INV), &[Tv(3700)]),
+    ((3712, false, INV), &[Tv(3701)]),
+    ((3725, false, INV), &[Tv(3717)]),
+    ((3726, false, INV), &[Tv(3718)]),
+    ((3728, false, INV), &[Tv(3719)]),
+    ((3729, false, INV), &[Tv(3720)]),
+    ((3731, false, INV), &[Tv(3721)]),
+    ((3732, false, INV), &[Tv(3722)]),
+    ((3734, false, INV), &[Tv(3723)]),
+    ((3735, false, INV), &[Tv(3724)]),
+    ((3749, false, INV), &[Tv(3741)]),
+    ((3750, false, INV), &[Tv(3742)]),
+    ((3752, false, INV), &[Tv(3743)]),
+    ((3753, false, INV), &[Tv(3744)]),
+    ((3755, false, INV), &[Tv(3745)]),
+    ((3756, false, INV), &[Tv(3746)]),
+    ((3758, false, INV), &[Tv(3747)]),
+    ((3759, false, INV), &[Tv(3748)]),
+    ((3772, false, INV), &[Tv(3764)]),
+    ((3773, false, INV), &[Tv(3765)]),
+    ((3775, false, INV), &[Tv(3766)]),
+    ((3776, false, INV), &[Tv(3767)]),
+    ((3778, false, INV), &[Tv(3768)]),
+    ((3779, false, INV), &[Tv(3769)]),
+    ((3781, false, INV), &[Tv(3770)]),
+    ((3782, false, INV), &[Tv(3771)]),
+    ((3796, false, INV), &[Tv(3788)]),
+    ((3797, false, INV), &[Tv(3789)]),
+    ((3799, false, INV), &[Tv(3790)]),
+    ((3800, false, INV), &[Tv(3791)]),
+    ((3802, false, INV), &[Tv(3792)]),
+    ((3803, false, INV), &[Tv(3793)]),
+    ((3805, false, INV), &[Tv(3794)]),
+    ((3806, false, INV), &[Tv(3795)]),
+    ((3819, false, INV), &[Tv(3811)]),
+    ((3820, false, INV), &[Tv(3812)]),
+    ((3822, false, INV), &[Tv(3813)]),
+    ((3823, false, INV), &[Tv(3814)]),
+    ((3825, false, INV), &[Tv(3815)]),
+    ((3826, false, INV), &[Tv(3816)]),
+    ((3828, false, INV), &[Tv(3817)]),
+    ((3829, false, INV), &[Tv(3818)]),
+    ((3843, false, INV), &[Tv(3835)]),
+    ((3844, false, INV), &[Tv(3836)]),
+    ((3846, false, INV), &[Tv(3837)]),
+    ((3847, false, INV), &[Tv(3838)]),
+    ((3849, false, INV), &[Tv(3839)]),
+    ((3850, false, INV), &[Tv(3840)]),
+    ((3852, false, INV), &[Tv(3841)]),
+    ((3853, false, INV), &[Tv(3842)]),
+    ((3866, false, INV), &[Tv(3858)]),
+    ((3867, false, INV), &[Tv(3859)]),
+    ((3869, false, INV), &[Tv(3860)]),
+    ((3870, false, INV), &[Tv(3861)]),
+    ((3872, false, INV), &[Tv(3862)]),
+    ((3873, false, INV), &[Tv(3863)]),
+    ((3875, false, INV), &[Tv(3864)]),
+    ((3876, false, INV), &[Tv(3865)]),
+    ((3893, false, AND2), &[Tv(3889), Tv(3890)]),
+    ((3894, false, AND2), &[Tv(3891), Tv(3892)]),
+    ((3897, false, NAND2), &[Arg(0, 531), Tv(3896)]),
+    ((3901, false, NAND2), &[Arg(0, 532), Tv(3900)]),
+    ((3906, false, NAND2), &[Arg(0, 533), Tv(3905)]),
+    ((3910, false, NAND2), &[Arg(0, 534), Tv(3909)]),
+    ((3915, false, NAND2), &[Arg(0, 535), Tv(3914)]),
+    ((3919, false, NAND2), &[Arg(0, 536), Tv(3918)]),
+    ((3923, false, NAND2), &[Arg(0, 531), Tv(3904)]),
+    ((3931, false, AND2), &[Tv(3904), Tv(3913)]),
+    ((3935, false, AND2), &[Tv(3890), Tv(3891)]),
+    ((3939, false, AND2), &[Tv(3913), Tv(3922)]),
+    ((3955, false, XNOR2), &[Arg(0, 532), Tv(3947)]),
+    ((4011, false, INV), &[Tv(4003)]),
+    ((4012, false, INV), &[Tv(4004)]),
+    ((4014, false, INV), &[Tv(4005)]),
+    ((4015, false, INV), &[Tv(4006)]),
+    ((4017, false, INV), &[Tv(4007)]),
+    ((4018, false, INV), &[Tv(4008)]),
+    ((4020, false, INV), &[Tv(4009)]),
+    ((4021, false, INV), &[Tv(4010)]),
+    ((4034, false, INV), &[Tv(4026)]),
+    ((4035, false, INV), &[Tv(4027)]),
+    ((4037, false, INV), &[Tv(4028)]),
+    ((4038, false, INV), &[Tv(4029)]),
+    ((4040, false, INV), &[Tv(4030)]),
+    ((4041, false, INV), &[Tv(4031)]),
+    ((4043, false, INV), &[Tv(4032)]),
+    ((4044, false, INV), &[Tv(4033)]),
+    ((4058, false, INV), &[Tv(4050)]),
+    ((4059, false, INV), &[Tv(4051)]),
+    ((4061, false, INV), &[Tv(4052)]),
+    ((4062, false, INV), &[Tv(4053)]),
+    ((4064, false, INV), &[Tv(4054)]),
+    ((4065, false, INV), &[Tv(4055)]),
+    ((4067, false, INV), &[Tv(4056)]),
+    ((4068, false, INV), &[Tv(4057)]),
+    ((4081, false, INV), &[Tv(4073)]),
+    ((4082, false, INV), &[Tv(4074)]),
+    ((4084, false, INV), &[Tv(4075)]),
+    ((4085, false, INV), &[Tv(4076)]),
+    ((4087, false, INV), &[Tv(4077)]),
+    ((4088, false, INV), &[Tv(4078)]),
+    ((4090, false, INV), &[Tv(4079)]),
+    ((4091, false, INV), &[Tv(4080)]),
+    ((4105, false, INV), &[Tv(4097)]),
+    ((4106, false, INV), &[Tv(4098)]),
+    ((4108, false, INV), &[Tv(4099)]),
+    ((4109, false, INV), &[Tv(4100)]),
+    ((4111, false, INV), &[Tv(4101)]),
+    ((4112, false, INV), &[Tv(4102)]),
+    ((4114, false, INV), &[Tv(4103)]),
+    ((4115, false, INV), &[Tv(4104)]),
+    ((4128, false, INV), &[Tv(4120)]),
+    ((4129, false, INV), &[Tv(4121)]),
+    ((4131, false, INV), &[Tv(4122)]),
+    ((4132, false, INV), &[Tv(4123)]),
+    ((4134, false, INV), &[Tv(4124)]),
+    ((4135, false, INV), &[Tv(4125)]),
+    ((4137, false, INV), &[Tv(4126)]),
+    ((4138, false, INV), &[Tv(4127)]),
+    ((4152, false, INV), &[Tv(4144)]),
+    ((4153, false, INV), &[Tv(4145)]),
+    ((4155, false, INV), &[Tv(4146)]),
+    ((4156, false, INV), &[Tv(4147)]),
+    ((4158, false, INV), &[Tv(4148)]),
+    ((4159, false, INV), &[Tv(4149)]),
+    ((4161, false, INV), &[Tv(4150)]),
+    ((4162, false, INV), &[Tv(4151)]),
+    ((4175, false, INV), &[Tv(4167)]),
+    ((4176, false, INV), &[Tv(4168)]),
+    ((4178, false, INV), &[Tv(4169)]),
+    ((4179, false, INV), &[Tv(4170)]),
+    ((4181, false, INV), &[Tv(4171)]),
+    ((4182, false, INV), &[Tv(4172)]),
+    ((4184, false, INV), &[Tv(4173)]),
+    ((4185, false, INV), &[Tv(4174)]),
+    ((4202, false, AND2), &[Tv(4198), Tv(4199)]),
+    ((4203, false, AND2), &[Tv(4200), Tv(4201)]),
+    ((4206, false, NAND2), &[Arg(0, 580), Tv(4205)]),
+    ((4210, false, NAND2), &[Arg(0, 581), Tv(4209)]),
+    ((4215, false, NAND2), &[Arg(0, 582), Tv(4214)]),
+    ((4219, false, NAND2), &[Arg(0, 583), Tv(4218)]),
+    ((4224, false, NAND2), &[Arg(0, 584), Tv(4223)]),
+    ((4228, false, NAND2), &[Arg(0, 585), Tv(4227)]),
+    ((4232, false, NAND2), &[Arg(0, 580), Tv(4213)]),
+    ((4240, false, AND2), &[Tv(4213), Tv(4222)]),
+    ((4244, false, AND2), &[Tv(4199), Tv(4200)]),
+    ((4248, false, AND2), &[Tv(4222), Tv(4231)]),
+    ((4264, false, XNOR2), &[Arg(0, 581), Tv(4256)]),
+];
+
+static LEVEL_2: [((usize, bool, CellType), &[GateInput]); 492] = [
+    ((614, false, NOR2), &[Tv(612), Tv(613)]),
+    ((617, false, NOR2), &[Tv(615), Tv(616)]),
+    ((620, false, NOR2), &[Tv(618), Tv(619)]),
+    ((623, false, NOR2), &[Tv(621), Tv(622)]),
+    ((637, false, NOR2), &[Tv(635), Tv(636)]),
+    ((640, false, NOR2), &[Tv(638), Tv(639)]),
+    ((643, false, NOR2), &[Tv(641), Tv(642)]),
+    ((646, false, NOR2), &[Tv(644), Tv(645)]),
+    ((661, false, NOR2), &[Tv(659), Tv(660)]),
+    ((664, false, NOR2), &[Tv(662), Tv(663)]),
+    ((667, false, NOR2), &[Tv(665), Tv(666)]),
+    ((670, false, NOR2), &[Tv(668), Tv(669)]),
+    ((684, false, NOR2), &[Tv(682), Tv(683)]),
+    ((687, false, NOR2), &[Tv(685), Tv(686)]),
+    ((690, false, NOR2), &[Tv(688), Tv(689)]),
+    ((693, false, NOR2), &[Tv(691), Tv(692)]),
+    ((708, false, NOR2), &[Tv(706), Tv(707)]),
+    ((711, false, NOR2), &[Tv(709), Tv(710)]),
+    ((714, false, NOR2), &[Tv(712), Tv(713)]),
+    ((717, false, NOR2), &[Tv(715), Tv(716)]),
+    ((731, false, NOR2), &[Tv(729), Tv(730)]),
+    ((734, false, NOR2), &[Tv(732), Tv(733)]),
+    ((737, false, NOR2), &[Tv(735), Tv(736)]),
+    ((740, false, NOR2), &[Tv(738), Tv(739)]),
+    ((755, false, NOR2), &[Tv(753), Tv(754)]),
+    ((758, false, NOR2), &[Tv(756), Tv(757)]),
+    ((761, false, NOR2), &[Tv(759), Tv(760)]),
+    ((764, false, NOR2), &[Tv(762), Tv(763)]),
+    ((778, false, NOR2), &[Tv(776), Tv(777)]),
+    ((781, false, NOR2), &[Tv(779), Tv(780)]),
+    ((784, false, NOR2), &[Tv(782), Tv(783)]),
+    ((787, false, NOR2), &[Tv(785), Tv(786)]),
+    ((805, false, AND2), &[Tv(803), Tv(804)]),
+    ((808, false, INV), &[Tv(807)]),
+    ((812, false, INV), &[Tv(811)]),
+    ((817, false, INV), &[Tv(816)]),
+    ((821, false, INV), &[Tv(820)]),
+    ((826, false, INV), &[Tv(825)]),
+    ((830, false, INV), &[Tv(829)]),
+    ((850, false, NAND2), &[Arg(0, 41), Tv(841)]),
+    ((866, false, INV), &[Tv(865)]),
+    ((923, false, NOR2), &[Tv(921), Tv(922)]),
+    ((926, false, NOR2), &[Tv(924), Tv(925)]),
+    ((929, false, NOR2), &[Tv(927), Tv(928)]),
+    ((932, false, NOR2), &[Tv(930), Tv(931)]),
+    ((946, false, NOR2), &[Tv(944), Tv(945)]),
+    ((949, false, NOR2), &[Tv(947), Tv(948)]),
+    ((952, false, NOR2), &[Tv(950), Tv(951)]),
+    ((955, false, NOR2), &[Tv(953), Tv(954)]),
+    ((970, false, NOR2), &[Tv(968), Tv(969)]),
+    ((973, false, NOR2), &[Tv(971), Tv(972)]),
+    ((976, false, NOR2), &[Tv(974), Tv(975)]),
+    ((979, false, NOR2), &[Tv(977), Tv(978)]),
+    ((993, false, NOR2), &[Tv(991), Tv(992)]),
+    ((996, false, NOR2), &[Tv(994), Tv(995)]),
+    ((999, false, NOR2), &[Tv(997), Tv(998)]),
+    ((1002, false, NOR2), &[Tv(1000), Tv(1001)]),
+    ((1017, false, NOR2), &[Tv(1015), Tv(1016)]),
+    ((1020, false, NOR2), &[Tv(1018), Tv(1019)]),
+    ((1023, false, NOR2), &[Tv(1021), Tv(1022)]),
+    ((1026, false, NOR2), &[Tv(1024), Tv(1025)]),
+    ((1040, false, NOR2), &[Tv(1038), Tv(1039)]),
+    ((1043, false, NOR2), &[Tv(1041), Tv(1042)]),
+    ((1046, false, NOR2), &[Tv(1044), Tv(1045)]),
+    ((1049, false, NOR2), &[Tv(1047), Tv(1048)]),
+    ((1064, false, NOR2), &[Tv(1062), Tv(1063)]),
+    ((1067, false, NOR2), &[Tv(1065), Tv(1066)]),
+    ((1070, false, NOR2), &[Tv(1068), Tv(1069)]),
+    ((1073, false, NOR2), &[Tv(1071), Tv(1072)]),
+    ((1087, false, NOR2), &[Tv(1085), Tv(1086)]),
+    ((1090, false, NOR2), &[Tv(1088), Tv(1089)]),
+    ((1093, false, NOR2), &[Tv(1091), Tv(1092)]),
+    ((1096, false, NOR2), &[Tv(1094), Tv(1095)]),
+    ((1114, false, AND2), &[Tv(1112), Tv(1113)]),
+    ((1117, false, INV), &[Tv(1116)]),
+    ((1121, false, INV), &[Tv(1120)]),
+    ((1126, false, INV), &[Tv(1125)]),
+    ((1130, false, INV), &[Tv(1129)]),
+    ((1135, false, INV), &[Tv(1134)]),
+    ((1139, false, INV), &[Tv(1138)]),
+    ((1159, false, NAND2), &[Arg(0, 90), Tv(1150)]),
+    ((1175, false, INV), &[Tv(1174)]),
+    ((1232, false, NOR2), &[Tv(1230), Tv(1231)]),
+    ((1235, false, NOR2), &[Tv(1233), Tv(1234)]),
+    ((1238, false, NOR2), &[Tv(1236), Tv(1237)]),
+    ((1241, false, NOR2), &[Tv(1239), Tv(1240)]),
+    ((1255, false, NOR2), &[Tv(1253), Tv(1254)]),
+    ((1258, false, NOR2), &[Tv(1256), Tv(1257)]),
+    ((1261, false, NOR2), &[Tv(1259), Tv(1260)]),
+    ((1264, false, NOR2), &[Tv(1262), Tv(1263)]),
+    ((1279, false, NOR2), &[Tv(1277), Tv(1278)]),
+    ((1282, false, NOR2), &[Tv(1280), Tv(1281)]),
+    ((1285, false, NOR2), &[Tv(1283), Tv(1284)]),
+    ((1288, false, NOR2), &[Tv(1286), Tv(1287)]),
+    ((1302, false, NOR2), &[Tv(1300), Tv(1301)]),
+    ((1305, false, NOR2), &[Tv(1303), Tv(1304)]),
+    ((1308, false, NOR2), &[Tv(1306), Tv(1307)]),
+    ((1311, false, NOR2), &[Tv(1309), Tv(1310)]),
+    ((1326, false, NOR2), &[Tv(1324), Tv(1325)]),
+    ((1329, false, NOR2), &[Tv(1327), Tv(1328)]),
+    ((1332, false, NOR2), &[Tv(1330), Tv(1331)]),
+    ((1335, false, NOR2), &[Tv(1333), Tv(1334)]),
+    ((1349, false, NOR2), &[Tv(1347), Tv(1348)]),
+    ((1352, false, NOR2), &[Tv(1350), Tv(1351)]),
+    ((1355, false, NOR2), &[Tv(1353), Tv(1354)]),
+    ((1358, false, NOR2), &[Tv(1356), Tv(1357)]),
+    ((1373, false, NOR2), &[Tv(1371), Tv(1372)]),
+    ((1376, false, NOR2), &[Tv(1374), Tv(1375)]),
+    ((1379, false, NOR2), &[Tv(1377), Tv(1378)]),
+    ((1382, false, NOR2), &[Tv(1380), Tv(1381)]),
+    ((1396, false, NOR2), &[Tv(1394), Tv(1395)]),
+    ((1399, false, NOR2), &[Tv(1397), Tv(1398)]),
+    ((1402, false, NOR2), &[Tv(1400), Tv(1401)]),
+    ((1405, false, NOR2), &[Tv(1403), Tv(1404)]),
+    ((1423, false, AND2), &[Tv(1421), Tv(1422)]),
+    ((1426, false, INV), &[Tv(1425)]),
+    ((1430, false, INV), &[Tv(1429)]),
+    ((1435, false, INV), &[Tv(1434)]),
+    ((1439, false, INV), &[Tv(1438)]),
+    ((1444, false, INV), &[Tv(1443)]),
+    ((1448, false, INV), &[Tv(1447)]),
+    ((1468, false, NAND2), &[Arg(0, 139), Tv(1459)]),
+    ((1484, false, INV), &[Tv(1483)]),
+    ((1541, false, NOR2), &[Tv(1539), Tv(1540)]),
+    ((1544, false, NOR2), &[Tv(1542), Tv(1543)]),
+    ((1547, false, NOR2), &[Tv(1545), Tv(1546)]),
+    ((1550, false, NOR2), &[Tv(1548), Tv(1549)]),
+    ((1564, false, NOR2), &[Tv(1562), Tv(1563)]),
+    ((1567, false, NOR2), &[Tv(1565), Tv(1566)]),
+    ((1570, false, NOR2), &[Tv(1568), Tv(1569)]),
+    ((1573, false, NOR2), &[Tv(1571), Tv(1572)]),
+    ((1588, false, NOR2), &[Tv(1586), Tv(1587)]),
+    ((1591, false, NOR2), &[Tv(1589), Tv(1590)]),
+    ((1594, false, NOR2), &[Tv(1592), Tv(1593)]),
+    ((1597, false, NOR2), &[Tv(1595), Tv(1596)]),
+    ((1611, false, NOR2), &[Tv(1609), Tv(1610)]),
+    ((1614, false, NOR2), &[Tv(1612), Tv(1613)]),
+    ((1617, false, NOR2), &[Tv(1615), Tv(1616)]),
+    ((1620, false, NOR2), &[Tv(1618), Tv(1619)]),
+    ((1635, false, NOR2), &[Tv(1633), Tv(1634)]),
+    ((1638, false, NOR2), &[Tv(1636), Tv(1637)]),
+    ((1641, false, NOR2), &[Tv(1639), Tv(1640)]),
+    ((1644, false, NOR2), &[Tv(1642), Tv(1643)]),
+    ((1658, false, NOR2), &[Tv(1656), Tv(1657)]),
+    ((1661, false, NOR2), &[Tv(1659), Tv(1660)]),
+    ((1664, false, NOR2), &[Tv(1662), Tv(1663)]),
+    ((1667, false, NOR2), &[Tv(1665), Tv(1666)]),
+    ((1682, false, NOR2), &[Tv(1680), Tv(1681)]),
+    ((1685, false, NOR2), &[Tv(1683), Tv(1684)]),
+    ((1688, false, NOR2), &[Tv(1686), Tv(1687)]),
+    ((1691, false, NOR2), &[Tv(1689), Tv(1690)]),
+    ((1705, false, NOR2), &[Tv(1703), Tv(1704)]),
+    ((1708, false, NOR2), &[Tv(1706), Tv(1707)]),
+    ((1711, false, NOR2), &[Tv(1709), Tv(1710)]),
+    ((1714, false, NOR2), &[Tv(1712), Tv(1713)]),
+    ((1732, false, AND2), &[Tv(1730), Tv(1731)]),
+    ((1735, false, INV), &[Tv(1734)]),
+    ((1739, false, INV), &[Tv(1738)]),
+    ((1744, false, INV), &[Tv(1743)]),
+    ((1748, false, INV), &[Tv(1747)]),
+    ((1753, false, INV), &[Tv(1752)]),
+    ((1757, false, INV), &[Tv(1756)]),
+    ((1777, false, NAND2), &[Arg(0, 188), Tv(1768)]),
+    ((1793, false, INV), &[Tv(1792)]),
+    ((1850, false, NOR2), &[Tv(1848), Tv(1849)]),
+    ((1853, false, NOR2), &[Tv(1851), Tv(1852)]),
+    ((1856, false, NOR2), &[Tv(1854), Tv(1855)]),
+    ((1859, false, NOR2), &[Tv(1857), Tv(1858)]),
+    ((1873, false, NOR2), &[Tv(1871), Tv(1872)]),
+    ((1876, false, NOR2), &[Tv(1874), Tv(1875)]),
+    ((1879, false, NOR2), &[Tv(1877), Tv(1878)]),
+    ((1882, false, NOR2), &[Tv(1880), Tv(1881)]),
+    ((1897, false, NOR2), &[Tv(1895), Tv(1896)]),
+    ((1900, false, NOR2), &[Tv(1898), Tv(1899)]),
+    ((1903, false, NOR2), &[Tv(1901), Tv(1902)]),
+    ((1906, false, NOR2), &[Tv(1904), Tv(1905)]),
+    ((1920, false, NOR2), &[Tv(1918), Tv(1919)]),
+    ((1923, false, NOR2), &[Tv(1921), Tv(1922)]),
+    ((1926, false, NOR2), &[Tv(1924), Tv(1925)]),
+    ((1929, false, NOR2), &[Tv(1927), Tv(1928)]),
+    ((1944, false, NOR2), &[Tv(1942), Tv(1943)]),
+    ((1947, false, NOR2), &[Tv(1945), Tv(1946)]),
+    ((1950, false, NOR2), &[Tv(1948), Tv(1949)]),
+    ((1953, false, NOR2), &[Tv(1951), Tv(1952)]),
+    ((1967, false, NOR2), &[Tv(1965), Tv(1966)]),
+    ((1970, false, NOR2), &[Tv(1968), Tv(1969)]),
+    ((1973, false, NOR2), &[Tv(1971), Tv(1972)]),
+    ((1976, false, NOR2), &[Tv(1974), Tv(1975)]),
+    ((1991, false, NOR2), &[Tv(1989), Tv(1990)]),
+    ((1994, false, NOR2), &[Tv(1992), Tv(1993)]),
+    ((1997, false, NOR2), &[Tv(1995), Tv(1996)]),
+    ((2000, false, NOR2), &[Tv(1998), Tv(1999)]),
+    ((2014, false, NOR2), &[Tv(2012), Tv(2013)]),
+    ((2017, false, NOR2), &[Tv(2015), Tv(2016)]),
+    ((2020, false, NOR2), &[Tv(2018), Tv(2019)]),
+    ((2023, false, NOR2), &[Tv(2021), Tv(2022)]),
+    ((2041, false, AND2), &[Tv(2039), Tv(2040)]),
+    ((2044, false, INV), &[Tv(2043)]),
+    ((2048, false, INV), &[Tv(2047)]),
+    ((2053, false, INV), &[Tv(2052)]),
+    ((2057, false, INV), &[Tv(2056)]),
+    ((2062, false, INV), &[Tv(2061)]),
+    ((2066, false, INV), &[Tv(2065)]),
+    ((2086, false, NAND2), &[Arg(0, 237), Tv(2077)]),
+    ((2102, false, INV), &[Tv(2101)]),
+    ((2159, false, NOR2), &[Tv(2157), Tv(2158)]),
+    ((2162, false, NOR2), &[Tv(2160), Tv(2161)]),
+    ((2165, false, NOR2), &[Tv(2163), Tv(2164)]),
+    ((2168, false, NOR2), &[Tv(2166), Tv(2167)]),
+    ((2182, false, NOR2), &[Tv(2180), Tv(2181)]),
+    ((2185, false, NOR2), &[Tv(2183), Tv(2184)]),
+    ((2188, false, NOR2), &[Tv(2186), Tv(2187)]),
+    ((2191, false, NOR2), &[Tv(2189), Tv(2190)]),
+    ((2206, false, NOR2), &[Tv(2204), Tv(2205)]),
+    ((2209, false, NOR2), &[Tv(2207), Tv(2208)]),
+    ((2212, false, NOR2), &[Tv(2210), Tv(2211)]),
+    ((2215, false, NOR2), &[Tv(2213), Tv(2214)]),
+    ((2229, false, NOR2), &[Tv(2227), Tv(2228)]),
+    ((2232, false, NOR2), &[Tv(2230), Tv(2231)]),
+    ((2235, false, NOR2), &[Tv(2233), Tv(2234)]),
+    ((2238, false, NOR2), &[Tv(2236), Tv(2237)]),
+    ((2253, false, NOR2), &[Tv(2251), Tv(2252)]),
+    ((2256, false, NOR2), &[Tv(2254), Tv(2255)]),
+    ((2259, false, NOR2), &[Tv(2257), Tv(2258)]),
+    ((2262, false, NOR2), &[Tv(2260), Tv(2261)]),
+    ((2276, false, NOR2), &[Tv(2274), Tv(2275)]),
+    ((2279, false, NOR2), &[Tv(2277), Tv(2278)]),
+    ((2282, false, NOR2), &[Tv(2280), Tv(2281)]),
+    ((2285, false, NOR2), &[Tv(2283), Tv(2284)]),
+    ((2300, false, NOR2), &[Tv(2298), Tv(2299)]),
+    ((2303, false, NOR2), &[Tv(2301), Tv(2302)]),
+    ((2306, false, NOR2), &[Tv(2304), Tv(2305)]),
+    ((2309, false, NOR2), &[Tv(2307), Tv(2308)]),
+    ((2323, false, NOR2), &[Tv(2321), Tv(2322)]),
+    ((2326, false, NOR2), &[Tv(2324), Tv(2325)]),
+    ((2329, false, NOR2), &[Tv(2327), Tv(2328)]),
+    ((2332, false, NOR2), &[Tv(2330), Tv(2331)]),
+    ((2350, false, AND2), &[Tv(2348), Tv(2349)]),
+    ((2353, false, INV), &[Tv(2352)]),
+    ((2357, false, INV), &[Tv(2356)]),
+    ((2362, false, INV), &[Tv(2361)]),
+    ((2366, false, INV), &[Tv(2365)]),
+    ((2371, false, INV), &[Tv(2370)]),
+    ((2375, false, INV), &[Tv(2374)]),
+    ((2395, false, NAND2), &[Arg(0, 286), Tv(2386)]),
+    ((2411, false, INV), &[Tv(2410)]),
+    ((2468, false, NOR2), &[Tv(2466), Tv(2467)]),
+    ((2471, false, NOR2), &[Tv(2469), Tv(2470)]),
+    ((2474, false, NOR2), &[Tv(2472), Tv(2473)]),
+    ((2477, false, NOR2), &[Tv(2475), Tv(2476)]),
+    ((2491, false, NOR2), &[Tv(2489), Tv(2490)]),
+    ((2494, false, NOR2), &[Tv(2492), Tv(2493)]),
+    ((2497, false, NOR2), &[Tv(2495), Tv(2496)]),
+    ((2500, false, NOR2), &[Tv(2498), Tv(2499)]),
+    ((2515, false, NOR2), &[Tv(2513), Tv(2514)]),
+    ((2518, false, NOR2), &[Tv(2516), Tv(2517)]),
+    ((2521, false, NOR2), &[Tv(2519), Tv(2520)]),
+    ((2524, false, NOR2), &[Tv(2522), Tv(2523)]),
+    ((2538, false, NOR2), &[Tv(2536), Tv(2537)]),
+    ((2541, false, NOR2), &[Tv(2539), Tv(2540)]),
+    ((2544, false, NOR2), &[Tv(2542), Tv(2543)]),
+    ((2547, false, NOR2), &[Tv(2545), Tv(2546)]),
+    ((2562, false, NOR2), &[Tv(2560), Tv(2561)]),
+    ((2565, false, NOR2), &[Tv(2563), Tv(2564)]),
+    ((2568, false, NOR2), &[Tv(2566), Tv(2567)]),
+    ((2571, false, NOR2), &[Tv(2569), Tv(2570)]),
+    ((2585, false, NOR2), &[Tv(2583), Tv(2584)]),
+    ((2588, false, NOR2), &[Tv(2586), Tv(2587)]),
+    ((2591, false, NOR2), &[Tv(2589), Tv(2590)]),
+    ((2594, false, NOR2), &[Tv(2592), Tv(2593)]),
+    ((2609, false, NOR2), &[Tv(2607), Tv(2608)]),
+    ((2612, false, NOR2), &[Tv(2610), Tv(2611)]),
+    ((2615, false, NOR2), &[Tv(2613), Tv(2614)]),
+    ((2618, false, NOR2), &[Tv(2616), Tv(2617)]),
+    ((2632, false, NOR2), &[Tv(2630), Tv(2631)]),
+    ((2635, false, NOR2), &[Tv(2633), Tv(2634)]),
+    ((2638, false, NOR2), &[Tv(2636), Tv(2637)]),
+    ((2641, false, NOR2), &[Tv(2639), Tv(2640)]),
+    ((2659, false, AND2), &[Tv(2657), Tv(2658)]),
+    ((2662, false, INV), &[Tv(2661)]),
+    ((2666, false, INV), &[Tv(2665)]),
+    ((2671, false, INV), &[Tv(2670)]),
+    ((2675, false, INV), &[Tv(2674)]),
+    ((2680, false, INV), &[Tv(2679)]),
+    ((2684, false, INV), &[Tv(2683)]),
+    ((2704, false, NAND2), &[Arg(0, 335), Tv(2695)]),
+    ((2720, false, INV), &[Tv(2719)]),
+    ((2777, false, NOR2), &[Tv(2775), Tv(2776)]),
+    ((2780, false, NOR2), &[Tv(2778), Tv(2779)]),
+    ((2783, false, NOR2), &[Tv(2781), Tv(2782)]),
+    ((2786, false, NOR2), &[Tv(2784), Tv(2785)]),
+    ((2800, false, NOR2), &[Tv(2798), Tv(2799)]),
+    ((2803, false, NOR2), &[Tv(2801), Tv(2802)]),
+    ((2806, false, NOR2), &[Tv(2804), Tv(2805)]),
+    ((2809, false, NOR2), &[Tv(2807), Tv(2808)]),
+    ((2824, false, NOR2), &[Tv(2822), Tv(2823)]),
+    ((2827, false, NOR2), &[Tv(2825), Tv(2826)]),
+    ((2830, false, NOR2), &[Tv(2828), Tv(2829)]),
+    ((2833, false, NOR2), &[Tv(2831), Tv(2832)]),
+    ((2847, false, NOR2), &[Tv(2845), Tv(2846)]),
+    ((2850, false, NOR2), &[Tv(2848), Tv(2849)]),
+    ((2853, false, NOR2), &[Tv(2851), Tv(2852)]),
+    ((2856, false, NOR2), &[Tv(2854), Tv(2855)]),
+    ((2871, false, NOR2), &[Tv(2869), Tv(2870)]),
+    ((2874, false, NOR2), &[Tv(2872), Tv(2873)]),
+    ((2877, false, NOR2), &[Tv(2875), Tv(2876)]),
+    ((2880, false, NOR2), &[Tv(2878), Tv(2879)]),
+    ((2894, false, NOR2), &[Tv(2892), Tv(2893)]),
+    ((2897, false, NOR2), &[Tv(2895), Tv(2896)]),
+    ((2900, false, NOR2), &[Tv(2898), Tv(2899)]),
+    ((2903, false, NOR2), &[Tv(2901), Tv(2902)]),
+    ((2918, false, NOR2), &[Tv(2916), Tv(2917)]),
+    ((2921, false, NOR2), &[Tv(2919), Tv(2920)]),
+    ((2924, false, NOR2), &[Tv(2922), Tv(2923)]),
+    ((2927, false, NOR2), &[Tv(2925), Tv(2926)]),
+    ((2941, false, NOR2), &[Tv(2939), Tv(2940)]),
+    ((2944, false, NOR2), &[Tv(2942), Tv(2943)]),
+    ((2947, false, NOR2), &[Tv(2945), Tv(2946)]),
+    ((2950, false, NOR2), &[Tv(2948), Tv(2949)]),
+    ((2968, false, AND2), &[Tv(2966), Tv(2967)]),
+    ((2971, false, INV), &[Tv(2970)]),
+    ((2975, false, INV), &[Tv(2974)]),
+    ((2980, false, INV), &[Tv(2979)]),
+    ((2984, false, INV), &[Tv(2983)]),
+    ((2989, false, INV), &[Tv(2988)]),
+    ((2993, false, INV), &[Tv(2992)]),
+    ((3013, false, NAND2), &[Arg(0, 384), Tv(3004)]),
+    ((3029, false, INV), &[Tv(3028)]),
+    ((3086, false, NOR2), &[Tv(3084), Tv(3085)]),
+    ((3089, false, NOR2), &[Tv(3087), Tv(3088)]),
+    ((3092, false, NOR2), &[Tv(3090), Tv(3091)]),
+    ((3095, false, NOR2), &[Tv(3093), Tv(3094)]),
+    ((3109, false, NOR2), &[Tv(3107), Tv(3108)]),
+    ((3112, false, NOR2), &[Tv(3110), Tv(3111)]),
+    ((3115, false, NOR2), &[Tv(3113), Tv(3114)]),
+    ((3118, false, NOR2), &[Tv(3116), Tv(3117)]),
+    ((3133, false, NOR2), &[Tv(3131), Tv(3132)]),
+    ((3136, false, NOR2), &[Tv(3134), Tv(3135)]),
+    ((3139, false, NOR2), &[Tv(3137), Tv(3138)]),
+    ((3142, false, NOR2), &[Tv(3140), Tv(3141)]),
+    ((3156, false, NOR2), &[Tv(3154), Tv(3155)]),
+    ((3159, false, NOR2), &[Tv(3157), Tv(3158)]),
+    ((3162, false, NOR2), &[Tv(3160), Tv(3161)]),
+    ((3165, false, NOR2), &[Tv(3163), Tv(3164)]),
+    ((3180, false, NOR2), &[Tv(3178), Tv(3179)]),
+    ((3183, false, NOR2), &[Tv(3181), Tv(3182)]),
+    ((3186, false, NOR2), &[Tv(3184), Tv(3185)]),
+    ((3189, false, NOR2), &[Tv(3187), Tv(3188)]),
+    ((3203, false, NOR2), &[Tv(3201), Tv(3202)]),
+    ((3206, false, NOR2), &[Tv(3204), Tv(3205)]),
+    ((3209, false, NOR2), &[Tv(3207), Tv(3208)]),
+    ((3212, false, NOR2), &[Tv(3210), Tv(3211)]),
+    ((3227, false, NOR2), &[Tv(3225), Tv(3226)]),
+    ((3230, false, NOR2), &[Tv(3228), Tv(3229)]),
+    ((3233, false, NOR2), &[Tv(3231), Tv(3232)]),
+    ((3236, false, NOR2), &[Tv(3234), Tv(3235)]),
+    ((3250, false, NOR2), &[Tv(3248), Tv(3249)]),
+    ((3253, false, NOR2), &[Tv(3251), Tv(3252)]),
+    ((3256, false, NOR2), &[Tv(3254), Tv(3255)]),
+    ((3259, false, NOR2), &[Tv(3257), Tv(3258)]),
+    ((3277, false, AND2), &[Tv(3275), Tv(3276)]),
+    ((3280, false, INV), &[Tv(3279)]),
+    ((3284, false, INV), &[Tv(3283)]),
+    ((3289, false, INV), &[Tv(3288)]),
+    ((3293, false, INV), &[Tv(3292)]),
+    ((3298, false, INV), &[Tv(3297)]),
+    ((3302, false, INV), &[Tv(3301)]),
+    ((3322, false, NAND2), &[Arg(0, 433), Tv(3313)]),
+    ((3338, false, INV), &[Tv(3337)]),
+    ((3395, false, NOR2), &[Tv(3393), Tv(3394)]),
+    ((3398, false, NOR2), &[Tv(3396), Tv(3397)]),
+    ((3401, false, NOR2), &[Tv(3399), Tv(3400)]),
+    ((3404, false, NOR2), &[Tv(3402), Tv(3403)]),
+    ((3418, false, NOR2), &[Tv(3416), Tv(3417)]),
+    ((3421, false, NOR2), &[Tv(3419), Tv(3420)]),
+    ((3424, false, NOR2), &[Tv(3422), Tv(3423)]),
+    ((3427, false, NOR2), &[Tv(3425), Tv(3426)]),
+    ((3442, false, NOR2), &[Tv(3440), Tv(3441)]),
+    ((3445, false, NOR2), &[Tv(3443), Tv(3444)]),
+    ((3448, false, NOR2), &[Tv(3446), Tv(3447)]),
+    ((3451, false, NOR2), &[Tv(3449), Tv(3450)]),
+    ((3465, false, NOR2), &[Tv(3463), Tv(3464)]),
+    ((3468, false, NOR2), &[Tv(3466), Tv(3467)]),
+    ((3471, false, NOR2), &[Tv(3469), Tv(3470)]),
+    ((3474, false, NOR2), &[Tv(3472), Tv(3473)]),
+    ((3489, false, NOR2), &[Tv(3487), Tv(3488)]),
+    ((3492, false, NOR2), &[Tv(3490), Tv(3491)]),
+    ((3495, false, NOR2), &[Tv(3493), Tv(3494)]),
+    ((3498, false, NOR2), &[Tv(3496), Tv(3497)]),
+    ((3512, false, NOR2), &[Tv(3510), Tv(3511)]),
+    ((3515, false, NOR2), &[Tv(3513), Tv(3514)]),
+    ((3518, false, NOR2), &[Tv(3516), Tv(3517)]),
+    ((3521, false, NOR2), &[Tv(3519), Tv(3520)]),
+    ((3536, false, NOR2), &[Tv(3534), Tv(3535)]),
+    ((3539, false, NOR2), &[Tv(3537), Tv(3538)]),
+    ((3542, false, NOR2), &[Tv(3540), Tv(3541)]),
+    ((3545, false, NOR2), &[Tv(3543), Tv(3544)]),
+    ((3559, false, NOR2), &[Tv(3557), Tv(3558)]),
+    ((3562, false, NOR2), &[Tv(3560), Tv(3561)]),
+    ((3565, false, NOR2), &[Tv(3563), Tv(3564)]),
+    ((3568, false, NOR2), &[Tv(3566), Tv(3567)]),
+    ((3586, false, AND2), &[Tv(3584), Tv(3585)]),
+    ((3589, false, INV), &[Tv(3588)]),
+    ((3593, false, INV), &[Tv(3592)]),
+    ((3598, false, INV), &[Tv(3597)]),
+    ((3602, false, INV), &[Tv(3601)]),
+    ((3607, false, INV), &[Tv(3606)]),
+    ((3611, false, INV), &[Tv(3610)]),
+    ((3631, false, NAND2), &[Arg(0, 482), Tv(3622)]),
+    ((3647, false, INV), &[Tv(3646)]),
+    ((3704, false, NOR2), &[Tv(3702), Tv(3703)]),
+    ((3707, false, NOR2), &[Tv(3705), Tv(3706)]),
+    ((3710, false, NOR2), &[Tv(3708), Tv(3709)]),
+    ((3713, false, NOR2), &[Tv(3711), Tv(3712)]),
+    ((3727, false, NOR2), &[Tv(3725), Tv(3726)]),
+    ((3730, false, NOR2), &[Tv(3728), Tv(3729)]),
+    ((3733, false, NOR2), &[Tv(3731), Tv(3732)]),
+    ((3736, false, NOR2), &[Tv(3734), Tv(3735)]),
+    ((3751, false, NOR2), &[Tv(3749), Tv(3750)]),
+    ((3754, false, NOR2), &[Tv(3752), Tv(3753)]),
+    ((3757, false, NOR2), &[Tv(3755), Tv(3756)]),
+    ((3760, false, NOR2), &[Tv(3758), Tv(3759)]),
+    ((3774, false, NOR2), &[Tv(3772), Tv(3773)]),
+    ((3777, false, NOR2), &[Tv(3775), Tv(3776)]),
+    ((3780, false, NOR2), &[Tv(3778), Tv(3779)]),
+    ((3783, false, NOR2), &[Tv(3781), Tv(3782)]),
+    ((3798, false, NOR2), &[Tv(3796), Tv(3797)]),
+    ((3801, false, NOR2), &[Tv(3799), Tv(3800)]),
+    ((3804, false, NOR2), &[Tv(3802), Tv(3803)]),
+    ((3807, false, NOR2), &[Tv(3805), Tv(3806)]),
+    ((3821, false, NOR2), &[Tv(3819), Tv(3820)]),
+    ((3824, false, NOR2), &[Tv(3822), Tv(3823)]),
+    ((3827, false, NOR2), &[Tv(3825), Tv(3826)]),
+    ((3830, false, NOR2), &[Tv(3828), Tv(3829)]),
+    ((3845, false, NOR2), &[Tv(3843), Tv(3844)]),
+    ((3848, false, NOR2), &[Tv(3846), Tv(3847)]),
+    ((3851, false, NOR2), &[Tv(3849), Tv(3850)]),
+    ((3854, false, NOR2), &[Tv(3852), Tv(3853)]),
+    ((3868, false, NOR2), &[Tv(3866), Tv(3867)]),
+    ((3871, false, NOR2), &[Tv(3869), Tv(3870)]),
+    ((3874, false, NOR2), &[Tv(3872), Tv(3873)]),
+    ((3877, false, NOR2), &[Tv(3875), Tv(3876)]),
+    ((3895, false, AND2), &[Tv(3893), Tv(3894)]),
+    ((3898, false, INV), &[Tv(3897)]),
+    ((3902, false, INV), &[Tv(3901)]),
+    ((3907, false, INV), &[Tv(3906)]),
+    ((3911, false, INV), &[Tv(3910)]),
+    ((3916, false, INV), &[Tv(3915)]),
+    ((3920, false, INV), &[Tv(3919)]),
+    ((3940, false, NAND2), &[Arg(0, 531), Tv(3931)]),
+    ((3956, false, INV), &[Tv(3955)]),
+    ((4013, false, NOR2), &[Tv(4011), Tv(4012)]),
+    ((4016, false, NOR2), &[Tv(4014), Tv(4015)]),
+    ((4019, false, NOR2), &[Tv(4017), Tv(4018)]),
+    ((4022, false, NOR2), &[Tv(4020), Tv(4021)]),
+    ((4036, false, NOR2), &[Tv(4034), Tv(4035)]),
+    ((4039, false, NOR2), &[Tv(4037), Tv(4038)]),
+    ((4042, false, NOR2), &[Tv(4040), Tv(4041)]),
+    ((4045, false, NOR2), &[Tv(4043), Tv(4044)]),
+    ((4060, false, NOR2), &[Tv(4058), Tv(4059)]),
+    ((4063, false, NOR2), &[Tv(4061), Tv(4062)]),
+    ((4066, false, NOR2), &[Tv(4064), Tv(4065)]),
+    ((4069, false, NOR2), &[Tv(4067), Tv(4068)]),
+    ((4083, false, NOR2), &[Tv(4081), Tv(4082)]),
+    ((4086, false, NOR2), &[Tv(4084), Tv(4085)]),
+    ((4089, false, NOR2), &[Tv(4087), Tv(4088)]),
+    ((4092, false, NOR2), &[Tv(4090), Tv(4091)]),
+    ((4107, false, NOR2), &[Tv(4105), Tv(4106)]),
+    ((4110, false, NOR2), &[Tv(4108), Tv(4109)]),
+    ((4113, false, NOR2), &[Tv(4111), Tv(4112)]),
+    ((4116, false, NOR2), &[Tv(4114), Tv(4115)]),
+    ((4130, false, NOR2), &[Tv(4128), Tv(4129)]),
+    ((4133, false, NOR2), &[Tv(4131), Tv(4132)]),
+    ((4136, false, NOR2), &[Tv(4134), Tv(4135)]),
+    ((4139, false, NOR2), &[Tv(4137), Tv(4138)]),
+    ((4154, false, NOR2), &[Tv(4152), Tv(4153)]),
+    ((4157, false, NOR2), &[Tv(4155), Tv(4156)]),
+    ((4160, false, NOR2), &[Tv(4158), Tv(4159)]),
+    ((4163, false, NOR2), &[Tv(4161), Tv(4162)]),
+    ((4177, false, NOR2), &[Tv(4175), Tv(4176)]),
+    ((4180, false, NOR2), &[Tv(4178), Tv(4179)]),
+    ((4183, false, NOR2), &[Tv(4181), Tv(4182)]),
+    ((4186, false, NOR2), &[Tv(4184), Tv(4185)]),
+    ((4204, false, AND2), &[Tv(4202), Tv(4203)]),
+    ((4207, false, INV), &[Tv(4206)]),
+    ((4211, false, INV), &[Tv(4210)]),
+    ((4216, false, INV), &[Tv(4215)]),
+    ((4220, false, INV), &[Tv(4219)]),
+    ((4225, false, INV), &[Tv(4224)]),
+    ((4229, false, INV), &[Tv(4228)]),
+    ((4249, false, NAND2), &[Arg(0, 580), Tv(4240)]),
+    ((4265, false, INV), &[Tv(4264)]),
+];
+
+static LEVEL_3: [((usize, bool, CellType), &[GateInput]); 300] = [
+    ((624, false, AND2), &[Tv(614), Tv(617)]),
+    ((625, false, AND2), &[Tv(620), Tv(623)]),
+    ((647, false, AND2), &[Tv(637), Tv(640)]),
+    ((648, false, AND2), &[Tv(643), Tv(646)]),
+    ((671, false, AND2), &[Tv(661), Tv(664)]),
+    ((672, false, AND2), &[Tv(667), Tv(670)]),
+    ((694, false, AND2), &[Tv(684), Tv(687)]),
+    ((695, false, AND2), &[Tv(690), Tv(693)]),
+    ((718, false, AND2), &[Tv(708), Tv(711)]),
+    ((719, false, AND2), &[Tv(714), Tv(717)]),
+    ((741, false, AND2), &[Tv(731), Tv(734)]),
+    ((742, false, AND2), &[Tv(737), Tv(740)]),
+    ((765, false, AND2), &[Tv(755), Tv(758)]),
+    ((766, false, AND2), &[Tv(761), Tv(764)]),
+    ((788, false, AND2), &[Tv(778), Tv(781)]),
+    ((789, false, AND2), &[Tv(784), Tv(787)]),
+    ((809, false, NOR2), &[Arg(0, 42), Tv(808)]),
+    ((813, false, NOR2), &[Arg(0, 43), Tv(812)]),
+    ((818, false, NOR2), &[Arg(0, 44), Tv(817)]),
+    ((822, false, NOR2), &[Arg(0, 45), Tv(821)]),
+    ((827, false, NOR2), &[Arg(0, 46), Tv(826)]),
+    ((831, false, NOR2), &[Arg(0, 47), Tv(830)]),
+    ((864, false, XOR2), &[Arg(0, 41), Tv(805)]),
+    ((867, false, NOR2), &[Tv(805), Tv(866)]),
+    ((870, false, INV), &[Tv(805)]),
+    ((933, false, AND2), &[Tv(923), Tv(926)]),
+    ((934, false, AND2), &[Tv(929), Tv(932)]),
+    ((956, false, AND2), &[Tv(946), Tv(949)]),
+    ((957, false, AND2), &[Tv(952), Tv(955)]),
+    ((980, false, AND2), &[Tv(970), Tv(973)]),
+    ((981, false, AND2), &[Tv(976), Tv(979)]),
+    ((1003, false, AND2), &[Tv(993), Tv(996)]),
+    ((1004, false, AND2), &[Tv(999), Tv(1002)]),
+    ((1027, false, AND2), &[Tv(1017), Tv(1020)]),
+    ((1028, false, AND2), &[Tv(1023), Tv(1026)]),
+    ((1050, false, AND2), &[Tv(1040), Tv(1043)]),
+    ((1051, false, AND2), &[Tv(1046), Tv(1049)]),
+    ((1074, false, AND2), &[Tv(1064), Tv(1067)]),
+    ((1075, false, AND2), &[Tv(1070), Tv(1073)]),
+    ((1097, false, AND2), &[Tv(1087), Tv(1090)]),
+    ((1098, false, AND2), &[Tv(1093), Tv(1096)]),
+    ((1118, false, NOR2), &[Arg(0, 91), Tv(1117)]),
+    ((1122, false, NOR2), &[Arg(0, 92), Tv(1121)]),
+    ((1127, false, NOR2), &[Arg(0, 93), Tv(1126)]),
+    ((1131, false, NOR2), &[Arg(0, 94), Tv(1130)]),
+    ((1136, false, NOR2), &[Arg(0, 95), Tv(1135)]),
+    ((1140, false, NOR2), &[Arg(0, 96), Tv(1139)]),
+    ((1173, false, XOR2), &[Arg(0, 90), Tv(1114)]),
+    ((1176, false, NOR2), &[Tv(1114), Tv(1175)]),
+    ((1179, false, INV), &[Tv(1114)]),
+    ((1242, false, AND2), &[Tv(1232), Tv(1235)]),
+    ((1243, false, AND2), &[Tv(1238), Tv(1241)]),
+    ((1265, false, AND2), &[Tv(1255), Tv(1258)]),
+    ((1266, false, AND2), &[Tv(1261), Tv(1264)]),
+    ((1289, false, AND2), &[Tv(1279), Tv(1282)]),
+    ((1290, false, AND2), &[Tv(1285), Tv(1288)]),
+    ((1312, false, AND2), &[Tv(1302), Tv(1305)]),
+    ((1313, false, AND2), &[Tv(1308), Tv(1311)]),
+    ((1336, false, AND2), &[Tv(1326), Tv(1329)]),
+    ((1337, false, AND2), &[Tv(1332), Tv(1335)]),
+    ((1359, false, AND2), &[Tv(1349), Tv(1352)]),
+    ((1360, false, AND2), &[Tv(1355), Tv(1358)]),
+    ((1383, false, AND2), &[Tv(1373), Tv(1376)]),
+    ((1384, false, AND2), &[Tv(1379), Tv(1382)]),
+    ((1406, false, AND2), &[Tv(1396), Tv(1399)]),
+    ((1407, false, AND2), &[Tv(1402), Tv(1405)]),
+    ((1427, false, NOR2), &[Arg(0, 140), Tv(1426)]),
+    ((1431, false, NOR2), &[Arg(0, 141), Tv(1430)]),
+    ((1436, false, NOR2), &[Arg(0, 142), Tv(1435)]),
+    ((1440, false, NOR2), &[Arg(0, 143), Tv(1439)]),
+    ((1445, false, NOR2), &[Arg(0, 144), Tv(1444)]),
+    ((1449, false, NOR2), &[Arg(0, 145), Tv(1448)]),
+    ((1482, false, XOR2), &[Arg(0, 139), Tv(1423)]),
+    ((1485, false, NOR2), &[Tv(1423), Tv(1484)]),
+    ((1488, false, INV), &[Tv(1423)]),
+    ((1551, false, AND2), &[Tv(1541), Tv(1544)]),
+    ((1552, false, AND2), &[Tv(1547), Tv(1550)]),
+    ((1574, false, AND2), &[Tv(1564), Tv(1567)]),
+    ((1575, false, AND2), &[Tv(1570), Tv(1573)]),
+    ((1598, false, AND2), &[Tv(1588), Tv(1591)]),
+    ((1599, false, AND2), &[Tv(1594), Tv(1597)]),
+    ((1621, false, AND2), &[Tv(1611), Tv(1614)]),
+    ((1622, false, AND2), &[Tv(1617), Tv(1620)]),
+    ((1645, false, AND2), &[Tv(1635), Tv(1638)]),
+    ((1646, false, AND2), &[Tv(1641), Tv(1644)]),
+    ((1668, false, AND2), &[Tv(1658), Tv(1661)]),
+    ((1669, false, AND2), &[Tv(1664), Tv(1667)]),
+    ((1692, false, AND2), &[Tv(1682), Tv(1685)]),
+    ((1693, false, AND2), &[Tv(1688), Tv(1691)]),
+    ((1715, false, AND2), &[Tv(1705), Tv(1708)]),
+    ((1716, false, AND2), &[Tv(1711), Tv(1714)]),
+    ((1736, false, NOR2), &[Arg(0, 189), Tv(1735)]),
+    ((1740, false, NOR2), &[Arg(0, 190), Tv(1739)]),
+    ((1745, false, NOR2), &[Arg(0, 191), Tv(1744)]),
+    ((1749, false, NOR2), &[Arg(0, 192), Tv(1748)]),
+    ((1754, false, NOR2), &[Arg(0, 193), Tv(1753)]),
+    ((1758, false, NOR2), &[Arg(0, 194), Tv(1757)]),
+    ((1791, false, XOR2), &[Arg(0, 188), Tv(1732)]),
+    ((1794, false, NOR2), &[Tv(1732), Tv(1793)]),
+    ((1797, false, INV), &[Tv(1732)]),
+    ((1860, false, AND2), &[Tv(1850), Tv(1853)]),
+    ((1861, false, AND2), &[Tv(1856), Tv(1859)]),
+    ((1883, false, AND2), &[Tv(1873), Tv(1876)]),
+    ((1884, false, AND2), &[Tv(1879), Tv(1882)]),
+    ((1907, false, AND2), &[Tv(1897), Tv(1900)]),
+    ((1908, false, AND2), &[Tv(1903), Tv(1906)]),
+    ((1930, false, AND2), &[Tv(1920), Tv(1923)]),
+    ((1931, false, AND2), &[Tv(1926), Tv(1929)]),
+    ((1954, false, AND2), &[Tv(1944), Tv(1947)]),
+    ((1955, false, AND2), &[Tv(1950), Tv(1953)]),
+    ((1977, false, AND2), &[Tv(1967), Tv(1970)]),
+    ((1978, false, AND2), &[Tv(1973), Tv(1976)]),
+    ((2001, false, AND2), &[Tv(1991), Tv(1994)]),
+    ((2002, false, AND2), &[Tv(1997), Tv(2000)]),
+    ((2024, false, AND2), &[Tv(2014), Tv(2017)]),
+    ((2025, false, AND2), &[Tv(2020), Tv(2023)]),
+    ((2045, false, NOR2), &[Arg(0, 238), Tv(2044)]),
+    ((2049, false, NOR2), &[Arg(0, 239), Tv(2048)]),
+    ((2054, false, NOR2), &[Arg(0, 240), Tv(2053)]),
+    ((2058, false, NOR2), &[Arg(0, 241), Tv(2057)]),
+    ((2063, false, NOR2), &[Arg(0, 242), Tv(2062)]),
+    ((2067, false, NOR2), &[Arg(0, 243), Tv(2066)]),
+    ((2100, false, XOR2), &[Arg(0, 237), Tv(2041)]),
+    ((2103, false, NOR2), &[Tv(2041), Tv(2102)]),
+    ((2106, false, INV), &[Tv(2041)]),
+    ((2169, false, AND2), &[Tv(2159), Tv(2162)]),
+    ((2170, false, AND2), &[Tv(2165), Tv(2168)]),
+    ((2192, false, AND2), &[Tv(2182), Tv(2185)]),
+    ((2193, false, AND2), &[Tv(2188), Tv(2191)]),
+    ((2216, false, AND2), &[Tv(2206), Tv(2209)]),
+    ((2217, false, AND2), &[Tv(2212), Tv(2215)]),
+    ((2239, false, AND2), &[Tv(2229), Tv(2232)]),
+    ((2240, false, AND2), &[Tv(2235), Tv(2238)]),
+    ((2263, false, AND2), &[Tv(2253), Tv(2256)]),
+    ((2264, false, AND2), &[Tv(2259), Tv(2262)]),
+    ((2286, false, AND2), &[Tv(2276), Tv(2279)]),
+    ((2287, false, AND2), &[Tv(2282), Tv(2285)]),
+    ((2310, false, AND2), &[Tv(2300), Tv(2303)]),
+    ((2311, false, AND2), &[Tv(2306), Tv(2309)]),
+    ((2333, false, AND2), &[Tv(2323), Tv(2326)]),
+    ((2334, false, AND2), &[Tv(2329), Tv(2332)]),
+    ((2354, false, NOR2), &[Arg(0, 287), Tv(2353)]),
+    ((2358, false, NOR2), &[Arg(0, 288), Tv(2357)]),
+    ((2363, false, NOR2), &[Arg(0, 289), Tv(2362)]),
+    ((2367, false, NOR2), &[Arg(0, 290), Tv(2366)]),
+    ((2372, false, NOR2), &[Arg(0, 291), Tv(2371)]),
+    ((2376, false, NOR2), &[Arg(0, 292), Tv(2375)]),
+    ((2409, false, XOR2), &[Arg(0, 286), Tv(2350)]),
+    ((2412, false, NOR2), &[Tv(2350), Tv(2411)]),
+    ((2415, false, INV), &[Tv(2350)]),
+    ((2478, false, AND2), &[Tv(2468), Tv(2471)]),
+    ((2479, false, AND2), &[Tv(2474), Tv(2477)]),
+    ((2501, false, AND2), &[Tv(2491), Tv(2494)]),
+    ((2502, false, AND2), &[Tv(2497), Tv(2500)]),
+    ((2525, false, AND2), &[Tv(2515), Tv(2518)]),
+    ((2526, false, AND2), &[Tv(2521), Tv(2524)]),
+    ((2548, false, AND2), &[Tv(2538), Tv(2541)]),
+    ((2549, false, AND2), &[Tv(2544), Tv(2547)]),
+    ((2572, false, AND2), &[Tv(2562), Tv(2565)]),
+    ((2573, false, AND2), &[Tv(2568), Tv(2571)]),
+    ((2595, false, AND2), &[Tv(2585), Tv(2588)]),
+    ((2596, false, AND2), &[Tv(2591), Tv(2594)]),
+    ((2619, false, AND2), &[Tv(2609), Tv(2612)]),
+    ((2620, false, AND2), &[Tv(2615), Tv(2618)]),
+    ((2642, false, AND2), &[Tv(2632), Tv(2635)]),
+    ((2643, false, AND2), &[Tv(2638), Tv(2641)]),
+    ((2663, false, NOR2), &[Arg(0, 336), Tv(2662)]),
+    ((2667, false, NOR2), &[Arg(0, 337), Tv(2666)]),
+    ((2672, false, NOR2), &[Arg(0, 338), Tv(2671)]),
+    ((2676, false, NOR2), &[Arg(0, 339), Tv(2675)]),
+    ((2681, false, NOR2), &[Arg(0, 340), Tv(2680)]),
+    ((2685, false, NOR2), &[Arg(0, 341), Tv(2684)]),
+    ((2718, false, XOR2), &[Arg(0, 335), Tv(2659)]),
+    ((2721, false, NOR2), &[Tv(2659), Tv(2720)]),
+    ((2724, false, INV), &[Tv(2659)]),
+    ((2787, false, AND2), &[Tv(2777), Tv(2780)]),
+    ((2788, false, AND2), &[Tv(2783), Tv(2786)]),
+    ((2810, false, AND2), &[Tv(2800), Tv(2803)]),
+    ((2811, false, AND2), &[Tv(2806), Tv(2809)]),
+    ((2834, false, AND2), &[Tv(2824), Tv(2827)]),
+    ((2835, false, AND2), &[Tv(2830), Tv(2833)]),
+    ((2857, false, AND2), &[Tv(2847), Tv(2850)]),
+    ((2858, false, AND2), &[Tv(2853), Tv(2856)]),
+    ((2881, false, AND2), &[Tv(2871), Tv(2874)]),
+    ((2882, false, AND2), &[Tv(2877), Tv(2880)]),
+    ((2904, false, AND2), &[Tv(2894), Tv(2897)]),
+    ((2905, false, AND2), &[Tv(2900), Tv(2903)]),
+    ((2928, false, AND2), &[Tv(2918), Tv(2921)]),
+    ((2929, false, AND2), &[Tv(2924), Tv(2927)]),
+    ((2951, false, AND2), &[Tv(2941), Tv(2944)]),
+    ((2952, false, AND2), &[Tv(2947), Tv(2950)]),
+    ((2972, false, NOR2), &[Arg(0, 385), Tv(2971)]),
+    ((2976, false, NOR2), &[Arg(0, 386), Tv(2975)]),
+    ((2981, false, NOR2), &[Arg(0, 387), Tv(2980)]),
+    ((2985, false, NOR2), &[Arg(0, 388), Tv(2984)]),
+    ((2990, false, NOR2), &[Arg(0, 389), Tv(2989)]),
+    ((2994, false, NOR2), &[Arg(0, 390), Tv(2993)]),
+    ((3027, false, XOR2), &[Arg(0, 384), Tv(2968)]),
+    ((3030, false, NOR2), &[Tv(2968), Tv(3029)]),
+    ((3033, false, INV), &[Tv(2968)]),
+    ((3096, false, AND2), &[Tv(3086), Tv(3089)]),
+    ((3097, false, AND2), &[Tv(3092), Tv(3095)]),
+    ((3119, false, AND2), &[Tv(3109), Tv(3112)]),
+    ((3120, false, AND2), &[Tv(3115), Tv(3118)]),
+    ((3143, false, AND2), &[Tv(3133), Tv(3136)]),
+    ((3144, false, AND2), &[Tv(3139), Tv(3142)]),
+    ((3166, false, AND2), &[Tv(3156), Tv(3159)]),
+    ((3167, false, AND2), &[Tv(3162), Tv(3165)]),
+    ((3190, false, AND2), &[Tv(3180), Tv(3183)]),
+    ((3191, false, AND2), &[Tv(3186), Tv(3189)]),
+    ((3213, false, AND2), &[Tv(3203), Tv(3206)]),
+    ((3214, false, AND2), &[Tv(3209), Tv(3212)]),
+    ((3237, false, AND2), &[Tv(3227), Tv(3230)]),
+    ((3238, false, AND2), &[Tv(3233), Tv(3236)]),
+    ((3260, false, AND2), &[Tv(3250), Tv(3253)]),
+    ((3261, false, AND2), &[Tv(3256), Tv(3259)]),
+    ((3281, false, NOR2), &[Arg(0, 434), Tv(3280)]),
+    ((3285, false, NOR2), &[Arg(0, 435), Tv(3284)]),
+    ((3290, false, NOR2), &[Arg(0, 436), Tv(3289)]),
+    ((3294, false, NOR2), &[Arg(0, 437), Tv(3293)]),
+    ((3299, false, NOR2), &[Arg(0, 438), Tv(3298)]),
+    ((3303, false, NOR2), &[Arg(0, 439), Tv(3302)]),
+    ((3336, false, XOR2), &[Arg(0, 433), Tv(3277)]),
+    ((3339, false, NOR2), &[Tv(3277), Tv(3338)]),
+    ((3342, false, INV), &[Tv(3277)]),
+    ((3405, false, AND2), &[Tv(3395), Tv(3398)]),
+    ((3406, false, AND2), &[Tv(3401), Tv(3404)]),
+    ((3428, false, AND2), &[Tv(3418), Tv(3421)]),
+    ((3429, false, AND2), &[Tv(3424), Tv(3427)]),
+    ((3452, false, AND2), &[Tv(3442), Tv(3445)]),
+    ((3453, false, AND2), &[Tv(3448), Tv(3451)]),
+    ((3475, false, AND2), &[Tv(3465), Tv(3468)]),
+    ((3476, false, AND2), &[Tv(3471), Tv(3474)]),
+    ((3499, false, AND2), &[Tv(3489), Tv(3492)]),
+    ((3500, false, AND2), &[Tv(3495), Tv(3498)]),
+    ((3522, false, AND2), &[Tv(3512), Tv(3515)]),
+    ((3523, false, AND2), &[Tv(3518), Tv(3521)]),
+    ((3546, false, AND2), &[Tv(3536), Tv(3539)]),
+    ((3547, false, AND2), &[Tv(3542), Tv(3545)]),
+    ((3569, false, AND2), &[Tv(3559), Tv(3562)]),
+    ((3570, false, AND2), &[Tv(3565), Tv(3568)]),
+    ((3590, false, NOR2), &[Arg(0, 483), Tv(3589)]),
+    ((3594, false, NOR2), &[Arg(0, 484), Tv(3593)]),
+    ((3599, false, NOR2), &[Arg(0, 485), Tv(3598)]),
+    ((3603, false, NOR2), &[Arg(0, 486), Tv(3602)]),
+    ((3608, false, NOR2), &[Arg(0, 487), Tv(3607)]),
+    ((3612, false, NOR2), &[Arg(0, 488), Tv(3611)]),
+    ((3645, false, XOR2), &[Arg(0, 482), Tv(3586)]),
+    ((3648, false, NOR2), &[Tv(3586), Tv(3647)]),
+    ((3651, false, INV), &[Tv(3586)]),
+    ((3714, false, AND2), &[Tv(3704), Tv(3707)]),
+    ((3715, false, AND2), &[Tv(3710), Tv(3713)]),
+    ((3737, false, AND2), &[Tv(3727), Tv(3730)]),
+    ((3738, false, AND2), &[Tv(3733), Tv(3736)]),
+    ((3761, false, AND2), &[Tv(3751), Tv(3754)]),
+    ((3762, false, AND2), &[Tv(3757), Tv(3760)]),
+    ((3784, false, AND2), &[Tv(3774), Tv(3777)]),
+    ((3785, false, AND2), &[Tv(3780), Tv(3783)]),
+    ((3808, false, AND2), &[Tv(3798), Tv(3801)]),
+    ((3809, false, AND2), &[Tv(3804), Tv(3807)]),
+    ((3831, false, AND2), &[Tv(3821), Tv(3824)]),
+    ((3832, false, AND2), &[Tv(3827), Tv(3830)]),
+    ((3855, false, AND2), &[Tv(3845), Tv(3848)]),
+    ((3856, false, AND2), &[Tv(3851), Tv(3854)]),
+    ((3878, false, AND2), &[Tv(3868), Tv(3871)]),
+    ((3879, false, AND2), &[Tv(3874), Tv(3877)]),
+    ((3899, false, NOR2), &[Arg(0, 532), Tv(3898)]),
+    ((3903, false, NOR2), &[Arg(0, 533), Tv(3902)]),
+    ((3908, false, NOR2), &[Arg(0, 534), Tv(3907)]),
+    ((3912, false, NOR2), &[Arg(0, 535), Tv(3911)]),
+    ((3917, false, NOR2), &[Arg(0, 536), Tv(3916)]),
+    ((3921, false, NOR2), &[Arg(0, 537), Tv(3920)]),
+    ((3954, false, XOR2), &[Arg(0, 531), Tv(3895)]),
+    ((3957, false, NOR2), &[Tv(3895), Tv(3956)]),
+    ((3960, false, INV), &[Tv(3895)]),
+    ((4023, false, AND2), &[Tv(4013), Tv(4016)]),
+    ((4024, false, AND2), &[Tv(4019), Tv(4022)]),
+    ((4046, false, AND2), &[Tv(4036), Tv(4039)]),
+    ((4047, false, AND2), &[Tv(4042), Tv(4045)]),
+    ((4070, false, AND2), &[Tv(4060), Tv(4063)]),
+    ((4071, false, AND2), &[Tv(4066), Tv(4069)]),
+    ((4093, false, AND2), &[Tv(4083), Tv(4086)]),
+    ((4094, false, AND2), &[Tv(4089), Tv(4092)]),
+    ((4117, false, AND2), &[Tv(4107), Tv(4110)]),
+    ((4118, false, AND2), &[Tv(4113), Tv(4116)]),
+    ((4140, false, AND2), &[Tv(4130), Tv(4133)]),
+    ((4141, false, AND2), &[Tv(4136), Tv(4139)]),
+    ((4164, false, AND2), &[Tv(4154), Tv(4157)]),
+    ((4165, false, AND2), &[Tv(4160), Tv(4163)]),
+    ((4187, false, AND2), &[Tv(4177), Tv(4180)]),
+    ((4188, false, AND2), &[Tv(4183), Tv(4186)]),
+    ((4208, false, NOR2), &[Arg(0, 581), Tv(4207)]),
+    ((4212, false, NOR2), &[Arg(0, 582), Tv(4211)]),
+    ((4217, false, NOR2), &[Arg(0, 583), Tv(4216)]),
+    ((4221, false, NOR2), &[Arg(0, 584), Tv(4220)]),
+    ((4226, false, NOR2), &[Arg(0, 585), Tv(4225)]),
+    ((4230, false, NOR2), &[Arg(0, 586), Tv(4229)]),
+    ((4263, false, XOR2), &[Arg(0, 580), Tv(4204)]),
+    ((4266, false, NOR2), &[Tv(4204), Tv(4265)]),
+    ((4269, false, INV), &[Tv(4204)]),
+];
+
+static LEVEL_4: [((usize, bool, CellType), &[GateInput]); 192] = [
+    ((626, false, AND2), &[Tv(624), Tv(625)]),
+    ((649, false, AND2), &[Tv(647), Tv(648)]),
+    ((673, false, AND2), &[Tv(671), Tv(672)]),
+    ((696, false, AND2), &[Tv(694), Tv(695)]),
+    ((720, false, AND2), &[Tv(718), Tv(719)]),
+    ((743, false, AND2), &[Tv(741), Tv(742)]),
+    ((767, false, AND2), &[Tv(765), Tv(766)]),
+    ((790, false, AND2), &[Tv(788), Tv(789)]),
+    ((834, false, AND2), &[Tv(813), Tv(833)]),
+    ((835, false, INV), &[Tv(809)]),
+    ((838, false, INV), &[Tv(813)]),
+    ((842, false, INV), &[Tv(818)]),
+    ((846, false, INV), &[Tv(822)]),
+    ((858, false, XOR2), &[Arg(0, 43), Tv(809)]),
+    ((868, false, XOR2), &[Arg(0, 42), Tv(867)]),
+    ((888, false, XNOR2), &[Arg(0, 41), Tv(864)]),
+    ((935, false, AND2), &[Tv(933), Tv(934)]),
+    ((958, false, AND2), &[Tv(956), Tv(957)]),
+    ((982, false, AND2), &[Tv(980), Tv(981)]),
+    ((1005, false, AND2), &[Tv(1003), Tv(1004)]),
+    ((1029, false, AND2), &[Tv(1027), Tv(1028)]),
+    ((1052, false, AND2), &[Tv(1050), Tv(1051)]),
+    ((1076, false, AND2), &[Tv(1074), Tv(1075)]),
+    ((1099, false, AND2), &[Tv(1097), Tv(1098)]),
+    ((1143, false, AND2), &[Tv(1122), Tv(1142)]),
+    ((1144, false, INV), &[Tv(1118)]),
+    ((1147, false, INV), &[Tv(1122)]),
+    ((1151, false, INV), &[Tv(1127)]),
+    ((1155, false, INV), &[Tv(1131)]),
+    ((1167, false, XOR2), &[Arg(0, 92), Tv(1118)]),
+    ((1177, false, XOR2), &[Arg(0, 91), Tv(1176)]),
+    ((1197, false, XNOR2), &[Arg(0, 90), Tv(1173)]),
+    ((1244, false, AND2), &[Tv(1242), Tv(1243)]),
+    ((1267, false, AND2), &[Tv(1265), Tv(1266)]),
+    ((1291, false, AND2), &[Tv(1289), Tv(1290)]),
+    ((1314, false, AND2), &[Tv(1312), Tv(1313)]),
+    ((1338, false, AND2), &[Tv(1336), Tv(1337)]),
+    ((1361, false, AND2), &[Tv(1359), Tv(1360)]),
+    ((1385, false, AND2), &[Tv(1383), Tv(1384)]),
+    ((1408, false, AND2), &[Tv(1406), Tv(1407)]),
+    ((1452, false, AND2), &[Tv(1431), Tv(1451)]),
+    ((1453, false, INV), &[Tv(1427)]),
+    ((1456, false, INV), &[Tv(1431)]),
+    ((1460, false, INV), &[Tv(1436)]),
+    ((1464, false, INV), &[Tv(1440)]),
+    ((1476, false, XOR2), &[Arg(0, 141), Tv(1427)]),
+    ((1486, false, XOR2), &[Arg(0, 140), Tv(1485)]),
+    ((1506, false, XNOR2), &[Arg(0, 139), Tv(1482)]),
+    ((1553, false, AND2), &[Tv(1551), Tv(1552)]),
+    ((1576, false, AND2), &[Tv(1574), Tv(1575)]),
+    ((1600, false, AND2), &[Tv(1598), Tv(1599)]),
+    ((1623, false, AND2), &[Tv(1621), Tv(1622)]),
+    ((1647, false, AND2), &[Tv(1645), Tv(1646)]),
+    ((1670, false, AND2), &[Tv(1668), Tv(1669)]),
+    ((1694, false, AND2), &[Tv(1692), Tv(1693)]),
+    ((1717, false, AND2), &[Tv(1715), Tv(1716)]),
+    ((1761, false, AND2), &[Tv(1740), Tv(1760)]),
+    ((1762, false, INV), &[Tv(1736)]),
+    ((1765, false, INV), &[Tv(1740)]),
+    ((1769, false, INV), &[Tv(1745)]),
+    ((1773, false, INV), &[Tv(1749)]),
+    ((1785, false, XOR2), &[Arg(0, 190), Tv(1736)]),
+    ((1795, false, XOR2), &[Arg(0, 189), Tv(1794)]),
+    ((1815, false, XNOR2), &[Arg(0, 188), Tv(1791)]),
+    ((1862, false, AND2), &[Tv(1860), Tv(1861)]),
+    ((1885, false, AND2), &[Tv(1883), Tv(1884)]),
+    ((1909, false, AND2), &[Tv(1907), Tv(1908)]),
+    ((1932, false, AND2), &[Tv(1930), Tv(1931)]),
+    ((1956, false, AND2), &[Tv(1954), Tv(1955)]),
+    ((1979, false, AND2), &[Tv(1977), Tv(1978)]),
+    ((2003, false, AND2), &[Tv(2001), Tv(2002)]),
+    ((2026, false, AND2), &[Tv(2024), Tv(2025)]),
+    ((2070, false, AND2), &[Tv(2049), Tv(2069)]),
+    ((2071, false, INV), &[Tv(2045)]),
+    ((2074, false, INV), &[Tv(2049)]),
+    ((2078, false, INV), &[Tv(2054)]),
+    ((2082, false, INV), &[Tv(2058)]),
+    ((2094, false, XOR2), &[Arg(0, 239), Tv(2045)]),
+    ((2104, false, XOR2), &[Arg(0, 238), Tv(2103)]),
+    ((2124, false, XNOR2), &[Arg(0, 237), Tv(2100)]),
+    ((2171, false, AND2), &[Tv(2169), Tv(2170)]),
+    ((2194, false, AND2), &[Tv(2192), Tv(2193)]),
+    ((2218, false, AND2), &[Tv(2216), Tv(2217)]),
+    ((2241, false, AND2), &[Tv(2239), Tv(2240)]),
+    ((2265, false, AND2), &[Tv(2263), Tv(2264)]),
+    ((2288, false, AND2), &[Tv(2286), Tv(2287)]),
+    ((2312, false, AND2), &[Tv(2310), Tv(2311)]),
+    ((2335, false, AND2), &[Tv(2333), Tv(2334)]),
+    ((2379, false, AND2), &[Tv(2358), Tv(2378)]),
+    ((2380, false, INV), &[Tv(2354)]),
+    ((2383, false, INV), &[Tv(2358)]),
+    ((2387, false, INV), &[Tv(2363)]),
+    ((2391, false, INV), &[Tv(2367)]),
+    ((2403, false, XOR2), &[Arg(0, 288), Tv(2354)]),
+    ((2413, false, XOR2), &[Arg(0, 287), Tv(2412)]),
+    ((2433, false, XNOR2), &[Arg(0, 286), Tv(2409)]),
+    ((2480, false, AND2), &[Tv(2478), Tv(2479)]),
+    ((2503, false, AND2), &[Tv(2501), Tv(2502)]),
+    ((2527, false, AND2), &[Tv(2525), Tv(2526)]),
+    ((2550, false, AND2), &[Tv(2548), Tv(2549)]),
+    ((2574, false, AND2), &[Tv(2572), Tv(2573)]),
+    ((2597, false, AND2), &[Tv(2595), Tv(2596)]),
+    ((2621, false, AND2), &[Tv(2619), Tv(2620)]),
+    ((2644, false, AND2), &[Tv(2642), Tv(2643)]),
+    ((2688, false, AND2), &[Tv(2667), Tv(2687)]),
+    ((2689, false, INV), &[Tv(2663)]),
+    ((2692, false, INV), &[Tv(2667)]),
+    ((2696, false, INV), &[Tv(2672)]),
+    ((2700, false, INV), &[Tv(2676)]),
+    ((2712, false, XOR2), &[Arg(0, 337), Tv(2663)]),
+    ((2722, false, XOR2), &[Arg(0, 336), Tv(2721)]),
+    ((2742, false, XNOR2), &[Arg(0, 335), Tv(2718)]),
+    ((2789, false, AND2), &[Tv(2787), Tv(2788)]),
+    ((2812, false, AND2), &[Tv(2810), Tv(2811)]),
+    ((2836, false, AND2), &[Tv(2834), Tv(2835)]),
+    ((2859, false, AND2), &[Tv(2857), Tv(2858)]),
+    ((2883, false, AND2), &[Tv(2881), Tv(2882)]),
+    ((2906, false, AND2), &[Tv(2904), Tv(2905)]),
+    ((2930, false, AND2), &[Tv(2928), Tv(2929)]),
+    ((2953, false, AND2), &[Tv(2951), Tv(2952)]),
+    ((2997, false, AND2), &[Tv(2976), Tv(2996)]),
+    ((2998, false, INV), &[Tv(2972)]),
+    ((3001, false, INV), &[Tv(2976)]),
+    ((3005, false, INV), &[Tv(2981)]),
+    ((3009, false, INV), &[Tv(2985)]),
+    ((3021, false, XOR2), &[Arg(0, 386), Tv(2972)]),
+    ((3031, false, XOR2), &[Arg(0, 385), Tv(3030)]),
+    ((3051, false, XNOR2), &[Arg(0, 384), Tv(3027)]),
+    ((3098, false, AND2), &[Tv(3096), Tv(3097)]),
+    ((3121, false, AND2), &[Tv(3119), Tv(3120)]),
+    ((3145, false, AND2), &[Tv(3143), Tv(3144)]),
+    ((3168, false, AND2), &[Tv(3166), Tv(3167)]),
+    ((3192, false, AND2), &[Tv(3190), Tv(3191)]),
+    ((3215, false, AND2), &[Tv(3213), Tv(3214)]),
+    ((3239, false, AND2), &[Tv(3237), Tv(3238)]),
+    ((3262, false, AND2), &[Tv(3260), Tv(3261)]),
+    ((3306, false, AND2), &[Tv(3285), Tv(3305)]),
+    ((3307, false, INV), &[Tv(3281)]),
+    ((3310, false, INV), &[Tv(3285)]),
+    ((3314, false, INV), &[Tv(3290)]),
+    ((3318, false, INV), &[Tv(3294)]),
+    ((3330, false, XOR2), &[Arg(0, 435), Tv(3281)]),
+    ((3340, false, XOR2), &[Arg(0, 434), Tv(3339)]),
+    ((3360, false, XNOR2), &[Arg(0, 433), Tv(3336)]),
+    ((3407, false, AND2), &[Tv(3405), Tv(3406)]),
+    ((3430, false, AND2), &[Tv(3428), Tv(3429)]),
+    ((3454, false, AND2), &[Tv(3452), Tv(3453)]),
+    ((3477, false, AND2), &[Tv(3475), Tv(3476)]),
+    ((3501, false, AND2), &[Tv(3499), Tv(3500)]),
+    ((3524, false, AND2), &[Tv(3522), Tv(3523)]),
+    ((3548, false, AND2), &[Tv(3546), Tv(3547)]),
+    ((3571, false, AND2), &[Tv(3569), Tv(3570)]),
+    ((3615, false, AND2), &[Tv(3594), Tv(3614)]),
+    ((3616, false, INV), &[Tv(3590)]),
+    ((3619, false, INV), &[Tv(3594)]),
+    ((3623, false, INV), &[Tv(3599)]),
+    ((3627, false, INV), &[Tv(3603)]),
+    ((3639, false, XOR2), &[Arg(0, 484), Tv(3590)]),
+    ((3649, false, XOR2), &[Arg(0, 483), Tv(3648)]),
+    ((3669, false, XNOR2), &[Arg(0, 482), Tv(3645)]),
+    ((3716, false, AND2), &[Tv(3714), Tv(3715)]),
+    ((3739, false, AND2), &[Tv(3737), Tv(3738)]),
+    ((3763, false, AND2), &[Tv(3761), Tv(3762)]),
+    ((3786, false, AND2), &[Tv(3784), Tv(3785)]),
+    ((3810, false, AND2), &[Tv(3808), Tv(3809)]),
+    ((3833, false, AND2), &[Tv(3831), Tv(3832)]),
+    ((3857, false, AND2), &[Tv(3855), Tv(3856)]),
+    ((3880, false, AND2), &[Tv(3878), Tv(3879)]),
+    ((3924, false, AND2), &[Tv(3903), Tv(3923)]),
+    ((3925, false, INV), &[Tv(3899)]),
+    ((3928, false, INV), &[Tv(3903)]),
+    ((3932, false, INV), &[Tv(3908)]),
+    ((3936, false, INV), &[Tv(3912)]),
+    ((3948, false, XOR2), &[Arg(0, 533), Tv(3899)]),
+    ((3958, false, XOR2), &[Arg(0, 532), Tv(3957)]),
+    ((3978, false, XNOR2), &[Arg(0, 531), Tv(3954)]),
+    ((4025, false, AND2), &[Tv(4023), Tv(4024)]),
+    ((4048, false, AND2), &[Tv(4046), Tv(4047)]),
+    ((4072, false, AND2), &[Tv(4070), Tv(4071)]),
+    ((4095, false, AND2), &[Tv(4093), Tv(4094)]),
+    ((4119, false, AND2), &[Tv(4117), Tv(4118)]),
+    ((4142, false, AND2), &[Tv(4140), Tv(4141)]),
+    ((4166, false, AND2), &[Tv(4164), Tv(4165)]),
+    ((4189, false, AND2), &[Tv(4187), Tv(4188)]),
+    ((4233, false, AND2), &[Tv(4212), Tv(4232)]),
+    ((4234, false, INV), &[Tv(4208)]),
+    ((4237, false, INV), &[Tv(4212)]),
+    ((4241, false, INV), &[Tv(4217)]),
+    ((4245, false, INV), &[Tv(4221)]),
+    ((4257, false, XOR2), &[Arg(0, 582), Tv(4208)]),
+    ((4267, false, XOR2), &[Arg(0, 581), Tv(4266)]),
+    ((4287, false, XNOR2), &[Arg(0, 580), Tv(4263)]),
+];
+
+static LEVEL_5: [((usize, bool, CellType), &[GateInput]); 180] = [
+    ((650, false, NAND2), &[Tv(626), Tv(649)]),
+    ((697, false, NAND2), &[Tv(673), Tv(696)]),
+    ((744, false, NAND2), &[Tv(720), Tv(743)]),
+    ((791, false, NAND2), &[Tv(767), Tv(790)]),
+    ((836, false, NAND2), &[Tv(800), Tv(835)]),
+    ((839, false, NAND2), &[Tv(838), Tv(823)]),
+    ((843, false, NAND2), &[Tv(801), Tv(842)]),
+    ((847, false, NAND2), &[Tv(846), Tv(832)]),
+    ((852, false, NAND2), &[Tv(835), Tv(845)]),
+    ((854, false, INV), &[Tv(834)]),
+    ((859, false, XOR2), &[Arg(0, 44), Tv(834)]),
+    ((869, false, XOR2), &[Arg(0, 43), Tv(858)]),
+    ((889, false, AND2), &[Arg(0, 32), Tv(888)]),
+    ((890, false, XOR2), &[Arg(0, 42), Tv(868)]),
+    ((904, false, INV), &[Tv(868)]),
+    ((959, false, NAND2), &[Tv(935), Tv(958)]),
+    ((1006, false, NAND2), &[Tv(982), Tv(1005)]),
+    ((1053, false, NAND2), &[Tv(1029), Tv(1052)]),
+    ((1100, false, NAND2), &[Tv(1076), Tv(1099)]),
+    ((1145, false, NAND2), &[Tv(1109), Tv(1144)]),
+    ((1148, false, NAND2), &[Tv(1147), Tv(1132)]),
+    ((1152, false, NAND2), &[Tv(1110), Tv(1151)]),
+    ((1156, false, NAND2), &[Tv(1155), Tv(1141)]),
+    ((1161, false, NAND2), &[Tv(1144), Tv(1154)]),
+    ((1163, false, INV), &[Tv(1143)]),
+    ((1168, false, XOR2), &[Arg(0, 93), Tv(1143)]),
+    ((1178, false, XOR2), &[Arg(0, 92), Tv(1167)]),
+    ((1198, false, AND2), &[Arg(0, 81), Tv(1197)]),
+    ((1199, false, XOR2), &[Arg(0, 91), Tv(1177)]),
+    ((1213, false, INV), &[Tv(1177)]),
+    ((1268, false, NAND2), &[Tv(1244), Tv(1267)]),
+    ((1315, false, NAND2), &[Tv(1291), Tv(1314)]),
+    ((1362, false, NAND2), &[Tv(1338), Tv(1361)]),
+    ((1409, false, NAND2), &[Tv(1385), Tv(1408)]),
+    ((1454, false, NAND2), &[Tv(1418), Tv(1453)]),
+    ((1457, false, NAND2), &[Tv(1456), Tv(1441)]),
+    ((1461, false, NAND2), &[Tv(1419), Tv(1460)]),
+    ((1465, false, NAND2), &[Tv(1464), Tv(1450)]),
+    ((1470, false, NAND2), &[Tv(1453), Tv(1463)]),
+    ((1472, false, INV), &[Tv(1452)]),
+    ((1477, false, XOR2), &[Arg(0, 142), Tv(1452)]),
+    ((1487, false, XOR2), &[Arg(0, 141), Tv(1476)]),
+    ((1507, false, AND2), &[Arg(0, 130), Tv(1506)]),
+    ((1508, false, XOR2), &[Arg(0, 140), Tv(1486)]),
+    ((1522, false, INV), &[Tv(1486)]),
+    ((1577, false, NAND2), &[Tv(1553), Tv(1576)]),
+    ((1624, false, NAND2), &[Tv(1600), Tv(1623)]),
+    ((1671, false, NAND2), &[Tv(1647), Tv(1670)]),
+    ((1718, false, NAND2), &[Tv(1694), Tv(1717)]),
+    ((1763, false, NAND2), &[Tv(1727), Tv(1762)]),
+    ((1766, false, NAND2), &[Tv(1765), Tv(1750)]),
+    ((1770, false, NAND2), &[Tv(1728), Tv(1769)]),
+    ((1774, false, NAND2), &[Tv(1773), Tv(1759)]),
+    ((1779, false, NAND2), &[Tv(1762), Tv(1772)]),
+    ((1781, false, INV), &[Tv(1761)]),
+    ((1786, false, XOR2), &[Arg(0, 191), Tv(1761)]),
+    ((1796, false, XOR2), &[Arg(0, 190), Tv(1785)]),
+    ((1816, false, AND2), &[Arg(0, 179), Tv(1815)]),
+    ((1817, false, XOR2), &[Arg(0, 189), Tv(1795)]),
+    ((1831, false, INV), &[Tv(1795)]),
+    ((1886, false, NAND2), &[Tv(1862), Tv(1885)]),
+    ((1933, false, NAND2), &[Tv(1909), Tv(1932)]),
+    ((1980, false, NAND2), &[Tv(1956), Tv(1979)]),
+    ((2027, false, NAND2), &[Tv(2003), Tv(2026)]),
+    ((2072, false, NAND2), &[Tv(2036), Tv(2071)]),
+    ((2075, false, NAND2), &[Tv(2074), Tv(2059)]),
+    ((2079, false, NAND2), &[Tv(2037), Tv(2078)]),
+    ((2083, false, NAND2), &[Tv(2082), Tv(2068)]),
+    ((2088, false, NAND2), &[Tv(2071), Tv(2081)]),
+    ((2090, false, INV), &[Tv(2070)]),
+    ((2095, false, XOR2), &[Arg(0, 240), Tv(2070)]),
+    ((2105, false, XOR2), &[Arg(0, 239), Tv(2094)]),
+    ((2125, false, AND2), &[Arg(0, 228), Tv(2124)]),
+    ((2126, false, XOR2), &[Arg(0, 238), Tv(2104)]),
+    ((2140, false, INV), &[Tv(2104)]),
+    ((2195, false, NAND2), &[Tv(2171), Tv(2194)]),
+    ((2242, false, NAND2), &[Tv(2218), Tv(2241)]),
+    ((2289, false, NAND2), &[Tv(2265), Tv(2288)]),
+    ((2336, false, NAND2), &[Tv(2312), Tv(2335)]),
+    ((2381, false, NAND2), &[Tv(2345), Tv(2380)]),
+    ((2384, false, NAND2), &[Tv(2383), Tv(2368)]),
+    ((2388, false, NAND2), &[Tv(2346), Tv(2387)]),
+    ((2392, false, NAND2), &[Tv(2391), Tv(2377)]),
+    ((2397, false, NAND2), &[Tv(2380), Tv(2390)]),
+    ((2399, false, INV), &[Tv(2379)]),
+    ((2404, false, XOR2), &[Arg(0, 289), Tv(2379)]),
+    ((2414, false, XOR2), &[Arg(0, 288), Tv(2403)]),
+    ((2434, false, AND2), &[Arg(0, 277), Tv(2433)]),
+    ((2435, false, XOR2), &[Arg(0, 287), Tv(2413)]),
+    ((2449, false, INV), &[Tv(2413)]),
+    ((2504, false, NAND2), &[Tv(2480), Tv(2503)]),
+    ((2551, false, NAND2), &[Tv(2527), Tv(2550)]),
+    ((2598, false, NAND2), &[Tv(2574), Tv(2597)]),
+    ((2645, false, NAND2), &[Tv(2621), Tv(2644)]),
+    ((2690, false, NAND2), &[Tv(2654), Tv(2689)]),
+    ((2693, false, NAND2), &[Tv(2692), Tv(2677)]),
+    ((2697, false, NAND2), &[Tv(2655), Tv(2696)]),
+    ((2701, false, NAND2), &[Tv(2700), Tv(2686)]),
+    ((2706, false, NAND2), &[Tv(2689), Tv(2699)]),
+    ((2708, false, INV), &[Tv(2688)]),
+    ((2713, false, XOR2), &[Arg(0, 338), Tv(2688)]),
+    ((2723, false, XOR2), &[Arg(0, 337), Tv(2712)]),
+    ((2743, false, AND2), &[Arg(0, 326), Tv(2742)]),
+    ((2744, false, XOR2), &[Arg(0, 336), Tv(2722)]),
+    ((2758, false, INV), &[Tv(2722)]),
+    ((2813, false, NAND2), &[Tv(2789), Tv(2812)]),
+    ((2860, false, NAND2), &[Tv(2836), Tv(2859)]),
+    ((2907, false, NAND2), &[Tv(2883), Tv(2906)]),
+    ((2954, false, NAND2), &[Tv(2930), Tv(2953)]),
+    ((2999, false, NAND2), &[Tv(2963), Tv(2998)]),
+    ((3002, false, NAND2), &[Tv(3001), Tv(2986)]),
+    ((3006, false, NAND2), &[Tv(2964), Tv(3005)]),
+    ((3010, false, NAND2), &[Tv(3009), Tv(2995)]),
+    ((3015, false, NAND2), &[Tv(2998), Tv(3008)]),
+    ((3017, false, INV), &[Tv(2997)]),
+    ((3022, false, XOR2), &[Arg(0, 387), Tv(2997)]),
+    ((3032, false, XOR2), &[Arg(0, 386), Tv(3021)]),
+    ((3052, false, AND2), &[Arg(0, 375), Tv(3051)]),
+    ((3053, false, XOR2), &[Arg(0, 385), Tv(3031)]),
+    ((3067, false, INV), &[Tv(3031)]),
+    ((3122, false, NAND2), &[Tv(3098), Tv(3121)]),
+    ((3169, false, NAND2), &[Tv(3145), Tv(3168)]),
+    ((3216, false, NAND2), &[Tv(3192), Tv(3215)]),
+    ((3263, false, NAND2), &[Tv(3239), Tv(3262)]),
+    ((3308, false, NAND2), &[Tv(3272), Tv(3307)]),
+    ((3311, false, NAND2), &[Tv(3310), Tv(3295)]),
+    ((3315, false, NAND2), &[Tv(3273), Tv(3314)]),
+    ((3319, false, NAND2), &[Tv(3318), Tv(3304)]),
+    ((3324, false, NAND2), &[Tv(3307), Tv(3317)]),
+    ((3326, false, INV), &[Tv(3306)]),
+    ((3331, false, XOR2), &[Arg(0, 436), Tv(3306)]),
+    ((3341, false, XOR2), &[Arg(0, 435), Tv(3330)]),
+    ((3361, false, AND2), &[Arg(0, 424), Tv(3360)]),
+    ((3362, false, XOR2), &[Arg(0, 434), Tv(3340)]),
+    ((3376, false, INV), &[Tv(3340)]),
+    ((3431, false, NAND2), &[Tv(3407), Tv(3430)]),
+    ((3478, false, NAND2), &[Tv(3454), Tv(3477)]),
+    ((3525, false, NAND2), &[Tv(3501), Tv(3524)]),
+    ((3572, false, NAND2), &[Tv(3548), Tv(3571)]),
+    ((3617, false, NAND2), &[Tv(3581), Tv(3616)]),
+    ((3620, false, NAND2), &[Tv(3619), Tv(3604)]),
+    ((3624, false, NAND2), &[Tv(3582), Tv(3623)]),
+    ((3628, false, NAND2), &[Tv(3627), Tv(3613)]),
+    ((3633, false, NAND2), &[Tv(3616), Tv(3626)]),
+    ((3635, false, INV), &[Tv(3615)]),
+    ((3640, false, XOR2), &[Arg(0, 485), Tv(3615)]),
+    ((3650, false, XOR2), &[Arg(0, 484), Tv(3639)]),
+    ((3670, false, AND2), &[Arg(0, 473), Tv(3669)]),
+    ((3671, false, XOR2), &[Arg(0, 483), Tv(3649)]),
+    ((3685, false, INV), &[Tv(3649)]),
+    ((3740, false, NAND2), &[Tv(3716), Tv(3739)]),
+    ((3787, false, NAND2), &[Tv(3763), Tv(3786)]),
+    ((3834, false, NAND2), &[Tv(3810), Tv(3833)]),
+    ((3881, false, NAND2), &[Tv(3857), Tv(3880)]),
+    ((3926, false, NAND2), &[Tv(3890), Tv(3925)]),
+    ((3929, false, NAND2), &[Tv(3928), Tv(3913)]),
+    ((3933, false, NAND2), &[Tv(3891), Tv(3932)]),
+    ((3937, false, NAND2), &[Tv(3936), Tv(3922)]),
+    ((3942, false, NAND2), &[Tv(3925), Tv(3935)]),
+    ((3944, false, INV), &[Tv(3924)]),
+    ((3949, false, XOR2), &[Arg(0, 534), Tv(3924)]),
+    ((3959, false, XOR2), &[Arg(0, 533), Tv(3948)]),
+    ((3979, false, AND2), &[Arg(0, 522), Tv(3978)]),
+    ((3980, false, XOR2), &[Arg(0, 532), Tv(3958)]),
+    ((3994, false, INV), &[Tv(3958)]),
+    ((4049, false, NAND2), &[Tv(4025), Tv(4048)]),
+    ((4096, false, NAND2), &[Tv(4072), Tv(4095)]),
+    ((4143, false, NAND2), &[Tv(4119), Tv(4142)]),
+    ((4190, false, NAND2), &[Tv(4166), Tv(4189)]),
+    ((4235, false, NAND2), &[Tv(4199), Tv(4234)]),
+    ((4238, false, NAND2), &[Tv(4237), Tv(4222)]),
+    ((4242, false, NAND2), &[Tv(4200), Tv(4241)]),
+    ((4246, false, NAND2), &[Tv(4245), Tv(4231)]),
+    ((4251, false, NAND2), &[Tv(4234), Tv(4244)]),
+    ((4253, false, INV), &[Tv(4233)]),
+    ((4258, false, XOR2), &[Arg(0, 583), Tv(4233)]),
+    ((4268, false, XOR2), &[Arg(0, 582), Tv(4257)]),
+    ((4288, false, AND2), &[Arg(0, 571), Tv(4287)]),
+    ((4289, false, XOR2), &[Arg(0, 581), Tv(4267)]),
+    ((4303, false, INV), &[Tv(4267)]),
+];
+
+static LEVEL_6: [((usize, bool, CellType), &[GateInput]); 168] = [
+    ((41, true, XOR2), &[Arg(0, 41), Tv(889)]),
+    ((90, true, XOR2), &[Arg(0, 90), Tv(1198)]),
+    ((139, true, XOR2), &[Arg(0, 139), Tv(1507)]),
+    ((188, true, XOR2), &[Arg(0, 188), Tv(1816)]),
+    ((237, true, XOR2), &[Arg(0, 237), Tv(2125)]),
+    ((286, true, XOR2), &[Arg(0, 286), Tv(2434)]),
+    ((335, true, XOR2), &[Arg(0, 335), Tv(2743)]),
+    ((384, true, XOR2), &[Arg(0, 384), Tv(3052)]),
+    ((433, true, XOR2), &[Arg(0, 433), Tv(3361)]),
+    ((482, true, XOR2), &[Arg(0, 482), Tv(3670)]),
+    ((531, true, XOR2), &[Arg(0, 531), Tv(3979)]),
+    ((580, true, XOR2), &[Arg(0, 580), Tv(4288)]),
+    ((792, false, INV), &[Tv(650)]),
+    ((793, false, INV), &[Tv(697)]),
+    ((795, false, INV), &[Tv(744)]),
+    ((796, false, INV), &[Tv(791)]),
+    ((837, false, AND2), &[Tv(818), Tv(836)]),
+    ((840, false, AND2), &[Tv(822), Tv(839)]),
+    ((844, false, AND2), &[Tv(827), Tv(843)]),
+    ((848, false, AND2), &[Tv(831), Tv(847)]),
+    ((855, false, NAND2), &[Tv(854), Tv(849)]),
+    ((871, false, AND2), &[Tv(870), Tv(869)]),
+    ((873, false, XOR2), &[Arg(0, 44), Tv(859)]),
+    ((891, false, AND2), &[Arg(0, 32), Tv(890)]),
+    ((905, false, AND2), &[Tv(864), Tv(904)]),
+    ((1101, false, INV), &[Tv(959)]),
+    ((1102, false, INV), &[Tv(1006)]),
+    ((1104, false, INV), &[Tv(1053)]),
+    ((1105, false, INV), &[Tv(1100)]),
+    ((1146, false, AND2), &[Tv(1127), Tv(1145)]),
+    ((1149, false, AND2), &[Tv(1131), Tv(1148)]),
+    ((1153, false, AND2), &[Tv(1136), Tv(1152)]),
+    ((1157, false, AND2), &[Tv(1140), Tv(1156)]),
+    ((1164, false, NAND2), &[Tv(1163), Tv(1158)]),
+    ((1180, false, AND2), &[Tv(1179), Tv(1178)]),
+    ((1182, false, XOR2), &[Arg(0, 93), Tv(1168)]),
+    ((1200, false, AND2), &[Arg(0, 81), Tv(1199)]),
+    ((1214, false, AND2), &[Tv(1173), Tv(1213)]),
+    ((1410, false, INV), &[Tv(1268)]),
+    ((1411, false, INV), &[Tv(1315)]),
+    ((1413, false, INV), &[Tv(1362)]),
+    ((1414, false, INV), &[Tv(1409)]),
+    ((1455, false, AND2), &[Tv(1436), Tv(1454)]),
+    ((1458, false, AND2), &[Tv(1440), Tv(1457)]),
+    ((1462, false, AND2), &[Tv(1445), Tv(1461)]),
+    ((1466, false, AND2), &[Tv(1449), Tv(1465)]),
+    ((1473, false, NAND2), &[Tv(1472), Tv(1467)]),
+    ((1489, false, AND2), &[Tv(1488), Tv(1487)]),
+    ((1491, false, XOR2), &[Arg(0, 142), Tv(1477)]),
+    ((1509, false, AND2), &[Arg(0, 130), Tv(1508)]),
+    ((1523, false, AND2), &[Tv(1482), Tv(1522)]),
+    ((1719, false, INV), &[Tv(1577)]),
+    ((1720, false, INV), &[Tv(1624)]),
+    ((1722, false, INV), &[Tv(1671)]),
+    ((1723, false, INV), &[Tv(1718)]),
+    ((1764, false, AND2), &[Tv(1745), Tv(1763)]),
+    ((1767, false, AND2), &[Tv(1749), Tv(1766)]),
+    ((1771, false, AND2), &[Tv(1754), Tv(1770)]),
+    ((1775, false, AND2), &[Tv(1758), Tv(1774)]),
+    ((1782, false, NAND2), &[Tv(1781), Tv(1776)]),
+    ((1798, false, AND2), &[Tv(1797), Tv(1796)]),
+    ((1800, false, XOR2), &[Arg(0, 191), Tv(1786)]),
+    ((1818, false, AND2), &[Arg(0, 179), Tv(1817)]),
+    ((1832, false, AND2), &[Tv(1791), Tv(1831)]),
+    ((2028, false, INV), &[Tv(1886)]),
+    ((2029, false, INV), &[Tv(1933)]),
+    ((2031, false, INV), &[Tv(1980)]),
+    ((2032, false, INV), &[Tv(2027)]),
+    ((2073, false, AND2), &[Tv(2054), Tv(2072)]),
+    ((2076, false, AND2), &[Tv(2058), Tv(2075)]),
+    ((2080, false, AND2), &[Tv(2063), Tv(2079)]),
+    ((2084, false, AND2), &[Tv(2067), Tv(2083)]),
+    ((2091, false, NAND2), &[Tv(2090), Tv(2085)]),
+    ((2107, false, AND2), &[Tv(2106), Tv(2105)]),
+    ((2109, false, XOR2), &[Arg(0, 240), Tv(2095)]),
+    ((2127, false, AND2), &[Arg(0, 228), Tv(2126)]),
+    ((2141, false, AND2), &[Tv(2100), Tv(2140)]),
+    ((2337, false, INV), &[Tv(2195)]),
+    ((2338, false, INV), &[Tv(2242)]),
+    ((2340, false, INV), &[Tv(2289)]),
+    ((2341, false, INV), &[Tv(2336)]),
+    ((2382, false, AND2), &[Tv(2363), Tv(2381)]),
+    ((2385, false, AND2), &[Tv(2367), Tv(2384)]),
+    ((2389, false, AND2), &[Tv(2372), Tv(2388)]),
+    ((2393, false, AND2), &[Tv(2376), Tv(2392)]),
+    ((2400, false, NAND2), &[Tv(2399), Tv(2394)]),
+    ((2416, false, AND2), &[Tv(2415), Tv(2414)]),
+    ((2418, false, XOR2), &[Arg(0, 289), Tv(2404)]),
+    ((2436, false, AND2), &[Arg(0, 277), Tv(2435)]),
+    ((2450, false, AND2), &[Tv(2409), Tv(2449)]),
+    ((2646, false, INV), &[Tv(2504)]),
+    ((2647, false, INV), &[Tv(2551)]),
+    ((2649, false, INV), &[Tv(2598)]),
+    ((2650, false, INV), &[Tv(2645)]),
+    ((2691, false, AND2), &[Tv(2672), Tv(2690)]),
+    ((2694, false, AND2), &[Tv(2676), Tv(2693)]),
+    ((2698, false, AND2), &[Tv(2681), Tv(2697)]),
+    ((2702, false, AND2), &[Tv(2685), Tv(2701)]),
+    ((2709, false, NAND2), &[Tv(2708), Tv(2703)]),
+    ((2725, false, AND2), &[Tv(2724), Tv(2723)]),
+    ((2727, false, XOR2), &[Arg(0, 338), Tv(2713)]),
+    ((2745, false, AND2), &[Arg(0, 326), Tv(2744)]),
+    ((2759, false, AND2), &[Tv(2718), Tv(2758)]),
+    ((2955, false, INV), &[Tv(2813)]),
+    ((2956, false, INV), &[Tv(2860)]),
+    ((2958, false, INV), &[Tv(2907)]),
+    ((2959, false, INV), &[Tv(2954)]),
+    ((3000, false, AND2), &[Tv(2981), Tv(2999)]),
+    ((3003, false, AND2), &[Tv(2985), Tv(3002)]),
+    ((3007, false, AND2), &[Tv(2990), Tv(3006)]),
+    ((3011, false, AND2), &[Tv(2994), Tv(3010)]),
+    ((3018, false, NAND2), &[Tv(3017), Tv(3012)]),
+    ((3034, false, AND2), &[Tv(3033), Tv(3032)]),
+    ((3036, false, XOR2), &[Arg(0, 387), Tv(3022)]),
+    ((3054, false, AND2), &[Arg(0, 375), Tv(3053)]),
+    ((3068, false, AND2), &[Tv(3027), Tv(3067)]),
+    ((3264, false, INV), &[Tv(3122)]),
+    ((3265, false, INV), &[Tv(3169)]),
+    ((3267, false, INV), &[Tv(3216)]),
+    ((3268, false, INV), &[Tv(3263)]),
+    ((3309, false, AND2), &[Tv(3290), Tv(3308)]),
+    ((3312, false, AND2), &[Tv(3294), Tv(3311)]),
+    ((3316, false, AND2), &[Tv(3299), Tv(3315)]),
+    ((3320, false, AND2), &[Tv(3303), Tv(3319)]),
+    ((3327, false, NAND2), &[Tv(3326), Tv(3321)]),
+    ((3343, false, AND2), &[Tv(3342), Tv(3341)]),
+    ((3345, false, XOR2), &[Arg(0, 436), Tv(3331)]),
+    ((3363, false, AND2), &[Arg(0, 424), Tv(3362)]),
+    ((3377, false, AND2), &[Tv(3336), Tv(3376)]),
+    ((3573, false, INV), &[Tv(3431)]),
+    ((3574, false, INV), &[Tv(3478)]),
+    ((3576, false, INV), &[Tv(3525)]),
+    ((3577, false, INV), &[Tv(3572)]),
+    ((3618, false, AND2), &[Tv(3599), Tv(3617)]),
+    ((3621, false, AND2), &[Tv(3603), Tv(3620)]),
+    ((3625, false, AND2), &[Tv(3608), Tv(3624)]),
+    ((3629, false, AND2), &[Tv(3612), Tv(3628)]),
+    ((3636, false, NAND2), &[Tv(3635), Tv(3630)]),
+    ((3652, false, AND2), &[Tv(3651), Tv(3650)]),
+    ((3654, false, XOR2), &[Arg(0, 485), Tv(3640)]),
+    ((3672, false, AND2), &[Arg(0, 473), Tv(3671)]),
+    ((3686, false, AND2), &[Tv(3645), Tv(3685)]),
+    ((3882, false, INV), &[Tv(3740)]),
+    ((3883, false, INV), &[Tv(3787)]),
+    ((3885, false, INV), &[Tv(3834)]),
+    ((3886, false, INV), &[Tv(3881)]),
+    ((3927, false, AND2), &[Tv(3908), Tv(3926)]),
+    ((3930, false, AND2), &[Tv(3912), Tv(3929)]),
+    ((3934, false, AND2), &[Tv(3917), Tv(3933)]),
+    ((3938, false, AND2), &[Tv(3921), Tv(3937)]),
+    ((3945, false, NAND2), &[Tv(3944), Tv(3939)]),
+    ((3961, false, AND2), &[Tv(3960), Tv(3959)]),
+    ((3963, false, XOR2), &[Arg(0, 534), Tv(3949)]),
+    ((3981, false, AND2), &[Arg(0, 522), Tv(3980)]),
+    ((3995, false, AND2), &[Tv(3954), Tv(3994)]),
+    ((4191, false, INV), &[Tv(4049)]),
+    ((4192, false, INV), &[Tv(4096)]),
+    ((4194, false, INV), &[Tv(4143)]),
+    ((4195, false, INV), &[Tv(4190)]),
+    ((4236, false, AND2), &[Tv(4217), Tv(4235)]),
+    ((4239, false, AND2), &[Tv(4221), Tv(4238)]),
+    ((4243, false, AND2), &[Tv(4226), Tv(4242)]),
+    ((4247, false, AND2), &[Tv(4230), Tv(4246)]),
+    ((4254, false, NAND2), &[Tv(4253), Tv(4248)]),
+    ((4270, false, AND2), &[Tv(4269), Tv(4268)]),
+    ((4272, false, XOR2), &[Arg(0, 583), Tv(4258)]),
+    ((4290, false, AND2), &[Arg(0, 571), Tv(4289)]),
+    ((4304, false, AND2), &[Tv(4263), Tv(4303)]),
+];
+
+static LEVEL_7: [((usize, bool, CellType), &[GateInput]); 108] = [
+    ((42, true, XOR2), &[Arg(0, 42), Tv(891)]),
+    ((91, true, XOR2), &[Arg(0, 91), Tv(1200)]),
+    ((140, true, XOR2), &[Arg(0, 140), Tv(1509)]),
+    ((189, true, XOR2), &[Arg(0, 189), Tv(1818)]),
+    ((238, true, XOR2), &[Arg(0, 238), Tv(2127)]),
+    ((287, true, XOR2), &[Arg(0, 287), Tv(2436)]),
+    ((336, true, XOR2), &[Arg(0, 336), Tv(2745)]),
+    ((385, true, XOR2), &[Arg(0, 385), Tv(3054)]),
+    ((434, true, XOR2), &[Arg(0, 434), Tv(3363)]),
+    ((483, true, XOR2), &[Arg(0, 483), Tv(3672)]),
+    ((532, true, XOR2), &[Arg(0, 532), Tv(3981)]),
+    ((581, true, XOR2), &[Arg(0, 581), Tv(4290)]),
+    ((794, false, NOR2), &[Tv(792), Tv(793)]),
+    ((797, false, NOR2), &[Tv(795), Tv(796)]),
+    ((851, false, AND2), &[Tv(840), Tv(850)]),
+    ((853, false, AND2), &[Tv(844), Tv(852)]),
+    ((856, false, AND2), &[Tv(848), Tv(855)]),
+    ((860, false, XOR2), &[Arg(0, 45), Tv(837)]),
+    ((872, false, XOR2), &[Arg(0, 43), Tv(871)]),
+    ((874, false, AND2), &[Tv(870), Tv(873)]),
+    ((1103, false, NOR2), &[Tv(1101), Tv(1102)]),
+    ((1106, false, NOR2), &[Tv(1104), Tv(1105)]),
+    ((1160, false, AND2), &[Tv(1149), Tv(1159)]),
+    ((1162, false, AND2), &[Tv(1153), Tv(1161)]),
+    ((1165, false, AND2), &[Tv(1157), Tv(1164)]),
+    ((1169, false, XOR2), &[Arg(0, 94), Tv(1146)]),
+    ((1181, false, XOR2), &[Arg(0, 92), Tv(1180)]),
+    ((1183, false, AND2), &[Tv(1179), Tv(1182)]),
+    ((1412, false, NOR2), &[Tv(1410), Tv(1411)]),
+    ((1415, false, NOR2), &[Tv(1413), Tv(1414)]),
+    ((1469, false, AND2), &[Tv(1458), Tv(1468)]),
+    ((1471, false, AND2), &[Tv(1462), Tv(1470)]),
+    ((1474, false, AND2), &[Tv(1466), Tv(1473)]),
+    ((1478, false, XOR2), &[Arg(0, 143), Tv(1455)]),
+    ((1490, false, XOR2), &[Arg(0, 141), Tv(1489)]),
+    ((1492, false, AND2), &[Tv(1488), Tv(1491)]),
+    ((1721, false, NOR2), &[Tv(1719), Tv(1720)]),
+    ((1724, false, NOR2), &[Tv(1722), Tv(1723)]),
+    ((1778, false, AND2), &[Tv(1767), Tv(1777)]),
+    ((1780, false, AND2), &[Tv(1771), Tv(1779)]),
+    ((1783, false, AND2), &[Tv(1775), Tv(1782)]),
+    ((1787, false, XOR2), &[Arg(0, 192), Tv(1764)]),
+    ((1799, false, XOR2), &[Arg(0, 190), Tv(1798)]),
+    ((1801, false, AND2), &[Tv(1797), Tv(1800)]),
+    ((2030, false, NOR2), &[Tv(2028), Tv(2029)]),
+    ((2033, false, NOR2), &[Tv(2031), Tv(2032)]),
+    ((2087, false, AND2), &[Tv(2076), Tv(2086)]),
+    ((2089, false, AND2), &[Tv(2080), Tv(2088)]),
+    ((2092, false, AND2), &[Tv(2084), Tv(2091)]),
+    ((2096, false, XOR2), &[Arg(0, 241), Tv(2073)]),
+    ((2108, false, XOR2), &[Arg(0, 239), Tv(2107)]),
+    ((2110, false, AND2), &[Tv(2106), Tv(2109)]),
+    ((2339, false, NOR2), &[Tv(2337), Tv(2338)]),
+    ((2342, false, NOR2), &[Tv(2340), Tv(2341)]),
+    ((2396, false, AND2), &[Tv(2385), Tv(2395)]),
+    ((2398, false, AND2), &[Tv(2389), Tv(2397)]),
+    ((2401, false, AND2), &[Tv(2393), Tv(2400)]),
+    ((2405, false, XOR2), &[Arg(0, 290), Tv(2382)]),
+    ((2417, false, XOR2), &[Arg(0, 288), Tv(2416)]),
+    ((2419, false, AND2), &[Tv(2415), Tv(2418)]),
+    ((2648, false, NOR2), &[Tv(2646), Tv(2647)]),
+    ((2651, false, NOR2), &[Tv(2649), Tv(2650)]),
+    ((2705, false, AND2), &[Tv(2694), Tv(2704)]),
+    ((2707, false, AND2), &[Tv(2698), Tv(2706)]),
+    ((2710, false, AND2), &[Tv(2702), Tv(2709)]),
+    ((2714, false, XOR2), &[Arg(0, 339), Tv(2691)]),
+    ((2726, false, XOR2), &[Arg(0, 337), Tv(2725)]),
+    ((2728, false, AND2), &[Tv(2724), Tv(2727)]),
+    ((2957, false, NOR2), &[Tv(2955), Tv(2956)]),
+    ((2960, false, NOR2), &[Tv(2958), Tv(2959)]),
+    ((3014, false, AND2), &[Tv(3003), Tv(3013)]),
+    ((3016, false, AND2), &[Tv(3007), Tv(3015)]),
+    ((3019, false, AND2), &[Tv(3011), Tv(3018)]),
+    ((3023, false, XOR2), &[Arg(0, 388), Tv(3000)]),
+    ((3035, false, XOR2), &[Arg(0, 386), Tv(3034)]),
+    ((3037, false, AND2), &[Tv(3033), Tv(3036)]),
+    ((3266, false, NOR2), &[Tv(3264), Tv(3265)]),
+    ((3269, false, NOR2), &[Tv(3267), Tv(3268)]),
+    ((3323, false, AND2), &[Tv(3312), Tv(3322)]),
+    ((3325, false, AND2), &[Tv(3316), Tv(3324)]),
+    ((3328, false, AND2), &[Tv(3320), Tv(3327)]),
+    ((3332, false, XOR2), &[Arg(0, 437), Tv(3309)]),
+    ((3344, false, XOR2), &[Arg(0, 435), Tv(3343)]),
+    ((3346, false, AND2), &[Tv(3342), Tv(3345)]),
+    ((3575, false, NOR2), &[Tv(3573), Tv(3574)]),
+    ((3578, false, NOR2), &[Tv(3576), Tv(3577)]),
+    ((3632, false, AND2), &[Tv(3621), Tv(3631)]),
+    ((3634, false, AND2), &[Tv(3625), Tv(3633)]),
+    ((3637, false, AND2), &[Tv(3629), Tv(3636)]),
+    ((3641, false, XOR2), &[Arg(0, 486), Tv(3618)]),
+    ((3653, false, XOR2), &[Arg(0, 484), Tv(3652)]),
+    ((3655, false, AND2), &[Tv(3651), Tv(3654)]),
+    ((3884, false, NOR2), &[Tv(3882), Tv(3883)]),
+    ((3887, false, NOR2), &[Tv(3885), Tv(3886)]),
+    ((3941, false, AND2), &[Tv(3930), Tv(3940)]),
+    ((3943, false, AND2), &[Tv(3934), Tv(3942)]),
+    ((3946, false, AND2), &[Tv(3938), Tv(3945)]),
+    ((3950, false, XOR2), &[Arg(0, 535), Tv(3927)]),
+    ((3962, false, XOR2), &[Arg(0, 533), Tv(3961)]),
+    ((3964, false, AND2), &[Tv(3960), Tv(3963)]),
+    ((4193, false, NOR2), &[Tv(4191), Tv(4192)]),
+    ((4196, false, NOR2), &[Tv(4194), Tv(4195)]),
+    ((4250, false, AND2), &[Tv(4239), Tv(4249)]),
+    ((4252, false, AND2), &[Tv(4243), Tv(4251)]),
+    ((4255, false, AND2), &[Tv(4247), Tv(4254)]),
+    ((4259, false, XOR2), &[Arg(0, 584), Tv(4236)]),
+    ((4271, false, XOR2), &[Arg(0, 582), Tv(4270)]),
+    ((4273, false, AND2), &[Tv(4269), Tv(4272)]),
+];
+
+static LEVEL_8: [((usize, bool, CellType), &[GateInput]); 84] = [
+    ((798, false, AND2), &[Tv(794), Tv(797)]),
+    ((861, false, XOR2), &[Arg(0, 46), Tv(851)]),
+    ((862, false, XOR2), &[Arg(0, 47), Tv(853)]),
+    ((863, false, XOR2), &[Arg(0, 48), Tv(856)]),
+    ((875, false, XOR2), &[Arg(0, 44), Tv(874)]),
+    ((876, false, XOR2), &[Arg(0, 45), Tv(860)]),
+    ((892, false, XOR2), &[Arg(0, 43), Tv(872)]),
+    ((1107, false, AND2), &[Tv(1103), Tv(1106)]),
+    ((1170, false, XOR2), &[Arg(0, 95), Tv(1160)]),
+    ((1171, false, XOR2), &[Arg(0, 96), Tv(1162)]),
+    ((1172, false, XOR2), &[Arg(0, 97), Tv(1165)]),
+    ((1184, false, XOR2), &[Arg(0, 93), Tv(1183)]),
+    ((1185, false, XOR2), &[Arg(0, 94), Tv(1169)]),
+    ((1201, false, XOR2), &[Arg(0, 92), Tv(1181)]),
+    ((1416, false, AND2), &[Tv(1412), Tv(1415)]),
+    ((1479, false, XOR2), &[Arg(0, 144), Tv(1469)]),
+    ((1480, false, XOR2), &[Arg(0, 145), Tv(1471)]),
+    ((1481, false, XOR2), &[Arg(0, 146), Tv(1474)]),
+    ((1493, false, XOR2), &[Arg(0, 142), Tv(1492)]),
+    ((1494, false, XOR2), &[Arg(0, 143), Tv(1478)]),
+    ((1510, false, XOR2), &[Arg(0, 141), Tv(1490)]),
+    ((1725, false, AND2), &[Tv(1721), Tv(1724)]),
+    ((1788, false, XOR2), &[Arg(0, 193), Tv(1778)]),
+    ((1789, false, XOR2), &[Arg(0, 194), Tv(1780)]),
+    ((1790, false, XOR2), &[Arg(0, 195), Tv(1783)]),
+    ((1802, false, XOR2), &[Arg(0, 191), Tv(1801)]),
+    ((1803, false, XOR2), &[Arg(0, 192), Tv(1787)]),
+    ((1819, false, XOR2), &[Arg(0, 190), Tv(1799)]),
+    ((2034, false, AND2), &[Tv(2030), Tv(2033)]),
+    ((2097, false, XOR2), &[Arg(0, 242), Tv(2087)]),
+    ((2098, false, XOR2), &[Arg(0, 243), Tv(2089)]),
+    ((2099, false, XOR2), &[Arg(0, 244), Tv(2092)]),
+    ((2111, false, XOR2), &[Arg(0, 240), Tv(2110)]),
+    ((2112, false, XOR2), &[Arg(0, 241), Tv(2096)]),
+    ((2128, false, XOR2), &[Arg(0, 239), Tv(2108)]),
+    ((2343, false, AND2), &[Tv(2339), Tv(2342)]),
+    ((2406, false, XOR2), &[Arg(0, 291), Tv(2396)]),
+    ((2407, false, XOR2), &[Arg(0, 292), Tv(2398)]),
+    ((2408, false, XOR2), &[Arg(0, 293), Tv(2401)]),
+    ((2420, false, XOR2), &[Arg(0, 289), Tv(2419)]),
+    ((2421, false, XOR2), &[Arg(0, 290), Tv(2405)]),
+    ((2437, false, XOR2), &[Arg(0, 288), Tv(2417)]),
+    ((2652, false, AND2), &[Tv(2648), Tv(2651)]),
+    ((2715, false, XOR2), &[Arg(0, 340), Tv(2705)]),
+    ((2716, false, XOR2), &[Arg(0, 341), Tv(2707)]),
+    ((2717, false, XOR2), &[Arg(0, 342), Tv(2710)]),
+    ((2729, false, XOR2), &[Arg(0, 338), Tv(2728)]),
+    ((2730, false, XOR2), &[Arg(0, 339), Tv(2714)]),
+    ((2746, false, XOR2), &[Arg(0, 337), Tv(2726)]),
+    ((2961, false, AND2), &[Tv(2957), Tv(2960)]),
+    ((3024, false, XOR2), &[Arg(0, 389), Tv(3014)]),
+    ((3025, false, XOR2), &[Arg(0, 390), Tv(3016)]),
+    ((3026, false, XOR2), &[Arg(0, 391), Tv(3019)]),
+    ((3038, false, XOR2), &[Arg(0, 387), Tv(3037)]),
+    ((3039, false, XOR2), &[Arg(0, 388), Tv(3023)]),
+    ((3055, false, XOR2), &[Arg(0, 386), Tv(3035)]),
+    ((3270, false, AND2), &[Tv(3266), Tv(3269)]),
+    ((3333, false, XOR2), &[Arg(0, 438), Tv(3323)]),
+    ((3334, false, XOR2), &[Arg(0, 439), Tv(3325)]),
+    ((3335, false, XOR2), &[Arg(0, 440), Tv(3328)]),
+    ((3347, false, XOR2), &[Arg(0, 436), Tv(3346)]),
+    ((3348, false, XOR2), &[Arg(0, 437), Tv(3332)]),
+    ((3364, false, XOR2), &[Arg(0, 435), Tv(3344)]),
+    ((3579, false, AND2), &[Tv(3575), Tv(3578)]),
+    ((3642, false, XOR2), &[Arg(0, 487), Tv(3632)]),
+    ((3643, false, XOR2), &[Arg(0, 488), Tv(3634)]),
+    ((3644, false, XOR2), &[Arg(0, 489), Tv(3637)]),
+    ((3656, false, XOR2), &[Arg(0, 485), Tv(3655)]),
+    ((3657, false, XOR2), &[Arg(0, 486), Tv(3641)]),
+    ((3673, false, XOR2), &[Arg(0, 484), Tv(3653)]),
+    ((3888, false, AND2), &[Tv(3884), Tv(3887)]),
+    ((3951, false, XOR2), &[Arg(0, 536), Tv(3941)]),
+    ((3952, false, XOR2), &[Arg(0, 537), Tv(3943)]),
+    ((3953, false, XOR2), &[Arg(0, 538), Tv(3946)]),
+    ((3965, false, XOR2), &[Arg(0, 534), Tv(3964)]),
+    ((3966, false, XOR2), &[Arg(0, 535), Tv(3950)]),
+    ((3982, false, XOR2), &[Arg(0, 533), Tv(3962)]),
+    ((4197, false, AND2), &[Tv(4193), Tv(4196)]),
+    ((4260, false, XOR2), &[Arg(0, 585), Tv(4250)]),
+    ((4261, false, XOR2), &[Arg(0, 586), Tv(4252)]),
+    ((4262, false, XOR2), &[Arg(0, 587), Tv(4255)]),
+    ((4274, false, XOR2), &[Arg(0, 583), Tv(4273)]),
+    ((4275, false, XOR2), &[Arg(0, 584), Tv(4259)]),
+    ((4291, false, XOR2), &[Arg(0, 582), Tv(4271)]),
+];
+
+static LEVEL_9: [((usize, bool, CellType), &[GateInput]); 84] = [
+    ((877, false, AND2), &[Tv(870), Tv(876)]),
+    ((879, false, XOR2), &[Arg(0, 46), Tv(861)]),
+    ((882, false, XOR2), &[Arg(0, 47), Tv(862)]),
+    ((885, false, XOR2), &[Arg(0, 48), Tv(863)]),
+    ((893, false, AND2), &[Arg(0, 32), Tv(892)]),
+    ((894, false, XOR2), &[Arg(0, 44), Tv(875)]),
+    ((906, false, NOR2), &[Tv(872), Tv(875)]),
+    ((1186, false, AND2), &[Tv(1179), Tv(1185)]),
+    ((1188, false, XOR2), &[Arg(0, 95), Tv(1170)]),
+    ((1191, false, XOR2), &[Arg(0, 96), Tv(1171)]),
+    ((1194, false, XOR2), &[Arg(0, 97), Tv(1172)]),
+    ((1202, false, AND2), &[Arg(0, 81), Tv(1201)]),
+    ((1203, false, XOR2), &[Arg(0, 93), Tv(1184)]),
+    ((1215, false, NOR2), &[Tv(1181), Tv(1184)]),
+    ((1495, false, AND2), &[Tv(1488), Tv(1494)]),
+    ((1497, false, XOR2), &[Arg(0, 144), Tv(1479)]),
+    ((1500, false, XOR2), &[Arg(0, 145), Tv(1480)]),
+    ((1503, false, XOR2), &[Arg(0, 146), Tv(1481)]),
+    ((1511, false, AND2), &[Arg(0, 130), Tv(1510)]),
+    ((1512, false, XOR2), &[Arg(0, 142), Tv(1493)]),
+    ((1524, false, NOR2), &[Tv(1490), Tv(1493)]),
+    ((1804, false, AND2), &[Tv(1797), Tv(1803)]),
+    ((1806, false, XOR2), &[Arg(0, 193), Tv(1788)]),
+    ((1809, false, XOR2), &[Arg(0, 194), Tv(1789)]),
+    ((1812, false, XOR2), &[Arg(0, 195), Tv(1790)]),
+    ((1820, false, AND2), &[Arg(0, 179), Tv(1819)]),
+    ((1821, false, XOR2), &[Arg(0, 191), Tv(1802)]),
+    ((1833, false, NOR2), &[Tv(1799), Tv(1802)]),
+    ((2113, false, AND2), &[Tv(2106), Tv(2112)]),
+    ((2115, false, XOR2), &[Arg(0, 242), Tv(2097)]),
+    ((2118, false, XOR2), &[Arg(0, 243), Tv(2098)]),
+    ((2121, false, XOR2), &[Arg(0, 244), Tv(2099)]),
+    ((2129, false, AND2), &[Arg(0, 228), Tv(2128)]),
+    ((2130, false, XOR2), &[Arg(0, 240), Tv(2111)]),
+    ((2142, false, NOR2), &[Tv(2108), Tv(2111)]),
+    ((2422, false, AND2), &[Tv(2415), Tv(2421)]),
+    ((2424, false, XOR2), &[Arg(0, 291), Tv(2406)]),
+    ((2427, false, XOR2), &[Arg(0, 292), Tv(2407)]),
+    ((2430, false, XOR2), &[Arg(0, 293), Tv(2408)]),
+    ((2438, false, AND2), &[Arg(0, 277), Tv(2437)]),
+    ((2439, false, XOR2), &[Arg(0, 289), Tv(2420)]),
+    ((2451, false, NOR2), &[Tv(2417), Tv(2420)]),
+    ((2731, false, AND2), &[Tv(2724), Tv(2730)]),
+    ((2733, false, XOR2), &[Arg(0, 340), Tv(2715)]),
+    ((2736, false, XOR2), &[Arg(0, 341), Tv(2716)]),
+    ((2739, false, XOR2), &[Arg(0, 342), Tv(2717)]),
+    ((2747, false, AND2), &[Arg(0, 326), Tv(2746)]),
+    ((2748, false, XOR2), &[Arg(0, 338), Tv(2729)]),
+    ((2760, false, NOR2), &[Tv(2726), Tv(2729)]),
+    ((3040, false, AND2), &[Tv(3033), Tv(3039)]),
+    ((3042, false, XOR2), &[Arg(0, 389), Tv(3024)]),
+    ((3045, false, XOR2), &[Arg(0, 390), Tv(3025)]),
+    ((3048, false, XOR2), &[Arg(0, 391), Tv(3026)]),
+    ((3056, false, AND2), &[Arg(0, 375), Tv(3055)]),
+    ((3057, false, XOR2), &[Arg(0, 387), Tv(3038)]),
+    ((3069, false, NOR2), &[Tv(3035), Tv(3038)]),
+    ((3349, false, AND2), &[Tv(3342), Tv(3348)]),
+    ((3351, false, XOR2), &[Arg(0, 438), Tv(3333)]),
+    ((3354, false, XOR2), &[Arg(0, 439), Tv(3334)]),
+    ((3357, false, XOR2), &[Arg(0, 440), Tv(3335)]),
+    ((3365, false, AND2), &[Arg(0, 424), Tv(3364)]),
+    ((3366, false, XOR2), &[Arg(0, 436), Tv(3347)]),
+    ((3378, false, NOR2), &[Tv(3344), Tv(3347)]),
+    ((3658, false, AND2), &[Tv(3651), Tv(3657)]),
+    ((3660, false, XOR2), &[Arg(0, 487), Tv(3642)]),
+    ((3663, false, XOR2), &[Arg(0, 488), Tv(3643)]),
+    ((3666, false, XOR2), &[Arg(0, 489), Tv(3644)]),
+    ((3674, false, AND2), &[Arg(0, 473), Tv(3673)]),
+    ((3675, false, XOR2), &[Arg(0, 485), Tv(3656)]),
+    ((3687, false, NOR2), &[Tv(3653), Tv(3656)]),
+    ((3967, false, AND2), &[Tv(3960), Tv(3966)]),
+    ((3969, false, XOR2), &[Arg(0, 536), Tv(3951)]),
+    ((3972, false, XOR2), &[Arg(0, 537), Tv(3952)]),
+    ((3975, false, XOR2), &[Arg(0, 538), Tv(3953)]),
+    ((3983, false, AND2), &[Arg(0, 522), Tv(3982)]),
+    ((3984, false, XOR2), &[Arg(0, 534), Tv(3965)]),
+    ((3996, false, NOR2), &[Tv(3962), Tv(3965)]),
+    ((4276, false, AND2), &[Tv(4269), Tv(4275)]),
+    ((4278, false, XOR2), &[Arg(0, 585), Tv(4260)]),
+    ((4281, false, XOR2), &[Arg(0, 586), Tv(4261)]),
+    ((4284, false, XOR2), &[Arg(0, 587), Tv(4262)]),
+    ((4292, false, AND2), &[Arg(0, 571), Tv(4291)]),
+    ((4293, false, XOR2), &[Arg(0, 583), Tv(4274)]),
+    ((4305, false, NOR2), &[Tv(4271), Tv(4274)]),
+];
+
+static LEVEL_10: [((usize, bool, CellType), &[GateInput]); 84] = [
+    ((43, true, XOR2), &[Arg(0, 43), Tv(893)]),
+    ((92, true, XOR2), &[Arg(0, 92), Tv(1202)]),
+    ((141, true, XOR2), &[Arg(0, 141), Tv(1511)]),
+    ((190, true, XOR2), &[Arg(0, 190), Tv(1820)]),
+    ((239, true, XOR2), &[Arg(0, 239), Tv(2129)]),
+    ((288, true, XOR2), &[Arg(0, 288), Tv(2438)]),
+    ((337, true, XOR2), &[Arg(0, 337), Tv(2747)]),
+    ((386, true, XOR2), &[Arg(0, 386), Tv(3056)]),
+    ((435, true, XOR2), &[Arg(0, 435), Tv(3365)]),
+    ((484, true, XOR2), &[Arg(0, 484), Tv(3674)]),
+    ((533, true, XOR2), &[Arg(0, 533), Tv(3983)]),
+    ((582, true, XOR2), &[Arg(0, 582), Tv(4292)]),
+    ((878, false, XOR2), &[Arg(0, 45), Tv(877)]),
+    ((880, false, AND2), &[Tv(870), Tv(879)]),
+    ((883, false, AND2), &[Tv(870), Tv(882)]),
+    ((886, false, AND2), &[Tv(870), Tv(885)]),
+    ((895, false, AND2), &[Arg(0, 32), Tv(894)]),
+    ((909, false, AND2), &[Tv(905), Tv(906)]),
+    ((1187, false, XOR2), &[Arg(0, 94), Tv(1186)]),
+    ((1189, false, AND2), &[Tv(1179), Tv(1188)]),
+    ((1192, false, AND2), &[Tv(1179), Tv(1191)]),
+    ((1195, false, AND2), &[Tv(1179), Tv(1194)]),
+    ((1204, false, AND2), &[Arg(0, 81), Tv(1203)]),
+    ((1218, false, AND2), &[Tv(1214), Tv(1215)]),
+    ((1496, false, XOR2), &[Arg(0, 143), Tv(1495)]),
+    ((1498, false, AND2), &[Tv(1488), Tv(1497)]),
+    ((1501, false, AND2), &[Tv(1488), Tv(1500)]),
+    ((1504, false, AND2), &[Tv(1488), Tv(1503)]),
+    ((1513, false, AND2), &[Arg(0, 130), Tv(1512)]),
+    ((1527, false, AND2), &[Tv(1523), Tv(1524)]),
+    ((1805, false, XOR2), &[Arg(0, 192), Tv(1804)]),
+    ((1807, false, AND2), &[Tv(1797), Tv(1806)]),
+    ((1810, false, AND2), &[Tv(1797), Tv(1809)]),
+    ((1813, false, AND2), &[Tv(1797), Tv(1812)]),
+    ((1822, false, AND2), &[Arg(0, 179), Tv(1821)]),
+    ((1836, false, AND2), &[Tv(1832), Tv(1833)]),
+    ((2114, false, XOR2), &[Arg(0, 241), Tv(2113)]),
+    ((2116, false, AND2), &[Tv(2106), Tv(2115)]),
+    ((2119, false, AND2), &[Tv(2106), Tv(2118)]),
+    ((2122, false, AND2), &[Tv(2106), Tv(2121)]),
+    ((2131, false, AND2), &[Arg(0, 228), Tv(2130)]),
+    ((2145, false, AND2), &[Tv(2141), Tv(2142)]),
+    ((2423, false, XOR2), &[Arg(0, 290), Tv(2422)]),
+    ((2425, false, AND2), &[Tv(2415), Tv(2424)]),
+    ((2428, false, AND2), &[Tv(2415), Tv(2427)]),
+    ((2431, false, AND2), &[Tv(2415), Tv(2430)]),
+    ((2440, false, AND2), &[Arg(0, 277), Tv(2439)]),
+    ((2454, false, AND2), &[Tv(2450), Tv(2451)]),
+    ((2732, false, XOR2), &[Arg(0, 339), Tv(2731)]),
+    ((2734, false, AND2), &[Tv(2724), Tv(2733)]),
+    ((2737, false, AND2), &[Tv(2724), Tv(2736)]),
+    ((2740, false, AND2), &[Tv(2724), Tv(2739)]),
+    ((2749, false, AND2), &[Arg(0, 326), Tv(2748)]),
+    ((2763, false, AND2), &[Tv(2759), Tv(2760)]),
+    ((3041, false, XOR2), &[Arg(0, 388), Tv(3040)]),
+    ((3043, false, AND2), &[Tv(3033), Tv(3042)]),
+    ((3046, false, AND2), &[Tv(3033), Tv(3045)]),
+    ((3049, false, AND2), &[Tv(3033), Tv(3048)]),
+    ((3058, false, AND2), &[Arg(0, 375), Tv(3057)]),
+    ((3072, false, AND2), &[Tv(3068), Tv(3069)]),
+    ((3350, false, XOR2), &[Arg(0, 437), Tv(3349)]),
+    ((3352, false, AND2), &[Tv(3342), Tv(3351)]),
+    ((3355, false, AND2), &[Tv(3342), Tv(3354)]),
+    ((3358, false, AND2), &[Tv(3342), Tv(3357)]),
+    ((3367, false, AND2), &[Arg(0, 424), Tv(3366)]),
+    ((3381, false, AND2), &[Tv(3377), Tv(3378)]),
+    ((3659, false, XOR2), &[Arg(0, 486), Tv(3658)]),
+    ((3661, false, AND2), &[Tv(3651), Tv(3660)]),
+    ((3664, false, AND2), &[Tv(3651), Tv(3663)]),
+    ((3667, false, AND2), &[Tv(3651), Tv(3666)]),
+    ((3676, false, AND2), &[Arg(0, 473), Tv(3675)]),
+    ((3690, false, AND2), &[Tv(3686), Tv(3687)]),
+    ((3968, false, XOR2), &[Arg(0, 535), Tv(3967)]),
+    ((3970, false, AND2), &[Tv(3960), Tv(3969)]),
+    ((3973, false, AND2), &[Tv(3960), Tv(3972)]),
+    ((3976, false, AND2), &[Tv(3960), Tv(3975)]),
+    ((3985, false, AND2), &[Arg(0, 522), Tv(3984)]),
+    ((3999, false, AND2), &[Tv(3995), Tv(3996)]),
+    ((4277, false, XOR2), &[Arg(0, 584), Tv(4276)]),
+    ((4279, false, AND2), &[Tv(4269), Tv(4278)]),
+    ((4282, false, AND2), &[Tv(4269), Tv(4281)]),
+    ((4285, false, AND2), &[Tv(4269), Tv(4284)]),
+    ((4294, false, AND2), &[Arg(0, 571), Tv(4293)]),
+    ((4308, false, AND2), &[Tv(4304), Tv(4305)]),
+];
+
+static LEVEL_11: [((usize, bool, CellType), &[GateInput]); 60] = [
+    ((44, true, XOR2), &[Arg(0, 44), Tv(895)]),
+    ((93, true, XOR2), &[Arg(0, 93), Tv(1204)]),
+    ((142, true, XOR2), &[Arg(0, 142), Tv(1513)]),
+    ((191, true, XOR2), &[Arg(0, 191), Tv(1822)]),
+    ((240, true, XOR2), &[Arg(0, 240), Tv(2131)]),
+    ((289, true, XOR2), &[Arg(0, 289), Tv(2440)]),
+    ((338, true, XOR2), &[Arg(0, 338), Tv(2749)]),
+    ((387, true, XOR2), &[Arg(0, 387), Tv(3058)]),
+    ((436, true, XOR2), &[Arg(0, 436), Tv(3367)]),
+    ((485, true, XOR2), &[Arg(0, 485), Tv(3676)]),
+    ((534, true, XOR2), &[Arg(0, 534), Tv(3985)]),
+    ((583, true, XOR2), &[Arg(0, 583), Tv(4294)]),
+    ((881, false, XOR2), &[Arg(0, 46), Tv(880)]),
+    ((884, false, XOR2), &[Arg(0, 47), Tv(883)]),
+    ((887, false, XOR2), &[Arg(0, 48), Tv(886)]),
+    ((896, false, XOR2), &[Arg(0, 45), Tv(878)]),
+    ((1190, false, XOR2), &[Arg(0, 95), Tv(1189)]),
+    ((1193, false, XOR2), &[Arg(0, 96), Tv(1192)]),
+    ((1196, false, XOR2), &[Arg(0, 97), Tv(1195)]),
+    ((1205, false, XOR2), &[Arg(0, 94), Tv(1187)]),
+    ((1499, false, XOR2), &[Arg(0, 144), Tv(1498)]),
+    ((1502, false, XOR2), &[Arg(0, 145), Tv(1501)]),
+    ((1505, false, XOR2), &[Arg(0, 146), Tv(1504)]),
+    ((1514, false, XOR2), &[Arg(0, 143), Tv(1496)]),
+    ((1808, false, XOR2), &[Arg(0, 193), Tv(1807)]),
+    ((1811, false, XOR2), &[Arg(0, 194), Tv(1810)]),
+    ((1814, false, XOR2), &[Arg(0, 195), Tv(1813)]),
+    ((1823, false, XOR2), &[Arg(0, 192), Tv(1805)]),
+    ((2117, false, XOR2), &[Arg(0, 242), Tv(2116)]),
+    ((2120, false, XOR2), &[Arg(0, 243), Tv(2119)]),
+    ((2123, false, XOR2), &[Arg(0, 244), Tv(2122)]),
+    ((2132, false, XOR2), &[Arg(0, 241), Tv(2114)]),
+    ((2426, false, XOR2), &[Arg(0, 291), Tv(2425)]),
+    ((2429, false, XOR2), &[Arg(0, 292), Tv(2428)]),
+    ((2432, false, XOR2), &[Arg(0, 293), Tv(2431)]),
+    ((2441, false, XOR2), &[Arg(0, 290), Tv(2423)]),
+    ((2735, false, XOR2), &[Arg(0, 340), Tv(2734)]),
+    ((2738, false, XOR2), &[Arg(0, 341), Tv(2737)]),
+    ((2741, false, XOR2), &[Arg(0, 342), Tv(2740)]),
+    ((2750, false, XOR2), &[Arg(0, 339), Tv(2732)]),
+    ((3044, false, XOR2), &[Arg(0, 389), Tv(3043)]),
+    ((3047, false, XOR2), &[Arg(0, 390), Tv(3046)]),
+    ((3050, false, XOR2), &[Arg(0, 391), Tv(3049)]),
+    ((3059, false, XOR2), &[Arg(0, 388), Tv(3041)]),
+    ((3353, false, XOR2), &[Arg(0, 438), Tv(3352)]),
+    ((3356, false, XOR2), &[Arg(0, 439), Tv(3355)]),
+    ((3359, false, XOR2), &[Arg(0, 440), Tv(3358)]),
+    ((3368, false, XOR2), &[Arg(0, 437), Tv(3350)]),
+    ((3662, false, XOR2), &[Arg(0, 487), Tv(3661)]),
+    ((3665, false, XOR2), &[Arg(0, 488), Tv(3664)]),
+    ((3668, false, XOR2), &[Arg(0, 489), Tv(3667)]),
+    ((3677, false, XOR2), &[Arg(0, 486), Tv(3659)]),
+    ((3971, false, XOR2), &[Arg(0, 536), Tv(3970)]),
+    ((3974, false, XOR2), &[Arg(0, 537), Tv(3973)]),
+    ((3977, false, XOR2), &[Arg(0, 538), Tv(3976)]),
+    ((3986, false, XOR2), &[Arg(0, 535), Tv(3968)]),
+    ((4280, false, XOR2), &[Arg(0, 585), Tv(4279)]),
+    ((4283, false, XOR2), &[Arg(0, 586), Tv(4282)]),
+    ((4286, false, XOR2), &[Arg(0, 587), Tv(4285)]),
+    ((4295, false, XOR2), &[Arg(0, 584), Tv(4277)]),
+];
+
+static LEVEL_12: [((usize, bool, CellType), &[GateInput]); 72] = [
+    ((897, false, AND2), &[Arg(0, 32), Tv(896)]),
+    ((898, false, XOR2), &[Arg(0, 46), Tv(881)]),
+    ((900, false, XOR2), &[Arg(0, 47), Tv(884)]),
+    ((902, false, XOR2), &[Arg(0, 48), Tv(887)]),
+    ((907, false, NOR2), &[Tv(878), Tv(881)]),
+    ((908, false, NOR2), &[Tv(884), Tv(887)]),
+    ((1206, false, AND2), &[Arg(0, 81), Tv(1205)]),
+    ((1207, false, XOR2), &[Arg(0, 95), Tv(1190)]),
+    ((1209, false, XOR2), &[Arg(0, 96), Tv(1193)]),
+    ((1211, false, XOR2), &[Arg(0, 97), Tv(1196)]),
+    ((1216, false, NOR2), &[Tv(1187), Tv(1190)]),
+    ((1217, false, NOR2), &[Tv(1193), Tv(1196)]),
+    ((1515, false, AND2), &[Arg(0, 130), Tv(1514)]),
+    ((1516, false, XOR2), &[Arg(0, 144), Tv(1499)]),
+    ((1518, false, XOR2), &[Arg(0, 145), Tv(1502)]),
+    ((1520, false, XOR2), &[Arg(0, 146), Tv(1505)]),
+    ((1525, false, NOR2), &[Tv(1496), Tv(1499)]),
+    ((1526, false, NOR2), &[Tv(1502), Tv(1505)]),
+    ((1824, false, AND2), &[Arg(0, 179), Tv(1823)]),
+    ((1825, false, XOR2), &[Arg(0, 193), Tv(1808)]),
+    ((1827, false, XOR2), &[Arg(0, 194), Tv(1811)]),
+    ((1829, false, XOR2), &[Arg(0, 195), Tv(1814)]),
+    ((1834, false, NOR2), &[Tv(1805), Tv(1808)]),
+    ((1835, false, NOR2), &[Tv(1811), Tv(1814)]),
+    ((2133, false, AND2), &[Arg(0, 228), Tv(2132)]),
+    ((2134, false, XOR2), &[Arg(0, 242), Tv(2117)]),
+    ((2136, false, XOR2), &[Arg(0, 243), Tv(2120)]),
+    ((2138, false, XOR2), &[Arg(0, 244), Tv(2123)]),
+    ((2143, false, NOR2), &[Tv(2114), Tv(2117)]),
+    ((2144, false, NOR2), &[Tv(2120), Tv(2123)]),
+    ((2442, false, AND2), &[Arg(0, 277), Tv(2441)]),
+    ((2443, false, XOR2), &[Arg(0, 291), Tv(2426)]),
+    ((2445, false, XOR2), &[Arg(0, 292), Tv(2429)]),
+    ((2447, false, XOR2), &[Arg(0, 293), Tv(2432)]),
+    ((2452, false, NOR2), &[Tv(2423), Tv(2426)]),
+    ((2453, false, NOR2), &[Tv(2429), Tv(2432)]),
+    ((2751, false, AND2), &[Arg(0, 326), Tv(2750)]),
+    ((2752, false, XOR2), &[Arg(0, 340), Tv(2735)]),
+    ((2754, false, XOR2), &[Arg(0, 341), Tv(2738)]),
+    ((2756, false, XOR2), &[Arg(0, 342), Tv(2741)]),
+    ((2761, false, NOR2), &[Tv(2732), Tv(2735)]),
+    ((2762, false, NOR2), &[Tv(2738), Tv(2741)]),
+    ((3060, false, AND2), &[Arg(0, 375), Tv(3059)]),
+    ((3061, false, XOR2), &[Arg(0, 389), Tv(3044)]),
+    ((3063, false, XOR2), &[Arg(0, 390), Tv(3047)]),
+    ((3065, false, XOR2), &[Arg(0, 391), Tv(3050)]),
+    ((3070, false, NOR2), &[Tv(3041), Tv(3044)]),
+    ((3071, false, NOR2), &[Tv(3047), Tv(3050)]),
+    ((3369, false, AND2), &[Arg(0, 424), Tv(3368)]),
+    ((3370, false, XOR2), &[Arg(0, 438), Tv(3353)]),
+    ((3372, false, XOR2), &[Arg(0, 439), Tv(3356)]),
+    ((3374, false, XOR2), &[Arg(0, 440), Tv(3359)]),
+    ((3379, false, NOR2), &[Tv(3350), Tv(3353)]),
+    ((3380, false, NOR2), &[Tv(3356), Tv(3359)]),
+    ((3678, false, AND2), &[Arg(0, 473), Tv(3677)]),
+    ((3679, false, XOR2), &[Arg(0, 487), Tv(3662)]),
+    ((3681, false, XOR2), &[Arg(0, 488), Tv(3665)]),
+    ((3683, false, XOR2), &[Arg(0, 489), Tv(3668)]),
+    ((3688, false, NOR2), &[Tv(3659), Tv(3662)]),
+    ((3689, false, NOR2), &[Tv(3665), Tv(3668)]),
+    ((3987, false, AND2), &[Arg(0, 522), Tv(3986)]),
+    ((3988, false, XOR2), &[Arg(0, 536), Tv(3971)]),
+    ((3990, false, XOR2), &[Arg(0, 537), Tv(3974)]),
+    ((3992, false, XOR2), &[Arg(0, 538), Tv(3977)]),
+    ((3997, false, NOR2), &[Tv(3968), Tv(3971)]),
+    ((3998, false, NOR2), &[Tv(3974), Tv(3977)]),
+    ((4296, false, AND2), &[Arg(0, 571), Tv(4295)]),
+    ((4297, false, XOR2), &[Arg(0, 585), Tv(4280)]),
+    ((4299, false, XOR2), &[Arg(0, 586), Tv(4283)]),
+    ((4301, false, XOR2), &[Arg(0, 587), Tv(4286)]),
+    ((4306, false, NOR2), &[Tv(4277), Tv(4280)]),
+    ((4307, false, NOR2), &[Tv(4283), Tv(4286)]),
+];
+
+static LEVEL_13: [((usize, bool, CellType), &[GateInput]); 60] = [
+    ((45, true, XOR2), &[Arg(0, 45), Tv(897)]),
+    ((94, true, XOR2), &[Arg(0, 94), Tv(1206)]),
+    ((143, true, XOR2), &[Arg(0, 143), Tv(1515)]),
+    ((192, true, XOR2), &[Arg(0, 192), Tv(1824)]),
+    ((241, true, XOR2), &[Arg(0, 241), Tv(2133)]),
+    ((290, true, XOR2), &[Arg(0, 290), Tv(2442)]),
+    ((339, true, XOR2), &[Arg(0, 339), Tv(2751)]),
+    ((388, true, XOR2), &[Arg(0, 388), Tv(3060)]),
+    ((437, true, XOR2), &[Arg(0, 437), Tv(3369)]),
+    ((486, true, XOR2), &[Arg(0, 486), Tv(3678)]),
+    ((535, true, XOR2), &[Arg(0, 535), Tv(3987)]),
+    ((584, true, XOR2), &[Arg(0, 584), Tv(4296)]),
+    ((899, false, AND2), &[Arg(0, 32), Tv(898)]),
+    ((901, false, AND2), &[Arg(0, 32), Tv(900)]),
+    ((903, false, AND2), &[Arg(0, 32), Tv(902)]),
+    ((910, false, AND2), &[Tv(907), Tv(908)]),
+    ((1208, false, AND2), &[Arg(0, 81), Tv(1207)]),
+    ((1210, false, AND2), &[Arg(0, 81), Tv(1209)]),
+    ((1212, false, AND2), &[Arg(0, 81), Tv(1211)]),
+    ((1219, false, AND2), &[Tv(1216), Tv(1217)]),
+    ((1517, false, AND2), &[Arg(0, 130), Tv(1516)]),
+    ((1519, false, AND2), &[Arg(0, 130), Tv(1518)]),
+    ((1521, false, AND2), &[Arg(0, 130), Tv(1520)]),
+    ((1528, false, AND2), &[Tv(1525), Tv(1526)]),
+    ((1826, false, AND2), &[Arg(0, 179), Tv(1825)]),
+    ((1828, false, AND2), &[Arg(0, 179), Tv(1827)]),
+    ((1830, false, AND2), &[Arg(0, 179), Tv(1829)]),
+    ((1837, false, AND2), &[Tv(1834), Tv(1835)]),
+    ((2135, false, AND2), &[Arg(0, 228), Tv(2134)]),
+    ((2137, false, AND2), &[Arg(0, 228), Tv(2136)]),
+    ((2139, false, AND2), &[Arg(0, 228), Tv(2138)]),
+    ((2146, false, AND2), &[Tv(2143), Tv(2144)]),
+    ((2444, false, AND2), &[Arg(0, 277), Tv(2443)]),
+    ((2446, false, AND2), &[Arg(0, 277), Tv(2445)]),
+    ((2448, false, AND2), &[Arg(0, 277), Tv(2447)]),
+    ((2455, false, AND2), &[Tv(2452), Tv(2453)]),
+    ((2753, false, AND2), &[Arg(0, 326), Tv(2752)]),
+    ((2755, false, AND2), &[Arg(0, 326), Tv(2754)]),
+    ((2757, false, AND2), &[Arg(0, 326), Tv(2756)]),
+    ((2764, false, AND2), &[Tv(2761), Tv(2762)]),
+    ((3062, false, AND2), &[Arg(0, 375), Tv(3061)]),
+    ((3064, false, AND2), &[Arg(0, 375), Tv(3063)]),
+    ((3066, false, AND2), &[Arg(0, 375), Tv(3065)]),
+    ((3073, false, AND2), &[Tv(3070), Tv(3071)]),
+    ((3371, false, AND2), &[Arg(0, 424), Tv(3370)]),
+    ((3373, false, AND2), &[Arg(0, 424), Tv(3372)]),
+    ((3375, false, AND2), &[Arg(0, 424), Tv(3374)]),
+    ((3382, false, AND2), &[Tv(3379), Tv(3380)]),
+    ((3680, false, AND2), &[Arg(0, 473), Tv(3679)]),
+    ((3682, false, AND2), &[Arg(0, 473), Tv(3681)]),
+    ((3684, false, AND2), &[Arg(0, 473), Tv(3683)]),
+    ((3691, false, AND2), &[Tv(3688), Tv(3689)]),
+    ((3989, false, AND2), &[Arg(0, 522), Tv(3988)]),
+    ((3991, false, AND2), &[Arg(0, 522), Tv(3990)]),
+    ((3993, false, AND2), &[Arg(0, 522), Tv(3992)]),
+    ((4000, false, AND2), &[Tv(3997), Tv(3998)]),
+    ((4298, false, AND2), &[Arg(0, 571), Tv(4297)]),
+    ((4300, false, AND2), &[Arg(0, 571), Tv(4299)]),
+    ((4302, false, AND2), &[Arg(0, 571), Tv(4301)]),
+    ((4309, false, AND2), &[Tv(4306), Tv(4307)]),
+];
+
+static LEVEL_14: [((usize, bool, CellType), &[GateInput]); 48] = [
+    ((46, true, XOR2), &[Arg(0, 46), Tv(899)]),
+    ((47, true, XOR2), &[Arg(0, 47), Tv(901)]),
+    ((48, true, XOR2), &[Arg(0, 48), Tv(903)]),
+    ((95, true, XOR2), &[Arg(0, 95), Tv(1208)]),
+    ((96, true, XOR2), &[Arg(0, 96), Tv(1210)]),
+    ((97, true, XOR2), &[Arg(0, 97), Tv(1212)]),
+    ((144, true, XOR2), &[Arg(0, 144), Tv(1517)]),
+    ((145, true, XOR2), &[Arg(0, 145), Tv(1519)]),
+    ((146, true, XOR2), &[Arg(0, 146), Tv(1521)]),
+    ((193, true, XOR2), &[Arg(0, 193), Tv(1826)]),
+    ((194, true, XOR2), &[Arg(0, 194), Tv(1828)]),
+    ((195, true, XOR2), &[Arg(0, 195), Tv(1830)]),
+    ((242, true, XOR2), &[Arg(0, 242), Tv(2135)]),
+    ((243, true, XOR2), &[Arg(0, 243), Tv(2137)]),
+    ((244, true, XOR2), &[Arg(0, 244), Tv(2139)]),
+    ((291, true, XOR2), &[Arg(0, 291), Tv(2444)]),
+    ((292, true, XOR2), &[Arg(0, 292), Tv(2446)]),
+    ((293, true, XOR2), &[Arg(0, 293), Tv(2448)]),
+    ((340, true, XOR2), &[Arg(0, 340), Tv(2753)]),
+    ((341, true, XOR2), &[Arg(0, 341), Tv(2755)]),
+    ((342, true, XOR2), &[Arg(0, 342), Tv(2757)]),
+    ((389, true, XOR2), &[Arg(0, 389), Tv(3062)]),
+    ((390, true, XOR2), &[Arg(0, 390), Tv(3064)]),
+    ((391, true, XOR2), &[Arg(0, 391), Tv(3066)]),
+    ((438, true, XOR2), &[Arg(0, 438), Tv(3371)]),
+    ((439, true, XOR2), &[Arg(0, 439), Tv(3373)]),
+    ((440, true, XOR2), &[Arg(0, 440), Tv(3375)]),
+    ((487, true, XOR2), &[Arg(0, 487), Tv(3680)]),
+    ((488, true, XOR2), &[Arg(0, 488), Tv(3682)]),
+    ((489, true, XOR2), &[Arg(0, 489), Tv(3684)]),
+    ((536, true, XOR2), &[Arg(0, 536), Tv(3989)]),
+    ((537, true, XOR2), &[Arg(0, 537), Tv(3991)]),
+    ((538, true, XOR2), &[Arg(0, 538), Tv(3993)]),
+    ((585, true, XOR2), &[Arg(0, 585), Tv(4298)]),
+    ((586, true, XOR2), &[Arg(0, 586), Tv(4300)]),
+    ((587, true, XOR2), &[Arg(0, 587), Tv(4302)]),
+    ((911, false, AND2), &[Tv(909), Tv(910)]),
+    ((1220, false, AND2), &[Tv(1218), Tv(1219)]),
+    ((1529, false, AND2), &[Tv(1527), Tv(1528)]),
+    ((1838, false, AND2), &[Tv(1836), Tv(1837)]),
+    ((2147, false, AND2), &[Tv(2145), Tv(2146)]),
+    ((2456, false, AND2), &[Tv(2454), Tv(2455)]),
+    ((2765, false, AND2), &[Tv(2763), Tv(2764)]),
+    ((3074, false, AND2), &[Tv(3072), Tv(3073)]),
+    ((3383, false, AND2), &[Tv(3381), Tv(3382)]),
+    ((3692, false, AND2), &[Tv(3690), Tv(3691)]),
+    ((4001, false, AND2), &[Tv(3999), Tv(4000)]),
+    ((4310, false, AND2), &[Tv(4308), Tv(4309)]),
+];
+
+static LEVEL_15: [((usize, bool, CellType), &[GateInput]); 12] = [
+    ((912, false, NAND2), &[Tv(798), Tv(911)]),
+    ((1221, false, NAND2), &[Tv(1107), Tv(1220)]),
+    ((1530, false, NAND2), &[Tv(1416), Tv(1529)]),
+    ((1839, false, NAND2), &[Tv(1725), Tv(1838)]),
+    ((2148, false, NAND2), &[Tv(2034), Tv(2147)]),
+    ((2457, false, NAND2), &[Tv(2343), Tv(2456)]),
+    ((2766, false, NAND2), &[Tv(2652), Tv(2765)]),
+    ((3075, false, NAND2), &[Tv(2961), Tv(3074)]),
+    ((3384, false, NAND2), &[Tv(3270), Tv(3383)]),
+    ((3693, false, NAND2), &[Tv(3579), Tv(3692)]),
+    ((4002, false, NAND2), &[Tv(3888), Tv(4001)]),
+    ((4311, false, NAND2), &[Tv(4197), Tv(4310)]),
+];
+
+static LEVEL_16: [((usize, bool, CellType), &[GateInput]); 12] = [
+    ((32, true, AND2), &[Arg(0, 32), Tv(912)]),
+    ((81, true, AND2), &[Arg(0, 81), Tv(1221)]),
+    ((130, true, AND2), &[Arg(0, 130), Tv(1530)]),
+    ((179, true, AND2), &[Arg(0, 179), Tv(1839)]),
+    ((228, true, AND2), &[Arg(0, 228), Tv(2148)]),
+    ((277, true, AND2), &[Arg(0, 277), Tv(2457)]),
+    ((326, true, AND2), &[Arg(0, 326), Tv(2766)]),
+    ((375, true, AND2), &[Arg(0, 375), Tv(3075)]),
+    ((424, true, AND2), &[Arg(0, 424), Tv(3384)]),
+    ((473, true, AND2), &[Arg(0, 473), Tv(3693)]),
+    ((522, true, AND2), &[Arg(0, 522), Tv(4002)]),
+    ((571, true, AND2), &[Arg(0, 571), Tv(4311)]),
+];
+
+static PRUNE_1: [usize; 888] = [
+  604,
+  605,
+  606,
+  607,
+  608,
+  609,
+  610,
+  611,
+  627,
+  628,
+  629,
+  630,
+  631,
+  632,
+  633,
+  634,
+  651,
+  652,
+  653,
+  654,
+  655,
+  656,
+  657,
+  658,
+  674,
+  675,
+  676,
+  677,
+  678,
+  679,
+  680,
+  681,
+  698,
+  699,
+  700,
+  701,
+  702,
+  703,
+  704,
+  705,
+  721,
+  722,
+  723,
+  724,
+  725,
+  726,
+  727,
+  728,
+  745,
+  746,
+  747,
+  748,
+  749,
+  750,
+  751,
+  752,
+  768,
+  769,
+  770,
+  771,
+  772,
+  773,
+  774,
+  775,
+  799,
+  802,
+  806,
+  810,
+  814,
+  815,
+  819,
+  824,
+  828,
+  857,
+  913,
+  914,
+  915,
+  916,
+  917,
+  918,
+  919,
+  920,
+  936,
+  937,
+  938,
+  939,
+  940,
+  941,
+  942,
+  943,
+  960,
+  961,
+  962,
+  963,
+  964,
+  965,
+  966,
+  967,
+  983,
+  984,
+  985,
+  986,
+  987,
+  988,
+  989,
+  990,
+  1007,
+  1008,
+  1009,
+  1010,
+  1011,
+  1012,
+  1013,
+  1014,
+  1030,
+  1031,
+  1032,
+  1033,
+  1034,
+  1035,
+  1036,
+  1037,
+  1054,
+  1055,
+  1056,
+  1057,
+  1058,
+  1059,
+  1060,
+  1061,
+  1077,
+  1078,
+  1079,
+  1080,
+  1081,
+  1082,
+  1083,
+  1084,
+  1108,
+  1111,
+  1115,
+  1119,
+  1123,
+  1124,
+  1128,
+  1133,
+  1137,
+  1166,
+  1222,
+  1223,
+  1224,
+  1225,
+  1226,
+  1227,
+  1228,
+  1229,
+  1245,
+  1246,
+  1247,
+  1248,
+  1249,
+  1250,
+  1251,
+  1252,
+  1269,
+  1270,
+  1271,
+  1272,
+  1273,
+  1274,
+  1275,
+  1276,
+  1292,
+  1293,
+  1294,
+  1295,
+  1296,
+  1297,
+  1298,
+  1299,
+  1316,
+  1317,
+  1318,
+  1319,
+  1320,
+  1321,
+  1322,
+  1323,
+  1339,
+  1340,
+  1341,
+  1342,
+  1343,
+  1344,
+  1345,
+  1346,
+  1363,
+  1364,
+  1365,
+  1366,
+  1367,
+  1368,
+  1369,
+  1370,
+  1386,
+  1387,
+  1388,
+  1389,
+  1390,
+  1391,
+  1392,
+  1393,
+  1417,
+  1420,
+  1424,
+  1428,
+  1432,
+  1433,
+  1437,
+  1442,
+  1446,
+  1475,
+  1531,
+  1532,
+  1533,
+  1534,
+  1535,
+  1536,
+  1537,
+  1538,
+  1554,
+  1555,
+  1556,
+  1557,
+  1558,
+  1559,
+  1560,
+  1561,
+  1578,
+  1579,
+  1580,
+  1581,
+  1582,
+  1583,
+  1584,
+  1585,
+  1601,
+  1602,
+  1603,
+  1604,
+  1605,
+  1606,
+  1607,
+  1608,
+  1625,
+  1626,
+  1627,
+  1628,
+  1629,
+  1630,
+  1631,
+  1632,
+  1648,
+  1649,
+  1650,
+  1651,
+  1652,
+  1653,
+  1654,
+  1655,
+  1672,
+  1673,
+  1674,
+  1675,
+  1676,
+  1677,
+  1678,
+  1679,
+  1695,
+  1696,
+  1697,
+  1698,
+  1699,
+  1700,
+  1701,
+  1702,
+  1726,
+  1729,
+  1733,
+  1737,
+  1741,
+  1742,
+  1746,
+  1751,
+  1755,
+  1784,
+  1840,
+  1841,
+  1842,
+  1843,
+  1844,
+  1845,
+  1846,
+  1847,
+  1863,
+  1864,
+  1865,
+  1866,
+  1867,
+  1868,
+  1869,
+  1870,
+  1887,
+  1888,
+  1889,
+  1890,
+  1891,
+  1892,
+  1893,
+  1894,
+  1910,
+  1911,
+  1912,
+  1913,
+  1914,
+  1915,
+  1916,
+  1917,
+  1934,
+  1935,
+  1936,
+  1937,
+  1938,
+  1939,
+  1940,
+  1941,
+  1957,
+  1958,
+  1959,
+  1960,
+  1961,
+  1962,
+  1963,
+  1964,
+  1981,
+  1982,
+  1983,
+  1984,
+  1985,
+  1986,
+  1987,
+  1988,
+  2004,
+  2005,
+  2006,
+  2007,
+  2008,
+  2009,
+  2010,
+  2011,
+  2035,
+  2038,
+  2042,
+  2046,
+  2050,
+  2051,
+  2055,
+  2060,
+  2064,
+  2093,
+  2149,
+  2150,
+  2151,
+  2152,
+  2153,
+  2154,
+  2155,
+  2156,
+  2172,
+  2173,
+  2174,
+  2175,
+  2176,
+  2177,
+  2178,
+  2179,
+  2196,
+  2197,
+  2198,
+  2199,
+  2200,
+  2201,
+  2202,
+  2203,
+  2219,
+  2220,
+  2221,
+  2222,
+  2223,
+  2224,
+  2225,
+  2226,
+  2243,
+  2244,
+  2245,
+  2246,
+  2247,
+  2248,
+  2249,
+  2250,
+  2266,
+  2267,
+  2268,
+  2269,
+  2270,
+  2271,
+  2272,
+  2273,
+  2290,
+  2291,
+  2292,
+  2293,
+  2294,
+  2295,
+  2296,
+  2297,
+  2313,
+  2314,
+  2315,
+  2316,
+  2317,
+  2318,
+  2319,
+  2320,
+  2344,
+  2347,
+  2351,
+  2355,
+  2359,
+  2360,
+  2364,
+  2369,
+  2373,
+  2402,
+  2458,
+  2459,
+  2460,
+  2461,
+  2462,
+  2463,
+  2464,
+  2465,
+  2481,
+  2482,
+  2483,
+  2484,
+  2485,
+  2486,
+  2487,
+  2488,
+  2505,
+  2506,
+  2507,
+  2508,
+  2509,
+  2510,
+  2511,
+  2512,
+  2528,
+  2529,
+  2530,
+  2531,
+  2532,
+  2533,
+  2534,
+  2535,
+  2552,
+  2553,
+  2554,
+  2555,
+  2556,
+  2557,
+  2558,
+  2559,
+  2575,
+  2576,
+  2577,
+  2578,
+  2579,
+  2580,
+  2581,
+  2582,
+  2599,
+  2600,
+  2601,
+  2602,
+  2603,
+  2604,
+  2605,
+  2606,
+  2622,
+  2623,
+  2624,
+  2625,
+  2626,
+  2627,
+  2628,
+  2629,
+  2653,
+  2656,
+  2660,
+  2664,
+  2668,
+  2669,
+  2673,
+  2678,
+  2682,
+  2711,
+  2767,
+  2768,
+  2769,
+  2770,
+  2771,
+  2772,
+  2773,
+  2774,
+  2790,
+  2791,
+  2792,
+  2793,
+  2794,
+  2795,
+  2796,
+  2797,
+  2814,
+  2815,
+  2816,
+  2817,
+  2818,
+  2819,
+  2820,
+  2821,
+  2837,
+  2838,
+  2839,
+  2840,
+  2841,
+  2842,
+  2843,
+  2844,
+  2861,
+  2862,
+  2863,
+  2864,
+  2865,
+  2866,
+  2867,
+  2868,
+  2884,
+  2885,
+  2886,
+  2887,
+  2888,
+  2889,
+  2890,
+  2891,
+  2908,
+  2909,
+  2910,
+  2911,
+  2912,
+  2913,
+  2914,
+  2915,
+  2931,
+  2932,
+  2933,
+  2934,
+  2935,
+  2936,
+  2937,
+  2938,
+  2962,
+  2965,
+  2969,
+  2973,
+  2977,
+  2978,
+  2982,
+  2987,
+  2991,
+  3020,
+  3076,
+  3077,
+  3078,
+  3079,
+  3080,
+  3081,
+  3082,
+  3083,
+  3099,
+  3100,
+  3101,
+  3102,
+  3103,
+  3104,
+  3105,
+  3106,
+  3123,
+  3124,
+  3125,
+  3126,
+  3127,
+  3128,
+  3129,
+  3130,
+  3146,
+  3147,
+  3148,
+  3149,
+  3150,
+  3151,
+  3152,
+  3153,
+  3170,
+  3171,
+  3172,
+  3173,
+  3174,
+  3175,
+  3176,
+  3177,
+  3193,
+  3194,
+  3195,
+  3196,
+  3197,
+  3198,
+  3199,
+  3200,
+  3217,
+  3218,
+  3219,
+  3220,
+  3221,
+  3222,
+  3223,
+  3224,
+  3240,
+  3241,
+  3242,
+  3243,
+  3244,
+  3245,
+  3246,
+  3247,
+  3271,
+  3274,
+  3278,
+  3282,
+  3286,
+  3287,
+  3291,
+  3296,
+  3300,
+  3329,
+  3385,
+  3386,
+  3387,
+  3388,
+  3389,
+  3390,
+  3391,
+  3392,
+  3408,
+  3409,
+  3410,
+  3411,
+  3412,
+  3413,
+  3414,
+  3415,
+  3432,
+  3433,
+  3434,
+  3435,
+  3436,
+  3437,
+  3438,
+  3439,
+  3455,
+  3456,
+  3457,
+  3458,
+  3459,
+  3460,
+  3461,
+  3462,
+  3479,
+  3480,
+  3481,
+  3482,
+  3483,
+  3484,
+  3485,
+  3486,
+  3502,
+  3503,
+  3504,
+  3505,
+  3506,
+  3507,
+  3508,
+  3509,
+  3526,
+  3527,
+  3528,
+  3529,
+  3530,
+  3531,
+  3532,
+  3533,
+  3549,
+  3550,
+  3551,
+  3552,
+  3553,
+  3554,
+  3555,
+  3556,
+  3580,
+  3583,
+  3587,
+  3591,
+  3595,
+  3596,
+  3600,
+  3605,
+  3609,
+  3638,
+  3694,
+  3695,
+  3696,
+  3697,
+  3698,
+  3699,
+  3700,
+  3701,
+  3717,
+  3718,
+  3719,
+  3720,
+  3721,
+  3722,
+  3723,
+  3724,
+  3741,
+  3742,
+  3743,
+  3744,
+  3745,
+  3746,
+  3747,
+  3748,
+  3764,
+  3765,
+  3766,
+  3767,
+  3768,
+  3769,
+  3770,
+  3771,
+  3788,
+  3789,
+  3790,
+  3791,
+  3792,
+  3793,
+  3794,
+  3795,
+  3811,
+  3812,
+  3813,
+  3814,
+  3815,
+  3816,
+  3817,
+  3818,
+  3835,
+  3836,
+  3837,
+  3838,
+  3839,
+  3840,
+  3841,
+  3842,
+  3858,
+  3859,
+  3860,
+  3861,
+  3862,
+  3863,
+  3864,
+  3865,
+  3889,
+  3892,
+  3896,
+  3900,
+  3904,
+  3905,
+  3909,
+  3914,
+  3918,
+  3947,
+  4003,
+  4004,
+  4005,
+  4006,
+  4007,
+  4008,
+  4009,
+  4010,
+  4026,
+  4027,
+  4028,
+  4029,
+  4030,
+  4031,
+  4032,
+  4033,
+  4050,
+  4051,
+  4052,
+  4053,
+  4054,
+  4055,
+  4056,
+  4057,
+  4073,
+  4074,
+  4075,
+  4076,
+  4077,
+  4078,
+  4079,
+  4080,
+  4097,
+  4098,
+  4099,
+  4100,
+  4101,
+  4102,
+  4103,
+  4104,
+  4120,
+  4121,
+  4122,
+  4123,
+  4124,
+  4125,
+  4126,
+  4127,
+  4144,
+  4145,
+  4146,
+  4147,
+  4148,
+  4149,
+  4150,
+  4151,
+  4167,
+  4168,
+  4169,
+  4170,
+  4171,
+  4172,
+  4173,
+  4174,
+  4198,
+  4201,
+  4205,
+  4209,
+  4213,
+  4214,
+  4218,
+  4223,
+  4227,
+  4256,
+];
+
+static PRUNE_2: [usize; 888] = [
+  612,
+  613,
+  615,
+  616,
+  618,
+  619,
+  621,
+  622,
+  635,
+  636,
+  638,
+  639,
+  641,
+  642,
+  644,
+  645,
+  659,
+  660,
+  662,
+  663,
+  665,
+  666,
+  668,
+  669,
+  682,
+  683,
+  685,
+  686,
+  688,
+  689,
+  691,
+  692,
+  706,
+  707,
+  709,
+  710,
+  712,
+  713,
+  715,
+  716,
+  729,
+  730,
+  732,
+  733,
+  735,
+  736,
+  738,
+  739,
+  753,
+  754,
+  756,
+  757,
+  759,
+  760,
+  762,
+  763,
+  776,
+  777,
+  779,
+  780,
+  782,
+  783,
+  785,
+  786,
+  803,
+  804,
+  807,
+  811,
+  816,
+  820,
+  825,
+  829,
+  841,
+  865,
+  921,
+  922,
+  924,
+  925,
+  927,
+  928,
+  930,
+  931,
+  944,
+  945,
+  947,
+  948,
+  950,
+  951,
+  953,
+  954,
+  968,
+  969,
+  971,
+  972,
+  974,
+  975,
+  977,
+  978,
+  991,
+  992,
+  994,
+  995,
+  997,
+  998,
+  1000,
+  1001,
+  1015,
+  1016,
+  1018,
+  1019,
+  1021,
+  1022,
+  1024,
+  1025,
+  1038,
+  1039,
+  1041,
+  1042,
+  1044,
+  1045,
+  1047,
+  1048,
+  1062,
+  1063,
+  1065,
+  1066,
+  1068,
+  1069,
+  1071,
+  1072,
+  1085,
+  1086,
+  1088,
+  1089,
+  1091,
+  1092,
+  1094,
+  1095,
+  1112,
+  1113,
+  1116,
+  1120,
+  1125,
+  1129,
+  1134,
+  1138,
+  1150,
+  1174,
+  1230,
+  1231,
+  1233,
+  1234,
+  1236,
+  1237,
+  1239,
+  1240,
+  1253,
+  1254,
+  1256,
+  1257,
+  1259,
+  1260,
+  1262,
+  1263,
+  1277,
+  1278,
+  1280,
+  1281,
+  1283,
+  1284,
+  1286,
+  1287,
+  1300,
+  1301,
+  1303,
+  1304,
+  1306,
+  1307,
+  1309,
+  1310,
+  1324,
+  1325,
+  1327,
+  1328,
+  1330,
+  1331,
+  1333,
+  1334,
+  1347,
+  1348,
+  1350,
+  1351,
+  1353,
+  1354,
+  1356,
+  1357,
+  1371,
+  1372,
+  1374,
+  1375,
+  1377,
+  1378,
+  1380,
+  1381,
+  1394,
+  1395,
+  1397,
+  1398,
+  1400,
+  1401,
+  1403,
+  1404,
+  1421,
+  1422,
+  1425,
+  1429,
+  1434,
+  1438,
+  1443,
+  1447,
+  1459,
+  1483,
+  1539,
+  1540,
+  1542,
+  1543,
+  1545,
+  1546,
+  1548,
+  1549,
+  1562,
+  1563,
+  1565,
+  1566,
+  1568,
+  1569,
+  1571,
+  1572,
+  1586,
+  1587,
+  1589,
+  1590,
+  1592,
+  1593,
+  1595,
+  1596,
+  1609,
+  1610,
+  1612,
+  1613,
+  1615,
+  1616,
+  1618,
+  1619,
+  1633,
+  1634,
+  1636,
+  1637,
+  1639,
+  1640,
+  1642,
+  1643,
+  1656,
+  1657,
+  1659,
+  1660,
+  1662,
+  1663,
+  1665,
+  1666,
+  1680,
+  1681,
+  1683,
+  1684,
+  1686,
+  1687,
+  1689,
+  1690,
+  1703,
+  1704,
+  1706,
+  1707,
+  1709,
+  1710,
+  1712,
+  1713,
+  1730,
+  1731,
+  1734,
+  1738,
+  1743,
+  1747,
+  1752,
+  1756,
+  1768,
+  1792,
+  1848,
+  1849,
+  1851,
+  1852,
+  1854,
+  1855,
+  1857,
+  1858,
+  1871,
+  1872,
+  1874,
+  1875,
+  1877,
+  1878,
+  1880,
+  1881,
+  1895,
+  1896,
+  1898,
+  1899,
+  1901,
+  1902,
+  1904,
+  1905,
+  1918,
+  1919,
+  1921,
+  1922,
+  1924,
+  1925,
+  1927,
+  1928,
+  1942,
+  1943,
+  1945,
+  1946,
+  1948,
+  1949,
+  1951,
+  1952,
+  1965,
+  1966,
+  1968,
+  1969,
+  1971,
+  1972,
+  1974,
+  1975,
+  1989,
+  1990,
+  1992,
+  1993,
+  1995,
+  1996,
+  1998,
+  1999,
+  2012,
+  2013,
+  2015,
+  2016,
+  2018,
+  2019,
+  2021,
+  2022,
+  2039,
+  2040,
+  2043,
+  2047,
+  2052,
+  2056,
+  2061,
+  2065,
+  2077,
+  2101,
+  2157,
+  2158,
+  2160,
+  2161,
+  2163,
+  2164,
+  2166,
+  2167,
+  2180,
+  2181,
+  2183,
+  2184,
+  2186,
+  2187,
+  2189,
+  2190,
+  2204,
+  2205,
+  2207,
+  2208,
+  2210,
+  2211,
+  2213,
+  2214,
+  2227,
+  2228,
+  2230,
+  2231,
+  2233,
+  2234,
+  2236,
+  2237,
+  2251,
+  2252,
+  2254,
+  2255,
+  2257,
+  2258,
+  2260,
+  2261,
+  2274,
+  2275,
+  2277,
+  2278,
+  2280,
+  2281,
+  2283,
+  2284,
+  2298,
+  2299,
+  2301,
+  2302,
+  2304,
+  2305,
+  2307,
+  2308,
+  2321,
+  2322,
+  2324,
+  2325,
+  2327,
+  2328,
+  2330,
+  2331,
+  2348,
+  2349,
+  2352,
+  2356,
+  2361,
+  2365,
+  2370,
+  2374,
+  2386,
+  2410,
+  2466,
+  2467,
+  2469,
+  2470,
+  2472,
+  2473,
+  2475,
+  2476,
+  2489,
+  2490,
+  2492,
+  2493,
+  2495,
+  2496,
+  2498,
+  2499,
+  2513,
+  2514,
+  2516,
+  2517,
+  2519,
+  2520,
+  2522,
+  2523,
+  2536,
+  2537,
+  2539,
+  2540,
+  2542,
+  2543,
+  2545,
+  2546,
+  2560,
+  2561,
+  2563,
+  2564,
+  2566,
+  2567,
+  2569,
+  2570,
+  2583,
+  2584,
+  2586,
+  2587,
+  2589,
+  2590,
+  2592,
+  2593,
+  2607,
+  2608,
+  2610,
+  2611,
+  2613,
+  2614,
+  2616,
+  2617,
+  2630,
+  2631,
+  2633,
+  2634,
+  2636,
+  2637,
+  2639,
+  2640,
+  2657,
+  2658,
+  2661,
+  2665,
+  2670,
+  2674,
+  2679,
+  2683,
+  2695,
+  2719,
+  2775,
+  2776,
+  2778,
+  2779,
+  2781,
+  2782,
+  2784,
+  2785,
+  2798,
+  2799,
+  2801,
+  2802,
+  2804,
+  2805,
+  2807,
+  2808,
+  2822,
+  2823,
+  2825,
+  2826,
+  2828,
+  2829,
+  2831,
+  2832,
+  2845,
+  2846,
+  2848,
+  2849,
+  2851,
+  2852,
+  2854,
+  2855,
+  2869,
+  2870,
+  2872,
+  2873,
+  2875,
+  2876,
+  2878,
+  2879,
+  2892,
+  2893,
+  2895,
+  2896,
+  2898,
+  2899,
+  2901,
+  2902,
+  2916,
+  2917,
+  2919,
+  2920,
+  2922,
+  2923,
+  2925,
+  2926,
+  2939,
+  2940,
+  2942,
+  2943,
+  2945,
+  2946,
+  2948,
+  2949,
+  2966,
+  2967,
+  2970,
+  2974,
+  2979,
+  2983,
+  2988,
+  2992,
+  3004,
+  3028,
+  3084,
+  3085,
+  3087,
+  3088,
+  3090,
+  3091,
+  3093,
+  3094,
+  3107,
+  3108,
+  3110,
+  3111,
+  3113,
+  3114,
+  3116,
+  3117,
+  3131,
+  3132,
+  3134,
+  3135,
+  3137,
+  3138,
+  3140,
+  3141,
+  3154,
+  3155,
+  3157,
+  3158,
+  3160,
+  3161,
+  3163,
+  3164,
+  3178,
+  3179,
+  3181,
+  3182,
+  3184,
+  3185,
+  3187,
+  3188,
+  3201,
+  3202,
+  3204,
+  3205,
+  3207,
+  3208,
+  3210,
+  3211,
+  3225,
+  3226,
+  3228,
+  3229,
+  3231,
+  3232,
+  3234,
+  3235,
+  3248,
+  3249,
+  3251,
+  3252,
+  3254,
+  3255,
+  3257,
+  3258,
+  3275,
+  3276,
+  3279,
+  3283,
+  3288,
+  3292,
+  3297,
+  3301,
+  3313,
+  3337,
+  3393,
+  3394,
+  3396,
+  3397,
+  3399,
+  3400,
+  3402,
+  3403,
+  3416,
+  3417,
+  3419,
+  3420,
+  3422,
+  3423,
+  3425,
+  3426,
+  3440,
+  3441,
+  3443,
+  3444,
+  3446,
+  3447,
+  3449,
+  3450,
+  3463,
+  3464,
+  3466,
+  3467,
+  3469,
+  3470,
+  3472,
+  3473,
+  3487,
+  3488,
+  3490,
+  3491,
+  3493,
+  3494,
+  3496,
+  3497,
+  3510,
+  3511,
+  3513,
+  3514,
+  3516,
+  3517,
+  3519,
+  3520,
+  3534,
+  3535,
+  3537,
+  3538,
+  3540,
+  3541,
+  3543,
+  3544,
+  3557,
+  3558,
+  3560,
+  3561,
+  3563,
+  3564,
+  3566,
+  3567,
+  3584,
+  3585,
+  3588,
+  3592,
+  3597,
+  3601,
+  3606,
+  3610,
+  3622,
+  3646,
+  3702,
+  3703,
+  3705,
+  3706,
+  3708,
+  3709,
+  3711,
+  3712,
+  3725,
+  3726,
+  3728,
+  3729,
+  3731,
+  3732,
+  3734,
+  3735,
+  3749,
+  3750,
+  3752,
+  3753,
+  3755,
+  3756,
+  3758,
+  3759,
+  3772,
+  3773,
+  3775,
+  3776,
+  3778,
+  3779,
+  3781,
+  3782,
+  3796,
+  3797,
+  3799,
+  3800,
+  3802,
+  3803,
+  3805,
+  3806,
+  3819,
+  3820,
+  3822,
+  3823,
+  3825,
+  3826,
+  3828,
+  3829,
+  3843,
+  3844,
+  3846,
+  3847,
+  3849,
+  3850,
+  3852,
+  3853,
+  3866,
+  3867,
+  3869,
+  3870,
+  3872,
+  3873,
+  3875,
+  3876,
+  3893,
+  3894,
+  3897,
+  3901,
+  3906,
+  3910,
+  3915,
+  3919,
+  3931,
+  3955,
+  4011,
+  4012,
+  4014,
+  4015,
+  4017,
+  4018,
+  4020,
+  4021,
+  4034,
+  4035,
+  4037,
+  4038,
+  4040,
+  4041,
+  4043,
+  4044,
+  4058,
+  4059,
+  4061,
+  4062,
+  4064,
+  4065,
+  4067,
+  4068,
+  4081,
+  4082,
+  4084,
+  4085,
+  4087,
+  4088,
+  4090,
+  4091,
+  4105,
+  4106,
+  4108,
+  4109,
+  4111,
+  4112,
+  4114,
+  4115,
+  4128,
+  4129,
+  4131,
+  4132,
+  4134,
+  4135,
+  4137,
+  4138,
+  4152,
+  4153,
+  4155,
+  4156,
+  4158,
+  4159,
+  4161,
+  4162,
+  4175,
+  4176,
+  4178,
+  4179,
+  4181,
+  4182,
+  4184,
+  4185,
+  4202,
+  4203,
+  4206,
+  4210,
+  4215,
+  4219,
+  4224,
+  4228,
+  4240,
+  4264,
+];
+
+static PRUNE_3: [usize; 480] = [
+  614,
+  617,
+  620,
+  623,
+  637,
+  640,
+  643,
+  646,
+  661,
+  664,
+  667,
+  670,
+  684,
+  687,
+  690,
+  693,
+  708,
+  711,
+  714,
+  717,
+  731,
+  734,
+  737,
+  740,
+  755,
+  758,
+  761,
+  764,
+  778,
+  781,
+  784,
+  787,
+  805,
+  808,
+  812,
+  817,
+  821,
+  826,
+  830,
+  866,
+  923,
+  926,
+  929,
+  932,
+  946,
+  949,
+  952,
+  955,
+  970,
+  973,
+  976,
+  979,
+  993,
+  996,
+  999,
+  1002,
+  1017,
+  1020,
+  1023,
+  1026,
+  1040,
+  1043,
+  1046,
+  1049,
+  1064,
+  1067,
+  1070,
+  1073,
+  1087,
+  1090,
+  1093,
+  1096,
+  1114,
+  1117,
+  1121,
+  1126,
+  1130,
+  1135,
+  1139,
+  1175,
+  1232,
+  1235,
+  1238,
+  1241,
+  1255,
+  1258,
+  1261,
+  1264,
+  1279,
+  1282,
+  1285,
+  1288,
+  1302,
+  1305,
+  1308,
+  1311,
+  1326,
+  1329,
+  1332,
+  1335,
+  1349,
+  1352,
+  1355,
+  1358,
+  1373,
+  1376,
+  1379,
+  1382,
+  1396,
+  1399,
+  1402,
+  1405,
+  1423,
+  1426,
+  1430,
+  1435,
+  1439,
+  1444,
+  1448,
+  1484,
+  1541,
+  1544,
+  1547,
+  1550,
+  1564,
+  1567,
+  1570,
+  1573,
+  1588,
+  1591,
+  1594,
+  1597,
+  1611,
+  1614,
+  1617,
+  1620,
+  1635,
+  1638,
+  1641,
+  1644,
+  1658,
+  1661,
+  1664,
+  1667,
+  1682,
+  1685,
+  1688,
+  1691,
+  1705,
+  1708,
+  1711,
+  1714,
+  1732,
+  1735,
+  1739,
+  1744,
+  1748,
+  1753,
+  1757,
+  1793,
+  1850,
+  1853,
+  1856,
+  1859,
+  1873,
+  1876,
+  1879,
+  1882,
+  1897,
+  1900,
+  1903,
+  1906,
+  1920,
+  1923,
+  1926,
+  1929,
+  1944,
+  1947,
+  1950,
+  1953,
+  1967,
+  1970,
+  1973,
+  1976,
+  1991,
+  1994,
+  1997,
+  2000,
+  2014,
+  2017,
+  2020,
+  2023,
+  2041,
+  2044,
+  2048,
+  2053,
+  2057,
+  2062,
+  2066,
+  2102,
+  2159,
+  2162,
+  2165,
+  2168,
+  2182,
+  2185,
+  2188,
+  2191,
+  2206,
+  2209,
+  2212,
+  2215,
+  2229,
+  2232,
+  2235,
+  2238,
+  2253,
+  2256,
+  2259,
+  2262,
+  2276,
+  2279,
+  2282,
+  2285,
+  2300,
+  2303,
+  2306,
+  2309,
+  2323,
+  2326,
+  2329,
+  2332,
+  2350,
+  2353,
+  2357,
+  2362,
+  2366,
+  2371,
+  2375,
+  2411,
+  2468,
+  2471,
+  2474,
+  2477,
+  2491,
+  2494,
+  2497,
+  2500,
+  2515,
+  2518,
+  2521,
+  2524,
+  2538,
+  2541,
+  2544,
+  2547,
+  2562,
+  2565,
+  2568,
+  2571,
+  2585,
+  2588,
+  2591,
+  2594,
+  2609,
+  2612,
+  2615,
+  2618,
+  2632,
+  2635,
+  2638,
+  2641,
+  2659,
+  2662,
+  2666,
+  2671,
+  2675,
+  2680,
+  2684,
+  2720,
+  2777,
+  2780,
+  2783,
+  2786,
+  2800,
+  2803,
+  2806,
+  2809,
+  2824,
+  2827,
+  2830,
+  2833,
+  2847,
+  2850,
+  2853,
+  2856,
+  2871,
+  2874,
+  2877,
+  2880,
+  2894,
+  2897,
+  2900,
+  2903,
+  2918,
+  2921,
+  2924,
+  2927,
+  2941,
+  2944,
+  2947,
+  2950,
+  2968,
+  2971,
+  2975,
+  2980,
+  2984,
+  2989,
+  2993,
+  3029,
+  3086,
+  3089,
+  3092,
+  3095,
+  3109,
+  3112,
+  3115,
+  3118,
+  3133,
+  3136,
+  3139,
+  3142,
+  3156,
+  3159,
+  3162,
+  3165,
+  3180,
+  3183,
+  3186,
+  3189,
+  3203,
+  3206,
+  3209,
+  3212,
+  3227,
+  3230,
+  3233,
+  3236,
+  3250,
+  3253,
+  3256,
+  3259,
+  3277,
+  3280,
+  3284,
+  3289,
+  3293,
+  3298,
+  3302,
+  3338,
+  3395,
+  3398,
+  3401,
+  3404,
+  3418,
+  3421,
+  3424,
+  3427,
+  3442,
+  3445,
+  3448,
+  3451,
+  3465,
+  3468,
+  3471,
+  3474,
+  3489,
+  3492,
+  3495,
+  3498,
+  3512,
+  3515,
+  3518,
+  3521,
+  3536,
+  3539,
+  3542,
+  3545,
+  3559,
+  3562,
+  3565,
+  3568,
+  3586,
+  3589,
+  3593,
+  3598,
+  3602,
+  3607,
+  3611,
+  3647,
+  3704,
+  3707,
+  3710,
+  3713,
+  3727,
+  3730,
+  3733,
+  3736,
+  3751,
+  3754,
+  3757,
+  3760,
+  3774,
+  3777,
+  3780,
+  3783,
+  3798,
+  3801,
+  3804,
+  3807,
+  3821,
+  3824,
+  3827,
+  3830,
+  3845,
+  3848,
+  3851,
+  3854,
+  3868,
+  3871,
+  3874,
+  3877,
+  3895,
+  3898,
+  3902,
+  3907,
+  3911,
+  3916,
+  3920,
+  3956,
+  4013,
+  4016,
+  4019,
+  4022,
+  4036,
+  4039,
+  4042,
+  4045,
+  4060,
+  4063,
+  4066,
+  4069,
+  4083,
+  4086,
+  4089,
+  4092,
+  4107,
+  4110,
+  4113,
+  4116,
+  4130,
+  4133,
+  4136,
+  4139,
+  4154,
+  4157,
+  4160,
+  4163,
+  4177,
+  4180,
+  4183,
+  4186,
+  4204,
+  4207,
+  4211,
+  4216,
+  4220,
+  4225,
+  4229,
+  4265,
+];
+
+static PRUNE_4: [usize; 240] = [
+  624,
+  625,
+  647,
+  648,
+  671,
+  672,
+  694,
+  695,
+  718,
+  719,
+  741,
+  742,
+  765,
+  766,
+  788,
+  789,
+  809,
+  813,
+  833,
+  867,
+  933,
+  934,
+  956,
+  957,
+  980,
+  981,
+  1003,
+  1004,
+  1027,
+  1028,
+  1050,
+  1051,
+  1074,
+  1075,
+  1097,
+  1098,
+  1118,
+  1122,
+  1142,
+  1176,
+  1242,
+  1243,
+  1265,
+  1266,
+  1289,
+  1290,
+  1312,
+  1313,
+  1336,
+  1337,
+  1359,
+  1360,
+  1383,
+  1384,
+  1406,
+  1407,
+  1427,
+  1431,
+  1451,
+  1485,
+  1551,
+  1552,
+  1574,
+  1575,
+  1598,
+  1599,
+  1621,
+  1622,
+  1645,
+  1646,
+  1668,
+  1669,
+  1692,
+  1693,
+  1715,
+  1716,
+  1736,
+  1740,
+  1760,
+  1794,
+  1860,
+  1861,
+  1883,
+  1884,
+  1907,
+  1908,
+  1930,
+  1931,
+  1954,
+  1955,
+  1977,
+  1978,
+  2001,
+  2002,
+  2024,
+  2025,
+  2045,
+  2049,
+  2069,
+  2103,
+  2169,
+  2170,
+  2192,
+  2193,
+  2216,
+  2217,
+  2239,
+  2240,
+  2263,
+  2264,
+  2286,
+  2287,
+  2310,
+  2311,
+  2333,
+  2334,
+  2354,
+  2358,
+  2378,
+  2412,
+  2478,
+  2479,
+  2501,
+  2502,
+  2525,
+  2526,
+  2548,
+  2549,
+  2572,
+  2573,
+  2595,
+  2596,
+  2619,
+  2620,
+  2642,
+  2643,
+  2663,
+  2667,
+  2687,
+  2721,
+  2787,
+  2788,
+  2810,
+  2811,
+  2834,
+  2835,
+  2857,
+  2858,
+  2881,
+  2882,
+  2904,
+  2905,
+  2928,
+  2929,
+  2951,
+  2952,
+  2972,
+  2976,
+  2996,
+  3030,
+  3096,
+  3097,
+  3119,
+  3120,
+  3143,
+  3144,
+  3166,
+  3167,
+  3190,
+  3191,
+  3213,
+  3214,
+  3237,
+  3238,
+  3260,
+  3261,
+  3281,
+  3285,
+  3305,
+  3339,
+  3405,
+  3406,
+  3428,
+  3429,
+  3452,
+  3453,
+  3475,
+  3476,
+  3499,
+  3500,
+  3522,
+  3523,
+  3546,
+  3547,
+  3569,
+  3570,
+  3590,
+  3594,
+  3614,
+  3648,
+  3714,
+  3715,
+  3737,
+  3738,
+  3761,
+  3762,
+  3784,
+  3785,
+  3808,
+  3809,
+  3831,
+  3832,
+  3855,
+  3856,
+  3878,
+  3879,
+  3899,
+  3903,
+  3923,
+  3957,
+  4023,
+  4024,
+  4046,
+  4047,
+  4070,
+  4071,
+  4093,
+  4094,
+  4117,
+  4118,
+  4140,
+  4141,
+  4164,
+  4165,
+  4187,
+  4188,
+  4208,
+  4212,
+  4232,
+  4266,
+];
+
+static PRUNE_5: [usize; 252] = [
+  626,
+  649,
+  673,
+  696,
+  720,
+  743,
+  767,
+  790,
+  800,
+  801,
+  823,
+  832,
+  834,
+  835,
+  838,
+  842,
+  845,
+  846,
+  858,
+  868,
+  888,
+  935,
+  958,
+  982,
+  1005,
+  1029,
+  1052,
+  1076,
+  1099,
+  1109,
+  1110,
+  1132,
+  1141,
+  1143,
+  1144,
+  1147,
+  1151,
+  1154,
+  1155,
+  1167,
+  1177,
+  1197,
+  1244,
+  1267,
+  1291,
+  1314,
+  1338,
+  1361,
+  1385,
+  1408,
+  1418,
+  1419,
+  1441,
+  1450,
+  1452,
+  1453,
+  1456,
+  1460,
+  1463,
+  1464,
+  1476,
+  1486,
+  1506,
+  1553,
+  1576,
+  1600,
+  1623,
+  1647,
+  1670,
+  1694,
+  1717,
+  1727,
+  1728,
+  1750,
+  1759,
+  1761,
+  1762,
+  1765,
+  1769,
+  1772,
+  1773,
+  1785,
+  1795,
+  1815,
+  1862,
+  1885,
+  1909,
+  1932,
+  1956,
+  1979,
+  2003,
+  2026,
+  2036,
+  2037,
+  2059,
+  2068,
+  2070,
+  2071,
+  2074,
+  2078,
+  2081,
+  2082,
+  2094,
+  2104,
+  2124,
+  2171,
+  2194,
+  2218,
+  2241,
+  2265,
+  2288,
+  2312,
+  2335,
+  2345,
+  2346,
+  2368,
+  2377,
+  2379,
+  2380,
+  2383,
+  2387,
+  2390,
+  2391,
+  2403,
+  2413,
+  2433,
+  2480,
+  2503,
+  2527,
+  2550,
+  2574,
+  2597,
+  2621,
+  2644,
+  2654,
+  2655,
+  2677,
+  2686,
+  2688,
+  2689,
+  2692,
+  2696,
+  2699,
+  2700,
+  2712,
+  2722,
+  2742,
+  2789,
+  2812,
+  2836,
+  2859,
+  2883,
+  2906,
+  2930,
+  2953,
+  2963,
+  2964,
+  2986,
+  2995,
+  2997,
+  2998,
+  3001,
+  3005,
+  3008,
+  3009,
+  3021,
+  3031,
+  3051,
+  3098,
+  3121,
+  3145,
+  3168,
+  3192,
+  3215,
+  3239,
+  3262,
+  3272,
+  3273,
+  3295,
+  3304,
+  3306,
+  3307,
+  3310,
+  3314,
+  3317,
+  3318,
+  3330,
+  3340,
+  3360,
+  3407,
+  3430,
+  3454,
+  3477,
+  3501,
+  3524,
+  3548,
+  3571,
+  3581,
+  3582,
+  3604,
+  3613,
+  3615,
+  3616,
+  3619,
+  3623,
+  3626,
+  3627,
+  3639,
+  3649,
+  3669,
+  3716,
+  3739,
+  3763,
+  3786,
+  3810,
+  3833,
+  3857,
+  3880,
+  3890,
+  3891,
+  3913,
+  3922,
+  3924,
+  3925,
+  3928,
+  3932,
+  3935,
+  3936,
+  3948,
+  3958,
+  3978,
+  4025,
+  4048,
+  4072,
+  4095,
+  4119,
+  4142,
+  4166,
+  4189,
+  4199,
+  4200,
+  4222,
+  4231,
+  4233,
+  4234,
+  4237,
+  4241,
+  4244,
+  4245,
+  4257,
+  4267,
+  4287,
+];
+
+static PRUNE_6: [usize; 240] = [
+  650,
+  697,
+  744,
+  791,
+  818,
+  822,
+  827,
+  831,
+  836,
+  839,
+  843,
+  847,
+  849,
+  854,
+  859,
+  864,
+  869,
+  889,
+  890,
+  904,
+  959,
+  1006,
+  1053,
+  1100,
+  1127,
+  1131,
+  1136,
+  1140,
+  1145,
+  1148,
+  1152,
+  1156,
+  1158,
+  1163,
+  1168,
+  1173,
+  1178,
+  1198,
+  1199,
+  1213,
+  1268,
+  1315,
+  1362,
+  1409,
+  1436,
+  1440,
+  1445,
+  1449,
+  1454,
+  1457,
+  1461,
+  1465,
+  1467,
+  1472,
+  1477,
+  1482,
+  1487,
+  1507,
+  1508,
+  1522,
+  1577,
+  1624,
+  1671,
+  1718,
+  1745,
+  1749,
+  1754,
+  1758,
+  1763,
+  1766,
+  1770,
+  1774,
+  1776,
+  1781,
+  1786,
+  1791,
+  1796,
+  1816,
+  1817,
+  1831,
+  1886,
+  1933,
+  1980,
+  2027,
+  2054,
+  2058,
+  2063,
+  2067,
+  2072,
+  2075,
+  2079,
+  2083,
+  2085,
+  2090,
+  2095,
+  2100,
+  2105,
+  2125,
+  2126,
+  2140,
+  2195,
+  2242,
+  2289,
+  2336,
+  2363,
+  2367,
+  2372,
+  2376,
+  2381,
+  2384,
+  2388,
+  2392,
+  2394,
+  2399,
+  2404,
+  2409,
+  2414,
+  2434,
+  2435,
+  2449,
+  2504,
+  2551,
+  2598,
+  2645,
+  2672,
+  2676,
+  2681,
+  2685,
+  2690,
+  2693,
+  2697,
+  2701,
+  2703,
+  2708,
+  2713,
+  2718,
+  2723,
+  2743,
+  2744,
+  2758,
+  2813,
+  2860,
+  2907,
+  2954,
+  2981,
+  2985,
+  2990,
+  2994,
+  2999,
+  3002,
+  3006,
+  3010,
+  3012,
+  3017,
+  3022,
+  3027,
+  3032,
+  3052,
+  3053,
+  3067,
+  3122,
+  3169,
+  3216,
+  3263,
+  3290,
+  3294,
+  3299,
+  3303,
+  3308,
+  3311,
+  3315,
+  3319,
+  3321,
+  3326,
+  3331,
+  3336,
+  3341,
+  3361,
+  3362,
+  3376,
+  3431,
+  3478,
+  3525,
+  3572,
+  3599,
+  3603,
+  3608,
+  3612,
+  3617,
+  3620,
+  3624,
+  3628,
+  3630,
+  3635,
+  3640,
+  3645,
+  3650,
+  3670,
+  3671,
+  3685,
+  3740,
+  3787,
+  3834,
+  3881,
+  3908,
+  3912,
+  3917,
+  3921,
+  3926,
+  3929,
+  3933,
+  3937,
+  3939,
+  3944,
+  3949,
+  3954,
+  3959,
+  3979,
+  3980,
+  3994,
+  4049,
+  4096,
+  4143,
+  4190,
+  4217,
+  4221,
+  4226,
+  4230,
+  4235,
+  4238,
+  4242,
+  4246,
+  4248,
+  4253,
+  4258,
+  4263,
+  4268,
+  4288,
+  4289,
+  4303,
+];
+
+static PRUNE_7: [usize; 168] = [
+  792,
+  793,
+  795,
+  796,
+  837,
+  840,
+  844,
+  848,
+  850,
+  852,
+  855,
+  871,
+  873,
+  891,
+  1101,
+  1102,
+  1104,
+  1105,
+  1146,
+  1149,
+  1153,
+  1157,
+  1159,
+  1161,
+  1164,
+  1180,
+  1182,
+  1200,
+  1410,
+  1411,
+  1413,
+  1414,
+  1455,
+  1458,
+  1462,
+  1466,
+  1468,
+  1470,
+  1473,
+  1489,
+  1491,
+  1509,
+  1719,
+  1720,
+  1722,
+  1723,
+  1764,
+  1767,
+  1771,
+  1775,
+  1777,
+  1779,
+  1782,
+  1798,
+  1800,
+  1818,
+  2028,
+  2029,
+  2031,
+  2032,
+  2073,
+  2076,
+  2080,
+  2084,
+  2086,
+  2088,
+  2091,
+  2107,
+  2109,
+  2127,
+  2337,
+  2338,
+  2340,
+  2341,
+  2382,
+  2385,
+  2389,
+  2393,
+  2395,
+  2397,
+  2400,
+  2416,
+  2418,
+  2436,
+  2646,
+  2647,
+  2649,
+  2650,
+  2691,
+  2694,
+  2698,
+  2702,
+  2704,
+  2706,
+  2709,
+  2725,
+  2727,
+  2745,
+  2955,
+  2956,
+  2958,
+  2959,
+  3000,
+  3003,
+  3007,
+  3011,
+  3013,
+  3015,
+  3018,
+  3034,
+  3036,
+  3054,
+  3264,
+  3265,
+  3267,
+  3268,
+  3309,
+  3312,
+  3316,
+  3320,
+  3322,
+  3324,
+  3327,
+  3343,
+  3345,
+  3363,
+  3573,
+  3574,
+  3576,
+  3577,
+  3618,
+  3621,
+  3625,
+  3629,
+  3631,
+  3633,
+  3636,
+  3652,
+  3654,
+  3672,
+  3882,
+  3883,
+  3885,
+  3886,
+  3927,
+  3930,
+  3934,
+  3938,
+  3940,
+  3942,
+  3945,
+  3961,
+  3963,
+  3981,
+  4191,
+  4192,
+  4194,
+  4195,
+  4236,
+  4239,
+  4243,
+  4247,
+  4249,
+  4251,
+  4254,
+  4270,
+  4272,
+  4290,
+];
+
+static PRUNE_8: [usize; 84] = [
+  794,
+  797,
+  851,
+  853,
+  856,
+  860,
+  874,
+  1103,
+  1106,
+  1160,
+  1162,
+  1165,
+  1169,
+  1183,
+  1412,
+  1415,
+  1469,
+  1471,
+  1474,
+  1478,
+  1492,
+  1721,
+  1724,
+  1778,
+  1780,
+  1783,
+  1787,
+  1801,
+  2030,
+  2033,
+  2087,
+  2089,
+  2092,
+  2096,
+  2110,
+  2339,
+  2342,
+  2396,
+  2398,
+  2401,
+  2405,
+  2419,
+  2648,
+  2651,
+  2705,
+  2707,
+  2710,
+  2714,
+  2728,
+  2957,
+  2960,
+  3014,
+  3016,
+  3019,
+  3023,
+  3037,
+  3266,
+  3269,
+  3323,
+  3325,
+  3328,
+  3332,
+  3346,
+  3575,
+  3578,
+  3632,
+  3634,
+  3637,
+  3641,
+  3655,
+  3884,
+  3887,
+  3941,
+  3943,
+  3946,
+  3950,
+  3964,
+  4193,
+  4196,
+  4250,
+  4252,
+  4255,
+  4259,
+  4273,
+];
+
+static PRUNE_9: [usize; 84] = [
+  861,
+  862,
+  863,
+  872,
+  875,
+  876,
+  892,
+  1170,
+  1171,
+  1172,
+  1181,
+  1184,
+  1185,
+  1201,
+  1479,
+  1480,
+  1481,
+  1490,
+  1493,
+  1494,
+  1510,
+  1788,
+  1789,
+  1790,
+  1799,
+  1802,
+  1803,
+  1819,
+  2097,
+  2098,
+  2099,
+  2108,
+  2111,
+  2112,
+  2128,
+  2406,
+  2407,
+  2408,
+  2417,
+  2420,
+  2421,
+  2437,
+  2715,
+  2716,
+  2717,
+  2726,
+  2729,
+  2730,
+  2746,
+  3024,
+  3025,
+  3026,
+  3035,
+  3038,
+  3039,
+  3055,
+  3333,
+  3334,
+  3335,
+  3344,
+  3347,
+  3348,
+  3364,
+  3642,
+  3643,
+  3644,
+  3653,
+  3656,
+  3657,
+  3673,
+  3951,
+  3952,
+  3953,
+  3962,
+  3965,
+  3966,
+  3982,
+  4260,
+  4261,
+  4262,
+  4271,
+  4274,
+  4275,
+  4291,
+];
+
+static PRUNE_10: [usize; 108] = [
+  870,
+  877,
+  879,
+  882,
+  885,
+  893,
+  894,
+  905,
+  906,
+  1179,
+  1186,
+  1188,
+  1191,
+  1194,
+  1202,
+  1203,
+  1214,
+  1215,
+  1488,
+  1495,
+  1497,
+  1500,
+  1503,
+  1511,
+  1512,
+  1523,
+  1524,
+  1797,
+  1804,
+  1806,
+  1809,
+  1812,
+  1820,
+  1821,
+  1832,
+  1833,
+  2106,
+  2113,
+  2115,
+  2118,
+  2121,
+  2129,
+  2130,
+  2141,
+  2142,
+  2415,
+  2422,
+  2424,
+  2427,
+  2430,
+  2438,
+  2439,
+  2450,
+  2451,
+  2724,
+  2731,
+  2733,
+  2736,
+  2739,
+  2747,
+  2748,
+  2759,
+  2760,
+  3033,
+  3040,
+  3042,
+  3045,
+  3048,
+  3056,
+  3057,
+  3068,
+  3069,
+  3342,
+  3349,
+  3351,
+  3354,
+  3357,
+  3365,
+  3366,
+  3377,
+  3378,
+  3651,
+  3658,
+  3660,
+  3663,
+  3666,
+  3674,
+  3675,
+  3686,
+  3687,
+  3960,
+  3967,
+  3969,
+  3972,
+  3975,
+  3983,
+  3984,
+  3995,
+  3996,
+  4269,
+  4276,
+  4278,
+  4281,
+  4284,
+  4292,
+  4293,
+  4304,
+  4305,
+];
+
+static PRUNE_11: [usize; 48] = [
+  880,
+  883,
+  886,
+  895,
+  1189,
+  1192,
+  1195,
+  1204,
+  1498,
+  1501,
+  1504,
+  1513,
+  1807,
+  1810,
+  1813,
+  1822,
+  2116,
+  2119,
+  2122,
+  2131,
+  2425,
+  2428,
+  2431,
+  2440,
+  2734,
+  2737,
+  2740,
+  2749,
+  3043,
+  3046,
+  3049,
+  3058,
+  3352,
+  3355,
+  3358,
+  3367,
+  3661,
+  3664,
+  3667,
+  3676,
+  3970,
+  3973,
+  3976,
+  3985,
+  4279,
+  4282,
+  4285,
+  4294,
+];
+
+static PRUNE_12: [usize; 60] = [
+  878,
+  881,
+  884,
+  887,
+  896,
+  1187,
+  1190,
+  1193,
+  1196,
+  1205,
+  1496,
+  1499,
+  1502,
+  1505,
+  1514,
+  1805,
+  1808,
+  1811,
+  1814,
+  1823,
+  2114,
+  2117,
+  2120,
+  2123,
+  2132,
+  2423,
+  2426,
+  2429,
+  2432,
+  2441,
+  2732,
+  2735,
+  2738,
+  2741,
+  2750,
+  3041,
+  3044,
+  3047,
+  3050,
+  3059,
+  3350,
+  3353,
+  3356,
+  3359,
+  3368,
+  3659,
+  3662,
+  3665,
+  3668,
+  3677,
+  3968,
+  3971,
+  3974,
+  3977,
+  3986,
+  4277,
+  4280,
+  4283,
+  4286,
+  4295,
+];
+
+static PRUNE_13: [usize; 72] = [
+  897,
+  898,
+  900,
+  902,
+  907,
+  908,
+  1206,
+  1207,
+  1209,
+  1211,
+  1216,
+  1217,
+  1515,
+  1516,
+  1518,
+  1520,
+  1525,
+  1526,
+  1824,
+  1825,
+  1827,
+  1829,
+  1834,
+  1835,
+  2133,
+  2134,
+  2136,
+  2138,
+  2143,
+  2144,
+  2442,
+  2443,
+  2445,
+  2447,
+  2452,
+  2453,
+  2751,
+  2752,
+  2754,
+  2756,
+  2761,
+  2762,
+  3060,
+  3061,
+  3063,
+  3065,
+  3070,
+  3071,
+  3369,
+  3370,
+  3372,
+  3374,
+  3379,
+  3380,
+  3678,
+  3679,
+  3681,
+  3683,
+  3688,
+  3689,
+  3987,
+  3988,
+  3990,
+  3992,
+  3997,
+  3998,
+  4296,
+  4297,
+  4299,
+  4301,
+  4306,
+  4307,
+];
+
+static PRUNE_14: [usize; 60] = [
+  899,
+  901,
+  903,
+  909,
+  910,
+  1208,
+  1210,
+  1212,
+  1218,
+  1219,
+  1517,
+  1519,
+  1521,
+  1527,
+  1528,
+  1826,
+  1828,
+  1830,
+  1836,
+  1837,
+  2135,
+  2137,
+  2139,
+  2145,
+  2146,
+  2444,
+  2446,
+  2448,
+  2454,
+  2455,
+  2753,
+  2755,
+  2757,
+  2763,
+  2764,
+  3062,
+  3064,
+  3066,
+  3072,
+  3073,
+  3371,
+  3373,
+  3375,
+  3381,
+  3382,
+  3680,
+  3682,
+  3684,
+  3690,
+  3691,
+  3989,
+  3991,
+  3993,
+  3999,
+  4000,
+  4298,
+  4300,
+  4302,
+  4308,
+  4309,
+];
+
+static PRUNE_15: [usize; 24] = [
+  798,
+  911,
+  1107,
+  1220,
+  1416,
+  1529,
+  1725,
+  1838,
+  2034,
+  2147,
+  2343,
+  2456,
+  2652,
+  2765,
+  2961,
+  3074,
+  3270,
+  3383,
+  3579,
+  3692,
+  3888,
+  4001,
+  4197,
+  4310,
+];
+
+static PRUNE_16: [usize; 12] = [
+  912,
+  1221,
+  1530,
+  1839,
+  2148,
+  2457,
+  2766,
+  3075,
+  3384,
+  3693,
+  4002,
+  4311,
+];
+
+fn prune<E: BoolEvaluator>(
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    temp_node_ids: &[usize],
+) {
+  for x in temp_node_ids {
+    temp_nodes.remove(&x);
+  }
+}
+
+pub fn maintain_items<E: BoolEvaluator>(items: &Vec<FheBool<E>>, players_coords: &Vec<FheBool<E>>) -> Vec<FheBool<E>> {
+    let args: &[&Vec<FheBool<E>>] = &[items, players_coords];
+
+    let mut temp_nodes = HashMap::new();
+    let mut out = Vec::new();
+    out.resize(588, None);
+
+    let mut run_level = |
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    tasks: &[((usize, bool, CellType), &[GateInput])]
+    | {
+        let updates = tasks
+            .into_par_iter()
+            .map(|(k, task_args)| {
+                let (id, is_output, celltype) = k;
+                let task_args = task_args.into_iter()
+                .map(|arg| match arg {
+                    Cst(false) => todo!(),
+                    Cst(true) => todo!(),
+                    Arg(pos, ndx) => &args[*pos][*ndx],
+                    Tv(ndx) => &temp_nodes[ndx],
+                    Output(ndx) => &out[*ndx]
+                                .as_ref()
+                                .expect(&format!("Output node {ndx} not found")),
+                }).collect::<Vec<_>>();
+
+                let gate_func = |args: &[&FheBool<E>]| match celltype {
+                    AND2 => args[0] & args[1],
+                    NAND2 => args[0].bitnand(args[1]),
+                    OR2 => args[0] | args[1],
+                    NOR2 => args[0].bitnor(args[1]),
+                    XOR2 => args[0] ^ args[1],
+                    XNOR2 => args[0].bitxnor(args[1]),
+                    INV => !args[0],
+                };
+                
+                ((*id, *is_output), gate_func(&task_args))
+            })
+            .collect::<Vec<_>>();
+        updates.into_iter().for_each(|(k, v)| {
+            let (index, is_output) = k;
+            if is_output {
+                out[index] = Some(v);
+            } else {
+                temp_nodes.insert(index, v);
+            }
+        });
+    };
+
+        run_level(&mut temp_nodes, &LEVEL_0);
+        run_level(&mut temp_nodes, &LEVEL_1);
+        prune(&mut temp_nodes, &PRUNE_1);
+        run_level(&mut temp_nodes, &LEVEL_2);
+        prune(&mut temp_nodes, &PRUNE_2);
+        run_level(&mut temp_nodes, &LEVEL_3);
+        prune(&mut temp_nodes, &PRUNE_3);
+        run_level(&mut temp_nodes, &LEVEL_4);
+        prune(&mut temp_nodes, &PRUNE_4);
+        run_level(&mut temp_nodes, &LEVEL_5);
+        prune(&mut temp_nodes, &PRUNE_5);
+        run_level(&mut temp_nodes, &LEVEL_6);
+        prune(&mut temp_nodes, &PRUNE_6);
+        run_level(&mut temp_nodes, &LEVEL_7);
+        prune(&mut temp_nodes, &PRUNE_7);
+        run_level(&mut temp_nodes, &LEVEL_8);
+        prune(&mut temp_nodes, &PRUNE_8);
+        run_level(&mut temp_nodes, &LEVEL_9);
+        prune(&mut temp_nodes, &PRUNE_9);
+        run_level(&mut temp_nodes, &LEVEL_10);
+        prune(&mut temp_nodes, &PRUNE_10);
+        run_level(&mut temp_nodes, &LEVEL_11);
+        prune(&mut temp_nodes, &PRUNE_11);
+        run_level(&mut temp_nodes, &LEVEL_12);
+        prune(&mut temp_nodes, &PRUNE_12);
+        run_level(&mut temp_nodes, &LEVEL_13);
+        prune(&mut temp_nodes, &PRUNE_13);
+        run_level(&mut temp_nodes, &LEVEL_14);
+        prune(&mut temp_nodes, &PRUNE_14);
+        run_level(&mut temp_nodes, &LEVEL_15);
+        prune(&mut temp_nodes, &PRUNE_15);
+        run_level(&mut temp_nodes, &LEVEL_16);
+        prune(&mut temp_nodes, &PRUNE_16);
+
+        out[0] = Some(items[0].clone());
+        out[1] = Some(items[1].clone());
+        out[10] = Some(items[10].clone());
+        out[100] = Some(items[100].clone());
+        out[101] = Some(items[101].clone());
+        out[102] = Some(items[102].clone());
+        out[103] = Some(items[103].clone());
+        out[104] = Some(items[104].clone());
+        out[105] = Some(items[105].clone());
+        out[106] = Some(items[106].clone());
+        out[107] = Some(items[107].clone());
+        out[108] = Some(items[108].clone());
+        out[109] = Some(items[109].clone());
+        out[11] = Some(items[11].clone());
+        out[110] = Some(items[110].clone());
+        out[111] = Some(items[111].clone());
+        out[112] = Some(items[112].clone());
+        out[113] = Some(items[113].clone());
+        out[114] = Some(items[114].clone());
+        out[115] = Some(items[115].clone());
+        out[116] = Some(items[116].clone());
+        out[117] = Some(items[117].clone());
+        out[118] = Some(items[118].clone());
+        out[119] = Some(items[119].clone());
+        out[12] = Some(items[12].clone());
+        out[120] = Some(items[120].clone());
+        out[121] = Some(items[121].clone());
+        out[122] = Some(items[122].clone());
+        out[123] = Some(items[123].clone());
+        out[124] = Some(items[124].clone());
+        out[125] = Some(items[125].clone());
+        out[126] = Some(items[126].clone());
+        out[127] = Some(items[127].clone());
+        out[128] = Some(items[128].clone());
+        out[129] = Some(items[129].clone());
+        out[13] = Some(items[13].clone());
+        out[131] = Some(items[131].clone());
+        out[132] = Some(items[132].clone());
+        out[133] = Some(items[133].clone());
+        out[134] = Some(items[134].clone());
+        out[135] = Some(items[135].clone());
+        out[136] = Some(items[136].clone());
+        out[137] = Some(items[137].clone());
+        out[138] = Some(items[138].clone());
+        out[14] = Some(items[14].clone());
+        out[147] = Some(items[147].clone());
+        out[148] = Some(items[148].clone());
+        out[149] = Some(items[149].clone());
+        out[15] = Some(items[15].clone());
+        out[150] = Some(items[150].clone());
+        out[151] = Some(items[151].clone());
+        out[152] = Some(items[152].clone());
+        out[153] = Some(items[153].clone());
+        out[154] = Some(items[154].clone());
+        out[155] = Some(items[155].clone());
+        out[156] = Some(items[156].clone());
+        out[157] = Some(items[157].clone());
+        out[158] = Some(items[158].clone());
+        out[159] = Some(items[159].clone());
+        out[16] = Some(items[16].clone());
+        out[160] = Some(items[160].clone());
+        out[161] = Some(items[161].clone());
+        out[162] = Some(items[162].clone());
+        out[163] = Some(items[163].clone());
+        out[164] = Some(items[164].clone());
+        out[165] = Some(items[165].clone());
+        out[166] = Some(items[166].clone());
+        out[167] = Some(items[167].clone());
+        out[168] = Some(items[168].clone());
+        out[169] = Some(items[169].clone());
+        out[17] = Some(items[17].clone());
+        out[170] = Some(items[170].clone());
+        out[171] = Some(items[171].clone());
+        out[172] = Some(items[172].clone());
+        out[173] = Some(items[173].clone());
+        out[174] = Some(items[174].clone());
+        out[175] = Some(items[175].clone());
+        out[176] = Some(items[176].clone());
+        out[177] = Some(items[177].clone());
+        out[178] = Some(items[178].clone());
+        out[18] = Some(items[18].clone());
+        out[180] = Some(items[180].clone());
+        out[181] = Some(items[181].clone());
+        out[182] = Some(items[182].clone());
+        out[183] = Some(items[183].clone());
+        out[184] = Some(items[184].clone());
+        out[185] = Some(items[185].clone());
+        out[186] = Some(items[186].clone());
+        out[187] = Some(items[187].clone());
+        out[19] = Some(items[19].clone());
+        out[196] = Some(items[196].clone());
+        out[197] = Some(items[197].clone());
+        out[198] = Some(items[198].clone());
+        out[199] = Some(items[199].clone());
+        out[2] = Some(items[2].clone());
+        out[20] = Some(items[20].clone());
+        out[200] = Some(items[200].clone());
+        out[201] = Some(items[201].clone());
+        out[202] = Some(items[202].clone());
+        out[203] = Some(items[203].clone());
+        out[204] = Some(items[204].clone());
+        out[205] = Some(items[205].clone());
+        out[206] = Some(items[206].clone());
+        out[207] = Some(items[207].clone());
+        out[208] = Some(items[208].clone());
+        out[209] = Some(items[209].clone());
+        out[21] = Some(items[21].clone());
+        out[210] = Some(items[210].clone());
+        out[211] = Some(items[211].clone());
+        out[212] = Some(items[212].clone());
+        out[213] = Some(items[213].clone());
+        out[214] = Some(items[214].clone());
+        out[215] = Some(items[215].clone());
+        out[216] = Some(items[216].clone());
+        out[217] = Some(items[217].clone());
+        out[218] = Some(items[218].clone());
+        out[219] = Some(items[219].clone());
+        out[22] = Some(items[22].clone());
+        out[220] = Some(items[220].clone());
+        out[221] = Some(items[221].clone());
+        out[222] = Some(items[222].clone());
+        out[223] = Some(items[223].clone());
+        out[224] = Some(items[224].clone());
+        out[225] = Some(items[225].clone());
+        out[226] = Some(items[226].clone());
+        out[227] = Some(items[227].clone());
+        out[229] = Some(items[229].clone());
+        out[23] = Some(items[23].clone());
+        out[230] = Some(items[230].clone());
+        out[231] = Some(items[231].clone());
+        out[232] = Some(items[232].clone());
+        out[233] = Some(items[233].clone());
+        out[234] = Some(items[234].clone());
+        out[235] = Some(items[235].clone());
+        out[236] = Some(items[236].clone());
+        out[24] = Some(items[24].clone());
+        out[245] = Some(items[245].clone());
+        out[246] = Some(items[246].clone());
+        out[247] = Some(items[247].clone());
+        out[248] = Some(items[248].clone());
+        out[249] = Some(items[249].clone());
+        out[25] = Some(items[25].clone());
+        out[250] = Some(items[250].clone());
+        out[251] = Some(items[251].clone());
+        out[252] = Some(items[252].clone());
+        out[253] = Some(items[253].clone());
+        out[254] = Some(items[254].clone());
+        out[255] = Some(items[255].clone());
+        out[256] = Some(items[256].clone());
+        out[257] = Some(items[257].clone());
+        out[258] = Some(items[258].clone());
+        out[259] = Some(items[259].clone());
+        out[26] = Some(items[26].clone());
+        out[260] = Some(items[260].clone());
+        out[261] = Some(items[261].clone());
+        out[262] = Some(items[262].clone());
+        out[263] = Some(items[263].clone());
+        out[264] = Some(items[264].clone());
+        out[265] = Some(items[265].clone());
+        out[266] = Some(items[266].clone());
+        out[267] = Some(items[267].clone());
+        out[268] = Some(items[268].clone());
+        out[269] = Some(items[269].clone());
+        out[27] = Some(items[27].clone());
+        out[270] = Some(items[270].clone());
+        out[271] = Some(items[271].clone());
+        out[272] = Some(items[272].clone());
+        out[273] = Some(items[273].clone());
+        out[274] = Some(items[274].clone());
+        out[275] = Some(items[275].clone());
+        out[276] = Some(items[276].clone());
+        out[278] = Some(items[278].clone());
+        out[279] = Some(items[279].clone());
+        out[28] = Some(items[28].clone());
+        out[280] = Some(items[280].clone());
+        out[281] = Some(items[281].clone());
+        out[282] = Some(items[282].clone());
+        out[283] = Some(items[283].clone());
+        out[284] = Some(items[284].clone());
+        out[285] = Some(items[285].clone());
+        out[29] = Some(items[29].clone());
+        out[294] = Some(items[294].clone());
+        out[295] = Some(items[295].clone());
+        out[296] = Some(items[296].clone());
+        out[297] = Some(items[297].clone());
+        out[298] = Some(items[298].clone());
+        out[299] = Some(items[299].clone());
+        out[3] = Some(items[3].clone());
+        out[30] = Some(items[30].clone());
+        out[300] = Some(items[300].clone());
+        out[301] = Some(items[301].clone());
+        out[302] = Some(items[302].clone());
+        out[303] = Some(items[303].clone());
+        out[304] = Some(items[304].clone());
+        out[305] = Some(items[305].clone());
+        out[306] = Some(items[306].clone());
+        out[307] = Some(items[307].clone());
+        out[308] = Some(items[308].clone());
+        out[309] = Some(items[309].clone());
+        out[31] = Some(items[31].clone());
+        out[310] = Some(items[310].clone());
+        out[311] = Some(items[311].clone());
+        out[312] = Some(items[312].clone());
+        out[313] = Some(items[313].clone());
+        out[314] = Some(items[314].clone());
+        out[315] = Some(items[315].clone());
+        out[316] = Some(items[316].clone());
+        out[317] = Some(items[317].clone());
+        out[318] = Some(items[318].clone());
+        out[319] = Some(items[319].clone());
+        out[320] = Some(items[320].clone());
+        out[321] = Some(items[321].clone());
+        out[322] = Some(items[322].clone());
+        out[323] = Some(items[323].clone());
+        out[324] = Some(items[324].clone());
+        out[325] = Some(items[325].clone());
+        out[327] = Some(items[327].clone());
+        out[328] = Some(items[328].clone());
+        out[329] = Some(items[329].clone());
+        out[33] = Some(items[33].clone());
+        out[330] = Some(items[330].clone());
+        out[331] = Some(items[331].clone());
+        out[332] = Some(items[332].clone());
+        out[333] = Some(items[333].clone());
+        out[334] = Some(items[334].clone());
+        out[34] = Some(items[34].clone());
+        out[343] = Some(items[343].clone());
+        out[344] = Some(items[344].clone());
+        out[345] = Some(items[345].clone());
+        out[346] = Some(items[346].clone());
+        out[347] = Some(items[347].clone());
+        out[348] = Some(items[348].clone());
+        out[349] = Some(items[349].clone());
+        out[35] = Some(items[35].clone());
+        out[350] = Some(items[350].clone());
+        out[351] = Some(items[351].clone());
+        out[352] = Some(items[352].clone());
+        out[353] = Some(items[353].clone());
+        out[354] = Some(items[354].clone());
+        out[355] = Some(items[355].clone());
+        out[356] = Some(items[356].clone());
+        out[357] = Some(items[357].clone());
+        out[358] = Some(items[358].clone());
+        out[359] = Some(items[359].clone());
+        out[36] = Some(items[36].clone());
+        out[360] = Some(items[360].clone());
+        out[361] = Some(items[361].clone());
+        out[362] = Some(items[362].clone());
+        out[363] = Some(items[363].clone());
+        out[364] = Some(items[364].clone());
+        out[365] = Some(items[365].clone());
+        out[366] = Some(items[366].clone());
+        out[367] = Some(items[367].clone());
+        out[368] = Some(items[368].clone());
+        out[369] = Some(items[369].clone());
+        out[37] = Some(items[37].clone());
+        out[370] = Some(items[370].clone());
+        out[371] = Some(items[371].clone());
+        out[372] = Some(items[372].clone());
+        out[373] = Some(items[373].clone());
+        out[374] = Some(items[374].clone());
+        out[376] = Some(items[376].clone());
+        out[377] = Some(items[377].clone());
+        out[378] = Some(items[378].clone());
+        out[379] = Some(items[379].clone());
+        out[38] = Some(items[38].clone());
+        out[380] = Some(items[380].clone());
+        out[381] = Some(items[381].clone());
+        out[382] = Some(items[382].clone());
+        out[383] = Some(items[383].clone());
+        out[39] = Some(items[39].clone());
+        out[392] = Some(items[392].clone());
+        out[393] = Some(items[393].clone());
+        out[394] = Some(items[394].clone());
+        out[395] = Some(items[395].clone());
+        out[396] = Some(items[396].clone());
+        out[397] = Some(items[397].clone());
+        out[398] = Some(items[398].clone());
+        out[399] = Some(items[399].clone());
+        out[4] = Some(items[4].clone());
+        out[40] = Some(items[40].clone());
+        out[400] = Some(items[400].clone());
+        out[401] = Some(items[401].clone());
+        out[402] = Some(items[402].clone());
+        out[403] = Some(items[403].clone());
+        out[404] = Some(items[404].clone());
+        out[405] = Some(items[405].clone());
+        out[406] = Some(items[406].clone());
+        out[407] = Some(items[407].clone());
+        out[408] = Some(items[408].clone());
+        out[409] = Some(items[409].clone());
+        out[410] = Some(items[410].clone());
+        out[411] = Some(items[411].clone());
+        out[412] = Some(items[412].clone());
+        out[413] = Some(items[413].clone());
+        out[414] = Some(items[414].clone());
+        out[415] = Some(items[415].clone());
+        out[416] = Some(items[416].clone());
+        out[417] = Some(items[417].clone());
+        out[418] = Some(items[418].clone());
+        out[419] = Some(items[419].clone());
+        out[420] = Some(items[420].clone());
+        out[421] = Some(items[421].clone());
+        out[422] = Some(items[422].clone());
+        out[423] = Some(items[423].clone());
+        out[425] = Some(items[425].clone());
+        out[426] = Some(items[426].clone());
+        out[427] = Some(items[427].clone());
+        out[428] = Some(items[428].clone());
+        out[429] = Some(items[429].clone());
+        out[430] = Some(items[430].clone());
+        out[431] = Some(items[431].clone());
+        out[432] = Some(items[432].clone());
+        out[441] = Some(items[441].clone());
+        out[442] = Some(items[442].clone());
+        out[443] = Some(items[443].clone());
+        out[444] = Some(items[444].clone());
+        out[445] = Some(items[445].clone());
+        out[446] = Some(items[446].clone());
+        out[447] = Some(items[447].clone());
+        out[448] = Some(items[448].clone());
+        out[449] = Some(items[449].clone());
+        out[450] = Some(items[450].clone());
+        out[451] = Some(items[451].clone());
+        out[452] = Some(items[452].clone());
+        out[453] = Some(items[453].clone());
+        out[454] = Some(items[454].clone());
+        out[455] = Some(items[455].clone());
+        out[456] = Some(items[456].clone());
+        out[457] = Some(items[457].clone());
+        out[458] = Some(items[458].clone());
+        out[459] = Some(items[459].clone());
+        out[460] = Some(items[460].clone());
+        out[461] = Some(items[461].clone());
+        out[462] = Some(items[462].clone());
+        out[463] = Some(items[463].clone());
+        out[464] = Some(items[464].clone());
+        out[465] = Some(items[465].clone());
+        out[466] = Some(items[466].clone());
+        out[467] = Some(items[467].clone());
+        out[468] = Some(items[468].clone());
+        out[469] = Some(items[469].clone());
+        out[470] = Some(items[470].clone());
+        out[471] = Some(items[471].clone());
+        out[472] = Some(items[472].clone());
+        out[474] = Some(items[474].clone());
+        out[475] = Some(items[475].clone());
+        out[476] = Some(items[476].clone());
+        out[477] = Some(items[477].clone());
+        out[478] = Some(items[478].clone());
+        out[479] = Some(items[479].clone());
+        out[480] = Some(items[480].clone());
+        out[481] = Some(items[481].clone());
+        out[49] = Some(items[49].clone());
+        out[490] = Some(items[490].clone());
+        out[491] = Some(items[491].clone());
+        out[492] = Some(items[492].clone());
+        out[493] = Some(items[493].clone());
+        out[494] = Some(items[494].clone());
+        out[495] = Some(items[495].clone());
+        out[496] = Some(items[496].clone());
+        out[497] = Some(items[497].clone());
+        out[498] = Some(items[498].clone());
+        out[499] = Some(items[499].clone());
+        out[5] = Some(items[5].clone());
+        out[50] = Some(items[50].clone());
+        out[500] = Some(items[500].clone());
+        out[501] = Some(items[501].clone());
+        out[502] = Some(items[502].clone());
+        out[503] = Some(items[503].clone());
+        out[504] = Some(items[504].clone());
+        out[505] = Some(items[505].clone());
+        out[506] = Some(items[506].clone());
+        out[507] = Some(items[507].clone());
+        out[508] = Some(items[508].clone());
+        out[509] = Some(items[509].clone());
+        out[51] = Some(items[51].clone());
+        out[510] = Some(items[510].clone());
+        out[511] = Some(items[511].clone());
+        out[512] = Some(items[512].clone());
+        out[513] = Some(items[513].clone());
+        out[514] = Some(items[514].clone());
+        out[515] = Some(items[515].clone());
+        out[516] = Some(items[516].clone());
+        out[517] = Some(items[517].clone());
+        out[518] = Some(items[518].clone());
+        out[519] = Some(items[519].clone());
+        out[52] = Some(items[52].clone());
+        out[520] = Some(items[520].clone());
+        out[521] = Some(items[521].clone());
+        out[523] = Some(items[523].clone());
+        out[524] = Some(items[524].clone());
+        out[525] = Some(items[525].clone());
+        out[526] = Some(items[526].clone());
+        out[527] = Some(items[527].clone());
+        out[528] = Some(items[528].clone());
+        out[529] = Some(items[529].clone());
+        out[53] = Some(items[53].clone());
+        out[530] = Some(items[530].clone());
+        out[539] = Some(items[539].clone());
+        out[54] = Some(items[54].clone());
+        out[540] = Some(items[540].clone());
+        out[541] = Some(items[541].clone());
+        out[542] = Some(items[542].clone());
+        out[543] = Some(items[543].clone());
+        out[544] = Some(items[544].clone());
+        out[545] = Some(items[545].clone());
+        out[546] = Some(items[546].clone());
+        out[547] = Some(items[547].clone());
+        out[548] = Some(items[548].clone());
+        out[549] = Some(items[549].clone());
+        out[55] = Some(items[55].clone());
+        out[550] = Some(items[550].clone());
+        out[551] = Some(items[551].clone());
+        out[552] = Some(items[552].clone());
+        out[553] = Some(items[553].clone());
+        out[554] = Some(items[554].clone());
+        out[555] = Some(items[555].clone());
+        out[556] = Some(items[556].clone());
+        out[557] = Some(items[557].clone());
+        out[558] = Some(items[558].clone());
+        out[559] = Some(items[559].clone());
+        out[56] = Some(items[56].clone());
+        out[560] = Some(items[560].clone());
+        out[561] = Some(items[561].clone());
+        out[562] = Some(items[562].clone());
+        out[563] = Some(items[563].clone());
+        out[564] = Some(items[564].clone());
+        out[565] = Some(items[565].clone());
+        out[566] = Some(items[566].clone());
+        out[567] = Some(items[567].clone());
+        out[568] = Some(items[568].clone());
+        out[569] = Some(items[569].clone());
+        out[57] = Some(items[57].clone());
+        out[570] = Some(items[570].clone());
+        out[572] = Some(items[572].clone());
+        out[573] = Some(items[573].clone());
+        out[574] = Some(items[574].clone());
+        out[575] = Some(items[575].clone());
+        out[576] = Some(items[576].clone());
+        out[577] = Some(items[577].clone());
+        out[578] = Some(items[578].clone());
+        out[579] = Some(items[579].clone());
+        out[58] = Some(items[58].clone());
+        out[59] = Some(items[59].clone());
+        out[6] = Some(items[6].clone());
+        out[60] = Some(items[60].clone());
+        out[61] = Some(items[61].clone());
+        out[62] = Some(items[62].clone());
+        out[63] = Some(items[63].clone());
+        out[64] = Some(items[64].clone());
+        out[65] = Some(items[65].clone());
+        out[66] = Some(items[66].clone());
+        out[67] = Some(items[67].clone());
+        out[68] = Some(items[68].clone());
+        out[69] = Some(items[69].clone());
+        out[7] = Some(items[7].clone());
+        out[70] = Some(items[70].clone());
+        out[71] = Some(items[71].clone());
+        out[72] = Some(items[72].clone());
+        out[73] = Some(items[73].clone());
+        out[74] = Some(items[74].clone());
+        out[75] = Some(items[75].clone());
+        out[76] = Some(items[76].clone());
+        out[77] = Some(items[77].clone());
+        out[78] = Some(items[78].clone());
+        out[79] = Some(items[79].clone());
+        out[8] = Some(items[8].clone());
+        out[80] = Some(items[80].clone());
+        out[82] = Some(items[82].clone());
+        out[83] = Some(items[83].clone());
+        out[84] = Some(items[84].clone());
+        out[85] = Some(items[85].clone());
+        out[86] = Some(items[86].clone());
+        out[87] = Some(items[87].clone());
+        out[88] = Some(items[88].clone());
+        out[89] = Some(items[89].clone());
+        out[9] = Some(items[9].clone());
+        out[98] = Some(items[98].clone());
+        out[99] = Some(items[99].clone());
+
+    out.into_iter().map(|c| c.unwrap()).collect()
+}
@@ -0,0 +1,9819 @@
+
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use phantom_zone_evaluator::boolean::{fhew::prelude::*, FheBool};
+
+enum GateInput {
+    Arg(usize, usize), // arg + index
+    Output(usize), // reuse of output wire
+    Tv(usize),  // temp value
+    Cst(bool),  // constant
+}
+
+use GateInput::*;
+
+#[derive(PartialEq, Eq, Hash)]
+enum CellType {
+    AND2,
+    NAND2,
+    XOR2,
+    XNOR2,
+    OR2,
+    NOR2,
+    INV,
+    // TODO: Add back MUX2
+}
+
+use CellType::*;
+
+
+static LEVEL_0: [((usize, bool, CellType), &[GateInput]); 604] = [
+    ((1120, false, NOR2), &[Arg(0, 16), Arg(0, 17)]),
+    ((1121, false, NOR2), &[Arg(0, 18), Arg(0, 19)]),
+    ((1122, false, NOR2), &[Arg(0, 20), Arg(0, 21)]),
+    ((1123, false, NOR2), &[Arg(0, 22), Arg(0, 23)]),
+    ((1127, false, INV), &[Arg(2, 1)]),
+    ((1129, false, AND2), &[Arg(2, 2), Arg(2, 3)]),
+    ((1130, false, NOR2), &[Arg(2, 4), Arg(2, 5)]),
+    ((1131, false, NOR2), &[Arg(2, 6), Arg(2, 7)]),
+    ((1135, false, AND2), &[Arg(2, 8), Arg(2, 9)]),
+    ((1136, false, NOR2), &[Arg(2, 10), Arg(2, 11)]),
+    ((1137, false, NOR2), &[Arg(2, 12), Arg(2, 13)]),
+    ((1138, false, NOR2), &[Arg(2, 14), Arg(2, 15)]),
+    ((1143, false, INV), &[Arg(2, 17)]),
+    ((1145, false, AND2), &[Arg(2, 18), Arg(2, 19)]),
+    ((1146, false, NOR2), &[Arg(2, 20), Arg(2, 21)]),
+    ((1147, false, NOR2), &[Arg(2, 22), Arg(2, 23)]),
+    ((1151, false, AND2), &[Arg(2, 24), Arg(2, 25)]),
+    ((1152, false, NOR2), &[Arg(2, 26), Arg(2, 27)]),
+    ((1153, false, NOR2), &[Arg(2, 28), Arg(2, 29)]),
+    ((1154, false, NOR2), &[Arg(2, 30), Arg(2, 31)]),
+    ((1159, false, INV), &[Arg(2, 33)]),
+    ((1161, false, AND2), &[Arg(2, 34), Arg(2, 35)]),
+    ((1162, false, NOR2), &[Arg(2, 36), Arg(2, 37)]),
+    ((1163, false, NOR2), &[Arg(2, 38), Arg(2, 39)]),
+    ((1167, false, AND2), &[Arg(2, 40), Arg(2, 41)]),
+    ((1168, false, NOR2), &[Arg(2, 42), Arg(2, 43)]),
+    ((1169, false, NOR2), &[Arg(2, 44), Arg(2, 45)]),
+    ((1170, false, NOR2), &[Arg(2, 46), Arg(2, 47)]),
+    ((1175, false, INV), &[Arg(2, 49)]),
+    ((1177, false, AND2), &[Arg(2, 50), Arg(2, 51)]),
+    ((1178, false, NOR2), &[Arg(2, 52), Arg(2, 53)]),
+    ((1179, false, NOR2), &[Arg(2, 54), Arg(2, 55)]),
+    ((1183, false, AND2), &[Arg(2, 56), Arg(2, 57)]),
+    ((1184, false, NOR2), &[Arg(2, 58), Arg(2, 59)]),
+    ((1185, false, NOR2), &[Arg(2, 60), Arg(2, 61)]),
+    ((1186, false, NOR2), &[Arg(2, 62), Arg(2, 63)]),
+    ((1198, false, AND2), &[Arg(1, 0), Arg(1, 1)]),
+    ((1199, false, AND2), &[Arg(1, 1), Arg(1, 2)]),
+    ((1200, false, AND2), &[Arg(1, 2), Arg(1, 3)]),
+    ((1201, false, AND2), &[Arg(1, 3), Arg(1, 4)]),
+    ((1202, false, AND2), &[Arg(1, 4), Arg(1, 5)]),
+    ((1203, false, AND2), &[Arg(1, 5), Arg(1, 6)]),
+    ((1204, false, AND2), &[Arg(1, 6), Arg(1, 7)]),
+    ((1215, false, XNOR2), &[Arg(1, 0), Arg(1, 1)]),
+    ((1259, false, AND2), &[Arg(0, 17), Arg(0, 18)]),
+    ((1260, false, INV), &[Arg(0, 19)]),
+    ((1262, false, AND2), &[Arg(0, 20), Arg(0, 21)]),
+    ((1263, false, INV), &[Arg(0, 20)]),
+    ((1265, false, AND2), &[Arg(0, 21), Arg(0, 22)]),
+    ((1266, false, INV), &[Arg(0, 23)]),
+    ((1279, false, AND2), &[Arg(0, 16), Arg(0, 17)]),
+    ((1280, false, AND2), &[Arg(0, 18), Arg(0, 19)]),
+    ((1281, false, AND2), &[Arg(0, 19), Arg(0, 20)]),
+    ((1290, false, XOR2), &[Arg(0, 16), Arg(0, 17)]),
+    ((1346, false, NOR2), &[Arg(0, 56), Arg(0, 57)]),
+    ((1347, false, NOR2), &[Arg(0, 58), Arg(0, 59)]),
+    ((1348, false, NOR2), &[Arg(0, 60), Arg(0, 61)]),
+    ((1349, false, NOR2), &[Arg(0, 62), Arg(0, 63)]),
+    ((1353, false, NOR2), &[Arg(2, 0), Arg(2, 1)]),
+    ((1354, false, NOR2), &[Arg(2, 2), Arg(2, 3)]),
+    ((1357, false, INV), &[Arg(2, 9)]),
+    ((1359, false, AND2), &[Arg(2, 10), Arg(2, 11)]),
+    ((1363, false, NOR2), &[Arg(2, 16), Arg(2, 17)]),
+    ((1364, false, NOR2), &[Arg(2, 18), Arg(2, 19)]),
+    ((1367, false, INV), &[Arg(2, 25)]),
+    ((1369, false, AND2), &[Arg(2, 26), Arg(2, 27)]),
+    ((1373, false, NOR2), &[Arg(2, 32), Arg(2, 33)]),
+    ((1374, false, NOR2), &[Arg(2, 34), Arg(2, 35)]),
+    ((1377, false, INV), &[Arg(2, 41)]),
+    ((1379, false, AND2), &[Arg(2, 42), Arg(2, 43)]),
+    ((1383, false, NOR2), &[Arg(2, 48), Arg(2, 49)]),
+    ((1384, false, NOR2), &[Arg(2, 50), Arg(2, 51)]),
+    ((1387, false, INV), &[Arg(2, 57)]),
+    ((1389, false, AND2), &[Arg(2, 58), Arg(2, 59)]),
+    ((1400, false, AND2), &[Arg(1, 8), Arg(1, 9)]),
+    ((1401, false, AND2), &[Arg(1, 9), Arg(1, 10)]),
+    ((1402, false, AND2), &[Arg(1, 10), Arg(1, 11)]),
+    ((1403, false, AND2), &[Arg(1, 11), Arg(1, 12)]),
+    ((1404, false, AND2), &[Arg(1, 12), Arg(1, 13)]),
+    ((1405, false, AND2), &[Arg(1, 13), Arg(1, 14)]),
+    ((1406, false, AND2), &[Arg(1, 14), Arg(1, 15)]),
+    ((1417, false, XNOR2), &[Arg(1, 8), Arg(1, 9)]),
+    ((1461, false, INV), &[Arg(0, 57)]),
+    ((1465, false, INV), &[Arg(0, 59)]),
+    ((1469, false, INV), &[Arg(0, 61)]),
+    ((1473, false, INV), &[Arg(0, 63)]),
+    ((1483, false, XOR2), &[Arg(0, 56), Arg(0, 57)]),
+    ((1487, false, AND2), &[Arg(0, 56), Arg(0, 57)]),
+    ((1488, false, AND2), &[Arg(0, 57), Arg(0, 58)]),
+    ((1489, false, AND2), &[Arg(0, 58), Arg(0, 59)]),
+    ((1490, false, AND2), &[Arg(0, 59), Arg(0, 60)]),
+    ((1491, false, AND2), &[Arg(0, 60), Arg(0, 61)]),
+    ((1492, false, AND2), &[Arg(0, 61), Arg(0, 62)]),
+    ((1554, false, NOR2), &[Arg(0, 96), Arg(0, 97)]),
+    ((1555, false, NOR2), &[Arg(0, 98), Arg(0, 99)]),
+    ((1556, false, NOR2), &[Arg(0, 100), Arg(0, 101)]),
+    ((1557, false, NOR2), &[Arg(0, 102), Arg(0, 103)]),
+    ((1561, false, INV), &[Arg(2, 2)]),
+    ((1565, false, INV), &[Arg(2, 8)]),
+    ((1570, false, INV), &[Arg(2, 18)]),
+    ((1574, false, INV), &[Arg(2, 24)]),
+    ((1579, false, INV), &[Arg(2, 34)]),
+    ((1583, false, INV), &[Arg(2, 40)]),
+    ((1588, false, INV), &[Arg(2, 50)]),
+    ((1592, false, INV), &[Arg(2, 56)]),
+    ((1604, false, AND2), &[Arg(1, 16), Arg(1, 17)]),
+    ((1605, false, AND2), &[Arg(1, 17), Arg(1, 18)]),
+    ((1606, false, AND2), &[Arg(1, 18), Arg(1, 19)]),
+    ((1607, false, AND2), &[Arg(1, 19), Arg(1, 20)]),
+    ((1608, false, AND2), &[Arg(1, 20), Arg(1, 21)]),
+    ((1609, false, AND2), &[Arg(1, 21), Arg(1, 22)]),
+    ((1610, false, AND2), &[Arg(1, 22), Arg(1, 23)]),
+    ((1621, false, XNOR2), &[Arg(1, 16), Arg(1, 17)]),
+    ((1665, false, INV), &[Arg(0, 97)]),
+    ((1669, false, INV), &[Arg(0, 99)]),
+    ((1673, false, INV), &[Arg(0, 101)]),
+    ((1677, false, INV), &[Arg(0, 103)]),
+    ((1687, false, XOR2), &[Arg(0, 96), Arg(0, 97)]),
+    ((1691, false, AND2), &[Arg(0, 96), Arg(0, 97)]),
+    ((1692, false, AND2), &[Arg(0, 97), Arg(0, 98)]),
+    ((1693, false, AND2), &[Arg(0, 98), Arg(0, 99)]),
+    ((1694, false, AND2), &[Arg(0, 99), Arg(0, 100)]),
+    ((1695, false, AND2), &[Arg(0, 100), Arg(0, 101)]),
+    ((1696, false, AND2), &[Arg(0, 101), Arg(0, 102)]),
+    ((1758, false, NOR2), &[Arg(0, 136), Arg(0, 137)]),
+    ((1759, false, NOR2), &[Arg(0, 138), Arg(0, 139)]),
+    ((1760, false, NOR2), &[Arg(0, 140), Arg(0, 141)]),
+    ((1761, false, NOR2), &[Arg(0, 142), Arg(0, 143)]),
+    ((1765, false, INV), &[Arg(2, 0)]),
+    ((1770, false, INV), &[Arg(2, 16)]),
+    ((1775, false, INV), &[Arg(2, 32)]),
+    ((1780, false, INV), &[Arg(2, 48)]),
+    ((1792, false, AND2), &[Arg(1, 24), Arg(1, 25)]),
+    ((1793, false, AND2), &[Arg(1, 25), Arg(1, 26)]),
+    ((1794, false, AND2), &[Arg(1, 26), Arg(1, 27)]),
+    ((1795, false, AND2), &[Arg(1, 27), Arg(1, 28)]),
+    ((1796, false, AND2), &[Arg(1, 28), Arg(1, 29)]),
+    ((1797, false, AND2), &[Arg(1, 29), Arg(1, 30)]),
+    ((1798, false, AND2), &[Arg(1, 30), Arg(1, 31)]),
+    ((1809, false, XNOR2), &[Arg(1, 24), Arg(1, 25)]),
+    ((1853, false, INV), &[Arg(0, 137)]),
+    ((1857, false, INV), &[Arg(0, 139)]),
+    ((1861, false, INV), &[Arg(0, 141)]),
+    ((1865, false, INV), &[Arg(0, 143)]),
+    ((1875, false, XOR2), &[Arg(0, 136), Arg(0, 137)]),
+    ((1879, false, AND2), &[Arg(0, 136), Arg(0, 137)]),
+    ((1880, false, AND2), &[Arg(0, 137), Arg(0, 138)]),
+    ((1881, false, AND2), &[Arg(0, 138), Arg(0, 139)]),
+    ((1882, false, AND2), &[Arg(0, 139), Arg(0, 140)]),
+    ((1883, false, AND2), &[Arg(0, 140), Arg(0, 141)]),
+    ((1884, false, AND2), &[Arg(0, 141), Arg(0, 142)]),
+    ((1946, false, NOR2), &[Arg(0, 176), Arg(0, 177)]),
+    ((1947, false, NOR2), &[Arg(0, 178), Arg(0, 179)]),
+    ((1948, false, NOR2), &[Arg(0, 180), Arg(0, 181)]),
+    ((1949, false, NOR2), &[Arg(0, 182), Arg(0, 183)]),
+    ((1953, false, INV), &[Arg(2, 5)]),
+    ((1957, false, NOR2), &[Arg(2, 8), Arg(2, 9)]),
+    ((1958, false, INV), &[Arg(2, 11)]),
+    ((1960, false, INV), &[Arg(2, 13)]),
+    ((1966, false, INV), &[Arg(2, 21)]),
+    ((1970, false, NOR2), &[Arg(2, 24), Arg(2, 25)]),
+    ((1971, false, INV), &[Arg(2, 27)]),
+    ((1973, false, INV), &[Arg(2, 29)]),
+    ((1979, false, INV), &[Arg(2, 37)]),
+    ((1983, false, NOR2), &[Arg(2, 40), Arg(2, 41)]),
+    ((1984, false, INV), &[Arg(2, 43)]),
+    ((1986, false, INV), &[Arg(2, 45)]),
+    ((1992, false, INV), &[Arg(2, 53)]),
+    ((1996, false, NOR2), &[Arg(2, 56), Arg(2, 57)]),
+    ((1997, false, INV), &[Arg(2, 59)]),
+    ((1999, false, INV), &[Arg(2, 61)]),
+    ((2012, false, AND2), &[Arg(1, 32), Arg(1, 33)]),
+    ((2013, false, AND2), &[Arg(1, 33), Arg(1, 34)]),
+    ((2014, false, AND2), &[Arg(1, 34), Arg(1, 35)]),
+    ((2015, false, AND2), &[Arg(1, 35), Arg(1, 36)]),
+    ((2016, false, AND2), &[Arg(1, 36), Arg(1, 37)]),
+    ((2017, false, AND2), &[Arg(1, 37), Arg(1, 38)]),
+    ((2018, false, AND2), &[Arg(1, 38), Arg(1, 39)]),
+    ((2029, false, XNOR2), &[Arg(1, 32), Arg(1, 33)]),
+    ((2073, false, INV), &[Arg(0, 177)]),
+    ((2077, false, INV), &[Arg(0, 179)]),
+    ((2081, false, INV), &[Arg(0, 181)]),
+    ((2085, false, INV), &[Arg(0, 183)]),
+    ((2095, false, XOR2), &[Arg(0, 176), Arg(0, 177)]),
+    ((2099, false, AND2), &[Arg(0, 176), Arg(0, 177)]),
+    ((2100, false, AND2), &[Arg(0, 177), Arg(0, 178)]),
+    ((2101, false, AND2), &[Arg(0, 178), Arg(0, 179)]),
+    ((2102, false, AND2), &[Arg(0, 179), Arg(0, 180)]),
+    ((2103, false, AND2), &[Arg(0, 180), Arg(0, 181)]),
+    ((2104, false, AND2), &[Arg(0, 181), Arg(0, 182)]),
+    ((2166, false, NOR2), &[Arg(0, 216), Arg(0, 217)]),
+    ((2167, false, NOR2), &[Arg(0, 218), Arg(0, 219)]),
+    ((2168, false, NOR2), &[Arg(0, 220), Arg(0, 221)]),
+    ((2169, false, NOR2), &[Arg(0, 222), Arg(0, 223)]),
+    ((2200, false, AND2), &[Arg(1, 40), Arg(1, 41)]),
+    ((2201, false, AND2), &[Arg(1, 41), Arg(1, 42)]),
+    ((2202, false, AND2), &[Arg(1, 42), Arg(1, 43)]),
+    ((2203, false, AND2), &[Arg(1, 43), Arg(1, 44)]),
+    ((2204, false, AND2), &[Arg(1, 44), Arg(1, 45)]),
+    ((2205, false, AND2), &[Arg(1, 45), Arg(1, 46)]),
+    ((2206, false, AND2), &[Arg(1, 46), Arg(1, 47)]),
+    ((2217, false, XNOR2), &[Arg(1, 40), Arg(1, 41)]),
+    ((2261, false, INV), &[Arg(0, 217)]),
+    ((2265, false, INV), &[Arg(0, 219)]),
+    ((2269, false, INV), &[Arg(0, 221)]),
+    ((2273, false, INV), &[Arg(0, 223)]),
+    ((2283, false, XOR2), &[Arg(0, 216), Arg(0, 217)]),
+    ((2287, false, AND2), &[Arg(0, 216), Arg(0, 217)]),
+    ((2288, false, AND2), &[Arg(0, 217), Arg(0, 218)]),
+    ((2289, false, AND2), &[Arg(0, 218), Arg(0, 219)]),
+    ((2290, false, AND2), &[Arg(0, 219), Arg(0, 220)]),
+    ((2291, false, AND2), &[Arg(0, 220), Arg(0, 221)]),
+    ((2292, false, AND2), &[Arg(0, 221), Arg(0, 222)]),
+    ((2354, false, NOR2), &[Arg(0, 256), Arg(0, 257)]),
+    ((2355, false, NOR2), &[Arg(0, 258), Arg(0, 259)]),
+    ((2356, false, NOR2), &[Arg(0, 260), Arg(0, 261)]),
+    ((2357, false, NOR2), &[Arg(0, 262), Arg(0, 263)]),
+    ((2376, false, AND2), &[Arg(1, 48), Arg(1, 49)]),
+    ((2377, false, AND2), &[Arg(1, 49), Arg(1, 50)]),
+    ((2378, false, AND2), &[Arg(1, 50), Arg(1, 51)]),
+    ((2379, false, AND2), &[Arg(1, 51), Arg(1, 52)]),
+    ((2380, false, AND2), &[Arg(1, 52), Arg(1, 53)]),
+    ((2381, false, AND2), &[Arg(1, 53), Arg(1, 54)]),
+    ((2382, false, AND2), &[Arg(1, 54), Arg(1, 55)]),
+    ((2393, false, XNOR2), &[Arg(1, 48), Arg(1, 49)]),
+    ((2437, false, INV), &[Arg(0, 257)]),
+    ((2441, false, INV), &[Arg(0, 259)]),
+    ((2445, false, INV), &[Arg(0, 261)]),
+    ((2449, false, INV), &[Arg(0, 263)]),
+    ((2459, false, XOR2), &[Arg(0, 256), Arg(0, 257)]),
+    ((2463, false, AND2), &[Arg(0, 256), Arg(0, 257)]),
+    ((2464, false, AND2), &[Arg(0, 257), Arg(0, 258)]),
+    ((2465, false, AND2), &[Arg(0, 258), Arg(0, 259)]),
+    ((2466, false, AND2), &[Arg(0, 259), Arg(0, 260)]),
+    ((2467, false, AND2), &[Arg(0, 260), Arg(0, 261)]),
+    ((2468, false, AND2), &[Arg(0, 261), Arg(0, 262)]),
+    ((2530, false, NOR2), &[Arg(0, 296), Arg(0, 297)]),
+    ((2531, false, NOR2), &[Arg(0, 298), Arg(0, 299)]),
+    ((2532, false, NOR2), &[Arg(0, 300), Arg(0, 301)]),
+    ((2533, false, NOR2), &[Arg(0, 302), Arg(0, 303)]),
+    ((2556, false, AND2), &[Arg(1, 56), Arg(1, 57)]),
+    ((2557, false, AND2), &[Arg(1, 57), Arg(1, 58)]),
+    ((2558, false, AND2), &[Arg(1, 58), Arg(1, 59)]),
+    ((2559, false, AND2), &[Arg(1, 59), Arg(1, 60)]),
+    ((2560, false, AND2), &[Arg(1, 60), Arg(1, 61)]),
+    ((2561, false, AND2), &[Arg(1, 61), Arg(1, 62)]),
+    ((2562, false, AND2), &[Arg(1, 62), Arg(1, 63)]),
+    ((2573, false, XNOR2), &[Arg(1, 56), Arg(1, 57)]),
+    ((2617, false, INV), &[Arg(0, 297)]),
+    ((2621, false, INV), &[Arg(0, 299)]),
+    ((2625, false, INV), &[Arg(0, 301)]),
+    ((2629, false, INV), &[Arg(0, 303)]),
+    ((2639, false, XOR2), &[Arg(0, 296), Arg(0, 297)]),
+    ((2643, false, AND2), &[Arg(0, 296), Arg(0, 297)]),
+    ((2644, false, AND2), &[Arg(0, 297), Arg(0, 298)]),
+    ((2645, false, AND2), &[Arg(0, 298), Arg(0, 299)]),
+    ((2646, false, AND2), &[Arg(0, 299), Arg(0, 300)]),
+    ((2647, false, AND2), &[Arg(0, 300), Arg(0, 301)]),
+    ((2648, false, AND2), &[Arg(0, 301), Arg(0, 302)]),
+    ((2710, false, NOR2), &[Arg(0, 336), Arg(0, 337)]),
+    ((2711, false, NOR2), &[Arg(0, 338), Arg(0, 339)]),
+    ((2712, false, NOR2), &[Arg(0, 340), Arg(0, 341)]),
+    ((2713, false, NOR2), &[Arg(0, 342), Arg(0, 343)]),
+    ((2719, false, INV), &[Arg(2, 10)]),
+    ((2726, false, INV), &[Arg(2, 26)]),
+    ((2733, false, INV), &[Arg(2, 42)]),
+    ((2740, false, INV), &[Arg(2, 58)]),
+    ((2752, false, AND2), &[Arg(1, 64), Arg(1, 65)]),
+    ((2753, false, AND2), &[Arg(1, 65), Arg(1, 66)]),
+    ((2754, false, AND2), &[Arg(1, 66), Arg(1, 67)]),
+    ((2755, false, AND2), &[Arg(1, 67), Arg(1, 68)]),
+    ((2756, false, AND2), &[Arg(1, 68), Arg(1, 69)]),
+    ((2757, false, AND2), &[Arg(1, 69), Arg(1, 70)]),
+    ((2758, false, AND2), &[Arg(1, 70), Arg(1, 71)]),
+    ((2769, false, XNOR2), &[Arg(1, 64), Arg(1, 65)]),
+    ((2813, false, INV), &[Arg(0, 337)]),
+    ((2817, false, INV), &[Arg(0, 339)]),
+    ((2821, false, INV), &[Arg(0, 341)]),
+    ((2825, false, INV), &[Arg(0, 343)]),
+    ((2835, false, XOR2), &[Arg(0, 336), Arg(0, 337)]),
+    ((2839, false, AND2), &[Arg(0, 336), Arg(0, 337)]),
+    ((2840, false, AND2), &[Arg(0, 337), Arg(0, 338)]),
+    ((2841, false, AND2), &[Arg(0, 338), Arg(0, 339)]),
+    ((2842, false, AND2), &[Arg(0, 339), Arg(0, 340)]),
+    ((2843, false, AND2), &[Arg(0, 340), Arg(0, 341)]),
+    ((2844, false, AND2), &[Arg(0, 341), Arg(0, 342)]),
+    ((2906, false, NOR2), &[Arg(0, 376), Arg(0, 377)]),
+    ((2907, false, NOR2), &[Arg(0, 378), Arg(0, 379)]),
+    ((2908, false, NOR2), &[Arg(0, 380), Arg(0, 381)]),
+    ((2909, false, NOR2), &[Arg(0, 382), Arg(0, 383)]),
+    ((2932, false, AND2), &[Arg(1, 72), Arg(1, 73)]),
+    ((2933, false, AND2), &[Arg(1, 73), Arg(1, 74)]),
+    ((2934, false, AND2), &[Arg(1, 74), Arg(1, 75)]),
+    ((2935, false, AND2), &[Arg(1, 75), Arg(1, 76)]),
+    ((2936, false, AND2), &[Arg(1, 76), Arg(1, 77)]),
+    ((2937, false, AND2), &[Arg(1, 77), Arg(1, 78)]),
+    ((2938, false, AND2), &[Arg(1, 78), Arg(1, 79)]),
+    ((2949, false, XNOR2), &[Arg(1, 72), Arg(1, 73)]),
+    ((2993, false, INV), &[Arg(0, 377)]),
+    ((2997, false, INV), &[Arg(0, 379)]),
+    ((3001, false, INV), &[Arg(0, 381)]),
+    ((3005, false, INV), &[Arg(0, 383)]),
+    ((3015, false, XOR2), &[Arg(0, 376), Arg(0, 377)]),
+    ((3019, false, AND2), &[Arg(0, 376), Arg(0, 377)]),
+    ((3020, false, AND2), &[Arg(0, 377), Arg(0, 378)]),
+    ((3021, false, AND2), &[Arg(0, 378), Arg(0, 379)]),
+    ((3022, false, AND2), &[Arg(0, 379), Arg(0, 380)]),
+    ((3023, false, AND2), &[Arg(0, 380), Arg(0, 381)]),
+    ((3024, false, AND2), &[Arg(0, 381), Arg(0, 382)]),
+    ((3086, false, NOR2), &[Arg(0, 416), Arg(0, 417)]),
+    ((3087, false, NOR2), &[Arg(0, 418), Arg(0, 419)]),
+    ((3088, false, NOR2), &[Arg(0, 420), Arg(0, 421)]),
+    ((3089, false, NOR2), &[Arg(0, 422), Arg(0, 423)]),
+    ((3093, false, AND2), &[Arg(2, 0), Arg(2, 1)]),
+    ((3099, false, AND2), &[Arg(2, 16), Arg(2, 17)]),
+    ((3105, false, AND2), &[Arg(2, 32), Arg(2, 33)]),
+    ((3111, false, AND2), &[Arg(2, 48), Arg(2, 49)]),
+    ((3124, false, AND2), &[Arg(1, 80), Arg(1, 81)]),
+    ((3125, false, AND2), &[Arg(1, 81), Arg(1, 82)]),
+    ((3126, false, AND2), &[Arg(1, 82), Arg(1, 83)]),
+    ((3127, false, AND2), &[Arg(1, 83), Arg(1, 84)]),
+    ((3128, false, AND2), &[Arg(1, 84), Arg(1, 85)]),
+    ((3129, false, AND2), &[Arg(1, 85), Arg(1, 86)]),
+    ((3130, false, AND2), &[Arg(1, 86), Arg(1, 87)]),
+    ((3141, false, XNOR2), &[Arg(1, 80), Arg(1, 81)]),
+    ((3185, false, AND2), &[Arg(0, 416), Arg(0, 417)]),
+    ((3186, false, AND2), &[Arg(0, 417), Arg(0, 418)]),
+    ((3187, false, AND2), &[Arg(0, 418), Arg(0, 419)]),
+    ((3188, false, INV), &[Arg(0, 421)]),
+    ((3192, false, INV), &[Arg(0, 423)]),
+    ((3202, false, XOR2), &[Arg(0, 416), Arg(0, 417)]),
+    ((3209, false, AND2), &[Arg(0, 419), Arg(0, 420)]),
+    ((3210, false, AND2), &[Arg(0, 420), Arg(0, 421)]),
+    ((3211, false, AND2), &[Arg(0, 421), Arg(0, 422)]),
+    ((3271, false, NOR2), &[Arg(0, 456), Arg(0, 457)]),
+    ((3272, false, NOR2), &[Arg(0, 458), Arg(0, 459)]),
+    ((3273, false, NOR2), &[Arg(0, 460), Arg(0, 461)]),
+    ((3274, false, NOR2), &[Arg(0, 462), Arg(0, 463)]),
+    ((3297, false, AND2), &[Arg(1, 88), Arg(1, 89)]),
+    ((3298, false, AND2), &[Arg(1, 89), Arg(1, 90)]),
+    ((3299, false, AND2), &[Arg(1, 90), Arg(1, 91)]),
+    ((3300, false, AND2), &[Arg(1, 91), Arg(1, 92)]),
+    ((3301, false, AND2), &[Arg(1, 92), Arg(1, 93)]),
+    ((3302, false, AND2), &[Arg(1, 93), Arg(1, 94)]),
+    ((3303, false, AND2), &[Arg(1, 94), Arg(1, 95)]),
+    ((3314, false, XNOR2), &[Arg(1, 88), Arg(1, 89)]),
+    ((3358, false, AND2), &[Arg(0, 456), Arg(0, 457)]),
+    ((3359, false, AND2), &[Arg(0, 457), Arg(0, 458)]),
+    ((3360, false, AND2), &[Arg(0, 458), Arg(0, 459)]),
+    ((3361, false, INV), &[Arg(0, 461)]),
+    ((3365, false, INV), &[Arg(0, 463)]),
+    ((3375, false, XOR2), &[Arg(0, 456), Arg(0, 457)]),
+    ((3382, false, AND2), &[Arg(0, 459), Arg(0, 460)]),
+    ((3383, false, AND2), &[Arg(0, 460), Arg(0, 461)]),
+    ((3384, false, AND2), &[Arg(0, 461), Arg(0, 462)]),
+    ((3444, false, NOR2), &[Arg(0, 496), Arg(0, 497)]),
+    ((3445, false, NOR2), &[Arg(0, 498), Arg(0, 499)]),
+    ((3446, false, NOR2), &[Arg(0, 500), Arg(0, 501)]),
+    ((3447, false, NOR2), &[Arg(0, 502), Arg(0, 503)]),
+    ((3470, false, AND2), &[Arg(1, 96), Arg(1, 97)]),
+    ((3471, false, AND2), &[Arg(1, 97), Arg(1, 98)]),
+    ((3472, false, AND2), &[Arg(1, 98), Arg(1, 99)]),
+    ((3473, false, AND2), &[Arg(1, 99), Arg(1, 100)]),
+    ((3474, false, AND2), &[Arg(1, 100), Arg(1, 101)]),
+    ((3475, false, AND2), &[Arg(1, 101), Arg(1, 102)]),
+    ((3476, false, AND2), &[Arg(1, 102), Arg(1, 103)]),
+    ((3487, false, XNOR2), &[Arg(1, 96), Arg(1, 97)]),
+    ((3531, false, AND2), &[Arg(0, 496), Arg(0, 497)]),
+    ((3532, false, AND2), &[Arg(0, 497), Arg(0, 498)]),
+    ((3533, false, AND2), &[Arg(0, 498), Arg(0, 499)]),
+    ((3534, false, INV), &[Arg(0, 501)]),
+    ((3538, false, INV), &[Arg(0, 503)]),
+    ((3548, false, XOR2), &[Arg(0, 496), Arg(0, 497)]),
+    ((3555, false, AND2), &[Arg(0, 499), Arg(0, 500)]),
+    ((3556, false, AND2), &[Arg(0, 500), Arg(0, 501)]),
+    ((3557, false, AND2), &[Arg(0, 501), Arg(0, 502)]),
+    ((3617, false, NOR2), &[Arg(0, 536), Arg(0, 537)]),
+    ((3618, false, NOR2), &[Arg(0, 538), Arg(0, 539)]),
+    ((3619, false, NOR2), &[Arg(0, 540), Arg(0, 541)]),
+    ((3620, false, NOR2), &[Arg(0, 542), Arg(0, 543)]),
+    ((3639, false, AND2), &[Arg(1, 104), Arg(1, 105)]),
+    ((3640, false, AND2), &[Arg(1, 105), Arg(1, 106)]),
+    ((3641, false, AND2), &[Arg(1, 106), Arg(1, 107)]),
+    ((3642, false, AND2), &[Arg(1, 107), Arg(1, 108)]),
+    ((3643, false, AND2), &[Arg(1, 108), Arg(1, 109)]),
+    ((3644, false, AND2), &[Arg(1, 109), Arg(1, 110)]),
+    ((3645, false, AND2), &[Arg(1, 110), Arg(1, 111)]),
+    ((3656, false, XNOR2), &[Arg(1, 104), Arg(1, 105)]),
+    ((3700, false, INV), &[Arg(0, 537)]),
+    ((3704, false, AND2), &[Arg(0, 537), Arg(0, 538)]),
+    ((3705, false, INV), &[Arg(0, 539)]),
+    ((3707, false, INV), &[Arg(0, 541)]),
+    ((3711, false, INV), &[Arg(0, 543)]),
+    ((3722, false, XOR2), &[Arg(0, 536), Arg(0, 537)]),
+    ((3726, false, AND2), &[Arg(0, 536), Arg(0, 537)]),
+    ((3727, false, AND2), &[Arg(0, 538), Arg(0, 539)]),
+    ((3728, false, AND2), &[Arg(0, 539), Arg(0, 540)]),
+    ((3729, false, AND2), &[Arg(0, 540), Arg(0, 541)]),
+    ((3730, false, AND2), &[Arg(0, 541), Arg(0, 542)]),
+    ((3793, false, NOR2), &[Arg(0, 576), Arg(0, 577)]),
+    ((3794, false, NOR2), &[Arg(0, 578), Arg(0, 579)]),
+    ((3795, false, NOR2), &[Arg(0, 580), Arg(0, 581)]),
+    ((3796, false, NOR2), &[Arg(0, 582), Arg(0, 583)]),
+    ((3815, false, AND2), &[Arg(1, 112), Arg(1, 113)]),
+    ((3816, false, AND2), &[Arg(1, 113), Arg(1, 114)]),
+    ((3817, false, AND2), &[Arg(1, 114), Arg(1, 115)]),
+    ((3818, false, AND2), &[Arg(1, 115), Arg(1, 116)]),
+    ((3819, false, AND2), &[Arg(1, 116), Arg(1, 117)]),
+    ((3820, false, AND2), &[Arg(1, 117), Arg(1, 118)]),
+    ((3821, false, AND2), &[Arg(1, 118), Arg(1, 119)]),
+    ((3832, false, XNOR2), &[Arg(1, 112), Arg(1, 113)]),
+    ((3876, false, INV), &[Arg(0, 577)]),
+    ((3880, false, AND2), &[Arg(0, 577), Arg(0, 578)]),
+    ((3881, false, INV), &[Arg(0, 579)]),
+    ((3883, false, INV), &[Arg(0, 581)]),
+    ((3887, false, INV), &[Arg(0, 583)]),
+    ((3898, false, XOR2), &[Arg(0, 576), Arg(0, 577)]),
+    ((3902, false, AND2), &[Arg(0, 576), Arg(0, 577)]),
+    ((3903, false, AND2), &[Arg(0, 578), Arg(0, 579)]),
+    ((3904, false, AND2), &[Arg(0, 579), Arg(0, 580)]),
+    ((3905, false, AND2), &[Arg(0, 580), Arg(0, 581)]),
+    ((3906, false, AND2), &[Arg(0, 581), Arg(0, 582)]),
+    ((3969, false, NOR2), &[Arg(0, 616), Arg(0, 617)]),
+    ((3970, false, NOR2), &[Arg(0, 618), Arg(0, 619)]),
+    ((3971, false, NOR2), &[Arg(0, 620), Arg(0, 621)]),
+    ((3972, false, NOR2), &[Arg(0, 622), Arg(0, 623)]),
+    ((3987, false, AND2), &[Arg(1, 120), Arg(1, 121)]),
+    ((3988, false, AND2), &[Arg(1, 121), Arg(1, 122)]),
+    ((3989, false, AND2), &[Arg(1, 122), Arg(1, 123)]),
+    ((3990, false, AND2), &[Arg(1, 123), Arg(1, 124)]),
+    ((3991, false, AND2), &[Arg(1, 124), Arg(1, 125)]),
+    ((3992, false, AND2), &[Arg(1, 125), Arg(1, 126)]),
+    ((3993, false, AND2), &[Arg(1, 126), Arg(1, 127)]),
+    ((4004, false, XNOR2), &[Arg(1, 120), Arg(1, 121)]),
+    ((4048, false, INV), &[Arg(0, 617)]),
+    ((4052, false, AND2), &[Arg(0, 617), Arg(0, 618)]),
+    ((4053, false, INV), &[Arg(0, 619)]),
+    ((4055, false, INV), &[Arg(0, 621)]),
+    ((4059, false, INV), &[Arg(0, 623)]),
+    ((4070, false, XOR2), &[Arg(0, 616), Arg(0, 617)]),
+    ((4074, false, AND2), &[Arg(0, 616), Arg(0, 617)]),
+    ((4075, false, AND2), &[Arg(0, 618), Arg(0, 619)]),
+    ((4076, false, AND2), &[Arg(0, 619), Arg(0, 620)]),
+    ((4077, false, AND2), &[Arg(0, 620), Arg(0, 621)]),
+    ((4078, false, AND2), &[Arg(0, 621), Arg(0, 622)]),
+    ((4141, false, NOR2), &[Arg(0, 656), Arg(0, 657)]),
+    ((4142, false, NOR2), &[Arg(0, 658), Arg(0, 659)]),
+    ((4143, false, NOR2), &[Arg(0, 660), Arg(0, 661)]),
+    ((4144, false, NOR2), &[Arg(0, 662), Arg(0, 663)]),
+    ((4148, false, INV), &[Arg(2, 3)]),
+    ((4154, false, INV), &[Arg(2, 19)]),
+    ((4160, false, INV), &[Arg(2, 35)]),
+    ((4166, false, INV), &[Arg(2, 51)]),
+    ((4179, false, AND2), &[Arg(1, 128), Arg(1, 129)]),
+    ((4180, false, AND2), &[Arg(1, 129), Arg(1, 130)]),
+    ((4181, false, AND2), &[Arg(1, 130), Arg(1, 131)]),
+    ((4182, false, AND2), &[Arg(1, 131), Arg(1, 132)]),
+    ((4183, false, AND2), &[Arg(1, 132), Arg(1, 133)]),
+    ((4184, false, AND2), &[Arg(1, 133), Arg(1, 134)]),
+    ((4185, false, AND2), &[Arg(1, 134), Arg(1, 135)]),
+    ((4196, false, XNOR2), &[Arg(1, 128), Arg(1, 129)]),
+    ((4240, false, INV), &[Arg(0, 657)]),
+    ((4244, false, AND2), &[Arg(0, 657), Arg(0, 658)]),
+    ((4245, false, INV), &[Arg(0, 659)]),
+    ((4247, false, INV), &[Arg(0, 661)]),
+    ((4251, false, INV), &[Arg(0, 663)]),
+    ((4262, false, XOR2), &[Arg(0, 656), Arg(0, 657)]),
+    ((4266, false, AND2), &[Arg(0, 656), Arg(0, 657)]),
+    ((4267, false, AND2), &[Arg(0, 658), Arg(0, 659)]),
+    ((4268, false, AND2), &[Arg(0, 659), Arg(0, 660)]),
+    ((4269, false, AND2), &[Arg(0, 660), Arg(0, 661)]),
+    ((4270, false, AND2), &[Arg(0, 661), Arg(0, 662)]),
+    ((4333, false, NOR2), &[Arg(0, 696), Arg(0, 697)]),
+    ((4334, false, NOR2), &[Arg(0, 698), Arg(0, 699)]),
+    ((4335, false, NOR2), &[Arg(0, 700), Arg(0, 701)]),
+    ((4336, false, NOR2), &[Arg(0, 702), Arg(0, 703)]),
+    ((4355, false, AND2), &[Arg(1, 136), Arg(1, 137)]),
+    ((4356, false, AND2), &[Arg(1, 137), Arg(1, 138)]),
+    ((4357, false, AND2), &[Arg(1, 138), Arg(1, 139)]),
+    ((4358, false, AND2), &[Arg(1, 139), Arg(1, 140)]),
+    ((4359, false, AND2), &[Arg(1, 140), Arg(1, 141)]),
+    ((4360, false, AND2), &[Arg(1, 141), Arg(1, 142)]),
+    ((4361, false, AND2), &[Arg(1, 142), Arg(1, 143)]),
+    ((4372, false, XNOR2), &[Arg(1, 136), Arg(1, 137)]),
+    ((4416, false, INV), &[Arg(0, 699)]),
+    ((4420, false, INV), &[Arg(0, 701)]),
+    ((4424, false, INV), &[Arg(0, 703)]),
+    ((4437, false, AND2), &[Arg(0, 696), Arg(0, 697)]),
+    ((4438, false, AND2), &[Arg(0, 697), Arg(0, 698)]),
+    ((4439, false, AND2), &[Arg(0, 698), Arg(0, 699)]),
+    ((4440, false, AND2), &[Arg(0, 699), Arg(0, 700)]),
+    ((4441, false, AND2), &[Arg(0, 700), Arg(0, 701)]),
+    ((4442, false, AND2), &[Arg(0, 701), Arg(0, 702)]),
+    ((4451, false, XOR2), &[Arg(0, 696), Arg(0, 697)]),
+    ((4505, false, NOR2), &[Arg(0, 736), Arg(0, 737)]),
+    ((4506, false, NOR2), &[Arg(0, 738), Arg(0, 739)]),
+    ((4507, false, NOR2), &[Arg(0, 740), Arg(0, 741)]),
+    ((4508, false, NOR2), &[Arg(0, 742), Arg(0, 743)]),
+    ((4523, false, AND2), &[Arg(1, 144), Arg(1, 145)]),
+    ((4524, false, AND2), &[Arg(1, 145), Arg(1, 146)]),
+    ((4525, false, AND2), &[Arg(1, 146), Arg(1, 147)]),
+    ((4526, false, AND2), &[Arg(1, 147), Arg(1, 148)]),
+    ((4527, false, AND2), &[Arg(1, 148), Arg(1, 149)]),
+    ((4528, false, AND2), &[Arg(1, 149), Arg(1, 150)]),
+    ((4529, false, AND2), &[Arg(1, 150), Arg(1, 151)]),
+    ((4540, false, XNOR2), &[Arg(1, 144), Arg(1, 145)]),
+    ((4584, false, INV), &[Arg(0, 739)]),
+    ((4588, false, INV), &[Arg(0, 741)]),
+    ((4592, false, INV), &[Arg(0, 743)]),
+    ((4605, false, AND2), &[Arg(0, 736), Arg(0, 737)]),
+    ((4606, false, AND2), &[Arg(0, 737), Arg(0, 738)]),
+    ((4607, false, AND2), &[Arg(0, 738), Arg(0, 739)]),
+    ((4608, false, AND2), &[Arg(0, 739), Arg(0, 740)]),
+    ((4609, false, AND2), &[Arg(0, 740), Arg(0, 741)]),
+    ((4610, false, AND2), &[Arg(0, 741), Arg(0, 742)]),
+    ((4619, false, XOR2), &[Arg(0, 736), Arg(0, 737)]),
+    ((4673, false, NOR2), &[Arg(0, 776), Arg(0, 777)]),
+    ((4674, false, NOR2), &[Arg(0, 778), Arg(0, 779)]),
+    ((4675, false, NOR2), &[Arg(0, 780), Arg(0, 781)]),
+    ((4676, false, NOR2), &[Arg(0, 782), Arg(0, 783)]),
+    ((4699, false, AND2), &[Arg(1, 152), Arg(1, 153)]),
+    ((4700, false, AND2), &[Arg(1, 153), Arg(1, 154)]),
+    ((4701, false, AND2), &[Arg(1, 154), Arg(1, 155)]),
+    ((4702, false, AND2), &[Arg(1, 155), Arg(1, 156)]),
+    ((4703, false, AND2), &[Arg(1, 156), Arg(1, 157)]),
+    ((4704, false, AND2), &[Arg(1, 157), Arg(1, 158)]),
+    ((4705, false, AND2), &[Arg(1, 158), Arg(1, 159)]),
+    ((4716, false, XNOR2), &[Arg(1, 152), Arg(1, 153)]),
+    ((4760, false, INV), &[Arg(0, 779)]),
+    ((4764, false, INV), &[Arg(0, 781)]),
+    ((4768, false, INV), &[Arg(0, 783)]),
+    ((4781, false, AND2), &[Arg(0, 776), Arg(0, 777)]),
+    ((4782, false, AND2), &[Arg(0, 777), Arg(0, 778)]),
+    ((4783, false, AND2), &[Arg(0, 778), Arg(0, 779)]),
+    ((4784, false, AND2), &[Arg(0, 779), Arg(0, 780)]),
+    ((4785, false, AND2), &[Arg(0, 780), Arg(0, 781)]),
+    ((4786, false, AND2), &[Arg(0, 781), Arg(0, 782)]),
+    ((4795, false, XOR2), &[Arg(0, 776), Arg(0, 777)]),
+    ((4849, false, NOR2), &[Arg(0, 816), Arg(0, 817)]),
+    ((4850, false, NOR2), &[Arg(0, 818), Arg(0, 819)]),
+    ((4851, false, NOR2), &[Arg(0, 820), Arg(0, 821)]),
+    ((4852, false, NOR2), &[Arg(0, 822), Arg(0, 823)]),
+    ((4875, false, AND2), &[Arg(1, 160), Arg(1, 161)]),
+    ((4876, false, AND2), &[Arg(1, 161), Arg(1, 162)]),
+    ((4877, false, AND2), &[Arg(1, 162), Arg(1, 163)]),
+    ((4878, false, AND2), &[Arg(1, 163), Arg(1, 164)]),
+    ((4879, false, AND2), &[Arg(1, 164), Arg(1, 165)]),
+    ((4880, false, AND2), &[Arg(1, 165), Arg(1, 166)]),
+    ((4881, false, AND2), &[Arg(1, 166), Arg(1, 167)]),
+    ((4892, false, XNOR2), &[Arg(1, 160), Arg(1, 161)]),
+    ((4936, false, INV), &[Arg(0, 819)]),
+    ((4940, false, INV), &[Arg(0, 821)]),
+    ((4944, false, INV), &[Arg(0, 823)]),
+    ((4957, false, AND2), &[Arg(0, 816), Arg(0, 817)]),
+    ((4958, false, AND2), &[Arg(0, 817), Arg(0, 818)]),
+    ((4959, false, AND2), &[Arg(0, 818), Arg(0, 819)]),
+    ((4960, false, AND2), &[Arg(0, 819), Arg(0, 820)]),
+    ((4961, false, AND2), &[Arg(0, 820), Arg(0, 821)]),
+    ((4962, false, AND2), &[Arg(0, 821), Arg(0, 822)]),
+    ((4971, false, XOR2), &[Arg(0, 816), Arg(0, 817)]),
+    ((5025, false, NOR2), &[Arg(0, 856), Arg(0, 857)]),
+    ((5026, false, NOR2), &[Arg(0, 858), Arg(0, 859)]),
+    ((5027, false, NOR2), &[Arg(0, 860), Arg(0, 861)]),
+    ((5028, false, NOR2), &[Arg(0, 862), Arg(0, 863)]),
+    ((5047, false, AND2), &[Arg(1, 168), Arg(1, 169)]),
+    ((5048, false, AND2), &[Arg(1, 169), Arg(1, 170)]),
+    ((5049, false, AND2), &[Arg(1, 170), Arg(1, 171)]),
+    ((5050, false, AND2), &[Arg(1, 171), Arg(1, 172)]),
+    ((5051, false, AND2), &[Arg(1, 172), Arg(1, 173)]),
+    ((5052, false, AND2), &[Arg(1, 173), Arg(1, 174)]),
+    ((5053, false, AND2), &[Arg(1, 174), Arg(1, 175)]),
+    ((5064, false, XNOR2), &[Arg(1, 168), Arg(1, 169)]),
+    ((5108, false, INV), &[Arg(0, 859)]),
+    ((5112, false, INV), &[Arg(0, 861)]),
+    ((5116, false, INV), &[Arg(0, 863)]),
+    ((5129, false, AND2), &[Arg(0, 856), Arg(0, 857)]),
+    ((5130, false, AND2), &[Arg(0, 857), Arg(0, 858)]),
+    ((5131, false, AND2), &[Arg(0, 858), Arg(0, 859)]),
+    ((5132, false, AND2), &[Arg(0, 859), Arg(0, 860)]),
+    ((5133, false, AND2), &[Arg(0, 860), Arg(0, 861)]),
+    ((5134, false, AND2), &[Arg(0, 861), Arg(0, 862)]),
+    ((5143, false, XOR2), &[Arg(0, 856), Arg(0, 857)]),
+    ((5197, false, NOR2), &[Arg(0, 896), Arg(0, 897)]),
+    ((5198, false, NOR2), &[Arg(0, 898), Arg(0, 899)]),
+    ((5199, false, NOR2), &[Arg(0, 900), Arg(0, 901)]),
+    ((5200, false, NOR2), &[Arg(0, 902), Arg(0, 903)]),
+    ((5223, false, AND2), &[Arg(1, 176), Arg(1, 177)]),
+    ((5224, false, AND2), &[Arg(1, 177), Arg(1, 178)]),
+    ((5225, false, AND2), &[Arg(1, 178), Arg(1, 179)]),
+    ((5226, false, AND2), &[Arg(1, 179), Arg(1, 180)]),
+    ((5227, false, AND2), &[Arg(1, 180), Arg(1, 181)]),
+    ((5228, false, AND2), &[Arg(1, 181), Arg(1, 182)]),
+    ((5229, false, AND2), &[Arg(1, 182), Arg(1, 183)]),
+    ((5240, false, XNOR2), &[Arg(1, 176), Arg(1, 177)]),
+    ((5284, false, INV), &[Arg(0, 899)]),
+    ((5288, false, INV), &[Arg(0, 901)]),
+    ((5292, false, INV), &[Arg(0, 903)]),
+    ((5305, false, AND2), &[Arg(0, 896), Arg(0, 897)]),
+    ((5306, false, AND2), &[Arg(0, 897), Arg(0, 898)]),
+    ((5307, false, AND2), &[Arg(0, 898), Arg(0, 899)]),
+    ((5308, false, AND2), &[Arg(0, 899), Arg(0, 900)]),
+    ((5309, false, AND2), &[Arg(0, 900), Arg(0, 901)]),
+    ((5310, false, AND2), &[Arg(0, 901), Arg(0, 902)]),
+    ((5319, false, XOR2), &[Arg(0, 896), Arg(0, 897)]),
+];
+
+static LEVEL_1: [((usize, bool, CellType), &[GateInput]); 544] = [
+    ((1124, false, AND2), &[Tv(1120), Tv(1121)]),
+    ((1125, false, AND2), &[Tv(1122), Tv(1123)]),
+    ((1128, false, AND2), &[Arg(2, 0), Tv(1127)]),
+    ((1133, false, AND2), &[Tv(1130), Tv(1131)]),
+    ((1139, false, AND2), &[Tv(1135), Tv(1136)]),
+    ((1140, false, AND2), &[Tv(1137), Tv(1138)]),
+    ((1144, false, AND2), &[Arg(2, 16), Tv(1143)]),
+    ((1149, false, AND2), &[Tv(1146), Tv(1147)]),
+    ((1155, false, AND2), &[Tv(1151), Tv(1152)]),
+    ((1156, false, AND2), &[Tv(1153), Tv(1154)]),
+    ((1160, false, AND2), &[Arg(2, 32), Tv(1159)]),
+    ((1165, false, AND2), &[Tv(1162), Tv(1163)]),
+    ((1171, false, AND2), &[Tv(1167), Tv(1168)]),
+    ((1172, false, AND2), &[Tv(1169), Tv(1170)]),
+    ((1176, false, AND2), &[Arg(2, 48), Tv(1175)]),
+    ((1181, false, AND2), &[Tv(1178), Tv(1179)]),
+    ((1187, false, AND2), &[Tv(1183), Tv(1184)]),
+    ((1188, false, AND2), &[Tv(1185), Tv(1186)]),
+    ((1205, false, AND2), &[Arg(1, 0), Tv(1199)]),
+    ((1206, false, AND2), &[Tv(1198), Tv(1200)]),
+    ((1207, false, AND2), &[Tv(1199), Tv(1201)]),
+    ((1208, false, AND2), &[Tv(1200), Tv(1202)]),
+    ((1209, false, AND2), &[Tv(1201), Tv(1203)]),
+    ((1210, false, AND2), &[Tv(1202), Tv(1204)]),
+    ((1216, false, XNOR2), &[Arg(1, 2), Tv(1198)]),
+    ((1224, false, INV), &[Tv(1215)]),
+    ((1261, false, NAND2), &[Arg(0, 18), Tv(1260)]),
+    ((1264, false, AND2), &[Tv(1263), Arg(0, 21)]),
+    ((1267, false, AND2), &[Arg(0, 22), Tv(1266)]),
+    ((1282, false, AND2), &[Arg(0, 16), Tv(1259)]),
+    ((1283, false, AND2), &[Tv(1279), Tv(1280)]),
+    ((1284, false, AND2), &[Tv(1259), Tv(1281)]),
+    ((1285, false, AND2), &[Tv(1262), Tv(1280)]),
+    ((1286, false, AND2), &[Tv(1265), Tv(1281)]),
+    ((1291, false, XOR2), &[Arg(0, 18), Tv(1279)]),
+    ((1298, false, XOR2), &[Arg(0, 17), Tv(1290)]),
+    ((1350, false, AND2), &[Tv(1346), Tv(1347)]),
+    ((1351, false, AND2), &[Tv(1348), Tv(1349)]),
+    ((1355, false, AND2), &[Tv(1353), Tv(1354)]),
+    ((1358, false, AND2), &[Arg(2, 8), Tv(1357)]),
+    ((1365, false, AND2), &[Tv(1363), Tv(1364)]),
+    ((1368, false, AND2), &[Arg(2, 24), Tv(1367)]),
+    ((1375, false, AND2), &[Tv(1373), Tv(1374)]),
+    ((1378, false, AND2), &[Arg(2, 40), Tv(1377)]),
+    ((1385, false, AND2), &[Tv(1383), Tv(1384)]),
+    ((1388, false, AND2), &[Arg(2, 56), Tv(1387)]),
+    ((1407, false, AND2), &[Arg(1, 8), Tv(1401)]),
+    ((1408, false, AND2), &[Tv(1400), Tv(1402)]),
+    ((1409, false, AND2), &[Tv(1401), Tv(1403)]),
+    ((1410, false, AND2), &[Tv(1402), Tv(1404)]),
+    ((1411, false, AND2), &[Tv(1403), Tv(1405)]),
+    ((1412, false, AND2), &[Tv(1404), Tv(1406)]),
+    ((1418, false, XNOR2), &[Arg(1, 10), Tv(1400)]),
+    ((1426, false, INV), &[Tv(1417)]),
+    ((1462, false, NAND2), &[Arg(0, 56), Tv(1461)]),
+    ((1466, false, NAND2), &[Arg(0, 58), Tv(1465)]),
+    ((1470, false, NAND2), &[Arg(0, 60), Tv(1469)]),
+    ((1474, false, NAND2), &[Arg(0, 62), Tv(1473)]),
+    ((1493, false, AND2), &[Arg(0, 56), Tv(1488)]),
+    ((1494, false, AND2), &[Tv(1487), Tv(1489)]),
+    ((1495, false, AND2), &[Tv(1488), Tv(1490)]),
+    ((1496, false, AND2), &[Tv(1489), Tv(1491)]),
+    ((1497, false, AND2), &[Tv(1490), Tv(1492)]),
+    ((1501, false, XOR2), &[Arg(0, 58), Tv(1487)]),
+    ((1508, false, XOR2), &[Arg(0, 57), Tv(1483)]),
+    ((1558, false, AND2), &[Tv(1554), Tv(1555)]),
+    ((1559, false, AND2), &[Tv(1556), Tv(1557)]),
+    ((1562, false, AND2), &[Tv(1561), Arg(2, 3)]),
+    ((1566, false, AND2), &[Tv(1565), Arg(2, 9)]),
+    ((1571, false, AND2), &[Tv(1570), Arg(2, 19)]),
+    ((1575, false, AND2), &[Tv(1574), Arg(2, 25)]),
+    ((1580, false, AND2), &[Tv(1579), Arg(2, 35)]),
+    ((1584, false, AND2), &[Tv(1583), Arg(2, 41)]),
+    ((1589, false, AND2), &[Tv(1588), Arg(2, 51)]),
+    ((1593, false, AND2), &[Tv(1592), Arg(2, 57)]),
+    ((1611, false, AND2), &[Arg(1, 16), Tv(1605)]),
+    ((1612, false, AND2), &[Tv(1604), Tv(1606)]),
+    ((1613, false, AND2), &[Tv(1605), Tv(1607)]),
+    ((1614, false, AND2), &[Tv(1606), Tv(1608)]),
+    ((1615, false, AND2), &[Tv(1607), Tv(1609)]),
+    ((1616, false, AND2), &[Tv(1608), Tv(1610)]),
+    ((1622, false, XNOR2), &[Arg(1, 18), Tv(1604)]),
+    ((1630, false, INV), &[Tv(1621)]),
+    ((1666, false, NAND2), &[Arg(0, 96), Tv(1665)]),
+    ((1670, false, NAND2), &[Arg(0, 98), Tv(1669)]),
+    ((1674, false, NAND2), &[Arg(0, 100), Tv(1673)]),
+    ((1678, false, NAND2), &[Arg(0, 102), Tv(1677)]),
+    ((1697, false, AND2), &[Arg(0, 96), Tv(1692)]),
+    ((1698, false, AND2), &[Tv(1691), Tv(1693)]),
+    ((1699, false, AND2), &[Tv(1692), Tv(1694)]),
+    ((1700, false, AND2), &[Tv(1693), Tv(1695)]),
+    ((1701, false, AND2), &[Tv(1694), Tv(1696)]),
+    ((1705, false, XOR2), &[Arg(0, 98), Tv(1691)]),
+    ((1712, false, XOR2), &[Arg(0, 97), Tv(1687)]),
+    ((1762, false, AND2), &[Tv(1758), Tv(1759)]),
+    ((1763, false, AND2), &[Tv(1760), Tv(1761)]),
+    ((1766, false, AND2), &[Tv(1765), Arg(2, 1)]),
+    ((1771, false, AND2), &[Tv(1770), Arg(2, 17)]),
+    ((1776, false, AND2), &[Tv(1775), Arg(2, 33)]),
+    ((1781, false, AND2), &[Tv(1780), Arg(2, 49)]),
+    ((1799, false, AND2), &[Arg(1, 24), Tv(1793)]),
+    ((1800, false, AND2), &[Tv(1792), Tv(1794)]),
+    ((1801, false, AND2), &[Tv(1793), Tv(1795)]),
+    ((1802, false, AND2), &[Tv(1794), Tv(1796)]),
+    ((1803, false, AND2), &[Tv(1795), Tv(1797)]),
+    ((1804, false, AND2), &[Tv(1796), Tv(1798)]),
+    ((1810, false, XNOR2), &[Arg(1, 26), Tv(1792)]),
+    ((1818, false, INV), &[Tv(1809)]),
+    ((1854, false, NAND2), &[Arg(0, 136), Tv(1853)]),
+    ((1858, false, NAND2), &[Arg(0, 138), Tv(1857)]),
+    ((1862, false, NAND2), &[Arg(0, 140), Tv(1861)]),
+    ((1866, false, NAND2), &[Arg(0, 142), Tv(1865)]),
+    ((1885, false, AND2), &[Arg(0, 136), Tv(1880)]),
+    ((1886, false, AND2), &[Tv(1879), Tv(1881)]),
+    ((1887, false, AND2), &[Tv(1880), Tv(1882)]),
+    ((1888, false, AND2), &[Tv(1881), Tv(1883)]),
+    ((1889, false, AND2), &[Tv(1882), Tv(1884)]),
+    ((1893, false, XOR2), &[Arg(0, 138), Tv(1879)]),
+    ((1900, false, XOR2), &[Arg(0, 137), Tv(1875)]),
+    ((1950, false, AND2), &[Tv(1946), Tv(1947)]),
+    ((1951, false, AND2), &[Tv(1948), Tv(1949)]),
+    ((1954, false, AND2), &[Arg(2, 4), Tv(1953)]),
+    ((1959, false, AND2), &[Arg(2, 10), Tv(1958)]),
+    ((1961, false, AND2), &[Arg(2, 12), Tv(1960)]),
+    ((1967, false, AND2), &[Arg(2, 20), Tv(1966)]),
+    ((1972, false, AND2), &[Arg(2, 26), Tv(1971)]),
+    ((1974, false, AND2), &[Arg(2, 28), Tv(1973)]),
+    ((1980, false, AND2), &[Arg(2, 36), Tv(1979)]),
+    ((1985, false, AND2), &[Arg(2, 42), Tv(1984)]),
+    ((1987, false, AND2), &[Arg(2, 44), Tv(1986)]),
+    ((1993, false, AND2), &[Arg(2, 52), Tv(1992)]),
+    ((1998, false, AND2), &[Arg(2, 58), Tv(1997)]),
+    ((2000, false, AND2), &[Arg(2, 60), Tv(1999)]),
+    ((2019, false, AND2), &[Arg(1, 32), Tv(2013)]),
+    ((2020, false, AND2), &[Tv(2012), Tv(2014)]),
+    ((2021, false, AND2), &[Tv(2013), Tv(2015)]),
+    ((2022, false, AND2), &[Tv(2014), Tv(2016)]),
+    ((2023, false, AND2), &[Tv(2015), Tv(2017)]),
+    ((2024, false, AND2), &[Tv(2016), Tv(2018)]),
+    ((2030, false, XNOR2), &[Arg(1, 34), Tv(2012)]),
+    ((2038, false, INV), &[Tv(2029)]),
+    ((2074, false, NAND2), &[Arg(0, 176), Tv(2073)]),
+    ((2078, false, NAND2), &[Arg(0, 178), Tv(2077)]),
+    ((2082, false, NAND2), &[Arg(0, 180), Tv(2081)]),
+    ((2086, false, NAND2), &[Arg(0, 182), Tv(2085)]),
+    ((2105, false, AND2), &[Arg(0, 176), Tv(2100)]),
+    ((2106, false, AND2), &[Tv(2099), Tv(2101)]),
+    ((2107, false, AND2), &[Tv(2100), Tv(2102)]),
+    ((2108, false, AND2), &[Tv(2101), Tv(2103)]),
+    ((2109, false, AND2), &[Tv(2102), Tv(2104)]),
+    ((2113, false, XOR2), &[Arg(0, 178), Tv(2099)]),
+    ((2120, false, XOR2), &[Arg(0, 177), Tv(2095)]),
+    ((2170, false, AND2), &[Tv(2166), Tv(2167)]),
+    ((2171, false, AND2), &[Tv(2168), Tv(2169)]),
+    ((2175, false, AND2), &[Tv(1135), Tv(1359)]),
+    ((2180, false, AND2), &[Tv(1151), Tv(1369)]),
+    ((2185, false, AND2), &[Tv(1167), Tv(1379)]),
+    ((2190, false, AND2), &[Tv(1183), Tv(1389)]),
+    ((2207, false, AND2), &[Arg(1, 40), Tv(2201)]),
+    ((2208, false, AND2), &[Tv(2200), Tv(2202)]),
+    ((2209, false, AND2), &[Tv(2201), Tv(2203)]),
+    ((2210, false, AND2), &[Tv(2202), Tv(2204)]),
+    ((2211, false, AND2), &[Tv(2203), Tv(2205)]),
+    ((2212, false, AND2), &[Tv(2204), Tv(2206)]),
+    ((2218, false, XNOR2), &[Arg(1, 42), Tv(2200)]),
+    ((2226, false, INV), &[Tv(2217)]),
+    ((2262, false, NAND2), &[Arg(0, 216), Tv(2261)]),
+    ((2266, false, NAND2), &[Arg(0, 218), Tv(2265)]),
+    ((2270, false, NAND2), &[Arg(0, 220), Tv(2269)]),
+    ((2274, false, NAND2), &[Arg(0, 222), Tv(2273)]),
+    ((2293, false, AND2), &[Arg(0, 216), Tv(2288)]),
+    ((2294, false, AND2), &[Tv(2287), Tv(2289)]),
+    ((2295, false, AND2), &[Tv(2288), Tv(2290)]),
+    ((2296, false, AND2), &[Tv(2289), Tv(2291)]),
+    ((2297, false, AND2), &[Tv(2290), Tv(2292)]),
+    ((2301, false, XOR2), &[Arg(0, 218), Tv(2287)]),
+    ((2308, false, XOR2), &[Arg(0, 217), Tv(2283)]),
+    ((2358, false, AND2), &[Tv(2354), Tv(2355)]),
+    ((2359, false, AND2), &[Tv(2356), Tv(2357)]),
+    ((2383, false, AND2), &[Arg(1, 48), Tv(2377)]),
+    ((2384, false, AND2), &[Tv(2376), Tv(2378)]),
+    ((2385, false, AND2), &[Tv(2377), Tv(2379)]),
+    ((2386, false, AND2), &[Tv(2378), Tv(2380)]),
+    ((2387, false, AND2), &[Tv(2379), Tv(2381)]),
+    ((2388, false, AND2), &[Tv(2380), Tv(2382)]),
+    ((2394, false, XNOR2), &[Arg(1, 50), Tv(2376)]),
+    ((2402, false, INV), &[Tv(2393)]),
+    ((2438, false, NAND2), &[Arg(0, 256), Tv(2437)]),
+    ((2442, false, NAND2), &[Arg(0, 258), Tv(2441)]),
+    ((2446, false, NAND2), &[Arg(0, 260), Tv(2445)]),
+    ((2450, false, NAND2), &[Arg(0, 262), Tv(2449)]),
+    ((2469, false, AND2), &[Arg(0, 256), Tv(2464)]),
+    ((2470, false, AND2), &[Tv(2463), Tv(2465)]),
+    ((2471, false, AND2), &[Tv(2464), Tv(2466)]),
+    ((2472, false, AND2), &[Tv(2465), Tv(2467)]),
+    ((2473, false, AND2), &[Tv(2466), Tv(2468)]),
+    ((2477, false, XOR2), &[Arg(0, 258), Tv(2463)]),
+    ((2484, false, XOR2), &[Arg(0, 257), Tv(2459)]),
+    ((2534, false, AND2), &[Tv(2530), Tv(2531)]),
+    ((2535, false, AND2), &[Tv(2532), Tv(2533)]),
+    ((2563, false, AND2), &[Arg(1, 56), Tv(2557)]),
+    ((2564, false, AND2), &[Tv(2556), Tv(2558)]),
+    ((2565, false, AND2), &[Tv(2557), Tv(2559)]),
+    ((2566, false, AND2), &[Tv(2558), Tv(2560)]),
+    ((2567, false, AND2), &[Tv(2559), Tv(2561)]),
+    ((2568, false, AND2), &[Tv(2560), Tv(2562)]),
+    ((2574, false, XNOR2), &[Arg(1, 58), Tv(2556)]),
+    ((2582, false, INV), &[Tv(2573)]),
+    ((2618, false, NAND2), &[Arg(0, 296), Tv(2617)]),
+    ((2622, false, NAND2), &[Arg(0, 298), Tv(2621)]),
+    ((2626, false, NAND2), &[Arg(0, 300), Tv(2625)]),
+    ((2630, false, NAND2), &[Arg(0, 302), Tv(2629)]),
+    ((2649, false, AND2), &[Arg(0, 296), Tv(2644)]),
+    ((2650, false, AND2), &[Tv(2643), Tv(2645)]),
+    ((2651, false, AND2), &[Tv(2644), Tv(2646)]),
+    ((2652, false, AND2), &[Tv(2645), Tv(2647)]),
+    ((2653, false, AND2), &[Tv(2646), Tv(2648)]),
+    ((2657, false, XOR2), &[Arg(0, 298), Tv(2643)]),
+    ((2664, false, XOR2), &[Arg(0, 297), Tv(2639)]),
+    ((2714, false, AND2), &[Tv(2710), Tv(2711)]),
+    ((2715, false, AND2), &[Tv(2712), Tv(2713)]),
+    ((2720, false, AND2), &[Tv(2719), Arg(2, 11)]),
+    ((2727, false, AND2), &[Tv(2726), Arg(2, 27)]),
+    ((2734, false, AND2), &[Tv(2733), Arg(2, 43)]),
+    ((2741, false, AND2), &[Tv(2740), Arg(2, 59)]),
+    ((2759, false, AND2), &[Arg(1, 64), Tv(2753)]),
+    ((2760, false, AND2), &[Tv(2752), Tv(2754)]),
+    ((2761, false, AND2), &[Tv(2753), Tv(2755)]),
+    ((2762, false, AND2), &[Tv(2754), Tv(2756)]),
+    ((2763, false, AND2), &[Tv(2755), Tv(2757)]),
+    ((2764, false, AND2), &[Tv(2756), Tv(2758)]),
+    ((2770, false, XNOR2), &[Arg(1, 66), Tv(2752)]),
+    ((2778, false, INV), &[Tv(2769)]),
+    ((2814, false, NAND2), &[Arg(0, 336), Tv(2813)]),
+    ((2818, false, NAND2), &[Arg(0, 338), Tv(2817)]),
+    ((2822, false, NAND2), &[Arg(0, 340), Tv(2821)]),
+    ((2826, false, NAND2), &[Arg(0, 342), Tv(2825)]),
+    ((2845, false, AND2), &[Arg(0, 336), Tv(2840)]),
+    ((2846, false, AND2), &[Tv(2839), Tv(2841)]),
+    ((2847, false, AND2), &[Tv(2840), Tv(2842)]),
+    ((2848, false, AND2), &[Tv(2841), Tv(2843)]),
+    ((2849, false, AND2), &[Tv(2842), Tv(2844)]),
+    ((2853, false, XOR2), &[Arg(0, 338), Tv(2839)]),
+    ((2860, false, XOR2), &[Arg(0, 337), Tv(2835)]),
+    ((2910, false, AND2), &[Tv(2906), Tv(2907)]),
+    ((2911, false, AND2), &[Tv(2908), Tv(2909)]),
+    ((2939, false, AND2), &[Arg(1, 72), Tv(2933)]),
+    ((2940, false, AND2), &[Tv(2932), Tv(2934)]),
+    ((2941, false, AND2), &[Tv(2933), Tv(2935)]),
+    ((2942, false, AND2), &[Tv(2934), Tv(2936)]),
+    ((2943, false, AND2), &[Tv(2935), Tv(2937)]),
+    ((2944, false, AND2), &[Tv(2936), Tv(2938)]),
+    ((2950, false, XNOR2), &[Arg(1, 74), Tv(2932)]),
+    ((2958, false, INV), &[Tv(2949)]),
+    ((2994, false, NAND2), &[Arg(0, 376), Tv(2993)]),
+    ((2998, false, NAND2), &[Arg(0, 378), Tv(2997)]),
+    ((3002, false, NAND2), &[Arg(0, 380), Tv(3001)]),
+    ((3006, false, NAND2), &[Arg(0, 382), Tv(3005)]),
+    ((3025, false, AND2), &[Arg(0, 376), Tv(3020)]),
+    ((3026, false, AND2), &[Tv(3019), Tv(3021)]),
+    ((3027, false, AND2), &[Tv(3020), Tv(3022)]),
+    ((3028, false, AND2), &[Tv(3021), Tv(3023)]),
+    ((3029, false, AND2), &[Tv(3022), Tv(3024)]),
+    ((3033, false, XOR2), &[Arg(0, 378), Tv(3019)]),
+    ((3040, false, XOR2), &[Arg(0, 377), Tv(3015)]),
+    ((3090, false, AND2), &[Tv(3086), Tv(3087)]),
+    ((3091, false, AND2), &[Tv(3088), Tv(3089)]),
+    ((3131, false, AND2), &[Arg(1, 80), Tv(3125)]),
+    ((3132, false, AND2), &[Tv(3124), Tv(3126)]),
+    ((3133, false, AND2), &[Tv(3125), Tv(3127)]),
+    ((3134, false, AND2), &[Tv(3126), Tv(3128)]),
+    ((3135, false, AND2), &[Tv(3127), Tv(3129)]),
+    ((3136, false, AND2), &[Tv(3128), Tv(3130)]),
+    ((3142, false, XNOR2), &[Arg(1, 82), Tv(3124)]),
+    ((3150, false, INV), &[Tv(3141)]),
+    ((3189, false, NAND2), &[Arg(0, 420), Tv(3188)]),
+    ((3193, false, NAND2), &[Arg(0, 422), Tv(3192)]),
+    ((3196, false, AND2), &[Arg(0, 416), Tv(3186)]),
+    ((3197, false, AND2), &[Tv(3185), Tv(3187)]),
+    ((3203, false, XOR2), &[Arg(0, 418), Tv(3185)]),
+    ((3212, false, AND2), &[Tv(3186), Tv(3209)]),
+    ((3213, false, AND2), &[Tv(3187), Tv(3210)]),
+    ((3214, false, AND2), &[Tv(3209), Tv(3211)]),
+    ((3222, false, XOR2), &[Arg(0, 417), Tv(3202)]),
+    ((3275, false, AND2), &[Tv(3271), Tv(3272)]),
+    ((3276, false, AND2), &[Tv(3273), Tv(3274)]),
+    ((3304, false, AND2), &[Arg(1, 88), Tv(3298)]),
+    ((3305, false, AND2), &[Tv(3297), Tv(3299)]),
+    ((3306, false, AND2), &[Tv(3298), Tv(3300)]),
+    ((3307, false, AND2), &[Tv(3299), Tv(3301)]),
+    ((3308, false, AND2), &[Tv(3300), Tv(3302)]),
+    ((3309, false, AND2), &[Tv(3301), Tv(3303)]),
+    ((3315, false, XNOR2), &[Arg(1, 90), Tv(3297)]),
+    ((3323, false, INV), &[Tv(3314)]),
+    ((3362, false, NAND2), &[Arg(0, 460), Tv(3361)]),
+    ((3366, false, NAND2), &[Arg(0, 462), Tv(3365)]),
+    ((3369, false, AND2), &[Arg(0, 456), Tv(3359)]),
+    ((3370, false, AND2), &[Tv(3358), Tv(3360)]),
+    ((3376, false, XOR2), &[Arg(0, 458), Tv(3358)]),
+    ((3385, false, AND2), &[Tv(3359), Tv(3382)]),
+    ((3386, false, AND2), &[Tv(3360), Tv(3383)]),
+    ((3387, false, AND2), &[Tv(3382), Tv(3384)]),
+    ((3395, false, XOR2), &[Arg(0, 457), Tv(3375)]),
+    ((3448, false, AND2), &[Tv(3444), Tv(3445)]),
+    ((3449, false, AND2), &[Tv(3446), Tv(3447)]),
+    ((3451, false, AND2), &[Tv(1354), Tv(3093)]),
+    ((3454, false, AND2), &[Tv(1364), Tv(3099)]),
+    ((3457, false, AND2), &[Tv(1374), Tv(3105)]),
+    ((3460, false, AND2), &[Tv(1384), Tv(3111)]),
+    ((3477, false, AND2), &[Arg(1, 96), Tv(3471)]),
+    ((3478, false, AND2), &[Tv(3470), Tv(3472)]),
+    ((3479, false, AND2), &[Tv(3471), Tv(3473)]),
+    ((3480, false, AND2), &[Tv(3472), Tv(3474)]),
+    ((3481, false, AND2), &[Tv(3473), Tv(3475)]),
+    ((3482, false, AND2), &[Tv(3474), Tv(3476)]),
+    ((3488, false, XNOR2), &[Arg(1, 98), Tv(3470)]),
+    ((3496, false, INV), &[Tv(3487)]),
+    ((3535, false, NAND2), &[Arg(0, 500), Tv(3534)]),
+    ((3539, false, NAND2), &[Arg(0, 502), Tv(3538)]),
+    ((3542, false, AND2), &[Arg(0, 496), Tv(3532)]),
+    ((3543, false, AND2), &[Tv(3531), Tv(3533)]),
+    ((3549, false, XOR2), &[Arg(0, 498), Tv(3531)]),
+    ((3558, false, AND2), &[Tv(3532), Tv(3555)]),
+    ((3559, false, AND2), &[Tv(3533), Tv(3556)]),
+    ((3560, false, AND2), &[Tv(3555), Tv(3557)]),
+    ((3568, false, XOR2), &[Arg(0, 497), Tv(3548)]),
+    ((3621, false, AND2), &[Tv(3617), Tv(3618)]),
+    ((3622, false, AND2), &[Tv(3619), Tv(3620)]),
+    ((3646, false, AND2), &[Arg(1, 104), Tv(3640)]),
+    ((3647, false, AND2), &[Tv(3639), Tv(3641)]),
+    ((3648, false, AND2), &[Tv(3640), Tv(3642)]),
+    ((3649, false, AND2), &[Tv(3641), Tv(3643)]),
+    ((3650, false, AND2), &[Tv(3642), Tv(3644)]),
+    ((3651, false, AND2), &[Tv(3643), Tv(3645)]),
+    ((3657, false, XNOR2), &[Arg(1, 106), Tv(3639)]),
+    ((3665, false, INV), &[Tv(3656)]),
+    ((3701, false, NAND2), &[Arg(0, 536), Tv(3700)]),
+    ((3706, false, AND2), &[Arg(0, 538), Tv(3705)]),
+    ((3708, false, NAND2), &[Arg(0, 540), Tv(3707)]),
+    ((3712, false, NAND2), &[Arg(0, 542), Tv(3711)]),
+    ((3731, false, AND2), &[Arg(0, 536), Tv(3704)]),
+    ((3732, false, AND2), &[Tv(3726), Tv(3727)]),
+    ((3733, false, AND2), &[Tv(3704), Tv(3728)]),
+    ((3734, false, AND2), &[Tv(3727), Tv(3729)]),
+    ((3735, false, AND2), &[Tv(3728), Tv(3730)]),
+    ((3739, false, XOR2), &[Arg(0, 538), Tv(3726)]),
+    ((3746, false, XOR2), &[Arg(0, 537), Tv(3722)]),
+    ((3797, false, AND2), &[Tv(3793), Tv(3794)]),
+    ((3798, false, AND2), &[Tv(3795), Tv(3796)]),
+    ((3822, false, AND2), &[Arg(1, 112), Tv(3816)]),
+    ((3823, false, AND2), &[Tv(3815), Tv(3817)]),
+    ((3824, false, AND2), &[Tv(3816), Tv(3818)]),
+    ((3825, false, AND2), &[Tv(3817), Tv(3819)]),
+    ((3826, false, AND2), &[Tv(3818), Tv(3820)]),
+    ((3827, false, AND2), &[Tv(3819), Tv(3821)]),
+    ((3833, false, XNOR2), &[Arg(1, 114), Tv(3815)]),
+    ((3841, false, INV), &[Tv(3832)]),
+    ((3877, false, NAND2), &[Arg(0, 576), Tv(3876)]),
+    ((3882, false, AND2), &[Arg(0, 578), Tv(3881)]),
+    ((3884, false, NAND2), &[Arg(0, 580), Tv(3883)]),
+    ((3888, false, NAND2), &[Arg(0, 582), Tv(3887)]),
+    ((3907, false, AND2), &[Arg(0, 576), Tv(3880)]),
+    ((3908, false, AND2), &[Tv(3902), Tv(3903)]),
+    ((3909, false, AND2), &[Tv(3880), Tv(3904)]),
+    ((3910, false, AND2), &[Tv(3903), Tv(3905)]),
+    ((3911, false, AND2), &[Tv(3904), Tv(3906)]),
+    ((3915, false, XOR2), &[Arg(0, 578), Tv(3902)]),
+    ((3922, false, XOR2), &[Arg(0, 577), Tv(3898)]),
+    ((3973, false, AND2), &[Tv(3969), Tv(3970)]),
+    ((3974, false, AND2), &[Tv(3971), Tv(3972)]),
+    ((3994, false, AND2), &[Arg(1, 120), Tv(3988)]),
+    ((3995, false, AND2), &[Tv(3987), Tv(3989)]),
+    ((3996, false, AND2), &[Tv(3988), Tv(3990)]),
+    ((3997, false, AND2), &[Tv(3989), Tv(3991)]),
+    ((3998, false, AND2), &[Tv(3990), Tv(3992)]),
+    ((3999, false, AND2), &[Tv(3991), Tv(3993)]),
+    ((4005, false, XNOR2), &[Arg(1, 122), Tv(3987)]),
+    ((4013, false, INV), &[Tv(4004)]),
+    ((4049, false, NAND2), &[Arg(0, 616), Tv(4048)]),
+    ((4054, false, AND2), &[Arg(0, 618), Tv(4053)]),
+    ((4056, false, NAND2), &[Arg(0, 620), Tv(4055)]),
+    ((4060, false, NAND2), &[Arg(0, 622), Tv(4059)]),
+    ((4079, false, AND2), &[Arg(0, 616), Tv(4052)]),
+    ((4080, false, AND2), &[Tv(4074), Tv(4075)]),
+    ((4081, false, AND2), &[Tv(4052), Tv(4076)]),
+    ((4082, false, AND2), &[Tv(4075), Tv(4077)]),
+    ((4083, false, AND2), &[Tv(4076), Tv(4078)]),
+    ((4087, false, XOR2), &[Arg(0, 618), Tv(4074)]),
+    ((4094, false, XOR2), &[Arg(0, 617), Tv(4070)]),
+    ((4145, false, AND2), &[Tv(4141), Tv(4142)]),
+    ((4146, false, AND2), &[Tv(4143), Tv(4144)]),
+    ((4149, false, AND2), &[Arg(2, 2), Tv(4148)]),
+    ((4155, false, AND2), &[Arg(2, 18), Tv(4154)]),
+    ((4161, false, AND2), &[Arg(2, 34), Tv(4160)]),
+    ((4167, false, AND2), &[Arg(2, 50), Tv(4166)]),
+    ((4186, false, AND2), &[Arg(1, 128), Tv(4180)]),
+    ((4187, false, AND2), &[Tv(4179), Tv(4181)]),
+    ((4188, false, AND2), &[Tv(4180), Tv(4182)]),
+    ((4189, false, AND2), &[Tv(4181), Tv(4183)]),
+    ((4190, false, AND2), &[Tv(4182), Tv(4184)]),
+    ((4191, false, AND2), &[Tv(4183), Tv(4185)]),
+    ((4197, false, XNOR2), &[Arg(1, 130), Tv(4179)]),
+    ((4205, false, INV), &[Tv(4196)]),
+    ((4241, false, NAND2), &[Arg(0, 656), Tv(4240)]),
+    ((4246, false, AND2), &[Arg(0, 658), Tv(4245)]),
+    ((4248, false, NAND2), &[Arg(0, 660), Tv(4247)]),
+    ((4252, false, NAND2), &[Arg(0, 662), Tv(4251)]),
+    ((4271, false, AND2), &[Arg(0, 656), Tv(4244)]),
+    ((4272, false, AND2), &[Tv(4266), Tv(4267)]),
+    ((4273, false, AND2), &[Tv(4244), Tv(4268)]),
+    ((4274, false, AND2), &[Tv(4267), Tv(4269)]),
+    ((4275, false, AND2), &[Tv(4268), Tv(4270)]),
+    ((4279, false, XOR2), &[Arg(0, 658), Tv(4266)]),
+    ((4286, false, XOR2), &[Arg(0, 657), Tv(4262)]),
+    ((4337, false, AND2), &[Tv(4333), Tv(4334)]),
+    ((4338, false, AND2), &[Tv(4335), Tv(4336)]),
+    ((4362, false, AND2), &[Arg(1, 136), Tv(4356)]),
+    ((4363, false, AND2), &[Tv(4355), Tv(4357)]),
+    ((4364, false, AND2), &[Tv(4356), Tv(4358)]),
+    ((4365, false, AND2), &[Tv(4357), Tv(4359)]),
+    ((4366, false, AND2), &[Tv(4358), Tv(4360)]),
+    ((4367, false, AND2), &[Tv(4359), Tv(4361)]),
+    ((4373, false, XNOR2), &[Arg(1, 138), Tv(4355)]),
+    ((4381, false, INV), &[Tv(4372)]),
+    ((4417, false, NAND2), &[Arg(0, 698), Tv(4416)]),
+    ((4421, false, NAND2), &[Arg(0, 700), Tv(4420)]),
+    ((4425, false, NAND2), &[Arg(0, 702), Tv(4424)]),
+    ((4428, false, NAND2), &[Arg(0, 697), Tv(4334)]),
+    ((4443, false, AND2), &[Arg(0, 696), Tv(4438)]),
+    ((4444, false, AND2), &[Tv(4437), Tv(4439)]),
+    ((4445, false, AND2), &[Tv(4438), Tv(4440)]),
+    ((4446, false, AND2), &[Tv(4439), Tv(4441)]),
+    ((4447, false, AND2), &[Tv(4440), Tv(4442)]),
+    ((4452, false, XOR2), &[Arg(0, 698), Tv(4437)]),
+    ((4459, false, XOR2), &[Arg(0, 697), Tv(4451)]),
+    ((4509, false, AND2), &[Tv(4505), Tv(4506)]),
+    ((4510, false, AND2), &[Tv(4507), Tv(4508)]),
+    ((4530, false, AND2), &[Arg(1, 144), Tv(4524)]),
+    ((4531, false, AND2), &[Tv(4523), Tv(4525)]),
+    ((4532, false, AND2), &[Tv(4524), Tv(4526)]),
+    ((4533, false, AND2), &[Tv(4525), Tv(4527)]),
+    ((4534, false, AND2), &[Tv(4526), Tv(4528)]),
+    ((4535, false, AND2), &[Tv(4527), Tv(4529)]),
+    ((4541, false, XNOR2), &[Arg(1, 146), Tv(4523)]),
+    ((4549, false, INV), &[Tv(4540)]),
+    ((4585, false, NAND2), &[Arg(0, 738), Tv(4584)]),
+    ((4589, false, NAND2), &[Arg(0, 740), Tv(4588)]),
+    ((4593, false, NAND2), &[Arg(0, 742), Tv(4592)]),
+    ((4596, false, NAND2), &[Arg(0, 737), Tv(4506)]),
+    ((4611, false, AND2), &[Arg(0, 736), Tv(4606)]),
+    ((4612, false, AND2), &[Tv(4605), Tv(4607)]),
+    ((4613, false, AND2), &[Tv(4606), Tv(4608)]),
+    ((4614, false, AND2), &[Tv(4607), Tv(4609)]),
+    ((4615, false, AND2), &[Tv(4608), Tv(4610)]),
+    ((4620, false, XOR2), &[Arg(0, 738), Tv(4605)]),
+    ((4627, false, XOR2), &[Arg(0, 737), Tv(4619)]),
+    ((4677, false, AND2), &[Tv(4673), Tv(4674)]),
+    ((4678, false, AND2), &[Tv(4675), Tv(4676)]),
+    ((4680, false, AND2), &[Tv(1136), Tv(1957)]),
+    ((4683, false, AND2), &[Tv(1152), Tv(1970)]),
+    ((4686, false, AND2), &[Tv(1168), Tv(1983)]),
+    ((4689, false, AND2), &[Tv(1184), Tv(1996)]),
+    ((4706, false, AND2), &[Arg(1, 152), Tv(4700)]),
+    ((4707, false, AND2), &[Tv(4699), Tv(4701)]),
+    ((4708, false, AND2), &[Tv(4700), Tv(4702)]),
+    ((4709, false, AND2), &[Tv(4701), Tv(4703)]),
+    ((4710, false, AND2), &[Tv(4702), Tv(4704)]),
+    ((4711, false, AND2), &[Tv(4703), Tv(4705)]),
+    ((4717, false, XNOR2), &[Arg(1, 154), Tv(4699)]),
+    ((4725, false, INV), &[Tv(4716)]),
+    ((4761, false, NAND2), &[Arg(0, 778), Tv(4760)]),
+    ((4765, false, NAND2), &[Arg(0, 780), Tv(4764)]),
+    ((4769, false, NAND2), &[Arg(0, 782), Tv(4768)]),
+    ((4772, false, NAND2), &[Arg(0, 777), Tv(4674)]),
+    ((4787, false, AND2), &[Arg(0, 776), Tv(4782)]),
+    ((4788, false, AND2), &[Tv(4781), Tv(4783)]),
+    ((4789, false, AND2), &[Tv(4782), Tv(4784)]),
+    ((4790, false, AND2), &[Tv(4783), Tv(4785)]),
+    ((4791, false, AND2), &[Tv(4784), Tv(4786)]),
+    ((4796, false, XOR2), &[Arg(0, 778), Tv(4781)]),
+    ((4803, false, XOR2), &[Arg(0, 777), Tv(4795)]),
+    ((4853, false, AND2), &[Tv(4849), Tv(4850)]),
+    ((4854, false, AND2), &[Tv(4851), Tv(4852)]),
+    ((4882, false, AND2), &[Arg(1, 160), Tv(4876)]),
+    ((4883, false, AND2), &[Tv(4875), Tv(4877)]),
+    ((4884, false, AND2), &[Tv(4876), Tv(4878)]),
+    ((4885, false, AND2), &[Tv(4877), Tv(4879)]),
+    ((4886, false, AND2), &[Tv(4878), Tv(4880)]),
+    ((4887, false, AND2), &[Tv(4879), Tv(4881)]),
+    ((4893, false, XNOR2), &[Arg(1, 162), Tv(4875)]),
+    ((4901, false, INV), &[Tv(4892)]),
+    ((4937, false, NAND2), &[Arg(0, 818), Tv(4936)]),
+    ((4941, false, NAND2), &[Arg(0, 820), Tv(4940)]),
+    ((4945, false, NAND2), &[Arg(0, 822), Tv(4944)]),
+    ((4948, false, NAND2), &[Arg(0, 817), Tv(4850)]),
+    ((4963, false, AND2), &[Arg(0, 816), Tv(4958)]),
+    ((4964, false, AND2), &[Tv(4957), Tv(4959)]),
+    ((4965, false, AND2), &[Tv(4958), Tv(4960)]),
+    ((4966, false, AND2), &[Tv(4959), Tv(4961)]),
+    ((4967, false, AND2), &[Tv(4960), Tv(4962)]),
+    ((4972, false, XOR2), &[Arg(0, 818), Tv(4957)]),
+    ((4979, false, XOR2), &[Arg(0, 817), Tv(4971)]),
+    ((5029, false, AND2), &[Tv(5025), Tv(5026)]),
+    ((5030, false, AND2), &[Tv(5027), Tv(5028)]),
+    ((5054, false, AND2), &[Arg(1, 168), Tv(5048)]),
+    ((5055, false, AND2), &[Tv(5047), Tv(5049)]),
+    ((5056, false, AND2), &[Tv(5048), Tv(5050)]),
+    ((5057, false, AND2), &[Tv(5049), Tv(5051)]),
+    ((5058, false, AND2), &[Tv(5050), Tv(5052)]),
+    ((5059, false, AND2), &[Tv(5051), Tv(5053)]),
+    ((5065, false, XNOR2), &[Arg(1, 170), Tv(5047)]),
+    ((5073, false, INV), &[Tv(5064)]),
+    ((5109, false, NAND2), &[Arg(0, 858), Tv(5108)]),
+    ((5113, false, NAND2), &[Arg(0, 860), Tv(5112)]),
+    ((5117, false, NAND2), &[Arg(0, 862), Tv(5116)]),
+    ((5120, false, NAND2), &[Arg(0, 857), Tv(5026)]),
+    ((5135, false, AND2), &[Arg(0, 856), Tv(5130)]),
+    ((5136, false, AND2), &[Tv(5129), Tv(5131)]),
+    ((5137, false, AND2), &[Tv(5130), Tv(5132)]),
+    ((5138, false, AND2), &[Tv(5131), Tv(5133)]),
+    ((5139, false, AND2), &[Tv(5132), Tv(5134)]),
+    ((5144, false, XOR2), &[Arg(0, 858), Tv(5129)]),
+    ((5151, false, XOR2), &[Arg(0, 857), Tv(5143)]),
+    ((5201, false, AND2), &[Tv(5197), Tv(5198)]),
+    ((5202, false, AND2), &[Tv(5199), Tv(5200)]),
+    ((5230, false, AND2), &[Arg(1, 176), Tv(5224)]),
+    ((5231, false, AND2), &[Tv(5223), Tv(5225)]),
+    ((5232, false, AND2), &[Tv(5224), Tv(5226)]),
+    ((5233, false, AND2), &[Tv(5225), Tv(5227)]),
+    ((5234, false, AND2), &[Tv(5226), Tv(5228)]),
+    ((5235, false, AND2), &[Tv(5227), Tv(5229)]),
+    ((5241, false, XNOR2), &[Arg(1, 178), Tv(5223)]),
+    ((5249, false, INV), &[Tv(5240)]),
+    ((5285, false, NAND2), &[Arg(0, 898), Tv(5284)]),
+    ((5289, false, NAND2), &[Arg(0, 900), Tv(5288)]),
+    ((5293, false, NAND2), &[Arg(0, 902), Tv(5292)]),
+    ((5296, false, NAND2), &[Arg(0, 897), Tv(5198)]),
+    ((5311, false, AND2), &[Arg(0, 896), Tv(5306)]),
+    ((5312, false, AND2), &[Tv(5305), Tv(5307)]),
+    ((5313, false, AND2), &[Tv(5306), Tv(5308)]),
+    ((5314, false, AND2), &[Tv(5307), Tv(5309)]),
+    ((5315, false, AND2), &[Tv(5308), Tv(5310)]),
+    ((5320, false, XOR2), &[Arg(0, 898), Tv(5305)]),
+    ((5327, false, XOR2), &[Arg(0, 897), Tv(5319)]),
+];
+
+static LEVEL_2: [((usize, bool, CellType), &[GateInput]); 492] = [
+    ((1126, false, AND2), &[Tv(1124), Tv(1125)]),
+    ((1132, false, AND2), &[Tv(1128), Tv(1129)]),
+    ((1141, false, AND2), &[Tv(1139), Tv(1140)]),
+    ((1148, false, AND2), &[Tv(1144), Tv(1145)]),
+    ((1157, false, AND2), &[Tv(1155), Tv(1156)]),
+    ((1164, false, AND2), &[Tv(1160), Tv(1161)]),
+    ((1173, false, AND2), &[Tv(1171), Tv(1172)]),
+    ((1180, false, AND2), &[Tv(1176), Tv(1177)]),
+    ((1189, false, AND2), &[Tv(1187), Tv(1188)]),
+    ((1211, false, AND2), &[Arg(1, 0), Tv(1207)]),
+    ((1212, false, AND2), &[Tv(1198), Tv(1208)]),
+    ((1213, false, AND2), &[Tv(1205), Tv(1209)]),
+    ((1214, false, NAND2), &[Tv(1206), Tv(1210)]),
+    ((1217, false, XNOR2), &[Arg(1, 3), Tv(1205)]),
+    ((1218, false, XNOR2), &[Arg(1, 4), Tv(1206)]),
+    ((1226, false, INV), &[Tv(1216)]),
+    ((1268, false, INV), &[Tv(1261)]),
+    ((1270, false, NAND2), &[Tv(1262), Tv(1267)]),
+    ((1273, false, AND2), &[Tv(1264), Tv(1267)]),
+    ((1287, false, AND2), &[Arg(0, 16), Tv(1284)]),
+    ((1288, false, AND2), &[Tv(1279), Tv(1285)]),
+    ((1289, false, AND2), &[Tv(1282), Tv(1286)]),
+    ((1292, false, XOR2), &[Arg(0, 19), Tv(1282)]),
+    ((1293, false, XOR2), &[Arg(0, 20), Tv(1283)]),
+    ((1301, false, XOR2), &[Arg(0, 18), Tv(1291)]),
+    ((1352, false, AND2), &[Tv(1350), Tv(1351)]),
+    ((1356, false, AND2), &[Tv(1133), Tv(1355)]),
+    ((1360, false, AND2), &[Tv(1358), Tv(1359)]),
+    ((1366, false, AND2), &[Tv(1149), Tv(1365)]),
+    ((1370, false, AND2), &[Tv(1368), Tv(1369)]),
+    ((1376, false, AND2), &[Tv(1165), Tv(1375)]),
+    ((1380, false, AND2), &[Tv(1378), Tv(1379)]),
+    ((1386, false, AND2), &[Tv(1181), Tv(1385)]),
+    ((1390, false, AND2), &[Tv(1388), Tv(1389)]),
+    ((1413, false, AND2), &[Arg(1, 8), Tv(1409)]),
+    ((1414, false, AND2), &[Tv(1400), Tv(1410)]),
+    ((1415, false, AND2), &[Tv(1407), Tv(1411)]),
+    ((1416, false, NAND2), &[Tv(1408), Tv(1412)]),
+    ((1419, false, XNOR2), &[Arg(1, 11), Tv(1407)]),
+    ((1420, false, XNOR2), &[Arg(1, 12), Tv(1408)]),
+    ((1428, false, INV), &[Tv(1418)]),
+    ((1463, false, INV), &[Tv(1462)]),
+    ((1467, false, INV), &[Tv(1466)]),
+    ((1471, false, INV), &[Tv(1470)]),
+    ((1475, false, INV), &[Tv(1474)]),
+    ((1498, false, AND2), &[Arg(0, 56), Tv(1495)]),
+    ((1499, false, AND2), &[Tv(1487), Tv(1496)]),
+    ((1500, false, AND2), &[Tv(1493), Tv(1497)]),
+    ((1502, false, XOR2), &[Arg(0, 59), Tv(1493)]),
+    ((1503, false, XOR2), &[Arg(0, 60), Tv(1494)]),
+    ((1511, false, XOR2), &[Arg(0, 58), Tv(1501)]),
+    ((1560, false, AND2), &[Tv(1558), Tv(1559)]),
+    ((1563, false, AND2), &[Tv(1353), Tv(1562)]),
+    ((1567, false, AND2), &[Tv(1359), Tv(1566)]),
+    ((1572, false, AND2), &[Tv(1363), Tv(1571)]),
+    ((1576, false, AND2), &[Tv(1369), Tv(1575)]),
+    ((1581, false, AND2), &[Tv(1373), Tv(1580)]),
+    ((1585, false, AND2), &[Tv(1379), Tv(1584)]),
+    ((1590, false, AND2), &[Tv(1383), Tv(1589)]),
+    ((1594, false, AND2), &[Tv(1389), Tv(1593)]),
+    ((1617, false, AND2), &[Arg(1, 16), Tv(1613)]),
+    ((1618, false, AND2), &[Tv(1604), Tv(1614)]),
+    ((1619, false, AND2), &[Tv(1611), Tv(1615)]),
+    ((1620, false, NAND2), &[Tv(1612), Tv(1616)]),
+    ((1623, false, XNOR2), &[Arg(1, 19), Tv(1611)]),
+    ((1624, false, XNOR2), &[Arg(1, 20), Tv(1612)]),
+    ((1632, false, INV), &[Tv(1622)]),
+    ((1667, false, INV), &[Tv(1666)]),
+    ((1671, false, INV), &[Tv(1670)]),
+    ((1675, false, INV), &[Tv(1674)]),
+    ((1679, false, INV), &[Tv(1678)]),
+    ((1702, false, AND2), &[Arg(0, 96), Tv(1699)]),
+    ((1703, false, AND2), &[Tv(1691), Tv(1700)]),
+    ((1704, false, AND2), &[Tv(1697), Tv(1701)]),
+    ((1706, false, XOR2), &[Arg(0, 99), Tv(1697)]),
+    ((1707, false, XOR2), &[Arg(0, 100), Tv(1698)]),
+    ((1715, false, XOR2), &[Arg(0, 98), Tv(1705)]),
+    ((1764, false, AND2), &[Tv(1762), Tv(1763)]),
+    ((1767, false, AND2), &[Tv(1129), Tv(1766)]),
+    ((1772, false, AND2), &[Tv(1145), Tv(1771)]),
+    ((1777, false, AND2), &[Tv(1161), Tv(1776)]),
+    ((1782, false, AND2), &[Tv(1177), Tv(1781)]),
+    ((1805, false, AND2), &[Arg(1, 24), Tv(1801)]),
+    ((1806, false, AND2), &[Tv(1792), Tv(1802)]),
+    ((1807, false, AND2), &[Tv(1799), Tv(1803)]),
+    ((1808, false, NAND2), &[Tv(1800), Tv(1804)]),
+    ((1811, false, XNOR2), &[Arg(1, 27), Tv(1799)]),
+    ((1812, false, XNOR2), &[Arg(1, 28), Tv(1800)]),
+    ((1820, false, INV), &[Tv(1810)]),
+    ((1855, false, INV), &[Tv(1854)]),
+    ((1859, false, INV), &[Tv(1858)]),
+    ((1863, false, INV), &[Tv(1862)]),
+    ((1867, false, INV), &[Tv(1866)]),
+    ((1890, false, AND2), &[Arg(0, 136), Tv(1887)]),
+    ((1891, false, AND2), &[Tv(1879), Tv(1888)]),
+    ((1892, false, AND2), &[Tv(1885), Tv(1889)]),
+    ((1894, false, XOR2), &[Arg(0, 139), Tv(1885)]),
+    ((1895, false, XOR2), &[Arg(0, 140), Tv(1886)]),
+    ((1903, false, XOR2), &[Arg(0, 138), Tv(1893)]),
+    ((1952, false, AND2), &[Tv(1950), Tv(1951)]),
+    ((1955, false, AND2), &[Tv(1131), Tv(1954)]),
+    ((1962, false, AND2), &[Tv(1957), Tv(1959)]),
+    ((1963, false, AND2), &[Tv(1138), Tv(1961)]),
+    ((1968, false, AND2), &[Tv(1147), Tv(1967)]),
+    ((1975, false, AND2), &[Tv(1970), Tv(1972)]),
+    ((1976, false, AND2), &[Tv(1154), Tv(1974)]),
+    ((1981, false, AND2), &[Tv(1163), Tv(1980)]),
+    ((1988, false, AND2), &[Tv(1983), Tv(1985)]),
+    ((1989, false, AND2), &[Tv(1170), Tv(1987)]),
+    ((1994, false, AND2), &[Tv(1179), Tv(1993)]),
+    ((2001, false, AND2), &[Tv(1996), Tv(1998)]),
+    ((2002, false, AND2), &[Tv(1186), Tv(2000)]),
+    ((2025, false, AND2), &[Arg(1, 32), Tv(2021)]),
+    ((2026, false, AND2), &[Tv(2012), Tv(2022)]),
+    ((2027, false, AND2), &[Tv(2019), Tv(2023)]),
+    ((2028, false, NAND2), &[Tv(2020), Tv(2024)]),
+    ((2031, false, XNOR2), &[Arg(1, 35), Tv(2019)]),
+    ((2032, false, XNOR2), &[Arg(1, 36), Tv(2020)]),
+    ((2040, false, INV), &[Tv(2030)]),
+    ((2075, false, INV), &[Tv(2074)]),
+    ((2079, false, INV), &[Tv(2078)]),
+    ((2083, false, INV), &[Tv(2082)]),
+    ((2087, false, INV), &[Tv(2086)]),
+    ((2110, false, AND2), &[Arg(0, 176), Tv(2107)]),
+    ((2111, false, AND2), &[Tv(2099), Tv(2108)]),
+    ((2112, false, AND2), &[Tv(2105), Tv(2109)]),
+    ((2114, false, XOR2), &[Arg(0, 179), Tv(2105)]),
+    ((2115, false, XOR2), &[Arg(0, 180), Tv(2106)]),
+    ((2123, false, XOR2), &[Arg(0, 178), Tv(2113)]),
+    ((2172, false, AND2), &[Tv(2170), Tv(2171)]),
+    ((2173, false, AND2), &[Tv(1128), Tv(1562)]),
+    ((2176, false, AND2), &[Tv(1140), Tv(2175)]),
+    ((2178, false, AND2), &[Tv(1144), Tv(1571)]),
+    ((2181, false, AND2), &[Tv(1156), Tv(2180)]),
+    ((2183, false, AND2), &[Tv(1160), Tv(1580)]),
+    ((2186, false, AND2), &[Tv(1172), Tv(2185)]),
+    ((2188, false, AND2), &[Tv(1176), Tv(1589)]),
+    ((2191, false, AND2), &[Tv(1188), Tv(2190)]),
+    ((2213, false, AND2), &[Arg(1, 40), Tv(2209)]),
+    ((2214, false, AND2), &[Tv(2200), Tv(2210)]),
+    ((2215, false, AND2), &[Tv(2207), Tv(2211)]),
+    ((2216, false, NAND2), &[Tv(2208), Tv(2212)]),
+    ((2219, false, XNOR2), &[Arg(1, 43), Tv(2207)]),
+    ((2220, false, XNOR2), &[Arg(1, 44), Tv(2208)]),
+    ((2228, false, INV), &[Tv(2218)]),
+    ((2263, false, INV), &[Tv(2262)]),
+    ((2267, false, INV), &[Tv(2266)]),
+    ((2271, false, INV), &[Tv(2270)]),
+    ((2275, false, INV), &[Tv(2274)]),
+    ((2298, false, AND2), &[Arg(0, 216), Tv(2295)]),
+    ((2299, false, AND2), &[Tv(2287), Tv(2296)]),
+    ((2300, false, AND2), &[Tv(2293), Tv(2297)]),
+    ((2302, false, XOR2), &[Arg(0, 219), Tv(2293)]),
+    ((2303, false, XOR2), &[Arg(0, 220), Tv(2294)]),
+    ((2311, false, XOR2), &[Arg(0, 218), Tv(2301)]),
+    ((2360, false, AND2), &[Tv(2358), Tv(2359)]),
+    ((2389, false, AND2), &[Arg(1, 48), Tv(2385)]),
+    ((2390, false, AND2), &[Tv(2376), Tv(2386)]),
+    ((2391, false, AND2), &[Tv(2383), Tv(2387)]),
+    ((2392, false, NAND2), &[Tv(2384), Tv(2388)]),
+    ((2395, false, XNOR2), &[Arg(1, 51), Tv(2383)]),
+    ((2396, false, XNOR2), &[Arg(1, 52), Tv(2384)]),
+    ((2404, false, INV), &[Tv(2394)]),
+    ((2439, false, INV), &[Tv(2438)]),
+    ((2443, false, INV), &[Tv(2442)]),
+    ((2447, false, INV), &[Tv(2446)]),
+    ((2451, false, INV), &[Tv(2450)]),
+    ((2474, false, AND2), &[Arg(0, 256), Tv(2471)]),
+    ((2475, false, AND2), &[Tv(2463), Tv(2472)]),
+    ((2476, false, AND2), &[Tv(2469), Tv(2473)]),
+    ((2478, false, XOR2), &[Arg(0, 259), Tv(2469)]),
+    ((2479, false, XOR2), &[Arg(0, 260), Tv(2470)]),
+    ((2487, false, XOR2), &[Arg(0, 258), Tv(2477)]),
+    ((2536, false, AND2), &[Tv(2534), Tv(2535)]),
+    ((2537, false, AND2), &[Tv(1566), Tv(1959)]),
+    ((2540, false, AND2), &[Tv(1575), Tv(1972)]),
+    ((2543, false, AND2), &[Tv(1584), Tv(1985)]),
+    ((2546, false, AND2), &[Tv(1593), Tv(1998)]),
+    ((2569, false, AND2), &[Arg(1, 56), Tv(2565)]),
+    ((2570, false, AND2), &[Tv(2556), Tv(2566)]),
+    ((2571, false, AND2), &[Tv(2563), Tv(2567)]),
+    ((2572, false, NAND2), &[Tv(2564), Tv(2568)]),
+    ((2575, false, XNOR2), &[Arg(1, 59), Tv(2563)]),
+    ((2576, false, XNOR2), &[Arg(1, 60), Tv(2564)]),
+    ((2584, false, INV), &[Tv(2574)]),
+    ((2619, false, INV), &[Tv(2618)]),
+    ((2623, false, INV), &[Tv(2622)]),
+    ((2627, false, INV), &[Tv(2626)]),
+    ((2631, false, INV), &[Tv(2630)]),
+    ((2654, false, AND2), &[Arg(0, 296), Tv(2651)]),
+    ((2655, false, AND2), &[Tv(2643), Tv(2652)]),
+    ((2656, false, AND2), &[Tv(2649), Tv(2653)]),
+    ((2658, false, XOR2), &[Arg(0, 299), Tv(2649)]),
+    ((2659, false, XOR2), &[Arg(0, 300), Tv(2650)]),
+    ((2667, false, XOR2), &[Arg(0, 298), Tv(2657)]),
+    ((2716, false, AND2), &[Tv(2714), Tv(2715)]),
+    ((2717, false, AND2), &[Tv(1128), Tv(1354)]),
+    ((2721, false, AND2), &[Tv(1957), Tv(2720)]),
+    ((2724, false, AND2), &[Tv(1144), Tv(1364)]),
+    ((2728, false, AND2), &[Tv(1970), Tv(2727)]),
+    ((2731, false, AND2), &[Tv(1160), Tv(1374)]),
+    ((2735, false, AND2), &[Tv(1983), Tv(2734)]),
+    ((2738, false, AND2), &[Tv(1176), Tv(1384)]),
+    ((2742, false, AND2), &[Tv(1996), Tv(2741)]),
+    ((2765, false, AND2), &[Arg(1, 64), Tv(2761)]),
+    ((2766, false, AND2), &[Tv(2752), Tv(2762)]),
+    ((2767, false, AND2), &[Tv(2759), Tv(2763)]),
+    ((2768, false, NAND2), &[Tv(2760), Tv(2764)]),
+    ((2771, false, XNOR2), &[Arg(1, 67), Tv(2759)]),
+    ((2772, false, XNOR2), &[Arg(1, 68), Tv(2760)]),
+    ((2780, false, INV), &[Tv(2770)]),
+    ((2815, false, INV), &[Tv(2814)]),
+    ((2819, false, INV), &[Tv(2818)]),
+    ((2823, false, INV), &[Tv(2822)]),
+    ((2827, false, INV), &[Tv(2826)]),
+    ((2850, false, AND2), &[Arg(0, 336), Tv(2847)]),
+    ((2851, false, AND2), &[Tv(2839), Tv(2848)]),
+    ((2852, false, AND2), &[Tv(2845), Tv(2849)]),
+    ((2854, false, XOR2), &[Arg(0, 339), Tv(2845)]),
+    ((2855, false, XOR2), &[Arg(0, 340), Tv(2846)]),
+    ((2863, false, XOR2), &[Arg(0, 338), Tv(2853)]),
+    ((2912, false, AND2), &[Tv(2910), Tv(2911)]),
+    ((2913, false, AND2), &[Tv(1354), Tv(1766)]),
+    ((2916, false, AND2), &[Tv(1364), Tv(1771)]),
+    ((2919, false, AND2), &[Tv(1374), Tv(1776)]),
+    ((2922, false, AND2), &[Tv(1384), Tv(1781)]),
+    ((2945, false, AND2), &[Arg(1, 72), Tv(2941)]),
+    ((2946, false, AND2), &[Tv(2932), Tv(2942)]),
+    ((2947, false, AND2), &[Tv(2939), Tv(2943)]),
+    ((2948, false, NAND2), &[Tv(2940), Tv(2944)]),
+    ((2951, false, XNOR2), &[Arg(1, 75), Tv(2939)]),
+    ((2952, false, XNOR2), &[Arg(1, 76), Tv(2940)]),
+    ((2960, false, INV), &[Tv(2950)]),
+    ((2995, false, INV), &[Tv(2994)]),
+    ((2999, false, INV), &[Tv(2998)]),
+    ((3003, false, INV), &[Tv(3002)]),
+    ((3007, false, INV), &[Tv(3006)]),
+    ((3030, false, AND2), &[Arg(0, 376), Tv(3027)]),
+    ((3031, false, AND2), &[Tv(3019), Tv(3028)]),
+    ((3032, false, AND2), &[Tv(3025), Tv(3029)]),
+    ((3034, false, XOR2), &[Arg(0, 379), Tv(3025)]),
+    ((3035, false, XOR2), &[Arg(0, 380), Tv(3026)]),
+    ((3043, false, XOR2), &[Arg(0, 378), Tv(3033)]),
+    ((3092, false, AND2), &[Tv(3090), Tv(3091)]),
+    ((3094, false, AND2), &[Tv(1562), Tv(3093)]),
+    ((3096, false, AND2), &[Tv(1135), Tv(1959)]),
+    ((3100, false, AND2), &[Tv(1571), Tv(3099)]),
+    ((3102, false, AND2), &[Tv(1151), Tv(1972)]),
+    ((3106, false, AND2), &[Tv(1580), Tv(3105)]),
+    ((3108, false, AND2), &[Tv(1167), Tv(1985)]),
+    ((3112, false, AND2), &[Tv(1589), Tv(3111)]),
+    ((3114, false, AND2), &[Tv(1183), Tv(1998)]),
+    ((3137, false, AND2), &[Arg(1, 80), Tv(3133)]),
+    ((3138, false, AND2), &[Tv(3124), Tv(3134)]),
+    ((3139, false, AND2), &[Tv(3131), Tv(3135)]),
+    ((3140, false, NAND2), &[Tv(3132), Tv(3136)]),
+    ((3143, false, XNOR2), &[Arg(1, 83), Tv(3131)]),
+    ((3144, false, XNOR2), &[Arg(1, 84), Tv(3132)]),
+    ((3152, false, INV), &[Tv(3142)]),
+    ((3190, false, INV), &[Tv(3189)]),
+    ((3194, false, INV), &[Tv(3193)]),
+    ((3200, false, NAND2), &[Tv(3091), Tv(3197)]),
+    ((3204, false, XOR2), &[Arg(0, 419), Tv(3196)]),
+    ((3205, false, XOR2), &[Arg(0, 420), Tv(3197)]),
+    ((3215, false, AND2), &[Arg(0, 416), Tv(3212)]),
+    ((3216, false, AND2), &[Tv(3185), Tv(3213)]),
+    ((3217, false, AND2), &[Tv(3196), Tv(3214)]),
+    ((3225, false, XOR2), &[Arg(0, 418), Tv(3203)]),
+    ((3277, false, AND2), &[Tv(3275), Tv(3276)]),
+    ((3278, false, AND2), &[Tv(1135), Tv(2720)]),
+    ((3281, false, AND2), &[Tv(1151), Tv(2727)]),
+    ((3284, false, AND2), &[Tv(1167), Tv(2734)]),
+    ((3287, false, AND2), &[Tv(1183), Tv(2741)]),
+    ((3310, false, AND2), &[Arg(1, 88), Tv(3306)]),
+    ((3311, false, AND2), &[Tv(3297), Tv(3307)]),
+    ((3312, false, AND2), &[Tv(3304), Tv(3308)]),
+    ((3313, false, NAND2), &[Tv(3305), Tv(3309)]),
+    ((3316, false, XNOR2), &[Arg(1, 91), Tv(3304)]),
+    ((3317, false, XNOR2), &[Arg(1, 92), Tv(3305)]),
+    ((3325, false, INV), &[Tv(3315)]),
+    ((3363, false, INV), &[Tv(3362)]),
+    ((3367, false, INV), &[Tv(3366)]),
+    ((3373, false, NAND2), &[Tv(3276), Tv(3370)]),
+    ((3377, false, XOR2), &[Arg(0, 459), Tv(3369)]),
+    ((3378, false, XOR2), &[Arg(0, 460), Tv(3370)]),
+    ((3388, false, AND2), &[Arg(0, 456), Tv(3385)]),
+    ((3389, false, AND2), &[Tv(3358), Tv(3386)]),
+    ((3390, false, AND2), &[Tv(3369), Tv(3387)]),
+    ((3398, false, XOR2), &[Arg(0, 458), Tv(3376)]),
+    ((3450, false, AND2), &[Tv(3448), Tv(3449)]),
+    ((3452, false, AND2), &[Tv(1133), Tv(3451)]),
+    ((3455, false, AND2), &[Tv(1149), Tv(3454)]),
+    ((3458, false, AND2), &[Tv(1165), Tv(3457)]),
+    ((3461, false, AND2), &[Tv(1181), Tv(3460)]),
+    ((3483, false, AND2), &[Arg(1, 96), Tv(3479)]),
+    ((3484, false, AND2), &[Tv(3470), Tv(3480)]),
+    ((3485, false, AND2), &[Tv(3477), Tv(3481)]),
+    ((3486, false, NAND2), &[Tv(3478), Tv(3482)]),
+    ((3489, false, XNOR2), &[Arg(1, 99), Tv(3477)]),
+    ((3490, false, XNOR2), &[Arg(1, 100), Tv(3478)]),
+    ((3498, false, INV), &[Tv(3488)]),
+    ((3536, false, INV), &[Tv(3535)]),
+    ((3540, false, INV), &[Tv(3539)]),
+    ((3546, false, NAND2), &[Tv(3449), Tv(3543)]),
+    ((3550, false, XOR2), &[Arg(0, 499), Tv(3542)]),
+    ((3551, false, XOR2), &[Arg(0, 500), Tv(3543)]),
+    ((3561, false, AND2), &[Arg(0, 496), Tv(3558)]),
+    ((3562, false, AND2), &[Tv(3531), Tv(3559)]),
+    ((3563, false, AND2), &[Tv(3542), Tv(3560)]),
+    ((3571, false, XOR2), &[Arg(0, 498), Tv(3549)]),
+    ((3623, false, AND2), &[Tv(3621), Tv(3622)]),
+    ((3652, false, AND2), &[Arg(1, 104), Tv(3648)]),
+    ((3653, false, AND2), &[Tv(3639), Tv(3649)]),
+    ((3654, false, AND2), &[Tv(3646), Tv(3650)]),
+    ((3655, false, NAND2), &[Tv(3647), Tv(3651)]),
+    ((3658, false, XNOR2), &[Arg(1, 107), Tv(3646)]),
+    ((3659, false, XNOR2), &[Arg(1, 108), Tv(3647)]),
+    ((3667, false, INV), &[Tv(3657)]),
+    ((3702, false, INV), &[Tv(3701)]),
+    ((3709, false, INV), &[Tv(3708)]),
+    ((3713, false, INV), &[Tv(3712)]),
+    ((3736, false, AND2), &[Arg(0, 536), Tv(3733)]),
+    ((3737, false, AND2), &[Tv(3726), Tv(3734)]),
+    ((3738, false, AND2), &[Tv(3731), Tv(3735)]),
+    ((3740, false, XOR2), &[Arg(0, 539), Tv(3731)]),
+    ((3741, false, XOR2), &[Arg(0, 540), Tv(3732)]),
+    ((3749, false, XOR2), &[Arg(0, 538), Tv(3739)]),
+    ((3799, false, AND2), &[Tv(3797), Tv(3798)]),
+    ((3828, false, AND2), &[Arg(1, 112), Tv(3824)]),
+    ((3829, false, AND2), &[Tv(3815), Tv(3825)]),
+    ((3830, false, AND2), &[Tv(3822), Tv(3826)]),
+    ((3831, false, NAND2), &[Tv(3823), Tv(3827)]),
+    ((3834, false, XNOR2), &[Arg(1, 115), Tv(3822)]),
+    ((3835, false, XNOR2), &[Arg(1, 116), Tv(3823)]),
+    ((3843, false, INV), &[Tv(3833)]),
+    ((3878, false, INV), &[Tv(3877)]),
+    ((3885, false, INV), &[Tv(3884)]),
+    ((3889, false, INV), &[Tv(3888)]),
+    ((3912, false, AND2), &[Arg(0, 576), Tv(3909)]),
+    ((3913, false, AND2), &[Tv(3902), Tv(3910)]),
+    ((3914, false, AND2), &[Tv(3907), Tv(3911)]),
+    ((3916, false, XOR2), &[Arg(0, 579), Tv(3907)]),
+    ((3917, false, XOR2), &[Arg(0, 580), Tv(3908)]),
+    ((3925, false, XOR2), &[Arg(0, 578), Tv(3915)]),
+    ((3975, false, AND2), &[Tv(3973), Tv(3974)]),
+    ((4000, false, AND2), &[Arg(1, 120), Tv(3996)]),
+    ((4001, false, AND2), &[Tv(3987), Tv(3997)]),
+    ((4002, false, AND2), &[Tv(3994), Tv(3998)]),
+    ((4003, false, NAND2), &[Tv(3995), Tv(3999)]),
+    ((4006, false, XNOR2), &[Arg(1, 123), Tv(3994)]),
+    ((4007, false, XNOR2), &[Arg(1, 124), Tv(3995)]),
+    ((4015, false, INV), &[Tv(4005)]),
+    ((4050, false, INV), &[Tv(4049)]),
+    ((4057, false, INV), &[Tv(4056)]),
+    ((4061, false, INV), &[Tv(4060)]),
+    ((4084, false, AND2), &[Arg(0, 616), Tv(4081)]),
+    ((4085, false, AND2), &[Tv(4074), Tv(4082)]),
+    ((4086, false, AND2), &[Tv(4079), Tv(4083)]),
+    ((4088, false, XOR2), &[Arg(0, 619), Tv(4079)]),
+    ((4089, false, XOR2), &[Arg(0, 620), Tv(4080)]),
+    ((4097, false, XOR2), &[Arg(0, 618), Tv(4087)]),
+    ((4147, false, AND2), &[Tv(4145), Tv(4146)]),
+    ((4150, false, AND2), &[Tv(1128), Tv(4149)]),
+    ((4156, false, AND2), &[Tv(1144), Tv(4155)]),
+    ((4162, false, AND2), &[Tv(1160), Tv(4161)]),
+    ((4168, false, AND2), &[Tv(1176), Tv(4167)]),
+    ((4192, false, AND2), &[Arg(1, 128), Tv(4188)]),
+    ((4193, false, AND2), &[Tv(4179), Tv(4189)]),
+    ((4194, false, AND2), &[Tv(4186), Tv(4190)]),
+    ((4195, false, NAND2), &[Tv(4187), Tv(4191)]),
+    ((4198, false, XNOR2), &[Arg(1, 131), Tv(4186)]),
+    ((4199, false, XNOR2), &[Arg(1, 132), Tv(4187)]),
+    ((4207, false, INV), &[Tv(4197)]),
+    ((4242, false, INV), &[Tv(4241)]),
+    ((4249, false, INV), &[Tv(4248)]),
+    ((4253, false, INV), &[Tv(4252)]),
+    ((4276, false, AND2), &[Arg(0, 656), Tv(4273)]),
+    ((4277, false, AND2), &[Tv(4266), Tv(4274)]),
+    ((4278, false, AND2), &[Tv(4271), Tv(4275)]),
+    ((4280, false, XOR2), &[Arg(0, 659), Tv(4271)]),
+    ((4281, false, XOR2), &[Arg(0, 660), Tv(4272)]),
+    ((4289, false, XOR2), &[Arg(0, 658), Tv(4279)]),
+    ((4339, false, AND2), &[Tv(4337), Tv(4338)]),
+    ((4368, false, AND2), &[Arg(1, 136), Tv(4364)]),
+    ((4369, false, AND2), &[Tv(4355), Tv(4365)]),
+    ((4370, false, AND2), &[Tv(4362), Tv(4366)]),
+    ((4371, false, NAND2), &[Tv(4363), Tv(4367)]),
+    ((4374, false, XNOR2), &[Arg(1, 139), Tv(4362)]),
+    ((4375, false, XNOR2), &[Arg(1, 140), Tv(4363)]),
+    ((4383, false, INV), &[Tv(4373)]),
+    ((4418, false, INV), &[Tv(4417)]),
+    ((4422, false, INV), &[Tv(4421)]),
+    ((4426, false, INV), &[Tv(4425)]),
+    ((4448, false, AND2), &[Arg(0, 696), Tv(4445)]),
+    ((4449, false, AND2), &[Tv(4437), Tv(4446)]),
+    ((4450, false, AND2), &[Tv(4443), Tv(4447)]),
+    ((4453, false, XOR2), &[Arg(0, 699), Tv(4443)]),
+    ((4454, false, XOR2), &[Arg(0, 700), Tv(4444)]),
+    ((4462, false, XOR2), &[Arg(0, 698), Tv(4452)]),
+    ((4511, false, AND2), &[Tv(4509), Tv(4510)]),
+    ((4536, false, AND2), &[Arg(1, 144), Tv(4532)]),
+    ((4537, false, AND2), &[Tv(4523), Tv(4533)]),
+    ((4538, false, AND2), &[Tv(4530), Tv(4534)]),
+    ((4539, false, NAND2), &[Tv(4531), Tv(4535)]),
+    ((4542, false, XNOR2), &[Arg(1, 147), Tv(4530)]),
+    ((4543, false, XNOR2), &[Arg(1, 148), Tv(4531)]),
+    ((4551, false, INV), &[Tv(4541)]),
+    ((4586, false, INV), &[Tv(4585)]),
+    ((4590, false, INV), &[Tv(4589)]),
+    ((4594, false, INV), &[Tv(4593)]),
+    ((4616, false, AND2), &[Arg(0, 736), Tv(4613)]),
+    ((4617, false, AND2), &[Tv(4605), Tv(4614)]),
+    ((4618, false, AND2), &[Tv(4611), Tv(4615)]),
+    ((4621, false, XOR2), &[Arg(0, 739), Tv(4611)]),
+    ((4622, false, XOR2), &[Arg(0, 740), Tv(4612)]),
+    ((4630, false, XOR2), &[Arg(0, 738), Tv(4620)]),
+    ((4679, false, AND2), &[Tv(4677), Tv(4678)]),
+    ((4712, false, AND2), &[Arg(1, 152), Tv(4708)]),
+    ((4713, false, AND2), &[Tv(4699), Tv(4709)]),
+    ((4714, false, AND2), &[Tv(4706), Tv(4710)]),
+    ((4715, false, NAND2), &[Tv(4707), Tv(4711)]),
+    ((4718, false, XNOR2), &[Arg(1, 155), Tv(4706)]),
+    ((4719, false, XNOR2), &[Arg(1, 156), Tv(4707)]),
+    ((4727, false, INV), &[Tv(4717)]),
+    ((4762, false, INV), &[Tv(4761)]),
+    ((4766, false, INV), &[Tv(4765)]),
+    ((4770, false, INV), &[Tv(4769)]),
+    ((4792, false, AND2), &[Arg(0, 776), Tv(4789)]),
+    ((4793, false, AND2), &[Tv(4781), Tv(4790)]),
+    ((4794, false, AND2), &[Tv(4787), Tv(4791)]),
+    ((4797, false, XOR2), &[Arg(0, 779), Tv(4787)]),
+    ((4798, false, XOR2), &[Arg(0, 780), Tv(4788)]),
+    ((4806, false, XOR2), &[Arg(0, 778), Tv(4796)]),
+    ((4855, false, AND2), &[Tv(4853), Tv(4854)]),
+    ((4856, false, AND2), &[Tv(1136), Tv(1358)]),
+    ((4859, false, AND2), &[Tv(1152), Tv(1368)]),
+    ((4862, false, AND2), &[Tv(1168), Tv(1378)]),
+    ((4865, false, AND2), &[Tv(1184), Tv(1388)]),
+    ((4888, false, AND2), &[Arg(1, 160), Tv(4884)]),
+    ((4889, false, AND2), &[Tv(4875), Tv(4885)]),
+    ((4890, false, AND2), &[Tv(4882), Tv(4886)]),
+    ((4891, false, NAND2), &[Tv(4883), Tv(4887)]),
+    ((4894, false, XNOR2), &[Arg(1, 163), Tv(4882)]),
+    ((4895, false, XNOR2), &[Arg(1, 164), Tv(4883)]),
+    ((4903, false, INV), &[Tv(4893)]),
+    ((4938, false, INV), &[Tv(4937)]),
+    ((4942, false, INV), &[Tv(4941)]),
+    ((4946, false, INV), &[Tv(4945)]),
+    ((4968, false, AND2), &[Arg(0, 816), Tv(4965)]),
+    ((4969, false, AND2), &[Tv(4957), Tv(4966)]),
+    ((4970, false, AND2), &[Tv(4963), Tv(4967)]),
+    ((4973, false, XOR2), &[Arg(0, 819), Tv(4963)]),
+    ((4974, false, XOR2), &[Arg(0, 820), Tv(4964)]),
+    ((4982, false, XOR2), &[Arg(0, 818), Tv(4972)]),
+    ((5031, false, AND2), &[Tv(5029), Tv(5030)]),
+    ((5060, false, AND2), &[Arg(1, 168), Tv(5056)]),
+    ((5061, false, AND2), &[Tv(5047), Tv(5057)]),
+    ((5062, false, AND2), &[Tv(5054), Tv(5058)]),
+    ((5063, false, NAND2), &[Tv(5055), Tv(5059)]),
+    ((5066, false, XNOR2), &[Arg(1, 171), Tv(5054)]),
+    ((5067, false, XNOR2), &[Arg(1, 172), Tv(5055)]),
+    ((5075, false, INV), &[Tv(5065)]),
+    ((5110, false, INV), &[Tv(5109)]),
+    ((5114, false, INV), &[Tv(5113)]),
+    ((5118, false, INV), &[Tv(5117)]),
+    ((5140, false, AND2), &[Arg(0, 856), Tv(5137)]),
+    ((5141, false, AND2), &[Tv(5129), Tv(5138)]),
+    ((5142, false, AND2), &[Tv(5135), Tv(5139)]),
+    ((5145, false, XOR2), &[Arg(0, 859), Tv(5135)]),
+    ((5146, false, XOR2), &[Arg(0, 860), Tv(5136)]),
+    ((5154, false, XOR2), &[Arg(0, 858), Tv(5144)]),
+    ((5203, false, AND2), &[Tv(5201), Tv(5202)]),
+    ((5204, false, AND2), &[Tv(1562), Tv(1766)]),
+    ((5207, false, AND2), &[Tv(1571), Tv(1771)]),
+    ((5210, false, AND2), &[Tv(1580), Tv(1776)]),
+    ((5213, false, AND2), &[Tv(1589), Tv(1781)]),
+    ((5236, false, AND2), &[Arg(1, 176), Tv(5232)]),
+    ((5237, false, AND2), &[Tv(5223), Tv(5233)]),
+    ((5238, false, AND2), &[Tv(5230), Tv(5234)]),
+    ((5239, false, NAND2), &[Tv(5231), Tv(5235)]),
+    ((5242, false, XNOR2), &[Arg(1, 179), Tv(5230)]),
+    ((5243, false, XNOR2), &[Arg(1, 180), Tv(5231)]),
+    ((5251, false, INV), &[Tv(5241)]),
+    ((5286, false, INV), &[Tv(5285)]),
+    ((5290, false, INV), &[Tv(5289)]),
+    ((5294, false, INV), &[Tv(5293)]),
+    ((5316, false, AND2), &[Arg(0, 896), Tv(5313)]),
+    ((5317, false, AND2), &[Tv(5305), Tv(5314)]),
+    ((5318, false, AND2), &[Tv(5311), Tv(5315)]),
+    ((5321, false, XOR2), &[Arg(0, 899), Tv(5311)]),
+    ((5322, false, XOR2), &[Arg(0, 900), Tv(5312)]),
+    ((5330, false, XOR2), &[Arg(0, 898), Tv(5320)]),
+];
+
+static LEVEL_3: [((usize, bool, CellType), &[GateInput]); 473] = [
+    ((1134, false, AND2), &[Tv(1132), Tv(1133)]),
+    ((1150, false, AND2), &[Tv(1148), Tv(1149)]),
+    ((1166, false, AND2), &[Tv(1164), Tv(1165)]),
+    ((1182, false, AND2), &[Tv(1180), Tv(1181)]),
+    ((1219, false, XNOR2), &[Arg(1, 5), Tv(1211)]),
+    ((1220, false, XNOR2), &[Arg(1, 6), Tv(1212)]),
+    ((1221, false, XNOR2), &[Arg(1, 7), Tv(1213)]),
+    ((1222, false, AND2), &[Arg(1, 0), Tv(1214)]),
+    ((1223, false, INV), &[Tv(1214)]),
+    ((1228, false, INV), &[Tv(1217)]),
+    ((1230, false, INV), &[Tv(1218)]),
+    ((1269, false, OR2), &[Arg(0, 19), Tv(1268)]),
+    ((1271, false, INV), &[Tv(1270)]),
+    ((1276, false, INV), &[Tv(1126)]),
+    ((1294, false, XOR2), &[Arg(0, 21), Tv(1287)]),
+    ((1295, false, XOR2), &[Arg(0, 22), Tv(1288)]),
+    ((1296, false, XOR2), &[Arg(0, 23), Tv(1289)]),
+    ((1304, false, XOR2), &[Arg(0, 19), Tv(1292)]),
+    ((1307, false, XOR2), &[Arg(0, 20), Tv(1293)]),
+    ((1361, false, AND2), &[Tv(1140), Tv(1360)]),
+    ((1371, false, AND2), &[Tv(1156), Tv(1370)]),
+    ((1381, false, AND2), &[Tv(1172), Tv(1380)]),
+    ((1391, false, AND2), &[Tv(1188), Tv(1390)]),
+    ((1421, false, XNOR2), &[Arg(1, 13), Tv(1413)]),
+    ((1422, false, XNOR2), &[Arg(1, 14), Tv(1414)]),
+    ((1423, false, XNOR2), &[Arg(1, 15), Tv(1415)]),
+    ((1424, false, AND2), &[Arg(1, 8), Tv(1416)]),
+    ((1425, false, INV), &[Tv(1416)]),
+    ((1430, false, INV), &[Tv(1419)]),
+    ((1432, false, INV), &[Tv(1420)]),
+    ((1464, false, OR2), &[Arg(0, 57), Tv(1463)]),
+    ((1468, false, NOR2), &[Arg(0, 59), Tv(1467)]),
+    ((1472, false, OR2), &[Arg(0, 61), Tv(1471)]),
+    ((1476, false, NOR2), &[Arg(0, 63), Tv(1475)]),
+    ((1484, false, INV), &[Tv(1352)]),
+    ((1504, false, XOR2), &[Arg(0, 61), Tv(1498)]),
+    ((1505, false, XOR2), &[Arg(0, 62), Tv(1499)]),
+    ((1506, false, XOR2), &[Arg(0, 63), Tv(1500)]),
+    ((1514, false, XOR2), &[Arg(0, 59), Tv(1502)]),
+    ((1517, false, XOR2), &[Arg(0, 60), Tv(1503)]),
+    ((1564, false, AND2), &[Tv(1133), Tv(1563)]),
+    ((1568, false, AND2), &[Tv(1140), Tv(1567)]),
+    ((1573, false, AND2), &[Tv(1149), Tv(1572)]),
+    ((1577, false, AND2), &[Tv(1156), Tv(1576)]),
+    ((1582, false, AND2), &[Tv(1165), Tv(1581)]),
+    ((1586, false, AND2), &[Tv(1172), Tv(1585)]),
+    ((1591, false, AND2), &[Tv(1181), Tv(1590)]),
+    ((1595, false, AND2), &[Tv(1188), Tv(1594)]),
+    ((1625, false, XNOR2), &[Arg(1, 21), Tv(1617)]),
+    ((1626, false, XNOR2), &[Arg(1, 22), Tv(1618)]),
+    ((1627, false, XNOR2), &[Arg(1, 23), Tv(1619)]),
+    ((1628, false, AND2), &[Arg(1, 16), Tv(1620)]),
+    ((1629, false, INV), &[Tv(1620)]),
+    ((1634, false, INV), &[Tv(1623)]),
+    ((1636, false, INV), &[Tv(1624)]),
+    ((1668, false, OR2), &[Arg(0, 97), Tv(1667)]),
+    ((1672, false, NOR2), &[Arg(0, 99), Tv(1671)]),
+    ((1676, false, OR2), &[Arg(0, 101), Tv(1675)]),
+    ((1680, false, NOR2), &[Arg(0, 103), Tv(1679)]),
+    ((1688, false, INV), &[Tv(1560)]),
+    ((1708, false, XOR2), &[Arg(0, 101), Tv(1702)]),
+    ((1709, false, XOR2), &[Arg(0, 102), Tv(1703)]),
+    ((1710, false, XOR2), &[Arg(0, 103), Tv(1704)]),
+    ((1718, false, XOR2), &[Arg(0, 99), Tv(1706)]),
+    ((1721, false, XOR2), &[Arg(0, 100), Tv(1707)]),
+    ((1768, false, AND2), &[Tv(1133), Tv(1767)]),
+    ((1773, false, AND2), &[Tv(1149), Tv(1772)]),
+    ((1778, false, AND2), &[Tv(1165), Tv(1777)]),
+    ((1783, false, AND2), &[Tv(1181), Tv(1782)]),
+    ((1813, false, XNOR2), &[Arg(1, 29), Tv(1805)]),
+    ((1814, false, XNOR2), &[Arg(1, 30), Tv(1806)]),
+    ((1815, false, XNOR2), &[Arg(1, 31), Tv(1807)]),
+    ((1816, false, AND2), &[Arg(1, 24), Tv(1808)]),
+    ((1817, false, INV), &[Tv(1808)]),
+    ((1822, false, INV), &[Tv(1811)]),
+    ((1824, false, INV), &[Tv(1812)]),
+    ((1856, false, OR2), &[Arg(0, 137), Tv(1855)]),
+    ((1860, false, NOR2), &[Arg(0, 139), Tv(1859)]),
+    ((1864, false, OR2), &[Arg(0, 141), Tv(1863)]),
+    ((1868, false, NOR2), &[Arg(0, 143), Tv(1867)]),
+    ((1876, false, INV), &[Tv(1764)]),
+    ((1896, false, XOR2), &[Arg(0, 141), Tv(1890)]),
+    ((1897, false, XOR2), &[Arg(0, 142), Tv(1891)]),
+    ((1898, false, XOR2), &[Arg(0, 143), Tv(1892)]),
+    ((1906, false, XOR2), &[Arg(0, 139), Tv(1894)]),
+    ((1909, false, XOR2), &[Arg(0, 140), Tv(1895)]),
+    ((1956, false, AND2), &[Tv(1132), Tv(1955)]),
+    ((1964, false, AND2), &[Tv(1962), Tv(1963)]),
+    ((1969, false, AND2), &[Tv(1148), Tv(1968)]),
+    ((1977, false, AND2), &[Tv(1975), Tv(1976)]),
+    ((1982, false, AND2), &[Tv(1164), Tv(1981)]),
+    ((1990, false, AND2), &[Tv(1988), Tv(1989)]),
+    ((1995, false, AND2), &[Tv(1180), Tv(1994)]),
+    ((2003, false, AND2), &[Tv(2001), Tv(2002)]),
+    ((2033, false, XNOR2), &[Arg(1, 37), Tv(2025)]),
+    ((2034, false, XNOR2), &[Arg(1, 38), Tv(2026)]),
+    ((2035, false, XNOR2), &[Arg(1, 39), Tv(2027)]),
+    ((2036, false, AND2), &[Arg(1, 32), Tv(2028)]),
+    ((2037, false, INV), &[Tv(2028)]),
+    ((2042, false, INV), &[Tv(2031)]),
+    ((2044, false, INV), &[Tv(2032)]),
+    ((2076, false, OR2), &[Arg(0, 177), Tv(2075)]),
+    ((2080, false, NOR2), &[Arg(0, 179), Tv(2079)]),
+    ((2084, false, OR2), &[Arg(0, 181), Tv(2083)]),
+    ((2088, false, NOR2), &[Arg(0, 183), Tv(2087)]),
+    ((2096, false, INV), &[Tv(1952)]),
+    ((2116, false, XOR2), &[Arg(0, 181), Tv(2110)]),
+    ((2117, false, XOR2), &[Arg(0, 182), Tv(2111)]),
+    ((2118, false, XOR2), &[Arg(0, 183), Tv(2112)]),
+    ((2126, false, XOR2), &[Arg(0, 179), Tv(2114)]),
+    ((2129, false, XOR2), &[Arg(0, 180), Tv(2115)]),
+    ((2174, false, AND2), &[Tv(1955), Tv(2173)]),
+    ((2179, false, AND2), &[Tv(1968), Tv(2178)]),
+    ((2184, false, AND2), &[Tv(1981), Tv(2183)]),
+    ((2189, false, AND2), &[Tv(1994), Tv(2188)]),
+    ((2221, false, XNOR2), &[Arg(1, 45), Tv(2213)]),
+    ((2222, false, XNOR2), &[Arg(1, 46), Tv(2214)]),
+    ((2223, false, XNOR2), &[Arg(1, 47), Tv(2215)]),
+    ((2224, false, AND2), &[Arg(1, 40), Tv(2216)]),
+    ((2225, false, INV), &[Tv(2216)]),
+    ((2230, false, INV), &[Tv(2219)]),
+    ((2232, false, INV), &[Tv(2220)]),
+    ((2264, false, OR2), &[Arg(0, 217), Tv(2263)]),
+    ((2268, false, NOR2), &[Arg(0, 219), Tv(2267)]),
+    ((2272, false, OR2), &[Arg(0, 221), Tv(2271)]),
+    ((2276, false, NOR2), &[Arg(0, 223), Tv(2275)]),
+    ((2284, false, INV), &[Tv(2172)]),
+    ((2304, false, XOR2), &[Arg(0, 221), Tv(2298)]),
+    ((2305, false, XOR2), &[Arg(0, 222), Tv(2299)]),
+    ((2306, false, XOR2), &[Arg(0, 223), Tv(2300)]),
+    ((2314, false, XOR2), &[Arg(0, 219), Tv(2302)]),
+    ((2317, false, XOR2), &[Arg(0, 220), Tv(2303)]),
+    ((2361, false, AND2), &[Tv(1563), Tv(1955)]),
+    ((2363, false, AND2), &[Tv(1572), Tv(1968)]),
+    ((2365, false, AND2), &[Tv(1581), Tv(1981)]),
+    ((2367, false, AND2), &[Tv(1590), Tv(1994)]),
+    ((2397, false, XNOR2), &[Arg(1, 53), Tv(2389)]),
+    ((2398, false, XNOR2), &[Arg(1, 54), Tv(2390)]),
+    ((2399, false, XNOR2), &[Arg(1, 55), Tv(2391)]),
+    ((2400, false, AND2), &[Arg(1, 48), Tv(2392)]),
+    ((2401, false, INV), &[Tv(2392)]),
+    ((2406, false, INV), &[Tv(2395)]),
+    ((2408, false, INV), &[Tv(2396)]),
+    ((2440, false, OR2), &[Arg(0, 257), Tv(2439)]),
+    ((2444, false, NOR2), &[Arg(0, 259), Tv(2443)]),
+    ((2448, false, OR2), &[Arg(0, 261), Tv(2447)]),
+    ((2452, false, NOR2), &[Arg(0, 263), Tv(2451)]),
+    ((2460, false, INV), &[Tv(2360)]),
+    ((2480, false, XOR2), &[Arg(0, 261), Tv(2474)]),
+    ((2481, false, XOR2), &[Arg(0, 262), Tv(2475)]),
+    ((2482, false, XOR2), &[Arg(0, 263), Tv(2476)]),
+    ((2490, false, XOR2), &[Arg(0, 259), Tv(2478)]),
+    ((2493, false, XOR2), &[Arg(0, 260), Tv(2479)]),
+    ((2538, false, AND2), &[Tv(1963), Tv(2537)]),
+    ((2541, false, AND2), &[Tv(1976), Tv(2540)]),
+    ((2544, false, AND2), &[Tv(1989), Tv(2543)]),
+    ((2547, false, AND2), &[Tv(2002), Tv(2546)]),
+    ((2577, false, XNOR2), &[Arg(1, 61), Tv(2569)]),
+    ((2578, false, XNOR2), &[Arg(1, 62), Tv(2570)]),
+    ((2579, false, XNOR2), &[Arg(1, 63), Tv(2571)]),
+    ((2580, false, AND2), &[Arg(1, 56), Tv(2572)]),
+    ((2581, false, INV), &[Tv(2572)]),
+    ((2586, false, INV), &[Tv(2575)]),
+    ((2588, false, INV), &[Tv(2576)]),
+    ((2620, false, OR2), &[Arg(0, 297), Tv(2619)]),
+    ((2624, false, NOR2), &[Arg(0, 299), Tv(2623)]),
+    ((2628, false, OR2), &[Arg(0, 301), Tv(2627)]),
+    ((2632, false, NOR2), &[Arg(0, 303), Tv(2631)]),
+    ((2640, false, INV), &[Tv(2536)]),
+    ((2660, false, XOR2), &[Arg(0, 301), Tv(2654)]),
+    ((2661, false, XOR2), &[Arg(0, 302), Tv(2655)]),
+    ((2662, false, XOR2), &[Arg(0, 303), Tv(2656)]),
+    ((2670, false, XOR2), &[Arg(0, 299), Tv(2658)]),
+    ((2673, false, XOR2), &[Arg(0, 300), Tv(2659)]),
+    ((2718, false, AND2), &[Tv(1133), Tv(2717)]),
+    ((2722, false, AND2), &[Tv(1963), Tv(2721)]),
+    ((2725, false, AND2), &[Tv(1149), Tv(2724)]),
+    ((2729, false, AND2), &[Tv(1976), Tv(2728)]),
+    ((2732, false, AND2), &[Tv(1165), Tv(2731)]),
+    ((2736, false, AND2), &[Tv(1989), Tv(2735)]),
+    ((2739, false, AND2), &[Tv(1181), Tv(2738)]),
+    ((2743, false, AND2), &[Tv(2002), Tv(2742)]),
+    ((2773, false, XNOR2), &[Arg(1, 69), Tv(2765)]),
+    ((2774, false, XNOR2), &[Arg(1, 70), Tv(2766)]),
+    ((2775, false, XNOR2), &[Arg(1, 71), Tv(2767)]),
+    ((2776, false, AND2), &[Arg(1, 64), Tv(2768)]),
+    ((2777, false, INV), &[Tv(2768)]),
+    ((2782, false, INV), &[Tv(2771)]),
+    ((2784, false, INV), &[Tv(2772)]),
+    ((2816, false, OR2), &[Arg(0, 337), Tv(2815)]),
+    ((2820, false, NOR2), &[Arg(0, 339), Tv(2819)]),
+    ((2824, false, OR2), &[Arg(0, 341), Tv(2823)]),
+    ((2828, false, NOR2), &[Arg(0, 343), Tv(2827)]),
+    ((2836, false, INV), &[Tv(2716)]),
+    ((2856, false, XOR2), &[Arg(0, 341), Tv(2850)]),
+    ((2857, false, XOR2), &[Arg(0, 342), Tv(2851)]),
+    ((2858, false, XOR2), &[Arg(0, 343), Tv(2852)]),
+    ((2866, false, XOR2), &[Arg(0, 339), Tv(2854)]),
+    ((2869, false, XOR2), &[Arg(0, 340), Tv(2855)]),
+    ((2914, false, AND2), &[Tv(1133), Tv(2913)]),
+    ((2917, false, AND2), &[Tv(1149), Tv(2916)]),
+    ((2920, false, AND2), &[Tv(1165), Tv(2919)]),
+    ((2923, false, AND2), &[Tv(1181), Tv(2922)]),
+    ((2953, false, XNOR2), &[Arg(1, 77), Tv(2945)]),
+    ((2954, false, XNOR2), &[Arg(1, 78), Tv(2946)]),
+    ((2955, false, XNOR2), &[Arg(1, 79), Tv(2947)]),
+    ((2956, false, AND2), &[Arg(1, 72), Tv(2948)]),
+    ((2957, false, INV), &[Tv(2948)]),
+    ((2962, false, INV), &[Tv(2951)]),
+    ((2964, false, INV), &[Tv(2952)]),
+    ((2996, false, OR2), &[Arg(0, 377), Tv(2995)]),
+    ((3000, false, NOR2), &[Arg(0, 379), Tv(2999)]),
+    ((3004, false, OR2), &[Arg(0, 381), Tv(3003)]),
+    ((3008, false, NOR2), &[Arg(0, 383), Tv(3007)]),
+    ((3016, false, INV), &[Tv(2912)]),
+    ((3036, false, XOR2), &[Arg(0, 381), Tv(3030)]),
+    ((3037, false, XOR2), &[Arg(0, 382), Tv(3031)]),
+    ((3038, false, XOR2), &[Arg(0, 383), Tv(3032)]),
+    ((3046, false, XOR2), &[Arg(0, 379), Tv(3034)]),
+    ((3049, false, XOR2), &[Arg(0, 380), Tv(3035)]),
+    ((3095, false, AND2), &[Tv(1955), Tv(3094)]),
+    ((3097, false, AND2), &[Tv(1140), Tv(3096)]),
+    ((3101, false, AND2), &[Tv(1968), Tv(3100)]),
+    ((3103, false, AND2), &[Tv(1156), Tv(3102)]),
+    ((3107, false, AND2), &[Tv(1981), Tv(3106)]),
+    ((3109, false, AND2), &[Tv(1172), Tv(3108)]),
+    ((3113, false, AND2), &[Tv(1994), Tv(3112)]),
+    ((3115, false, AND2), &[Tv(1188), Tv(3114)]),
+    ((3145, false, XNOR2), &[Arg(1, 85), Tv(3137)]),
+    ((3146, false, XNOR2), &[Arg(1, 86), Tv(3138)]),
+    ((3147, false, XNOR2), &[Arg(1, 87), Tv(3139)]),
+    ((3148, false, AND2), &[Arg(1, 80), Tv(3140)]),
+    ((3149, false, INV), &[Tv(3140)]),
+    ((3154, false, INV), &[Tv(3143)]),
+    ((3156, false, INV), &[Tv(3144)]),
+    ((3191, false, OR2), &[Arg(0, 421), Tv(3190)]),
+    ((3195, false, NOR2), &[Arg(0, 423), Tv(3194)]),
+    ((3206, false, INV), &[Tv(3092)]),
+    ((3218, false, XOR2), &[Arg(0, 421), Tv(3215)]),
+    ((3219, false, XOR2), &[Arg(0, 422), Tv(3216)]),
+    ((3220, false, XOR2), &[Arg(0, 423), Tv(3217)]),
+    ((3228, false, XOR2), &[Arg(0, 419), Tv(3204)]),
+    ((3231, false, XOR2), &[Arg(0, 420), Tv(3205)]),
+    ((3279, false, AND2), &[Tv(1140), Tv(3278)]),
+    ((3282, false, AND2), &[Tv(1156), Tv(3281)]),
+    ((3285, false, AND2), &[Tv(1172), Tv(3284)]),
+    ((3288, false, AND2), &[Tv(1188), Tv(3287)]),
+    ((3318, false, XNOR2), &[Arg(1, 93), Tv(3310)]),
+    ((3319, false, XNOR2), &[Arg(1, 94), Tv(3311)]),
+    ((3320, false, XNOR2), &[Arg(1, 95), Tv(3312)]),
+    ((3321, false, AND2), &[Arg(1, 88), Tv(3313)]),
+    ((3322, false, INV), &[Tv(3313)]),
+    ((3327, false, INV), &[Tv(3316)]),
+    ((3329, false, INV), &[Tv(3317)]),
+    ((3364, false, OR2), &[Arg(0, 461), Tv(3363)]),
+    ((3368, false, NOR2), &[Arg(0, 463), Tv(3367)]),
+    ((3379, false, INV), &[Tv(3277)]),
+    ((3391, false, XOR2), &[Arg(0, 461), Tv(3388)]),
+    ((3392, false, XOR2), &[Arg(0, 462), Tv(3389)]),
+    ((3393, false, XOR2), &[Arg(0, 463), Tv(3390)]),
+    ((3401, false, XOR2), &[Arg(0, 459), Tv(3377)]),
+    ((3404, false, XOR2), &[Arg(0, 460), Tv(3378)]),
+    ((3491, false, XNOR2), &[Arg(1, 101), Tv(3483)]),
+    ((3492, false, XNOR2), &[Arg(1, 102), Tv(3484)]),
+    ((3493, false, XNOR2), &[Arg(1, 103), Tv(3485)]),
+    ((3494, false, AND2), &[Arg(1, 96), Tv(3486)]),
+    ((3495, false, INV), &[Tv(3486)]),
+    ((3500, false, INV), &[Tv(3489)]),
+    ((3502, false, INV), &[Tv(3490)]),
+    ((3537, false, OR2), &[Arg(0, 501), Tv(3536)]),
+    ((3541, false, NOR2), &[Arg(0, 503), Tv(3540)]),
+    ((3552, false, INV), &[Tv(3450)]),
+    ((3564, false, XOR2), &[Arg(0, 501), Tv(3561)]),
+    ((3565, false, XOR2), &[Arg(0, 502), Tv(3562)]),
+    ((3566, false, XOR2), &[Arg(0, 503), Tv(3563)]),
+    ((3574, false, XOR2), &[Arg(0, 499), Tv(3550)]),
+    ((3577, false, XOR2), &[Arg(0, 500), Tv(3551)]),
+    ((3624, false, AND2), &[Tv(1133), Tv(3094)]),
+    ((3626, false, AND2), &[Tv(1149), Tv(3100)]),
+    ((3628, false, AND2), &[Tv(1165), Tv(3106)]),
+    ((3630, false, AND2), &[Tv(1181), Tv(3112)]),
+    ((3660, false, XNOR2), &[Arg(1, 109), Tv(3652)]),
+    ((3661, false, XNOR2), &[Arg(1, 110), Tv(3653)]),
+    ((3662, false, XNOR2), &[Arg(1, 111), Tv(3654)]),
+    ((3663, false, AND2), &[Arg(1, 104), Tv(3655)]),
+    ((3664, false, INV), &[Tv(3655)]),
+    ((3669, false, INV), &[Tv(3658)]),
+    ((3671, false, INV), &[Tv(3659)]),
+    ((3703, false, OR2), &[Arg(0, 537), Tv(3702)]),
+    ((3710, false, OR2), &[Arg(0, 541), Tv(3709)]),
+    ((3714, false, NOR2), &[Arg(0, 543), Tv(3713)]),
+    ((3723, false, INV), &[Tv(3623)]),
+    ((3742, false, XOR2), &[Arg(0, 541), Tv(3736)]),
+    ((3743, false, XOR2), &[Arg(0, 542), Tv(3737)]),
+    ((3744, false, XOR2), &[Arg(0, 543), Tv(3738)]),
+    ((3752, false, XOR2), &[Arg(0, 539), Tv(3740)]),
+    ((3755, false, XOR2), &[Arg(0, 540), Tv(3741)]),
+    ((3800, false, AND2), &[Tv(1955), Tv(3451)]),
+    ((3802, false, AND2), &[Tv(1968), Tv(3454)]),
+    ((3804, false, AND2), &[Tv(1981), Tv(3457)]),
+    ((3806, false, AND2), &[Tv(1994), Tv(3460)]),
+    ((3836, false, XNOR2), &[Arg(1, 117), Tv(3828)]),
+    ((3837, false, XNOR2), &[Arg(1, 118), Tv(3829)]),
+    ((3838, false, XNOR2), &[Arg(1, 119), Tv(3830)]),
+    ((3839, false, AND2), &[Arg(1, 112), Tv(3831)]),
+    ((3840, false, INV), &[Tv(3831)]),
+    ((3845, false, INV), &[Tv(3834)]),
+    ((3847, false, INV), &[Tv(3835)]),
+    ((3879, false, OR2), &[Arg(0, 577), Tv(3878)]),
+    ((3886, false, OR2), &[Arg(0, 581), Tv(3885)]),
+    ((3890, false, NOR2), &[Arg(0, 583), Tv(3889)]),
+    ((3899, false, INV), &[Tv(3799)]),
+    ((3918, false, XOR2), &[Arg(0, 581), Tv(3912)]),
+    ((3919, false, XOR2), &[Arg(0, 582), Tv(3913)]),
+    ((3920, false, XOR2), &[Arg(0, 583), Tv(3914)]),
+    ((3928, false, XOR2), &[Arg(0, 579), Tv(3916)]),
+    ((3931, false, XOR2), &[Arg(0, 580), Tv(3917)]),
+    ((4008, false, XNOR2), &[Arg(1, 125), Tv(4000)]),
+    ((4009, false, XNOR2), &[Arg(1, 126), Tv(4001)]),
+    ((4010, false, XNOR2), &[Arg(1, 127), Tv(4002)]),
+    ((4011, false, AND2), &[Arg(1, 120), Tv(4003)]),
+    ((4012, false, INV), &[Tv(4003)]),
+    ((4017, false, INV), &[Tv(4006)]),
+    ((4019, false, INV), &[Tv(4007)]),
+    ((4051, false, OR2), &[Arg(0, 617), Tv(4050)]),
+    ((4058, false, OR2), &[Arg(0, 621), Tv(4057)]),
+    ((4062, false, NOR2), &[Arg(0, 623), Tv(4061)]),
+    ((4071, false, INV), &[Tv(3975)]),
+    ((4090, false, XOR2), &[Arg(0, 621), Tv(4084)]),
+    ((4091, false, XOR2), &[Arg(0, 622), Tv(4085)]),
+    ((4092, false, XOR2), &[Arg(0, 623), Tv(4086)]),
+    ((4100, false, XOR2), &[Arg(0, 619), Tv(4088)]),
+    ((4103, false, XOR2), &[Arg(0, 620), Tv(4089)]),
+    ((4151, false, AND2), &[Tv(1133), Tv(4150)]),
+    ((4152, false, AND2), &[Tv(1963), Tv(3096)]),
+    ((4157, false, AND2), &[Tv(1149), Tv(4156)]),
+    ((4158, false, AND2), &[Tv(1976), Tv(3102)]),
+    ((4163, false, AND2), &[Tv(1165), Tv(4162)]),
+    ((4164, false, AND2), &[Tv(1989), Tv(3108)]),
+    ((4169, false, AND2), &[Tv(1181), Tv(4168)]),
+    ((4170, false, AND2), &[Tv(2002), Tv(3114)]),
+    ((4200, false, XNOR2), &[Arg(1, 133), Tv(4192)]),
+    ((4201, false, XNOR2), &[Arg(1, 134), Tv(4193)]),
+    ((4202, false, XNOR2), &[Arg(1, 135), Tv(4194)]),
+    ((4203, false, AND2), &[Arg(1, 128), Tv(4195)]),
+    ((4204, false, INV), &[Tv(4195)]),
+    ((4209, false, INV), &[Tv(4198)]),
+    ((4211, false, INV), &[Tv(4199)]),
+    ((4243, false, OR2), &[Arg(0, 657), Tv(4242)]),
+    ((4250, false, OR2), &[Arg(0, 661), Tv(4249)]),
+    ((4254, false, NOR2), &[Arg(0, 663), Tv(4253)]),
+    ((4263, false, INV), &[Tv(4147)]),
+    ((4282, false, XOR2), &[Arg(0, 661), Tv(4276)]),
+    ((4283, false, XOR2), &[Arg(0, 662), Tv(4277)]),
+    ((4284, false, XOR2), &[Arg(0, 663), Tv(4278)]),
+    ((4292, false, XOR2), &[Arg(0, 659), Tv(4280)]),
+    ((4295, false, XOR2), &[Arg(0, 660), Tv(4281)]),
+    ((4340, false, AND2), &[Tv(1140), Tv(2721)]),
+    ((4342, false, AND2), &[Tv(1156), Tv(2728)]),
+    ((4344, false, AND2), &[Tv(1172), Tv(2735)]),
+    ((4346, false, AND2), &[Tv(1188), Tv(2742)]),
+    ((4376, false, XNOR2), &[Arg(1, 141), Tv(4368)]),
+    ((4377, false, XNOR2), &[Arg(1, 142), Tv(4369)]),
+    ((4378, false, XNOR2), &[Arg(1, 143), Tv(4370)]),
+    ((4379, false, AND2), &[Arg(1, 136), Tv(4371)]),
+    ((4380, false, INV), &[Tv(4371)]),
+    ((4385, false, INV), &[Tv(4374)]),
+    ((4387, false, INV), &[Tv(4375)]),
+    ((4419, false, NOR2), &[Arg(0, 699), Tv(4418)]),
+    ((4423, false, OR2), &[Arg(0, 701), Tv(4422)]),
+    ((4427, false, NOR2), &[Arg(0, 703), Tv(4426)]),
+    ((4434, false, INV), &[Tv(4339)]),
+    ((4455, false, XOR2), &[Arg(0, 701), Tv(4448)]),
+    ((4456, false, XOR2), &[Arg(0, 702), Tv(4449)]),
+    ((4457, false, XOR2), &[Arg(0, 703), Tv(4450)]),
+    ((4465, false, XOR2), &[Arg(0, 699), Tv(4453)]),
+    ((4468, false, XOR2), &[Arg(0, 700), Tv(4454)]),
+    ((4544, false, XNOR2), &[Arg(1, 149), Tv(4536)]),
+    ((4545, false, XNOR2), &[Arg(1, 150), Tv(4537)]),
+    ((4546, false, XNOR2), &[Arg(1, 151), Tv(4538)]),
+    ((4547, false, AND2), &[Arg(1, 144), Tv(4539)]),
+    ((4548, false, INV), &[Tv(4539)]),
+    ((4553, false, INV), &[Tv(4542)]),
+    ((4555, false, INV), &[Tv(4543)]),
+    ((4587, false, NOR2), &[Arg(0, 739), Tv(4586)]),
+    ((4591, false, OR2), &[Arg(0, 741), Tv(4590)]),
+    ((4595, false, NOR2), &[Arg(0, 743), Tv(4594)]),
+    ((4602, false, INV), &[Tv(4511)]),
+    ((4623, false, XOR2), &[Arg(0, 741), Tv(4616)]),
+    ((4624, false, XOR2), &[Arg(0, 742), Tv(4617)]),
+    ((4625, false, XOR2), &[Arg(0, 743), Tv(4618)]),
+    ((4633, false, XOR2), &[Arg(0, 739), Tv(4621)]),
+    ((4636, false, XOR2), &[Arg(0, 740), Tv(4622)]),
+    ((4681, false, AND2), &[Tv(1963), Tv(4680)]),
+    ((4684, false, AND2), &[Tv(1976), Tv(4683)]),
+    ((4687, false, AND2), &[Tv(1989), Tv(4686)]),
+    ((4690, false, AND2), &[Tv(2002), Tv(4689)]),
+    ((4720, false, XNOR2), &[Arg(1, 157), Tv(4712)]),
+    ((4721, false, XNOR2), &[Arg(1, 158), Tv(4713)]),
+    ((4722, false, XNOR2), &[Arg(1, 159), Tv(4714)]),
+    ((4723, false, AND2), &[Arg(1, 152), Tv(4715)]),
+    ((4724, false, INV), &[Tv(4715)]),
+    ((4729, false, INV), &[Tv(4718)]),
+    ((4731, false, INV), &[Tv(4719)]),
+    ((4763, false, NOR2), &[Arg(0, 779), Tv(4762)]),
+    ((4767, false, OR2), &[Arg(0, 781), Tv(4766)]),
+    ((4771, false, NOR2), &[Arg(0, 783), Tv(4770)]),
+    ((4778, false, INV), &[Tv(4679)]),
+    ((4799, false, XOR2), &[Arg(0, 781), Tv(4792)]),
+    ((4800, false, XOR2), &[Arg(0, 782), Tv(4793)]),
+    ((4801, false, XOR2), &[Arg(0, 783), Tv(4794)]),
+    ((4809, false, XOR2), &[Arg(0, 779), Tv(4797)]),
+    ((4812, false, XOR2), &[Arg(0, 780), Tv(4798)]),
+    ((4857, false, AND2), &[Tv(1963), Tv(4856)]),
+    ((4860, false, AND2), &[Tv(1976), Tv(4859)]),
+    ((4863, false, AND2), &[Tv(1989), Tv(4862)]),
+    ((4866, false, AND2), &[Tv(2002), Tv(4865)]),
+    ((4896, false, XNOR2), &[Arg(1, 165), Tv(4888)]),
+    ((4897, false, XNOR2), &[Arg(1, 166), Tv(4889)]),
+    ((4898, false, XNOR2), &[Arg(1, 167), Tv(4890)]),
+    ((4899, false, AND2), &[Arg(1, 160), Tv(4891)]),
+    ((4900, false, INV), &[Tv(4891)]),
+    ((4905, false, INV), &[Tv(4894)]),
+    ((4907, false, INV), &[Tv(4895)]),
+    ((4939, false, NOR2), &[Arg(0, 819), Tv(4938)]),
+    ((4943, false, OR2), &[Arg(0, 821), Tv(4942)]),
+    ((4947, false, NOR2), &[Arg(0, 823), Tv(4946)]),
+    ((4954, false, INV), &[Tv(4855)]),
+    ((4975, false, XOR2), &[Arg(0, 821), Tv(4968)]),
+    ((4976, false, XOR2), &[Arg(0, 822), Tv(4969)]),
+    ((4977, false, XOR2), &[Arg(0, 823), Tv(4970)]),
+    ((4985, false, XOR2), &[Arg(0, 819), Tv(4973)]),
+    ((4988, false, XOR2), &[Arg(0, 820), Tv(4974)]),
+    ((5032, false, AND2), &[Tv(1355), Tv(1955)]),
+    ((5034, false, AND2), &[Tv(1365), Tv(1968)]),
+    ((5036, false, AND2), &[Tv(1375), Tv(1981)]),
+    ((5038, false, AND2), &[Tv(1385), Tv(1994)]),
+    ((5068, false, XNOR2), &[Arg(1, 173), Tv(5060)]),
+    ((5069, false, XNOR2), &[Arg(1, 174), Tv(5061)]),
+    ((5070, false, XNOR2), &[Arg(1, 175), Tv(5062)]),
+    ((5071, false, AND2), &[Arg(1, 168), Tv(5063)]),
+    ((5072, false, INV), &[Tv(5063)]),
+    ((5077, false, INV), &[Tv(5066)]),
+    ((5079, false, INV), &[Tv(5067)]),
+    ((5111, false, NOR2), &[Arg(0, 859), Tv(5110)]),
+    ((5115, false, OR2), &[Arg(0, 861), Tv(5114)]),
+    ((5119, false, NOR2), &[Arg(0, 863), Tv(5118)]),
+    ((5126, false, INV), &[Tv(5031)]),
+    ((5147, false, XOR2), &[Arg(0, 861), Tv(5140)]),
+    ((5148, false, XOR2), &[Arg(0, 862), Tv(5141)]),
+    ((5149, false, XOR2), &[Arg(0, 863), Tv(5142)]),
+    ((5157, false, XOR2), &[Arg(0, 859), Tv(5145)]),
+    ((5160, false, XOR2), &[Arg(0, 860), Tv(5146)]),
+    ((5205, false, AND2), &[Tv(1955), Tv(5204)]),
+    ((5208, false, AND2), &[Tv(1968), Tv(5207)]),
+    ((5211, false, AND2), &[Tv(1981), Tv(5210)]),
+    ((5214, false, AND2), &[Tv(1994), Tv(5213)]),
+    ((5244, false, XNOR2), &[Arg(1, 181), Tv(5236)]),
+    ((5245, false, XNOR2), &[Arg(1, 182), Tv(5237)]),
+    ((5246, false, XNOR2), &[Arg(1, 183), Tv(5238)]),
+    ((5247, false, AND2), &[Arg(1, 176), Tv(5239)]),
+    ((5248, false, INV), &[Tv(5239)]),
+    ((5253, false, INV), &[Tv(5242)]),
+    ((5255, false, INV), &[Tv(5243)]),
+    ((5287, false, NOR2), &[Arg(0, 899), Tv(5286)]),
+    ((5291, false, OR2), &[Arg(0, 901), Tv(5290)]),
+    ((5295, false, NOR2), &[Arg(0, 903), Tv(5294)]),
+    ((5302, false, INV), &[Tv(5203)]),
+    ((5323, false, XOR2), &[Arg(0, 901), Tv(5316)]),
+    ((5324, false, XOR2), &[Arg(0, 902), Tv(5317)]),
+    ((5325, false, XOR2), &[Arg(0, 903), Tv(5318)]),
+    ((5333, false, XOR2), &[Arg(0, 899), Tv(5321)]),
+    ((5336, false, XOR2), &[Arg(0, 900), Tv(5322)]),
+];
+
+static LEVEL_4: [((usize, bool, CellType), &[GateInput]); 388] = [
+    ((1142, false, NAND2), &[Tv(1134), Tv(1141)]),
+    ((1158, false, NAND2), &[Tv(1150), Tv(1157)]),
+    ((1174, false, NAND2), &[Tv(1166), Tv(1173)]),
+    ((1190, false, NAND2), &[Tv(1182), Tv(1189)]),
+    ((1225, false, NOR2), &[Tv(1223), Tv(1224)]),
+    ((1227, false, NOR2), &[Tv(1223), Tv(1226)]),
+    ((1229, false, NOR2), &[Tv(1223), Tv(1228)]),
+    ((1231, false, NOR2), &[Tv(1223), Tv(1230)]),
+    ((1232, false, INV), &[Tv(1219)]),
+    ((1234, false, INV), &[Tv(1220)]),
+    ((1236, false, INV), &[Tv(1221)]),
+    ((1272, false, NOR2), &[Arg(0, 23), Tv(1271)]),
+    ((1274, false, NAND2), &[Tv(1269), Tv(1273)]),
+    ((1310, false, XOR2), &[Arg(0, 21), Tv(1294)]),
+    ((1313, false, XOR2), &[Arg(0, 22), Tv(1295)]),
+    ((1316, false, XOR2), &[Arg(0, 23), Tv(1296)]),
+    ((1322, false, XNOR2), &[Arg(1, 0), Tv(1222)]),
+    ((1362, false, NAND2), &[Tv(1356), Tv(1361)]),
+    ((1372, false, NAND2), &[Tv(1366), Tv(1371)]),
+    ((1382, false, NAND2), &[Tv(1376), Tv(1381)]),
+    ((1392, false, NAND2), &[Tv(1386), Tv(1391)]),
+    ((1427, false, NOR2), &[Tv(1425), Tv(1426)]),
+    ((1429, false, NOR2), &[Tv(1425), Tv(1428)]),
+    ((1431, false, NOR2), &[Tv(1425), Tv(1430)]),
+    ((1433, false, NOR2), &[Tv(1425), Tv(1432)]),
+    ((1434, false, INV), &[Tv(1421)]),
+    ((1436, false, INV), &[Tv(1422)]),
+    ((1438, false, INV), &[Tv(1423)]),
+    ((1477, false, NAND2), &[Tv(1347), Tv(1464)]),
+    ((1479, false, NAND2), &[Tv(1349), Tv(1472)]),
+    ((1520, false, XOR2), &[Arg(0, 61), Tv(1504)]),
+    ((1523, false, XOR2), &[Arg(0, 62), Tv(1505)]),
+    ((1526, false, XOR2), &[Arg(0, 63), Tv(1506)]),
+    ((1530, false, XNOR2), &[Arg(1, 8), Tv(1424)]),
+    ((1569, false, NAND2), &[Tv(1564), Tv(1568)]),
+    ((1578, false, NAND2), &[Tv(1573), Tv(1577)]),
+    ((1587, false, NAND2), &[Tv(1582), Tv(1586)]),
+    ((1596, false, NAND2), &[Tv(1591), Tv(1595)]),
+    ((1631, false, NOR2), &[Tv(1629), Tv(1630)]),
+    ((1633, false, NOR2), &[Tv(1629), Tv(1632)]),
+    ((1635, false, NOR2), &[Tv(1629), Tv(1634)]),
+    ((1637, false, NOR2), &[Tv(1629), Tv(1636)]),
+    ((1638, false, INV), &[Tv(1625)]),
+    ((1640, false, INV), &[Tv(1626)]),
+    ((1642, false, INV), &[Tv(1627)]),
+    ((1681, false, NAND2), &[Tv(1555), Tv(1668)]),
+    ((1683, false, NAND2), &[Tv(1557), Tv(1676)]),
+    ((1724, false, XOR2), &[Arg(0, 101), Tv(1708)]),
+    ((1727, false, XOR2), &[Arg(0, 102), Tv(1709)]),
+    ((1730, false, XOR2), &[Arg(0, 103), Tv(1710)]),
+    ((1734, false, XNOR2), &[Arg(1, 16), Tv(1628)]),
+    ((1769, false, NAND2), &[Tv(1568), Tv(1768)]),
+    ((1774, false, NAND2), &[Tv(1577), Tv(1773)]),
+    ((1779, false, NAND2), &[Tv(1586), Tv(1778)]),
+    ((1784, false, NAND2), &[Tv(1595), Tv(1783)]),
+    ((1819, false, NOR2), &[Tv(1817), Tv(1818)]),
+    ((1821, false, NOR2), &[Tv(1817), Tv(1820)]),
+    ((1823, false, NOR2), &[Tv(1817), Tv(1822)]),
+    ((1825, false, NOR2), &[Tv(1817), Tv(1824)]),
+    ((1826, false, INV), &[Tv(1813)]),
+    ((1828, false, INV), &[Tv(1814)]),
+    ((1830, false, INV), &[Tv(1815)]),
+    ((1869, false, NAND2), &[Tv(1759), Tv(1856)]),
+    ((1871, false, NAND2), &[Tv(1761), Tv(1864)]),
+    ((1912, false, XOR2), &[Arg(0, 141), Tv(1896)]),
+    ((1915, false, XOR2), &[Arg(0, 142), Tv(1897)]),
+    ((1918, false, XOR2), &[Arg(0, 143), Tv(1898)]),
+    ((1922, false, XNOR2), &[Arg(1, 24), Tv(1816)]),
+    ((1965, false, NAND2), &[Tv(1956), Tv(1964)]),
+    ((1978, false, NAND2), &[Tv(1969), Tv(1977)]),
+    ((1991, false, NAND2), &[Tv(1982), Tv(1990)]),
+    ((2004, false, NAND2), &[Tv(1995), Tv(2003)]),
+    ((2039, false, NOR2), &[Tv(2037), Tv(2038)]),
+    ((2041, false, NOR2), &[Tv(2037), Tv(2040)]),
+    ((2043, false, NOR2), &[Tv(2037), Tv(2042)]),
+    ((2045, false, NOR2), &[Tv(2037), Tv(2044)]),
+    ((2046, false, INV), &[Tv(2033)]),
+    ((2048, false, INV), &[Tv(2034)]),
+    ((2050, false, INV), &[Tv(2035)]),
+    ((2089, false, NAND2), &[Tv(1947), Tv(2076)]),
+    ((2091, false, NAND2), &[Tv(1949), Tv(2084)]),
+    ((2132, false, XOR2), &[Arg(0, 181), Tv(2116)]),
+    ((2135, false, XOR2), &[Arg(0, 182), Tv(2117)]),
+    ((2138, false, XOR2), &[Arg(0, 183), Tv(2118)]),
+    ((2142, false, XNOR2), &[Arg(1, 32), Tv(2036)]),
+    ((2177, false, NAND2), &[Tv(2174), Tv(2176)]),
+    ((2182, false, NAND2), &[Tv(2179), Tv(2181)]),
+    ((2187, false, NAND2), &[Tv(2184), Tv(2186)]),
+    ((2192, false, NAND2), &[Tv(2189), Tv(2191)]),
+    ((2227, false, NOR2), &[Tv(2225), Tv(2226)]),
+    ((2229, false, NOR2), &[Tv(2225), Tv(2228)]),
+    ((2231, false, NOR2), &[Tv(2225), Tv(2230)]),
+    ((2233, false, NOR2), &[Tv(2225), Tv(2232)]),
+    ((2234, false, INV), &[Tv(2221)]),
+    ((2236, false, INV), &[Tv(2222)]),
+    ((2238, false, INV), &[Tv(2223)]),
+    ((2277, false, NAND2), &[Tv(2167), Tv(2264)]),
+    ((2279, false, NAND2), &[Tv(2169), Tv(2272)]),
+    ((2320, false, XOR2), &[Arg(0, 221), Tv(2304)]),
+    ((2323, false, XOR2), &[Arg(0, 222), Tv(2305)]),
+    ((2326, false, XOR2), &[Arg(0, 223), Tv(2306)]),
+    ((2330, false, XNOR2), &[Arg(1, 40), Tv(2224)]),
+    ((2362, false, NAND2), &[Tv(1964), Tv(2361)]),
+    ((2364, false, NAND2), &[Tv(1977), Tv(2363)]),
+    ((2366, false, NAND2), &[Tv(1990), Tv(2365)]),
+    ((2368, false, NAND2), &[Tv(2003), Tv(2367)]),
+    ((2403, false, NOR2), &[Tv(2401), Tv(2402)]),
+    ((2405, false, NOR2), &[Tv(2401), Tv(2404)]),
+    ((2407, false, NOR2), &[Tv(2401), Tv(2406)]),
+    ((2409, false, NOR2), &[Tv(2401), Tv(2408)]),
+    ((2410, false, INV), &[Tv(2397)]),
+    ((2412, false, INV), &[Tv(2398)]),
+    ((2414, false, INV), &[Tv(2399)]),
+    ((2453, false, NAND2), &[Tv(2355), Tv(2440)]),
+    ((2455, false, NAND2), &[Tv(2357), Tv(2448)]),
+    ((2496, false, XOR2), &[Arg(0, 261), Tv(2480)]),
+    ((2499, false, XOR2), &[Arg(0, 262), Tv(2481)]),
+    ((2502, false, XOR2), &[Arg(0, 263), Tv(2482)]),
+    ((2506, false, XNOR2), &[Arg(1, 48), Tv(2400)]),
+    ((2539, false, NAND2), &[Tv(1134), Tv(2538)]),
+    ((2542, false, NAND2), &[Tv(1150), Tv(2541)]),
+    ((2545, false, NAND2), &[Tv(1166), Tv(2544)]),
+    ((2548, false, NAND2), &[Tv(1182), Tv(2547)]),
+    ((2583, false, NOR2), &[Tv(2581), Tv(2582)]),
+    ((2585, false, NOR2), &[Tv(2581), Tv(2584)]),
+    ((2587, false, NOR2), &[Tv(2581), Tv(2586)]),
+    ((2589, false, NOR2), &[Tv(2581), Tv(2588)]),
+    ((2590, false, INV), &[Tv(2577)]),
+    ((2592, false, INV), &[Tv(2578)]),
+    ((2594, false, INV), &[Tv(2579)]),
+    ((2633, false, NAND2), &[Tv(2531), Tv(2620)]),
+    ((2635, false, NAND2), &[Tv(2533), Tv(2628)]),
+    ((2676, false, XOR2), &[Arg(0, 301), Tv(2660)]),
+    ((2679, false, XOR2), &[Arg(0, 302), Tv(2661)]),
+    ((2682, false, XOR2), &[Arg(0, 303), Tv(2662)]),
+    ((2686, false, XNOR2), &[Arg(1, 56), Tv(2580)]),
+    ((2723, false, NAND2), &[Tv(2718), Tv(2722)]),
+    ((2730, false, NAND2), &[Tv(2725), Tv(2729)]),
+    ((2737, false, NAND2), &[Tv(2732), Tv(2736)]),
+    ((2744, false, NAND2), &[Tv(2739), Tv(2743)]),
+    ((2779, false, NOR2), &[Tv(2777), Tv(2778)]),
+    ((2781, false, NOR2), &[Tv(2777), Tv(2780)]),
+    ((2783, false, NOR2), &[Tv(2777), Tv(2782)]),
+    ((2785, false, NOR2), &[Tv(2777), Tv(2784)]),
+    ((2786, false, INV), &[Tv(2773)]),
+    ((2788, false, INV), &[Tv(2774)]),
+    ((2790, false, INV), &[Tv(2775)]),
+    ((2829, false, NAND2), &[Tv(2711), Tv(2816)]),
+    ((2831, false, NAND2), &[Tv(2713), Tv(2824)]),
+    ((2872, false, XOR2), &[Arg(0, 341), Tv(2856)]),
+    ((2875, false, XOR2), &[Arg(0, 342), Tv(2857)]),
+    ((2878, false, XOR2), &[Arg(0, 343), Tv(2858)]),
+    ((2882, false, XNOR2), &[Arg(1, 64), Tv(2776)]),
+    ((2915, false, NAND2), &[Tv(2722), Tv(2914)]),
+    ((2918, false, NAND2), &[Tv(2729), Tv(2917)]),
+    ((2921, false, NAND2), &[Tv(2736), Tv(2920)]),
+    ((2924, false, NAND2), &[Tv(2743), Tv(2923)]),
+    ((2959, false, NOR2), &[Tv(2957), Tv(2958)]),
+    ((2961, false, NOR2), &[Tv(2957), Tv(2960)]),
+    ((2963, false, NOR2), &[Tv(2957), Tv(2962)]),
+    ((2965, false, NOR2), &[Tv(2957), Tv(2964)]),
+    ((2966, false, INV), &[Tv(2953)]),
+    ((2968, false, INV), &[Tv(2954)]),
+    ((2970, false, INV), &[Tv(2955)]),
+    ((3009, false, NAND2), &[Tv(2907), Tv(2996)]),
+    ((3011, false, NAND2), &[Tv(2909), Tv(3004)]),
+    ((3052, false, XOR2), &[Arg(0, 381), Tv(3036)]),
+    ((3055, false, XOR2), &[Arg(0, 382), Tv(3037)]),
+    ((3058, false, XOR2), &[Arg(0, 383), Tv(3038)]),
+    ((3062, false, XNOR2), &[Arg(1, 72), Tv(2956)]),
+    ((3098, false, NAND2), &[Tv(3095), Tv(3097)]),
+    ((3104, false, NAND2), &[Tv(3101), Tv(3103)]),
+    ((3110, false, NAND2), &[Tv(3107), Tv(3109)]),
+    ((3116, false, NAND2), &[Tv(3113), Tv(3115)]),
+    ((3151, false, NOR2), &[Tv(3149), Tv(3150)]),
+    ((3153, false, NOR2), &[Tv(3149), Tv(3152)]),
+    ((3155, false, NOR2), &[Tv(3149), Tv(3154)]),
+    ((3157, false, NOR2), &[Tv(3149), Tv(3156)]),
+    ((3158, false, INV), &[Tv(3145)]),
+    ((3160, false, INV), &[Tv(3146)]),
+    ((3162, false, INV), &[Tv(3147)]),
+    ((3198, false, NAND2), &[Tv(3089), Tv(3191)]),
+    ((3234, false, XOR2), &[Arg(0, 421), Tv(3218)]),
+    ((3237, false, XOR2), &[Arg(0, 422), Tv(3219)]),
+    ((3240, false, XOR2), &[Arg(0, 423), Tv(3220)]),
+    ((3247, false, XNOR2), &[Arg(1, 80), Tv(3148)]),
+    ((3280, false, NAND2), &[Tv(3095), Tv(3279)]),
+    ((3283, false, NAND2), &[Tv(3101), Tv(3282)]),
+    ((3286, false, NAND2), &[Tv(3107), Tv(3285)]),
+    ((3289, false, NAND2), &[Tv(3113), Tv(3288)]),
+    ((3324, false, NOR2), &[Tv(3322), Tv(3323)]),
+    ((3326, false, NOR2), &[Tv(3322), Tv(3325)]),
+    ((3328, false, NOR2), &[Tv(3322), Tv(3327)]),
+    ((3330, false, NOR2), &[Tv(3322), Tv(3329)]),
+    ((3331, false, INV), &[Tv(3318)]),
+    ((3333, false, INV), &[Tv(3319)]),
+    ((3335, false, INV), &[Tv(3320)]),
+    ((3371, false, NAND2), &[Tv(3274), Tv(3364)]),
+    ((3407, false, XOR2), &[Arg(0, 461), Tv(3391)]),
+    ((3410, false, XOR2), &[Arg(0, 462), Tv(3392)]),
+    ((3413, false, XOR2), &[Arg(0, 463), Tv(3393)]),
+    ((3420, false, XNOR2), &[Arg(1, 88), Tv(3321)]),
+    ((3453, false, NAND2), &[Tv(1568), Tv(3452)]),
+    ((3456, false, NAND2), &[Tv(1577), Tv(3455)]),
+    ((3459, false, NAND2), &[Tv(1586), Tv(3458)]),
+    ((3462, false, NAND2), &[Tv(1595), Tv(3461)]),
+    ((3497, false, NOR2), &[Tv(3495), Tv(3496)]),
+    ((3499, false, NOR2), &[Tv(3495), Tv(3498)]),
+    ((3501, false, NOR2), &[Tv(3495), Tv(3500)]),
+    ((3503, false, NOR2), &[Tv(3495), Tv(3502)]),
+    ((3504, false, INV), &[Tv(3491)]),
+    ((3506, false, INV), &[Tv(3492)]),
+    ((3508, false, INV), &[Tv(3493)]),
+    ((3544, false, NAND2), &[Tv(3447), Tv(3537)]),
+    ((3580, false, XOR2), &[Arg(0, 501), Tv(3564)]),
+    ((3583, false, XOR2), &[Arg(0, 502), Tv(3565)]),
+    ((3586, false, XOR2), &[Arg(0, 503), Tv(3566)]),
+    ((3593, false, XNOR2), &[Arg(1, 96), Tv(3494)]),
+    ((3625, false, NAND2), &[Tv(1964), Tv(3624)]),
+    ((3627, false, NAND2), &[Tv(1977), Tv(3626)]),
+    ((3629, false, NAND2), &[Tv(1990), Tv(3628)]),
+    ((3631, false, NAND2), &[Tv(2003), Tv(3630)]),
+    ((3666, false, NOR2), &[Tv(3664), Tv(3665)]),
+    ((3668, false, NOR2), &[Tv(3664), Tv(3667)]),
+    ((3670, false, NOR2), &[Tv(3664), Tv(3669)]),
+    ((3672, false, NOR2), &[Tv(3664), Tv(3671)]),
+    ((3673, false, INV), &[Tv(3660)]),
+    ((3675, false, INV), &[Tv(3661)]),
+    ((3677, false, INV), &[Tv(3662)]),
+    ((3715, false, NAND2), &[Tv(3703), Tv(3706)]),
+    ((3718, false, NAND2), &[Tv(3620), Tv(3710)]),
+    ((3758, false, XOR2), &[Arg(0, 541), Tv(3742)]),
+    ((3761, false, XOR2), &[Arg(0, 542), Tv(3743)]),
+    ((3764, false, XOR2), &[Arg(0, 543), Tv(3744)]),
+    ((3769, false, XNOR2), &[Arg(1, 104), Tv(3663)]),
+    ((3801, false, NAND2), &[Tv(1964), Tv(3800)]),
+    ((3803, false, NAND2), &[Tv(1977), Tv(3802)]),
+    ((3805, false, NAND2), &[Tv(1990), Tv(3804)]),
+    ((3807, false, NAND2), &[Tv(2003), Tv(3806)]),
+    ((3842, false, NOR2), &[Tv(3840), Tv(3841)]),
+    ((3844, false, NOR2), &[Tv(3840), Tv(3843)]),
+    ((3846, false, NOR2), &[Tv(3840), Tv(3845)]),
+    ((3848, false, NOR2), &[Tv(3840), Tv(3847)]),
+    ((3849, false, INV), &[Tv(3836)]),
+    ((3851, false, INV), &[Tv(3837)]),
+    ((3853, false, INV), &[Tv(3838)]),
+    ((3891, false, NAND2), &[Tv(3879), Tv(3882)]),
+    ((3894, false, NAND2), &[Tv(3796), Tv(3886)]),
+    ((3934, false, XOR2), &[Arg(0, 581), Tv(3918)]),
+    ((3937, false, XOR2), &[Arg(0, 582), Tv(3919)]),
+    ((3940, false, XOR2), &[Arg(0, 583), Tv(3920)]),
+    ((3945, false, XNOR2), &[Arg(1, 112), Tv(3839)]),
+    ((3976, false, NAND2), &[Tv(1964), Tv(3095)]),
+    ((3977, false, NAND2), &[Tv(1977), Tv(3101)]),
+    ((3978, false, NAND2), &[Tv(1990), Tv(3107)]),
+    ((3979, false, NAND2), &[Tv(2003), Tv(3113)]),
+    ((4014, false, NOR2), &[Tv(4012), Tv(4013)]),
+    ((4016, false, NOR2), &[Tv(4012), Tv(4015)]),
+    ((4018, false, NOR2), &[Tv(4012), Tv(4017)]),
+    ((4020, false, NOR2), &[Tv(4012), Tv(4019)]),
+    ((4021, false, INV), &[Tv(4008)]),
+    ((4023, false, INV), &[Tv(4009)]),
+    ((4025, false, INV), &[Tv(4010)]),
+    ((4063, false, NAND2), &[Tv(4051), Tv(4054)]),
+    ((4066, false, NAND2), &[Tv(3972), Tv(4058)]),
+    ((4106, false, XOR2), &[Arg(0, 621), Tv(4090)]),
+    ((4109, false, XOR2), &[Arg(0, 622), Tv(4091)]),
+    ((4112, false, XOR2), &[Arg(0, 623), Tv(4092)]),
+    ((4117, false, XNOR2), &[Arg(1, 120), Tv(4011)]),
+    ((4153, false, NAND2), &[Tv(4151), Tv(4152)]),
+    ((4159, false, NAND2), &[Tv(4157), Tv(4158)]),
+    ((4165, false, NAND2), &[Tv(4163), Tv(4164)]),
+    ((4171, false, NAND2), &[Tv(4169), Tv(4170)]),
+    ((4206, false, NOR2), &[Tv(4204), Tv(4205)]),
+    ((4208, false, NOR2), &[Tv(4204), Tv(4207)]),
+    ((4210, false, NOR2), &[Tv(4204), Tv(4209)]),
+    ((4212, false, NOR2), &[Tv(4204), Tv(4211)]),
+    ((4213, false, INV), &[Tv(4200)]),
+    ((4215, false, INV), &[Tv(4201)]),
+    ((4217, false, INV), &[Tv(4202)]),
+    ((4255, false, NAND2), &[Tv(4243), Tv(4246)]),
+    ((4258, false, NAND2), &[Tv(4144), Tv(4250)]),
+    ((4298, false, XOR2), &[Arg(0, 661), Tv(4282)]),
+    ((4301, false, XOR2), &[Arg(0, 662), Tv(4283)]),
+    ((4304, false, XOR2), &[Arg(0, 663), Tv(4284)]),
+    ((4309, false, XNOR2), &[Arg(1, 128), Tv(4203)]),
+    ((4341, false, NAND2), &[Tv(1564), Tv(4340)]),
+    ((4343, false, NAND2), &[Tv(1573), Tv(4342)]),
+    ((4345, false, NAND2), &[Tv(1582), Tv(4344)]),
+    ((4347, false, NAND2), &[Tv(1591), Tv(4346)]),
+    ((4382, false, NOR2), &[Tv(4380), Tv(4381)]),
+    ((4384, false, NOR2), &[Tv(4380), Tv(4383)]),
+    ((4386, false, NOR2), &[Tv(4380), Tv(4385)]),
+    ((4388, false, NOR2), &[Tv(4380), Tv(4387)]),
+    ((4389, false, INV), &[Tv(4376)]),
+    ((4391, false, INV), &[Tv(4377)]),
+    ((4393, false, INV), &[Tv(4378)]),
+    ((4429, false, NAND2), &[Tv(4419), Tv(4428)]),
+    ((4430, false, NAND2), &[Tv(4336), Tv(4423)]),
+    ((4471, false, XOR2), &[Arg(0, 701), Tv(4455)]),
+    ((4474, false, XOR2), &[Arg(0, 702), Tv(4456)]),
+    ((4477, false, XOR2), &[Arg(0, 703), Tv(4457)]),
+    ((4481, false, XNOR2), &[Arg(1, 136), Tv(4379)]),
+    ((4512, false, NAND2), &[Tv(1956), Tv(2176)]),
+    ((4513, false, NAND2), &[Tv(1969), Tv(2181)]),
+    ((4514, false, NAND2), &[Tv(1982), Tv(2186)]),
+    ((4515, false, NAND2), &[Tv(1995), Tv(2191)]),
+    ((4550, false, NOR2), &[Tv(4548), Tv(4549)]),
+    ((4552, false, NOR2), &[Tv(4548), Tv(4551)]),
+    ((4554, false, NOR2), &[Tv(4548), Tv(4553)]),
+    ((4556, false, NOR2), &[Tv(4548), Tv(4555)]),
+    ((4557, false, INV), &[Tv(4544)]),
+    ((4559, false, INV), &[Tv(4545)]),
+    ((4561, false, INV), &[Tv(4546)]),
+    ((4597, false, NAND2), &[Tv(4587), Tv(4596)]),
+    ((4598, false, NAND2), &[Tv(4508), Tv(4591)]),
+    ((4639, false, XOR2), &[Arg(0, 741), Tv(4623)]),
+    ((4642, false, XOR2), &[Arg(0, 742), Tv(4624)]),
+    ((4645, false, XOR2), &[Arg(0, 743), Tv(4625)]),
+    ((4649, false, XNOR2), &[Arg(1, 144), Tv(4547)]),
+    ((4682, false, NAND2), &[Tv(4151), Tv(4681)]),
+    ((4685, false, NAND2), &[Tv(4157), Tv(4684)]),
+    ((4688, false, NAND2), &[Tv(4163), Tv(4687)]),
+    ((4691, false, NAND2), &[Tv(4169), Tv(4690)]),
+    ((4726, false, NOR2), &[Tv(4724), Tv(4725)]),
+    ((4728, false, NOR2), &[Tv(4724), Tv(4727)]),
+    ((4730, false, NOR2), &[Tv(4724), Tv(4729)]),
+    ((4732, false, NOR2), &[Tv(4724), Tv(4731)]),
+    ((4733, false, INV), &[Tv(4720)]),
+    ((4735, false, INV), &[Tv(4721)]),
+    ((4737, false, INV), &[Tv(4722)]),
+    ((4773, false, NAND2), &[Tv(4763), Tv(4772)]),
+    ((4774, false, NAND2), &[Tv(4676), Tv(4767)]),
+    ((4815, false, XOR2), &[Arg(0, 781), Tv(4799)]),
+    ((4818, false, XOR2), &[Arg(0, 782), Tv(4800)]),
+    ((4821, false, XOR2), &[Arg(0, 783), Tv(4801)]),
+    ((4825, false, XNOR2), &[Arg(1, 152), Tv(4723)]),
+    ((4858, false, NAND2), &[Tv(1134), Tv(4857)]),
+    ((4861, false, NAND2), &[Tv(1150), Tv(4860)]),
+    ((4864, false, NAND2), &[Tv(1166), Tv(4863)]),
+    ((4867, false, NAND2), &[Tv(1182), Tv(4866)]),
+    ((4902, false, NOR2), &[Tv(4900), Tv(4901)]),
+    ((4904, false, NOR2), &[Tv(4900), Tv(4903)]),
+    ((4906, false, NOR2), &[Tv(4900), Tv(4905)]),
+    ((4908, false, NOR2), &[Tv(4900), Tv(4907)]),
+    ((4909, false, INV), &[Tv(4896)]),
+    ((4911, false, INV), &[Tv(4897)]),
+    ((4913, false, INV), &[Tv(4898)]),
+    ((4949, false, NAND2), &[Tv(4939), Tv(4948)]),
+    ((4950, false, NAND2), &[Tv(4852), Tv(4943)]),
+    ((4991, false, XOR2), &[Arg(0, 821), Tv(4975)]),
+    ((4994, false, XOR2), &[Arg(0, 822), Tv(4976)]),
+    ((4997, false, XOR2), &[Arg(0, 823), Tv(4977)]),
+    ((5001, false, XNOR2), &[Arg(1, 160), Tv(4899)]),
+    ((5033, false, NAND2), &[Tv(4857), Tv(5032)]),
+    ((5035, false, NAND2), &[Tv(4860), Tv(5034)]),
+    ((5037, false, NAND2), &[Tv(4863), Tv(5036)]),
+    ((5039, false, NAND2), &[Tv(4866), Tv(5038)]),
+    ((5074, false, NOR2), &[Tv(5072), Tv(5073)]),
+    ((5076, false, NOR2), &[Tv(5072), Tv(5075)]),
+    ((5078, false, NOR2), &[Tv(5072), Tv(5077)]),
+    ((5080, false, NOR2), &[Tv(5072), Tv(5079)]),
+    ((5081, false, INV), &[Tv(5068)]),
+    ((5083, false, INV), &[Tv(5069)]),
+    ((5085, false, INV), &[Tv(5070)]),
+    ((5121, false, NAND2), &[Tv(5111), Tv(5120)]),
+    ((5122, false, NAND2), &[Tv(5028), Tv(5115)]),
+    ((5163, false, XOR2), &[Arg(0, 861), Tv(5147)]),
+    ((5166, false, XOR2), &[Arg(0, 862), Tv(5148)]),
+    ((5169, false, XOR2), &[Arg(0, 863), Tv(5149)]),
+    ((5173, false, XNOR2), &[Arg(1, 168), Tv(5071)]),
+    ((5206, false, NAND2), &[Tv(4857), Tv(5205)]),
+    ((5209, false, NAND2), &[Tv(4860), Tv(5208)]),
+    ((5212, false, NAND2), &[Tv(4863), Tv(5211)]),
+    ((5215, false, NAND2), &[Tv(4866), Tv(5214)]),
+    ((5250, false, NOR2), &[Tv(5248), Tv(5249)]),
+    ((5252, false, NOR2), &[Tv(5248), Tv(5251)]),
+    ((5254, false, NOR2), &[Tv(5248), Tv(5253)]),
+    ((5256, false, NOR2), &[Tv(5248), Tv(5255)]),
+    ((5257, false, INV), &[Tv(5244)]),
+    ((5259, false, INV), &[Tv(5245)]),
+    ((5261, false, INV), &[Tv(5246)]),
+    ((5297, false, NAND2), &[Tv(5287), Tv(5296)]),
+    ((5298, false, NAND2), &[Tv(5200), Tv(5291)]),
+    ((5339, false, XOR2), &[Arg(0, 901), Tv(5323)]),
+    ((5342, false, XOR2), &[Arg(0, 902), Tv(5324)]),
+    ((5345, false, XOR2), &[Arg(0, 903), Tv(5325)]),
+    ((5349, false, XNOR2), &[Arg(1, 176), Tv(5247)]),
+];
+
+static LEVEL_5: [((usize, bool, CellType), &[GateInput]); 387] = [
+    ((1191, false, INV), &[Tv(1142)]),
+    ((1192, false, INV), &[Tv(1158)]),
+    ((1194, false, INV), &[Tv(1174)]),
+    ((1195, false, INV), &[Tv(1190)]),
+    ((1233, false, NOR2), &[Tv(1223), Tv(1232)]),
+    ((1235, false, NOR2), &[Tv(1223), Tv(1234)]),
+    ((1237, false, NOR2), &[Tv(1223), Tv(1236)]),
+    ((1238, false, OR2), &[Tv(1227), Tv(1229)]),
+    ((1239, false, INV), &[Tv(1229)]),
+    ((1241, false, INV), &[Tv(1231)]),
+    ((1275, false, AND2), &[Tv(1272), Tv(1274)]),
+    ((1323, false, AND2), &[Tv(1126), Tv(1322)]),
+    ((1325, false, XNOR2), &[Arg(1, 1), Tv(1225)]),
+    ((1328, false, XNOR2), &[Arg(1, 2), Tv(1227)]),
+    ((1331, false, XNOR2), &[Arg(1, 3), Tv(1229)]),
+    ((1334, false, XNOR2), &[Arg(1, 4), Tv(1231)]),
+    ((1393, false, INV), &[Tv(1362)]),
+    ((1394, false, INV), &[Tv(1372)]),
+    ((1396, false, INV), &[Tv(1382)]),
+    ((1397, false, INV), &[Tv(1392)]),
+    ((1435, false, NOR2), &[Tv(1425), Tv(1434)]),
+    ((1437, false, NOR2), &[Tv(1425), Tv(1436)]),
+    ((1439, false, NOR2), &[Tv(1425), Tv(1438)]),
+    ((1440, false, OR2), &[Tv(1429), Tv(1431)]),
+    ((1441, false, INV), &[Tv(1431)]),
+    ((1443, false, INV), &[Tv(1433)]),
+    ((1478, false, NAND2), &[Tv(1468), Tv(1477)]),
+    ((1480, false, AND2), &[Tv(1476), Tv(1479)]),
+    ((1531, false, AND2), &[Tv(1352), Tv(1530)]),
+    ((1533, false, XNOR2), &[Arg(1, 9), Tv(1427)]),
+    ((1536, false, XNOR2), &[Arg(1, 10), Tv(1429)]),
+    ((1539, false, XNOR2), &[Arg(1, 11), Tv(1431)]),
+    ((1542, false, XNOR2), &[Arg(1, 12), Tv(1433)]),
+    ((1597, false, INV), &[Tv(1569)]),
+    ((1598, false, INV), &[Tv(1578)]),
+    ((1600, false, INV), &[Tv(1587)]),
+    ((1601, false, INV), &[Tv(1596)]),
+    ((1639, false, NOR2), &[Tv(1629), Tv(1638)]),
+    ((1641, false, NOR2), &[Tv(1629), Tv(1640)]),
+    ((1643, false, NOR2), &[Tv(1629), Tv(1642)]),
+    ((1644, false, OR2), &[Tv(1633), Tv(1635)]),
+    ((1645, false, INV), &[Tv(1635)]),
+    ((1647, false, INV), &[Tv(1637)]),
+    ((1682, false, NAND2), &[Tv(1672), Tv(1681)]),
+    ((1684, false, AND2), &[Tv(1680), Tv(1683)]),
+    ((1735, false, AND2), &[Tv(1560), Tv(1734)]),
+    ((1737, false, XNOR2), &[Arg(1, 17), Tv(1631)]),
+    ((1740, false, XNOR2), &[Arg(1, 18), Tv(1633)]),
+    ((1743, false, XNOR2), &[Arg(1, 19), Tv(1635)]),
+    ((1746, false, XNOR2), &[Arg(1, 20), Tv(1637)]),
+    ((1785, false, INV), &[Tv(1769)]),
+    ((1786, false, INV), &[Tv(1774)]),
+    ((1788, false, INV), &[Tv(1779)]),
+    ((1789, false, INV), &[Tv(1784)]),
+    ((1827, false, NOR2), &[Tv(1817), Tv(1826)]),
+    ((1829, false, NOR2), &[Tv(1817), Tv(1828)]),
+    ((1831, false, NOR2), &[Tv(1817), Tv(1830)]),
+    ((1832, false, OR2), &[Tv(1821), Tv(1823)]),
+    ((1833, false, INV), &[Tv(1823)]),
+    ((1835, false, INV), &[Tv(1825)]),
+    ((1870, false, NAND2), &[Tv(1860), Tv(1869)]),
+    ((1872, false, AND2), &[Tv(1868), Tv(1871)]),
+    ((1923, false, AND2), &[Tv(1764), Tv(1922)]),
+    ((1925, false, XNOR2), &[Arg(1, 25), Tv(1819)]),
+    ((1928, false, XNOR2), &[Arg(1, 26), Tv(1821)]),
+    ((1931, false, XNOR2), &[Arg(1, 27), Tv(1823)]),
+    ((1934, false, XNOR2), &[Arg(1, 28), Tv(1825)]),
+    ((2005, false, INV), &[Tv(1965)]),
+    ((2006, false, INV), &[Tv(1978)]),
+    ((2008, false, INV), &[Tv(1991)]),
+    ((2009, false, INV), &[Tv(2004)]),
+    ((2047, false, NOR2), &[Tv(2037), Tv(2046)]),
+    ((2049, false, NOR2), &[Tv(2037), Tv(2048)]),
+    ((2051, false, NOR2), &[Tv(2037), Tv(2050)]),
+    ((2052, false, OR2), &[Tv(2041), Tv(2043)]),
+    ((2053, false, INV), &[Tv(2043)]),
+    ((2055, false, INV), &[Tv(2045)]),
+    ((2090, false, NAND2), &[Tv(2080), Tv(2089)]),
+    ((2092, false, AND2), &[Tv(2088), Tv(2091)]),
+    ((2143, false, AND2), &[Tv(1952), Tv(2142)]),
+    ((2145, false, XNOR2), &[Arg(1, 33), Tv(2039)]),
+    ((2148, false, XNOR2), &[Arg(1, 34), Tv(2041)]),
+    ((2151, false, XNOR2), &[Arg(1, 35), Tv(2043)]),
+    ((2154, false, XNOR2), &[Arg(1, 36), Tv(2045)]),
+    ((2193, false, INV), &[Tv(2177)]),
+    ((2194, false, INV), &[Tv(2182)]),
+    ((2196, false, INV), &[Tv(2187)]),
+    ((2197, false, INV), &[Tv(2192)]),
+    ((2235, false, NOR2), &[Tv(2225), Tv(2234)]),
+    ((2237, false, NOR2), &[Tv(2225), Tv(2236)]),
+    ((2239, false, NOR2), &[Tv(2225), Tv(2238)]),
+    ((2240, false, OR2), &[Tv(2229), Tv(2231)]),
+    ((2241, false, INV), &[Tv(2231)]),
+    ((2243, false, INV), &[Tv(2233)]),
+    ((2278, false, NAND2), &[Tv(2268), Tv(2277)]),
+    ((2280, false, AND2), &[Tv(2276), Tv(2279)]),
+    ((2331, false, AND2), &[Tv(2172), Tv(2330)]),
+    ((2333, false, XNOR2), &[Arg(1, 41), Tv(2227)]),
+    ((2336, false, XNOR2), &[Arg(1, 42), Tv(2229)]),
+    ((2339, false, XNOR2), &[Arg(1, 43), Tv(2231)]),
+    ((2342, false, XNOR2), &[Arg(1, 44), Tv(2233)]),
+    ((2369, false, INV), &[Tv(2362)]),
+    ((2370, false, INV), &[Tv(2364)]),
+    ((2372, false, INV), &[Tv(2366)]),
+    ((2373, false, INV), &[Tv(2368)]),
+    ((2411, false, NOR2), &[Tv(2401), Tv(2410)]),
+    ((2413, false, NOR2), &[Tv(2401), Tv(2412)]),
+    ((2415, false, NOR2), &[Tv(2401), Tv(2414)]),
+    ((2416, false, OR2), &[Tv(2405), Tv(2407)]),
+    ((2417, false, INV), &[Tv(2407)]),
+    ((2419, false, INV), &[Tv(2409)]),
+    ((2454, false, NAND2), &[Tv(2444), Tv(2453)]),
+    ((2456, false, AND2), &[Tv(2452), Tv(2455)]),
+    ((2507, false, AND2), &[Tv(2360), Tv(2506)]),
+    ((2509, false, XNOR2), &[Arg(1, 49), Tv(2403)]),
+    ((2512, false, XNOR2), &[Arg(1, 50), Tv(2405)]),
+    ((2515, false, XNOR2), &[Arg(1, 51), Tv(2407)]),
+    ((2518, false, XNOR2), &[Arg(1, 52), Tv(2409)]),
+    ((2549, false, INV), &[Tv(2539)]),
+    ((2550, false, INV), &[Tv(2542)]),
+    ((2552, false, INV), &[Tv(2545)]),
+    ((2553, false, INV), &[Tv(2548)]),
+    ((2591, false, NOR2), &[Tv(2581), Tv(2590)]),
+    ((2593, false, NOR2), &[Tv(2581), Tv(2592)]),
+    ((2595, false, NOR2), &[Tv(2581), Tv(2594)]),
+    ((2596, false, OR2), &[Tv(2585), Tv(2587)]),
+    ((2597, false, INV), &[Tv(2587)]),
+    ((2599, false, INV), &[Tv(2589)]),
+    ((2634, false, NAND2), &[Tv(2624), Tv(2633)]),
+    ((2636, false, AND2), &[Tv(2632), Tv(2635)]),
+    ((2687, false, AND2), &[Tv(2536), Tv(2686)]),
+    ((2689, false, XNOR2), &[Arg(1, 57), Tv(2583)]),
+    ((2692, false, XNOR2), &[Arg(1, 58), Tv(2585)]),
+    ((2695, false, XNOR2), &[Arg(1, 59), Tv(2587)]),
+    ((2698, false, XNOR2), &[Arg(1, 60), Tv(2589)]),
+    ((2745, false, INV), &[Tv(2723)]),
+    ((2746, false, INV), &[Tv(2730)]),
+    ((2748, false, INV), &[Tv(2737)]),
+    ((2749, false, INV), &[Tv(2744)]),
+    ((2787, false, NOR2), &[Tv(2777), Tv(2786)]),
+    ((2789, false, NOR2), &[Tv(2777), Tv(2788)]),
+    ((2791, false, NOR2), &[Tv(2777), Tv(2790)]),
+    ((2792, false, OR2), &[Tv(2781), Tv(2783)]),
+    ((2793, false, INV), &[Tv(2783)]),
+    ((2795, false, INV), &[Tv(2785)]),
+    ((2830, false, NAND2), &[Tv(2820), Tv(2829)]),
+    ((2832, false, AND2), &[Tv(2828), Tv(2831)]),
+    ((2883, false, AND2), &[Tv(2716), Tv(2882)]),
+    ((2885, false, XNOR2), &[Arg(1, 65), Tv(2779)]),
+    ((2888, false, XNOR2), &[Arg(1, 66), Tv(2781)]),
+    ((2891, false, XNOR2), &[Arg(1, 67), Tv(2783)]),
+    ((2894, false, XNOR2), &[Arg(1, 68), Tv(2785)]),
+    ((2925, false, INV), &[Tv(2915)]),
+    ((2926, false, INV), &[Tv(2918)]),
+    ((2928, false, INV), &[Tv(2921)]),
+    ((2929, false, INV), &[Tv(2924)]),
+    ((2967, false, NOR2), &[Tv(2957), Tv(2966)]),
+    ((2969, false, NOR2), &[Tv(2957), Tv(2968)]),
+    ((2971, false, NOR2), &[Tv(2957), Tv(2970)]),
+    ((2972, false, OR2), &[Tv(2961), Tv(2963)]),
+    ((2973, false, INV), &[Tv(2963)]),
+    ((2975, false, INV), &[Tv(2965)]),
+    ((3010, false, NAND2), &[Tv(3000), Tv(3009)]),
+    ((3012, false, AND2), &[Tv(3008), Tv(3011)]),
+    ((3063, false, AND2), &[Tv(2912), Tv(3062)]),
+    ((3065, false, XNOR2), &[Arg(1, 73), Tv(2959)]),
+    ((3068, false, XNOR2), &[Arg(1, 74), Tv(2961)]),
+    ((3071, false, XNOR2), &[Arg(1, 75), Tv(2963)]),
+    ((3074, false, XNOR2), &[Arg(1, 76), Tv(2965)]),
+    ((3117, false, INV), &[Tv(3098)]),
+    ((3118, false, INV), &[Tv(3104)]),
+    ((3120, false, INV), &[Tv(3110)]),
+    ((3121, false, INV), &[Tv(3116)]),
+    ((3159, false, NOR2), &[Tv(3149), Tv(3158)]),
+    ((3161, false, NOR2), &[Tv(3149), Tv(3160)]),
+    ((3163, false, NOR2), &[Tv(3149), Tv(3162)]),
+    ((3164, false, OR2), &[Tv(3153), Tv(3155)]),
+    ((3165, false, INV), &[Tv(3155)]),
+    ((3167, false, INV), &[Tv(3157)]),
+    ((3199, false, AND2), &[Tv(3195), Tv(3198)]),
+    ((3248, false, AND2), &[Tv(3092), Tv(3247)]),
+    ((3250, false, XNOR2), &[Arg(1, 81), Tv(3151)]),
+    ((3253, false, XNOR2), &[Arg(1, 82), Tv(3153)]),
+    ((3256, false, XNOR2), &[Arg(1, 83), Tv(3155)]),
+    ((3259, false, XNOR2), &[Arg(1, 84), Tv(3157)]),
+    ((3290, false, INV), &[Tv(3280)]),
+    ((3291, false, INV), &[Tv(3283)]),
+    ((3293, false, INV), &[Tv(3286)]),
+    ((3294, false, INV), &[Tv(3289)]),
+    ((3332, false, NOR2), &[Tv(3322), Tv(3331)]),
+    ((3334, false, NOR2), &[Tv(3322), Tv(3333)]),
+    ((3336, false, NOR2), &[Tv(3322), Tv(3335)]),
+    ((3337, false, OR2), &[Tv(3326), Tv(3328)]),
+    ((3338, false, INV), &[Tv(3328)]),
+    ((3340, false, INV), &[Tv(3330)]),
+    ((3372, false, AND2), &[Tv(3368), Tv(3371)]),
+    ((3421, false, AND2), &[Tv(3277), Tv(3420)]),
+    ((3423, false, XNOR2), &[Arg(1, 89), Tv(3324)]),
+    ((3426, false, XNOR2), &[Arg(1, 90), Tv(3326)]),
+    ((3429, false, XNOR2), &[Arg(1, 91), Tv(3328)]),
+    ((3432, false, XNOR2), &[Arg(1, 92), Tv(3330)]),
+    ((3463, false, INV), &[Tv(3453)]),
+    ((3464, false, INV), &[Tv(3456)]),
+    ((3466, false, INV), &[Tv(3459)]),
+    ((3467, false, INV), &[Tv(3462)]),
+    ((3505, false, NOR2), &[Tv(3495), Tv(3504)]),
+    ((3507, false, NOR2), &[Tv(3495), Tv(3506)]),
+    ((3509, false, NOR2), &[Tv(3495), Tv(3508)]),
+    ((3510, false, OR2), &[Tv(3499), Tv(3501)]),
+    ((3511, false, INV), &[Tv(3501)]),
+    ((3513, false, INV), &[Tv(3503)]),
+    ((3545, false, AND2), &[Tv(3541), Tv(3544)]),
+    ((3594, false, AND2), &[Tv(3450), Tv(3593)]),
+    ((3596, false, XNOR2), &[Arg(1, 97), Tv(3497)]),
+    ((3599, false, XNOR2), &[Arg(1, 98), Tv(3499)]),
+    ((3602, false, XNOR2), &[Arg(1, 99), Tv(3501)]),
+    ((3605, false, XNOR2), &[Arg(1, 100), Tv(3503)]),
+    ((3632, false, INV), &[Tv(3625)]),
+    ((3633, false, INV), &[Tv(3627)]),
+    ((3635, false, INV), &[Tv(3629)]),
+    ((3636, false, INV), &[Tv(3631)]),
+    ((3674, false, NOR2), &[Tv(3664), Tv(3673)]),
+    ((3676, false, NOR2), &[Tv(3664), Tv(3675)]),
+    ((3678, false, NOR2), &[Tv(3664), Tv(3677)]),
+    ((3679, false, OR2), &[Tv(3668), Tv(3670)]),
+    ((3680, false, INV), &[Tv(3670)]),
+    ((3682, false, INV), &[Tv(3672)]),
+    ((3716, false, INV), &[Tv(3715)]),
+    ((3719, false, AND2), &[Tv(3714), Tv(3718)]),
+    ((3770, false, AND2), &[Tv(3623), Tv(3769)]),
+    ((3772, false, XNOR2), &[Arg(1, 105), Tv(3666)]),
+    ((3775, false, XNOR2), &[Arg(1, 106), Tv(3668)]),
+    ((3778, false, XNOR2), &[Arg(1, 107), Tv(3670)]),
+    ((3781, false, XNOR2), &[Arg(1, 108), Tv(3672)]),
+    ((3808, false, INV), &[Tv(3801)]),
+    ((3809, false, INV), &[Tv(3803)]),
+    ((3811, false, INV), &[Tv(3805)]),
+    ((3812, false, INV), &[Tv(3807)]),
+    ((3850, false, NOR2), &[Tv(3840), Tv(3849)]),
+    ((3852, false, NOR2), &[Tv(3840), Tv(3851)]),
+    ((3854, false, NOR2), &[Tv(3840), Tv(3853)]),
+    ((3855, false, OR2), &[Tv(3844), Tv(3846)]),
+    ((3856, false, INV), &[Tv(3846)]),
+    ((3858, false, INV), &[Tv(3848)]),
+    ((3892, false, INV), &[Tv(3891)]),
+    ((3895, false, AND2), &[Tv(3890), Tv(3894)]),
+    ((3946, false, AND2), &[Tv(3799), Tv(3945)]),
+    ((3948, false, XNOR2), &[Arg(1, 113), Tv(3842)]),
+    ((3951, false, XNOR2), &[Arg(1, 114), Tv(3844)]),
+    ((3954, false, XNOR2), &[Arg(1, 115), Tv(3846)]),
+    ((3957, false, XNOR2), &[Arg(1, 116), Tv(3848)]),
+    ((3980, false, INV), &[Tv(3976)]),
+    ((3981, false, INV), &[Tv(3977)]),
+    ((3983, false, INV), &[Tv(3978)]),
+    ((3984, false, INV), &[Tv(3979)]),
+    ((4022, false, NOR2), &[Tv(4012), Tv(4021)]),
+    ((4024, false, NOR2), &[Tv(4012), Tv(4023)]),
+    ((4026, false, NOR2), &[Tv(4012), Tv(4025)]),
+    ((4027, false, OR2), &[Tv(4016), Tv(4018)]),
+    ((4028, false, INV), &[Tv(4018)]),
+    ((4030, false, INV), &[Tv(4020)]),
+    ((4064, false, INV), &[Tv(4063)]),
+    ((4067, false, AND2), &[Tv(4062), Tv(4066)]),
+    ((4118, false, AND2), &[Tv(3975), Tv(4117)]),
+    ((4120, false, XNOR2), &[Arg(1, 121), Tv(4014)]),
+    ((4123, false, XNOR2), &[Arg(1, 122), Tv(4016)]),
+    ((4126, false, XNOR2), &[Arg(1, 123), Tv(4018)]),
+    ((4129, false, XNOR2), &[Arg(1, 124), Tv(4020)]),
+    ((4172, false, INV), &[Tv(4153)]),
+    ((4173, false, INV), &[Tv(4159)]),
+    ((4175, false, INV), &[Tv(4165)]),
+    ((4176, false, INV), &[Tv(4171)]),
+    ((4214, false, NOR2), &[Tv(4204), Tv(4213)]),
+    ((4216, false, NOR2), &[Tv(4204), Tv(4215)]),
+    ((4218, false, NOR2), &[Tv(4204), Tv(4217)]),
+    ((4219, false, OR2), &[Tv(4208), Tv(4210)]),
+    ((4220, false, INV), &[Tv(4210)]),
+    ((4222, false, INV), &[Tv(4212)]),
+    ((4256, false, INV), &[Tv(4255)]),
+    ((4259, false, AND2), &[Tv(4254), Tv(4258)]),
+    ((4310, false, AND2), &[Tv(4147), Tv(4309)]),
+    ((4312, false, XNOR2), &[Arg(1, 129), Tv(4206)]),
+    ((4315, false, XNOR2), &[Arg(1, 130), Tv(4208)]),
+    ((4318, false, XNOR2), &[Arg(1, 131), Tv(4210)]),
+    ((4321, false, XNOR2), &[Arg(1, 132), Tv(4212)]),
+    ((4348, false, INV), &[Tv(4341)]),
+    ((4349, false, INV), &[Tv(4343)]),
+    ((4351, false, INV), &[Tv(4345)]),
+    ((4352, false, INV), &[Tv(4347)]),
+    ((4390, false, NOR2), &[Tv(4380), Tv(4389)]),
+    ((4392, false, NOR2), &[Tv(4380), Tv(4391)]),
+    ((4394, false, NOR2), &[Tv(4380), Tv(4393)]),
+    ((4395, false, OR2), &[Tv(4384), Tv(4386)]),
+    ((4396, false, INV), &[Tv(4386)]),
+    ((4398, false, INV), &[Tv(4388)]),
+    ((4431, false, AND2), &[Tv(4427), Tv(4430)]),
+    ((4432, false, NAND2), &[Tv(4338), Tv(4429)]),
+    ((4482, false, AND2), &[Tv(4339), Tv(4481)]),
+    ((4484, false, XNOR2), &[Arg(1, 137), Tv(4382)]),
+    ((4487, false, XNOR2), &[Arg(1, 138), Tv(4384)]),
+    ((4490, false, XNOR2), &[Arg(1, 139), Tv(4386)]),
+    ((4493, false, XNOR2), &[Arg(1, 140), Tv(4388)]),
+    ((4516, false, INV), &[Tv(4512)]),
+    ((4517, false, INV), &[Tv(4513)]),
+    ((4519, false, INV), &[Tv(4514)]),
+    ((4520, false, INV), &[Tv(4515)]),
+    ((4558, false, NOR2), &[Tv(4548), Tv(4557)]),
+    ((4560, false, NOR2), &[Tv(4548), Tv(4559)]),
+    ((4562, false, NOR2), &[Tv(4548), Tv(4561)]),
+    ((4563, false, OR2), &[Tv(4552), Tv(4554)]),
+    ((4564, false, INV), &[Tv(4554)]),
+    ((4566, false, INV), &[Tv(4556)]),
+    ((4599, false, AND2), &[Tv(4595), Tv(4598)]),
+    ((4600, false, NAND2), &[Tv(4510), Tv(4597)]),
+    ((4650, false, AND2), &[Tv(4511), Tv(4649)]),
+    ((4652, false, XNOR2), &[Arg(1, 145), Tv(4550)]),
+    ((4655, false, XNOR2), &[Arg(1, 146), Tv(4552)]),
+    ((4658, false, XNOR2), &[Arg(1, 147), Tv(4554)]),
+    ((4661, false, XNOR2), &[Arg(1, 148), Tv(4556)]),
+    ((4692, false, INV), &[Tv(4682)]),
+    ((4693, false, INV), &[Tv(4685)]),
+    ((4695, false, INV), &[Tv(4688)]),
+    ((4696, false, INV), &[Tv(4691)]),
+    ((4734, false, NOR2), &[Tv(4724), Tv(4733)]),
+    ((4736, false, NOR2), &[Tv(4724), Tv(4735)]),
+    ((4738, false, NOR2), &[Tv(4724), Tv(4737)]),
+    ((4739, false, OR2), &[Tv(4728), Tv(4730)]),
+    ((4740, false, INV), &[Tv(4730)]),
+    ((4742, false, INV), &[Tv(4732)]),
+    ((4775, false, AND2), &[Tv(4771), Tv(4774)]),
+    ((4776, false, NAND2), &[Tv(4678), Tv(4773)]),
+    ((4826, false, AND2), &[Tv(4679), Tv(4825)]),
+    ((4828, false, XNOR2), &[Arg(1, 153), Tv(4726)]),
+    ((4831, false, XNOR2), &[Arg(1, 154), Tv(4728)]),
+    ((4834, false, XNOR2), &[Arg(1, 155), Tv(4730)]),
+    ((4837, false, XNOR2), &[Arg(1, 156), Tv(4732)]),
+    ((4868, false, INV), &[Tv(4858)]),
+    ((4869, false, INV), &[Tv(4861)]),
+    ((4871, false, INV), &[Tv(4864)]),
+    ((4872, false, INV), &[Tv(4867)]),
+    ((4910, false, NOR2), &[Tv(4900), Tv(4909)]),
+    ((4912, false, NOR2), &[Tv(4900), Tv(4911)]),
+    ((4914, false, NOR2), &[Tv(4900), Tv(4913)]),
+    ((4915, false, OR2), &[Tv(4904), Tv(4906)]),
+    ((4916, false, INV), &[Tv(4906)]),
+    ((4918, false, INV), &[Tv(4908)]),
+    ((4951, false, AND2), &[Tv(4947), Tv(4950)]),
+    ((4952, false, NAND2), &[Tv(4854), Tv(4949)]),
+    ((5002, false, AND2), &[Tv(4855), Tv(5001)]),
+    ((5004, false, XNOR2), &[Arg(1, 161), Tv(4902)]),
+    ((5007, false, XNOR2), &[Arg(1, 162), Tv(4904)]),
+    ((5010, false, XNOR2), &[Arg(1, 163), Tv(4906)]),
+    ((5013, false, XNOR2), &[Arg(1, 164), Tv(4908)]),
+    ((5040, false, INV), &[Tv(5033)]),
+    ((5041, false, INV), &[Tv(5035)]),
+    ((5043, false, INV), &[Tv(5037)]),
+    ((5044, false, INV), &[Tv(5039)]),
+    ((5082, false, NOR2), &[Tv(5072), Tv(5081)]),
+    ((5084, false, NOR2), &[Tv(5072), Tv(5083)]),
+    ((5086, false, NOR2), &[Tv(5072), Tv(5085)]),
+    ((5087, false, OR2), &[Tv(5076), Tv(5078)]),
+    ((5088, false, INV), &[Tv(5078)]),
+    ((5090, false, INV), &[Tv(5080)]),
+    ((5123, false, AND2), &[Tv(5119), Tv(5122)]),
+    ((5124, false, NAND2), &[Tv(5030), Tv(5121)]),
+    ((5174, false, AND2), &[Tv(5031), Tv(5173)]),
+    ((5176, false, XNOR2), &[Arg(1, 169), Tv(5074)]),
+    ((5179, false, XNOR2), &[Arg(1, 170), Tv(5076)]),
+    ((5182, false, XNOR2), &[Arg(1, 171), Tv(5078)]),
+    ((5185, false, XNOR2), &[Arg(1, 172), Tv(5080)]),
+    ((5216, false, INV), &[Tv(5206)]),
+    ((5217, false, INV), &[Tv(5209)]),
+    ((5219, false, INV), &[Tv(5212)]),
+    ((5220, false, INV), &[Tv(5215)]),
+    ((5258, false, NOR2), &[Tv(5248), Tv(5257)]),
+    ((5260, false, NOR2), &[Tv(5248), Tv(5259)]),
+    ((5262, false, NOR2), &[Tv(5248), Tv(5261)]),
+    ((5263, false, OR2), &[Tv(5252), Tv(5254)]),
+    ((5264, false, INV), &[Tv(5254)]),
+    ((5266, false, INV), &[Tv(5256)]),
+    ((5299, false, AND2), &[Tv(5295), Tv(5298)]),
+    ((5300, false, NAND2), &[Tv(5202), Tv(5297)]),
+    ((5350, false, AND2), &[Tv(5203), Tv(5349)]),
+    ((5352, false, XNOR2), &[Arg(1, 177), Tv(5250)]),
+    ((5355, false, XNOR2), &[Arg(1, 178), Tv(5252)]),
+    ((5358, false, XNOR2), &[Arg(1, 179), Tv(5254)]),
+    ((5361, false, XNOR2), &[Arg(1, 180), Tv(5256)]),
+];
+
+static LEVEL_6: [((usize, bool, CellType), &[GateInput]); 391] = [
+    ((1193, false, NOR2), &[Tv(1191), Tv(1192)]),
+    ((1196, false, NOR2), &[Tv(1194), Tv(1195)]),
+    ((1240, false, NAND2), &[Tv(1227), Tv(1239)]),
+    ((1242, false, NAND2), &[Tv(1241), Tv(1233)]),
+    ((1244, false, AND2), &[Tv(1231), Tv(1233)]),
+    ((1245, false, INV), &[Tv(1235)]),
+    ((1248, false, AND2), &[Tv(1235), Tv(1237)]),
+    ((1249, false, INV), &[Tv(1238)]),
+    ((1277, false, AND2), &[Tv(1276), Tv(1275)]),
+    ((1324, false, XOR2), &[Arg(1, 0), Tv(1323)]),
+    ((1326, false, AND2), &[Tv(1126), Tv(1325)]),
+    ((1329, false, AND2), &[Tv(1126), Tv(1328)]),
+    ((1332, false, AND2), &[Tv(1126), Tv(1331)]),
+    ((1335, false, AND2), &[Tv(1126), Tv(1334)]),
+    ((1337, false, XNOR2), &[Arg(1, 5), Tv(1233)]),
+    ((1340, false, XNOR2), &[Arg(1, 6), Tv(1235)]),
+    ((1343, false, XNOR2), &[Arg(1, 7), Tv(1237)]),
+    ((1395, false, NOR2), &[Tv(1393), Tv(1394)]),
+    ((1398, false, NOR2), &[Tv(1396), Tv(1397)]),
+    ((1442, false, NAND2), &[Tv(1429), Tv(1441)]),
+    ((1444, false, NAND2), &[Tv(1443), Tv(1435)]),
+    ((1446, false, AND2), &[Tv(1433), Tv(1435)]),
+    ((1447, false, INV), &[Tv(1437)]),
+    ((1450, false, AND2), &[Tv(1437), Tv(1439)]),
+    ((1451, false, INV), &[Tv(1440)]),
+    ((1481, false, NAND2), &[Tv(1351), Tv(1478)]),
+    ((1532, false, XOR2), &[Arg(1, 8), Tv(1531)]),
+    ((1534, false, AND2), &[Tv(1352), Tv(1533)]),
+    ((1537, false, AND2), &[Tv(1352), Tv(1536)]),
+    ((1540, false, AND2), &[Tv(1352), Tv(1539)]),
+    ((1543, false, AND2), &[Tv(1352), Tv(1542)]),
+    ((1545, false, XNOR2), &[Arg(1, 13), Tv(1435)]),
+    ((1548, false, XNOR2), &[Arg(1, 14), Tv(1437)]),
+    ((1551, false, XNOR2), &[Arg(1, 15), Tv(1439)]),
+    ((1599, false, NOR2), &[Tv(1597), Tv(1598)]),
+    ((1602, false, NOR2), &[Tv(1600), Tv(1601)]),
+    ((1646, false, NAND2), &[Tv(1633), Tv(1645)]),
+    ((1648, false, NAND2), &[Tv(1647), Tv(1639)]),
+    ((1650, false, AND2), &[Tv(1637), Tv(1639)]),
+    ((1651, false, INV), &[Tv(1641)]),
+    ((1654, false, AND2), &[Tv(1641), Tv(1643)]),
+    ((1655, false, INV), &[Tv(1644)]),
+    ((1685, false, NAND2), &[Tv(1559), Tv(1682)]),
+    ((1736, false, XOR2), &[Arg(1, 16), Tv(1735)]),
+    ((1738, false, AND2), &[Tv(1560), Tv(1737)]),
+    ((1741, false, AND2), &[Tv(1560), Tv(1740)]),
+    ((1744, false, AND2), &[Tv(1560), Tv(1743)]),
+    ((1747, false, AND2), &[Tv(1560), Tv(1746)]),
+    ((1749, false, XNOR2), &[Arg(1, 21), Tv(1639)]),
+    ((1752, false, XNOR2), &[Arg(1, 22), Tv(1641)]),
+    ((1755, false, XNOR2), &[Arg(1, 23), Tv(1643)]),
+    ((1787, false, NOR2), &[Tv(1785), Tv(1786)]),
+    ((1790, false, NOR2), &[Tv(1788), Tv(1789)]),
+    ((1834, false, NAND2), &[Tv(1821), Tv(1833)]),
+    ((1836, false, NAND2), &[Tv(1835), Tv(1827)]),
+    ((1838, false, AND2), &[Tv(1825), Tv(1827)]),
+    ((1839, false, INV), &[Tv(1829)]),
+    ((1842, false, AND2), &[Tv(1829), Tv(1831)]),
+    ((1843, false, INV), &[Tv(1832)]),
+    ((1873, false, NAND2), &[Tv(1763), Tv(1870)]),
+    ((1924, false, XOR2), &[Arg(1, 24), Tv(1923)]),
+    ((1926, false, AND2), &[Tv(1764), Tv(1925)]),
+    ((1929, false, AND2), &[Tv(1764), Tv(1928)]),
+    ((1932, false, AND2), &[Tv(1764), Tv(1931)]),
+    ((1935, false, AND2), &[Tv(1764), Tv(1934)]),
+    ((1937, false, XNOR2), &[Arg(1, 29), Tv(1827)]),
+    ((1940, false, XNOR2), &[Arg(1, 30), Tv(1829)]),
+    ((1943, false, XNOR2), &[Arg(1, 31), Tv(1831)]),
+    ((2007, false, NOR2), &[Tv(2005), Tv(2006)]),
+    ((2010, false, NOR2), &[Tv(2008), Tv(2009)]),
+    ((2054, false, NAND2), &[Tv(2041), Tv(2053)]),
+    ((2056, false, NAND2), &[Tv(2055), Tv(2047)]),
+    ((2058, false, AND2), &[Tv(2045), Tv(2047)]),
+    ((2059, false, INV), &[Tv(2049)]),
+    ((2062, false, AND2), &[Tv(2049), Tv(2051)]),
+    ((2063, false, INV), &[Tv(2052)]),
+    ((2093, false, NAND2), &[Tv(1951), Tv(2090)]),
+    ((2144, false, XOR2), &[Arg(1, 32), Tv(2143)]),
+    ((2146, false, AND2), &[Tv(1952), Tv(2145)]),
+    ((2149, false, AND2), &[Tv(1952), Tv(2148)]),
+    ((2152, false, AND2), &[Tv(1952), Tv(2151)]),
+    ((2155, false, AND2), &[Tv(1952), Tv(2154)]),
+    ((2157, false, XNOR2), &[Arg(1, 37), Tv(2047)]),
+    ((2160, false, XNOR2), &[Arg(1, 38), Tv(2049)]),
+    ((2163, false, XNOR2), &[Arg(1, 39), Tv(2051)]),
+    ((2195, false, NOR2), &[Tv(2193), Tv(2194)]),
+    ((2198, false, NOR2), &[Tv(2196), Tv(2197)]),
+    ((2242, false, NAND2), &[Tv(2229), Tv(2241)]),
+    ((2244, false, NAND2), &[Tv(2243), Tv(2235)]),
+    ((2246, false, AND2), &[Tv(2233), Tv(2235)]),
+    ((2247, false, INV), &[Tv(2237)]),
+    ((2250, false, AND2), &[Tv(2237), Tv(2239)]),
+    ((2251, false, INV), &[Tv(2240)]),
+    ((2281, false, NAND2), &[Tv(2171), Tv(2278)]),
+    ((2332, false, XOR2), &[Arg(1, 40), Tv(2331)]),
+    ((2334, false, AND2), &[Tv(2172), Tv(2333)]),
+    ((2337, false, AND2), &[Tv(2172), Tv(2336)]),
+    ((2340, false, AND2), &[Tv(2172), Tv(2339)]),
+    ((2343, false, AND2), &[Tv(2172), Tv(2342)]),
+    ((2345, false, XNOR2), &[Arg(1, 45), Tv(2235)]),
+    ((2348, false, XNOR2), &[Arg(1, 46), Tv(2237)]),
+    ((2351, false, XNOR2), &[Arg(1, 47), Tv(2239)]),
+    ((2371, false, NOR2), &[Tv(2369), Tv(2370)]),
+    ((2374, false, NOR2), &[Tv(2372), Tv(2373)]),
+    ((2418, false, NAND2), &[Tv(2405), Tv(2417)]),
+    ((2420, false, NAND2), &[Tv(2419), Tv(2411)]),
+    ((2422, false, AND2), &[Tv(2409), Tv(2411)]),
+    ((2423, false, INV), &[Tv(2413)]),
+    ((2426, false, AND2), &[Tv(2413), Tv(2415)]),
+    ((2427, false, INV), &[Tv(2416)]),
+    ((2457, false, NAND2), &[Tv(2359), Tv(2454)]),
+    ((2508, false, XOR2), &[Arg(1, 48), Tv(2507)]),
+    ((2510, false, AND2), &[Tv(2360), Tv(2509)]),
+    ((2513, false, AND2), &[Tv(2360), Tv(2512)]),
+    ((2516, false, AND2), &[Tv(2360), Tv(2515)]),
+    ((2519, false, AND2), &[Tv(2360), Tv(2518)]),
+    ((2521, false, XNOR2), &[Arg(1, 53), Tv(2411)]),
+    ((2524, false, XNOR2), &[Arg(1, 54), Tv(2413)]),
+    ((2527, false, XNOR2), &[Arg(1, 55), Tv(2415)]),
+    ((2551, false, NOR2), &[Tv(2549), Tv(2550)]),
+    ((2554, false, NOR2), &[Tv(2552), Tv(2553)]),
+    ((2598, false, NAND2), &[Tv(2585), Tv(2597)]),
+    ((2600, false, NAND2), &[Tv(2599), Tv(2591)]),
+    ((2602, false, AND2), &[Tv(2589), Tv(2591)]),
+    ((2603, false, INV), &[Tv(2593)]),
+    ((2606, false, AND2), &[Tv(2593), Tv(2595)]),
+    ((2607, false, INV), &[Tv(2596)]),
+    ((2637, false, NAND2), &[Tv(2535), Tv(2634)]),
+    ((2688, false, XOR2), &[Arg(1, 56), Tv(2687)]),
+    ((2690, false, AND2), &[Tv(2536), Tv(2689)]),
+    ((2693, false, AND2), &[Tv(2536), Tv(2692)]),
+    ((2696, false, AND2), &[Tv(2536), Tv(2695)]),
+    ((2699, false, AND2), &[Tv(2536), Tv(2698)]),
+    ((2701, false, XNOR2), &[Arg(1, 61), Tv(2591)]),
+    ((2704, false, XNOR2), &[Arg(1, 62), Tv(2593)]),
+    ((2707, false, XNOR2), &[Arg(1, 63), Tv(2595)]),
+    ((2747, false, NOR2), &[Tv(2745), Tv(2746)]),
+    ((2750, false, NOR2), &[Tv(2748), Tv(2749)]),
+    ((2794, false, NAND2), &[Tv(2781), Tv(2793)]),
+    ((2796, false, NAND2), &[Tv(2795), Tv(2787)]),
+    ((2798, false, AND2), &[Tv(2785), Tv(2787)]),
+    ((2799, false, INV), &[Tv(2789)]),
+    ((2802, false, AND2), &[Tv(2789), Tv(2791)]),
+    ((2803, false, INV), &[Tv(2792)]),
+    ((2833, false, NAND2), &[Tv(2715), Tv(2830)]),
+    ((2884, false, XOR2), &[Arg(1, 64), Tv(2883)]),
+    ((2886, false, AND2), &[Tv(2716), Tv(2885)]),
+    ((2889, false, AND2), &[Tv(2716), Tv(2888)]),
+    ((2892, false, AND2), &[Tv(2716), Tv(2891)]),
+    ((2895, false, AND2), &[Tv(2716), Tv(2894)]),
+    ((2897, false, XNOR2), &[Arg(1, 69), Tv(2787)]),
+    ((2900, false, XNOR2), &[Arg(1, 70), Tv(2789)]),
+    ((2903, false, XNOR2), &[Arg(1, 71), Tv(2791)]),
+    ((2927, false, NOR2), &[Tv(2925), Tv(2926)]),
+    ((2930, false, NOR2), &[Tv(2928), Tv(2929)]),
+    ((2974, false, NAND2), &[Tv(2961), Tv(2973)]),
+    ((2976, false, NAND2), &[Tv(2975), Tv(2967)]),
+    ((2978, false, AND2), &[Tv(2965), Tv(2967)]),
+    ((2979, false, INV), &[Tv(2969)]),
+    ((2982, false, AND2), &[Tv(2969), Tv(2971)]),
+    ((2983, false, INV), &[Tv(2972)]),
+    ((3013, false, NAND2), &[Tv(2911), Tv(3010)]),
+    ((3064, false, XOR2), &[Arg(1, 72), Tv(3063)]),
+    ((3066, false, AND2), &[Tv(2912), Tv(3065)]),
+    ((3069, false, AND2), &[Tv(2912), Tv(3068)]),
+    ((3072, false, AND2), &[Tv(2912), Tv(3071)]),
+    ((3075, false, AND2), &[Tv(2912), Tv(3074)]),
+    ((3077, false, XNOR2), &[Arg(1, 77), Tv(2967)]),
+    ((3080, false, XNOR2), &[Arg(1, 78), Tv(2969)]),
+    ((3083, false, XNOR2), &[Arg(1, 79), Tv(2971)]),
+    ((3119, false, NOR2), &[Tv(3117), Tv(3118)]),
+    ((3122, false, NOR2), &[Tv(3120), Tv(3121)]),
+    ((3166, false, NAND2), &[Tv(3153), Tv(3165)]),
+    ((3168, false, NAND2), &[Tv(3167), Tv(3159)]),
+    ((3170, false, AND2), &[Tv(3157), Tv(3159)]),
+    ((3171, false, INV), &[Tv(3161)]),
+    ((3174, false, AND2), &[Tv(3161), Tv(3163)]),
+    ((3175, false, INV), &[Tv(3164)]),
+    ((3201, false, AND2), &[Tv(3199), Tv(3200)]),
+    ((3249, false, XOR2), &[Arg(1, 80), Tv(3248)]),
+    ((3251, false, AND2), &[Tv(3092), Tv(3250)]),
+    ((3254, false, AND2), &[Tv(3092), Tv(3253)]),
+    ((3257, false, AND2), &[Tv(3092), Tv(3256)]),
+    ((3260, false, AND2), &[Tv(3092), Tv(3259)]),
+    ((3262, false, XNOR2), &[Arg(1, 85), Tv(3159)]),
+    ((3265, false, XNOR2), &[Arg(1, 86), Tv(3161)]),
+    ((3268, false, XNOR2), &[Arg(1, 87), Tv(3163)]),
+    ((3292, false, NOR2), &[Tv(3290), Tv(3291)]),
+    ((3295, false, NOR2), &[Tv(3293), Tv(3294)]),
+    ((3339, false, NAND2), &[Tv(3326), Tv(3338)]),
+    ((3341, false, NAND2), &[Tv(3340), Tv(3332)]),
+    ((3343, false, AND2), &[Tv(3330), Tv(3332)]),
+    ((3344, false, INV), &[Tv(3334)]),
+    ((3347, false, AND2), &[Tv(3334), Tv(3336)]),
+    ((3348, false, INV), &[Tv(3337)]),
+    ((3374, false, AND2), &[Tv(3372), Tv(3373)]),
+    ((3422, false, XOR2), &[Arg(1, 88), Tv(3421)]),
+    ((3424, false, AND2), &[Tv(3277), Tv(3423)]),
+    ((3427, false, AND2), &[Tv(3277), Tv(3426)]),
+    ((3430, false, AND2), &[Tv(3277), Tv(3429)]),
+    ((3433, false, AND2), &[Tv(3277), Tv(3432)]),
+    ((3435, false, XNOR2), &[Arg(1, 93), Tv(3332)]),
+    ((3438, false, XNOR2), &[Arg(1, 94), Tv(3334)]),
+    ((3441, false, XNOR2), &[Arg(1, 95), Tv(3336)]),
+    ((3465, false, NOR2), &[Tv(3463), Tv(3464)]),
+    ((3468, false, NOR2), &[Tv(3466), Tv(3467)]),
+    ((3512, false, NAND2), &[Tv(3499), Tv(3511)]),
+    ((3514, false, NAND2), &[Tv(3513), Tv(3505)]),
+    ((3516, false, AND2), &[Tv(3503), Tv(3505)]),
+    ((3517, false, INV), &[Tv(3507)]),
+    ((3520, false, AND2), &[Tv(3507), Tv(3509)]),
+    ((3521, false, INV), &[Tv(3510)]),
+    ((3547, false, AND2), &[Tv(3545), Tv(3546)]),
+    ((3595, false, XOR2), &[Arg(1, 96), Tv(3594)]),
+    ((3597, false, AND2), &[Tv(3450), Tv(3596)]),
+    ((3600, false, AND2), &[Tv(3450), Tv(3599)]),
+    ((3603, false, AND2), &[Tv(3450), Tv(3602)]),
+    ((3606, false, AND2), &[Tv(3450), Tv(3605)]),
+    ((3608, false, XNOR2), &[Arg(1, 101), Tv(3505)]),
+    ((3611, false, XNOR2), &[Arg(1, 102), Tv(3507)]),
+    ((3614, false, XNOR2), &[Arg(1, 103), Tv(3509)]),
+    ((3634, false, NOR2), &[Tv(3632), Tv(3633)]),
+    ((3637, false, NOR2), &[Tv(3635), Tv(3636)]),
+    ((3681, false, NAND2), &[Tv(3668), Tv(3680)]),
+    ((3683, false, NAND2), &[Tv(3682), Tv(3674)]),
+    ((3685, false, AND2), &[Tv(3672), Tv(3674)]),
+    ((3686, false, INV), &[Tv(3676)]),
+    ((3689, false, AND2), &[Tv(3676), Tv(3678)]),
+    ((3690, false, INV), &[Tv(3679)]),
+    ((3717, false, OR2), &[Arg(0, 539), Tv(3716)]),
+    ((3771, false, XOR2), &[Arg(1, 104), Tv(3770)]),
+    ((3773, false, AND2), &[Tv(3623), Tv(3772)]),
+    ((3776, false, AND2), &[Tv(3623), Tv(3775)]),
+    ((3779, false, AND2), &[Tv(3623), Tv(3778)]),
+    ((3782, false, AND2), &[Tv(3623), Tv(3781)]),
+    ((3784, false, XNOR2), &[Arg(1, 109), Tv(3674)]),
+    ((3787, false, XNOR2), &[Arg(1, 110), Tv(3676)]),
+    ((3790, false, XNOR2), &[Arg(1, 111), Tv(3678)]),
+    ((3810, false, NOR2), &[Tv(3808), Tv(3809)]),
+    ((3813, false, NOR2), &[Tv(3811), Tv(3812)]),
+    ((3857, false, NAND2), &[Tv(3844), Tv(3856)]),
+    ((3859, false, NAND2), &[Tv(3858), Tv(3850)]),
+    ((3861, false, AND2), &[Tv(3848), Tv(3850)]),
+    ((3862, false, INV), &[Tv(3852)]),
+    ((3865, false, AND2), &[Tv(3852), Tv(3854)]),
+    ((3866, false, INV), &[Tv(3855)]),
+    ((3893, false, OR2), &[Arg(0, 579), Tv(3892)]),
+    ((3947, false, XOR2), &[Arg(1, 112), Tv(3946)]),
+    ((3949, false, AND2), &[Tv(3799), Tv(3948)]),
+    ((3952, false, AND2), &[Tv(3799), Tv(3951)]),
+    ((3955, false, AND2), &[Tv(3799), Tv(3954)]),
+    ((3958, false, AND2), &[Tv(3799), Tv(3957)]),
+    ((3960, false, XNOR2), &[Arg(1, 117), Tv(3850)]),
+    ((3963, false, XNOR2), &[Arg(1, 118), Tv(3852)]),
+    ((3966, false, XNOR2), &[Arg(1, 119), Tv(3854)]),
+    ((3982, false, NOR2), &[Tv(3980), Tv(3981)]),
+    ((3985, false, NOR2), &[Tv(3983), Tv(3984)]),
+    ((4029, false, NAND2), &[Tv(4016), Tv(4028)]),
+    ((4031, false, NAND2), &[Tv(4030), Tv(4022)]),
+    ((4033, false, AND2), &[Tv(4020), Tv(4022)]),
+    ((4034, false, INV), &[Tv(4024)]),
+    ((4037, false, AND2), &[Tv(4024), Tv(4026)]),
+    ((4038, false, INV), &[Tv(4027)]),
+    ((4065, false, OR2), &[Arg(0, 619), Tv(4064)]),
+    ((4119, false, XOR2), &[Arg(1, 120), Tv(4118)]),
+    ((4121, false, AND2), &[Tv(3975), Tv(4120)]),
+    ((4124, false, AND2), &[Tv(3975), Tv(4123)]),
+    ((4127, false, AND2), &[Tv(3975), Tv(4126)]),
+    ((4130, false, AND2), &[Tv(3975), Tv(4129)]),
+    ((4132, false, XNOR2), &[Arg(1, 125), Tv(4022)]),
+    ((4135, false, XNOR2), &[Arg(1, 126), Tv(4024)]),
+    ((4138, false, XNOR2), &[Arg(1, 127), Tv(4026)]),
+    ((4174, false, NOR2), &[Tv(4172), Tv(4173)]),
+    ((4177, false, NOR2), &[Tv(4175), Tv(4176)]),
+    ((4221, false, NAND2), &[Tv(4208), Tv(4220)]),
+    ((4223, false, NAND2), &[Tv(4222), Tv(4214)]),
+    ((4225, false, AND2), &[Tv(4212), Tv(4214)]),
+    ((4226, false, INV), &[Tv(4216)]),
+    ((4229, false, AND2), &[Tv(4216), Tv(4218)]),
+    ((4230, false, INV), &[Tv(4219)]),
+    ((4257, false, OR2), &[Arg(0, 659), Tv(4256)]),
+    ((4311, false, XOR2), &[Arg(1, 128), Tv(4310)]),
+    ((4313, false, AND2), &[Tv(4147), Tv(4312)]),
+    ((4316, false, AND2), &[Tv(4147), Tv(4315)]),
+    ((4319, false, AND2), &[Tv(4147), Tv(4318)]),
+    ((4322, false, AND2), &[Tv(4147), Tv(4321)]),
+    ((4324, false, XNOR2), &[Arg(1, 133), Tv(4214)]),
+    ((4327, false, XNOR2), &[Arg(1, 134), Tv(4216)]),
+    ((4330, false, XNOR2), &[Arg(1, 135), Tv(4218)]),
+    ((4350, false, NOR2), &[Tv(4348), Tv(4349)]),
+    ((4353, false, NOR2), &[Tv(4351), Tv(4352)]),
+    ((4397, false, NAND2), &[Tv(4384), Tv(4396)]),
+    ((4399, false, NAND2), &[Tv(4398), Tv(4390)]),
+    ((4401, false, AND2), &[Tv(4388), Tv(4390)]),
+    ((4402, false, INV), &[Tv(4392)]),
+    ((4405, false, AND2), &[Tv(4392), Tv(4394)]),
+    ((4406, false, INV), &[Tv(4395)]),
+    ((4433, false, AND2), &[Tv(4431), Tv(4432)]),
+    ((4483, false, XOR2), &[Arg(1, 136), Tv(4482)]),
+    ((4485, false, AND2), &[Tv(4339), Tv(4484)]),
+    ((4488, false, AND2), &[Tv(4339), Tv(4487)]),
+    ((4491, false, AND2), &[Tv(4339), Tv(4490)]),
+    ((4494, false, AND2), &[Tv(4339), Tv(4493)]),
+    ((4496, false, XNOR2), &[Arg(1, 141), Tv(4390)]),
+    ((4499, false, XNOR2), &[Arg(1, 142), Tv(4392)]),
+    ((4502, false, XNOR2), &[Arg(1, 143), Tv(4394)]),
+    ((4518, false, NOR2), &[Tv(4516), Tv(4517)]),
+    ((4521, false, NOR2), &[Tv(4519), Tv(4520)]),
+    ((4565, false, NAND2), &[Tv(4552), Tv(4564)]),
+    ((4567, false, NAND2), &[Tv(4566), Tv(4558)]),
+    ((4569, false, AND2), &[Tv(4556), Tv(4558)]),
+    ((4570, false, INV), &[Tv(4560)]),
+    ((4573, false, AND2), &[Tv(4560), Tv(4562)]),
+    ((4574, false, INV), &[Tv(4563)]),
+    ((4601, false, AND2), &[Tv(4599), Tv(4600)]),
+    ((4651, false, XOR2), &[Arg(1, 144), Tv(4650)]),
+    ((4653, false, AND2), &[Tv(4511), Tv(4652)]),
+    ((4656, false, AND2), &[Tv(4511), Tv(4655)]),
+    ((4659, false, AND2), &[Tv(4511), Tv(4658)]),
+    ((4662, false, AND2), &[Tv(4511), Tv(4661)]),
+    ((4664, false, XNOR2), &[Arg(1, 149), Tv(4558)]),
+    ((4667, false, XNOR2), &[Arg(1, 150), Tv(4560)]),
+    ((4670, false, XNOR2), &[Arg(1, 151), Tv(4562)]),
+    ((4694, false, NOR2), &[Tv(4692), Tv(4693)]),
+    ((4697, false, NOR2), &[Tv(4695), Tv(4696)]),
+    ((4741, false, NAND2), &[Tv(4728), Tv(4740)]),
+    ((4743, false, NAND2), &[Tv(4742), Tv(4734)]),
+    ((4745, false, AND2), &[Tv(4732), Tv(4734)]),
+    ((4746, false, INV), &[Tv(4736)]),
+    ((4749, false, AND2), &[Tv(4736), Tv(4738)]),
+    ((4750, false, INV), &[Tv(4739)]),
+    ((4777, false, AND2), &[Tv(4775), Tv(4776)]),
+    ((4827, false, XOR2), &[Arg(1, 152), Tv(4826)]),
+    ((4829, false, AND2), &[Tv(4679), Tv(4828)]),
+    ((4832, false, AND2), &[Tv(4679), Tv(4831)]),
+    ((4835, false, AND2), &[Tv(4679), Tv(4834)]),
+    ((4838, false, AND2), &[Tv(4679), Tv(4837)]),
+    ((4840, false, XNOR2), &[Arg(1, 157), Tv(4734)]),
+    ((4843, false, XNOR2), &[Arg(1, 158), Tv(4736)]),
+    ((4846, false, XNOR2), &[Arg(1, 159), Tv(4738)]),
+    ((4870, false, NOR2), &[Tv(4868), Tv(4869)]),
+    ((4873, false, NOR2), &[Tv(4871), Tv(4872)]),
+    ((4917, false, NAND2), &[Tv(4904), Tv(4916)]),
+    ((4919, false, NAND2), &[Tv(4918), Tv(4910)]),
+    ((4921, false, AND2), &[Tv(4908), Tv(4910)]),
+    ((4922, false, INV), &[Tv(4912)]),
+    ((4925, false, AND2), &[Tv(4912), Tv(4914)]),
+    ((4926, false, INV), &[Tv(4915)]),
+    ((4953, false, AND2), &[Tv(4951), Tv(4952)]),
+    ((5003, false, XOR2), &[Arg(1, 160), Tv(5002)]),
+    ((5005, false, AND2), &[Tv(4855), Tv(5004)]),
+    ((5008, false, AND2), &[Tv(4855), Tv(5007)]),
+    ((5011, false, AND2), &[Tv(4855), Tv(5010)]),
+    ((5014, false, AND2), &[Tv(4855), Tv(5013)]),
+    ((5016, false, XNOR2), &[Arg(1, 165), Tv(4910)]),
+    ((5019, false, XNOR2), &[Arg(1, 166), Tv(4912)]),
+    ((5022, false, XNOR2), &[Arg(1, 167), Tv(4914)]),
+    ((5042, false, NOR2), &[Tv(5040), Tv(5041)]),
+    ((5045, false, NOR2), &[Tv(5043), Tv(5044)]),
+    ((5089, false, NAND2), &[Tv(5076), Tv(5088)]),
+    ((5091, false, NAND2), &[Tv(5090), Tv(5082)]),
+    ((5093, false, AND2), &[Tv(5080), Tv(5082)]),
+    ((5094, false, INV), &[Tv(5084)]),
+    ((5097, false, AND2), &[Tv(5084), Tv(5086)]),
+    ((5098, false, INV), &[Tv(5087)]),
+    ((5125, false, AND2), &[Tv(5123), Tv(5124)]),
+    ((5175, false, XOR2), &[Arg(1, 168), Tv(5174)]),
+    ((5177, false, AND2), &[Tv(5031), Tv(5176)]),
+    ((5180, false, AND2), &[Tv(5031), Tv(5179)]),
+    ((5183, false, AND2), &[Tv(5031), Tv(5182)]),
+    ((5186, false, AND2), &[Tv(5031), Tv(5185)]),
+    ((5188, false, XNOR2), &[Arg(1, 173), Tv(5082)]),
+    ((5191, false, XNOR2), &[Arg(1, 174), Tv(5084)]),
+    ((5194, false, XNOR2), &[Arg(1, 175), Tv(5086)]),
+    ((5218, false, NOR2), &[Tv(5216), Tv(5217)]),
+    ((5221, false, NOR2), &[Tv(5219), Tv(5220)]),
+    ((5265, false, NAND2), &[Tv(5252), Tv(5264)]),
+    ((5267, false, NAND2), &[Tv(5266), Tv(5258)]),
+    ((5269, false, AND2), &[Tv(5256), Tv(5258)]),
+    ((5270, false, INV), &[Tv(5260)]),
+    ((5273, false, AND2), &[Tv(5260), Tv(5262)]),
+    ((5274, false, INV), &[Tv(5263)]),
+    ((5301, false, AND2), &[Tv(5299), Tv(5300)]),
+    ((5351, false, XOR2), &[Arg(1, 176), Tv(5350)]),
+    ((5353, false, AND2), &[Tv(5203), Tv(5352)]),
+    ((5356, false, AND2), &[Tv(5203), Tv(5355)]),
+    ((5359, false, AND2), &[Tv(5203), Tv(5358)]),
+    ((5362, false, AND2), &[Tv(5203), Tv(5361)]),
+    ((5364, false, XNOR2), &[Arg(1, 181), Tv(5258)]),
+    ((5367, false, XNOR2), &[Arg(1, 182), Tv(5260)]),
+    ((5370, false, XNOR2), &[Arg(1, 183), Tv(5262)]),
+];
+
+static LEVEL_7: [((usize, bool, CellType), &[GateInput]); 306] = [
+    ((1197, false, AND2), &[Tv(1193), Tv(1196)]),
+    ((1243, false, NAND2), &[Tv(1233), Tv(1242)]),
+    ((1246, false, NAND2), &[Tv(1245), Tv(1237)]),
+    ((1250, false, INV), &[Tv(1240)]),
+    ((1254, false, AND2), &[Tv(1244), Tv(1248)]),
+    ((1297, false, XOR2), &[Arg(0, 16), Tv(1277)]),
+    ((1299, false, AND2), &[Tv(1277), Tv(1298)]),
+    ((1302, false, AND2), &[Tv(1277), Tv(1301)]),
+    ((1305, false, AND2), &[Tv(1277), Tv(1304)]),
+    ((1308, false, AND2), &[Tv(1277), Tv(1307)]),
+    ((1311, false, AND2), &[Tv(1277), Tv(1310)]),
+    ((1314, false, AND2), &[Tv(1277), Tv(1313)]),
+    ((1317, false, AND2), &[Tv(1277), Tv(1316)]),
+    ((1327, false, XOR2), &[Arg(1, 1), Tv(1326)]),
+    ((1330, false, XOR2), &[Arg(1, 2), Tv(1329)]),
+    ((1333, false, XOR2), &[Arg(1, 3), Tv(1332)]),
+    ((1336, false, XOR2), &[Arg(1, 4), Tv(1335)]),
+    ((1338, false, AND2), &[Tv(1126), Tv(1337)]),
+    ((1341, false, AND2), &[Tv(1126), Tv(1340)]),
+    ((1344, false, AND2), &[Tv(1126), Tv(1343)]),
+    ((1399, false, AND2), &[Tv(1395), Tv(1398)]),
+    ((1445, false, NAND2), &[Tv(1435), Tv(1444)]),
+    ((1448, false, NAND2), &[Tv(1447), Tv(1439)]),
+    ((1452, false, INV), &[Tv(1442)]),
+    ((1456, false, AND2), &[Tv(1446), Tv(1450)]),
+    ((1482, false, AND2), &[Tv(1480), Tv(1481)]),
+    ((1535, false, XOR2), &[Arg(1, 9), Tv(1534)]),
+    ((1538, false, XOR2), &[Arg(1, 10), Tv(1537)]),
+    ((1541, false, XOR2), &[Arg(1, 11), Tv(1540)]),
+    ((1544, false, XOR2), &[Arg(1, 12), Tv(1543)]),
+    ((1546, false, AND2), &[Tv(1352), Tv(1545)]),
+    ((1549, false, AND2), &[Tv(1352), Tv(1548)]),
+    ((1552, false, AND2), &[Tv(1352), Tv(1551)]),
+    ((1603, false, AND2), &[Tv(1599), Tv(1602)]),
+    ((1649, false, NAND2), &[Tv(1639), Tv(1648)]),
+    ((1652, false, NAND2), &[Tv(1651), Tv(1643)]),
+    ((1656, false, INV), &[Tv(1646)]),
+    ((1660, false, AND2), &[Tv(1650), Tv(1654)]),
+    ((1686, false, AND2), &[Tv(1684), Tv(1685)]),
+    ((1739, false, XOR2), &[Arg(1, 17), Tv(1738)]),
+    ((1742, false, XOR2), &[Arg(1, 18), Tv(1741)]),
+    ((1745, false, XOR2), &[Arg(1, 19), Tv(1744)]),
+    ((1748, false, XOR2), &[Arg(1, 20), Tv(1747)]),
+    ((1750, false, AND2), &[Tv(1560), Tv(1749)]),
+    ((1753, false, AND2), &[Tv(1560), Tv(1752)]),
+    ((1756, false, AND2), &[Tv(1560), Tv(1755)]),
+    ((1791, false, AND2), &[Tv(1787), Tv(1790)]),
+    ((1837, false, NAND2), &[Tv(1827), Tv(1836)]),
+    ((1840, false, NAND2), &[Tv(1839), Tv(1831)]),
+    ((1844, false, INV), &[Tv(1834)]),
+    ((1848, false, AND2), &[Tv(1838), Tv(1842)]),
+    ((1874, false, AND2), &[Tv(1872), Tv(1873)]),
+    ((1927, false, XOR2), &[Arg(1, 25), Tv(1926)]),
+    ((1930, false, XOR2), &[Arg(1, 26), Tv(1929)]),
+    ((1933, false, XOR2), &[Arg(1, 27), Tv(1932)]),
+    ((1936, false, XOR2), &[Arg(1, 28), Tv(1935)]),
+    ((1938, false, AND2), &[Tv(1764), Tv(1937)]),
+    ((1941, false, AND2), &[Tv(1764), Tv(1940)]),
+    ((1944, false, AND2), &[Tv(1764), Tv(1943)]),
+    ((2011, false, AND2), &[Tv(2007), Tv(2010)]),
+    ((2057, false, NAND2), &[Tv(2047), Tv(2056)]),
+    ((2060, false, NAND2), &[Tv(2059), Tv(2051)]),
+    ((2064, false, INV), &[Tv(2054)]),
+    ((2068, false, AND2), &[Tv(2058), Tv(2062)]),
+    ((2094, false, AND2), &[Tv(2092), Tv(2093)]),
+    ((2147, false, XOR2), &[Arg(1, 33), Tv(2146)]),
+    ((2150, false, XOR2), &[Arg(1, 34), Tv(2149)]),
+    ((2153, false, XOR2), &[Arg(1, 35), Tv(2152)]),
+    ((2156, false, XOR2), &[Arg(1, 36), Tv(2155)]),
+    ((2158, false, AND2), &[Tv(1952), Tv(2157)]),
+    ((2161, false, AND2), &[Tv(1952), Tv(2160)]),
+    ((2164, false, AND2), &[Tv(1952), Tv(2163)]),
+    ((2199, false, AND2), &[Tv(2195), Tv(2198)]),
+    ((2245, false, NAND2), &[Tv(2235), Tv(2244)]),
+    ((2248, false, NAND2), &[Tv(2247), Tv(2239)]),
+    ((2252, false, INV), &[Tv(2242)]),
+    ((2256, false, AND2), &[Tv(2246), Tv(2250)]),
+    ((2282, false, AND2), &[Tv(2280), Tv(2281)]),
+    ((2335, false, XOR2), &[Arg(1, 41), Tv(2334)]),
+    ((2338, false, XOR2), &[Arg(1, 42), Tv(2337)]),
+    ((2341, false, XOR2), &[Arg(1, 43), Tv(2340)]),
+    ((2344, false, XOR2), &[Arg(1, 44), Tv(2343)]),
+    ((2346, false, AND2), &[Tv(2172), Tv(2345)]),
+    ((2349, false, AND2), &[Tv(2172), Tv(2348)]),
+    ((2352, false, AND2), &[Tv(2172), Tv(2351)]),
+    ((2375, false, AND2), &[Tv(2371), Tv(2374)]),
+    ((2421, false, NAND2), &[Tv(2411), Tv(2420)]),
+    ((2424, false, NAND2), &[Tv(2423), Tv(2415)]),
+    ((2428, false, INV), &[Tv(2418)]),
+    ((2432, false, AND2), &[Tv(2422), Tv(2426)]),
+    ((2458, false, AND2), &[Tv(2456), Tv(2457)]),
+    ((2511, false, XOR2), &[Arg(1, 49), Tv(2510)]),
+    ((2514, false, XOR2), &[Arg(1, 50), Tv(2513)]),
+    ((2517, false, XOR2), &[Arg(1, 51), Tv(2516)]),
+    ((2520, false, XOR2), &[Arg(1, 52), Tv(2519)]),
+    ((2522, false, AND2), &[Tv(2360), Tv(2521)]),
+    ((2525, false, AND2), &[Tv(2360), Tv(2524)]),
+    ((2528, false, AND2), &[Tv(2360), Tv(2527)]),
+    ((2555, false, AND2), &[Tv(2551), Tv(2554)]),
+    ((2601, false, NAND2), &[Tv(2591), Tv(2600)]),
+    ((2604, false, NAND2), &[Tv(2603), Tv(2595)]),
+    ((2608, false, INV), &[Tv(2598)]),
+    ((2612, false, AND2), &[Tv(2602), Tv(2606)]),
+    ((2638, false, AND2), &[Tv(2636), Tv(2637)]),
+    ((2691, false, XOR2), &[Arg(1, 57), Tv(2690)]),
+    ((2694, false, XOR2), &[Arg(1, 58), Tv(2693)]),
+    ((2697, false, XOR2), &[Arg(1, 59), Tv(2696)]),
+    ((2700, false, XOR2), &[Arg(1, 60), Tv(2699)]),
+    ((2702, false, AND2), &[Tv(2536), Tv(2701)]),
+    ((2705, false, AND2), &[Tv(2536), Tv(2704)]),
+    ((2708, false, AND2), &[Tv(2536), Tv(2707)]),
+    ((2751, false, AND2), &[Tv(2747), Tv(2750)]),
+    ((2797, false, NAND2), &[Tv(2787), Tv(2796)]),
+    ((2800, false, NAND2), &[Tv(2799), Tv(2791)]),
+    ((2804, false, INV), &[Tv(2794)]),
+    ((2808, false, AND2), &[Tv(2798), Tv(2802)]),
+    ((2834, false, AND2), &[Tv(2832), Tv(2833)]),
+    ((2887, false, XOR2), &[Arg(1, 65), Tv(2886)]),
+    ((2890, false, XOR2), &[Arg(1, 66), Tv(2889)]),
+    ((2893, false, XOR2), &[Arg(1, 67), Tv(2892)]),
+    ((2896, false, XOR2), &[Arg(1, 68), Tv(2895)]),
+    ((2898, false, AND2), &[Tv(2716), Tv(2897)]),
+    ((2901, false, AND2), &[Tv(2716), Tv(2900)]),
+    ((2904, false, AND2), &[Tv(2716), Tv(2903)]),
+    ((2931, false, AND2), &[Tv(2927), Tv(2930)]),
+    ((2977, false, NAND2), &[Tv(2967), Tv(2976)]),
+    ((2980, false, NAND2), &[Tv(2979), Tv(2971)]),
+    ((2984, false, INV), &[Tv(2974)]),
+    ((2988, false, AND2), &[Tv(2978), Tv(2982)]),
+    ((3014, false, AND2), &[Tv(3012), Tv(3013)]),
+    ((3067, false, XOR2), &[Arg(1, 73), Tv(3066)]),
+    ((3070, false, XOR2), &[Arg(1, 74), Tv(3069)]),
+    ((3073, false, XOR2), &[Arg(1, 75), Tv(3072)]),
+    ((3076, false, XOR2), &[Arg(1, 76), Tv(3075)]),
+    ((3078, false, AND2), &[Tv(2912), Tv(3077)]),
+    ((3081, false, AND2), &[Tv(2912), Tv(3080)]),
+    ((3084, false, AND2), &[Tv(2912), Tv(3083)]),
+    ((3123, false, AND2), &[Tv(3119), Tv(3122)]),
+    ((3169, false, NAND2), &[Tv(3159), Tv(3168)]),
+    ((3172, false, NAND2), &[Tv(3171), Tv(3163)]),
+    ((3176, false, INV), &[Tv(3166)]),
+    ((3180, false, AND2), &[Tv(3170), Tv(3174)]),
+    ((3207, false, AND2), &[Tv(3206), Tv(3201)]),
+    ((3252, false, XOR2), &[Arg(1, 81), Tv(3251)]),
+    ((3255, false, XOR2), &[Arg(1, 82), Tv(3254)]),
+    ((3258, false, XOR2), &[Arg(1, 83), Tv(3257)]),
+    ((3261, false, XOR2), &[Arg(1, 84), Tv(3260)]),
+    ((3263, false, AND2), &[Tv(3092), Tv(3262)]),
+    ((3266, false, AND2), &[Tv(3092), Tv(3265)]),
+    ((3269, false, AND2), &[Tv(3092), Tv(3268)]),
+    ((3296, false, AND2), &[Tv(3292), Tv(3295)]),
+    ((3342, false, NAND2), &[Tv(3332), Tv(3341)]),
+    ((3345, false, NAND2), &[Tv(3344), Tv(3336)]),
+    ((3349, false, INV), &[Tv(3339)]),
+    ((3353, false, AND2), &[Tv(3343), Tv(3347)]),
+    ((3380, false, AND2), &[Tv(3379), Tv(3374)]),
+    ((3425, false, XOR2), &[Arg(1, 89), Tv(3424)]),
+    ((3428, false, XOR2), &[Arg(1, 90), Tv(3427)]),
+    ((3431, false, XOR2), &[Arg(1, 91), Tv(3430)]),
+    ((3434, false, XOR2), &[Arg(1, 92), Tv(3433)]),
+    ((3436, false, AND2), &[Tv(3277), Tv(3435)]),
+    ((3439, false, AND2), &[Tv(3277), Tv(3438)]),
+    ((3442, false, AND2), &[Tv(3277), Tv(3441)]),
+    ((3469, false, AND2), &[Tv(3465), Tv(3468)]),
+    ((3515, false, NAND2), &[Tv(3505), Tv(3514)]),
+    ((3518, false, NAND2), &[Tv(3517), Tv(3509)]),
+    ((3522, false, INV), &[Tv(3512)]),
+    ((3526, false, AND2), &[Tv(3516), Tv(3520)]),
+    ((3553, false, AND2), &[Tv(3552), Tv(3547)]),
+    ((3598, false, XOR2), &[Arg(1, 97), Tv(3597)]),
+    ((3601, false, XOR2), &[Arg(1, 98), Tv(3600)]),
+    ((3604, false, XOR2), &[Arg(1, 99), Tv(3603)]),
+    ((3607, false, XOR2), &[Arg(1, 100), Tv(3606)]),
+    ((3609, false, AND2), &[Tv(3450), Tv(3608)]),
+    ((3612, false, AND2), &[Tv(3450), Tv(3611)]),
+    ((3615, false, AND2), &[Tv(3450), Tv(3614)]),
+    ((3638, false, AND2), &[Tv(3634), Tv(3637)]),
+    ((3684, false, NAND2), &[Tv(3674), Tv(3683)]),
+    ((3687, false, NAND2), &[Tv(3686), Tv(3678)]),
+    ((3691, false, INV), &[Tv(3681)]),
+    ((3695, false, AND2), &[Tv(3685), Tv(3689)]),
+    ((3720, false, NAND2), &[Tv(3622), Tv(3717)]),
+    ((3774, false, XOR2), &[Arg(1, 105), Tv(3773)]),
+    ((3777, false, XOR2), &[Arg(1, 106), Tv(3776)]),
+    ((3780, false, XOR2), &[Arg(1, 107), Tv(3779)]),
+    ((3783, false, XOR2), &[Arg(1, 108), Tv(3782)]),
+    ((3785, false, AND2), &[Tv(3623), Tv(3784)]),
+    ((3788, false, AND2), &[Tv(3623), Tv(3787)]),
+    ((3791, false, AND2), &[Tv(3623), Tv(3790)]),
+    ((3814, false, AND2), &[Tv(3810), Tv(3813)]),
+    ((3860, false, NAND2), &[Tv(3850), Tv(3859)]),
+    ((3863, false, NAND2), &[Tv(3862), Tv(3854)]),
+    ((3867, false, INV), &[Tv(3857)]),
+    ((3871, false, AND2), &[Tv(3861), Tv(3865)]),
+    ((3896, false, NAND2), &[Tv(3798), Tv(3893)]),
+    ((3950, false, XOR2), &[Arg(1, 113), Tv(3949)]),
+    ((3953, false, XOR2), &[Arg(1, 114), Tv(3952)]),
+    ((3956, false, XOR2), &[Arg(1, 115), Tv(3955)]),
+    ((3959, false, XOR2), &[Arg(1, 116), Tv(3958)]),
+    ((3961, false, AND2), &[Tv(3799), Tv(3960)]),
+    ((3964, false, AND2), &[Tv(3799), Tv(3963)]),
+    ((3967, false, AND2), &[Tv(3799), Tv(3966)]),
+    ((3986, false, AND2), &[Tv(3982), Tv(3985)]),
+    ((4032, false, NAND2), &[Tv(4022), Tv(4031)]),
+    ((4035, false, NAND2), &[Tv(4034), Tv(4026)]),
+    ((4039, false, INV), &[Tv(4029)]),
+    ((4043, false, AND2), &[Tv(4033), Tv(4037)]),
+    ((4068, false, NAND2), &[Tv(3974), Tv(4065)]),
+    ((4122, false, XOR2), &[Arg(1, 121), Tv(4121)]),
+    ((4125, false, XOR2), &[Arg(1, 122), Tv(4124)]),
+    ((4128, false, XOR2), &[Arg(1, 123), Tv(4127)]),
+    ((4131, false, XOR2), &[Arg(1, 124), Tv(4130)]),
+    ((4133, false, AND2), &[Tv(3975), Tv(4132)]),
+    ((4136, false, AND2), &[Tv(3975), Tv(4135)]),
+    ((4139, false, AND2), &[Tv(3975), Tv(4138)]),
+    ((4178, false, AND2), &[Tv(4174), Tv(4177)]),
+    ((4224, false, NAND2), &[Tv(4214), Tv(4223)]),
+    ((4227, false, NAND2), &[Tv(4226), Tv(4218)]),
+    ((4231, false, INV), &[Tv(4221)]),
+    ((4235, false, AND2), &[Tv(4225), Tv(4229)]),
+    ((4260, false, NAND2), &[Tv(4146), Tv(4257)]),
+    ((4314, false, XOR2), &[Arg(1, 129), Tv(4313)]),
+    ((4317, false, XOR2), &[Arg(1, 130), Tv(4316)]),
+    ((4320, false, XOR2), &[Arg(1, 131), Tv(4319)]),
+    ((4323, false, XOR2), &[Arg(1, 132), Tv(4322)]),
+    ((4325, false, AND2), &[Tv(4147), Tv(4324)]),
+    ((4328, false, AND2), &[Tv(4147), Tv(4327)]),
+    ((4331, false, AND2), &[Tv(4147), Tv(4330)]),
+    ((4354, false, AND2), &[Tv(4350), Tv(4353)]),
+    ((4400, false, NAND2), &[Tv(4390), Tv(4399)]),
+    ((4403, false, NAND2), &[Tv(4402), Tv(4394)]),
+    ((4407, false, INV), &[Tv(4397)]),
+    ((4411, false, AND2), &[Tv(4401), Tv(4405)]),
+    ((4435, false, AND2), &[Tv(4434), Tv(4433)]),
+    ((4486, false, XOR2), &[Arg(1, 137), Tv(4485)]),
+    ((4489, false, XOR2), &[Arg(1, 138), Tv(4488)]),
+    ((4492, false, XOR2), &[Arg(1, 139), Tv(4491)]),
+    ((4495, false, XOR2), &[Arg(1, 140), Tv(4494)]),
+    ((4497, false, AND2), &[Tv(4339), Tv(4496)]),
+    ((4500, false, AND2), &[Tv(4339), Tv(4499)]),
+    ((4503, false, AND2), &[Tv(4339), Tv(4502)]),
+    ((4522, false, AND2), &[Tv(4518), Tv(4521)]),
+    ((4568, false, NAND2), &[Tv(4558), Tv(4567)]),
+    ((4571, false, NAND2), &[Tv(4570), Tv(4562)]),
+    ((4575, false, INV), &[Tv(4565)]),
+    ((4579, false, AND2), &[Tv(4569), Tv(4573)]),
+    ((4603, false, AND2), &[Tv(4602), Tv(4601)]),
+    ((4654, false, XOR2), &[Arg(1, 145), Tv(4653)]),
+    ((4657, false, XOR2), &[Arg(1, 146), Tv(4656)]),
+    ((4660, false, XOR2), &[Arg(1, 147), Tv(4659)]),
+    ((4663, false, XOR2), &[Arg(1, 148), Tv(4662)]),
+    ((4665, false, AND2), &[Tv(4511), Tv(4664)]),
+    ((4668, false, AND2), &[Tv(4511), Tv(4667)]),
+    ((4671, false, AND2), &[Tv(4511), Tv(4670)]),
+    ((4698, false, AND2), &[Tv(4694), Tv(4697)]),
+    ((4744, false, NAND2), &[Tv(4734), Tv(4743)]),
+    ((4747, false, NAND2), &[Tv(4746), Tv(4738)]),
+    ((4751, false, INV), &[Tv(4741)]),
+    ((4755, false, AND2), &[Tv(4745), Tv(4749)]),
+    ((4779, false, AND2), &[Tv(4778), Tv(4777)]),
+    ((4830, false, XOR2), &[Arg(1, 153), Tv(4829)]),
+    ((4833, false, XOR2), &[Arg(1, 154), Tv(4832)]),
+    ((4836, false, XOR2), &[Arg(1, 155), Tv(4835)]),
+    ((4839, false, XOR2), &[Arg(1, 156), Tv(4838)]),
+    ((4841, false, AND2), &[Tv(4679), Tv(4840)]),
+    ((4844, false, AND2), &[Tv(4679), Tv(4843)]),
+    ((4847, false, AND2), &[Tv(4679), Tv(4846)]),
+    ((4874, false, AND2), &[Tv(4870), Tv(4873)]),
+    ((4920, false, NAND2), &[Tv(4910), Tv(4919)]),
+    ((4923, false, NAND2), &[Tv(4922), Tv(4914)]),
+    ((4927, false, INV), &[Tv(4917)]),
+    ((4931, false, AND2), &[Tv(4921), Tv(4925)]),
+    ((4955, false, AND2), &[Tv(4954), Tv(4953)]),
+    ((5006, false, XOR2), &[Arg(1, 161), Tv(5005)]),
+    ((5009, false, XOR2), &[Arg(1, 162), Tv(5008)]),
+    ((5012, false, XOR2), &[Arg(1, 163), Tv(5011)]),
+    ((5015, false, XOR2), &[Arg(1, 164), Tv(5014)]),
+    ((5017, false, AND2), &[Tv(4855), Tv(5016)]),
+    ((5020, false, AND2), &[Tv(4855), Tv(5019)]),
+    ((5023, false, AND2), &[Tv(4855), Tv(5022)]),
+    ((5046, false, AND2), &[Tv(5042), Tv(5045)]),
+    ((5092, false, NAND2), &[Tv(5082), Tv(5091)]),
+    ((5095, false, NAND2), &[Tv(5094), Tv(5086)]),
+    ((5099, false, INV), &[Tv(5089)]),
+    ((5103, false, AND2), &[Tv(5093), Tv(5097)]),
+    ((5127, false, AND2), &[Tv(5126), Tv(5125)]),
+    ((5178, false, XOR2), &[Arg(1, 169), Tv(5177)]),
+    ((5181, false, XOR2), &[Arg(1, 170), Tv(5180)]),
+    ((5184, false, XOR2), &[Arg(1, 171), Tv(5183)]),
+    ((5187, false, XOR2), &[Arg(1, 172), Tv(5186)]),
+    ((5189, false, AND2), &[Tv(5031), Tv(5188)]),
+    ((5192, false, AND2), &[Tv(5031), Tv(5191)]),
+    ((5195, false, AND2), &[Tv(5031), Tv(5194)]),
+    ((5222, false, AND2), &[Tv(5218), Tv(5221)]),
+    ((5268, false, NAND2), &[Tv(5258), Tv(5267)]),
+    ((5271, false, NAND2), &[Tv(5270), Tv(5262)]),
+    ((5275, false, INV), &[Tv(5265)]),
+    ((5279, false, AND2), &[Tv(5269), Tv(5273)]),
+    ((5303, false, AND2), &[Tv(5302), Tv(5301)]),
+    ((5354, false, XOR2), &[Arg(1, 177), Tv(5353)]),
+    ((5357, false, XOR2), &[Arg(1, 178), Tv(5356)]),
+    ((5360, false, XOR2), &[Arg(1, 179), Tv(5359)]),
+    ((5363, false, XOR2), &[Arg(1, 180), Tv(5362)]),
+    ((5365, false, AND2), &[Tv(5203), Tv(5364)]),
+    ((5368, false, AND2), &[Tv(5203), Tv(5367)]),
+    ((5371, false, AND2), &[Tv(5203), Tv(5370)]),
+];
+
+static LEVEL_8: [((usize, bool, CellType), &[GateInput]); 230] = [
+    ((1247, false, AND2), &[Tv(1237), Tv(1246)]),
+    ((1251, false, OR2), &[Tv(1249), Tv(1250)]),
+    ((1252, false, NAND2), &[Tv(1243), Tv(1248)]),
+    ((1300, false, XOR2), &[Arg(0, 17), Tv(1299)]),
+    ((1303, false, XNOR2), &[Arg(0, 18), Tv(1302)]),
+    ((1306, false, XOR2), &[Arg(0, 19), Tv(1305)]),
+    ((1309, false, XOR2), &[Arg(0, 20), Tv(1308)]),
+    ((1312, false, XNOR2), &[Arg(0, 21), Tv(1311)]),
+    ((1315, false, XNOR2), &[Arg(0, 22), Tv(1314)]),
+    ((1318, false, XOR2), &[Arg(0, 23), Tv(1317)]),
+    ((1339, false, XOR2), &[Arg(1, 5), Tv(1338)]),
+    ((1342, false, XOR2), &[Arg(1, 6), Tv(1341)]),
+    ((1345, false, XOR2), &[Arg(1, 7), Tv(1344)]),
+    ((1449, false, AND2), &[Tv(1439), Tv(1448)]),
+    ((1453, false, OR2), &[Tv(1451), Tv(1452)]),
+    ((1454, false, NAND2), &[Tv(1445), Tv(1450)]),
+    ((1485, false, AND2), &[Tv(1484), Tv(1482)]),
+    ((1547, false, XOR2), &[Arg(1, 13), Tv(1546)]),
+    ((1550, false, XOR2), &[Arg(1, 14), Tv(1549)]),
+    ((1553, false, XOR2), &[Arg(1, 15), Tv(1552)]),
+    ((1653, false, AND2), &[Tv(1643), Tv(1652)]),
+    ((1657, false, OR2), &[Tv(1655), Tv(1656)]),
+    ((1658, false, NAND2), &[Tv(1649), Tv(1654)]),
+    ((1689, false, AND2), &[Tv(1688), Tv(1686)]),
+    ((1751, false, XOR2), &[Arg(1, 21), Tv(1750)]),
+    ((1754, false, XOR2), &[Arg(1, 22), Tv(1753)]),
+    ((1757, false, XOR2), &[Arg(1, 23), Tv(1756)]),
+    ((1841, false, AND2), &[Tv(1831), Tv(1840)]),
+    ((1845, false, OR2), &[Tv(1843), Tv(1844)]),
+    ((1846, false, NAND2), &[Tv(1837), Tv(1842)]),
+    ((1877, false, AND2), &[Tv(1876), Tv(1874)]),
+    ((1939, false, XOR2), &[Arg(1, 29), Tv(1938)]),
+    ((1942, false, XOR2), &[Arg(1, 30), Tv(1941)]),
+    ((1945, false, XOR2), &[Arg(1, 31), Tv(1944)]),
+    ((2061, false, AND2), &[Tv(2051), Tv(2060)]),
+    ((2065, false, OR2), &[Tv(2063), Tv(2064)]),
+    ((2066, false, NAND2), &[Tv(2057), Tv(2062)]),
+    ((2097, false, AND2), &[Tv(2096), Tv(2094)]),
+    ((2159, false, XOR2), &[Arg(1, 37), Tv(2158)]),
+    ((2162, false, XOR2), &[Arg(1, 38), Tv(2161)]),
+    ((2165, false, XOR2), &[Arg(1, 39), Tv(2164)]),
+    ((2249, false, AND2), &[Tv(2239), Tv(2248)]),
+    ((2253, false, OR2), &[Tv(2251), Tv(2252)]),
+    ((2254, false, NAND2), &[Tv(2245), Tv(2250)]),
+    ((2285, false, AND2), &[Tv(2284), Tv(2282)]),
+    ((2347, false, XOR2), &[Arg(1, 45), Tv(2346)]),
+    ((2350, false, XOR2), &[Arg(1, 46), Tv(2349)]),
+    ((2353, false, XOR2), &[Arg(1, 47), Tv(2352)]),
+    ((2425, false, AND2), &[Tv(2415), Tv(2424)]),
+    ((2429, false, OR2), &[Tv(2427), Tv(2428)]),
+    ((2430, false, NAND2), &[Tv(2421), Tv(2426)]),
+    ((2461, false, AND2), &[Tv(2460), Tv(2458)]),
+    ((2523, false, XOR2), &[Arg(1, 53), Tv(2522)]),
+    ((2526, false, XOR2), &[Arg(1, 54), Tv(2525)]),
+    ((2529, false, XOR2), &[Arg(1, 55), Tv(2528)]),
+    ((2605, false, AND2), &[Tv(2595), Tv(2604)]),
+    ((2609, false, OR2), &[Tv(2607), Tv(2608)]),
+    ((2610, false, NAND2), &[Tv(2601), Tv(2606)]),
+    ((2641, false, AND2), &[Tv(2640), Tv(2638)]),
+    ((2703, false, XOR2), &[Arg(1, 61), Tv(2702)]),
+    ((2706, false, XOR2), &[Arg(1, 62), Tv(2705)]),
+    ((2709, false, XOR2), &[Arg(1, 63), Tv(2708)]),
+    ((2801, false, AND2), &[Tv(2791), Tv(2800)]),
+    ((2805, false, OR2), &[Tv(2803), Tv(2804)]),
+    ((2806, false, NAND2), &[Tv(2797), Tv(2802)]),
+    ((2837, false, AND2), &[Tv(2836), Tv(2834)]),
+    ((2899, false, XOR2), &[Arg(1, 69), Tv(2898)]),
+    ((2902, false, XOR2), &[Arg(1, 70), Tv(2901)]),
+    ((2905, false, XOR2), &[Arg(1, 71), Tv(2904)]),
+    ((2981, false, AND2), &[Tv(2971), Tv(2980)]),
+    ((2985, false, OR2), &[Tv(2983), Tv(2984)]),
+    ((2986, false, NAND2), &[Tv(2977), Tv(2982)]),
+    ((3017, false, AND2), &[Tv(3016), Tv(3014)]),
+    ((3079, false, XOR2), &[Arg(1, 77), Tv(3078)]),
+    ((3082, false, XOR2), &[Arg(1, 78), Tv(3081)]),
+    ((3085, false, XOR2), &[Arg(1, 79), Tv(3084)]),
+    ((3173, false, AND2), &[Tv(3163), Tv(3172)]),
+    ((3177, false, OR2), &[Tv(3175), Tv(3176)]),
+    ((3178, false, NAND2), &[Tv(3169), Tv(3174)]),
+    ((3221, false, XNOR2), &[Arg(0, 416), Tv(3207)]),
+    ((3223, false, AND2), &[Tv(3207), Tv(3222)]),
+    ((3226, false, AND2), &[Tv(3207), Tv(3225)]),
+    ((3229, false, AND2), &[Tv(3207), Tv(3228)]),
+    ((3232, false, AND2), &[Tv(3207), Tv(3231)]),
+    ((3235, false, AND2), &[Tv(3207), Tv(3234)]),
+    ((3238, false, AND2), &[Tv(3207), Tv(3237)]),
+    ((3241, false, AND2), &[Tv(3207), Tv(3240)]),
+    ((3264, false, XOR2), &[Arg(1, 85), Tv(3263)]),
+    ((3267, false, XOR2), &[Arg(1, 86), Tv(3266)]),
+    ((3270, false, XOR2), &[Arg(1, 87), Tv(3269)]),
+    ((3346, false, AND2), &[Tv(3336), Tv(3345)]),
+    ((3350, false, OR2), &[Tv(3348), Tv(3349)]),
+    ((3351, false, NAND2), &[Tv(3342), Tv(3347)]),
+    ((3394, false, XNOR2), &[Arg(0, 456), Tv(3380)]),
+    ((3396, false, AND2), &[Tv(3380), Tv(3395)]),
+    ((3399, false, AND2), &[Tv(3380), Tv(3398)]),
+    ((3402, false, AND2), &[Tv(3380), Tv(3401)]),
+    ((3405, false, AND2), &[Tv(3380), Tv(3404)]),
+    ((3408, false, AND2), &[Tv(3380), Tv(3407)]),
+    ((3411, false, AND2), &[Tv(3380), Tv(3410)]),
+    ((3414, false, AND2), &[Tv(3380), Tv(3413)]),
+    ((3437, false, XOR2), &[Arg(1, 93), Tv(3436)]),
+    ((3440, false, XOR2), &[Arg(1, 94), Tv(3439)]),
+    ((3443, false, XOR2), &[Arg(1, 95), Tv(3442)]),
+    ((3519, false, AND2), &[Tv(3509), Tv(3518)]),
+    ((3523, false, OR2), &[Tv(3521), Tv(3522)]),
+    ((3524, false, NAND2), &[Tv(3515), Tv(3520)]),
+    ((3567, false, XNOR2), &[Arg(0, 496), Tv(3553)]),
+    ((3569, false, AND2), &[Tv(3553), Tv(3568)]),
+    ((3572, false, AND2), &[Tv(3553), Tv(3571)]),
+    ((3575, false, AND2), &[Tv(3553), Tv(3574)]),
+    ((3578, false, AND2), &[Tv(3553), Tv(3577)]),
+    ((3581, false, AND2), &[Tv(3553), Tv(3580)]),
+    ((3584, false, AND2), &[Tv(3553), Tv(3583)]),
+    ((3587, false, AND2), &[Tv(3553), Tv(3586)]),
+    ((3610, false, XOR2), &[Arg(1, 101), Tv(3609)]),
+    ((3613, false, XOR2), &[Arg(1, 102), Tv(3612)]),
+    ((3616, false, XOR2), &[Arg(1, 103), Tv(3615)]),
+    ((3688, false, AND2), &[Tv(3678), Tv(3687)]),
+    ((3692, false, OR2), &[Tv(3690), Tv(3691)]),
+    ((3693, false, NAND2), &[Tv(3684), Tv(3689)]),
+    ((3721, false, AND2), &[Tv(3719), Tv(3720)]),
+    ((3786, false, XOR2), &[Arg(1, 109), Tv(3785)]),
+    ((3789, false, XOR2), &[Arg(1, 110), Tv(3788)]),
+    ((3792, false, XOR2), &[Arg(1, 111), Tv(3791)]),
+    ((3864, false, AND2), &[Tv(3854), Tv(3863)]),
+    ((3868, false, OR2), &[Tv(3866), Tv(3867)]),
+    ((3869, false, NAND2), &[Tv(3860), Tv(3865)]),
+    ((3897, false, AND2), &[Tv(3895), Tv(3896)]),
+    ((3962, false, XOR2), &[Arg(1, 117), Tv(3961)]),
+    ((3965, false, XOR2), &[Arg(1, 118), Tv(3964)]),
+    ((3968, false, XOR2), &[Arg(1, 119), Tv(3967)]),
+    ((4036, false, AND2), &[Tv(4026), Tv(4035)]),
+    ((4040, false, OR2), &[Tv(4038), Tv(4039)]),
+    ((4041, false, NAND2), &[Tv(4032), Tv(4037)]),
+    ((4069, false, AND2), &[Tv(4067), Tv(4068)]),
+    ((4134, false, XOR2), &[Arg(1, 125), Tv(4133)]),
+    ((4137, false, XOR2), &[Arg(1, 126), Tv(4136)]),
+    ((4140, false, XOR2), &[Arg(1, 127), Tv(4139)]),
+    ((4228, false, AND2), &[Tv(4218), Tv(4227)]),
+    ((4232, false, OR2), &[Tv(4230), Tv(4231)]),
+    ((4233, false, NAND2), &[Tv(4224), Tv(4229)]),
+    ((4261, false, AND2), &[Tv(4259), Tv(4260)]),
+    ((4326, false, XOR2), &[Arg(1, 133), Tv(4325)]),
+    ((4329, false, XOR2), &[Arg(1, 134), Tv(4328)]),
+    ((4332, false, XOR2), &[Arg(1, 135), Tv(4331)]),
+    ((4404, false, AND2), &[Tv(4394), Tv(4403)]),
+    ((4408, false, OR2), &[Tv(4406), Tv(4407)]),
+    ((4409, false, NAND2), &[Tv(4400), Tv(4405)]),
+    ((4458, false, XOR2), &[Arg(0, 696), Tv(4435)]),
+    ((4460, false, AND2), &[Tv(4435), Tv(4459)]),
+    ((4463, false, AND2), &[Tv(4435), Tv(4462)]),
+    ((4466, false, AND2), &[Tv(4435), Tv(4465)]),
+    ((4469, false, AND2), &[Tv(4435), Tv(4468)]),
+    ((4472, false, AND2), &[Tv(4435), Tv(4471)]),
+    ((4475, false, AND2), &[Tv(4435), Tv(4474)]),
+    ((4478, false, AND2), &[Tv(4435), Tv(4477)]),
+    ((4498, false, XOR2), &[Arg(1, 141), Tv(4497)]),
+    ((4501, false, XOR2), &[Arg(1, 142), Tv(4500)]),
+    ((4504, false, XOR2), &[Arg(1, 143), Tv(4503)]),
+    ((4572, false, AND2), &[Tv(4562), Tv(4571)]),
+    ((4576, false, OR2), &[Tv(4574), Tv(4575)]),
+    ((4577, false, NAND2), &[Tv(4568), Tv(4573)]),
+    ((4626, false, XOR2), &[Arg(0, 736), Tv(4603)]),
+    ((4628, false, AND2), &[Tv(4603), Tv(4627)]),
+    ((4631, false, AND2), &[Tv(4603), Tv(4630)]),
+    ((4634, false, AND2), &[Tv(4603), Tv(4633)]),
+    ((4637, false, AND2), &[Tv(4603), Tv(4636)]),
+    ((4640, false, AND2), &[Tv(4603), Tv(4639)]),
+    ((4643, false, AND2), &[Tv(4603), Tv(4642)]),
+    ((4646, false, AND2), &[Tv(4603), Tv(4645)]),
+    ((4666, false, XOR2), &[Arg(1, 149), Tv(4665)]),
+    ((4669, false, XOR2), &[Arg(1, 150), Tv(4668)]),
+    ((4672, false, XOR2), &[Arg(1, 151), Tv(4671)]),
+    ((4748, false, AND2), &[Tv(4738), Tv(4747)]),
+    ((4752, false, OR2), &[Tv(4750), Tv(4751)]),
+    ((4753, false, NAND2), &[Tv(4744), Tv(4749)]),
+    ((4802, false, XOR2), &[Arg(0, 776), Tv(4779)]),
+    ((4804, false, AND2), &[Tv(4779), Tv(4803)]),
+    ((4807, false, AND2), &[Tv(4779), Tv(4806)]),
+    ((4810, false, AND2), &[Tv(4779), Tv(4809)]),
+    ((4813, false, AND2), &[Tv(4779), Tv(4812)]),
+    ((4816, false, AND2), &[Tv(4779), Tv(4815)]),
+    ((4819, false, AND2), &[Tv(4779), Tv(4818)]),
+    ((4822, false, AND2), &[Tv(4779), Tv(4821)]),
+    ((4842, false, XOR2), &[Arg(1, 157), Tv(4841)]),
+    ((4845, false, XOR2), &[Arg(1, 158), Tv(4844)]),
+    ((4848, false, XOR2), &[Arg(1, 159), Tv(4847)]),
+    ((4924, false, AND2), &[Tv(4914), Tv(4923)]),
+    ((4928, false, OR2), &[Tv(4926), Tv(4927)]),
+    ((4929, false, NAND2), &[Tv(4920), Tv(4925)]),
+    ((4978, false, XOR2), &[Arg(0, 816), Tv(4955)]),
+    ((4980, false, AND2), &[Tv(4955), Tv(4979)]),
+    ((4983, false, AND2), &[Tv(4955), Tv(4982)]),
+    ((4986, false, AND2), &[Tv(4955), Tv(4985)]),
+    ((4989, false, AND2), &[Tv(4955), Tv(4988)]),
+    ((4992, false, AND2), &[Tv(4955), Tv(4991)]),
+    ((4995, false, AND2), &[Tv(4955), Tv(4994)]),
+    ((4998, false, AND2), &[Tv(4955), Tv(4997)]),
+    ((5018, false, XOR2), &[Arg(1, 165), Tv(5017)]),
+    ((5021, false, XOR2), &[Arg(1, 166), Tv(5020)]),
+    ((5024, false, XOR2), &[Arg(1, 167), Tv(5023)]),
+    ((5096, false, AND2), &[Tv(5086), Tv(5095)]),
+    ((5100, false, OR2), &[Tv(5098), Tv(5099)]),
+    ((5101, false, NAND2), &[Tv(5092), Tv(5097)]),
+    ((5150, false, XOR2), &[Arg(0, 856), Tv(5127)]),
+    ((5152, false, AND2), &[Tv(5127), Tv(5151)]),
+    ((5155, false, AND2), &[Tv(5127), Tv(5154)]),
+    ((5158, false, AND2), &[Tv(5127), Tv(5157)]),
+    ((5161, false, AND2), &[Tv(5127), Tv(5160)]),
+    ((5164, false, AND2), &[Tv(5127), Tv(5163)]),
+    ((5167, false, AND2), &[Tv(5127), Tv(5166)]),
+    ((5170, false, AND2), &[Tv(5127), Tv(5169)]),
+    ((5190, false, XOR2), &[Arg(1, 173), Tv(5189)]),
+    ((5193, false, XOR2), &[Arg(1, 174), Tv(5192)]),
+    ((5196, false, XOR2), &[Arg(1, 175), Tv(5195)]),
+    ((5272, false, AND2), &[Tv(5262), Tv(5271)]),
+    ((5276, false, OR2), &[Tv(5274), Tv(5275)]),
+    ((5277, false, NAND2), &[Tv(5268), Tv(5273)]),
+    ((5326, false, XOR2), &[Arg(0, 896), Tv(5303)]),
+    ((5328, false, AND2), &[Tv(5303), Tv(5327)]),
+    ((5331, false, AND2), &[Tv(5303), Tv(5330)]),
+    ((5334, false, AND2), &[Tv(5303), Tv(5333)]),
+    ((5337, false, AND2), &[Tv(5303), Tv(5336)]),
+    ((5340, false, AND2), &[Tv(5303), Tv(5339)]),
+    ((5343, false, AND2), &[Tv(5303), Tv(5342)]),
+    ((5346, false, AND2), &[Tv(5303), Tv(5345)]),
+    ((5366, false, XOR2), &[Arg(1, 181), Tv(5365)]),
+    ((5369, false, XOR2), &[Arg(1, 182), Tv(5368)]),
+    ((5372, false, XOR2), &[Arg(1, 183), Tv(5371)]),
+];
+
+static LEVEL_9: [((usize, bool, CellType), &[GateInput]); 191] = [
+    ((1253, false, AND2), &[Tv(1247), Tv(1252)]),
+    ((1255, false, NAND2), &[Tv(1251), Tv(1254)]),
+    ((1319, false, INV), &[Tv(1303)]),
+    ((1320, false, INV), &[Tv(1312)]),
+    ((1321, false, INV), &[Tv(1315)]),
+    ((1455, false, AND2), &[Tv(1449), Tv(1454)]),
+    ((1457, false, NAND2), &[Tv(1453), Tv(1456)]),
+    ((1507, false, XNOR2), &[Arg(0, 56), Tv(1485)]),
+    ((1509, false, AND2), &[Tv(1485), Tv(1508)]),
+    ((1512, false, AND2), &[Tv(1485), Tv(1511)]),
+    ((1515, false, AND2), &[Tv(1485), Tv(1514)]),
+    ((1518, false, AND2), &[Tv(1485), Tv(1517)]),
+    ((1521, false, AND2), &[Tv(1485), Tv(1520)]),
+    ((1524, false, AND2), &[Tv(1485), Tv(1523)]),
+    ((1527, false, AND2), &[Tv(1485), Tv(1526)]),
+    ((1659, false, AND2), &[Tv(1653), Tv(1658)]),
+    ((1661, false, NAND2), &[Tv(1657), Tv(1660)]),
+    ((1711, false, XNOR2), &[Arg(0, 96), Tv(1689)]),
+    ((1713, false, AND2), &[Tv(1689), Tv(1712)]),
+    ((1716, false, AND2), &[Tv(1689), Tv(1715)]),
+    ((1719, false, AND2), &[Tv(1689), Tv(1718)]),
+    ((1722, false, AND2), &[Tv(1689), Tv(1721)]),
+    ((1725, false, AND2), &[Tv(1689), Tv(1724)]),
+    ((1728, false, AND2), &[Tv(1689), Tv(1727)]),
+    ((1731, false, AND2), &[Tv(1689), Tv(1730)]),
+    ((1847, false, AND2), &[Tv(1841), Tv(1846)]),
+    ((1849, false, NAND2), &[Tv(1845), Tv(1848)]),
+    ((1899, false, XNOR2), &[Arg(0, 136), Tv(1877)]),
+    ((1901, false, AND2), &[Tv(1877), Tv(1900)]),
+    ((1904, false, AND2), &[Tv(1877), Tv(1903)]),
+    ((1907, false, AND2), &[Tv(1877), Tv(1906)]),
+    ((1910, false, AND2), &[Tv(1877), Tv(1909)]),
+    ((1913, false, AND2), &[Tv(1877), Tv(1912)]),
+    ((1916, false, AND2), &[Tv(1877), Tv(1915)]),
+    ((1919, false, AND2), &[Tv(1877), Tv(1918)]),
+    ((2067, false, AND2), &[Tv(2061), Tv(2066)]),
+    ((2069, false, NAND2), &[Tv(2065), Tv(2068)]),
+    ((2119, false, XNOR2), &[Arg(0, 176), Tv(2097)]),
+    ((2121, false, AND2), &[Tv(2097), Tv(2120)]),
+    ((2124, false, AND2), &[Tv(2097), Tv(2123)]),
+    ((2127, false, AND2), &[Tv(2097), Tv(2126)]),
+    ((2130, false, AND2), &[Tv(2097), Tv(2129)]),
+    ((2133, false, AND2), &[Tv(2097), Tv(2132)]),
+    ((2136, false, AND2), &[Tv(2097), Tv(2135)]),
+    ((2139, false, AND2), &[Tv(2097), Tv(2138)]),
+    ((2255, false, AND2), &[Tv(2249), Tv(2254)]),
+    ((2257, false, NAND2), &[Tv(2253), Tv(2256)]),
+    ((2307, false, XNOR2), &[Arg(0, 216), Tv(2285)]),
+    ((2309, false, AND2), &[Tv(2285), Tv(2308)]),
+    ((2312, false, AND2), &[Tv(2285), Tv(2311)]),
+    ((2315, false, AND2), &[Tv(2285), Tv(2314)]),
+    ((2318, false, AND2), &[Tv(2285), Tv(2317)]),
+    ((2321, false, AND2), &[Tv(2285), Tv(2320)]),
+    ((2324, false, AND2), &[Tv(2285), Tv(2323)]),
+    ((2327, false, AND2), &[Tv(2285), Tv(2326)]),
+    ((2431, false, AND2), &[Tv(2425), Tv(2430)]),
+    ((2433, false, NAND2), &[Tv(2429), Tv(2432)]),
+    ((2483, false, XNOR2), &[Arg(0, 256), Tv(2461)]),
+    ((2485, false, AND2), &[Tv(2461), Tv(2484)]),
+    ((2488, false, AND2), &[Tv(2461), Tv(2487)]),
+    ((2491, false, AND2), &[Tv(2461), Tv(2490)]),
+    ((2494, false, AND2), &[Tv(2461), Tv(2493)]),
+    ((2497, false, AND2), &[Tv(2461), Tv(2496)]),
+    ((2500, false, AND2), &[Tv(2461), Tv(2499)]),
+    ((2503, false, AND2), &[Tv(2461), Tv(2502)]),
+    ((2611, false, AND2), &[Tv(2605), Tv(2610)]),
+    ((2613, false, NAND2), &[Tv(2609), Tv(2612)]),
+    ((2663, false, XNOR2), &[Arg(0, 296), Tv(2641)]),
+    ((2665, false, AND2), &[Tv(2641), Tv(2664)]),
+    ((2668, false, AND2), &[Tv(2641), Tv(2667)]),
+    ((2671, false, AND2), &[Tv(2641), Tv(2670)]),
+    ((2674, false, AND2), &[Tv(2641), Tv(2673)]),
+    ((2677, false, AND2), &[Tv(2641), Tv(2676)]),
+    ((2680, false, AND2), &[Tv(2641), Tv(2679)]),
+    ((2683, false, AND2), &[Tv(2641), Tv(2682)]),
+    ((2807, false, AND2), &[Tv(2801), Tv(2806)]),
+    ((2809, false, NAND2), &[Tv(2805), Tv(2808)]),
+    ((2859, false, XNOR2), &[Arg(0, 336), Tv(2837)]),
+    ((2861, false, AND2), &[Tv(2837), Tv(2860)]),
+    ((2864, false, AND2), &[Tv(2837), Tv(2863)]),
+    ((2867, false, AND2), &[Tv(2837), Tv(2866)]),
+    ((2870, false, AND2), &[Tv(2837), Tv(2869)]),
+    ((2873, false, AND2), &[Tv(2837), Tv(2872)]),
+    ((2876, false, AND2), &[Tv(2837), Tv(2875)]),
+    ((2879, false, AND2), &[Tv(2837), Tv(2878)]),
+    ((2987, false, AND2), &[Tv(2981), Tv(2986)]),
+    ((2989, false, NAND2), &[Tv(2985), Tv(2988)]),
+    ((3039, false, XNOR2), &[Arg(0, 376), Tv(3017)]),
+    ((3041, false, AND2), &[Tv(3017), Tv(3040)]),
+    ((3044, false, AND2), &[Tv(3017), Tv(3043)]),
+    ((3047, false, AND2), &[Tv(3017), Tv(3046)]),
+    ((3050, false, AND2), &[Tv(3017), Tv(3049)]),
+    ((3053, false, AND2), &[Tv(3017), Tv(3052)]),
+    ((3056, false, AND2), &[Tv(3017), Tv(3055)]),
+    ((3059, false, AND2), &[Tv(3017), Tv(3058)]),
+    ((3179, false, AND2), &[Tv(3173), Tv(3178)]),
+    ((3181, false, NAND2), &[Tv(3177), Tv(3180)]),
+    ((3224, false, XNOR2), &[Arg(0, 417), Tv(3223)]),
+    ((3227, false, XNOR2), &[Arg(0, 418), Tv(3226)]),
+    ((3230, false, XNOR2), &[Arg(0, 419), Tv(3229)]),
+    ((3233, false, XOR2), &[Arg(0, 420), Tv(3232)]),
+    ((3236, false, XOR2), &[Arg(0, 421), Tv(3235)]),
+    ((3239, false, XOR2), &[Arg(0, 422), Tv(3238)]),
+    ((3242, false, XOR2), &[Arg(0, 423), Tv(3241)]),
+    ((3243, false, INV), &[Tv(3221)]),
+    ((3352, false, AND2), &[Tv(3346), Tv(3351)]),
+    ((3354, false, NAND2), &[Tv(3350), Tv(3353)]),
+    ((3397, false, XNOR2), &[Arg(0, 457), Tv(3396)]),
+    ((3400, false, XNOR2), &[Arg(0, 458), Tv(3399)]),
+    ((3403, false, XNOR2), &[Arg(0, 459), Tv(3402)]),
+    ((3406, false, XOR2), &[Arg(0, 460), Tv(3405)]),
+    ((3409, false, XOR2), &[Arg(0, 461), Tv(3408)]),
+    ((3412, false, XOR2), &[Arg(0, 462), Tv(3411)]),
+    ((3415, false, XOR2), &[Arg(0, 463), Tv(3414)]),
+    ((3416, false, INV), &[Tv(3394)]),
+    ((3525, false, AND2), &[Tv(3519), Tv(3524)]),
+    ((3527, false, NAND2), &[Tv(3523), Tv(3526)]),
+    ((3570, false, XNOR2), &[Arg(0, 497), Tv(3569)]),
+    ((3573, false, XNOR2), &[Arg(0, 498), Tv(3572)]),
+    ((3576, false, XNOR2), &[Arg(0, 499), Tv(3575)]),
+    ((3579, false, XOR2), &[Arg(0, 500), Tv(3578)]),
+    ((3582, false, XOR2), &[Arg(0, 501), Tv(3581)]),
+    ((3585, false, XOR2), &[Arg(0, 502), Tv(3584)]),
+    ((3588, false, XOR2), &[Arg(0, 503), Tv(3587)]),
+    ((3589, false, INV), &[Tv(3567)]),
+    ((3694, false, AND2), &[Tv(3688), Tv(3693)]),
+    ((3696, false, NAND2), &[Tv(3692), Tv(3695)]),
+    ((3724, false, AND2), &[Tv(3723), Tv(3721)]),
+    ((3870, false, AND2), &[Tv(3864), Tv(3869)]),
+    ((3872, false, NAND2), &[Tv(3868), Tv(3871)]),
+    ((3900, false, AND2), &[Tv(3899), Tv(3897)]),
+    ((4042, false, AND2), &[Tv(4036), Tv(4041)]),
+    ((4044, false, NAND2), &[Tv(4040), Tv(4043)]),
+    ((4072, false, AND2), &[Tv(4071), Tv(4069)]),
+    ((4234, false, AND2), &[Tv(4228), Tv(4233)]),
+    ((4236, false, NAND2), &[Tv(4232), Tv(4235)]),
+    ((4264, false, AND2), &[Tv(4263), Tv(4261)]),
+    ((4410, false, AND2), &[Tv(4404), Tv(4409)]),
+    ((4412, false, NAND2), &[Tv(4408), Tv(4411)]),
+    ((4461, false, XNOR2), &[Arg(0, 697), Tv(4460)]),
+    ((4464, false, XOR2), &[Arg(0, 698), Tv(4463)]),
+    ((4467, false, XOR2), &[Arg(0, 699), Tv(4466)]),
+    ((4470, false, XOR2), &[Arg(0, 700), Tv(4469)]),
+    ((4473, false, XOR2), &[Arg(0, 701), Tv(4472)]),
+    ((4476, false, XOR2), &[Arg(0, 702), Tv(4475)]),
+    ((4479, false, XOR2), &[Arg(0, 703), Tv(4478)]),
+    ((4578, false, AND2), &[Tv(4572), Tv(4577)]),
+    ((4580, false, NAND2), &[Tv(4576), Tv(4579)]),
+    ((4629, false, XNOR2), &[Arg(0, 737), Tv(4628)]),
+    ((4632, false, XOR2), &[Arg(0, 738), Tv(4631)]),
+    ((4635, false, XOR2), &[Arg(0, 739), Tv(4634)]),
+    ((4638, false, XOR2), &[Arg(0, 740), Tv(4637)]),
+    ((4641, false, XOR2), &[Arg(0, 741), Tv(4640)]),
+    ((4644, false, XOR2), &[Arg(0, 742), Tv(4643)]),
+    ((4647, false, XOR2), &[Arg(0, 743), Tv(4646)]),
+    ((4754, false, AND2), &[Tv(4748), Tv(4753)]),
+    ((4756, false, NAND2), &[Tv(4752), Tv(4755)]),
+    ((4805, false, XNOR2), &[Arg(0, 777), Tv(4804)]),
+    ((4808, false, XOR2), &[Arg(0, 778), Tv(4807)]),
+    ((4811, false, XOR2), &[Arg(0, 779), Tv(4810)]),
+    ((4814, false, XOR2), &[Arg(0, 780), Tv(4813)]),
+    ((4817, false, XOR2), &[Arg(0, 781), Tv(4816)]),
+    ((4820, false, XOR2), &[Arg(0, 782), Tv(4819)]),
+    ((4823, false, XOR2), &[Arg(0, 783), Tv(4822)]),
+    ((4930, false, AND2), &[Tv(4924), Tv(4929)]),
+    ((4932, false, NAND2), &[Tv(4928), Tv(4931)]),
+    ((4981, false, XNOR2), &[Arg(0, 817), Tv(4980)]),
+    ((4984, false, XOR2), &[Arg(0, 818), Tv(4983)]),
+    ((4987, false, XOR2), &[Arg(0, 819), Tv(4986)]),
+    ((4990, false, XOR2), &[Arg(0, 820), Tv(4989)]),
+    ((4993, false, XOR2), &[Arg(0, 821), Tv(4992)]),
+    ((4996, false, XOR2), &[Arg(0, 822), Tv(4995)]),
+    ((4999, false, XOR2), &[Arg(0, 823), Tv(4998)]),
+    ((5102, false, AND2), &[Tv(5096), Tv(5101)]),
+    ((5104, false, NAND2), &[Tv(5100), Tv(5103)]),
+    ((5153, false, XNOR2), &[Arg(0, 857), Tv(5152)]),
+    ((5156, false, XOR2), &[Arg(0, 858), Tv(5155)]),
+    ((5159, false, XOR2), &[Arg(0, 859), Tv(5158)]),
+    ((5162, false, XOR2), &[Arg(0, 860), Tv(5161)]),
+    ((5165, false, XOR2), &[Arg(0, 861), Tv(5164)]),
+    ((5168, false, XOR2), &[Arg(0, 862), Tv(5167)]),
+    ((5171, false, XOR2), &[Arg(0, 863), Tv(5170)]),
+    ((5278, false, AND2), &[Tv(5272), Tv(5277)]),
+    ((5280, false, NAND2), &[Tv(5276), Tv(5279)]),
+    ((5329, false, XNOR2), &[Arg(0, 897), Tv(5328)]),
+    ((5332, false, XOR2), &[Arg(0, 898), Tv(5331)]),
+    ((5335, false, XOR2), &[Arg(0, 899), Tv(5334)]),
+    ((5338, false, XOR2), &[Arg(0, 900), Tv(5337)]),
+    ((5341, false, XOR2), &[Arg(0, 901), Tv(5340)]),
+    ((5344, false, XOR2), &[Arg(0, 902), Tv(5343)]),
+    ((5347, false, XOR2), &[Arg(0, 903), Tv(5346)]),
+];
+
+static LEVEL_10: [((usize, bool, CellType), &[GateInput]); 142] = [
+    ((1256, false, NAND2), &[Tv(1253), Tv(1255)]),
+    ((1458, false, NAND2), &[Tv(1455), Tv(1457)]),
+    ((1510, false, XOR2), &[Arg(0, 57), Tv(1509)]),
+    ((1513, false, XOR2), &[Arg(0, 58), Tv(1512)]),
+    ((1516, false, XOR2), &[Arg(0, 59), Tv(1515)]),
+    ((1519, false, XOR2), &[Arg(0, 60), Tv(1518)]),
+    ((1522, false, XOR2), &[Arg(0, 61), Tv(1521)]),
+    ((1525, false, XOR2), &[Arg(0, 62), Tv(1524)]),
+    ((1528, false, XOR2), &[Arg(0, 63), Tv(1527)]),
+    ((1529, false, INV), &[Tv(1507)]),
+    ((1662, false, NAND2), &[Tv(1659), Tv(1661)]),
+    ((1714, false, XOR2), &[Arg(0, 97), Tv(1713)]),
+    ((1717, false, XOR2), &[Arg(0, 98), Tv(1716)]),
+    ((1720, false, XOR2), &[Arg(0, 99), Tv(1719)]),
+    ((1723, false, XOR2), &[Arg(0, 100), Tv(1722)]),
+    ((1726, false, XOR2), &[Arg(0, 101), Tv(1725)]),
+    ((1729, false, XOR2), &[Arg(0, 102), Tv(1728)]),
+    ((1732, false, XOR2), &[Arg(0, 103), Tv(1731)]),
+    ((1733, false, INV), &[Tv(1711)]),
+    ((1850, false, NAND2), &[Tv(1847), Tv(1849)]),
+    ((1902, false, XOR2), &[Arg(0, 137), Tv(1901)]),
+    ((1905, false, XOR2), &[Arg(0, 138), Tv(1904)]),
+    ((1908, false, XOR2), &[Arg(0, 139), Tv(1907)]),
+    ((1911, false, XOR2), &[Arg(0, 140), Tv(1910)]),
+    ((1914, false, XOR2), &[Arg(0, 141), Tv(1913)]),
+    ((1917, false, XOR2), &[Arg(0, 142), Tv(1916)]),
+    ((1920, false, XOR2), &[Arg(0, 143), Tv(1919)]),
+    ((1921, false, INV), &[Tv(1899)]),
+    ((2070, false, NAND2), &[Tv(2067), Tv(2069)]),
+    ((2122, false, XOR2), &[Arg(0, 177), Tv(2121)]),
+    ((2125, false, XOR2), &[Arg(0, 178), Tv(2124)]),
+    ((2128, false, XOR2), &[Arg(0, 179), Tv(2127)]),
+    ((2131, false, XOR2), &[Arg(0, 180), Tv(2130)]),
+    ((2134, false, XOR2), &[Arg(0, 181), Tv(2133)]),
+    ((2137, false, XOR2), &[Arg(0, 182), Tv(2136)]),
+    ((2140, false, XOR2), &[Arg(0, 183), Tv(2139)]),
+    ((2141, false, INV), &[Tv(2119)]),
+    ((2258, false, NAND2), &[Tv(2255), Tv(2257)]),
+    ((2310, false, XOR2), &[Arg(0, 217), Tv(2309)]),
+    ((2313, false, XOR2), &[Arg(0, 218), Tv(2312)]),
+    ((2316, false, XOR2), &[Arg(0, 219), Tv(2315)]),
+    ((2319, false, XOR2), &[Arg(0, 220), Tv(2318)]),
+    ((2322, false, XOR2), &[Arg(0, 221), Tv(2321)]),
+    ((2325, false, XOR2), &[Arg(0, 222), Tv(2324)]),
+    ((2328, false, XOR2), &[Arg(0, 223), Tv(2327)]),
+    ((2329, false, INV), &[Tv(2307)]),
+    ((2434, false, NAND2), &[Tv(2431), Tv(2433)]),
+    ((2486, false, XOR2), &[Arg(0, 257), Tv(2485)]),
+    ((2489, false, XOR2), &[Arg(0, 258), Tv(2488)]),
+    ((2492, false, XOR2), &[Arg(0, 259), Tv(2491)]),
+    ((2495, false, XOR2), &[Arg(0, 260), Tv(2494)]),
+    ((2498, false, XOR2), &[Arg(0, 261), Tv(2497)]),
+    ((2501, false, XOR2), &[Arg(0, 262), Tv(2500)]),
+    ((2504, false, XOR2), &[Arg(0, 263), Tv(2503)]),
+    ((2505, false, INV), &[Tv(2483)]),
+    ((2614, false, NAND2), &[Tv(2611), Tv(2613)]),
+    ((2666, false, XOR2), &[Arg(0, 297), Tv(2665)]),
+    ((2669, false, XOR2), &[Arg(0, 298), Tv(2668)]),
+    ((2672, false, XOR2), &[Arg(0, 299), Tv(2671)]),
+    ((2675, false, XOR2), &[Arg(0, 300), Tv(2674)]),
+    ((2678, false, XOR2), &[Arg(0, 301), Tv(2677)]),
+    ((2681, false, XOR2), &[Arg(0, 302), Tv(2680)]),
+    ((2684, false, XOR2), &[Arg(0, 303), Tv(2683)]),
+    ((2685, false, INV), &[Tv(2663)]),
+    ((2810, false, NAND2), &[Tv(2807), Tv(2809)]),
+    ((2862, false, XOR2), &[Arg(0, 337), Tv(2861)]),
+    ((2865, false, XOR2), &[Arg(0, 338), Tv(2864)]),
+    ((2868, false, XOR2), &[Arg(0, 339), Tv(2867)]),
+    ((2871, false, XOR2), &[Arg(0, 340), Tv(2870)]),
+    ((2874, false, XOR2), &[Arg(0, 341), Tv(2873)]),
+    ((2877, false, XOR2), &[Arg(0, 342), Tv(2876)]),
+    ((2880, false, XOR2), &[Arg(0, 343), Tv(2879)]),
+    ((2881, false, INV), &[Tv(2859)]),
+    ((2990, false, NAND2), &[Tv(2987), Tv(2989)]),
+    ((3042, false, XOR2), &[Arg(0, 377), Tv(3041)]),
+    ((3045, false, XOR2), &[Arg(0, 378), Tv(3044)]),
+    ((3048, false, XOR2), &[Arg(0, 379), Tv(3047)]),
+    ((3051, false, XOR2), &[Arg(0, 380), Tv(3050)]),
+    ((3054, false, XOR2), &[Arg(0, 381), Tv(3053)]),
+    ((3057, false, XOR2), &[Arg(0, 382), Tv(3056)]),
+    ((3060, false, XOR2), &[Arg(0, 383), Tv(3059)]),
+    ((3061, false, INV), &[Tv(3039)]),
+    ((3182, false, NAND2), &[Tv(3179), Tv(3181)]),
+    ((3244, false, INV), &[Tv(3224)]),
+    ((3245, false, INV), &[Tv(3227)]),
+    ((3246, false, INV), &[Tv(3230)]),
+    ((3355, false, NAND2), &[Tv(3352), Tv(3354)]),
+    ((3417, false, INV), &[Tv(3397)]),
+    ((3418, false, INV), &[Tv(3400)]),
+    ((3419, false, INV), &[Tv(3403)]),
+    ((3528, false, NAND2), &[Tv(3525), Tv(3527)]),
+    ((3590, false, INV), &[Tv(3570)]),
+    ((3591, false, INV), &[Tv(3573)]),
+    ((3592, false, INV), &[Tv(3576)]),
+    ((3697, false, NAND2), &[Tv(3694), Tv(3696)]),
+    ((3745, false, XNOR2), &[Arg(0, 536), Tv(3724)]),
+    ((3747, false, AND2), &[Tv(3724), Tv(3746)]),
+    ((3750, false, AND2), &[Tv(3724), Tv(3749)]),
+    ((3753, false, AND2), &[Tv(3724), Tv(3752)]),
+    ((3756, false, AND2), &[Tv(3724), Tv(3755)]),
+    ((3759, false, AND2), &[Tv(3724), Tv(3758)]),
+    ((3762, false, AND2), &[Tv(3724), Tv(3761)]),
+    ((3765, false, AND2), &[Tv(3724), Tv(3764)]),
+    ((3873, false, NAND2), &[Tv(3870), Tv(3872)]),
+    ((3921, false, XNOR2), &[Arg(0, 576), Tv(3900)]),
+    ((3923, false, AND2), &[Tv(3900), Tv(3922)]),
+    ((3926, false, AND2), &[Tv(3900), Tv(3925)]),
+    ((3929, false, AND2), &[Tv(3900), Tv(3928)]),
+    ((3932, false, AND2), &[Tv(3900), Tv(3931)]),
+    ((3935, false, AND2), &[Tv(3900), Tv(3934)]),
+    ((3938, false, AND2), &[Tv(3900), Tv(3937)]),
+    ((3941, false, AND2), &[Tv(3900), Tv(3940)]),
+    ((4045, false, NAND2), &[Tv(4042), Tv(4044)]),
+    ((4093, false, XNOR2), &[Arg(0, 616), Tv(4072)]),
+    ((4095, false, AND2), &[Tv(4072), Tv(4094)]),
+    ((4098, false, AND2), &[Tv(4072), Tv(4097)]),
+    ((4101, false, AND2), &[Tv(4072), Tv(4100)]),
+    ((4104, false, AND2), &[Tv(4072), Tv(4103)]),
+    ((4107, false, AND2), &[Tv(4072), Tv(4106)]),
+    ((4110, false, AND2), &[Tv(4072), Tv(4109)]),
+    ((4113, false, AND2), &[Tv(4072), Tv(4112)]),
+    ((4237, false, NAND2), &[Tv(4234), Tv(4236)]),
+    ((4285, false, XNOR2), &[Arg(0, 656), Tv(4264)]),
+    ((4287, false, AND2), &[Tv(4264), Tv(4286)]),
+    ((4290, false, AND2), &[Tv(4264), Tv(4289)]),
+    ((4293, false, AND2), &[Tv(4264), Tv(4292)]),
+    ((4296, false, AND2), &[Tv(4264), Tv(4295)]),
+    ((4299, false, AND2), &[Tv(4264), Tv(4298)]),
+    ((4302, false, AND2), &[Tv(4264), Tv(4301)]),
+    ((4305, false, AND2), &[Tv(4264), Tv(4304)]),
+    ((4413, false, NAND2), &[Tv(4410), Tv(4412)]),
+    ((4480, false, INV), &[Tv(4461)]),
+    ((4581, false, NAND2), &[Tv(4578), Tv(4580)]),
+    ((4648, false, INV), &[Tv(4629)]),
+    ((4757, false, NAND2), &[Tv(4754), Tv(4756)]),
+    ((4824, false, INV), &[Tv(4805)]),
+    ((4933, false, NAND2), &[Tv(4930), Tv(4932)]),
+    ((5000, false, INV), &[Tv(4981)]),
+    ((5105, false, NAND2), &[Tv(5102), Tv(5104)]),
+    ((5172, false, INV), &[Tv(5153)]),
+    ((5281, false, NAND2), &[Tv(5278), Tv(5280)]),
+    ((5348, false, INV), &[Tv(5329)]),
+];
+
+static LEVEL_11: [((usize, bool, CellType), &[GateInput]); 55] = [
+    ((1257, false, AND2), &[Tv(1197), Tv(1256)]),
+    ((1459, false, AND2), &[Tv(1399), Tv(1458)]),
+    ((1663, false, AND2), &[Tv(1603), Tv(1662)]),
+    ((1851, false, AND2), &[Tv(1791), Tv(1850)]),
+    ((2071, false, AND2), &[Tv(2011), Tv(2070)]),
+    ((2259, false, AND2), &[Tv(2199), Tv(2258)]),
+    ((2435, false, AND2), &[Tv(2375), Tv(2434)]),
+    ((2615, false, AND2), &[Tv(2555), Tv(2614)]),
+    ((2811, false, AND2), &[Tv(2751), Tv(2810)]),
+    ((2991, false, AND2), &[Tv(2931), Tv(2990)]),
+    ((3183, false, AND2), &[Tv(3123), Tv(3182)]),
+    ((3356, false, AND2), &[Tv(3296), Tv(3355)]),
+    ((3529, false, AND2), &[Tv(3469), Tv(3528)]),
+    ((3698, false, AND2), &[Tv(3638), Tv(3697)]),
+    ((3748, false, XOR2), &[Arg(0, 537), Tv(3747)]),
+    ((3751, false, XNOR2), &[Arg(0, 538), Tv(3750)]),
+    ((3754, false, XOR2), &[Arg(0, 539), Tv(3753)]),
+    ((3757, false, XOR2), &[Arg(0, 540), Tv(3756)]),
+    ((3760, false, XOR2), &[Arg(0, 541), Tv(3759)]),
+    ((3763, false, XOR2), &[Arg(0, 542), Tv(3762)]),
+    ((3766, false, XOR2), &[Arg(0, 543), Tv(3765)]),
+    ((3767, false, INV), &[Tv(3745)]),
+    ((3874, false, AND2), &[Tv(3814), Tv(3873)]),
+    ((3924, false, XOR2), &[Arg(0, 577), Tv(3923)]),
+    ((3927, false, XNOR2), &[Arg(0, 578), Tv(3926)]),
+    ((3930, false, XOR2), &[Arg(0, 579), Tv(3929)]),
+    ((3933, false, XOR2), &[Arg(0, 580), Tv(3932)]),
+    ((3936, false, XOR2), &[Arg(0, 581), Tv(3935)]),
+    ((3939, false, XOR2), &[Arg(0, 582), Tv(3938)]),
+    ((3942, false, XOR2), &[Arg(0, 583), Tv(3941)]),
+    ((3943, false, INV), &[Tv(3921)]),
+    ((4046, false, AND2), &[Tv(3986), Tv(4045)]),
+    ((4096, false, XOR2), &[Arg(0, 617), Tv(4095)]),
+    ((4099, false, XNOR2), &[Arg(0, 618), Tv(4098)]),
+    ((4102, false, XOR2), &[Arg(0, 619), Tv(4101)]),
+    ((4105, false, XOR2), &[Arg(0, 620), Tv(4104)]),
+    ((4108, false, XOR2), &[Arg(0, 621), Tv(4107)]),
+    ((4111, false, XOR2), &[Arg(0, 622), Tv(4110)]),
+    ((4114, false, XOR2), &[Arg(0, 623), Tv(4113)]),
+    ((4115, false, INV), &[Tv(4093)]),
+    ((4238, false, AND2), &[Tv(4178), Tv(4237)]),
+    ((4288, false, XOR2), &[Arg(0, 657), Tv(4287)]),
+    ((4291, false, XNOR2), &[Arg(0, 658), Tv(4290)]),
+    ((4294, false, XOR2), &[Arg(0, 659), Tv(4293)]),
+    ((4297, false, XOR2), &[Arg(0, 660), Tv(4296)]),
+    ((4300, false, XOR2), &[Arg(0, 661), Tv(4299)]),
+    ((4303, false, XOR2), &[Arg(0, 662), Tv(4302)]),
+    ((4306, false, XOR2), &[Arg(0, 663), Tv(4305)]),
+    ((4307, false, INV), &[Tv(4285)]),
+    ((4414, false, AND2), &[Tv(4354), Tv(4413)]),
+    ((4582, false, AND2), &[Tv(4522), Tv(4581)]),
+    ((4758, false, AND2), &[Tv(4698), Tv(4757)]),
+    ((4934, false, AND2), &[Tv(4874), Tv(4933)]),
+    ((5106, false, AND2), &[Tv(5046), Tv(5105)]),
+    ((5282, false, AND2), &[Tv(5222), Tv(5281)]),
+];
+
+static LEVEL_12: [((usize, bool, CellType), &[GateInput]); 27] = [
+    ((1258, false, NAND2), &[Tv(1126), Tv(1257)]),
+    ((1460, false, NAND2), &[Tv(1352), Tv(1459)]),
+    ((1664, false, NAND2), &[Tv(1560), Tv(1663)]),
+    ((1852, false, NAND2), &[Tv(1764), Tv(1851)]),
+    ((2072, false, NAND2), &[Tv(1952), Tv(2071)]),
+    ((2260, false, NAND2), &[Tv(2172), Tv(2259)]),
+    ((2436, false, NAND2), &[Tv(2360), Tv(2435)]),
+    ((2616, false, NAND2), &[Tv(2536), Tv(2615)]),
+    ((2812, false, NAND2), &[Tv(2716), Tv(2811)]),
+    ((2992, false, NAND2), &[Tv(2912), Tv(2991)]),
+    ((3184, false, NAND2), &[Tv(3092), Tv(3183)]),
+    ((3357, false, NAND2), &[Tv(3277), Tv(3356)]),
+    ((3530, false, NAND2), &[Tv(3450), Tv(3529)]),
+    ((3699, false, NAND2), &[Tv(3623), Tv(3698)]),
+    ((3768, false, INV), &[Tv(3751)]),
+    ((3875, false, NAND2), &[Tv(3799), Tv(3874)]),
+    ((3944, false, INV), &[Tv(3927)]),
+    ((4047, false, NAND2), &[Tv(3975), Tv(4046)]),
+    ((4116, false, INV), &[Tv(4099)]),
+    ((4239, false, NAND2), &[Tv(4147), Tv(4238)]),
+    ((4308, false, INV), &[Tv(4291)]),
+    ((4415, false, NAND2), &[Tv(4339), Tv(4414)]),
+    ((4583, false, NAND2), &[Tv(4511), Tv(4582)]),
+    ((4759, false, NAND2), &[Tv(4679), Tv(4758)]),
+    ((4935, false, NAND2), &[Tv(4855), Tv(4934)]),
+    ((5107, false, NAND2), &[Tv(5031), Tv(5106)]),
+    ((5283, false, NAND2), &[Tv(5203), Tv(5282)]),
+];
+
+static LEVEL_13: [((usize, bool, CellType), &[GateInput]); 924] = [
+    ((1, true, AND2), &[Arg(0, 1), Tv(1258)]),
+    ((4, true, AND2), &[Arg(0, 4), Tv(1258)]),
+    ((5, true, AND2), &[Arg(0, 5), Tv(1258)]),
+    ((6, true, AND2), &[Arg(0, 6), Tv(1258)]),
+    ((7, true, AND2), &[Arg(0, 7), Tv(1258)]),
+    ((10, true, AND2), &[Arg(0, 10), Tv(1258)]),
+    ((11, true, AND2), &[Arg(0, 11), Tv(1258)]),
+    ((12, true, AND2), &[Arg(0, 12), Tv(1258)]),
+    ((13, true, AND2), &[Arg(0, 13), Tv(1258)]),
+    ((14, true, AND2), &[Arg(0, 14), Tv(1258)]),
+    ((15, true, AND2), &[Arg(0, 15), Tv(1258)]),
+    ((16, true, AND2), &[Tv(1258), Tv(1297)]),
+    ((17, true, AND2), &[Tv(1258), Tv(1300)]),
+    ((19, true, AND2), &[Tv(1258), Tv(1306)]),
+    ((20, true, AND2), &[Tv(1258), Tv(1309)]),
+    ((23, true, AND2), &[Tv(1258), Tv(1318)]),
+    ((24, true, AND2), &[Arg(0, 24), Tv(1258)]),
+    ((26, true, AND2), &[Arg(0, 26), Tv(1258)]),
+    ((27, true, AND2), &[Arg(0, 27), Tv(1258)]),
+    ((28, true, AND2), &[Arg(0, 28), Tv(1258)]),
+    ((29, true, AND2), &[Arg(0, 29), Tv(1258)]),
+    ((30, true, AND2), &[Arg(0, 30), Tv(1258)]),
+    ((31, true, AND2), &[Arg(0, 31), Tv(1258)]),
+    ((33, true, AND2), &[Arg(0, 33), Tv(1258)]),
+    ((34, true, AND2), &[Arg(0, 34), Tv(1258)]),
+    ((37, true, AND2), &[Arg(0, 37), Tv(1258)]),
+    ((38, true, AND2), &[Arg(0, 38), Tv(1258)]),
+    ((39, true, AND2), &[Arg(0, 39), Tv(1258)]),
+    ((40, true, AND2), &[Arg(0, 40), Tv(1460)]),
+    ((41, true, AND2), &[Arg(0, 41), Tv(1460)]),
+    ((42, true, AND2), &[Arg(0, 42), Tv(1460)]),
+    ((43, true, AND2), &[Arg(0, 43), Tv(1460)]),
+    ((44, true, AND2), &[Arg(0, 44), Tv(1460)]),
+    ((45, true, AND2), &[Arg(0, 45), Tv(1460)]),
+    ((46, true, AND2), &[Arg(0, 46), Tv(1460)]),
+    ((47, true, AND2), &[Arg(0, 47), Tv(1460)]),
+    ((49, true, AND2), &[Arg(0, 49), Tv(1460)]),
+    ((52, true, AND2), &[Arg(0, 52), Tv(1460)]),
+    ((53, true, AND2), &[Arg(0, 53), Tv(1460)]),
+    ((54, true, AND2), &[Arg(0, 54), Tv(1460)]),
+    ((55, true, AND2), &[Arg(0, 55), Tv(1460)]),
+    ((57, true, AND2), &[Tv(1460), Tv(1510)]),
+    ((58, true, AND2), &[Tv(1460), Tv(1513)]),
+    ((59, true, AND2), &[Tv(1460), Tv(1516)]),
+    ((60, true, AND2), &[Tv(1460), Tv(1519)]),
+    ((61, true, AND2), &[Tv(1460), Tv(1522)]),
+    ((62, true, AND2), &[Tv(1460), Tv(1525)]),
+    ((63, true, AND2), &[Tv(1460), Tv(1528)]),
+    ((65, true, AND2), &[Arg(0, 65), Tv(1460)]),
+    ((66, true, AND2), &[Arg(0, 66), Tv(1460)]),
+    ((67, true, AND2), &[Arg(0, 67), Tv(1460)]),
+    ((68, true, AND2), &[Arg(0, 68), Tv(1460)]),
+    ((69, true, AND2), &[Arg(0, 69), Tv(1460)]),
+    ((70, true, AND2), &[Arg(0, 70), Tv(1460)]),
+    ((71, true, AND2), &[Arg(0, 71), Tv(1460)]),
+    ((73, true, AND2), &[Arg(0, 73), Tv(1460)]),
+    ((74, true, AND2), &[Arg(0, 74), Tv(1460)]),
+    ((75, true, AND2), &[Arg(0, 75), Tv(1460)]),
+    ((76, true, AND2), &[Arg(0, 76), Tv(1460)]),
+    ((77, true, AND2), &[Arg(0, 77), Tv(1460)]),
+    ((78, true, AND2), &[Arg(0, 78), Tv(1460)]),
+    ((79, true, AND2), &[Arg(0, 79), Tv(1460)]),
+    ((80, true, AND2), &[Arg(0, 80), Tv(1664)]),
+    ((81, true, AND2), &[Arg(0, 81), Tv(1664)]),
+    ((82, true, AND2), &[Arg(0, 82), Tv(1664)]),
+    ((84, true, AND2), &[Arg(0, 84), Tv(1664)]),
+    ((85, true, AND2), &[Arg(0, 85), Tv(1664)]),
+    ((86, true, AND2), &[Arg(0, 86), Tv(1664)]),
+    ((87, true, AND2), &[Arg(0, 87), Tv(1664)]),
+    ((88, true, AND2), &[Arg(0, 88), Tv(1664)]),
+    ((92, true, AND2), &[Arg(0, 92), Tv(1664)]),
+    ((93, true, AND2), &[Arg(0, 93), Tv(1664)]),
+    ((94, true, AND2), &[Arg(0, 94), Tv(1664)]),
+    ((95, true, AND2), &[Arg(0, 95), Tv(1664)]),
+    ((97, true, AND2), &[Tv(1664), Tv(1714)]),
+    ((98, true, AND2), &[Tv(1664), Tv(1717)]),
+    ((99, true, AND2), &[Tv(1664), Tv(1720)]),
+    ((100, true, AND2), &[Tv(1664), Tv(1723)]),
+    ((101, true, AND2), &[Tv(1664), Tv(1726)]),
+    ((102, true, AND2), &[Tv(1664), Tv(1729)]),
+    ((103, true, AND2), &[Tv(1664), Tv(1732)]),
+    ((105, true, AND2), &[Arg(0, 105), Tv(1664)]),
+    ((106, true, AND2), &[Arg(0, 106), Tv(1664)]),
+    ((107, true, AND2), &[Arg(0, 107), Tv(1664)]),
+    ((108, true, AND2), &[Arg(0, 108), Tv(1664)]),
+    ((109, true, AND2), &[Arg(0, 109), Tv(1664)]),
+    ((110, true, AND2), &[Arg(0, 110), Tv(1664)]),
+    ((111, true, AND2), &[Arg(0, 111), Tv(1664)]),
+    ((113, true, AND2), &[Arg(0, 113), Tv(1664)]),
+    ((114, true, AND2), &[Arg(0, 114), Tv(1664)]),
+    ((115, true, AND2), &[Arg(0, 115), Tv(1664)]),
+    ((116, true, AND2), &[Arg(0, 116), Tv(1664)]),
+    ((117, true, AND2), &[Arg(0, 117), Tv(1664)]),
+    ((118, true, AND2), &[Arg(0, 118), Tv(1664)]),
+    ((119, true, AND2), &[Arg(0, 119), Tv(1664)]),
+    ((120, true, AND2), &[Arg(0, 120), Tv(1852)]),
+    ((124, true, AND2), &[Arg(0, 124), Tv(1852)]),
+    ((125, true, AND2), &[Arg(0, 125), Tv(1852)]),
+    ((126, true, AND2), &[Arg(0, 126), Tv(1852)]),
+    ((127, true, AND2), &[Arg(0, 127), Tv(1852)]),
+    ((128, true, AND2), &[Arg(0, 128), Tv(1852)]),
+    ((132, true, AND2), &[Arg(0, 132), Tv(1852)]),
+    ((133, true, AND2), &[Arg(0, 133), Tv(1852)]),
+    ((134, true, AND2), &[Arg(0, 134), Tv(1852)]),
+    ((135, true, AND2), &[Arg(0, 135), Tv(1852)]),
+    ((137, true, AND2), &[Tv(1852), Tv(1902)]),
+    ((138, true, AND2), &[Tv(1852), Tv(1905)]),
+    ((139, true, AND2), &[Tv(1852), Tv(1908)]),
+    ((140, true, AND2), &[Tv(1852), Tv(1911)]),
+    ((141, true, AND2), &[Tv(1852), Tv(1914)]),
+    ((142, true, AND2), &[Tv(1852), Tv(1917)]),
+    ((143, true, AND2), &[Tv(1852), Tv(1920)]),
+    ((145, true, AND2), &[Arg(0, 145), Tv(1852)]),
+    ((146, true, AND2), &[Arg(0, 146), Tv(1852)]),
+    ((147, true, AND2), &[Arg(0, 147), Tv(1852)]),
+    ((148, true, AND2), &[Arg(0, 148), Tv(1852)]),
+    ((149, true, AND2), &[Arg(0, 149), Tv(1852)]),
+    ((150, true, AND2), &[Arg(0, 150), Tv(1852)]),
+    ((151, true, AND2), &[Arg(0, 151), Tv(1852)]),
+    ((153, true, AND2), &[Arg(0, 153), Tv(1852)]),
+    ((154, true, AND2), &[Arg(0, 154), Tv(1852)]),
+    ((155, true, AND2), &[Arg(0, 155), Tv(1852)]),
+    ((156, true, AND2), &[Arg(0, 156), Tv(1852)]),
+    ((157, true, AND2), &[Arg(0, 157), Tv(1852)]),
+    ((158, true, AND2), &[Arg(0, 158), Tv(1852)]),
+    ((159, true, AND2), &[Arg(0, 159), Tv(1852)]),
+    ((161, true, AND2), &[Arg(0, 161), Tv(2072)]),
+    ((165, true, AND2), &[Arg(0, 165), Tv(2072)]),
+    ((166, true, AND2), &[Arg(0, 166), Tv(2072)]),
+    ((167, true, AND2), &[Arg(0, 167), Tv(2072)]),
+    ((168, true, AND2), &[Arg(0, 168), Tv(2072)]),
+    ((169, true, AND2), &[Arg(0, 169), Tv(2072)]),
+    ((171, true, AND2), &[Arg(0, 171), Tv(2072)]),
+    ((173, true, AND2), &[Arg(0, 173), Tv(2072)]),
+    ((174, true, AND2), &[Arg(0, 174), Tv(2072)]),
+    ((175, true, AND2), &[Arg(0, 175), Tv(2072)]),
+    ((177, true, AND2), &[Tv(2072), Tv(2122)]),
+    ((178, true, AND2), &[Tv(2072), Tv(2125)]),
+    ((179, true, AND2), &[Tv(2072), Tv(2128)]),
+    ((180, true, AND2), &[Tv(2072), Tv(2131)]),
+    ((181, true, AND2), &[Tv(2072), Tv(2134)]),
+    ((182, true, AND2), &[Tv(2072), Tv(2137)]),
+    ((183, true, AND2), &[Tv(2072), Tv(2140)]),
+    ((185, true, AND2), &[Arg(0, 185), Tv(2072)]),
+    ((186, true, AND2), &[Arg(0, 186), Tv(2072)]),
+    ((187, true, AND2), &[Arg(0, 187), Tv(2072)]),
+    ((188, true, AND2), &[Arg(0, 188), Tv(2072)]),
+    ((189, true, AND2), &[Arg(0, 189), Tv(2072)]),
+    ((190, true, AND2), &[Arg(0, 190), Tv(2072)]),
+    ((191, true, AND2), &[Arg(0, 191), Tv(2072)]),
+    ((193, true, AND2), &[Arg(0, 193), Tv(2072)]),
+    ((194, true, AND2), &[Arg(0, 194), Tv(2072)]),
+    ((195, true, AND2), &[Arg(0, 195), Tv(2072)]),
+    ((196, true, AND2), &[Arg(0, 196), Tv(2072)]),
+    ((197, true, AND2), &[Arg(0, 197), Tv(2072)]),
+    ((198, true, AND2), &[Arg(0, 198), Tv(2072)]),
+    ((199, true, AND2), &[Arg(0, 199), Tv(2072)]),
+    ((201, true, AND2), &[Arg(0, 201), Tv(2260)]),
+    ((202, true, AND2), &[Arg(0, 202), Tv(2260)]),
+    ((205, true, AND2), &[Arg(0, 205), Tv(2260)]),
+    ((206, true, AND2), &[Arg(0, 206), Tv(2260)]),
+    ((207, true, AND2), &[Arg(0, 207), Tv(2260)]),
+    ((212, true, AND2), &[Arg(0, 212), Tv(2260)]),
+    ((213, true, AND2), &[Arg(0, 213), Tv(2260)]),
+    ((214, true, AND2), &[Arg(0, 214), Tv(2260)]),
+    ((215, true, AND2), &[Arg(0, 215), Tv(2260)]),
+    ((217, true, AND2), &[Tv(2260), Tv(2310)]),
+    ((218, true, AND2), &[Tv(2260), Tv(2313)]),
+    ((219, true, AND2), &[Tv(2260), Tv(2316)]),
+    ((220, true, AND2), &[Tv(2260), Tv(2319)]),
+    ((221, true, AND2), &[Tv(2260), Tv(2322)]),
+    ((222, true, AND2), &[Tv(2260), Tv(2325)]),
+    ((223, true, AND2), &[Tv(2260), Tv(2328)]),
+    ((225, true, AND2), &[Arg(0, 225), Tv(2260)]),
+    ((226, true, AND2), &[Arg(0, 226), Tv(2260)]),
+    ((227, true, AND2), &[Arg(0, 227), Tv(2260)]),
+    ((228, true, AND2), &[Arg(0, 228), Tv(2260)]),
+    ((229, true, AND2), &[Arg(0, 229), Tv(2260)]),
+    ((230, true, AND2), &[Arg(0, 230), Tv(2260)]),
+    ((231, true, AND2), &[Arg(0, 231), Tv(2260)]),
+    ((233, true, AND2), &[Arg(0, 233), Tv(2260)]),
+    ((234, true, AND2), &[Arg(0, 234), Tv(2260)]),
+    ((235, true, AND2), &[Arg(0, 235), Tv(2260)]),
+    ((236, true, AND2), &[Arg(0, 236), Tv(2260)]),
+    ((237, true, AND2), &[Arg(0, 237), Tv(2260)]),
+    ((238, true, AND2), &[Arg(0, 238), Tv(2260)]),
+    ((239, true, AND2), &[Arg(0, 239), Tv(2260)]),
+    ((240, true, AND2), &[Arg(0, 240), Tv(2436)]),
+    ((241, true, AND2), &[Arg(0, 241), Tv(2436)]),
+    ((242, true, AND2), &[Arg(0, 242), Tv(2436)]),
+    ((245, true, AND2), &[Arg(0, 245), Tv(2436)]),
+    ((246, true, AND2), &[Arg(0, 246), Tv(2436)]),
+    ((247, true, AND2), &[Arg(0, 247), Tv(2436)]),
+    ((248, true, AND2), &[Arg(0, 248), Tv(2436)]),
+    ((249, true, AND2), &[Arg(0, 249), Tv(2436)]),
+    ((251, true, AND2), &[Arg(0, 251), Tv(2436)]),
+    ((253, true, AND2), &[Arg(0, 253), Tv(2436)]),
+    ((254, true, AND2), &[Arg(0, 254), Tv(2436)]),
+    ((255, true, AND2), &[Arg(0, 255), Tv(2436)]),
+    ((257, true, AND2), &[Tv(2436), Tv(2486)]),
+    ((258, true, AND2), &[Tv(2436), Tv(2489)]),
+    ((259, true, AND2), &[Tv(2436), Tv(2492)]),
+    ((260, true, AND2), &[Tv(2436), Tv(2495)]),
+    ((261, true, AND2), &[Tv(2436), Tv(2498)]),
+    ((262, true, AND2), &[Tv(2436), Tv(2501)]),
+    ((263, true, AND2), &[Tv(2436), Tv(2504)]),
+    ((265, true, AND2), &[Arg(0, 265), Tv(2436)]),
+    ((266, true, AND2), &[Arg(0, 266), Tv(2436)]),
+    ((267, true, AND2), &[Arg(0, 267), Tv(2436)]),
+    ((268, true, AND2), &[Arg(0, 268), Tv(2436)]),
+    ((269, true, AND2), &[Arg(0, 269), Tv(2436)]),
+    ((270, true, AND2), &[Arg(0, 270), Tv(2436)]),
+    ((271, true, AND2), &[Arg(0, 271), Tv(2436)]),
+    ((273, true, AND2), &[Arg(0, 273), Tv(2436)]),
+    ((274, true, AND2), &[Arg(0, 274), Tv(2436)]),
+    ((275, true, AND2), &[Arg(0, 275), Tv(2436)]),
+    ((276, true, AND2), &[Arg(0, 276), Tv(2436)]),
+    ((277, true, AND2), &[Arg(0, 277), Tv(2436)]),
+    ((278, true, AND2), &[Arg(0, 278), Tv(2436)]),
+    ((279, true, AND2), &[Arg(0, 279), Tv(2436)]),
+    ((281, true, AND2), &[Arg(0, 281), Tv(2616)]),
+    ((284, true, AND2), &[Arg(0, 284), Tv(2616)]),
+    ((285, true, AND2), &[Arg(0, 285), Tv(2616)]),
+    ((286, true, AND2), &[Arg(0, 286), Tv(2616)]),
+    ((287, true, AND2), &[Arg(0, 287), Tv(2616)]),
+    ((288, true, AND2), &[Arg(0, 288), Tv(2616)]),
+    ((291, true, AND2), &[Arg(0, 291), Tv(2616)]),
+    ((293, true, AND2), &[Arg(0, 293), Tv(2616)]),
+    ((294, true, AND2), &[Arg(0, 294), Tv(2616)]),
+    ((295, true, AND2), &[Arg(0, 295), Tv(2616)]),
+    ((297, true, AND2), &[Tv(2616), Tv(2666)]),
+    ((298, true, AND2), &[Tv(2616), Tv(2669)]),
+    ((299, true, AND2), &[Tv(2616), Tv(2672)]),
+    ((300, true, AND2), &[Tv(2616), Tv(2675)]),
+    ((301, true, AND2), &[Tv(2616), Tv(2678)]),
+    ((302, true, AND2), &[Tv(2616), Tv(2681)]),
+    ((303, true, AND2), &[Tv(2616), Tv(2684)]),
+    ((305, true, AND2), &[Arg(0, 305), Tv(2616)]),
+    ((306, true, AND2), &[Arg(0, 306), Tv(2616)]),
+    ((307, true, AND2), &[Arg(0, 307), Tv(2616)]),
+    ((308, true, AND2), &[Arg(0, 308), Tv(2616)]),
+    ((309, true, AND2), &[Arg(0, 309), Tv(2616)]),
+    ((310, true, AND2), &[Arg(0, 310), Tv(2616)]),
+    ((311, true, AND2), &[Arg(0, 311), Tv(2616)]),
+    ((313, true, AND2), &[Arg(0, 313), Tv(2616)]),
+    ((314, true, AND2), &[Arg(0, 314), Tv(2616)]),
+    ((315, true, AND2), &[Arg(0, 315), Tv(2616)]),
+    ((316, true, AND2), &[Arg(0, 316), Tv(2616)]),
+    ((317, true, AND2), &[Arg(0, 317), Tv(2616)]),
+    ((318, true, AND2), &[Arg(0, 318), Tv(2616)]),
+    ((319, true, AND2), &[Arg(0, 319), Tv(2616)]),
+    ((321, true, AND2), &[Arg(0, 321), Tv(2812)]),
+    ((322, true, AND2), &[Arg(0, 322), Tv(2812)]),
+    ((323, true, AND2), &[Arg(0, 323), Tv(2812)]),
+    ((324, true, AND2), &[Arg(0, 324), Tv(2812)]),
+    ((325, true, AND2), &[Arg(0, 325), Tv(2812)]),
+    ((326, true, AND2), &[Arg(0, 326), Tv(2812)]),
+    ((327, true, AND2), &[Arg(0, 327), Tv(2812)]),
+    ((328, true, AND2), &[Arg(0, 328), Tv(2812)]),
+    ((329, true, AND2), &[Arg(0, 329), Tv(2812)]),
+    ((330, true, AND2), &[Arg(0, 330), Tv(2812)]),
+    ((333, true, AND2), &[Arg(0, 333), Tv(2812)]),
+    ((334, true, AND2), &[Arg(0, 334), Tv(2812)]),
+    ((335, true, AND2), &[Arg(0, 335), Tv(2812)]),
+    ((337, true, AND2), &[Tv(2812), Tv(2862)]),
+    ((338, true, AND2), &[Tv(2812), Tv(2865)]),
+    ((339, true, AND2), &[Tv(2812), Tv(2868)]),
+    ((340, true, AND2), &[Tv(2812), Tv(2871)]),
+    ((341, true, AND2), &[Tv(2812), Tv(2874)]),
+    ((342, true, AND2), &[Tv(2812), Tv(2877)]),
+    ((343, true, AND2), &[Tv(2812), Tv(2880)]),
+    ((345, true, AND2), &[Arg(0, 345), Tv(2812)]),
+    ((346, true, AND2), &[Arg(0, 346), Tv(2812)]),
+    ((347, true, AND2), &[Arg(0, 347), Tv(2812)]),
+    ((348, true, AND2), &[Arg(0, 348), Tv(2812)]),
+    ((349, true, AND2), &[Arg(0, 349), Tv(2812)]),
+    ((350, true, AND2), &[Arg(0, 350), Tv(2812)]),
+    ((351, true, AND2), &[Arg(0, 351), Tv(2812)]),
+    ((353, true, AND2), &[Arg(0, 353), Tv(2812)]),
+    ((354, true, AND2), &[Arg(0, 354), Tv(2812)]),
+    ((355, true, AND2), &[Arg(0, 355), Tv(2812)]),
+    ((356, true, AND2), &[Arg(0, 356), Tv(2812)]),
+    ((357, true, AND2), &[Arg(0, 357), Tv(2812)]),
+    ((358, true, AND2), &[Arg(0, 358), Tv(2812)]),
+    ((359, true, AND2), &[Arg(0, 359), Tv(2812)]),
+    ((360, true, AND2), &[Arg(0, 360), Tv(2992)]),
+    ((362, true, AND2), &[Arg(0, 362), Tv(2992)]),
+    ((363, true, AND2), &[Arg(0, 363), Tv(2992)]),
+    ((364, true, AND2), &[Arg(0, 364), Tv(2992)]),
+    ((365, true, AND2), &[Arg(0, 365), Tv(2992)]),
+    ((366, true, AND2), &[Arg(0, 366), Tv(2992)]),
+    ((367, true, AND2), &[Arg(0, 367), Tv(2992)]),
+    ((368, true, AND2), &[Arg(0, 368), Tv(2992)]),
+    ((369, true, AND2), &[Arg(0, 369), Tv(2992)]),
+    ((370, true, AND2), &[Arg(0, 370), Tv(2992)]),
+    ((373, true, AND2), &[Arg(0, 373), Tv(2992)]),
+    ((374, true, AND2), &[Arg(0, 374), Tv(2992)]),
+    ((375, true, AND2), &[Arg(0, 375), Tv(2992)]),
+    ((377, true, AND2), &[Tv(2992), Tv(3042)]),
+    ((378, true, AND2), &[Tv(2992), Tv(3045)]),
+    ((379, true, AND2), &[Tv(2992), Tv(3048)]),
+    ((380, true, AND2), &[Tv(2992), Tv(3051)]),
+    ((381, true, AND2), &[Tv(2992), Tv(3054)]),
+    ((382, true, AND2), &[Tv(2992), Tv(3057)]),
+    ((383, true, AND2), &[Tv(2992), Tv(3060)]),
+    ((385, true, AND2), &[Arg(0, 385), Tv(2992)]),
+    ((386, true, AND2), &[Arg(0, 386), Tv(2992)]),
+    ((387, true, AND2), &[Arg(0, 387), Tv(2992)]),
+    ((388, true, AND2), &[Arg(0, 388), Tv(2992)]),
+    ((389, true, AND2), &[Arg(0, 389), Tv(2992)]),
+    ((390, true, AND2), &[Arg(0, 390), Tv(2992)]),
+    ((391, true, AND2), &[Arg(0, 391), Tv(2992)]),
+    ((393, true, AND2), &[Arg(0, 393), Tv(2992)]),
+    ((394, true, AND2), &[Arg(0, 394), Tv(2992)]),
+    ((395, true, AND2), &[Arg(0, 395), Tv(2992)]),
+    ((396, true, AND2), &[Arg(0, 396), Tv(2992)]),
+    ((397, true, AND2), &[Arg(0, 397), Tv(2992)]),
+    ((398, true, AND2), &[Arg(0, 398), Tv(2992)]),
+    ((399, true, AND2), &[Arg(0, 399), Tv(2992)]),
+    ((402, true, AND2), &[Arg(0, 402), Tv(3184)]),
+    ((405, true, AND2), &[Arg(0, 405), Tv(3184)]),
+    ((406, true, AND2), &[Arg(0, 406), Tv(3184)]),
+    ((407, true, AND2), &[Arg(0, 407), Tv(3184)]),
+    ((411, true, AND2), &[Arg(0, 411), Tv(3184)]),
+    ((412, true, AND2), &[Arg(0, 412), Tv(3184)]),
+    ((413, true, AND2), &[Arg(0, 413), Tv(3184)]),
+    ((414, true, AND2), &[Arg(0, 414), Tv(3184)]),
+    ((415, true, AND2), &[Arg(0, 415), Tv(3184)]),
+    ((420, true, AND2), &[Tv(3184), Tv(3233)]),
+    ((421, true, AND2), &[Tv(3184), Tv(3236)]),
+    ((422, true, AND2), &[Tv(3184), Tv(3239)]),
+    ((423, true, AND2), &[Tv(3184), Tv(3242)]),
+    ((425, true, AND2), &[Arg(0, 425), Tv(3184)]),
+    ((426, true, AND2), &[Arg(0, 426), Tv(3184)]),
+    ((427, true, AND2), &[Arg(0, 427), Tv(3184)]),
+    ((428, true, AND2), &[Arg(0, 428), Tv(3184)]),
+    ((429, true, AND2), &[Arg(0, 429), Tv(3184)]),
+    ((430, true, AND2), &[Arg(0, 430), Tv(3184)]),
+    ((431, true, AND2), &[Arg(0, 431), Tv(3184)]),
+    ((433, true, AND2), &[Arg(0, 433), Tv(3184)]),
+    ((435, true, AND2), &[Arg(0, 435), Tv(3184)]),
+    ((436, true, AND2), &[Arg(0, 436), Tv(3184)]),
+    ((437, true, AND2), &[Arg(0, 437), Tv(3184)]),
+    ((438, true, AND2), &[Arg(0, 438), Tv(3184)]),
+    ((439, true, AND2), &[Arg(0, 439), Tv(3184)]),
+    ((442, true, AND2), &[Arg(0, 442), Tv(3357)]),
+    ((445, true, AND2), &[Arg(0, 445), Tv(3357)]),
+    ((446, true, AND2), &[Arg(0, 446), Tv(3357)]),
+    ((447, true, AND2), &[Arg(0, 447), Tv(3357)]),
+    ((450, true, AND2), &[Arg(0, 450), Tv(3357)]),
+    ((452, true, AND2), &[Arg(0, 452), Tv(3357)]),
+    ((453, true, AND2), &[Arg(0, 453), Tv(3357)]),
+    ((454, true, AND2), &[Arg(0, 454), Tv(3357)]),
+    ((455, true, AND2), &[Arg(0, 455), Tv(3357)]),
+    ((460, true, AND2), &[Tv(3357), Tv(3406)]),
+    ((461, true, AND2), &[Tv(3357), Tv(3409)]),
+    ((462, true, AND2), &[Tv(3357), Tv(3412)]),
+    ((463, true, AND2), &[Tv(3357), Tv(3415)]),
+    ((465, true, AND2), &[Arg(0, 465), Tv(3357)]),
+    ((466, true, AND2), &[Arg(0, 466), Tv(3357)]),
+    ((467, true, AND2), &[Arg(0, 467), Tv(3357)]),
+    ((468, true, AND2), &[Arg(0, 468), Tv(3357)]),
+    ((469, true, AND2), &[Arg(0, 469), Tv(3357)]),
+    ((470, true, AND2), &[Arg(0, 470), Tv(3357)]),
+    ((471, true, AND2), &[Arg(0, 471), Tv(3357)]),
+    ((473, true, AND2), &[Arg(0, 473), Tv(3357)]),
+    ((475, true, AND2), &[Arg(0, 475), Tv(3357)]),
+    ((476, true, AND2), &[Arg(0, 476), Tv(3357)]),
+    ((477, true, AND2), &[Arg(0, 477), Tv(3357)]),
+    ((478, true, AND2), &[Arg(0, 478), Tv(3357)]),
+    ((479, true, AND2), &[Arg(0, 479), Tv(3357)]),
+    ((482, true, AND2), &[Arg(0, 482), Tv(3530)]),
+    ((483, true, AND2), &[Arg(0, 483), Tv(3530)]),
+    ((484, true, AND2), &[Arg(0, 484), Tv(3530)]),
+    ((485, true, AND2), &[Arg(0, 485), Tv(3530)]),
+    ((486, true, AND2), &[Arg(0, 486), Tv(3530)]),
+    ((487, true, AND2), &[Arg(0, 487), Tv(3530)]),
+    ((488, true, AND2), &[Arg(0, 488), Tv(3530)]),
+    ((492, true, AND2), &[Arg(0, 492), Tv(3530)]),
+    ((493, true, AND2), &[Arg(0, 493), Tv(3530)]),
+    ((494, true, AND2), &[Arg(0, 494), Tv(3530)]),
+    ((495, true, AND2), &[Arg(0, 495), Tv(3530)]),
+    ((500, true, AND2), &[Tv(3530), Tv(3579)]),
+    ((501, true, AND2), &[Tv(3530), Tv(3582)]),
+    ((502, true, AND2), &[Tv(3530), Tv(3585)]),
+    ((503, true, AND2), &[Tv(3530), Tv(3588)]),
+    ((505, true, AND2), &[Arg(0, 505), Tv(3530)]),
+    ((506, true, AND2), &[Arg(0, 506), Tv(3530)]),
+    ((507, true, AND2), &[Arg(0, 507), Tv(3530)]),
+    ((508, true, AND2), &[Arg(0, 508), Tv(3530)]),
+    ((509, true, AND2), &[Arg(0, 509), Tv(3530)]),
+    ((510, true, AND2), &[Arg(0, 510), Tv(3530)]),
+    ((511, true, AND2), &[Arg(0, 511), Tv(3530)]),
+    ((513, true, AND2), &[Arg(0, 513), Tv(3530)]),
+    ((515, true, AND2), &[Arg(0, 515), Tv(3530)]),
+    ((516, true, AND2), &[Arg(0, 516), Tv(3530)]),
+    ((517, true, AND2), &[Arg(0, 517), Tv(3530)]),
+    ((518, true, AND2), &[Arg(0, 518), Tv(3530)]),
+    ((519, true, AND2), &[Arg(0, 519), Tv(3530)]),
+    ((522, true, AND2), &[Arg(0, 522), Tv(3699)]),
+    ((524, true, AND2), &[Arg(0, 524), Tv(3699)]),
+    ((525, true, AND2), &[Arg(0, 525), Tv(3699)]),
+    ((526, true, AND2), &[Arg(0, 526), Tv(3699)]),
+    ((527, true, AND2), &[Arg(0, 527), Tv(3699)]),
+    ((528, true, AND2), &[Arg(0, 528), Tv(3699)]),
+    ((529, true, AND2), &[Arg(0, 529), Tv(3699)]),
+    ((531, true, AND2), &[Arg(0, 531), Tv(3699)]),
+    ((533, true, AND2), &[Arg(0, 533), Tv(3699)]),
+    ((534, true, AND2), &[Arg(0, 534), Tv(3699)]),
+    ((535, true, AND2), &[Arg(0, 535), Tv(3699)]),
+    ((537, true, AND2), &[Tv(3699), Tv(3748)]),
+    ((539, true, AND2), &[Tv(3699), Tv(3754)]),
+    ((540, true, AND2), &[Tv(3699), Tv(3757)]),
+    ((541, true, AND2), &[Tv(3699), Tv(3760)]),
+    ((542, true, AND2), &[Tv(3699), Tv(3763)]),
+    ((543, true, AND2), &[Tv(3699), Tv(3766)]),
+    ((545, true, AND2), &[Arg(0, 545), Tv(3699)]),
+    ((546, true, AND2), &[Arg(0, 546), Tv(3699)]),
+    ((547, true, AND2), &[Arg(0, 547), Tv(3699)]),
+    ((548, true, AND2), &[Arg(0, 548), Tv(3699)]),
+    ((549, true, AND2), &[Arg(0, 549), Tv(3699)]),
+    ((550, true, AND2), &[Arg(0, 550), Tv(3699)]),
+    ((551, true, AND2), &[Arg(0, 551), Tv(3699)]),
+    ((552, true, AND2), &[Arg(0, 552), Tv(3699)]),
+    ((554, true, AND2), &[Arg(0, 554), Tv(3699)]),
+    ((555, true, AND2), &[Arg(0, 555), Tv(3699)]),
+    ((556, true, AND2), &[Arg(0, 556), Tv(3699)]),
+    ((557, true, AND2), &[Arg(0, 557), Tv(3699)]),
+    ((558, true, AND2), &[Arg(0, 558), Tv(3699)]),
+    ((559, true, AND2), &[Arg(0, 559), Tv(3699)]),
+    ((562, true, AND2), &[Arg(0, 562), Tv(3875)]),
+    ((563, true, AND2), &[Arg(0, 563), Tv(3875)]),
+    ((565, true, AND2), &[Arg(0, 565), Tv(3875)]),
+    ((566, true, AND2), &[Arg(0, 566), Tv(3875)]),
+    ((567, true, AND2), &[Arg(0, 567), Tv(3875)]),
+    ((568, true, AND2), &[Arg(0, 568), Tv(3875)]),
+    ((569, true, AND2), &[Arg(0, 569), Tv(3875)]),
+    ((571, true, AND2), &[Arg(0, 571), Tv(3875)]),
+    ((573, true, AND2), &[Arg(0, 573), Tv(3875)]),
+    ((574, true, AND2), &[Arg(0, 574), Tv(3875)]),
+    ((575, true, AND2), &[Arg(0, 575), Tv(3875)]),
+    ((577, true, AND2), &[Tv(3875), Tv(3924)]),
+    ((579, true, AND2), &[Tv(3875), Tv(3930)]),
+    ((580, true, AND2), &[Tv(3875), Tv(3933)]),
+    ((581, true, AND2), &[Tv(3875), Tv(3936)]),
+    ((582, true, AND2), &[Tv(3875), Tv(3939)]),
+    ((583, true, AND2), &[Tv(3875), Tv(3942)]),
+    ((585, true, AND2), &[Arg(0, 585), Tv(3875)]),
+    ((586, true, AND2), &[Arg(0, 586), Tv(3875)]),
+    ((587, true, AND2), &[Arg(0, 587), Tv(3875)]),
+    ((588, true, AND2), &[Arg(0, 588), Tv(3875)]),
+    ((589, true, AND2), &[Arg(0, 589), Tv(3875)]),
+    ((590, true, AND2), &[Arg(0, 590), Tv(3875)]),
+    ((591, true, AND2), &[Arg(0, 591), Tv(3875)]),
+    ((592, true, AND2), &[Arg(0, 592), Tv(3875)]),
+    ((594, true, AND2), &[Arg(0, 594), Tv(3875)]),
+    ((595, true, AND2), &[Arg(0, 595), Tv(3875)]),
+    ((596, true, AND2), &[Arg(0, 596), Tv(3875)]),
+    ((597, true, AND2), &[Arg(0, 597), Tv(3875)]),
+    ((598, true, AND2), &[Arg(0, 598), Tv(3875)]),
+    ((599, true, AND2), &[Arg(0, 599), Tv(3875)]),
+    ((602, true, AND2), &[Arg(0, 602), Tv(4047)]),
+    ((605, true, AND2), &[Arg(0, 605), Tv(4047)]),
+    ((606, true, AND2), &[Arg(0, 606), Tv(4047)]),
+    ((607, true, AND2), &[Arg(0, 607), Tv(4047)]),
+    ((608, true, AND2), &[Arg(0, 608), Tv(4047)]),
+    ((609, true, AND2), &[Arg(0, 609), Tv(4047)]),
+    ((611, true, AND2), &[Arg(0, 611), Tv(4047)]),
+    ((613, true, AND2), &[Arg(0, 613), Tv(4047)]),
+    ((614, true, AND2), &[Arg(0, 614), Tv(4047)]),
+    ((615, true, AND2), &[Arg(0, 615), Tv(4047)]),
+    ((617, true, AND2), &[Tv(4047), Tv(4096)]),
+    ((619, true, AND2), &[Tv(4047), Tv(4102)]),
+    ((620, true, AND2), &[Tv(4047), Tv(4105)]),
+    ((621, true, AND2), &[Tv(4047), Tv(4108)]),
+    ((622, true, AND2), &[Tv(4047), Tv(4111)]),
+    ((623, true, AND2), &[Tv(4047), Tv(4114)]),
+    ((625, true, AND2), &[Arg(0, 625), Tv(4047)]),
+    ((626, true, AND2), &[Arg(0, 626), Tv(4047)]),
+    ((627, true, AND2), &[Arg(0, 627), Tv(4047)]),
+    ((628, true, AND2), &[Arg(0, 628), Tv(4047)]),
+    ((629, true, AND2), &[Arg(0, 629), Tv(4047)]),
+    ((630, true, AND2), &[Arg(0, 630), Tv(4047)]),
+    ((631, true, AND2), &[Arg(0, 631), Tv(4047)]),
+    ((632, true, AND2), &[Arg(0, 632), Tv(4047)]),
+    ((634, true, AND2), &[Arg(0, 634), Tv(4047)]),
+    ((635, true, AND2), &[Arg(0, 635), Tv(4047)]),
+    ((636, true, AND2), &[Arg(0, 636), Tv(4047)]),
+    ((637, true, AND2), &[Arg(0, 637), Tv(4047)]),
+    ((638, true, AND2), &[Arg(0, 638), Tv(4047)]),
+    ((639, true, AND2), &[Arg(0, 639), Tv(4047)]),
+    ((641, true, AND2), &[Arg(0, 641), Tv(4239)]),
+    ((643, true, AND2), &[Arg(0, 643), Tv(4239)]),
+    ((644, true, AND2), &[Arg(0, 644), Tv(4239)]),
+    ((645, true, AND2), &[Arg(0, 645), Tv(4239)]),
+    ((646, true, AND2), &[Arg(0, 646), Tv(4239)]),
+    ((647, true, AND2), &[Arg(0, 647), Tv(4239)]),
+    ((651, true, AND2), &[Arg(0, 651), Tv(4239)]),
+    ((653, true, AND2), &[Arg(0, 653), Tv(4239)]),
+    ((654, true, AND2), &[Arg(0, 654), Tv(4239)]),
+    ((655, true, AND2), &[Arg(0, 655), Tv(4239)]),
+    ((657, true, AND2), &[Tv(4239), Tv(4288)]),
+    ((659, true, AND2), &[Tv(4239), Tv(4294)]),
+    ((660, true, AND2), &[Tv(4239), Tv(4297)]),
+    ((661, true, AND2), &[Tv(4239), Tv(4300)]),
+    ((662, true, AND2), &[Tv(4239), Tv(4303)]),
+    ((663, true, AND2), &[Tv(4239), Tv(4306)]),
+    ((665, true, AND2), &[Arg(0, 665), Tv(4239)]),
+    ((666, true, AND2), &[Arg(0, 666), Tv(4239)]),
+    ((667, true, AND2), &[Arg(0, 667), Tv(4239)]),
+    ((668, true, AND2), &[Arg(0, 668), Tv(4239)]),
+    ((669, true, AND2), &[Arg(0, 669), Tv(4239)]),
+    ((670, true, AND2), &[Arg(0, 670), Tv(4239)]),
+    ((671, true, AND2), &[Arg(0, 671), Tv(4239)]),
+    ((672, true, AND2), &[Arg(0, 672), Tv(4239)]),
+    ((674, true, AND2), &[Arg(0, 674), Tv(4239)]),
+    ((675, true, AND2), &[Arg(0, 675), Tv(4239)]),
+    ((676, true, AND2), &[Arg(0, 676), Tv(4239)]),
+    ((677, true, AND2), &[Arg(0, 677), Tv(4239)]),
+    ((678, true, AND2), &[Arg(0, 678), Tv(4239)]),
+    ((679, true, AND2), &[Arg(0, 679), Tv(4239)]),
+    ((680, true, AND2), &[Arg(0, 680), Tv(4415)]),
+    ((681, true, AND2), &[Arg(0, 681), Tv(4415)]),
+    ((682, true, AND2), &[Arg(0, 682), Tv(4415)]),
+    ((684, true, AND2), &[Arg(0, 684), Tv(4415)]),
+    ((685, true, AND2), &[Arg(0, 685), Tv(4415)]),
+    ((686, true, AND2), &[Arg(0, 686), Tv(4415)]),
+    ((687, true, AND2), &[Arg(0, 687), Tv(4415)]),
+    ((688, true, AND2), &[Arg(0, 688), Tv(4415)]),
+    ((689, true, AND2), &[Arg(0, 689), Tv(4415)]),
+    ((690, true, AND2), &[Arg(0, 690), Tv(4415)]),
+    ((692, true, AND2), &[Arg(0, 692), Tv(4415)]),
+    ((693, true, AND2), &[Arg(0, 693), Tv(4415)]),
+    ((694, true, AND2), &[Arg(0, 694), Tv(4415)]),
+    ((695, true, AND2), &[Arg(0, 695), Tv(4415)]),
+    ((696, true, AND2), &[Tv(4415), Tv(4458)]),
+    ((698, true, AND2), &[Tv(4415), Tv(4464)]),
+    ((699, true, AND2), &[Tv(4415), Tv(4467)]),
+    ((700, true, AND2), &[Tv(4415), Tv(4470)]),
+    ((701, true, AND2), &[Tv(4415), Tv(4473)]),
+    ((702, true, AND2), &[Tv(4415), Tv(4476)]),
+    ((703, true, AND2), &[Tv(4415), Tv(4479)]),
+    ((705, true, AND2), &[Arg(0, 705), Tv(4415)]),
+    ((706, true, AND2), &[Arg(0, 706), Tv(4415)]),
+    ((707, true, AND2), &[Arg(0, 707), Tv(4415)]),
+    ((708, true, AND2), &[Arg(0, 708), Tv(4415)]),
+    ((709, true, AND2), &[Arg(0, 709), Tv(4415)]),
+    ((710, true, AND2), &[Arg(0, 710), Tv(4415)]),
+    ((711, true, AND2), &[Arg(0, 711), Tv(4415)]),
+    ((712, true, AND2), &[Arg(0, 712), Tv(4415)]),
+    ((714, true, AND2), &[Arg(0, 714), Tv(4415)]),
+    ((715, true, AND2), &[Arg(0, 715), Tv(4415)]),
+    ((716, true, AND2), &[Arg(0, 716), Tv(4415)]),
+    ((717, true, AND2), &[Arg(0, 717), Tv(4415)]),
+    ((718, true, AND2), &[Arg(0, 718), Tv(4415)]),
+    ((719, true, AND2), &[Arg(0, 719), Tv(4415)]),
+    ((721, true, AND2), &[Arg(0, 721), Tv(4583)]),
+    ((725, true, AND2), &[Arg(0, 725), Tv(4583)]),
+    ((726, true, AND2), &[Arg(0, 726), Tv(4583)]),
+    ((727, true, AND2), &[Arg(0, 727), Tv(4583)]),
+    ((732, true, AND2), &[Arg(0, 732), Tv(4583)]),
+    ((733, true, AND2), &[Arg(0, 733), Tv(4583)]),
+    ((734, true, AND2), &[Arg(0, 734), Tv(4583)]),
+    ((735, true, AND2), &[Arg(0, 735), Tv(4583)]),
+    ((736, true, AND2), &[Tv(4583), Tv(4626)]),
+    ((738, true, AND2), &[Tv(4583), Tv(4632)]),
+    ((739, true, AND2), &[Tv(4583), Tv(4635)]),
+    ((740, true, AND2), &[Tv(4583), Tv(4638)]),
+    ((741, true, AND2), &[Tv(4583), Tv(4641)]),
+    ((742, true, AND2), &[Tv(4583), Tv(4644)]),
+    ((743, true, AND2), &[Tv(4583), Tv(4647)]),
+    ((745, true, AND2), &[Arg(0, 745), Tv(4583)]),
+    ((746, true, AND2), &[Arg(0, 746), Tv(4583)]),
+    ((747, true, AND2), &[Arg(0, 747), Tv(4583)]),
+    ((748, true, AND2), &[Arg(0, 748), Tv(4583)]),
+    ((749, true, AND2), &[Arg(0, 749), Tv(4583)]),
+    ((750, true, AND2), &[Arg(0, 750), Tv(4583)]),
+    ((751, true, AND2), &[Arg(0, 751), Tv(4583)]),
+    ((752, true, AND2), &[Arg(0, 752), Tv(4583)]),
+    ((754, true, AND2), &[Arg(0, 754), Tv(4583)]),
+    ((755, true, AND2), &[Arg(0, 755), Tv(4583)]),
+    ((756, true, AND2), &[Arg(0, 756), Tv(4583)]),
+    ((757, true, AND2), &[Arg(0, 757), Tv(4583)]),
+    ((758, true, AND2), &[Arg(0, 758), Tv(4583)]),
+    ((759, true, AND2), &[Arg(0, 759), Tv(4583)]),
+    ((761, true, AND2), &[Arg(0, 761), Tv(4759)]),
+    ((763, true, AND2), &[Arg(0, 763), Tv(4759)]),
+    ((764, true, AND2), &[Arg(0, 764), Tv(4759)]),
+    ((765, true, AND2), &[Arg(0, 765), Tv(4759)]),
+    ((766, true, AND2), &[Arg(0, 766), Tv(4759)]),
+    ((767, true, AND2), &[Arg(0, 767), Tv(4759)]),
+    ((768, true, AND2), &[Arg(0, 768), Tv(4759)]),
+    ((769, true, AND2), &[Arg(0, 769), Tv(4759)]),
+    ((770, true, AND2), &[Arg(0, 770), Tv(4759)]),
+    ((771, true, AND2), &[Arg(0, 771), Tv(4759)]),
+    ((773, true, AND2), &[Arg(0, 773), Tv(4759)]),
+    ((774, true, AND2), &[Arg(0, 774), Tv(4759)]),
+    ((775, true, AND2), &[Arg(0, 775), Tv(4759)]),
+    ((776, true, AND2), &[Tv(4759), Tv(4802)]),
+    ((778, true, AND2), &[Tv(4759), Tv(4808)]),
+    ((779, true, AND2), &[Tv(4759), Tv(4811)]),
+    ((780, true, AND2), &[Tv(4759), Tv(4814)]),
+    ((781, true, AND2), &[Tv(4759), Tv(4817)]),
+    ((782, true, AND2), &[Tv(4759), Tv(4820)]),
+    ((783, true, AND2), &[Tv(4759), Tv(4823)]),
+    ((785, true, AND2), &[Arg(0, 785), Tv(4759)]),
+    ((786, true, AND2), &[Arg(0, 786), Tv(4759)]),
+    ((787, true, AND2), &[Arg(0, 787), Tv(4759)]),
+    ((788, true, AND2), &[Arg(0, 788), Tv(4759)]),
+    ((789, true, AND2), &[Arg(0, 789), Tv(4759)]),
+    ((790, true, AND2), &[Arg(0, 790), Tv(4759)]),
+    ((791, true, AND2), &[Arg(0, 791), Tv(4759)]),
+    ((792, true, AND2), &[Arg(0, 792), Tv(4759)]),
+    ((794, true, AND2), &[Arg(0, 794), Tv(4759)]),
+    ((795, true, AND2), &[Arg(0, 795), Tv(4759)]),
+    ((796, true, AND2), &[Arg(0, 796), Tv(4759)]),
+    ((797, true, AND2), &[Arg(0, 797), Tv(4759)]),
+    ((798, true, AND2), &[Arg(0, 798), Tv(4759)]),
+    ((799, true, AND2), &[Arg(0, 799), Tv(4759)]),
+    ((801, true, AND2), &[Arg(0, 801), Tv(4935)]),
+    ((804, true, AND2), &[Arg(0, 804), Tv(4935)]),
+    ((805, true, AND2), &[Arg(0, 805), Tv(4935)]),
+    ((806, true, AND2), &[Arg(0, 806), Tv(4935)]),
+    ((807, true, AND2), &[Arg(0, 807), Tv(4935)]),
+    ((809, true, AND2), &[Arg(0, 809), Tv(4935)]),
+    ((810, true, AND2), &[Arg(0, 810), Tv(4935)]),
+    ((811, true, AND2), &[Arg(0, 811), Tv(4935)]),
+    ((813, true, AND2), &[Arg(0, 813), Tv(4935)]),
+    ((814, true, AND2), &[Arg(0, 814), Tv(4935)]),
+    ((815, true, AND2), &[Arg(0, 815), Tv(4935)]),
+    ((816, true, AND2), &[Tv(4935), Tv(4978)]),
+    ((818, true, AND2), &[Tv(4935), Tv(4984)]),
+    ((819, true, AND2), &[Tv(4935), Tv(4987)]),
+    ((820, true, AND2), &[Tv(4935), Tv(4990)]),
+    ((821, true, AND2), &[Tv(4935), Tv(4993)]),
+    ((822, true, AND2), &[Tv(4935), Tv(4996)]),
+    ((823, true, AND2), &[Tv(4935), Tv(4999)]),
+    ((825, true, AND2), &[Arg(0, 825), Tv(4935)]),
+    ((826, true, AND2), &[Arg(0, 826), Tv(4935)]),
+    ((827, true, AND2), &[Arg(0, 827), Tv(4935)]),
+    ((828, true, AND2), &[Arg(0, 828), Tv(4935)]),
+    ((829, true, AND2), &[Arg(0, 829), Tv(4935)]),
+    ((830, true, AND2), &[Arg(0, 830), Tv(4935)]),
+    ((831, true, AND2), &[Arg(0, 831), Tv(4935)]),
+    ((832, true, AND2), &[Arg(0, 832), Tv(4935)]),
+    ((834, true, AND2), &[Arg(0, 834), Tv(4935)]),
+    ((835, true, AND2), &[Arg(0, 835), Tv(4935)]),
+    ((836, true, AND2), &[Arg(0, 836), Tv(4935)]),
+    ((837, true, AND2), &[Arg(0, 837), Tv(4935)]),
+    ((838, true, AND2), &[Arg(0, 838), Tv(4935)]),
+    ((839, true, AND2), &[Arg(0, 839), Tv(4935)]),
+    ((840, true, AND2), &[Arg(0, 840), Tv(5107)]),
+    ((841, true, AND2), &[Arg(0, 841), Tv(5107)]),
+    ((842, true, AND2), &[Arg(0, 842), Tv(5107)]),
+    ((843, true, AND2), &[Arg(0, 843), Tv(5107)]),
+    ((845, true, AND2), &[Arg(0, 845), Tv(5107)]),
+    ((846, true, AND2), &[Arg(0, 846), Tv(5107)]),
+    ((847, true, AND2), &[Arg(0, 847), Tv(5107)]),
+    ((849, true, AND2), &[Arg(0, 849), Tv(5107)]),
+    ((850, true, AND2), &[Arg(0, 850), Tv(5107)]),
+    ((851, true, AND2), &[Arg(0, 851), Tv(5107)]),
+    ((853, true, AND2), &[Arg(0, 853), Tv(5107)]),
+    ((854, true, AND2), &[Arg(0, 854), Tv(5107)]),
+    ((855, true, AND2), &[Arg(0, 855), Tv(5107)]),
+    ((856, true, AND2), &[Tv(5107), Tv(5150)]),
+    ((858, true, AND2), &[Tv(5107), Tv(5156)]),
+    ((859, true, AND2), &[Tv(5107), Tv(5159)]),
+    ((860, true, AND2), &[Tv(5107), Tv(5162)]),
+    ((861, true, AND2), &[Tv(5107), Tv(5165)]),
+    ((862, true, AND2), &[Tv(5107), Tv(5168)]),
+    ((863, true, AND2), &[Tv(5107), Tv(5171)]),
+    ((865, true, AND2), &[Arg(0, 865), Tv(5107)]),
+    ((866, true, AND2), &[Arg(0, 866), Tv(5107)]),
+    ((867, true, AND2), &[Arg(0, 867), Tv(5107)]),
+    ((868, true, AND2), &[Arg(0, 868), Tv(5107)]),
+    ((869, true, AND2), &[Arg(0, 869), Tv(5107)]),
+    ((870, true, AND2), &[Arg(0, 870), Tv(5107)]),
+    ((871, true, AND2), &[Arg(0, 871), Tv(5107)]),
+    ((872, true, AND2), &[Arg(0, 872), Tv(5107)]),
+    ((874, true, AND2), &[Arg(0, 874), Tv(5107)]),
+    ((875, true, AND2), &[Arg(0, 875), Tv(5107)]),
+    ((876, true, AND2), &[Arg(0, 876), Tv(5107)]),
+    ((877, true, AND2), &[Arg(0, 877), Tv(5107)]),
+    ((878, true, AND2), &[Arg(0, 878), Tv(5107)]),
+    ((879, true, AND2), &[Arg(0, 879), Tv(5107)]),
+    ((880, true, AND2), &[Arg(0, 880), Tv(5283)]),
+    ((882, true, AND2), &[Arg(0, 882), Tv(5283)]),
+    ((885, true, AND2), &[Arg(0, 885), Tv(5283)]),
+    ((886, true, AND2), &[Arg(0, 886), Tv(5283)]),
+    ((887, true, AND2), &[Arg(0, 887), Tv(5283)]),
+    ((889, true, AND2), &[Arg(0, 889), Tv(5283)]),
+    ((890, true, AND2), &[Arg(0, 890), Tv(5283)]),
+    ((891, true, AND2), &[Arg(0, 891), Tv(5283)]),
+    ((893, true, AND2), &[Arg(0, 893), Tv(5283)]),
+    ((894, true, AND2), &[Arg(0, 894), Tv(5283)]),
+    ((895, true, AND2), &[Arg(0, 895), Tv(5283)]),
+    ((896, true, AND2), &[Tv(5283), Tv(5326)]),
+    ((898, true, AND2), &[Tv(5283), Tv(5332)]),
+    ((899, true, AND2), &[Tv(5283), Tv(5335)]),
+    ((900, true, AND2), &[Tv(5283), Tv(5338)]),
+    ((901, true, AND2), &[Tv(5283), Tv(5341)]),
+    ((902, true, AND2), &[Tv(5283), Tv(5344)]),
+    ((903, true, AND2), &[Tv(5283), Tv(5347)]),
+    ((905, true, AND2), &[Arg(0, 905), Tv(5283)]),
+    ((906, true, AND2), &[Arg(0, 906), Tv(5283)]),
+    ((907, true, AND2), &[Arg(0, 907), Tv(5283)]),
+    ((908, true, AND2), &[Arg(0, 908), Tv(5283)]),
+    ((909, true, AND2), &[Arg(0, 909), Tv(5283)]),
+    ((910, true, AND2), &[Arg(0, 910), Tv(5283)]),
+    ((911, true, AND2), &[Arg(0, 911), Tv(5283)]),
+    ((912, true, AND2), &[Arg(0, 912), Tv(5283)]),
+    ((914, true, AND2), &[Arg(0, 914), Tv(5283)]),
+    ((915, true, AND2), &[Arg(0, 915), Tv(5283)]),
+    ((916, true, AND2), &[Arg(0, 916), Tv(5283)]),
+    ((917, true, AND2), &[Arg(0, 917), Tv(5283)]),
+    ((918, true, AND2), &[Arg(0, 918), Tv(5283)]),
+    ((919, true, AND2), &[Arg(0, 919), Tv(5283)]),
+    ((920, true, AND2), &[Tv(1258), Tv(1324)]),
+    ((921, true, AND2), &[Tv(1258), Tv(1327)]),
+    ((922, true, AND2), &[Tv(1258), Tv(1330)]),
+    ((923, true, AND2), &[Tv(1258), Tv(1333)]),
+    ((924, true, AND2), &[Tv(1258), Tv(1336)]),
+    ((925, true, AND2), &[Tv(1258), Tv(1339)]),
+    ((926, true, AND2), &[Tv(1258), Tv(1342)]),
+    ((927, true, AND2), &[Tv(1258), Tv(1345)]),
+    ((928, true, AND2), &[Tv(1460), Tv(1532)]),
+    ((929, true, AND2), &[Tv(1460), Tv(1535)]),
+    ((930, true, AND2), &[Tv(1460), Tv(1538)]),
+    ((931, true, AND2), &[Tv(1460), Tv(1541)]),
+    ((932, true, AND2), &[Tv(1460), Tv(1544)]),
+    ((933, true, AND2), &[Tv(1460), Tv(1547)]),
+    ((934, true, AND2), &[Tv(1460), Tv(1550)]),
+    ((935, true, AND2), &[Tv(1460), Tv(1553)]),
+    ((936, true, AND2), &[Tv(1664), Tv(1736)]),
+    ((937, true, AND2), &[Tv(1664), Tv(1739)]),
+    ((938, true, AND2), &[Tv(1664), Tv(1742)]),
+    ((939, true, AND2), &[Tv(1664), Tv(1745)]),
+    ((940, true, AND2), &[Tv(1664), Tv(1748)]),
+    ((941, true, AND2), &[Tv(1664), Tv(1751)]),
+    ((942, true, AND2), &[Tv(1664), Tv(1754)]),
+    ((943, true, AND2), &[Tv(1664), Tv(1757)]),
+    ((944, true, AND2), &[Tv(1852), Tv(1924)]),
+    ((945, true, AND2), &[Tv(1852), Tv(1927)]),
+    ((946, true, AND2), &[Tv(1852), Tv(1930)]),
+    ((947, true, AND2), &[Tv(1852), Tv(1933)]),
+    ((948, true, AND2), &[Tv(1852), Tv(1936)]),
+    ((949, true, AND2), &[Tv(1852), Tv(1939)]),
+    ((950, true, AND2), &[Tv(1852), Tv(1942)]),
+    ((951, true, AND2), &[Tv(1852), Tv(1945)]),
+    ((952, true, AND2), &[Tv(2072), Tv(2144)]),
+    ((953, true, AND2), &[Tv(2072), Tv(2147)]),
+    ((954, true, AND2), &[Tv(2072), Tv(2150)]),
+    ((955, true, AND2), &[Tv(2072), Tv(2153)]),
+    ((956, true, AND2), &[Tv(2072), Tv(2156)]),
+    ((957, true, AND2), &[Tv(2072), Tv(2159)]),
+    ((958, true, AND2), &[Tv(2072), Tv(2162)]),
+    ((959, true, AND2), &[Tv(2072), Tv(2165)]),
+    ((960, true, AND2), &[Tv(2260), Tv(2332)]),
+    ((961, true, AND2), &[Tv(2260), Tv(2335)]),
+    ((962, true, AND2), &[Tv(2260), Tv(2338)]),
+    ((963, true, AND2), &[Tv(2260), Tv(2341)]),
+    ((964, true, AND2), &[Tv(2260), Tv(2344)]),
+    ((965, true, AND2), &[Tv(2260), Tv(2347)]),
+    ((966, true, AND2), &[Tv(2260), Tv(2350)]),
+    ((967, true, AND2), &[Tv(2260), Tv(2353)]),
+    ((968, true, AND2), &[Tv(2436), Tv(2508)]),
+    ((969, true, AND2), &[Tv(2436), Tv(2511)]),
+    ((970, true, AND2), &[Tv(2436), Tv(2514)]),
+    ((971, true, AND2), &[Tv(2436), Tv(2517)]),
+    ((972, true, AND2), &[Tv(2436), Tv(2520)]),
+    ((973, true, AND2), &[Tv(2436), Tv(2523)]),
+    ((974, true, AND2), &[Tv(2436), Tv(2526)]),
+    ((975, true, AND2), &[Tv(2436), Tv(2529)]),
+    ((976, true, AND2), &[Tv(2616), Tv(2688)]),
+    ((977, true, AND2), &[Tv(2616), Tv(2691)]),
+    ((978, true, AND2), &[Tv(2616), Tv(2694)]),
+    ((979, true, AND2), &[Tv(2616), Tv(2697)]),
+    ((980, true, AND2), &[Tv(2616), Tv(2700)]),
+    ((981, true, AND2), &[Tv(2616), Tv(2703)]),
+    ((982, true, AND2), &[Tv(2616), Tv(2706)]),
+    ((983, true, AND2), &[Tv(2616), Tv(2709)]),
+    ((984, true, AND2), &[Tv(2812), Tv(2884)]),
+    ((985, true, AND2), &[Tv(2812), Tv(2887)]),
+    ((986, true, AND2), &[Tv(2812), Tv(2890)]),
+    ((987, true, AND2), &[Tv(2812), Tv(2893)]),
+    ((988, true, AND2), &[Tv(2812), Tv(2896)]),
+    ((989, true, AND2), &[Tv(2812), Tv(2899)]),
+    ((990, true, AND2), &[Tv(2812), Tv(2902)]),
+    ((991, true, AND2), &[Tv(2812), Tv(2905)]),
+    ((992, true, AND2), &[Tv(2992), Tv(3064)]),
+    ((993, true, AND2), &[Tv(2992), Tv(3067)]),
+    ((994, true, AND2), &[Tv(2992), Tv(3070)]),
+    ((995, true, AND2), &[Tv(2992), Tv(3073)]),
+    ((996, true, AND2), &[Tv(2992), Tv(3076)]),
+    ((997, true, AND2), &[Tv(2992), Tv(3079)]),
+    ((998, true, AND2), &[Tv(2992), Tv(3082)]),
+    ((999, true, AND2), &[Tv(2992), Tv(3085)]),
+    ((1000, true, AND2), &[Tv(3184), Tv(3249)]),
+    ((1001, true, AND2), &[Tv(3184), Tv(3252)]),
+    ((1002, true, AND2), &[Tv(3184), Tv(3255)]),
+    ((1003, true, AND2), &[Tv(3184), Tv(3258)]),
+    ((1004, true, AND2), &[Tv(3184), Tv(3261)]),
+    ((1005, true, AND2), &[Tv(3184), Tv(3264)]),
+    ((1006, true, AND2), &[Tv(3184), Tv(3267)]),
+    ((1007, true, AND2), &[Tv(3184), Tv(3270)]),
+    ((1008, true, AND2), &[Tv(3357), Tv(3422)]),
+    ((1009, true, AND2), &[Tv(3357), Tv(3425)]),
+    ((1010, true, AND2), &[Tv(3357), Tv(3428)]),
+    ((1011, true, AND2), &[Tv(3357), Tv(3431)]),
+    ((1012, true, AND2), &[Tv(3357), Tv(3434)]),
+    ((1013, true, AND2), &[Tv(3357), Tv(3437)]),
+    ((1014, true, AND2), &[Tv(3357), Tv(3440)]),
+    ((1015, true, AND2), &[Tv(3357), Tv(3443)]),
+    ((1016, true, AND2), &[Tv(3530), Tv(3595)]),
+    ((1017, true, AND2), &[Tv(3530), Tv(3598)]),
+    ((1018, true, AND2), &[Tv(3530), Tv(3601)]),
+    ((1019, true, AND2), &[Tv(3530), Tv(3604)]),
+    ((1020, true, AND2), &[Tv(3530), Tv(3607)]),
+    ((1021, true, AND2), &[Tv(3530), Tv(3610)]),
+    ((1022, true, AND2), &[Tv(3530), Tv(3613)]),
+    ((1023, true, AND2), &[Tv(3530), Tv(3616)]),
+    ((1024, true, AND2), &[Tv(3699), Tv(3771)]),
+    ((1025, true, AND2), &[Tv(3699), Tv(3774)]),
+    ((1026, true, AND2), &[Tv(3699), Tv(3777)]),
+    ((1027, true, AND2), &[Tv(3699), Tv(3780)]),
+    ((1028, true, AND2), &[Tv(3699), Tv(3783)]),
+    ((1029, true, AND2), &[Tv(3699), Tv(3786)]),
+    ((1030, true, AND2), &[Tv(3699), Tv(3789)]),
+    ((1031, true, AND2), &[Tv(3699), Tv(3792)]),
+    ((1032, true, AND2), &[Tv(3875), Tv(3947)]),
+    ((1033, true, AND2), &[Tv(3875), Tv(3950)]),
+    ((1034, true, AND2), &[Tv(3875), Tv(3953)]),
+    ((1035, true, AND2), &[Tv(3875), Tv(3956)]),
+    ((1036, true, AND2), &[Tv(3875), Tv(3959)]),
+    ((1037, true, AND2), &[Tv(3875), Tv(3962)]),
+    ((1038, true, AND2), &[Tv(3875), Tv(3965)]),
+    ((1039, true, AND2), &[Tv(3875), Tv(3968)]),
+    ((1040, true, AND2), &[Tv(4047), Tv(4119)]),
+    ((1041, true, AND2), &[Tv(4047), Tv(4122)]),
+    ((1042, true, AND2), &[Tv(4047), Tv(4125)]),
+    ((1043, true, AND2), &[Tv(4047), Tv(4128)]),
+    ((1044, true, AND2), &[Tv(4047), Tv(4131)]),
+    ((1045, true, AND2), &[Tv(4047), Tv(4134)]),
+    ((1046, true, AND2), &[Tv(4047), Tv(4137)]),
+    ((1047, true, AND2), &[Tv(4047), Tv(4140)]),
+    ((1048, true, AND2), &[Tv(4239), Tv(4311)]),
+    ((1049, true, AND2), &[Tv(4239), Tv(4314)]),
+    ((1050, true, AND2), &[Tv(4239), Tv(4317)]),
+    ((1051, true, AND2), &[Tv(4239), Tv(4320)]),
+    ((1052, true, AND2), &[Tv(4239), Tv(4323)]),
+    ((1053, true, AND2), &[Tv(4239), Tv(4326)]),
+    ((1054, true, AND2), &[Tv(4239), Tv(4329)]),
+    ((1055, true, AND2), &[Tv(4239), Tv(4332)]),
+    ((1056, true, AND2), &[Tv(4415), Tv(4483)]),
+    ((1057, true, AND2), &[Tv(4415), Tv(4486)]),
+    ((1058, true, AND2), &[Tv(4415), Tv(4489)]),
+    ((1059, true, AND2), &[Tv(4415), Tv(4492)]),
+    ((1060, true, AND2), &[Tv(4415), Tv(4495)]),
+    ((1061, true, AND2), &[Tv(4415), Tv(4498)]),
+    ((1062, true, AND2), &[Tv(4415), Tv(4501)]),
+    ((1063, true, AND2), &[Tv(4415), Tv(4504)]),
+    ((1064, true, AND2), &[Tv(4583), Tv(4651)]),
+    ((1065, true, AND2), &[Tv(4583), Tv(4654)]),
+    ((1066, true, AND2), &[Tv(4583), Tv(4657)]),
+    ((1067, true, AND2), &[Tv(4583), Tv(4660)]),
+    ((1068, true, AND2), &[Tv(4583), Tv(4663)]),
+    ((1069, true, AND2), &[Tv(4583), Tv(4666)]),
+    ((1070, true, AND2), &[Tv(4583), Tv(4669)]),
+    ((1071, true, AND2), &[Tv(4583), Tv(4672)]),
+    ((1072, true, AND2), &[Tv(4759), Tv(4827)]),
+    ((1073, true, AND2), &[Tv(4759), Tv(4830)]),
+    ((1074, true, AND2), &[Tv(4759), Tv(4833)]),
+    ((1075, true, AND2), &[Tv(4759), Tv(4836)]),
+    ((1076, true, AND2), &[Tv(4759), Tv(4839)]),
+    ((1077, true, AND2), &[Tv(4759), Tv(4842)]),
+    ((1078, true, AND2), &[Tv(4759), Tv(4845)]),
+    ((1079, true, AND2), &[Tv(4759), Tv(4848)]),
+    ((1080, true, AND2), &[Tv(4935), Tv(5003)]),
+    ((1081, true, AND2), &[Tv(4935), Tv(5006)]),
+    ((1082, true, AND2), &[Tv(4935), Tv(5009)]),
+    ((1083, true, AND2), &[Tv(4935), Tv(5012)]),
+    ((1084, true, AND2), &[Tv(4935), Tv(5015)]),
+    ((1085, true, AND2), &[Tv(4935), Tv(5018)]),
+    ((1086, true, AND2), &[Tv(4935), Tv(5021)]),
+    ((1087, true, AND2), &[Tv(4935), Tv(5024)]),
+    ((1088, true, AND2), &[Tv(5107), Tv(5175)]),
+    ((1089, true, AND2), &[Tv(5107), Tv(5178)]),
+    ((1090, true, AND2), &[Tv(5107), Tv(5181)]),
+    ((1091, true, AND2), &[Tv(5107), Tv(5184)]),
+    ((1092, true, AND2), &[Tv(5107), Tv(5187)]),
+    ((1093, true, AND2), &[Tv(5107), Tv(5190)]),
+    ((1094, true, AND2), &[Tv(5107), Tv(5193)]),
+    ((1095, true, AND2), &[Tv(5107), Tv(5196)]),
+    ((1096, true, AND2), &[Tv(5283), Tv(5351)]),
+    ((1097, true, AND2), &[Tv(5283), Tv(5354)]),
+    ((1098, true, AND2), &[Tv(5283), Tv(5357)]),
+    ((1099, true, AND2), &[Tv(5283), Tv(5360)]),
+    ((1100, true, AND2), &[Tv(5283), Tv(5363)]),
+    ((1101, true, AND2), &[Tv(5283), Tv(5366)]),
+    ((1102, true, AND2), &[Tv(5283), Tv(5369)]),
+    ((1103, true, AND2), &[Tv(5283), Tv(5372)]),
+    ((1278, false, INV), &[Tv(1258)]),
+    ((1486, false, INV), &[Tv(1460)]),
+    ((1690, false, INV), &[Tv(1664)]),
+    ((1878, false, INV), &[Tv(1852)]),
+    ((2098, false, INV), &[Tv(2072)]),
+    ((2286, false, INV), &[Tv(2260)]),
+    ((2462, false, INV), &[Tv(2436)]),
+    ((2642, false, INV), &[Tv(2616)]),
+    ((2838, false, INV), &[Tv(2812)]),
+    ((3018, false, INV), &[Tv(2992)]),
+    ((3208, false, INV), &[Tv(3184)]),
+    ((3381, false, INV), &[Tv(3357)]),
+    ((3554, false, INV), &[Tv(3530)]),
+    ((3725, false, INV), &[Tv(3699)]),
+    ((3901, false, INV), &[Tv(3875)]),
+    ((4073, false, INV), &[Tv(4047)]),
+    ((4265, false, INV), &[Tv(4239)]),
+    ((4436, false, INV), &[Tv(4415)]),
+    ((4604, false, INV), &[Tv(4583)]),
+    ((4780, false, INV), &[Tv(4759)]),
+    ((4956, false, INV), &[Tv(4935)]),
+    ((5128, false, INV), &[Tv(5107)]),
+    ((5304, false, INV), &[Tv(5283)]),
+];
+
+static LEVEL_14: [((usize, bool, CellType), &[GateInput]); 203] = [
+    ((0, true, OR2), &[Arg(0, 0), Tv(1278)]),
+    ((2, true, OR2), &[Arg(0, 2), Tv(1278)]),
+    ((3, true, OR2), &[Arg(0, 3), Tv(1278)]),
+    ((8, true, OR2), &[Arg(0, 8), Tv(1278)]),
+    ((9, true, OR2), &[Arg(0, 9), Tv(1278)]),
+    ((18, true, OR2), &[Tv(1278), Tv(1319)]),
+    ((21, true, OR2), &[Tv(1278), Tv(1320)]),
+    ((22, true, OR2), &[Tv(1278), Tv(1321)]),
+    ((25, true, OR2), &[Arg(0, 25), Tv(1278)]),
+    ((32, true, OR2), &[Arg(0, 32), Tv(1278)]),
+    ((35, true, OR2), &[Arg(0, 35), Tv(1278)]),
+    ((36, true, OR2), &[Arg(0, 36), Tv(1278)]),
+    ((48, true, OR2), &[Arg(0, 48), Tv(1486)]),
+    ((50, true, OR2), &[Arg(0, 50), Tv(1486)]),
+    ((51, true, OR2), &[Arg(0, 51), Tv(1486)]),
+    ((56, true, OR2), &[Tv(1486), Tv(1529)]),
+    ((64, true, OR2), &[Arg(0, 64), Tv(1486)]),
+    ((72, true, OR2), &[Arg(0, 72), Tv(1486)]),
+    ((83, true, OR2), &[Arg(0, 83), Tv(1690)]),
+    ((89, true, OR2), &[Arg(0, 89), Tv(1690)]),
+    ((90, true, OR2), &[Arg(0, 90), Tv(1690)]),
+    ((91, true, OR2), &[Arg(0, 91), Tv(1690)]),
+    ((96, true, OR2), &[Tv(1690), Tv(1733)]),
+    ((104, true, OR2), &[Arg(0, 104), Tv(1690)]),
+    ((112, true, OR2), &[Arg(0, 112), Tv(1690)]),
+    ((121, true, OR2), &[Arg(0, 121), Tv(1878)]),
+    ((122, true, OR2), &[Arg(0, 122), Tv(1878)]),
+    ((123, true, OR2), &[Arg(0, 123), Tv(1878)]),
+    ((129, true, OR2), &[Arg(0, 129), Tv(1878)]),
+    ((130, true, OR2), &[Arg(0, 130), Tv(1878)]),
+    ((131, true, OR2), &[Arg(0, 131), Tv(1878)]),
+    ((136, true, OR2), &[Tv(1878), Tv(1921)]),
+    ((144, true, OR2), &[Arg(0, 144), Tv(1878)]),
+    ((152, true, OR2), &[Arg(0, 152), Tv(1878)]),
+    ((160, true, OR2), &[Arg(0, 160), Tv(2098)]),
+    ((162, true, OR2), &[Arg(0, 162), Tv(2098)]),
+    ((163, true, OR2), &[Arg(0, 163), Tv(2098)]),
+    ((164, true, OR2), &[Arg(0, 164), Tv(2098)]),
+    ((170, true, OR2), &[Arg(0, 170), Tv(2098)]),
+    ((172, true, OR2), &[Arg(0, 172), Tv(2098)]),
+    ((176, true, OR2), &[Tv(2098), Tv(2141)]),
+    ((184, true, OR2), &[Arg(0, 184), Tv(2098)]),
+    ((192, true, OR2), &[Arg(0, 192), Tv(2098)]),
+    ((200, true, OR2), &[Arg(0, 200), Tv(2286)]),
+    ((203, true, OR2), &[Arg(0, 203), Tv(2286)]),
+    ((204, true, OR2), &[Arg(0, 204), Tv(2286)]),
+    ((208, true, OR2), &[Arg(0, 208), Tv(2286)]),
+    ((209, true, OR2), &[Arg(0, 209), Tv(2286)]),
+    ((210, true, OR2), &[Arg(0, 210), Tv(2286)]),
+    ((211, true, OR2), &[Arg(0, 211), Tv(2286)]),
+    ((216, true, OR2), &[Tv(2286), Tv(2329)]),
+    ((224, true, OR2), &[Arg(0, 224), Tv(2286)]),
+    ((232, true, OR2), &[Arg(0, 232), Tv(2286)]),
+    ((243, true, OR2), &[Arg(0, 243), Tv(2462)]),
+    ((244, true, OR2), &[Arg(0, 244), Tv(2462)]),
+    ((250, true, OR2), &[Arg(0, 250), Tv(2462)]),
+    ((252, true, OR2), &[Arg(0, 252), Tv(2462)]),
+    ((256, true, OR2), &[Tv(2462), Tv(2505)]),
+    ((264, true, OR2), &[Arg(0, 264), Tv(2462)]),
+    ((272, true, OR2), &[Arg(0, 272), Tv(2462)]),
+    ((280, true, OR2), &[Arg(0, 280), Tv(2642)]),
+    ((282, true, OR2), &[Arg(0, 282), Tv(2642)]),
+    ((283, true, OR2), &[Arg(0, 283), Tv(2642)]),
+    ((289, true, OR2), &[Arg(0, 289), Tv(2642)]),
+    ((290, true, OR2), &[Arg(0, 290), Tv(2642)]),
+    ((292, true, OR2), &[Arg(0, 292), Tv(2642)]),
+    ((296, true, OR2), &[Tv(2642), Tv(2685)]),
+    ((304, true, OR2), &[Arg(0, 304), Tv(2642)]),
+    ((312, true, OR2), &[Arg(0, 312), Tv(2642)]),
+    ((320, true, OR2), &[Arg(0, 320), Tv(2838)]),
+    ((331, true, OR2), &[Arg(0, 331), Tv(2838)]),
+    ((332, true, OR2), &[Arg(0, 332), Tv(2838)]),
+    ((336, true, OR2), &[Tv(2838), Tv(2881)]),
+    ((344, true, OR2), &[Arg(0, 344), Tv(2838)]),
+    ((352, true, OR2), &[Arg(0, 352), Tv(2838)]),
+    ((361, true, OR2), &[Arg(0, 361), Tv(3018)]),
+    ((371, true, OR2), &[Arg(0, 371), Tv(3018)]),
+    ((372, true, OR2), &[Arg(0, 372), Tv(3018)]),
+    ((376, true, OR2), &[Tv(3018), Tv(3061)]),
+    ((384, true, OR2), &[Arg(0, 384), Tv(3018)]),
+    ((392, true, OR2), &[Arg(0, 392), Tv(3018)]),
+    ((400, true, OR2), &[Arg(0, 400), Tv(3208)]),
+    ((401, true, OR2), &[Arg(0, 401), Tv(3208)]),
+    ((403, true, OR2), &[Arg(0, 403), Tv(3208)]),
+    ((404, true, OR2), &[Arg(0, 404), Tv(3208)]),
+    ((408, true, OR2), &[Arg(0, 408), Tv(3208)]),
+    ((409, true, OR2), &[Arg(0, 409), Tv(3208)]),
+    ((410, true, OR2), &[Arg(0, 410), Tv(3208)]),
+    ((416, true, OR2), &[Tv(3208), Tv(3243)]),
+    ((417, true, OR2), &[Tv(3208), Tv(3244)]),
+    ((418, true, OR2), &[Tv(3208), Tv(3245)]),
+    ((419, true, OR2), &[Tv(3208), Tv(3246)]),
+    ((424, true, OR2), &[Arg(0, 424), Tv(3208)]),
+    ((432, true, OR2), &[Arg(0, 432), Tv(3208)]),
+    ((434, true, OR2), &[Arg(0, 434), Tv(3208)]),
+    ((440, true, OR2), &[Arg(0, 440), Tv(3381)]),
+    ((441, true, OR2), &[Arg(0, 441), Tv(3381)]),
+    ((443, true, OR2), &[Arg(0, 443), Tv(3381)]),
+    ((444, true, OR2), &[Arg(0, 444), Tv(3381)]),
+    ((448, true, OR2), &[Arg(0, 448), Tv(3381)]),
+    ((449, true, OR2), &[Arg(0, 449), Tv(3381)]),
+    ((451, true, OR2), &[Arg(0, 451), Tv(3381)]),
+    ((456, true, OR2), &[Tv(3381), Tv(3416)]),
+    ((457, true, OR2), &[Tv(3381), Tv(3417)]),
+    ((458, true, OR2), &[Tv(3381), Tv(3418)]),
+    ((459, true, OR2), &[Tv(3381), Tv(3419)]),
+    ((464, true, OR2), &[Arg(0, 464), Tv(3381)]),
+    ((472, true, OR2), &[Arg(0, 472), Tv(3381)]),
+    ((474, true, OR2), &[Arg(0, 474), Tv(3381)]),
+    ((480, true, OR2), &[Arg(0, 480), Tv(3554)]),
+    ((481, true, OR2), &[Arg(0, 481), Tv(3554)]),
+    ((489, true, OR2), &[Arg(0, 489), Tv(3554)]),
+    ((490, true, OR2), &[Arg(0, 490), Tv(3554)]),
+    ((491, true, OR2), &[Arg(0, 491), Tv(3554)]),
+    ((496, true, OR2), &[Tv(3554), Tv(3589)]),
+    ((497, true, OR2), &[Tv(3554), Tv(3590)]),
+    ((498, true, OR2), &[Tv(3554), Tv(3591)]),
+    ((499, true, OR2), &[Tv(3554), Tv(3592)]),
+    ((504, true, OR2), &[Arg(0, 504), Tv(3554)]),
+    ((512, true, OR2), &[Arg(0, 512), Tv(3554)]),
+    ((514, true, OR2), &[Arg(0, 514), Tv(3554)]),
+    ((520, true, OR2), &[Arg(0, 520), Tv(3725)]),
+    ((521, true, OR2), &[Arg(0, 521), Tv(3725)]),
+    ((523, true, OR2), &[Arg(0, 523), Tv(3725)]),
+    ((530, true, OR2), &[Arg(0, 530), Tv(3725)]),
+    ((532, true, OR2), &[Arg(0, 532), Tv(3725)]),
+    ((536, true, OR2), &[Tv(3725), Tv(3767)]),
+    ((538, true, OR2), &[Tv(3725), Tv(3768)]),
+    ((544, true, OR2), &[Arg(0, 544), Tv(3725)]),
+    ((553, true, OR2), &[Arg(0, 553), Tv(3725)]),
+    ((560, true, OR2), &[Arg(0, 560), Tv(3901)]),
+    ((561, true, OR2), &[Arg(0, 561), Tv(3901)]),
+    ((564, true, OR2), &[Arg(0, 564), Tv(3901)]),
+    ((570, true, OR2), &[Arg(0, 570), Tv(3901)]),
+    ((572, true, OR2), &[Arg(0, 572), Tv(3901)]),
+    ((576, true, OR2), &[Tv(3901), Tv(3943)]),
+    ((578, true, OR2), &[Tv(3901), Tv(3944)]),
+    ((584, true, OR2), &[Arg(0, 584), Tv(3901)]),
+    ((593, true, OR2), &[Arg(0, 593), Tv(3901)]),
+    ((600, true, OR2), &[Arg(0, 600), Tv(4073)]),
+    ((601, true, OR2), &[Arg(0, 601), Tv(4073)]),
+    ((603, true, OR2), &[Arg(0, 603), Tv(4073)]),
+    ((604, true, OR2), &[Arg(0, 604), Tv(4073)]),
+    ((610, true, OR2), &[Arg(0, 610), Tv(4073)]),
+    ((612, true, OR2), &[Arg(0, 612), Tv(4073)]),
+    ((616, true, OR2), &[Tv(4073), Tv(4115)]),
+    ((618, true, OR2), &[Tv(4073), Tv(4116)]),
+    ((624, true, OR2), &[Arg(0, 624), Tv(4073)]),
+    ((633, true, OR2), &[Arg(0, 633), Tv(4073)]),
+    ((640, true, OR2), &[Arg(0, 640), Tv(4265)]),
+    ((642, true, OR2), &[Arg(0, 642), Tv(4265)]),
+    ((648, true, OR2), &[Arg(0, 648), Tv(4265)]),
+    ((649, true, OR2), &[Arg(0, 649), Tv(4265)]),
+    ((650, true, OR2), &[Arg(0, 650), Tv(4265)]),
+    ((652, true, OR2), &[Arg(0, 652), Tv(4265)]),
+    ((656, true, OR2), &[Tv(4265), Tv(4307)]),
+    ((658, true, OR2), &[Tv(4265), Tv(4308)]),
+    ((664, true, OR2), &[Arg(0, 664), Tv(4265)]),
+    ((673, true, OR2), &[Arg(0, 673), Tv(4265)]),
+    ((683, true, OR2), &[Arg(0, 683), Tv(4436)]),
+    ((691, true, OR2), &[Arg(0, 691), Tv(4436)]),
+    ((697, true, OR2), &[Tv(4436), Tv(4480)]),
+    ((704, true, OR2), &[Arg(0, 704), Tv(4436)]),
+    ((713, true, OR2), &[Arg(0, 713), Tv(4436)]),
+    ((720, true, OR2), &[Arg(0, 720), Tv(4604)]),
+    ((722, true, OR2), &[Arg(0, 722), Tv(4604)]),
+    ((723, true, OR2), &[Arg(0, 723), Tv(4604)]),
+    ((724, true, OR2), &[Arg(0, 724), Tv(4604)]),
+    ((728, true, OR2), &[Arg(0, 728), Tv(4604)]),
+    ((729, true, OR2), &[Arg(0, 729), Tv(4604)]),
+    ((730, true, OR2), &[Arg(0, 730), Tv(4604)]),
+    ((731, true, OR2), &[Arg(0, 731), Tv(4604)]),
+    ((737, true, OR2), &[Tv(4604), Tv(4648)]),
+    ((744, true, OR2), &[Arg(0, 744), Tv(4604)]),
+    ((753, true, OR2), &[Arg(0, 753), Tv(4604)]),
+    ((760, true, OR2), &[Arg(0, 760), Tv(4780)]),
+    ((762, true, OR2), &[Arg(0, 762), Tv(4780)]),
+    ((772, true, OR2), &[Arg(0, 772), Tv(4780)]),
+    ((777, true, OR2), &[Tv(4780), Tv(4824)]),
+    ((784, true, OR2), &[Arg(0, 784), Tv(4780)]),
+    ((793, true, OR2), &[Arg(0, 793), Tv(4780)]),
+    ((800, true, OR2), &[Arg(0, 800), Tv(4956)]),
+    ((802, true, OR2), &[Arg(0, 802), Tv(4956)]),
+    ((803, true, OR2), &[Arg(0, 803), Tv(4956)]),
+    ((808, true, OR2), &[Arg(0, 808), Tv(4956)]),
+    ((812, true, OR2), &[Arg(0, 812), Tv(4956)]),
+    ((817, true, OR2), &[Tv(4956), Tv(5000)]),
+    ((824, true, OR2), &[Arg(0, 824), Tv(4956)]),
+    ((833, true, OR2), &[Arg(0, 833), Tv(4956)]),
+    ((844, true, OR2), &[Arg(0, 844), Tv(5128)]),
+    ((848, true, OR2), &[Arg(0, 848), Tv(5128)]),
+    ((852, true, OR2), &[Arg(0, 852), Tv(5128)]),
+    ((857, true, OR2), &[Tv(5128), Tv(5172)]),
+    ((864, true, OR2), &[Arg(0, 864), Tv(5128)]),
+    ((873, true, OR2), &[Arg(0, 873), Tv(5128)]),
+    ((881, true, OR2), &[Arg(0, 881), Tv(5304)]),
+    ((883, true, OR2), &[Arg(0, 883), Tv(5304)]),
+    ((884, true, OR2), &[Arg(0, 884), Tv(5304)]),
+    ((888, true, OR2), &[Arg(0, 888), Tv(5304)]),
+    ((892, true, OR2), &[Arg(0, 892), Tv(5304)]),
+    ((897, true, OR2), &[Tv(5304), Tv(5348)]),
+    ((904, true, OR2), &[Arg(0, 904), Tv(5304)]),
+    ((913, true, OR2), &[Arg(0, 913), Tv(5304)]),
+];
+
+static PRUNE_1: [usize; 486] = [
+  1120,
+  1121,
+  1122,
+  1123,
+  1127,
+  1130,
+  1137,
+  1143,
+  1146,
+  1153,
+  1159,
+  1162,
+  1169,
+  1175,
+  1178,
+  1185,
+  1199,
+  1200,
+  1201,
+  1202,
+  1203,
+  1204,
+  1215,
+  1259,
+  1260,
+  1263,
+  1265,
+  1266,
+  1280,
+  1281,
+  1290,
+  1346,
+  1348,
+  1357,
+  1367,
+  1377,
+  1387,
+  1401,
+  1402,
+  1403,
+  1404,
+  1405,
+  1406,
+  1417,
+  1461,
+  1465,
+  1469,
+  1473,
+  1483,
+  1488,
+  1489,
+  1490,
+  1491,
+  1492,
+  1554,
+  1556,
+  1561,
+  1565,
+  1570,
+  1574,
+  1579,
+  1583,
+  1588,
+  1592,
+  1605,
+  1606,
+  1607,
+  1608,
+  1609,
+  1610,
+  1621,
+  1665,
+  1669,
+  1673,
+  1677,
+  1687,
+  1692,
+  1693,
+  1694,
+  1695,
+  1696,
+  1758,
+  1760,
+  1765,
+  1770,
+  1775,
+  1780,
+  1793,
+  1794,
+  1795,
+  1796,
+  1797,
+  1798,
+  1809,
+  1853,
+  1857,
+  1861,
+  1865,
+  1875,
+  1880,
+  1881,
+  1882,
+  1883,
+  1884,
+  1946,
+  1948,
+  1953,
+  1958,
+  1960,
+  1966,
+  1971,
+  1973,
+  1979,
+  1984,
+  1986,
+  1992,
+  1997,
+  1999,
+  2013,
+  2014,
+  2015,
+  2016,
+  2017,
+  2018,
+  2029,
+  2073,
+  2077,
+  2081,
+  2085,
+  2095,
+  2100,
+  2101,
+  2102,
+  2103,
+  2104,
+  2166,
+  2168,
+  2201,
+  2202,
+  2203,
+  2204,
+  2205,
+  2206,
+  2217,
+  2261,
+  2265,
+  2269,
+  2273,
+  2283,
+  2288,
+  2289,
+  2290,
+  2291,
+  2292,
+  2354,
+  2356,
+  2377,
+  2378,
+  2379,
+  2380,
+  2381,
+  2382,
+  2393,
+  2437,
+  2441,
+  2445,
+  2449,
+  2459,
+  2464,
+  2465,
+  2466,
+  2467,
+  2468,
+  2530,
+  2532,
+  2557,
+  2558,
+  2559,
+  2560,
+  2561,
+  2562,
+  2573,
+  2617,
+  2621,
+  2625,
+  2629,
+  2639,
+  2644,
+  2645,
+  2646,
+  2647,
+  2648,
+  2710,
+  2712,
+  2719,
+  2726,
+  2733,
+  2740,
+  2753,
+  2754,
+  2755,
+  2756,
+  2757,
+  2758,
+  2769,
+  2813,
+  2817,
+  2821,
+  2825,
+  2835,
+  2840,
+  2841,
+  2842,
+  2843,
+  2844,
+  2906,
+  2908,
+  2933,
+  2934,
+  2935,
+  2936,
+  2937,
+  2938,
+  2949,
+  2993,
+  2997,
+  3001,
+  3005,
+  3015,
+  3020,
+  3021,
+  3022,
+  3023,
+  3024,
+  3086,
+  3087,
+  3088,
+  3125,
+  3126,
+  3127,
+  3128,
+  3129,
+  3130,
+  3141,
+  3186,
+  3187,
+  3188,
+  3192,
+  3202,
+  3209,
+  3210,
+  3211,
+  3271,
+  3272,
+  3273,
+  3298,
+  3299,
+  3300,
+  3301,
+  3302,
+  3303,
+  3314,
+  3359,
+  3360,
+  3361,
+  3365,
+  3375,
+  3382,
+  3383,
+  3384,
+  3444,
+  3445,
+  3446,
+  3471,
+  3472,
+  3473,
+  3474,
+  3475,
+  3476,
+  3487,
+  3532,
+  3533,
+  3534,
+  3538,
+  3548,
+  3555,
+  3556,
+  3557,
+  3617,
+  3618,
+  3619,
+  3640,
+  3641,
+  3642,
+  3643,
+  3644,
+  3645,
+  3656,
+  3700,
+  3704,
+  3705,
+  3707,
+  3711,
+  3722,
+  3727,
+  3728,
+  3729,
+  3730,
+  3793,
+  3794,
+  3795,
+  3816,
+  3817,
+  3818,
+  3819,
+  3820,
+  3821,
+  3832,
+  3876,
+  3880,
+  3881,
+  3883,
+  3887,
+  3898,
+  3903,
+  3904,
+  3905,
+  3906,
+  3969,
+  3970,
+  3971,
+  3988,
+  3989,
+  3990,
+  3991,
+  3992,
+  3993,
+  4004,
+  4048,
+  4052,
+  4053,
+  4055,
+  4059,
+  4070,
+  4075,
+  4076,
+  4077,
+  4078,
+  4141,
+  4142,
+  4143,
+  4148,
+  4154,
+  4160,
+  4166,
+  4180,
+  4181,
+  4182,
+  4183,
+  4184,
+  4185,
+  4196,
+  4240,
+  4244,
+  4245,
+  4247,
+  4251,
+  4262,
+  4267,
+  4268,
+  4269,
+  4270,
+  4333,
+  4334,
+  4335,
+  4356,
+  4357,
+  4358,
+  4359,
+  4360,
+  4361,
+  4372,
+  4416,
+  4420,
+  4424,
+  4438,
+  4439,
+  4440,
+  4441,
+  4442,
+  4451,
+  4505,
+  4506,
+  4507,
+  4524,
+  4525,
+  4526,
+  4527,
+  4528,
+  4529,
+  4540,
+  4584,
+  4588,
+  4592,
+  4606,
+  4607,
+  4608,
+  4609,
+  4610,
+  4619,
+  4673,
+  4674,
+  4675,
+  4700,
+  4701,
+  4702,
+  4703,
+  4704,
+  4705,
+  4716,
+  4760,
+  4764,
+  4768,
+  4782,
+  4783,
+  4784,
+  4785,
+  4786,
+  4795,
+  4849,
+  4850,
+  4851,
+  4876,
+  4877,
+  4878,
+  4879,
+  4880,
+  4881,
+  4892,
+  4936,
+  4940,
+  4944,
+  4958,
+  4959,
+  4960,
+  4961,
+  4962,
+  4971,
+  5025,
+  5026,
+  5027,
+  5048,
+  5049,
+  5050,
+  5051,
+  5052,
+  5053,
+  5064,
+  5108,
+  5112,
+  5116,
+  5130,
+  5131,
+  5132,
+  5133,
+  5134,
+  5143,
+  5197,
+  5198,
+  5199,
+  5224,
+  5225,
+  5226,
+  5227,
+  5228,
+  5229,
+  5240,
+  5284,
+  5288,
+  5292,
+  5306,
+  5307,
+  5308,
+  5309,
+  5310,
+  5319,
+];
+
+static PRUNE_2: [usize; 536] = [
+  1124,
+  1125,
+  1128,
+  1129,
+  1131,
+  1135,
+  1136,
+  1138,
+  1139,
+  1144,
+  1145,
+  1147,
+  1151,
+  1152,
+  1154,
+  1155,
+  1160,
+  1161,
+  1163,
+  1167,
+  1168,
+  1170,
+  1171,
+  1176,
+  1177,
+  1179,
+  1183,
+  1184,
+  1186,
+  1187,
+  1198,
+  1205,
+  1206,
+  1207,
+  1208,
+  1209,
+  1210,
+  1216,
+  1261,
+  1262,
+  1264,
+  1267,
+  1279,
+  1282,
+  1283,
+  1284,
+  1285,
+  1286,
+  1291,
+  1350,
+  1353,
+  1354,
+  1358,
+  1359,
+  1363,
+  1364,
+  1368,
+  1369,
+  1373,
+  1374,
+  1378,
+  1379,
+  1383,
+  1384,
+  1388,
+  1389,
+  1400,
+  1407,
+  1408,
+  1409,
+  1410,
+  1411,
+  1412,
+  1418,
+  1462,
+  1466,
+  1470,
+  1474,
+  1487,
+  1493,
+  1494,
+  1495,
+  1496,
+  1497,
+  1501,
+  1558,
+  1562,
+  1566,
+  1571,
+  1575,
+  1580,
+  1584,
+  1589,
+  1593,
+  1604,
+  1611,
+  1612,
+  1613,
+  1614,
+  1615,
+  1616,
+  1622,
+  1666,
+  1670,
+  1674,
+  1678,
+  1691,
+  1697,
+  1698,
+  1699,
+  1700,
+  1701,
+  1705,
+  1762,
+  1766,
+  1771,
+  1776,
+  1781,
+  1792,
+  1799,
+  1800,
+  1801,
+  1802,
+  1803,
+  1804,
+  1810,
+  1854,
+  1858,
+  1862,
+  1866,
+  1879,
+  1885,
+  1886,
+  1887,
+  1888,
+  1889,
+  1893,
+  1950,
+  1954,
+  1957,
+  1959,
+  1961,
+  1967,
+  1970,
+  1972,
+  1974,
+  1980,
+  1983,
+  1985,
+  1987,
+  1993,
+  1996,
+  1998,
+  2000,
+  2012,
+  2019,
+  2020,
+  2021,
+  2022,
+  2023,
+  2024,
+  2030,
+  2074,
+  2078,
+  2082,
+  2086,
+  2099,
+  2105,
+  2106,
+  2107,
+  2108,
+  2109,
+  2113,
+  2170,
+  2175,
+  2180,
+  2185,
+  2190,
+  2200,
+  2207,
+  2208,
+  2209,
+  2210,
+  2211,
+  2212,
+  2218,
+  2262,
+  2266,
+  2270,
+  2274,
+  2287,
+  2293,
+  2294,
+  2295,
+  2296,
+  2297,
+  2301,
+  2358,
+  2376,
+  2383,
+  2384,
+  2385,
+  2386,
+  2387,
+  2388,
+  2394,
+  2438,
+  2442,
+  2446,
+  2450,
+  2463,
+  2469,
+  2470,
+  2471,
+  2472,
+  2473,
+  2477,
+  2534,
+  2556,
+  2563,
+  2564,
+  2565,
+  2566,
+  2567,
+  2568,
+  2574,
+  2618,
+  2622,
+  2626,
+  2630,
+  2643,
+  2649,
+  2650,
+  2651,
+  2652,
+  2653,
+  2657,
+  2714,
+  2720,
+  2727,
+  2734,
+  2741,
+  2752,
+  2759,
+  2760,
+  2761,
+  2762,
+  2763,
+  2764,
+  2770,
+  2814,
+  2818,
+  2822,
+  2826,
+  2839,
+  2845,
+  2846,
+  2847,
+  2848,
+  2849,
+  2853,
+  2910,
+  2932,
+  2939,
+  2940,
+  2941,
+  2942,
+  2943,
+  2944,
+  2950,
+  2994,
+  2998,
+  3002,
+  3006,
+  3019,
+  3025,
+  3026,
+  3027,
+  3028,
+  3029,
+  3033,
+  3090,
+  3091,
+  3093,
+  3099,
+  3105,
+  3111,
+  3124,
+  3131,
+  3132,
+  3133,
+  3134,
+  3135,
+  3136,
+  3142,
+  3185,
+  3189,
+  3193,
+  3196,
+  3197,
+  3203,
+  3212,
+  3213,
+  3214,
+  3275,
+  3276,
+  3297,
+  3304,
+  3305,
+  3306,
+  3307,
+  3308,
+  3309,
+  3315,
+  3358,
+  3362,
+  3366,
+  3369,
+  3370,
+  3376,
+  3385,
+  3386,
+  3387,
+  3448,
+  3449,
+  3470,
+  3477,
+  3478,
+  3479,
+  3480,
+  3481,
+  3482,
+  3488,
+  3531,
+  3535,
+  3539,
+  3542,
+  3543,
+  3549,
+  3558,
+  3559,
+  3560,
+  3621,
+  3639,
+  3646,
+  3647,
+  3648,
+  3649,
+  3650,
+  3651,
+  3657,
+  3701,
+  3708,
+  3712,
+  3726,
+  3731,
+  3732,
+  3733,
+  3734,
+  3735,
+  3739,
+  3797,
+  3815,
+  3822,
+  3823,
+  3824,
+  3825,
+  3826,
+  3827,
+  3833,
+  3877,
+  3884,
+  3888,
+  3902,
+  3907,
+  3908,
+  3909,
+  3910,
+  3911,
+  3915,
+  3973,
+  3987,
+  3994,
+  3995,
+  3996,
+  3997,
+  3998,
+  3999,
+  4005,
+  4049,
+  4056,
+  4060,
+  4074,
+  4079,
+  4080,
+  4081,
+  4082,
+  4083,
+  4087,
+  4145,
+  4149,
+  4155,
+  4161,
+  4167,
+  4179,
+  4186,
+  4187,
+  4188,
+  4189,
+  4190,
+  4191,
+  4197,
+  4241,
+  4248,
+  4252,
+  4266,
+  4271,
+  4272,
+  4273,
+  4274,
+  4275,
+  4279,
+  4337,
+  4355,
+  4362,
+  4363,
+  4364,
+  4365,
+  4366,
+  4367,
+  4373,
+  4417,
+  4421,
+  4425,
+  4437,
+  4443,
+  4444,
+  4445,
+  4446,
+  4447,
+  4452,
+  4509,
+  4523,
+  4530,
+  4531,
+  4532,
+  4533,
+  4534,
+  4535,
+  4541,
+  4585,
+  4589,
+  4593,
+  4605,
+  4611,
+  4612,
+  4613,
+  4614,
+  4615,
+  4620,
+  4677,
+  4699,
+  4706,
+  4707,
+  4708,
+  4709,
+  4710,
+  4711,
+  4717,
+  4761,
+  4765,
+  4769,
+  4781,
+  4787,
+  4788,
+  4789,
+  4790,
+  4791,
+  4796,
+  4853,
+  4875,
+  4882,
+  4883,
+  4884,
+  4885,
+  4886,
+  4887,
+  4893,
+  4937,
+  4941,
+  4945,
+  4957,
+  4963,
+  4964,
+  4965,
+  4966,
+  4967,
+  4972,
+  5029,
+  5047,
+  5054,
+  5055,
+  5056,
+  5057,
+  5058,
+  5059,
+  5065,
+  5109,
+  5113,
+  5117,
+  5129,
+  5135,
+  5136,
+  5137,
+  5138,
+  5139,
+  5144,
+  5201,
+  5223,
+  5230,
+  5231,
+  5232,
+  5233,
+  5234,
+  5235,
+  5241,
+  5285,
+  5289,
+  5293,
+  5305,
+  5311,
+  5312,
+  5313,
+  5314,
+  5315,
+  5320,
+];
+
+static PRUNE_3: [usize; 423] = [
+  1132,
+  1133,
+  1140,
+  1148,
+  1149,
+  1156,
+  1164,
+  1165,
+  1172,
+  1180,
+  1181,
+  1188,
+  1211,
+  1212,
+  1213,
+  1214,
+  1217,
+  1218,
+  1268,
+  1270,
+  1287,
+  1288,
+  1289,
+  1292,
+  1293,
+  1355,
+  1360,
+  1365,
+  1370,
+  1375,
+  1380,
+  1385,
+  1390,
+  1413,
+  1414,
+  1415,
+  1416,
+  1419,
+  1420,
+  1463,
+  1467,
+  1471,
+  1475,
+  1498,
+  1499,
+  1500,
+  1502,
+  1503,
+  1563,
+  1567,
+  1572,
+  1576,
+  1581,
+  1585,
+  1590,
+  1594,
+  1617,
+  1618,
+  1619,
+  1620,
+  1623,
+  1624,
+  1667,
+  1671,
+  1675,
+  1679,
+  1702,
+  1703,
+  1704,
+  1706,
+  1707,
+  1767,
+  1772,
+  1777,
+  1782,
+  1805,
+  1806,
+  1807,
+  1808,
+  1811,
+  1812,
+  1855,
+  1859,
+  1863,
+  1867,
+  1890,
+  1891,
+  1892,
+  1894,
+  1895,
+  1955,
+  1962,
+  1963,
+  1968,
+  1975,
+  1976,
+  1981,
+  1988,
+  1989,
+  1994,
+  2001,
+  2002,
+  2025,
+  2026,
+  2027,
+  2028,
+  2031,
+  2032,
+  2075,
+  2079,
+  2083,
+  2087,
+  2110,
+  2111,
+  2112,
+  2114,
+  2115,
+  2173,
+  2178,
+  2183,
+  2188,
+  2213,
+  2214,
+  2215,
+  2216,
+  2219,
+  2220,
+  2263,
+  2267,
+  2271,
+  2275,
+  2298,
+  2299,
+  2300,
+  2302,
+  2303,
+  2389,
+  2390,
+  2391,
+  2392,
+  2395,
+  2396,
+  2439,
+  2443,
+  2447,
+  2451,
+  2474,
+  2475,
+  2476,
+  2478,
+  2479,
+  2537,
+  2540,
+  2543,
+  2546,
+  2569,
+  2570,
+  2571,
+  2572,
+  2575,
+  2576,
+  2619,
+  2623,
+  2627,
+  2631,
+  2654,
+  2655,
+  2656,
+  2658,
+  2659,
+  2717,
+  2721,
+  2724,
+  2728,
+  2731,
+  2735,
+  2738,
+  2742,
+  2765,
+  2766,
+  2767,
+  2768,
+  2771,
+  2772,
+  2815,
+  2819,
+  2823,
+  2827,
+  2850,
+  2851,
+  2852,
+  2854,
+  2855,
+  2913,
+  2916,
+  2919,
+  2922,
+  2945,
+  2946,
+  2947,
+  2948,
+  2951,
+  2952,
+  2995,
+  2999,
+  3003,
+  3007,
+  3030,
+  3031,
+  3032,
+  3034,
+  3035,
+  3094,
+  3096,
+  3100,
+  3102,
+  3106,
+  3108,
+  3112,
+  3114,
+  3137,
+  3138,
+  3139,
+  3140,
+  3143,
+  3144,
+  3190,
+  3194,
+  3204,
+  3205,
+  3215,
+  3216,
+  3217,
+  3278,
+  3281,
+  3284,
+  3287,
+  3310,
+  3311,
+  3312,
+  3313,
+  3316,
+  3317,
+  3363,
+  3367,
+  3377,
+  3378,
+  3388,
+  3389,
+  3390,
+  3451,
+  3454,
+  3457,
+  3460,
+  3483,
+  3484,
+  3485,
+  3486,
+  3489,
+  3490,
+  3536,
+  3540,
+  3550,
+  3551,
+  3561,
+  3562,
+  3563,
+  3652,
+  3653,
+  3654,
+  3655,
+  3658,
+  3659,
+  3702,
+  3709,
+  3713,
+  3736,
+  3737,
+  3738,
+  3740,
+  3741,
+  3828,
+  3829,
+  3830,
+  3831,
+  3834,
+  3835,
+  3878,
+  3885,
+  3889,
+  3912,
+  3913,
+  3914,
+  3916,
+  3917,
+  4000,
+  4001,
+  4002,
+  4003,
+  4006,
+  4007,
+  4050,
+  4057,
+  4061,
+  4084,
+  4085,
+  4086,
+  4088,
+  4089,
+  4150,
+  4156,
+  4162,
+  4168,
+  4192,
+  4193,
+  4194,
+  4195,
+  4198,
+  4199,
+  4242,
+  4249,
+  4253,
+  4276,
+  4277,
+  4278,
+  4280,
+  4281,
+  4368,
+  4369,
+  4370,
+  4371,
+  4374,
+  4375,
+  4418,
+  4422,
+  4426,
+  4448,
+  4449,
+  4450,
+  4453,
+  4454,
+  4536,
+  4537,
+  4538,
+  4539,
+  4542,
+  4543,
+  4586,
+  4590,
+  4594,
+  4616,
+  4617,
+  4618,
+  4621,
+  4622,
+  4680,
+  4683,
+  4686,
+  4689,
+  4712,
+  4713,
+  4714,
+  4715,
+  4718,
+  4719,
+  4762,
+  4766,
+  4770,
+  4792,
+  4793,
+  4794,
+  4797,
+  4798,
+  4856,
+  4859,
+  4862,
+  4865,
+  4888,
+  4889,
+  4890,
+  4891,
+  4894,
+  4895,
+  4938,
+  4942,
+  4946,
+  4968,
+  4969,
+  4970,
+  4973,
+  4974,
+  5060,
+  5061,
+  5062,
+  5063,
+  5066,
+  5067,
+  5110,
+  5114,
+  5118,
+  5140,
+  5141,
+  5142,
+  5145,
+  5146,
+  5204,
+  5207,
+  5210,
+  5213,
+  5236,
+  5237,
+  5238,
+  5239,
+  5242,
+  5243,
+  5286,
+  5290,
+  5294,
+  5316,
+  5317,
+  5318,
+  5321,
+  5322,
+];
+
+static PRUNE_4: [usize; 454] = [
+  1134,
+  1141,
+  1150,
+  1157,
+  1166,
+  1173,
+  1182,
+  1189,
+  1219,
+  1220,
+  1221,
+  1222,
+  1224,
+  1226,
+  1228,
+  1230,
+  1269,
+  1271,
+  1273,
+  1294,
+  1295,
+  1296,
+  1347,
+  1349,
+  1356,
+  1361,
+  1366,
+  1371,
+  1376,
+  1381,
+  1386,
+  1391,
+  1421,
+  1422,
+  1423,
+  1424,
+  1426,
+  1428,
+  1430,
+  1432,
+  1464,
+  1472,
+  1504,
+  1505,
+  1506,
+  1555,
+  1557,
+  1564,
+  1568,
+  1573,
+  1577,
+  1582,
+  1586,
+  1591,
+  1595,
+  1625,
+  1626,
+  1627,
+  1628,
+  1630,
+  1632,
+  1634,
+  1636,
+  1668,
+  1676,
+  1708,
+  1709,
+  1710,
+  1759,
+  1761,
+  1768,
+  1773,
+  1778,
+  1783,
+  1813,
+  1814,
+  1815,
+  1816,
+  1818,
+  1820,
+  1822,
+  1824,
+  1856,
+  1864,
+  1896,
+  1897,
+  1898,
+  1947,
+  1949,
+  1956,
+  1964,
+  1969,
+  1977,
+  1982,
+  1990,
+  1995,
+  2003,
+  2033,
+  2034,
+  2035,
+  2036,
+  2038,
+  2040,
+  2042,
+  2044,
+  2076,
+  2084,
+  2116,
+  2117,
+  2118,
+  2167,
+  2169,
+  2174,
+  2176,
+  2179,
+  2181,
+  2184,
+  2186,
+  2189,
+  2191,
+  2221,
+  2222,
+  2223,
+  2224,
+  2226,
+  2228,
+  2230,
+  2232,
+  2264,
+  2272,
+  2304,
+  2305,
+  2306,
+  2355,
+  2357,
+  2361,
+  2363,
+  2365,
+  2367,
+  2397,
+  2398,
+  2399,
+  2400,
+  2402,
+  2404,
+  2406,
+  2408,
+  2440,
+  2448,
+  2480,
+  2481,
+  2482,
+  2531,
+  2533,
+  2538,
+  2541,
+  2544,
+  2547,
+  2577,
+  2578,
+  2579,
+  2580,
+  2582,
+  2584,
+  2586,
+  2588,
+  2620,
+  2628,
+  2660,
+  2661,
+  2662,
+  2711,
+  2713,
+  2718,
+  2722,
+  2725,
+  2729,
+  2732,
+  2736,
+  2739,
+  2743,
+  2773,
+  2774,
+  2775,
+  2776,
+  2778,
+  2780,
+  2782,
+  2784,
+  2816,
+  2824,
+  2856,
+  2857,
+  2858,
+  2907,
+  2909,
+  2914,
+  2917,
+  2920,
+  2923,
+  2953,
+  2954,
+  2955,
+  2956,
+  2958,
+  2960,
+  2962,
+  2964,
+  2996,
+  3004,
+  3036,
+  3037,
+  3038,
+  3089,
+  3095,
+  3097,
+  3101,
+  3103,
+  3107,
+  3109,
+  3113,
+  3115,
+  3145,
+  3146,
+  3147,
+  3148,
+  3150,
+  3152,
+  3154,
+  3156,
+  3191,
+  3218,
+  3219,
+  3220,
+  3274,
+  3279,
+  3282,
+  3285,
+  3288,
+  3318,
+  3319,
+  3320,
+  3321,
+  3323,
+  3325,
+  3327,
+  3329,
+  3364,
+  3391,
+  3392,
+  3393,
+  3447,
+  3452,
+  3455,
+  3458,
+  3461,
+  3491,
+  3492,
+  3493,
+  3494,
+  3496,
+  3498,
+  3500,
+  3502,
+  3537,
+  3564,
+  3565,
+  3566,
+  3620,
+  3624,
+  3626,
+  3628,
+  3630,
+  3660,
+  3661,
+  3662,
+  3663,
+  3665,
+  3667,
+  3669,
+  3671,
+  3703,
+  3706,
+  3710,
+  3742,
+  3743,
+  3744,
+  3796,
+  3800,
+  3802,
+  3804,
+  3806,
+  3836,
+  3837,
+  3838,
+  3839,
+  3841,
+  3843,
+  3845,
+  3847,
+  3879,
+  3882,
+  3886,
+  3918,
+  3919,
+  3920,
+  3972,
+  4008,
+  4009,
+  4010,
+  4011,
+  4013,
+  4015,
+  4017,
+  4019,
+  4051,
+  4054,
+  4058,
+  4090,
+  4091,
+  4092,
+  4144,
+  4151,
+  4152,
+  4157,
+  4158,
+  4163,
+  4164,
+  4169,
+  4170,
+  4200,
+  4201,
+  4202,
+  4203,
+  4205,
+  4207,
+  4209,
+  4211,
+  4243,
+  4246,
+  4250,
+  4282,
+  4283,
+  4284,
+  4336,
+  4340,
+  4342,
+  4344,
+  4346,
+  4376,
+  4377,
+  4378,
+  4379,
+  4381,
+  4383,
+  4385,
+  4387,
+  4419,
+  4423,
+  4428,
+  4455,
+  4456,
+  4457,
+  4508,
+  4544,
+  4545,
+  4546,
+  4547,
+  4549,
+  4551,
+  4553,
+  4555,
+  4587,
+  4591,
+  4596,
+  4623,
+  4624,
+  4625,
+  4676,
+  4681,
+  4684,
+  4687,
+  4690,
+  4720,
+  4721,
+  4722,
+  4723,
+  4725,
+  4727,
+  4729,
+  4731,
+  4763,
+  4767,
+  4772,
+  4799,
+  4800,
+  4801,
+  4852,
+  4857,
+  4860,
+  4863,
+  4866,
+  4896,
+  4897,
+  4898,
+  4899,
+  4901,
+  4903,
+  4905,
+  4907,
+  4939,
+  4943,
+  4948,
+  4975,
+  4976,
+  4977,
+  5028,
+  5032,
+  5034,
+  5036,
+  5038,
+  5068,
+  5069,
+  5070,
+  5071,
+  5073,
+  5075,
+  5077,
+  5079,
+  5111,
+  5115,
+  5120,
+  5147,
+  5148,
+  5149,
+  5200,
+  5205,
+  5208,
+  5211,
+  5214,
+  5244,
+  5245,
+  5246,
+  5247,
+  5249,
+  5251,
+  5253,
+  5255,
+  5287,
+  5291,
+  5296,
+  5323,
+  5324,
+  5325,
+];
+
+static PRUNE_5: [usize; 333] = [
+  1142,
+  1158,
+  1174,
+  1190,
+  1223,
+  1225,
+  1229,
+  1232,
+  1234,
+  1236,
+  1272,
+  1274,
+  1322,
+  1362,
+  1372,
+  1382,
+  1392,
+  1425,
+  1427,
+  1431,
+  1434,
+  1436,
+  1438,
+  1468,
+  1476,
+  1477,
+  1479,
+  1530,
+  1569,
+  1578,
+  1587,
+  1596,
+  1629,
+  1631,
+  1635,
+  1638,
+  1640,
+  1642,
+  1672,
+  1680,
+  1681,
+  1683,
+  1734,
+  1769,
+  1774,
+  1779,
+  1784,
+  1817,
+  1819,
+  1823,
+  1826,
+  1828,
+  1830,
+  1860,
+  1868,
+  1869,
+  1871,
+  1922,
+  1965,
+  1978,
+  1991,
+  2004,
+  2037,
+  2039,
+  2043,
+  2046,
+  2048,
+  2050,
+  2080,
+  2088,
+  2089,
+  2091,
+  2142,
+  2177,
+  2182,
+  2187,
+  2192,
+  2225,
+  2227,
+  2231,
+  2234,
+  2236,
+  2238,
+  2268,
+  2276,
+  2277,
+  2279,
+  2330,
+  2362,
+  2364,
+  2366,
+  2368,
+  2401,
+  2403,
+  2407,
+  2410,
+  2412,
+  2414,
+  2444,
+  2452,
+  2453,
+  2455,
+  2506,
+  2539,
+  2542,
+  2545,
+  2548,
+  2581,
+  2583,
+  2587,
+  2590,
+  2592,
+  2594,
+  2624,
+  2632,
+  2633,
+  2635,
+  2686,
+  2723,
+  2730,
+  2737,
+  2744,
+  2777,
+  2779,
+  2783,
+  2786,
+  2788,
+  2790,
+  2820,
+  2828,
+  2829,
+  2831,
+  2882,
+  2915,
+  2918,
+  2921,
+  2924,
+  2957,
+  2959,
+  2963,
+  2966,
+  2968,
+  2970,
+  3000,
+  3008,
+  3009,
+  3011,
+  3062,
+  3098,
+  3104,
+  3110,
+  3116,
+  3149,
+  3151,
+  3155,
+  3158,
+  3160,
+  3162,
+  3195,
+  3198,
+  3247,
+  3280,
+  3283,
+  3286,
+  3289,
+  3322,
+  3324,
+  3328,
+  3331,
+  3333,
+  3335,
+  3368,
+  3371,
+  3420,
+  3453,
+  3456,
+  3459,
+  3462,
+  3495,
+  3497,
+  3501,
+  3504,
+  3506,
+  3508,
+  3541,
+  3544,
+  3593,
+  3625,
+  3627,
+  3629,
+  3631,
+  3664,
+  3666,
+  3670,
+  3673,
+  3675,
+  3677,
+  3714,
+  3715,
+  3718,
+  3769,
+  3801,
+  3803,
+  3805,
+  3807,
+  3840,
+  3842,
+  3846,
+  3849,
+  3851,
+  3853,
+  3890,
+  3891,
+  3894,
+  3945,
+  3976,
+  3977,
+  3978,
+  3979,
+  4012,
+  4014,
+  4018,
+  4021,
+  4023,
+  4025,
+  4062,
+  4063,
+  4066,
+  4117,
+  4153,
+  4159,
+  4165,
+  4171,
+  4204,
+  4206,
+  4210,
+  4213,
+  4215,
+  4217,
+  4254,
+  4255,
+  4258,
+  4309,
+  4338,
+  4341,
+  4343,
+  4345,
+  4347,
+  4380,
+  4382,
+  4386,
+  4389,
+  4391,
+  4393,
+  4427,
+  4429,
+  4430,
+  4481,
+  4510,
+  4512,
+  4513,
+  4514,
+  4515,
+  4548,
+  4550,
+  4554,
+  4557,
+  4559,
+  4561,
+  4595,
+  4597,
+  4598,
+  4649,
+  4678,
+  4682,
+  4685,
+  4688,
+  4691,
+  4724,
+  4726,
+  4730,
+  4733,
+  4735,
+  4737,
+  4771,
+  4773,
+  4774,
+  4825,
+  4854,
+  4858,
+  4861,
+  4864,
+  4867,
+  4900,
+  4902,
+  4906,
+  4909,
+  4911,
+  4913,
+  4947,
+  4949,
+  4950,
+  5001,
+  5030,
+  5033,
+  5035,
+  5037,
+  5039,
+  5072,
+  5074,
+  5078,
+  5081,
+  5083,
+  5085,
+  5119,
+  5121,
+  5122,
+  5173,
+  5202,
+  5206,
+  5209,
+  5212,
+  5215,
+  5248,
+  5250,
+  5254,
+  5257,
+  5259,
+  5261,
+  5295,
+  5297,
+  5298,
+  5349,
+];
+
+static PRUNE_6: [usize; 387] = [
+  1191,
+  1192,
+  1194,
+  1195,
+  1227,
+  1231,
+  1235,
+  1238,
+  1239,
+  1241,
+  1275,
+  1276,
+  1323,
+  1325,
+  1328,
+  1331,
+  1334,
+  1351,
+  1393,
+  1394,
+  1396,
+  1397,
+  1429,
+  1433,
+  1437,
+  1440,
+  1441,
+  1443,
+  1478,
+  1531,
+  1533,
+  1536,
+  1539,
+  1542,
+  1559,
+  1597,
+  1598,
+  1600,
+  1601,
+  1633,
+  1637,
+  1641,
+  1644,
+  1645,
+  1647,
+  1682,
+  1735,
+  1737,
+  1740,
+  1743,
+  1746,
+  1763,
+  1785,
+  1786,
+  1788,
+  1789,
+  1821,
+  1825,
+  1829,
+  1832,
+  1833,
+  1835,
+  1870,
+  1923,
+  1925,
+  1928,
+  1931,
+  1934,
+  1951,
+  2005,
+  2006,
+  2008,
+  2009,
+  2041,
+  2045,
+  2049,
+  2052,
+  2053,
+  2055,
+  2090,
+  2143,
+  2145,
+  2148,
+  2151,
+  2154,
+  2171,
+  2193,
+  2194,
+  2196,
+  2197,
+  2229,
+  2233,
+  2237,
+  2240,
+  2241,
+  2243,
+  2278,
+  2331,
+  2333,
+  2336,
+  2339,
+  2342,
+  2359,
+  2369,
+  2370,
+  2372,
+  2373,
+  2405,
+  2409,
+  2413,
+  2416,
+  2417,
+  2419,
+  2454,
+  2507,
+  2509,
+  2512,
+  2515,
+  2518,
+  2535,
+  2549,
+  2550,
+  2552,
+  2553,
+  2585,
+  2589,
+  2593,
+  2596,
+  2597,
+  2599,
+  2634,
+  2687,
+  2689,
+  2692,
+  2695,
+  2698,
+  2715,
+  2745,
+  2746,
+  2748,
+  2749,
+  2781,
+  2785,
+  2789,
+  2792,
+  2793,
+  2795,
+  2830,
+  2883,
+  2885,
+  2888,
+  2891,
+  2894,
+  2911,
+  2925,
+  2926,
+  2928,
+  2929,
+  2961,
+  2965,
+  2969,
+  2972,
+  2973,
+  2975,
+  3010,
+  3063,
+  3065,
+  3068,
+  3071,
+  3074,
+  3117,
+  3118,
+  3120,
+  3121,
+  3153,
+  3157,
+  3161,
+  3164,
+  3165,
+  3167,
+  3199,
+  3200,
+  3248,
+  3250,
+  3253,
+  3256,
+  3259,
+  3290,
+  3291,
+  3293,
+  3294,
+  3326,
+  3330,
+  3334,
+  3337,
+  3338,
+  3340,
+  3372,
+  3373,
+  3421,
+  3423,
+  3426,
+  3429,
+  3432,
+  3463,
+  3464,
+  3466,
+  3467,
+  3499,
+  3503,
+  3507,
+  3510,
+  3511,
+  3513,
+  3545,
+  3546,
+  3594,
+  3596,
+  3599,
+  3602,
+  3605,
+  3632,
+  3633,
+  3635,
+  3636,
+  3668,
+  3672,
+  3676,
+  3679,
+  3680,
+  3682,
+  3716,
+  3770,
+  3772,
+  3775,
+  3778,
+  3781,
+  3808,
+  3809,
+  3811,
+  3812,
+  3844,
+  3848,
+  3852,
+  3855,
+  3856,
+  3858,
+  3892,
+  3946,
+  3948,
+  3951,
+  3954,
+  3957,
+  3980,
+  3981,
+  3983,
+  3984,
+  4016,
+  4020,
+  4024,
+  4027,
+  4028,
+  4030,
+  4064,
+  4118,
+  4120,
+  4123,
+  4126,
+  4129,
+  4172,
+  4173,
+  4175,
+  4176,
+  4208,
+  4212,
+  4216,
+  4219,
+  4220,
+  4222,
+  4256,
+  4310,
+  4312,
+  4315,
+  4318,
+  4321,
+  4348,
+  4349,
+  4351,
+  4352,
+  4384,
+  4388,
+  4392,
+  4395,
+  4396,
+  4398,
+  4431,
+  4432,
+  4482,
+  4484,
+  4487,
+  4490,
+  4493,
+  4516,
+  4517,
+  4519,
+  4520,
+  4552,
+  4556,
+  4560,
+  4563,
+  4564,
+  4566,
+  4599,
+  4600,
+  4650,
+  4652,
+  4655,
+  4658,
+  4661,
+  4692,
+  4693,
+  4695,
+  4696,
+  4728,
+  4732,
+  4736,
+  4739,
+  4740,
+  4742,
+  4775,
+  4776,
+  4826,
+  4828,
+  4831,
+  4834,
+  4837,
+  4868,
+  4869,
+  4871,
+  4872,
+  4904,
+  4908,
+  4912,
+  4915,
+  4916,
+  4918,
+  4951,
+  4952,
+  5002,
+  5004,
+  5007,
+  5010,
+  5013,
+  5040,
+  5041,
+  5043,
+  5044,
+  5076,
+  5080,
+  5084,
+  5087,
+  5088,
+  5090,
+  5123,
+  5124,
+  5174,
+  5176,
+  5179,
+  5182,
+  5185,
+  5216,
+  5217,
+  5219,
+  5220,
+  5252,
+  5256,
+  5260,
+  5263,
+  5264,
+  5266,
+  5299,
+  5300,
+  5350,
+  5352,
+  5355,
+  5358,
+  5361,
+];
+
+static PRUNE_7: [usize; 374] = [
+  1193,
+  1196,
+  1233,
+  1240,
+  1242,
+  1244,
+  1245,
+  1277,
+  1298,
+  1301,
+  1304,
+  1307,
+  1310,
+  1313,
+  1316,
+  1326,
+  1329,
+  1332,
+  1335,
+  1337,
+  1340,
+  1343,
+  1395,
+  1398,
+  1435,
+  1442,
+  1444,
+  1446,
+  1447,
+  1480,
+  1481,
+  1534,
+  1537,
+  1540,
+  1543,
+  1545,
+  1548,
+  1551,
+  1599,
+  1602,
+  1639,
+  1646,
+  1648,
+  1650,
+  1651,
+  1684,
+  1685,
+  1738,
+  1741,
+  1744,
+  1747,
+  1749,
+  1752,
+  1755,
+  1787,
+  1790,
+  1827,
+  1834,
+  1836,
+  1838,
+  1839,
+  1872,
+  1873,
+  1926,
+  1929,
+  1932,
+  1935,
+  1937,
+  1940,
+  1943,
+  2007,
+  2010,
+  2047,
+  2054,
+  2056,
+  2058,
+  2059,
+  2092,
+  2093,
+  2146,
+  2149,
+  2152,
+  2155,
+  2157,
+  2160,
+  2163,
+  2195,
+  2198,
+  2235,
+  2242,
+  2244,
+  2246,
+  2247,
+  2280,
+  2281,
+  2334,
+  2337,
+  2340,
+  2343,
+  2345,
+  2348,
+  2351,
+  2371,
+  2374,
+  2411,
+  2418,
+  2420,
+  2422,
+  2423,
+  2456,
+  2457,
+  2510,
+  2513,
+  2516,
+  2519,
+  2521,
+  2524,
+  2527,
+  2551,
+  2554,
+  2591,
+  2598,
+  2600,
+  2602,
+  2603,
+  2636,
+  2637,
+  2690,
+  2693,
+  2696,
+  2699,
+  2701,
+  2704,
+  2707,
+  2747,
+  2750,
+  2787,
+  2794,
+  2796,
+  2798,
+  2799,
+  2832,
+  2833,
+  2886,
+  2889,
+  2892,
+  2895,
+  2897,
+  2900,
+  2903,
+  2927,
+  2930,
+  2967,
+  2974,
+  2976,
+  2978,
+  2979,
+  3012,
+  3013,
+  3066,
+  3069,
+  3072,
+  3075,
+  3077,
+  3080,
+  3083,
+  3119,
+  3122,
+  3159,
+  3166,
+  3168,
+  3170,
+  3171,
+  3201,
+  3206,
+  3251,
+  3254,
+  3257,
+  3260,
+  3262,
+  3265,
+  3268,
+  3292,
+  3295,
+  3332,
+  3339,
+  3341,
+  3343,
+  3344,
+  3374,
+  3379,
+  3424,
+  3427,
+  3430,
+  3433,
+  3435,
+  3438,
+  3441,
+  3465,
+  3468,
+  3505,
+  3512,
+  3514,
+  3516,
+  3517,
+  3547,
+  3552,
+  3597,
+  3600,
+  3603,
+  3606,
+  3608,
+  3611,
+  3614,
+  3622,
+  3634,
+  3637,
+  3674,
+  3681,
+  3683,
+  3685,
+  3686,
+  3717,
+  3773,
+  3776,
+  3779,
+  3782,
+  3784,
+  3787,
+  3790,
+  3798,
+  3810,
+  3813,
+  3850,
+  3857,
+  3859,
+  3861,
+  3862,
+  3893,
+  3949,
+  3952,
+  3955,
+  3958,
+  3960,
+  3963,
+  3966,
+  3974,
+  3982,
+  3985,
+  4022,
+  4029,
+  4031,
+  4033,
+  4034,
+  4065,
+  4121,
+  4124,
+  4127,
+  4130,
+  4132,
+  4135,
+  4138,
+  4146,
+  4174,
+  4177,
+  4214,
+  4221,
+  4223,
+  4225,
+  4226,
+  4257,
+  4313,
+  4316,
+  4319,
+  4322,
+  4324,
+  4327,
+  4330,
+  4350,
+  4353,
+  4390,
+  4397,
+  4399,
+  4401,
+  4402,
+  4433,
+  4434,
+  4485,
+  4488,
+  4491,
+  4494,
+  4496,
+  4499,
+  4502,
+  4518,
+  4521,
+  4558,
+  4565,
+  4567,
+  4569,
+  4570,
+  4601,
+  4602,
+  4653,
+  4656,
+  4659,
+  4662,
+  4664,
+  4667,
+  4670,
+  4694,
+  4697,
+  4734,
+  4741,
+  4743,
+  4745,
+  4746,
+  4777,
+  4778,
+  4829,
+  4832,
+  4835,
+  4838,
+  4840,
+  4843,
+  4846,
+  4870,
+  4873,
+  4910,
+  4917,
+  4919,
+  4921,
+  4922,
+  4953,
+  4954,
+  5005,
+  5008,
+  5011,
+  5014,
+  5016,
+  5019,
+  5022,
+  5042,
+  5045,
+  5082,
+  5089,
+  5091,
+  5093,
+  5094,
+  5125,
+  5126,
+  5177,
+  5180,
+  5183,
+  5186,
+  5188,
+  5191,
+  5194,
+  5218,
+  5221,
+  5258,
+  5265,
+  5267,
+  5269,
+  5270,
+  5301,
+  5302,
+  5353,
+  5356,
+  5359,
+  5362,
+  5364,
+  5367,
+  5370,
+];
+
+static PRUNE_8: [usize; 312] = [
+  1237,
+  1243,
+  1246,
+  1248,
+  1249,
+  1250,
+  1299,
+  1302,
+  1305,
+  1308,
+  1311,
+  1314,
+  1317,
+  1338,
+  1341,
+  1344,
+  1439,
+  1445,
+  1448,
+  1450,
+  1451,
+  1452,
+  1482,
+  1484,
+  1546,
+  1549,
+  1552,
+  1643,
+  1649,
+  1652,
+  1654,
+  1655,
+  1656,
+  1686,
+  1688,
+  1750,
+  1753,
+  1756,
+  1831,
+  1837,
+  1840,
+  1842,
+  1843,
+  1844,
+  1874,
+  1876,
+  1938,
+  1941,
+  1944,
+  2051,
+  2057,
+  2060,
+  2062,
+  2063,
+  2064,
+  2094,
+  2096,
+  2158,
+  2161,
+  2164,
+  2239,
+  2245,
+  2248,
+  2250,
+  2251,
+  2252,
+  2282,
+  2284,
+  2346,
+  2349,
+  2352,
+  2415,
+  2421,
+  2424,
+  2426,
+  2427,
+  2428,
+  2458,
+  2460,
+  2522,
+  2525,
+  2528,
+  2595,
+  2601,
+  2604,
+  2606,
+  2607,
+  2608,
+  2638,
+  2640,
+  2702,
+  2705,
+  2708,
+  2791,
+  2797,
+  2800,
+  2802,
+  2803,
+  2804,
+  2834,
+  2836,
+  2898,
+  2901,
+  2904,
+  2971,
+  2977,
+  2980,
+  2982,
+  2983,
+  2984,
+  3014,
+  3016,
+  3078,
+  3081,
+  3084,
+  3163,
+  3169,
+  3172,
+  3174,
+  3175,
+  3176,
+  3207,
+  3222,
+  3225,
+  3228,
+  3231,
+  3234,
+  3237,
+  3240,
+  3263,
+  3266,
+  3269,
+  3336,
+  3342,
+  3345,
+  3347,
+  3348,
+  3349,
+  3380,
+  3395,
+  3398,
+  3401,
+  3404,
+  3407,
+  3410,
+  3413,
+  3436,
+  3439,
+  3442,
+  3509,
+  3515,
+  3518,
+  3520,
+  3521,
+  3522,
+  3553,
+  3568,
+  3571,
+  3574,
+  3577,
+  3580,
+  3583,
+  3586,
+  3609,
+  3612,
+  3615,
+  3678,
+  3684,
+  3687,
+  3689,
+  3690,
+  3691,
+  3719,
+  3720,
+  3785,
+  3788,
+  3791,
+  3854,
+  3860,
+  3863,
+  3865,
+  3866,
+  3867,
+  3895,
+  3896,
+  3961,
+  3964,
+  3967,
+  4026,
+  4032,
+  4035,
+  4037,
+  4038,
+  4039,
+  4067,
+  4068,
+  4133,
+  4136,
+  4139,
+  4218,
+  4224,
+  4227,
+  4229,
+  4230,
+  4231,
+  4259,
+  4260,
+  4325,
+  4328,
+  4331,
+  4394,
+  4400,
+  4403,
+  4405,
+  4406,
+  4407,
+  4435,
+  4459,
+  4462,
+  4465,
+  4468,
+  4471,
+  4474,
+  4477,
+  4497,
+  4500,
+  4503,
+  4562,
+  4568,
+  4571,
+  4573,
+  4574,
+  4575,
+  4603,
+  4627,
+  4630,
+  4633,
+  4636,
+  4639,
+  4642,
+  4645,
+  4665,
+  4668,
+  4671,
+  4738,
+  4744,
+  4747,
+  4749,
+  4750,
+  4751,
+  4779,
+  4803,
+  4806,
+  4809,
+  4812,
+  4815,
+  4818,
+  4821,
+  4841,
+  4844,
+  4847,
+  4914,
+  4920,
+  4923,
+  4925,
+  4926,
+  4927,
+  4955,
+  4979,
+  4982,
+  4985,
+  4988,
+  4991,
+  4994,
+  4997,
+  5017,
+  5020,
+  5023,
+  5086,
+  5092,
+  5095,
+  5097,
+  5098,
+  5099,
+  5127,
+  5151,
+  5154,
+  5157,
+  5160,
+  5163,
+  5166,
+  5169,
+  5189,
+  5192,
+  5195,
+  5262,
+  5268,
+  5271,
+  5273,
+  5274,
+  5275,
+  5303,
+  5327,
+  5330,
+  5333,
+  5336,
+  5339,
+  5342,
+  5345,
+  5365,
+  5368,
+  5371,
+];
+
+static PRUNE_9: [usize; 241] = [
+  1247,
+  1251,
+  1252,
+  1254,
+  1303,
+  1312,
+  1315,
+  1449,
+  1453,
+  1454,
+  1456,
+  1485,
+  1508,
+  1511,
+  1514,
+  1517,
+  1520,
+  1523,
+  1526,
+  1653,
+  1657,
+  1658,
+  1660,
+  1689,
+  1712,
+  1715,
+  1718,
+  1721,
+  1724,
+  1727,
+  1730,
+  1841,
+  1845,
+  1846,
+  1848,
+  1877,
+  1900,
+  1903,
+  1906,
+  1909,
+  1912,
+  1915,
+  1918,
+  2061,
+  2065,
+  2066,
+  2068,
+  2097,
+  2120,
+  2123,
+  2126,
+  2129,
+  2132,
+  2135,
+  2138,
+  2249,
+  2253,
+  2254,
+  2256,
+  2285,
+  2308,
+  2311,
+  2314,
+  2317,
+  2320,
+  2323,
+  2326,
+  2425,
+  2429,
+  2430,
+  2432,
+  2461,
+  2484,
+  2487,
+  2490,
+  2493,
+  2496,
+  2499,
+  2502,
+  2605,
+  2609,
+  2610,
+  2612,
+  2641,
+  2664,
+  2667,
+  2670,
+  2673,
+  2676,
+  2679,
+  2682,
+  2801,
+  2805,
+  2806,
+  2808,
+  2837,
+  2860,
+  2863,
+  2866,
+  2869,
+  2872,
+  2875,
+  2878,
+  2981,
+  2985,
+  2986,
+  2988,
+  3017,
+  3040,
+  3043,
+  3046,
+  3049,
+  3052,
+  3055,
+  3058,
+  3173,
+  3177,
+  3178,
+  3180,
+  3221,
+  3223,
+  3226,
+  3229,
+  3232,
+  3235,
+  3238,
+  3241,
+  3346,
+  3350,
+  3351,
+  3353,
+  3394,
+  3396,
+  3399,
+  3402,
+  3405,
+  3408,
+  3411,
+  3414,
+  3519,
+  3523,
+  3524,
+  3526,
+  3567,
+  3569,
+  3572,
+  3575,
+  3578,
+  3581,
+  3584,
+  3587,
+  3688,
+  3692,
+  3693,
+  3695,
+  3721,
+  3723,
+  3864,
+  3868,
+  3869,
+  3871,
+  3897,
+  3899,
+  4036,
+  4040,
+  4041,
+  4043,
+  4069,
+  4071,
+  4228,
+  4232,
+  4233,
+  4235,
+  4261,
+  4263,
+  4404,
+  4408,
+  4409,
+  4411,
+  4460,
+  4463,
+  4466,
+  4469,
+  4472,
+  4475,
+  4478,
+  4572,
+  4576,
+  4577,
+  4579,
+  4628,
+  4631,
+  4634,
+  4637,
+  4640,
+  4643,
+  4646,
+  4748,
+  4752,
+  4753,
+  4755,
+  4804,
+  4807,
+  4810,
+  4813,
+  4816,
+  4819,
+  4822,
+  4924,
+  4928,
+  4929,
+  4931,
+  4980,
+  4983,
+  4986,
+  4989,
+  4992,
+  4995,
+  4998,
+  5096,
+  5100,
+  5101,
+  5103,
+  5152,
+  5155,
+  5158,
+  5161,
+  5164,
+  5167,
+  5170,
+  5272,
+  5276,
+  5277,
+  5279,
+  5328,
+  5331,
+  5334,
+  5337,
+  5340,
+  5343,
+  5346,
+];
+
+static PRUNE_10: [usize; 165] = [
+  1253,
+  1255,
+  1455,
+  1457,
+  1507,
+  1509,
+  1512,
+  1515,
+  1518,
+  1521,
+  1524,
+  1527,
+  1659,
+  1661,
+  1711,
+  1713,
+  1716,
+  1719,
+  1722,
+  1725,
+  1728,
+  1731,
+  1847,
+  1849,
+  1899,
+  1901,
+  1904,
+  1907,
+  1910,
+  1913,
+  1916,
+  1919,
+  2067,
+  2069,
+  2119,
+  2121,
+  2124,
+  2127,
+  2130,
+  2133,
+  2136,
+  2139,
+  2255,
+  2257,
+  2307,
+  2309,
+  2312,
+  2315,
+  2318,
+  2321,
+  2324,
+  2327,
+  2431,
+  2433,
+  2483,
+  2485,
+  2488,
+  2491,
+  2494,
+  2497,
+  2500,
+  2503,
+  2611,
+  2613,
+  2663,
+  2665,
+  2668,
+  2671,
+  2674,
+  2677,
+  2680,
+  2683,
+  2807,
+  2809,
+  2859,
+  2861,
+  2864,
+  2867,
+  2870,
+  2873,
+  2876,
+  2879,
+  2987,
+  2989,
+  3039,
+  3041,
+  3044,
+  3047,
+  3050,
+  3053,
+  3056,
+  3059,
+  3179,
+  3181,
+  3224,
+  3227,
+  3230,
+  3352,
+  3354,
+  3397,
+  3400,
+  3403,
+  3525,
+  3527,
+  3570,
+  3573,
+  3576,
+  3694,
+  3696,
+  3724,
+  3746,
+  3749,
+  3752,
+  3755,
+  3758,
+  3761,
+  3764,
+  3870,
+  3872,
+  3900,
+  3922,
+  3925,
+  3928,
+  3931,
+  3934,
+  3937,
+  3940,
+  4042,
+  4044,
+  4072,
+  4094,
+  4097,
+  4100,
+  4103,
+  4106,
+  4109,
+  4112,
+  4234,
+  4236,
+  4264,
+  4286,
+  4289,
+  4292,
+  4295,
+  4298,
+  4301,
+  4304,
+  4410,
+  4412,
+  4461,
+  4578,
+  4580,
+  4629,
+  4754,
+  4756,
+  4805,
+  4930,
+  4932,
+  4981,
+  5102,
+  5104,
+  5153,
+  5278,
+  5280,
+  5329,
+];
+
+static PRUNE_11: [usize; 78] = [
+  1197,
+  1256,
+  1399,
+  1458,
+  1603,
+  1662,
+  1791,
+  1850,
+  2011,
+  2070,
+  2199,
+  2258,
+  2375,
+  2434,
+  2555,
+  2614,
+  2751,
+  2810,
+  2931,
+  2990,
+  3123,
+  3182,
+  3296,
+  3355,
+  3469,
+  3528,
+  3638,
+  3697,
+  3745,
+  3747,
+  3750,
+  3753,
+  3756,
+  3759,
+  3762,
+  3765,
+  3814,
+  3873,
+  3921,
+  3923,
+  3926,
+  3929,
+  3932,
+  3935,
+  3938,
+  3941,
+  3986,
+  4045,
+  4093,
+  4095,
+  4098,
+  4101,
+  4104,
+  4107,
+  4110,
+  4113,
+  4178,
+  4237,
+  4285,
+  4287,
+  4290,
+  4293,
+  4296,
+  4299,
+  4302,
+  4305,
+  4354,
+  4413,
+  4522,
+  4581,
+  4698,
+  4757,
+  4874,
+  4933,
+  5046,
+  5105,
+  5222,
+  5281,
+];
+
+static PRUNE_12: [usize; 50] = [
+  1126,
+  1257,
+  1352,
+  1459,
+  1560,
+  1663,
+  1764,
+  1851,
+  1952,
+  2071,
+  2172,
+  2259,
+  2360,
+  2435,
+  2536,
+  2615,
+  2716,
+  2811,
+  2912,
+  2991,
+  3092,
+  3183,
+  3277,
+  3356,
+  3450,
+  3529,
+  3623,
+  3698,
+  3751,
+  3799,
+  3874,
+  3927,
+  3975,
+  4046,
+  4099,
+  4147,
+  4238,
+  4291,
+  4339,
+  4414,
+  4511,
+  4582,
+  4679,
+  4758,
+  4855,
+  4934,
+  5031,
+  5106,
+  5203,
+  5282,
+];
+
+static PRUNE_13: [usize; 353] = [
+  1258,
+  1297,
+  1300,
+  1306,
+  1309,
+  1318,
+  1324,
+  1327,
+  1330,
+  1333,
+  1336,
+  1339,
+  1342,
+  1345,
+  1460,
+  1510,
+  1513,
+  1516,
+  1519,
+  1522,
+  1525,
+  1528,
+  1532,
+  1535,
+  1538,
+  1541,
+  1544,
+  1547,
+  1550,
+  1553,
+  1664,
+  1714,
+  1717,
+  1720,
+  1723,
+  1726,
+  1729,
+  1732,
+  1736,
+  1739,
+  1742,
+  1745,
+  1748,
+  1751,
+  1754,
+  1757,
+  1852,
+  1902,
+  1905,
+  1908,
+  1911,
+  1914,
+  1917,
+  1920,
+  1924,
+  1927,
+  1930,
+  1933,
+  1936,
+  1939,
+  1942,
+  1945,
+  2072,
+  2122,
+  2125,
+  2128,
+  2131,
+  2134,
+  2137,
+  2140,
+  2144,
+  2147,
+  2150,
+  2153,
+  2156,
+  2159,
+  2162,
+  2165,
+  2260,
+  2310,
+  2313,
+  2316,
+  2319,
+  2322,
+  2325,
+  2328,
+  2332,
+  2335,
+  2338,
+  2341,
+  2344,
+  2347,
+  2350,
+  2353,
+  2436,
+  2486,
+  2489,
+  2492,
+  2495,
+  2498,
+  2501,
+  2504,
+  2508,
+  2511,
+  2514,
+  2517,
+  2520,
+  2523,
+  2526,
+  2529,
+  2616,
+  2666,
+  2669,
+  2672,
+  2675,
+  2678,
+  2681,
+  2684,
+  2688,
+  2691,
+  2694,
+  2697,
+  2700,
+  2703,
+  2706,
+  2709,
+  2812,
+  2862,
+  2865,
+  2868,
+  2871,
+  2874,
+  2877,
+  2880,
+  2884,
+  2887,
+  2890,
+  2893,
+  2896,
+  2899,
+  2902,
+  2905,
+  2992,
+  3042,
+  3045,
+  3048,
+  3051,
+  3054,
+  3057,
+  3060,
+  3064,
+  3067,
+  3070,
+  3073,
+  3076,
+  3079,
+  3082,
+  3085,
+  3184,
+  3233,
+  3236,
+  3239,
+  3242,
+  3249,
+  3252,
+  3255,
+  3258,
+  3261,
+  3264,
+  3267,
+  3270,
+  3357,
+  3406,
+  3409,
+  3412,
+  3415,
+  3422,
+  3425,
+  3428,
+  3431,
+  3434,
+  3437,
+  3440,
+  3443,
+  3530,
+  3579,
+  3582,
+  3585,
+  3588,
+  3595,
+  3598,
+  3601,
+  3604,
+  3607,
+  3610,
+  3613,
+  3616,
+  3699,
+  3748,
+  3754,
+  3757,
+  3760,
+  3763,
+  3766,
+  3771,
+  3774,
+  3777,
+  3780,
+  3783,
+  3786,
+  3789,
+  3792,
+  3875,
+  3924,
+  3930,
+  3933,
+  3936,
+  3939,
+  3942,
+  3947,
+  3950,
+  3953,
+  3956,
+  3959,
+  3962,
+  3965,
+  3968,
+  4047,
+  4096,
+  4102,
+  4105,
+  4108,
+  4111,
+  4114,
+  4119,
+  4122,
+  4125,
+  4128,
+  4131,
+  4134,
+  4137,
+  4140,
+  4239,
+  4288,
+  4294,
+  4297,
+  4300,
+  4303,
+  4306,
+  4311,
+  4314,
+  4317,
+  4320,
+  4323,
+  4326,
+  4329,
+  4332,
+  4415,
+  4458,
+  4464,
+  4467,
+  4470,
+  4473,
+  4476,
+  4479,
+  4483,
+  4486,
+  4489,
+  4492,
+  4495,
+  4498,
+  4501,
+  4504,
+  4583,
+  4626,
+  4632,
+  4635,
+  4638,
+  4641,
+  4644,
+  4647,
+  4651,
+  4654,
+  4657,
+  4660,
+  4663,
+  4666,
+  4669,
+  4672,
+  4759,
+  4802,
+  4808,
+  4811,
+  4814,
+  4817,
+  4820,
+  4823,
+  4827,
+  4830,
+  4833,
+  4836,
+  4839,
+  4842,
+  4845,
+  4848,
+  4935,
+  4978,
+  4984,
+  4987,
+  4990,
+  4993,
+  4996,
+  4999,
+  5003,
+  5006,
+  5009,
+  5012,
+  5015,
+  5018,
+  5021,
+  5024,
+  5107,
+  5150,
+  5156,
+  5159,
+  5162,
+  5165,
+  5168,
+  5171,
+  5175,
+  5178,
+  5181,
+  5184,
+  5187,
+  5190,
+  5193,
+  5196,
+  5283,
+  5326,
+  5332,
+  5335,
+  5338,
+  5341,
+  5344,
+  5347,
+  5351,
+  5354,
+  5357,
+  5360,
+  5363,
+  5366,
+  5369,
+  5372,
+];
+
+static PRUNE_14: [usize; 61] = [
+  1278,
+  1319,
+  1320,
+  1321,
+  1486,
+  1529,
+  1690,
+  1733,
+  1878,
+  1921,
+  2098,
+  2141,
+  2286,
+  2329,
+  2462,
+  2505,
+  2642,
+  2685,
+  2838,
+  2881,
+  3018,
+  3061,
+  3208,
+  3243,
+  3244,
+  3245,
+  3246,
+  3381,
+  3416,
+  3417,
+  3418,
+  3419,
+  3554,
+  3589,
+  3590,
+  3591,
+  3592,
+  3725,
+  3767,
+  3768,
+  3901,
+  3943,
+  3944,
+  4073,
+  4115,
+  4116,
+  4265,
+  4307,
+  4308,
+  4436,
+  4480,
+  4604,
+  4648,
+  4780,
+  4824,
+  4956,
+  5000,
+  5128,
+  5172,
+  5304,
+  5348,
+];
+
+fn prune<E: BoolEvaluator>(
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    temp_node_ids: &[usize],
+) {
+  for x in temp_node_ids {
+    temp_nodes.remove(&x);
+  }
+}
+
+pub fn maintain_monsters<E: BoolEvaluator>(dead_ticks: &Vec<FheBool<E>>, monsters: &Vec<FheBool<E>>, players_coords: &Vec<FheBool<E>>) -> Vec<FheBool<E>> {
+    let args: &[&Vec<FheBool<E>>] = &[monsters, dead_ticks, players_coords];
+
+    let mut temp_nodes = HashMap::new();
+    let mut out = Vec::new();
+    out.resize(1104, None);
+
+    let mut run_level = |
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    tasks: &[((usize, bool, CellType), &[GateInput])]
+    | {
+        let updates = tasks
+            .into_par_iter()
+            .map(|(k, task_args)| {
+                let (id, is_output, celltype) = k;
+                let task_args = task_args.into_iter()
+                .map(|arg| match arg {
+                    Cst(false) => todo!(),
+                    Cst(true) => todo!(),
+                    Arg(pos, ndx) => &args[*pos][*ndx],
+                    Tv(ndx) => &temp_nodes[ndx],
+                    Output(ndx) => &out[*ndx]
+                                .as_ref()
+                                .expect(&format!("Output node {ndx} not found")),
+                }).collect::<Vec<_>>();
+
+                let gate_func = |args: &[&FheBool<E>]| match celltype {
+                    AND2 => args[0] & args[1],
+                    NAND2 => args[0].bitnand(args[1]),
+                    OR2 => args[0] | args[1],
+                    NOR2 => args[0].bitnor(args[1]),
+                    XOR2 => args[0] ^ args[1],
+                    XNOR2 => args[0].bitxnor(args[1]),
+                    INV => !args[0],
+                };
+                
+                ((*id, *is_output), gate_func(&task_args))
+            })
+            .collect::<Vec<_>>();
+        updates.into_iter().for_each(|(k, v)| {
+            let (index, is_output) = k;
+            if is_output {
+                out[index] = Some(v);
+            } else {
+                temp_nodes.insert(index, v);
+            }
+        });
+    };
+
+        run_level(&mut temp_nodes, &LEVEL_0);
+        run_level(&mut temp_nodes, &LEVEL_1);
+        prune(&mut temp_nodes, &PRUNE_1);
+        run_level(&mut temp_nodes, &LEVEL_2);
+        prune(&mut temp_nodes, &PRUNE_2);
+        run_level(&mut temp_nodes, &LEVEL_3);
+        prune(&mut temp_nodes, &PRUNE_3);
+        run_level(&mut temp_nodes, &LEVEL_4);
+        prune(&mut temp_nodes, &PRUNE_4);
+        run_level(&mut temp_nodes, &LEVEL_5);
+        prune(&mut temp_nodes, &PRUNE_5);
+        run_level(&mut temp_nodes, &LEVEL_6);
+        prune(&mut temp_nodes, &PRUNE_6);
+        run_level(&mut temp_nodes, &LEVEL_7);
+        prune(&mut temp_nodes, &PRUNE_7);
+        run_level(&mut temp_nodes, &LEVEL_8);
+        prune(&mut temp_nodes, &PRUNE_8);
+        run_level(&mut temp_nodes, &LEVEL_9);
+        prune(&mut temp_nodes, &PRUNE_9);
+        run_level(&mut temp_nodes, &LEVEL_10);
+        prune(&mut temp_nodes, &PRUNE_10);
+        run_level(&mut temp_nodes, &LEVEL_11);
+        prune(&mut temp_nodes, &PRUNE_11);
+        run_level(&mut temp_nodes, &LEVEL_12);
+        prune(&mut temp_nodes, &PRUNE_12);
+        run_level(&mut temp_nodes, &LEVEL_13);
+        prune(&mut temp_nodes, &PRUNE_13);
+        run_level(&mut temp_nodes, &LEVEL_14);
+        prune(&mut temp_nodes, &PRUNE_14);
+
+    
+
+    out.into_iter().map(|c| c.unwrap()).collect()
+}
@@ -0,0 +1,2968 @@
+
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use phantom_zone_evaluator::boolean::{fhew::prelude::*, FheBool};
+
+enum GateInput {
+    Arg(usize, usize), // arg + index
+    Output(usize), // reuse of output wire
+    Tv(usize),  // temp value
+    Cst(bool),  // constant
+}
+
+use GateInput::*;
+
+#[derive(PartialEq, Eq, Hash)]
+enum CellType {
+    AND2,
+    NAND2,
+    XOR2,
+    XNOR2,
+    OR2,
+    NOR2,
+    INV,
+    // TODO: Add back MUX2
+}
+
+use CellType::*;
+
+
+static LEVEL_0: [((usize, bool, CellType), &[GateInput]); 38] = [
+    ((56, false, NOR2), &[Arg(0, 16), Arg(0, 17)]),
+    ((57, false, NOR2), &[Arg(0, 18), Arg(0, 19)]),
+    ((58, false, NOR2), &[Arg(0, 20), Arg(0, 21)]),
+    ((59, false, NOR2), &[Arg(0, 22), Arg(0, 23)]),
+    ((63, false, XOR2), &[Arg(0, 32), Arg(0, 33)]),
+    ((66, false, XOR2), &[Arg(0, 33), Arg(0, 34)]),
+    ((69, false, XOR2), &[Arg(0, 34), Arg(0, 35)]),
+    ((72, false, XOR2), &[Arg(0, 35), Arg(0, 36)]),
+    ((75, false, XOR2), &[Arg(0, 36), Arg(0, 37)]),
+    ((78, false, XOR2), &[Arg(0, 37), Arg(0, 38)]),
+    ((81, false, XOR2), &[Arg(0, 38), Arg(0, 39)]),
+    ((84, false, INV), &[Arg(2, 0)]),
+    ((104, false, INV), &[Arg(2, 1)]),
+    ((119, false, INV), &[Arg(2, 2)]),
+    ((124, false, NOR2), &[Arg(2, 3), Arg(2, 4)]),
+    ((125, false, NOR2), &[Arg(2, 5), Arg(2, 6)]),
+    ((127, false, INV), &[Arg(2, 7)]),
+    ((137, false, NOR2), &[Arg(1, 0), Arg(1, 1)]),
+    ((138, false, INV), &[Arg(1, 2)]),
+    ((140, false, INV), &[Arg(1, 1)]),
+    ((143, false, INV), &[Arg(1, 0)]),
+    ((146, false, AND2), &[Arg(1, 0), Arg(1, 1)]),
+    ((189, false, INV), &[Arg(3, 5)]),
+    ((191, false, NOR2), &[Arg(3, 6), Arg(3, 7)]),
+    ((202, false, INV), &[Arg(3, 13)]),
+    ((204, false, NOR2), &[Arg(3, 14), Arg(3, 15)]),
+    ((216, false, INV), &[Arg(3, 21)]),
+    ((218, false, NOR2), &[Arg(3, 22), Arg(3, 23)]),
+    ((229, false, INV), &[Arg(3, 29)]),
+    ((231, false, NOR2), &[Arg(3, 30), Arg(3, 31)]),
+    ((243, false, INV), &[Arg(3, 37)]),
+    ((245, false, NOR2), &[Arg(3, 38), Arg(3, 39)]),
+    ((256, false, INV), &[Arg(3, 45)]),
+    ((258, false, NOR2), &[Arg(3, 46), Arg(3, 47)]),
+    ((270, false, INV), &[Arg(3, 53)]),
+    ((272, false, NOR2), &[Arg(3, 54), Arg(3, 55)]),
+    ((283, false, INV), &[Arg(3, 61)]),
+    ((285, false, NOR2), &[Arg(3, 62), Arg(3, 63)]),
+];
+
+static LEVEL_1: [((usize, bool, CellType), &[GateInput]); 17] = [
+    ((60, false, AND2), &[Tv(56), Tv(57)]),
+    ((61, false, AND2), &[Tv(58), Tv(59)]),
+    ((64, false, AND2), &[Arg(2, 0), Tv(63)]),
+    ((67, false, AND2), &[Arg(2, 0), Tv(66)]),
+    ((70, false, AND2), &[Arg(2, 0), Tv(69)]),
+    ((73, false, AND2), &[Arg(2, 0), Tv(72)]),
+    ((76, false, AND2), &[Arg(2, 0), Tv(75)]),
+    ((79, false, AND2), &[Arg(2, 0), Tv(78)]),
+    ((82, false, AND2), &[Arg(2, 0), Tv(81)]),
+    ((85, false, AND2), &[Arg(0, 39), Tv(84)]),
+    ((126, false, AND2), &[Tv(124), Tv(125)]),
+    ((139, false, NAND2), &[Tv(138), Tv(137)]),
+    ((141, false, AND2), &[Arg(1, 0), Tv(140)]),
+    ((144, false, AND2), &[Tv(143), Arg(1, 1)]),
+    ((147, false, NAND2), &[Tv(138), Tv(146)]),
+    ((148, false, NAND2), &[Arg(1, 2), Tv(137)]),
+    ((151, false, NAND2), &[Arg(1, 2), Tv(146)]),
+];
+
+static LEVEL_2: [((usize, bool, CellType), &[GateInput]); 18] = [
+    ((62, false, AND2), &[Tv(60), Tv(61)]),
+    ((65, false, XOR2), &[Arg(0, 32), Tv(64)]),
+    ((68, false, XOR2), &[Arg(0, 33), Tv(67)]),
+    ((71, false, XOR2), &[Arg(0, 34), Tv(70)]),
+    ((74, false, XOR2), &[Arg(0, 35), Tv(73)]),
+    ((77, false, XOR2), &[Arg(0, 36), Tv(76)]),
+    ((80, false, XOR2), &[Arg(0, 37), Tv(79)]),
+    ((83, false, XOR2), &[Arg(0, 38), Tv(82)]),
+    ((106, false, AND2), &[Tv(104), Tv(85)]),
+    ((128, false, AND2), &[Tv(127), Tv(126)]),
+    ((142, false, NAND2), &[Tv(138), Tv(141)]),
+    ((145, false, NAND2), &[Tv(138), Tv(144)]),
+    ((149, false, NAND2), &[Arg(1, 2), Tv(141)]),
+    ((150, false, NAND2), &[Arg(1, 2), Tv(144)]),
+    ((152, false, INV), &[Tv(139)]),
+    ((155, false, INV), &[Tv(148)]),
+    ((161, false, INV), &[Tv(147)]),
+    ((171, false, INV), &[Tv(151)]),
+];
+
+static LEVEL_3: [((usize, bool, CellType), &[GateInput]); 20] = [
+    ((16, true, OR2), &[Arg(0, 16), Tv(62)]),
+    ((18, true, OR2), &[Arg(0, 18), Tv(62)]),
+    ((86, false, XOR2), &[Tv(65), Tv(71)]),
+    ((89, false, XOR2), &[Tv(68), Tv(74)]),
+    ((92, false, XOR2), &[Tv(71), Tv(77)]),
+    ((95, false, XOR2), &[Tv(74), Tv(80)]),
+    ((98, false, XOR2), &[Tv(77), Tv(83)]),
+    ((101, false, XOR2), &[Tv(80), Tv(85)]),
+    ((105, false, AND2), &[Tv(104), Tv(83)]),
+    ((123, false, AND2), &[Tv(119), Tv(106)]),
+    ((153, false, INV), &[Tv(142)]),
+    ((156, false, INV), &[Tv(150)]),
+    ((160, false, INV), &[Tv(145)]),
+    ((167, false, INV), &[Tv(149)]),
+    ((172, false, NOR2), &[Tv(161), Tv(171)]),
+    ((334, false, NOR2), &[Tv(152), Tv(161)]),
+    ((347, false, NOR2), &[Tv(152), Tv(155)]),
+    ((349, false, NOR2), &[Tv(161), Tv(155)]),
+    ((878, false, NOR2), &[Tv(155), Tv(171)]),
+    ((1295, false, INV), &[Tv(62)]),
+];
+
+static LEVEL_4: [((usize, bool, CellType), &[GateInput]); 33] = [
+    ((17, true, AND2), &[Arg(0, 17), Tv(1295)]),
+    ((19, true, AND2), &[Arg(0, 19), Tv(1295)]),
+    ((20, true, AND2), &[Arg(0, 20), Tv(1295)]),
+    ((21, true, AND2), &[Arg(0, 21), Tv(1295)]),
+    ((22, true, AND2), &[Arg(0, 22), Tv(1295)]),
+    ((23, true, AND2), &[Arg(0, 23), Tv(1295)]),
+    ((87, false, AND2), &[Arg(2, 1), Tv(86)]),
+    ((90, false, AND2), &[Arg(2, 1), Tv(89)]),
+    ((93, false, AND2), &[Arg(2, 1), Tv(92)]),
+    ((96, false, AND2), &[Arg(2, 1), Tv(95)]),
+    ((99, false, AND2), &[Arg(2, 1), Tv(98)]),
+    ((102, false, AND2), &[Arg(2, 1), Tv(101)]),
+    ((122, false, AND2), &[Tv(119), Tv(105)]),
+    ((136, false, AND2), &[Tv(123), Tv(128)]),
+    ((154, false, NOR2), &[Tv(152), Tv(153)]),
+    ((157, false, NOR2), &[Tv(155), Tv(156)]),
+    ((162, false, NOR2), &[Tv(160), Tv(161)]),
+    ((166, false, NOR2), &[Tv(153), Tv(160)]),
+    ((168, false, NOR2), &[Tv(167), Tv(156)]),
+    ((175, false, NOR2), &[Tv(155), Tv(167)]),
+    ((178, false, NOR2), &[Tv(156), Tv(171)]),
+    ((335, false, NOR2), &[Tv(167), Tv(171)]),
+    ((339, false, NOR2), &[Tv(161), Tv(167)]),
+    ((345, false, NOR2), &[Tv(152), Tv(160)]),
+    ((474, false, NOR2), &[Tv(160), Tv(155)]),
+    ((483, false, NOR2), &[Tv(153), Tv(167)]),
+    ((610, false, NOR2), &[Tv(153), Tv(161)]),
+    ((616, false, AND2), &[Tv(151), Tv(347)]),
+    ((746, false, NOR2), &[Tv(161), Tv(156)]),
+    ((752, false, NOR2), &[Tv(160), Tv(156)]),
+    ((881, false, NOR2), &[Tv(160), Tv(167)]),
+    ((1008, false, NOR2), &[Tv(153), Tv(155)]),
+    ((1138, false, AND2), &[Tv(148), Tv(334)]),
+];
+
+static LEVEL_5: [((usize, bool, CellType), &[GateInput]); 60] = [
+    ((88, false, XOR2), &[Tv(65), Tv(87)]),
+    ((91, false, XOR2), &[Tv(68), Tv(90)]),
+    ((94, false, XOR2), &[Tv(71), Tv(93)]),
+    ((97, false, XOR2), &[Tv(74), Tv(96)]),
+    ((100, false, XOR2), &[Tv(77), Tv(99)]),
+    ((103, false, XOR2), &[Tv(80), Tv(102)]),
+    ((135, false, AND2), &[Tv(122), Tv(128)]),
+    ((158, false, AND2), &[Tv(154), Tv(157)]),
+    ((163, false, AND2), &[Tv(157), Tv(162)]),
+    ((165, false, AND2), &[Tv(151), Tv(162)]),
+    ((169, false, AND2), &[Tv(166), Tv(168)]),
+    ((173, false, AND2), &[Tv(154), Tv(172)]),
+    ((174, false, AND2), &[Tv(154), Tv(168)]),
+    ((176, false, AND2), &[Tv(162), Tv(175)]),
+    ((179, false, AND2), &[Tv(154), Tv(162)]),
+    ((180, false, AND2), &[Tv(175), Tv(178)]),
+    ((336, false, AND2), &[Tv(334), Tv(335)]),
+    ((337, false, AND2), &[Tv(168), Tv(334)]),
+    ((340, false, AND2), &[Tv(166), Tv(339)]),
+    ((342, false, AND2), &[Tv(150), Tv(166)]),
+    ((343, false, AND2), &[Tv(154), Tv(175)]),
+    ((346, false, AND2), &[Tv(178), Tv(345)]),
+    ((348, false, AND2), &[Tv(335), Tv(347)]),
+    ((350, false, AND2), &[Tv(166), Tv(349)]),
+    ((475, false, AND2), &[Tv(178), Tv(474)]),
+    ((476, false, AND2), &[Tv(168), Tv(474)]),
+    ((478, false, AND2), &[Tv(154), Tv(474)]),
+    ((480, false, AND2), &[Tv(149), Tv(154)]),
+    ((481, false, AND2), &[Tv(175), Tv(334)]),
+    ((484, false, AND2), &[Tv(178), Tv(483)]),
+    ((485, false, AND2), &[Tv(178), Tv(349)]),
+    ((611, false, AND2), &[Tv(168), Tv(610)]),
+    ((612, false, AND2), &[Tv(175), Tv(610)]),
+    ((614, false, AND2), &[Tv(154), Tv(349)]),
+    ((617, false, AND2), &[Tv(168), Tv(347)]),
+    ((618, false, AND2), &[Tv(162), Tv(168)]),
+    ((623, false, XOR2), &[Arg(3, 3), Tv(616)]),
+    ((642, false, XOR2), &[Arg(3, 19), Tv(616)]),
+    ((661, false, XOR2), &[Arg(3, 35), Tv(616)]),
+    ((680, false, XOR2), &[Arg(3, 51), Tv(616)]),
+    ((743, false, AND2), &[Tv(175), Tv(345)]),
+    ((744, false, AND2), &[Tv(345), Tv(349)]),
+    ((747, false, AND2), &[Tv(345), Tv(746)]),
+    ((749, false, AND2), &[Tv(151), Tv(746)]),
+    ((750, false, AND2), &[Tv(335), Tv(349)]),
+    ((751, false, AND2), &[Tv(166), Tv(175)]),
+    ((753, false, AND2), &[Tv(154), Tv(752)]),
+    ((879, false, AND2), &[Tv(610), Tv(878)]),
+    ((882, false, AND2), &[Tv(150), Tv(881)]),
+    ((1009, false, AND2), &[Tv(149), Tv(1008)]),
+    ((1010, false, AND2), &[Tv(172), Tv(345)]),
+    ((1135, false, AND2), &[Tv(166), Tv(335)]),
+    ((1136, false, AND2), &[Tv(154), Tv(881)]),
+    ((1139, false, AND2), &[Tv(166), Tv(178)]),
+    ((1140, false, AND2), &[Tv(168), Tv(349)]),
+    ((1145, false, XOR2), &[Arg(3, 3), Tv(1138)]),
+    ((1164, false, XOR2), &[Arg(3, 19), Tv(1138)]),
+    ((1183, false, XOR2), &[Arg(3, 35), Tv(1138)]),
+    ((1202, false, XOR2), &[Arg(3, 51), Tv(1138)]),
+    ((1310, false, XOR2), &[Arg(0, 39), Tv(136)]),
+];
+
+static LEVEL_6: [((usize, bool, CellType), &[GateInput]); 157] = [
+    ((107, false, XOR2), &[Tv(88), Tv(100)]),
+    ((110, false, XOR2), &[Tv(91), Tv(103)]),
+    ((113, false, XOR2), &[Tv(94), Tv(105)]),
+    ((116, false, XOR2), &[Tv(97), Tv(106)]),
+    ((120, false, AND2), &[Tv(119), Tv(100)]),
+    ((121, false, AND2), &[Tv(119), Tv(103)]),
+    ((159, false, AND2), &[Tv(151), Tv(158)]),
+    ((164, false, AND2), &[Tv(151), Tv(163)]),
+    ((170, false, AND2), &[Tv(151), Tv(169)]),
+    ((177, false, AND2), &[Tv(150), Tv(176)]),
+    ((181, false, AND2), &[Tv(179), Tv(180)]),
+    ((182, false, XOR2), &[Arg(3, 0), Tv(158)]),
+    ((185, false, XOR2), &[Arg(3, 3), Tv(165)]),
+    ((195, false, XOR2), &[Arg(3, 8), Tv(173)]),
+    ((196, false, XOR2), &[Arg(3, 9), Tv(174)]),
+    ((209, false, XOR2), &[Arg(3, 16), Tv(158)]),
+    ((212, false, XOR2), &[Arg(3, 19), Tv(165)]),
+    ((222, false, XOR2), &[Arg(3, 24), Tv(173)]),
+    ((223, false, XOR2), &[Arg(3, 25), Tv(174)]),
+    ((236, false, XOR2), &[Arg(3, 32), Tv(158)]),
+    ((239, false, XOR2), &[Arg(3, 35), Tv(165)]),
+    ((249, false, XOR2), &[Arg(3, 40), Tv(173)]),
+    ((250, false, XOR2), &[Arg(3, 41), Tv(174)]),
+    ((263, false, XOR2), &[Arg(3, 48), Tv(158)]),
+    ((266, false, XOR2), &[Arg(3, 51), Tv(165)]),
+    ((276, false, XOR2), &[Arg(3, 56), Tv(173)]),
+    ((277, false, XOR2), &[Arg(3, 57), Tv(174)]),
+    ((297, false, XNOR2), &[Arg(0, 0), Tv(158)]),
+    ((306, false, XNOR2), &[Arg(0, 3), Tv(165)]),
+    ((316, false, XNOR2), &[Arg(0, 8), Tv(173)]),
+    ((319, false, XNOR2), &[Arg(0, 9), Tv(174)]),
+    ((338, false, AND2), &[Tv(151), Tv(337)]),
+    ((341, false, AND2), &[Tv(150), Tv(340)]),
+    ((344, false, AND2), &[Tv(150), Tv(343)]),
+    ((351, false, AND2), &[Tv(149), Tv(350)]),
+    ((352, false, XOR2), &[Arg(3, 0), Tv(336)]),
+    ((355, false, XOR2), &[Arg(3, 3), Tv(342)]),
+    ((363, false, XOR2), &[Arg(3, 8), Tv(346)]),
+    ((364, false, XOR2), &[Arg(3, 9), Tv(348)]),
+    ((371, false, XOR2), &[Arg(3, 16), Tv(336)]),
+    ((374, false, XOR2), &[Arg(3, 19), Tv(342)]),
+    ((382, false, XOR2), &[Arg(3, 24), Tv(346)]),
+    ((383, false, XOR2), &[Arg(3, 25), Tv(348)]),
+    ((390, false, XOR2), &[Arg(3, 32), Tv(336)]),
+    ((393, false, XOR2), &[Arg(3, 35), Tv(342)]),
+    ((401, false, XOR2), &[Arg(3, 40), Tv(346)]),
+    ((402, false, XOR2), &[Arg(3, 41), Tv(348)]),
+    ((409, false, XOR2), &[Arg(3, 48), Tv(336)]),
+    ((412, false, XOR2), &[Arg(3, 51), Tv(342)]),
+    ((420, false, XOR2), &[Arg(3, 56), Tv(346)]),
+    ((421, false, XOR2), &[Arg(3, 57), Tv(348)]),
+    ((477, false, AND2), &[Tv(151), Tv(476)]),
+    ((479, false, AND2), &[Tv(149), Tv(478)]),
+    ((482, false, AND2), &[Tv(151), Tv(481)]),
+    ((486, false, AND2), &[Tv(148), Tv(179)]),
+    ((487, false, XOR2), &[Arg(3, 0), Tv(475)]),
+    ((490, false, XOR2), &[Arg(3, 3), Tv(480)]),
+    ((498, false, XOR2), &[Arg(3, 8), Tv(484)]),
+    ((499, false, XOR2), &[Arg(3, 9), Tv(485)]),
+    ((506, false, XOR2), &[Arg(3, 16), Tv(475)]),
+    ((509, false, XOR2), &[Arg(3, 19), Tv(480)]),
+    ((517, false, XOR2), &[Arg(3, 24), Tv(484)]),
+    ((518, false, XOR2), &[Arg(3, 25), Tv(485)]),
+    ((525, false, XOR2), &[Arg(3, 32), Tv(475)]),
+    ((528, false, XOR2), &[Arg(3, 35), Tv(480)]),
+    ((536, false, XOR2), &[Arg(3, 40), Tv(484)]),
+    ((537, false, XOR2), &[Arg(3, 41), Tv(485)]),
+    ((544, false, XOR2), &[Arg(3, 48), Tv(475)]),
+    ((547, false, XOR2), &[Arg(3, 51), Tv(480)]),
+    ((555, false, XOR2), &[Arg(3, 56), Tv(484)]),
+    ((556, false, XOR2), &[Arg(3, 57), Tv(485)]),
+    ((613, false, AND2), &[Tv(150), Tv(612)]),
+    ((615, false, AND2), &[Tv(151), Tv(614)]),
+    ((619, false, AND2), &[Tv(151), Tv(179)]),
+    ((620, false, XOR2), &[Arg(3, 0), Tv(611)]),
+    ((631, false, XOR2), &[Arg(3, 8), Tv(617)]),
+    ((632, false, XOR2), &[Arg(3, 9), Tv(618)]),
+    ((639, false, XOR2), &[Arg(3, 16), Tv(611)]),
+    ((650, false, XOR2), &[Arg(3, 24), Tv(617)]),
+    ((651, false, XOR2), &[Arg(3, 25), Tv(618)]),
+    ((658, false, XOR2), &[Arg(3, 32), Tv(611)]),
+    ((669, false, XOR2), &[Arg(3, 40), Tv(617)]),
+    ((670, false, XOR2), &[Arg(3, 41), Tv(618)]),
+    ((677, false, XOR2), &[Arg(3, 48), Tv(611)]),
+    ((688, false, XOR2), &[Arg(3, 56), Tv(617)]),
+    ((689, false, XOR2), &[Arg(3, 57), Tv(618)]),
+    ((745, false, AND2), &[Tv(149), Tv(744)]),
+    ((748, false, AND2), &[Tv(151), Tv(747)]),
+    ((754, false, AND2), &[Tv(151), Tv(753)]),
+    ((755, false, XOR2), &[Arg(3, 0), Tv(743)]),
+    ((758, false, XOR2), &[Arg(3, 3), Tv(749)]),
+    ((766, false, XOR2), &[Arg(3, 8), Tv(750)]),
+    ((767, false, XOR2), &[Arg(3, 9), Tv(751)]),
+    ((774, false, XOR2), &[Arg(3, 16), Tv(743)]),
+    ((777, false, XOR2), &[Arg(3, 19), Tv(749)]),
+    ((785, false, XOR2), &[Arg(3, 24), Tv(750)]),
+    ((786, false, XOR2), &[Arg(3, 25), Tv(751)]),
+    ((793, false, XOR2), &[Arg(3, 32), Tv(743)]),
+    ((796, false, XOR2), &[Arg(3, 35), Tv(749)]),
+    ((804, false, XOR2), &[Arg(3, 40), Tv(750)]),
+    ((805, false, XOR2), &[Arg(3, 41), Tv(751)]),
+    ((812, false, XOR2), &[Arg(3, 48), Tv(743)]),
+    ((815, false, XOR2), &[Arg(3, 51), Tv(749)]),
+    ((823, false, XOR2), &[Arg(3, 56), Tv(750)]),
+    ((824, false, XOR2), &[Arg(3, 57), Tv(751)]),
+    ((880, false, AND2), &[Tv(151), Tv(350)]),
+    ((883, false, AND2), &[Tv(151), Tv(174)]),
+    ((884, false, XOR2), &[Arg(3, 0), Tv(879)]),
+    ((887, false, XOR2), &[Arg(3, 3), Tv(882)]),
+    ((895, false, XOR2), &[Arg(3, 8), Tv(163)]),
+    ((896, false, XOR2), &[Arg(3, 9), Tv(614)]),
+    ((903, false, XOR2), &[Arg(3, 16), Tv(879)]),
+    ((906, false, XOR2), &[Arg(3, 19), Tv(882)]),
+    ((914, false, XOR2), &[Arg(3, 24), Tv(163)]),
+    ((915, false, XOR2), &[Arg(3, 25), Tv(614)]),
+    ((922, false, XOR2), &[Arg(3, 32), Tv(879)]),
+    ((925, false, XOR2), &[Arg(3, 35), Tv(882)]),
+    ((933, false, XOR2), &[Arg(3, 40), Tv(163)]),
+    ((934, false, XOR2), &[Arg(3, 41), Tv(614)]),
+    ((941, false, XOR2), &[Arg(3, 48), Tv(879)]),
+    ((944, false, XOR2), &[Arg(3, 51), Tv(882)]),
+    ((952, false, XOR2), &[Arg(3, 56), Tv(163)]),
+    ((953, false, XOR2), &[Arg(3, 57), Tv(614)]),
+    ((1007, false, AND2), &[Tv(150), Tv(179)]),
+    ((1011, false, AND2), &[Tv(151), Tv(617)]),
+    ((1012, false, XOR2), &[Arg(3, 0), Tv(747)]),
+    ((1015, false, XOR2), &[Arg(3, 3), Tv(1009)]),
+    ((1023, false, XOR2), &[Arg(3, 8), Tv(340)]),
+    ((1024, false, XOR2), &[Arg(3, 9), Tv(1010)]),
+    ((1031, false, XOR2), &[Arg(3, 16), Tv(747)]),
+    ((1034, false, XOR2), &[Arg(3, 19), Tv(1009)]),
+    ((1042, false, XOR2), &[Arg(3, 24), Tv(340)]),
+    ((1043, false, XOR2), &[Arg(3, 25), Tv(1010)]),
+    ((1050, false, XOR2), &[Arg(3, 32), Tv(747)]),
+    ((1053, false, XOR2), &[Arg(3, 35), Tv(1009)]),
+    ((1061, false, XOR2), &[Arg(3, 40), Tv(340)]),
+    ((1062, false, XOR2), &[Arg(3, 41), Tv(1010)]),
+    ((1069, false, XOR2), &[Arg(3, 48), Tv(747)]),
+    ((1072, false, XOR2), &[Arg(3, 51), Tv(1009)]),
+    ((1080, false, XOR2), &[Arg(3, 56), Tv(340)]),
+    ((1081, false, XOR2), &[Arg(3, 57), Tv(1010)]),
+    ((1137, false, AND2), &[Tv(151), Tv(1136)]),
+    ((1141, false, AND2), &[Tv(151), Tv(1140)]),
+    ((1142, false, XOR2), &[Arg(3, 0), Tv(1135)]),
+    ((1153, false, XOR2), &[Arg(3, 8), Tv(478)]),
+    ((1154, false, XOR2), &[Arg(3, 9), Tv(1139)]),
+    ((1161, false, XOR2), &[Arg(3, 16), Tv(1135)]),
+    ((1172, false, XOR2), &[Arg(3, 24), Tv(478)]),
+    ((1173, false, XOR2), &[Arg(3, 25), Tv(1139)]),
+    ((1180, false, XOR2), &[Arg(3, 32), Tv(1135)]),
+    ((1191, false, XOR2), &[Arg(3, 40), Tv(478)]),
+    ((1192, false, XOR2), &[Arg(3, 41), Tv(1139)]),
+    ((1199, false, XOR2), &[Arg(3, 48), Tv(1135)]),
+    ((1210, false, XOR2), &[Arg(3, 56), Tv(478)]),
+    ((1211, false, XOR2), &[Arg(3, 57), Tv(1139)]),
+    ((1308, false, XOR2), &[Arg(0, 38), Tv(135)]),
+    ((1311, false, AND2), &[Tv(62), Tv(1310)]),
+];
+
+static LEVEL_7: [((usize, bool, CellType), &[GateInput]); 182] = [
+    ((39, true, XOR2), &[Arg(0, 39), Tv(1311)]),
+    ((108, false, AND2), &[Arg(2, 2), Tv(107)]),
+    ((111, false, AND2), &[Arg(2, 2), Tv(110)]),
+    ((114, false, AND2), &[Arg(2, 2), Tv(113)]),
+    ((117, false, AND2), &[Arg(2, 2), Tv(116)]),
+    ((133, false, AND2), &[Tv(120), Tv(128)]),
+    ((134, false, AND2), &[Tv(121), Tv(128)]),
+    ((183, false, XOR2), &[Arg(3, 1), Tv(159)]),
+    ((184, false, XOR2), &[Arg(3, 2), Tv(164)]),
+    ((186, false, XOR2), &[Arg(3, 4), Tv(170)]),
+    ((197, false, XOR2), &[Arg(3, 10), Tv(177)]),
+    ((198, false, XOR2), &[Arg(3, 11), Tv(181)]),
+    ((199, false, XOR2), &[Arg(3, 12), Tv(181)]),
+    ((200, false, AND2), &[Tv(195), Tv(196)]),
+    ((210, false, XOR2), &[Arg(3, 17), Tv(159)]),
+    ((211, false, XOR2), &[Arg(3, 18), Tv(164)]),
+    ((213, false, XOR2), &[Arg(3, 20), Tv(170)]),
+    ((224, false, XOR2), &[Arg(3, 26), Tv(177)]),
+    ((225, false, XOR2), &[Arg(3, 27), Tv(181)]),
+    ((226, false, XOR2), &[Arg(3, 28), Tv(181)]),
+    ((227, false, AND2), &[Tv(222), Tv(223)]),
+    ((237, false, XOR2), &[Arg(3, 33), Tv(159)]),
+    ((238, false, XOR2), &[Arg(3, 34), Tv(164)]),
+    ((240, false, XOR2), &[Arg(3, 36), Tv(170)]),
+    ((251, false, XOR2), &[Arg(3, 42), Tv(177)]),
+    ((252, false, XOR2), &[Arg(3, 43), Tv(181)]),
+    ((253, false, XOR2), &[Arg(3, 44), Tv(181)]),
+    ((254, false, AND2), &[Tv(249), Tv(250)]),
+    ((264, false, XOR2), &[Arg(3, 49), Tv(159)]),
+    ((265, false, XOR2), &[Arg(3, 50), Tv(164)]),
+    ((267, false, XOR2), &[Arg(3, 52), Tv(170)]),
+    ((278, false, XOR2), &[Arg(3, 58), Tv(177)]),
+    ((279, false, XOR2), &[Arg(3, 59), Tv(181)]),
+    ((280, false, XOR2), &[Arg(3, 60), Tv(181)]),
+    ((281, false, AND2), &[Tv(276), Tv(277)]),
+    ((300, false, XNOR2), &[Arg(0, 1), Tv(159)]),
+    ((303, false, XNOR2), &[Arg(0, 2), Tv(164)]),
+    ((309, false, XNOR2), &[Arg(0, 4), Tv(170)]),
+    ((322, false, XNOR2), &[Arg(0, 10), Tv(177)]),
+    ((325, false, XNOR2), &[Arg(0, 11), Tv(181)]),
+    ((328, false, XNOR2), &[Arg(0, 12), Tv(181)]),
+    ((353, false, XOR2), &[Arg(3, 1), Tv(338)]),
+    ((354, false, XOR2), &[Arg(3, 2), Tv(341)]),
+    ((356, false, XOR2), &[Arg(3, 4), Tv(344)]),
+    ((365, false, XOR2), &[Arg(3, 10), Tv(351)]),
+    ((366, false, AND2), &[Tv(363), Tv(364)]),
+    ((372, false, XOR2), &[Arg(3, 17), Tv(338)]),
+    ((373, false, XOR2), &[Arg(3, 18), Tv(341)]),
+    ((375, false, XOR2), &[Arg(3, 20), Tv(344)]),
+    ((384, false, XOR2), &[Arg(3, 26), Tv(351)]),
+    ((385, false, AND2), &[Tv(382), Tv(383)]),
+    ((391, false, XOR2), &[Arg(3, 33), Tv(338)]),
+    ((392, false, XOR2), &[Arg(3, 34), Tv(341)]),
+    ((394, false, XOR2), &[Arg(3, 36), Tv(344)]),
+    ((403, false, XOR2), &[Arg(3, 42), Tv(351)]),
+    ((404, false, AND2), &[Tv(401), Tv(402)]),
+    ((410, false, XOR2), &[Arg(3, 49), Tv(338)]),
+    ((411, false, XOR2), &[Arg(3, 50), Tv(341)]),
+    ((413, false, XOR2), &[Arg(3, 52), Tv(344)]),
+    ((422, false, XOR2), &[Arg(3, 58), Tv(351)]),
+    ((423, false, AND2), &[Tv(420), Tv(421)]),
+    ((488, false, XOR2), &[Arg(3, 1), Tv(477)]),
+    ((489, false, XOR2), &[Arg(3, 2), Tv(479)]),
+    ((491, false, XOR2), &[Arg(3, 4), Tv(482)]),
+    ((500, false, XOR2), &[Arg(3, 10), Tv(486)]),
+    ((501, false, AND2), &[Tv(498), Tv(499)]),
+    ((507, false, XOR2), &[Arg(3, 17), Tv(477)]),
+    ((508, false, XOR2), &[Arg(3, 18), Tv(479)]),
+    ((510, false, XOR2), &[Arg(3, 20), Tv(482)]),
+    ((519, false, XOR2), &[Arg(3, 26), Tv(486)]),
+    ((520, false, AND2), &[Tv(517), Tv(518)]),
+    ((526, false, XOR2), &[Arg(3, 33), Tv(477)]),
+    ((527, false, XOR2), &[Arg(3, 34), Tv(479)]),
+    ((529, false, XOR2), &[Arg(3, 36), Tv(482)]),
+    ((538, false, XOR2), &[Arg(3, 42), Tv(486)]),
+    ((539, false, AND2), &[Tv(536), Tv(537)]),
+    ((545, false, XOR2), &[Arg(3, 49), Tv(477)]),
+    ((546, false, XOR2), &[Arg(3, 50), Tv(479)]),
+    ((548, false, XOR2), &[Arg(3, 52), Tv(482)]),
+    ((557, false, XOR2), &[Arg(3, 58), Tv(486)]),
+    ((558, false, AND2), &[Tv(555), Tv(556)]),
+    ((621, false, XOR2), &[Arg(3, 1), Tv(613)]),
+    ((622, false, XOR2), &[Arg(3, 2), Tv(615)]),
+    ((624, false, XOR2), &[Arg(3, 4), Tv(164)]),
+    ((633, false, XOR2), &[Arg(3, 10), Tv(619)]),
+    ((634, false, AND2), &[Tv(631), Tv(632)]),
+    ((640, false, XOR2), &[Arg(3, 17), Tv(613)]),
+    ((641, false, XOR2), &[Arg(3, 18), Tv(615)]),
+    ((643, false, XOR2), &[Arg(3, 20), Tv(164)]),
+    ((652, false, XOR2), &[Arg(3, 26), Tv(619)]),
+    ((653, false, AND2), &[Tv(650), Tv(651)]),
+    ((659, false, XOR2), &[Arg(3, 33), Tv(613)]),
+    ((660, false, XOR2), &[Arg(3, 34), Tv(615)]),
+    ((662, false, XOR2), &[Arg(3, 36), Tv(164)]),
+    ((671, false, XOR2), &[Arg(3, 42), Tv(619)]),
+    ((672, false, AND2), &[Tv(669), Tv(670)]),
+    ((678, false, XOR2), &[Arg(3, 49), Tv(613)]),
+    ((679, false, XOR2), &[Arg(3, 50), Tv(615)]),
+    ((681, false, XOR2), &[Arg(3, 52), Tv(164)]),
+    ((690, false, XOR2), &[Arg(3, 58), Tv(619)]),
+    ((691, false, AND2), &[Tv(688), Tv(689)]),
+    ((756, false, XOR2), &[Arg(3, 1), Tv(745)]),
+    ((757, false, XOR2), &[Arg(3, 2), Tv(748)]),
+    ((759, false, XOR2), &[Arg(3, 4), Tv(341)]),
+    ((768, false, XOR2), &[Arg(3, 10), Tv(754)]),
+    ((769, false, AND2), &[Tv(766), Tv(767)]),
+    ((775, false, XOR2), &[Arg(3, 17), Tv(745)]),
+    ((776, false, XOR2), &[Arg(3, 18), Tv(748)]),
+    ((778, false, XOR2), &[Arg(3, 20), Tv(341)]),
+    ((787, false, XOR2), &[Arg(3, 26), Tv(754)]),
+    ((788, false, AND2), &[Tv(785), Tv(786)]),
+    ((794, false, XOR2), &[Arg(3, 33), Tv(745)]),
+    ((795, false, XOR2), &[Arg(3, 34), Tv(748)]),
+    ((797, false, XOR2), &[Arg(3, 36), Tv(341)]),
+    ((806, false, XOR2), &[Arg(3, 42), Tv(754)]),
+    ((807, false, AND2), &[Tv(804), Tv(805)]),
+    ((813, false, XOR2), &[Arg(3, 49), Tv(745)]),
+    ((814, false, XOR2), &[Arg(3, 50), Tv(748)]),
+    ((816, false, XOR2), &[Arg(3, 52), Tv(341)]),
+    ((825, false, XOR2), &[Arg(3, 58), Tv(754)]),
+    ((826, false, AND2), &[Tv(823), Tv(824)]),
+    ((885, false, XOR2), &[Arg(3, 1), Tv(880)]),
+    ((886, false, XOR2), &[Arg(3, 2), Tv(170)]),
+    ((888, false, XOR2), &[Arg(3, 4), Tv(479)]),
+    ((897, false, XOR2), &[Arg(3, 10), Tv(883)]),
+    ((898, false, AND2), &[Tv(895), Tv(896)]),
+    ((904, false, XOR2), &[Arg(3, 17), Tv(880)]),
+    ((905, false, XOR2), &[Arg(3, 18), Tv(170)]),
+    ((907, false, XOR2), &[Arg(3, 20), Tv(479)]),
+    ((916, false, XOR2), &[Arg(3, 26), Tv(883)]),
+    ((917, false, AND2), &[Tv(914), Tv(915)]),
+    ((923, false, XOR2), &[Arg(3, 33), Tv(880)]),
+    ((924, false, XOR2), &[Arg(3, 34), Tv(170)]),
+    ((926, false, XOR2), &[Arg(3, 36), Tv(479)]),
+    ((935, false, XOR2), &[Arg(3, 42), Tv(883)]),
+    ((936, false, AND2), &[Tv(933), Tv(934)]),
+    ((942, false, XOR2), &[Arg(3, 49), Tv(880)]),
+    ((943, false, XOR2), &[Arg(3, 50), Tv(170)]),
+    ((945, false, XOR2), &[Arg(3, 52), Tv(479)]),
+    ((954, false, XOR2), &[Arg(3, 58), Tv(883)]),
+    ((955, false, AND2), &[Tv(952), Tv(953)]),
+    ((1013, false, XOR2), &[Arg(3, 1), Tv(1007)]),
+    ((1014, false, XOR2), &[Arg(3, 2), Tv(344)]),
+    ((1016, false, XOR2), &[Arg(3, 4), Tv(615)]),
+    ((1025, false, XOR2), &[Arg(3, 10), Tv(1011)]),
+    ((1026, false, AND2), &[Tv(1023), Tv(1024)]),
+    ((1032, false, XOR2), &[Arg(3, 17), Tv(1007)]),
+    ((1033, false, XOR2), &[Arg(3, 18), Tv(344)]),
+    ((1035, false, XOR2), &[Arg(3, 20), Tv(615)]),
+    ((1044, false, XOR2), &[Arg(3, 26), Tv(1011)]),
+    ((1045, false, AND2), &[Tv(1042), Tv(1043)]),
+    ((1051, false, XOR2), &[Arg(3, 33), Tv(1007)]),
+    ((1052, false, XOR2), &[Arg(3, 34), Tv(344)]),
+    ((1054, false, XOR2), &[Arg(3, 36), Tv(615)]),
+    ((1063, false, XOR2), &[Arg(3, 42), Tv(1011)]),
+    ((1064, false, AND2), &[Tv(1061), Tv(1062)]),
+    ((1070, false, XOR2), &[Arg(3, 49), Tv(1007)]),
+    ((1071, false, XOR2), &[Arg(3, 50), Tv(344)]),
+    ((1073, false, XOR2), &[Arg(3, 52), Tv(615)]),
+    ((1082, false, XOR2), &[Arg(3, 58), Tv(1011)]),
+    ((1083, false, AND2), &[Tv(1080), Tv(1081)]),
+    ((1143, false, XOR2), &[Arg(3, 1), Tv(1137)]),
+    ((1144, false, XOR2), &[Arg(3, 2), Tv(482)]),
+    ((1146, false, XOR2), &[Arg(3, 4), Tv(748)]),
+    ((1155, false, XOR2), &[Arg(3, 10), Tv(1141)]),
+    ((1156, false, AND2), &[Tv(1153), Tv(1154)]),
+    ((1162, false, XOR2), &[Arg(3, 17), Tv(1137)]),
+    ((1163, false, XOR2), &[Arg(3, 18), Tv(482)]),
+    ((1165, false, XOR2), &[Arg(3, 20), Tv(748)]),
+    ((1174, false, XOR2), &[Arg(3, 26), Tv(1141)]),
+    ((1175, false, AND2), &[Tv(1172), Tv(1173)]),
+    ((1181, false, XOR2), &[Arg(3, 33), Tv(1137)]),
+    ((1182, false, XOR2), &[Arg(3, 34), Tv(482)]),
+    ((1184, false, XOR2), &[Arg(3, 36), Tv(748)]),
+    ((1193, false, XOR2), &[Arg(3, 42), Tv(1141)]),
+    ((1194, false, AND2), &[Tv(1191), Tv(1192)]),
+    ((1200, false, XOR2), &[Arg(3, 49), Tv(1137)]),
+    ((1201, false, XOR2), &[Arg(3, 50), Tv(482)]),
+    ((1203, false, XOR2), &[Arg(3, 52), Tv(748)]),
+    ((1212, false, XOR2), &[Arg(3, 58), Tv(1141)]),
+    ((1213, false, AND2), &[Tv(1210), Tv(1211)]),
+    ((1309, false, AND2), &[Tv(62), Tv(1308)]),
+];
+
+static LEVEL_8: [((usize, bool, CellType), &[GateInput]); 139] = [
+    ((38, true, XOR2), &[Arg(0, 38), Tv(1309)]),
+    ((109, false, XOR2), &[Tv(88), Tv(108)]),
+    ((112, false, XOR2), &[Tv(91), Tv(111)]),
+    ((115, false, XOR2), &[Tv(94), Tv(114)]),
+    ((118, false, XOR2), &[Tv(97), Tv(117)]),
+    ((187, false, AND2), &[Tv(182), Tv(183)]),
+    ((188, false, AND2), &[Tv(184), Tv(185)]),
+    ((190, false, AND2), &[Tv(189), Tv(186)]),
+    ((201, false, AND2), &[Tv(197), Tv(198)]),
+    ((203, false, AND2), &[Tv(202), Tv(199)]),
+    ((214, false, AND2), &[Tv(209), Tv(210)]),
+    ((215, false, AND2), &[Tv(211), Tv(212)]),
+    ((217, false, AND2), &[Tv(216), Tv(213)]),
+    ((228, false, AND2), &[Tv(224), Tv(225)]),
+    ((230, false, AND2), &[Tv(229), Tv(226)]),
+    ((241, false, AND2), &[Tv(236), Tv(237)]),
+    ((242, false, AND2), &[Tv(238), Tv(239)]),
+    ((244, false, AND2), &[Tv(243), Tv(240)]),
+    ((255, false, AND2), &[Tv(251), Tv(252)]),
+    ((257, false, AND2), &[Tv(256), Tv(253)]),
+    ((268, false, AND2), &[Tv(263), Tv(264)]),
+    ((269, false, AND2), &[Tv(265), Tv(266)]),
+    ((271, false, AND2), &[Tv(270), Tv(267)]),
+    ((282, false, AND2), &[Tv(278), Tv(279)]),
+    ((284, false, AND2), &[Tv(283), Tv(280)]),
+    ((357, false, AND2), &[Tv(352), Tv(353)]),
+    ((358, false, AND2), &[Tv(354), Tv(355)]),
+    ((359, false, AND2), &[Tv(189), Tv(356)]),
+    ((367, false, AND2), &[Tv(198), Tv(365)]),
+    ((376, false, AND2), &[Tv(371), Tv(372)]),
+    ((377, false, AND2), &[Tv(373), Tv(374)]),
+    ((378, false, AND2), &[Tv(216), Tv(375)]),
+    ((386, false, AND2), &[Tv(225), Tv(384)]),
+    ((395, false, AND2), &[Tv(390), Tv(391)]),
+    ((396, false, AND2), &[Tv(392), Tv(393)]),
+    ((397, false, AND2), &[Tv(243), Tv(394)]),
+    ((405, false, AND2), &[Tv(252), Tv(403)]),
+    ((414, false, AND2), &[Tv(409), Tv(410)]),
+    ((415, false, AND2), &[Tv(411), Tv(412)]),
+    ((416, false, AND2), &[Tv(270), Tv(413)]),
+    ((424, false, AND2), &[Tv(279), Tv(422)]),
+    ((492, false, AND2), &[Tv(487), Tv(488)]),
+    ((493, false, AND2), &[Tv(489), Tv(490)]),
+    ((494, false, AND2), &[Tv(189), Tv(491)]),
+    ((502, false, AND2), &[Tv(198), Tv(500)]),
+    ((511, false, AND2), &[Tv(506), Tv(507)]),
+    ((512, false, AND2), &[Tv(508), Tv(509)]),
+    ((513, false, AND2), &[Tv(216), Tv(510)]),
+    ((521, false, AND2), &[Tv(225), Tv(519)]),
+    ((530, false, AND2), &[Tv(525), Tv(526)]),
+    ((531, false, AND2), &[Tv(527), Tv(528)]),
+    ((532, false, AND2), &[Tv(243), Tv(529)]),
+    ((540, false, AND2), &[Tv(252), Tv(538)]),
+    ((549, false, AND2), &[Tv(544), Tv(545)]),
+    ((550, false, AND2), &[Tv(546), Tv(547)]),
+    ((551, false, AND2), &[Tv(270), Tv(548)]),
+    ((559, false, AND2), &[Tv(279), Tv(557)]),
+    ((625, false, AND2), &[Tv(620), Tv(621)]),
+    ((626, false, AND2), &[Tv(622), Tv(623)]),
+    ((627, false, AND2), &[Tv(189), Tv(624)]),
+    ((635, false, AND2), &[Tv(198), Tv(633)]),
+    ((644, false, AND2), &[Tv(639), Tv(640)]),
+    ((645, false, AND2), &[Tv(641), Tv(642)]),
+    ((646, false, AND2), &[Tv(216), Tv(643)]),
+    ((654, false, AND2), &[Tv(225), Tv(652)]),
+    ((663, false, AND2), &[Tv(658), Tv(659)]),
+    ((664, false, AND2), &[Tv(660), Tv(661)]),
+    ((665, false, AND2), &[Tv(243), Tv(662)]),
+    ((673, false, AND2), &[Tv(252), Tv(671)]),
+    ((682, false, AND2), &[Tv(677), Tv(678)]),
+    ((683, false, AND2), &[Tv(679), Tv(680)]),
+    ((684, false, AND2), &[Tv(270), Tv(681)]),
+    ((692, false, AND2), &[Tv(279), Tv(690)]),
+    ((760, false, AND2), &[Tv(755), Tv(756)]),
+    ((761, false, AND2), &[Tv(757), Tv(758)]),
+    ((762, false, AND2), &[Tv(189), Tv(759)]),
+    ((770, false, AND2), &[Tv(198), Tv(768)]),
+    ((779, false, AND2), &[Tv(774), Tv(775)]),
+    ((780, false, AND2), &[Tv(776), Tv(777)]),
+    ((781, false, AND2), &[Tv(216), Tv(778)]),
+    ((789, false, AND2), &[Tv(225), Tv(787)]),
+    ((798, false, AND2), &[Tv(793), Tv(794)]),
+    ((799, false, AND2), &[Tv(795), Tv(796)]),
+    ((800, false, AND2), &[Tv(243), Tv(797)]),
+    ((808, false, AND2), &[Tv(252), Tv(806)]),
+    ((817, false, AND2), &[Tv(812), Tv(813)]),
+    ((818, false, AND2), &[Tv(814), Tv(815)]),
+    ((819, false, AND2), &[Tv(270), Tv(816)]),
+    ((827, false, AND2), &[Tv(279), Tv(825)]),
+    ((889, false, AND2), &[Tv(884), Tv(885)]),
+    ((890, false, AND2), &[Tv(886), Tv(887)]),
+    ((891, false, AND2), &[Tv(189), Tv(888)]),
+    ((899, false, AND2), &[Tv(198), Tv(897)]),
+    ((908, false, AND2), &[Tv(903), Tv(904)]),
+    ((909, false, AND2), &[Tv(905), Tv(906)]),
+    ((910, false, AND2), &[Tv(216), Tv(907)]),
+    ((918, false, AND2), &[Tv(225), Tv(916)]),
+    ((927, false, AND2), &[Tv(922), Tv(923)]),
+    ((928, false, AND2), &[Tv(924), Tv(925)]),
+    ((929, false, AND2), &[Tv(243), Tv(926)]),
+    ((937, false, AND2), &[Tv(252), Tv(935)]),
+    ((946, false, AND2), &[Tv(941), Tv(942)]),
+    ((947, false, AND2), &[Tv(943), Tv(944)]),
+    ((948, false, AND2), &[Tv(270), Tv(945)]),
+    ((956, false, AND2), &[Tv(279), Tv(954)]),
+    ((1017, false, AND2), &[Tv(1012), Tv(1013)]),
+    ((1018, false, AND2), &[Tv(1014), Tv(1015)]),
+    ((1019, false, AND2), &[Tv(189), Tv(1016)]),
+    ((1027, false, AND2), &[Tv(198), Tv(1025)]),
+    ((1036, false, AND2), &[Tv(1031), Tv(1032)]),
+    ((1037, false, AND2), &[Tv(1033), Tv(1034)]),
+    ((1038, false, AND2), &[Tv(216), Tv(1035)]),
+    ((1046, false, AND2), &[Tv(225), Tv(1044)]),
+    ((1055, false, AND2), &[Tv(1050), Tv(1051)]),
+    ((1056, false, AND2), &[Tv(1052), Tv(1053)]),
+    ((1057, false, AND2), &[Tv(243), Tv(1054)]),
+    ((1065, false, AND2), &[Tv(252), Tv(1063)]),
+    ((1074, false, AND2), &[Tv(1069), Tv(1070)]),
+    ((1075, false, AND2), &[Tv(1071), Tv(1072)]),
+    ((1076, false, AND2), &[Tv(270), Tv(1073)]),
+    ((1084, false, AND2), &[Tv(279), Tv(1082)]),
+    ((1147, false, AND2), &[Tv(1142), Tv(1143)]),
+    ((1148, false, AND2), &[Tv(1144), Tv(1145)]),
+    ((1149, false, AND2), &[Tv(189), Tv(1146)]),
+    ((1157, false, AND2), &[Tv(198), Tv(1155)]),
+    ((1166, false, AND2), &[Tv(1161), Tv(1162)]),
+    ((1167, false, AND2), &[Tv(1163), Tv(1164)]),
+    ((1168, false, AND2), &[Tv(216), Tv(1165)]),
+    ((1176, false, AND2), &[Tv(225), Tv(1174)]),
+    ((1185, false, AND2), &[Tv(1180), Tv(1181)]),
+    ((1186, false, AND2), &[Tv(1182), Tv(1183)]),
+    ((1187, false, AND2), &[Tv(243), Tv(1184)]),
+    ((1195, false, AND2), &[Tv(252), Tv(1193)]),
+    ((1204, false, AND2), &[Tv(1199), Tv(1200)]),
+    ((1205, false, AND2), &[Tv(1201), Tv(1202)]),
+    ((1206, false, AND2), &[Tv(270), Tv(1203)]),
+    ((1214, false, AND2), &[Tv(279), Tv(1212)]),
+    ((1304, false, XOR2), &[Arg(0, 36), Tv(133)]),
+    ((1306, false, XOR2), &[Arg(0, 37), Tv(134)]),
+];
+
+static LEVEL_9: [((usize, bool, CellType), &[GateInput]); 106] = [
+    ((129, false, AND2), &[Tv(109), Tv(128)]),
+    ((130, false, AND2), &[Tv(112), Tv(128)]),
+    ((131, false, AND2), &[Tv(115), Tv(128)]),
+    ((132, false, AND2), &[Tv(118), Tv(128)]),
+    ((192, false, AND2), &[Tv(187), Tv(188)]),
+    ((193, false, AND2), &[Tv(190), Tv(191)]),
+    ((205, false, AND2), &[Tv(200), Tv(201)]),
+    ((206, false, AND2), &[Tv(203), Tv(204)]),
+    ((219, false, AND2), &[Tv(214), Tv(215)]),
+    ((220, false, AND2), &[Tv(217), Tv(218)]),
+    ((232, false, AND2), &[Tv(227), Tv(228)]),
+    ((233, false, AND2), &[Tv(230), Tv(231)]),
+    ((246, false, AND2), &[Tv(241), Tv(242)]),
+    ((247, false, AND2), &[Tv(244), Tv(245)]),
+    ((259, false, AND2), &[Tv(254), Tv(255)]),
+    ((260, false, AND2), &[Tv(257), Tv(258)]),
+    ((273, false, AND2), &[Tv(268), Tv(269)]),
+    ((274, false, AND2), &[Tv(271), Tv(272)]),
+    ((286, false, AND2), &[Tv(281), Tv(282)]),
+    ((287, false, AND2), &[Tv(284), Tv(285)]),
+    ((360, false, AND2), &[Tv(357), Tv(358)]),
+    ((361, false, AND2), &[Tv(191), Tv(359)]),
+    ((368, false, AND2), &[Tv(366), Tv(367)]),
+    ((379, false, AND2), &[Tv(376), Tv(377)]),
+    ((380, false, AND2), &[Tv(218), Tv(378)]),
+    ((387, false, AND2), &[Tv(385), Tv(386)]),
+    ((398, false, AND2), &[Tv(395), Tv(396)]),
+    ((399, false, AND2), &[Tv(245), Tv(397)]),
+    ((406, false, AND2), &[Tv(404), Tv(405)]),
+    ((417, false, AND2), &[Tv(414), Tv(415)]),
+    ((418, false, AND2), &[Tv(272), Tv(416)]),
+    ((425, false, AND2), &[Tv(423), Tv(424)]),
+    ((495, false, AND2), &[Tv(492), Tv(493)]),
+    ((496, false, AND2), &[Tv(191), Tv(494)]),
+    ((503, false, AND2), &[Tv(501), Tv(502)]),
+    ((514, false, AND2), &[Tv(511), Tv(512)]),
+    ((515, false, AND2), &[Tv(218), Tv(513)]),
+    ((522, false, AND2), &[Tv(520), Tv(521)]),
+    ((533, false, AND2), &[Tv(530), Tv(531)]),
+    ((534, false, AND2), &[Tv(245), Tv(532)]),
+    ((541, false, AND2), &[Tv(539), Tv(540)]),
+    ((552, false, AND2), &[Tv(549), Tv(550)]),
+    ((553, false, AND2), &[Tv(272), Tv(551)]),
+    ((560, false, AND2), &[Tv(558), Tv(559)]),
+    ((628, false, AND2), &[Tv(625), Tv(626)]),
+    ((629, false, AND2), &[Tv(191), Tv(627)]),
+    ((636, false, AND2), &[Tv(634), Tv(635)]),
+    ((647, false, AND2), &[Tv(644), Tv(645)]),
+    ((648, false, AND2), &[Tv(218), Tv(646)]),
+    ((655, false, AND2), &[Tv(653), Tv(654)]),
+    ((666, false, AND2), &[Tv(663), Tv(664)]),
+    ((667, false, AND2), &[Tv(245), Tv(665)]),
+    ((674, false, AND2), &[Tv(672), Tv(673)]),
+    ((685, false, AND2), &[Tv(682), Tv(683)]),
+    ((686, false, AND2), &[Tv(272), Tv(684)]),
+    ((693, false, AND2), &[Tv(691), Tv(692)]),
+    ((763, false, AND2), &[Tv(760), Tv(761)]),
+    ((764, false, AND2), &[Tv(191), Tv(762)]),
+    ((771, false, AND2), &[Tv(769), Tv(770)]),
+    ((782, false, AND2), &[Tv(779), Tv(780)]),
+    ((783, false, AND2), &[Tv(218), Tv(781)]),
+    ((790, false, AND2), &[Tv(788), Tv(789)]),
+    ((801, false, AND2), &[Tv(798), Tv(799)]),
+    ((802, false, AND2), &[Tv(245), Tv(800)]),
+    ((809, false, AND2), &[Tv(807), Tv(808)]),
+    ((820, false, AND2), &[Tv(817), Tv(818)]),
+    ((821, false, AND2), &[Tv(272), Tv(819)]),
+    ((828, false, AND2), &[Tv(826), Tv(827)]),
+    ((892, false, AND2), &[Tv(889), Tv(890)]),
+    ((893, false, AND2), &[Tv(191), Tv(891)]),
+    ((900, false, AND2), &[Tv(898), Tv(899)]),
+    ((911, false, AND2), &[Tv(908), Tv(909)]),
+    ((912, false, AND2), &[Tv(218), Tv(910)]),
+    ((919, false, AND2), &[Tv(917), Tv(918)]),
+    ((930, false, AND2), &[Tv(927), Tv(928)]),
+    ((931, false, AND2), &[Tv(245), Tv(929)]),
+    ((938, false, AND2), &[Tv(936), Tv(937)]),
+    ((949, false, AND2), &[Tv(946), Tv(947)]),
+    ((950, false, AND2), &[Tv(272), Tv(948)]),
+    ((957, false, AND2), &[Tv(955), Tv(956)]),
+    ((1020, false, AND2), &[Tv(1017), Tv(1018)]),
+    ((1021, false, AND2), &[Tv(191), Tv(1019)]),
+    ((1028, false, AND2), &[Tv(1026), Tv(1027)]),
+    ((1039, false, AND2), &[Tv(1036), Tv(1037)]),
+    ((1040, false, AND2), &[Tv(218), Tv(1038)]),
+    ((1047, false, AND2), &[Tv(1045), Tv(1046)]),
+    ((1058, false, AND2), &[Tv(1055), Tv(1056)]),
+    ((1059, false, AND2), &[Tv(245), Tv(1057)]),
+    ((1066, false, AND2), &[Tv(1064), Tv(1065)]),
+    ((1077, false, AND2), &[Tv(1074), Tv(1075)]),
+    ((1078, false, AND2), &[Tv(272), Tv(1076)]),
+    ((1085, false, AND2), &[Tv(1083), Tv(1084)]),
+    ((1150, false, AND2), &[Tv(1147), Tv(1148)]),
+    ((1151, false, AND2), &[Tv(191), Tv(1149)]),
+    ((1158, false, AND2), &[Tv(1156), Tv(1157)]),
+    ((1169, false, AND2), &[Tv(1166), Tv(1167)]),
+    ((1170, false, AND2), &[Tv(218), Tv(1168)]),
+    ((1177, false, AND2), &[Tv(1175), Tv(1176)]),
+    ((1188, false, AND2), &[Tv(1185), Tv(1186)]),
+    ((1189, false, AND2), &[Tv(245), Tv(1187)]),
+    ((1196, false, AND2), &[Tv(1194), Tv(1195)]),
+    ((1207, false, AND2), &[Tv(1204), Tv(1205)]),
+    ((1208, false, AND2), &[Tv(272), Tv(1206)]),
+    ((1215, false, AND2), &[Tv(1213), Tv(1214)]),
+    ((1305, false, AND2), &[Tv(62), Tv(1304)]),
+    ((1307, false, AND2), &[Tv(62), Tv(1306)]),
+];
+
+static LEVEL_10: [((usize, bool, CellType), &[GateInput]); 70] = [
+    ((36, true, XOR2), &[Arg(0, 36), Tv(1305)]),
+    ((37, true, XOR2), &[Arg(0, 37), Tv(1307)]),
+    ((194, false, AND2), &[Tv(192), Tv(193)]),
+    ((207, false, AND2), &[Tv(205), Tv(206)]),
+    ((221, false, AND2), &[Tv(219), Tv(220)]),
+    ((234, false, AND2), &[Tv(232), Tv(233)]),
+    ((248, false, AND2), &[Tv(246), Tv(247)]),
+    ((261, false, AND2), &[Tv(259), Tv(260)]),
+    ((275, false, AND2), &[Tv(273), Tv(274)]),
+    ((288, false, AND2), &[Tv(286), Tv(287)]),
+    ((362, false, AND2), &[Tv(360), Tv(361)]),
+    ((369, false, AND2), &[Tv(206), Tv(368)]),
+    ((381, false, AND2), &[Tv(379), Tv(380)]),
+    ((388, false, AND2), &[Tv(233), Tv(387)]),
+    ((400, false, AND2), &[Tv(398), Tv(399)]),
+    ((407, false, AND2), &[Tv(260), Tv(406)]),
+    ((419, false, AND2), &[Tv(417), Tv(418)]),
+    ((426, false, AND2), &[Tv(287), Tv(425)]),
+    ((497, false, AND2), &[Tv(495), Tv(496)]),
+    ((504, false, AND2), &[Tv(206), Tv(503)]),
+    ((516, false, AND2), &[Tv(514), Tv(515)]),
+    ((523, false, AND2), &[Tv(233), Tv(522)]),
+    ((535, false, AND2), &[Tv(533), Tv(534)]),
+    ((542, false, AND2), &[Tv(260), Tv(541)]),
+    ((554, false, AND2), &[Tv(552), Tv(553)]),
+    ((561, false, AND2), &[Tv(287), Tv(560)]),
+    ((630, false, AND2), &[Tv(628), Tv(629)]),
+    ((637, false, AND2), &[Tv(206), Tv(636)]),
+    ((649, false, AND2), &[Tv(647), Tv(648)]),
+    ((656, false, AND2), &[Tv(233), Tv(655)]),
+    ((668, false, AND2), &[Tv(666), Tv(667)]),
+    ((675, false, AND2), &[Tv(260), Tv(674)]),
+    ((687, false, AND2), &[Tv(685), Tv(686)]),
+    ((694, false, AND2), &[Tv(287), Tv(693)]),
+    ((765, false, AND2), &[Tv(763), Tv(764)]),
+    ((772, false, AND2), &[Tv(206), Tv(771)]),
+    ((784, false, AND2), &[Tv(782), Tv(783)]),
+    ((791, false, AND2), &[Tv(233), Tv(790)]),
+    ((803, false, AND2), &[Tv(801), Tv(802)]),
+    ((810, false, AND2), &[Tv(260), Tv(809)]),
+    ((822, false, AND2), &[Tv(820), Tv(821)]),
+    ((829, false, AND2), &[Tv(287), Tv(828)]),
+    ((894, false, AND2), &[Tv(892), Tv(893)]),
+    ((901, false, AND2), &[Tv(206), Tv(900)]),
+    ((913, false, AND2), &[Tv(911), Tv(912)]),
+    ((920, false, AND2), &[Tv(233), Tv(919)]),
+    ((932, false, AND2), &[Tv(930), Tv(931)]),
+    ((939, false, AND2), &[Tv(260), Tv(938)]),
+    ((951, false, AND2), &[Tv(949), Tv(950)]),
+    ((958, false, AND2), &[Tv(287), Tv(957)]),
+    ((1022, false, AND2), &[Tv(1020), Tv(1021)]),
+    ((1029, false, AND2), &[Tv(206), Tv(1028)]),
+    ((1041, false, AND2), &[Tv(1039), Tv(1040)]),
+    ((1048, false, AND2), &[Tv(233), Tv(1047)]),
+    ((1060, false, AND2), &[Tv(1058), Tv(1059)]),
+    ((1067, false, AND2), &[Tv(260), Tv(1066)]),
+    ((1079, false, AND2), &[Tv(1077), Tv(1078)]),
+    ((1086, false, AND2), &[Tv(287), Tv(1085)]),
+    ((1152, false, AND2), &[Tv(1150), Tv(1151)]),
+    ((1159, false, AND2), &[Tv(206), Tv(1158)]),
+    ((1171, false, AND2), &[Tv(1169), Tv(1170)]),
+    ((1178, false, AND2), &[Tv(233), Tv(1177)]),
+    ((1190, false, AND2), &[Tv(1188), Tv(1189)]),
+    ((1197, false, AND2), &[Tv(260), Tv(1196)]),
+    ((1209, false, AND2), &[Tv(1207), Tv(1208)]),
+    ((1216, false, AND2), &[Tv(287), Tv(1215)]),
+    ((1296, false, XOR2), &[Arg(0, 32), Tv(129)]),
+    ((1298, false, XOR2), &[Arg(0, 33), Tv(130)]),
+    ((1300, false, XOR2), &[Arg(0, 34), Tv(131)]),
+    ((1302, false, XOR2), &[Arg(0, 35), Tv(132)]),
+];
+
+static LEVEL_11: [((usize, bool, CellType), &[GateInput]); 36] = [
+    ((208, false, NAND2), &[Tv(194), Tv(207)]),
+    ((235, false, NAND2), &[Tv(221), Tv(234)]),
+    ((262, false, NAND2), &[Tv(248), Tv(261)]),
+    ((289, false, NAND2), &[Tv(275), Tv(288)]),
+    ((370, false, NAND2), &[Tv(362), Tv(369)]),
+    ((389, false, NAND2), &[Tv(381), Tv(388)]),
+    ((408, false, NAND2), &[Tv(400), Tv(407)]),
+    ((427, false, NAND2), &[Tv(419), Tv(426)]),
+    ((505, false, NAND2), &[Tv(497), Tv(504)]),
+    ((524, false, NAND2), &[Tv(516), Tv(523)]),
+    ((543, false, NAND2), &[Tv(535), Tv(542)]),
+    ((562, false, NAND2), &[Tv(554), Tv(561)]),
+    ((638, false, NAND2), &[Tv(630), Tv(637)]),
+    ((657, false, NAND2), &[Tv(649), Tv(656)]),
+    ((676, false, NAND2), &[Tv(668), Tv(675)]),
+    ((695, false, NAND2), &[Tv(687), Tv(694)]),
+    ((773, false, NAND2), &[Tv(765), Tv(772)]),
+    ((792, false, NAND2), &[Tv(784), Tv(791)]),
+    ((811, false, NAND2), &[Tv(803), Tv(810)]),
+    ((830, false, NAND2), &[Tv(822), Tv(829)]),
+    ((902, false, NAND2), &[Tv(894), Tv(901)]),
+    ((921, false, NAND2), &[Tv(913), Tv(920)]),
+    ((940, false, NAND2), &[Tv(932), Tv(939)]),
+    ((959, false, NAND2), &[Tv(951), Tv(958)]),
+    ((1030, false, NAND2), &[Tv(1022), Tv(1029)]),
+    ((1049, false, NAND2), &[Tv(1041), Tv(1048)]),
+    ((1068, false, NAND2), &[Tv(1060), Tv(1067)]),
+    ((1087, false, NAND2), &[Tv(1079), Tv(1086)]),
+    ((1160, false, NAND2), &[Tv(1152), Tv(1159)]),
+    ((1179, false, NAND2), &[Tv(1171), Tv(1178)]),
+    ((1198, false, NAND2), &[Tv(1190), Tv(1197)]),
+    ((1217, false, NAND2), &[Tv(1209), Tv(1216)]),
+    ((1297, false, AND2), &[Tv(62), Tv(1296)]),
+    ((1299, false, AND2), &[Tv(62), Tv(1298)]),
+    ((1301, false, AND2), &[Tv(62), Tv(1300)]),
+    ((1303, false, AND2), &[Tv(62), Tv(1302)]),
+];
+
+static LEVEL_12: [((usize, bool, CellType), &[GateInput]); 36] = [
+    ((32, true, XOR2), &[Arg(0, 32), Tv(1297)]),
+    ((33, true, XOR2), &[Arg(0, 33), Tv(1299)]),
+    ((34, true, XOR2), &[Arg(0, 34), Tv(1301)]),
+    ((35, true, XOR2), &[Arg(0, 35), Tv(1303)]),
+    ((290, false, INV), &[Tv(208)]),
+    ((291, false, INV), &[Tv(235)]),
+    ((293, false, INV), &[Tv(262)]),
+    ((294, false, INV), &[Tv(289)]),
+    ((428, false, INV), &[Tv(370)]),
+    ((429, false, INV), &[Tv(389)]),
+    ((431, false, INV), &[Tv(408)]),
+    ((432, false, INV), &[Tv(427)]),
+    ((563, false, INV), &[Tv(505)]),
+    ((564, false, INV), &[Tv(524)]),
+    ((566, false, INV), &[Tv(543)]),
+    ((567, false, INV), &[Tv(562)]),
+    ((696, false, INV), &[Tv(638)]),
+    ((697, false, INV), &[Tv(657)]),
+    ((699, false, INV), &[Tv(676)]),
+    ((700, false, INV), &[Tv(695)]),
+    ((831, false, INV), &[Tv(773)]),
+    ((832, false, INV), &[Tv(792)]),
+    ((834, false, INV), &[Tv(811)]),
+    ((835, false, INV), &[Tv(830)]),
+    ((960, false, INV), &[Tv(902)]),
+    ((961, false, INV), &[Tv(921)]),
+    ((963, false, INV), &[Tv(940)]),
+    ((964, false, INV), &[Tv(959)]),
+    ((1088, false, INV), &[Tv(1030)]),
+    ((1089, false, INV), &[Tv(1049)]),
+    ((1091, false, INV), &[Tv(1068)]),
+    ((1092, false, INV), &[Tv(1087)]),
+    ((1218, false, INV), &[Tv(1160)]),
+    ((1219, false, INV), &[Tv(1179)]),
+    ((1221, false, INV), &[Tv(1198)]),
+    ((1222, false, INV), &[Tv(1217)]),
+];
+
+static LEVEL_13: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((292, false, NOR2), &[Tv(290), Tv(291)]),
+    ((295, false, NOR2), &[Tv(293), Tv(294)]),
+    ((430, false, NOR2), &[Tv(428), Tv(429)]),
+    ((433, false, NOR2), &[Tv(431), Tv(432)]),
+    ((565, false, NOR2), &[Tv(563), Tv(564)]),
+    ((568, false, NOR2), &[Tv(566), Tv(567)]),
+    ((698, false, NOR2), &[Tv(696), Tv(697)]),
+    ((701, false, NOR2), &[Tv(699), Tv(700)]),
+    ((833, false, NOR2), &[Tv(831), Tv(832)]),
+    ((836, false, NOR2), &[Tv(834), Tv(835)]),
+    ((962, false, NOR2), &[Tv(960), Tv(961)]),
+    ((965, false, NOR2), &[Tv(963), Tv(964)]),
+    ((1090, false, NOR2), &[Tv(1088), Tv(1089)]),
+    ((1093, false, NOR2), &[Tv(1091), Tv(1092)]),
+    ((1220, false, NOR2), &[Tv(1218), Tv(1219)]),
+    ((1223, false, NOR2), &[Tv(1221), Tv(1222)]),
+];
+
+static LEVEL_14: [((usize, bool, CellType), &[GateInput]); 8] = [
+    ((296, false, AND2), &[Tv(292), Tv(295)]),
+    ((434, false, AND2), &[Tv(430), Tv(433)]),
+    ((569, false, AND2), &[Tv(565), Tv(568)]),
+    ((702, false, AND2), &[Tv(698), Tv(701)]),
+    ((837, false, AND2), &[Tv(833), Tv(836)]),
+    ((966, false, AND2), &[Tv(962), Tv(965)]),
+    ((1094, false, AND2), &[Tv(1090), Tv(1093)]),
+    ((1224, false, AND2), &[Tv(1220), Tv(1223)]),
+];
+
+static LEVEL_15: [((usize, bool, CellType), &[GateInput]); 17] = [
+    ((298, false, AND2), &[Tv(296), Tv(297)]),
+    ((301, false, AND2), &[Tv(296), Tv(300)]),
+    ((304, false, AND2), &[Tv(296), Tv(303)]),
+    ((307, false, AND2), &[Tv(296), Tv(306)]),
+    ((310, false, AND2), &[Tv(296), Tv(309)]),
+    ((312, false, INV), &[Tv(296)]),
+    ((317, false, AND2), &[Tv(296), Tv(316)]),
+    ((320, false, AND2), &[Tv(296), Tv(319)]),
+    ((323, false, AND2), &[Tv(296), Tv(322)]),
+    ((326, false, AND2), &[Tv(296), Tv(325)]),
+    ((329, false, AND2), &[Tv(296), Tv(328)]),
+    ((473, false, NOR2), &[Tv(296), Tv(434)]),
+    ((608, false, INV), &[Tv(569)]),
+    ((741, false, INV), &[Tv(702)]),
+    ((876, false, INV), &[Tv(837)]),
+    ((1005, false, INV), &[Tv(966)]),
+    ((1133, false, INV), &[Tv(1094)]),
+];
+
+static LEVEL_16: [((usize, bool, CellType), &[GateInput]); 19] = [
+    ((299, false, XOR2), &[Arg(0, 0), Tv(298)]),
+    ((302, false, XOR2), &[Arg(0, 1), Tv(301)]),
+    ((305, false, XOR2), &[Arg(0, 2), Tv(304)]),
+    ((308, false, XOR2), &[Arg(0, 3), Tv(307)]),
+    ((311, false, XOR2), &[Arg(0, 4), Tv(310)]),
+    ((313, false, AND2), &[Arg(0, 5), Tv(312)]),
+    ((314, false, AND2), &[Arg(0, 6), Tv(312)]),
+    ((315, false, AND2), &[Arg(0, 7), Tv(312)]),
+    ((318, false, XOR2), &[Arg(0, 8), Tv(317)]),
+    ((321, false, XOR2), &[Arg(0, 9), Tv(320)]),
+    ((324, false, XOR2), &[Arg(0, 10), Tv(323)]),
+    ((327, false, XOR2), &[Arg(0, 11), Tv(326)]),
+    ((330, false, XOR2), &[Arg(0, 12), Tv(329)]),
+    ((331, false, AND2), &[Arg(0, 13), Tv(312)]),
+    ((332, false, AND2), &[Arg(0, 14), Tv(312)]),
+    ((333, false, AND2), &[Arg(0, 15), Tv(312)]),
+    ((435, false, AND2), &[Tv(312), Tv(434)]),
+    ((570, false, AND2), &[Tv(473), Tv(569)]),
+    ((609, false, AND2), &[Tv(473), Tv(608)]),
+];
+
+static LEVEL_17: [((usize, bool, CellType), &[GateInput]); 14] = [
+    ((436, false, XNOR2), &[Tv(299), Tv(336)]),
+    ((439, false, XNOR2), &[Tv(302), Tv(338)]),
+    ((442, false, XNOR2), &[Tv(305), Tv(341)]),
+    ((445, false, XNOR2), &[Tv(308), Tv(342)]),
+    ((448, false, XNOR2), &[Tv(311), Tv(344)]),
+    ((451, false, INV), &[Tv(435)]),
+    ((455, false, XNOR2), &[Tv(318), Tv(346)]),
+    ((458, false, XNOR2), &[Tv(321), Tv(348)]),
+    ((461, false, XNOR2), &[Tv(324), Tv(351)]),
+    ((464, false, XNOR2), &[Tv(181), Tv(327)]),
+    ((467, false, XNOR2), &[Tv(181), Tv(330)]),
+    ((586, false, INV), &[Tv(570)]),
+    ((703, false, AND2), &[Tv(609), Tv(702)]),
+    ((742, false, AND2), &[Tv(609), Tv(741)]),
+];
+
+static LEVEL_18: [((usize, bool, CellType), &[GateInput]); 19] = [
+    ((437, false, AND2), &[Tv(435), Tv(436)]),
+    ((440, false, AND2), &[Tv(435), Tv(439)]),
+    ((443, false, AND2), &[Tv(435), Tv(442)]),
+    ((446, false, AND2), &[Tv(435), Tv(445)]),
+    ((449, false, AND2), &[Tv(435), Tv(448)]),
+    ((452, false, AND2), &[Tv(313), Tv(451)]),
+    ((453, false, AND2), &[Tv(314), Tv(451)]),
+    ((454, false, AND2), &[Tv(315), Tv(451)]),
+    ((456, false, AND2), &[Tv(435), Tv(455)]),
+    ((459, false, AND2), &[Tv(435), Tv(458)]),
+    ((462, false, AND2), &[Tv(435), Tv(461)]),
+    ((465, false, AND2), &[Tv(435), Tv(464)]),
+    ((468, false, AND2), &[Tv(435), Tv(467)]),
+    ((470, false, AND2), &[Tv(331), Tv(451)]),
+    ((471, false, AND2), &[Tv(332), Tv(451)]),
+    ((472, false, AND2), &[Tv(333), Tv(451)]),
+    ((719, false, INV), &[Tv(703)]),
+    ((838, false, AND2), &[Tv(742), Tv(837)]),
+    ((877, false, AND2), &[Tv(742), Tv(876)]),
+];
+
+static LEVEL_19: [((usize, bool, CellType), &[GateInput]); 19] = [
+    ((438, false, XOR2), &[Tv(299), Tv(437)]),
+    ((441, false, XOR2), &[Tv(302), Tv(440)]),
+    ((444, false, XOR2), &[Tv(305), Tv(443)]),
+    ((447, false, XOR2), &[Tv(308), Tv(446)]),
+    ((450, false, XOR2), &[Tv(311), Tv(449)]),
+    ((457, false, XOR2), &[Tv(318), Tv(456)]),
+    ((460, false, XOR2), &[Tv(321), Tv(459)]),
+    ((463, false, XOR2), &[Tv(324), Tv(462)]),
+    ((466, false, XOR2), &[Tv(327), Tv(465)]),
+    ((469, false, XOR2), &[Tv(330), Tv(468)]),
+    ((587, false, AND2), &[Tv(452), Tv(586)]),
+    ((588, false, AND2), &[Tv(453), Tv(586)]),
+    ((589, false, AND2), &[Tv(454), Tv(586)]),
+    ((605, false, AND2), &[Tv(470), Tv(586)]),
+    ((606, false, AND2), &[Tv(471), Tv(586)]),
+    ((607, false, AND2), &[Tv(472), Tv(586)]),
+    ((854, false, INV), &[Tv(838)]),
+    ((967, false, AND2), &[Tv(877), Tv(966)]),
+    ((1006, false, AND2), &[Tv(877), Tv(1005)]),
+];
+
+static LEVEL_20: [((usize, bool, CellType), &[GateInput]); 19] = [
+    ((571, false, XNOR2), &[Tv(438), Tv(475)]),
+    ((574, false, XNOR2), &[Tv(441), Tv(477)]),
+    ((577, false, XNOR2), &[Tv(444), Tv(479)]),
+    ((580, false, XNOR2), &[Tv(447), Tv(480)]),
+    ((583, false, XNOR2), &[Tv(450), Tv(482)]),
+    ((590, false, XNOR2), &[Tv(457), Tv(484)]),
+    ((593, false, XNOR2), &[Tv(460), Tv(485)]),
+    ((596, false, XNOR2), &[Tv(463), Tv(486)]),
+    ((599, false, XNOR2), &[Tv(181), Tv(466)]),
+    ((602, false, XNOR2), &[Tv(181), Tv(469)]),
+    ((720, false, AND2), &[Tv(587), Tv(719)]),
+    ((721, false, AND2), &[Tv(588), Tv(719)]),
+    ((722, false, AND2), &[Tv(589), Tv(719)]),
+    ((738, false, AND2), &[Tv(605), Tv(719)]),
+    ((739, false, AND2), &[Tv(606), Tv(719)]),
+    ((740, false, AND2), &[Tv(607), Tv(719)]),
+    ((983, false, INV), &[Tv(967)]),
+    ((1095, false, AND2), &[Tv(1006), Tv(1094)]),
+    ((1134, false, AND2), &[Tv(1006), Tv(1133)]),
+];
+
+static LEVEL_21: [((usize, bool, CellType), &[GateInput]); 18] = [
+    ((572, false, AND2), &[Tv(570), Tv(571)]),
+    ((575, false, AND2), &[Tv(570), Tv(574)]),
+    ((578, false, AND2), &[Tv(570), Tv(577)]),
+    ((581, false, AND2), &[Tv(570), Tv(580)]),
+    ((584, false, AND2), &[Tv(570), Tv(583)]),
+    ((591, false, AND2), &[Tv(570), Tv(590)]),
+    ((594, false, AND2), &[Tv(570), Tv(593)]),
+    ((597, false, AND2), &[Tv(570), Tv(596)]),
+    ((600, false, AND2), &[Tv(570), Tv(599)]),
+    ((603, false, AND2), &[Tv(570), Tv(602)]),
+    ((855, false, AND2), &[Tv(720), Tv(854)]),
+    ((856, false, AND2), &[Tv(721), Tv(854)]),
+    ((857, false, AND2), &[Tv(722), Tv(854)]),
+    ((873, false, AND2), &[Tv(738), Tv(854)]),
+    ((874, false, AND2), &[Tv(739), Tv(854)]),
+    ((875, false, AND2), &[Tv(740), Tv(854)]),
+    ((1111, false, INV), &[Tv(1095)]),
+    ((1225, false, AND2), &[Tv(1134), Tv(1224)]),
+];
+
+static LEVEL_22: [((usize, bool, CellType), &[GateInput]); 17] = [
+    ((573, false, XOR2), &[Tv(438), Tv(572)]),
+    ((576, false, XOR2), &[Tv(441), Tv(575)]),
+    ((579, false, XOR2), &[Tv(444), Tv(578)]),
+    ((582, false, XOR2), &[Tv(447), Tv(581)]),
+    ((585, false, XOR2), &[Tv(450), Tv(584)]),
+    ((592, false, XOR2), &[Tv(457), Tv(591)]),
+    ((595, false, XOR2), &[Tv(460), Tv(594)]),
+    ((598, false, XOR2), &[Tv(463), Tv(597)]),
+    ((601, false, XOR2), &[Tv(466), Tv(600)]),
+    ((604, false, XOR2), &[Tv(469), Tv(603)]),
+    ((984, false, AND2), &[Tv(855), Tv(983)]),
+    ((985, false, AND2), &[Tv(856), Tv(983)]),
+    ((986, false, AND2), &[Tv(857), Tv(983)]),
+    ((1002, false, AND2), &[Tv(873), Tv(983)]),
+    ((1003, false, AND2), &[Tv(874), Tv(983)]),
+    ((1004, false, AND2), &[Tv(875), Tv(983)]),
+    ((1241, false, INV), &[Tv(1225)]),
+];
+
+static LEVEL_23: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((704, false, XNOR2), &[Tv(573), Tv(611)]),
+    ((707, false, XNOR2), &[Tv(576), Tv(613)]),
+    ((710, false, XNOR2), &[Tv(579), Tv(615)]),
+    ((713, false, XNOR2), &[Tv(582), Tv(616)]),
+    ((716, false, XNOR2), &[Tv(164), Tv(585)]),
+    ((723, false, XNOR2), &[Tv(592), Tv(617)]),
+    ((726, false, XNOR2), &[Tv(595), Tv(618)]),
+    ((729, false, XNOR2), &[Tv(598), Tv(619)]),
+    ((732, false, XNOR2), &[Tv(181), Tv(601)]),
+    ((735, false, XNOR2), &[Tv(181), Tv(604)]),
+    ((1112, false, AND2), &[Tv(984), Tv(1111)]),
+    ((1113, false, AND2), &[Tv(985), Tv(1111)]),
+    ((1114, false, AND2), &[Tv(986), Tv(1111)]),
+    ((1130, false, AND2), &[Tv(1002), Tv(1111)]),
+    ((1131, false, AND2), &[Tv(1003), Tv(1111)]),
+    ((1132, false, AND2), &[Tv(1004), Tv(1111)]),
+];
+
+static LEVEL_24: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((705, false, AND2), &[Tv(703), Tv(704)]),
+    ((708, false, AND2), &[Tv(703), Tv(707)]),
+    ((711, false, AND2), &[Tv(703), Tv(710)]),
+    ((714, false, AND2), &[Tv(703), Tv(713)]),
+    ((717, false, AND2), &[Tv(703), Tv(716)]),
+    ((724, false, AND2), &[Tv(703), Tv(723)]),
+    ((727, false, AND2), &[Tv(703), Tv(726)]),
+    ((730, false, AND2), &[Tv(703), Tv(729)]),
+    ((733, false, AND2), &[Tv(703), Tv(732)]),
+    ((736, false, AND2), &[Tv(703), Tv(735)]),
+    ((1242, false, AND2), &[Tv(1112), Tv(1241)]),
+    ((1243, false, AND2), &[Tv(1113), Tv(1241)]),
+    ((1244, false, AND2), &[Tv(1114), Tv(1241)]),
+    ((1260, false, AND2), &[Tv(1130), Tv(1241)]),
+    ((1261, false, AND2), &[Tv(1131), Tv(1241)]),
+    ((1262, false, AND2), &[Tv(1132), Tv(1241)]),
+];
+
+static LEVEL_25: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((706, false, XOR2), &[Tv(573), Tv(705)]),
+    ((709, false, XOR2), &[Tv(576), Tv(708)]),
+    ((712, false, XOR2), &[Tv(579), Tv(711)]),
+    ((715, false, XOR2), &[Tv(582), Tv(714)]),
+    ((718, false, XOR2), &[Tv(585), Tv(717)]),
+    ((725, false, XOR2), &[Tv(592), Tv(724)]),
+    ((728, false, XOR2), &[Tv(595), Tv(727)]),
+    ((731, false, XOR2), &[Tv(598), Tv(730)]),
+    ((734, false, XOR2), &[Tv(601), Tv(733)]),
+    ((737, false, XOR2), &[Tv(604), Tv(736)]),
+    ((1273, false, XOR2), &[Arg(0, 5), Tv(1242)]),
+    ((1275, false, XOR2), &[Arg(0, 6), Tv(1243)]),
+    ((1277, false, XOR2), &[Arg(0, 7), Tv(1244)]),
+    ((1289, false, XOR2), &[Arg(0, 13), Tv(1260)]),
+    ((1291, false, XOR2), &[Arg(0, 14), Tv(1261)]),
+    ((1293, false, XOR2), &[Arg(0, 15), Tv(1262)]),
+];
+
+static LEVEL_26: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((839, false, XNOR2), &[Tv(706), Tv(743)]),
+    ((842, false, XNOR2), &[Tv(709), Tv(745)]),
+    ((845, false, XNOR2), &[Tv(712), Tv(748)]),
+    ((848, false, XNOR2), &[Tv(715), Tv(749)]),
+    ((851, false, XNOR2), &[Tv(341), Tv(718)]),
+    ((858, false, XNOR2), &[Tv(725), Tv(750)]),
+    ((861, false, XNOR2), &[Tv(728), Tv(751)]),
+    ((864, false, XNOR2), &[Tv(731), Tv(754)]),
+    ((867, false, XNOR2), &[Tv(181), Tv(734)]),
+    ((870, false, XNOR2), &[Tv(181), Tv(737)]),
+    ((1274, false, AND2), &[Tv(62), Tv(1273)]),
+    ((1276, false, AND2), &[Tv(62), Tv(1275)]),
+    ((1278, false, AND2), &[Tv(62), Tv(1277)]),
+    ((1290, false, AND2), &[Tv(62), Tv(1289)]),
+    ((1292, false, AND2), &[Tv(62), Tv(1291)]),
+    ((1294, false, AND2), &[Tv(62), Tv(1293)]),
+];
+
+static LEVEL_27: [((usize, bool, CellType), &[GateInput]); 16] = [
+    ((5, true, XOR2), &[Arg(0, 5), Tv(1274)]),
+    ((6, true, XOR2), &[Arg(0, 6), Tv(1276)]),
+    ((7, true, XOR2), &[Arg(0, 7), Tv(1278)]),
+    ((13, true, XOR2), &[Arg(0, 13), Tv(1290)]),
+    ((14, true, XOR2), &[Arg(0, 14), Tv(1292)]),
+    ((15, true, XOR2), &[Arg(0, 15), Tv(1294)]),
+    ((840, false, AND2), &[Tv(838), Tv(839)]),
+    ((843, false, AND2), &[Tv(838), Tv(842)]),
+    ((846, false, AND2), &[Tv(838), Tv(845)]),
+    ((849, false, AND2), &[Tv(838), Tv(848)]),
+    ((852, false, AND2), &[Tv(838), Tv(851)]),
+    ((859, false, AND2), &[Tv(838), Tv(858)]),
+    ((862, false, AND2), &[Tv(838), Tv(861)]),
+    ((865, false, AND2), &[Tv(838), Tv(864)]),
+    ((868, false, AND2), &[Tv(838), Tv(867)]),
+    ((871, false, AND2), &[Tv(838), Tv(870)]),
+];
+
+static LEVEL_28: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((841, false, XOR2), &[Tv(706), Tv(840)]),
+    ((844, false, XOR2), &[Tv(709), Tv(843)]),
+    ((847, false, XOR2), &[Tv(712), Tv(846)]),
+    ((850, false, XOR2), &[Tv(715), Tv(849)]),
+    ((853, false, XOR2), &[Tv(718), Tv(852)]),
+    ((860, false, XOR2), &[Tv(725), Tv(859)]),
+    ((863, false, XOR2), &[Tv(728), Tv(862)]),
+    ((866, false, XOR2), &[Tv(731), Tv(865)]),
+    ((869, false, XOR2), &[Tv(734), Tv(868)]),
+    ((872, false, XOR2), &[Tv(737), Tv(871)]),
+];
+
+static LEVEL_29: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((968, false, XNOR2), &[Tv(841), Tv(879)]),
+    ((971, false, XNOR2), &[Tv(844), Tv(880)]),
+    ((974, false, XNOR2), &[Tv(170), Tv(847)]),
+    ((977, false, XNOR2), &[Tv(850), Tv(882)]),
+    ((980, false, XNOR2), &[Tv(479), Tv(853)]),
+    ((987, false, XNOR2), &[Tv(163), Tv(860)]),
+    ((990, false, XNOR2), &[Tv(614), Tv(863)]),
+    ((993, false, XNOR2), &[Tv(866), Tv(883)]),
+    ((996, false, XNOR2), &[Tv(181), Tv(869)]),
+    ((999, false, XNOR2), &[Tv(181), Tv(872)]),
+];
+
+static LEVEL_30: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((969, false, AND2), &[Tv(967), Tv(968)]),
+    ((972, false, AND2), &[Tv(967), Tv(971)]),
+    ((975, false, AND2), &[Tv(967), Tv(974)]),
+    ((978, false, AND2), &[Tv(967), Tv(977)]),
+    ((981, false, AND2), &[Tv(967), Tv(980)]),
+    ((988, false, AND2), &[Tv(967), Tv(987)]),
+    ((991, false, AND2), &[Tv(967), Tv(990)]),
+    ((994, false, AND2), &[Tv(967), Tv(993)]),
+    ((997, false, AND2), &[Tv(967), Tv(996)]),
+    ((1000, false, AND2), &[Tv(967), Tv(999)]),
+];
+
+static LEVEL_31: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((970, false, XOR2), &[Tv(841), Tv(969)]),
+    ((973, false, XOR2), &[Tv(844), Tv(972)]),
+    ((976, false, XOR2), &[Tv(847), Tv(975)]),
+    ((979, false, XOR2), &[Tv(850), Tv(978)]),
+    ((982, false, XOR2), &[Tv(853), Tv(981)]),
+    ((989, false, XOR2), &[Tv(860), Tv(988)]),
+    ((992, false, XOR2), &[Tv(863), Tv(991)]),
+    ((995, false, XOR2), &[Tv(866), Tv(994)]),
+    ((998, false, XOR2), &[Tv(869), Tv(997)]),
+    ((1001, false, XOR2), &[Tv(872), Tv(1000)]),
+];
+
+static LEVEL_32: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1096, false, XNOR2), &[Tv(747), Tv(970)]),
+    ((1099, false, XNOR2), &[Tv(973), Tv(1007)]),
+    ((1102, false, XNOR2), &[Tv(344), Tv(976)]),
+    ((1105, false, XNOR2), &[Tv(979), Tv(1009)]),
+    ((1108, false, XNOR2), &[Tv(615), Tv(982)]),
+    ((1115, false, XNOR2), &[Tv(340), Tv(989)]),
+    ((1118, false, XNOR2), &[Tv(992), Tv(1010)]),
+    ((1121, false, XNOR2), &[Tv(995), Tv(1011)]),
+    ((1124, false, XNOR2), &[Tv(181), Tv(998)]),
+    ((1127, false, XNOR2), &[Tv(181), Tv(1001)]),
+];
+
+static LEVEL_33: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1097, false, AND2), &[Tv(1095), Tv(1096)]),
+    ((1100, false, AND2), &[Tv(1095), Tv(1099)]),
+    ((1103, false, AND2), &[Tv(1095), Tv(1102)]),
+    ((1106, false, AND2), &[Tv(1095), Tv(1105)]),
+    ((1109, false, AND2), &[Tv(1095), Tv(1108)]),
+    ((1116, false, AND2), &[Tv(1095), Tv(1115)]),
+    ((1119, false, AND2), &[Tv(1095), Tv(1118)]),
+    ((1122, false, AND2), &[Tv(1095), Tv(1121)]),
+    ((1125, false, AND2), &[Tv(1095), Tv(1124)]),
+    ((1128, false, AND2), &[Tv(1095), Tv(1127)]),
+];
+
+static LEVEL_34: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1098, false, XOR2), &[Tv(970), Tv(1097)]),
+    ((1101, false, XOR2), &[Tv(973), Tv(1100)]),
+    ((1104, false, XOR2), &[Tv(976), Tv(1103)]),
+    ((1107, false, XOR2), &[Tv(979), Tv(1106)]),
+    ((1110, false, XOR2), &[Tv(982), Tv(1109)]),
+    ((1117, false, XOR2), &[Tv(989), Tv(1116)]),
+    ((1120, false, XOR2), &[Tv(992), Tv(1119)]),
+    ((1123, false, XOR2), &[Tv(995), Tv(1122)]),
+    ((1126, false, XOR2), &[Tv(998), Tv(1125)]),
+    ((1129, false, XOR2), &[Tv(1001), Tv(1128)]),
+];
+
+static LEVEL_35: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1226, false, XNOR2), &[Tv(1098), Tv(1135)]),
+    ((1229, false, XNOR2), &[Tv(1101), Tv(1137)]),
+    ((1232, false, XNOR2), &[Tv(482), Tv(1104)]),
+    ((1235, false, XNOR2), &[Tv(1107), Tv(1138)]),
+    ((1238, false, XNOR2), &[Tv(748), Tv(1110)]),
+    ((1245, false, XNOR2), &[Tv(478), Tv(1117)]),
+    ((1248, false, XNOR2), &[Tv(1120), Tv(1139)]),
+    ((1251, false, XNOR2), &[Tv(1123), Tv(1141)]),
+    ((1254, false, XNOR2), &[Tv(181), Tv(1126)]),
+    ((1257, false, XNOR2), &[Tv(181), Tv(1129)]),
+];
+
+static LEVEL_36: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1227, false, AND2), &[Tv(1225), Tv(1226)]),
+    ((1230, false, AND2), &[Tv(1225), Tv(1229)]),
+    ((1233, false, AND2), &[Tv(1225), Tv(1232)]),
+    ((1236, false, AND2), &[Tv(1225), Tv(1235)]),
+    ((1239, false, AND2), &[Tv(1225), Tv(1238)]),
+    ((1246, false, AND2), &[Tv(1225), Tv(1245)]),
+    ((1249, false, AND2), &[Tv(1225), Tv(1248)]),
+    ((1252, false, AND2), &[Tv(1225), Tv(1251)]),
+    ((1255, false, AND2), &[Tv(1225), Tv(1254)]),
+    ((1258, false, AND2), &[Tv(1225), Tv(1257)]),
+];
+
+static LEVEL_37: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1228, false, XOR2), &[Tv(1098), Tv(1227)]),
+    ((1231, false, XOR2), &[Tv(1101), Tv(1230)]),
+    ((1234, false, XOR2), &[Tv(1104), Tv(1233)]),
+    ((1237, false, XOR2), &[Tv(1107), Tv(1236)]),
+    ((1240, false, XOR2), &[Tv(1110), Tv(1239)]),
+    ((1247, false, XOR2), &[Tv(1117), Tv(1246)]),
+    ((1250, false, XOR2), &[Tv(1120), Tv(1249)]),
+    ((1253, false, XOR2), &[Tv(1123), Tv(1252)]),
+    ((1256, false, XOR2), &[Tv(1126), Tv(1255)]),
+    ((1259, false, XOR2), &[Tv(1129), Tv(1258)]),
+];
+
+static LEVEL_38: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1263, false, XOR2), &[Arg(0, 0), Tv(1228)]),
+    ((1265, false, XOR2), &[Arg(0, 1), Tv(1231)]),
+    ((1267, false, XOR2), &[Arg(0, 2), Tv(1234)]),
+    ((1269, false, XOR2), &[Arg(0, 3), Tv(1237)]),
+    ((1271, false, XOR2), &[Arg(0, 4), Tv(1240)]),
+    ((1279, false, XOR2), &[Arg(0, 8), Tv(1247)]),
+    ((1281, false, XOR2), &[Arg(0, 9), Tv(1250)]),
+    ((1283, false, XOR2), &[Arg(0, 10), Tv(1253)]),
+    ((1285, false, XOR2), &[Arg(0, 11), Tv(1256)]),
+    ((1287, false, XOR2), &[Arg(0, 12), Tv(1259)]),
+];
+
+static LEVEL_39: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((1264, false, AND2), &[Tv(62), Tv(1263)]),
+    ((1266, false, AND2), &[Tv(62), Tv(1265)]),
+    ((1268, false, AND2), &[Tv(62), Tv(1267)]),
+    ((1270, false, AND2), &[Tv(62), Tv(1269)]),
+    ((1272, false, AND2), &[Tv(62), Tv(1271)]),
+    ((1280, false, AND2), &[Tv(62), Tv(1279)]),
+    ((1282, false, AND2), &[Tv(62), Tv(1281)]),
+    ((1284, false, AND2), &[Tv(62), Tv(1283)]),
+    ((1286, false, AND2), &[Tv(62), Tv(1285)]),
+    ((1288, false, AND2), &[Tv(62), Tv(1287)]),
+];
+
+static LEVEL_40: [((usize, bool, CellType), &[GateInput]); 10] = [
+    ((0, true, XOR2), &[Arg(0, 0), Tv(1264)]),
+    ((1, true, XOR2), &[Arg(0, 1), Tv(1266)]),
+    ((2, true, XOR2), &[Arg(0, 2), Tv(1268)]),
+    ((3, true, XOR2), &[Arg(0, 3), Tv(1270)]),
+    ((4, true, XOR2), &[Arg(0, 4), Tv(1272)]),
+    ((8, true, XOR2), &[Arg(0, 8), Tv(1280)]),
+    ((9, true, XOR2), &[Arg(0, 9), Tv(1282)]),
+    ((10, true, XOR2), &[Arg(0, 10), Tv(1284)]),
+    ((11, true, XOR2), &[Arg(0, 11), Tv(1286)]),
+    ((12, true, XOR2), &[Arg(0, 12), Tv(1288)]),
+];
+
+static PRUNE_1: [usize; 18] = [
+  56,
+  57,
+  58,
+  59,
+  63,
+  66,
+  69,
+  72,
+  75,
+  78,
+  81,
+  84,
+  124,
+  125,
+  137,
+  140,
+  143,
+  146,
+];
+
+static PRUNE_2: [usize; 16] = [
+  60,
+  61,
+  64,
+  67,
+  70,
+  73,
+  76,
+  79,
+  82,
+  126,
+  127,
+  138,
+  139,
+  141,
+  144,
+  147,
+];
+
+static PRUNE_3: [usize; 5] = [
+  83,
+  85,
+  104,
+  142,
+  145,
+];
+
+static PRUNE_4: [usize; 16] = [
+  86,
+  89,
+  92,
+  95,
+  98,
+  101,
+  123,
+  152,
+  153,
+  155,
+  156,
+  160,
+  161,
+  167,
+  171,
+  1295,
+];
+
+static PRUNE_5: [usize; 36] = [
+  65,
+  68,
+  71,
+  74,
+  77,
+  80,
+  87,
+  90,
+  93,
+  96,
+  99,
+  102,
+  122,
+  136,
+  154,
+  157,
+  162,
+  166,
+  168,
+  172,
+  175,
+  178,
+  334,
+  335,
+  339,
+  345,
+  347,
+  349,
+  474,
+  483,
+  610,
+  746,
+  752,
+  878,
+  881,
+  1008,
+];
+
+static PRUNE_6: [usize; 29] = [
+  100,
+  103,
+  105,
+  106,
+  119,
+  135,
+  148,
+  149,
+  150,
+  151,
+  158,
+  165,
+  169,
+  173,
+  174,
+  176,
+  179,
+  180,
+  337,
+  343,
+  350,
+  476,
+  481,
+  612,
+  744,
+  753,
+  1136,
+  1140,
+  1310,
+];
+
+static PRUNE_7: [usize; 74] = [
+  107,
+  110,
+  113,
+  116,
+  120,
+  121,
+  159,
+  177,
+  195,
+  196,
+  222,
+  223,
+  249,
+  250,
+  276,
+  277,
+  363,
+  364,
+  382,
+  383,
+  401,
+  402,
+  420,
+  421,
+  498,
+  499,
+  517,
+  518,
+  536,
+  537,
+  555,
+  556,
+  631,
+  632,
+  650,
+  651,
+  669,
+  670,
+  688,
+  689,
+  766,
+  767,
+  785,
+  786,
+  804,
+  805,
+  823,
+  824,
+  895,
+  896,
+  914,
+  915,
+  933,
+  934,
+  952,
+  953,
+  1023,
+  1024,
+  1042,
+  1043,
+  1061,
+  1062,
+  1080,
+  1081,
+  1153,
+  1154,
+  1172,
+  1173,
+  1191,
+  1192,
+  1210,
+  1211,
+  1308,
+  1311,
+];
+
+static PRUNE_8: [usize; 219] = [
+  88,
+  91,
+  94,
+  97,
+  108,
+  111,
+  114,
+  117,
+  133,
+  134,
+  182,
+  183,
+  184,
+  185,
+  186,
+  189,
+  197,
+  198,
+  199,
+  202,
+  209,
+  210,
+  211,
+  212,
+  213,
+  216,
+  224,
+  225,
+  226,
+  229,
+  236,
+  237,
+  238,
+  239,
+  240,
+  243,
+  251,
+  252,
+  253,
+  256,
+  263,
+  264,
+  265,
+  266,
+  267,
+  270,
+  278,
+  279,
+  280,
+  283,
+  352,
+  353,
+  354,
+  355,
+  356,
+  365,
+  371,
+  372,
+  373,
+  374,
+  375,
+  384,
+  390,
+  391,
+  392,
+  393,
+  394,
+  403,
+  409,
+  410,
+  411,
+  412,
+  413,
+  422,
+  487,
+  488,
+  489,
+  490,
+  491,
+  500,
+  506,
+  507,
+  508,
+  509,
+  510,
+  519,
+  525,
+  526,
+  527,
+  528,
+  529,
+  538,
+  544,
+  545,
+  546,
+  547,
+  548,
+  557,
+  620,
+  621,
+  622,
+  623,
+  624,
+  633,
+  639,
+  640,
+  641,
+  642,
+  643,
+  652,
+  658,
+  659,
+  660,
+  661,
+  662,
+  671,
+  677,
+  678,
+  679,
+  680,
+  681,
+  690,
+  755,
+  756,
+  757,
+  758,
+  759,
+  768,
+  774,
+  775,
+  776,
+  777,
+  778,
+  787,
+  793,
+  794,
+  795,
+  796,
+  797,
+  806,
+  812,
+  813,
+  814,
+  815,
+  816,
+  825,
+  884,
+  885,
+  886,
+  887,
+  888,
+  897,
+  903,
+  904,
+  905,
+  906,
+  907,
+  916,
+  922,
+  923,
+  924,
+  925,
+  926,
+  935,
+  941,
+  942,
+  943,
+  944,
+  945,
+  954,
+  1012,
+  1013,
+  1014,
+  1015,
+  1016,
+  1025,
+  1031,
+  1032,
+  1033,
+  1034,
+  1035,
+  1044,
+  1050,
+  1051,
+  1052,
+  1053,
+  1054,
+  1063,
+  1069,
+  1070,
+  1071,
+  1072,
+  1073,
+  1082,
+  1142,
+  1143,
+  1144,
+  1145,
+  1146,
+  1155,
+  1161,
+  1162,
+  1163,
+  1164,
+  1165,
+  1174,
+  1180,
+  1181,
+  1182,
+  1183,
+  1184,
+  1193,
+  1199,
+  1200,
+  1201,
+  1202,
+  1203,
+  1212,
+  1309,
+];
+
+static PRUNE_9: [usize; 179] = [
+  109,
+  112,
+  115,
+  118,
+  128,
+  187,
+  188,
+  190,
+  191,
+  200,
+  201,
+  203,
+  204,
+  214,
+  215,
+  217,
+  218,
+  227,
+  228,
+  230,
+  231,
+  241,
+  242,
+  244,
+  245,
+  254,
+  255,
+  257,
+  258,
+  268,
+  269,
+  271,
+  272,
+  281,
+  282,
+  284,
+  285,
+  357,
+  358,
+  359,
+  366,
+  367,
+  376,
+  377,
+  378,
+  385,
+  386,
+  395,
+  396,
+  397,
+  404,
+  405,
+  414,
+  415,
+  416,
+  423,
+  424,
+  492,
+  493,
+  494,
+  501,
+  502,
+  511,
+  512,
+  513,
+  520,
+  521,
+  530,
+  531,
+  532,
+  539,
+  540,
+  549,
+  550,
+  551,
+  558,
+  559,
+  625,
+  626,
+  627,
+  634,
+  635,
+  644,
+  645,
+  646,
+  653,
+  654,
+  663,
+  664,
+  665,
+  672,
+  673,
+  682,
+  683,
+  684,
+  691,
+  692,
+  760,
+  761,
+  762,
+  769,
+  770,
+  779,
+  780,
+  781,
+  788,
+  789,
+  798,
+  799,
+  800,
+  807,
+  808,
+  817,
+  818,
+  819,
+  826,
+  827,
+  889,
+  890,
+  891,
+  898,
+  899,
+  908,
+  909,
+  910,
+  917,
+  918,
+  927,
+  928,
+  929,
+  936,
+  937,
+  946,
+  947,
+  948,
+  955,
+  956,
+  1017,
+  1018,
+  1019,
+  1026,
+  1027,
+  1036,
+  1037,
+  1038,
+  1045,
+  1046,
+  1055,
+  1056,
+  1057,
+  1064,
+  1065,
+  1074,
+  1075,
+  1076,
+  1083,
+  1084,
+  1147,
+  1148,
+  1149,
+  1156,
+  1157,
+  1166,
+  1167,
+  1168,
+  1175,
+  1176,
+  1185,
+  1186,
+  1187,
+  1194,
+  1195,
+  1204,
+  1205,
+  1206,
+  1213,
+  1214,
+  1304,
+  1306,
+];
+
+static PRUNE_10: [usize; 106] = [
+  129,
+  130,
+  131,
+  132,
+  192,
+  193,
+  205,
+  206,
+  219,
+  220,
+  232,
+  233,
+  246,
+  247,
+  259,
+  260,
+  273,
+  274,
+  286,
+  287,
+  360,
+  361,
+  368,
+  379,
+  380,
+  387,
+  398,
+  399,
+  406,
+  417,
+  418,
+  425,
+  495,
+  496,
+  503,
+  514,
+  515,
+  522,
+  533,
+  534,
+  541,
+  552,
+  553,
+  560,
+  628,
+  629,
+  636,
+  647,
+  648,
+  655,
+  666,
+  667,
+  674,
+  685,
+  686,
+  693,
+  763,
+  764,
+  771,
+  782,
+  783,
+  790,
+  801,
+  802,
+  809,
+  820,
+  821,
+  828,
+  892,
+  893,
+  900,
+  911,
+  912,
+  919,
+  930,
+  931,
+  938,
+  949,
+  950,
+  957,
+  1020,
+  1021,
+  1028,
+  1039,
+  1040,
+  1047,
+  1058,
+  1059,
+  1066,
+  1077,
+  1078,
+  1085,
+  1150,
+  1151,
+  1158,
+  1169,
+  1170,
+  1177,
+  1188,
+  1189,
+  1196,
+  1207,
+  1208,
+  1215,
+  1305,
+  1307,
+];
+
+static PRUNE_11: [usize; 68] = [
+  194,
+  207,
+  221,
+  234,
+  248,
+  261,
+  275,
+  288,
+  362,
+  369,
+  381,
+  388,
+  400,
+  407,
+  419,
+  426,
+  497,
+  504,
+  516,
+  523,
+  535,
+  542,
+  554,
+  561,
+  630,
+  637,
+  649,
+  656,
+  668,
+  675,
+  687,
+  694,
+  765,
+  772,
+  784,
+  791,
+  803,
+  810,
+  822,
+  829,
+  894,
+  901,
+  913,
+  920,
+  932,
+  939,
+  951,
+  958,
+  1022,
+  1029,
+  1041,
+  1048,
+  1060,
+  1067,
+  1079,
+  1086,
+  1152,
+  1159,
+  1171,
+  1178,
+  1190,
+  1197,
+  1209,
+  1216,
+  1296,
+  1298,
+  1300,
+  1302,
+];
+
+static PRUNE_12: [usize; 36] = [
+  208,
+  235,
+  262,
+  289,
+  370,
+  389,
+  408,
+  427,
+  505,
+  524,
+  543,
+  562,
+  638,
+  657,
+  676,
+  695,
+  773,
+  792,
+  811,
+  830,
+  902,
+  921,
+  940,
+  959,
+  1030,
+  1049,
+  1068,
+  1087,
+  1160,
+  1179,
+  1198,
+  1217,
+  1297,
+  1299,
+  1301,
+  1303,
+];
+
+static PRUNE_13: [usize; 32] = [
+  290,
+  291,
+  293,
+  294,
+  428,
+  429,
+  431,
+  432,
+  563,
+  564,
+  566,
+  567,
+  696,
+  697,
+  699,
+  700,
+  831,
+  832,
+  834,
+  835,
+  960,
+  961,
+  963,
+  964,
+  1088,
+  1089,
+  1091,
+  1092,
+  1218,
+  1219,
+  1221,
+  1222,
+];
+
+static PRUNE_14: [usize; 16] = [
+  292,
+  295,
+  430,
+  433,
+  565,
+  568,
+  698,
+  701,
+  833,
+  836,
+  962,
+  965,
+  1090,
+  1093,
+  1220,
+  1223,
+];
+
+static PRUNE_15: [usize; 11] = [
+  296,
+  297,
+  300,
+  303,
+  306,
+  309,
+  316,
+  319,
+  322,
+  325,
+  328,
+];
+
+static PRUNE_16: [usize; 15] = [
+  298,
+  301,
+  304,
+  307,
+  310,
+  312,
+  317,
+  320,
+  323,
+  326,
+  329,
+  434,
+  473,
+  569,
+  608,
+];
+
+static PRUNE_17: [usize; 9] = [
+  336,
+  338,
+  342,
+  346,
+  348,
+  351,
+  609,
+  702,
+  741,
+];
+
+static PRUNE_18: [usize; 21] = [
+  313,
+  314,
+  315,
+  331,
+  332,
+  333,
+  435,
+  436,
+  439,
+  442,
+  445,
+  448,
+  451,
+  455,
+  458,
+  461,
+  464,
+  467,
+  742,
+  837,
+  876,
+];
+
+static PRUNE_19: [usize; 30] = [
+  299,
+  302,
+  305,
+  308,
+  311,
+  318,
+  321,
+  324,
+  327,
+  330,
+  437,
+  440,
+  443,
+  446,
+  449,
+  452,
+  453,
+  454,
+  456,
+  459,
+  462,
+  465,
+  468,
+  470,
+  471,
+  472,
+  586,
+  877,
+  966,
+  1005,
+];
+
+static PRUNE_20: [usize; 16] = [
+  475,
+  477,
+  480,
+  484,
+  485,
+  486,
+  587,
+  588,
+  589,
+  605,
+  606,
+  607,
+  719,
+  1006,
+  1094,
+  1133,
+];
+
+static PRUNE_21: [usize; 20] = [
+  570,
+  571,
+  574,
+  577,
+  580,
+  583,
+  590,
+  593,
+  596,
+  599,
+  602,
+  720,
+  721,
+  722,
+  738,
+  739,
+  740,
+  854,
+  1134,
+  1224,
+];
+
+static PRUNE_22: [usize; 27] = [
+  438,
+  441,
+  444,
+  447,
+  450,
+  457,
+  460,
+  463,
+  466,
+  469,
+  572,
+  575,
+  578,
+  581,
+  584,
+  591,
+  594,
+  597,
+  600,
+  603,
+  855,
+  856,
+  857,
+  873,
+  874,
+  875,
+  983,
+];
+
+static PRUNE_23: [usize; 14] = [
+  164,
+  611,
+  613,
+  616,
+  617,
+  618,
+  619,
+  984,
+  985,
+  986,
+  1002,
+  1003,
+  1004,
+  1111,
+];
+
+static PRUNE_24: [usize; 18] = [
+  703,
+  704,
+  707,
+  710,
+  713,
+  716,
+  723,
+  726,
+  729,
+  732,
+  735,
+  1112,
+  1113,
+  1114,
+  1130,
+  1131,
+  1132,
+  1241,
+];
+
+static PRUNE_25: [usize; 26] = [
+  573,
+  576,
+  579,
+  582,
+  585,
+  592,
+  595,
+  598,
+  601,
+  604,
+  705,
+  708,
+  711,
+  714,
+  717,
+  724,
+  727,
+  730,
+  733,
+  736,
+  1242,
+  1243,
+  1244,
+  1260,
+  1261,
+  1262,
+];
+
+static PRUNE_26: [usize; 13] = [
+  341,
+  743,
+  745,
+  749,
+  750,
+  751,
+  754,
+  1273,
+  1275,
+  1277,
+  1289,
+  1291,
+  1293,
+];
+
+static PRUNE_27: [usize; 17] = [
+  838,
+  839,
+  842,
+  845,
+  848,
+  851,
+  858,
+  861,
+  864,
+  867,
+  870,
+  1274,
+  1276,
+  1278,
+  1290,
+  1292,
+  1294,
+];
+
+static PRUNE_28: [usize; 20] = [
+  706,
+  709,
+  712,
+  715,
+  718,
+  725,
+  728,
+  731,
+  734,
+  737,
+  840,
+  843,
+  846,
+  849,
+  852,
+  859,
+  862,
+  865,
+  868,
+  871,
+];
+
+static PRUNE_29: [usize; 8] = [
+  163,
+  170,
+  479,
+  614,
+  879,
+  880,
+  882,
+  883,
+];
+
+static PRUNE_30: [usize; 11] = [
+  967,
+  968,
+  971,
+  974,
+  977,
+  980,
+  987,
+  990,
+  993,
+  996,
+  999,
+];
+
+static PRUNE_31: [usize; 20] = [
+  841,
+  844,
+  847,
+  850,
+  853,
+  860,
+  863,
+  866,
+  869,
+  872,
+  969,
+  972,
+  975,
+  978,
+  981,
+  988,
+  991,
+  994,
+  997,
+  1000,
+];
+
+static PRUNE_32: [usize; 8] = [
+  340,
+  344,
+  615,
+  747,
+  1007,
+  1009,
+  1010,
+  1011,
+];
+
+static PRUNE_33: [usize; 11] = [
+  1095,
+  1096,
+  1099,
+  1102,
+  1105,
+  1108,
+  1115,
+  1118,
+  1121,
+  1124,
+  1127,
+];
+
+static PRUNE_34: [usize; 20] = [
+  970,
+  973,
+  976,
+  979,
+  982,
+  989,
+  992,
+  995,
+  998,
+  1001,
+  1097,
+  1100,
+  1103,
+  1106,
+  1109,
+  1116,
+  1119,
+  1122,
+  1125,
+  1128,
+];
+
+static PRUNE_35: [usize; 9] = [
+  181,
+  478,
+  482,
+  748,
+  1135,
+  1137,
+  1138,
+  1139,
+  1141,
+];
+
+static PRUNE_36: [usize; 11] = [
+  1225,
+  1226,
+  1229,
+  1232,
+  1235,
+  1238,
+  1245,
+  1248,
+  1251,
+  1254,
+  1257,
+];
+
+static PRUNE_37: [usize; 20] = [
+  1098,
+  1101,
+  1104,
+  1107,
+  1110,
+  1117,
+  1120,
+  1123,
+  1126,
+  1129,
+  1227,
+  1230,
+  1233,
+  1236,
+  1239,
+  1246,
+  1249,
+  1252,
+  1255,
+  1258,
+];
+
+static PRUNE_38: [usize; 10] = [
+  1228,
+  1231,
+  1234,
+  1237,
+  1240,
+  1247,
+  1250,
+  1253,
+  1256,
+  1259,
+];
+
+static PRUNE_39: [usize; 11] = [
+  62,
+  1263,
+  1265,
+  1267,
+  1269,
+  1271,
+  1279,
+  1281,
+  1283,
+  1285,
+  1287,
+];
+
+static PRUNE_40: [usize; 10] = [
+  1264,
+  1266,
+  1268,
+  1270,
+  1272,
+  1280,
+  1282,
+  1284,
+  1286,
+  1288,
+];
+
+fn prune<E: BoolEvaluator>(
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    temp_node_ids: &[usize],
+) {
+  for x in temp_node_ids {
+    temp_nodes.remove(&x);
+  }
+}
+
+pub fn respawn<E: BoolEvaluator>(player_data: &Vec<FheBool<E>>, players_coords: &Vec<FheBool<E>>, points_shift: &Vec<FheBool<E>>, random_state: &Vec<FheBool<E>>) -> Vec<FheBool<E>> {
+    let args: &[&Vec<FheBool<E>>] = &[player_data, random_state, points_shift, players_coords];
+
+    let mut temp_nodes = HashMap::new();
+    let mut out = Vec::new();
+    out.resize(40, None);
+
+    let mut run_level = |
+    temp_nodes: &mut HashMap<usize, FheBool<E>>,
+    tasks: &[((usize, bool, CellType), &[GateInput])]
+    | {
+        let updates = tasks
+            .into_par_iter()
+            .map(|(k, task_args)| {
+                let (id, is_output, celltype) = k;
+                let task_args = task_args.into_iter()
+                .map(|arg| match arg {
+                    Cst(false) => todo!(),
+                    Cst(true) => todo!(),
+                    Arg(pos, ndx) => &args[*pos][*ndx],
+                    Tv(ndx) => &temp_nodes[ndx],
+                    Output(ndx) => &out[*ndx]
+                                .as_ref()
+                                .expect(&format!("Output node {ndx} not found")),
+                }).collect::<Vec<_>>();
+
+                let gate_func = |args: &[&FheBool<E>]| match celltype {
+                    AND2 => args[0] & args[1],
+                    NAND2 => args[0].bitnand(args[1]),
+                    OR2 => args[0] | args[1],
+                    NOR2 => args[0].bitnor(args[1]),
+                    XOR2 => args[0] ^ args[1],
+                    XNOR2 => args[0].bitxnor(args[1]),
+                    INV => !args[0],
+                };
+                
+                ((*id, *is_output), gate_func(&task_args))
+            })
+            .collect::<Vec<_>>();
+        updates.into_iter().for_each(|(k, v)| {
+            let (index, is_output) = k;
+            if is_output {
+                out[index] = Some(v);
+            } else {
+                temp_nodes.insert(index, v);
+            }
+        });
+    };
+
+        run_level(&mut temp_nodes, &LEVEL_0);
+        run_level(&mut temp_nodes, &LEVEL_1);
+        prune(&mut temp_nodes, &PRUNE_1);
+        run_level(&mut temp_nodes, &LEVEL_2);
+        prune(&mut temp_nodes, &PRUNE_2);
+        run_level(&mut temp_nodes, &LEVEL_3);
+        prune(&mut temp_nodes, &PRUNE_3);
+        run_level(&mut temp_nodes, &LEVEL_4);
+        prune(&mut temp_nodes, &PRUNE_4);
+        run_level(&mut temp_nodes, &LEVEL_5);
+        prune(&mut temp_nodes, &PRUNE_5);
+        run_level(&mut temp_nodes, &LEVEL_6);
+        prune(&mut temp_nodes, &PRUNE_6);
+        run_level(&mut temp_nodes, &LEVEL_7);
+        prune(&mut temp_nodes, &PRUNE_7);
+        run_level(&mut temp_nodes, &LEVEL_8);
+        prune(&mut temp_nodes, &PRUNE_8);
+        run_level(&mut temp_nodes, &LEVEL_9);
+        prune(&mut temp_nodes, &PRUNE_9);
+        run_level(&mut temp_nodes, &LEVEL_10);
+        prune(&mut temp_nodes, &PRUNE_10);
+        run_level(&mut temp_nodes, &LEVEL_11);
+        prune(&mut temp_nodes, &PRUNE_11);
+        run_level(&mut temp_nodes, &LEVEL_12);
+        prune(&mut temp_nodes, &PRUNE_12);
+        run_level(&mut temp_nodes, &LEVEL_13);
+        prune(&mut temp_nodes, &PRUNE_13);
+        run_level(&mut temp_nodes, &LEVEL_14);
+        prune(&mut temp_nodes, &PRUNE_14);
+        run_level(&mut temp_nodes, &LEVEL_15);
+        prune(&mut temp_nodes, &PRUNE_15);
+        run_level(&mut temp_nodes, &LEVEL_16);
+        prune(&mut temp_nodes, &PRUNE_16);
+        run_level(&mut temp_nodes, &LEVEL_17);
+        prune(&mut temp_nodes, &PRUNE_17);
+        run_level(&mut temp_nodes, &LEVEL_18);
+        prune(&mut temp_nodes, &PRUNE_18);
+        run_level(&mut temp_nodes, &LEVEL_19);
+        prune(&mut temp_nodes, &PRUNE_19);
+        run_level(&mut temp_nodes, &LEVEL_20);
+        prune(&mut temp_nodes, &PRUNE_20);
+        run_level(&mut temp_nodes, &LEVEL_21);
+        prune(&mut temp_nodes, &PRUNE_21);
+        run_level(&mut temp_nodes, &LEVEL_22);
+        prune(&mut temp_nodes, &PRUNE_22);
+        run_level(&mut temp_nodes, &LEVEL_23);
+        prune(&mut temp_nodes, &PRUNE_23);
+        run_level(&mut temp_nodes, &LEVEL_24);
+        prune(&mut temp_nodes, &PRUNE_24);
+        run_level(&mut temp_nodes, &LEVEL_25);
+        prune(&mut temp_nodes, &PRUNE_25);
+        run_level(&mut temp_nodes, &LEVEL_26);
+        prune(&mut temp_nodes, &PRUNE_26);
+        run_level(&mut temp_nodes, &LEVEL_27);
+        prune(&mut temp_nodes, &PRUNE_27);
+        run_level(&mut temp_nodes, &LEVEL_28);
+        prune(&mut temp_nodes, &PRUNE_28);
+        run_level(&mut temp_nodes, &LEVEL_29);
+        prune(&mut temp_nodes, &PRUNE_29);
+        run_level(&mut temp_nodes, &LEVEL_30);
+        prune(&mut temp_nodes, &PRUNE_30);
+        run_level(&mut temp_nodes, &LEVEL_31);
+        prune(&mut temp_nodes, &PRUNE_31);
+        run_level(&mut temp_nodes, &LEVEL_32);
+        prune(&mut temp_nodes, &PRUNE_32);
+        run_level(&mut temp_nodes, &LEVEL_33);
+        prune(&mut temp_nodes, &PRUNE_33);
+        run_level(&mut temp_nodes, &LEVEL_34);
+        prune(&mut temp_nodes, &PRUNE_34);
+        run_level(&mut temp_nodes, &LEVEL_35);
+        prune(&mut temp_nodes, &PRUNE_35);
+        run_level(&mut temp_nodes, &LEVEL_36);
+        prune(&mut temp_nodes, &PRUNE_36);
+        run_level(&mut temp_nodes, &LEVEL_37);
+        prune(&mut temp_nodes, &PRUNE_37);
+        run_level(&mut temp_nodes, &LEVEL_38);
+        prune(&mut temp_nodes, &PRUNE_38);
+        run_level(&mut temp_nodes, &LEVEL_39);
+        prune(&mut temp_nodes, &PRUNE_39);
+        run_level(&mut temp_nodes, &LEVEL_40);
+        prune(&mut temp_nodes, &PRUNE_40);
+
+        out[24] = Some(player_data[24].clone());
+        out[25] = Some(player_data[25].clone());
+        out[26] = Some(player_data[26].clone());
+        out[27] = Some(player_data[27].clone());
+        out[28] = Some(player_data[28].clone());
+        out[29] = Some(player_data[29].clone());
+        out[30] = Some(player_data[30].clone());
+        out[31] = Some(player_data[31].clone());
+
+    out.into_iter().map(|c| c.unwrap()).collect()
+}
@@ -0,0 +1,40 @@
+#include "frogzone.h"
+#include "map.h"
+
+// Brings a dead frog back with RESPAWN_HP, keeping `points >> points_shift`
+// of its points.  The frog is placed at the spawn point picked by
+// `random_state`, or the next one not taken by another frog.  A frog that is
+// still alive is left unchanged.
+#pragma hls_top
+PlayerData respawn(
+                   PlayerData player_data,
+                   unsigned char random_state,
+                   unsigned char points_shift,
+                   Obstacles4 players_coords) {
+  if (player_data.hp != 0) {
+    return player_data;
+  }
+
+  PlayerData new_player_data = player_data;
+  new_player_data.hp = RESPAWN_HP;
+  new_player_data.points = player_data.points >> points_shift;
+
+  bool found = false;
+  #pragma hls_unroll yes
+  for (int i = 0; i < NUM_SPAWN_POINTS; i++) {
+    Coord spawn_point = spawn_points[(random_state + i) % NUM_SPAWN_POINTS];
+    bool taken = false;
+    #pragma hls_unroll yes
+    for (int j = 0; j < NUM_PLAYERS; j++) {
+      if (spawn_point == players_coords.values[j]) {
+        taken = true;
+      }
+    }
+    if (!found && !taken) {
+      new_player_data.loc = spawn_point;
+      found = true;
+    }
+  }
+
+  return new_player_data;
+}
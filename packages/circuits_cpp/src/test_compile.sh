@@ -7,8 +7,12 @@
 set -ex
 
 g++ apply_move.cc \
+    apply_move_pvp.cc \
     apply_move_monster.cc \
     apply_move_flying.cc \
+    respawn.cc \
+    maintain_monsters.cc \
+    maintain_items.cc \
     get_cell_no_check.cc \
     get_cell.cc \
     get_five_cells.cc \
@@ -0,0 +1,227 @@
+//! Escrow standing in for offline players in the server's joint decryptions,
+//! see [`server::escrow`].
+//!
+//! Players deposit their key seed when given the escrow's uri and public key.
+//! The escrow then polls the server for overdue decryptions, and submits the
+//! decryption shares of the players missing from them. The public key of the
+//! current game is fetched from the server.
+//!
+//! ```text
+//! escrow <port> <server uri> <key file>
+//! ```
+//!
+//! The secret key seeds are sealed to is read from `key file`, or generated
+//! there on the first run. Its public key is logged at startup, to be given to
+//! the players.
+
+use crypto_box::{aead::OsRng, SecretKey};
+use phantom::{Fingerprint, PhantomPk, PhantomUser};
+use rocket::response::status::Custom;
+use rocket::serde::json::Json;
+use rocket::{Config, State};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use server::client::{
+    GetPkRequest, GetPkResponse, PendingDecryption, SubmitDecShareRequest, SubmitDecShareResponse,
+};
+use server::escrow::*;
+use server::telemetry::{self, RequestId, REQUEST_ID_HEADER};
+use server::zone::PHANTOM_PARAM;
+use server::{bad_request, internal_server_error};
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time;
+use tracing::{info, info_span};
+
+#[macro_use]
+extern crate rocket;
+
+const POLL_MILLIS: u64 = 1000;
+
+static PORT: LazyLock<u16> = LazyLock::new(|| {
+    env::args()
+        .nth(1)
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(8010)
+});
+
+static SERVER_URI: LazyLock<String> = LazyLock::new(|| {
+    env::args()
+        .nth(2)
+        .unwrap_or_else(|| panic!("missing server's uri"))
+});
+
+static KEY_FILE: LazyLock<String> = LazyLock::new(|| {
+    env::args()
+        .nth(3)
+        .unwrap_or_else(|| panic!("missing key file"))
+});
+
+/// Secret key the players' seeds are sealed to.
+static SECRET_KEY: LazyLock<SecretKey> = LazyLock::new(|| {
+    let path = &*KEY_FILE;
+    match fs::read(path) {
+        Ok(bytes) => SecretKey::from_slice(&bytes)
+            .unwrap_or_else(|_| panic!("{path}: expected 32 bytes, got {}", bytes.len())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let secret_key = SecretKey::generate(&mut OsRng);
+            OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(path)
+                .and_then(|mut file| file.write_all(&secret_key.to_bytes()))
+                .unwrap_or_else(|err| panic!("{path}: {err}"));
+            secret_key
+        }
+        Err(err) => panic!("{path}: {err}"),
+    }
+});
+
+/// Seeds of the players who deposited theirs, by player id, and the public key
+/// of the current game, once fetched.
+#[derive(Default)]
+struct Deposits {
+    seeds: HashMap<usize, Vec<u8>>,
+    pk: Option<PhantomPk>,
+}
+
+type SharedDeposits = Arc<Mutex<Deposits>>;
+
+#[post("/deposit", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id, player_id = request.player_id))]
+async fn deposit(
+    deposits: &State<SharedDeposits>,
+    request: Json<DepositRequest>,
+    request_id: RequestId,
+) -> Result<Json<DepositResponse>, Custom<String>> {
+    let DepositRequest {
+        player_id,
+        sealed_seed,
+    } = request.into_inner();
+    if player_id >= PHANTOM_PARAM.total_shares {
+        return Err(bad_request("Invalid player_id"));
+    }
+    let seed = SECRET_KEY
+        .unseal(&sealed_seed)
+        .ok()
+        .filter(|seed| seed.len() == 32)
+        .ok_or_else(|| bad_request("Invalid sealed seed"))?;
+
+    deposits.lock().await.seeds.insert(player_id, seed);
+
+    info!("processed /deposit request");
+    Ok(Json(DepositResponse {}))
+}
+
+/// Submits the decryption shares of the players missing from an overdue
+/// decryption, for those who deposited their seed.
+async fn start_stand_in_loop(deposits: SharedDeposits) {
+    loop {
+        if let Err(Custom(status, err)) = stand_in(&deposits).await {
+            tracing::warn!("failed to stand in for players: {status} {err}");
+        }
+        time::sleep(Duration::from_millis(POLL_MILLIS)).await;
+    }
+}
+
+async fn stand_in(deposits: &SharedDeposits) -> Result<(), Custom<String>> {
+    let request_id = RequestId::new();
+
+    let GetOverdueDecryptionResponse {
+        decryption,
+        player_ids,
+    } = post(
+        "/get_overdue_decryption",
+        &GetOverdueDecryptionRequest {},
+        &request_id,
+    )
+    .await?;
+    let Some(PendingDecryption { id, ct }) = decryption else {
+        return Ok(());
+    };
+
+    let mut deposits = deposits.lock().await;
+    // Keys change with every game.
+    let key_id = deposits.pk.as_ref().map(Fingerprint::fingerprint);
+    if key_id != Some(ct.key_id()) {
+        let GetPkResponse { pk } = post("/get_pk", &GetPkRequest {}, &request_id).await?;
+        deposits.pk = Some(pk);
+    }
+    for player_id in player_ids {
+        let Some(seed) = deposits.seeds.get(&player_id) else {
+            continue;
+        };
+        let mut user = PhantomUser::new(*PHANTOM_PARAM, player_id, seed.clone());
+        user.set_pk(deposits.pk.clone().unwrap());
+        let dec_share = info_span!("decrypt_share", player_id, n = ct.n())
+            .in_scope(|| user.decrypt_share(&ct))
+            .map_err(internal_server_error)?;
+
+        info!(player_id, id, "standing in for player");
+        let SubmitDecShareResponse {} = post(
+            "/submit_dec_share",
+            &SubmitDecShareRequest {
+                player_id,
+                id,
+                dec_share,
+            },
+            &request_id,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Posts `body` to the server without logging it, as it may hold a whole
+/// ciphertext.
+async fn post<S: DeserializeOwned>(
+    path: &str,
+    body: &impl Serialize,
+    request_id: &RequestId,
+) -> Result<S, Custom<String>> {
+    let response = reqwest::Client::new()
+        .post(format!("{}{path}", *SERVER_URI))
+        .header(REQUEST_ID_HEADER, request_id.as_str())
+        .json(body)
+        .send()
+        .await
+        .map_err(internal_server_error)?;
+    if !response.status().is_success() {
+        let status = response.status();
+        return Err(internal_server_error(format!(
+            "request to server's {path} failed: {status}"
+        )));
+    }
+    response.json().await.map_err(internal_server_error)
+}
+
+#[rocket::main]
+async fn main() -> Result<(), rocket::Error> {
+    telemetry::init("escrow");
+    let _ = &*SERVER_URI;
+    let public_key = SECRET_KEY.public_key();
+    info!("public key: {}", hex::encode(public_key.as_bytes()));
+
+    let config = Config {
+        port: *PORT,
+        address: std::net::IpAddr::V4("0.0.0.0".parse().unwrap()),
+        ..Config::default()
+    };
+
+    let deposits = SharedDeposits::default();
+    tokio::spawn(start_stand_in_loop(deposits.clone()));
+
+    rocket::custom(config)
+        .manage(deposits)
+        .mount("/", routes![deposit])
+        .launch()
+        .await
+        .map(|_rocket| ()) // Convert `Result<Rocket<Ignite>, rocket::Error>` to `Result<(), rocket::Error>`
+}
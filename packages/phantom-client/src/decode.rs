@@ -0,0 +1,217 @@
+//! Decoding of decrypted outputs.
+//!
+//! Decrypted bits depend on the server's evaluation and on every peer's
+//! decryption share, so a misbehaving party shows up here as bits that don't
+//! decode to a valid output. Such failures are counted in
+//! [`metrics::DECODE_MISMATCHES`] and reported as suspected misbehaviour rather
+//! than as internal errors.
+
+use crate::{CellData, Coord, PlayerData};
+use core::fmt;
+use phantom::{Enc, EncU8, PhantomChunkLayout};
+use rocket::http::Status;
+use rocket::response::status::Custom;
+use server::client::EntityType;
+use server::map::MAP;
+use server::metrics;
+use server::zone::PHANTOM_PARAM;
+use std::vec;
+
+/// Bits of a [`Coord`], `x || y`.
+pub const COORD_BITS: usize = 16;
+
+/// Bits of a [`PlayerData`], `loc || hp || atk || points`.
+pub const PLAYER_BITS: usize = COORD_BITS + 24;
+
+/// Bits of a [`CellData`], `entity_type || entity_id || hp || atk || points`,
+/// the entity type being 3 bits.
+pub const CELL_BITS: usize = 3 + 32;
+
+#[derive(Debug)]
+pub enum DecodeError {
+    /// Decrypted bits are not as many as the output has.
+    Length { expected: usize, actual: usize },
+    /// Entity type beyond the 5 of [`EntityType`].
+    EntityType(u8),
+    /// Entity id beyond the entities of its type in the map.
+    EntityId {
+        entity_type: EntityType,
+        entity_id: u8,
+    },
+    /// Coordinate outside of the map.
+    OutOfMap(Coord),
+    /// Chunk not following the previous ones, or of other items.
+    Layout(PhantomChunkLayout),
+}
+
+impl DecodeError {
+    /// Label of the error in [`metrics::DECODE_MISMATCHES`].
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Length { .. } => "length",
+            Self::EntityType(_) => "entity_type",
+            Self::EntityId { .. } => "entity_id",
+            Self::OutOfMap(_) => "out_of_map",
+            Self::Layout(_) => "layout",
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { expected, actual } => {
+                write!(f, "expected {expected} decrypted bits, got {actual}")
+            }
+            Self::EntityType(entity_type) => write!(f, "invalid entity type {entity_type}"),
+            Self::EntityId {
+                entity_type,
+                entity_id,
+            } => write!(f, "invalid {entity_type:?} id {entity_id}"),
+            Self::OutOfMap(Coord { x, y }) => write!(
+                f,
+                "({x}, {y}) is outside the {}x{} map",
+                MAP.width, MAP.height
+            ),
+            Self::Layout(layout) => write!(f, "unexpected chunk {layout:?}"),
+        }
+    }
+}
+
+impl From<DecodeError> for Custom<String> {
+    fn from(err: DecodeError) -> Self {
+        metrics::DECODE_MISMATCHES
+            .with_label_values(&[err.kind()])
+            .inc();
+        tracing::error!("decode mismatch: {err}");
+        Custom(
+            Status::BadGateway,
+            format!("invalid decrypted output, the server or a peer may be misbehaving: {err}"),
+        )
+    }
+}
+
+/// Decodes a [`Coord`].
+pub fn coord(bits: Vec<bool>) -> Result<Coord, DecodeError> {
+    Bits::new(bits, COORD_BITS)?.coord()
+}
+
+/// Decodes a [`PlayerData`].
+pub fn player(bits: Vec<bool>) -> Result<PlayerData, DecodeError> {
+    Bits::new(bits, PLAYER_BITS)?.player()
+}
+
+/// Decodes `n` [`CellData`].
+pub fn cells(bits: Vec<bool>, n: usize) -> Result<Vec<CellData>, DecodeError> {
+    let mut bits = Bits::new(bits, n * CELL_BITS)?;
+    (0..n).map(|_| bits.cell()).collect()
+}
+
+/// Checks chunks of `layouts` hold the `n` items of `item_bits` in order, so
+/// that each chunk is decoded on its own.
+pub fn chunks<'a>(
+    layouts: impl IntoIterator<Item = &'a PhantomChunkLayout>,
+    item_bits: usize,
+    n: usize,
+) -> Result<(), DecodeError> {
+    let mut offset = 0;
+    for layout in layouts {
+        let expected = PhantomChunkLayout {
+            item_bits,
+            offset,
+            len: layout.len,
+            total: n,
+        };
+        if *layout != expected {
+            return Err(DecodeError::Layout(*layout));
+        }
+        offset += layout.len;
+    }
+    if offset != n {
+        return Err(DecodeError::Length {
+            expected: n * item_bits,
+            actual: offset * item_bits,
+        });
+    }
+    Ok(())
+}
+
+/// Decodes `N` [`CellData`].
+pub fn cell_array<const N: usize>(bits: Vec<bool>) -> Result<[CellData; N], DecodeError> {
+    Ok(cells(bits, N)?.try_into().unwrap())
+}
+
+/// Decrypted bits, checked to be as many as the output being decoded.
+struct Bits(vec::IntoIter<bool>);
+
+impl Bits {
+    fn new(bits: Vec<bool>, expected: usize) -> Result<Self, DecodeError> {
+        if bits.len() != expected {
+            return Err(DecodeError::Length {
+                expected,
+                actual: bits.len(),
+            });
+        }
+        Ok(Self(bits.into_iter()))
+    }
+
+    /// Takes `n` little-endian bits.
+    fn take(&mut self, n: usize) -> u8 {
+        (0..n).fold(0, |value, i| value | ((self.0.next().unwrap() as u8) << i))
+    }
+
+    fn u8(&mut self) -> u8 {
+        EncU8::decode(&mut self.0)
+    }
+
+    fn coord(&mut self) -> Result<Coord, DecodeError> {
+        let coord = Coord {
+            x: self.u8(),
+            y: self.u8(),
+        };
+        if coord.x >= MAP.width || coord.y >= MAP.height {
+            return Err(DecodeError::OutOfMap(coord));
+        }
+        Ok(coord)
+    }
+
+    fn player(&mut self) -> Result<PlayerData, DecodeError> {
+        Ok(PlayerData {
+            loc: self.coord()?,
+            hp: self.u8(),
+            atk: self.u8(),
+            points: self.u8(),
+        })
+    }
+
+    fn cell(&mut self) -> Result<CellData, DecodeError> {
+        let entity_type = match self.take(3) {
+            0 => EntityType::Invalid,
+            1 => EntityType::Player,
+            2 => EntityType::Item,
+            3 => EntityType::Monster,
+            4 => EntityType::None,
+            entity_type => return Err(DecodeError::EntityType(entity_type)),
+        };
+        let entity_id = self.u8();
+        let num_entities = match entity_type {
+            EntityType::Player => PHANTOM_PARAM.total_shares,
+            EntityType::Item => MAP.items.len(),
+            EntityType::Monster => MAP.monsters.len(),
+            EntityType::Invalid | EntityType::None => usize::MAX,
+        };
+        if entity_id as usize >= num_entities {
+            return Err(DecodeError::EntityId {
+                entity_type,
+                entity_id,
+            });
+        }
+        Ok(CellData {
+            entity_type,
+            entity_id,
+            hp: self.u8(),
+            atk: self.u8(),
+            points: self.u8(),
+        })
+    }
+}
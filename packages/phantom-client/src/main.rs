@@ -1,11 +1,13 @@
+mod decode;
 mod proxy;
 
-use itertools::{chain, Itertools};
-use phantom::{PhantomPackedCt, PhantomPackedCtDecShare, PhantomParam, PhantomPk, PhantomUser};
+use crypto_box::{aead::OsRng, PublicKey};
+use itertools::{chain, izip};
+use phantom::{Enc, EncU8, PhantomCrs, PhantomPackedCt, PhantomPackedCtDecShare, PhantomUser};
 use rand::thread_rng;
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::Rng;
 use reqwest::StatusCode;
-use rocket::futures::stream::FuturesUnordered;
+use rocket::futures::stream::{FuturesOrdered, FuturesUnordered};
 use rocket::futures::TryStreamExt;
 use rocket::http::{Method, Status};
 use rocket::response::status::Custom;
@@ -13,22 +15,33 @@ use rocket::serde::{json::Json, Deserialize, Serialize};
 use rocket::Config;
 use rocket::State;
 use rocket_cors::{AllowedHeaders, AllowedOrigins, Cors, CorsOptions};
+use serde::de::DeserializeOwned;
 use server::client::{Direction, EntityType};
+use server::escrow;
+use server::metrics;
 use server::mock_zone::{CellEncryptedData, MockEncryptedCoord};
-use std::array::from_fn;
+use server::telemetry::{self, RequestId, REQUEST_ID_HEADER};
+use server::zone::{num_players, other_player_ids, PHANTOM_PARAM};
 use std::env;
-use std::iter::repeat_with;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::fs::OpenOptionsExt;
 use std::sync::{Arc, LazyLock};
 use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::time;
-use tracing::info;
+use tracing::{info, info_span, Instrument};
 
 #[macro_use]
 extern crate rocket;
 
 const GET_CELL_MOCK_TIME_MILLIS: u64 = 140; // based on benchmark of 700ms for 5 cells
 const MOVE_MOCK_TIME_MILLIS: u64 = 750;
+const KEYGEN_POLL_MILLIS: u64 = 1000;
+const DECRYPTION_POLL_MILLIS: u64 = 1000;
+const DEC_SHARE_TIMEOUT_MILLIS: u64 = 5000;
+const DEC_SHARE_RETRY_MILLIS: u64 = 200;
+const DEC_SHARE_ATTEMPTS: usize = 3;
 
 static PORT: LazyLock<u16> = LazyLock::new(|| {
     env::args()
@@ -47,80 +60,102 @@ static SERVER_URI: LazyLock<String> = LazyLock::new(|| {
         .unwrap_or_else(|| panic!("missing server's uri"))
 });
 
-static OTHER_PLAYER_URIS: LazyLock<[String; 3]> = LazyLock::new(|| {
+/// Uris of the other players, in order of player id, whose decryption shares
+/// are needed to decrypt.
+static OTHER_PLAYER_URIS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let num_other_players = num_players() - 1;
     env::args()
         .nth(4)
-        .and_then(|p| {
-            let mut p = p.split(",");
-            Some([
-                p.next()?.to_string(),
-                p.next()?.to_string(),
-                p.next()?.to_string(),
-            ])
-        })
-        .unwrap_or_else(|| panic!("missing other 3 players' uris"))
+        .map(|p| p.split(",").map(ToString::to_string).collect::<Vec<_>>())
+        .filter(|uris| uris.len() == num_other_players)
+        .unwrap_or_else(|| panic!("missing other {num_other_players} players' uris"))
+});
+
+/// Uri of the escrow standing in for us in the server's decryptions when we are
+/// offline, and its hex encoded public key the seed is sealed to, see
+/// [`escrow`].
+static ESCROW: LazyLock<Option<(String, PublicKey)>> = LazyLock::new(|| {
+    let uri = env::args().nth(5)?;
+    let public_key = env::args()
+        .nth(6)
+        .and_then(|key| hex::decode(key).ok())
+        .and_then(|key| PublicKey::from_slice(&key).ok())
+        .unwrap_or_else(|| panic!("missing escrow's public key"));
+    Some((uri, public_key))
+});
+
+/// Seed of our keys, read from the file named by the `PHANTOM_SEED_FILE`
+/// environment variable, `seed_<player id>` by default, or generated there on
+/// the first run.
+///
+/// It is random rather than derived from the player id, as whoever knows it
+/// can decrypt our share of everything, and produce decryption shares in our
+/// name.
+static SEED: LazyLock<[u8; 32]> = LazyLock::new(|| {
+    let path = env::var("PHANTOM_SEED_FILE").unwrap_or_else(|_| format!("seed_{}", *PLAYER_ID));
+    match fs::read(&path) {
+        Ok(bytes) => bytes.try_into().unwrap_or_else(|bytes: Vec<u8>| {
+            panic!("{path}: expected 32 bytes, got {}", bytes.len())
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let seed: [u8; 32] = thread_rng().gen();
+            OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(&path)
+                .and_then(|mut file| file.write_all(&seed))
+                .unwrap_or_else(|err| panic!("{path}: {err}"));
+            seed
+        }
+        Err(err) => panic!("{path}: {err}"),
+    }
 });
 
 struct AppState {
     user: PhantomUser,
     player_coord: Coord,
+    /// CRS of the game our round 1 key is submitted under, see [`keygen`].
+    crs: Option<PhantomCrs>,
+    round_2_key_submitted: bool,
 }
 
 impl AppState {
+    /// Returns the state of `player_id`, without keys until [`keygen`] is run
+    /// with the server.
     fn new(player_id: usize) -> Self {
-        let seed = StdRng::seed_from_u64(player_id as u64)
-            .gen::<[u8; 32]>()
-            .to_vec();
-        let mut user = PhantomUser::new(PhantomParam::I_4P_40, player_id, seed);
-        let pk: PhantomPk = bincode::deserialize(include_bytes!("../../server/pk")).unwrap();
-        user.set_pk(pk);
         Self {
-            user,
+            user: PhantomUser::new(*PHANTOM_PARAM, player_id, SEED.to_vec()),
             player_coord: Coord { x: 0, y: 0 },
+            crs: None,
+            round_2_key_submitted: false,
         }
     }
 
-    fn decrypt(&self, ct: &PhantomPackedCt, dec_shares: [PhantomPackedCtDecShare; 3]) -> Vec<bool> {
-        self.user.aggregate_dec_shares(
-            ct,
-            chain![dec_shares, [self.user.decrypt_share(ct)]].collect(),
-        )
+    fn check_pk(&self) -> Result<(), Custom<String>> {
+        if self.user.has_pk() {
+            Ok(())
+        } else {
+            Err(bad_request("pk is not ready yet"))
+        }
+    }
+
+    fn decrypt(
+        &self,
+        ct: &PhantomPackedCt,
+        dec_shares: Vec<PhantomPackedCtDecShare>,
+    ) -> Result<Vec<bool>, Custom<String>> {
+        self.check_pk()?;
+        let _span = info_span!("aggregate", n = ct.n()).entered();
+        let dec_share = self.user.decrypt_share(ct).map_err(bad_gateway)?;
+        self.user
+            .aggregate_dec_shares(ct, chain![dec_shares, [dec_share]].collect())
+            .map_err(bad_gateway)
     }
 }
 
 type SharedState = Arc<Mutex<AppState>>;
 
-fn to_le_bits(value: u8) -> impl Iterator<Item = bool> {
-    (0..8).map(move |i| (value >> i) & 1 == 1)
-}
-
-fn try_from_le_bits<const N: usize>(
-    bits: &mut impl Iterator<Item = bool>,
-) -> Result<u8, Custom<String>> {
-    (0..N)
-        .try_fold(0, |value, i| Some(value ^ ((bits.next()? as u8) << i)))
-        .ok_or(internal_server_error(""))
-}
-
-fn cell_try_from_le_bits(
-    bits: &mut impl Iterator<Item = bool>,
-) -> Result<CellData, Custom<String>> {
-    Ok(CellData {
-        entity_type: match try_from_le_bits::<3>(bits)? {
-            0 => EntityType::Invalid,
-            1 => EntityType::Player,
-            2 => EntityType::Item,
-            3 => EntityType::Monster,
-            4 => EntityType::None,
-            _ => return Err(internal_server_error("")),
-        },
-        entity_id: try_from_le_bits::<8>(bits)?,
-        hp: try_from_le_bits::<8>(bits)?,
-        atk: try_from_le_bits::<8>(bits)?,
-        points: try_from_le_bits::<8>(bits)?,
-    })
-}
-
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Coord {
     pub x: u8,
@@ -212,10 +247,12 @@ struct MoveResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct ResetRequest {}
+struct RespawnRequest {}
 
 #[derive(Debug, Serialize, Deserialize)]
-struct ResetResponse {}
+struct RespawnResponse {
+    my_new_coords: Coord,
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ResetGameRequest {}
@@ -289,6 +326,7 @@ fn make_cors() -> Cors {
             "Accept",
             "Access-Control-Allow-Origin",
             "Content-Type",
+            REQUEST_ID_HEADER,
         ]),
         allow_credentials: true,
         ..Default::default()
@@ -322,9 +360,11 @@ fn mock_decrypt_cell(cell: CellEncryptedData) -> CellData {
 }
 
 #[post("/reset_game", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn reset_game(
     state: &State<SharedState>,
     _request: Json<ResetGameRequest>,
+    request_id: RequestId,
 ) -> Result<Json<ResetGameResponse>, Custom<String>> {
     let mut app_state = state.lock().await;
 
@@ -332,35 +372,22 @@ async fn reset_game(
         player_id: app_state.user.user_id(),
     };
 
-    let proxy::ResetGameResponse {} = proxy::proxy(&*SERVER_URI, "/reset_game", post_data)
-        .await?
-        .0;
+    let proxy::ResetGameResponse {} =
+        proxy::proxy(&*SERVER_URI, "/reset_game", post_data, &request_id)
+            .await?
+            .0;
 
     app_state.player_coord = Coord { x: 0, y: 0 };
 
     Ok(Json(ResetGameResponse {}))
 }
 
-#[post("/reset", format = "json", data = "<_request>")]
-async fn reset(
-    state: &State<SharedState>,
-    _request: Json<ResetRequest>,
-) -> Result<Json<ResetResponse>, Custom<String>> {
-    let mut app_state = state.lock().await;
-
-    let post_data = proxy::ResetRequest {};
-
-    let proxy::ResetResponse {} = proxy::proxy(&*SERVER_URI, "/reset", post_data).await?.0;
-
-    *app_state = AppState::new(*PLAYER_ID);
-
-    Ok(Json(ResetResponse {}))
-}
-
 #[post("/mock_get_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn mock_get_cells(
     state: &State<SharedState>,
     request: Json<GetCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetCellsResponse>, Custom<String>> {
     let app_state = state.lock().await;
 
@@ -374,7 +401,7 @@ async fn mock_get_cells(
     };
 
     let proxy::MockGetCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/mock_get_cells", post_data)
+        proxy::proxy(&*SERVER_URI, "/mock_get_cells", post_data, &request_id)
             .await?
             .0;
 
@@ -393,18 +420,21 @@ async fn mock_get_cells(
 }
 
 #[post("/get_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cells(
     state: &State<SharedState>,
     request: Json<GetCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetCellsResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
+        app_state.check_pk()?;
 
         let coords = app_state.user.batched_pk_encrypt(
             request
                 .coords
                 .iter()
-                .flat_map(|coord| chain![to_le_bits(coord.x), to_le_bits(coord.y)]),
+                .flat_map(|coord| chain![EncU8::encode(coord.x), EncU8::encode(coord.y)]),
         );
 
         proxy::GetCellsRequest {
@@ -414,34 +444,47 @@ async fn get_cells(
     };
 
     let proxy::GetCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/get_cells", post_data).await?.0;
+        proxy::proxy(&*SERVER_URI, "/get_cells", post_data, &request_id)
+            .await?
+            .0;
 
-    let dec_shares = get_dec_shares(&cell_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&cell_data, dec_shares)
-        .into_iter();
-    let cell_data = repeat_with(|| cell_try_from_le_bits(&mut bits))
-        .take(cell_data.n() / 27)
-        .try_collect()?;
+    decode::chunks(
+        cell_data.iter().map(|chunk| &chunk.layout),
+        decode::CELL_BITS,
+        request.coords.len(),
+    )?;
+    // Each chunk has its own round of decryption shares, so chunks are
+    // decrypted concurrently rather than as a single large ciphertext.
+    let cell_data = cell_data
+        .iter()
+        .map(|chunk| async {
+            let dec_shares = get_dec_shares(&chunk.ct, &request_id).await?;
+            let bits = state.lock().await.decrypt(&chunk.ct, dec_shares)?;
+            Ok::<_, Custom<String>>(decode::cells(bits, chunk.layout.len)?)
+        })
+        .collect::<FuturesOrdered<_>>()
+        .try_concat()
+        .await?;
 
     Ok(Json(GetCellsResponse { cell_data }))
 }
 
 #[post("/get_five_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_five_cells(
     state: &State<SharedState>,
     request: Json<GetFiveCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetFiveCellsResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
+        app_state.check_pk()?;
 
         let coords = app_state.user.batched_pk_encrypt(
             request
                 .coords
                 .iter()
-                .flat_map(|coord| chain![to_le_bits(coord.x), to_le_bits(coord.y)]),
+                .flat_map(|coord| chain![EncU8::encode(coord.x), EncU8::encode(coord.y)]),
         );
 
         proxy::GetFiveCellsRequest {
@@ -451,31 +494,23 @@ async fn get_five_cells(
     };
 
     let proxy::GetFiveCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/get_five_cells", post_data)
+        proxy::proxy(&*SERVER_URI, "/get_five_cells", post_data, &request_id)
             .await?
             .0;
 
-    let dec_shares = get_dec_shares(&cell_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&cell_data, dec_shares)
-        .into_iter();
-    let cell_data = [
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-    ];
+    let dec_shares = get_dec_shares(&cell_data, &request_id).await?;
+    let bits = state.lock().await.decrypt(&cell_data, dec_shares)?;
+    let cell_data = decode::cell_array(bits)?;
 
     Ok(Json(GetFiveCellsResponse { cell_data }))
 }
 
 #[post("/get_cross_cells", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cross_cells(
     state: &State<SharedState>,
     _request: Json<GetCrossCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetCrossCellsResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
@@ -486,38 +521,31 @@ async fn get_cross_cells(
     };
 
     let proxy::GetCrossCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/get_cross_cells", post_data)
+        proxy::proxy(&*SERVER_URI, "/get_cross_cells", post_data, &request_id)
             .await?
             .0;
 
-    let dec_shares = get_dec_shares(&cell_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&cell_data, dec_shares)
-        .into_iter();
-    let cell_data = [
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-    ];
+    let dec_shares = get_dec_shares(&cell_data, &request_id).await?;
+    let bits = state.lock().await.decrypt(&cell_data, dec_shares)?;
+    let cell_data = decode::cell_array(bits)?;
 
     Ok(Json(GetCrossCellsResponse { cell_data }))
 }
 
 #[post("/get_vertical_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_vertical_cells(
     state: &State<SharedState>,
     request: Json<GetVerticalCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetVerticalCellsResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
+        app_state.check_pk()?;
 
         let coord = app_state.user.batched_pk_encrypt(chain![
-            to_le_bits(request.center_coord.x),
-            to_le_bits(request.center_coord.y)
+            EncU8::encode(request.center_coord.x),
+            EncU8::encode(request.center_coord.y)
         ]);
 
         proxy::GetVerticalCellsRequest {
@@ -527,38 +555,31 @@ async fn get_vertical_cells(
     };
 
     let proxy::GetVerticalCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/get_vertical_cells", post_data)
+        proxy::proxy(&*SERVER_URI, "/get_vertical_cells", post_data, &request_id)
             .await?
             .0;
 
-    let dec_shares = get_dec_shares(&cell_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&cell_data, dec_shares)
-        .into_iter();
-    let cell_data = [
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-    ];
+    let dec_shares = get_dec_shares(&cell_data, &request_id).await?;
+    let bits = state.lock().await.decrypt(&cell_data, dec_shares)?;
+    let cell_data = decode::cell_array(bits)?;
 
     Ok(Json(GetVerticalCellsResponse { cell_data }))
 }
 
 #[post("/get_horizontal_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_horizontal_cells(
     state: &State<SharedState>,
     request: Json<GetHorizontalCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetHorizontalCellsResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
+        app_state.check_pk()?;
 
         let coord = app_state.user.batched_pk_encrypt(chain![
-            to_le_bits(request.center_coord.x),
-            to_le_bits(request.center_coord.y)
+            EncU8::encode(request.center_coord.x),
+            EncU8::encode(request.center_coord.y)
         ]);
 
         proxy::GetHorizontalCellsRequest {
@@ -567,32 +588,28 @@ async fn get_horizontal_cells(
         }
     };
 
-    let proxy::GetHorizontalCellsResponse { cell_data } =
-        proxy::proxy(&*SERVER_URI, "/get_horizontal_cells", post_data)
-            .await?
-            .0;
+    let proxy::GetHorizontalCellsResponse { cell_data } = proxy::proxy(
+        &*SERVER_URI,
+        "/get_horizontal_cells",
+        post_data,
+        &request_id,
+    )
+    .await?
+    .0;
 
-    let dec_shares = get_dec_shares(&cell_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&cell_data, dec_shares)
-        .into_iter();
-    let cell_data = [
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-        cell_try_from_le_bits(&mut bits)?,
-    ];
+    let dec_shares = get_dec_shares(&cell_data, &request_id).await?;
+    let bits = state.lock().await.decrypt(&cell_data, dec_shares)?;
+    let cell_data = decode::cell_array(bits)?;
 
     Ok(Json(GetHorizontalCellsResponse { cell_data }))
 }
 
 #[post("/mock_get_player", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn mock_get_player(
     state: &State<SharedState>,
     _request: Json<GetPlayerRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetPlayerResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
@@ -603,7 +620,7 @@ async fn mock_get_player(
     };
 
     let proxy::MockGetPlayerResponse { player_data } =
-        proxy::proxy(&*SERVER_URI, "/mock_get_player", post_data)
+        proxy::proxy(&*SERVER_URI, "/mock_get_player", post_data, &request_id)
             .await?
             .0;
 
@@ -620,9 +637,11 @@ async fn mock_get_player(
 }
 
 #[post("/get_player", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_player(
     state: &State<SharedState>,
     _request: Json<GetPlayerRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetPlayerResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
@@ -633,25 +652,13 @@ async fn get_player(
     };
 
     let proxy::GetPlayerResponse { player_data } =
-        proxy::proxy(&*SERVER_URI, "/get_player", post_data)
+        proxy::proxy(&*SERVER_URI, "/get_player", post_data, &request_id)
             .await?
             .0;
 
-    let dec_shares = get_dec_shares(&player_data).await?;
-    let mut bits = state
-        .lock()
-        .await
-        .decrypt(&player_data, dec_shares)
-        .into_iter();
-    let player_data = PlayerData {
-        loc: Coord {
-            x: try_from_le_bits::<8>(&mut bits)?,
-            y: try_from_le_bits::<8>(&mut bits)?,
-        },
-        hp: try_from_le_bits::<8>(&mut bits)?,
-        atk: try_from_le_bits::<8>(&mut bits)?,
-        points: try_from_le_bits::<8>(&mut bits)?,
-    };
+    let dec_shares = get_dec_shares(&player_data, &request_id).await?;
+    let bits = state.lock().await.decrypt(&player_data, dec_shares)?;
+    let player_data = decode::player(bits)?;
 
     state.lock().await.player_coord = player_data.loc;
 
@@ -659,9 +666,11 @@ async fn get_player(
 }
 
 #[post("/mock_move", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn mock_move(
     state: &State<SharedState>,
     request: Json<MoveRequest>,
+    request_id: RequestId,
 ) -> Result<Json<MoveResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
@@ -676,7 +685,9 @@ async fn mock_move(
     let proxy::MockMoveResponse {
         my_new_coords,
         rate_limited,
-    } = proxy::proxy(&*SERVER_URI, "/mock_move", post_data).await?.0;
+    } = proxy::proxy(&*SERVER_URI, "/mock_move", post_data, &request_id)
+        .await?
+        .0;
 
     let my_new_coords = if let Some(my_new_coords) = my_new_coords {
         let mut app_state = state.lock().await;
@@ -696,12 +707,15 @@ async fn mock_move(
 }
 
 #[post("/move", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn queue_move(
     state: &State<SharedState>,
     request: Json<MoveRequest>,
+    request_id: RequestId,
 ) -> Result<Json<MoveResponse>, Custom<String>> {
     let post_data = {
         let app_state = state.lock().await;
+        app_state.check_pk()?;
 
         let direction = match request.direction {
             Direction::Up => [false, false],
@@ -709,10 +723,7 @@ async fn queue_move(
             Direction::Left => [false, true],
             Direction::Right => [true, true],
         };
-        let random_input = {
-            let v: u8 = thread_rng().gen();
-            from_fn::<_, 8, _>(|i| (v >> i) & 1 == 1)
-        };
+        let random_input = EncU8::encode(thread_rng().gen());
         let direction_and_random_input = app_state
             .user
             .batched_pk_encrypt(chain![direction, random_input]);
@@ -726,16 +737,14 @@ async fn queue_move(
     let proxy::MoveResponse {
         my_new_coords,
         rate_limited,
-    } = proxy::proxy(&*SERVER_URI, "/move", post_data).await?.0;
+    } = proxy::proxy(&*SERVER_URI, "/move", post_data, &request_id)
+        .await?
+        .0;
 
     let my_new_coords = if let Some(my_new_coords) = my_new_coords {
-        let dec_shares = get_dec_shares(&my_new_coords).await?;
+        let dec_shares = get_dec_shares(&my_new_coords, &request_id).await?;
         let mut app_state = state.lock().await;
-        let mut bits = app_state.decrypt(&my_new_coords, dec_shares).into_iter();
-        let coord = Coord {
-            x: try_from_le_bits::<8>(&mut bits)?,
-            y: try_from_le_bits::<8>(&mut bits)?,
-        };
+        let coord = decode::coord(app_state.decrypt(&my_new_coords, dec_shares)?)?;
         app_state.player_coord = coord;
         Some(coord)
     } else {
@@ -748,6 +757,35 @@ async fn queue_move(
     }))
 }
 
+#[post("/respawn", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn respawn(
+    state: &State<SharedState>,
+    _request: Json<RespawnRequest>,
+    request_id: RequestId,
+) -> Result<Json<RespawnResponse>, Custom<String>> {
+    let post_data = {
+        let app_state = state.lock().await;
+        proxy::RespawnRequest {
+            player_id: app_state.user.user_id(),
+        }
+    };
+
+    let proxy::RespawnResponse { my_new_coords } =
+        proxy::proxy(&*SERVER_URI, "/respawn", post_data, &request_id)
+            .await?
+            .0;
+
+    let dec_shares = get_dec_shares(&my_new_coords, &request_id).await?;
+    let mut app_state = state.lock().await;
+    let coord = decode::coord(app_state.decrypt(&my_new_coords, dec_shares)?)?;
+    app_state.player_coord = coord;
+
+    Ok(Json(RespawnResponse {
+        my_new_coords: coord,
+    }))
+}
+
 #[post("/get_id", format = "json", data = "<_request>")]
 async fn get_id(state: &State<SharedState>, _request: Json<GetIdRequest>) -> Json<GetIdResponse> {
     let app_state = state.lock().await;
@@ -771,49 +809,89 @@ async fn set_id(state: &State<SharedState>, request: Json<SetIdRequest>) -> Json
 }
 
 #[post("/submit_r1", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn submit_r1(
     state: &State<SharedState>,
     _request: Json<SubmitRound1KeyRequest>,
+    request_id: RequestId,
 ) -> Result<Json<SubmitRound1KeyResponse>, Custom<String>> {
-    let app_state = state.lock().await;
-
-    let post_data = proxy::SubmitRound1KeyRequest {
-        player_id: app_state.user.user_id(),
-        key: app_state.user.round_1_key_gen(),
-    };
-
-    let _: Json<proxy::SubmitRound1KeyResponse> =
-        proxy::proxy(&*SERVER_URI, "/submit_r1", post_data).await?;
+    let proxy::GetCrsResponse { crs } = get_crs(&request_id).await?;
+    submit_round_1_key(state, crs, &request_id).await?;
 
     Ok(Json(SubmitRound1KeyResponse {}))
 }
 
 #[post("/get_pk", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_pk(
     state: &State<SharedState>,
     _request: Json<GetPkRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetPkResponse>, Custom<String>> {
-    let mut app_state = state.lock().await;
-
     let response: proxy::GetPkResponse =
-        proxy::proxy(&*SERVER_URI, "/get_pk", proxy::GetPkRequest {})
+        proxy::proxy(&*SERVER_URI, "/get_pk", proxy::GetPkRequest {}, &request_id)
             .await?
             .0;
-    app_state.user.set_pk(response.pk.clone());
+    state.lock().await.user.set_pk(response.pk);
 
     Ok(Json(GetPkResponse {}))
 }
 
 #[post("/submit_r2", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn submit_r2(
     state: &State<SharedState>,
     _request: Json<SubmitRound2KeyRequest>,
+    request_id: RequestId,
 ) -> Result<Json<SubmitRound2KeyResponse>, Custom<String>> {
-    let app_state = state.lock().await;
+    submit_round_2_key(state, &request_id).await?;
 
-    if !app_state.user.has_pk() {
-        return Err(bad_request("pk is not ready yet"));
-    }
+    Ok(Json(SubmitRound2KeyResponse {}))
+}
+
+async fn get_crs(request_id: &RequestId) -> Result<proxy::GetCrsResponse, Custom<String>> {
+    proxy::proxy(
+        &*SERVER_URI,
+        "/get_crs",
+        proxy::GetCrsRequest {},
+        request_id,
+    )
+    .await
+    .map(|response| response.0)
+}
+
+/// Submits our round 1 key under `crs`, the CRS the server picked for the game.
+async fn submit_round_1_key(
+    state: &SharedState,
+    crs: PhantomCrs,
+    request_id: &RequestId,
+) -> Result<(), Custom<String>> {
+    let mut app_state = state.lock().await;
+
+    let player_id = app_state.user.user_id();
+    let user = PhantomUser::new(PHANTOM_PARAM.with_crs(crs), player_id, SEED.to_vec());
+    let post_data = proxy::SubmitRound1KeyRequest {
+        player_id,
+        key: user.round_1_key_gen(),
+    };
+
+    let _: Json<proxy::SubmitRound1KeyResponse> =
+        proxy::proxy(&*SERVER_URI, "/submit_r1", post_data, request_id).await?;
+
+    app_state.user = user;
+    app_state.crs = Some(crs);
+    app_state.round_2_key_submitted = false;
+
+    Ok(())
+}
+
+async fn submit_round_2_key(
+    state: &SharedState,
+    request_id: &RequestId,
+) -> Result<(), Custom<String>> {
+    let mut app_state = state.lock().await;
+
+    app_state.check_pk()?;
 
     let post_data = proxy::SubmitRound2KeyRequest {
         player_id: app_state.user.user_id(),
@@ -821,52 +899,239 @@ async fn submit_r2(
     };
 
     let _: Json<proxy::SubmitRound2KeyResponse> =
-        proxy::proxy(&*SERVER_URI, "/submit_r2", post_data).await?;
+        proxy::proxy(&*SERVER_URI, "/submit_r2", post_data, request_id).await?;
 
-    Ok(Json(SubmitRound2KeyResponse {}))
+    app_state.round_2_key_submitted = true;
+
+    Ok(())
+}
+
+/// Runs the key generation of each game with the server: whenever the server
+/// picks a new CRS, e.g. on `/reset_game`, submits our round 1 key under it,
+/// then our round 2 key once the public key is aggregated.
+async fn start_keygen_loop(state: SharedState) {
+    loop {
+        if let Err(Custom(status, err)) = keygen(&state).await {
+            tracing::warn!("key generation failed: {status} {err}");
+        }
+        time::sleep(Duration::from_millis(KEYGEN_POLL_MILLIS)).await;
+    }
+}
+
+async fn keygen(state: &SharedState) -> Result<(), Custom<String>> {
+    let request_id = RequestId::new();
+
+    let proxy::GetCrsResponse { crs } = get_crs(&request_id).await?;
+    let (current_crs, round_2_key_submitted) = {
+        let app_state = state.lock().await;
+        (app_state.crs, app_state.round_2_key_submitted)
+    };
+    if current_crs != Some(crs) {
+        info!("submitting round 1 key under a new crs");
+        return submit_round_1_key(state, crs, &request_id).await;
+    }
+    if round_2_key_submitted {
+        return Ok(());
+    }
+
+    let response = proxy::proxy::<_, proxy::GetPkResponse>(
+        &*SERVER_URI,
+        "/get_pk",
+        proxy::GetPkRequest {},
+        &request_id,
+    )
+    .await;
+    let pk = match response {
+        Ok(response) => response.0.pk,
+        // Not every round 1 key is submitted yet.
+        Err(Custom(status, _)) if status == Status::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    let player_id = {
+        let mut app_state = state.lock().await;
+        app_state.user.set_pk(pk);
+        app_state.user.user_id()
+    };
+    info!("submitting round 2 key");
+    submit_round_2_key(state, &request_id).await?;
+    tokio::spawn(deposit_seed(player_id));
+    Ok(())
 }
 
 #[post("/get_dec_share", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_dec_share(
     state: &State<SharedState>,
     request: Json<GetDecShareRequest>,
-) -> Json<GetDecShareResponse> {
+    request_id: RequestId,
+) -> Result<Json<GetDecShareResponse>, Custom<String>> {
     let app_state = state.lock().await;
+    app_state.check_pk()?;
 
-    let dec_share = app_state.user.decrypt_share(&request.ct);
+    let dec_share = info_span!("decrypt_share", n = request.ct.n())
+        .in_scope(|| app_state.user.decrypt_share(&request.ct))
+        .map_err(bad_request)?;
 
-    Json(GetDecShareResponse { dec_share })
+    Ok(Json(GetDecShareResponse { dec_share }))
 }
 
+/// Collects the decryption shares of the other players. Each peer is retried
+/// on its own, up to [`DEC_SHARE_ATTEMPTS`], as every share is needed.
+#[tracing::instrument(name = "dec_share_fanout", skip_all, fields(n = ct.n()))]
 async fn get_dec_shares(
     ct: &PhantomPackedCt,
-) -> Result<[PhantomPackedCtDecShare; 3], Custom<String>> {
+    request_id: &RequestId,
+) -> Result<Vec<PhantomPackedCtDecShare>, Custom<String>> {
     let body = &GetDecShareRequest { ct: ct.clone() };
-    OTHER_PLAYER_URIS
-        .iter()
-        .map(move |uri| async move {
-            let client = reqwest::Client::new();
-            let response = client
-                .post(format!("{uri}/get_dec_share"))
-                .json(body)
-                .send()
-                .await
-                .map_err(internal_server_error)?;
-            if response.status().is_success() {
-                let body: GetDecShareResponse =
-                    response.json().await.map_err(internal_server_error)?;
-                Ok(body.dec_share)
-            } else {
-                let status = response.status();
-                let body = response.text().await.map_err(internal_server_error)?;
-                tracing::error!("Request failed with status: {status} body: {body}");
-                Err(custom(status, body))
+    izip!(other_player_ids(*PLAYER_ID), OTHER_PLAYER_URIS.iter())
+        .map(move |(peer_id, uri)| {
+            async move {
+                get_peer_dec_share(uri, body, request_id)
+                    .await
+                    .map_err(|Custom(status, err)| {
+                        Custom(
+                            status,
+                            format!("no decryption share from player {peer_id}: {err}"),
+                        )
+                    })
             }
+            .instrument(info_span!("get_dec_share", peer = uri.as_str()))
         })
         .collect::<FuturesUnordered<_>>()
-        .try_collect::<Vec<_>>()
+        .try_collect()
+        .await
+}
+
+async fn get_peer_dec_share(
+    uri: &str,
+    body: &GetDecShareRequest,
+    request_id: &RequestId,
+) -> Result<PhantomPackedCtDecShare, Custom<String>> {
+    let mut attempt = 1;
+    loop {
+        match post_with_timeout(uri, "/get_dec_share", body, request_id).await {
+            Ok(GetDecShareResponse { dec_share }) => return Ok(dec_share),
+            Err(Custom(status, err)) if attempt < DEC_SHARE_ATTEMPTS => {
+                tracing::warn!("attempt {attempt} failed: {status} {err}");
+                time::sleep(Duration::from_millis(DEC_SHARE_RETRY_MILLIS)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Posts `body` to `uri` without logging it, as it holds a whole ciphertext,
+/// failing after [`DEC_SHARE_TIMEOUT_MILLIS`].
+async fn post_with_timeout<S: DeserializeOwned>(
+    uri: &str,
+    path: &str,
+    body: &impl Serialize,
+    request_id: &RequestId,
+) -> Result<S, Custom<String>> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{uri}{path}"))
+        .header(REQUEST_ID_HEADER, request_id.as_str())
+        .timeout(Duration::from_millis(DEC_SHARE_TIMEOUT_MILLIS))
+        .json(body)
+        .send()
         .await
-        .map(|dec_shares| dec_shares.try_into().unwrap())
+        .map_err(internal_server_error)?;
+    if response.status().is_success() {
+        response.json().await.map_err(internal_server_error)
+    } else {
+        let status = response.status();
+        let body = response.text().await.map_err(internal_server_error)?;
+        tracing::error!("Request failed with status: {status} body: {body}");
+        Err(custom(status, body))
+    }
+}
+
+/// Deposits the seed of `player_id` with the escrow, if any, sealed to the
+/// escrow's public key.
+async fn deposit_seed(player_id: usize) {
+    let Some((escrow_uri, public_key)) = &*ESCROW else {
+        return;
+    };
+    let request = escrow::DepositRequest {
+        player_id,
+        sealed_seed: public_key.seal(&mut OsRng, &*SEED).unwrap(),
+    };
+    let response = proxy::proxy::<_, escrow::DepositResponse>(
+        escrow_uri,
+        "/deposit",
+        request,
+        &RequestId::new(),
+    )
+    .await;
+    if let Err(Custom(status, err)) = response {
+        tracing::warn!("failed to deposit seed with escrow: {status} {err}");
+    }
+}
+
+#[post("/results", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn results(
+    _request: Json<proxy::ResultsRequest>,
+    request_id: RequestId,
+) -> Result<Json<proxy::ResultsResponse>, Custom<String>> {
+    proxy::proxy(
+        &*SERVER_URI,
+        "/results",
+        proxy::ResultsRequest {},
+        &request_id,
+    )
+    .await
+}
+
+/// Submits our decryption share whenever the server is waiting for one, e.g.
+/// for game over checks and final scores.
+async fn start_decryption_loop(state: SharedState) {
+    loop {
+        if let Err(Custom(status, err)) = submit_pending_dec_share(&state).await {
+            tracing::warn!("failed to submit decryption share: {status} {err}");
+        }
+        time::sleep(Duration::from_millis(DECRYPTION_POLL_MILLIS)).await;
+    }
+}
+
+async fn submit_pending_dec_share(state: &SharedState) -> Result<(), Custom<String>> {
+    let request_id = RequestId::new();
+    let player_id = state.lock().await.user.user_id();
+
+    let proxy::GetPendingDecryptionResponse { decryption } = proxy::proxy(
+        &*SERVER_URI,
+        "/get_pending_decryption",
+        proxy::GetPendingDecryptionRequest { player_id },
+        &request_id,
+    )
+    .await?
+    .0;
+
+    if let Some(proxy::PendingDecryption { id, ct }) = decryption {
+        let dec_share = {
+            let app_state = state.lock().await;
+            app_state.check_pk()?;
+            info_span!("decrypt_share", n = ct.n())
+                .in_scope(|| app_state.user.decrypt_share(&ct))
+                .map_err(bad_gateway)?
+        };
+        let proxy::SubmitDecShareResponse {} = proxy::proxy(
+            &*SERVER_URI,
+            "/submit_dec_share",
+            proxy::SubmitDecShareRequest {
+                player_id,
+                id,
+                dec_share,
+            },
+            &request_id,
+        )
+        .await?
+        .0;
+    }
+
+    Ok(())
 }
 
 fn bad_request(err: impl ToString) -> Custom<String> {
@@ -877,12 +1142,18 @@ fn internal_server_error(err: impl ToString) -> Custom<String> {
     custom(StatusCode::INTERNAL_SERVER_ERROR, err)
 }
 
+fn bad_gateway(err: impl ToString) -> Custom<String> {
+    custom(StatusCode::BAD_GATEWAY, err)
+}
+
 fn custom(stauts: StatusCode, err: impl ToString) -> Custom<String> {
     Custom(Status::from_code(stauts.as_u16()).unwrap(), err.to_string())
 }
 
 #[rocket::main]
 async fn main() -> Result<(), rocket::Error> {
+    telemetry::init("phantom-client");
+
     let shared_state: Arc<Mutex<AppState>> = Arc::new(Mutex::new(AppState::new(*PLAYER_ID)));
 
     // Create a custom configuration
@@ -893,16 +1164,21 @@ async fn main() -> Result<(), rocket::Error> {
     };
 
     let _ = &*OTHER_PLAYER_URIS;
+    let _ = &*ESCROW;
+    let _ = &*SEED;
+
+    tokio::spawn(start_keygen_loop(shared_state.clone()));
+    tokio::spawn(start_decryption_loop(shared_state.clone()));
 
     rocket::custom(config)
         .manage(shared_state.clone())
         .mount(
             "/",
             routes![
-                reset,
                 reset_game,
                 mock_move,
                 queue_move,
+                respawn,
                 mock_get_cells,
                 get_cells,
                 get_five_cells,
@@ -913,10 +1189,12 @@ async fn main() -> Result<(), rocket::Error> {
                 get_player,
                 get_id,
                 set_id,
-                // get_pk,
-                // submit_r1,
-                // submit_r2,
-                get_dec_share
+                results,
+                get_pk,
+                submit_r1,
+                submit_r2,
+                get_dec_share,
+                metrics::metrics,
             ],
         )
         .attach(make_cors())
@@ -2,14 +2,17 @@ use crate::{custom, internal_server_error};
 use core::fmt::Debug;
 use rocket::{response::status::Custom, serde::json::Json};
 use serde::{de::DeserializeOwned, Serialize};
+use server::telemetry::{RequestId, REQUEST_ID_HEADER};
 
 pub use server::client::*;
 pub use server::worker::*;
 
+#[tracing::instrument(skip_all, fields(path = path.as_ref()))]
 pub async fn proxy<R: Serialize, S: Debug + DeserializeOwned>(
     server_uri: impl AsRef<str>,
     path: impl AsRef<str>,
     body: R,
+    request_id: &RequestId,
 ) -> Result<Json<S>, Custom<String>> {
     // Create a client
     let client = reqwest::Client::new();
@@ -20,6 +23,7 @@ pub async fn proxy<R: Serialize, S: Debug + DeserializeOwned>(
     // Send the request
     let response = client
         .post(format!("{}{}", server_uri.as_ref(), path.as_ref()))
+        .header(REQUEST_ID_HEADER, request_id.as_str())
         .json(&body)
         .send()
         .await
@@ -0,0 +1,191 @@
+//! Generates, inspects and checks aggregated keys outside of a game, e.g. to
+//! benchmark or debug with fixed keys. Games generate their own keys under a
+//! fresh CRS with the players.
+//!
+//! ```text
+//! phantom-keygen seeds <dir> <n>
+//! phantom-keygen keygen <dir> <seed>...
+//! phantom-keygen fingerprint <dir>
+//! phantom-keygen verify <dir> <seed>...
+//! ```
+//!
+//! `seeds` writes `n` seed files `seed_0`..`seed_{n-1}` of 32 random bytes, as
+//! `phantom-client` reads its seed from. `keygen` runs the key generation of one user per seed file, in order of user
+//! id, and writes the aggregated `pk`, `rp_key` and `bs_key` to `dir` in the
+//! bincode format the server sends them to the workers in. `verify` checks the
+//! keys in `dir` decrypt what they encrypt with the users of the seed files.
+//!
+//! Keys are generated under the parameter of [`PhantomParam::NAMED`] named by
+//! the `PHANTOM_PARAM` environment variable, as the server picks it,
+//! [`PhantomParam::I_4P_40`] by default.
+
+use phantom::{
+    Fingerprint, PhantomBsKey, PhantomEvaluator, PhantomParam, PhantomPk, PhantomRound1Key,
+    PhantomRound2Key, PhantomRpKey, PhantomUser,
+};
+use rand::{thread_rng, Rng};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    env, fs,
+    iter::repeat_with,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "usage:
+  phantom-keygen seeds <dir> <n>
+  phantom-keygen keygen <dir> <seed>...
+  phantom-keygen fingerprint <dir>
+  phantom-keygen verify <dir> <seed>...";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["seeds", dir, n] => seeds(dir, n),
+        ["keygen", dir, ref seeds @ ..] if !seeds.is_empty() => keygen(dir, seeds),
+        ["fingerprint", dir] => fingerprint(dir),
+        ["verify", dir, ref seeds @ ..] if !seeds.is_empty() => verify(dir, seeds),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn seeds(dir: &str, n: &str) -> Result<(), String> {
+    let n: usize = n.parse().map_err(|_| format!("invalid n {n:?}"))?;
+    fs::create_dir_all(dir).map_err(|err| format!("{dir}: {err}"))?;
+    for user_id in 0..n {
+        let seed: [u8; 32] = thread_rng().gen();
+        write(Path::new(dir).join(format!("seed_{user_id}")), &seed)?;
+    }
+    Ok(())
+}
+
+fn keygen(dir: &str, seeds: &[&str]) -> Result<(), String> {
+    let (param, mut users) = users(seeds)?;
+    let mut evaluator = PhantomEvaluator::new(param);
+
+    let round_1_keys: Vec<PhantomRound1Key> =
+        users.iter().map(|user| user.round_1_key_gen()).collect();
+    evaluator
+        .aggregate_round_1_keys(&round_1_keys)
+        .map_err(|err| err.to_string())?;
+    let pk = evaluator.pk().cloned().unwrap();
+
+    users.iter_mut().for_each(|user| user.set_pk(pk.clone()));
+    let round_2_keys: Vec<PhantomRound2Key> =
+        users.iter().map(|user| user.round_2_key_gen()).collect();
+    evaluator
+        .aggregate_round_2_keys(&round_2_keys)
+        .map_err(|err| err.to_string())?;
+
+    let dir = Path::new(dir);
+    fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    write_bincode(dir.join("pk"), &pk)?;
+    write_bincode(dir.join("rp_key"), evaluator.rp_key().unwrap())?;
+    write_bincode(dir.join("bs_key"), evaluator.bs_key().unwrap())?;
+    fingerprint(dir.to_str().unwrap())
+}
+
+fn fingerprint(dir: &str) -> Result<(), String> {
+    let (pk, rp_key, bs_key) = keys(dir)?;
+    println!("pk     {}", pk.fingerprint());
+    println!("rp_key {}", rp_key.fingerprint());
+    println!("bs_key {}", bs_key.fingerprint());
+    Ok(())
+}
+
+/// Encrypts random bits, xors them pairwise homomorphically, then packs and
+/// jointly decrypts the xors.
+fn verify(dir: &str, seeds: &[&str]) -> Result<(), String> {
+    let (param, mut users) = users(seeds)?;
+    let (pk, rp_key, bs_key) = keys(dir)?;
+    let mut evaluator = PhantomEvaluator::new(param);
+    evaluator.set_pk(pk.clone());
+    evaluator.set_rp_key(rp_key);
+    evaluator.set_bs_key(bs_key);
+    users.iter_mut().for_each(|user| user.set_pk(pk.clone()));
+
+    let mut rng = thread_rng();
+    let bits = repeat_with(|| rng.gen_bool(0.5))
+        .take(32)
+        .collect::<Vec<_>>();
+    let cts = evaluator
+        .unbatch(&users[0].batched_pk_encrypt(bits.iter().copied()))
+        .map_err(|err| err.to_string())?;
+    let xor_cts = cts
+        .chunks(2)
+        .map(|pair| &pair[0] ^ &pair[1])
+        .collect::<Vec<_>>();
+    let ct_packed = evaluator.pack(&xor_cts);
+    let dec_shares = users
+        .iter()
+        .map(|user| user.decrypt_share(&ct_packed))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| err.to_string())?;
+    let decrypted = evaluator
+        .aggregate_dec_shares(&ct_packed, dec_shares)
+        .map_err(|err| err.to_string())?;
+
+    let expected = bits
+        .chunks(2)
+        .map(|pair| pair[0] ^ pair[1])
+        .collect::<Vec<_>>();
+    if decrypted != expected {
+        return Err(format!(
+            "keys in {dir} don't round-trip: expected {expected:?}, decrypted {decrypted:?}"
+        ));
+    }
+    println!("keys in {dir} round-trip with {} users", users.len());
+    Ok(())
+}
+
+/// Users of the seed files, user `i` being of `seeds[i]`, and the parameter
+/// shared between them.
+fn users(seeds: &[&str]) -> Result<(PhantomParam, Vec<PhantomUser>), String> {
+    let param = match env::var("PHANTOM_PARAM") {
+        Err(_) => PhantomParam::I_4P_40,
+        Ok(name) => PhantomParam::from_name(&name)
+            .ok_or_else(|| format!("invalid PHANTOM_PARAM {name:?}"))?,
+    }
+    .with_total_shares(seeds.len());
+    let users = seeds
+        .iter()
+        .enumerate()
+        .map(|(user_id, path)| {
+            let seed = fs::read(path).map_err(|err| format!("{path}: {err}"))?;
+            if seed.len() != 32 {
+                return Err(format!("{path}: expected 32 bytes, got {}", seed.len()));
+            }
+            Ok(PhantomUser::new(param, user_id, seed))
+        })
+        .collect::<Result<_, String>>()?;
+    Ok((param, users))
+}
+
+fn keys(dir: &str) -> Result<(PhantomPk, PhantomRpKey, PhantomBsKey), String> {
+    let dir = Path::new(dir);
+    Ok((
+        read_bincode(dir.join("pk"))?,
+        read_bincode(dir.join("rp_key"))?,
+        read_bincode(dir.join("bs_key"))?,
+    ))
+}
+
+fn read_bincode<T: DeserializeOwned>(path: PathBuf) -> Result<T, String> {
+    let bytes = fs::read(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    bincode::deserialize(&bytes).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write_bincode<T: Serialize>(path: PathBuf, value: &T) -> Result<(), String> {
+    write(path, &bincode::serialize(value).unwrap())
+}
+
+fn write(path: PathBuf, bytes: &[u8]) -> Result<(), String> {
+    fs::write(&path, bytes).map_err(|err| format!("{}: {err}", path.display()))
+}
@@ -0,0 +1,868 @@
+//! FHE backend, multi-party FHEW on top of `phantom-zone`.
+
+use crate::{
+    check_dec_shares,
+    proof::{add_short, mul, Relation, Statement},
+    Fingerprint, PhantomCrs, PhantomCrsId, PhantomCrsMismatch, PhantomDecryptionError,
+    PhantomFingerprint, PhantomKeyId, PhantomKeyMismatch, PhantomPackedCtDecShare, PhantomParam,
+};
+use core::ops::Deref;
+use itertools::chain;
+use phantom_zone_evaluator::boolean::fhew::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+#[cfg(any(test, feature = "dev"))]
+pub mod noise;
+
+/// Bound of secret keys proven in decryption share proofs, loose for the
+/// secret key distributions of the named parameters.
+const SECRET_BOUND: u64 = 1 << 6;
+
+/// Bound of the errors of public key shares, loose for the noise distributions
+/// of the named parameters.
+const PK_ERROR_BOUND: u64 = 1 << 6;
+
+/// Bound of the errors of decryption shares, far below the ring-packing
+/// modulus over the ciphertext's encoding.
+const DEC_ERROR_BOUND: u64 = 1 << 32;
+
+impl PhantomCrs {
+    fn fhew(&self) -> FhewBoolMpiCrs<StdRng> {
+        FhewBoolMpiCrs::new(StdRng::from_hierarchical_seed(self.0, &[0]).gen())
+    }
+
+    fn ring_packing(&self) -> RingPackingCrs<StdRng> {
+        RingPackingCrs::new(StdRng::from_hierarchical_seed(self.0, &[1]).gen())
+    }
+}
+
+/// Round 1 key share during key generation, containing public key share.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomRound1Key {
+    pk_share: PhantomPkShare,
+    crs_id: PhantomCrsId,
+}
+
+impl PhantomRound1Key {
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.crs_id
+    }
+}
+
+/// Round 2 key share during key generation, containing ring-packing key share
+/// and bootstrapping key share.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomRound2Key {
+    rp_key_share: PhantomRpKeyShare,
+    bs_key_share: PhantomBsKeyShare,
+    crs_id: PhantomCrsId,
+}
+
+impl PhantomRound2Key {
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.crs_id
+    }
+}
+
+/// [`PhantomUser`] proivdes necessary functionality to do deterministic key
+/// generation given a seed, encryption, decryption share generation, and
+/// decryption shares aggregation.
+pub struct PhantomUser {
+    ops: PhantomOps,
+    user_id: usize,
+    seed: <StdRng as SeedableRng>::Seed,
+}
+
+impl PhantomUser {
+    /// Returns a new [`PhantomUser`].
+    pub fn new(param: PhantomParam, user_id: usize, seed: Vec<u8>) -> Self {
+        Self {
+            ops: PhantomOps::new(param),
+            user_id,
+            seed: seed.try_into().unwrap(),
+        }
+    }
+
+    /// Returns user id.
+    pub fn user_id(&self) -> usize {
+        self.user_id
+    }
+
+    /// Generates round 1 key.
+    pub fn round_1_key_gen(&self) -> PhantomRound1Key {
+        PhantomRound1Key {
+            pk_share: self
+                .ops
+                .pk_share_gen(&self.sk(), self.deterministic_rng(&[1, 0])),
+            crs_id: self.ops.crs_id(),
+        }
+    }
+
+    /// Returns if aggregated public key is set or not.
+    pub fn has_pk(&self) -> bool {
+        self.ops.pk.is_some()
+    }
+
+    /// Sets aggregated public key retrieved from server.
+    pub fn set_pk(&mut self, pk: PhantomPk) {
+        self.ops.set_pk(pk)
+    }
+
+    /// Generates round 2 key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn round_2_key_gen(&self) -> PhantomRound2Key {
+        PhantomRound2Key {
+            bs_key_share: self.ops.bs_key_share_gen(
+                self.user_id,
+                &self.sk(),
+                &self.sk_ks(),
+                self.deterministic_rng(&[1, 2]),
+            ),
+            rp_key_share: self
+                .ops
+                .rp_key_share_gen(&self.sk(), self.deterministic_rng(&[1, 1])),
+            crs_id: self.ops.crs_id(),
+        }
+    }
+
+    /// Encrypts bits in batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
+        self.ops.batched_pk_encrypt(ms, &mut StdRng::from_entropy())
+    }
+
+    /// Like [`PhantomUser::batched_pk_encrypt`], with randomness from `rng`.
+    #[cfg(test)]
+    pub(crate) fn batched_pk_encrypt_with_rng(
+        &self,
+        ms: impl IntoIterator<Item = bool>,
+        rng: &mut StdRng,
+    ) -> PhantomBatchedCt {
+        self.ops.batched_pk_encrypt(ms, rng)
+    }
+
+    /// Generates decryption share, with a proof that it is computed with the
+    /// secret key of the user's public key share.
+    ///
+    /// The share is deterministic in the seed and the ciphertext, so asking
+    /// for it again reveals nothing more.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn decrypt_share(
+        &self,
+        ct_packed: &PhantomPackedCt,
+    ) -> Result<PhantomPackedCtDecShare, PhantomKeyMismatch> {
+        self.ops.check_key_id(ct_packed.key_id)?;
+        let ct_id = ct_packed.fingerprint();
+        let sk = self.sk();
+        let share = self
+            .ops
+            .decrypt_share(&sk, ct_packed, self.dec_share_seed(ct_id));
+        let statement = self
+            .ops
+            .dec_share_statement(ct_packed, self.user_id, &share);
+        Ok(PhantomPackedCtDecShare::prove(
+            self.user_id,
+            ct_id,
+            share,
+            &statement,
+            sk.as_ref(),
+        ))
+    }
+
+    /// Aggregates decryption share and returns decrypted bits.
+    ///
+    /// Each share is verified, see [`PhantomPackedCtDecShare`], and the error
+    /// names the user of the first rejected one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn aggregate_dec_shares(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_shares: Vec<PhantomPackedCtDecShare>,
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
+    }
+
+    fn sk(&self) -> PhantomSk {
+        self.ops
+            .sk_gen(StdRng::from_hierarchical_seed(self.seed, &[0, 0]))
+    }
+
+    fn sk_ks(&self) -> PhantomSkKs {
+        self.ops
+            .sk_ks_gen(StdRng::from_hierarchical_seed(self.seed, &[0, 1]))
+    }
+
+    fn deterministic_rng(&self, path: &[usize]) -> StdRng {
+        StdRng::from_hierarchical_seed(self.seed, path)
+    }
+
+    /// Seed of the randomness of the decryption share of the ciphertext
+    /// `ct_id`.
+    fn dec_share_seed(&self, ct_id: PhantomFingerprint) -> <StdRng as SeedableRng>::Seed {
+        let mut hasher = Sha256::new();
+        hasher.update(self.seed);
+        hasher.update(ct_id.0);
+        hasher.finalize().into()
+    }
+}
+
+/// [`PhantomEvaluator`] provides necessary functionality to do key aggregation,
+/// compuation on encrypted bits, packing of encrypted bits.
+#[derive(Clone, Debug)]
+pub struct PhantomEvaluator {
+    ops: PhantomOps,
+    rp_key: Option<PhantomRpKey>,
+    rp_key_prep: Option<PhantomRpKeyPrep>,
+    bs_key: Option<PhantomBsKey>,
+    evaluator: Option<Arc<FhewBoolEvaluator<EvaluationRing, KeySwitchMod>>>,
+}
+
+impl PhantomEvaluator {
+    /// Returns a new [`PhantomEvaluator`].
+    pub fn new(param: PhantomParam) -> Self {
+        Self {
+            ops: PhantomOps::new(param),
+            rp_key: None,
+            rp_key_prep: None,
+            bs_key: None,
+            evaluator: None,
+        }
+    }
+
+    /// Returns the parameter, with the CRS key shares must be generated under.
+    pub fn param(&self) -> &PhantomParam {
+        self.ops.param()
+    }
+
+    /// Returns [`Option`] of aggregated public key.
+    pub fn pk(&self) -> Option<&PhantomPk> {
+        self.ops.pk.as_ref()
+    }
+
+    /// Returns [`Option`] of aggregated ring-packing key.
+    pub fn rp_key(&self) -> Option<&PhantomRpKey> {
+        self.rp_key.as_ref()
+    }
+
+    /// Returns [`Option`] of aggregated bootstrapping key.
+    pub fn bs_key(&self) -> Option<&PhantomBsKey> {
+        self.bs_key.as_ref()
+    }
+
+    /// Returns the id of the CRS key shares must be generated under.
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.ops.crs_id()
+    }
+
+    /// Returns an error if a key share is generated under another CRS.
+    pub fn check_crs_id(&self, crs_id: PhantomCrsId) -> Result<(), PhantomCrsMismatch> {
+        self.ops.check_crs_id(crs_id)
+    }
+
+    /// Aggregates round 1 keys and sets the aggregated public key.
+    pub fn aggregate_round_1_keys<'a>(
+        &mut self,
+        round_1_keys: impl IntoIterator<Item = &'a PhantomRound1Key>,
+    ) -> Result<(), PhantomCrsMismatch> {
+        let pk_shares = round_1_keys
+            .into_iter()
+            .map(|key| {
+                self.check_crs_id(key.crs_id)?;
+                Ok(key.pk_share.clone())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.ops.aggregate_pk_shares(pk_shares);
+        Ok(())
+    }
+
+    /// Aggregates round 1 keys and sets the aggregated ring-packing key and
+    /// bootstrapping key.
+    pub fn aggregate_round_2_keys<'a>(
+        &mut self,
+        round_2_keys: impl IntoIterator<Item = &'a PhantomRound2Key>,
+    ) -> Result<(), PhantomCrsMismatch> {
+        let (rp_key_shares, bs_key_shares): (Vec<_>, Vec<_>) = round_2_keys
+            .into_iter()
+            .map(|key| {
+                self.check_crs_id(key.crs_id)?;
+                Ok((&key.rp_key_share, &key.bs_key_share))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        let rp_key = self.ops.aggregate_rp_key_shares(rp_key_shares);
+        self.set_rp_key(rp_key);
+        let bs_key = self.ops.aggregate_bs_key_shares(bs_key_shares);
+        self.set_bs_key(bs_key);
+        Ok(())
+    }
+
+    // Sets the aggregated public key.
+    pub fn set_pk(&mut self, pk: PhantomPk) {
+        self.ops.set_pk(pk);
+    }
+
+    /// Sets the aggregated ring-packing key.
+    pub fn set_rp_key(&mut self, rp_key: PhantomRpKey) {
+        let mut rp_key_prep = PhantomRpKeyPrep::allocate_eval(
+            self.ops.ring_packing_param(),
+            self.ops.ring_rp().eval_size(),
+        );
+        prepare_rp_key(self.ops.ring_rp(), &mut rp_key_prep, &rp_key);
+        self.rp_key = Some(rp_key);
+        self.rp_key_prep = Some(rp_key_prep);
+    }
+
+    /// Sets the aggregated bootstrapping key.
+    pub fn set_bs_key(&mut self, bs_key: PhantomBsKey) {
+        let ring: EvaluationRing = RingOps::new(self.ops.param.modulus, self.ops.param.ring_size);
+        let mut bs_key_prep = PhantomBsKeyPrep::allocate_eval(**self.ops.param, ring.eval_size());
+        prepare_bs_key(&ring, &mut bs_key_prep, &bs_key);
+        self.bs_key = Some(bs_key);
+        self.evaluator = Some(Arc::new(FhewBoolEvaluator::new(bs_key_prep)));
+    }
+
+    /// Encrypts bits in batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
+        self.ops.batched_pk_encrypt(ms, &mut StdRng::from_entropy())
+    }
+
+    /// Wraps a raw ct into [`PhantomBool`] for further computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_bs_key`] is not called yet.
+    pub fn wrap(&self, ct: PhantomCt) -> PhantomBool {
+        PhantomBool::new(Arc::clone(self.evaluator.as_ref().unwrap()), ct)
+    }
+
+    /// Unbatchs ct generated by [`PhantomUser::batched_pk_encrypt`] or
+    /// [`PhantomEvaluator::batched_pk_encrypt`], and wrap them into
+    /// [`PhantomBool`] for further computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] or [`PhantomEvaluator::set_bs_key`]
+    /// is not called yet.
+    pub fn unbatch(
+        &self,
+        ct_batched: &PhantomBatchedCt,
+    ) -> Result<Vec<PhantomBool>, PhantomKeyMismatch> {
+        self.ops.check_key_id(ct_batched.key_id)?;
+        Ok(ct_batched
+            .ct
+            .extract_all(self.ops.ring())
+            .into_iter()
+            .map(|ct| self.wrap(ct))
+            .collect())
+    }
+
+    /// Packs [`PhantomBool`]s into [`PhantomPackedCt`] for decryption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] or [`PhantomEvaluator::set_rp_key`]
+    /// is not called yet.
+    pub fn pack<'a>(&self, cts: impl IntoIterator<Item = &'a PhantomBool>) -> PhantomPackedCt {
+        PhantomPackedCt {
+            ct: FhewBoolPackedCiphertext::pack_ms(
+                self.ops.ring(),
+                self.ops.ring_rp(),
+                self.rp_key_prep.as_ref().unwrap(),
+                cts.into_iter().map(PhantomBool::ct),
+            ),
+            key_id: self.ops.key_id(),
+        }
+    }
+
+    /// Verifies a decryption share of `ct_packed`, see
+    /// [`PhantomPackedCtDecShare`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn verify_dec_share(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_share: &PhantomPackedCtDecShare,
+    ) -> Result<(), PhantomDecryptionError> {
+        self.ops.verify_dec_share(ct_packed, dec_share)
+    }
+
+    /// Aggregates decryption share and returns decrypted bits.
+    ///
+    /// Each share is verified, see [`PhantomPackedCtDecShare`], and the error
+    /// names the user of the first rejected one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn aggregate_dec_shares(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_shares: Vec<PhantomPackedCtDecShare>,
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
+    }
+}
+
+type Ring = NativeRing;
+
+type EvaluationRing = NoisyNativeRing;
+
+type KeySwitchMod = NonNativePowerOfTwo;
+
+type PackingRing = PrimeRing;
+
+pub type PhantomRpKey = RingPackingKeyOwned<Elem<PackingRing>>;
+
+pub type PhantomRpKeyPrep = RingPackingKeyOwned<<PackingRing as RingOps>::EvalPrep>;
+
+pub type PhantomBsKey = FhewBoolKeyOwned<Elem<Ring>, Elem<KeySwitchMod>>;
+
+pub type PhantomBsKeyPrep =
+    FhewBoolKeyOwned<<EvaluationRing as RingOps>::EvalPrep, Elem<KeySwitchMod>>;
+
+pub type PhantomCt = FhewBoolCiphertextOwned<Elem<Ring>>;
+
+pub type PhantomBool = FheBool<Arc<FhewBoolEvaluator<EvaluationRing, KeySwitchMod>>>;
+
+wasm_bindgen_wrapper!(
+    PhantomSk(RlweSecretKeyOwned<i64>),
+    PhantomSkKs(LweSecretKeyOwned<i64>),
+    PhantomPkShare(SeededRlwePublicKeyOwned<Elem<Ring>>),
+    PhantomRpKeyShare(RingPackingKeyShareOwned<Elem<PackingRing>>),
+    PhantomBsKeyShare(FhewBoolMpiKeyShareOwned<Elem<Ring>, Elem<KeySwitchMod>>),
+);
+
+/// Decryption share, of the coefficients of the packed bits, and of the rest
+/// of the ring element for the share's proof to be over a whole ring element.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomDecShare {
+    share: RlweDecryptionShareListOwned<Elem<PackingRing>>,
+    rest: Vec<Elem<PackingRing>>,
+}
+
+impl PhantomDecShare {
+    /// Returns the share of the whole ring element.
+    fn to_vec(&self) -> Vec<u64> {
+        chain(self.share.as_ref(), &self.rest).copied().collect()
+    }
+}
+
+/// Aggregated public key, with the public key share of each user its
+/// decryption shares are verified against.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomPk {
+    pk: RlwePublicKeyOwned<Elem<Ring>>,
+    pk_shares: Vec<PhantomPkShare>,
+}
+
+impl Deref for PhantomPk {
+    type Target = RlwePublicKeyOwned<Elem<Ring>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pk
+    }
+}
+
+/// Batched ciphertext, tagged with the key it is encrypted under.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomBatchedCt {
+    ct: FhewBoolBatchedCiphertextOwned<Elem<Ring>>,
+    key_id: PhantomKeyId,
+}
+
+impl PhantomBatchedCt {
+    pub fn n(&self) -> usize {
+        self.ct.n()
+    }
+
+    pub fn key_id(&self) -> PhantomKeyId {
+        self.key_id
+    }
+}
+
+/// Packed ciphertext, tagged with the key it is to be decrypted under.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomPackedCt {
+    ct: FhewBoolPackedCiphertextOwned<Elem<PackingRing>>,
+    key_id: PhantomKeyId,
+}
+
+impl PhantomPackedCt {
+    pub fn n(&self) -> usize {
+        self.ct.n()
+    }
+
+    pub fn key_id(&self) -> PhantomKeyId {
+        self.key_id
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PhantomOps {
+    param: PhantomParam,
+    ring: NativeRing,
+    mod_ks: NonNativePowerOfTwo,
+    ring_rp: PrimeRing,
+    pk: Option<PhantomPk>,
+    key_id: Option<PhantomKeyId>,
+}
+
+impl PhantomOps {
+    pub fn new(param: PhantomParam) -> Self {
+        Self {
+            param,
+            ring: RingOps::new(param.modulus, param.ring_size),
+            mod_ks: ModulusOps::new(param.lwe_modulus),
+            ring_rp: RingOps::new(param.ring_packing_modulus.unwrap(), param.ring_size),
+            pk: None,
+            key_id: None,
+        }
+    }
+
+    pub fn param(&self) -> &PhantomParam {
+        &self.param
+    }
+
+    fn crs(&self) -> &PhantomCrs {
+        &self.param.crs
+    }
+
+    fn crs_id(&self) -> PhantomCrsId {
+        self.crs().fingerprint()
+    }
+
+    fn check_crs_id(&self, crs_id: PhantomCrsId) -> Result<(), PhantomCrsMismatch> {
+        PhantomCrsMismatch::check(self.crs_id(), crs_id)
+    }
+
+    fn fhew_param(&self) -> &FhewBoolParam {
+        self.param()
+    }
+
+    fn ring_packing_param(&self) -> RingPackingParam {
+        RingPackingParam {
+            modulus: self
+                .param()
+                .ring_packing_modulus
+                .unwrap_or_else(|| self.param().modulus),
+            ring_size: self.param().ring_size,
+            sk_distribution: self.param().sk_distribution,
+            noise_distribution: self.param().noise_distribution,
+            auto_decomposition_param: self.param().ring_packing_auto_decomposition_param,
+        }
+    }
+
+    fn ring(&self) -> &Ring {
+        &self.ring
+    }
+
+    fn mod_ks(&self) -> &KeySwitchMod {
+        &self.mod_ks
+    }
+
+    fn ring_rp(&self) -> &PackingRing {
+        &self.ring_rp
+    }
+
+    fn sk_gen(&self, mut rng: StdRng) -> PhantomSk {
+        PhantomSk(RlweSecretKey::sample(
+            self.param().ring_size,
+            self.param().sk_distribution,
+            &mut rng,
+        ))
+    }
+
+    fn sk_ks_gen(&self, mut rng: StdRng) -> PhantomSkKs {
+        PhantomSkKs(LweSecretKey::sample(
+            self.param().lwe_dimension,
+            self.param().lwe_sk_distribution,
+            &mut rng,
+        ))
+    }
+
+    fn pk_share_gen(&self, sk: &PhantomSk, mut rng: StdRng) -> PhantomPkShare {
+        let mut pk = SeededRlwePublicKey::allocate(self.param().ring_size);
+        pk_share_gen(
+            self.ring(),
+            &mut pk,
+            self.param(),
+            &self.crs().fhew(),
+            sk.as_view(),
+            &mut rng,
+        );
+        PhantomPkShare(pk)
+    }
+
+    fn rp_key_share_gen(&self, sk: &PhantomSk, mut rng: StdRng) -> PhantomRpKeyShare {
+        let mut rp_key = RingPackingKeyShareOwned::allocate(self.ring_packing_param());
+        rp_key_share_gen(
+            self.ring_rp(),
+            &mut rp_key,
+            &self.crs().ring_packing(),
+            sk.as_view(),
+            &mut rng,
+        );
+        PhantomRpKeyShare(rp_key)
+    }
+
+    fn bs_key_share_gen(
+        &self,
+        share_idx: usize,
+        sk: &PhantomSk,
+        sk_ks: &PhantomSkKs,
+        mut rng: StdRng,
+    ) -> PhantomBsKeyShare {
+        let mut bs_key_share = FhewBoolMpiKeyShareOwned::allocate(**self.param(), share_idx);
+        bs_key_share_gen(
+            self.ring(),
+            self.mod_ks(),
+            &mut bs_key_share,
+            &self.crs().fhew(),
+            sk.as_view(),
+            self.pk.as_deref().unwrap(),
+            sk_ks.as_view(),
+            &mut rng,
+        );
+        PhantomBsKeyShare(bs_key_share)
+    }
+
+    fn aggregate_pk_shares(&mut self, pk_shares: Vec<PhantomPkShare>) {
+        let mut pk = RlwePublicKey::allocate(self.fhew_param().ring_size);
+        aggregate_pk_shares(
+            self.ring(),
+            &mut pk,
+            &self.crs().fhew(),
+            pk_shares.iter().map(|wrapper| &wrapper.0),
+        );
+        self.set_pk(PhantomPk { pk, pk_shares });
+    }
+
+    fn set_pk(&mut self, pk: PhantomPk) {
+        self.key_id = Some(pk.fingerprint());
+        self.pk = Some(pk);
+    }
+
+    fn key_id(&self) -> PhantomKeyId {
+        self.key_id.unwrap()
+    }
+
+    fn check_key_id(&self, key_id: PhantomKeyId) -> Result<(), PhantomKeyMismatch> {
+        PhantomKeyMismatch::check(self.key_id(), key_id)
+    }
+
+    fn aggregate_rp_key_shares<'a>(
+        &mut self,
+        rp_key_shares: impl IntoIterator<Item = &'a PhantomRpKeyShare>,
+    ) -> PhantomRpKey {
+        let mut rp_key = PhantomRpKey::allocate(self.ring_packing_param());
+        aggregate_rp_key_shares(
+            self.ring_rp(),
+            &mut rp_key,
+            &self.crs().ring_packing(),
+            rp_key_shares.into_iter().map(|wrapper| &wrapper.0),
+        );
+        rp_key
+    }
+
+    fn aggregate_bs_key_shares<'a>(
+        &mut self,
+        bs_key_shares: impl IntoIterator<Item = &'a PhantomBsKeyShare>,
+    ) -> PhantomBsKey {
+        let mut bs_key = PhantomBsKey::allocate(*self.fhew_param());
+        aggregate_bs_key_shares(
+            self.ring(),
+            self.mod_ks(),
+            &mut bs_key,
+            &self.crs().fhew(),
+            bs_key_shares.into_iter().map(|wrapper| &wrapper.0),
+        );
+        bs_key
+    }
+
+    fn batched_pk_encrypt(
+        &self,
+        ms: impl IntoIterator<Item = bool>,
+        rng: &mut StdRng,
+    ) -> PhantomBatchedCt {
+        let mut rng = LweRng::new(
+            StdRng::from_rng(&mut *rng).unwrap(),
+            StdRng::from_rng(rng).unwrap(),
+        );
+        PhantomBatchedCt {
+            ct: FhewBoolBatchedCiphertext::pk_encrypt(
+                self.fhew_param(),
+                self.ring(),
+                self.pk.as_deref().unwrap(),
+                ms,
+                &mut rng,
+            ),
+            key_id: self.key_id(),
+        }
+    }
+
+    /// Returns a decryption share with randomness from `seed`, its error on
+    /// the rest of the ring element being as large as on the packed bits.
+    fn decrypt_share(
+        &self,
+        sk: &PhantomSk,
+        ct: &PhantomPackedCt,
+        seed: <StdRng as SeedableRng>::Seed,
+    ) -> PhantomDecShare {
+        let mut rng = LweRng::new(
+            StdRng::from_hierarchical_seed(seed, &[0]),
+            StdRng::from_hierarchical_seed(seed, &[1]),
+        );
+        let share = ct
+            .ct
+            .decrypt_share(&self.param, self.ring_rp(), sk.as_view(), &mut rng);
+
+        let modulus = self.dec_modulus();
+        let c_s = mul(ct.ct.ct().a(), sk.as_ref(), modulus);
+        let mut rng = StdRng::from_hierarchical_seed(seed, &[2]);
+        let bound = DEC_ERROR_BOUND as i64 / 2;
+        let e = (share.as_ref().len()..c_s.len())
+            .map(|_| rng.gen_range(-bound..=bound))
+            .collect::<Vec<_>>();
+        let rest = add_short(&c_s[share.as_ref().len()..], &e, modulus);
+        PhantomDecShare { share, rest }
+    }
+
+    fn pk_modulus(&self) -> u128 {
+        modulus_value(self.param().modulus)
+    }
+
+    fn dec_modulus(&self) -> u128 {
+        modulus_value(self.ring_packing_param().modulus)
+    }
+
+    fn total_shares(&self) -> usize {
+        self.pk.as_ref().unwrap().pk_shares.len()
+    }
+
+    /// Relations of the public key share of `user_id` and of its decryption
+    /// `share` of `ct`, both in the convention `b = a * s + e`.
+    fn dec_share_statement(
+        &self,
+        ct: &PhantomPackedCt,
+        user_id: usize,
+        share: &PhantomDecShare,
+    ) -> Statement {
+        let pk = self.pk.as_ref().unwrap();
+        Statement {
+            secret_bound: SECRET_BOUND,
+            relations: vec![
+                Relation {
+                    modulus: self.pk_modulus(),
+                    a: pk.pk.ct().a().to_vec(),
+                    b: pk.pk_shares[user_id].ct().b().to_vec(),
+                    error_bound: PK_ERROR_BOUND,
+                },
+                Relation {
+                    modulus: self.dec_modulus(),
+                    a: ct.ct.ct().a().to_vec(),
+                    b: share.to_vec(),
+                    error_bound: DEC_ERROR_BOUND,
+                },
+            ],
+        }
+    }
+
+    fn verify_dec_share(
+        &self,
+        ct: &PhantomPackedCt,
+        dec_share: &PhantomPackedCtDecShare,
+    ) -> Result<(), PhantomDecryptionError> {
+        self.check_key_id(ct.key_id)?;
+        Ok(
+            dec_share.verify(self.total_shares(), ct.fingerprint(), |dec_share| {
+                self.dec_share_statement(ct, dec_share.user_id, &dec_share.share)
+            })?,
+        )
+    }
+
+    fn aggregate_dec_shares(
+        &self,
+        ct: &PhantomPackedCt,
+        dec_shares: &[PhantomPackedCtDecShare],
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.check_key_id(ct.key_id)?;
+        let dec_shares = check_dec_shares(
+            self.total_shares(),
+            ct.fingerprint(),
+            dec_shares,
+            |dec_share| self.dec_share_statement(ct, dec_share.user_id, &dec_share.share),
+        )?;
+        Ok(ct.ct.aggregate_decryption_shares(
+            self.ring_rp(),
+            dec_shares.into_iter().map(|dec_share| &dec_share.share),
+        ))
+    }
+}
+
+fn modulus_value(modulus: Modulus) -> u128 {
+    match modulus {
+        Modulus::PowerOfTwo(bits) => 1 << bits,
+        Modulus::Prime(q) => q as u128,
+    }
+}
+
+macro_rules! wasm_bindgen_wrapper {
+    (@ $outer:ident($inner:ty)) => {
+
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        pub struct $outer($inner);
+
+        impl core::ops::Deref for $outer {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $outer {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<$inner> for $outer {
+            fn from(inner: $inner) -> $outer {
+                $outer(inner)
+            }
+        }
+
+        impl From<$outer> for $inner {
+            fn from(outer: $outer) -> $inner {
+                outer.0
+            }
+        }
+    };
+    ($($outer:ident($inner:ty),)*) => {
+        $(wasm_bindgen_wrapper!(@ $outer($inner));)*
+    }
+}
+
+use wasm_bindgen_wrapper;
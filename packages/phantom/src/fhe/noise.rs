@@ -0,0 +1,179 @@
+//! Noise diagnostics, enabled by the `dev` feature.
+//!
+//! With the seeds of every user, [`PhantomNoiseMeter`] rebuilds the secret key
+//! the aggregated keys are under, the sum of the users' secret keys, and
+//! computes the phase of ciphertexts, their encoded bit plus noise. The
+//! encoding of a bit is estimated as the mean phase of the ciphertexts of that
+//! bit, so a measurement needs ciphertexts of both bits, and decryption fails
+//! once noise reaches half the distance between the two encodings.
+//!
+//! It needs every secret key, so it's only meant for tests and for tuning
+//! parameters.
+
+use super::{PhantomBool, PhantomCt, PhantomPackedCt, PhantomUser};
+use core::{
+    f64::consts::{LN_2, TAU},
+    fmt::{self, Display},
+};
+use itertools::{izip, Itertools};
+use phantom_zone_evaluator::boolean::fhew::prelude::*;
+
+/// Measures noise of ciphertexts with the secret keys of test users.
+pub struct PhantomNoiseMeter {
+    sk: Vec<i64>,
+    modulus: u128,
+    ring_packing_modulus: u128,
+}
+
+impl PhantomNoiseMeter {
+    /// Returns a new [`PhantomNoiseMeter`] with the secret keys of `users`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `users` are not all the users of the parameter.
+    pub fn new(users: &[PhantomUser]) -> Self {
+        let param = users[0].ops.param();
+        assert_eq!(users.len(), param.total_shares);
+        let mut sk = vec![0; param.ring_size];
+        users.iter().for_each(|user| {
+            izip!(&mut sk, user.sk().as_ref()).for_each(|(sk, share)| *sk += share)
+        });
+        Self {
+            sk,
+            modulus: modulus(param.modulus),
+            ring_packing_modulus: modulus(param.ring_packing_modulus.unwrap_or(param.modulus)),
+        }
+    }
+
+    /// Measures noise of [`PhantomBool`]s, such as circuit outputs, encrypting
+    /// `ms`.
+    pub fn measure<'a>(
+        &self,
+        cts: impl IntoIterator<Item = &'a PhantomBool>,
+        ms: impl IntoIterator<Item = bool>,
+    ) -> PhantomNoise {
+        let phases = cts.into_iter().map(|ct| self.lwe_phase(ct.ct()));
+        PhantomNoise::new(izip!(phases, ms))
+    }
+
+    /// Measures noise of a [`PhantomPackedCt`] of `ms` after
+    /// [`PhantomEvaluator::pack`](super::PhantomEvaluator::pack), as seen by
+    /// decryption.
+    pub fn measure_packed(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        ms: impl IntoIterator<Item = bool>,
+    ) -> PhantomNoise {
+        PhantomNoise::new(izip!(self.rlwe_phases(ct_packed), ms))
+    }
+
+    /// Phase `b - <a, s>` of an LWE ciphertext.
+    fn lwe_phase(&self, ct: &PhantomCt) -> f64 {
+        let a_s = izip!(ct.a(), &self.sk)
+            .map(|(a, s)| *a as i128 * *s as i128)
+            .sum::<i128>();
+        fraction(*ct.b() as i128 - a_s, self.modulus)
+    }
+
+    /// Phases of the packed bits, the first coefficients of `b - a * s`.
+    fn rlwe_phases(&self, ct_packed: &PhantomPackedCt) -> Vec<f64> {
+        let (a, b) = (ct_packed.ct.ct().a(), ct_packed.ct.ct().b());
+        let ring_size = self.sk.len();
+        (0..ct_packed.n())
+            .map(|k| {
+                // Coefficient `k` of `a * s` in `Z_q[X] / (X^N + 1)`.
+                let a_s = (0..ring_size)
+                    .map(|i| {
+                        let term = a[i] as i128 * self.sk[(k + ring_size - i) % ring_size] as i128;
+                        if i <= k {
+                            term
+                        } else {
+                            -term
+                        }
+                    })
+                    .sum::<i128>();
+                fraction(b[k] as i128 - a_s, self.ring_packing_modulus)
+            })
+            .collect()
+    }
+}
+
+/// Noise of ciphertexts, in fractions of the modulus.
+#[derive(Clone, Copy, Debug)]
+pub struct PhantomNoise {
+    /// Number of ciphertexts measured.
+    pub samples: usize,
+    /// Standard deviation of noise.
+    pub std_dev: f64,
+    /// Largest noise in absolute value.
+    pub max: f64,
+    /// Noise from which decryption fails.
+    pub margin: f64,
+}
+
+impl PhantomNoise {
+    /// # Panics
+    ///
+    /// Panics if there isn't a phase of each bit.
+    fn new(phases: impl IntoIterator<Item = (f64, bool)>) -> Self {
+        let phases = phases.into_iter().collect_vec();
+        // Circular mean, as phases wrap around the modulus.
+        let [encoding_false, encoding_true] = [false, true].map(|m| {
+            let (sin, cos) = phases
+                .iter()
+                .filter(|(_, bit)| *bit == m)
+                .fold((0., 0.), |(sin, cos), (phase, _)| {
+                    (sin + (TAU * phase).sin(), cos + (TAU * phase).cos())
+                });
+            assert!(sin != 0. || cos != 0., "no ciphertext of {m} to measure");
+            sin.atan2(cos) / TAU
+        });
+        let noises = phases
+            .iter()
+            .map(|(phase, m)| centered(phase - [encoding_false, encoding_true][*m as usize]))
+            .collect_vec();
+        Self {
+            samples: noises.len(),
+            std_dev: (noises.iter().map(|e| e * e).sum::<f64>() / noises.len() as f64).sqrt(),
+            max: noises.iter().fold(0., |max: f64, e| max.max(e.abs())),
+            margin: centered(encoding_true - encoding_false).abs() / 2.,
+        }
+    }
+
+    /// Log2 of the probability a ciphertext fails to decrypt, by the Gaussian
+    /// tail bound `2 exp(-margin^2 / (2 std_dev^2))`.
+    pub fn log2_failure_probability(&self) -> f64 {
+        1. - self.margin.powi(2) / (2. * self.std_dev.powi(2) * LN_2)
+    }
+}
+
+impl Display for PhantomNoise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "std dev 2^{:.1}, max 2^{:.1}, margin 2^{:.1}, failure probability 2^{:.1} over {} ciphertexts",
+            self.std_dev.log2(),
+            self.max.log2(),
+            self.margin.log2(),
+            self.log2_failure_probability(),
+            self.samples,
+        )
+    }
+}
+
+fn modulus(modulus: Modulus) -> u128 {
+    match modulus {
+        Modulus::PowerOfTwo(bits) => 1 << bits,
+        Modulus::Prime(q) => q as u128,
+    }
+}
+
+/// `value` modulo `q`, as a fraction of `q`.
+fn fraction(value: i128, q: u128) -> f64 {
+    value.rem_euclid(q as i128) as f64 / q as f64
+}
+
+/// `x` modulo 1 in `[-0.5, 0.5)`.
+fn centered(x: f64) -> f64 {
+    x - (x + 0.5).floor()
+}
@@ -0,0 +1,213 @@
+//! Typed encrypted values on top of [`PhantomBool`], [`EncBool`] and the
+//! unsigned integers [`EncU8`] and [`EncU16`] of little-endian bits.
+//!
+//! [`Enc`] moves them in and out of ciphertexts, packs them for decryption,
+//! and encodes and decodes their plaintext, so that the server and its clients
+//! agree on the layout of every value. [`EncUint`] also has gate-level
+//! addition, comparisons and multiplexing, for small computations that don't
+//! need a transpiled circuit. Each gate is a bootstrapping, so an addition or a
+//! comparison costs a few of them per bit.
+
+use crate::{PhantomBool, PhantomCt, PhantomEvaluator, PhantomPackedCt};
+use core::{
+    array::from_fn,
+    iter,
+    ops::{Add, Deref, DerefMut},
+    slice,
+};
+
+/// Encrypted [`bool`].
+pub type EncBool = PhantomBool;
+
+/// Encrypted [`u8`].
+pub type EncU8 = EncUint<8>;
+
+/// Encrypted [`u16`].
+pub type EncU16 = EncUint<16>;
+
+/// Encrypted value of [`Enc::BITS`] bits.
+pub trait Enc: Sized {
+    /// Plaintext value.
+    type Plain;
+
+    /// Number of bits of the value.
+    const BITS: usize;
+
+    /// Returns bits of the value, in little-endian.
+    fn bits(&self) -> impl Iterator<Item = &PhantomBool>;
+
+    /// Takes the [`Enc::BITS`] bits of a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self;
+
+    /// Encodes a plaintext into [`Enc::BITS`] bits, in little-endian, such as
+    /// to be encrypted and taken back with [`Enc::from_cts`].
+    fn encode(m: Self::Plain) -> impl Iterator<Item = bool>;
+
+    /// Takes the [`Enc::BITS`] bits of a plaintext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    fn decode(bits: &mut impl Iterator<Item = bool>) -> Self::Plain;
+
+    /// Returns cts of the value, in little-endian.
+    fn cts(&self) -> impl Iterator<Item = &PhantomCt> {
+        self.bits().map(PhantomBool::ct)
+    }
+
+    /// Takes and wraps the [`Enc::BITS`] cts of a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` runs out, or if [`PhantomEvaluator::set_bs_key`] is not
+    /// called yet.
+    fn from_cts(cts: &mut impl Iterator<Item = PhantomCt>, evaluator: &PhantomEvaluator) -> Self {
+        Self::from_bits(&mut cts.map(|ct| evaluator.wrap(ct)))
+    }
+
+    /// Packs the value for decryption, see [`Enc::unpack`].
+    fn pack(&self, evaluator: &PhantomEvaluator) -> PhantomPackedCt {
+        evaluator.pack(self.bits())
+    }
+
+    /// Decodes bits decrypted from [`Enc::pack`], or returns [`None`] if they
+    /// are not [`Enc::BITS`].
+    fn unpack(bits: Vec<bool>) -> Option<Self::Plain> {
+        (bits.len() == Self::BITS).then(|| Self::decode(&mut bits.into_iter()))
+    }
+}
+
+impl Enc for EncBool {
+    type Plain = bool;
+
+    const BITS: usize = 1;
+
+    fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
+        iter::once(self)
+    }
+
+    fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+        bits.next().unwrap()
+    }
+
+    fn encode(m: bool) -> impl Iterator<Item = bool> {
+        iter::once(m)
+    }
+
+    fn decode(bits: &mut impl Iterator<Item = bool>) -> bool {
+        bits.next().unwrap()
+    }
+}
+
+/// Encrypted unsigned integer of `N` little-endian bits.
+#[derive(Clone, Debug)]
+pub struct EncUint<const N: usize>(pub [PhantomBool; N]);
+
+macro_rules! impl_enc_uint {
+    ($($n:literal => $plain:ty),*) => {
+        $(
+            impl Enc for EncUint<$n> {
+                type Plain = $plain;
+
+                const BITS: usize = $n;
+
+                fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
+                    self.0.iter()
+                }
+
+                fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+                    Self(from_fn(|_| bits.next().unwrap()))
+                }
+
+                fn encode(m: $plain) -> impl Iterator<Item = bool> {
+                    (0..$n).map(move |i| (m >> i) & 1 == 1)
+                }
+
+                fn decode(bits: &mut impl Iterator<Item = bool>) -> $plain {
+                    (0..$n).fold(0, |m, i| m | ((bits.next().unwrap() as $plain) << i))
+                }
+            }
+        )*
+    };
+}
+
+impl_enc_uint!(8 => u8, 16 => u16);
+
+impl<const N: usize> EncUint<N> {
+    /// Returns if `self == rhs`.
+    pub fn eq(&self, rhs: &Self) -> EncBool {
+        let ne = (1..N).fold(&self.0[0] ^ &rhs.0[0], |ne, i| {
+            ne | &(&self.0[i] ^ &rhs.0[i])
+        });
+        !&ne
+    }
+
+    /// Returns if `self < rhs`, or with operands swapped if `self > rhs`.
+    pub fn lt(&self, rhs: &Self) -> EncBool {
+        // The most significant differing bit decides, so going from the least
+        // significant bit, a differing bit overrides the lower bits.
+        let lt = &(&self.0[0] ^ &rhs.0[0]) & &rhs.0[0];
+        (1..N).fold(lt, |lt, i| {
+            let (a, b) = (&self.0[i], &rhs.0[i]);
+            &lt ^ &(&(a ^ b) & &(b ^ &lt))
+        })
+    }
+
+    /// Returns `a` if `cond`, or `b` otherwise.
+    pub fn mux(cond: &EncBool, a: &Self, b: &Self) -> Self {
+        Self(from_fn(|i| &b.0[i] ^ &(cond & &(&a.0[i] ^ &b.0[i]))))
+    }
+}
+
+/// Wrapping addition, with a ripple-carry adder.
+impl<const N: usize> Add for &EncUint<N> {
+    type Output = EncUint<N>;
+
+    fn add(self, rhs: Self) -> EncUint<N> {
+        let mut carry: Option<EncBool> = None;
+        EncUint(from_fn(|i| {
+            let (a, b) = (&self.0[i], &rhs.0[i]);
+            let xor = a ^ b;
+            let sum = match &carry {
+                Some(carry) => &xor ^ carry,
+                None => xor.clone(),
+            };
+            // No carry out of the most significant bit.
+            if i + 1 < N {
+                let and = a & b;
+                carry = Some(match &carry {
+                    Some(carry) => and | &(carry & &xor),
+                    None => and,
+                });
+            }
+            sum
+        }))
+    }
+}
+
+impl<const N: usize> Deref for EncUint<N> {
+    type Target = [PhantomBool; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for EncUint<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a EncUint<N> {
+    type Item = &'a PhantomBool;
+    type IntoIter = slice::Iter<'a, PhantomBool>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -1,12 +1,31 @@
-use core::{fmt::Debug, ops::Deref};
-use phantom_zone_evaluator::boolean::fhew::{param::I_4P_40, prelude::*};
+use core::{
+    fmt::{self, Debug, Display},
+    ops::Deref,
+};
+use phantom_zone_evaluator::boolean::fhew::{
+    param::{I_4P_40, I_4P_60},
+    prelude::*,
+};
+use proof::{PhantomDecShareProof, Statement};
 use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-
+use sha2::{Digest, Sha256};
+
+#[cfg(not(feature = "mock"))]
+mod fhe;
+mod int;
+#[cfg(feature = "mock")]
+mod mock;
+mod proof;
 #[cfg(test)]
 pub mod test;
 
+#[cfg(not(feature = "mock"))]
+pub use fhe::*;
+pub use int::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
+
 /// Parameter shared between server and users.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct PhantomParam {
@@ -17,6 +36,7 @@ pub struct PhantomParam {
 }
 
 impl PhantomParam {
+    /// Interactive parameter for 4 users, the default.
     pub const I_4P_40: Self = Self {
         param: I_4P_40,
         ring_packing_modulus: Some(Modulus::Prime(2305843009213554689)),
@@ -26,6 +46,53 @@ impl PhantomParam {
         },
         crs: PhantomCrs::new(*b"0xPARC0xPARC0xPARC0xPARC0xPARC0x"),
     };
+
+    /// Interactive parameter for 4 users with a lower failure probability than
+    /// [`PhantomParam::I_4P_40`], at the cost of slower evaluation.
+    pub const I_4P_60: Self = Self {
+        param: I_4P_60,
+        crs: PhantomCrs::new(*b"0xPARC/I_4P_60/0xPARC/I_4P_60/0x"),
+        ..Self::I_4P_40
+    };
+
+    /// Named parameters, see [`PhantomParam::from_name`].
+    ///
+    /// Each has its own CRS, so keys generated under one can't be mixed with
+    /// another.
+    pub const NAMED: [(&'static str, Self); 2] =
+        [("I_4P_40", Self::I_4P_40), ("I_4P_60", Self::I_4P_60)];
+
+    /// Returns the parameter of [`PhantomParam::NAMED`] called `name`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMED
+            .into_iter()
+            .find_map(|(named, param)| (named == name).then_some(param))
+    }
+
+    /// This parameter with another CRS, such as a fresh one per key
+    /// generation from [`PhantomCrs::from_entropy`].
+    pub const fn with_crs(self, crs: PhantomCrs) -> Self {
+        Self { crs, ..self }
+    }
+
+    pub fn crs(&self) -> &PhantomCrs {
+        &self.crs
+    }
+
+    /// This parameter shared between `total_shares` users.
+    ///
+    /// Noise grows with the number of users, and the failure rate is only
+    /// vetted for the 4 users of the named parameters, so other numbers are
+    /// for tests.
+    pub const fn with_total_shares(self, total_shares: usize) -> Self {
+        Self {
+            param: FhewBoolMpiParam {
+                total_shares,
+                ..self.param
+            },
+            ..self
+        }
+    }
 }
 
 impl Deref for PhantomParam {
@@ -47,542 +114,372 @@ impl PhantomCrs {
     pub fn from_entropy() -> Self {
         Self::new(thread_rng().gen())
     }
+}
 
-    fn fhew(&self) -> FhewBoolMpiCrs<StdRng> {
-        FhewBoolMpiCrs::new(StdRng::from_hierarchical_seed(self.0, &[0]).gen())
-    }
+/// Where a [`PhantomPackedChunk`] sits among the outputs packed by
+/// [`PhantomEvaluator::pack_chunks`].
+///
+/// Outputs are items of the same number of bits, such as cells, and an item
+/// never straddles two chunks, so each chunk decodes on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhantomChunkLayout {
+    /// Bits of each item.
+    pub item_bits: usize,
+    /// Index of the first item of the chunk among all items.
+    pub offset: usize,
+    /// Number of items in the chunk.
+    pub len: usize,
+    /// Number of items in all chunks.
+    pub total: usize,
+}
 
-    fn ring_packing(&self) -> RingPackingCrs<StdRng> {
-        RingPackingCrs::new(StdRng::from_hierarchical_seed(self.0, &[1]).gen())
+impl PhantomChunkLayout {
+    /// Returns the number of bits in the chunk.
+    pub fn bits(&self) -> usize {
+        self.item_bits * self.len
     }
 }
 
-/// Round 1 key share during key generation, containing public key share.
-pub type PhantomRound1Key = PhantomPkShare;
-
-/// Round 2 key share during key generation, containing ring-packing key share
-/// and bootstrapping key share.
+/// Packed ciphertext of a chunk of outputs, decrypted on its own.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct PhantomRound2Key {
-    rp_key_share: PhantomRpKeyShare,
-    bs_key_share: PhantomBsKeyShare,
-}
-
-/// [`PhantomUser`] proivdes necessary functionality to do deterministic key
-/// generation given a seed, encryption, decryption share generation, and
-/// decryption shares aggregation.
-pub struct PhantomUser {
-    ops: PhantomOps,
-    user_id: usize,
-    seed: <StdRng as SeedableRng>::Seed,
+pub struct PhantomPackedChunk {
+    pub ct: PhantomPackedCt,
+    pub layout: PhantomChunkLayout,
 }
 
-impl PhantomUser {
-    /// Returns a new [`PhantomUser`].
-    pub fn new(param: PhantomParam, user_id: usize, seed: Vec<u8>) -> Self {
-        Self {
-            ops: PhantomOps::new(param),
-            user_id,
-            seed: seed.try_into().unwrap(),
-        }
-    }
-
-    /// Returns user id.
-    pub fn user_id(&self) -> usize {
-        self.user_id
-    }
-
-    /// Generates round 1 key.
-    pub fn round_1_key_gen(&self) -> PhantomRound1Key {
-        self.ops
-            .pk_share_gen(&self.sk(), self.deterministic_rng(&[1, 0]))
-    }
-
-    /// Returns if aggregated public key is set or not.
-    pub fn has_pk(&self) -> bool {
-        self.ops.pk.is_some()
-    }
-
-    /// Sets aggregated public key retrieved from server.
-    pub fn set_pk(&mut self, pk: PhantomPk) {
-        self.ops.pk = Some(pk)
-    }
-
-    /// Generates round 2 key.
-    ///
-    /// # Panics
-    ///
-    /// Panics if [`PhantomUser::set_pk`] is not called yet.
-    pub fn round_2_key_gen(&self) -> PhantomRound2Key {
-        PhantomRound2Key {
-            bs_key_share: self.ops.bs_key_share_gen(
-                self.user_id,
-                &self.sk(),
-                &self.sk_ks(),
-                self.deterministic_rng(&[1, 2]),
-            ),
-            rp_key_share: self
-                .ops
-                .rp_key_share_gen(&self.sk(), self.deterministic_rng(&[1, 1])),
-        }
-    }
-
-    /// Encrypts bits in batch.
+impl PhantomEvaluator {
+    /// Packs items of `item_bits` [`PhantomBool`]s each into chunks of at most
+    /// a ring size of bits, the most a [`PhantomPackedCt`] holds, so that large
+    /// outputs are decrypted a chunk at a time.
     ///
     /// # Panics
     ///
-    /// Panics if [`PhantomUser::set_pk`] is not called yet.
-    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
-        self.ops.batched_pk_encrypt(ms)
-    }
-
-    /// Generates decryption share.
-    pub fn decrypt_share(&self, ct_packed: &PhantomPackedCt) -> PhantomPackedCtDecShare {
-        self.ops.decrypt_share(&self.sk(), ct_packed)
-    }
-
-    /// Aggregates decryption share and returns decrypted bits.
-    pub fn aggregate_dec_shares(
+    /// Panics if `item_bits` is 0 or more than the ring size, or doesn't divide
+    /// the number of `cts`, or if [`PhantomEvaluator::set_pk`] or
+    /// [`PhantomEvaluator::set_rp_key`] is not called yet.
+    pub fn pack_chunks<'a>(
         &self,
-        ct_packed: &PhantomPackedCt,
-        dec_shares: Vec<PhantomPackedCtDecShare>,
-    ) -> Vec<bool> {
-        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
-    }
-
-    fn sk(&self) -> PhantomSk {
-        self.ops
-            .sk_gen(StdRng::from_hierarchical_seed(self.seed, &[0, 0]))
-    }
-
-    fn sk_ks(&self) -> PhantomSkKs {
-        self.ops
-            .sk_ks_gen(StdRng::from_hierarchical_seed(self.seed, &[0, 1]))
-    }
-
-    fn deterministic_rng(&self, path: &[usize]) -> StdRng {
-        StdRng::from_hierarchical_seed(self.seed, path)
+        cts: impl IntoIterator<Item = &'a PhantomBool>,
+        item_bits: usize,
+    ) -> Vec<PhantomPackedChunk> {
+        let ring_size = self.param().ring_size;
+        assert!(
+            (1..=ring_size).contains(&item_bits),
+            "item of {item_bits} bits doesn't fit in a ring of size {ring_size}"
+        );
+        let cts = cts.into_iter().collect::<Vec<_>>();
+        assert_eq!(cts.len() % item_bits, 0, "{item_bits} bits per item");
+        let chunk_len = ring_size / item_bits;
+        cts.chunks(chunk_len * item_bits)
+            .enumerate()
+            .map(|(i, chunk)| PhantomPackedChunk {
+                ct: self.pack(chunk.iter().copied()),
+                layout: PhantomChunkLayout {
+                    item_bits,
+                    offset: i * chunk_len,
+                    len: chunk.len() / item_bits,
+                    total: cts.len() / item_bits,
+                },
+            })
+            .collect()
     }
 }
 
-/// [`PhantomEvaluator`] provides necessary functionality to do key aggregation,
-/// compuation on encrypted bits, packing of encrypted bits.
-#[derive(Clone, Debug)]
-pub struct PhantomEvaluator {
-    ops: PhantomOps,
-    rp_key: Option<PhantomRpKey>,
-    rp_key_prep: Option<PhantomRpKeyPrep>,
-    bs_key: Option<PhantomBsKey>,
-    evaluator: Option<Arc<FhewBoolEvaluator<EvaluationRing, KeySwitchMod>>>,
-}
-
-impl PhantomEvaluator {
-    /// Returns a new [`PhantomEvaluator`].
-    pub fn new(param: PhantomParam) -> Self {
-        Self {
-            ops: PhantomOps::new(param),
-            rp_key: None,
-            rp_key_prep: None,
-            bs_key: None,
-            evaluator: None,
-        }
-    }
-
-    /// Returns [`Option`] of aggregated public key.
-    pub fn pk(&self) -> Option<&PhantomPk> {
-        self.ops.pk.as_ref()
-    }
-
-    /// Returns [`Option`] of aggregated ring-packing key.
-    pub fn rp_key(&self) -> Option<&PhantomRpKey> {
-        self.rp_key.as_ref()
-    }
+/// SHA-256 of the bincode serialization of a key or a ciphertext, see
+/// [`Fingerprint`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PhantomFingerprint([u8; 32]);
 
-    /// Returns [`Option`] of aggregated bootstrapping key.
-    pub fn bs_key(&self) -> Option<&PhantomBsKey> {
-        self.bs_key.as_ref()
-    }
-
-    /// Aggregates round 1 keys and sets the aggregated public key.
-    pub fn aggregate_round_1_keys<'a>(
-        &mut self,
-        round_1_keys: impl IntoIterator<Item = &'a PhantomRound1Key>,
-    ) {
-        self.ops.aggregate_pk_shares(round_1_keys);
-    }
-
-    /// Aggregates round 1 keys and sets the aggregated ring-packing key and
-    /// bootstrapping key.
-    pub fn aggregate_round_2_keys<'a>(
-        &mut self,
-        round_2_keys: impl IntoIterator<Item = &'a PhantomRound2Key>,
-    ) {
-        let (rp_key_shares, bs_key_shares): (Vec<_>, Vec<_>) = round_2_keys
-            .into_iter()
-            .map(|key| (&key.rp_key_share, &key.bs_key_share))
-            .unzip();
-        let rp_key = self.ops.aggregate_rp_key_shares(rp_key_shares);
-        self.set_rp_key(rp_key);
-        let bs_key = self.ops.aggregate_bs_key_shares(bs_key_shares);
-        self.set_bs_key(bs_key);
-    }
-
-    // Sets the aggregated public key.
-    pub fn set_pk(&mut self, pk: PhantomPk) {
-        self.ops.set_pk(pk);
-    }
-
-    /// Sets the aggregated ring-packing key.
-    pub fn set_rp_key(&mut self, rp_key: PhantomRpKey) {
-        let mut rp_key_prep = PhantomRpKeyPrep::allocate_eval(
-            self.ops.ring_packing_param(),
-            self.ops.ring_rp().eval_size(),
-        );
-        prepare_rp_key(self.ops.ring_rp(), &mut rp_key_prep, &rp_key);
-        self.rp_key = Some(rp_key);
-        self.rp_key_prep = Some(rp_key_prep);
+impl Display for PhantomFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
     }
+}
 
-    /// Sets the aggregated bootstrapping key.
-    pub fn set_bs_key(&mut self, bs_key: PhantomBsKey) {
-        let ring: EvaluationRing = RingOps::new(self.ops.param.modulus, self.ops.param.ring_size);
-        let mut bs_key_prep = PhantomBsKeyPrep::allocate_eval(**self.ops.param, ring.eval_size());
-        prepare_bs_key(&ring, &mut bs_key_prep, &bs_key);
-        self.bs_key = Some(bs_key);
-        self.evaluator = Some(Arc::new(FhewBoolEvaluator::new(bs_key_prep)));
+impl Debug for PhantomFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PhantomFingerprint({self})")
     }
+}
 
-    /// Encrypts bits in batch.
-    ///
-    /// # Panics
-    ///
-    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
-    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
-        self.ops.batched_pk_encrypt(ms)
+/// Identity of the keys of a key generation, the [`PhantomFingerprint`] of the
+/// aggregated [`PhantomPk`].
+///
+/// [`PhantomBatchedCt`] and [`PhantomPackedCt`] carry the key id they are
+/// produced under, and are rejected by users and evaluators holding another
+/// [`PhantomPk`].
+pub type PhantomKeyId = PhantomFingerprint;
+
+/// Identity of a CRS, its [`PhantomFingerprint`].
+///
+/// [`PhantomRound1Key`] and [`PhantomRound2Key`] carry the CRS id they are
+/// generated under, and are rejected by evaluators with another [`PhantomCrs`].
+pub type PhantomCrsId = PhantomFingerprint;
+
+/// Stable content hash of keys and ciphertexts, for telling apart the keys in
+/// use by different parties.
+pub trait Fingerprint: Serialize {
+    fn fingerprint(&self) -> PhantomFingerprint {
+        let mut hasher = Sha256::new();
+        bincode::serialize_into(&mut hasher, self).unwrap();
+        PhantomFingerprint(hasher.finalize().into())
     }
+}
 
-    /// Wraps a raw ct into [`PhantomBool`] for further computation.
-    ///
-    /// # Panics
-    ///
-    /// Panics if [`PhantomEvaluator::set_bs_key`] is not called yet.
-    pub fn wrap(&self, ct: PhantomCt) -> PhantomBool {
-        PhantomBool::new(Arc::clone(self.evaluator.as_ref().unwrap()), ct)
-    }
+impl Fingerprint for PhantomCrs {}
+impl Fingerprint for PhantomPk {}
+impl Fingerprint for PhantomRpKey {}
+impl Fingerprint for PhantomBsKey {}
+impl Fingerprint for PhantomRound1Key {}
+impl Fingerprint for PhantomRound2Key {}
+impl Fingerprint for PhantomCt {}
+impl Fingerprint for PhantomBatchedCt {}
+impl Fingerprint for PhantomPackedCt {}
+impl Fingerprint for PhantomPackedCtDecShare {}
+
+/// Ciphertext produced under another key than the one in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhantomKeyMismatch {
+    pub expected: PhantomKeyId,
+    pub actual: PhantomKeyId,
+}
 
-    /// Unbatchs ct generated by [`PhantomUser::batched_pk_encrypt`] or
-    /// [`PhantomEvaluator::batched_pk_encrypt`], and wrap them into
-    /// [`PhantomBool`] for further computation.
-    ///
-    /// # Panics
-    ///
-    /// Panics if [`PhantomEvaluator::set_bs_key`] is not called yet.
-    pub fn unbatch(&self, ct_batched: &PhantomBatchedCt) -> Vec<PhantomBool> {
-        ct_batched
-            .extract_all(self.ops.ring())
-            .into_iter()
-            .map(|ct| self.wrap(ct))
-            .collect()
+impl Display for PhantomKeyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ciphertext is under key {}, expected key {}",
+            self.actual, self.expected
+        )
     }
+}
 
-    /// Packs [`PhantomBool`]s into [`PhantomPackedCt`] for decryption.
-    ///
-    /// # Panics
-    ///
-    /// Panics if [`PhantomEvaluator::set_rp_key`] is not called yet.
-    pub fn pack<'a>(&self, cts: impl IntoIterator<Item = &'a PhantomBool>) -> PhantomPackedCt {
-        PhantomPackedCt(FhewBoolPackedCiphertext::pack_ms(
-            self.ops.ring(),
-            self.ops.ring_rp(),
-            self.rp_key_prep.as_ref().unwrap(),
-            cts.into_iter().map(PhantomBool::ct),
-        ))
-    }
+impl std::error::Error for PhantomKeyMismatch {}
 
-    /// Aggregates decryption share and returns decrypted bits.
-    pub fn aggregate_dec_shares(
-        &self,
-        ct_packed: &PhantomPackedCt,
-        dec_shares: Vec<PhantomPackedCtDecShare>,
-    ) -> Vec<bool> {
-        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
+impl PhantomKeyMismatch {
+    fn check(expected: PhantomKeyId, actual: PhantomKeyId) -> Result<(), Self> {
+        if actual != expected {
+            return Err(Self { expected, actual });
+        }
+        Ok(())
     }
 }
 
-type Ring = NativeRing;
-
-type EvaluationRing = NoisyNativeRing;
-
-type KeySwitchMod = NonNativePowerOfTwo;
-
-type PackingRing = PrimeRing;
-
-pub type PhantomRpKey = RingPackingKeyOwned<Elem<PackingRing>>;
-
-pub type PhantomRpKeyPrep = RingPackingKeyOwned<<PackingRing as RingOps>::EvalPrep>;
-
-pub type PhantomBsKey = FhewBoolKeyOwned<Elem<Ring>, Elem<KeySwitchMod>>;
-
-pub type PhantomBsKeyPrep =
-    FhewBoolKeyOwned<<EvaluationRing as RingOps>::EvalPrep, Elem<KeySwitchMod>>;
-
-pub type PhantomCt = FhewBoolCiphertextOwned<Elem<Ring>>;
-
-pub type PhantomBool = FheBool<Arc<FhewBoolEvaluator<EvaluationRing, KeySwitchMod>>>;
-
-wasm_bindgen_wrapper!(
-    PhantomSk(RlweSecretKeyOwned<i64>),
-    PhantomSkKs(LweSecretKeyOwned<i64>),
-    PhantomPkShare(SeededRlwePublicKeyOwned<Elem<Ring>>),
-    PhantomRpKeyShare(RingPackingKeyShareOwned<Elem<PackingRing>>),
-    PhantomBsKeyShare(FhewBoolMpiKeyShareOwned<Elem<Ring>, Elem<KeySwitchMod>>),
-    PhantomPk(RlwePublicKeyOwned<Elem<Ring>>),
-    PhantomBatchedCt(FhewBoolBatchedCiphertextOwned<Elem<Ring>>),
-    PhantomPackedCt(FhewBoolPackedCiphertextOwned<Elem<PackingRing>>),
-    PhantomPackedCtDecShare(RlweDecryptionShareListOwned<Elem<Ring>>),
-);
+/// Key share generated under another CRS than the one in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhantomCrsMismatch {
+    pub expected: PhantomCrsId,
+    pub actual: PhantomCrsId,
+}
 
-impl PhantomBatchedCt {
-    pub fn n(&self) -> usize {
-        self.0.n()
+impl Display for PhantomCrsMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "key share is under crs {}, expected crs {}",
+            self.actual, self.expected
+        )
     }
 }
 
-impl PhantomPackedCt {
-    pub fn n(&self) -> usize {
-        self.0.n()
+impl std::error::Error for PhantomCrsMismatch {}
+
+impl PhantomCrsMismatch {
+    fn check(expected: PhantomCrsId, actual: PhantomCrsId) -> Result<(), Self> {
+        if actual != expected {
+            return Err(Self { expected, actual });
+        }
+        Ok(())
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct PhantomOps {
-    param: PhantomParam,
-    ring: NativeRing,
-    mod_ks: NonNativePowerOfTwo,
-    ring_rp: PrimeRing,
-    pk: Option<PhantomPk>,
+/// Decryption share of a user, with a proof that it is computed with the
+/// secret key of the user's public key share.
+///
+/// The proof is bound to the user id and the [`PhantomFingerprint`] of the
+/// packed ciphertext, so a share can't be passed off as another user's or
+/// replayed for another ciphertext, and is checked against the user's public
+/// key share in the [`PhantomPk`], so only the holder of its secret key can
+/// produce a share that verifies. See the `proof` module for what it proves.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomPackedCtDecShare {
+    user_id: usize,
+    ct_id: PhantomFingerprint,
+    share: PhantomDecShare,
+    proof: PhantomDecShareProof,
 }
 
-impl PhantomOps {
-    pub fn new(param: PhantomParam) -> Self {
+impl PhantomPackedCtDecShare {
+    /// Proves `share` of the ciphertext `ct_id` with the secret key `sk`,
+    /// `statement` being the relations of `share` and of the user's public key
+    /// share.
+    fn prove(
+        user_id: usize,
+        ct_id: PhantomFingerprint,
+        share: PhantomDecShare,
+        statement: &Statement,
+        sk: &[i64],
+    ) -> Self {
+        let proof = PhantomDecShareProof::prove(&proof_context(user_id, ct_id), statement, sk);
         Self {
-            param,
-            ring: RingOps::new(param.modulus, param.ring_size),
-            mod_ks: ModulusOps::new(param.lwe_modulus),
-            ring_rp: RingOps::new(param.ring_packing_modulus.unwrap(), param.ring_size),
-            pk: None,
+            user_id,
+            ct_id,
+            share,
+            proof,
         }
     }
 
-    pub fn param(&self) -> &PhantomParam {
-        &self.param
-    }
-
-    fn crs(&self) -> &PhantomCrs {
-        &self.param.crs
+    /// Returns the id of the user the share claims to be from.
+    pub fn user_id(&self) -> usize {
+        self.user_id
     }
 
-    fn fhew_param(&self) -> &FhewBoolParam {
-        self.param()
+    /// Returns the [`PhantomFingerprint`] of the packed ciphertext the share
+    /// is for.
+    pub fn ct_id(&self) -> PhantomFingerprint {
+        self.ct_id
     }
 
-    fn ring_packing_param(&self) -> RingPackingParam {
-        RingPackingParam {
-            modulus: self
-                .param()
-                .ring_packing_modulus
-                .unwrap_or_else(|| self.param().modulus),
-            ring_size: self.param().ring_size,
-            sk_distribution: self.param().sk_distribution,
-            noise_distribution: self.param().noise_distribution,
-            auto_decomposition_param: self.param().ring_packing_auto_decomposition_param,
+    /// Verifies the share is of the ciphertext `ct_id` by one of
+    /// `total_shares` users, with `statement` returning the relations of the
+    /// share and of the user's public key share.
+    fn verify(
+        &self,
+        total_shares: usize,
+        ct_id: PhantomFingerprint,
+        statement: impl FnOnce(&Self) -> Statement,
+    ) -> Result<(), PhantomDecShareError> {
+        let user_id = self.user_id;
+        if user_id >= total_shares {
+            return Err(PhantomDecShareError::UnknownUser { user_id });
         }
+        if self.ct_id != ct_id {
+            return Err(PhantomDecShareError::CtMismatch {
+                user_id,
+                expected: ct_id,
+                actual: self.ct_id,
+            });
+        }
+        if !self
+            .proof
+            .verify(&proof_context(user_id, ct_id), &statement(self))
+        {
+            return Err(PhantomDecShareError::BadProof { user_id });
+        }
+        Ok(())
     }
+}
 
-    fn ring(&self) -> &Ring {
-        &self.ring
-    }
-
-    fn mod_ks(&self) -> &KeySwitchMod {
-        &self.mod_ks
-    }
-
-    fn ring_rp(&self) -> &PackingRing {
-        &self.ring_rp
-    }
-
-    fn sk_gen(&self, mut rng: StdRng) -> PhantomSk {
-        PhantomSk(RlweSecretKey::sample(
-            self.param().ring_size,
-            self.param().sk_distribution,
-            &mut rng,
-        ))
-    }
-
-    fn sk_ks_gen(&self, mut rng: StdRng) -> PhantomSkKs {
-        PhantomSkKs(LweSecretKey::sample(
-            self.param().lwe_dimension,
-            self.param().lwe_sk_distribution,
-            &mut rng,
-        ))
-    }
+/// What a [`PhantomDecShareProof`] is bound to besides its statement.
+fn proof_context(user_id: usize, ct_id: PhantomFingerprint) -> Vec<u8> {
+    bincode::serialize(&(user_id as u64, ct_id)).unwrap()
+}
 
-    fn pk_share_gen(&self, sk: &PhantomSk, mut rng: StdRng) -> PhantomPkShare {
-        let mut pk = SeededRlwePublicKey::allocate(self.param().ring_size);
-        pk_share_gen(
-            self.ring(),
-            &mut pk,
-            self.param(),
-            &self.crs().fhew(),
-            sk.as_view(),
-            &mut rng,
-        );
-        PhantomPkShare(pk)
+/// Verifies decryption shares of the ciphertext `ct_id`, and that there is
+/// exactly one per user, returning the shares to aggregate.
+fn check_dec_shares(
+    total_shares: usize,
+    ct_id: PhantomFingerprint,
+    dec_shares: &[PhantomPackedCtDecShare],
+    statement: impl Fn(&PhantomPackedCtDecShare) -> Statement,
+) -> Result<Vec<&PhantomDecShare>, PhantomDecShareError> {
+    let mut submitted = vec![false; total_shares];
+    for dec_share in dec_shares {
+        dec_share.verify(total_shares, ct_id, &statement)?;
+        let user_id = dec_share.user_id;
+        if std::mem::replace(&mut submitted[user_id], true) {
+            return Err(PhantomDecShareError::Duplicate { user_id });
+        }
     }
-
-    fn rp_key_share_gen(&self, sk: &PhantomSk, mut rng: StdRng) -> PhantomRpKeyShare {
-        let mut rp_key = RingPackingKeyShareOwned::allocate(self.ring_packing_param());
-        rp_key_share_gen(
-            self.ring_rp(),
-            &mut rp_key,
-            &self.crs().ring_packing(),
-            sk.as_view(),
-            &mut rng,
-        );
-        PhantomRpKeyShare(rp_key)
+    if let Some(user_id) = submitted.iter().position(|submitted| !submitted) {
+        return Err(PhantomDecShareError::Missing { user_id });
     }
+    Ok(dec_shares
+        .iter()
+        .map(|dec_share| &dec_share.share)
+        .collect())
+}
 
-    fn bs_key_share_gen(
-        &self,
-        share_idx: usize,
-        sk: &PhantomSk,
-        sk_ks: &PhantomSkKs,
-        mut rng: StdRng,
-    ) -> PhantomBsKeyShare {
-        let mut bs_key_share = FhewBoolMpiKeyShareOwned::allocate(**self.param(), share_idx);
-        bs_key_share_gen(
-            self.ring(),
-            self.mod_ks(),
-            &mut bs_key_share,
-            &self.crs().fhew(),
-            sk.as_view(),
-            self.pk.as_deref().unwrap(),
-            sk_ks.as_view(),
-            &mut rng,
-        );
-        PhantomBsKeyShare(bs_key_share)
-    }
+/// Decryption share rejected, naming the user at fault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhantomDecShareError {
+    /// Share of a user id out of the key's users.
+    UnknownUser { user_id: usize },
+    /// Share of another ciphertext.
+    CtMismatch {
+        user_id: usize,
+        expected: PhantomFingerprint,
+        actual: PhantomFingerprint,
+    },
+    /// Proof doesn't verify against the user's public key share.
+    BadProof { user_id: usize },
+    /// Second share of the same user.
+    Duplicate { user_id: usize },
+    /// No share of the user.
+    Missing { user_id: usize },
+}
 
-    fn aggregate_pk_shares<'a>(&mut self, pk_shares: impl IntoIterator<Item = &'a PhantomPkShare>) {
-        let mut pk = RlwePublicKey::allocate(self.fhew_param().ring_size);
-        aggregate_pk_shares(
-            self.ring(),
-            &mut pk,
-            &self.crs().fhew(),
-            pk_shares.into_iter().map(|wrapper| &wrapper.0),
-        );
-        self.set_pk(PhantomPk(pk));
+impl PhantomDecShareError {
+    /// Returns the id of the user at fault.
+    pub fn user_id(&self) -> usize {
+        match *self {
+            Self::UnknownUser { user_id }
+            | Self::CtMismatch { user_id, .. }
+            | Self::BadProof { user_id }
+            | Self::Duplicate { user_id }
+            | Self::Missing { user_id } => user_id,
+        }
     }
+}
 
-    fn set_pk(&mut self, pk: PhantomPk) {
-        self.pk = Some(pk);
+impl Display for PhantomDecShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let user_id = self.user_id();
+        match self {
+            Self::UnknownUser { .. } => write!(f, "decryption share of unknown user {user_id}"),
+            Self::CtMismatch {
+                expected, actual, ..
+            } => write!(
+                f,
+                "decryption share of user {user_id} is for ciphertext {actual}, expected ciphertext {expected}"
+            ),
+            Self::BadProof { .. } => {
+                write!(f, "decryption share of user {user_id} has an invalid proof")
+            }
+            Self::Duplicate { .. } => write!(f, "duplicate decryption share of user {user_id}"),
+            Self::Missing { .. } => write!(f, "missing decryption share of user {user_id}"),
+        }
     }
+}
 
-    fn aggregate_rp_key_shares<'a>(
-        &mut self,
-        rp_key_shares: impl IntoIterator<Item = &'a PhantomRpKeyShare>,
-    ) -> PhantomRpKey {
-        let mut rp_key = PhantomRpKey::allocate(self.ring_packing_param());
-        aggregate_rp_key_shares(
-            self.ring_rp(),
-            &mut rp_key,
-            &self.crs().ring_packing(),
-            rp_key_shares.into_iter().map(|wrapper| &wrapper.0),
-        );
-        rp_key
-    }
+impl std::error::Error for PhantomDecShareError {}
 
-    fn aggregate_bs_key_shares<'a>(
-        &mut self,
-        bs_key_shares: impl IntoIterator<Item = &'a PhantomBsKeyShare>,
-    ) -> PhantomBsKey {
-        let mut bs_key = PhantomBsKey::allocate(*self.fhew_param());
-        aggregate_bs_key_shares(
-            self.ring(),
-            self.mod_ks(),
-            &mut bs_key,
-            &self.crs().fhew(),
-            bs_key_shares.into_iter().map(|wrapper| &wrapper.0),
-        );
-        bs_key
-    }
+/// Decryption of a ciphertext under another key, or with a rejected share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhantomDecryptionError {
+    KeyMismatch(PhantomKeyMismatch),
+    DecShare(PhantomDecShareError),
+}
 
-    fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
-        PhantomBatchedCt(FhewBoolBatchedCiphertext::pk_encrypt(
-            self.fhew_param(),
-            self.ring(),
-            self.pk.as_deref().unwrap(),
-            ms,
-            &mut LweRng::new(StdRng::from_entropy(), StdRng::from_entropy()),
-        ))
+impl Display for PhantomDecryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeyMismatch(err) => Display::fmt(err, f),
+            Self::DecShare(err) => Display::fmt(err, f),
+        }
     }
+}
 
-    fn decrypt_share(&self, sk: &PhantomSk, ct: &PhantomPackedCt) -> PhantomPackedCtDecShare {
-        PhantomPackedCtDecShare(ct.decrypt_share(
-            &self.param,
-            self.ring_rp(),
-            sk.as_view(),
-            &mut LweRng::new(StdRng::from_entropy(), StdRng::from_entropy()),
-        ))
-    }
+impl std::error::Error for PhantomDecryptionError {}
 
-    fn aggregate_dec_shares<'a>(
-        &self,
-        ct: &PhantomPackedCt,
-        dec_shares: impl IntoIterator<Item = &'a PhantomPackedCtDecShare>,
-    ) -> Vec<bool> {
-        ct.aggregate_decryption_shares(
-            self.ring_rp(),
-            dec_shares.into_iter().map(|wrapper| &wrapper.0),
-        )
+impl From<PhantomKeyMismatch> for PhantomDecryptionError {
+    fn from(err: PhantomKeyMismatch) -> Self {
+        Self::KeyMismatch(err)
     }
 }
 
-macro_rules! wasm_bindgen_wrapper {
-    (@ $outer:ident($inner:ty)) => {
-
-        #[derive(Clone, Debug, Serialize, Deserialize)]
-        pub struct $outer($inner);
-
-        impl core::ops::Deref for $outer {
-            type Target = $inner;
-
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
-
-        impl core::ops::DerefMut for $outer {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
-
-        impl From<$inner> for $outer {
-            fn from(inner: $inner) -> $outer {
-                $outer(inner)
-            }
-        }
-
-        impl From<$outer> for $inner {
-            fn from(outer: $outer) -> $inner {
-                outer.0
-            }
-        }
-    };
-    ($($outer:ident($inner:ty),)*) => {
-        $(wasm_bindgen_wrapper!(@ $outer($inner));)*
+impl From<PhantomDecShareError> for PhantomDecryptionError {
+    fn from(err: PhantomDecShareError) -> Self {
+        Self::DecShare(err)
     }
 }
-
-use wasm_bindgen_wrapper;
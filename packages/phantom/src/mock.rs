@@ -0,0 +1,547 @@
+//! Cleartext backend, enabled by the `mock` feature.
+//!
+//! It has the API of the FHE backend, but ciphertexts hold their bits in the
+//! clear and the bootstrapping and ring-packing keys are placeholders, so key
+//! generation is instant and circuits evaluate on plain booleans with
+//! `phantom-zone`'s `MockBoolEvaluator`. Key ids, CRS ids and decryption share
+//! proofs are still checked as by the FHE backend, so protocol mistakes show
+//! up in tests: public key shares and decryption shares are those of a toy
+//! RLWE over a ring of size [`RING_SIZE`], decryption shares being of a mask
+//! derived from the ciphertext's fingerprint.
+//!
+//! It provides no privacy at all, and is only meant for tests and local
+//! development.
+
+use crate::{
+    check_dec_shares,
+    proof::{add_short, mul, Relation, Statement},
+    Fingerprint, PhantomCrs, PhantomCrsId, PhantomCrsMismatch, PhantomDecryptionError,
+    PhantomFingerprint, PhantomKeyId, PhantomKeyMismatch, PhantomPackedCtDecShare, PhantomParam,
+};
+use phantom_zone_evaluator::boolean::{dev::MockBoolEvaluator, FheBool};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// Ring size of the toy RLWE.
+const RING_SIZE: usize = 256;
+
+/// Modulus of public key shares.
+const PK_MODULUS: u128 = 1 << 64;
+
+/// Modulus of decryption shares, prime as the FHE backend's ring-packing
+/// modulus.
+const DEC_MODULUS: u128 = (1 << 61) - 1;
+
+/// Bound of the ternary secret keys.
+const SECRET_BOUND: u64 = 1;
+
+const PK_ERROR_BOUND: u64 = 8;
+
+const DEC_ERROR_BOUND: u64 = 1 << 20;
+
+/// Round 1 key share during key generation, containing public key share.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomRound1Key {
+    pk_share: Vec<u64>,
+    crs_id: PhantomCrsId,
+}
+
+impl PhantomRound1Key {
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.crs_id
+    }
+}
+
+/// Round 2 key share during key generation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomRound2Key {
+    crs_id: PhantomCrsId,
+}
+
+impl PhantomRound2Key {
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.crs_id
+    }
+}
+
+/// [`PhantomUser`] with the API of the FHE backend, see the [module
+/// documentation](self).
+pub struct PhantomUser {
+    ops: PhantomOps,
+    user_id: usize,
+    seed: [u8; 32],
+}
+
+impl PhantomUser {
+    /// Returns a new [`PhantomUser`].
+    pub fn new(param: PhantomParam, user_id: usize, seed: Vec<u8>) -> Self {
+        Self {
+            ops: PhantomOps::new(param),
+            user_id,
+            seed: seed.try_into().unwrap(),
+        }
+    }
+
+    /// Returns user id.
+    pub fn user_id(&self) -> usize {
+        self.user_id
+    }
+
+    /// Generates round 1 key.
+    pub fn round_1_key_gen(&self) -> PhantomRound1Key {
+        let a = self.ops.param.crs().pk_mask();
+        let e = sample_error(self.rng(b"pk_error"), PK_ERROR_BOUND);
+        PhantomRound1Key {
+            pk_share: add_short(&mul(&a, &self.sk(), PK_MODULUS), &e, PK_MODULUS),
+            crs_id: self.ops.crs_id(),
+        }
+    }
+
+    /// Returns if aggregated public key is set or not.
+    pub fn has_pk(&self) -> bool {
+        self.ops.pk.is_some()
+    }
+
+    /// Sets aggregated public key retrieved from server.
+    pub fn set_pk(&mut self, pk: PhantomPk) {
+        self.ops.set_pk(pk)
+    }
+
+    /// Generates round 2 key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn round_2_key_gen(&self) -> PhantomRound2Key {
+        assert!(self.has_pk());
+        PhantomRound2Key {
+            crs_id: self.ops.crs_id(),
+        }
+    }
+
+    /// Encrypts bits in batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
+        self.ops.batched_pk_encrypt(ms)
+    }
+
+    /// Generates decryption share, with a proof that it is computed with the
+    /// secret key of the user's public key share.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn decrypt_share(
+        &self,
+        ct_packed: &PhantomPackedCt,
+    ) -> Result<PhantomPackedCtDecShare, PhantomKeyMismatch> {
+        self.ops.check_key_id(ct_packed.key_id)?;
+        let ct_id = ct_packed.fingerprint();
+        let sk = self.sk();
+        let e = sample_error(self.rng(&ct_id.0), DEC_ERROR_BOUND);
+        let share = PhantomDecShare(add_short(
+            &mul(&ct_mask(ct_id), &sk, DEC_MODULUS),
+            &e,
+            DEC_MODULUS,
+        ));
+        let statement = self.ops.dec_share_statement(ct_id, self.user_id, &share);
+        Ok(PhantomPackedCtDecShare::prove(
+            self.user_id,
+            ct_id,
+            share,
+            &statement,
+            &sk,
+        ))
+    }
+
+    /// Aggregates decryption share and returns decrypted bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomUser::set_pk`] is not called yet.
+    pub fn aggregate_dec_shares(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_shares: Vec<PhantomPackedCtDecShare>,
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
+    }
+
+    /// Ternary secret key.
+    fn sk(&self) -> Vec<i64> {
+        let mut rng = self.rng(b"sk");
+        (0..RING_SIZE).map(|_| rng.gen_range(-1..=1)).collect()
+    }
+
+    /// Rng of the seed for `purpose`.
+    fn rng(&self, purpose: &[u8]) -> StdRng {
+        let mut hasher = Sha256::new();
+        hasher.update(self.seed);
+        hasher.update(purpose);
+        StdRng::from_seed(hasher.finalize().into())
+    }
+}
+
+/// [`PhantomEvaluator`] with the API of the FHE backend, see the [module
+/// documentation](self).
+#[derive(Clone, Debug)]
+pub struct PhantomEvaluator {
+    ops: PhantomOps,
+    rp_key: Option<PhantomRpKey>,
+    bs_key: Option<PhantomBsKey>,
+    evaluator: Option<Arc<MockBoolEvaluator>>,
+}
+
+impl PhantomEvaluator {
+    /// Returns a new [`PhantomEvaluator`].
+    pub fn new(param: PhantomParam) -> Self {
+        Self {
+            ops: PhantomOps::new(param),
+            rp_key: None,
+            bs_key: None,
+            evaluator: None,
+        }
+    }
+
+    /// Returns the parameter, with the CRS key shares must be generated under.
+    pub fn param(&self) -> &PhantomParam {
+        &self.ops.param
+    }
+
+    /// Returns [`Option`] of aggregated public key.
+    pub fn pk(&self) -> Option<&PhantomPk> {
+        self.ops.pk.as_ref()
+    }
+
+    /// Returns [`Option`] of aggregated ring-packing key.
+    pub fn rp_key(&self) -> Option<&PhantomRpKey> {
+        self.rp_key.as_ref()
+    }
+
+    /// Returns [`Option`] of aggregated bootstrapping key.
+    pub fn bs_key(&self) -> Option<&PhantomBsKey> {
+        self.bs_key.as_ref()
+    }
+
+    /// Returns the id of the CRS key shares must be generated under.
+    pub fn crs_id(&self) -> PhantomCrsId {
+        self.ops.crs_id()
+    }
+
+    /// Returns an error if a key share is generated under another CRS.
+    pub fn check_crs_id(&self, crs_id: PhantomCrsId) -> Result<(), PhantomCrsMismatch> {
+        self.ops.check_crs_id(crs_id)
+    }
+
+    /// Aggregates round 1 keys and sets the aggregated public key.
+    pub fn aggregate_round_1_keys<'a>(
+        &mut self,
+        round_1_keys: impl IntoIterator<Item = &'a PhantomRound1Key>,
+    ) -> Result<(), PhantomCrsMismatch> {
+        let pk_shares = round_1_keys
+            .into_iter()
+            .map(|key| {
+                self.check_crs_id(key.crs_id)?;
+                Ok(key.pk_share.clone())
+            })
+            .collect::<Result<_, _>>()?;
+        self.set_pk(PhantomPk {
+            a: self.ops.param.crs().pk_mask(),
+            pk_shares,
+        });
+        Ok(())
+    }
+
+    /// Aggregates round 1 keys and sets the aggregated ring-packing key and
+    /// bootstrapping key.
+    pub fn aggregate_round_2_keys<'a>(
+        &mut self,
+        round_2_keys: impl IntoIterator<Item = &'a PhantomRound2Key>,
+    ) -> Result<(), PhantomCrsMismatch> {
+        round_2_keys
+            .into_iter()
+            .try_for_each(|key| self.check_crs_id(key.crs_id))?;
+        self.set_rp_key(PhantomRpKey);
+        self.set_bs_key(PhantomBsKey);
+        Ok(())
+    }
+
+    // Sets the aggregated public key.
+    pub fn set_pk(&mut self, pk: PhantomPk) {
+        self.ops.set_pk(pk);
+    }
+
+    /// Sets the aggregated ring-packing key.
+    pub fn set_rp_key(&mut self, rp_key: PhantomRpKey) {
+        self.rp_key = Some(rp_key);
+    }
+
+    /// Sets the aggregated bootstrapping key.
+    pub fn set_bs_key(&mut self, bs_key: PhantomBsKey) {
+        self.bs_key = Some(bs_key);
+        self.evaluator = Some(Arc::new(MockBoolEvaluator));
+    }
+
+    /// Encrypts bits in batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
+        self.ops.batched_pk_encrypt(ms)
+    }
+
+    /// Wraps a raw ct into [`PhantomBool`] for further computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_bs_key`] is not called yet.
+    pub fn wrap(&self, ct: PhantomCt) -> PhantomBool {
+        PhantomBool::new(Arc::clone(self.evaluator.as_ref().unwrap()), ct)
+    }
+
+    /// Unbatchs ct generated by [`PhantomUser::batched_pk_encrypt`] or
+    /// [`PhantomEvaluator::batched_pk_encrypt`], and wrap them into
+    /// [`PhantomBool`] for further computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] or [`PhantomEvaluator::set_bs_key`]
+    /// is not called yet.
+    pub fn unbatch(
+        &self,
+        ct_batched: &PhantomBatchedCt,
+    ) -> Result<Vec<PhantomBool>, PhantomKeyMismatch> {
+        self.ops.check_key_id(ct_batched.key_id)?;
+        Ok(ct_batched.bits.iter().map(|bit| self.wrap(*bit)).collect())
+    }
+
+    /// Packs [`PhantomBool`]s into [`PhantomPackedCt`] for decryption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] or [`PhantomEvaluator::set_rp_key`]
+    /// is not called yet.
+    pub fn pack<'a>(&self, cts: impl IntoIterator<Item = &'a PhantomBool>) -> PhantomPackedCt {
+        assert!(self.rp_key.is_some());
+        PhantomPackedCt {
+            bits: cts.into_iter().map(|ct| *ct.ct()).collect(),
+            key_id: self.ops.key_id(),
+        }
+    }
+
+    /// Verifies a decryption share of `ct_packed`, see
+    /// [`PhantomPackedCtDecShare`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn verify_dec_share(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_share: &PhantomPackedCtDecShare,
+    ) -> Result<(), PhantomDecryptionError> {
+        self.ops.verify_dec_share(ct_packed, dec_share)
+    }
+
+    /// Aggregates decryption share and returns decrypted bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PhantomEvaluator::set_pk`] is not called yet.
+    pub fn aggregate_dec_shares(
+        &self,
+        ct_packed: &PhantomPackedCt,
+        dec_shares: Vec<PhantomPackedCtDecShare>,
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.ops.aggregate_dec_shares(ct_packed, &dec_shares)
+    }
+}
+
+/// Aggregated public key, the mask of the CRS and the users' public key shares
+/// in order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomPk {
+    a: Vec<u64>,
+    pk_shares: Vec<Vec<u64>>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PhantomRpKey;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PhantomBsKey;
+
+pub type PhantomCt = bool;
+
+pub type PhantomBool = FheBool<Arc<MockBoolEvaluator>>;
+
+/// Decryption share of the mask of a ciphertext, see the [module
+/// documentation](self).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomDecShare(Vec<u64>);
+
+/// Batched ciphertext, tagged with the key it is encrypted under.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomBatchedCt {
+    bits: Vec<bool>,
+    key_id: PhantomKeyId,
+}
+
+impl PhantomBatchedCt {
+    pub fn n(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn key_id(&self) -> PhantomKeyId {
+        self.key_id
+    }
+}
+
+/// Packed ciphertext, tagged with the key it is to be decrypted under.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomPackedCt {
+    bits: Vec<bool>,
+    key_id: PhantomKeyId,
+}
+
+impl PhantomPackedCt {
+    pub fn n(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn key_id(&self) -> PhantomKeyId {
+        self.key_id
+    }
+}
+
+impl PhantomCrs {
+    /// Mask of the public key shares.
+    fn pk_mask(&self) -> Vec<u64> {
+        let mut rng = StdRng::from_seed(self.0);
+        (0..RING_SIZE).map(|_| rng.gen()).collect()
+    }
+}
+
+/// Mask of the ciphertext `ct_id` decryption shares are of.
+fn ct_mask(ct_id: PhantomFingerprint) -> Vec<u64> {
+    let mut rng = StdRng::from_seed(ct_id.0);
+    (0..RING_SIZE)
+        .map(|_| rng.gen_range(0..DEC_MODULUS as u64))
+        .collect()
+}
+
+fn sample_error(mut rng: StdRng, bound: u64) -> Vec<i64> {
+    let bound = bound as i64;
+    (0..RING_SIZE)
+        .map(|_| rng.gen_range(-bound..=bound))
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+struct PhantomOps {
+    param: PhantomParam,
+    pk: Option<PhantomPk>,
+    key_id: Option<PhantomKeyId>,
+}
+
+impl PhantomOps {
+    fn new(param: PhantomParam) -> Self {
+        Self {
+            param,
+            pk: None,
+            key_id: None,
+        }
+    }
+
+    fn crs_id(&self) -> PhantomCrsId {
+        self.param.crs().fingerprint()
+    }
+
+    fn check_crs_id(&self, crs_id: PhantomCrsId) -> Result<(), PhantomCrsMismatch> {
+        PhantomCrsMismatch::check(self.crs_id(), crs_id)
+    }
+
+    fn set_pk(&mut self, pk: PhantomPk) {
+        self.key_id = Some(pk.fingerprint());
+        self.pk = Some(pk);
+    }
+
+    fn key_id(&self) -> PhantomKeyId {
+        self.key_id.unwrap()
+    }
+
+    fn check_key_id(&self, key_id: PhantomKeyId) -> Result<(), PhantomKeyMismatch> {
+        PhantomKeyMismatch::check(self.key_id(), key_id)
+    }
+
+    fn batched_pk_encrypt(&self, ms: impl IntoIterator<Item = bool>) -> PhantomBatchedCt {
+        PhantomBatchedCt {
+            bits: ms.into_iter().collect(),
+            key_id: self.key_id(),
+        }
+    }
+
+    fn total_shares(&self) -> usize {
+        self.pk.as_ref().unwrap().pk_shares.len()
+    }
+
+    /// Relations of the public key share of `user_id` and of its decryption
+    /// `share` of the ciphertext `ct_id`.
+    fn dec_share_statement(
+        &self,
+        ct_id: PhantomFingerprint,
+        user_id: usize,
+        share: &PhantomDecShare,
+    ) -> Statement {
+        let pk = self.pk.as_ref().unwrap();
+        Statement {
+            secret_bound: SECRET_BOUND,
+            relations: vec![
+                Relation {
+                    modulus: PK_MODULUS,
+                    a: pk.a.clone(),
+                    b: pk.pk_shares[user_id].clone(),
+                    error_bound: PK_ERROR_BOUND,
+                },
+                Relation {
+                    modulus: DEC_MODULUS,
+                    a: ct_mask(ct_id),
+                    b: share.0.clone(),
+                    error_bound: DEC_ERROR_BOUND,
+                },
+            ],
+        }
+    }
+
+    fn verify_dec_share(
+        &self,
+        ct: &PhantomPackedCt,
+        dec_share: &PhantomPackedCtDecShare,
+    ) -> Result<(), PhantomDecryptionError> {
+        self.check_key_id(ct.key_id)?;
+        let ct_id = ct.fingerprint();
+        Ok(dec_share.verify(self.total_shares(), ct_id, |dec_share| {
+            self.dec_share_statement(ct_id, dec_share.user_id, &dec_share.share)
+        })?)
+    }
+
+    /// Verifies the decryption shares, and returns the bits in the clear.
+    fn aggregate_dec_shares(
+        &self,
+        ct: &PhantomPackedCt,
+        dec_shares: &[PhantomPackedCtDecShare],
+    ) -> Result<Vec<bool>, PhantomDecryptionError> {
+        self.check_key_id(ct.key_id)?;
+        let ct_id = ct.fingerprint();
+        check_dec_shares(self.total_shares(), ct_id, dec_shares, |dec_share| {
+            self.dec_share_statement(ct_id, dec_share.user_id, &dec_share.share)
+        })?;
+        Ok(ct.bits.clone())
+    }
+}
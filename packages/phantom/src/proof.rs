@@ -0,0 +1,314 @@
+//! Proofs that a decryption share is computed with the secret key of the
+//! user's public key share.
+//!
+//! A user's public key share is `b_pk = a_pk * s + e_pk` and its decryption
+//! share of a ciphertext of mask `c` is `d = c * s + e_dec`, both in
+//! `Z_q[X]/(X^n + 1)` with their own `q`, for a short secret key `s` and short
+//! errors. A [`PhantomDecShareProof`] is a proof of knowledge of short `s`,
+//! `e_pk` and `e_dec` satisfying both with the same `s`, so a share computed
+//! with another key, or with a larger error than its bound, doesn't verify.
+//!
+//! The proof is a Σ-protocol made non-interactive with Fiat-Shamir, with
+//! rejection sampling so that responses don't leak the witness (Lyubashevsky,
+//! "Fiat-Shamir with aborts"). The challenge is a polynomial of
+//! [`CHALLENGE_WEIGHT`] coefficients in `{-1, 1}` hashed from the statement,
+//! the commitments and a context binding the user id and the ciphertext, so a
+//! proof can't be replayed for another user or ciphertext.
+
+use rand::{rngs::StdRng, seq::index, thread_rng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Number of non-zero coefficients of a challenge, so that there are more
+/// than `2^256` challenges for rings of size 256 and up.
+const CHALLENGE_WEIGHT: usize = 60;
+
+/// Largest mask bound, so that products with ring elements of up to 64 bits
+/// accumulate in an `i128`.
+const MAX_MASK_BOUND: u64 = 1 << 52;
+
+/// Relation `b = a * s + e mod modulus`, with `e` at most `error_bound` in
+/// absolute value.
+#[derive(Clone)]
+pub(crate) struct Relation {
+    pub(crate) modulus: u128,
+    pub(crate) a: Vec<u64>,
+    pub(crate) b: Vec<u64>,
+    pub(crate) error_bound: u64,
+}
+
+/// Relations holding with the same secret, at most `secret_bound` in absolute
+/// value.
+#[derive(Clone)]
+pub(crate) struct Statement {
+    pub(crate) secret_bound: u64,
+    pub(crate) relations: Vec<Relation>,
+}
+
+/// Proof of knowledge of a short secret satisfying a [`Statement`], see the
+/// [module documentation](self).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PhantomDecShareProof {
+    challenge: [u8; 32],
+    z_s: Vec<i64>,
+    z_e: Vec<Vec<i64>>,
+}
+
+impl PhantomDecShareProof {
+    /// Proves `statement` with the secret `s`, the proof being bound to
+    /// `context`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` or an error `b - a * s` is out of its bound, or if the
+    /// relations are of another ring size than `s`.
+    pub(crate) fn prove(context: &[u8], statement: &Statement, s: &[i64]) -> Self {
+        let n = s.len();
+        assert!(statement.relations.iter().all(|r| r.a.len() == n));
+        assert!(
+            inf_norm(s) <= statement.secret_bound,
+            "secret out of its bound {}",
+            statement.secret_bound
+        );
+        let es = statement
+            .relations
+            .iter()
+            .map(|r| {
+                let e = centered(&sub(&r.b, &mul(&r.a, s, r.modulus), r.modulus), r.modulus);
+                assert!(
+                    inf_norm(&e) <= r.error_bound,
+                    "error out of its bound {}",
+                    r.error_bound
+                );
+                e
+            })
+            .collect::<Vec<_>>();
+
+        let gamma_s = mask_bound(n, statement.secret_bound);
+        let gamma_e = statement
+            .relations
+            .iter()
+            .map(|r| mask_bound(n, r.error_bound))
+            .collect::<Vec<_>>();
+        let mut rng = thread_rng();
+        loop {
+            let y_s = sample_mask(&mut rng, n, gamma_s);
+            let y_e = gamma_e
+                .iter()
+                .map(|gamma| sample_mask(&mut rng, n, *gamma))
+                .collect::<Vec<_>>();
+            let ws = statement
+                .relations
+                .iter()
+                .zip(&y_e)
+                .map(|(r, y_e)| add_short(&mul(&r.a, &y_s, r.modulus), y_e, r.modulus))
+                .collect::<Vec<_>>();
+            let challenge = hash(context, statement, &ws);
+            let ch = challenge_poly(challenge, n);
+
+            let z_s = add(&y_s, &mul_challenge(&ch, s));
+            let z_e = y_e
+                .iter()
+                .zip(&es)
+                .map(|(y_e, e)| add(y_e, &mul_challenge(&ch, e)))
+                .collect::<Vec<_>>();
+            let accepted = in_response_bound(&z_s, gamma_s, statement.secret_bound)
+                && statement
+                    .relations
+                    .iter()
+                    .zip(&z_e)
+                    .zip(&gamma_e)
+                    .all(|((r, z_e), gamma)| in_response_bound(z_e, *gamma, r.error_bound));
+            if accepted {
+                return Self {
+                    challenge,
+                    z_s,
+                    z_e,
+                };
+            }
+        }
+    }
+
+    /// Returns if the proof is of `statement` and bound to `context`.
+    pub(crate) fn verify(&self, context: &[u8], statement: &Statement) -> bool {
+        let n = self.z_s.len();
+        let well_formed = self.z_e.len() == statement.relations.len()
+            && statement.relations.iter().zip(&self.z_e).all(|(r, z_e)| {
+                r.a.len() == n
+                    && r.b.len() == n
+                    && z_e.len() == n
+                    && in_response_bound(z_e, mask_bound(n, r.error_bound), r.error_bound)
+            })
+            && in_response_bound(
+                &self.z_s,
+                mask_bound(n, statement.secret_bound),
+                statement.secret_bound,
+            );
+        if !well_formed {
+            return false;
+        }
+
+        // w = a * z_s + z_e - ch * b, which is a * y_s + y_e for an honest
+        // prover.
+        let ch = challenge_poly(self.challenge, n);
+        let ws = statement
+            .relations
+            .iter()
+            .zip(&self.z_e)
+            .map(|(r, z_e)| {
+                let w = add_short(&mul(&r.a, &self.z_s, r.modulus), z_e, r.modulus);
+                sub(&w, &mul_challenge_mod(&ch, &r.b, r.modulus), r.modulus)
+            })
+            .collect::<Vec<_>>();
+        hash(context, statement, &ws) == self.challenge
+    }
+}
+
+/// Returns `a * s mod modulus` for a short `s`, with `a` reduced.
+pub(crate) fn mul(a: &[u64], s: &[i64], modulus: u128) -> Vec<u64> {
+    let n = a.len();
+    let mut acc = vec![0i128; n];
+    for (i, a) in a.iter().enumerate() {
+        let a = center(*a, modulus);
+        for (j, s) in s.iter().enumerate() {
+            let product = a * *s as i128;
+            if i + j < n {
+                acc[i + j] += product;
+            } else {
+                acc[i + j - n] -= product;
+            }
+        }
+    }
+    acc.into_iter().map(|acc| reduce(acc, modulus)).collect()
+}
+
+/// Returns `a + e mod modulus` for a short `e`, with `a` reduced.
+pub(crate) fn add_short(a: &[u64], e: &[i64], modulus: u128) -> Vec<u64> {
+    a.iter()
+        .zip(e)
+        .map(|(a, e)| reduce(*a as i128 + *e as i128, modulus))
+        .collect()
+}
+
+fn sub(a: &[u64], b: &[u64], modulus: u128) -> Vec<u64> {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| reduce(*a as i128 - *b as i128, modulus))
+        .collect()
+}
+
+/// Returns `a` centered in `(-modulus / 2, modulus / 2]`, saturated to `i64`.
+fn centered(a: &[u64], modulus: u128) -> Vec<i64> {
+    a.iter()
+        .map(|a| center(*a, modulus).clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+        .collect()
+}
+
+fn center(a: u64, modulus: u128) -> i128 {
+    let a = a as i128;
+    if a > (modulus / 2) as i128 {
+        a - modulus as i128
+    } else {
+        a
+    }
+}
+
+fn reduce(a: i128, modulus: u128) -> u64 {
+    a.rem_euclid(modulus as i128) as u64
+}
+
+fn add(a: &[i64], b: &[i64]) -> Vec<i64> {
+    a.iter().zip(b).map(|(a, b)| a + b).collect()
+}
+
+fn inf_norm(v: &[i64]) -> u64 {
+    v.iter().map(|v| v.unsigned_abs()).max().unwrap_or(0)
+}
+
+/// Returns the bound masks are sampled within, large enough for a response to
+/// be accepted with probability about `e^-1/4` per ring element.
+fn mask_bound(n: usize, bound: u64) -> u64 {
+    let gamma = 4 * (n * CHALLENGE_WEIGHT) as u64 * bound.max(1);
+    assert!(gamma <= MAX_MASK_BOUND, "bound {bound} too large");
+    gamma
+}
+
+/// Returns if a response is within the bound where it leaks nothing of the
+/// witness, i.e. `|z| <= gamma - weight * bound`.
+fn in_response_bound(z: &[i64], gamma: u64, bound: u64) -> bool {
+    inf_norm(z) <= gamma - CHALLENGE_WEIGHT as u64 * bound
+}
+
+fn sample_mask(rng: &mut impl Rng, n: usize, gamma: u64) -> Vec<i64> {
+    let gamma = gamma as i64;
+    (0..n).map(|_| rng.gen_range(-gamma..=gamma)).collect()
+}
+
+/// Non-zero coefficients of a challenge, as their degree and if they are
+/// `-1`.
+type Challenge = Vec<(usize, bool)>;
+
+fn challenge_poly(challenge: [u8; 32], n: usize) -> Challenge {
+    let mut rng = StdRng::from_seed(challenge);
+    index::sample(&mut rng, n, CHALLENGE_WEIGHT.min(n))
+        .into_iter()
+        .map(|degree| (degree, rng.gen()))
+        .collect()
+}
+
+fn mul_challenge(ch: &Challenge, v: &[i64]) -> Vec<i64> {
+    let n = v.len();
+    let mut out = vec![0; n];
+    for (degree, negative) in ch {
+        for (i, v) in v.iter().enumerate() {
+            // X^n = -1.
+            let (k, wrapped) = if i + degree < n {
+                (i + degree, false)
+            } else {
+                (i + degree - n, true)
+            };
+            if *negative != wrapped {
+                out[k] -= v;
+            } else {
+                out[k] += v;
+            }
+        }
+    }
+    out
+}
+
+fn mul_challenge_mod(ch: &Challenge, v: &[u64], modulus: u128) -> Vec<u64> {
+    let n = v.len();
+    let mut acc = vec![0i128; n];
+    for (degree, negative) in ch {
+        for (i, v) in v.iter().enumerate() {
+            let (k, wrapped) = if i + degree < n {
+                (i + degree, false)
+            } else {
+                (i + degree - n, true)
+            };
+            if *negative != wrapped {
+                acc[k] -= *v as i128;
+            } else {
+                acc[k] += *v as i128;
+            }
+        }
+    }
+    acc.into_iter().map(|acc| reduce(acc, modulus)).collect()
+}
+
+fn hash(context: &[u8], statement: &Statement, ws: &[Vec<u64>]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"phantom/dec-share-proof");
+    hasher.update((context.len() as u64).to_le_bytes());
+    hasher.update(context);
+    hasher.update(statement.secret_bound.to_le_bytes());
+    for (r, w) in statement.relations.iter().zip(ws) {
+        hasher.update(r.modulus.to_le_bytes());
+        hasher.update(r.error_bound.to_le_bytes());
+        for poly in [&r.a, &r.b, w] {
+            poly.iter().for_each(|c| hasher.update(c.to_le_bytes()));
+        }
+    }
+    hasher.finalize().into()
+}
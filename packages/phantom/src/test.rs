@@ -1,19 +1,53 @@
 use crate::{
-    PhantomBatchedCt, PhantomBool, PhantomEvaluator, PhantomPackedCt, PhantomParam,
-    PhantomRound1Key, PhantomRound2Key, PhantomUser,
+    proof::{add_short, mul, PhantomDecShareProof, Relation, Statement},
+    Enc, EncBool, EncU16, EncU8, Fingerprint, PhantomBatchedCt, PhantomBool, PhantomChunkLayout,
+    PhantomCrs, PhantomCrsMismatch, PhantomDecShareError, PhantomDecryptionError, PhantomEvaluator,
+    PhantomKeyMismatch, PhantomPackedCt, PhantomParam, PhantomRound1Key, PhantomRound2Key,
+    PhantomUser,
 };
-use core::{array::from_fn, iter::repeat_with, ops::*};
-use itertools::{izip, Itertools};
+use core::{iter::repeat_with, ops::*};
+use itertools::{chain, izip, Itertools};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
+#[cfg(not(feature = "mock"))]
+mod noise;
+
 #[test]
 fn e2e() {
-    let param = PhantomParam::I_4P_40;
+    run_e2e(PhantomParam::I_4P_40)
+}
+
+#[test]
+fn e2e_i_4p_60() {
+    run_e2e(PhantomParam::I_4P_60)
+}
+
+#[test]
+fn e2e_8_users() {
+    run_e2e(PhantomParam::I_4P_40.with_total_shares(8))
+}
+
+#[test]
+fn named_params() {
+    for (name, param) in PhantomParam::NAMED {
+        assert_eq!(PhantomParam::from_name(name), Some(param));
+    }
+    assert_eq!(PhantomParam::from_name("I_4P_50"), None);
+    // Each parameter is domain separated by its CRS.
+    assert!(PhantomParam::NAMED
+        .iter()
+        .tuple_combinations()
+        .all(|((_, a), (_, b))| a.crs != b.crs));
+}
+
+fn run_e2e(param: PhantomParam) {
     let mut server = PhantomEvaluator::new(param);
-    let mut users: [PhantomUser; 4] = from_fn(|user_id| {
-        let seed = StdRng::from_entropy().gen::<[u8; 32]>().to_vec();
-        PhantomUser::new(param, user_id, seed)
-    });
+    let mut users: Vec<PhantomUser> = (0..param.total_shares)
+        .map(|user_id| {
+            let seed = StdRng::from_entropy().gen::<[u8; 32]>().to_vec();
+            PhantomUser::new(param, user_id, seed)
+        })
+        .collect();
 
     // Initially server doesn't have aggregated public key or bootstrapping key,
     assert!(server.pk().is_none());
@@ -25,20 +59,22 @@ fn e2e() {
     /*  Start round 1 key generation (collecting public key shares) */
 
     // Each user generates round 1 key and submit to server.
-    let round_1_keys: [PhantomRound1Key; 4] = users.each_ref().map(|user| user.round_1_key_gen());
+    let round_1_keys: Vec<PhantomRound1Key> =
+        users.iter().map(|user| user.round_1_key_gen()).collect();
     // Server aggregates after collecting all round 1 key shares.
-    server.aggregate_round_1_keys(&round_1_keys);
+    server.aggregate_round_1_keys(&round_1_keys).unwrap();
     // Now server has aggregated public key for round 2 key generation.
     let pk = server.pk().cloned().unwrap();
 
     /*  Start round 2 key generation (collecting bootstrapping key shares) */
 
     // Each user first retrieves aggregated public key from server,
-    users.each_mut().map(|user| user.set_pk(pk.clone()));
+    users.iter_mut().for_each(|user| user.set_pk(pk.clone()));
     // then generates round 2 key and submit to server.
-    let round_2_keys: [PhantomRound2Key; 4] = users.each_ref().map(|user| user.round_2_key_gen());
+    let round_2_keys: Vec<PhantomRound2Key> =
+        users.iter().map(|user| user.round_2_key_gen()).collect();
     // Server aggregates after collecting all round 2 key shares.
-    server.aggregate_round_2_keys(&round_2_keys);
+    server.aggregate_round_2_keys(&round_2_keys).unwrap();
     // Now server has bootstrapping key,
     assert!(server.bs_key().is_some());
     // and ring packing key.
@@ -47,27 +83,337 @@ fn e2e() {
     /*  Start to do some FHE computation */
 
     // Each user generates some random bits.
-    let inputs: [Vec<bool>; 4] = from_fn(|_| random_bits(10));
+    let inputs: Vec<Vec<bool>> = users.iter().map(|_| random_bits(10)).collect();
     // Each user encrypts bits in batch and submit to server
-    let cts_batched: [PhantomBatchedCt; 4] =
-        from_fn(|i| users[i].batched_pk_encrypt(inputs[i].clone()));
+    let cts_batched: Vec<PhantomBatchedCt> = izip!(&users, &inputs)
+        .map(|(user, inputs)| user.batched_pk_encrypt(inputs.clone()))
+        .collect();
     // Server extract batched cts from each batch and wrap it as inputs of FHE computation.
-    let ct_inputs: [Vec<PhantomBool>; 4] = cts_batched
-        .each_ref()
-        .map(|ct_batched| server.unbatch(ct_batched));
-    // Now we can do FHE computation on these cts, for example XOR 4 users' inputs.
-    let ct_outputs: Vec<PhantomBool> = xor_4_bit_vecs(&ct_inputs);
+    let ct_inputs: Vec<Vec<PhantomBool>> = cts_batched
+        .iter()
+        .map(|ct_batched| server.unbatch(ct_batched).unwrap())
+        .collect();
+    // Now we can do FHE computation on these cts, for example XOR all users' inputs.
+    let ct_outputs: Vec<PhantomBool> = xor_bit_vecs(&ct_inputs);
     // Before user gets the outputs, server packs the outputs to save network bandwidth.
     let ct_packed: PhantomPackedCt = server.pack(&ct_outputs);
 
     /*  Start to collect decryption shares */
 
     // Each user generates decryption share and submit to server.
-    let dec_shares = users.each_ref().map(|user| user.decrypt_share(&ct_packed));
+    let dec_shares = users
+        .iter()
+        .map(|user| user.decrypt_share(&ct_packed).unwrap())
+        .collect();
     // Anyone with all the decryption shares can aggregate decryption shares and decrypt.
-    let outputs = users[0].aggregate_dec_shares(&ct_packed, dec_shares.to_vec());
+    let outputs = users[0]
+        .aggregate_dec_shares(&ct_packed, dec_shares)
+        .unwrap();
+
+    assert_eq!(outputs, xor_bit_vecs(&inputs))
+}
+
+#[test]
+fn key_mismatch() {
+    // Two evaluators only going through round 1 key generation, with
+    // different users' seeds, so with different public keys.
+    let [server_a, server_b] = [0, 1].map(|_| {
+        let param = PhantomParam::I_4P_40;
+        let mut server = PhantomEvaluator::new(param);
+        let round_1_keys: Vec<PhantomRound1Key> = (0..param.total_shares)
+            .map(|user_id| {
+                let seed = StdRng::from_entropy().gen::<[u8; 32]>().to_vec();
+                PhantomUser::new(param, user_id, seed).round_1_key_gen()
+            })
+            .collect();
+        server.aggregate_round_1_keys(&round_1_keys).unwrap();
+        server
+    });
+    let key_id_a = server_a.pk().unwrap().fingerprint();
+    let key_id_b = server_b.pk().unwrap().fingerprint();
+    assert_ne!(key_id_a, key_id_b);
+
+    let ct_batched = server_a.batched_pk_encrypt(random_bits(10));
+    assert_eq!(ct_batched.key_id(), key_id_a);
+    assert_eq!(
+        server_b.unbatch(&ct_batched).err(),
+        Some(PhantomKeyMismatch {
+            expected: key_id_b,
+            actual: key_id_a,
+        })
+    );
+}
+
+#[test]
+fn crs_mismatch() {
+    // Round 1 key of a user in a previous game, replayed in the next one.
+    let [last_game, next_game] =
+        [0, 1].map(|_| PhantomParam::I_4P_40.with_crs(PhantomCrs::from_entropy()));
+    let seed = StdRng::from_entropy().gen::<[u8; 32]>().to_vec();
+    let round_1_key = PhantomUser::new(last_game, 0, seed).round_1_key_gen();
+    assert_eq!(round_1_key.crs_id(), last_game.crs().fingerprint());
+
+    let mut server = PhantomEvaluator::new(next_game);
+    assert_eq!(
+        server.aggregate_round_1_keys([&round_1_key]).err(),
+        Some(PhantomCrsMismatch {
+            expected: next_game.crs().fingerprint(),
+            actual: last_game.crs().fingerprint(),
+        })
+    );
+    assert!(server.pk().is_none());
+}
+
+#[test]
+fn dec_share_verification() {
+    let (server, users) = keygen(PhantomParam::I_4P_40);
+    let [ct_a, ct_b] = [false, true].map(|bit| {
+        let ct_batched = users[0].batched_pk_encrypt([bit; 8]);
+        server.pack(&server.unbatch(&ct_batched).unwrap())
+    });
+    let dec_shares = users
+        .iter()
+        .map(|user| user.decrypt_share(&ct_a).unwrap())
+        .collect_vec();
+    for dec_share in &dec_shares {
+        assert_eq!(server.verify_dec_share(&ct_a, dec_share), Ok(()));
+    }
+    let rejected = |dec_shares: Vec<_>| {
+        let err = server.aggregate_dec_shares(&ct_a, dec_shares).unwrap_err();
+        match err {
+            PhantomDecryptionError::DecShare(err) => err,
+            _ => panic!("{err}"),
+        }
+    };
+
+    // Share of another ciphertext.
+    let mut replayed = dec_shares.clone();
+    replayed[1] = users[1].decrypt_share(&ct_b).unwrap();
+    assert_eq!(
+        rejected(replayed),
+        PhantomDecShareError::CtMismatch {
+            user_id: 1,
+            expected: ct_a.fingerprint(),
+            actual: ct_b.fingerprint(),
+        }
+    );
+
+    // Share of user 1 passed off as user 2's.
+    let mut forged = dec_shares.clone();
+    forged[2] = dec_shares[1].clone();
+    forged[2].user_id = 2;
+    assert_eq!(
+        rejected(forged),
+        PhantomDecShareError::BadProof { user_id: 2 }
+    );
+
+    // Share of another ciphertext, relabeled as of this one.
+    let mut wrong = dec_shares.clone();
+    wrong[1].share = users[1].decrypt_share(&ct_b).unwrap().share;
+    assert_eq!(
+        rejected(wrong),
+        PhantomDecShareError::BadProof { user_id: 1 }
+    );
+
+    // Share of a user out of the key's users.
+    let mut unknown = dec_shares.clone();
+    unknown[3].user_id = 4;
+    assert_eq!(
+        rejected(unknown),
+        PhantomDecShareError::UnknownUser { user_id: 4 }
+    );
+
+    // Same share twice, so without the share of user 3.
+    let mut duplicate = dec_shares.clone();
+    duplicate[3] = dec_shares[1].clone();
+    assert_eq!(
+        rejected(duplicate),
+        PhantomDecShareError::Duplicate { user_id: 1 }
+    );
+    assert_eq!(
+        rejected(dec_shares[..3].to_vec()),
+        PhantomDecShareError::Missing { user_id: 3 }
+    );
+}
+
+#[test]
+fn dec_share_proof() {
+    let n = 128;
+    let mut rng = StdRng::seed_from_u64(0);
+    let short = |rng: &mut StdRng, bound: i64| -> Vec<i64> {
+        (0..n).map(|_| rng.gen_range(-bound..=bound)).collect()
+    };
+    let relation = |rng: &mut StdRng, s: &[i64], modulus: u128, error_bound: u64| {
+        let a = (0..n)
+            .map(|_| rng.gen_range(0..=(modulus - 1) as u64))
+            .collect_vec();
+        let e = short(rng, error_bound as i64);
+        let b = add_short(&mul(&a, s, modulus), &e, modulus);
+        Relation {
+            modulus,
+            a,
+            b,
+            error_bound,
+        }
+    };
+    let s = short(&mut rng, 1);
+    let statement = Statement {
+        secret_bound: 1,
+        relations: vec![
+            relation(&mut rng, &s, 1 << 64, 8),
+            relation(&mut rng, &s, (1 << 61) - 1, 1 << 20),
+        ],
+    };
+    let proof = PhantomDecShareProof::prove(b"context", &statement, &s);
+    assert!(proof.verify(b"context", &statement));
+
+    // Bound to its context.
+    assert!(!proof.verify(b"another context", &statement));
+
+    // A share off by more than its error bound.
+    let mut tampered = statement.clone();
+    tampered.relations[1].b[0] += 1 << 40;
+    assert!(!proof.verify(b"context", &tampered));
+
+    // A share computed with another secret than the public key share's.
+    let other = short(&mut rng, 1);
+    let other_statement = Statement {
+        secret_bound: 1,
+        relations: vec![
+            relation(&mut rng, &other, 1 << 64, 8),
+            relation(&mut rng, &other, (1 << 61) - 1, 1 << 20),
+        ],
+    };
+    let other_proof = PhantomDecShareProof::prove(b"context", &other_statement, &other);
+    assert!(other_proof.verify(b"context", &other_statement));
+    let mut mixed = other_statement.clone();
+    mixed.relations[0] = statement.relations[0].clone();
+    assert!(!other_proof.verify(b"context", &mixed));
+}
+
+#[test]
+fn pack_chunks() {
+    let param = PhantomParam::I_4P_40;
+    let (server, users) = keygen(param);
+    let item_bits = 35;
+    let chunk_len = param.ring_size / item_bits;
+
+    // Two full chunks and a last one with a single item.
+    let bits = random_bits((2 * chunk_len + 1) * item_bits);
+    let cts = bits
+        .chunks(param.ring_size)
+        .flat_map(|bits| {
+            let ct_batched = users[0].batched_pk_encrypt(bits.iter().copied());
+            server.unbatch(&ct_batched).unwrap()
+        })
+        .collect_vec();
+    let chunks = server.pack_chunks(&cts, item_bits);
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.layout).collect_vec(),
+        [(0, chunk_len), (chunk_len, chunk_len), (2 * chunk_len, 1)].map(|(offset, len)| {
+            PhantomChunkLayout {
+                item_bits,
+                offset,
+                len,
+                total: 2 * chunk_len + 1,
+            }
+        })
+    );
+
+    let decrypted = chunks
+        .iter()
+        .flat_map(|chunk| {
+            assert_eq!(chunk.ct.n(), chunk.layout.bits());
+            decrypt(&users, &chunk.ct)
+        })
+        .collect_vec();
+    assert_eq!(decrypted, bits);
+}
+
+#[test]
+fn enc_uint() {
+    let (server, users) = keygen(PhantomParam::I_4P_40);
+    let mut rng = StdRng::from_entropy();
+    for _ in 0..4 {
+        let (a, b, cond): (u8, u8, bool) = rng.gen();
+        let ct_batched = users[0].batched_pk_encrypt(chain![
+            EncU8::encode(a),
+            EncU8::encode(b),
+            EncBool::encode(cond),
+        ]);
+        let mut cts = server.unbatch(&ct_batched).unwrap().into_iter();
+        let ct_a = EncU8::from_bits(&mut cts);
+        let ct_b = EncU8::from_bits(&mut cts);
+        let ct_cond = EncBool::from_bits(&mut cts);
+
+        let sum = &ct_a + &ct_b;
+        assert_eq!(
+            EncU8::unpack(decrypt(&users, &sum.pack(&server))),
+            Some(a.wrapping_add(b))
+        );
+        for (ct, m) in [
+            (ct_a.eq(&ct_b), a == b),
+            (ct_a.eq(&ct_a), true),
+            (ct_a.lt(&ct_b), a < b),
+            (ct_b.lt(&ct_a), b < a),
+        ] {
+            assert_eq!(EncBool::unpack(decrypt(&users, &ct.pack(&server))), Some(m));
+        }
+        let mux = EncU8::mux(&ct_cond, &ct_a, &ct_b);
+        assert_eq!(
+            EncU8::unpack(decrypt(&users, &mux.pack(&server))),
+            Some(if cond { a } else { b })
+        );
+    }
+}
+
+#[test]
+fn enc_encode() {
+    let mut rng = StdRng::from_entropy();
+    for m in [0, 1, u16::MAX, rng.gen()] {
+        let bits = EncU16::encode(m).collect_vec();
+        assert_eq!(bits.len(), EncU16::BITS);
+        assert_eq!(EncU16::unpack(bits), Some(m));
+    }
+    assert_eq!(
+        EncU8::encode(0b1010_0001).collect_vec(),
+        [true, false, false, false, false, true, false, true]
+    );
+    assert_eq!(EncU8::unpack(vec![true; 9]), None);
+}
+
+/// Evaluator and users after key generation.
+fn keygen(param: PhantomParam) -> (PhantomEvaluator, Vec<PhantomUser>) {
+    keygen_with_rng(param, &mut StdRng::from_entropy())
+}
+
+/// Like [`keygen`], with the users' seeds from `rng`.
+fn keygen_with_rng(param: PhantomParam, rng: &mut StdRng) -> (PhantomEvaluator, Vec<PhantomUser>) {
+    let mut server = PhantomEvaluator::new(param);
+    let mut users: Vec<PhantomUser> = (0..param.total_shares)
+        .map(|user_id| {
+            let seed = rng.gen::<[u8; 32]>().to_vec();
+            PhantomUser::new(param, user_id, seed)
+        })
+        .collect();
+    let round_1_keys: Vec<_> = users.iter().map(PhantomUser::round_1_key_gen).collect();
+    server.aggregate_round_1_keys(&round_1_keys).unwrap();
+    let pk = server.pk().cloned().unwrap();
+    users.iter_mut().for_each(|user| user.set_pk(pk.clone()));
+    let round_2_keys: Vec<_> = users.iter().map(PhantomUser::round_2_key_gen).collect();
+    server.aggregate_round_2_keys(&round_2_keys).unwrap();
+    (server, users)
+}
 
-    assert_eq!(outputs, xor_4_bit_vecs(&inputs))
+/// Decrypts with the decryption shares of every user.
+fn decrypt(users: &[PhantomUser], ct_packed: &PhantomPackedCt) -> Vec<bool> {
+    let dec_shares = users
+        .iter()
+        .map(|user| user.decrypt_share(ct_packed).unwrap())
+        .collect();
+    users[0]
+        .aggregate_dec_shares(ct_packed, dec_shares)
+        .unwrap()
 }
 
 fn random_bits(n: usize) -> Vec<bool> {
@@ -75,13 +421,16 @@ fn random_bits(n: usize) -> Vec<bool> {
     repeat_with(|| rng.gen_bool(0.5)).take(n).collect()
 }
 
-fn xor_4_bit_vecs<T>(inputs: &[Vec<T>; 4]) -> Vec<T>
+fn xor_bit_vecs<T>(inputs: &[Vec<T>]) -> Vec<T>
 where
     T: for<'t> BitOps<&'t T, T>,
     for<'t> &'t T: BitOps<&'t T, T>,
 {
-    izip!(&inputs[0], &inputs[1], &inputs[2], &inputs[3])
-        .map(|(a, b, c, d)| a ^ b ^ c ^ d)
+    (0..inputs[0].len())
+        .map(|i| {
+            let xor = &inputs[0][i] ^ &inputs[1][i];
+            inputs[2..].iter().fold(xor, |xor, bits| &xor ^ &bits[i])
+        })
         .collect_vec()
 }
 
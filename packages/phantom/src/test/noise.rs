@@ -0,0 +1,105 @@
+use super::{keygen_with_rng, BitOps};
+use crate::{noise::PhantomNoiseMeter, PhantomParam};
+use core::iter::repeat_with;
+use itertools::{izip, Itertools};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Failure probability of a decrypted bit, estimated from the measured noise,
+/// above which a named parameter is flagged.
+///
+/// Counting failures among the sampled outputs would take on the order of
+/// `1 / MAX_FAILURE_RATE` of them to mean anything, so only the estimate is
+/// checked.
+const MAX_FAILURE_RATE: f64 = 0.001;
+
+/// Seed of the keys, inputs and circuits, so that a flagged parameter is
+/// flagged on every run.
+const SEED: u64 = 0x6672_6f67;
+
+/// Number of inputs and outputs of the sampled circuits.
+const WIDTH: usize = 16;
+
+/// Number of gate layers of each sampled circuit per parameter. The deepest is
+/// deeper than the transpiled cell queries, e.g. `get_five_cells` has 26.
+const DEPTHS: [usize; 5] = [4, 4, 4, 4, 64];
+
+#[test]
+fn failure_rate() {
+    let flagged = PhantomParam::NAMED
+        .into_iter()
+        .filter_map(|(name, param)| {
+            let mut rng = StdRng::seed_from_u64(SEED);
+            let (server, users) = keygen_with_rng(param, &mut rng);
+            let meter = PhantomNoiseMeter::new(&users);
+
+            let (outputs, ct_outputs): (Vec<_>, Vec<_>) = DEPTHS
+                .into_iter()
+                .flat_map(|depth| {
+                    let circuit = sample_circuit(&mut rng, depth);
+                    let inputs = repeat_with(|| rng.gen_bool(0.5)).take(WIDTH).collect_vec();
+                    let ct_inputs = server
+                        .unbatch(&users[0].batched_pk_encrypt_with_rng(inputs.clone(), &mut rng))
+                        .unwrap();
+                    izip!(
+                        eval_circuit(&circuit, inputs),
+                        eval_circuit(&circuit, ct_inputs)
+                    )
+                })
+                .unzip();
+            let ct_packed = server.pack(&ct_outputs);
+            let noise = meter.measure(&ct_outputs, outputs.iter().copied());
+            let noise_packed = meter.measure_packed(&ct_packed, outputs.iter().copied());
+
+            [noise, noise_packed]
+                .iter()
+                .any(|noise| noise.log2_failure_probability() > MAX_FAILURE_RATE.log2())
+                .then(|| format!("{name} (outputs: {noise}, packed: {noise_packed})"))
+        })
+        .collect_vec();
+    assert!(
+        flagged.is_empty(),
+        "estimated failure probability above {MAX_FAILURE_RATE} with {flagged:?}"
+    );
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Gate {
+    And(usize, usize),
+    Or(usize, usize),
+    Xor(usize, usize),
+}
+
+/// `depth` layers of [`WIDTH`] gates, each on 2 random outputs of the previous
+/// layer.
+fn sample_circuit(rng: &mut StdRng, depth: usize) -> Vec<Vec<Gate>> {
+    (0..depth)
+        .map(|_| {
+            (0..WIDTH)
+                .map(|_| {
+                    let (a, b) = (rng.gen_range(0..WIDTH), rng.gen_range(0..WIDTH));
+                    match rng.gen_range(0..3) {
+                        0 => Gate::And(a, b),
+                        1 => Gate::Or(a, b),
+                        _ => Gate::Xor(a, b),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn eval_circuit<T>(circuit: &[Vec<Gate>], inputs: Vec<T>) -> Vec<T>
+where
+    for<'t> &'t T: BitOps<&'t T, T>,
+{
+    circuit.iter().fold(inputs, |wires, layer| {
+        layer
+            .iter()
+            .map(|gate| match *gate {
+                Gate::And(a, b) => &wires[a] & &wires[b],
+                Gate::Or(a, b) => &wires[a] | &wires[b],
+                Gate::Xor(a, b) => &wires[a] ^ &wires[b],
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,182 @@
+//! Types and authentication for the admin API mounted under `/admin`.
+//!
+//! Every admin route requires an `Authorization: Bearer <token>` header
+//! matching the `ADMIN_TOKEN` environment variable of the server. When the
+//! variable is unset the admin API rejects every request.
+
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome, Request},
+};
+use serde::{Deserialize, Serialize};
+use std::{env, sync::LazyLock};
+
+static ADMIN_TOKEN: LazyLock<Option<String>> = LazyLock::new(|| {
+    env::var("ADMIN_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+});
+
+/// Request guard succeeding only for requests carrying the admin token.
+pub struct Admin;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(admin_token) = ADMIN_TOKEN.as_deref() else {
+            return Outcome::Error((Status::Forbidden, "admin api is disabled"));
+        };
+        let token = request
+            .headers()
+            .get_one("Authorization")
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), admin_token.as_bytes()) => {
+                Outcome::Success(Admin)
+            }
+            _ => Outcome::Error((Status::Unauthorized, "invalid admin token")),
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum ActionType {
+    None,
+    Move,
+    MoveMonster,
+    MoveFlyer,
+    MaintainMonsters,
+    MaintainItems,
+    Respawn,
+    CheckGameOver,
+    ResetGame,
+}
+
+/// Which background loops a pause or resume applies to.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub enum Loops {
+    #[default]
+    All,
+    Actions,
+    Monsters,
+}
+
+impl Loops {
+    pub fn actions(self) -> bool {
+        matches!(self, Loops::All | Loops::Actions)
+    }
+
+    pub fn monsters(self) -> bool {
+        matches!(self, Loops::All | Loops::Monsters)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoopsStatus {
+    pub action_loop_paused: bool,
+    pub monster_loop_paused: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PauseRequest {
+    pub loops: Loops,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PauseResponse {
+    pub status: LoopsStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResumeRequest {
+    pub loops: Loops,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResumeResponse {
+    pub status: LoopsStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResyncWorkersRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResyncWorkersResponse {
+    pub workers: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRateLimitRequest {
+    pub move_rate_limit_millis: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRateLimitResponse {
+    pub previous_move_rate_limit_millis: u64,
+    pub move_rate_limit_millis: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPlayersRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlayerStatus {
+    pub player_id: usize,
+    /// Unix time in millis of the player's last request, 0 if never seen.
+    pub last_seen_time: u64,
+    /// Unix time in millis of the player's last accepted move, 0 if none.
+    pub last_move_time: u64,
+    pub round_1_key_submitted: bool,
+    pub round_2_key_submitted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeygenStatus {
+    pub pk_ready: bool,
+    pub bs_key_ready: bool,
+    pub rp_key_ready: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPlayersResponse {
+    pub players: Vec<PlayerStatus>,
+    pub keygen: KeygenStatus,
+    pub game_ready: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InspectQueueRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueuedAction {
+    pub action_type: ActionType,
+    pub entity_id: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InspectQueueResponse {
+    pub status: LoopsStatus,
+    pub move_rate_limit_millis: u64,
+    pub actions: Vec<QueuedAction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EndGameRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EndGameResponse {
+    /// Number of queued actions dropped.
+    pub dropped_actions: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResetRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResetResponse {}
@@ -0,0 +1,97 @@
+//! Command line client for the server's admin API.
+//!
+//! Usage: `ADMIN_TOKEN=<token> admin <server_uri> <command> [args]`
+
+use serde::Serialize;
+use server::admin::*;
+use std::{env, process};
+
+const USAGE: &str = "\
+usage: admin <server_uri> <command> [args]
+
+commands:
+  pause [all|actions|monsters]   pause the action and/or monster loop
+  resume [all|actions|monsters]  resume the action and/or monster loop
+  resync                         send the current zone to every worker
+  rate-limit <millis>            set the minimum time between two moves
+  players                        list players and keygen status
+  queue                          inspect the action queue
+  end-game                       end the game and start final scoring
+  reset                          start a new game, dropping the admin controls
+
+the admin token is read from the ADMIN_TOKEN environment variable";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2)
+}
+
+fn parse_loops(arg: Option<&str>) -> Loops {
+    match arg {
+        None | Some("all") => Loops::All,
+        Some("actions") => Loops::Actions,
+        Some("monsters") => Loops::Monsters,
+        Some(_) => usage(),
+    }
+}
+
+async fn post(server_uri: &str, path: &str, body: impl Serialize) -> Result<String, String> {
+    let token = env::var("ADMIN_TOKEN").map_err(|_| "missing ADMIN_TOKEN".to_string())?;
+    let response = reqwest::Client::new()
+        .post(format!("{server_uri}/admin{path}"))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    let status = response.status();
+    let body = response.text().await.map_err(|err| err.to_string())?;
+    if status.is_success() {
+        let body: serde_json::Value = serde_json::from_str(&body).map_err(|err| err.to_string())?;
+        Ok(serde_json::to_string_pretty(&body).unwrap())
+    } else {
+        Err(format!("request failed with status: {status} body: {body}"))
+    }
+}
+
+#[rocket::main]
+async fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let Some((server_uri, command)) = args.split_first() else {
+        usage()
+    };
+    let server_uri = server_uri.trim_end_matches('/');
+
+    let result = match command {
+        ["pause", rest @ ..] if rest.len() <= 1 => {
+            let loops = parse_loops(rest.first().copied());
+            post(server_uri, "/pause", PauseRequest { loops }).await
+        }
+        ["resume", rest @ ..] if rest.len() <= 1 => {
+            let loops = parse_loops(rest.first().copied());
+            post(server_uri, "/resume", ResumeRequest { loops }).await
+        }
+        ["resync"] => post(server_uri, "/resync_workers", ResyncWorkersRequest {}).await,
+        ["rate-limit", millis] => {
+            let move_rate_limit_millis = millis.parse().unwrap_or_else(|_| usage());
+            let request = SetRateLimitRequest {
+                move_rate_limit_millis,
+            };
+            post(server_uri, "/set_rate_limit", request).await
+        }
+        ["players"] => post(server_uri, "/players", ListPlayersRequest {}).await,
+        ["queue"] => post(server_uri, "/queue", InspectQueueRequest {}).await,
+        ["end-game"] => post(server_uri, "/end_game", EndGameRequest {}).await,
+        ["reset"] => post(server_uri, "/reset", ResetRequest {}).await,
+        _ => usage(),
+    };
+
+    match result {
+        Ok(body) => println!("{body}"),
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1)
+        }
+    }
+}
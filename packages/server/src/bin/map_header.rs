@@ -0,0 +1,47 @@
+//! Generates `circuits_cpp/src/map.h`, the parts of the default map compiled
+//! into the circuits, from `maps/default.json`.
+//!
+//! ```sh
+//! cargo run --bin map_header > ../circuits_cpp/src/map.h
+//! ```
+
+use server::map::Map;
+
+fn main() {
+    let map = Map::builtin();
+
+    println!("// Generated by `cargo run --bin map_header` from `maps/default.json`, do not");
+    println!("// edit by hand.");
+    println!();
+    println!("#ifndef MAP_H");
+    println!("#define MAP_H");
+    println!();
+    println!("#include \"frogzone.h\"");
+    println!();
+    println!("#define WIDTH {}", map.width);
+    println!("#define HEIGHT {}", map.height);
+    println!();
+    println!("const Coord obstacles[] = {{");
+    for obstacle in &map.obstacles {
+        println!("  Coord{{{}, {}}},", obstacle.x, obstacle.y);
+    }
+    println!("}};");
+    println!("const int obstacles_len = {};", map.obstacles.len());
+    println!();
+    println!("const Coord spawn_points[NUM_SPAWN_POINTS] = {{");
+    for spawn in &map.spawns {
+        println!("  Coord{{{}, {}}},", spawn.x, spawn.y);
+    }
+    println!("}};");
+    println!();
+    println!("const MonsterData initial_monsters[NUM_MONSTERS] = {{");
+    for monster in &map.monsters {
+        println!(
+            "  MonsterData{{Coord{{{}, {}}}, {}, {}, {}}},",
+            monster.x, monster.y, monster.hp, monster.atk, monster.points
+        );
+    }
+    println!("}};");
+    println!();
+    println!("#endif");
+}
@@ -1,6 +1,10 @@
+use crate::game_over::{GameOverReason, GameResults};
 use crate::mock_zone::{CellEncryptedData, MockEncrypted, MockEncryptedCoord, PlayerEncryptedData};
 use core::fmt::Debug;
-use phantom::{PhantomBatchedCt, PhantomPackedCt, PhantomPk, PhantomRound1Key, PhantomRound2Key};
+use phantom::{
+    PhantomBatchedCt, PhantomCrs, PhantomPackedCt, PhantomPackedCtDecShare, PhantomPk,
+    PhantomRound1Key, PhantomRound2Key,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -26,12 +30,6 @@ pub enum Direction {
     Right,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ResetRequest {}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ResetResponse {}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResetGameRequest {
     pub player_id: usize,
@@ -95,6 +93,25 @@ pub struct MoveResponse {
     pub rate_limited: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RespawnRequest {
+    pub player_id: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RespawnResponse {
+    /// Unchanged if the player was still alive.
+    pub my_new_coords: PhantomPackedCt, // EncryptedCoord
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCrsRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCrsResponse {
+    pub crs: PhantomCrs,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubmitRound1KeyRequest {
     pub player_id: usize,
@@ -120,3 +137,40 @@ pub struct SubmitRound2KeyRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubmitRound2KeyResponse {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPendingDecryptionRequest {
+    pub player_id: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PendingDecryption {
+    pub id: u64,
+    pub ct: PhantomPackedCt,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPendingDecryptionResponse {
+    /// `None` if there is nothing left for the player to decrypt.
+    pub decryption: Option<PendingDecryption>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitDecShareRequest {
+    pub player_id: usize,
+    pub id: u64,
+    pub dec_share: PhantomPackedCtDecShare,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitDecShareResponse {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResultsRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResultsResponse {
+    pub game_over: Option<GameOverReason>,
+    /// `None` until the final scores are jointly decrypted.
+    pub results: Option<GameResults>,
+}
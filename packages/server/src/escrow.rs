@@ -0,0 +1,48 @@
+//! Requests to and from the escrow, a party holding a copy of each player's key
+//! seed, standing in for offline players in the joint decryptions the server
+//! runs, i.e. game over checks and final scores.
+//!
+//! The secret key is additively shared between all players, and the escrow
+//! holds every share once each player has deposited its seed. It can therefore
+//! decrypt anything on its own, and must be run by someone the players trust
+//! not to. There is no threshold: it is the escrow or every player.
+//!
+//! The escrow only submits decryption shares to the server, for the players
+//! still missing from a decryption [`OVERDUE_DECRYPTION_MILLIS`] after it
+//! started. Decryptions a player asks its peers for, e.g. of its cells, still
+//! need every peer online.
+//!
+//! Seeds are sealed to the escrow's public key, which players are given along
+//! with its uri, so they aren't readable in transit. A deposit replaces the
+//! player's earlier one, and anyone can make one: a seed that isn't the
+//! player's only yields decryption shares the server rejects.
+
+use crate::client::PendingDecryption;
+use serde::{Deserialize, Serialize};
+
+/// Time after which the escrow stands in for the players who haven't submitted
+/// their decryption share yet, shorter than
+/// [`GAME_OVER_CHECK_TIMEOUT_MILLIS`](crate::game_over::GAME_OVER_CHECK_TIMEOUT_MILLIS).
+pub const OVERDUE_DECRYPTION_MILLIS: u64 = 10 * 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DepositRequest {
+    pub player_id: usize,
+    /// Seed sealed to the escrow's public key, as a libsodium sealed box.
+    pub sealed_seed: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DepositResponse {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetOverdueDecryptionRequest {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetOverdueDecryptionResponse {
+    /// `None` unless a decryption has been pending for longer than
+    /// [`OVERDUE_DECRYPTION_MILLIS`].
+    pub decryption: Option<PendingDecryption>,
+    /// Players who haven't submitted their decryption share yet.
+    pub player_ids: Vec<usize>,
+}
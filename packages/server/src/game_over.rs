@@ -0,0 +1,145 @@
+//! End of game detection and final scoring.
+//!
+//! The end conditions other than the time limit depend on encrypted state, so
+//! the server evaluates them homomorphically and asks the players to jointly
+//! decrypt the result. Once the game is over, the players' final `hp` and
+//! `points` go through the same decryption flow to build the leaderboard.
+
+use crate::{
+    bad_request,
+    zone::{num_players, Zone},
+};
+use phantom::{Enc, EncU8, PhantomPackedCt, PhantomPackedCtDecShare};
+use rocket::response::status::Custom;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Time limit of a game, counted from the first move.
+pub const GAME_DURATION_MILLIS: u64 = 20 * 60 * 1000;
+
+/// Time a game over check waits for the decryption shares before it is
+/// replaced by a new one, so a player who never submits a share doesn't stall
+/// the checks for the rest of the game.
+pub const GAME_OVER_CHECK_TIMEOUT_MILLIS: u64 = 60 * 1000;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameOverReason {
+    TimeLimit,
+    MonstersCleared,
+    PlayersDead,
+    Admin,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecryptionPurpose {
+    /// Decrypts to `[all monsters are dead, all players are dead]`.
+    GameOverCheck,
+    /// Decrypts to `hp || points` of each player, see [`Zone::score_bits`].
+    FinalScores,
+}
+
+/// A packed ciphertext waiting for the decryption share of every player.
+#[derive(Clone, Debug)]
+pub struct JointDecryption {
+    pub id: u64,
+    pub purpose: DecryptionPurpose,
+    pub ct: PhantomPackedCt,
+    /// Unix time in millis when the decryption started.
+    pub started_at: u64,
+    dec_shares: Vec<Option<PhantomPackedCtDecShare>>,
+}
+
+impl JointDecryption {
+    pub fn new(id: u64, purpose: DecryptionPurpose, ct: PhantomPackedCt, started_at: u64) -> Self {
+        Self {
+            id,
+            purpose,
+            ct,
+            started_at,
+            dec_shares: vec![None; num_players()],
+        }
+    }
+
+    pub fn has_submitted(&self, player_id: usize) -> bool {
+        self.dec_shares
+            .get(player_id)
+            .is_some_and(|dec_share| dec_share.is_some())
+    }
+
+    pub fn submit(
+        &mut self,
+        player_id: usize,
+        dec_share: PhantomPackedCtDecShare,
+    ) -> Result<(), Custom<String>> {
+        let slot = self
+            .dec_shares
+            .get_mut(player_id)
+            .ok_or_else(|| bad_request("invalid player_id"))?;
+        *slot = Some(dec_share);
+        Ok(())
+    }
+
+    /// Returns the decryption shares once every player has submitted one.
+    pub fn dec_shares(&self) -> Option<Vec<PhantomPackedCtDecShare>> {
+        self.dec_shares.iter().cloned().collect()
+    }
+
+    /// Returns the players who haven't submitted their decryption share yet.
+    pub fn pending_players(&self) -> Vec<usize> {
+        (0..self.dec_shares.len())
+            .filter(|player_id| !self.has_submitted(*player_id))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub player_id: usize,
+    pub points: u8,
+    pub hp: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameResults {
+    pub reason: GameOverReason,
+    /// Unix time in millis when the game ended.
+    pub ended_at: u64,
+    pub leaderboard: Vec<LeaderboardEntry>,
+    /// Hex encoded SHA-256 of the final zone ciphertexts, see [`zone_hash`].
+    pub zone_hash: String,
+}
+
+/// Builds the leaderboard from decrypted [`Zone::score_bits`], ranking players
+/// by points. Players with the same points share the same rank.
+pub fn leaderboard(bits: &[bool]) -> Vec<LeaderboardEntry> {
+    let mut entries = bits
+        .chunks_exact(16)
+        .enumerate()
+        .map(|(player_id, bits)| {
+            let mut bits = bits.iter().copied();
+            LeaderboardEntry {
+                rank: 0,
+                player_id,
+                hp: EncU8::decode(&mut bits),
+                points: EncU8::decode(&mut bits),
+            }
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| b.points.cmp(&a.points).then(a.player_id.cmp(&b.player_id)));
+    let mut rank = (0, None);
+    for (i, entry) in entries.iter_mut().enumerate() {
+        if rank.1 != Some(entry.points) {
+            rank = (i + 1, Some(entry.points));
+        }
+        entry.rank = rank.0;
+    }
+    entries
+}
+
+/// Hex encoded SHA-256 of the bincode serialized [`Zone::cts`], so anyone
+/// holding a copy of the final zone can check it against the results.
+pub fn zone_hash(zone: &Zone) -> String {
+    let digest = Sha256::digest(bincode::serialize(&zone.cts()).unwrap());
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -1,8 +1,14 @@
 use rocket::{http::Status, response::status::Custom};
 
+pub mod admin;
 pub mod client;
-pub mod initial_data;
+pub mod escrow;
+pub mod game_over;
+pub mod map;
+pub mod metrics;
 pub mod mock_zone;
+pub mod respawn;
+pub mod telemetry;
 pub mod worker;
 pub mod zone;
 
@@ -1,6 +1,6 @@
 use phantom::{
-    PhantomBsKey, PhantomEvaluator, PhantomParam, PhantomPk, PhantomRound1Key, PhantomRound2Key,
-    PhantomRpKey,
+    Enc, EncU8, Fingerprint, PhantomBsKey, PhantomCrs, PhantomEvaluator, PhantomPackedCt,
+    PhantomPk, PhantomRound1Key, PhantomRound2Key, PhantomRpKey,
 };
 use rocket::data::{Limits, ToByteUnit};
 use rocket::figment::{util::map, Figment};
@@ -8,14 +8,24 @@ use rocket::futures::stream::FuturesUnordered;
 use rocket::futures::TryStreamExt;
 use rocket::http::{Method, Status};
 use rocket::response::status::{Custom, NotFound};
-use rocket::serde::{json::Json, Deserialize, Serialize};
+use rocket::serde::{json::Json, Serialize};
 use rocket::{Config, State};
 use rocket_cors::{AllowedHeaders, AllowedOrigins, Cors, CorsOptions};
 use server::mock_zone::MockZone;
-use server::zone::{EncryptedDirection, EncryptedRandomState, Zone, ZoneDiff};
+use server::zone::{
+    num_players, EncryptedDirection, EncryptedRandomState, Zone, ZoneDiff, PHANTOM_PARAM,
+};
 use server::{
+    admin::{self, ActionType, Admin},
     bad_request,
     client::*,
+    escrow::{self, GetOverdueDecryptionRequest, GetOverdueDecryptionResponse},
+    game_over::{self, DecryptionPurpose, GameOverReason, GameResults, JointDecryption},
+    internal_server_error,
+    map::MAP,
+    metrics,
+    respawn::{POINTS_FORFEIT, RESPAWN_COOLDOWN_MILLIS},
+    telemetry::{self, RequestId},
     worker::{self, *},
 };
 use std::array::from_fn;
@@ -23,8 +33,7 @@ use std::collections::VecDeque;
 use std::sync::{Arc, LazyLock};
 use std::{env, mem};
 use tokio::sync::{Mutex, Notify};
-use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::{info, info_span, warn, Span};
 
 #[macro_use]
 extern crate rocket;
@@ -41,15 +50,6 @@ const GET_CELL_TIME_MILLIS: u64 = 140; // based on benchmark of 700ms for 5 cell
 const GET_PLAYER_TIME_MILLIS: u64 = 140;
 const MOVE_TIME_RATE_LIMIT_MILLIS: u64 = 3500;
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-pub enum ActionType {
-    None,
-    Move,
-    MoveMonster,
-    MoveFlyer,
-    ResetGame,
-}
-
 struct GameState {
     zone: Option<Zone>,
     mock_zone: Option<MockZone>,
@@ -59,19 +59,59 @@ struct GameState {
         Option<EncryptedDirection>,
         Option<EncryptedRandomState>, // random input
         Option<Arc<Notify>>,
+        Span, // span of the request that queued the action
     )>,
-    player_last_move_time: [u64; 4],
-    mock_player_last_move_time: [u64; 4],
+    player_last_move_time: Vec<u64>,
+    mock_player_last_move_time: Vec<u64>,
+    player_last_seen_time: Vec<u64>,
+    respawn_pending: Vec<bool>,
+    // Admin controls
+    action_loop_paused: bool,
+    monster_loop_paused: bool,
+    move_rate_limit_millis: u64,
+    // Game over
+    game_start_time: Option<u64>,
+    game_over: Option<(GameOverReason, u64)>, // reason and time
+    decryption: Option<JointDecryption>,
+    decryption_counter: u64,
+    results: Option<GameResults>,
     // Phantom
     evaluator: PhantomEvaluator,
-    player_round_1_key: [Option<PhantomRound1Key>; 4],
-    player_round_2_key: [Option<PhantomRound2Key>; 4],
+    player_round_1_key: Vec<Option<PhantomRound1Key>>,
+    player_round_2_key: Vec<Option<PhantomRound2Key>>,
     work_counter: usize,
     // For each worker, store flags indicating whether to sync players data or not.
-    worker_diff: Vec<[bool; 4]>,
+    worker_diff: Vec<Vec<bool>>,
 }
 
 impl GameState {
+    /// Returns the state of a new game, waiting for the players' key shares
+    /// under a fresh CRS, so they can't be replayed across games.
+    fn new() -> Self {
+        Self {
+            zone: None, // Will be initialized from MAP when keygen is finished.
+            mock_zone: None,
+            action_queue: VecDeque::new(),
+            player_last_move_time: vec![0; num_players()],
+            mock_player_last_move_time: vec![0; num_players()],
+            player_last_seen_time: vec![0; num_players()],
+            respawn_pending: vec![false; num_players()],
+            action_loop_paused: false,
+            monster_loop_paused: false,
+            move_rate_limit_millis: MOVE_TIME_RATE_LIMIT_MILLIS,
+            game_start_time: None,
+            game_over: None,
+            decryption: None,
+            decryption_counter: 0,
+            results: None,
+            evaluator: PhantomEvaluator::new(PHANTOM_PARAM.with_crs(PhantomCrs::from_entropy())),
+            player_round_1_key: vec![None; num_players()],
+            player_round_2_key: vec![None; num_players()],
+            work_counter: 0,
+            worker_diff: vec![vec![false; num_players()]; WORKER_URIS.len()],
+        }
+    }
+
     fn zone(&self) -> Result<&Zone, Custom<String>> {
         self.zone
             .as_ref()
@@ -96,16 +136,107 @@ impl GameState {
             .ok_or_else(|| Custom(Status::BadRequest, "Game is not ready yet".to_string()))
     }
 
+    fn mark_seen(&mut self, player_id: usize) {
+        if let Some(last_seen_time) = self.player_last_seen_time.get_mut(player_id) {
+            *last_seen_time = current_time_millis();
+        }
+    }
+
+    fn loops_status(&self) -> admin::LoopsStatus {
+        admin::LoopsStatus {
+            action_loop_paused: self.action_loop_paused,
+            monster_loop_paused: self.monster_loop_paused,
+        }
+    }
+
+    fn start_decryption(&mut self, purpose: DecryptionPurpose, ct: PhantomPackedCt) {
+        self.decryption_counter += 1;
+        self.decryption = Some(JointDecryption::new(
+            self.decryption_counter,
+            purpose,
+            ct,
+            current_time_millis(),
+        ));
+    }
+
+    /// Starts a game over check, or ends the game if the time limit is hit.
+    fn check_game_over(&mut self) {
+        let Some(game_start_time) = self.game_start_time else {
+            return;
+        };
+        if self.game_over.is_some() {
+            return;
+        }
+        if current_time_millis() - game_start_time > game_over::GAME_DURATION_MILLIS {
+            self.end_game(GameOverReason::TimeLimit);
+            return;
+        }
+        // Wait for the players to finish the previous decryption, unless it
+        // timed out.
+        if let Some(decryption) = &self.decryption {
+            if current_time_millis() - decryption.started_at
+                < game_over::GAME_OVER_CHECK_TIMEOUT_MILLIS
+            {
+                return;
+            }
+            warn!(
+                id = decryption.id,
+                pending_players = ?decryption.pending_players(),
+                "game over check timed out"
+            );
+        }
+        if let Some(zone) = &self.zone {
+            let flags = zone.game_over_flags();
+            let ct = telemetry::pack(&self.evaluator, &flags);
+            self.start_decryption(DecryptionPurpose::GameOverCheck, ct);
+        }
+    }
+
+    /// Ends the game and starts the joint decryption of final scores. Returns
+    /// the number of queued actions dropped.
+    fn end_game(&mut self, reason: GameOverReason) -> usize {
+        // Wake up the pending moves, the zone is frozen from now on.
+        let action_queue = mem::take(&mut self.action_queue);
+        let dropped_actions = action_queue.len();
+        action_queue
+            .into_iter()
+            .filter_map(|(.., notify, _)| notify)
+            .for_each(|notify| notify.notify_one());
+        metrics::ACTION_QUEUE_DEPTH.set(0);
+
+        self.game_over = Some((reason, current_time_millis()));
+        if let Some(zone) = &self.zone {
+            let ct = telemetry::pack(&self.evaluator, zone.score_bits());
+            self.start_decryption(DecryptionPurpose::FinalScores, ct);
+        }
+
+        info!("game over: {reason:?}");
+
+        dropped_actions
+    }
+
+    /// Returns the keys the workers evaluate with, once keygen is finished.
+    fn worker_keys(&self) -> Option<(PhantomPk, PhantomBsKey, PhantomRpKey)> {
+        Some((
+            self.evaluator.pk()?.clone(),
+            self.evaluator.bs_key()?.clone(),
+            self.evaluator.rp_key()?.clone(),
+        ))
+    }
+
     fn next_worker_uri_and_diff(&mut self) -> Result<(&'static str, ZoneDiff), Custom<String>> {
         self.zone
             .as_ref()
             .map(|zone| {
                 let next = self.work_counter % WORKER_URIS.len();
                 self.work_counter += 1;
-                (
-                    WORKER_URIS[next].as_str(),
-                    zone.cts_diff(mem::take(&mut self.worker_diff[next])),
-                )
+                let flags = vec![false; num_players()];
+                let diff = zone.cts_diff(&mem::replace(&mut self.worker_diff[next], flags));
+                metrics::WORKER_DISPATCHES
+                    .with_label_values(&[&WORKER_URIS[next]])
+                    .inc();
+                metrics::DIFF_SIZE_BYTES.observe(bincode::serialized_size(&diff).unwrap() as f64);
+                (WORKER_URIS[next].as_str(), diff)
             })
             .ok_or_else(|| Custom(Status::BadRequest, "Game is not ready yet".to_string()))
     }
@@ -113,6 +244,13 @@ impl GameState {
 
 type SharedState = Arc<Mutex<GameState>>;
 
+fn current_time_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
 #[derive(Serialize)]
 struct SubmitRound2KeyResponse {}
 
@@ -151,73 +289,48 @@ async fn reset_game(
 ) -> Result<Json<ResetGameResponse>, Custom<String>> {
     let mut game_state = state.lock().await;
 
-    // Don't reset again if the game state is just reset.
-    if game_state.zone.is_some() && game_state.player_last_move_time == [0, 0, 0, 0] {
+    // Don't reset again if no one has moved since the last reset, e.g. while
+    // the players run the key generation of the new game.
+    if game_state
+        .player_last_move_time
+        .iter()
+        .all(|&time| time == 0)
+    {
         return Ok(Json(ResetGameResponse {}));
     }
 
-    game_state.zone = Some(Zone::new(32, 32, &game_state.evaluator));
-    game_state.mock_zone = Some(MockZone::new(32, 32));
-    game_state.action_queue = VecDeque::new();
-    game_state.player_last_move_time = [0, 0, 0, 0];
-    game_state.work_counter = 0;
-    game_state.worker_diff = vec![Default::default(); WORKER_URIS.len()];
-
-    // Call /init without keys to all workers
-    let request = InitRequest {
-        zone_width: 32,
-        zone_height: 32,
-        zone_cts: game_state.zone.as_ref().unwrap().cts(),
-        keys: None,
-    };
-    WORKER_URIS
-        .iter()
-        .map(|worker_uri| worker::request::<_, InitResponse>(worker_uri, "/init", request.clone()))
-        .collect::<FuturesUnordered<_>>()
-        .try_collect::<Vec<_>>()
-        .await?;
+    // The players generate their keys again under a fresh CRS, and the workers
+    // are initialized with them once it's done, see `submit_r2`.
+    let previous = mem::replace(&mut *game_state, GameState::new());
+    // Admin controls outlive games, and decryption ids stay unique.
+    game_state.action_loop_paused = previous.action_loop_paused;
+    game_state.monster_loop_paused = previous.monster_loop_paused;
+    game_state.move_rate_limit_millis = previous.move_rate_limit_millis;
+    game_state.decryption_counter = previous.decryption_counter;
+    metrics::ACTION_QUEUE_DEPTH.set(0);
 
     info!("processed /reset_game request");
 
     Ok(Json(ResetGameResponse {}))
 }
 
-#[post("/reset", format = "json", data = "<_request>")]
-async fn reset(
-    state: &State<SharedState>,
-    _request: Json<ResetRequest>,
-) -> Result<Json<ResetResponse>, Custom<String>> {
-    let mut game_state = state.lock().await;
-
-    *game_state = GameState {
-        zone: None, // 32x32 zone, will be initialized when keygen is finished.
-        mock_zone: None,
-        action_queue: VecDeque::new(),
-        player_last_move_time: [0, 0, 0, 0],
-        mock_player_last_move_time: [0, 0, 0, 0],
-        evaluator: PhantomEvaluator::new(PhantomParam::I_4P_40),
-        player_round_1_key: [None, None, None, None],
-        player_round_2_key: [None, None, None, None],
-        work_counter: 0,
-        worker_diff: vec![Default::default(); WORKER_URIS.len()],
-    };
-
-    info!("processed /reset request");
-
-    Ok(Json(ResetResponse {}))
-}
-
 #[post("/get_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cells(
     state: &State<SharedState>,
     request: Json<GetCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetCellsResponse>, Custom<String>> {
-    let (worker_uri, diff) = state.lock().await.next_worker_uri_and_diff()?;
+    let (worker_uri, diff) = {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
+        game_state.next_worker_uri_and_diff()?
+    };
     let request = RequestWithDiff {
         request: request.0,
         diff,
     };
-    worker::request(worker_uri, "/get_cells", request).await
+    worker::request(worker_uri, "/get_cells", request, &request_id).await
 }
 
 #[post("/mock_get_cells", format = "json", data = "<request>")]
@@ -238,55 +351,79 @@ async fn mock_get_cells(
 }
 
 #[post("/get_five_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_five_cells(
     state: &State<SharedState>,
     request: Json<GetFiveCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetFiveCellsResponse>, Custom<String>> {
-    let (worker_uri, diff) = state.lock().await.next_worker_uri_and_diff()?;
+    let (worker_uri, diff) = {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
+        game_state.next_worker_uri_and_diff()?
+    };
     let request = RequestWithDiff {
         request: request.0,
         diff,
     };
-    worker::request(worker_uri, "/get_five_cells", request).await
+    worker::request(worker_uri, "/get_five_cells", request, &request_id).await
 }
 
 #[post("/get_cross_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cross_cells(
     state: &State<SharedState>,
     request: Json<GetCrossCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetCrossCellsResponse>, Custom<String>> {
-    let (worker_uri, diff) = state.lock().await.next_worker_uri_and_diff()?;
+    let (worker_uri, diff) = {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
+        game_state.next_worker_uri_and_diff()?
+    };
     let request = RequestWithDiff {
         request: request.0,
         diff,
     };
-    worker::request(worker_uri, "/get_cross_cells", request).await
+    worker::request(worker_uri, "/get_cross_cells", request, &request_id).await
 }
 
 #[post("/get_vertical_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_vertical_cells(
     state: &State<SharedState>,
     request: Json<GetVerticalCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetVerticalCellsResponse>, Custom<String>> {
-    let (worker_uri, diff) = state.lock().await.next_worker_uri_and_diff()?;
+    let (worker_uri, diff) = {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
+        game_state.next_worker_uri_and_diff()?
+    };
     let request = RequestWithDiff {
         request: request.0,
         diff,
     };
-    worker::request(worker_uri, "/get_vertical_cells", request).await
+    worker::request(worker_uri, "/get_vertical_cells", request, &request_id).await
 }
 
 #[post("/get_horizontal_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_horizontal_cells(
     state: &State<SharedState>,
     request: Json<GetHorizontalCellsRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetHorizontalCellsResponse>, Custom<String>> {
-    let (worker_uri, diff) = state.lock().await.next_worker_uri_and_diff()?;
+    let (worker_uri, diff) = {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
+        game_state.next_worker_uri_and_diff()?
+    };
     let request = RequestWithDiff {
         request: request.0,
         diff,
     };
-    worker::request(worker_uri, "/get_horizontal_cells", request).await
+    worker::request(worker_uri, "/get_horizontal_cells", request, &request_id).await
 }
 
 #[post("/mock_get_player", format = "json", data = "<request>")]
@@ -308,16 +445,20 @@ async fn mock_get_player(
 }
 
 #[post("/get_player", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_player(
     state: &State<SharedState>,
     request: Json<GetPlayerRequest>,
+    request_id: RequestId,
 ) -> Result<Json<GetPlayerResponse>, Custom<String>> {
     let player_response = {
-        let game_state = state.lock().await;
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(request.player_id);
         let zone = game_state.zone()?;
-        game_state
-            .evaluator
-            .pack(zone.get_player(request.player_id).bits())
+        telemetry::pack(
+            &game_state.evaluator,
+            zone.get_player(request.player_id).bits(),
+        )
     };
 
     info!("processed /get_player request");
@@ -339,7 +480,7 @@ async fn mock_move(
             .as_millis() as u64;
         let game_state = state.lock().await;
         let last_request_time = game_state.mock_player_last_move_time[move_request.player_id];
-        current_time - last_request_time > MOVE_TIME_RATE_LIMIT_MILLIS
+        current_time - last_request_time > game_state.move_rate_limit_millis
     };
 
     if !can_move {
@@ -351,7 +492,7 @@ async fn mock_move(
 
     {
         let mut game_state = state.lock().await;
-        let zone = &mut game_state.mock_zone_mut().unwrap();
+        let zone = game_state.mock_zone_mut()?;
         let (direction, random_input) = move_request.direction_and_random_input;
         zone.move_player(move_request.player_id, direction);
         zone.mix_random_input(move_request.player_id, random_input);
@@ -368,7 +509,7 @@ async fn mock_move(
 
     let my_new_coords = {
         let game_state = state.lock().await;
-        game_state.mock_zone().unwrap().players[move_request.player_id]
+        game_state.mock_zone()?.players[move_request.player_id]
             .data
             .loc
     };
@@ -382,21 +523,29 @@ async fn mock_move(
 }
 
 #[post("/move", format = "json", data = "<move_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn queue_move(
     state: &State<SharedState>,
     move_request: Json<MoveRequest>,
+    request_id: RequestId,
 ) -> Result<Json<MoveResponse>, Custom<String>> {
     let can_move = {
         let current_time = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_millis() as u64;
-        let game_state = state.lock().await;
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(move_request.player_id);
+        game_state.zone()?;
+        if game_state.game_over.is_some() {
+            return Err(bad_request("Game is over"));
+        }
         let last_request_time = game_state.player_last_move_time[move_request.player_id];
-        current_time - last_request_time > MOVE_TIME_RATE_LIMIT_MILLIS
+        current_time - last_request_time > game_state.move_rate_limit_millis
     };
 
     if !can_move {
+        metrics::RATE_LIMIT_HITS.inc();
         return Ok(Json(MoveResponse {
             my_new_coords: None,
             rate_limited: true,
@@ -408,36 +557,45 @@ async fn queue_move(
 
     {
         let mut game_state = state.lock().await;
-        let direction_and_random_input = game_state
-            .evaluator
-            .unbatch(&move_request.direction_and_random_input);
+        // The keys the input is checked against exist only once the zone
+        // does, and a reset may have dropped both since the check above.
+        game_state.zone()?;
+        let direction_and_random_input = telemetry::unbatch(
+            &game_state.evaluator,
+            &move_request.direction_and_random_input,
+        )
+        .map_err(bad_request)?;
         if direction_and_random_input.len() != 10 {
             return Err(bad_request("invalid direction_and_random_input"));
         }
         let mut direction_and_random_input = direction_and_random_input.into_iter();
         let direction = from_fn(|_| direction_and_random_input.next().unwrap());
-        let random_input = from_fn(|_| direction_and_random_input.next().unwrap());
+        let random_input = EncU8::from_bits(&mut direction_and_random_input);
         game_state.action_queue.push_back((
             ActionType::Move,
             Some(move_request.player_id),
             Some(direction),
             Some(random_input),
             Some(notify_clone),
+            Span::current(),
         ));
+        metrics::ACTION_QUEUE_DEPTH.set(game_state.action_queue.len() as i64);
         game_state.player_last_move_time[move_request.player_id] = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_millis() as u64;
+        game_state
+            .game_start_time
+            .get_or_insert_with(current_time_millis);
     }
 
     notify.notified().await;
 
     let game_state = state.lock().await;
-    let my_new_coords = game_state.evaluator.pack(
-        game_state.zone()?.players[move_request.player_id]
-            .data
-            .loc
-            .bits(),
+    let zone = game_state.zone()?;
+    let my_new_coords = telemetry::pack(
+        &game_state.evaluator,
+        zone.players[move_request.player_id].data.loc.bits(),
     );
 
     info!("processed /move request");
@@ -448,11 +606,77 @@ async fn queue_move(
     }))
 }
 
+#[post("/respawn", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn respawn(
+    state: &State<SharedState>,
+    request: Json<RespawnRequest>,
+    request_id: RequestId,
+) -> Result<Json<RespawnResponse>, Custom<String>> {
+    let player_id = request.player_id;
+    if player_id >= num_players() {
+        return Err(bad_request("invalid player_id"));
+    }
+
+    {
+        let mut game_state = state.lock().await;
+        game_state.mark_seen(player_id);
+        game_state.zone()?;
+        if game_state.game_over.is_some() {
+            return Err(bad_request("Game is over"));
+        }
+        if mem::replace(&mut game_state.respawn_pending[player_id], true) {
+            return Err(bad_request("Respawn is already pending"));
+        }
+    }
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(RESPAWN_COOLDOWN_MILLIS)).await;
+
+    let notify = Arc::new(Notify::new());
+
+    {
+        let mut game_state = state.lock().await;
+        if game_state.game_over.is_some() {
+            game_state.respawn_pending[player_id] = false;
+            return Err(bad_request("Game is over"));
+        }
+        game_state.action_queue.push_back((
+            ActionType::Respawn,
+            Some(player_id),
+            None,
+            None,
+            Some(notify.clone()),
+            Span::current(),
+        ));
+        metrics::ACTION_QUEUE_DEPTH.set(game_state.action_queue.len() as i64);
+    }
+
+    notify.notified().await;
+
+    let mut game_state = state.lock().await;
+    game_state.respawn_pending[player_id] = false;
+    let zone = game_state.zone()?;
+    let my_new_coords = telemetry::pack(
+        &game_state.evaluator,
+        zone.players[player_id].data.loc.bits(),
+    );
+
+    info!("processed /respawn request");
+
+    Ok(Json(RespawnResponse { my_new_coords }))
+}
+
 async fn process_actions(state: SharedState) {
     loop {
-        let (action_type, entity_id, direction, random_input, notify) = {
+        let (action_type, entity_id, direction, random_input, notify, span) = {
             let mut game_state = state.lock().await;
+            if game_state.action_loop_paused {
+                drop(game_state);
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                continue;
+            }
             if let Some(action_request) = game_state.action_queue.pop_front() {
+                metrics::ACTION_QUEUE_DEPTH.set(game_state.action_queue.len() as i64);
                 action_request
             } else {
                 // No moves to process, wait for a new move to be queued
@@ -466,24 +690,61 @@ async fn process_actions(state: SharedState) {
             match action_type {
                 ActionType::Move => {
                     let mut game_state = state.lock().await;
-                    let zone = game_state.zone_mut().unwrap();
-                    let start = std::time::Instant::now();
+                    // A reset since the action was queued drops the zone,
+                    // release the waiter which then reports the game is not
+                    // ready.
+                    let Ok(zone) = game_state.zone_mut() else {
+                        notify.unwrap().notify_one();
+                        continue;
+                    };
                     let unwrapped_player_id = entity_id.unwrap();
+                    let _span =
+                        info_span!(parent: &span, "move_player", player_id = unwrapped_player_id)
+                            .entered();
                     let unwrapped_direction = direction.unwrap();
                     let unwrapped_random_input = random_input.unwrap();
                     zone.move_player(unwrapped_player_id, unwrapped_direction);
                     zone.mix_random_input(unwrapped_player_id, unwrapped_random_input);
-                    info!("zone.move_player takes: {:?}", start.elapsed());
 
-                    // For each worker, mark the flag of `player_id` to be true.
+                    // The move may have attacked any other player, so for each
+                    // worker mark the flags of every player to be true.
                     game_state
                         .worker_diff
                         .iter_mut()
-                        .for_each(|flag| flag[unwrapped_player_id] = true);
+                        .for_each(|flags| flags.fill(true));
 
                     let unwrapped_notify = notify.unwrap();
                     unwrapped_notify.notify_one();
                 }
+                ActionType::Respawn => {
+                    let mut game_state = state.lock().await;
+                    let game_state = &mut *game_state;
+                    let Some(zone) = game_state.zone.as_mut() else {
+                        notify.unwrap().notify_one();
+                        continue;
+                    };
+                    let unwrapped_player_id = entity_id.unwrap();
+                    let _span =
+                        info_span!(parent: &span, "respawn_player", player_id = unwrapped_player_id)
+                            .entered();
+                    zone.respawn_player(
+                        unwrapped_player_id,
+                        POINTS_FORFEIT.shift(),
+                        &game_state.evaluator,
+                    );
+
+                    // For each worker, mark the flag of `player_id` to be true.
+                    game_state
+                        .worker_diff
+                        .iter_mut()
+                        .for_each(|flag| flag[unwrapped_player_id] = true);
+
+                    notify.unwrap().notify_one();
+                }
+                ActionType::CheckGameOver => {
+                    let mut game_state = state.lock().await;
+                    game_state.check_game_over();
+                }
                 ActionType::ResetGame => {}
                 ActionType::MoveMonster => {
                     let mut game_state = state.lock().await;
@@ -495,10 +756,11 @@ async fn process_actions(state: SharedState) {
                         }
                     }
                     if has_started {
-                        let zone = game_state.zone_mut().unwrap();
-                        let start = std::time::Instant::now();
+                        let Ok(zone) = game_state.zone_mut() else {
+                            continue;
+                        };
+                        let _span = info_span!("move_random_monster").entered();
                         zone.move_random_monster();
-                        info!("zone.move_random_monster takes: {:?}", start.elapsed());
                     } else {
                         println!("Game has not started yet, waiting for players to move");
                     }
@@ -513,14 +775,43 @@ async fn process_actions(state: SharedState) {
                         }
                     }
                     if has_started {
-                        let zone = game_state.zone_mut().unwrap();
-                        let start = std::time::Instant::now();
+                        let Ok(zone) = game_state.zone_mut() else {
+                            continue;
+                        };
+                        let _span = info_span!("move_random_flyer").entered();
                         zone.move_random_flyer();
-                        info!("zone.move_random_flyer takes: {:?}", start.elapsed());
                     } else {
                         println!("Game has not started yet, waiting for players to move");
                     }
                 }
+                ActionType::MaintainMonsters => {
+                    let mut game_state = state.lock().await;
+                    if game_state
+                        .player_last_move_time
+                        .iter()
+                        .any(|time| *time > 0)
+                    {
+                        let Ok(zone) = game_state.zone_mut() else {
+                            continue;
+                        };
+                        let _span = info_span!("maintain_monsters").entered();
+                        zone.maintain_monsters();
+                    }
+                }
+                ActionType::MaintainItems => {
+                    let mut game_state = state.lock().await;
+                    if game_state
+                        .player_last_move_time
+                        .iter()
+                        .any(|time| *time > 0)
+                    {
+                        let Ok(zone) = game_state.zone_mut() else {
+                            continue;
+                        };
+                        let _span = info_span!("maintain_items").entered();
+                        zone.maintain_items();
+                    }
+                }
                 ActionType::None => {}
             }
         }
@@ -532,15 +823,57 @@ async fn start_monster_loop(state: SharedState) {
         {
             let mut game_state = state.lock().await;
 
-            game_state
-                .action_queue
-                .push_back((ActionType::MoveMonster, None, None, None, None));
-            game_state
-                .action_queue
-                .push_back((ActionType::MoveFlyer, None, None, None, None));
-            game_state
-                .action_queue
-                .push_back((ActionType::MoveFlyer, None, None, None, None));
+            if !game_state.monster_loop_paused && game_state.game_over.is_none() {
+                game_state.action_queue.push_back((
+                    ActionType::MoveMonster,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                game_state.action_queue.push_back((
+                    ActionType::MoveFlyer,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                game_state.action_queue.push_back((
+                    ActionType::MoveFlyer,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                game_state.action_queue.push_back((
+                    ActionType::MaintainMonsters,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                game_state.action_queue.push_back((
+                    ActionType::MaintainItems,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                game_state.action_queue.push_back((
+                    ActionType::CheckGameOver,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Span::none(),
+                ));
+                metrics::ACTION_QUEUE_DEPTH.set(game_state.action_queue.len() as i64);
+            }
         }
         tokio::time::sleep(tokio::time::Duration::from_millis(7000)).await;
     }
@@ -559,10 +892,16 @@ async fn start_mock_monster_loop(state: SharedState) {
                 }
             }
 
-            if has_started {
-                let mock_zone = game_state.mock_zone_mut().unwrap();
-                mock_zone.move_random_monster();
-                mock_zone.move_random_flyer();
+            if game_state.monster_loop_paused {
+                // Paused by an admin.
+            } else if has_started {
+                // The mock zone is created with the keys.
+                if let Ok(mock_zone) = game_state.mock_zone_mut() {
+                    mock_zone.move_random_monster();
+                    mock_zone.move_random_flyer();
+                    mock_zone.maintain_monsters();
+                    mock_zone.maintain_items();
+                }
             } else {
                 println!("MOCK: Game has not started yet, waiting for players to move");
             }
@@ -572,18 +911,34 @@ async fn start_mock_monster_loop(state: SharedState) {
     }
 }
 
+#[post("/get_crs", format = "json", data = "<_request>")]
+async fn get_crs(
+    state: &State<SharedState>,
+    _request: Json<GetCrsRequest>,
+) -> Json<GetCrsResponse> {
+    let crs = *state.lock().await.evaluator.param().crs();
+    Json(GetCrsResponse { crs })
+}
+
 #[post("/submit_r1", format = "json", data = "<request>")]
 async fn submit_r1(
     state: &State<SharedState>,
     request: Json<SubmitRound1KeyRequest>,
-) -> Json<SubmitRound1KeyResponse> {
+) -> Result<Json<SubmitRound1KeyResponse>, Custom<String>> {
     let mut game_state = state.lock().await;
 
-    // Ignore key share submission if keygen is already done.
-    if game_state.zone.is_some() {
-        return Json(SubmitRound1KeyResponse {});
+    // Ignore key share submission if round 1 is already done.
+    if game_state.evaluator.pk().is_some() {
+        return Ok(Json(SubmitRound1KeyResponse {}));
+    }
+    if request.player_id >= num_players() {
+        return Err(bad_request("Invalid player_id"));
     }
 
+    game_state
+        .evaluator
+        .check_crs_id(request.key.crs_id())
+        .map_err(bad_request)?;
     game_state.player_round_1_key[request.0.player_id] = Some(request.0.key);
 
     if game_state.player_round_1_key.iter().all(Option::is_some) {
@@ -593,10 +948,13 @@ async fn submit_r1(
             .flatten()
             .cloned()
             .collect();
-        game_state.evaluator.aggregate_round_1_keys(&round_1_keys);
+        game_state
+            .evaluator
+            .aggregate_round_1_keys(&round_1_keys)
+            .map_err(internal_server_error)?;
     }
 
-    Json(SubmitRound1KeyResponse {})
+    Ok(Json(SubmitRound1KeyResponse {}))
 }
 
 #[post("/get_pk", format = "json", data = "<_request>")]
@@ -612,9 +970,11 @@ async fn get_pk(
 }
 
 #[post("/submit_r2", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn submit_r2(
     state: &State<SharedState>,
     request: Json<SubmitRound2KeyRequest>,
+    request_id: RequestId,
 ) -> Result<Json<SubmitRound2KeyResponse>, Custom<String>> {
     let mut game_state = state.lock().await;
 
@@ -622,7 +982,14 @@ async fn submit_r2(
     if game_state.zone.is_some() {
         return Ok(Json(SubmitRound2KeyResponse {}));
     }
+    if request.player_id >= num_players() {
+        return Err(bad_request("Invalid player_id"));
+    }
 
+    game_state
+        .evaluator
+        .check_crs_id(request.key.crs_id())
+        .map_err(bad_request)?;
     game_state.player_round_2_key[request.0.player_id] = Some(request.0.key);
 
     if game_state.player_round_2_key.iter().all(Option::is_some) {
@@ -632,25 +999,37 @@ async fn submit_r2(
             .flatten()
             .cloned()
             .collect();
-        game_state.evaluator.aggregate_round_2_keys(&round_2_keys);
-        game_state.zone = Some(Zone::new(32, 32, &game_state.evaluator));
-        game_state.mock_zone = Some(MockZone::new(32, 32));
+        game_state
+            .evaluator
+            .aggregate_round_2_keys(&round_2_keys)
+            .map_err(internal_server_error)?;
+        info!(
+            pk = %game_state.evaluator.pk().unwrap().fingerprint(),
+            rp_key = %game_state.evaluator.rp_key().unwrap().fingerprint(),
+            bs_key = %game_state.evaluator.bs_key().unwrap().fingerprint(),
+            "aggregated keys"
+        );
+        game_state.zone = Some(Zone::new(&MAP, &game_state.evaluator));
+        game_state.mock_zone = Some(MockZone::new(&MAP));
+        metrics::KEY_VERSION.inc();
+        metrics::ZONE_VERSION.inc();
 
         // Call /init to all workers
         let request = InitRequest {
-            zone_width: 32,
-            zone_height: 32,
+            zone_width: MAP.width,
+            zone_height: MAP.height,
             zone_cts: game_state.zone.as_ref().unwrap().cts(),
-            keys: Some((
-                game_state.evaluator.pk().unwrap().clone(),
-                game_state.evaluator.bs_key().unwrap().clone(),
-                game_state.evaluator.rp_key().unwrap().clone(),
-            )),
+            keys: game_state.worker_keys(),
         };
         WORKER_URIS
             .iter()
             .map(|worker_uri| {
-                worker::request::<_, InitResponse>(worker_uri, "/init", request.clone())
+                worker::request::<_, InitResponse>(
+                    worker_uri,
+                    "/init",
+                    request.clone(),
+                    &request_id,
+                )
             })
             .collect::<FuturesUnordered<_>>()
             .try_collect::<Vec<_>>()
@@ -660,33 +1039,326 @@ async fn submit_r2(
     Ok(Json(SubmitRound2KeyResponse {}))
 }
 
+#[post("/pause", format = "json", data = "<request>")]
+async fn admin_pause(
+    _admin: Admin,
+    state: &State<SharedState>,
+    request: Json<admin::PauseRequest>,
+) -> Json<admin::PauseResponse> {
+    let mut game_state = state.lock().await;
+    game_state.action_loop_paused |= request.loops.actions();
+    game_state.monster_loop_paused |= request.loops.monsters();
+
+    info!("processed /admin/pause request");
+
+    Json(admin::PauseResponse {
+        status: game_state.loops_status(),
+    })
+}
+
+#[post("/resume", format = "json", data = "<request>")]
+async fn admin_resume(
+    _admin: Admin,
+    state: &State<SharedState>,
+    request: Json<admin::ResumeRequest>,
+) -> Json<admin::ResumeResponse> {
+    let mut game_state = state.lock().await;
+    game_state.action_loop_paused &= !request.loops.actions();
+    game_state.monster_loop_paused &= !request.loops.monsters();
+
+    info!("processed /admin/resume request");
+
+    Json(admin::ResumeResponse {
+        status: game_state.loops_status(),
+    })
+}
+
+#[post("/resync_workers", format = "json", data = "<_request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn admin_resync_workers(
+    _admin: Admin,
+    state: &State<SharedState>,
+    _request: Json<admin::ResyncWorkersRequest>,
+    request_id: RequestId,
+) -> Result<Json<admin::ResyncWorkersResponse>, Custom<String>> {
+    let mut game_state = state.lock().await;
+
+    // Send the whole zone to every worker, so no diff is pending afterwards.
+    let zone = game_state.zone()?;
+    let request = InitRequest {
+        zone_width: zone.width,
+        zone_height: zone.height,
+        zone_cts: zone.cts(),
+        // A restarted worker has no keys.
+        keys: game_state.worker_keys(),
+    };
+    WORKER_URIS
+        .iter()
+        .map(|worker_uri| {
+            worker::request::<_, InitResponse>(worker_uri, "/init", request.clone(), &request_id)
+        })
+        .collect::<FuturesUnordered<_>>()
+        .try_collect::<Vec<_>>()
+        .await?;
+    game_state.worker_diff = vec![vec![false; num_players()]; WORKER_URIS.len()];
+
+    info!("processed /admin/resync_workers request");
+
+    Ok(Json(admin::ResyncWorkersResponse {
+        workers: WORKER_URIS.clone(),
+    }))
+}
+
+#[post("/set_rate_limit", format = "json", data = "<request>")]
+async fn admin_set_rate_limit(
+    _admin: Admin,
+    state: &State<SharedState>,
+    request: Json<admin::SetRateLimitRequest>,
+) -> Json<admin::SetRateLimitResponse> {
+    let mut game_state = state.lock().await;
+    let previous_move_rate_limit_millis = mem::replace(
+        &mut game_state.move_rate_limit_millis,
+        request.move_rate_limit_millis,
+    );
+
+    info!("processed /admin/set_rate_limit request");
+
+    Json(admin::SetRateLimitResponse {
+        previous_move_rate_limit_millis,
+        move_rate_limit_millis: game_state.move_rate_limit_millis,
+    })
+}
+
+#[post("/players", format = "json", data = "<_request>")]
+async fn admin_list_players(
+    _admin: Admin,
+    state: &State<SharedState>,
+    _request: Json<admin::ListPlayersRequest>,
+) -> Json<admin::ListPlayersResponse> {
+    let game_state = state.lock().await;
+    let players = (0..num_players())
+        .map(|player_id| admin::PlayerStatus {
+            player_id,
+            last_seen_time: game_state.player_last_seen_time[player_id],
+            last_move_time: game_state.player_last_move_time[player_id],
+            round_1_key_submitted: game_state.player_round_1_key[player_id].is_some(),
+            round_2_key_submitted: game_state.player_round_2_key[player_id].is_some(),
+        })
+        .collect();
+    let keygen = admin::KeygenStatus {
+        pk_ready: game_state.evaluator.pk().is_some(),
+        bs_key_ready: game_state.evaluator.bs_key().is_some(),
+        rp_key_ready: game_state.evaluator.rp_key().is_some(),
+    };
+
+    info!("processed /admin/players request");
+
+    Json(admin::ListPlayersResponse {
+        players,
+        keygen,
+        game_ready: game_state.zone.is_some(),
+    })
+}
+
+#[post("/queue", format = "json", data = "<_request>")]
+async fn admin_inspect_queue(
+    _admin: Admin,
+    state: &State<SharedState>,
+    _request: Json<admin::InspectQueueRequest>,
+) -> Json<admin::InspectQueueResponse> {
+    let game_state = state.lock().await;
+    let actions = game_state
+        .action_queue
+        .iter()
+        .map(|(action_type, entity_id, ..)| admin::QueuedAction {
+            action_type: *action_type,
+            entity_id: *entity_id,
+        })
+        .collect();
+
+    info!("processed /admin/queue request");
+
+    Json(admin::InspectQueueResponse {
+        status: game_state.loops_status(),
+        move_rate_limit_millis: game_state.move_rate_limit_millis,
+        actions,
+    })
+}
+
+#[post("/end_game", format = "json", data = "<_request>")]
+async fn admin_end_game(
+    _admin: Admin,
+    state: &State<SharedState>,
+    _request: Json<admin::EndGameRequest>,
+) -> Result<Json<admin::EndGameResponse>, Custom<String>> {
+    let mut game_state = state.lock().await;
+    game_state.zone()?;
+    if game_state.game_over.is_some() {
+        return Err(bad_request("Game is already over"));
+    }
+
+    let dropped_actions = game_state.end_game(GameOverReason::Admin);
+
+    info!("processed /admin/end_game request");
+
+    Ok(Json(admin::EndGameResponse { dropped_actions }))
+}
+
+#[post("/reset", format = "json", data = "<_request>")]
+async fn admin_reset(
+    _admin: Admin,
+    state: &State<SharedState>,
+    _request: Json<admin::ResetRequest>,
+) -> Json<admin::ResetResponse> {
+    let mut game_state = state.lock().await;
+
+    // Unlike `/reset_game`, this also drops the admin controls.
+    *game_state = GameState::new();
+    metrics::ACTION_QUEUE_DEPTH.set(0);
+
+    info!("processed /admin/reset request");
+
+    Json(admin::ResetResponse {})
+}
+
+#[post("/get_pending_decryption", format = "json", data = "<request>")]
+async fn get_pending_decryption(
+    state: &State<SharedState>,
+    request: Json<GetPendingDecryptionRequest>,
+) -> Json<GetPendingDecryptionResponse> {
+    let mut game_state = state.lock().await;
+    game_state.mark_seen(request.player_id);
+
+    let decryption = game_state
+        .decryption
+        .as_ref()
+        .filter(|decryption| !decryption.has_submitted(request.player_id))
+        .map(|decryption| PendingDecryption {
+            id: decryption.id,
+            ct: decryption.ct.clone(),
+        });
+
+    Json(GetPendingDecryptionResponse { decryption })
+}
+
+/// Returns the decryption the escrow should stand in for players in, without
+/// marking them as seen.
+#[post("/get_overdue_decryption", format = "json", data = "<_request>")]
+async fn get_overdue_decryption(
+    state: &State<SharedState>,
+    _request: Json<GetOverdueDecryptionRequest>,
+) -> Json<GetOverdueDecryptionResponse> {
+    let game_state = state.lock().await;
+
+    let (decryption, player_ids) = game_state
+        .decryption
+        .as_ref()
+        .filter(|decryption| {
+            current_time_millis() - decryption.started_at > escrow::OVERDUE_DECRYPTION_MILLIS
+        })
+        .map(|decryption| {
+            let pending = PendingDecryption {
+                id: decryption.id,
+                ct: decryption.ct.clone(),
+            };
+            (Some(pending), decryption.pending_players())
+        })
+        .unwrap_or_default();
+
+    Json(GetOverdueDecryptionResponse {
+        decryption,
+        player_ids,
+    })
+}
+
+#[post("/submit_dec_share", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn submit_dec_share(
+    state: &State<SharedState>,
+    request: Json<SubmitDecShareRequest>,
+    request_id: RequestId,
+) -> Result<Json<SubmitDecShareResponse>, Custom<String>> {
+    let SubmitDecShareRequest {
+        player_id,
+        id,
+        dec_share,
+    } = request.0;
+    if dec_share.user_id() != player_id {
+        return Err(bad_request("Decryption share is of another player"));
+    }
+    let mut game_state = state.lock().await;
+    let game_state = &mut *game_state;
+
+    let decryption = match &mut game_state.decryption {
+        Some(decryption) if decryption.id == id => decryption,
+        _ => return Err(bad_request("Decryption is not pending")),
+    };
+    // Shares are proven against the player's public key share, so only the
+    // holder of its secret key can submit one that verifies.
+    game_state
+        .evaluator
+        .verify_dec_share(&decryption.ct, &dec_share)
+        .map_err(bad_request)?;
+    decryption.submit(player_id, dec_share)?;
+
+    if let Some(dec_shares) = decryption.dec_shares() {
+        let decryption = game_state.decryption.take().unwrap();
+        let bits = info_span!("aggregate", purpose = ?decryption.purpose)
+            .in_scope(|| {
+                game_state
+                    .evaluator
+                    .aggregate_dec_shares(&decryption.ct, dec_shares)
+            })
+            .map_err(internal_server_error)?;
+        match decryption.purpose {
+            DecryptionPurpose::GameOverCheck => {
+                if bits[0] {
+                    game_state.end_game(GameOverReason::MonstersCleared);
+                } else if bits[1] {
+                    game_state.end_game(GameOverReason::PlayersDead);
+                }
+            }
+            DecryptionPurpose::FinalScores => {
+                let (reason, ended_at) = game_state.game_over.unwrap();
+                game_state.results = Some(GameResults {
+                    reason,
+                    ended_at,
+                    leaderboard: game_over::leaderboard(&bits),
+                    zone_hash: game_over::zone_hash(game_state.zone()?),
+                });
+            }
+        }
+    }
+
+    // Not marked as seen, as the escrow submits shares for offline players.
+    info!("processed /submit_dec_share request");
+
+    Ok(Json(SubmitDecShareResponse {}))
+}
+
+#[post("/results", format = "json", data = "<_request>")]
+async fn results(
+    state: &State<SharedState>,
+    _request: Json<ResultsRequest>,
+) -> Json<ResultsResponse> {
+    let game_state = state.lock().await;
+
+    Json(ResultsResponse {
+        game_over: game_state.game_over.map(|(reason, _)| reason),
+        results: game_state.results.clone(),
+    })
+}
+
 #[launch]
 async fn rocket() -> _ {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    let pk: PhantomPk = bincode::deserialize(include_bytes!(".././pk")).unwrap();
-    let rp_key: PhantomRpKey = bincode::deserialize(include_bytes!(".././rp_key")).unwrap();
-    let bs_key: PhantomBsKey = bincode::deserialize(include_bytes!(".././bs_key")).unwrap();
-
-    let mut evaluator = PhantomEvaluator::new(PhantomParam::I_4P_40);
-    evaluator.set_pk(pk);
-    evaluator.set_rp_key(rp_key);
-    evaluator.set_bs_key(bs_key);
-    let shared_state: Arc<Mutex<GameState>> = Arc::new(Mutex::new(GameState {
-        zone: None,
-        mock_zone: None,
-        action_queue: VecDeque::new(),
-        player_last_move_time: [0, 0, 0, 0],
-        mock_player_last_move_time: [0, 0, 0, 0],
-        evaluator,
-        player_round_1_key: [None, None, None, None],
-        player_round_2_key: [None, None, None, None],
-        work_counter: 0,
-        worker_diff: vec![Default::default(); WORKER_URIS.len()],
-    }));
+    telemetry::init("server");
+    // Fail at startup rather than when the game starts.
+    if let Err(err) = server::zone::check_circuits() {
+        panic!("{err}");
+    }
+    info!("map: {}x{}", MAP.width, MAP.height);
+
+    let shared_state: Arc<Mutex<GameState>> = Arc::new(Mutex::new(GameState::new()));
 
     let state_clone_process_actions = shared_state.clone();
     tokio::spawn(async move {
@@ -718,9 +1390,9 @@ async fn rocket() -> _ {
             "/",
             routes![
                 reset_game,
-                // reset,
                 mock_move,
                 queue_move,
+                respawn,
                 mock_get_cells,
                 get_cells,
                 get_five_cells,
@@ -729,9 +1401,28 @@ async fn rocket() -> _ {
                 get_horizontal_cells,
                 mock_get_player,
                 get_player,
-                // submit_r1,
-                // get_pk,
-                // submit_r2,
+                get_pending_decryption,
+                get_overdue_decryption,
+                submit_dec_share,
+                results,
+                metrics::metrics,
+                get_crs,
+                submit_r1,
+                get_pk,
+                submit_r2,
+            ],
+        )
+        .mount(
+            "/admin",
+            routes![
+                admin_pause,
+                admin_resume,
+                admin_resync_workers,
+                admin_set_rate_limit,
+                admin_list_players,
+                admin_inspect_queue,
+                admin_end_game,
+                admin_reset,
             ],
         )
         .attach(make_cors())
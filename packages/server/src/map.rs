@@ -0,0 +1,175 @@
+//! Maps: dimensions, spawn points, items, monsters and obstacles of a zone.
+//!
+//! Maps are JSON files, see `maps/default.json`. The server plays the map at
+//! the path in the `MAP` environment variable, or the built-in default map.
+//!
+//! Dimensions, obstacles, spawn points and the monsters' initial data are
+//! compiled into the circuits (`circuits_cpp/src/map.h`, generated from the
+//! default map with `cargo run --bin map_header`, and `frogzone.h`), so a map
+//! can only change them once it becomes the default map and the circuits are
+//! regenerated. [`Map::load`] rejects maps that disagree with the circuits, so
+//! the map selected at startup only varies the items.
+
+use itertools::chain;
+use phantom_benchs::capacity::{NUM_ITEMS, NUM_MONSTERS, NUM_OBSTACLES, NUM_SPAWN_POINTS};
+use serde::Deserialize;
+use std::{env, fs, path::Path, sync::LazyLock};
+
+/// Map the circuits are generated from.
+const DEFAULT_MAP: &str = include_str!("../maps/default.json");
+
+/// `hp` of a frog when the game starts.
+pub const INITIAL_PLAYER_HP: u8 = 5;
+
+/// `atk` of a frog when the game starts.
+pub const INITIAL_PLAYER_ATK: u8 = 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct PlaintextMonster {
+    #[serde(skip)]
+    pub id: usize,
+    pub x: u8,
+    pub y: u8,
+    pub hp: u8,
+    pub atk: u8,
+    pub points: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct PlaintextItem {
+    #[serde(skip)]
+    pub id: usize,
+    pub x: u8,
+    pub y: u8,
+    pub hp: u8,
+    pub atk: u8,
+    pub points: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct PlaintextObstacle {
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct PlaintextSpawnPoint {
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Map {
+    pub width: u8,
+    pub height: u8,
+    /// Player `i` starts at `spawns[i]`, dead frogs respawn at any of them.
+    pub spawns: Vec<PlaintextSpawnPoint>,
+    pub items: Vec<PlaintextItem>,
+    pub monsters: Vec<PlaintextMonster>,
+    pub obstacles: Vec<PlaintextObstacle>,
+}
+
+impl Map {
+    /// The map the circuits are generated from.
+    pub fn builtin() -> Self {
+        Self::from_json(DEFAULT_MAP).unwrap()
+    }
+
+    /// Parses a map and checks it fits in the zone.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut map: Map = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        for (id, item) in map.items.iter_mut().enumerate() {
+            item.id = id;
+        }
+        for (id, monster) in map.monsters.iter_mut().enumerate() {
+            monster.id = id;
+        }
+        map.validate()?;
+        Ok(map)
+    }
+
+    /// Reads a map file, and checks it fits in the zone and agrees with the
+    /// circuits.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let map = Self::from_json(&json)?;
+        map.check_compiled_in(&Self::builtin())?;
+        Ok(map)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.spawns.len() != NUM_SPAWN_POINTS {
+            return Err(format!(
+                "expected {NUM_SPAWN_POINTS} spawns, got {}",
+                self.spawns.len()
+            ));
+        }
+        if self.items.len() > NUM_ITEMS {
+            return Err(format!(
+                "expected at most {NUM_ITEMS} items, got {}",
+                self.items.len()
+            ));
+        }
+        // Every monster slot needs initial data to revive at.
+        if self.monsters.len() != NUM_MONSTERS {
+            return Err(format!(
+                "expected {NUM_MONSTERS} monsters, got {}",
+                self.monsters.len()
+            ));
+        }
+        if self.obstacles.len() > NUM_OBSTACLES {
+            return Err(format!(
+                "expected at most {NUM_OBSTACLES} obstacles, got {}",
+                self.obstacles.len()
+            ));
+        }
+
+        let coords = chain![
+            self.spawns.iter().map(|s| ("spawn", s.x, s.y)),
+            self.items.iter().map(|i| ("item", i.x, i.y)),
+            self.monsters.iter().map(|m| ("monster", m.x, m.y)),
+            self.obstacles.iter().map(|o| ("obstacle", o.x, o.y)),
+        ];
+        for (kind, x, y) in coords {
+            if x >= self.width || y >= self.height {
+                return Err(format!(
+                    "{kind} at ({x}, {y}) is outside the {}x{} map",
+                    self.width, self.height
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the parts of the map compiled into the circuits are the same as
+    /// in `compiled`, the map the circuits were generated from.
+    fn check_compiled_in(&self, compiled: &Map) -> Result<(), String> {
+        let mismatch = if (self.width, self.height) != (compiled.width, compiled.height) {
+            "dimensions"
+        } else if self.spawns != compiled.spawns {
+            "spawns"
+        } else if self.monsters != compiled.monsters {
+            "monsters"
+        } else if self.obstacles != compiled.obstacles {
+            "obstacles"
+        } else {
+            return Ok(());
+        };
+        Err(format!(
+            "{mismatch} differ from the map compiled into the circuits, make it \
+             maps/default.json and regenerate them with `cargo run --bin map_header`"
+        ))
+    }
+}
+
+/// Map played by the server, read from the file at the `MAP` environment
+/// variable, or [`Map::builtin`].
+///
+/// The file must have the dimensions, spawns, monsters and obstacles of the
+/// built-in map, compiled into the circuits, so it can only change the items.
+pub static MAP: LazyLock<Map> = LazyLock::new(|| match env::var("MAP") {
+    Err(_) => Map::builtin(),
+    Ok(path) => Map::load(&path).unwrap_or_else(|err| panic!("invalid MAP {path:?}: {err}")),
+});
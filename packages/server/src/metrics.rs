@@ -0,0 +1,111 @@
+use prometheus::{
+    exponential_buckets, register_histogram, register_histogram_vec, register_int_counter,
+    register_int_counter_vec, register_int_gauge, Encoder, Histogram, HistogramVec, IntCounter,
+    IntCounterVec, IntGauge, TextEncoder,
+};
+use rocket::get;
+use std::sync::LazyLock;
+
+/// Time spent evaluating each transpiled circuit, labeled by circuit name.
+pub static CIRCUIT_EVAL_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "frogzone_circuit_eval_seconds",
+        "Time spent evaluating a FHE circuit",
+        &["circuit"],
+        exponential_buckets(0.05, 2.0, 12).unwrap()
+    )
+    .unwrap()
+});
+
+/// Size of the [`ZoneDiff`](crate::zone::ZoneDiff) sent along with each worker request.
+pub static DIFF_SIZE_BYTES: LazyLock<Histogram> = LazyLock::new(|| {
+    register_histogram!(
+        "frogzone_diff_size_bytes",
+        "Size in bytes of the zone diff sent to a worker",
+        exponential_buckets(1024.0, 4.0, 10).unwrap()
+    )
+    .unwrap()
+});
+
+/// Time spent in [`PhantomEvaluator::pack`](phantom::PhantomEvaluator::pack).
+pub static PACK_SECONDS: LazyLock<Histogram> = LazyLock::new(|| {
+    register_histogram!(
+        "frogzone_pack_seconds",
+        "Time spent packing ciphertexts for decryption",
+        exponential_buckets(0.005, 2.0, 12).unwrap()
+    )
+    .unwrap()
+});
+
+/// Time spent in [`PhantomEvaluator::unbatch`](phantom::PhantomEvaluator::unbatch).
+pub static UNBATCH_SECONDS: LazyLock<Histogram> = LazyLock::new(|| {
+    register_histogram!(
+        "frogzone_unbatch_seconds",
+        "Time spent unbatching client ciphertexts",
+        exponential_buckets(0.001, 2.0, 12).unwrap()
+    )
+    .unwrap()
+});
+
+/// Number of actions waiting in the server's action queue.
+pub static ACTION_QUEUE_DEPTH: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "frogzone_action_queue_depth",
+        "Number of actions waiting to be processed"
+    )
+    .unwrap()
+});
+
+/// Number of move requests rejected by the rate limit.
+pub static RATE_LIMIT_HITS: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "frogzone_rate_limit_hits_total",
+        "Number of move requests rejected by the rate limit"
+    )
+    .unwrap()
+});
+
+/// Number of requests dispatched to each worker, labeled by worker uri.
+pub static WORKER_DISPATCHES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "frogzone_worker_dispatches_total",
+        "Number of requests dispatched to a worker",
+        &["worker"]
+    )
+    .unwrap()
+});
+
+/// Bumped every time the evaluation keys are (re)set.
+pub static KEY_VERSION: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "frogzone_key_version",
+        "Version of the evaluation keys in use"
+    )
+    .unwrap()
+});
+
+/// Bumped every time a fresh zone is created or received from the server.
+pub static ZONE_VERSION: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!("frogzone_zone_version", "Version of the zone in use").unwrap()
+});
+
+/// Number of decrypted outputs a client failed to decode, labeled by the kind
+/// of mismatch.
+pub static DECODE_MISMATCHES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "frogzone_decode_mismatches_total",
+        "Number of decrypted outputs that failed to decode",
+        &["kind"]
+    )
+    .unwrap()
+});
+
+/// Renders all registered metrics in the Prometheus text format.
+#[get("/metrics")]
+pub fn metrics() -> String {
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
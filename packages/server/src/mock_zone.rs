@@ -1,13 +1,14 @@
 use core::array::from_fn;
+use phantom_benchs::capacity::{NUM_ENTITY_IDS, NUM_ITEMS, NUM_MONSTERS, NUM_OBSTACLES};
 use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Direction, EntityType};
-use crate::initial_data::{get_all_items, get_all_monsters, get_all_obstacles};
-
-const NUM_ITEMS: usize = 12;
-const NUM_MONSTERS: usize = 23;
-const NUM_OBSTACLES: usize = 193;
+use crate::map::{
+    Map, PlaintextMonster, PlaintextSpawnPoint, INITIAL_PLAYER_ATK, INITIAL_PLAYER_HP,
+};
+use crate::respawn::{ITEM_RESPAWN_TICKS, MONSTER_RESPAWN_TICKS, RESPAWN_HP};
+use crate::zone::{num_players, other_player_ids};
 
 const NUM_MOVABLE_MONSTERS: usize = 4;
 const NUM_MOVABLE_FLYERS: usize = 6;
@@ -47,6 +48,7 @@ pub struct ItemEncryptedData {
     pub atk: MockEncrypted<u8>,
     pub is_consumed: MockEncrypted<bool>,
     pub points: MockEncrypted<u8>,
+    pub respawn_ticks: MockEncrypted<u8>,
 }
 
 #[derive(Clone, Debug)]
@@ -94,12 +96,20 @@ pub struct CellEncryptedData {
 pub struct MockZone {
     pub width: u8,
     pub height: u8,
-    pub players: [Player; 4],
+    pub players: Vec<Player>,
     pub items: [Item; NUM_ITEMS],
     pub monsters: [Monster; NUM_MONSTERS],
+    /// Plaintext copy of the obstacles compiled into the move circuits, padded
+    /// with filler.
     pub obstacles: [MockEncryptedCoord; NUM_OBSTACLES],
+    /// Plaintext copy of the spawn points compiled into the respawn circuit.
+    pub spawns: Vec<PlaintextSpawnPoint>,
+    /// Plaintext copy of the monsters' initial data compiled into the
+    /// maintain monsters circuit.
+    pub initial_monsters: Vec<PlaintextMonster>,
     pub random_state: u8,
-    pub precomputed_ids: [MockEncrypted<u8>; 34],
+    pub precomputed_ids: [MockEncrypted<u8>; NUM_ENTITY_IDS],
+    pub monster_dead_ticks: [MockEncrypted<u8>; NUM_MONSTERS],
 }
 
 pub fn fhe_apply_move_raw(
@@ -160,7 +170,7 @@ pub fn fhe_apply_move_check_collisions(
 pub fn fhe_apply_move_monster(
     old_coords: MockEncryptedCoord,
     direction: MockEncrypted<Direction>,
-    player_coords: [MockEncryptedCoord; 4],
+    player_coords: Vec<MockEncryptedCoord>,
     height: u8,
     width: u8,
     obstacle_coords: [MockEncryptedCoord; NUM_OBSTACLES],
@@ -199,7 +209,7 @@ pub fn fhe_apply_move_monster(
 pub fn fhe_apply_move_flying(
     old_coords: MockEncryptedCoord,
     direction: MockEncrypted<Direction>,
-    player_coords: [MockEncryptedCoord; 4],
+    player_coords: Vec<MockEncryptedCoord>,
     height: u8,
     width: u8,
     monster_coords: [MockEncryptedCoord; NUM_MONSTERS],
@@ -241,11 +251,17 @@ pub fn fhe_apply_move(
     [ItemEncryptedData; NUM_ITEMS],
     [MonsterEncryptedData; NUM_MONSTERS],
 ) {
+    // A dead player can't move, pick up items or attack.
+    if player_data.hp == 0 {
+        return (player_data, items, monsters);
+    }
+
     let old_coords = player_data.loc;
 
     let mut new_coords =
         fhe_apply_move_check_collisions(player_data.loc, direction, height, width, obstacles);
 
+    // Like the circuit, stats saturate at u8::MAX instead of wrapping around.
     let mut new_player_data = player_data;
     let mut new_item_data = items;
     let mut new_monster_data = monsters;
@@ -253,9 +269,10 @@ pub fn fhe_apply_move(
     for (idx, item) in items.iter().enumerate() {
         if new_coords == item.loc && !item.is_consumed {
             new_item_data[idx].is_consumed = true;
-            new_player_data.atk += new_item_data[idx].atk;
-            new_player_data.hp += new_item_data[idx].hp;
-            new_player_data.points += new_item_data[idx].points;
+            new_item_data[idx].respawn_ticks = ITEM_RESPAWN_TICKS;
+            new_player_data.atk = new_player_data.atk.saturating_add(item.atk);
+            new_player_data.hp = new_player_data.hp.saturating_add(item.hp);
+            new_player_data.points = new_player_data.points.saturating_add(item.points);
         }
     }
 
@@ -265,16 +282,16 @@ pub fn fhe_apply_move(
             if player_data.hp <= monster.atk {
                 new_player_data.hp = 0;
             } else {
-                new_player_data.hp -= monster.atk;
+                new_player_data.hp = new_player_data.hp.wrapping_sub(monster.atk);
             }
 
             // apply player's attack
             if monster.hp <= player_data.atk {
                 new_monster_data[idx].hp = 0;
-                new_player_data.atk += monster.atk;
-                new_player_data.points += monster.points;
+                new_player_data.atk = new_player_data.atk.saturating_add(monster.atk);
+                new_player_data.points = new_player_data.points.saturating_add(monster.points);
             } else {
-                new_monster_data[idx].hp -= player_data.atk
+                new_monster_data[idx].hp -= player_data.atk;
             }
 
             // revert player back to their coords
@@ -287,11 +304,206 @@ pub fn fhe_apply_move(
     (new_player_data, new_item_data, new_monster_data)
 }
 
+/// Same as [`fhe_apply_move`], except that moving into another player attacks
+/// it: the attacker's `atk` is subtracted from the defender's `hp`, and on a
+/// kill the defender's points go to the attacker. Either way the attacker
+/// stays in place. `others` are the players other than the one moving.
+pub fn fhe_apply_move_pvp(
+    player_data: PlayerEncryptedData,
+    direction: MockEncrypted<Direction>,
+    height: u8,
+    width: u8,
+    obstacles: [MockEncryptedCoord; NUM_OBSTACLES],
+    others: Vec<PlayerEncryptedData>,
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    items: [ItemEncryptedData; NUM_ITEMS],
+) -> (
+    PlayerEncryptedData,
+    Vec<PlayerEncryptedData>,
+    [ItemEncryptedData; NUM_ITEMS],
+    [MonsterEncryptedData; NUM_MONSTERS],
+) {
+    // A dead player can't move, pick up items or attack.
+    if player_data.hp == 0 {
+        return (player_data, others, items, monsters);
+    }
+
+    let old_coords = player_data.loc;
+
+    let mut new_coords = fhe_apply_move_raw(player_data.loc, direction, height, width);
+    if obstacles.contains(&new_coords) {
+        new_coords = old_coords;
+    }
+
+    let mut new_player_data = player_data;
+    let mut new_other_data = others.clone();
+    let mut new_item_data = items;
+    let mut new_monster_data = monsters;
+
+    let mut hit_player = false;
+    for (idx, other) in others.iter().enumerate() {
+        if new_coords == other.loc {
+            hit_player = true;
+            // dead players still block the way, but can't be attacked
+            if other.hp != 0 {
+                if other.hp <= player_data.atk {
+                    new_other_data[idx].hp = 0;
+                    new_other_data[idx].points = 0;
+                    new_player_data.points = new_player_data.points.saturating_add(other.points);
+                } else {
+                    new_other_data[idx].hp -= player_data.atk;
+                }
+            }
+        }
+    }
+    if hit_player {
+        new_coords = old_coords;
+    }
+
+    for (idx, item) in items.iter().enumerate() {
+        if new_coords == item.loc && !item.is_consumed {
+            new_item_data[idx].is_consumed = true;
+            new_item_data[idx].respawn_ticks = ITEM_RESPAWN_TICKS;
+            new_player_data.atk = new_player_data.atk.saturating_add(item.atk);
+            new_player_data.hp = new_player_data.hp.saturating_add(item.hp);
+            new_player_data.points = new_player_data.points.saturating_add(item.points);
+        }
+    }
+
+    for (idx, monster) in monsters.iter().enumerate() {
+        if new_coords == monster.loc && monster.hp != 0 {
+            // apply monster's attack
+            if player_data.hp <= monster.atk {
+                new_player_data.hp = 0;
+            } else {
+                new_player_data.hp = new_player_data.hp.wrapping_sub(monster.atk);
+            }
+
+            // apply player's attack
+            if monster.hp <= player_data.atk {
+                new_monster_data[idx].hp = 0;
+                new_player_data.atk = new_player_data.atk.saturating_add(monster.atk);
+                new_player_data.points = new_player_data.points.saturating_add(monster.points);
+            } else {
+                new_monster_data[idx].hp -= player_data.atk;
+            }
+
+            // revert player back to their coords
+            new_coords = old_coords;
+        }
+    }
+
+    new_player_data.loc = new_coords;
+
+    (
+        new_player_data,
+        new_other_data,
+        new_item_data,
+        new_monster_data,
+    )
+}
+
+/// Brings a dead player back with [`RESPAWN_HP`], keeping `points >>
+/// points_shift` of its points, at the spawn point picked by `random_state` or
+/// the next one not taken by a player. A player still alive is left unchanged.
+pub fn fhe_respawn(
+    player_data: PlayerEncryptedData,
+    random_state: MockEncrypted<u8>,
+    points_shift: MockEncrypted<u8>,
+    spawn_points: &[PlaintextSpawnPoint],
+    players_coords: Vec<MockEncryptedCoord>,
+) -> PlayerEncryptedData {
+    if player_data.hp != 0 {
+        return player_data;
+    }
+
+    let spawn_point = (0..spawn_points.len())
+        .map(|i| spawn_points[(random_state as usize + i) % spawn_points.len()])
+        .map(|spawn_point| MockEncryptedCoord {
+            x: spawn_point.x,
+            y: spawn_point.y,
+        })
+        .find(|spawn_point| !players_coords.contains(spawn_point));
+
+    PlayerEncryptedData {
+        loc: spawn_point.unwrap_or(player_data.loc),
+        hp: RESPAWN_HP,
+        atk: player_data.atk,
+        points: player_data
+            .points
+            .checked_shr(points_shift as u32)
+            .unwrap_or(0),
+    }
+}
+
+/// Revives monsters dead for [`MONSTER_RESPAWN_TICKS`] ticks at their initial
+/// location, unless a player stands there, and regenerates 1 hp of wounded
+/// monsters up to their initial hp.
+pub fn fhe_maintain_monsters(
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    dead_ticks: [MockEncrypted<u8>; NUM_MONSTERS],
+    initial_monsters: &[PlaintextMonster],
+    players_coords: Vec<MockEncryptedCoord>,
+) -> (
+    [MonsterEncryptedData; NUM_MONSTERS],
+    [MockEncrypted<u8>; NUM_MONSTERS],
+) {
+    let mut new_monster_data = monsters;
+    let mut new_dead_ticks = dead_ticks;
+
+    for (idx, monster) in monsters.iter().enumerate() {
+        let initial = initial_monsters[idx];
+        let initial_loc = MockEncryptedCoord {
+            x: initial.x,
+            y: initial.y,
+        };
+        if monster.hp == 0 {
+            let ticks = dead_ticks[idx].saturating_add(1);
+            if ticks >= MONSTER_RESPAWN_TICKS && !players_coords.contains(&initial_loc) {
+                new_monster_data[idx] = MonsterEncryptedData {
+                    loc: initial_loc,
+                    hp: initial.hp,
+                    atk: initial.atk,
+                    points: initial.points,
+                };
+                new_dead_ticks[idx] = 0;
+            } else {
+                new_dead_ticks[idx] = ticks;
+            }
+        } else if monster.hp < initial.hp {
+            new_monster_data[idx].hp += 1;
+        }
+    }
+
+    (new_monster_data, new_dead_ticks)
+}
+
+/// Counts down the respawn ticks of consumed items, and puts an item back once
+/// they reach 0, unless a player stands on it.
+pub fn fhe_maintain_items(
+    items: [ItemEncryptedData; NUM_ITEMS],
+    players_coords: Vec<MockEncryptedCoord>,
+) -> [ItemEncryptedData; NUM_ITEMS] {
+    let mut new_item_data = items;
+
+    for (idx, item) in items.iter().enumerate() {
+        if item.is_consumed {
+            let ticks = item.respawn_ticks.saturating_sub(1);
+            new_item_data[idx].respawn_ticks = ticks;
+            if ticks == 0 && !players_coords.contains(&item.loc) {
+                new_item_data[idx].is_consumed = false;
+            }
+        }
+    }
+
+    new_item_data
+}
+
 fn fhe_get_cell_no_check(
     coord: MockEncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: &[PlayerWithEncryptedId],
 ) -> CellEncryptedData {
     let mut cell = CellEncryptedData::default();
 
@@ -333,7 +545,7 @@ fn fhe_get_cell(
     query_coord: MockEncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> CellEncryptedData {
     // coord's x and y values must be within [-2, +2] of player's x and y values
     // can ignore this check if necessary for performance
@@ -343,7 +555,7 @@ fn fhe_get_cell(
         return ret;
     }
 
-    fhe_get_cell_no_check(query_coord, monsters, items, players)
+    fhe_get_cell_no_check(query_coord, monsters, items, &players)
 }
 
 fn fhe_get_five_cells(
@@ -351,7 +563,7 @@ fn fhe_get_five_cells(
     query_coords: [MockEncryptedCoord; 5],
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
     let mut cells = [CellEncryptedData::default(); 5];
 
@@ -364,7 +576,7 @@ fn fhe_get_five_cells(
             ret.entity_type = EntityType::Invalid;
             cells[idx] = ret;
         } else {
-            cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, players);
+            cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, &players);
         }
     }
 
@@ -375,7 +587,7 @@ fn fhe_get_cross_cells(
     player_coord: MockEncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
     let mut cells = [CellEncryptedData::default(); 5];
 
@@ -400,7 +612,7 @@ fn fhe_get_cross_cells(
     ];
 
     for (idx, query_coord) in query_coords.iter().enumerate() {
-        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, players);
+        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, &players);
     }
 
     cells
@@ -411,7 +623,7 @@ fn fhe_get_vertical_cells(
     query_coord: MockEncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
     let mut cells = [CellEncryptedData::default(); 5];
 
@@ -444,7 +656,7 @@ fn fhe_get_vertical_cells(
     ];
 
     for (idx, query_coord) in query_coords.iter().enumerate() {
-        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, players);
+        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, &players);
     }
 
     cells
@@ -455,7 +667,7 @@ fn fhe_get_horizontal_cells(
     query_coord: MockEncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
     let mut cells = [CellEncryptedData::default(); 5];
 
@@ -488,7 +700,7 @@ fn fhe_get_horizontal_cells(
     ];
 
     for (idx, query_coord) in query_coords.iter().enumerate() {
-        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, players);
+        cells[idx] = fhe_get_cell_no_check(query_coord.clone(), monsters, items, &players);
     }
 
     cells
@@ -499,74 +711,40 @@ fn pk_encrypt<T>(value: T) -> MockEncrypted<T> {
 }
 
 impl MockZone {
-    pub fn new(width: u8, height: u8) -> Self {
-        let players = [
-            Player {
-                id: 0,
-                data: PlayerEncryptedData {
-                    loc: MockEncryptedCoord {
-                        x: pk_encrypt(3),
-                        y: pk_encrypt(27),
-                    },
-                    hp: pk_encrypt(5),
-                    atk: pk_encrypt(1),
-                    points: pk_encrypt(0),
-                },
-            },
-            Player {
-                id: 1,
-                data: PlayerEncryptedData {
-                    loc: MockEncryptedCoord {
-                        x: pk_encrypt(19),
-                        y: pk_encrypt(27),
-                    },
-                    hp: pk_encrypt(5),
-                    atk: pk_encrypt(1),
-                    points: pk_encrypt(0),
-                },
-            },
-            Player {
-                id: 2,
+    pub fn new(map: &Map) -> Self {
+        let players = (0..num_players())
+            .map(|id| Player {
+                id,
                 data: PlayerEncryptedData {
                     loc: MockEncryptedCoord {
-                        x: pk_encrypt(28),
-                        y: pk_encrypt(28),
+                        x: pk_encrypt(map.spawns[id].x),
+                        y: pk_encrypt(map.spawns[id].y),
                     },
-                    hp: pk_encrypt(5),
-                    atk: pk_encrypt(1),
+                    hp: pk_encrypt(INITIAL_PLAYER_HP),
+                    atk: pk_encrypt(INITIAL_PLAYER_ATK),
                     points: pk_encrypt(0),
                 },
-            },
-            Player {
-                id: 3,
-                data: PlayerEncryptedData {
-                    loc: MockEncryptedCoord {
-                        x: pk_encrypt(12),
-                        y: pk_encrypt(29),
-                    },
-                    hp: pk_encrypt(5),
-                    atk: pk_encrypt(1),
-                    points: pk_encrypt(0),
-                },
-            },
-        ];
+            })
+            .collect();
 
+        // Filler items start consumed and lie outside the map, where no move
+        // or cell query reaches them.
         let filler_item = Item {
             id: 0,
             data: ItemEncryptedData {
                 loc: MockEncryptedCoord {
-                    x: pk_encrypt(0),
-                    y: pk_encrypt(0),
+                    x: pk_encrypt(255),
+                    y: pk_encrypt(255),
                 },
                 hp: pk_encrypt(0),
                 atk: pk_encrypt(0),
                 points: pk_encrypt(0),
-                is_consumed: pk_encrypt(false),
+                is_consumed: pk_encrypt(true),
+                respawn_ticks: pk_encrypt(0),
             },
         };
         let mut items: [Item; NUM_ITEMS] = from_fn(|_| filler_item.clone());
-        let plaintext_items = get_all_items();
-        for (idx, plaintext_item) in plaintext_items.iter().enumerate() {
+        for (idx, plaintext_item) in map.items.iter().enumerate() {
             items[idx] = Item {
                 id: idx,
                 data: ItemEncryptedData {
@@ -578,6 +756,7 @@ impl MockZone {
                     atk: pk_encrypt(plaintext_item.atk),
                     points: pk_encrypt(plaintext_item.points),
                     is_consumed: pk_encrypt(false),
+                    respawn_ticks: pk_encrypt(0),
                 },
             };
         }
@@ -595,8 +774,7 @@ impl MockZone {
             },
         };
         let mut monsters: [Monster; NUM_MONSTERS] = from_fn(|_| filler_monster.clone());
-        let plaintext_monsters = get_all_monsters();
-        for (idx, plaintext_monster) in plaintext_monsters.iter().enumerate() {
+        for (idx, plaintext_monster) in map.monsters.iter().enumerate() {
             monsters[idx] = Monster {
                 id: idx,
                 data: MonsterEncryptedData {
@@ -616,8 +794,7 @@ impl MockZone {
             y: pk_encrypt(255),
         };
         let mut obstacles: [MockEncryptedCoord; NUM_OBSTACLES] = from_fn(|_| filler_coord.clone());
-        let plaintext_obstacles = get_all_obstacles();
-        for (idx, plaintext_obstacle) in plaintext_obstacles.iter().enumerate() {
+        for (idx, plaintext_obstacle) in map.obstacles.iter().enumerate() {
             obstacles[idx] = MockEncryptedCoord {
                 x: pk_encrypt(plaintext_obstacle.x),
                 y: pk_encrypt(plaintext_obstacle.y),
@@ -626,52 +803,20 @@ impl MockZone {
 
         let random_state = thread_rng().gen();
 
-        let precomputed_ids = [
-            pk_encrypt(0),
-            pk_encrypt(1),
-            pk_encrypt(2),
-            pk_encrypt(3),
-            pk_encrypt(4),
-            pk_encrypt(5),
-            pk_encrypt(6),
-            pk_encrypt(7),
-            pk_encrypt(8),
-            pk_encrypt(9),
-            pk_encrypt(10),
-            pk_encrypt(11),
-            pk_encrypt(12),
-            pk_encrypt(13),
-            pk_encrypt(14),
-            pk_encrypt(15),
-            pk_encrypt(16),
-            pk_encrypt(17),
-            pk_encrypt(18),
-            pk_encrypt(19),
-            pk_encrypt(20),
-            pk_encrypt(21),
-            pk_encrypt(22),
-            pk_encrypt(23),
-            pk_encrypt(24),
-            pk_encrypt(25),
-            pk_encrypt(26),
-            pk_encrypt(27),
-            pk_encrypt(28),
-            pk_encrypt(29),
-            pk_encrypt(30),
-            pk_encrypt(31),
-            pk_encrypt(32),
-            pk_encrypt(33),
-        ];
+        let precomputed_ids = from_fn(|id| pk_encrypt(id as _));
 
         Self {
-            width,
-            height,
+            width: map.width,
+            height: map.height,
             players,
             items,
             monsters,
             obstacles,
+            spawns: map.spawns.clone(),
+            initial_monsters: map.monsters.clone(),
             random_state,
             precomputed_ids,
+            monster_dead_ticks: [0; NUM_MONSTERS],
         }
     }
 
@@ -692,7 +837,7 @@ impl MockZone {
 
         let old_coords = self.monsters[monster_idx as usize].data.loc.clone();
 
-        let player_coords = self.players.each_ref().map(|i| i.data.loc.clone());
+        let player_coords = self.players.iter().map(|i| i.data.loc.clone()).collect();
 
         let monster_coords = self.monsters.each_ref().map(|i| i.data.loc.clone());
 
@@ -704,8 +849,8 @@ impl MockZone {
             old_coords,
             direction,
             player_coords,
-            32,
-            32,
+            self.height,
+            self.width,
             obstacle_coords,
             monster_coords,
             item_coords,
@@ -732,7 +877,7 @@ impl MockZone {
 
         let old_coords = self.monsters[monster_idx as usize].data.loc.clone();
 
-        let player_coords = self.players.each_ref().map(|i| i.data.loc.clone());
+        let player_coords = self.players.iter().map(|i| i.data.loc.clone()).collect();
 
         let monster_coords = self.monsters.each_ref().map(|i| i.data.loc.clone());
 
@@ -742,8 +887,8 @@ impl MockZone {
             old_coords,
             direction,
             player_coords,
-            32,
-            32,
+            self.height,
+            self.width,
             monster_coords,
             item_coords,
         );
@@ -760,30 +905,34 @@ impl MockZone {
 
         let player_data = self.players[player_id].data.clone();
 
+        let other_ids = other_player_ids(player_id);
+
+        let other_data = other_ids
+            .iter()
+            .map(|&id| self.players[id].data.clone())
+            .collect();
+
         let item_data = self.items.each_ref().map(|i| i.data.clone());
 
         let monster_data = self.monsters.each_ref().map(|i| i.data.clone());
 
-        let obstacles: [_; NUM_OBSTACLES + 4] = from_fn(|i| {
-            if i < NUM_OBSTACLES {
-                self.obstacles[i].clone()
-            } else {
-                self.players[i - NUM_OBSTACLES].data.loc.clone()
-            }
-        });
-
-        let (new_player_data, new_item_data, new_monster_data) = fhe_apply_move(
+        let (new_player_data, new_other_data, new_item_data, new_monster_data) = fhe_apply_move_pvp(
             player_data,
             direction,
             self.height,
             self.width,
-            obstacles,
+            self.obstacles,
+            other_data,
             monster_data,
             item_data,
         );
 
         self.players[player_id].data = new_player_data;
 
+        for (id, data) in other_ids.into_iter().zip(new_other_data) {
+            self.players[id].data = data;
+        }
+
         for i in 0..NUM_ITEMS {
             self.items[i].data = new_item_data[i].clone();
         }
@@ -795,20 +944,66 @@ impl MockZone {
         self.players[player_id].data.loc.clone()
     }
 
+    pub fn respawn_player(&mut self, player_id: usize, points_shift: u8) -> MockEncryptedCoord {
+        assert!(player_id < self.players.len());
+
+        let players_coords = self.players.iter().map(|player| player.data.loc).collect();
+
+        self.players[player_id].data = fhe_respawn(
+            self.players[player_id].data,
+            self.random_state,
+            points_shift,
+            &self.spawns,
+            players_coords,
+        );
+
+        self.players[player_id].data.loc
+    }
+
+    pub fn maintain_monsters(&mut self) {
+        let monster_data = self.monsters.each_ref().map(|i| i.data.clone());
+
+        let players_coords = self.players.iter().map(|player| player.data.loc).collect();
+
+        let (new_monster_data, new_dead_ticks) = fhe_maintain_monsters(
+            monster_data,
+            self.monster_dead_ticks,
+            &self.initial_monsters,
+            players_coords,
+        );
+
+        for i in 0..NUM_MONSTERS {
+            self.monsters[i].data = new_monster_data[i];
+        }
+        self.monster_dead_ticks = new_dead_ticks;
+    }
+
+    pub fn maintain_items(&mut self) {
+        let item_data = self.items.each_ref().map(|i| i.data);
+
+        let players_coords = self.players.iter().map(|player| player.data.loc).collect();
+
+        let new_item_data = fhe_maintain_items(item_data, players_coords);
+
+        for i in 0..NUM_ITEMS {
+            self.items[i].data = new_item_data[i];
+        }
+    }
+
     pub fn mix_random_input(&mut self, player_id: usize, random_input: u8) {
         assert!(player_id < self.players.len());
 
         self.random_state ^= random_input;
     }
 
-    fn fully_encrypted_players(&self) -> [PlayerWithEncryptedId; 4] {
-        from_fn(|i| {
-            let player = self.players[i].clone();
-            PlayerWithEncryptedId {
+    fn fully_encrypted_players(&self) -> Vec<PlayerWithEncryptedId> {
+        self.players
+            .iter()
+            .map(|player| PlayerWithEncryptedId {
                 id: self.precomputed_ids[player.id].clone(),
                 data: player.data,
-            }
-        })
+            })
+            .collect()
     }
 
     fn fully_encrypted_items(&self) -> [ItemWithEncryptedId; NUM_ITEMS] {
@@ -0,0 +1,60 @@
+//! Respawn of dead frogs, monsters and consumed items.
+//!
+//! A player asks to respawn through `/respawn`. After
+//! [`RESPAWN_COOLDOWN_MILLIS`] the server runs the respawn circuit, which
+//! brings the frog back with [`RESPAWN_HP`] at a spawn point picked with the
+//! shared random state, and forfeits part of its points according to
+//! [`PointsForfeit`]. The circuit leaves a frog that is still alive unchanged,
+//! so the server never learns whether the player was actually dead.
+//!
+//! Monsters are maintained by the monster loop on every tick, see
+//! [`Zone::maintain_monsters`](crate::zone::Zone::maintain_monsters), and
+//! consumed items by
+//! [`Zone::maintain_items`](crate::zone::Zone::maintain_items).
+
+use std::{env, sync::LazyLock};
+
+/// Time a player waits between asking to respawn and respawning.
+pub const RESPAWN_COOLDOWN_MILLIS: u64 = 10_000;
+
+/// `hp` of a respawned frog, same as `RESPAWN_HP` in `frogzone.h`.
+pub const RESPAWN_HP: u8 = 5;
+
+/// Ticks of the monster loop a dead monster waits before reviving, same as
+/// `MONSTER_RESPAWN_TICKS` in `frogzone.h`.
+pub const MONSTER_RESPAWN_TICKS: u8 = 8;
+
+/// Ticks of the monster loop a consumed item waits before respawning, same as
+/// `ITEM_RESPAWN_TICKS` in `frogzone.h`.
+pub const ITEM_RESPAWN_TICKS: u8 = 12;
+
+/// Share of its points a frog loses when it respawns, set by the
+/// `RESPAWN_POINTS_FORFEIT` environment variable to `none`, `half` or `all`.
+/// Defaults to `half`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PointsForfeit {
+    None,
+    #[default]
+    Half,
+    All,
+}
+
+impl PointsForfeit {
+    /// The frog keeps `points >> shift`.
+    pub fn shift(self) -> u8 {
+        match self {
+            PointsForfeit::None => 0,
+            PointsForfeit::Half => 1,
+            PointsForfeit::All => 8,
+        }
+    }
+}
+
+pub static POINTS_FORFEIT: LazyLock<PointsForfeit> =
+    LazyLock::new(|| match env::var("RESPAWN_POINTS_FORFEIT").as_deref() {
+        Err(_) => PointsForfeit::default(),
+        Ok("none") => PointsForfeit::None,
+        Ok("half") => PointsForfeit::Half,
+        Ok("all") => PointsForfeit::All,
+        Ok(value) => panic!("invalid RESPAWN_POINTS_FORFEIT {value:?}, expected none, half or all"),
+    });
@@ -0,0 +1,142 @@
+use crate::metrics;
+use phantom::{
+    PhantomBatchedCt, PhantomBool, PhantomEvaluator, PhantomKeyMismatch, PhantomPackedChunk,
+    PhantomPackedCt,
+};
+use rand::{thread_rng, Rng};
+use rocket::request::{FromRequest, Outcome, Request};
+use std::{convert::Infallible, fmt};
+use tracing::info_span;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+/// Header used to propagate the request id from phantom-client to the server,
+/// the workers and the other players.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Correlation id of a player's request, generated once at phantom-client.
+///
+/// As a request guard it reads [`REQUEST_ID_HEADER`], or generates a fresh id
+/// when the header is missing (e.g. requests coming from the browser).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    pub fn new() -> Self {
+        Self(format!("{:016x}", thread_rng().gen::<u64>()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RequestId {
+    type Error = Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let request_id = request
+            .headers()
+            .get_one(REQUEST_ID_HEADER)
+            .filter(|id| !id.is_empty() && id.len() <= 64)
+            .map(|id| Self(id.to_string()))
+            .unwrap_or_default();
+        Outcome::Success(request_id)
+    }
+}
+
+/// Installs the global tracing subscriber.
+///
+/// Logs are filtered by `RUST_LOG`. With the `otlp` feature enabled and
+/// `OTEL_EXPORTER_OTLP_ENDPOINT` set, spans are also exported over OTLP/HTTP
+/// with JSON encoding, tagged with `service_name`.
+pub fn init(service_name: &'static str) {
+    let registry = tracing_subscriber::registry()
+        .with(EnvFilter::from_default_env())
+        .with(tracing_subscriber::fmt::layer());
+
+    #[cfg(feature = "otlp")]
+    let registry = registry.with(otlp::layer(service_name));
+    #[cfg(not(feature = "otlp"))]
+    let _ = service_name;
+
+    registry.init();
+}
+
+#[cfg(feature = "otlp")]
+mod otlp {
+    use opentelemetry::{trace::TracerProvider as _, KeyValue};
+    use opentelemetry_otlp::{Protocol, WithExportConfig};
+    use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+    use std::env;
+    use tracing::Subscriber;
+    use tracing_subscriber::{registry::LookupSpan, Layer};
+
+    pub fn layer<S>(service_name: &'static str) -> Option<impl Layer<S>>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        let endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok()?;
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_protocol(Protocol::HttpJson)
+            .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+            .build()
+            .expect("failed to build OTLP exporter");
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .with_resource(Resource::new([KeyValue::new("service.name", service_name)]))
+            .build();
+        let tracer = provider.tracer(service_name);
+        opentelemetry::global::set_tracer_provider(provider);
+        Some(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+}
+
+/// [`PhantomEvaluator::unbatch`] within an `unbatch` span.
+pub fn unbatch(
+    evaluator: &PhantomEvaluator,
+    ct: &PhantomBatchedCt,
+) -> Result<Vec<PhantomBool>, PhantomKeyMismatch> {
+    let _span = info_span!("unbatch", n = ct.n()).entered();
+    metrics::UNBATCH_SECONDS.observe_closure_duration(|| evaluator.unbatch(ct))
+}
+
+/// [`PhantomEvaluator::pack`] within a `pack` span.
+pub fn pack<'a>(
+    evaluator: &PhantomEvaluator,
+    cts: impl IntoIterator<Item = &'a PhantomBool>,
+) -> PhantomPackedCt {
+    let _span = info_span!("pack").entered();
+    metrics::PACK_SECONDS.observe_closure_duration(|| evaluator.pack(cts))
+}
+
+/// [`PhantomEvaluator::pack_chunks`] within a `pack` span.
+pub fn pack_chunks<'a>(
+    evaluator: &PhantomEvaluator,
+    cts: impl IntoIterator<Item = &'a PhantomBool>,
+    item_bits: usize,
+) -> Vec<PhantomPackedChunk> {
+    let _span = info_span!("pack", item_bits).entered();
+    metrics::PACK_SECONDS.observe_closure_duration(|| evaluator.pack_chunks(cts, item_bits))
+}
+
+/// Evaluates a transpiled circuit within an `evaluate` span.
+pub fn evaluate<T>(circuit: &'static str, f: impl FnOnce() -> T) -> T {
+    let _span = info_span!("evaluate", circuit).entered();
+    metrics::CIRCUIT_EVAL_SECONDS
+        .with_label_values(&[circuit])
+        .observe_closure_duration(f)
+}
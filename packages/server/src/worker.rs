@@ -1,6 +1,11 @@
-use crate::{custom, internal_server_error, zone::ZoneDiff};
+use crate::{
+    custom, internal_server_error,
+    telemetry::{RequestId, REQUEST_ID_HEADER},
+    zone::ZoneDiff,
+};
 use phantom::{
-    PhantomBatchedCt, PhantomBsKey, PhantomCt, PhantomPackedCt, PhantomPk, PhantomRpKey,
+    PhantomBatchedCt, PhantomBsKey, PhantomCt, PhantomPackedChunk, PhantomPackedCt, PhantomPk,
+    PhantomRpKey,
 };
 use rocket::{
     http::Status,
@@ -43,7 +48,7 @@ pub struct GetCellsRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetCellsResponse {
-    pub cell_data: PhantomPackedCt, // Vec<CellEncryptedData>,
+    pub cell_data: Vec<PhantomPackedChunk>, // Vec<CellEncryptedData>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -89,10 +94,12 @@ pub struct GetHorizontalCellsResponse {
     pub cell_data: PhantomPackedCt, // [CellEncryptedData; 5],
 }
 
+#[tracing::instrument(skip_all, fields(worker = worker_uri.as_ref(), path = path.as_ref()))]
 pub async fn request<R: Serialize, S: Debug + DeserializeOwned>(
     worker_uri: impl AsRef<str>,
     path: impl AsRef<str>,
     body: R,
+    request_id: &RequestId,
 ) -> Result<Json<S>, Custom<String>> {
     // Create a client
     let client = reqwest::Client::new();
@@ -103,6 +110,7 @@ pub async fn request<R: Serialize, S: Debug + DeserializeOwned>(
     // Send the request
     let response = client
         .post(format!("{}{}", worker_uri.as_ref(), path.as_ref()))
+        .header(REQUEST_ID_HEADER, request_id.as_str())
         .json(&body)
         .send()
         .await
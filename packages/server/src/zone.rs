@@ -1,23 +1,49 @@
 use core::array::from_fn;
 use itertools::{chain, izip, Itertools};
-use phantom::{PhantomBool, PhantomCt, PhantomEvaluator};
+use phantom::{Enc, EncBool, EncU8, PhantomBool, PhantomCt, PhantomEvaluator, PhantomParam};
+use phantom_benchs::capacity::{
+    NUM_ENTITY_IDS, NUM_ITEMS, NUM_MONSTERS, NUM_PLAYERS, NUM_SPAWN_POINTS,
+};
+use phantom_benchs::widths::{self, ArgLayout};
 use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
+use std::{env, sync::LazyLock};
 
-use crate::initial_data::{get_all_items, get_all_monsters, get_all_obstacles};
-
-const NUM_ITEMS: usize = 12;
-const NUM_MONSTERS: usize = 23;
-const NUM_OBSTACLES: usize = 193;
+use crate::map::{Map, INITIAL_PLAYER_ATK, INITIAL_PLAYER_HP};
+use crate::telemetry;
 
 const NUM_MOVABLE_MONSTERS: usize = 4;
 const NUM_MOVABLE_FLYERS: usize = 6;
 
+/// FHE parameter of the zone, with a key share per player.
+///
+/// Picked by name from [`PhantomParam::NAMED`] with the `PHANTOM_PARAM`
+/// environment variable, [`PhantomParam::I_4P_40`] by default. The server, the
+/// workers and the players must agree on it. Its number of shares is the
+/// number of players, see [`num_players`].
+pub static PHANTOM_PARAM: LazyLock<PhantomParam> =
+    LazyLock::new(|| match env::var("PHANTOM_PARAM") {
+        Err(_) => PhantomParam::I_4P_40,
+        Ok(name) => PhantomParam::from_name(&name).unwrap_or_else(|| {
+            let names = PhantomParam::NAMED.map(|(name, _)| name);
+            panic!("invalid PHANTOM_PARAM {name:?}, expected one of {names:?}")
+        }),
+    });
+
+/// Number of players of the zone, one per key share of [`PHANTOM_PARAM`].
+///
+/// Only [`NUM_PLAYERS`] players are supported: the named parameters are vetted
+/// for that many users and the circuits are generated for them, see
+/// [`check_circuits`].
+pub fn num_players() -> usize {
+    PHANTOM_PARAM.total_shares
+}
+
 /// Encrypted [`bool`]
-pub type EncryptedBool = PhantomBool;
+pub type EncryptedBool = EncBool;
 
 /// Encrypted [`u8`] in little-endian 8-bits
-pub type EncryptedU8 = [EncryptedBool; 8];
+pub type EncryptedU8 = EncU8;
 
 /// Encrypted [`Direction`] in little-endian 2-bits
 pub type EncryptedDirection = [EncryptedBool; 2];
@@ -39,6 +65,9 @@ pub struct EncryptedCoord {
 }
 
 impl EncryptedCoord {
+    /// Number of [`EncryptedCoord::bits`].
+    pub const BITS: usize = 2 * 8;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![&self.x, &self.y]
@@ -48,14 +77,29 @@ impl EncryptedCoord {
         self.bits().map(|bit| bit.ct())
     }
 
+    /// Returns [`EncryptedCoord::bits`] collected, such as to pass to a circuit.
+    pub fn to_bits(&self) -> Vec<PhantomBool> {
+        self.bits().cloned().collect()
+    }
+
+    /// Takes the [`EncryptedCoord::BITS`] bits of a coord, such as from the
+    /// output of a circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    pub fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+        Self {
+            x: EncU8::from_bits(bits),
+            y: EncU8::from_bits(bits),
+        }
+    }
+
     pub fn from_cts(
         cts: &mut impl Iterator<Item = PhantomCt>,
         evaluator: &PhantomEvaluator,
     ) -> Self {
-        Self {
-            x: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            y: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-        }
+        Self::from_bits(&mut cts.map(|ct| evaluator.wrap(ct)))
     }
 }
 
@@ -68,6 +112,9 @@ pub struct PlayerEncryptedData {
 }
 
 impl PlayerEncryptedData {
+    /// Number of [`PlayerEncryptedData::bits`].
+    pub const BITS: usize = EncryptedCoord::BITS + 3 * 8;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![self.loc.bits(), &self.hp, &self.atk, &self.points]
@@ -77,16 +124,31 @@ impl PlayerEncryptedData {
         self.bits().map(|bit| bit.ct())
     }
 
+    /// Returns [`PlayerEncryptedData::bits`] collected, such as to pass to a circuit.
+    pub fn to_bits(&self) -> Vec<PhantomBool> {
+        self.bits().cloned().collect()
+    }
+
+    /// Takes the [`PlayerEncryptedData::BITS`] bits of a value, such as from the
+    /// output of a circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    pub fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+        Self {
+            loc: EncryptedCoord::from_bits(bits),
+            hp: EncU8::from_bits(bits),
+            atk: EncU8::from_bits(bits),
+            points: EncU8::from_bits(bits),
+        }
+    }
+
     pub fn from_cts(
         cts: &mut impl Iterator<Item = PhantomCt>,
         evaluator: &PhantomEvaluator,
     ) -> Self {
-        Self {
-            loc: EncryptedCoord::from_cts(cts, evaluator),
-            hp: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            atk: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            points: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-        }
+        Self::from_bits(&mut cts.map(|ct| evaluator.wrap(ct)))
     }
 }
 
@@ -103,6 +165,9 @@ pub struct PlayerWithEncryptedId {
 }
 
 impl PlayerWithEncryptedId {
+    /// Number of [`PlayerWithEncryptedId::bits`].
+    pub const BITS: usize = 8 + PlayerEncryptedData::BITS;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![&self.id, self.data.bits()]
@@ -120,9 +185,14 @@ pub struct ItemEncryptedData {
     pub atk: EncryptedU8,
     pub is_consumed: EncryptedBool,
     pub points: EncryptedU8,
+    /// Ticks left before a consumed item respawns, see [`Zone::maintain_items`].
+    pub respawn_ticks: EncryptedU8,
 }
 
 impl ItemEncryptedData {
+    /// Number of [`ItemEncryptedData::bits`].
+    pub const BITS: usize = EncryptedCoord::BITS + 1 + 4 * 8;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![
@@ -130,7 +200,8 @@ impl ItemEncryptedData {
             &self.hp,
             &self.atk,
             [&self.is_consumed],
-            &self.points
+            &self.points,
+            &self.respawn_ticks
         ]
     }
 
@@ -138,17 +209,33 @@ impl ItemEncryptedData {
         self.bits().map(|bit| bit.ct())
     }
 
+    /// Returns [`ItemEncryptedData::bits`] collected, such as to pass to a circuit.
+    pub fn to_bits(&self) -> Vec<PhantomBool> {
+        self.bits().cloned().collect()
+    }
+
+    /// Takes the [`ItemEncryptedData::BITS`] bits of a value, such as from the
+    /// output of a circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    pub fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+        Self {
+            loc: EncryptedCoord::from_bits(bits),
+            hp: EncU8::from_bits(bits),
+            atk: EncU8::from_bits(bits),
+            is_consumed: EncBool::from_bits(bits),
+            points: EncU8::from_bits(bits),
+            respawn_ticks: EncU8::from_bits(bits),
+        }
+    }
+
     pub fn from_cts(
         cts: &mut impl Iterator<Item = PhantomCt>,
         evaluator: &PhantomEvaluator,
     ) -> Self {
-        Self {
-            loc: EncryptedCoord::from_cts(cts, evaluator),
-            hp: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            atk: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            is_consumed: evaluator.wrap(cts.next().unwrap()),
-            points: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-        }
+        Self::from_bits(&mut cts.map(|ct| evaluator.wrap(ct)))
     }
 }
 
@@ -165,6 +252,9 @@ pub struct ItemWithEncryptedId {
 }
 
 impl ItemWithEncryptedId {
+    /// Number of [`ItemWithEncryptedId::bits`].
+    pub const BITS: usize = 8 + ItemEncryptedData::BITS;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![&self.id, self.data.bits()]
@@ -184,6 +274,9 @@ pub struct MonsterEncryptedData {
 }
 
 impl MonsterEncryptedData {
+    /// Number of [`MonsterEncryptedData::bits`].
+    pub const BITS: usize = EncryptedCoord::BITS + 3 * 8;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![self.loc.bits(), &self.hp, &self.atk, &self.points]
@@ -193,16 +286,31 @@ impl MonsterEncryptedData {
         self.bits().map(|bit| bit.ct())
     }
 
+    /// Returns [`MonsterEncryptedData::bits`] collected, such as to pass to a circuit.
+    pub fn to_bits(&self) -> Vec<PhantomBool> {
+        self.bits().cloned().collect()
+    }
+
+    /// Takes the [`MonsterEncryptedData::BITS`] bits of a value, such as from the
+    /// output of a circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` runs out.
+    pub fn from_bits(bits: &mut impl Iterator<Item = PhantomBool>) -> Self {
+        Self {
+            loc: EncryptedCoord::from_bits(bits),
+            hp: EncU8::from_bits(bits),
+            atk: EncU8::from_bits(bits),
+            points: EncU8::from_bits(bits),
+        }
+    }
+
     pub fn from_cts(
         cts: &mut impl Iterator<Item = PhantomCt>,
         evaluator: &PhantomEvaluator,
     ) -> Self {
-        Self {
-            loc: EncryptedCoord::from_cts(cts, evaluator),
-            hp: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            atk: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-            points: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
-        }
+        Self::from_bits(&mut cts.map(|ct| evaluator.wrap(ct)))
     }
 }
 
@@ -219,6 +327,9 @@ pub struct MonsterWithEncryptedId {
 }
 
 impl MonsterWithEncryptedId {
+    /// Number of [`MonsterWithEncryptedId::bits`].
+    pub const BITS: usize = 8 + MonsterEncryptedData::BITS;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![&self.id, self.data.bits()]
@@ -239,6 +350,9 @@ pub struct CellEncryptedData {
 }
 
 impl CellEncryptedData {
+    /// Number of [`CellEncryptedData::bits`].
+    pub const BITS: usize = 3 + 4 * 8;
+
     /// Returns concatenation of each field as bits in little-endian.
     pub fn bits(&self) -> impl Iterator<Item = &PhantomBool> {
         chain![
@@ -255,16 +369,148 @@ impl CellEncryptedData {
     }
 }
 
+/// Checks every circuit the zone evaluates against the layouts it passes and
+/// reads back, see [`phantom_benchs::widths`].
+pub fn check_circuits() -> Result<(), String> {
+    let num_players = num_players();
+    if num_players != NUM_PLAYERS {
+        return Err(format!(
+            "the FHE parameter has {num_players} shares, but the circuits are \
+             generated for {NUM_PLAYERS} players"
+        ));
+    }
+    // Player `i` starts at spawn point `i` and is numbered among the entities.
+    if num_players > NUM_SPAWN_POINTS.min(NUM_ENTITY_IDS) {
+        return Err(format!(
+            "{num_players} players don't fit the {NUM_SPAWN_POINTS} spawn points \
+             and {NUM_ENTITY_IDS} entity ids of the zone"
+        ));
+    }
+    let arg = |name, len, bits| ArgLayout { name, len, bits };
+    let coord = |name| arg(name, 1, EncryptedCoord::BITS);
+    let coords = |name, len| arg(name, len, EncryptedCoord::BITS);
+    let direction = arg("direction", 1, 2);
+    let cells_args = |query| {
+        chain![
+            [coord("player_coord")],
+            query,
+            [
+                arg("monsters", NUM_MONSTERS, MonsterWithEncryptedId::BITS),
+                arg("items", NUM_ITEMS, ItemWithEncryptedId::BITS),
+                arg("players", num_players, PlayerWithEncryptedId::BITS),
+            ]
+        ]
+        .collect_vec()
+    };
+    let move_args = [
+        coord("old_coords"),
+        direction,
+        coords("players_coords", num_players),
+        coords("monster_coords", NUM_MONSTERS),
+        coords("item_coords", NUM_ITEMS),
+    ];
+    let layouts = [
+        (
+            "apply_move_monster",
+            move_args.to_vec(),
+            EncryptedCoord::BITS,
+        ),
+        (
+            "apply_move_flying",
+            move_args.to_vec(),
+            EncryptedCoord::BITS,
+        ),
+        (
+            "apply_move_pvp",
+            vec![
+                arg("player_data", 1, PlayerEncryptedData::BITS),
+                direction,
+                arg("others", num_players - 1, PlayerEncryptedData::BITS),
+                arg("monsters", NUM_MONSTERS, MonsterEncryptedData::BITS),
+                arg("items", NUM_ITEMS, ItemEncryptedData::BITS),
+            ],
+            num_players * PlayerEncryptedData::BITS
+                + NUM_ITEMS * ItemEncryptedData::BITS
+                + NUM_MONSTERS * MonsterEncryptedData::BITS,
+        ),
+        (
+            "respawn",
+            vec![
+                arg("player_data", 1, PlayerEncryptedData::BITS),
+                arg("random_state", 1, 8),
+                arg("points_shift", 1, 8),
+                coords("players_coords", num_players),
+            ],
+            PlayerEncryptedData::BITS,
+        ),
+        (
+            "maintain_monsters",
+            vec![
+                arg("monsters", NUM_MONSTERS, MonsterEncryptedData::BITS),
+                arg("dead_ticks", NUM_MONSTERS, 8),
+                coords("players_coords", num_players),
+            ],
+            NUM_MONSTERS * (MonsterEncryptedData::BITS + 8),
+        ),
+        (
+            "maintain_items",
+            vec![
+                arg("items", NUM_ITEMS, ItemEncryptedData::BITS),
+                coords("players_coords", num_players),
+            ],
+            NUM_ITEMS * ItemEncryptedData::BITS,
+        ),
+        (
+            "get_cell",
+            cells_args(vec![coord("query_coord")]),
+            CellEncryptedData::BITS,
+        ),
+        (
+            "get_cross_cells",
+            cells_args(vec![]),
+            5 * CellEncryptedData::BITS,
+        ),
+        (
+            "get_five_cells",
+            cells_args(vec![coords("query_coords", 5)]),
+            5 * CellEncryptedData::BITS,
+        ),
+        (
+            "get_horizontal_cells",
+            cells_args(vec![coord("query_coord")]),
+            5 * CellEncryptedData::BITS,
+        ),
+        (
+            "get_vertical_cells",
+            cells_args(vec![coord("query_coord")]),
+            5 * CellEncryptedData::BITS,
+        ),
+    ];
+    for (name, args, output_bits) in layouts {
+        widths::check(name, &args, output_bits)?;
+    }
+    Ok(())
+}
+
+/// Ids of the players other than `player_id`, in increasing order.
+pub fn other_player_ids(player_id: usize) -> Vec<usize> {
+    (0..num_players()).filter(|&id| id != player_id).collect()
+}
+
+/// Encrypted state of the game. Obstacles are not part of it: the map is public
+/// and compiled into the move circuits, see `circuits_cpp/src/map.h`.
 #[derive(Clone, Debug)]
 pub struct Zone {
     pub width: u8,
     pub height: u8,
-    pub players: [Player; 4],
+    pub players: Vec<Player>,
     pub items: [Item; NUM_ITEMS],
     pub monsters: [Monster; NUM_MONSTERS],
-    pub obstacles: [EncryptedCoord; NUM_OBSTACLES],
     pub random_state: EncryptedRandomState,
-    pub precomputed_ids: [EncryptedU8; 34],
+    pub precomputed_ids: [EncryptedU8; NUM_ENTITY_IDS],
+    /// Ticks each monster has been dead for, see [`Zone::maintain_monsters`].
+    /// Only used by the server, so it's left out of [`ZoneDiff`].
+    pub monster_dead_ticks: [EncryptedU8; NUM_MONSTERS],
 }
 
 pub fn fhe_apply_move_raw(
@@ -333,11 +579,11 @@ pub fn fhe_apply_move_check_collisions(
 pub fn fhe_apply_move_monster(
     old_coords: EncryptedCoord,
     direction: EncryptedDirection,
-    player_coords: [EncryptedCoord; 4],
+    player_coords: Vec<EncryptedCoord>,
     monster_coords: [EncryptedCoord; NUM_MONSTERS],
     item_coords: [EncryptedCoord; NUM_ITEMS],
 ) -> EncryptedCoord {
-    let mut output_bits =
+    let mut output_bits = telemetry::evaluate("apply_move_monster", || {
         phantom_benchs::frogzone_apply_move_monster_rs_fhe_lib::apply_move_monster(
             &direction.to_vec(),
             &item_coords
@@ -357,12 +603,10 @@ pub fn fhe_apply_move_monster(
                 .cloned()
                 .collect_vec(),
         )
-        .into_iter();
+    })
+    .into_iter();
 
-    let output = EncryptedCoord {
-        x: from_fn(|_| output_bits.next().unwrap()),
-        y: from_fn(|_| output_bits.next().unwrap()),
-    };
+    let output = EncryptedCoord::from_bits(&mut output_bits);
     assert!(output_bits.next().is_none());
     output
 }
@@ -370,110 +614,166 @@ pub fn fhe_apply_move_monster(
 pub fn fhe_apply_move_flying(
     old_coords: EncryptedCoord,
     direction: EncryptedDirection,
-    player_coords: [EncryptedCoord; 4],
+    player_coords: Vec<EncryptedCoord>,
     monster_coords: [EncryptedCoord; NUM_MONSTERS],
     item_coords: [EncryptedCoord; NUM_ITEMS],
 ) -> EncryptedCoord {
-    let mut output_bits = phantom_benchs::frogzone_apply_move_flying_rs_fhe_lib::apply_move_flying(
-        &direction.to_vec(),
-        &item_coords
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &monster_coords
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &old_coords.bits().cloned().collect_vec(),
-        &player_coords
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-    )
+    let mut output_bits = telemetry::evaluate("apply_move_flying", || {
+        phantom_benchs::frogzone_apply_move_flying_rs_fhe_lib::apply_move_flying(
+            &direction.to_vec(),
+            &item_coords
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &monster_coords
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &old_coords.bits().cloned().collect_vec(),
+            &player_coords
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+        )
+    })
     .into_iter();
 
-    let output = EncryptedCoord {
-        x: from_fn(|_| output_bits.next().unwrap()),
-        y: from_fn(|_| output_bits.next().unwrap()),
-    };
+    let output = EncryptedCoord::from_bits(&mut output_bits);
     assert!(output_bits.next().is_none());
     output
 }
 
-pub fn fhe_apply_move(
+/// Moves a player, picking up items and attacking monsters on the way, and
+/// attacking another player instead of being blocked by it, see
+/// [`mock_zone::fhe_apply_move_pvp`](crate::mock_zone::fhe_apply_move_pvp).
+/// Outputs every player's data, `others` being the players other than the one
+/// moving, see [`other_player_ids`].
+pub fn fhe_apply_move_pvp(
     player_data: PlayerEncryptedData,
     direction: EncryptedDirection,
-    height: u8,
-    width: u8,
-    obstacles: [EncryptedCoord; 4],
+    others: Vec<PlayerEncryptedData>,
     monsters: [MonsterEncryptedData; NUM_MONSTERS],
     items: [ItemEncryptedData; NUM_ITEMS],
 ) -> (
     PlayerEncryptedData,
+    Vec<PlayerEncryptedData>,
     [ItemEncryptedData; NUM_ITEMS],
     [MonsterEncryptedData; NUM_MONSTERS],
 ) {
-    let mut output_bits = phantom_benchs::frogzone_apply_move_rs_fhe_lib::apply_move(
-        &direction.to_vec(),
-        &items
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &monsters
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &player_data.bits().cloned().collect_vec(),
-        &obstacles
-            .iter()
-            .flat_map(|obstacle| obstacle.bits())
-            .cloned()
-            .collect_vec(),
-    )
+    let num_others = others.len();
+    let mut output_bits = telemetry::evaluate("apply_move_pvp", || {
+        phantom_benchs::frogzone_apply_move_pvp_rs_fhe_lib::apply_move_pvp(
+            &direction.to_vec(),
+            &items
+                .iter()
+                .flat_map(ItemEncryptedData::to_bits)
+                .collect_vec(),
+            &monsters
+                .iter()
+                .flat_map(MonsterEncryptedData::to_bits)
+                .collect_vec(),
+            &others
+                .iter()
+                .flat_map(PlayerEncryptedData::to_bits)
+                .collect_vec(),
+            &player_data.to_bits(),
+        )
+    })
     .into_iter();
     let output = (
-        PlayerEncryptedData {
-            loc: EncryptedCoord {
-                x: from_fn(|_| output_bits.next().unwrap()),
-                y: from_fn(|_| output_bits.next().unwrap()),
-            },
-            hp: from_fn(|_| output_bits.next().unwrap()),
-            atk: from_fn(|_| output_bits.next().unwrap()),
-            points: from_fn(|_| output_bits.next().unwrap()),
-        },
-        from_fn(|_| ItemEncryptedData {
-            loc: EncryptedCoord {
-                x: from_fn(|_| output_bits.next().unwrap()),
-                y: from_fn(|_| output_bits.next().unwrap()),
-            },
-            hp: from_fn(|_| output_bits.next().unwrap()),
-            atk: from_fn(|_| output_bits.next().unwrap()),
-            is_consumed: output_bits.next().unwrap(),
-            points: from_fn(|_| output_bits.next().unwrap()),
-        }),
-        from_fn(|_| MonsterEncryptedData {
-            loc: EncryptedCoord {
-                x: from_fn(|_| output_bits.next().unwrap()),
-                y: from_fn(|_| output_bits.next().unwrap()),
-            },
-            hp: from_fn(|_| output_bits.next().unwrap()),
-            atk: from_fn(|_| output_bits.next().unwrap()),
-            points: from_fn(|_| output_bits.next().unwrap()),
-        }),
+        PlayerEncryptedData::from_bits(&mut output_bits),
+        (0..num_others)
+            .map(|_| PlayerEncryptedData::from_bits(&mut output_bits))
+            .collect(),
+        from_fn(|_| ItemEncryptedData::from_bits(&mut output_bits)),
+        from_fn(|_| MonsterEncryptedData::from_bits(&mut output_bits)),
     );
     assert!(output_bits.next().is_none());
     output
 }
 
+pub fn fhe_respawn(
+    player_data: PlayerEncryptedData,
+    random_state: EncryptedRandomState,
+    points_shift: EncryptedU8,
+    players_coords: Vec<EncryptedCoord>,
+) -> PlayerEncryptedData {
+    let mut output_bits = telemetry::evaluate("respawn", || {
+        phantom_benchs::frogzone_respawn_rs_fhe_lib::respawn(
+            &player_data.to_bits(),
+            &players_coords
+                .iter()
+                .flat_map(EncryptedCoord::to_bits)
+                .collect_vec(),
+            &points_shift.to_vec(),
+            &random_state.to_vec(),
+        )
+    })
+    .into_iter();
+    let output = PlayerEncryptedData::from_bits(&mut output_bits);
+    assert!(output_bits.next().is_none());
+    output
+}
+
+pub fn fhe_maintain_monsters(
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    dead_ticks: [EncryptedU8; NUM_MONSTERS],
+    players_coords: Vec<EncryptedCoord>,
+) -> (
+    [MonsterEncryptedData; NUM_MONSTERS],
+    [EncryptedU8; NUM_MONSTERS],
+) {
+    let mut output_bits = telemetry::evaluate("maintain_monsters", || {
+        phantom_benchs::frogzone_maintain_monsters_rs_fhe_lib::maintain_monsters(
+            &dead_ticks.iter().flatten().cloned().collect_vec(),
+            &monsters
+                .iter()
+                .flat_map(MonsterEncryptedData::to_bits)
+                .collect_vec(),
+            &players_coords
+                .iter()
+                .flat_map(EncryptedCoord::to_bits)
+                .collect_vec(),
+        )
+    })
+    .into_iter();
+    let output = (
+        from_fn(|_| MonsterEncryptedData::from_bits(&mut output_bits)),
+        from_fn(|_| EncU8::from_bits(&mut output_bits)),
+    );
+    assert!(output_bits.next().is_none());
+    output
+}
+
+pub fn fhe_maintain_items(
+    items: [ItemEncryptedData; NUM_ITEMS],
+    players_coords: Vec<EncryptedCoord>,
+) -> [ItemEncryptedData; NUM_ITEMS] {
+    let mut output_bits = telemetry::evaluate("maintain_items", || {
+        phantom_benchs::frogzone_maintain_items_rs_fhe_lib::maintain_items(
+            &items
+                .iter()
+                .flat_map(ItemEncryptedData::to_bits)
+                .collect_vec(),
+            &players_coords
+                .iter()
+                .flat_map(EncryptedCoord::to_bits)
+                .collect_vec(),
+        )
+    })
+    .into_iter();
+    let output = from_fn(|_| ItemEncryptedData::from_bits(&mut output_bits));
+    assert!(output_bits.next().is_none());
+    output
+}
+
 fn fhe_get_cell_no_check(
     coord: EncryptedCoord,
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> CellEncryptedData {
     todo!()
     // let mut cell = CellEncryptedData::default();
@@ -516,34 +816,36 @@ fn fhe_get_cell(
     query_coord: EncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> CellEncryptedData {
-    let mut output_bits = phantom_benchs::frogzone_get_cell_rs_fhe_lib::get_cell(
-        &items
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &monsters
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &player_coord.bits().cloned().collect_vec(),
-        &players
-            .iter()
-            .flat_map(|player| player.bits())
-            .cloned()
-            .collect_vec(),
-        &query_coord.bits().cloned().collect_vec(),
-    )
+    let mut output_bits = telemetry::evaluate("get_cell", || {
+        phantom_benchs::frogzone_get_cell_rs_fhe_lib::get_cell(
+            &items
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &monsters
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &player_coord.bits().cloned().collect_vec(),
+            &players
+                .iter()
+                .flat_map(|player| player.bits())
+                .cloned()
+                .collect_vec(),
+            &query_coord.bits().cloned().collect_vec(),
+        )
+    })
     .into_iter();
     let output = CellEncryptedData {
         entity_type: from_fn(|_| output_bits.next().unwrap()),
-        entity_id: from_fn(|_| output_bits.next().unwrap()),
-        hp: from_fn(|_| output_bits.next().unwrap()),
-        atk: from_fn(|_| output_bits.next().unwrap()),
-        points: from_fn(|_| output_bits.next().unwrap()),
+        entity_id: EncU8::from_bits(&mut output_bits),
+        hp: EncU8::from_bits(&mut output_bits),
+        atk: EncU8::from_bits(&mut output_bits),
+        points: EncU8::from_bits(&mut output_bits),
     };
     assert!(output_bits.next().is_none());
     output
@@ -554,38 +856,40 @@ fn fhe_get_five_cells(
     query_coords: [EncryptedCoord; 5],
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
-    let mut output_bits = phantom_benchs::frogzone_get_five_cells_rs_fhe_lib::get_five_cells(
-        &items
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &monsters
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &player_coord.bits().cloned().collect_vec(),
-        &players
-            .iter()
-            .flat_map(|player| player.bits())
-            .cloned()
-            .collect_vec(),
-        &query_coords
-            .iter()
-            .flat_map(|query_coord| query_coord.bits())
-            .cloned()
-            .collect_vec(),
-    )
+    let mut output_bits = telemetry::evaluate("get_five_cells", || {
+        phantom_benchs::frogzone_get_five_cells_rs_fhe_lib::get_five_cells(
+            &items
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &monsters
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &player_coord.bits().cloned().collect_vec(),
+            &players
+                .iter()
+                .flat_map(|player| player.bits())
+                .cloned()
+                .collect_vec(),
+            &query_coords
+                .iter()
+                .flat_map(|query_coord| query_coord.bits())
+                .cloned()
+                .collect_vec(),
+        )
+    })
     .into_iter();
     let output = from_fn(|_| CellEncryptedData {
         entity_type: from_fn(|_| output_bits.next().unwrap()),
-        entity_id: from_fn(|_| output_bits.next().unwrap()),
-        hp: from_fn(|_| output_bits.next().unwrap()),
-        atk: from_fn(|_| output_bits.next().unwrap()),
-        points: from_fn(|_| output_bits.next().unwrap()),
+        entity_id: EncU8::from_bits(&mut output_bits),
+        hp: EncU8::from_bits(&mut output_bits),
+        atk: EncU8::from_bits(&mut output_bits),
+        points: EncU8::from_bits(&mut output_bits),
     });
     assert!(output_bits.next().is_none());
     output
@@ -595,33 +899,35 @@ fn fhe_get_cross_cells(
     player_coord: EncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
-    let mut output_bits = phantom_benchs::frogzone_get_cross_cells_rs_fhe_lib::get_cross_cells(
-        &items
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &monsters
-            .iter()
-            .flat_map(|item| item.bits())
-            .cloned()
-            .collect_vec(),
-        &player_coord.bits().cloned().collect_vec(),
-        &players
-            .iter()
-            .flat_map(|player| player.bits())
-            .cloned()
-            .collect_vec(),
-    )
+    let mut output_bits = telemetry::evaluate("get_cross_cells", || {
+        phantom_benchs::frogzone_get_cross_cells_rs_fhe_lib::get_cross_cells(
+            &items
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &monsters
+                .iter()
+                .flat_map(|item| item.bits())
+                .cloned()
+                .collect_vec(),
+            &player_coord.bits().cloned().collect_vec(),
+            &players
+                .iter()
+                .flat_map(|player| player.bits())
+                .cloned()
+                .collect_vec(),
+        )
+    })
     .into_iter();
     let output = from_fn(|_| CellEncryptedData {
         entity_type: from_fn(|_| output_bits.next().unwrap()),
-        entity_id: from_fn(|_| output_bits.next().unwrap()),
-        hp: from_fn(|_| output_bits.next().unwrap()),
-        atk: from_fn(|_| output_bits.next().unwrap()),
-        points: from_fn(|_| output_bits.next().unwrap()),
+        entity_id: EncU8::from_bits(&mut output_bits),
+        hp: EncU8::from_bits(&mut output_bits),
+        atk: EncU8::from_bits(&mut output_bits),
+        points: EncU8::from_bits(&mut output_bits),
     });
     assert!(output_bits.next().is_none());
     output
@@ -632,9 +938,9 @@ fn fhe_get_vertical_cells(
     query_coord: EncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
-    let mut output_bits =
+    let mut output_bits = telemetry::evaluate("get_vertical_cells", || {
         phantom_benchs::frogzone_get_vertical_cells_rs_fhe_lib::get_vertical_cells(
             &items
                 .iter()
@@ -654,13 +960,14 @@ fn fhe_get_vertical_cells(
                 .collect_vec(),
             &query_coord.bits().cloned().collect_vec(),
         )
-        .into_iter();
+    })
+    .into_iter();
     let output = from_fn(|_| CellEncryptedData {
         entity_type: from_fn(|_| output_bits.next().unwrap()),
-        entity_id: from_fn(|_| output_bits.next().unwrap()),
-        hp: from_fn(|_| output_bits.next().unwrap()),
-        atk: from_fn(|_| output_bits.next().unwrap()),
-        points: from_fn(|_| output_bits.next().unwrap()),
+        entity_id: EncU8::from_bits(&mut output_bits),
+        hp: EncU8::from_bits(&mut output_bits),
+        atk: EncU8::from_bits(&mut output_bits),
+        points: EncU8::from_bits(&mut output_bits),
     });
     assert!(output_bits.next().is_none());
     output
@@ -671,9 +978,9 @@ fn fhe_get_horizontal_cells(
     query_coord: EncryptedCoord,
     monsters: [MonsterWithEncryptedId; NUM_MONSTERS],
     items: [ItemWithEncryptedId; NUM_ITEMS],
-    players: [PlayerWithEncryptedId; 4],
+    players: Vec<PlayerWithEncryptedId>,
 ) -> [CellEncryptedData; 5] {
-    let mut output_bits =
+    let mut output_bits = telemetry::evaluate("get_horizontal_cells", || {
         phantom_benchs::frogzone_get_horizontal_cells_rs_fhe_lib::get_horizontal_cells(
             &items
                 .iter()
@@ -693,94 +1000,75 @@ fn fhe_get_horizontal_cells(
                 .collect_vec(),
             &query_coord.bits().cloned().collect_vec(),
         )
-        .into_iter();
+    })
+    .into_iter();
     let output = from_fn(|_| CellEncryptedData {
         entity_type: from_fn(|_| output_bits.next().unwrap()),
-        entity_id: from_fn(|_| output_bits.next().unwrap()),
-        hp: from_fn(|_| output_bits.next().unwrap()),
-        atk: from_fn(|_| output_bits.next().unwrap()),
-        points: from_fn(|_| output_bits.next().unwrap()),
+        entity_id: EncU8::from_bits(&mut output_bits),
+        hp: EncU8::from_bits(&mut output_bits),
+        atk: EncU8::from_bits(&mut output_bits),
+        points: EncU8::from_bits(&mut output_bits),
     });
     assert!(output_bits.next().is_none());
     output
 }
 
-fn pk_encrypt<const N: usize>(evaluator: &PhantomEvaluator, value: u8) -> [EncryptedBool; N] {
-    evaluator
-        .unbatch(&evaluator.batched_pk_encrypt((0..N).map(|i| (value >> i) & 1 == 1)))
-        .try_into()
+/// Returns encrypted `value == 0` for every value.
+fn all_zero<'a>(values: impl IntoIterator<Item = &'a EncryptedU8>) -> EncryptedBool {
+    values
+        .into_iter()
+        .map(|value| {
+            !value
+                .iter()
+                .skip(1)
+                .fold(value[0].clone(), |acc, bit| acc | bit)
+        })
+        .reduce(|acc, is_zero| acc & &is_zero)
         .unwrap()
 }
 
+fn pk_encrypt<T: Enc>(evaluator: &PhantomEvaluator, value: T::Plain) -> T {
+    let cts = evaluator
+        .unbatch(&evaluator.batched_pk_encrypt(T::encode(value)))
+        .unwrap();
+    T::from_bits(&mut cts.into_iter())
+}
+
 impl Zone {
-    pub fn new(width: u8, height: u8, evaluator: &PhantomEvaluator) -> Self {
-        let players = [
-            Player {
-                id: 0,
-                data: PlayerEncryptedData {
-                    loc: EncryptedCoord {
-                        x: pk_encrypt(evaluator, 3),
-                        y: pk_encrypt(evaluator, 27),
-                    },
-                    hp: pk_encrypt(evaluator, 5),
-                    atk: pk_encrypt(evaluator, 1),
-                    points: pk_encrypt(evaluator, 0),
-                },
-            },
-            Player {
-                id: 1,
-                data: PlayerEncryptedData {
-                    loc: EncryptedCoord {
-                        x: pk_encrypt(evaluator, 19),
-                        y: pk_encrypt(evaluator, 27),
-                    },
-                    hp: pk_encrypt(evaluator, 5),
-                    atk: pk_encrypt(evaluator, 1),
-                    points: pk_encrypt(evaluator, 0),
-                },
-            },
-            Player {
-                id: 2,
-                data: PlayerEncryptedData {
-                    loc: EncryptedCoord {
-                        x: pk_encrypt(evaluator, 28),
-                        y: pk_encrypt(evaluator, 28),
-                    },
-                    hp: pk_encrypt(evaluator, 5),
-                    atk: pk_encrypt(evaluator, 1),
-                    points: pk_encrypt(evaluator, 0),
-                },
-            },
-            Player {
-                id: 3,
+    pub fn new(map: &Map, evaluator: &PhantomEvaluator) -> Self {
+        let players = (0..num_players())
+            .map(|id| Player {
+                id,
                 data: PlayerEncryptedData {
                     loc: EncryptedCoord {
-                        x: pk_encrypt(evaluator, 12),
-                        y: pk_encrypt(evaluator, 29),
+                        x: pk_encrypt(evaluator, map.spawns[id].x),
+                        y: pk_encrypt(evaluator, map.spawns[id].y),
                     },
-                    hp: pk_encrypt(evaluator, 5),
-                    atk: pk_encrypt(evaluator, 1),
+                    hp: pk_encrypt(evaluator, INITIAL_PLAYER_HP),
+                    atk: pk_encrypt(evaluator, INITIAL_PLAYER_ATK),
                     points: pk_encrypt(evaluator, 0),
                 },
-            },
-        ];
+            })
+            .collect();
 
+        // Filler items start consumed and lie outside the map, where no move
+        // or cell query reaches them.
         let filler_item = Item {
             id: 0,
             data: ItemEncryptedData {
                 loc: EncryptedCoord {
-                    x: pk_encrypt(evaluator, 0),
-                    y: pk_encrypt(evaluator, 0),
+                    x: pk_encrypt(evaluator, 255),
+                    y: pk_encrypt(evaluator, 255),
                 },
                 hp: pk_encrypt(evaluator, 0),
                 atk: pk_encrypt(evaluator, 0),
-                is_consumed: pk_encrypt::<1>(evaluator, 0)[0].clone(),
+                is_consumed: pk_encrypt(evaluator, true),
                 points: pk_encrypt(evaluator, 0),
+                respawn_ticks: pk_encrypt(evaluator, 0),
             },
         };
         let mut items: [Item; NUM_ITEMS] = from_fn(|_| filler_item.clone());
-        let plaintext_items = get_all_items();
-        for (idx, plaintext_item) in plaintext_items.iter().enumerate() {
+        for (idx, plaintext_item) in map.items.iter().enumerate() {
             items[idx] = Item {
                 id: idx,
                 data: ItemEncryptedData {
@@ -790,8 +1078,9 @@ impl Zone {
                     },
                     hp: pk_encrypt(evaluator, plaintext_item.hp),
                     atk: pk_encrypt(evaluator, plaintext_item.atk),
-                    is_consumed: pk_encrypt::<1>(evaluator, 0)[0].clone(),
+                    is_consumed: pk_encrypt(evaluator, false),
                     points: pk_encrypt(evaluator, plaintext_item.points),
+                    respawn_ticks: pk_encrypt(evaluator, 0),
                 },
             };
         }
@@ -809,8 +1098,7 @@ impl Zone {
             },
         };
         let mut monsters: [Monster; NUM_MONSTERS] = from_fn(|_| filler_monster.clone());
-        let plaintext_monsters = get_all_monsters();
-        for (idx, plaintext_monster) in plaintext_monsters.iter().enumerate() {
+        for (idx, plaintext_monster) in map.monsters.iter().enumerate() {
             monsters[idx] = Monster {
                 id: idx,
                 data: MonsterEncryptedData {
@@ -825,67 +1113,21 @@ impl Zone {
             };
         }
 
-        let filler_coord = EncryptedCoord {
-            x: pk_encrypt(evaluator, 255),
-            y: pk_encrypt(evaluator, 255),
-        };
-        let mut obstacles: [EncryptedCoord; NUM_OBSTACLES] = from_fn(|_| filler_coord.clone());
-        let plaintext_obstacles = get_all_obstacles();
-        for (idx, plaintext_obstacle) in plaintext_obstacles.iter().enumerate() {
-            obstacles[idx] = EncryptedCoord {
-                x: pk_encrypt(evaluator, plaintext_obstacle.x),
-                y: pk_encrypt(evaluator, plaintext_obstacle.y),
-            };
-        }
-
         let random_state = pk_encrypt(evaluator, 0);
 
-        let precomputed_ids = [
-            pk_encrypt(evaluator, 0),
-            pk_encrypt(evaluator, 1),
-            pk_encrypt(evaluator, 2),
-            pk_encrypt(evaluator, 3),
-            pk_encrypt(evaluator, 4),
-            pk_encrypt(evaluator, 5),
-            pk_encrypt(evaluator, 6),
-            pk_encrypt(evaluator, 7),
-            pk_encrypt(evaluator, 8),
-            pk_encrypt(evaluator, 9),
-            pk_encrypt(evaluator, 10),
-            pk_encrypt(evaluator, 11),
-            pk_encrypt(evaluator, 12),
-            pk_encrypt(evaluator, 13),
-            pk_encrypt(evaluator, 14),
-            pk_encrypt(evaluator, 15),
-            pk_encrypt(evaluator, 16),
-            pk_encrypt(evaluator, 17),
-            pk_encrypt(evaluator, 18),
-            pk_encrypt(evaluator, 19),
-            pk_encrypt(evaluator, 20),
-            pk_encrypt(evaluator, 21),
-            pk_encrypt(evaluator, 22),
-            pk_encrypt(evaluator, 23),
-            pk_encrypt(evaluator, 24),
-            pk_encrypt(evaluator, 25),
-            pk_encrypt(evaluator, 26),
-            pk_encrypt(evaluator, 27),
-            pk_encrypt(evaluator, 28),
-            pk_encrypt(evaluator, 29),
-            pk_encrypt(evaluator, 30),
-            pk_encrypt(evaluator, 31),
-            pk_encrypt(evaluator, 32),
-            pk_encrypt(evaluator, 33),
-        ];
+        let precomputed_ids = from_fn(|id| pk_encrypt(evaluator, id as _));
+
+        let monster_dead_ticks = from_fn(|_| pk_encrypt(evaluator, 0));
 
         Self {
-            width,
-            height,
+            width: map.width,
+            height: map.height,
             players,
             items,
             monsters,
-            obstacles,
             random_state,
             precomputed_ids,
+            monster_dead_ticks,
         }
     }
 
@@ -901,7 +1143,7 @@ impl Zone {
             self.random_state[1].clone() as EncryptedBool,
         ] as EncryptedDirection;
 
-        let player_coords = self.players.each_ref().map(|i| i.data.loc.clone());
+        let player_coords = self.players.iter().map(|i| i.data.loc.clone()).collect();
 
         let monster_coords = self.monsters.each_ref().map(|i| i.data.loc.clone());
 
@@ -934,7 +1176,7 @@ impl Zone {
             self.random_state[1].clone() as EncryptedBool,
         ] as EncryptedDirection;
 
-        let player_coords = self.players.each_ref().map(|i| i.data.loc.clone());
+        let player_coords = self.players.iter().map(|i| i.data.loc.clone()).collect();
 
         let monster_coords = self.monsters.each_ref().map(|i| i.data.loc.clone());
 
@@ -956,6 +1198,10 @@ impl Zone {
         println!("Moving random flyer {}", idx);
     }
 
+    /// Moves the player, picking up items, fighting monsters and attacking
+    /// other players on the way. A dead player (`hp == 0`) is left unchanged by
+    /// the circuit. Every player's data is re-encrypted by the circuit, so all
+    /// of them have to be synced to workers afterwards.
     pub fn move_player(
         &mut self,
         player_id: usize,
@@ -965,24 +1211,26 @@ impl Zone {
 
         let player_data = self.players[player_id].data.clone();
 
+        let other_ids = other_player_ids(player_id);
+
+        let other_data = other_ids
+            .iter()
+            .map(|&id| self.players[id].data.clone())
+            .collect();
+
         let item_data = self.items.each_ref().map(|i| i.data.clone());
 
         let monster_data = self.monsters.each_ref().map(|i| i.data.clone());
 
-        let obstacles: [EncryptedCoord; 4] = from_fn(|i| self.players[i].data.loc.clone());
-
-        let (new_player_data, new_item_data, new_monster_data) = fhe_apply_move(
-            player_data,
-            direction,
-            self.height,
-            self.width,
-            obstacles,
-            monster_data,
-            item_data,
-        );
+        let (new_player_data, new_other_data, new_item_data, new_monster_data) =
+            fhe_apply_move_pvp(player_data, direction, other_data, monster_data, item_data);
 
         self.players[player_id].data = new_player_data;
 
+        for (id, data) in izip!(other_ids, new_other_data) {
+            self.players[id].data = data;
+        }
+
         for i in 0..self.items.len() {
             self.items[i].data = new_item_data[i].clone();
         }
@@ -994,20 +1242,93 @@ impl Zone {
         self.players[player_id].data.loc.clone()
     }
 
+    /// Respawns the player if it is dead, see [`crate::respawn`]. The frog
+    /// keeps `points >> points_shift` of its points.
+    pub fn respawn_player(
+        &mut self,
+        player_id: usize,
+        points_shift: u8,
+        evaluator: &PhantomEvaluator,
+    ) -> EncryptedCoord {
+        assert!(player_id < self.players.len());
+
+        let players_coords = self
+            .players
+            .iter()
+            .map(|player| player.data.loc.clone())
+            .collect();
+
+        self.players[player_id].data = fhe_respawn(
+            self.players[player_id].data.clone(),
+            self.random_state.clone(),
+            pk_encrypt(evaluator, points_shift),
+            players_coords,
+        );
+
+        self.players[player_id].data.loc.clone()
+    }
+
+    /// Revives monsters dead for [`MONSTER_RESPAWN_TICKS`] ticks at their
+    /// initial location, and regenerates 1 hp of wounded monsters. Every
+    /// monster goes through the circuit, so nothing tells which one died.
+    ///
+    /// [`MONSTER_RESPAWN_TICKS`]: crate::respawn::MONSTER_RESPAWN_TICKS
+    pub fn maintain_monsters(&mut self) {
+        let monster_data = self.monsters.each_ref().map(|i| i.data.clone());
+
+        let players_coords = self
+            .players
+            .iter()
+            .map(|player| player.data.loc.clone())
+            .collect();
+
+        let (new_monster_data, new_dead_ticks) = fhe_maintain_monsters(
+            monster_data,
+            self.monster_dead_ticks.clone(),
+            players_coords,
+        );
+
+        for (monster, data) in izip!(&mut self.monsters, new_monster_data) {
+            monster.data = data;
+        }
+        self.monster_dead_ticks = new_dead_ticks;
+    }
+
+    /// Counts down the [`ITEM_RESPAWN_TICKS`] set on consumed items, and puts
+    /// them back once it reaches 0 and no player stands on them.
+    ///
+    /// [`ITEM_RESPAWN_TICKS`]: crate::respawn::ITEM_RESPAWN_TICKS
+    pub fn maintain_items(&mut self) {
+        let item_data = self.items.each_ref().map(|i| i.data.clone());
+
+        let players_coords = self
+            .players
+            .iter()
+            .map(|player| player.data.loc.clone())
+            .collect();
+
+        let new_item_data = fhe_maintain_items(item_data, players_coords);
+
+        for (item, data) in izip!(&mut self.items, new_item_data) {
+            item.data = data;
+        }
+    }
+
     pub fn mix_random_input(&mut self, player_id: usize, random_input: EncryptedRandomState) {
         assert!(player_id < self.players.len());
 
-        izip!(&mut self.random_state, random_input).for_each(|(state, input)| *state ^= input);
+        izip!(self.random_state.iter_mut(), random_input.0)
+            .for_each(|(state, input)| *state ^= input);
     }
 
-    fn fully_encrypted_players(&self) -> [PlayerWithEncryptedId; 4] {
-        from_fn(|i| {
-            let player = self.players[i].clone();
-            PlayerWithEncryptedId {
+    fn fully_encrypted_players(&self) -> Vec<PlayerWithEncryptedId> {
+        self.players
+            .iter()
+            .map(|player| PlayerWithEncryptedId {
                 id: self.precomputed_ids[player.id].clone(),
-                data: player.data,
-            }
-        })
+                data: player.data.clone(),
+            })
+            .collect()
     }
 
     fn fully_encrypted_items(&self) -> [ItemWithEncryptedId; NUM_ITEMS] {
@@ -1106,6 +1427,26 @@ impl Zone {
         )
     }
 
+    // For game over
+
+    /// Returns encrypted `[all monsters are dead, all players are dead]`.
+    pub fn game_over_flags(&self) -> [EncryptedBool; 2] {
+        telemetry::evaluate("game_over_flags", || {
+            [
+                all_zero(self.monsters.iter().map(|monster| &monster.data.hp)),
+                all_zero(self.players.iter().map(|player| &player.data.hp)),
+            ]
+        })
+    }
+
+    /// Returns concatenation of `hp` and `points` of each player as bits in
+    /// little-endian.
+    pub fn score_bits(&self) -> impl Iterator<Item = &PhantomBool> {
+        self.players
+            .iter()
+            .flat_map(|player| chain![&player.data.hp, &player.data.points])
+    }
+
     // For syncing with workers
 
     pub fn cts(&self) -> Vec<PhantomCt> {
@@ -1113,8 +1454,8 @@ impl Zone {
             self.players.iter().flat_map(|player| player.data.cts()),
             self.items.iter().flat_map(|item| item.data.cts()),
             self.monsters.iter().flat_map(|monster| monster.data.cts()),
-            self.obstacles.iter().flat_map(|obstacle| obstacle.cts()),
-            self.random_state.iter().map(|bit| bit.ct())
+            self.random_state.iter().map(|bit| bit.ct()),
+            self.monster_dead_ticks.iter().flatten().map(|bit| bit.ct())
         ]
         .cloned()
         .collect()
@@ -1130,10 +1471,12 @@ impl Zone {
         Zone {
             width,
             height,
-            players: from_fn(|id| Player {
-                id,
-                data: PlayerEncryptedData::from_cts(&mut cts, evaluator),
-            }),
+            players: (0..num_players())
+                .map(|id| Player {
+                    id,
+                    data: PlayerEncryptedData::from_cts(&mut cts, evaluator),
+                })
+                .collect(),
             items: from_fn(|id| Item {
                 id,
                 data: ItemEncryptedData::from_cts(&mut cts, evaluator),
@@ -1142,15 +1485,17 @@ impl Zone {
                 id,
                 data: MonsterEncryptedData::from_cts(&mut cts, evaluator),
             }),
-            obstacles: from_fn(|_| EncryptedCoord::from_cts(&mut cts, evaluator)),
-            random_state: from_fn(|_| evaluator.wrap(cts.next().unwrap())),
+            random_state: EncU8::from_cts(&mut cts, evaluator),
             precomputed_ids: from_fn(|id| pk_encrypt(evaluator, id as _)),
+            monster_dead_ticks: from_fn(|_| EncU8::from_cts(&mut cts, evaluator)),
         }
     }
 
-    pub fn cts_diff(&self, flags: [bool; 4]) -> ZoneDiff {
+    pub fn cts_diff(&self, flags: &[bool]) -> ZoneDiff {
         (
-            from_fn(|id| flags[id].then(|| self.players[id].data.cts().cloned().collect())),
+            izip!(&self.players, flags)
+                .map(|(player, flag)| flag.then(|| player.data.cts().cloned().collect()))
+                .collect(),
             self.items
                 .iter()
                 .flat_map(|item| item.data.cts())
@@ -1192,16 +1537,17 @@ impl Zone {
             id,
             data: MonsterEncryptedData::from_cts(&mut monsters, evaluator),
         });
-        let mut random_state = random_state.into_iter();
-        self.random_state = from_fn(|_| evaluator.wrap(random_state.next().unwrap()));
+        self.random_state = EncU8::from_cts(&mut random_state.into_iter(), evaluator);
     }
 }
 
 /// Diff of `players` and concatenation of `items` bits and cnocat of `mosnters` bits after some
 /// `Zone::move_player`, used to sync with workers. If player is not updated
-/// during the time, `players[id]` will be `None`.
+/// during the time, `players[id]` will be `None`. Since PvP, a move updates
+/// every player. Items carry their respawn counter, so the monster loop ticking
+/// it also produces a diff.
 pub type ZoneDiff = (
-    [Option<Vec<PhantomCt>>; 4],
+    Vec<Option<Vec<PhantomCt>>>,
     Vec<PhantomCt>,
     Vec<PhantomCt>,
     Vec<PhantomCt>,
@@ -0,0 +1,751 @@
+//! Differential tests between the C++ circuits (compiled natively with `g++`
+//! through `circuits_cpp/src/diff_main.cc`) and their plaintext mirror in
+//! [`server::mock_zone`].
+
+use phantom_benchs::capacity::{self, NUM_ITEMS, NUM_MONSTERS, NUM_PLAYERS};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use server::client::Direction;
+use server::map::Map;
+use server::mock_zone::{
+    fhe_apply_move, fhe_apply_move_pvp, fhe_maintain_items, fhe_maintain_monsters, fhe_respawn,
+    ItemEncryptedData, MockEncryptedCoord, MonsterEncryptedData, PlayerEncryptedData,
+};
+use server::respawn::{ITEM_RESPAWN_TICKS, MONSTER_RESPAWN_TICKS};
+use std::array::from_fn;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use std::thread;
+
+const WIDTH: u8 = 32;
+const HEIGHT: u8 = 32;
+
+const FILLER_COORD: MockEncryptedCoord = MockEncryptedCoord { x: 255, y: 255 };
+
+/// Compiles the C++ driver once, returns `None` if `g++` is not available.
+fn driver() -> Option<&'static Path> {
+    static DRIVER: OnceLock<Option<PathBuf>> = OnceLock::new();
+    DRIVER
+        .get_or_init(|| {
+            let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("../circuits_cpp/src");
+            let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("diff_main");
+            let status = Command::new("g++")
+                .current_dir(&src)
+                .args(["-std=c++17", "-w", "-O1", "-o"])
+                .arg(&out)
+                .arg("diff_main.cc")
+                .status();
+            match status {
+                Ok(status) if status.success() => Some(out),
+                Ok(status) => panic!("failed to compile diff_main.cc: {status}"),
+                Err(err) => {
+                    eprintln!("skipping differential tests, g++ is not available: {err}");
+                    None
+                }
+            }
+        })
+        .as_deref()
+}
+
+/// Runs the driver in `mode` with one case per line, returns one output per case.
+fn run_driver(driver: &Path, mode: &str, cases: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut child = Command::new(driver)
+        .arg(mode)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Write from another thread, so the driver never blocks on a full stdout.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = cases
+        .iter()
+        .map(|case| {
+            case.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+                + "\n"
+        })
+        .collect::<String>();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()).unwrap());
+    let output = child.wait_with_output().unwrap();
+    writer.join().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| value.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+/// Obstacles compiled into the circuits.
+fn circuit_obstacles(driver: &Path) -> Vec<MockEncryptedCoord> {
+    let output = Command::new(driver).arg("obstacles").output().unwrap();
+    let values = String::from_utf8(output.stdout)
+        .unwrap()
+        .split_whitespace()
+        .map(|value| value.parse().unwrap())
+        .collect::<Vec<u8>>();
+    values
+        .chunks_exact(2)
+        .map(|coord| MockEncryptedCoord {
+            x: coord[0],
+            y: coord[1],
+        })
+        .collect()
+}
+
+/// Obstacles in the layout [`fhe_apply_move`] expects, the circuit obstacles
+/// padded with filler followed by the players' coordinates.
+fn mock_obstacles<const N: usize>(
+    obstacles: &[MockEncryptedCoord],
+    players_coords: &[MockEncryptedCoord; NUM_PLAYERS],
+) -> [MockEncryptedCoord; N] {
+    from_fn(|i| {
+        if i >= N - NUM_PLAYERS {
+            players_coords[i - (N - NUM_PLAYERS)]
+        } else {
+            obstacles.get(i).copied().unwrap_or(FILLER_COORD)
+        }
+    })
+}
+
+#[test]
+fn capacity_h_is_generated() {
+    assert_eq!(
+        include_str!("../../circuits_cpp/src/capacity.h"),
+        capacity::header(),
+        "regenerate capacity.h with `cargo run --bin capacity_header`"
+    );
+}
+
+#[test]
+fn circuit_obstacles_match_map() {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let obstacles = Map::builtin()
+        .obstacles
+        .iter()
+        .map(|obstacle| MockEncryptedCoord {
+            x: obstacle.x,
+            y: obstacle.y,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        circuit_obstacles(driver),
+        obstacles,
+        "regenerate map.h with `cargo run --bin map_header`"
+    );
+}
+
+#[derive(Clone, Debug)]
+struct ApplyMoveCase {
+    player: PlayerEncryptedData,
+    direction: Direction,
+    players_coords: [MockEncryptedCoord; NUM_PLAYERS],
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    items: [ItemEncryptedData; NUM_ITEMS],
+}
+
+fn direction_to_u8(direction: Direction) -> u8 {
+    match direction {
+        Direction::Up => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 3,
+    }
+}
+
+fn player_values(player: &PlayerEncryptedData) -> [u8; 5] {
+    [
+        player.loc.x,
+        player.loc.y,
+        player.hp,
+        player.atk,
+        player.points,
+    ]
+}
+
+fn monster_values(monster: &MonsterEncryptedData) -> [u8; 5] {
+    [
+        monster.loc.x,
+        monster.loc.y,
+        monster.hp,
+        monster.atk,
+        monster.points,
+    ]
+}
+
+fn item_values(item: &ItemEncryptedData) -> [u8; 7] {
+    [
+        item.loc.x,
+        item.loc.y,
+        item.hp,
+        item.atk,
+        item.is_consumed as u8,
+        item.points,
+        item.respawn_ticks,
+    ]
+}
+
+impl ApplyMoveCase {
+    fn random(rng: &mut impl Rng) -> Self {
+        let player_loc = random_coord(rng);
+        let mut players_coords = from_fn(|_| nearby_coord(rng, player_loc));
+        players_coords[rng.gen_range(0..NUM_PLAYERS)] = player_loc;
+        Self {
+            player: PlayerEncryptedData {
+                loc: player_loc,
+                hp: if rng.gen_bool(0.25) {
+                    0
+                } else {
+                    rng.gen_range(1..=10)
+                },
+                atk: rng.gen_range(0..=10),
+                points: rng.gen_range(0..=10),
+            },
+            direction: [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ][rng.gen_range(0..4)],
+            players_coords,
+            monsters: from_fn(|_| MonsterEncryptedData {
+                loc: nearby_coord(rng, player_loc),
+                hp: rng.gen_range(0..=10),
+                atk: rng.gen_range(0..=10),
+                points: rng.gen_range(0..=10),
+            }),
+            items: from_fn(|_| ItemEncryptedData {
+                loc: nearby_coord(rng, player_loc),
+                hp: rng.gen_range(0..=10),
+                atk: rng.gen_range(0..=10),
+                is_consumed: rng.gen_bool(0.3),
+                points: rng.gen_range(0..=10),
+                respawn_ticks: rng.gen_range(0..=ITEM_RESPAWN_TICKS),
+            }),
+        }
+    }
+
+    fn driver_input(&self) -> Vec<u8> {
+        let mut values = player_values(&self.player).to_vec();
+        values.push(direction_to_u8(self.direction));
+        values.extend(
+            self.players_coords
+                .iter()
+                .flat_map(|coord| [coord.x, coord.y]),
+        );
+        values.extend(self.monsters.iter().flat_map(monster_values));
+        values.extend(self.items.iter().flat_map(item_values));
+        values
+    }
+
+    fn mock_output(&self, obstacles: &[MockEncryptedCoord]) -> Vec<u8> {
+        let (player, items, monsters) = fhe_apply_move(
+            self.player,
+            self.direction,
+            HEIGHT,
+            WIDTH,
+            mock_obstacles(obstacles, &self.players_coords),
+            self.monsters,
+            self.items,
+        );
+        apply_move_output(&player, &monsters, &items)
+    }
+}
+
+fn apply_move_output(
+    player: &PlayerEncryptedData,
+    monsters: &[MonsterEncryptedData; NUM_MONSTERS],
+    items: &[ItemEncryptedData; NUM_ITEMS],
+) -> Vec<u8> {
+    let mut values = player_values(player).to_vec();
+    values.extend(monsters.iter().flat_map(monster_values));
+    values.extend(items.iter().flat_map(item_values));
+    values
+}
+
+#[derive(Clone, Debug)]
+struct ApplyMovePvpCase {
+    player: PlayerEncryptedData,
+    direction: Direction,
+    others: [PlayerEncryptedData; NUM_PLAYERS - 1],
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    items: [ItemEncryptedData; NUM_ITEMS],
+}
+
+impl ApplyMovePvpCase {
+    fn random(rng: &mut impl Rng) -> Self {
+        let ApplyMoveCase {
+            player,
+            direction,
+            monsters,
+            items,
+            ..
+        } = ApplyMoveCase::random(rng);
+        // Put the other players next to the player often, so moves attack them.
+        let others = from_fn(|_| PlayerEncryptedData {
+            loc: if rng.gen_bool(0.5) {
+                neighbour(rng, player.loc)
+            } else {
+                random_coord(rng)
+            },
+            hp: if rng.gen_bool(0.25) {
+                0
+            } else {
+                rng.gen_range(1..=10)
+            },
+            atk: rng.gen_range(0..=10),
+            points: rng.gen_range(0..=10),
+        });
+        Self {
+            player,
+            direction,
+            others,
+            monsters,
+            items,
+        }
+    }
+
+    fn driver_input(&self) -> Vec<u8> {
+        let mut values = player_values(&self.player).to_vec();
+        values.push(direction_to_u8(self.direction));
+        values.extend(self.others.iter().flat_map(player_values));
+        values.extend(self.monsters.iter().flat_map(monster_values));
+        values.extend(self.items.iter().flat_map(item_values));
+        values
+    }
+
+    fn mock_output(&self, obstacles: &[MockEncryptedCoord]) -> Vec<u8> {
+        let (player, others, items, monsters) = fhe_apply_move_pvp(
+            self.player,
+            self.direction,
+            HEIGHT,
+            WIDTH,
+            from_fn(|i| obstacles.get(i).copied().unwrap_or(FILLER_COORD)),
+            self.others.to_vec(),
+            self.monsters,
+            self.items,
+        );
+        let mut values = player_values(&player).to_vec();
+        values.extend(others.iter().flat_map(player_values));
+        values.extend(monsters.iter().flat_map(monster_values));
+        values.extend(items.iter().flat_map(item_values));
+        values
+    }
+}
+
+fn random_coord(rng: &mut impl Rng) -> MockEncryptedCoord {
+    MockEncryptedCoord {
+        x: rng.gen_range(0..WIDTH),
+        y: rng.gen_range(0..HEIGHT),
+    }
+}
+
+/// Returns a coordinate next to `center` often, so moves run into entities.
+fn nearby_coord(rng: &mut impl Rng, center: MockEncryptedCoord) -> MockEncryptedCoord {
+    if rng.gen_bool(0.3) {
+        neighbour(rng, center)
+    } else {
+        random_coord(rng)
+    }
+}
+
+fn neighbour(rng: &mut impl Rng, coord: MockEncryptedCoord) -> MockEncryptedCoord {
+    let (dx, dy) = [(0, -1), (0, 1), (-1, 0), (1, 0)][rng.gen_range(0..4)];
+    MockEncryptedCoord {
+        x: coord.x.saturating_add_signed(dx).min(WIDTH - 1),
+        y: coord.y.saturating_add_signed(dy).min(HEIGHT - 1),
+    }
+}
+
+/// Asserts the circuit and the mock agree on every case.
+fn assert_apply_move_agrees(cases: &[ApplyMoveCase]) {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let obstacles = circuit_obstacles(driver);
+    let inputs = cases
+        .iter()
+        .map(ApplyMoveCase::driver_input)
+        .collect::<Vec<_>>();
+    let outputs = run_driver(driver, "apply_move", &inputs);
+    assert_eq!(outputs.len(), cases.len());
+    for (case, output) in cases.iter().zip(outputs) {
+        assert_eq!(case.mock_output(&obstacles), output, "{case:?}");
+    }
+}
+
+#[test]
+fn apply_move_random() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let cases = (0..1000)
+        .map(|_| ApplyMoveCase::random(&mut rng))
+        .collect::<Vec<_>>();
+    assert_apply_move_agrees(&cases);
+}
+
+#[test]
+fn apply_move_dead_player_is_frozen() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let cases = (0..100)
+        .map(|_| {
+            let mut case = ApplyMoveCase::random(&mut rng);
+            case.player.hp = 0;
+            // Put an item and a monster right where the player is heading.
+            let target = MockEncryptedCoord {
+                x: case.player.loc.x,
+                y: case.player.loc.y.saturating_sub(1),
+            };
+            case.direction = Direction::Up;
+            case.items[0] = ItemEncryptedData {
+                loc: target,
+                hp: 3,
+                atk: 3,
+                is_consumed: false,
+                points: 3,
+                respawn_ticks: 0,
+            };
+            case.monsters[0] = MonsterEncryptedData {
+                loc: target,
+                hp: 1,
+                atk: 1,
+                points: 1,
+            };
+            case
+        })
+        .collect::<Vec<_>>();
+
+    let Some(driver) = driver() else {
+        return;
+    };
+    let obstacles = circuit_obstacles(driver);
+    for case in &cases {
+        assert_eq!(
+            case.mock_output(&obstacles),
+            apply_move_output(&case.player, &case.monsters, &case.items),
+            "{case:?}"
+        );
+    }
+    assert_apply_move_agrees(&cases);
+}
+
+#[test]
+fn apply_move_saturates_at_u8_boundary() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let cases = (0..1000)
+        .map(|_| {
+            let mut case = ApplyMoveCase::random(&mut rng);
+            case.player.hp = rng.gen_range(200..=255);
+            case.player.atk = rng.gen_range(200..=255);
+            case.player.points = rng.gen_range(200..=255);
+            for item in &mut case.items {
+                (item.hp, item.atk, item.points) = rng.gen();
+            }
+            for monster in &mut case.monsters {
+                (monster.hp, monster.atk, monster.points) = rng.gen();
+            }
+            case
+        })
+        .collect::<Vec<_>>();
+    assert_apply_move_agrees(&cases);
+}
+
+fn assert_apply_move_pvp_agrees(cases: &[ApplyMovePvpCase]) {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let obstacles = circuit_obstacles(driver);
+    let inputs = cases
+        .iter()
+        .map(ApplyMovePvpCase::driver_input)
+        .collect::<Vec<_>>();
+    let outputs = run_driver(driver, "apply_move_pvp", &inputs);
+    assert_eq!(outputs.len(), cases.len());
+    for (case, output) in cases.iter().zip(outputs) {
+        assert_eq!(case.mock_output(&obstacles), output, "{case:?}");
+    }
+}
+
+#[test]
+fn apply_move_pvp_random() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let cases = (0..1000)
+        .map(|_| ApplyMovePvpCase::random(&mut rng))
+        .collect::<Vec<_>>();
+    assert_apply_move_pvp_agrees(&cases);
+}
+
+#[test]
+fn apply_move_pvp_saturates_at_u8_boundary() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let cases = (0..1000)
+        .map(|_| {
+            let mut case = ApplyMovePvpCase::random(&mut rng);
+            case.player.points = rng.gen_range(200..=255);
+            for other in &mut case.others {
+                (other.hp, other.atk, other.points) = rng.gen();
+            }
+            case
+        })
+        .collect::<Vec<_>>();
+    assert_apply_move_pvp_agrees(&cases);
+}
+
+#[derive(Clone, Debug)]
+struct RespawnCase {
+    player: PlayerEncryptedData,
+    random_state: u8,
+    points_shift: u8,
+    players_coords: [MockEncryptedCoord; NUM_PLAYERS],
+}
+
+impl RespawnCase {
+    fn random(rng: &mut impl Rng) -> Self {
+        let spawn_points = Map::builtin().spawns;
+        let mut players_coords: [_; NUM_PLAYERS] = from_fn(|_| {
+            // Take spawn points often, so respawns have to skip them.
+            if rng.gen_bool(0.7) {
+                let spawn_point = spawn_points[rng.gen_range(0..spawn_points.len())];
+                MockEncryptedCoord {
+                    x: spawn_point.x,
+                    y: spawn_point.y,
+                }
+            } else {
+                random_coord(rng)
+            }
+        });
+        let player_loc = random_coord(rng);
+        players_coords[rng.gen_range(0..NUM_PLAYERS)] = player_loc;
+        Self {
+            player: PlayerEncryptedData {
+                loc: player_loc,
+                hp: if rng.gen_bool(0.75) {
+                    0
+                } else {
+                    rng.gen_range(1..=10)
+                },
+                atk: rng.gen(),
+                points: rng.gen(),
+            },
+            random_state: rng.gen(),
+            points_shift: rng.gen_range(0..=8),
+            players_coords,
+        }
+    }
+
+    fn driver_input(&self) -> Vec<u8> {
+        let mut values = player_values(&self.player).to_vec();
+        values.extend([self.random_state, self.points_shift]);
+        values.extend(
+            self.players_coords
+                .iter()
+                .flat_map(|coord| [coord.x, coord.y]),
+        );
+        values
+    }
+
+    fn mock_output(&self) -> Vec<u8> {
+        let player = fhe_respawn(
+            self.player,
+            self.random_state,
+            self.points_shift,
+            &Map::builtin().spawns,
+            self.players_coords.to_vec(),
+        );
+        player_values(&player).to_vec()
+    }
+}
+
+#[test]
+fn respawn_random() {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let mut rng = StdRng::seed_from_u64(5);
+    let cases = (0..1000)
+        .map(|_| RespawnCase::random(&mut rng))
+        .collect::<Vec<_>>();
+    let inputs = cases
+        .iter()
+        .map(RespawnCase::driver_input)
+        .collect::<Vec<_>>();
+    let outputs = run_driver(driver, "respawn", &inputs);
+    assert_eq!(outputs.len(), cases.len());
+    for (case, output) in cases.iter().zip(outputs) {
+        assert_eq!(case.mock_output(), output, "{case:?}");
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MaintainMonstersCase {
+    monsters: [MonsterEncryptedData; NUM_MONSTERS],
+    dead_ticks: [u8; NUM_MONSTERS],
+    players_coords: [MockEncryptedCoord; NUM_PLAYERS],
+}
+
+impl MaintainMonstersCase {
+    fn random(rng: &mut impl Rng) -> Self {
+        let initial_monsters = Map::builtin().monsters;
+        let monsters: [_; NUM_MONSTERS] = from_fn(|i| {
+            let initial = initial_monsters[i];
+            MonsterEncryptedData {
+                loc: random_coord(rng),
+                hp: match rng.gen_range(0..3) {
+                    0 => 0,
+                    1 => rng.gen_range(0..=initial.hp),
+                    _ => initial.hp,
+                },
+                atk: rng.gen_range(0..=10),
+                points: rng.gen_range(0..=10),
+            }
+        });
+        // Stand on initial locations often, so revivals have to wait.
+        let players_coords = from_fn(|_| {
+            if rng.gen_bool(0.5) {
+                let initial = initial_monsters[rng.gen_range(0..NUM_MONSTERS)];
+                MockEncryptedCoord {
+                    x: initial.x,
+                    y: initial.y,
+                }
+            } else {
+                random_coord(rng)
+            }
+        });
+        Self {
+            monsters,
+            dead_ticks: from_fn(|_| match rng.gen_range(0..3) {
+                0 => rng.gen_range(0..MONSTER_RESPAWN_TICKS),
+                1 => MONSTER_RESPAWN_TICKS - 1,
+                _ => rng.gen(),
+            }),
+            players_coords,
+        }
+    }
+
+    fn driver_input(&self) -> Vec<u8> {
+        let mut values = self
+            .monsters
+            .iter()
+            .flat_map(monster_values)
+            .collect::<Vec<_>>();
+        values.extend(self.dead_ticks);
+        values.extend(
+            self.players_coords
+                .iter()
+                .flat_map(|coord| [coord.x, coord.y]),
+        );
+        values
+    }
+
+    fn mock_output(&self) -> Vec<u8> {
+        let (monsters, dead_ticks) = fhe_maintain_monsters(
+            self.monsters,
+            self.dead_ticks,
+            &Map::builtin().monsters,
+            self.players_coords.to_vec(),
+        );
+        let mut values = monsters.iter().flat_map(monster_values).collect::<Vec<_>>();
+        values.extend(dead_ticks);
+        values
+    }
+}
+
+#[test]
+fn maintain_monsters_random() {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let mut rng = StdRng::seed_from_u64(6);
+    let cases = (0..1000)
+        .map(|_| MaintainMonstersCase::random(&mut rng))
+        .collect::<Vec<_>>();
+    let inputs = cases
+        .iter()
+        .map(MaintainMonstersCase::driver_input)
+        .collect::<Vec<_>>();
+    let outputs = run_driver(driver, "maintain_monsters", &inputs);
+    assert_eq!(outputs.len(), cases.len());
+    for (case, output) in cases.iter().zip(outputs) {
+        assert_eq!(case.mock_output(), output, "{case:?}");
+    }
+}
+
+#[derive(Debug)]
+struct MaintainItemsCase {
+    items: [ItemEncryptedData; NUM_ITEMS],
+    players_coords: [MockEncryptedCoord; NUM_PLAYERS],
+}
+
+impl MaintainItemsCase {
+    fn random(rng: &mut impl Rng) -> Self {
+        let items: [_; NUM_ITEMS] = from_fn(|_| ItemEncryptedData {
+            loc: random_coord(rng),
+            hp: rng.gen_range(0..=10),
+            atk: rng.gen_range(0..=10),
+            is_consumed: rng.gen_bool(0.7),
+            points: rng.gen_range(0..=10),
+            respawn_ticks: match rng.gen_range(0..3) {
+                0 => 0,
+                1 => 1,
+                _ => rng.gen(),
+            },
+        });
+        // Stand on items often, so respawns have to wait.
+        let players_coords = from_fn(|_| {
+            if rng.gen_bool(0.5) {
+                items[rng.gen_range(0..NUM_ITEMS)].loc
+            } else {
+                random_coord(rng)
+            }
+        });
+        Self {
+            items,
+            players_coords,
+        }
+    }
+
+    fn driver_input(&self) -> Vec<u8> {
+        let mut values = self.items.iter().flat_map(item_values).collect::<Vec<_>>();
+        values.extend(
+            self.players_coords
+                .iter()
+                .flat_map(|coord| [coord.x, coord.y]),
+        );
+        values
+    }
+
+    fn mock_output(&self) -> Vec<u8> {
+        fhe_maintain_items(self.items, self.players_coords.to_vec())
+            .iter()
+            .flat_map(item_values)
+            .collect()
+    }
+}
+
+#[test]
+fn maintain_items_random() {
+    let Some(driver) = driver() else {
+        return;
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+    let cases = (0..1000)
+        .map(|_| MaintainItemsCase::random(&mut rng))
+        .collect::<Vec<_>>();
+    let inputs = cases
+        .iter()
+        .map(MaintainItemsCase::driver_input)
+        .collect::<Vec<_>>();
+    let outputs = run_driver(driver, "maintain_items", &inputs);
+    assert_eq!(outputs.len(), cases.len());
+    for (case, output) in cases.iter().zip(outputs) {
+        assert_eq!(case.mock_output(), output, "{case:?}");
+    }
+}
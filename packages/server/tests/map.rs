@@ -0,0 +1,76 @@
+//! Tests of the [`Map`] loader.
+
+use serde_json::{json, Value};
+use server::map::Map;
+use std::env;
+use std::fs;
+
+const DEFAULT_MAP: &str = include_str!("../maps/default.json");
+
+fn default_map() -> Value {
+    serde_json::from_str(DEFAULT_MAP).unwrap()
+}
+
+/// Writes `map` to a temporary file and loads it.
+fn load(name: &str, map: &Value) -> Result<Map, String> {
+    let path = env::temp_dir().join(format!("frogzone-map-{}-{name}.json", std::process::id()));
+    fs::write(&path, map.to_string()).unwrap();
+    let result = Map::load(&path);
+    fs::remove_file(&path).unwrap();
+    result
+}
+
+#[test]
+fn default_map_loads() {
+    let map = load("default", &default_map()).unwrap();
+    assert_eq!(map, Map::builtin());
+    assert_eq!(map.items[11].id, 11);
+}
+
+#[test]
+fn too_many_items_are_rejected() {
+    let mut map = default_map();
+    let items = map["items"].as_array_mut().unwrap();
+    items.push(items[0].clone());
+
+    let err = Map::from_json(&map.to_string()).unwrap_err();
+    assert!(err.contains("at most 12 items"), "{err}");
+}
+
+#[test]
+fn missing_monsters_are_rejected() {
+    let mut map = default_map();
+    map["monsters"].as_array_mut().unwrap().pop();
+
+    let err = Map::from_json(&map.to_string()).unwrap_err();
+    assert!(err.contains("expected 23 monsters"), "{err}");
+}
+
+#[test]
+fn coords_outside_the_map_are_rejected() {
+    let mut map = default_map();
+    map["items"][0]["x"] = json!(32);
+
+    let err = Map::from_json(&map.to_string()).unwrap_err();
+    assert!(err.contains("item at (32, 3)"), "{err}");
+}
+
+#[test]
+fn items_can_differ_from_the_circuits() {
+    let mut map = default_map();
+    map["items"][0] = json!({"x": 1, "y": 3, "hp": 1, "atk": 1, "points": 9});
+    map["items"].as_array_mut().unwrap().pop();
+
+    let map = load("items", &map).unwrap();
+    assert_eq!(map.items.len(), 11);
+    assert_eq!((map.items[0].x, map.items[0].points), (1, 9));
+}
+
+#[test]
+fn obstacles_must_match_the_circuits() {
+    let mut map = default_map();
+    map["obstacles"].as_array_mut().unwrap().pop();
+
+    let err = load("obstacles", &map).unwrap_err();
+    assert!(err.contains("obstacles differ"), "{err}");
+}
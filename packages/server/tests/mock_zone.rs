@@ -0,0 +1,259 @@
+//! Tests of the plaintext [`MockZone`].
+
+use server::client::Direction;
+use server::map::Map;
+use server::mock_zone::{MockEncryptedCoord, MockZone, PlayerEncryptedData};
+use server::respawn::{PointsForfeit, ITEM_RESPAWN_TICKS, MONSTER_RESPAWN_TICKS, RESPAWN_HP};
+
+const FILLER_COORD: MockEncryptedCoord = MockEncryptedCoord { x: 255, y: 255 };
+const PLAYER_LOC: MockEncryptedCoord = MockEncryptedCoord { x: 3, y: 27 };
+const ABOVE_PLAYER: MockEncryptedCoord = MockEncryptedCoord { x: 3, y: 26 };
+
+/// A zone with player 0 at [`PLAYER_LOC`], no obstacles, and every item consumed
+/// and every monster dead, so tests only place the entities they need.
+fn empty_zone(player: PlayerEncryptedData) -> MockZone {
+    let mut zone = MockZone::new(&Map::builtin());
+    zone.obstacles.fill(FILLER_COORD);
+    for item in &mut zone.items {
+        item.data.is_consumed = true;
+    }
+    for monster in &mut zone.monsters {
+        monster.data.hp = 0;
+    }
+    zone.players[0].data = player;
+    zone
+}
+
+fn player(hp: u8, atk: u8, points: u8) -> PlayerEncryptedData {
+    PlayerEncryptedData {
+        loc: PLAYER_LOC,
+        hp,
+        atk,
+        points,
+    }
+}
+
+#[test]
+fn item_stats_saturate() {
+    let mut zone = empty_zone(player(250, 254, 255));
+    let item = &mut zone.items[0].data;
+    item.loc = ABOVE_PLAYER;
+    item.is_consumed = false;
+    (item.hp, item.atk, item.points) = (10, 1, 255);
+
+    assert_eq!(zone.move_player(0, Direction::Up), ABOVE_PLAYER);
+    let player = zone.get_player(0);
+    assert_eq!((player.hp, player.atk, player.points), (255, 255, 255));
+    assert!(zone.items[0].data.is_consumed);
+}
+
+#[test]
+fn monster_rewards_saturate() {
+    let mut zone = empty_zone(player(100, 250, 200));
+    let monster = &mut zone.monsters[0].data;
+    monster.loc = ABOVE_PLAYER;
+    (monster.hp, monster.atk, monster.points) = (1, 10, 100);
+
+    assert_eq!(zone.move_player(0, Direction::Up), PLAYER_LOC);
+    let player = zone.get_player(0);
+    assert_eq!((player.hp, player.atk, player.points), (90, 255, 255));
+    assert_eq!(zone.monsters[0].data.hp, 0);
+}
+
+#[test]
+fn stats_reaching_boundary_are_exact() {
+    let mut zone = empty_zone(player(245, 0, 254));
+    let item = &mut zone.items[0].data;
+    item.loc = ABOVE_PLAYER;
+    item.is_consumed = false;
+    (item.hp, item.atk, item.points) = (10, 255, 1);
+
+    zone.move_player(0, Direction::Up);
+    let player = zone.get_player(0);
+    assert_eq!((player.hp, player.atk, player.points), (255, 255, 255));
+}
+
+#[test]
+fn attacking_player_subtracts_atk_from_hp() {
+    let mut zone = empty_zone(player(5, 3, 0));
+    zone.players[1].data = PlayerEncryptedData {
+        loc: ABOVE_PLAYER,
+        ..player(10, 2, 4)
+    };
+
+    assert_eq!(zone.move_player(0, Direction::Up), PLAYER_LOC);
+    let attacker = zone.get_player(0);
+    let defender = zone.get_player(1);
+    assert_eq!((attacker.hp, attacker.atk, attacker.points), (5, 3, 0));
+    assert_eq!((defender.hp, defender.atk, defender.points), (7, 2, 4));
+    assert_eq!(defender.loc, ABOVE_PLAYER);
+}
+
+#[test]
+fn killing_player_transfers_points() {
+    let mut zone = empty_zone(player(5, 3, 250));
+    zone.players[2].data = PlayerEncryptedData {
+        loc: ABOVE_PLAYER,
+        ..player(3, 2, 10)
+    };
+
+    assert_eq!(zone.move_player(0, Direction::Up), PLAYER_LOC);
+    let attacker = zone.get_player(0);
+    let defender = zone.get_player(2);
+    assert_eq!((attacker.hp, attacker.points), (5, 255));
+    assert_eq!((defender.hp, defender.points), (0, 0));
+}
+
+#[test]
+fn dead_player_blocks_without_being_attacked() {
+    let mut zone = empty_zone(player(5, 3, 0));
+    zone.players[3].data = PlayerEncryptedData {
+        loc: ABOVE_PLAYER,
+        ..player(0, 2, 4)
+    };
+
+    assert_eq!(zone.move_player(0, Direction::Up), PLAYER_LOC);
+    let attacker = zone.get_player(0);
+    let defender = zone.get_player(3);
+    assert_eq!(attacker.points, 0);
+    assert_eq!((defender.hp, defender.points), (0, 4));
+
+    // A dead player can't attack either.
+    assert_eq!(zone.move_player(3, Direction::Down), ABOVE_PLAYER);
+    assert_eq!(zone.get_player(0).hp, 5);
+}
+
+#[test]
+fn dead_player_respawns_at_free_spawn_point() {
+    let mut zone = empty_zone(player(0, 4, 9));
+    // Spawn points 2 and 3 are where players 2 and 3 start, so they are skipped.
+    zone.random_state = 2;
+
+    let new_loc = zone.respawn_player(0, PointsForfeit::Half.shift());
+    assert_eq!(new_loc, MockEncryptedCoord { x: 7, y: 28 });
+    let player = zone.get_player(0);
+    assert_eq!((player.hp, player.atk, player.points), (RESPAWN_HP, 4, 4));
+}
+
+#[test]
+fn alive_player_does_not_respawn() {
+    let mut zone = empty_zone(player(1, 4, 9));
+
+    assert_eq!(
+        zone.respawn_player(0, PointsForfeit::All.shift()),
+        PLAYER_LOC
+    );
+    let player = zone.get_player(0);
+    assert_eq!((player.hp, player.atk, player.points), (1, 4, 9));
+}
+
+#[test]
+fn points_forfeit_rules() {
+    for (forfeit, points) in [
+        (PointsForfeit::None, 9),
+        (PointsForfeit::Half, 4),
+        (PointsForfeit::All, 0),
+    ] {
+        let mut zone = empty_zone(player(0, 1, 9));
+        zone.respawn_player(0, forfeit.shift());
+        assert_eq!(zone.get_player(0).points, points, "{forfeit:?}");
+    }
+}
+
+#[test]
+fn dead_monster_revives_after_ticks() {
+    let mut zone = MockZone::new(&Map::builtin());
+    let initial = zone.monsters[1].data;
+    zone.monsters[1].data.hp = 0;
+    zone.monsters[1].data.loc = ABOVE_PLAYER;
+
+    for _ in 1..MONSTER_RESPAWN_TICKS {
+        zone.maintain_monsters();
+        assert_eq!(zone.monsters[1].data.hp, 0);
+    }
+    zone.maintain_monsters();
+    let monster = zone.monsters[1].data;
+    assert_eq!(monster.loc, initial.loc);
+    assert_eq!(
+        (monster.hp, monster.atk, monster.points),
+        (initial.hp, initial.atk, initial.points)
+    );
+    assert_eq!(zone.monster_dead_ticks[1], 0);
+}
+
+#[test]
+fn dead_monster_waits_for_player_to_leave() {
+    let mut zone = MockZone::new(&Map::builtin());
+    let initial_loc = zone.monsters[1].data.loc;
+    zone.monsters[1].data.hp = 0;
+    zone.monster_dead_ticks[1] = MONSTER_RESPAWN_TICKS;
+    zone.players[0].data.loc = initial_loc;
+
+    zone.maintain_monsters();
+    assert_eq!(zone.monsters[1].data.hp, 0);
+
+    zone.players[0].data.loc = PLAYER_LOC;
+    zone.maintain_monsters();
+    assert_ne!(zone.monsters[1].data.hp, 0);
+}
+
+#[test]
+fn wounded_monster_regenerates_up_to_initial_hp() {
+    let mut zone = MockZone::new(&Map::builtin());
+    let initial_hp = zone.monsters[0].data.hp;
+    zone.monsters[0].data.hp = initial_hp - 2;
+
+    zone.maintain_monsters();
+    assert_eq!(zone.monsters[0].data.hp, initial_hp - 1);
+    zone.maintain_monsters();
+    zone.maintain_monsters();
+    assert_eq!(zone.monsters[0].data.hp, initial_hp);
+}
+
+#[test]
+fn consumed_item_respawns_after_ticks() {
+    let mut zone = empty_zone(player(5, 1, 0));
+    let item = &mut zone.items[0].data;
+    item.loc = ABOVE_PLAYER;
+    item.is_consumed = false;
+
+    zone.move_player(0, Direction::Up);
+    assert!(zone.items[0].data.is_consumed);
+    assert_eq!(zone.items[0].data.respawn_ticks, ITEM_RESPAWN_TICKS);
+
+    zone.move_player(0, Direction::Down);
+    for _ in 1..ITEM_RESPAWN_TICKS {
+        zone.maintain_items();
+        assert!(zone.items[0].data.is_consumed);
+    }
+    zone.maintain_items();
+    assert!(!zone.items[0].data.is_consumed);
+    assert_eq!(zone.items[0].data.respawn_ticks, 0);
+}
+
+#[test]
+fn consumed_item_waits_for_player_to_leave() {
+    let mut zone = empty_zone(player(5, 1, 0));
+    zone.items[0].data.loc = PLAYER_LOC;
+    zone.items[0].data.respawn_ticks = 1;
+
+    zone.maintain_items();
+    assert!(zone.items[0].data.is_consumed);
+    assert_eq!(zone.items[0].data.respawn_ticks, 0);
+
+    zone.move_player(0, Direction::Up);
+    zone.maintain_items();
+    assert!(!zone.items[0].data.is_consumed);
+}
+
+#[test]
+fn filler_items_are_outside_the_map() {
+    let mut map = Map::builtin();
+    map.items.truncate(1);
+    let zone = MockZone::new(&map);
+
+    for item in &zone.items[1..] {
+        assert_eq!(item.data.loc, FILLER_COORD);
+        assert!(item.data.is_consumed);
+    }
+}
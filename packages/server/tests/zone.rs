@@ -0,0 +1,500 @@
+//! End-to-end tests of the [`Zone`], through the generated circuits, against
+//! the plaintext [`MockZone`].
+//!
+//! They need the cleartext backend of `phantom` to run in seconds, so they
+//! only build with `cargo test --features mock`.
+
+#![cfg(feature = "mock")]
+
+use itertools::{chain, Itertools};
+use phantom::{PhantomBool, PhantomEvaluator, PhantomUser};
+use phantom_benchs::capacity::NUM_OBSTACLES;
+use phantom_benchs::frogzone_apply_move_rs_fhe_lib::apply_move;
+use server::client::{Direction, EntityType};
+use server::map::{Map, PlaintextSpawnPoint, INITIAL_PLAYER_HP};
+use server::mock_zone::{self, MockZone};
+use server::respawn::{ITEM_RESPAWN_TICKS, MONSTER_RESPAWN_TICKS, RESPAWN_HP};
+use server::zone::{self, EncryptedCoord, EncryptedDirection, Zone, PHANTOM_PARAM};
+
+/// Evaluator and users after key generation.
+fn keygen() -> (PhantomEvaluator, Vec<PhantomUser>) {
+    let mut evaluator = PhantomEvaluator::new(*PHANTOM_PARAM);
+    let mut users: Vec<PhantomUser> = (0..PHANTOM_PARAM.total_shares)
+        .map(|user_id| PhantomUser::new(*PHANTOM_PARAM, user_id, vec![user_id as u8; 32]))
+        .collect();
+
+    let round_1_keys: Vec<_> = users.iter().map(PhantomUser::round_1_key_gen).collect();
+    evaluator.aggregate_round_1_keys(&round_1_keys).unwrap();
+    let pk = evaluator.pk().cloned().unwrap();
+    users.iter_mut().for_each(|user| user.set_pk(pk.clone()));
+    let round_2_keys: Vec<_> = users.iter().map(PhantomUser::round_2_key_gen).collect();
+    evaluator.aggregate_round_2_keys(&round_2_keys).unwrap();
+
+    (evaluator, users)
+}
+
+/// Packs bits and decrypts them with the shares of every user.
+fn decrypt_bits<'a>(
+    evaluator: &PhantomEvaluator,
+    users: &[PhantomUser],
+    bits: impl IntoIterator<Item = &'a PhantomBool>,
+) -> Vec<bool> {
+    let ct_packed = evaluator.pack(bits);
+    let dec_shares = users
+        .iter()
+        .map(|user| user.decrypt_share(&ct_packed).unwrap())
+        .collect();
+    evaluator
+        .aggregate_dec_shares(&ct_packed, dec_shares)
+        .unwrap()
+}
+
+/// Like [`decrypt_bits`], into little-endian bytes.
+fn decrypt<'a>(
+    evaluator: &PhantomEvaluator,
+    users: &[PhantomUser],
+    bits: impl IntoIterator<Item = &'a PhantomBool>,
+) -> Vec<u8> {
+    decrypt_bits(evaluator, users, bits)
+        .chunks(8)
+        .map(|byte| (0..8).fold(0, |value, i| value | ((byte[i] as u8) << i)))
+        .collect()
+}
+
+/// Little-endian bits of `bytes`.
+fn byte_bits(bytes: impl IntoIterator<Item = u8>) -> Vec<bool> {
+    bytes
+        .into_iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+/// Zone and mock zone on `map`, after key generation.
+fn zones(map: &Map) -> (PhantomEvaluator, Vec<PhantomUser>, Zone, MockZone) {
+    let (evaluator, users) = keygen();
+    let zone = Zone::new(map, &evaluator);
+    let mut mock_zone = MockZone::new(map);
+    // Like the zone, respawn at the spawn points compiled into the circuit,
+    // picked by a random state starting at 0.
+    mock_zone.spawns = Map::builtin().spawns;
+    mock_zone.random_state = 0;
+    (evaluator, users, zone, mock_zone)
+}
+
+/// The builtin map, with player 1 starting right next to player 0.
+fn neighbours_map() -> Map {
+    let mut map = Map::builtin();
+    map.spawns[1] = PlaintextSpawnPoint {
+        x: map.spawns[0].x + 1,
+        y: map.spawns[0].y,
+    };
+    map
+}
+
+fn encrypt_direction(evaluator: &PhantomEvaluator, direction: Direction) -> EncryptedDirection {
+    let bits = match direction {
+        Direction::Up => [false, false],
+        Direction::Down => [true, false],
+        Direction::Left => [false, true],
+        Direction::Right => [true, true],
+    };
+    let cts = evaluator
+        .unbatch(&evaluator.batched_pk_encrypt(bits))
+        .unwrap();
+    cts.try_into().unwrap()
+}
+
+fn player_bytes(player: &mock_zone::PlayerEncryptedData) -> Vec<u8> {
+    vec![
+        player.loc.x,
+        player.loc.y,
+        player.hp,
+        player.atk,
+        player.points,
+    ]
+}
+
+fn assert_players_eq(
+    evaluator: &PhantomEvaluator,
+    users: &[PhantomUser],
+    zone: &Zone,
+    mock_zone: &MockZone,
+) {
+    for player_id in 0..PHANTOM_PARAM.total_shares {
+        let player: zone::PlayerEncryptedData = zone.get_player(player_id);
+        assert_eq!(
+            decrypt(evaluator, users, player.bits()),
+            player_bytes(&mock_zone.get_player(player_id)),
+            "player {player_id}"
+        );
+    }
+}
+
+fn monster_bytes(monster: &mock_zone::MonsterEncryptedData) -> Vec<u8> {
+    vec![
+        monster.loc.x,
+        monster.loc.y,
+        monster.hp,
+        monster.atk,
+        monster.points,
+    ]
+}
+
+fn assert_monsters_eq(
+    evaluator: &PhantomEvaluator,
+    users: &[PhantomUser],
+    zone: &Zone,
+    mock_zone: &MockZone,
+) {
+    for (i, (monster, mock_monster)) in zone.monsters.iter().zip(&mock_zone.monsters).enumerate() {
+        assert_eq!(
+            decrypt(evaluator, users, monster.data.bits()),
+            monster_bytes(&mock_monster.data),
+            "monster {i}"
+        );
+    }
+    assert_eq!(
+        decrypt(
+            evaluator,
+            users,
+            zone.monster_dead_ticks
+                .iter()
+                .flat_map(|ticks| ticks.iter())
+        ),
+        mock_zone.monster_dead_ticks,
+    );
+}
+
+fn encrypt_coord(evaluator: &PhantomEvaluator, x: u8, y: u8) -> EncryptedCoord {
+    let bits = evaluator
+        .unbatch(&evaluator.batched_pk_encrypt(byte_bits([x, y])))
+        .unwrap();
+    EncryptedCoord::from_bits(&mut bits.into_iter())
+}
+
+fn player_bits(player: &mock_zone::PlayerEncryptedData) -> Vec<bool> {
+    byte_bits(player_bytes(player))
+}
+
+fn monster_bits(monster: &mock_zone::MonsterEncryptedData) -> Vec<bool> {
+    byte_bits(monster_bytes(monster))
+}
+
+fn item_bits(item: &mock_zone::ItemEncryptedData) -> Vec<bool> {
+    let mut bits = byte_bits([item.loc.x, item.loc.y, item.hp, item.atk]);
+    bits.push(item.is_consumed);
+    bits.extend(byte_bits([item.points, item.respawn_ticks]));
+    bits
+}
+
+fn assert_items_eq(
+    evaluator: &PhantomEvaluator,
+    users: &[PhantomUser],
+    zone: &Zone,
+    mock_zone: &MockZone,
+) {
+    for (i, (item, mock_item)) in zone.items.iter().zip(&mock_zone.items).enumerate() {
+        assert_eq!(
+            decrypt_bits(evaluator, users, item.data.bits()),
+            item_bits(&mock_item.data),
+            "item {i}"
+        );
+    }
+}
+
+/// Bits of cells as the circuits output them, whose entity types are
+/// numbered like `EntityType` in `frogzone.h`.
+fn cells_bits<'a>(cells: impl IntoIterator<Item = &'a mock_zone::CellEncryptedData>) -> Vec<bool> {
+    cells
+        .into_iter()
+        .flat_map(|cell| {
+            let entity_type: u8 = match cell.entity_type {
+                EntityType::Invalid => 0,
+                EntityType::Player => 1,
+                EntityType::Item => 2,
+                EntityType::Monster => 3,
+                EntityType::None => 4,
+            };
+            let bits = byte_bits([entity_type, cell.entity_id, cell.hp, cell.atk, cell.points]);
+            chain![&bits[..3], &bits[8..]].copied().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn new_zone() {
+    let (evaluator, users) = keygen();
+    let map = Map::builtin();
+    let zone = Zone::new(&map, &evaluator);
+    let mock_zone = MockZone::new(&map);
+
+    assert_players_eq(&evaluator, &users, &zone, &mock_zone);
+}
+
+#[test]
+fn move_player() {
+    let (evaluator, users) = keygen();
+    let map = Map::builtin();
+    let mut zone = Zone::new(&map, &evaluator);
+    let mut mock_zone = MockZone::new(&map);
+
+    for direction in [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ] {
+        let coord = zone.move_player(0, encrypt_direction(&evaluator, direction));
+        let mock_coord = mock_zone.move_player(0, direction);
+        assert_eq!(
+            decrypt(&evaluator, &users, coord.bits()),
+            [mock_coord.x, mock_coord.y],
+            "{direction:?}"
+        );
+        assert_players_eq(&evaluator, &users, &zone, &mock_zone);
+    }
+}
+
+#[test]
+fn move_player_attacks_other_player() {
+    let (evaluator, users, mut zone, mut mock_zone) = zones(&neighbours_map());
+
+    // Player 1 dies on the last attack, and still blocks the way afterwards.
+    for _ in 0..=INITIAL_PLAYER_HP {
+        let coord = zone.move_player(0, encrypt_direction(&evaluator, Direction::Right));
+        let mock_coord = mock_zone.move_player(0, Direction::Right);
+        assert_eq!(
+            decrypt(&evaluator, &users, coord.bits()),
+            [mock_coord.x, mock_coord.y]
+        );
+        assert_players_eq(&evaluator, &users, &zone, &mock_zone);
+    }
+    assert_eq!(mock_zone.get_player(1).hp, 0);
+}
+
+#[test]
+fn respawn_player() {
+    let (evaluator, users, mut zone, mut mock_zone) = zones(&neighbours_map());
+    for _ in 0..INITIAL_PLAYER_HP {
+        zone.move_player(0, encrypt_direction(&evaluator, Direction::Right));
+        mock_zone.move_player(0, Direction::Right);
+    }
+
+    // A live frog stays put, a dead one skips the spawn point player 0 took.
+    for player_id in [0, 1] {
+        let coord = zone.respawn_player(player_id, 1, &evaluator);
+        let mock_coord = mock_zone.respawn_player(player_id, 1);
+        assert_eq!(
+            decrypt(&evaluator, &users, coord.bits()),
+            [mock_coord.x, mock_coord.y],
+            "player {player_id}"
+        );
+        assert_players_eq(&evaluator, &users, &zone, &mock_zone);
+    }
+    assert_eq!(mock_zone.get_player(1).hp, RESPAWN_HP);
+}
+
+#[test]
+fn maintain_monsters() {
+    // Player 0 starts next to the weak monster 1, at (0, 13).
+    let mut map = Map::builtin();
+    map.spawns[0] = PlaintextSpawnPoint { x: 1, y: 13 };
+    let (evaluator, users, mut zone, mut mock_zone) = zones(&map);
+    zone.move_player(0, encrypt_direction(&evaluator, Direction::Left));
+    mock_zone.move_player(0, Direction::Left);
+    assert_eq!(mock_zone.monsters[1].data.hp, 0);
+
+    // It revives on the last tick.
+    for tick in 0..MONSTER_RESPAWN_TICKS {
+        zone.maintain_monsters();
+        mock_zone.maintain_monsters();
+        assert_monsters_eq(&evaluator, &users, &zone, &mock_zone);
+        assert_eq!(
+            mock_zone.monsters[1].data.hp == 0,
+            tick + 1 < MONSTER_RESPAWN_TICKS
+        );
+    }
+}
+
+#[test]
+fn maintain_items() {
+    // Player 0 starts next to item 0, at (7, 3).
+    let mut map = Map::builtin();
+    map.spawns[0] = PlaintextSpawnPoint { x: 8, y: 3 };
+    let (evaluator, users, mut zone, mut mock_zone) = zones(&map);
+    zone.move_player(0, encrypt_direction(&evaluator, Direction::Left));
+    mock_zone.move_player(0, Direction::Left);
+    assert!(mock_zone.items[0].data.is_consumed);
+
+    // It stays consumed while the player stands on it, and respawns on the
+    // first tick after the player leaves.
+    for _ in 0..ITEM_RESPAWN_TICKS {
+        zone.maintain_items();
+        mock_zone.maintain_items();
+        assert_items_eq(&evaluator, &users, &zone, &mock_zone);
+    }
+    assert!(mock_zone.items[0].data.is_consumed);
+    zone.move_player(0, encrypt_direction(&evaluator, Direction::Right));
+    mock_zone.move_player(0, Direction::Right);
+    zone.maintain_items();
+    mock_zone.maintain_items();
+    assert_items_eq(&evaluator, &users, &zone, &mock_zone);
+    assert!(!mock_zone.items[0].data.is_consumed);
+}
+
+#[test]
+fn get_cells() {
+    // Player 0 stands between item 8 at (25, 16) and monster 5 at (25, 15),
+    // next to player 1.
+    let mut map = Map::builtin();
+    map.spawns[0] = PlaintextSpawnPoint { x: 24, y: 16 };
+    map.spawns[1] = PlaintextSpawnPoint { x: 23, y: 15 };
+    let (evaluator, users, zone, mock_zone) = zones(&map);
+
+    // Every cell in sight, and one beyond.
+    let coords = chain![
+        (22..=26).flat_map(|x| (14..=18).map(move |y| (x, y))),
+        [(27, 16)]
+    ]
+    .collect_vec();
+    let cells = zone.get_cells(
+        0,
+        coords
+            .iter()
+            .map(|&(x, y)| encrypt_coord(&evaluator, x, y))
+            .collect(),
+    );
+    let mock_cells = mock_zone.get_cells(
+        0,
+        coords
+            .iter()
+            .map(|&(x, y)| mock_zone::MockEncryptedCoord { x, y })
+            .collect(),
+    );
+    assert_eq!(
+        decrypt_bits(
+            &evaluator,
+            &users,
+            cells.iter().flat_map(|cell| cell.bits())
+        ),
+        cells_bits(&mock_cells)
+    );
+
+    let coords = [(25, 16), (25, 15), (23, 15), (24, 18), (24, 19)];
+    let cells = zone.get_five_cells(0, coords.map(|(x, y)| encrypt_coord(&evaluator, x, y)));
+    let mock_cells = mock_zone.get_five_cells(
+        0,
+        coords.map(|(x, y)| mock_zone::MockEncryptedCoord { x, y }),
+    );
+    assert_eq!(
+        decrypt_bits(
+            &evaluator,
+            &users,
+            cells.iter().flat_map(|cell| cell.bits())
+        ),
+        cells_bits(&mock_cells)
+    );
+
+    let cells = zone.get_cross_cells(0);
+    let mock_cells = mock_zone.get_cross_cells(0);
+    assert_eq!(
+        decrypt_bits(
+            &evaluator,
+            &users,
+            cells.iter().flat_map(|cell| cell.bits())
+        ),
+        cells_bits(&mock_cells)
+    );
+
+    // Lines through the player, and centers out of line or out of sight.
+    for (x, y) in [(24, 16), (27, 16), (24, 19)] {
+        let cells = zone.get_vertical_cells(0, encrypt_coord(&evaluator, x, y));
+        let mock_cells = mock_zone.get_vertical_cells(0, mock_zone::MockEncryptedCoord { x, y });
+        assert_eq!(
+            decrypt_bits(
+                &evaluator,
+                &users,
+                cells.iter().flat_map(|cell| cell.bits())
+            ),
+            cells_bits(&mock_cells),
+            "vertical ({x}, {y})"
+        );
+        let cells = zone.get_horizontal_cells(0, encrypt_coord(&evaluator, x, y));
+        let mock_cells = mock_zone.get_horizontal_cells(0, mock_zone::MockEncryptedCoord { x, y });
+        assert_eq!(
+            decrypt_bits(
+                &evaluator,
+                &users,
+                cells.iter().flat_map(|cell| cell.bits())
+            ),
+            cells_bits(&mock_cells),
+            "horizontal ({x}, {y})"
+        );
+    }
+}
+
+/// The zone moves players with `apply_move_pvp`, so `apply_move` is only run
+/// here, on the cases where its stats saturate or its player is dead.
+#[test]
+fn apply_move_circuit() {
+    let (evaluator, users) = keygen();
+    let mock_zone = MockZone::new(&Map::builtin());
+    let players_coords = mock_zone.players.iter().map(|player| player.data.loc);
+    let obstacles: [_; NUM_OBSTACLES + 4] = chain![mock_zone.obstacles, players_coords.clone()]
+        .collect_vec()
+        .try_into()
+        .unwrap();
+    let items = mock_zone.items.each_ref().map(|item| item.data);
+    let monsters = mock_zone.monsters.each_ref().map(|monster| monster.data);
+
+    // Item 0 is at (7, 3), monster 1 at (0, 13).
+    let strong = |x, y, hp| mock_zone::PlayerEncryptedData {
+        loc: mock_zone::MockEncryptedCoord { x, y },
+        hp,
+        atk: 250,
+        points: 255,
+    };
+    for player in [strong(8, 3, 250), strong(8, 3, 0), strong(1, 13, 250)] {
+        let (mock_player, mock_items, mock_monsters) = mock_zone::fhe_apply_move(
+            player,
+            Direction::Left,
+            mock_zone.height,
+            mock_zone.width,
+            obstacles,
+            monsters,
+            items,
+        );
+        let encrypt = |bits: Vec<bool>| {
+            evaluator
+                .unbatch(&evaluator.batched_pk_encrypt(bits))
+                .unwrap()
+        };
+        let output = apply_move(
+            &encrypt(vec![false, true]),
+            &encrypt(items.iter().flat_map(item_bits).collect()),
+            &encrypt(monsters.iter().flat_map(monster_bits).collect()),
+            &encrypt(player_bits(&player)),
+            &encrypt(
+                players_coords
+                    .clone()
+                    .flat_map(|loc| byte_bits([loc.x, loc.y]))
+                    .collect(),
+            ),
+        );
+        assert_eq!(
+            decrypt_bits(&evaluator, &users, &output),
+            chain![
+                player_bits(&mock_player),
+                mock_items.iter().flat_map(item_bits),
+                mock_monsters.iter().flat_map(monster_bits)
+            ]
+            .collect_vec(),
+            "{player:?}"
+        );
+    }
+}
+
+#[test]
+fn circuits_match_layouts() {
+    zone::check_circuits().unwrap();
+}
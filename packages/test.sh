@@ -84,6 +84,11 @@ curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o
 curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o /dev/null http://localhost:8003/reset_game
 curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o /dev/null http://localhost:8004/reset_game
 
+# Each game has its own keys, generated by the clients with the server.
+echo "Waiting for key generation..."
+
+sleep 10
+
 echo "Getting player data..."
 
 log "  Player 1:" $(curl -sS --header "Content-Type: application/json" --request POST --data '{}' http://localhost:8001/get_player)
@@ -128,7 +133,9 @@ curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o
 curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o /dev/null http://localhost:8003/reset_game
 curl -sS --header "Content-Type: application/json" --request POST --data '{}' -o /dev/null http://localhost:8004/reset_game
 
-sleep 3
+echo "Waiting for key generation..."
+
+sleep 10
 
 echo "Getting player data..."
 
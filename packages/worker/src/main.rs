@@ -1,18 +1,18 @@
 use itertools::Itertools;
-use phantom::{PhantomBsKey, PhantomEvaluator, PhantomParam, PhantomPk, PhantomRpKey};
+use phantom::{Enc, EncU8, Fingerprint, PhantomEvaluator};
 use rocket::figment::{util::map, Figment};
 use rocket::http::Status;
 use rocket::response::status::Custom;
 use rocket::serde::json::Json;
 use rocket::{Config, State};
-use server::bad_request;
-use server::zone::{EncryptedCoord, ZoneDiff};
+use server::telemetry::{self, RequestId};
+use server::zone::{CellEncryptedData, EncryptedCoord, ZoneDiff, PHANTOM_PARAM};
+use server::{bad_request, metrics};
 use server::{worker::*, zone::Zone};
 use std::array::from_fn;
 use std::env;
 use std::sync::{Arc, LazyLock};
 use tokio::sync::Mutex;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[macro_use]
 extern crate rocket;
@@ -52,7 +52,12 @@ impl WorkerState {
 type SharedState = Arc<Mutex<WorkerState>>;
 
 #[post("/init", format = "json", data = "<request>")]
-async fn init(state: &State<SharedState>, request: Json<InitRequest>) -> Json<InitResponse> {
+#[tracing::instrument(skip_all, fields(%request_id))]
+async fn init(
+    state: &State<SharedState>,
+    request: Json<InitRequest>,
+    request_id: RequestId,
+) -> Result<Json<InitResponse>, Custom<String>> {
     let InitRequest {
         zone_width,
         zone_height,
@@ -60,24 +65,37 @@ async fn init(state: &State<SharedState>, request: Json<InitRequest>) -> Json<In
         keys,
     } = request.0;
     let mut app_state = state.lock().await;
-    // if let Some((pk, bs_key, rp_key)) = keys {
-    //     app_state.evaluator.set_pk(pk);
-    //     app_state.evaluator.set_bs_key(bs_key);
-    //     app_state.evaluator.set_rp_key(rp_key);
-    // }
+    if let Some((pk, bs_key, rp_key)) = keys {
+        tracing::info!(
+            pk = %pk.fingerprint(),
+            rp_key = %rp_key.fingerprint(),
+            bs_key = %bs_key.fingerprint(),
+            "received keys"
+        );
+        app_state.evaluator.set_pk(pk);
+        app_state.evaluator.set_bs_key(bs_key);
+        app_state.evaluator.set_rp_key(rp_key);
+        metrics::KEY_VERSION.inc();
+    }
+    if app_state.evaluator.bs_key().is_none() {
+        return Err(bad_request("Keys are not received yet"));
+    }
     app_state.zone = Some(Zone::from_cts(
         zone_width,
         zone_height,
         zone_cts,
         &app_state.evaluator,
     ));
-    Json(InitResponse {})
+    metrics::ZONE_VERSION.inc();
+    Ok(Json(InitResponse {}))
 }
 
 #[post("/get_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cells(
     state: &State<SharedState>,
     request: Json<RequestWithDiff<GetCellsRequest>>,
+    request_id: RequestId,
 ) -> Result<Json<GetCellsResponse>, Custom<String>> {
     let RequestWithDiff { request, diff } = request.0;
     let cell_data = {
@@ -85,7 +103,8 @@ async fn get_cells(
         worker_state.apply_diff(diff)?;
         let zone = worker_state.zone()?;
 
-        let bits = worker_state.evaluator.unbatch(&request.coords);
+        let bits =
+            telemetry::unbatch(&worker_state.evaluator, &request.coords).map_err(bad_request)?;
         if bits.len() % 16 != 0 {
             return Err(bad_request("invalid coordinates"));
         }
@@ -94,15 +113,17 @@ async fn get_cells(
             .chunks(16)
             .into_iter()
             .map(|mut chunk| EncryptedCoord {
-                x: from_fn(|_| chunk.next().unwrap()),
-                y: from_fn(|_| chunk.next().unwrap()),
+                x: EncU8::from_bits(&mut chunk),
+                y: EncU8::from_bits(&mut chunk),
             })
             .collect();
         let cells = zone.get_cells(request.player_id, coords);
 
-        worker_state
-            .evaluator
-            .pack(cells.iter().flat_map(|cell| cell.bits()))
+        telemetry::pack_chunks(
+            &worker_state.evaluator,
+            cells.iter().flat_map(|cell| cell.bits()),
+            CellEncryptedData::BITS,
+        )
     };
 
     info!("processed /get_cells request");
@@ -111,9 +132,11 @@ async fn get_cells(
 }
 
 #[post("/get_five_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_five_cells(
     state: &State<SharedState>,
     request: Json<RequestWithDiff<GetFiveCellsRequest>>,
+    request_id: RequestId,
 ) -> Result<Json<GetFiveCellsResponse>, Custom<String>> {
     let RequestWithDiff { request, diff } = request.0;
     let cell_data = {
@@ -121,20 +144,22 @@ async fn get_five_cells(
         worker_state.apply_diff(diff)?;
         let zone = worker_state.zone()?;
 
-        let bits = worker_state.evaluator.unbatch(&request.coords);
+        let bits =
+            telemetry::unbatch(&worker_state.evaluator, &request.coords).map_err(bad_request)?;
         if bits.len() != 5 * 16 {
             return Err(bad_request("invalid coordinates"));
         }
         let mut bits = bits.into_iter();
         let coords = from_fn(|_| EncryptedCoord {
-            x: from_fn(|_| bits.next().unwrap()),
-            y: from_fn(|_| bits.next().unwrap()),
+            x: EncU8::from_bits(&mut bits),
+            y: EncU8::from_bits(&mut bits),
         });
         let cells = zone.get_five_cells(request.player_id, coords);
 
-        worker_state
-            .evaluator
-            .pack(cells.iter().flat_map(|cell| cell.bits()))
+        telemetry::pack(
+            &worker_state.evaluator,
+            cells.iter().flat_map(|cell| cell.bits()),
+        )
     };
 
     info!("processed /get_five_cells request");
@@ -143,9 +168,11 @@ async fn get_five_cells(
 }
 
 #[post("/get_cross_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_cross_cells(
     state: &State<SharedState>,
     request: Json<RequestWithDiff<GetCrossCellsRequest>>,
+    request_id: RequestId,
 ) -> Result<Json<GetCrossCellsResponse>, Custom<String>> {
     let RequestWithDiff { request, diff } = request.0;
     let cell_data = {
@@ -155,9 +182,10 @@ async fn get_cross_cells(
 
         let cells = zone.get_cross_cells(request.player_id);
 
-        worker_state
-            .evaluator
-            .pack(cells.iter().flat_map(|cell| cell.bits()))
+        telemetry::pack(
+            &worker_state.evaluator,
+            cells.iter().flat_map(|cell| cell.bits()),
+        )
     };
 
     info!("processed /get_cross_cells request");
@@ -166,9 +194,11 @@ async fn get_cross_cells(
 }
 
 #[post("/get_vertical_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_vertical_cells(
     state: &State<SharedState>,
     request: Json<RequestWithDiff<GetVerticalCellsRequest>>,
+    request_id: RequestId,
 ) -> Result<Json<GetVerticalCellsResponse>, Custom<String>> {
     let RequestWithDiff { request, diff } = request.0;
     let cell_data = {
@@ -176,20 +206,22 @@ async fn get_vertical_cells(
         worker_state.apply_diff(diff)?;
         let zone = worker_state.zone()?;
 
-        let bits = worker_state.evaluator.unbatch(&request.coord);
+        let bits =
+            telemetry::unbatch(&worker_state.evaluator, &request.coord).map_err(bad_request)?;
         if bits.len() != 16 {
             return Err(bad_request("invalid coordinate"));
         }
         let mut bits = bits.into_iter();
         let coord = EncryptedCoord {
-            x: from_fn(|_| bits.next().unwrap()),
-            y: from_fn(|_| bits.next().unwrap()),
+            x: EncU8::from_bits(&mut bits),
+            y: EncU8::from_bits(&mut bits),
         };
         let cells = zone.get_vertical_cells(request.player_id, coord);
 
-        worker_state
-            .evaluator
-            .pack(cells.iter().flat_map(|cell| cell.bits()))
+        telemetry::pack(
+            &worker_state.evaluator,
+            cells.iter().flat_map(|cell| cell.bits()),
+        )
     };
 
     info!("processed /get_vertical_cells request");
@@ -198,9 +230,11 @@ async fn get_vertical_cells(
 }
 
 #[post("/get_horizontal_cells", format = "json", data = "<request>")]
+#[tracing::instrument(skip_all, fields(%request_id))]
 async fn get_horizontal_cells(
     state: &State<SharedState>,
     request: Json<RequestWithDiff<GetHorizontalCellsRequest>>,
+    request_id: RequestId,
 ) -> Result<Json<GetHorizontalCellsResponse>, Custom<String>> {
     let RequestWithDiff { request, diff } = request.0;
     let cell_data = {
@@ -208,20 +242,22 @@ async fn get_horizontal_cells(
         worker_state.apply_diff(diff)?;
         let zone = worker_state.zone()?;
 
-        let bits = worker_state.evaluator.unbatch(&request.coord);
+        let bits =
+            telemetry::unbatch(&worker_state.evaluator, &request.coord).map_err(bad_request)?;
         if bits.len() != 16 {
             return Err(bad_request("invalid coordinate"));
         }
         let mut bits = bits.into_iter();
         let coord = EncryptedCoord {
-            x: from_fn(|_| bits.next().unwrap()),
-            y: from_fn(|_| bits.next().unwrap()),
+            x: EncU8::from_bits(&mut bits),
+            y: EncU8::from_bits(&mut bits),
         };
         let cells = zone.get_horizontal_cells(request.player_id, coord);
 
-        worker_state
-            .evaluator
-            .pack(cells.iter().flat_map(|cell| cell.bits()))
+        telemetry::pack(
+            &worker_state.evaluator,
+            cells.iter().flat_map(|cell| cell.bits()),
+        )
     };
 
     info!("processed /get_horizontal_cells request");
@@ -231,23 +267,15 @@ async fn get_horizontal_cells(
 
 #[rocket::main]
 async fn main() -> Result<(), rocket::Error> {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    let pk: PhantomPk = bincode::deserialize(include_bytes!("../../server/pk")).unwrap();
-    let rp_key: PhantomRpKey = bincode::deserialize(include_bytes!("../../server/rp_key")).unwrap();
-    let bs_key: PhantomBsKey = bincode::deserialize(include_bytes!("../../server/bs_key")).unwrap();
-
-    let mut evaluator = PhantomEvaluator::new(PhantomParam::I_4P_40);
-    evaluator.set_pk(pk);
-    evaluator.set_rp_key(rp_key);
-    evaluator.set_bs_key(bs_key);
+    telemetry::init("worker");
+    if let Err(err) = server::zone::check_circuits() {
+        panic!("{err}");
+    }
 
     let shared_state: Arc<Mutex<WorkerState>> = Arc::new(Mutex::new(WorkerState {
         zone: None, // 32x32 zone, will be initialized when /init is called.
-        evaluator,
+        // Keys will be received with the zone when keygen is finished.
+        evaluator: PhantomEvaluator::new(*PHANTOM_PARAM),
     }));
 
     rocket::Rocket::custom(
@@ -267,6 +295,7 @@ async fn main() -> Result<(), rocket::Error> {
             get_cross_cells,
             get_vertical_cells,
             get_horizontal_cells,
+            metrics::metrics,
         ],
     )
     .launch()